@@ -0,0 +1,143 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! Minimal HTTP client for the crawlrs API.
+//!
+//! Adds the bearer token to every request and unwraps the `{success, data, error}`
+//! response envelope, turning error responses into [`ApiError`].
+
+use std::fmt;
+use std::time::Duration;
+
+use reqwest::{Client, RequestBuilder};
+use serde_json::Value;
+
+/// Error returned by the API.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApiError {
+    /// HTTP status code
+    pub status: u16,
+    /// Error code from the response envelope, e.g. `VALIDATION_ERROR`
+    pub code: String,
+    /// Human-readable message
+    pub message: String,
+    /// Seconds to wait before retrying, present on `429` responses
+    pub retry_after_seconds: Option<u64>,
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}: {}", self.status, self.code, self.message)?;
+        if self.status == 401 {
+            write!(f, " (set --api-key or CRAWLRS_API_KEY)")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ApiError {}
+
+/// Unwrap the response envelope into its `data`, or the API error it carries.
+pub fn unwrap_envelope(status: u16, body: Value) -> Result<Value, ApiError> {
+    if (200..300).contains(&status) && body.get("success").and_then(Value::as_bool) != Some(false) {
+        return Ok(body.get("data").cloned().unwrap_or(body));
+    }
+
+    let error = body.get("error");
+    let field = |name: &str| {
+        error
+            .and_then(|e| e.get(name))
+            .and_then(Value::as_str)
+            .map(str::to_string)
+    };
+    Err(ApiError {
+        status,
+        code: field("code").unwrap_or_else(|| "HTTP_ERROR".to_string()),
+        message: field("message")
+            .unwrap_or_else(|| format!("request failed with status {}", status)),
+        retry_after_seconds: body.get("retry_after_seconds").and_then(Value::as_u64),
+    })
+}
+
+/// crawlrs API client.
+#[derive(Debug, Clone)]
+pub struct ApiClient {
+    http: Client,
+    base_url: String,
+    api_key: Option<String>,
+}
+
+impl ApiClient {
+    /// Create a client for `base_url`, e.g. `http://localhost:8899`.
+    pub fn new(base_url: &str, api_key: Option<String>) -> anyhow::Result<Self> {
+        let http = Client::builder()
+            .timeout(Duration::from_secs(120))
+            .user_agent(concat!("crawlrs-cli/", env!("CARGO_PKG_VERSION")))
+            .build()?;
+        Ok(Self {
+            http,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            api_key,
+        })
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}{}", self.base_url, path)
+    }
+
+    /// `GET` a path and return the response `data`.
+    pub async fn get(&self, path: &str) -> anyhow::Result<Value> {
+        self.send(self.http.get(self.url(path))).await
+    }
+
+    /// `POST` a JSON body to a path and return the response `data`.
+    pub async fn post(&self, path: &str, body: &Value) -> anyhow::Result<Value> {
+        self.send(self.http.post(self.url(path)).json(body)).await
+    }
+
+    async fn send(&self, request: RequestBuilder) -> anyhow::Result<Value> {
+        let request = match &self.api_key {
+            Some(api_key) => request.bearer_auth(api_key),
+            None => request,
+        };
+        let response = request.send().await?;
+        let status = response.status().as_u16();
+        let text = response.text().await?;
+        let body = serde_json::from_str(&text).unwrap_or(Value::String(text));
+        Ok(unwrap_envelope(status, body)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_unwrap_envelope_returns_data() {
+        let body = json!({"success": true, "data": {"id": "abc"}});
+        assert_eq!(unwrap_envelope(201, body).unwrap(), json!({"id": "abc"}));
+    }
+
+    #[test]
+    fn test_unwrap_envelope_maps_errors() {
+        let body = json!({
+            "success": false,
+            "error": {"code": "RATE_LIMITED", "message": "Rate limit exceeded"},
+            "retry_after_seconds": 30
+        });
+        let error = unwrap_envelope(429, body).unwrap_err();
+        assert_eq!(error.code, "RATE_LIMITED");
+        assert_eq!(error.message, "Rate limit exceeded");
+        assert_eq!(error.retry_after_seconds, Some(30));
+    }
+
+    #[test]
+    fn test_unwrap_envelope_handles_non_json_errors() {
+        let error = unwrap_envelope(502, Value::String("Bad Gateway".into())).unwrap_err();
+        assert_eq!(error.code, "HTTP_ERROR");
+        assert_eq!(error.status, 502);
+    }
+}
@@ -0,0 +1,252 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! Command implementations.
+
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
+
+use anyhow::{bail, Context};
+use serde_json::{json, Value};
+
+use crate::client::{ApiClient, ApiError};
+use crate::output::{crawl_progress, render_results, render_scrape, ResultsFormat, ScrapeFormat};
+
+/// Final scrape task statuses.
+const TASK_TERMINAL: &[&str] = &["completed", "failed", "cancelled"];
+
+/// Final crawl statuses.
+const CRAWL_TERMINAL: &[&str] = &["completed", "completed_with_limit", "failed", "cancelled"];
+
+/// How long to poll and how often.
+#[derive(Debug, Clone, Copy)]
+pub struct PollOptions {
+    /// Delay between status requests
+    pub interval: Duration,
+    /// Give up after this long; `None` waits indefinitely
+    pub timeout: Option<Duration>,
+}
+
+fn status_of(value: &Value) -> &str {
+    value
+        .get("status")
+        .and_then(Value::as_str)
+        .unwrap_or_default()
+}
+
+fn id_of(value: &Value) -> anyhow::Result<String> {
+    value
+        .get("id")
+        .and_then(Value::as_str)
+        .map(str::to_string)
+        .context("response did not contain an id")
+}
+
+fn exit_code(status: &str) -> ExitCode {
+    match status {
+        "failed" | "cancelled" => ExitCode::FAILURE,
+        _ => ExitCode::SUCCESS,
+    }
+}
+
+/// Poll `path` until its `status` is one of `terminal`.
+///
+/// Rate-limited polls wait for the server's `retry_after_seconds` and try again.
+async fn poll_until(
+    client: &ApiClient,
+    path: &str,
+    terminal: &[&str],
+    options: PollOptions,
+    mut on_update: impl FnMut(&Value),
+) -> anyhow::Result<Value> {
+    let started = Instant::now();
+    loop {
+        let delay = match client.get(path).await {
+            Ok(value) => {
+                on_update(&value);
+                if terminal.contains(&status_of(&value)) {
+                    return Ok(value);
+                }
+                options.interval
+            }
+            Err(e) => {
+                let retry_after = e
+                    .downcast_ref::<ApiError>()
+                    .filter(|api| api.status == 429)
+                    .map(|api| api.retry_after_seconds.unwrap_or(5));
+                match retry_after {
+                    Some(seconds) => Duration::from_secs(seconds),
+                    None => return Err(e),
+                }
+            }
+        };
+        if options
+            .timeout
+            .is_some_and(|timeout| started.elapsed() + delay > timeout)
+        {
+            bail!(
+                "timed out after {}s waiting for {}",
+                started.elapsed().as_secs(),
+                path
+            );
+        }
+        tokio::time::sleep(delay).await;
+    }
+}
+
+/// `crawlrs scrape <url>`
+pub async fn scrape(
+    client: &ApiClient,
+    url: &str,
+    formats: &[String],
+    format: ScrapeFormat,
+    wait: Option<PollOptions>,
+) -> anyhow::Result<ExitCode> {
+    let mut body = json!({ "url": url });
+    if !formats.is_empty() {
+        body["formats"] = json!(formats);
+    }
+    let task = client.post("/v1/scrape", &body).await?;
+    let id = id_of(&task)?;
+
+    let Some(options) = wait else {
+        println!("{}", id);
+        return Ok(ExitCode::SUCCESS);
+    };
+
+    let status = poll_until(
+        client,
+        &format!("/v1/scrape/{}", id),
+        TASK_TERMINAL,
+        options,
+        |_| {},
+    )
+    .await?;
+    if status_of(&status) != "completed" {
+        let error = status
+            .get("error")
+            .and_then(Value::as_str)
+            .unwrap_or("no error reported");
+        eprintln!("scrape {} {}: {}", id, status_of(&status), error);
+        return Ok(exit_code(status_of(&status)));
+    }
+    println!("{}", render_scrape(&status, format)?);
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Crawl parameters accepted by `crawlrs crawl`.
+#[derive(Debug, Clone, Default)]
+pub struct CrawlOptions {
+    /// Maximum link depth from the start URL
+    pub depth: u32,
+    /// Maximum number of pages
+    pub limit: Option<u32>,
+    /// Regex patterns for URLs to include
+    pub include: Vec<String>,
+    /// Regex patterns for URLs to exclude
+    pub exclude: Vec<String>,
+}
+
+/// Build the `POST /v1/crawl` request body.
+pub fn crawl_request(url: &str, options: &CrawlOptions) -> Value {
+    let mut config = json!({ "max_depth": options.depth });
+    if let Some(limit) = options.limit {
+        config["limit"] = json!(limit);
+    }
+    if !options.include.is_empty() {
+        config["include_patterns"] = json!(options.include);
+    }
+    if !options.exclude.is_empty() {
+        config["exclude_patterns"] = json!(options.exclude);
+    }
+    // The client polls, so do not hold the request open on the server
+    json!({ "url": url, "config": config, "sync_wait_ms": 0 })
+}
+
+/// `crawlrs crawl <url>`
+pub async fn crawl(
+    client: &ApiClient,
+    url: &str,
+    options: &CrawlOptions,
+    wait: Option<PollOptions>,
+) -> anyhow::Result<ExitCode> {
+    let crawl = client
+        .post("/v1/crawl", &crawl_request(url, options))
+        .await?;
+    let id = id_of(&crawl)?;
+
+    let Some(poll) = wait else {
+        println!("{}", id);
+        return Ok(ExitCode::SUCCESS);
+    };
+
+    eprintln!("{}", crawl_progress(&crawl));
+    let mut last = crawl_progress(&crawl);
+    let crawl = poll_until(
+        client,
+        &format!("/v1/crawl/{}", id),
+        CRAWL_TERMINAL,
+        poll,
+        |value| {
+            let progress = crawl_progress(value);
+            if progress != last {
+                eprintln!("{}", progress);
+                last = progress;
+            }
+        },
+    )
+    .await?;
+    println!("{}", serde_json::to_string_pretty(&crawl)?);
+    Ok(exit_code(status_of(&crawl)))
+}
+
+/// `crawlrs results <crawl_id>`
+pub async fn results(
+    client: &ApiClient,
+    crawl_id: &str,
+    format: ResultsFormat,
+) -> anyhow::Result<ExitCode> {
+    let results = client
+        .get(&format!("/v1/crawl/{}/results", crawl_id))
+        .await?;
+    let rendered = render_results(&results, format)?;
+    if !rendered.is_empty() {
+        println!("{}", rendered);
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_crawl_request_only_sets_given_options() {
+        let body = crawl_request(
+            "https://example.com",
+            &CrawlOptions {
+                depth: 2,
+                limit: Some(50),
+                include: vec!["/blog/.*".to_string()],
+                exclude: Vec::new(),
+            },
+        );
+        assert_eq!(
+            body,
+            json!({
+                "url": "https://example.com",
+                "config": {"max_depth": 2, "limit": 50, "include_patterns": ["/blog/.*"]},
+                "sync_wait_ms": 0
+            })
+        );
+    }
+
+    #[test]
+    fn test_exit_code_fails_on_failed_or_cancelled() {
+        assert_eq!(exit_code("failed"), ExitCode::FAILURE);
+        assert_eq!(exit_code("cancelled"), ExitCode::FAILURE);
+        assert_eq!(exit_code("completed_with_limit"), ExitCode::SUCCESS);
+    }
+}
@@ -0,0 +1,234 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! crawlrs command line client.
+//!
+//! Talks to a running crawlrs server over its HTTP API.
+//!
+//! Usage:
+//!   CRAWLRS_API_KEY=sk-... crawlrs-cli scrape https://example.com
+//!   crawlrs-cli crawl https://example.com --depth 2 --wait
+//!   crawlrs-cli results <crawl_id> --format jsonl
+
+mod client;
+mod commands;
+mod output;
+
+use std::process::ExitCode;
+use std::time::Duration;
+
+use clap::{Args, Parser, Subcommand};
+
+use crate::client::ApiClient;
+use crate::commands::{CrawlOptions, PollOptions};
+use crate::output::{ResultsFormat, ScrapeFormat};
+
+/// Command line client for the crawlrs API
+#[derive(Debug, Parser)]
+#[command(name = "crawlrs-cli", version)]
+struct Cli {
+    /// Base URL of the crawlrs server
+    #[arg(
+        long,
+        global = true,
+        env = "CRAWLRS_API_URL",
+        default_value = "http://localhost:8899"
+    )]
+    api_url: String,
+
+    /// API key sent as a bearer token
+    #[arg(long, global = true, env = "CRAWLRS_API_KEY", hide_env_values = true)]
+    api_key: Option<String>,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Scrape a single page and print its content
+    Scrape {
+        /// Page URL
+        url: String,
+
+        /// Formats to request, e.g. markdown,html (server default if omitted)
+        #[arg(long, value_delimiter = ',')]
+        formats: Vec<String>,
+
+        /// What to print
+        #[arg(long, value_enum, default_value_t = ScrapeFormat::Content)]
+        format: ScrapeFormat,
+
+        /// Print the task ID without waiting for the result
+        #[arg(long)]
+        no_wait: bool,
+
+        #[command(flatten)]
+        poll: PollArgs,
+    },
+
+    /// Start a crawl
+    Crawl {
+        /// Start URL
+        url: String,
+
+        /// Maximum link depth from the start URL
+        #[arg(long, default_value_t = 1)]
+        depth: u32,
+
+        /// Maximum number of pages, including the start URL
+        #[arg(long)]
+        limit: Option<u32>,
+
+        /// Regex for URLs to include (repeatable)
+        #[arg(long)]
+        include: Vec<String>,
+
+        /// Regex for URLs to exclude (repeatable)
+        #[arg(long)]
+        exclude: Vec<String>,
+
+        /// Wait for the crawl to finish, reporting progress on stderr
+        #[arg(long)]
+        wait: bool,
+
+        #[command(flatten)]
+        poll: PollArgs,
+    },
+
+    /// Print the results of a crawl
+    Results {
+        /// Crawl ID
+        crawl_id: String,
+
+        /// Output format
+        #[arg(long, value_enum, default_value_t = ResultsFormat::Json)]
+        format: ResultsFormat,
+    },
+}
+
+#[derive(Debug, Args)]
+struct PollArgs {
+    /// Seconds between status checks while waiting
+    #[arg(long, default_value_t = 2)]
+    poll_interval: u64,
+
+    /// Give up waiting after this many seconds (0 waits indefinitely)
+    #[arg(long, default_value_t = 0)]
+    timeout: u64,
+}
+
+impl PollArgs {
+    fn options(&self) -> PollOptions {
+        PollOptions {
+            interval: Duration::from_secs(self.poll_interval.max(1)),
+            timeout: (self.timeout > 0).then(|| Duration::from_secs(self.timeout)),
+        }
+    }
+}
+
+async fn run(cli: Cli) -> anyhow::Result<ExitCode> {
+    let client = ApiClient::new(&cli.api_url, cli.api_key)?;
+    match cli.command {
+        Command::Scrape {
+            url,
+            formats,
+            format,
+            no_wait,
+            poll,
+        } => {
+            let wait = (!no_wait).then(|| poll.options());
+            commands::scrape(&client, &url, &formats, format, wait).await
+        }
+        Command::Crawl {
+            url,
+            depth,
+            limit,
+            include,
+            exclude,
+            wait,
+            poll,
+        } => {
+            let options = CrawlOptions {
+                depth,
+                limit,
+                include,
+                exclude,
+            };
+            commands::crawl(&client, &url, &options, wait.then(|| poll.options())).await
+        }
+        Command::Results { crawl_id, format } => {
+            commands::results(&client, &crawl_id, format).await
+        }
+    }
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    match run(Cli::parse()).await {
+        Ok(code) => code,
+        Err(e) => {
+            eprintln!("error: {:#}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_crawl_command() {
+        let cli = Cli::try_parse_from([
+            "crawlrs-cli",
+            "crawl",
+            "https://example.com",
+            "--depth",
+            "2",
+            "--wait",
+            "--include",
+            "/blog/.*",
+        ])
+        .unwrap();
+        match cli.command {
+            Command::Crawl {
+                depth,
+                wait,
+                include,
+                ..
+            } => {
+                assert_eq!(depth, 2);
+                assert!(wait);
+                assert_eq!(include, vec!["/blog/.*".to_string()]);
+            }
+            other => panic!("unexpected command: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_results_format() {
+        let cli =
+            Cli::try_parse_from(["crawlrs-cli", "results", "abc", "--format", "jsonl"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Command::Results {
+                format: ResultsFormat::Jsonl,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_zero_timeout_waits_indefinitely() {
+        let poll = PollArgs {
+            poll_interval: 0,
+            timeout: 0,
+        };
+        let options = poll.options();
+        assert_eq!(options.interval, Duration::from_secs(1));
+        assert!(options.timeout.is_none());
+    }
+}
@@ -0,0 +1,112 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! Output formatting for command results.
+
+use clap::ValueEnum;
+use serde_json::Value;
+
+/// Output format of `crawlrs scrape`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ScrapeFormat {
+    /// Page content only
+    Content,
+    /// Full task status as JSON
+    Json,
+}
+
+/// Output format of `crawlrs results`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ResultsFormat {
+    /// Pretty-printed JSON array
+    Json,
+    /// One JSON object per line
+    Jsonl,
+}
+
+/// Render a scrape task status.
+pub fn render_scrape(status: &Value, format: ScrapeFormat) -> anyhow::Result<String> {
+    match format {
+        ScrapeFormat::Content => Ok(status
+            .pointer("/result/content")
+            .and_then(Value::as_str)
+            .unwrap_or_default()
+            .to_string()),
+        ScrapeFormat::Json => Ok(serde_json::to_string_pretty(status)?),
+    }
+}
+
+/// Render crawl results.
+pub fn render_results(results: &Value, format: ResultsFormat) -> anyhow::Result<String> {
+    let items = match results {
+        Value::Array(items) => items.as_slice(),
+        other => std::slice::from_ref(other),
+    };
+    match format {
+        ResultsFormat::Json => Ok(serde_json::to_string_pretty(items)?),
+        ResultsFormat::Jsonl => {
+            let lines = items
+                .iter()
+                .map(serde_json::to_string)
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok(lines.join("\n"))
+        }
+    }
+}
+
+/// One-line crawl progress, e.g. `crawl 1f2e…: processing, 12/40 pages (1 failed)`.
+pub fn crawl_progress(crawl: &Value) -> String {
+    let count = |name: &str| crawl.get(name).and_then(Value::as_i64).unwrap_or(0);
+    format!(
+        "crawl {}: {}, {}/{} pages ({} failed)",
+        crawl.get("id").and_then(Value::as_str).unwrap_or("?"),
+        crawl
+            .get("status")
+            .and_then(Value::as_str)
+            .unwrap_or("unknown"),
+        count("completed_tasks"),
+        count("total_tasks"),
+        count("failed_tasks"),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_render_results_jsonl_writes_one_line_per_result() {
+        let results = json!([{"url": "https://a"}, {"url": "https://b"}]);
+        assert_eq!(
+            render_results(&results, ResultsFormat::Jsonl).unwrap(),
+            "{\"url\":\"https://a\"}\n{\"url\":\"https://b\"}"
+        );
+    }
+
+    #[test]
+    fn test_render_scrape_content() {
+        let status = json!({"status": "completed", "result": {"content": "# Title"}});
+        assert_eq!(
+            render_scrape(&status, ScrapeFormat::Content).unwrap(),
+            "# Title"
+        );
+    }
+
+    #[test]
+    fn test_crawl_progress() {
+        let crawl = json!({
+            "id": "c1",
+            "status": "processing",
+            "total_tasks": 40,
+            "completed_tasks": 12,
+            "failed_tasks": 1
+        });
+        assert_eq!(
+            crawl_progress(&crawl),
+            "crawl c1: processing, 12/40 pages (1 failed)"
+        );
+    }
+}
@@ -0,0 +1,323 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! Typed API client.
+
+use std::time::Duration;
+
+use reqwest::{Client, Method, RequestBuilder};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::error::{ClientError, Result};
+use crate::{
+    Crawl, CrawlRequestDto, CreateWebhookRequest, DomainThrottleStatus, ExtractRequestDto,
+    ExtractResponseDto, ScrapeRequestDto, ScrapeResponseDto, ScrapeResult, ScrapeStatusResponseDto,
+    SearchRequestDto, SearchResponseDto, TestWebhookRequest, Webhook, WebhookListResponse,
+    WebhookTestResult,
+};
+
+/// Default request timeout.
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// Response of `GET /v1/crawl/{id}`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CrawlStatusResponse {
+    /// The crawl
+    #[serde(flatten)]
+    pub crawl: Crawl,
+    /// Present while the crawl's root domain is being throttled
+    #[serde(default)]
+    pub throttle: Option<DomainThrottleStatus>,
+}
+
+/// Decode a response body, unwrapping the `{success, data, error}` envelope.
+///
+/// Error responses become [`ClientError::Api`]. Bodies without an envelope
+/// (e.g. `POST /v1/webhooks`) and empty bodies (`204 No Content`) are decoded as-is.
+pub fn decode_response<T: DeserializeOwned>(status: u16, body: &str) -> Result<T> {
+    let body: Value = if body.trim().is_empty() {
+        Value::Null
+    } else {
+        serde_json::from_str(body).unwrap_or_else(|_| Value::String(body.to_string()))
+    };
+
+    let success = body.get("success").and_then(Value::as_bool);
+    if (200..300).contains(&status) && success != Some(false) {
+        let data = match body {
+            Value::Object(mut map) if success.is_some() => map.remove("data").unwrap_or_default(),
+            other => other,
+        };
+        return Ok(serde_json::from_value(data)?);
+    }
+
+    let error = body.get("error");
+    let field = |name: &str| {
+        error
+            .and_then(|e| e.get(name))
+            .and_then(Value::as_str)
+            .map(str::to_string)
+    };
+    Err(ClientError::Api {
+        status,
+        code: field("code").unwrap_or_else(|| "HTTP_ERROR".to_string()),
+        message: field("message")
+            .or_else(|| body.as_str().map(str::to_string))
+            .unwrap_or_else(|| format!("request failed with status {}", status)),
+        retry_after_seconds: body.get("retry_after_seconds").and_then(Value::as_u64),
+    })
+}
+
+/// Async client for the crawlrs HTTP API.
+///
+/// ```no_run
+/// use crawlrs_client::{CrawlrsClient, ScrapeRequestDto};
+///
+/// # async fn run() -> crawlrs_client::Result<()> {
+/// let client = CrawlrsClient::new("http://localhost:8899", "sk-...")?;
+/// let task = client
+///     .scrape(&ScrapeRequestDto {
+///         url: "https://example.com".to_string(),
+///         ..Default::default()
+///     })
+///     .await?;
+/// let status = client.get_scrape(task.id).await?;
+/// println!("{}", status.status);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct CrawlrsClient {
+    http: Client,
+    base_url: String,
+    api_key: String,
+}
+
+impl CrawlrsClient {
+    /// Create a client for `base_url`, e.g. `http://localhost:8899`.
+    pub fn new(base_url: impl Into<String>, api_key: impl Into<String>) -> Result<Self> {
+        let http = Client::builder()
+            .timeout(DEFAULT_TIMEOUT)
+            .user_agent(concat!("crawlrs-client/", env!("CARGO_PKG_VERSION")))
+            .build()?;
+        Ok(Self::with_http_client(http, base_url, api_key))
+    }
+
+    /// Create a client that sends requests through an existing `reqwest::Client`.
+    pub fn with_http_client(
+        http: Client,
+        base_url: impl Into<String>,
+        api_key: impl Into<String>,
+    ) -> Self {
+        Self {
+            http,
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            api_key: api_key.into(),
+        }
+    }
+
+    /// Base URL requests are sent to.
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    fn request(&self, method: Method, path: &str) -> RequestBuilder {
+        self.http
+            .request(method, format!("{}{}", self.base_url, path))
+            .bearer_auth(&self.api_key)
+    }
+
+    async fn send<T: DeserializeOwned>(&self, request: RequestBuilder) -> Result<T> {
+        let response = request.send().await?;
+        let status = response.status().as_u16();
+        let body = response.text().await?;
+        decode_response(status, &body)
+    }
+
+    async fn get<T: DeserializeOwned>(&self, path: &str) -> Result<T> {
+        self.send(self.request(Method::GET, path)).await
+    }
+
+    async fn post<B: Serialize + ?Sized, T: DeserializeOwned>(
+        &self,
+        path: &str,
+        body: &B,
+    ) -> Result<T> {
+        self.send(self.request(Method::POST, path).json(body)).await
+    }
+
+    // ---- Scrape ----
+
+    /// `POST /v1/scrape` — queue a scrape task.
+    pub async fn scrape(&self, request: &ScrapeRequestDto) -> Result<ScrapeResponseDto> {
+        self.post("/v1/scrape", request).await
+    }
+
+    /// `GET /v1/scrape/{id}` — status and result of a scrape task.
+    pub async fn get_scrape(&self, id: Uuid) -> Result<ScrapeStatusResponseDto> {
+        self.get(&format!("/v1/scrape/{}", id)).await
+    }
+
+    // ---- Crawl ----
+
+    /// `POST /v1/crawl` — start a crawl.
+    pub async fn crawl(&self, request: &CrawlRequestDto) -> Result<Crawl> {
+        self.post("/v1/crawl", request).await
+    }
+
+    /// `GET /v1/crawl/{id}` — crawl progress.
+    pub async fn get_crawl(&self, id: Uuid) -> Result<CrawlStatusResponse> {
+        self.get(&format!("/v1/crawl/{}", id)).await
+    }
+
+    /// `GET /v1/crawl/{id}/results` — pages scraped by a crawl.
+    pub async fn crawl_results(&self, id: Uuid) -> Result<Vec<ScrapeResult>> {
+        self.get(&format!("/v1/crawl/{}/results", id)).await
+    }
+
+    /// `DELETE /v1/crawl/{id}` — cancel a crawl.
+    pub async fn cancel_crawl(&self, id: Uuid) -> Result<()> {
+        self.send(self.request(Method::DELETE, &format!("/v1/crawl/{}", id)))
+            .await
+    }
+
+    // ---- Search & extract ----
+
+    /// `POST /v1/search` — search the web, optionally crawling the results.
+    pub async fn search(&self, request: &SearchRequestDto) -> Result<SearchResponseDto> {
+        self.post("/v1/search", request).await
+    }
+
+    /// `POST /v1/extract` — extract structured data from pages.
+    pub async fn extract(&self, request: &ExtractRequestDto) -> Result<ExtractResponseDto> {
+        self.post("/v1/extract", request).await
+    }
+
+    // ---- Webhooks ----
+
+    /// `POST /v1/webhooks` — register a webhook.
+    pub async fn create_webhook(&self, request: &CreateWebhookRequest) -> Result<Webhook> {
+        self.post("/v1/webhooks", request).await
+    }
+
+    /// `GET /v1/webhooks` — webhooks registered by the team.
+    pub async fn list_webhooks(&self) -> Result<WebhookListResponse> {
+        self.get("/v1/webhooks").await
+    }
+
+    /// `POST /v1/webhooks/{id}/test` — send a signed sample event to a webhook.
+    pub async fn test_webhook(
+        &self,
+        id: Uuid,
+        request: &TestWebhookRequest,
+    ) -> Result<WebhookTestResult> {
+        self.post(&format!("/v1/webhooks/{}/test", id), request)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use wiremock::matchers::{body_partial_json, header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[test]
+    fn test_decode_response_unwraps_envelope() {
+        let id = Uuid::new_v4();
+        let body = json!({
+            "success": true,
+            "data": {"id": id, "url": "https://example.com", "credits_used": 1},
+            "timestamp": "2025-01-01T00:00:00Z"
+        });
+        let task: ScrapeResponseDto = decode_response(201, &body.to_string()).unwrap();
+        assert_eq!(task.id, id);
+        assert_eq!(task.credits_used, 1);
+    }
+
+    #[test]
+    fn test_decode_response_maps_api_errors() {
+        let body = json!({
+            "success": false,
+            "error": {"code": "RATE_LIMITED", "message": "Rate limit exceeded"},
+            "retry_after_seconds": 30
+        });
+        let error = decode_response::<Value>(429, &body.to_string()).unwrap_err();
+        assert!(error.is_rate_limited());
+        match error {
+            ClientError::Api {
+                code,
+                retry_after_seconds,
+                ..
+            } => {
+                assert_eq!(code, "RATE_LIMITED");
+                assert_eq!(retry_after_seconds, Some(30));
+            }
+            other => panic!("unexpected error: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_decode_response_accepts_empty_and_unwrapped_bodies() {
+        decode_response::<()>(204, "").unwrap();
+
+        let webhook = json!({
+            "id": Uuid::new_v4(),
+            "team_id": Uuid::new_v4(),
+            "url": "https://hooks.example.com",
+            "created_at": "2025-01-01T00:00:00Z"
+        });
+        let webhook: Webhook = decode_response(201, &webhook.to_string()).unwrap();
+        assert_eq!(webhook.url, "https://hooks.example.com");
+    }
+
+    #[tokio::test]
+    async fn test_scrape_sends_bearer_token_and_request_body() {
+        let server = MockServer::start().await;
+        let id = Uuid::new_v4();
+        Mock::given(method("POST"))
+            .and(path("/v1/scrape"))
+            .and(header("authorization", "Bearer sk-test"))
+            .and(body_partial_json(json!({"url": "https://example.com"})))
+            .respond_with(ResponseTemplate::new(201).set_body_json(json!({
+                "success": true,
+                "data": {"id": id, "url": "https://example.com", "credits_used": 1}
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = CrawlrsClient::new(format!("{}/", server.uri()), "sk-test").unwrap();
+        let task = client
+            .scrape(&ScrapeRequestDto {
+                url: "https://example.com".to_string(),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(task.id, id);
+    }
+
+    #[tokio::test]
+    async fn test_cancel_crawl_returns_not_found_error() {
+        let server = MockServer::start().await;
+        let id = Uuid::new_v4();
+        Mock::given(method("DELETE"))
+            .and(path(format!("/v1/crawl/{}", id)))
+            .respond_with(ResponseTemplate::new(404).set_body_json(json!({
+                "success": false,
+                "error": {"code": "NOT_FOUND", "message": "Crawl not found"}
+            })))
+            .mount(&server)
+            .await;
+
+        let client = CrawlrsClient::new(server.uri(), "sk-test").unwrap();
+        let error = client.cancel_crawl(id).await.unwrap_err();
+        assert_eq!(error.status(), Some(404));
+    }
+}
@@ -0,0 +1,52 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! Client error type.
+
+use thiserror::Error;
+
+/// Errors returned by [`CrawlrsClient`](crate::CrawlrsClient).
+#[derive(Debug, Error)]
+pub enum ClientError {
+    /// The API answered with an error response
+    #[error("{status} {code}: {message}")]
+    Api {
+        /// HTTP status code
+        status: u16,
+        /// Error code from the response envelope, e.g. `VALIDATION_ERROR`
+        code: String,
+        /// Human-readable message
+        message: String,
+        /// Seconds to wait before retrying, present on `429` responses
+        retry_after_seconds: Option<u64>,
+    },
+
+    /// The request could not be sent or the response could not be read
+    #[error("HTTP request failed: {0}")]
+    Http(#[from] reqwest::Error),
+
+    /// The response body did not match the expected type
+    #[error("Failed to decode response: {0}")]
+    Decode(#[from] serde_json::Error),
+}
+
+impl ClientError {
+    /// HTTP status code of an API error.
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            Self::Api { status, .. } => Some(*status),
+            Self::Http(e) => e.status().map(|s| s.as_u16()),
+            Self::Decode(_) => None,
+        }
+    }
+
+    /// Whether the request was rejected by rate limiting.
+    pub fn is_rate_limited(&self) -> bool {
+        self.status() == Some(429)
+    }
+}
+
+/// Result alias used by the client.
+pub type Result<T> = std::result::Result<T, ClientError>;
@@ -0,0 +1,39 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! Typed async Rust client for the crawlrs HTTP API.
+//!
+//! [`CrawlrsClient`] exposes one method per endpoint (scrape, crawl, search,
+//! extract, webhooks). Request and response types are the server's own DTOs,
+//! re-exported here so they always match the API.
+
+mod client;
+mod error;
+
+pub use client::{decode_response, CrawlStatusResponse, CrawlrsClient, DEFAULT_TIMEOUT};
+pub use error::{ClientError, Result};
+
+pub use crawlrs::application::dto::crawl_request::{CrawlConfigDto, CrawlRequestDto};
+pub use crawlrs::application::dto::extract_request::{
+    ExtractRequestDto, ExtractResponseDto, ExtractResultDto,
+};
+pub use crawlrs::application::dto::scrape_request::{
+    ScrapeActionStepDto, ScrapeOptionsDto, ScrapeRequestDto, ScreenshotOptionsDto, ScrollOptionsDto,
+};
+pub use crawlrs::application::dto::scrape_response::{
+    ScrapeResponseDto, ScrapeResultDto, ScrapeStatusResponseDto,
+};
+pub use crawlrs::application::dto::search_request::{
+    SearchRequestDto, SearchResponseDto, SearchResultDto,
+};
+pub use crawlrs::application::dto::webhook_request::{
+    CreateWebhookRequest, TestWebhookRequest, WebhookListResponse, WebhookResponse,
+};
+pub use crawlrs::domain::models::scrape_result::ScrapeResult;
+pub use crawlrs::domain::models::{
+    Crawl, CrawlStatus, DomainThrottleStatus, PriorityTier, Webhook, WebhookEventType,
+};
+pub use crawlrs::domain::services::extraction_service::ExtractionRule;
+pub use crawlrs::domain::services::webhook_service::WebhookTestResult;
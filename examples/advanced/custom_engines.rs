@@ -108,6 +108,19 @@ impl CustomHttpEngine {
                 request.url, request.url, self.name
             ),
             success: true,
+            truncated: false,
+            raw_content: None,
+            http_version: None,
+            tls_profile: None,
+            har: None,
+            mhtml: None,
+            pdf: None,
+            blocked_requests: None,
+            evaluate_results: Vec::new(),
+            frames: Vec::new(),
+            accessibility: None,
+            timings: None,
+            ip_family: None,
         };
 
         Ok(response)
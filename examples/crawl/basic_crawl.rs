@@ -42,6 +42,24 @@ async fn main() {
         proxy: None,                                 // 代理设置
         headers: None,                               // 自定义请求头
         extraction_rules: None,                      // 提取规则
+        skip_nofollow_links: None,
+        ignore_robots: None,
+        ignore_sitemap: None,
+        limit: None,
+        max_duration_seconds: None,
+        previous_crawl_id: None,
+        allow_subdomains: None,
+        allow_external_domains: None,
+        ignore_query_params: None,
+        ignore_all_query_params: None,
+        max_query_variants_per_path: None,
+        download_assets: None,
+        allowed_content_types: None,
+        excluded_content_types: None,
+        result_destination: None,
+        keep_local_results: None,
+        formats: None,
+        resolve: None,
     };
 
     info!("📋 爬取配置:");
@@ -65,6 +83,8 @@ async fn main() {
         config,
         sync_wait_ms: Some(5000),
         expires_at: None,
+        dry_run: None,
+        priority: None,
     };
 
     info!("📝 爬取请求:");
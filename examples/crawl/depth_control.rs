@@ -48,6 +48,24 @@ async fn main() {
         proxy: None,
         headers: None,
         extraction_rules: None,
+        skip_nofollow_links: None,
+        ignore_robots: None,
+        ignore_sitemap: None,
+        limit: None,
+        max_duration_seconds: None,
+        previous_crawl_id: None,
+        allow_subdomains: None,
+        allow_external_domains: None,
+        ignore_query_params: None,
+        ignore_all_query_params: None,
+        max_query_variants_per_path: None,
+        download_assets: None,
+        allowed_content_types: None,
+        excluded_content_types: None,
+        result_destination: None,
+        keep_local_results: None,
+        formats: None,
+        resolve: None,
     };
 
     info!("📊 预期结果:");
@@ -70,6 +88,24 @@ async fn main() {
         proxy: None,
         headers: None,
         extraction_rules: None,
+        skip_nofollow_links: None,
+        ignore_robots: None,
+        ignore_sitemap: None,
+        limit: None,
+        max_duration_seconds: None,
+        previous_crawl_id: None,
+        allow_subdomains: None,
+        allow_external_domains: None,
+        ignore_query_params: None,
+        ignore_all_query_params: None,
+        max_query_variants_per_path: None,
+        download_assets: None,
+        allowed_content_types: None,
+        excluded_content_types: None,
+        result_destination: None,
+        keep_local_results: None,
+        formats: None,
+        resolve: None,
     };
 
     info!("📊 预期结果:");
@@ -92,6 +128,24 @@ async fn main() {
         proxy: None,
         headers: None,
         extraction_rules: None,
+        skip_nofollow_links: None,
+        ignore_robots: None,
+        ignore_sitemap: None,
+        limit: None,
+        max_duration_seconds: None,
+        previous_crawl_id: None,
+        allow_subdomains: None,
+        allow_external_domains: None,
+        ignore_query_params: None,
+        ignore_all_query_params: None,
+        max_query_variants_per_path: None,
+        download_assets: None,
+        allowed_content_types: None,
+        excluded_content_types: None,
+        result_destination: None,
+        keep_local_results: None,
+        formats: None,
+        resolve: None,
     };
 
     info!("📊 预期结果:");
@@ -114,6 +168,24 @@ async fn main() {
         proxy: None,
         headers: None,
         extraction_rules: None,
+        skip_nofollow_links: None,
+        ignore_robots: None,
+        ignore_sitemap: None,
+        limit: None,
+        max_duration_seconds: None,
+        previous_crawl_id: None,
+        allow_subdomains: None,
+        allow_external_domains: None,
+        ignore_query_params: None,
+        ignore_all_query_params: None,
+        max_query_variants_per_path: None,
+        download_assets: None,
+        allowed_content_types: None,
+        excluded_content_types: None,
+        result_destination: None,
+        keep_local_results: None,
+        formats: None,
+        resolve: None,
     };
 
     info!("📊 预期结果:");
@@ -138,6 +210,24 @@ async fn main() {
         proxy: None,
         headers: None,
         extraction_rules: None,
+        skip_nofollow_links: None,
+        ignore_robots: None,
+        ignore_sitemap: None,
+        limit: None,
+        max_duration_seconds: None,
+        previous_crawl_id: None,
+        allow_subdomains: None,
+        allow_external_domains: None,
+        ignore_query_params: None,
+        ignore_all_query_params: None,
+        max_query_variants_per_path: None,
+        download_assets: None,
+        allowed_content_types: None,
+        excluded_content_types: None,
+        result_destination: None,
+        keep_local_results: None,
+        formats: None,
+        resolve: None,
     };
 
     info!("📝 博客站点配置:");
@@ -165,6 +255,24 @@ async fn main() {
         proxy: None,
         headers: None,
         extraction_rules: None,
+        skip_nofollow_links: None,
+        ignore_robots: None,
+        ignore_sitemap: None,
+        limit: None,
+        max_duration_seconds: None,
+        previous_crawl_id: None,
+        allow_subdomains: None,
+        allow_external_domains: None,
+        ignore_query_params: None,
+        ignore_all_query_params: None,
+        max_query_variants_per_path: None,
+        download_assets: None,
+        allowed_content_types: None,
+        excluded_content_types: None,
+        result_destination: None,
+        keep_local_results: None,
+        formats: None,
+        resolve: None,
     };
 
     info!("📝 电商站点配置:");
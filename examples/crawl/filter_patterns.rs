@@ -83,6 +83,24 @@ async fn main() {
         proxy: None,
         headers: None,
         extraction_rules: None,
+        skip_nofollow_links: None,
+        ignore_robots: None,
+        ignore_sitemap: None,
+        limit: None,
+        max_duration_seconds: None,
+        previous_crawl_id: None,
+        allow_subdomains: None,
+        allow_external_domains: None,
+        ignore_query_params: None,
+        ignore_all_query_params: None,
+        max_query_variants_per_path: None,
+        download_assets: None,
+        allowed_content_types: None,
+        excluded_content_types: None,
+        result_destination: None,
+        keep_local_results: None,
+        formats: None,
+        resolve: None,
     };
 
     info!("📝 博客配置:");
@@ -119,6 +137,24 @@ async fn main() {
         proxy: None,
         headers: None,
         extraction_rules: None,
+        skip_nofollow_links: None,
+        ignore_robots: None,
+        ignore_sitemap: None,
+        limit: None,
+        max_duration_seconds: None,
+        previous_crawl_id: None,
+        allow_subdomains: None,
+        allow_external_domains: None,
+        ignore_query_params: None,
+        ignore_all_query_params: None,
+        max_query_variants_per_path: None,
+        download_assets: None,
+        allowed_content_types: None,
+        excluded_content_types: None,
+        result_destination: None,
+        keep_local_results: None,
+        formats: None,
+        resolve: None,
     };
 
     info!("📝 电商配置:");
@@ -103,6 +103,25 @@ async fn main() {
             PageAction::Input { selector, text } => {
                 info!("   [{:2}] 输入到 {}: \"{}\"", i + 1, selector, text)
             }
+            PageAction::Evaluate { script } => info!("   [{:2}] 执行脚本: {}", i + 1, script),
+            PageAction::WaitForSelector {
+                selector, state, ..
+            } => info!("   [{:2}] 等待 {} 变为 {}", i + 1, selector, state.as_str()),
+            PageAction::WaitForNavigation { timeout_ms } => {
+                info!("   [{:2}] 等待导航完成（最长 {}ms）", i + 1, timeout_ms)
+            }
+            PageAction::Select { selector, value } => {
+                info!("   [{:2}] 选择 {} 的选项: {}", i + 1, selector, value)
+            }
+            PageAction::Check { selector, checked } => {
+                info!("   [{:2}] 设置 {} 勾选状态: {}", i + 1, selector, checked)
+            }
+            PageAction::PressKey { key } => info!("   [{:2}] 按键: {}", i + 1, key),
+            PageAction::Submit { selector } => info!("   [{:2}] 提交表单: {}", i + 1, selector),
+            PageAction::Hover { selector } => info!("   [{:2}] 悬停: {}", i + 1, selector),
+            PageAction::Drag { from, to } => info!("   [{:2}] 拖拽 {} -> {}", i + 1, from, to),
+            PageAction::KeyDown { key } => info!("   [{:2}] 按下按键: {}", i + 1, key),
+            PageAction::KeyUp { key } => info!("   [{:2}] 抬起按键: {}", i + 1, key),
         }
     }
     info!("");
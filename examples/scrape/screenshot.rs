@@ -81,6 +81,7 @@ async fn main() {
         quality: Some(85),
         format: Some("png".to_string()),
         selector: None,
+        max_height: None,
     };
 
     // 区域截图配置
@@ -89,6 +90,7 @@ async fn main() {
         selector: Some("#main-content".to_string()),
         quality: Some(90),
         format: Some("jpeg".to_string()),
+        max_height: None,
     };
 
     info!("✅ 截图配置创建成功");
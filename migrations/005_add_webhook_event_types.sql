@@ -0,0 +1,7 @@
+-- 为 webhooks 表添加事件类型订阅过滤
+-- Migration: add_webhook_event_types
+--
+-- event_types 为 JSON 字符串数组（如 ["crawl.completed", "scrape.failed"]），
+-- NULL 表示订阅全部事件，保持既有 webhook 的行为不变。
+
+ALTER TABLE webhooks ADD COLUMN IF NOT EXISTS event_types JSONB;
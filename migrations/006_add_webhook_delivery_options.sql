@@ -0,0 +1,9 @@
+-- 为 webhooks 表添加自定义请求头与载荷模板
+-- Migration: add_webhook_delivery_options
+--
+-- custom_headers 为 JSON 对象（如 {"Authorization": "Bearer ..."}），随每次投递发送；
+-- payload_template 为带 {{path}} 占位符的 JSON 模板文本。
+-- 两列均为 NULL 时保持既有 webhook 的投递行为不变。
+
+ALTER TABLE webhooks ADD COLUMN IF NOT EXISTS custom_headers JSONB;
+ALTER TABLE webhooks ADD COLUMN IF NOT EXISTS payload_template TEXT;
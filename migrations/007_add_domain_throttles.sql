@@ -0,0 +1,15 @@
+-- 新增 domain_throttles 表：按域名的自适应节流状态
+-- Migration: add_domain_throttles
+--
+-- 目标站点返回 429/503 时，worker 记录连续错误次数并按指数退避推迟该域名的任务派发；
+-- throttled_until 之前该域名的任务会被重新排期。API 进程读取此表在爬取状态中展示节流信息。
+
+CREATE TABLE IF NOT EXISTS domain_throttles (
+    domain TEXT PRIMARY KEY,
+    consecutive_errors INTEGER NOT NULL DEFAULT 0,
+    last_status INTEGER NOT NULL DEFAULT 0,
+    throttled_until TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_domain_throttles_throttled_until ON domain_throttles(throttled_until);
@@ -0,0 +1,18 @@
+-- 新增 task_events 表：任务生命周期事件
+-- Migration: add_task_events
+--
+-- 队列在入队、加锁、完成/失败/取消时写入事件，worker 额外记录每次引擎尝试（含耗时）、
+-- 推迟与重试。GET /v2/tasks/{id}/timeline 读取此表组装任务执行时间线。
+
+CREATE TABLE IF NOT EXISTS task_events (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    task_id UUID NOT NULL REFERENCES tasks(id) ON DELETE CASCADE,
+    event_type TEXT NOT NULL,
+    worker_id UUID,
+    attempt INTEGER NOT NULL DEFAULT 0,
+    duration_ms BIGINT,
+    message TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_task_events_task_id_created_at ON task_events(task_id, created_at);
@@ -0,0 +1,17 @@
+-- 新增 url_blocklist 表：禁止抓取的域名/URL
+-- Migration: add_url_blocklist
+--
+-- team_id 为空表示全局条目，对所有团队生效。pattern_type 为 wildcard（匹配主机名，
+-- 支持 * 通配，普通域名同时覆盖子域名）或 regex（匹配完整 URL）。
+-- 提交请求与爬取展开链接时都会检查此表。
+
+CREATE TABLE IF NOT EXISTS url_blocklist (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    team_id UUID REFERENCES teams(id) ON DELETE CASCADE,
+    pattern TEXT NOT NULL,
+    pattern_type TEXT NOT NULL DEFAULT 'wildcard',
+    reason TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_url_blocklist_team_id ON url_blocklist(team_id);
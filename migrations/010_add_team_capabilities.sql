@@ -0,0 +1,7 @@
+-- 为 teams 表新增能力开关
+-- Migration: add_team_capabilities
+--
+-- allow_ignore_robots：允许团队在爬取时设置 ignore_robots 忽略 robots.txt
+-- （用于抓取自有站点）。只能由管理员通过 admin API 开启，默认关闭。
+
+ALTER TABLE teams ADD COLUMN IF NOT EXISTS allow_ignore_robots BOOLEAN NOT NULL DEFAULT FALSE;
@@ -0,0 +1,8 @@
+-- 为 scrape_results 表新增 HTTP 缓存校验字段
+-- Migration: add_scrape_result_validators
+--
+-- etag / last_modified：抓取响应中的 ETag 与 Last-Modified 头，
+-- 增量重爬时作为 If-None-Match / If-Modified-Since 条件请求发送。
+
+ALTER TABLE scrape_results ADD COLUMN IF NOT EXISTS etag TEXT;
+ALTER TABLE scrape_results ADD COLUMN IF NOT EXISTS last_modified TEXT;
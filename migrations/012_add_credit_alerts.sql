@@ -0,0 +1,16 @@
+-- 新增 credit_alerts 表：团队积分余额不足提醒
+-- Migration: add_credit_alerts
+--
+-- 扣除积分后余额低于 threshold 时推送 credits.low webhook 事件（配置了 email 时同时发送邮件），
+-- 并将 alerted 置为 TRUE；余额回升到阈值之上一定比例后才重新置为 FALSE，
+-- 避免余额在阈值附近时每次请求都发送提醒。
+
+CREATE TABLE IF NOT EXISTS credit_alerts (
+    team_id UUID PRIMARY KEY,
+    threshold BIGINT NOT NULL,
+    email TEXT,
+    alerted BOOLEAN NOT NULL DEFAULT FALSE,
+    alerted_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
@@ -0,0 +1,10 @@
+-- 为 teams 表新增套餐字段
+-- Migration: add_team_plans
+--
+-- plan：团队套餐（free / pro / enterprise），决定每月赠送的积分与限流、并发、爬取深度上限，
+-- 只能由管理员通过 admin API 修改，默认 free。
+-- plan_credits_granted_at：最近一次发放月度积分的时间，月度积分 worker 据此判断本月是否已发放，
+-- 并以条件更新抢占发放权，多实例同时运行时每个团队每月只发放一次。
+
+ALTER TABLE teams ADD COLUMN IF NOT EXISTS plan TEXT NOT NULL DEFAULT 'free';
+ALTER TABLE teams ADD COLUMN IF NOT EXISTS plan_credits_granted_at TIMESTAMPTZ;
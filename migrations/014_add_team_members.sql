@@ -0,0 +1,29 @@
+-- 新增 users 与 team_members 表：团队成员与邀请
+-- Migration: add_team_members
+--
+-- users：以邮箱标识的用户，邮箱统一存储为小写。
+-- team_members：团队与用户的成员关系。邀请成员时写入 status = 'invited' 的记录，
+-- 并保存邀请令牌的 SHA-256 摘要（令牌明文只在创建邀请时返回一次）；
+-- 接受邀请后 status 变为 'active'，清空令牌摘要并记录 joined_at。
+-- role：owner / admin / member，每个团队至少保留一个 active 的 owner。
+
+CREATE TABLE IF NOT EXISTS users (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    email TEXT NOT NULL UNIQUE,
+    name TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE TABLE IF NOT EXISTS team_members (
+    team_id UUID NOT NULL REFERENCES teams(id) ON DELETE CASCADE,
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    role TEXT NOT NULL DEFAULT 'member',
+    status TEXT NOT NULL DEFAULT 'invited',
+    invitation_token_hash TEXT UNIQUE,
+    invited_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    joined_at TIMESTAMPTZ,
+    PRIMARY KEY (team_id, user_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_team_members_user_id ON team_members(user_id);
@@ -0,0 +1,12 @@
+-- 为没有 scopes 记录的已有 API Key 补齐成员权限
+-- Migration: backfill_member_scopes
+--
+-- 角色由 scopes 表的标志位推导：admin → 管理员，write → 成员，仅 read → 只读。
+-- 缺少 scopes 记录的 Key 按只读处理，鉴权中间件会拒绝其创建任务、管理 webhook 与查看计费，
+-- 因此迁移前已存在的 Key 补一条成员权限（read + write）记录，保持原有行为；
+-- 此后创建的 Key 需显式授予角色，否则为只读。
+
+INSERT INTO scopes (api_key_id, read, write, admin)
+SELECT k.id, TRUE, TRUE, FALSE
+FROM api_keys k
+WHERE NOT EXISTS (SELECT 1 FROM scopes s WHERE s.api_key_id = k.id);
@@ -0,0 +1,19 @@
+-- OIDC 单点登录
+-- Migration: add_oidc_sessions
+--
+-- api_keys.expires_at：OIDC 登录签发的会话令牌以带过期时间的 API Key 存储，
+-- 复用鉴权中间件的查找、角色与缓存逻辑；普通 API Key 该列为 NULL，不过期。
+-- oidc_login_states：登录发起时生成的 state、PKCE code_verifier 与 nonce，
+-- 回调时按 state 取出并删除（只能使用一次），多实例部署下回调可由任一实例处理。
+
+ALTER TABLE api_keys ADD COLUMN IF NOT EXISTS expires_at TIMESTAMPTZ;
+
+CREATE TABLE IF NOT EXISTS oidc_login_states (
+    state TEXT PRIMARY KEY,
+    code_verifier TEXT NOT NULL,
+    nonce TEXT NOT NULL,
+    team_id UUID REFERENCES teams(id) ON DELETE CASCADE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_oidc_login_states_created_at ON oidc_login_states(created_at);
@@ -0,0 +1,11 @@
+-- 优先级通道调度
+-- Migration: add_priority_lane_index
+--
+-- TaskRepository::acquire_next_in_lane 按档位领取任务：有效档位由 priority 所在档位
+-- 减去排队时长折算的老化档数得出，同一档位内按 created_at 先进先出。
+-- 有效档位是计算值，无法直接索引；按 created_at 有序扫描 queued 任务，
+-- 找到第一条符合档位的任务即停止（Index Scan + Filter with LIMIT 1）。
+
+CREATE INDEX IF NOT EXISTS idx_tasks_queued_created_at
+    ON tasks (created_at ASC)
+    WHERE status = 'queued';
@@ -0,0 +1,29 @@
+-- 新增 monitors 表：定期重新抓取的页面变更监控
+-- Migration: add_monitors
+--
+-- 每个监控按 interval_seconds 重新抓取 url，与上一次快照比较：mode 为 hash 时比较页面
+-- 可见文本，为 extract 时比较按 extraction_rules（CSS 选择器）提取的数据。快照发生变化时
+-- 推送 monitor.changed webhook 事件并附带差异。last_snapshot 为下一次比较的基线。
+-- monitor worker 领取 next_check_at 已到期的监控，领取时先推后 next_check_at，
+-- 多个实例不会重复检查同一监控。
+
+CREATE TABLE IF NOT EXISTS monitors (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    team_id UUID NOT NULL REFERENCES teams(id) ON DELETE CASCADE,
+    url TEXT NOT NULL,
+    interval_seconds BIGINT NOT NULL,
+    mode TEXT NOT NULL DEFAULT 'hash',
+    extraction_rules JSONB,
+    active BOOLEAN NOT NULL DEFAULT TRUE,
+    last_hash TEXT,
+    last_snapshot TEXT,
+    last_error TEXT,
+    last_checked_at TIMESTAMPTZ,
+    last_changed_at TIMESTAMPTZ,
+    next_check_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_monitors_team_id ON monitors(team_id);
+CREATE INDEX IF NOT EXISTS idx_monitors_due ON monitors(next_check_at) WHERE active;
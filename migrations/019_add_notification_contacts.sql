@@ -0,0 +1,16 @@
+-- 新增 notification_contacts 表：团队接收邮件通知的联系人
+-- Migration: add_notification_contacts
+--
+-- 每个团队最多一条。emails 为邮箱地址 JSON 数组；event_types 为事件类型 JSON 数组
+-- （如 ["crawl.summary", "credits.low"]），NULL 表示接收所有事件。
+-- 爬取结束、积分不足、webhook 投递最终失败等事件通过 SMTP（或 Amazon SES 的 SMTP 接口）
+-- 发送给这些联系人，enabled 为 FALSE 时暂停发送。
+
+CREATE TABLE IF NOT EXISTS notification_contacts (
+    team_id UUID PRIMARY KEY REFERENCES teams(id) ON DELETE CASCADE,
+    emails JSONB NOT NULL DEFAULT '[]'::jsonb,
+    event_types JSONB,
+    enabled BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
@@ -0,0 +1,21 @@
+-- 新增 notification_channels 表：团队接收事件消息的聊天频道
+-- Migration: add_notification_channels
+--
+-- kind 为 slack（Slack incoming webhook，发送 {"text": ...}）或 json（通用 JSON POST，
+-- 发送事件名、摘要与完整负载）；url 为频道的 incoming webhook 地址。
+-- event_types 为事件类型 JSON 数组，NULL 表示接收所有事件；enabled 为 FALSE 时暂停发送。
+
+CREATE TABLE IF NOT EXISTS notification_channels (
+    id UUID PRIMARY KEY,
+    team_id UUID NOT NULL REFERENCES teams(id) ON DELETE CASCADE,
+    name VARCHAR(100) NOT NULL,
+    kind VARCHAR(20) NOT NULL CHECK (kind IN ('slack', 'json')),
+    url TEXT NOT NULL,
+    event_types JSONB,
+    enabled BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- 分发事件时按团队查询启用的频道
+CREATE INDEX IF NOT EXISTS idx_notification_channels_team_id ON notification_channels(team_id);
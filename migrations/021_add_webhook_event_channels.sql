@@ -0,0 +1,10 @@
+-- 为 webhook_events 表添加投递渠道
+-- Migration: add_webhook_event_channels
+--
+-- webhook_events 同时作为所有通知渠道的投递记录：channel 为 webhook（签名 POST 到 webhook）、
+-- email（发送给团队通知联系人，webhook_url 为 mailto: 地址）、slack 或 json（聊天频道，
+-- webhook_id 为频道 ID）。通知投递失败后与 webhook 一样由 webhook worker 重试。
+-- 既有记录默认为 webhook，投递行为不变。
+
+ALTER TABLE webhook_events ADD COLUMN IF NOT EXISTS channel VARCHAR(20) NOT NULL DEFAULT 'webhook'
+    CHECK (channel IN ('webhook', 'email', 'slack', 'json'));
@@ -0,0 +1,19 @@
+-- 新增 crawl_links 表：爬取过程中发现的页面链接（链接图的边）
+-- Migration: add_crawl_links
+--
+-- 每条记录为一次爬取中 source_url 页面指向 target_url 的链接，同一对页面只记录首次发现的边。
+-- anchor_text 为链接的锚文本（图片为 alt 文本），rel 为空格分隔的 rel 取值，均可为空。
+-- 通过 GET /v1/crawl/{id}/graph 以 JSON、GraphML 或 DOT 格式导出。
+
+CREATE TABLE IF NOT EXISTS crawl_links (
+    crawl_id UUID NOT NULL REFERENCES crawls(id) ON DELETE CASCADE,
+    source_url TEXT NOT NULL,
+    target_url TEXT NOT NULL,
+    anchor_text TEXT,
+    rel TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (crawl_id, source_url, target_url)
+);
+
+-- 导出链接图时按发现顺序读取爬取的全部链接
+CREATE INDEX IF NOT EXISTS idx_crawl_links_crawl_created ON crawl_links(crawl_id, created_at);
@@ -0,0 +1,9 @@
+-- 为 scrape_results 表新增正文压缩字段
+-- Migration: add_scrape_result_content_encoding
+--
+-- content_encoding：正文编码，NULL 表示 content 列保存原文；zstd 表示正文以 zstd 压缩后
+-- 保存在 content_compressed 列，content 列为空字符串。由 [storage] compress_content 开启，
+-- 读取时透明解压，开启前写入的结果保持原文。
+
+ALTER TABLE scrape_results ADD COLUMN IF NOT EXISTS content_encoding TEXT;
+ALTER TABLE scrape_results ADD COLUMN IF NOT EXISTS content_compressed BYTEA;
@@ -0,0 +1,21 @@
+-- 为 scrape_results 表新增全文检索向量
+-- Migration: add_scrape_result_search_vector
+--
+-- search_vector：结果的 tsvector（simple 配置），URL 权重为 A，正文纯文本权重为 B。
+-- 正文可能以 zstd 压缩保存，数据库触发器无法读取，因此由 ScrapeResultRepositoryImpl
+-- 在插入时提取纯文本并写入。通过 GET /v1/crawl/{id}/results/search 检索。
+
+ALTER TABLE scrape_results ADD COLUMN IF NOT EXISTS search_vector TSVECTOR;
+
+CREATE INDEX IF NOT EXISTS idx_scrape_results_search_vector
+    ON scrape_results USING GIN (search_vector);
+
+-- 回填未压缩的历史结果：粗略移除 HTML 标签，正文截取前 512KB
+UPDATE scrape_results
+SET search_vector =
+    setweight(to_tsvector('simple', url), 'A') ||
+    setweight(
+        to_tsvector('simple', regexp_replace(left(content, 524288), '<[^>]*>', ' ', 'g')),
+        'B'
+    )
+WHERE search_vector IS NULL AND content_encoding IS NULL;
@@ -0,0 +1,22 @@
+-- 新增 resource_tags 表：客户端为爬取结果与爬取附加的标签和注释
+-- Migration: add_resource_tags
+--
+-- 每个资源（resource_type 为 result 或 crawl）一行，tags 为去重后的标签 JSON 数组，
+-- annotations 为任意 JSON 对象，供下游审核流程记录 reviewed、broken、relevant 等状态。
+-- 通过 /v1/results/{id}/tags 与 /v1/crawl/{id}/tags 管理，
+-- GET /v1/crawl/{id}/results 与结果检索接口可按 tags 过滤。
+
+CREATE TABLE IF NOT EXISTS resource_tags (
+    resource_type TEXT NOT NULL,
+    resource_id UUID NOT NULL,
+    team_id UUID NOT NULL REFERENCES teams(id) ON DELETE CASCADE,
+    tags JSONB NOT NULL DEFAULT '[]'::jsonb,
+    annotations JSONB NOT NULL DEFAULT '{}'::jsonb,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (resource_type, resource_id)
+);
+
+-- 按标签查找团队的资源（tags @> '["reviewed"]'）
+CREATE INDEX IF NOT EXISTS idx_resource_tags_tags ON resource_tags USING GIN (tags);
+CREATE INDEX IF NOT EXISTS idx_resource_tags_team ON resource_tags(team_id, resource_type);
@@ -0,0 +1,16 @@
+-- 新增 team_bandwidth_usage 表：团队每日下载流量汇总
+-- Migration: add_team_bandwidth_usage
+--
+-- Worker 每次取得引擎响应后把响应正文字节数累加到计数器（进程内或 Redis），
+-- 汇总 Worker 定期把计数器中的团队当日累计写入本表，每个团队每个 UTC 日一行。
+-- 写入取已有值与计数器值中的较大者，计数器重启丢失时不会回退已汇总的数据。
+-- 通过 GET /v1/usage 查询。
+
+CREATE TABLE IF NOT EXISTS team_bandwidth_usage (
+    team_id UUID NOT NULL REFERENCES teams(id) ON DELETE CASCADE,
+    day DATE NOT NULL,
+    bytes_downloaded BIGINT NOT NULL DEFAULT 0,
+    requests BIGINT NOT NULL DEFAULT 0,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (team_id, day)
+);
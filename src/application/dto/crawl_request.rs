@@ -5,8 +5,11 @@
 
 //! Crawl request DTO with URL validation
 
+use crate::engines::proxy_url::parse_proxy_url;
 use crate::utils::SafeUrl;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use uuid::Uuid;
 use validator::Validate;
 
 /// Maximum crawl depth limit
@@ -14,7 +17,7 @@ pub const MAX_CRAWL_DEPTH: u32 = 100;
 /// Maximum concurrent pages
 pub const MAX_CONCURRENCY: u32 = 50;
 
-#[derive(Debug, Clone, Deserialize, Serialize, Validate)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize, Validate)]
 #[serde(deny_unknown_fields)]
 pub struct CrawlRequestDto {
     /// URL to crawl
@@ -31,9 +34,13 @@ pub struct CrawlRequestDto {
     pub sync_wait_ms: Option<u32>,
     /// 任务过期时间
     pub expires_at: Option<chrono::DateTime<chrono::Utc>>,
+    /// Only discover links and estimate the cost; no tasks are queued and no credits are charged
+    pub dry_run: Option<bool>,
+    /// Queue priority of the crawl's tasks: `high`, `normal` or `low` (default: low)
+    pub priority: Option<crate::domain::models::PriorityTier>,
 }
 
-#[derive(Debug, Deserialize, Serialize, Clone)]
+#[derive(Debug, Default, Deserialize, Serialize, Clone)]
 #[serde(deny_unknown_fields)]
 pub struct CrawlConfigDto {
     pub max_depth: u32,
@@ -42,6 +49,7 @@ pub struct CrawlConfigDto {
     pub strategy: Option<String>,
     pub crawl_delay_ms: Option<u64>,
     pub max_concurrency: Option<u32>,
+    /// Proxy URL (`http`, `https`, `socks5` or `socks5h`), optionally with `user:pass@` credentials
     pub proxy: Option<String>,
     pub headers: Option<serde_json::Value>,
     pub extraction_rules: Option<
@@ -50,4 +58,61 @@ pub struct CrawlConfigDto {
             crate::domain::services::extraction_service::ExtractionRule,
         >,
     >,
+    /// Skip links marked `rel="nofollow"`, `ugc` or `sponsored` (default: follow all)
+    pub skip_nofollow_links: Option<bool>,
+    /// Ignore robots.txt; requires the team's `allow_ignore_robots` capability
+    pub ignore_robots: Option<bool>,
+    /// Do not seed the crawl from sitemaps declared in robots.txt (default: use them)
+    pub ignore_sitemap: Option<bool>,
+    /// Maximum number of pages to crawl, including the root page
+    pub limit: Option<u32>,
+    /// Maximum crawl duration in seconds, measured from crawl creation
+    pub max_duration_seconds: Option<u64>,
+    /// Previous crawl to re-crawl incrementally with conditional requests
+    pub previous_crawl_id: Option<Uuid>,
+    /// Follow links to other subdomains of the root's registrable domain (default: same host only)
+    pub allow_subdomains: Option<bool>,
+    /// Follow links to any domain; overrides `allow_subdomains` (default: false)
+    pub allow_external_domains: Option<bool>,
+    /// Query parameters removed from discovered URLs before deduplication (case-insensitive)
+    pub ignore_query_params: Option<Vec<String>>,
+    /// Remove all query parameters from discovered URLs (default: false)
+    pub ignore_all_query_params: Option<bool>,
+    /// Maximum number of distinct query strings followed per path (default: unlimited)
+    pub max_query_variants_per_path: Option<u32>,
+    /// Store non-text responses (images, PDFs, archives) in asset storage and also follow `<img src>` links (default: false)
+    pub download_assets: Option<bool>,
+    /// Content types to fetch and store, e.g. `text/html`, `application/pdf`, `image/*` (default: all)
+    pub allowed_content_types: Option<Vec<String>>,
+    /// Content types to skip, checked before `allowed_content_types` (default: none)
+    pub excluded_content_types: Option<Vec<String>>,
+    /// Upload every result to a customer-owned S3 bucket or presigned prefix as it completes
+    pub result_destination: Option<crate::domain::models::ResultDestination>,
+    /// Keep full result content locally when delivered to `result_destination` (default: true)
+    pub keep_local_results: Option<bool>,
+    /// Extra per-page outputs computed for every crawled page: `seo`, `tables`, `structured_data`
+    pub formats: Option<Vec<String>>,
+    /// Host name to IPv4 address overrides that bypass DNS, e.g. `{"example.com": "203.0.113.5"}` (HTTP engine only)
+    pub resolve: Option<HashMap<String, String>>,
+}
+
+impl CrawlConfigDto {
+    /// Check that `proxy` uses a supported scheme (`http`, `https`, `socks5`, `socks5h`)
+    pub fn validate_proxy(&self) -> Result<(), String> {
+        match self.proxy.as_deref() {
+            Some(proxy) => parse_proxy_url(proxy).map(|_| ()),
+            None => Ok(()),
+        }
+    }
+
+    /// Copy safe to persist on the crawl record, with destination credentials redacted
+    pub fn redacted(&self) -> Self {
+        Self {
+            result_destination: self
+                .result_destination
+                .as_ref()
+                .map(|destination| destination.redacted()),
+            ..self.clone()
+        }
+    }
 }
@@ -0,0 +1,65 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+/// 单个订阅源任务默认入队的条目数
+pub const DEFAULT_FEED_MAX_ITEMS: u32 = 50;
+
+/// 订阅源请求数据传输对象
+///
+/// 解析 RSS/Atom 订阅源，并可将条目链接作为抓取任务入队
+#[derive(Debug, Default, Clone, Deserialize, Serialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct FeedRequestDto {
+    /// 订阅源地址 (仅支持 http/https)
+    #[validate(length(min = 1, max = 2048))]
+    pub url: String,
+    /// 是否将条目链接作为抓取任务入队，默认 false
+    #[serde(default)]
+    pub enqueue_items: bool,
+    /// 最多入队的条目数（1-100，默认 50）
+    #[validate(range(min = 1, max = 100, message = "max_items must be between 1 and 100"))]
+    pub max_items: Option<u32>,
+    /// 条目抓取任务的输出格式
+    pub item_formats: Option<Vec<String>>,
+    /// 只入队在此时间之后发布的条目；没有发布时间的条目会被跳过
+    pub published_after: Option<DateTime<Utc>>,
+}
+
+impl FeedRequestDto {
+    /// 实际入队的条目上限
+    pub fn max_items(&self) -> usize {
+        self.max_items.unwrap_or(DEFAULT_FEED_MAX_ITEMS) as usize
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_feed_request_defaults() {
+        let dto: FeedRequestDto =
+            serde_json::from_str(r#"{"url": "https://example.com/feed.xml"}"#).unwrap();
+        assert!(!dto.enqueue_items);
+        assert_eq!(dto.max_items(), DEFAULT_FEED_MAX_ITEMS as usize);
+        assert!(dto.validate().is_ok());
+    }
+
+    #[test]
+    fn test_feed_request_validation() {
+        let dto: FeedRequestDto =
+            serde_json::from_str(r#"{"url": "https://example.com/feed.xml", "max_items": 0}"#)
+                .unwrap();
+        assert!(dto.validate().is_err());
+        assert!(serde_json::from_str::<FeedRequestDto>(
+            r#"{"url": "https://example.com/feed.xml", "unknown": 1}"#
+        )
+        .is_err());
+    }
+}
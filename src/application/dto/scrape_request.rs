@@ -9,6 +9,11 @@ use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use validator::Validate;
 
+use crate::engines::accessibility::AccessibilityOptions;
+use crate::engines::pdf::PdfOptions;
+use crate::engines::proxy_url::parse_proxy_url;
+use crate::utils::html_table::TableOptions;
+
 /// Maximum allowed URL length (2048 characters)
 pub const MAX_URL_LENGTH: usize = 2048;
 /// Maximum number of allowed tags in include/exclude lists
@@ -22,13 +27,14 @@ pub const MAX_METADATA_DEPTH: usize = 5;
 ///
 /// 用于封装客户端发起的网页爬取请求的相关参数
 /// 拒绝未知字段以增强安全性
-#[derive(Debug, Deserialize, Serialize, Validate)]
+#[derive(Debug, Default, Deserialize, Serialize, Validate)]
 #[serde(deny_unknown_fields)]
 pub struct ScrapeRequestDto {
     /// 要爬取的网页URL (仅支持 http/https)
     #[validate(length(min = 1, max = 2048))]
     pub url: String,
-    /// 请求的数据格式列表
+    /// 请求的数据格式列表（`har` 额外记录页面网络请求，`mhtml` 保存单文件页面快照，
+    /// `pdf` 将页面打印为 PDF，`accessibility` 运行 axe-core 无障碍扫描，仅浏览器引擎支持）
     pub formats: Option<Vec<String>>,
     /// 包含的HTML标签列表
     pub include_tags: Option<Vec<String>>,
@@ -43,8 +49,8 @@ pub struct ScrapeRequestDto {
             crate::domain::services::extraction_service::ExtractionRule,
         >,
     >,
-    /// 页面交互动作
-    pub actions: Option<Vec<ScrapeActionDto>>,
+    /// 页面交互动作（每个动作可通过 `on_error` 指定失败时 abort 或 continue）
+    pub actions: Option<Vec<ScrapeActionStepDto>>,
     /// 抓取选项
     pub options: Option<ScrapeOptionsDto>,
     /// 自定义元数据
@@ -56,6 +62,10 @@ pub struct ScrapeRequestDto {
         message = "sync_wait_ms must be between 0 and 30000"
     ))]
     pub sync_wait_ms: Option<u32>,
+    /// 下载模式：非文本响应（图片、PDF、压缩包等）以原始字节保存到对象存储，结果返回存储 URL
+    pub download: Option<bool>,
+    /// 任务优先级：high（默认）、normal 或 low
+    pub priority: Option<crate::domain::models::PriorityTier>,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -75,7 +85,7 @@ pub struct ScrapeOptionsDto {
     pub screenshot_options: Option<ScreenshotOptionsDto>,
     /// 是否模拟移动设备
     pub mobile: Option<bool>,
-    /// 代理配置 (URL)
+    /// 代理 URL（http / https / socks5 / socks5h），可包含 `user:pass@` 认证信息
     pub proxy: Option<String>,
     /// 是否跳过TLS验证
     pub skip_tls_verification: Option<bool>,
@@ -83,16 +93,207 @@ pub struct ScrapeOptionsDto {
     pub needs_tls_fingerprint: Option<bool>,
     /// 是否使用Fire Engine (CDP)
     pub use_fire_engine: Option<bool>,
+    /// HTTP 协议版本（auto / http1 / http2 / http3），默认自动协商
+    pub http_protocol: Option<String>,
+    /// 连接使用的 IP 地址族（ipv4 / ipv6 / happy_eyeballs），默认使用引擎配置
+    pub address_family: Option<String>,
+    /// TLS 指纹配置名称（如 chrome-120、firefox-121、safari-17），隐含 needs_tls_fingerprint
+    pub tls_profile: Option<String>,
+    /// 浏览器渲染时拦截的资源类型（image / font / media / stylesheet）
+    pub block_resources: Option<Vec<String>>,
+    /// 浏览器渲染时额外拦截的域名（含子域名）
+    pub blocked_domains: Option<Vec<String>>,
+    /// 是否拦截内置的广告与追踪域名
+    pub block_ads: Option<bool>,
+    /// 抓取 iframe 内容：inline（合并进主文档）或 separate（单独返回），隐含 js_rendering
+    pub iframes: Option<String>,
+    /// 是否同时抓取跨域 iframe（默认仅同源）
+    pub cross_origin_iframes: Option<bool>,
+    /// 是否将 Shadow DOM 展开到抓取的 HTML 中（Web Components 页面），隐含 js_rendering
+    pub flatten_shadow_dom: Option<bool>,
+    /// 无限滚动自动加载，隐含 js_rendering
+    pub scroll: Option<ScrollOptionsDto>,
+    /// 引擎回退链档位（cheap / standard / max），默认使用配置的默认档位
+    pub engine_tier: Option<String>,
+    /// PDF 打印配置（`formats` 包含 `pdf` 时生效）
+    pub pdf_options: Option<PdfOptionsDto>,
+    /// 表格提取配置（`formats` 包含 `tables` 时生效）
+    pub table_options: Option<TableOptions>,
+    /// 无障碍扫描配置（`formats` 包含 `accessibility` 时生效）
+    pub accessibility_options: Option<AccessibilityOptionsDto>,
+}
+
+impl ScrapeOptionsDto {
+    /// 校验代理地址：协议须为 http、https、socks5 或 socks5h
+    pub fn validate_proxy(&self) -> Result<(), String> {
+        match self.proxy.as_deref() {
+            Some(proxy) => parse_proxy_url(proxy).map(|_| ()),
+            None => Ok(()),
+        }
+    }
+}
+
+/// PDF 打印配置
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct PdfOptionsDto {
+    /// 纸张尺寸（letter / legal / tabloid / a3 / a4 / a5，默认 letter）
+    pub paper_size: Option<String>,
+    /// 是否横向打印（默认 false）
+    pub landscape: Option<bool>,
+    /// 页边距（毫米，默认各 10）
+    pub margin: Option<PdfMarginDto>,
+    /// 是否打印背景色与背景图（默认 true）
+    pub print_background: Option<bool>,
+}
+
+impl PdfOptionsDto {
+    /// 转换为引擎的打印配置，未设置的字段使用默认值
+    pub fn to_options(&self) -> PdfOptions {
+        PdfOptions::from_parts(
+            self.paper_size.as_deref(),
+            self.landscape,
+            self.margin
+                .as_ref()
+                .map(PdfMarginDto::sides)
+                .unwrap_or_default(),
+            self.print_background,
+        )
+    }
+}
+
+/// 无障碍扫描配置
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+#[serde(deny_unknown_fields)]
+pub struct AccessibilityOptionsDto {
+    /// 只运行带这些标签的 axe 规则（如 wcag2a、wcag2aa、best-practice），默认运行全部默认规则
+    pub tags: Option<Vec<String>>,
+}
+
+impl AccessibilityOptionsDto {
+    /// 转换为引擎的扫描配置
+    pub fn to_options(&self) -> AccessibilityOptions {
+        AccessibilityOptions::from_tags(self.tags.as_deref())
+    }
+}
+
+/// PDF 页边距（毫米）
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+#[serde(deny_unknown_fields)]
+pub struct PdfMarginDto {
+    pub top: Option<f64>,
+    pub right: Option<f64>,
+    pub bottom: Option<f64>,
+    pub left: Option<f64>,
+}
+
+impl PdfMarginDto {
+    /// 依次为上、右、下、左
+    pub fn sides(&self) -> [Option<f64>; 4] {
+        [self.top, self.right, self.bottom, self.left]
+    }
+}
+
+/// 无限滚动配置
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct ScrollOptionsDto {
+    /// 滚动模式，目前仅支持 auto
+    pub mode: String,
+    /// 最大滚动次数（默认 20，上限 200）
+    pub max_scrolls: Option<u32>,
+    /// 每次滚动后等待网络空闲的时长（毫秒，默认 1000）
+    pub idle_ms: Option<u64>,
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
 #[serde(tag = "type", rename_all = "camelCase")]
 pub enum ScrapeActionDto {
-    Wait { milliseconds: u64 },
-    Click { selector: String },
-    Scroll { direction: String },
-    Screenshot { full_page: Option<bool> },
-    Input { selector: String, text: String },
+    Wait {
+        milliseconds: u64,
+    },
+    Click {
+        selector: String,
+    },
+    Scroll {
+        direction: String,
+    },
+    Screenshot {
+        full_page: Option<bool>,
+    },
+    Input {
+        selector: String,
+        text: String,
+    },
+    /// 在页面中执行 JavaScript，返回值（JSON）写入结果元数据
+    Evaluate {
+        script: String,
+    },
+    /// 等待元素达到指定状态（attached / visible / hidden / detached，默认 visible）
+    WaitForSelector {
+        selector: String,
+        timeout_ms: Option<u64>,
+        state: Option<String>,
+    },
+    /// 等待页面导航完成
+    WaitForNavigation {
+        timeout_ms: Option<u64>,
+    },
+    /// 选择下拉框（`<select>`）中指定值的选项
+    Select {
+        selector: String,
+        value: String,
+    },
+    /// 勾选或取消勾选复选框 / 单选框（默认勾选）
+    Check {
+        selector: String,
+        checked: Option<bool>,
+    },
+    /// 在当前焦点元素上按键（如 Enter、Tab）
+    PressKey {
+        key: String,
+    },
+    /// 提交元素所在的表单
+    Submit {
+        selector: String,
+    },
+    /// 将鼠标移动到元素上（展开悬停菜单等）
+    Hover {
+        selector: String,
+    },
+    /// 按住鼠标从 `from` 元素拖拽到 `to` 元素
+    Drag {
+        from: String,
+        to: String,
+    },
+    /// 发送原始按键按下事件（需与 keyUp 配对使用）
+    KeyDown {
+        key: String,
+    },
+    /// 发送原始按键抬起事件
+    KeyUp {
+        key: String,
+    },
+}
+
+/// 带失败策略的页面交互动作
+///
+/// `on_error` 取值 `abort`（默认，终止整个抓取）或 `continue`（记录后执行下一个动作）
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ScrapeActionStepDto {
+    #[serde(flatten)]
+    pub action: ScrapeActionDto,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub on_error: Option<String>,
+}
+
+impl From<ScrapeActionDto> for ScrapeActionStepDto {
+    fn from(action: ScrapeActionDto) -> Self {
+        Self {
+            action,
+            on_error: None,
+        }
+    }
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -100,5 +301,8 @@ pub struct ScreenshotOptionsDto {
     pub full_page: Option<bool>,
     pub selector: Option<String>,
     pub quality: Option<u8>,
+    /// 图片格式：`jpeg`（默认）、`png` 或 `webp`
     pub format: Option<String>,
+    /// 整页截图的最大高度（CSS 像素），超长页面分段截取拼接后在此截断
+    pub max_height: Option<u32>,
 }
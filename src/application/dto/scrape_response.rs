@@ -60,12 +60,15 @@ pub struct ScrapeStatusResponseDto {
     pub created_at: NaiveDateTime,
     /// 完成时间
     pub completed_at: Option<NaiveDateTime>,
-    /// 爬取结果（仅当任务完成时存在）
+    /// 爬取结果（任务完成或被反爬拦截时存在）
     pub result: Option<ScrapeResultDto>,
     /// 任务元数据
     pub metadata: Option<Value>,
     /// 错误信息（仅当任务失败时存在）
     pub error: Option<String>,
+    /// 失败类别（如 `blocked`），未归类的失败为空
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub failure_reason: Option<String>,
 }
 
 /// 取消爬取响应数据传输对象
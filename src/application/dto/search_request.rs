@@ -6,7 +6,7 @@
 use crate::application::dto::crawl_request::CrawlConfigDto;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Default, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct SearchRequestDto {
     pub query: String,
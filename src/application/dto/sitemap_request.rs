@@ -0,0 +1,79 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+use crate::utils::sitemap::{SitemapFetch, SitemapLimits, SitemapUrl};
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+/// 默认最多返回的页面 URL 数
+pub const DEFAULT_SITEMAP_MAX_URLS: usize = 5000;
+
+/// 默认最多抓取的站点地图文件数
+pub const DEFAULT_SITEMAP_MAX_SITEMAPS: usize = 10;
+
+/// 站点地图解析请求数据传输对象
+#[derive(Debug, Default, Clone, Deserialize, Serialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct SitemapRequestDto {
+    /// 站点地图或站点地图索引地址 (仅支持 http/https)
+    #[validate(length(min = 1, max = 2048))]
+    pub url: String,
+    /// 最多返回的页面 URL 数（1-50000，默认 5000）
+    #[validate(range(min = 1, max = 50000, message = "max_urls must be between 1 and 50000"))]
+    pub max_urls: Option<usize>,
+    /// 最多抓取的站点地图文件数，含索引展开的子地图（1-50，默认 10）
+    #[validate(range(min = 1, max = 50, message = "max_sitemaps must be between 1 and 50"))]
+    pub max_sitemaps: Option<usize>,
+}
+
+impl SitemapRequestDto {
+    /// 本次请求的抓取上限
+    pub fn limits(&self) -> SitemapLimits {
+        SitemapLimits {
+            max_sitemaps: self.max_sitemaps.unwrap_or(DEFAULT_SITEMAP_MAX_SITEMAPS),
+            max_urls: self.max_urls.unwrap_or(DEFAULT_SITEMAP_MAX_URLS),
+        }
+    }
+}
+
+/// 站点地图解析响应数据传输对象
+#[derive(Debug, Clone, Serialize)]
+pub struct SitemapResponseDto {
+    /// 请求的站点地图地址
+    pub url: String,
+    /// 去重后的页面条目（含 lastmod / changefreq / priority）
+    pub urls: Vec<SitemapUrl>,
+    /// 抓取过的站点地图文件
+    pub sitemaps: Vec<SitemapFetch>,
+    /// 是否因达到上限而未读完全部站点地图或条目
+    pub truncated: bool,
+    /// 因命中 URL 黑名单而移除的条目数
+    pub blocked: usize,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sitemap_request_limits() {
+        let dto: SitemapRequestDto =
+            serde_json::from_str(r#"{"url": "https://example.com/sitemap.xml"}"#).unwrap();
+        assert!(dto.validate().is_ok());
+        assert_eq!(
+            dto.limits(),
+            SitemapLimits {
+                max_sitemaps: DEFAULT_SITEMAP_MAX_SITEMAPS,
+                max_urls: DEFAULT_SITEMAP_MAX_URLS,
+            }
+        );
+
+        let dto: SitemapRequestDto = serde_json::from_str(
+            r#"{"url": "https://example.com/sitemap.xml", "max_sitemaps": 51}"#,
+        )
+        .unwrap();
+        assert!(dto.validate().is_err());
+    }
+}
@@ -6,18 +6,37 @@
 //! Webhook request and response DTOs
 
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use uuid::Uuid;
 
 /// 创建 Webhook 的请求 DTO
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct CreateWebhookRequest {
     /// Webhook 回调 URL
     pub url: String,
+    /// 订阅的事件类型（如 `crawl.completed`、`scrape.failed`），为空表示订阅全部事件
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub event_types: Vec<String>,
+    /// 每次投递附带的静态请求头（如接收方的认证 token）
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub custom_headers: HashMap<String, String>,
+    /// 载荷模板（JSON 文本，`{{path}}` 占位符引用事件字段），缺省时发送原始载荷
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub payload_template: Option<String>,
+}
+
+/// 测试投递 Webhook 的请求 DTO
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct TestWebhookRequest {
+    /// 示例事件类型，缺省时使用 webhook 订阅的第一个事件类型或 `scrape.completed`
+    #[serde(default)]
+    pub event_type: Option<String>,
 }
 
 /// Webhook 响应 DTO
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WebhookResponse {
     /// Webhook ID
     pub id: Uuid,
@@ -25,6 +44,8 @@ pub struct WebhookResponse {
     pub team_id: Uuid,
     /// Webhook 回调 URL
     pub url: String,
+    /// 订阅的事件类型，为空表示订阅全部事件
+    pub event_types: Vec<String>,
     /// 创建时间
     pub created_at: chrono::DateTime<chrono::Utc>,
     /// 是否已激活
@@ -34,7 +55,7 @@ pub struct WebhookResponse {
 }
 
 /// Webhook 列表响应 DTO
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WebhookListResponse {
     pub webhooks: Vec<WebhookResponse>,
     pub total: usize,
@@ -0,0 +1,342 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! 爬取预演（dry run）
+//!
+//! 只执行链接发现：抓取起始页面并解析链接、读取 robots.txt 声明的站点地图，按爬取配置
+//! 过滤（域名范围、包含/排除模式、查询参数规则、黑名单）后返回待抓取的 URL 集合，以及
+//! 预估的页面数与积分消耗。不创建爬取记录与任务，也不扣除积分。
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::application::dto::crawl_request::{CrawlConfigDto, CrawlRequestDto};
+use crate::application::use_cases::crawl_use_case::{validate_crawl_config, CrawlUseCaseError};
+use crate::domain::models::UrlBlocklist;
+use crate::domain::services::credit_pricing::PricingTable;
+use crate::domain::services::pricing_service::PricingService;
+use crate::domain::services::url_blocklist_service::UrlBlocklistService;
+use crate::engines::engine_client::{EngineClient, HttpMethod, ScrapeOptions, ScrapeRequest};
+use crate::engines::host_resolve::parse_resolve_overrides;
+use crate::utils::regex_cache::RegexCache;
+use crate::utils::robots::RobotsCheckerTrait;
+use crate::workers::scrape_worker::{discover_page_links, discover_sitemap_urls, should_crawl_url};
+
+/// 预演抓取起始页面的超时时间
+const DRY_RUN_FETCH_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// 待抓取的 URL
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FrontierUrlDto {
+    /// URL
+    pub url: String,
+    /// 发现来源：`page`（起始页面中的链接）或 `sitemap`（站点地图）
+    pub source: String,
+}
+
+/// 爬取预演结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CrawlDryRunResponseDto {
+    /// 固定为 `true`，便于与正式创建的响应区分
+    pub dry_run: bool,
+    /// 起始 URL
+    pub url: String,
+    /// robots.txt 是否允许抓取起始 URL
+    pub robots_allowed: bool,
+    /// 起始 URL 之后的第一层待抓取 URL
+    pub frontier: Vec<FrontierUrlDto>,
+    /// 预估抓取的页面数（含起始页面，受 `limit` 约束）
+    pub estimated_pages: u64,
+    /// 预估页面数是否只是下限（`max_depth` 大于 1 时更深层的链接无法提前发现）
+    pub estimated_pages_is_lower_bound: bool,
+    /// 预估消耗的积分
+    pub estimated_credits: i64,
+}
+
+/// 爬取预演用例
+#[derive(Clone)]
+pub struct CrawlDryRunUseCase {
+    /// 引擎客户端，用于抓取起始页面与站点地图
+    engine_client: Arc<EngineClient>,
+    /// robots.txt 检查器
+    robots_checker: Arc<dyn RobotsCheckerTrait>,
+    /// 包含/排除模式的正则缓存
+    regex_cache: Arc<RegexCache>,
+    /// URL 黑名单（可选）
+    url_blocklist: Option<Arc<UrlBlocklistService>>,
+    /// 积分价格服务
+    pricing: Arc<PricingService>,
+}
+
+impl CrawlDryRunUseCase {
+    /// 创建预演用例
+    pub fn new(
+        engine_client: Arc<EngineClient>,
+        robots_checker: Arc<dyn RobotsCheckerTrait>,
+        regex_cache: Arc<RegexCache>,
+    ) -> Self {
+        Self {
+            engine_client,
+            robots_checker,
+            regex_cache,
+            url_blocklist: None,
+            pricing: Arc::new(PricingService::default()),
+        }
+    }
+
+    /// 附加 URL 黑名单，被禁止的 URL 不会出现在结果中
+    pub fn with_url_blocklist(mut self, url_blocklist: Arc<UrlBlocklistService>) -> Self {
+        self.url_blocklist = Some(url_blocklist);
+        self
+    }
+
+    /// 使用配置的价格表估算积分，未设置时使用内置默认价格
+    pub fn with_pricing_service(mut self, pricing: Arc<PricingService>) -> Self {
+        self.pricing = pricing;
+        self
+    }
+
+    /// 执行链接发现并估算成本
+    pub async fn execute(
+        &self,
+        team_id: Uuid,
+        dto: &CrawlRequestDto,
+    ) -> Result<CrawlDryRunResponseDto, CrawlUseCaseError> {
+        let config = &dto.config;
+        validate_crawl_config(config)?;
+        let pricing = self.pricing.pricing_for(team_id);
+
+        if !config.ignore_robots.unwrap_or(false)
+            && !self
+                .robots_checker
+                .is_allowed(&dto.url, "crawlrs-bot")
+                .await
+                .unwrap_or(true)
+        {
+            return Ok(build_response(
+                &dto.url,
+                config,
+                &pricing,
+                false,
+                Vec::new(),
+            ));
+        }
+
+        if config.max_depth == 0 {
+            return Ok(build_response(&dto.url, config, &pricing, true, Vec::new()));
+        }
+
+        let blocklist = match &self.url_blocklist {
+            Some(url_blocklist) => url_blocklist.blocklist_for_team(team_id).await?,
+            None => UrlBlocklist::default(),
+        };
+        let accept = |url: &str| {
+            should_crawl_url(&dto.url, url, config, &self.regex_cache) && !blocklist.is_blocked(url)
+        };
+
+        let response = self
+            .engine_client
+            .scrape(&root_request(&dto.url, config))
+            .await
+            .map_err(|e| {
+                CrawlUseCaseError::ValidationError(format!("Failed to fetch {}: {}", dto.url, e))
+            })?;
+        let page_links = if response.content_type.contains("text/html") {
+            discover_page_links(&dto.url, &response.content, config, accept)?
+        } else {
+            HashMap::new()
+        };
+
+        let mut sitemap_links = HashMap::new();
+        if !config.ignore_sitemap.unwrap_or(false) {
+            match self.robots_checker.get_sitemaps(&dto.url).await {
+                Ok(sitemaps) if !sitemaps.is_empty() => {
+                    sitemap_links = discover_sitemap_urls(
+                        &self.engine_client,
+                        &dto.url,
+                        sitemaps,
+                        config,
+                        accept,
+                    )
+                    .await;
+                }
+                Ok(_) => {}
+                Err(e) => log::warn!("Failed to read sitemaps for {}: {}", dto.url, e),
+            }
+        }
+
+        let frontier = merge_frontier(page_links.into_keys(), sitemap_links.into_keys());
+        Ok(build_response(&dto.url, config, &pricing, true, frontier))
+    }
+}
+
+/// 抓取起始页面的请求，沿用爬取配置中的请求头、代理与主机名解析覆盖
+fn root_request(url: &str, config: &CrawlConfigDto) -> ScrapeRequest {
+    let headers = config
+        .headers
+        .as_ref()
+        .and_then(|headers| headers.as_object())
+        .map(|headers| {
+            headers
+                .iter()
+                .filter_map(|(key, value)| Some((key.clone(), value.as_str()?.to_string())))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let mut options = ScrapeOptions::builder()
+        .method(HttpMethod::Get)
+        .timeout(DRY_RUN_FETCH_TIMEOUT)
+        .headers(headers);
+    if let Some(proxy) = &config.proxy {
+        options = options.proxy(proxy.clone());
+    }
+    if let Some(resolve) = &config.resolve {
+        options = options.resolve(parse_resolve_overrides(resolve).unwrap_or_default());
+    }
+    ScrapeRequest::new(url).with_options(options.build())
+}
+
+/// 合并页面链接与站点地图 URL，按 URL 排序；两处都出现的 URL 记为 `page`
+fn merge_frontier(
+    page_urls: impl IntoIterator<Item = String>,
+    sitemap_urls: impl IntoIterator<Item = String>,
+) -> Vec<FrontierUrlDto> {
+    let mut frontier: BTreeMap<String, &'static str> = BTreeMap::new();
+    for url in sitemap_urls {
+        frontier.insert(url, "sitemap");
+    }
+    for url in page_urls {
+        frontier.insert(url, "page");
+    }
+    frontier
+        .into_iter()
+        .map(|(url, source)| FrontierUrlDto {
+            url,
+            source: source.to_string(),
+        })
+        .collect()
+}
+
+/// 根据发现的 URL 估算页面数与积分
+///
+/// 爬取创建时收取一次固定费用；配置代理时每个页面额外收取积分。
+fn build_response(
+    url: &str,
+    config: &CrawlConfigDto,
+    pricing: &PricingTable,
+    robots_allowed: bool,
+    frontier: Vec<FrontierUrlDto>,
+) -> CrawlDryRunResponseDto {
+    let mut estimated_pages = if robots_allowed {
+        1 + frontier.len() as u64
+    } else {
+        0
+    };
+    if let Some(limit) = config.limit {
+        estimated_pages = estimated_pages.min(limit as u64);
+    }
+    let per_page = pricing.feature_credits(false, config.proxy.is_some());
+
+    CrawlDryRunResponseDto {
+        dry_run: true,
+        url: url.to_string(),
+        robots_allowed,
+        estimated_pages_is_lower_bound: robots_allowed
+            && config.max_depth > 1
+            && config
+                .limit
+                .is_none_or(|limit| estimated_pages < limit as u64),
+        estimated_credits: pricing.crawl + per_page * estimated_pages as i64,
+        estimated_pages,
+        frontier,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(max_depth: u32) -> CrawlConfigDto {
+        serde_json::from_value(serde_json::json!({ "max_depth": max_depth })).unwrap()
+    }
+
+    #[test]
+    fn test_merge_frontier_sorts_and_prefers_page_source() {
+        let frontier = merge_frontier(
+            vec![
+                "https://example.com/b".to_string(),
+                "https://example.com/a".to_string(),
+            ],
+            vec![
+                "https://example.com/a".to_string(),
+                "https://example.com/c".to_string(),
+            ],
+        );
+        let sources: Vec<(&str, &str)> = frontier
+            .iter()
+            .map(|entry| (entry.url.as_str(), entry.source.as_str()))
+            .collect();
+        assert_eq!(
+            sources,
+            vec![
+                ("https://example.com/a", "page"),
+                ("https://example.com/b", "page"),
+                ("https://example.com/c", "sitemap"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_build_response_estimates_pages_and_credits() {
+        let frontier = merge_frontier(
+            (0..5).map(|i| format!("https://example.com/{}", i)),
+            Vec::new(),
+        );
+
+        let pricing = PricingTable::default();
+        let response = build_response(
+            "https://example.com",
+            &config(1),
+            &pricing,
+            true,
+            frontier.clone(),
+        );
+        assert_eq!(response.estimated_pages, 6);
+        assert!(!response.estimated_pages_is_lower_bound);
+        assert_eq!(response.estimated_credits, pricing.crawl);
+
+        let mut limited = config(3);
+        limited.limit = Some(4);
+        limited.proxy = Some("http://proxy.example.com:8080".to_string());
+        let response = build_response(
+            "https://example.com",
+            &limited,
+            &pricing,
+            true,
+            frontier.clone(),
+        );
+        assert_eq!(response.estimated_pages, 4);
+        assert!(!response.estimated_pages_is_lower_bound);
+        assert_eq!(response.estimated_credits, pricing.crawl + 4);
+
+        let response = build_response("https://example.com", &config(2), &pricing, true, frontier);
+        assert!(response.estimated_pages_is_lower_bound);
+
+        let response = build_response(
+            "https://example.com",
+            &config(2),
+            &pricing,
+            false,
+            Vec::new(),
+        );
+        assert_eq!(response.estimated_pages, 0);
+        assert!(!response.estimated_pages_is_lower_bound);
+    }
+}
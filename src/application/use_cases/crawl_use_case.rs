@@ -4,18 +4,19 @@
 // See LICENSE file in the project root for full license information.
 
 use crate::{
-    application::dto::crawl_request::CrawlRequestDto,
+    application::dto::crawl_request::{CrawlConfigDto, CrawlRequestDto},
     domain::{
         models::{scrape_result::ScrapeResult, Crawl, CrawlStatus, Task, TaskStatus, TaskType},
         repositories::{
             crawl_repository::CrawlRepository,
             geo_restriction_repository::GeoRestrictionRepository,
-            scrape_result_repository::ScrapeResultRepository,
+            scrape_result_repository::{ResultSearchHit, ScrapeResultRepository},
             task_repository::{RepositoryError, TaskRepository},
             webhook_repository::WebhookRepository,
         },
         services::team_service::TeamService,
     },
+    engines::host_resolve::parse_resolve_overrides,
 };
 use chrono::Utc;
 use log::error;
@@ -24,6 +25,9 @@ use std::sync::Arc;
 use thiserror::Error;
 use uuid::Uuid;
 
+/// 全文检索词的最大字符数
+pub const MAX_SEARCH_QUERY_CHARS: usize = 256;
+
 /// 爬取用例错误类型
 ///
 /// 定义爬取用例中可能发生的各种错误情况
@@ -49,6 +53,41 @@ pub enum CrawlUseCaseError {
 /// 爬取用例
 ///
 /// 处理爬取任务的核心业务逻辑，包括创建、查询、取消等操作
+/// 校验爬取配置中与仓库无关的取值范围，正式爬取与预演共用
+pub(crate) fn validate_crawl_config(config: &CrawlConfigDto) -> Result<(), CrawlUseCaseError> {
+    if config.max_depth > 5 {
+        return Err(CrawlUseCaseError::ValidationError(
+            "max_depth must be between 0 and 5".to_string(),
+        ));
+    }
+    if let Some(concurrency) = config.max_concurrency {
+        if concurrency > 100 {
+            return Err(CrawlUseCaseError::ValidationError(
+                "max_concurrency must be between 1 and 100".to_string(),
+            ));
+        }
+    }
+    if config.limit == Some(0) {
+        return Err(CrawlUseCaseError::ValidationError(
+            "limit must be at least 1".to_string(),
+        ));
+    }
+    if config.max_duration_seconds == Some(0) {
+        return Err(CrawlUseCaseError::ValidationError(
+            "max_duration_seconds must be at least 1".to_string(),
+        ));
+    }
+    if let Some(destination) = &config.result_destination {
+        destination
+            .validate()
+            .map_err(CrawlUseCaseError::ValidationError)?;
+    }
+    if let Some(resolve) = &config.resolve {
+        parse_resolve_overrides(resolve).map_err(CrawlUseCaseError::ValidationError)?;
+    }
+    Ok(())
+}
+
 #[allow(dead_code)]
 pub struct CrawlUseCase {
     /// 爬取任务仓库
@@ -147,6 +186,104 @@ impl CrawlUseCase {
         Ok(results)
     }
 
+    /// 获取某次爬取中指定 URL 的页面版本
+    ///
+    /// 增量重爬中未变更（304）的结果不保存正文，此时从 `meta_data.content_task_id`
+    /// 指向的结果读取正文。
+    ///
+    /// # 参数
+    ///
+    /// * `crawl_id` - 爬取任务 ID
+    /// * `team_id` - 团队 ID，用于权限验证
+    /// * `url` - 页面 URL
+    ///
+    /// # 返回值
+    ///
+    /// * `Ok(Some(ScrapeResult))` - 该 URL 在此次爬取中的最新结果
+    /// * `Ok(None)` - 此次爬取没有该 URL 的结果
+    /// * `Err(CrawlUseCaseError)` - 爬取任务不存在、不属于该团队或查询失败
+    pub async fn get_page_version(
+        &self,
+        crawl_id: Uuid,
+        team_id: Uuid,
+        url: &str,
+    ) -> Result<Option<ScrapeResult>, CrawlUseCaseError> {
+        match self.crawl_repo.find_by_id(crawl_id).await? {
+            Some(crawl) if crawl.team_id == team_id => {}
+            _ => return Err(CrawlUseCaseError::NotFound),
+        }
+
+        let Some(mut result) = self
+            .scrape_result_repo
+            .find_latest_for_crawl_url(crawl_id, url)
+            .await?
+        else {
+            return Ok(None);
+        };
+
+        let content_task_id = result
+            .meta_data
+            .get("content_task_id")
+            .and_then(serde_json::Value::as_str)
+            .and_then(|id| Uuid::parse_str(id).ok());
+        if let (304, Some(content_task_id)) = (result.status_code, content_task_id) {
+            if let Some(stored) = self
+                .scrape_result_repo
+                .find_by_task_id(content_task_id)
+                .await?
+            {
+                result.content = stored.content;
+                result.content_type = stored.content_type;
+            }
+        }
+
+        Ok(Some(result))
+    }
+
+    /// 在爬取的结果中全文检索
+    ///
+    /// # 参数
+    ///
+    /// * `crawl_id` - 爬取任务 ID
+    /// * `team_id` - 团队 ID，用于权限验证
+    /// * `query` - 检索词，支持引号短语、`or` 与 `-` 排除
+    /// * `limit` - 返回的最大结果数
+    ///
+    /// # 返回值
+    ///
+    /// * `Ok(Vec<ResultSearchHit>)` - 按相关度降序排列的命中结果
+    /// * `Err(CrawlUseCaseError)` - 检索词无效、爬取任务不存在、不属于该团队或查询失败
+    pub async fn search_crawl_results(
+        &self,
+        crawl_id: Uuid,
+        team_id: Uuid,
+        query: &str,
+        limit: u64,
+    ) -> Result<Vec<ResultSearchHit>, CrawlUseCaseError> {
+        let query = query.trim();
+        if query.is_empty() {
+            return Err(CrawlUseCaseError::ValidationError(
+                "Search query must not be empty".to_string(),
+            ));
+        }
+        if query.chars().count() > MAX_SEARCH_QUERY_CHARS {
+            return Err(CrawlUseCaseError::ValidationError(format!(
+                "Search query must be at most {} characters",
+                MAX_SEARCH_QUERY_CHARS
+            )));
+        }
+
+        match self.crawl_repo.find_by_id(crawl_id).await? {
+            Some(crawl) if crawl.team_id == team_id => {}
+            _ => return Err(CrawlUseCaseError::NotFound),
+        }
+
+        Ok(self
+            .scrape_result_repo
+            .search_crawl_results(crawl_id, query, limit)
+            .await?)
+    }
+
     /// 创建新的爬取任务
     ///
     /// 验证请求参数并检查地理限制，然后创建新的爬取任务记录
@@ -177,17 +314,15 @@ impl CrawlUseCase {
     ) -> Result<Crawl, CrawlUseCaseError> {
         // 1. 验证请求参数 (URL 验证在 handler 中进行)
 
-        // 简化 config 验证
-        if dto.config.max_depth > 5 {
-            return Err(CrawlUseCaseError::ValidationError(
-                "max_depth must be between 0 and 5".to_string(),
-            ));
-        }
-        if let Some(concurrency) = dto.config.max_concurrency {
-            if concurrency > 100 {
-                return Err(CrawlUseCaseError::ValidationError(
-                    "max_concurrency must be between 1 and 100".to_string(),
-                ));
+        validate_crawl_config(&dto.config)?;
+        if let Some(previous_crawl_id) = dto.config.previous_crawl_id {
+            match self.crawl_repo.find_by_id(previous_crawl_id).await? {
+                Some(previous) if previous.team_id == team_id => {}
+                _ => {
+                    return Err(CrawlUseCaseError::ValidationError(
+                        "previous_crawl_id must reference a crawl of the same team".to_string(),
+                    ))
+                }
             }
         }
 
@@ -257,7 +392,8 @@ impl CrawlUseCase {
             url.clone(),
             url,
             CrawlStatus::Queued,
-            json!(dto.config),
+            // 爬取记录会返回给客户端，投递目标的凭证只保留在任务负载中
+            json!(dto.config.redacted()),
             1, // total_tasks
             0, // completed_tasks
             0, // failed_tasks
@@ -274,7 +410,8 @@ impl CrawlUseCase {
             id: Uuid::new_v4(),         // 生成任务 ID
             task_type: TaskType::Crawl, // 任务类型为爬取
             status: TaskStatus::Queued, // 任务状态为排队中
-            priority: 100,              // 默认优先级 100
+            // 默认低优先级，可由请求指定档位
+            priority: dto.priority.map_or(100, |tier| tier.priority()),
             team_id,
             api_key_id,   // API密钥ID
             url: dto.url, // 爬取目标 URL
@@ -369,10 +506,7 @@ impl CrawlUseCase {
                 }
 
                 // 如果任务已完成、失败或已取消，则无需操作
-                if c.status == CrawlStatus::Completed
-                    || c.status == CrawlStatus::Failed
-                    || c.status == CrawlStatus::Cancelled
-                {
+                if c.is_finished() {
                     return Ok(()); // 任务已结束
                 }
 
@@ -395,6 +529,7 @@ impl CrawlUseCase {
 mod tests {
     use super::*;
     use crate::domain::models::scrape_result::ScrapeResult;
+    use crate::domain::models::PriorityTier;
     use crate::domain::repositories::geo_restriction_repository::GeoRestrictionRepositoryError;
     use crate::domain::services::geo_location::{GeoLocation, GeoLocationService};
     use crate::domain::services::team_service::TeamGeoRestrictions;
@@ -606,6 +741,7 @@ mod tests {
                 )));
             }
             self.created_count.fetch_add(1, Ordering::SeqCst);
+            self.stored_tasks.lock().unwrap().push(task.clone());
             Ok(task.clone())
         }
 
@@ -754,8 +890,14 @@ mod tests {
             Ok(())
         }
 
-        async fn find_by_task_id(&self, _task_id: Uuid) -> anyhow::Result<Option<ScrapeResult>> {
-            Ok(None)
+        async fn find_by_task_id(&self, task_id: Uuid) -> anyhow::Result<Option<ScrapeResult>> {
+            Ok(self
+                .stored_results
+                .lock()
+                .unwrap()
+                .iter()
+                .find(|r| r.task_id == task_id)
+                .cloned())
         }
 
         async fn find_by_task_ids(&self, _task_ids: &[Uuid]) -> anyhow::Result<Vec<ScrapeResult>> {
@@ -765,9 +907,51 @@ mod tests {
             Ok(self.stored_results.lock().unwrap().clone())
         }
 
+        async fn find_latest_for_crawl_url(
+            &self,
+            _crawl_id: Uuid,
+            url: &str,
+        ) -> anyhow::Result<Option<ScrapeResult>> {
+            Ok(self
+                .stored_results
+                .lock()
+                .unwrap()
+                .iter()
+                .rev()
+                .find(|r| r.url == url)
+                .cloned())
+        }
+
+        async fn count_not_modified(&self, _task_ids: &[Uuid]) -> anyhow::Result<u64> {
+            Ok(0)
+        }
+
+        async fn response_times(&self, _task_ids: &[Uuid]) -> anyhow::Result<Vec<i64>> {
+            Ok(Vec::new())
+        }
+
         async fn get_team_avg_response_time(&self, _team_id: Uuid) -> anyhow::Result<f64> {
             Ok(0.0)
         }
+
+        async fn delete_for_team(
+            &self,
+            _team_id: Uuid,
+            _filter: &crate::domain::repositories::scrape_result_repository::ResultDeletionFilter,
+        ) -> anyhow::Result<Vec<ScrapeResult>> {
+            Ok(vec![])
+        }
+
+        async fn search_crawl_results(
+            &self,
+            _crawl_id: Uuid,
+            _query: &str,
+            _limit: u64,
+        ) -> anyhow::Result<
+            Vec<crate::domain::repositories::scrape_result_repository::ResultSearchHit>,
+        > {
+            Ok(vec![])
+        }
     }
 
     // ============ MockGeoRestrictionRepository ============
@@ -912,9 +1096,29 @@ mod tests {
                 proxy: None,
                 headers: None,
                 extraction_rules: None,
+                skip_nofollow_links: None,
+                ignore_robots: None,
+                ignore_sitemap: None,
+                limit: None,
+                max_duration_seconds: None,
+                previous_crawl_id: None,
+                allow_subdomains: None,
+                allow_external_domains: None,
+                ignore_query_params: None,
+                ignore_all_query_params: None,
+                max_query_variants_per_path: None,
+                download_assets: None,
+                allowed_content_types: None,
+                excluded_content_types: None,
+                result_destination: None,
+                keep_local_results: None,
+                formats: None,
+                resolve: None,
             },
             sync_wait_ms: None,
             expires_at: None,
+            dry_run: None,
+            priority: None,
         }
     }
 
@@ -931,6 +1135,8 @@ mod tests {
             screenshot: None,
             response_time_ms: 100,
             created_at: Utc::now().naive_utc(),
+            etag: None,
+            last_modified: None,
         }
     }
 
@@ -1020,6 +1226,101 @@ mod tests {
         assert_eq!(results[0].task_id, task_id);
     }
 
+    #[tokio::test]
+    async fn test_get_page_version_resolves_unchanged_content() {
+        let team_id = Uuid::new_v4();
+        let crawl_id = Uuid::new_v4();
+        let original = make_scrape_result(Uuid::new_v4());
+        let mut unchanged = make_scrape_result(Uuid::new_v4());
+        unchanged.status_code = 304;
+        unchanged.content = String::new();
+        unchanged.meta_data = json!({"unchanged": true, "content_task_id": original.task_id});
+
+        let use_case = build_use_case_allowed_geo(
+            Arc::new(MockCrawlRepository::with_crawl(make_crawl(
+                crawl_id,
+                team_id,
+                CrawlStatus::Completed,
+            ))),
+            Arc::new(MockTaskRepository::empty()),
+            Arc::new(MockScrapeResultRepository::with_results(vec![
+                original.clone(),
+                unchanged.clone(),
+            ])),
+        );
+
+        let version = use_case
+            .get_page_version(crawl_id, team_id, &unchanged.url)
+            .await
+            .expect("should succeed")
+            .expect("page should exist");
+        assert_eq!(version.id, unchanged.id);
+        assert_eq!(version.status_code, 304);
+        assert_eq!(version.content, original.content);
+
+        let missing = use_case
+            .get_page_version(crawl_id, team_id, "https://example.com/missing")
+            .await
+            .expect("should succeed");
+        assert!(missing.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_get_page_version_wrong_team_returns_not_found() {
+        let crawl_id = Uuid::new_v4();
+        let use_case = build_use_case_allowed_geo(
+            Arc::new(MockCrawlRepository::with_crawl(make_crawl(
+                crawl_id,
+                Uuid::new_v4(),
+                CrawlStatus::Completed,
+            ))),
+            Arc::new(MockTaskRepository::empty()),
+            Arc::new(MockScrapeResultRepository::empty()),
+        );
+
+        let result = use_case
+            .get_page_version(crawl_id, Uuid::new_v4(), "https://example.com")
+            .await;
+        assert!(matches!(result, Err(CrawlUseCaseError::NotFound)));
+    }
+
+    #[tokio::test]
+    async fn test_search_crawl_results_validates_query_and_ownership() {
+        let crawl_id = Uuid::new_v4();
+        let team_id = Uuid::new_v4();
+        let crawl = make_crawl(crawl_id, team_id, CrawlStatus::Completed);
+        let use_case = build_use_case_allowed_geo(
+            Arc::new(MockCrawlRepository::with_crawl(crawl)),
+            Arc::new(MockTaskRepository::empty()),
+            Arc::new(MockScrapeResultRepository::empty()),
+        );
+
+        let blank = use_case
+            .search_crawl_results(crawl_id, team_id, "   ", 20)
+            .await;
+        assert!(matches!(blank, Err(CrawlUseCaseError::ValidationError(_))));
+
+        let too_long = "a".repeat(MAX_SEARCH_QUERY_CHARS + 1);
+        let too_long = use_case
+            .search_crawl_results(crawl_id, team_id, &too_long, 20)
+            .await;
+        assert!(matches!(
+            too_long,
+            Err(CrawlUseCaseError::ValidationError(_))
+        ));
+
+        let other_team = use_case
+            .search_crawl_results(crawl_id, Uuid::new_v4(), "pricing", 20)
+            .await;
+        assert!(matches!(other_team, Err(CrawlUseCaseError::NotFound)));
+
+        let hits = use_case
+            .search_crawl_results(crawl_id, team_id, "pricing", 20)
+            .await
+            .unwrap();
+        assert!(hits.is_empty());
+    }
+
     #[tokio::test]
     async fn test_get_crawl_results_crawl_not_found() {
         let use_case = build_use_case_allowed_geo(
@@ -1166,6 +1467,36 @@ mod tests {
         assert_eq!(task_repo.created_count.load(Ordering::SeqCst), 1);
     }
 
+    #[tokio::test]
+    async fn test_create_crawl_priority_sets_initial_task_priority() {
+        let task_repo = Arc::new(MockTaskRepository::empty());
+        let use_case = build_use_case_allowed_geo(
+            Arc::new(MockCrawlRepository::empty()),
+            task_repo.clone(),
+            Arc::new(MockScrapeResultRepository::empty()),
+        );
+
+        let mut dto = make_crawl_dto();
+        dto.priority = Some(PriorityTier::High);
+        use_case
+            .create_crawl(Uuid::new_v4(), Uuid::new_v4(), dto, "1.2.3.4")
+            .await
+            .expect("should succeed");
+        use_case
+            .create_crawl(Uuid::new_v4(), Uuid::new_v4(), make_crawl_dto(), "1.2.3.4")
+            .await
+            .expect("should succeed");
+
+        let priorities: Vec<i32> = task_repo
+            .stored_tasks
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|task| task.priority)
+            .collect();
+        assert_eq!(priorities, vec![0, 100]);
+    }
+
     #[tokio::test]
     async fn test_create_crawl_name_none_uses_default() {
         let team_id = Uuid::new_v4();
@@ -1223,6 +1554,49 @@ mod tests {
         assert!(result.is_ok(), "max_depth=5 should be allowed");
     }
 
+    #[tokio::test]
+    async fn test_create_crawl_previous_crawl_must_belong_to_team() {
+        let team_id = Uuid::new_v4();
+        let previous = make_crawl(Uuid::new_v4(), Uuid::new_v4(), CrawlStatus::Completed);
+        let mut dto = make_crawl_dto();
+        dto.config.previous_crawl_id = Some(previous.id);
+
+        let use_case = build_use_case_allowed_geo(
+            Arc::new(MockCrawlRepository::with_crawl(previous)),
+            Arc::new(MockTaskRepository::empty()),
+            Arc::new(MockScrapeResultRepository::empty()),
+        );
+
+        let result = use_case
+            .create_crawl(team_id, Uuid::new_v4(), dto, "1.2.3.4")
+            .await;
+        let err = match result {
+            Err(CrawlUseCaseError::ValidationError(msg)) => msg,
+            e => panic!("expected ValidationError, got: {:?}", e),
+        };
+        assert!(err.contains("previous_crawl_id"), "got: {}", err);
+    }
+
+    #[tokio::test]
+    async fn test_create_crawl_with_previous_crawl_of_same_team_succeeds() {
+        let team_id = Uuid::new_v4();
+        let previous = make_crawl(Uuid::new_v4(), team_id, CrawlStatus::Completed);
+        let mut dto = make_crawl_dto();
+        dto.config.previous_crawl_id = Some(previous.id);
+
+        let use_case = build_use_case_allowed_geo(
+            Arc::new(MockCrawlRepository::with_crawl(previous.clone())),
+            Arc::new(MockTaskRepository::empty()),
+            Arc::new(MockScrapeResultRepository::empty()),
+        );
+
+        let crawl = use_case
+            .create_crawl(team_id, Uuid::new_v4(), dto, "1.2.3.4")
+            .await
+            .expect("previous crawl of the same team should be accepted");
+        assert_eq!(crawl.previous_crawl_id(), Some(previous.id));
+    }
+
     #[tokio::test]
     async fn test_create_crawl_max_concurrency_exceeds_limit() {
         let mut dto = make_crawl_dto();
@@ -1261,6 +1635,91 @@ mod tests {
         assert!(result.is_ok(), "max_concurrency=100 should be allowed");
     }
 
+    #[tokio::test]
+    async fn test_create_crawl_redacts_result_destination_on_crawl_record() {
+        let mut dto = make_crawl_dto();
+        dto.config.result_destination = Some(crate::domain::models::ResultDestination::S3 {
+            bucket: "acme-results".to_string(),
+            region: "eu-west-1".to_string(),
+            endpoint: None,
+            prefix: None,
+            access_key_id: "AKIAEXAMPLE".to_string(),
+            secret_access_key: "top-secret".to_string(),
+            session_token: None,
+        });
+
+        let task_repo = Arc::new(MockTaskRepository::empty());
+        let use_case = build_use_case_allowed_geo(
+            Arc::new(MockCrawlRepository::empty()),
+            task_repo.clone(),
+            Arc::new(MockScrapeResultRepository::empty()),
+        );
+
+        let crawl = use_case
+            .create_crawl(Uuid::new_v4(), Uuid::new_v4(), dto, "1.2.3.4")
+            .await
+            .expect("valid destination should be accepted");
+        assert_eq!(
+            crawl.config()["result_destination"]["secret_access_key"],
+            "***"
+        );
+        // The worker still receives the credentials through the task payload
+        let tasks = task_repo.stored_tasks.lock().unwrap();
+        assert_eq!(
+            tasks[0].payload["config"]["result_destination"]["secret_access_key"],
+            "top-secret"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_create_crawl_invalid_result_destination() {
+        let mut dto = make_crawl_dto();
+        dto.config.result_destination = Some(crate::domain::models::ResultDestination::Presigned {
+            url: "http://uploads.example.com/acme".to_string(),
+            headers: None,
+        });
+
+        let use_case = build_use_case_allowed_geo(
+            Arc::new(MockCrawlRepository::empty()),
+            Arc::new(MockTaskRepository::empty()),
+            Arc::new(MockScrapeResultRepository::empty()),
+        );
+
+        let result = use_case
+            .create_crawl(Uuid::new_v4(), Uuid::new_v4(), dto, "1.2.3.4")
+            .await;
+        let err = match result {
+            Err(CrawlUseCaseError::ValidationError(msg)) => msg,
+            e => panic!("expected ValidationError, got: {:?}", e),
+        };
+        assert!(err.contains("https"), "got: {}", err);
+    }
+
+    #[tokio::test]
+    async fn test_create_crawl_rejects_private_resolve_override() {
+        let mut dto = make_crawl_dto();
+        dto.config.resolve = Some(
+            [("example.com".to_string(), "10.0.0.5".to_string())]
+                .into_iter()
+                .collect(),
+        );
+
+        let use_case = build_use_case_allowed_geo(
+            Arc::new(MockCrawlRepository::empty()),
+            Arc::new(MockTaskRepository::empty()),
+            Arc::new(MockScrapeResultRepository::empty()),
+        );
+
+        let result = use_case
+            .create_crawl(Uuid::new_v4(), Uuid::new_v4(), dto, "1.2.3.4")
+            .await;
+        let err = match result {
+            Err(CrawlUseCaseError::ValidationError(msg)) => msg,
+            e => panic!("expected ValidationError, got: {:?}", e),
+        };
+        assert!(err.contains("not a public address"), "got: {}", err);
+    }
+
     #[tokio::test]
     async fn test_create_crawl_geo_denied_invalid_ip() {
         // enable_geo_restrictions=true + invalid IP → Denied without calling geolocation
@@ -15,13 +15,21 @@ use std::time::Duration;
 use serde_json::Value;
 
 use crate::application::dto::scrape_request::{
-    ScrapeActionDto, ScrapeOptionsDto, ScrapeRequestDto,
+    AccessibilityOptionsDto, PdfOptionsDto, ScrapeActionDto, ScrapeActionStepDto, ScrapeOptionsDto,
+    ScrapeRequestDto,
 };
 use crate::domain::models::DomainError;
+use crate::engines::accessibility::requests_accessibility;
+use crate::engines::auto_scroll::AutoScroll;
 use crate::engines::engine_client::{
-    EngineClient, HttpMethod, PageAction, ScrapeOptions, ScrapeRequest, ScrapeResponse,
-    ScreenshotConfig, ScrollDirection,
+    ActionErrorPolicy, EngineClient, HttpMethod, HttpProtocol, PageAction, ScrapeOptions,
+    ScrapeRequest, ScrapeResponse, ScreenshotConfig, ScrollDirection, DEFAULT_ACTION_TIMEOUT_MS,
 };
+use crate::engines::har::requests_har;
+use crate::engines::iframe::IframeCapture;
+use crate::engines::mhtml::requests_mhtml;
+use crate::engines::pdf::requests_pdf;
+use crate::engines::resource_blocking::ResourceBlocking;
 
 // === Section: Use Case Definition ===
 
@@ -87,6 +95,20 @@ impl CreateScrapeUseCase {
             skip_tls_verification: None,
             needs_tls_fingerprint: None,
             use_fire_engine: None,
+            http_protocol: None,
+            address_family: None,
+            tls_profile: None,
+            block_resources: None,
+            blocked_domains: None,
+            block_ads: None,
+            iframes: None,
+            cross_origin_iframes: None,
+            flatten_shadow_dom: None,
+            scroll: None,
+            engine_tier: None,
+            pdf_options: None,
+            table_options: None,
+            accessibility_options: None,
         });
 
         let headers = self.parse_headers(options.headers)?;
@@ -96,56 +118,155 @@ impl CreateScrapeUseCase {
             selector: opts.selector,
             quality: opts.quality,
             format: opts.format,
+            max_height: opts.max_height,
         });
 
+        let (actions, action_error_policies) = self.parse_actions(dto.actions);
+        // iframe、Shadow DOM 与无限滚动只能由浏览器引擎处理
+        let iframe_capture =
+            IframeCapture::from_parts(options.iframes.as_deref(), options.cross_origin_iframes);
+        let flatten_shadow_dom = options.flatten_shadow_dom.unwrap_or(false);
+        let auto_scroll = options
+            .scroll
+            .and_then(|s| AutoScroll::from_parts(&s.mode, s.max_scrolls, s.idle_ms));
+
         let scrape_options = ScrapeOptions {
             method: HttpMethod::Get,
-            needs_js: options.js_rendering.unwrap_or(false),
+            needs_js: options.js_rendering.unwrap_or(false)
+                || iframe_capture.is_some()
+                || flatten_shadow_dom
+                || auto_scroll.is_some(),
             needs_screenshot: options.screenshot.unwrap_or(false),
             mobile: options.mobile.unwrap_or(false),
             timeout: Duration::from_secs(options.timeout.unwrap_or(30)),
             body: None,
             sync_wait_ms: dto.sync_wait_ms.unwrap_or(0),
-            actions: self.parse_actions(dto.actions),
+            actions,
             screenshot_config,
             proxy: options.proxy,
             skip_tls_verification: options.skip_tls_verification.unwrap_or(false),
             headers,
-            needs_tls_fingerprint: options.needs_tls_fingerprint.unwrap_or(false),
+            // 指定指纹配置即意味着需要 TLS 指纹引擎
+            needs_tls_fingerprint: options.needs_tls_fingerprint.unwrap_or(false)
+                || options.tls_profile.is_some(),
             use_fire_engine: options.use_fire_engine.unwrap_or(false),
+            content_type_filter: None,
+            http_protocol: options
+                .http_protocol
+                .as_deref()
+                .and_then(|p| p.parse().ok())
+                .unwrap_or(HttpProtocol::Auto),
+            tls_profile: options.tls_profile,
+            capture_har: requests_har(dto.formats.as_deref()),
+            capture_mhtml: requests_mhtml(dto.formats.as_deref()),
+            pdf: requests_pdf(dto.formats.as_deref()).then(|| {
+                options
+                    .pdf_options
+                    .as_ref()
+                    .map(PdfOptionsDto::to_options)
+                    .unwrap_or_default()
+            }),
+            resource_blocking: ResourceBlocking::from_parts(
+                options.block_resources,
+                options.blocked_domains,
+                options.block_ads,
+            ),
+            action_error_policies,
+            iframe_capture,
+            flatten_shadow_dom,
+            auto_scroll,
+            accessibility: requests_accessibility(dto.formats.as_deref()).then(|| {
+                options
+                    .accessibility_options
+                    .as_ref()
+                    .map(AccessibilityOptionsDto::to_options)
+                    .unwrap_or_default()
+            }),
+            engine_tier: options.engine_tier.as_deref().and_then(|t| t.parse().ok()),
+            excluded_engines: Vec::new(),
+            resolve: HashMap::new(),
+            address_family: options
+                .address_family
+                .as_deref()
+                .and_then(|f| f.parse().ok()),
         };
 
         Ok(ScrapeRequest::new(dto.url).with_options(scrape_options))
     }
 
-    fn parse_actions(&self, dto_actions: Option<Vec<ScrapeActionDto>>) -> Vec<PageAction> {
+    /// 解析动作列表，返回与动作一一对应的失败策略（截图动作不进入动作列表）
+    fn parse_actions(
+        &self,
+        dto_actions: Option<Vec<ScrapeActionStepDto>>,
+    ) -> (Vec<PageAction>, Vec<ActionErrorPolicy>) {
         dto_actions
             .unwrap_or_default()
             .into_iter()
-            .filter_map(|a| match a {
-                ScrapeActionDto::Wait { milliseconds } => Some(PageAction::Wait { milliseconds }),
-                ScrapeActionDto::Click { selector } => Some(PageAction::Click { selector }),
-                ScrapeActionDto::Scroll { direction } => {
-                    let rust_direction = match direction.as_str() {
-                        "up" => ScrollDirection::Up,
-                        "down" => ScrollDirection::Down,
-                        "top" => ScrollDirection::Top,
-                        "bottom" => ScrollDirection::Bottom,
-                        _ => ScrollDirection::Down,
-                    };
-                    Some(PageAction::Scroll {
-                        direction: rust_direction,
-                    })
-                }
-                ScrapeActionDto::Screenshot { .. } => {
-                    // Screenshot is handled via ScrapeOptions.needs_screenshot
-                    None
-                }
-                ScrapeActionDto::Input { selector, text } => {
-                    Some(PageAction::Input { selector, text })
-                }
+            .filter_map(|step| {
+                let policy = step
+                    .on_error
+                    .as_deref()
+                    .and_then(|p| p.parse::<ActionErrorPolicy>().ok())
+                    .unwrap_or_default();
+                self.parse_action(step.action)
+                    .map(|action| (action, policy))
             })
-            .collect()
+            .unzip()
+    }
+
+    fn parse_action(&self, action: ScrapeActionDto) -> Option<PageAction> {
+        match action {
+            ScrapeActionDto::Wait { milliseconds } => Some(PageAction::Wait { milliseconds }),
+            ScrapeActionDto::Click { selector } => Some(PageAction::Click { selector }),
+            ScrapeActionDto::Scroll { direction } => {
+                let rust_direction = match direction.as_str() {
+                    "up" => ScrollDirection::Up,
+                    "down" => ScrollDirection::Down,
+                    "top" => ScrollDirection::Top,
+                    "bottom" => ScrollDirection::Bottom,
+                    _ => ScrollDirection::Down,
+                };
+                Some(PageAction::Scroll {
+                    direction: rust_direction,
+                })
+            }
+            ScrapeActionDto::Screenshot { .. } => {
+                // Screenshot is handled via ScrapeOptions.needs_screenshot
+                None
+            }
+            ScrapeActionDto::Input { selector, text } => Some(PageAction::Input { selector, text }),
+            ScrapeActionDto::Evaluate { script } => Some(PageAction::Evaluate { script }),
+            ScrapeActionDto::WaitForSelector {
+                selector,
+                timeout_ms,
+                state,
+            } => Some(PageAction::WaitForSelector {
+                selector,
+                timeout_ms: timeout_ms.unwrap_or(DEFAULT_ACTION_TIMEOUT_MS),
+                state: state
+                    .as_deref()
+                    .and_then(|s| s.parse().ok())
+                    .unwrap_or_default(),
+            }),
+            ScrapeActionDto::WaitForNavigation { timeout_ms } => {
+                Some(PageAction::WaitForNavigation {
+                    timeout_ms: timeout_ms.unwrap_or(DEFAULT_ACTION_TIMEOUT_MS),
+                })
+            }
+            ScrapeActionDto::Select { selector, value } => {
+                Some(PageAction::Select { selector, value })
+            }
+            ScrapeActionDto::Check { selector, checked } => Some(PageAction::Check {
+                selector,
+                checked: checked.unwrap_or(true),
+            }),
+            ScrapeActionDto::PressKey { key } => Some(PageAction::PressKey { key }),
+            ScrapeActionDto::Submit { selector } => Some(PageAction::Submit { selector }),
+            ScrapeActionDto::Hover { selector } => Some(PageAction::Hover { selector }),
+            ScrapeActionDto::Drag { from, to } => Some(PageAction::Drag { from, to }),
+            ScrapeActionDto::KeyDown { key } => Some(PageAction::KeyDown { key }),
+            ScrapeActionDto::KeyUp { key } => Some(PageAction::KeyUp { key }),
+        }
     }
 
     fn parse_headers(
@@ -207,6 +328,12 @@ fn map_engine_error(engine_error: crate::engines::engine_client::EngineError) ->
         crate::engines::engine_client::EngineError::Expired => {
             DomainError::EngineError("Engine request expired".to_string())
         }
+        crate::engines::engine_client::EngineError::ResponseTooLarge(limit) => {
+            DomainError::EngineError(format!("Response body exceeds {} bytes", limit))
+        }
+        crate::engines::engine_client::EngineError::ContentTypeRejected(content_type) => {
+            DomainError::EngineError(format!("Content type not accepted: {}", content_type))
+        }
         crate::engines::engine_client::EngineError::Other(msg) => DomainError::EngineError(msg),
     }
 }
@@ -214,7 +341,7 @@ fn map_engine_error(engine_error: crate::engines::engine_client::EngineError) ->
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::engines::engine_client::{EngineError, PageAction, ScrollDirection};
+    use crate::engines::engine_client::{EngineError, PageAction, ScrollDirection, SelectorState};
     use std::sync::Arc;
 
     // ============ Helpers ============
@@ -235,6 +362,8 @@ mod tests {
             options: None,
             metadata: None,
             sync_wait_ms: None,
+            download: None,
+            priority: None,
         }
     }
 
@@ -299,9 +428,25 @@ mod tests {
                 skip_tls_verification: Some(true),
                 needs_tls_fingerprint: Some(true),
                 use_fire_engine: Some(true),
+                http_protocol: None,
+                address_family: None,
+                tls_profile: None,
+                block_resources: None,
+                blocked_domains: None,
+                block_ads: None,
+                iframes: None,
+                cross_origin_iframes: None,
+                flatten_shadow_dom: None,
+                scroll: None,
+                engine_tier: None,
+                pdf_options: None,
+                table_options: None,
+                accessibility_options: None,
             }),
             metadata: None,
             sync_wait_ms: Some(500),
+            download: None,
+            priority: None,
         };
 
         let request = use_case
@@ -346,6 +491,7 @@ mod tests {
                         selector: Some("#content".to_string()),
                         quality: Some(80),
                         format: Some("png".to_string()),
+                        max_height: None,
                     },
                 ),
                 mobile: None,
@@ -353,9 +499,25 @@ mod tests {
                 skip_tls_verification: None,
                 needs_tls_fingerprint: None,
                 use_fire_engine: None,
+                http_protocol: None,
+                address_family: None,
+                tls_profile: None,
+                block_resources: None,
+                blocked_domains: None,
+                block_ads: None,
+                iframes: None,
+                cross_origin_iframes: None,
+                flatten_shadow_dom: None,
+                scroll: None,
+                engine_tier: None,
+                pdf_options: None,
+                table_options: None,
+                accessibility_options: None,
             }),
             metadata: None,
             sync_wait_ms: None,
+            download: None,
+            priority: None,
         };
 
         let request = use_case
@@ -396,6 +558,7 @@ mod tests {
                         selector: None,
                         quality: None,
                         format: None,
+                        max_height: None,
                     },
                 ),
                 mobile: None,
@@ -403,9 +566,25 @@ mod tests {
                 skip_tls_verification: None,
                 needs_tls_fingerprint: None,
                 use_fire_engine: None,
+                http_protocol: None,
+                address_family: None,
+                tls_profile: None,
+                block_resources: None,
+                blocked_domains: None,
+                block_ads: None,
+                iframes: None,
+                cross_origin_iframes: None,
+                flatten_shadow_dom: None,
+                scroll: None,
+                engine_tier: None,
+                pdf_options: None,
+                table_options: None,
+                accessibility_options: None,
             }),
             metadata: None,
             sync_wait_ms: None,
+            download: None,
+            priority: None,
         };
 
         let request = use_case
@@ -421,17 +600,22 @@ mod tests {
 
     // ============ parse_actions ============
 
+    fn steps(actions: Vec<ScrapeActionDto>) -> Option<Vec<ScrapeActionStepDto>> {
+        Some(actions.into_iter().map(Into::into).collect())
+    }
+
     #[test]
     fn test_parse_actions_none_returns_empty() {
         let use_case = CreateScrapeUseCase::new(make_engine_client());
-        let actions = use_case.parse_actions(None);
+        let (actions, policies) = use_case.parse_actions(None);
         assert!(actions.is_empty());
+        assert!(policies.is_empty());
     }
 
     #[test]
     fn test_parse_actions_empty_vec_returns_empty() {
         let use_case = CreateScrapeUseCase::new(make_engine_client());
-        let actions = use_case.parse_actions(Some(vec![]));
+        let (actions, _) = use_case.parse_actions(Some(vec![]));
         assert!(actions.is_empty());
     }
 
@@ -459,11 +643,14 @@ mod tests {
                 selector: "#field".to_string(),
                 text: "hello".to_string(),
             },
+            ScrapeActionDto::Evaluate {
+                script: "document.title".to_string(),
+            },
         ];
 
-        let actions = use_case.parse_actions(Some(dto_actions));
-        // Screenshot is filtered out, so 7 meaningful actions remain
-        assert_eq!(actions.len(), 7);
+        let (actions, _) = use_case.parse_actions(steps(dto_actions));
+        // Screenshot is filtered out, so 8 meaningful actions remain
+        assert_eq!(actions.len(), 8);
 
         assert!(matches!(
             &actions[0],
@@ -501,6 +688,132 @@ mod tests {
             &actions[6],
             PageAction::Input { selector, text } if selector == "#field" && text == "hello"
         ));
+        assert!(matches!(
+            &actions[7],
+            PageAction::Evaluate { script } if script == "document.title"
+        ));
+    }
+
+    #[test]
+    fn test_parse_actions_wait_for_selector_defaults() {
+        let use_case = CreateScrapeUseCase::new(make_engine_client());
+        let dto_actions = vec![
+            ScrapeActionDto::WaitForSelector {
+                selector: "#results".to_string(),
+                timeout_ms: None,
+                state: None,
+            },
+            ScrapeActionDto::WaitForSelector {
+                selector: ".spinner".to_string(),
+                timeout_ms: Some(5_000),
+                state: Some("detached".to_string()),
+            },
+            ScrapeActionDto::WaitForNavigation { timeout_ms: None },
+        ];
+
+        let (actions, _) = use_case.parse_actions(steps(dto_actions));
+        assert!(matches!(
+            &actions[0],
+            PageAction::WaitForSelector { selector, timeout_ms: DEFAULT_ACTION_TIMEOUT_MS, state: SelectorState::Visible }
+                if selector == "#results"
+        ));
+        assert!(matches!(
+            &actions[1],
+            PageAction::WaitForSelector {
+                timeout_ms: 5_000,
+                state: SelectorState::Detached,
+                ..
+            }
+        ));
+        assert!(matches!(
+            &actions[2],
+            PageAction::WaitForNavigation {
+                timeout_ms: DEFAULT_ACTION_TIMEOUT_MS
+            }
+        ));
+    }
+
+    #[test]
+    fn test_parse_actions_form_actions_with_error_policy() {
+        let use_case = CreateScrapeUseCase::new(make_engine_client());
+        let dto_actions = vec![
+            ScrapeActionStepDto {
+                action: ScrapeActionDto::Select {
+                    selector: "#country".to_string(),
+                    value: "de".to_string(),
+                },
+                on_error: Some("continue".to_string()),
+            },
+            ScrapeActionDto::Screenshot { full_page: None }.into(),
+            ScrapeActionDto::Check {
+                selector: "#remember".to_string(),
+                checked: None,
+            }
+            .into(),
+            ScrapeActionDto::PressKey {
+                key: "Enter".to_string(),
+            }
+            .into(),
+            ScrapeActionStepDto {
+                action: ScrapeActionDto::Submit {
+                    selector: "#login".to_string(),
+                },
+                on_error: Some("abort".to_string()),
+            },
+        ];
+
+        let (actions, policies) = use_case.parse_actions(Some(dto_actions));
+        assert_eq!(actions.len(), 4);
+        // 截图动作被过滤后策略仍与动作对齐
+        assert_eq!(
+            policies,
+            vec![
+                ActionErrorPolicy::Continue,
+                ActionErrorPolicy::Abort,
+                ActionErrorPolicy::Abort,
+                ActionErrorPolicy::Abort,
+            ]
+        );
+        assert!(matches!(
+            &actions[0],
+            PageAction::Select { selector, value } if selector == "#country" && value == "de"
+        ));
+        assert!(matches!(
+            &actions[1],
+            PageAction::Check { checked: true, .. }
+        ));
+        assert!(matches!(&actions[2], PageAction::PressKey { key } if key == "Enter"));
+        assert!(matches!(&actions[3], PageAction::Submit { selector } if selector == "#login"));
+    }
+
+    #[test]
+    fn test_parse_actions_pointer_and_keyboard_actions() {
+        let use_case = CreateScrapeUseCase::new(make_engine_client());
+        let dto_actions = vec![
+            ScrapeActionDto::Hover {
+                selector: "nav .menu".to_string(),
+            },
+            ScrapeActionDto::Drag {
+                from: "#slider-handle".to_string(),
+                to: "#slider-end".to_string(),
+            },
+            ScrapeActionDto::KeyDown {
+                key: "Shift".to_string(),
+            },
+            ScrapeActionDto::KeyUp {
+                key: "Shift".to_string(),
+            },
+        ];
+
+        let (actions, _) = use_case.parse_actions(steps(dto_actions));
+        assert_eq!(actions.len(), 4);
+        assert!(matches!(&actions[0], PageAction::Hover { selector } if selector == "nav .menu"));
+        assert!(matches!(
+            &actions[1],
+            PageAction::Drag { from, to } if from == "#slider-handle" && to == "#slider-end"
+        ));
+        assert!(matches!(&actions[2], PageAction::KeyDown { key } if key == "Shift"));
+        assert!(matches!(&actions[3], PageAction::KeyUp { key } if key == "Shift"));
     }
 
     #[test]
@@ -510,7 +823,7 @@ mod tests {
             direction: "sideways".to_string(),
         }];
 
-        let actions = use_case.parse_actions(Some(dto_actions));
+        let (actions, _) = use_case.parse_actions(steps(dto_actions));
         assert_eq!(actions.len(), 1);
         assert!(matches!(
             &actions[0],
@@ -530,7 +843,7 @@ mod tests {
             ScrapeActionDto::Screenshot { full_page: None },
         ];
 
-        let actions = use_case.parse_actions(Some(dto_actions));
+        let (actions, _) = use_case.parse_actions(steps(dto_actions));
         assert!(
             actions.is_empty(),
             "screenshot actions should be filtered out"
@@ -756,9 +1069,25 @@ mod tests {
                 skip_tls_verification: None,
                 needs_tls_fingerprint: None,
                 use_fire_engine: None,
+                http_protocol: None,
+                address_family: None,
+                tls_profile: None,
+                block_resources: None,
+                blocked_domains: None,
+                block_ads: None,
+                iframes: None,
+                cross_origin_iframes: None,
+                flatten_shadow_dom: None,
+                scroll: None,
+                engine_tier: None,
+                pdf_options: None,
+                table_options: None,
+                accessibility_options: None,
             }),
             metadata: None,
             sync_wait_ms: None,
+            download: None,
+            priority: None,
         };
 
         let result = use_case.execute(dto).await;
@@ -785,14 +1114,18 @@ mod tests {
             actions: Some(vec![
                 ScrapeActionDto::Click {
                     selector: "#x".to_string(),
-                },
+                }
+                .into(),
                 ScrapeActionDto::Scroll {
                     direction: "up".to_string(),
-                },
+                }
+                .into(),
             ]),
             options: None,
             metadata: None,
             sync_wait_ms: Some(100),
+            download: None,
+            priority: None,
         };
 
         let result = use_case.execute(dto).await;
@@ -0,0 +1,281 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! 积分费用预估
+//!
+//! 按团队生效的价格表（与实际扣费相同），预估抓取、爬取、提取请求将消耗的积分，
+//! 并按功能（基础费用、截图、代理、LLM token）拆分。LLM token 无法提前得知，按提示词
+//! 长度与单次调用的经验值估算。
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::application::dto::crawl_request::CrawlRequestDto;
+use crate::application::dto::extract_request::ExtractRequestDto;
+use crate::application::dto::scrape_request::ScrapeRequestDto;
+use crate::domain::services::credit_pricing::{estimate_llm_call_tokens, PricingTable};
+use crate::domain::services::extraction_service::ExtractionRule;
+
+/// 待预估的请求，与对应接口的请求体相同
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(tag = "type", content = "request", rename_all = "lowercase")]
+pub enum EstimateTargetDto {
+    /// `POST /v1/scrape`
+    Scrape(Box<ScrapeRequestDto>),
+    /// `POST /v1/crawl`
+    Crawl(Box<CrawlRequestDto>),
+    /// `POST /v1/extract`
+    Extract(Box<ExtractRequestDto>),
+}
+
+/// 费用预估请求
+#[derive(Debug, Deserialize, Serialize)]
+pub struct EstimateRequestDto {
+    #[serde(flatten)]
+    pub target: EstimateTargetDto,
+    /// 爬取预计抓取的页面数；未指定时使用 `config.limit`，两者都没有时只计起始页面
+    pub pages: Option<u32>,
+}
+
+/// 单项费用
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CreditLineItemDto {
+    /// 功能：`base` / `screenshot` / `proxy` / `llm_tokens`
+    pub feature: String,
+    /// 数量：页面数，`llm_tokens` 为预估 token 数
+    pub quantity: u64,
+    /// 积分
+    pub credits: i64,
+}
+
+/// 费用预估结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreditEstimateDto {
+    /// 请求类型：`scrape` / `crawl` / `extract`
+    pub request_type: String,
+    /// 计费的页面数
+    pub pages: u64,
+    /// 每个页面的附加积分（代理与 LLM token）
+    pub per_page_credits: i64,
+    /// 按功能拆分的积分
+    pub breakdown: Vec<CreditLineItemDto>,
+    /// 预估总积分
+    pub total_credits: i64,
+}
+
+impl CreditEstimateDto {
+    fn new(request_type: &str, pages: u64, breakdown: Vec<CreditLineItemDto>) -> Self {
+        let per_page_credits = breakdown
+            .iter()
+            .filter(|item| item.feature != "base")
+            .map(|item| item.credits)
+            .sum::<i64>()
+            / pages.max(1) as i64;
+        let total_credits = breakdown.iter().map(|item| item.credits).sum();
+        Self {
+            request_type: request_type.to_string(),
+            pages,
+            per_page_credits,
+            breakdown,
+            total_credits,
+        }
+    }
+}
+
+/// 预估请求将消耗的积分
+pub fn estimate_credits(request: &EstimateRequestDto, pricing: &PricingTable) -> CreditEstimateDto {
+    match &request.target {
+        EstimateTargetDto::Scrape(dto) => estimate_scrape(dto, pricing),
+        EstimateTargetDto::Crawl(dto) => estimate_crawl(dto, request.pages, pricing),
+        EstimateTargetDto::Extract(dto) => estimate_extract(dto, pricing),
+    }
+}
+
+/// 抓取：提交时的基础费用，成功后按截图与代理收取附加费用，提取规则中的 LLM 规则按 token 计费
+fn estimate_scrape(dto: &ScrapeRequestDto, pricing: &PricingTable) -> CreditEstimateDto {
+    let options = dto.options.as_ref();
+    let mut breakdown = vec![line_item("base", 1, pricing.scrape)];
+    if options.and_then(|o| o.screenshot).unwrap_or(false) {
+        breakdown.push(line_item("screenshot", 1, pricing.screenshot));
+    }
+    if options.is_some_and(|o| o.proxy.is_some()) {
+        breakdown.push(line_item("proxy", 1, pricing.proxy));
+    }
+    breakdown.extend(llm_line_item(
+        rule_tokens(dto.extraction_rules.as_ref()),
+        1,
+        pricing,
+    ));
+    CreditEstimateDto::new("scrape", 1, breakdown)
+}
+
+/// 爬取：创建时收取一次固定费用，每个页面按代理与提取规则中的 LLM 规则收取附加费用
+fn estimate_crawl(
+    dto: &CrawlRequestDto,
+    pages: Option<u32>,
+    pricing: &PricingTable,
+) -> CreditEstimateDto {
+    let config = &dto.config;
+    let pages = pages.or(config.limit).unwrap_or(1).max(1) as u64;
+    let mut breakdown = vec![line_item("base", 1, pricing.crawl)];
+    if config.proxy.is_some() {
+        breakdown.push(line_item("proxy", pages, pricing.proxy * pages as i64));
+    }
+    breakdown.extend(llm_line_item(
+        rule_tokens(config.extraction_rules.as_ref()),
+        pages,
+        pricing,
+    ));
+    CreditEstimateDto::new("crawl", pages, breakdown)
+}
+
+/// 提取：不收取基础费用，worker 只处理第一个 URL，按 LLM token 计费
+fn estimate_extract(dto: &ExtractRequestDto, pricing: &PricingTable) -> CreditEstimateDto {
+    let tokens = if dto.rules.is_some() {
+        rule_tokens(dto.rules.as_ref())
+    } else if let Some(prompt) = &dto.prompt {
+        estimate_llm_call_tokens(Some(prompt))
+    } else if let Some(schema) = &dto.schema {
+        estimate_llm_call_tokens(Some(&schema.to_string()))
+    } else {
+        0
+    };
+    CreditEstimateDto::new(
+        "extract",
+        1,
+        llm_line_item(tokens, 1, pricing).into_iter().collect(),
+    )
+}
+
+/// 提取规则中 LLM 规则的预估 token 数，每条 LLM 规则一次调用
+fn rule_tokens(rules: Option<&HashMap<String, ExtractionRule>>) -> u64 {
+    rules
+        .into_iter()
+        .flat_map(|rules| rules.values())
+        .filter(|rule| rule.use_llm.unwrap_or(false))
+        .map(|rule| estimate_llm_call_tokens(rule.llm_prompt.as_deref()))
+        .sum()
+}
+
+/// 每个页面 `tokens_per_page` 个 token 的费用；token 按页面分别折算积分
+fn llm_line_item(
+    tokens_per_page: u64,
+    pages: u64,
+    pricing: &PricingTable,
+) -> Option<CreditLineItemDto> {
+    (tokens_per_page > 0).then(|| {
+        line_item(
+            "llm_tokens",
+            tokens_per_page * pages,
+            pricing.token_credits(tokens_per_page) * pages as i64,
+        )
+    })
+}
+
+fn line_item(feature: &str, quantity: u64, credits: i64) -> CreditLineItemDto {
+    CreditLineItemDto {
+        feature: feature.to_string(),
+        quantity,
+        credits,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::services::credit_pricing::ESTIMATED_TOKENS_PER_LLM_CALL;
+    use serde_json::json;
+
+    fn estimate(body: serde_json::Value) -> CreditEstimateDto {
+        estimate_credits(
+            &serde_json::from_value(body).unwrap(),
+            &PricingTable::default(),
+        )
+    }
+
+    fn credits_for(estimate: &CreditEstimateDto, feature: &str) -> Option<i64> {
+        estimate
+            .breakdown
+            .iter()
+            .find(|item| item.feature == feature)
+            .map(|item| item.credits)
+    }
+
+    #[test]
+    fn test_estimate_scrape_with_screenshot_proxy_and_llm_rule() {
+        let result = estimate(json!({
+            "type": "scrape",
+            "request": {
+                "url": "https://example.com",
+                "options": { "screenshot": true, "proxy": "http://proxy:8080" },
+                "extraction_rules": {
+                    "title": { "selector": "h1", "is_array": false },
+                    "summary": { "is_array": false, "use_llm": true, "llm_prompt": "abcd" }
+                }
+            }
+        }));
+        let pricing = PricingTable::default();
+        let llm = pricing.token_credits(ESTIMATED_TOKENS_PER_LLM_CALL + 1);
+        assert_eq!(result.request_type, "scrape");
+        assert_eq!(credits_for(&result, "base"), Some(pricing.scrape));
+        assert_eq!(credits_for(&result, "screenshot"), Some(2));
+        assert_eq!(credits_for(&result, "proxy"), Some(1));
+        assert_eq!(credits_for(&result, "llm_tokens"), Some(llm));
+        assert_eq!(result.total_credits, pricing.scrape + 3 + llm);
+    }
+
+    #[test]
+    fn test_estimate_crawl_scales_per_page_costs() {
+        let body = json!({
+            "type": "crawl",
+            "request": {
+                "url": "https://example.com",
+                "config": { "max_depth": 2, "limit": 50, "proxy": "http://proxy:8080" }
+            }
+        });
+        let result = estimate(body.clone());
+        assert_eq!(result.pages, 50);
+        assert_eq!(result.per_page_credits, 1);
+        assert_eq!(result.total_credits, PricingTable::default().crawl + 50);
+
+        let mut body = body;
+        body["pages"] = json!(20);
+        let result = estimate(body);
+        assert_eq!(result.pages, 20);
+        assert_eq!(credits_for(&result, "proxy"), Some(20));
+    }
+
+    #[test]
+    fn test_estimate_uses_given_pricing_table() {
+        let pricing = PricingTable {
+            scrape: 5,
+            proxy: 0,
+            ..PricingTable::default()
+        };
+        let request = serde_json::from_value(json!({
+            "type": "scrape",
+            "request": {
+                "url": "https://example.com",
+                "options": { "proxy": "http://proxy:8080" }
+            }
+        }))
+        .unwrap();
+        let result = estimate_credits(&request, &pricing);
+        assert_eq!(credits_for(&result, "base"), Some(5));
+        assert_eq!(result.total_credits, 5);
+    }
+
+    #[test]
+    fn test_estimate_extract_has_no_base_charge() {
+        let result = estimate(json!({
+            "type": "extract",
+            "request": { "urls": ["https://example.com"], "prompt": "List the prices" }
+        }));
+        assert_eq!(credits_for(&result, "base"), None);
+        assert_eq!(result.breakdown.len(), 1);
+        assert!(result.total_credits > 0);
+    }
+}
@@ -7,5 +7,7 @@
 ///
 /// 包含应用程序的所有业务用例实现
 /// 每个用例代表一个完整的业务流程，遵循单一职责原则
+pub mod crawl_dry_run;
 pub mod crawl_use_case;
 pub mod create_scrape;
+pub mod estimate_credits;
@@ -7,30 +7,77 @@
 //!
 //! 此模块负责在应用启动早期进行配置和环境变量的安全验证
 
-use crate::config::settings::Settings;
+use crate::config::settings::{config_files, config_profile, Settings};
+use crate::config::validation::validate_settings;
 use crate::infrastructure::security::env_var_security::{EnvVarSecurityMonitor, EnvVarValidator};
 use anyhow::Result;
 use confers::{ConfigBuilder, EnvSource};
 use log::{debug, error, info, warn};
+use std::path::PathBuf;
 
-/// Load application configuration from the standard settings file and environment.
+/// Load application configuration from the layered settings files and environment.
 ///
-/// Uses confers 0.4 `ConfigBuilder` to merge `config/default.toml` with
-/// environment variables prefixed by `CRAWLRS__` (nested via `__`).
+/// Uses confers 0.4 `ConfigBuilder` to merge `config/default.toml`, the active
+/// profile's `config/{profile}.toml` and `config/local.toml` (later files win,
+/// missing optional files are skipped) with environment variables prefixed by
+/// `CRAWLRS__` (nested via `__`), which take precedence over every file. See
+/// [`crate::config::settings`] for how the profile is chosen.
 /// Note: confers 0.4's `load_sync()` only applies field-level defaults and env
 /// vars; it no longer auto-discovers config files (breaking change from 0.2.2).
 pub fn load_settings() -> Result<Settings> {
-    let settings = ConfigBuilder::<Settings>::new()
-        .file("config/default.toml")
+    let profile = config_profile();
+    let files = active_config_files(&profile);
+
+    let mut builder = ConfigBuilder::<Settings>::new();
+    for file in &files {
+        builder = builder.file(file.clone());
+    }
+    let settings = builder
         .source(Box::new(
             EnvSource::with_prefix("CRAWLRS__").separator("__"),
         ))
         .build()
         .map_err(|e| anyhow::anyhow!("Configuration load failed: {}", e))?;
-    info!("Configuration loaded successfully from config sources");
+    info!(
+        "Configuration loaded for profile '{}' from {:?}",
+        profile, files
+    );
     Ok(settings)
 }
 
+/// Settings files that are loaded for `profile`, lowest precedence first.
+///
+/// `config/default.toml` is always included so a missing base file is reported
+/// by the loader; the profile and local files are included only if they exist.
+pub fn active_config_files(profile: &str) -> Vec<PathBuf> {
+    config_files(profile)
+        .into_iter()
+        .enumerate()
+        .filter(|(index, file)| *index == 0 || file.exists())
+        .map(|(_, file)| file)
+        .collect()
+}
+
+/// Render the effective merged configuration with secrets redacted.
+///
+/// Backs the `--print-config` command line flag.
+pub fn print_config() -> Result<String> {
+    let settings = load_settings()?;
+    let profile = config_profile();
+    let files = active_config_files(&profile)
+        .iter()
+        .map(|file| file.display().to_string())
+        .collect::<Vec<_>>()
+        .join(", ");
+    let body = settings
+        .to_redacted_toml()
+        .map_err(|e| anyhow::anyhow!("Failed to render configuration: {}", e))?;
+    Ok(format!(
+        "# profile: {}\n# files: {}\n# environment overrides: CRAWLRS__*\n\n{}",
+        profile, files, body
+    ))
+}
+
 /// Validate configuration security settings.
 ///
 /// In production mode, this function will return an error if any security
@@ -186,8 +233,10 @@ pub fn detect_available_port(settings: &mut Settings) -> Result<u16> {
 
 /// Load, validate, and configure settings for application startup.
 ///
-/// This is a convenience function that combines loading, security validation,
-/// environment validation, and port detection into a single call.
+/// This is a convenience function that combines loading, value validation,
+/// security validation, environment validation, and port detection into a
+/// single call. Invalid values are collected into one error listing every
+/// problem so the process refuses to start before anything is initialized.
 ///
 /// # Arguments
 ///
@@ -200,19 +249,26 @@ pub fn load_and_configure(is_production: bool) -> Result<(Settings, u16)> {
     debug!("Starting application configuration...");
 
     // Step 1: Validate environment variables first (before loading config)
-    debug!("Step 1/4: Validating environment variables...");
+    debug!("Step 1/5: Validating environment variables...");
     validate_environment(is_production)?;
 
     // Step 2: Load configuration settings
-    debug!("Step 2/4: Loading configuration settings...");
+    debug!("Step 2/5: Loading configuration settings...");
     let mut settings = load_settings()?;
 
-    // Step 3: Validate configuration security
-    debug!("Step 3/4: Validating configuration security...");
+    // Step 3: Validate configuration values, reporting every problem at once
+    debug!("Step 3/5: Validating configuration values...");
+    if let Err(e) = validate_settings(&settings, is_production) {
+        error!("{}", e);
+        return Err(e.into());
+    }
+
+    // Step 4: Validate configuration security
+    debug!("Step 4/5: Validating configuration security...");
     validate_security(&settings, is_production)?;
 
-    // Step 4: Detect available port
-    debug!("Step 4/4: Detecting available port...");
+    // Step 5: Detect available port
+    debug!("Step 5/5: Detecting available port...");
     let port = detect_available_port(&mut settings)?;
 
     info!("Application configuration completed successfully");
@@ -0,0 +1,143 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! Configuration hot reload triggers.
+//!
+//! 轮询各层配置文件的修改时间（包括尚未创建的可选文件），并在 Unix 上监听 SIGHUP；
+//! 任一触发时重新加载配置，可热重载的字段立即生效，需要重启的修改只记录警告。
+
+use crate::config::{ReloadReport, ReloadableSettings};
+use log::{error, info, warn};
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+use tokio::task::JoinHandle;
+
+/// Default interval between configuration file checks.
+pub const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+fn modified_at(paths: &[PathBuf]) -> Vec<Option<SystemTime>> {
+    paths
+        .iter()
+        .map(|path| std::fs::metadata(path).and_then(|m| m.modified()).ok())
+        .collect()
+}
+
+/// Reload settings and log the outcome.
+///
+/// A failed reload keeps the current settings in effect.
+pub fn reload_and_log(settings: &ReloadableSettings, trigger: &str) -> Option<ReloadReport> {
+    match settings.reload() {
+        Ok(report) => {
+            if report.is_empty() {
+                info!("Configuration reload ({}): no changes", trigger);
+            } else {
+                if !report.reloaded.is_empty() {
+                    info!(
+                        "Configuration reload ({}): applied {:?}",
+                        trigger, report.reloaded
+                    );
+                }
+                if !report.restart_required.is_empty() {
+                    warn!(
+                        "Configuration reload ({}): {:?} changed but require a restart",
+                        trigger, report.restart_required
+                    );
+                }
+            }
+            Some(report)
+        }
+        Err(e) => {
+            error!(
+                "Configuration reload ({}) failed, keeping current settings: {:#}",
+                trigger, e
+            );
+            None
+        }
+    }
+}
+
+/// Spawn a background task that reloads settings when one of `paths` is
+/// created, modified or removed, or the process receives SIGHUP.
+///
+/// # Arguments
+///
+/// * `settings` - Reloadable settings shared with the running services
+/// * `paths` - Configuration files to watch
+/// * `poll_interval` - Interval between modification time checks
+pub fn spawn_config_watcher(
+    settings: Arc<ReloadableSettings>,
+    paths: Vec<PathBuf>,
+    poll_interval: Duration,
+) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        #[cfg(unix)]
+        let mut hangup = match tokio::signal::unix::signal(tokio::signal::unix::SignalKind::hangup())
+        {
+            Ok(signal) => Some(signal),
+            Err(e) => {
+                warn!("Failed to listen for SIGHUP, relying on file watch: {}", e);
+                None
+            }
+        };
+
+        let mut last_modified = modified_at(&paths);
+        let mut interval = tokio::time::interval(poll_interval);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        info!(
+            "Watching {:?} for configuration changes every {:?}",
+            paths, poll_interval
+        );
+
+        loop {
+            #[cfg(unix)]
+            let hangup_received = async {
+                match hangup.as_mut() {
+                    Some(signal) => signal.recv().await,
+                    None => std::future::pending().await,
+                }
+            };
+            #[cfg(not(unix))]
+            let hangup_received = std::future::pending::<Option<()>>();
+
+            tokio::select! {
+                _ = interval.tick() => {
+                    let modified = modified_at(&paths);
+                    if modified == last_modified {
+                        continue;
+                    }
+                    last_modified = modified;
+                    reload_and_log(&settings, "file change");
+                }
+                _ = hangup_received => {
+                    last_modified = modified_at(&paths);
+                    reload_and_log(&settings, "SIGHUP");
+                }
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bootstrap::config::load_settings;
+
+    #[test]
+    fn test_reload_and_log_keeps_settings_on_failure() {
+        let settings = ReloadableSettings::new(load_settings().unwrap());
+        let before = settings.current();
+
+        assert!(reload_and_log(&settings, "test").is_none());
+        assert!(Arc::ptr_eq(&before, &settings.current()));
+    }
+
+    #[test]
+    fn test_reload_and_log_returns_report() {
+        let settings = ReloadableSettings::new(load_settings().unwrap()).with_loader(load_settings);
+
+        assert!(reload_and_log(&settings, "test").is_some());
+    }
+}
@@ -5,15 +5,26 @@
 
 //! Scraper engines initialization and configuration.
 
-use crate::config::engines::EngineSettings;
+use crate::config::engines::{EnginePreflightSettings, EngineSettings};
+use crate::config::reload::ReloadableSettings;
+use crate::engines::block_escalation::BlockEscalation;
 #[cfg(feature = "engine-flaresolverr")]
 use crate::engines::client::flare_solverr::FlareSolverrEngine;
 #[cfg(feature = "engine-playwright")]
 use crate::engines::client::playwright::PlaywrightEngine;
+#[cfg(feature = "engine-playwright")]
+use crate::engines::client::playwright_pool::{init_global_pool, BrowserPoolConfig};
 use crate::engines::client::reqwest::ReqwestEngine;
+use crate::engines::domain_overrides::DomainOverrides;
+use crate::engines::engine_client::AddressFamily;
 use crate::engines::engine_client::EngineClient;
 use crate::engines::engine_client::ScraperEngine;
+use crate::engines::engine_tier::EngineTiers;
+use crate::engines::health_monitor::{EngineHealthMonitor, PreflightResult};
+use crate::engines::proxy_pool::ProxyPool;
 use crate::engines::router::EngineRouter;
+#[cfg(feature = "engine-playwright")]
+use crate::infrastructure::services::config_service::BrowserConfigComponent;
 use std::sync::Arc;
 
 /// All engine-related components.
@@ -25,6 +36,20 @@ pub struct EngineComponents {
     pub router: Arc<EngineRouter>,
     /// Engine client for making requests.
     pub engine_client: Arc<EngineClient>,
+    /// Health monitor shared with the router; engines it marks unhealthy are skipped.
+    pub health_monitor: Arc<EngineHealthMonitor>,
+    /// Proxy pool rotated by the router, if any proxy is configured.
+    pub proxy_pool: Option<Arc<ProxyPool>>,
+}
+
+/// Parse the configured IP address family of the reqwest engine.
+///
+/// Unknown values fall back to IPv4 (the previous hard-wired behaviour) with a warning.
+fn reqwest_address_family(value: &str) -> AddressFamily {
+    value.parse().unwrap_or_else(|e| {
+        log::warn!("{} in engines.reqwest.address_family, using ipv4", e);
+        AddressFamily::Ipv4
+    })
 }
 
 /// Initialize all scraper engines.
@@ -56,15 +81,30 @@ pub fn init_engines(
     // None → 空字符串（ReqwestEngine 内部会将空字符串视为未配置代理）
     let proxy_url_str = proxy_url.unwrap_or("");
     #[allow(unused_mut)]
-    let mut engines: Vec<Arc<dyn ScraperEngine>> =
-        vec![Arc::new(ReqwestEngine::with_proxy_and_timeout(
+    let mut engines: Vec<Arc<dyn ScraperEngine>> = vec![Arc::new(
+        ReqwestEngine::with_proxy_and_timeout(
             http_client.clone(),
             proxy_url_str.to_string(),
             timeout_seconds,
-        ))];
+        )
+        .with_response_limit(
+            engine_config.reqwest.max_response_bytes,
+            engine_config.reqwest.truncate_oversized_responses,
+        )
+        .with_address_family(reqwest_address_family(
+            &engine_config.reqwest.address_family,
+        )),
+    )];
 
+    // 浏览器池在进程内共享，Playwright 引擎与智能搜索使用同一批预热实例
     #[cfg(feature = "engine-playwright")]
-    engines.push(Arc::new(PlaywrightEngine::new()));
+    {
+        init_global_pool(
+            BrowserPoolConfig::from_settings(&engine_config.playwright),
+            Arc::new(BrowserConfigComponent::default()),
+        );
+        engines.push(Arc::new(PlaywrightEngine::new()));
+    }
 
     #[cfg(feature = "engine-flaresolverr")]
     if engine_config.fire_tls.enabled {
@@ -85,11 +125,14 @@ pub fn init_engines(
             "Fire Engine CDP enabled with URL: {}",
             engine_config.fire_cdp.url
         );
-        engines.push(Arc::new(FlareSolverrEngine::with_cdp_mode_and_url(
-            http_client.clone(),
-            &engine_config.fire_cdp.url,
-            proxy_url,
-        )));
+        engines.push(Arc::new(
+            FlareSolverrEngine::with_cdp_mode_and_url(
+                http_client.clone(),
+                &engine_config.fire_cdp.url,
+                proxy_url,
+            )
+            .with_endpoints(&engine_config.fire_cdp.endpoints),
+        ));
     }
 
     #[cfg(feature = "engine-flaresolverr")]
@@ -129,6 +172,48 @@ pub fn init_engine_components(
     proxy_url: Option<String>,
     _engine_config: &EngineSettings,
     timeout_seconds: u64,
+) -> EngineComponents {
+    build_engine_components(
+        http_client,
+        proxy_url,
+        _engine_config,
+        timeout_seconds,
+        None,
+        None,
+    )
+}
+
+/// Initialize engine components whose routing follows hot-reloaded settings.
+///
+/// Engines are created from the settings in effect at startup; domain overrides,
+/// tier chains and engine toggles are read from `settings` on every route.
+/// The proxy pool is built from the startup `proxy` settings.
+pub fn init_reloadable_engine_components(
+    http_client: Arc<reqwest::Client>,
+    proxy_url: Option<String>,
+    settings: Arc<ReloadableSettings>,
+    timeout_seconds: u64,
+) -> EngineComponents {
+    let current = settings.current();
+    let engine_config = current.engines.clone();
+    let proxy_pool = ProxyPool::from_settings(&current.proxy).map(Arc::new);
+    build_engine_components(
+        http_client,
+        proxy_url,
+        &engine_config,
+        timeout_seconds,
+        proxy_pool,
+        Some(settings),
+    )
+}
+
+fn build_engine_components(
+    http_client: Arc<reqwest::Client>,
+    proxy_url: Option<String>,
+    _engine_config: &EngineSettings,
+    timeout_seconds: u64,
+    proxy_pool: Option<Arc<ProxyPool>>,
+    reloadable_settings: Option<Arc<ReloadableSettings>>,
 ) -> EngineComponents {
     let engines = init_engines(
         http_client,
@@ -136,16 +221,75 @@ pub fn init_engine_components(
         _engine_config,
         timeout_seconds,
     );
-    let router = Arc::new(EngineRouter::new(engines.clone()));
+    let health_monitor = Arc::new(EngineHealthMonitor::new(engines.clone()));
+    let mut router = EngineRouter::new(engines.clone());
+    router.set_health_monitor(health_monitor.clone());
+    router.set_domain_overrides(DomainOverrides::new(
+        _engine_config
+            .domain_overrides
+            .iter()
+            .map(|rule| (&rule.domain, &rule.engine)),
+    ));
+    router.set_engine_tiers(EngineTiers::from_settings(&_engine_config.tiers));
+    if _engine_config.block_escalation.enabled {
+        router.set_block_escalation(BlockEscalation::new(
+            _engine_config.block_escalation.max_escalations as usize,
+            proxy_url,
+        ));
+    }
+    if let Some(pool) = &proxy_pool {
+        router.set_proxy_pool(pool.clone());
+    }
+    if let Some(settings) = reloadable_settings {
+        router.set_reloadable_settings(settings);
+    }
+    let router = Arc::new(router);
     let engine_client = Arc::new(EngineClient::with_router(router.clone()));
 
     EngineComponents {
         engines,
         router,
         engine_client,
+        health_monitor,
+        proxy_pool,
     }
 }
 
+/// Validate every configured engine before the service accepts work.
+///
+/// Runs each engine's preflight check (browser launch and CDP handshake,
+/// remote service reachability) and, when `test_url` is configured, a test
+/// fetch through the engine. Engines that fail are marked unhealthy in the
+/// shared health monitor so the router never selects them.
+///
+/// # Returns
+///
+/// Returns the per-engine results, or an empty list when preflight is disabled.
+pub async fn run_engine_preflight(
+    components: &EngineComponents,
+    settings: &EnginePreflightSettings,
+) -> Vec<PreflightResult> {
+    if !settings.enabled {
+        log::info!("Engine preflight disabled");
+        return Vec::new();
+    }
+
+    let results = components
+        .health_monitor
+        .run_preflight(
+            settings.test_url.as_deref(),
+            std::time::Duration::from_secs(settings.timeout_secs.max(1)),
+        )
+        .await;
+    let passed = results.iter().filter(|result| result.passed).count();
+    if passed == 0 && !results.is_empty() {
+        log::error!("No engine passed preflight; scrapes will fail until an engine recovers");
+    } else {
+        log::info!("{}/{} engines passed preflight", passed, results.len());
+    }
+    results
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -209,6 +353,16 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_reqwest_address_family_falls_back_to_ipv4() {
+        assert_eq!(reqwest_address_family("ipv6"), AddressFamily::Ipv6);
+        assert_eq!(
+            reqwest_address_family("happy_eyeballs"),
+            AddressFamily::HappyEyeballs
+        );
+        assert_eq!(reqwest_address_family("bogus"), AddressFamily::Ipv4);
+    }
+
     #[test]
     fn test_init_engines_with_empty_proxy_url() {
         // Verify init_engines works with None proxy URL.
@@ -290,6 +444,27 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_run_engine_preflight_respects_enabled_flag() {
+        let engine_config = EngineSettings::default();
+        let components = init_engine_components(make_http_client(), None, &engine_config, 30);
+
+        let mut preflight = engine_config.preflight.clone();
+        preflight.enabled = false;
+        assert!(run_engine_preflight(&components, &preflight)
+            .await
+            .is_empty());
+
+        // 默认特性下只有 reqwest 引擎，本地引擎无需连通性检查
+        preflight.enabled = true;
+        preflight.timeout_secs = 5;
+        let results = run_engine_preflight(&components, &preflight).await;
+        assert!(results
+            .iter()
+            .any(|result| result.engine_name == "reqwest" && result.passed));
+        assert!(!components.health_monitor.is_unhealthy("reqwest"));
+    }
+
     #[test]
     fn test_init_engine_components_clone() {
         let http_client = make_http_client();
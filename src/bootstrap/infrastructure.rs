@@ -6,18 +6,34 @@
 //! Infrastructure initialization: database, HTTP client, and repositories.
 
 use crate::config::settings::Settings;
+use crate::domain::services::bandwidth_service::{BandwidthCounter, InMemoryBandwidthCounter};
+use crate::domain::services::chat_notification_service::ChatNotificationService;
+use crate::domain::services::email_notification_service::EmailNotificationService;
+use crate::domain::services::low_balance_alert_service::LowBalanceAlertService;
+use crate::domain::services::notification_service::{EventNotifier, NotificationService};
 use crate::infrastructure::database::dbnexus_connection::DatabasePool;
 use crate::infrastructure::dns::DnsCacheService;
 use crate::infrastructure::oxcache::{create_cache, CacheService, OxcacheService, SearchCache};
 use crate::infrastructure::repositories::{
     crawl_repo_impl::CrawlRepositoryImpl, credits_repo_impl::CreditsRepositoryImpl,
     database_geo_restriction_repo::DatabaseGeoRestrictionRepository,
+    notification_channel_repo_impl::NotificationChannelRepositoryImpl,
+    notification_contacts_repo_impl::NotificationContactsRepositoryImpl,
     scrape_result_repo_impl::ScrapeResultRepositoryImpl, task_repo_impl::TaskRepositoryImpl,
     tasks_backlog_repo_impl::TasksBacklogRepositoryImpl,
-    webhook_event_repo_impl::WebhookEventRepoImpl, webhook_repo_impl::WebhookRepoImpl,
+    team_plan_repo_impl::TeamPlanRepositoryImpl, webhook_event_repo_impl::WebhookEventRepoImpl,
+    webhook_repo_impl::WebhookRepoImpl,
 };
+use crate::infrastructure::services::bandwidth_counter::bandwidth_counter_from_settings;
+use crate::infrastructure::services::smtp_email_sender::build_email_sender;
+use crate::infrastructure::services::webhook_sender_impl::WebhookSenderImpl;
+use crate::queue::result_stream::{
+    result_stream_from_settings, InMemoryResultStreamBus, ResultStreamBus,
+};
+use crate::utils::content_compression::ContentCompression;
+use crate::utils::http_client::create_http_client;
 use anyhow::Result;
-use log::info;
+use log::{info, warn};
 use std::net::Ipv4Addr;
 use std::sync::Arc;
 use std::time::Duration;
@@ -41,6 +57,16 @@ pub struct Repositories {
     pub geo_restriction_repo: Arc<DatabaseGeoRestrictionRepository>,
     /// Tasks backlog repository for backlog processing.
     pub tasks_backlog_repo: Arc<TasksBacklogRepositoryImpl>,
+    /// Team plan repository for plan limits and monthly credit grants.
+    pub team_plan_repo: Arc<TeamPlanRepositoryImpl>,
+    /// Notification contacts repository for team email contacts.
+    pub notification_contacts_repo: Arc<NotificationContactsRepositoryImpl>,
+    /// Unified notification service for team chat channels and, when enabled, email contacts.
+    pub notifier: Arc<dyn EventNotifier>,
+    /// Bus that carries newly saved crawl results from workers to followers.
+    pub result_stream: Arc<dyn ResultStreamBus>,
+    /// Counters of bytes downloaded per team and task, read by `GET /v1/usage`.
+    pub bandwidth_counter: Arc<dyn BandwidthCounter>,
 }
 
 /// Initialize database connection pool.
@@ -168,13 +194,79 @@ pub fn init_repositories(db: Arc<DatabasePool>, settings: &Settings) -> Reposito
         db.inner().clone(),
         chrono::Duration::seconds(settings.concurrency.task_lock_duration_seconds),
     ));
-    let result_repo = Arc::new(ScrapeResultRepositoryImpl::new(db.inner().clone()));
+    let result_repo = ScrapeResultRepositoryImpl::new(db.inner().clone());
+    let result_repo = Arc::new(if settings.storage.compress_content {
+        result_repo.with_compression(ContentCompression::new(
+            settings.storage.compression_level,
+            settings.storage.compression_min_bytes,
+        ))
+    } else {
+        result_repo
+    });
     let crawl_repo = Arc::new(CrawlRepositoryImpl::new(db.inner().clone()));
     let webhook_event_repo = Arc::new(WebhookEventRepoImpl::new(db.inner().clone()));
     let webhook_repo = Arc::new(WebhookRepoImpl::new(db.inner().clone()));
-    let credits_repo = Arc::new(CreditsRepositoryImpl::new(db.inner().clone()));
+    let notification_contacts_repo =
+        Arc::new(NotificationContactsRepositoryImpl::new(db.inner().clone()));
+    // 统一事件通知：投递记录与 webhook 共用事件仓库；注册团队的聊天频道，开启邮件通知时
+    // 还有邮件联系人；邮件配置无效时只记录日志
+    let mut notifications = NotificationService::new(webhook_event_repo.clone()).register(
+        Arc::new(ChatNotificationService::new(
+            Arc::new(NotificationChannelRepositoryImpl::new(db.inner().clone())),
+            Arc::new(WebhookSenderImpl::with_default_config()),
+        )),
+    );
+    match build_email_sender(&settings.email) {
+        Ok(Some(sender)) => {
+            info!(
+                "Email notifications enabled via {}",
+                settings.email.resolved_smtp_host()
+            );
+            notifications = notifications.register(Arc::new(EmailNotificationService::new(
+                notification_contacts_repo.clone(),
+                sender,
+            )));
+        }
+        Ok(None) => {}
+        Err(e) => warn!("Email notifications disabled: {}", e),
+    }
+    let notifier: Arc<dyn EventNotifier> = Arc::new(notifications);
+    // 扣除积分后余额跌破团队阈值时推送 credits.low 事件，配置了邮件中继时同时发送邮件
+    let mut low_balance_alerts =
+        LowBalanceAlertService::new(webhook_repo.clone(), webhook_event_repo.clone());
+    if !settings.credit_alerts.email_relay_url.is_empty() {
+        low_balance_alerts = low_balance_alerts.with_email_relay(
+            create_http_client(),
+            settings.credit_alerts.email_relay_url.clone(),
+        );
+    }
+    low_balance_alerts = low_balance_alerts.with_notifier(notifier.clone());
+    let credits_repo = Arc::new(
+        CreditsRepositoryImpl::new(db.inner().clone()).with_low_balance_alerts(
+            Arc::new(low_balance_alerts),
+            settings.credit_alerts.rearm_margin_percent,
+        ),
+    );
     let geo_restriction_repo = Arc::new(DatabaseGeoRestrictionRepository::new(db.inner().clone()));
     let tasks_backlog_repo = Arc::new(TasksBacklogRepositoryImpl::new(db.inner().clone()));
+    let team_plan_repo = Arc::new(TeamPlanRepositoryImpl::new(db.inner().clone()));
+    // 结果推送总线：Redis 总线配置无效时退回进程内总线，只在 API 与 Worker 同进程时可用
+    let result_stream =
+        result_stream_from_settings(&settings.queue.result_stream).unwrap_or_else(|e| {
+            warn!("Result stream bus unavailable, using in-process bus: {}", e);
+            Arc::new(InMemoryResultStreamBus::new())
+        });
+    // 下载流量计数器：Redis 计数器配置无效时退回进程内计数器
+    let bandwidth_counter =
+        bandwidth_counter_from_settings(&settings.bandwidth).unwrap_or_else(|e| {
+            warn!(
+                "Bandwidth counter unavailable, using in-process counter: {}",
+                e
+            );
+            Arc::new(InMemoryBandwidthCounter::new(Duration::from_secs(
+                settings.bandwidth.task_ttl_seconds,
+            )))
+        });
 
     Repositories {
         task_repo,
@@ -185,6 +277,11 @@ pub fn init_repositories(db: Arc<DatabasePool>, settings: &Settings) -> Reposito
         credits_repo,
         geo_restriction_repo,
         tasks_backlog_repo,
+        team_plan_repo,
+        notification_contacts_repo,
+        notifier,
+        result_stream,
+        bandwidth_counter,
     }
 }
 
@@ -300,28 +397,28 @@ pub async fn init_infrastructure(settings: &Settings) -> Result<InfrastructureCo
 /// 创建 DNS cache service（如果 cache enabled）.
 ///
 /// 用于 Ipv4OnlyResolver 的 DNS 查询缓存，避免每次 HTTP 请求都走系统 DNS。
+/// 容量与 TTL 取自 `cache.types.dns`；创建后安装为进程内共享缓存，引擎按请求构建的
+/// client 与 SSRF 校验共用同一份缓存，重复调用时直接返回已安装的缓存。
 /// cache disabled 或创建失败时返回 None，resolver 会 fallback 到系统 DNS。
-async fn init_dns_cache_service(settings: &Settings) -> Option<Arc<DnsCacheService>> {
+pub async fn init_dns_cache_service(settings: &Settings) -> Option<Arc<DnsCacheService>> {
     if !settings.cache.enabled {
         info!("Cache disabled, DNS cache not initialized");
         return None;
     }
+    if let Some(cache) = crate::infrastructure::dns::shared_dns_cache() {
+        return Some(cache);
+    }
 
-    match crate::infrastructure::oxcache::create_dns_cache(
-        settings.cache.memory.capacity,
-        settings.cache.memory.ttl_seconds,
-    )
-    .await
-    {
+    let dns = &settings.cache.types.dns;
+    match crate::infrastructure::oxcache::create_dns_cache(dns.max_size, dns.ttl_seconds).await {
         Ok(cache) => {
             info!(
                 "DNS cache initialized for IPv4 resolver (capacity: {}, ttl: {}s)",
-                settings.cache.memory.capacity, settings.cache.memory.ttl_seconds
+                dns.max_size, dns.ttl_seconds
             );
-            Some(Arc::new(DnsCacheService::new(
-                cache,
-                settings.cache.memory.ttl_seconds,
-            )))
+            let service = Arc::new(DnsCacheService::new(cache, dns.ttl_seconds));
+            crate::infrastructure::dns::install_shared_dns_cache(service.clone());
+            Some(service)
         }
         Err(e) => {
             log::warn!("Failed to create DNS cache: {}. Using system DNS.", e);
@@ -12,12 +12,14 @@
 //!
 //! - `telemetry` - Telemetry and metrics initialization
 //! - `config` - Configuration loading and validation
+//! - `config_watcher` - Configuration hot reload on file change or SIGHUP
 //! - `infrastructure` - Database, cache (oxcache), repositories
 //! - `engines` - Scraper engines and router
 //! - `services` - Application services
 //! - `routes` - Route configuration and application builder
 
 pub mod config;
+pub mod config_watcher;
 pub mod engines;
 pub mod infrastructure;
 pub mod routes;
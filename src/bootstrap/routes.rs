@@ -7,12 +7,49 @@
 
 use crate::config::settings::Settings;
 use crate::di::{CrawlRsState, CrawlRsStateExt};
+use crate::domain::repositories::dead_letter_repository::DeadLetterRepository;
 use crate::domain::repositories::geo_restriction_repository::GeoRestrictionRepository;
+use crate::domain::repositories::monitor_repository::MonitorRepository;
+use crate::domain::repositories::notification_channel_repository::NotificationChannelRepository;
+use crate::domain::repositories::notification_contacts_repository::NotificationContactsRepository;
+use crate::domain::repositories::resource_tag_repository::ResourceTagRepository;
+use crate::domain::repositories::storage_repository::StorageRepository;
+use crate::domain::repositories::task_event_repository::TaskEventRepository;
+use crate::domain::repositories::team_capability_repository::TeamCapabilityRepository;
+use crate::domain::repositories::team_member_repository::TeamMemberRepository;
+use crate::domain::repositories::team_plan_repository::TeamPlanRepository;
+use crate::domain::repositories::url_blocklist_repository::UrlBlocklistRepository;
+use crate::domain::services::auth_scope_service::AuthScopeServiceTrait;
+use crate::domain::services::bandwidth_service::BandwidthService;
+use crate::domain::services::data_erasure_service::DataErasureService;
+use crate::domain::services::oidc_service::OidcService;
+use crate::domain::services::plan_service::PlanService;
+use crate::domain::services::pricing_service::PricingService;
+use crate::domain::services::team_membership_service::TeamMembershipService;
+use crate::domain::services::url_blocklist_service::UrlBlocklistService;
+use crate::infrastructure::database::repositories::bandwidth_usage_repo_impl::BandwidthUsageRepositoryImpl;
 use crate::infrastructure::database::repositories::database_geo_restriction_repo::DatabaseGeoRestrictionRepository;
+use crate::infrastructure::database::repositories::dead_letter_repo_impl::DeadLetterRepositoryImpl;
+use crate::infrastructure::database::repositories::monitor_repo_impl::MonitorRepositoryImpl;
+use crate::infrastructure::database::repositories::notification_channel_repo_impl::NotificationChannelRepositoryImpl;
+use crate::infrastructure::database::repositories::notification_contacts_repo_impl::NotificationContactsRepositoryImpl;
+use crate::infrastructure::database::repositories::resource_tag_repo_impl::ResourceTagRepositoryImpl;
+use crate::infrastructure::database::repositories::sso_session_repo_impl::SsoSessionRepositoryImpl;
+use crate::infrastructure::database::repositories::task_event_repo_impl::TaskEventRepositoryImpl;
+use crate::infrastructure::database::repositories::team_capability_repo_impl::TeamCapabilityRepositoryImpl;
+use crate::infrastructure::database::repositories::team_member_repo_impl::TeamMemberRepositoryImpl;
+use crate::infrastructure::database::repositories::team_plan_repo_impl::TeamPlanRepositoryImpl;
+use crate::infrastructure::database::repositories::team_repo_impl::TeamRepositoryImpl;
+use crate::infrastructure::database::repositories::url_blocklist_repo_impl::UrlBlocklistRepositoryImpl;
 use crate::infrastructure::database::repositories::webhook_repo_impl::WebhookRepoImpl;
+use crate::infrastructure::storage::LocalStorageRepository;
 use crate::presentation::handlers::{
-    audit_handler, crawl_handler, extract_handler, metrics_handler, scrape_handler, search_handler,
-    team_handler, webhook_handler,
+    api_key_handler, asset_handler, audit_handler, blocklist_handler, config_admin_handler,
+    crawl_handler, credits_handler, data_handler, dlq_handler, engine_admin_handler,
+    export_handler, extract_handler, feed_handler, health_handler, metrics_handler,
+    monitor_handler, notification_handler, robots_admin_handler, scrape_handler, search_handler,
+    sitemap_handler, sso_handler, tag_handler, team_handler, team_member_handler, usage_handler,
+    webhook_handler,
 };
 use crate::presentation::middleware::auth_middleware::AuthState;
 use crate::presentation::middleware::rate_limit_middleware::RateLimitMiddleware;
@@ -20,6 +57,7 @@ use crate::presentation::middleware::team_semaphore_middleware::team_semaphore_m
 use crate::presentation::routes;
 use crate::presentation::routes::task::task_routes;
 use crate::presentation::state::CrawlHandlerState;
+use crate::utils::http_client::create_http_client;
 use axum::{
     routing::{delete, get, post, put},
     Extension, Router,
@@ -96,6 +134,21 @@ pub fn create_public_routes(state: &CrawlRsState) -> Router {
         .with_state(Arc::new(state.clone()))
 }
 
+/// Create the readiness probe route (no authentication required).
+///
+/// `/health/ready` pings the database and the enabled engine endpoints and
+/// answers 503 when a critical dependency is down.
+pub fn create_readiness_routes(state: &CrawlRsState, settings: &Settings) -> Router {
+    let readiness_state = Arc::new(health_handler::ReadinessState::from_settings(
+        state.db_pool.clone(),
+        settings,
+    ));
+
+    Router::new()
+        .route("/health/ready", get(health_handler::ready_check))
+        .layer(Extension(readiness_state))
+}
+
 /// Create the protected API routes using CrawlRsState.
 ///
 /// # Arguments
@@ -127,10 +180,101 @@ pub fn create_protected_routes_with_state(state: &CrawlRsState, settings: Arc<Se
     let webhook_repo_impl: Arc<WebhookRepoImpl> =
         Arc::new(WebhookRepoImpl::new(state.db_pool.clone()));
 
+    // URL 黑名单：配置中的全局模式 + 数据库中的全局/团队条目
+    let url_blocklist_repo: Arc<dyn UrlBlocklistRepository> =
+        Arc::new(UrlBlocklistRepositoryImpl::new(state.db_pool.clone()));
+    let url_blocklist = Arc::new(
+        UrlBlocklistService::new(&settings.url_blocklist.patterns)
+            .with_repository(url_blocklist_repo.clone()),
+    );
+
+    // 页面变更监控
+    let monitor_repo: Arc<dyn MonitorRepository> =
+        Arc::new(MonitorRepositoryImpl::new(state.db_pool.clone()));
+
+    // 通知渠道：邮件联系人与聊天频道
+    let notification_contacts_repo: Arc<dyn NotificationContactsRepository> = Arc::new(
+        NotificationContactsRepositoryImpl::new(state.db_pool.clone()),
+    );
+    let notification_channel_repo: Arc<dyn NotificationChannelRepository> = Arc::new(
+        NotificationChannelRepositoryImpl::new(state.db_pool.clone()),
+    );
+
+    // 积分价格：配置中的全局价格表 + 按团队的覆盖价格
+    let pricing = Arc::new(PricingService::from_reloadable_settings(
+        state.reloadable_settings.clone(),
+    ));
+
+    // 团队能力（admin 授予，如 allow_ignore_robots）
+    let team_capability_repo: Arc<dyn TeamCapabilityRepository> =
+        Arc::new(TeamCapabilityRepositoryImpl::new(state.db_pool.clone()));
+
+    // 团队套餐：启用后爬取接口按套餐限制爬取深度
+    let team_plan_repo: Arc<dyn TeamPlanRepository> =
+        Arc::new(TeamPlanRepositoryImpl::new(state.db_pool.clone()));
+    let plan_service = settings.plans.enabled.then(|| {
+        Arc::new(PlanService::from_settings(
+            team_plan_repo.clone(),
+            &settings.plans,
+        ))
+    });
+
+    // 团队与成员管理（创建团队、邀请与移除成员）
+    let team_member_repo: Arc<dyn TeamMemberRepository> =
+        Arc::new(TeamMemberRepositoryImpl::new(state.db_pool.clone()));
+    let team_membership = Arc::new(TeamMembershipService::new(
+        Arc::new(TeamRepositoryImpl::new(state.db_pool.clone())),
+        team_member_repo.clone(),
+    ));
+
+    // OIDC 单点登录：按团队成员关系签发会话令牌
+    let oidc_service = settings.oidc.enabled.then(|| {
+        Arc::new(OidcService::new(
+            settings.oidc.clone(),
+            create_http_client(),
+            Arc::new(SsoSessionRepositoryImpl::new(state.db_pool.clone())),
+            team_member_repo,
+        ))
+    });
+
+    // 下载模式保存的二进制资源（图片、PDF、压缩包等）
+    let storage_repo: Arc<dyn StorageRepository> = Arc::new(LocalStorageRepository::new(
+        &settings.storage.local_path,
+        &settings.storage.public_base_url,
+    ));
+
+    // 数据删除：按 URL 模式或爬取删除结果及其存储对象
+    let data_erasure = Arc::new(DataErasureService::new(
+        result_repo.clone(),
+        crawl_repo.clone(),
+        storage_repo.clone(),
+    ));
+
+    // 死信队列：浏览重试耗尽的任务并批量重新排队（仅 Admin）
+    let dead_letter_repo: Arc<dyn DeadLetterRepository> =
+        Arc::new(DeadLetterRepositoryImpl::new(state.db_pool.clone()));
+
+    // 下载流量用量：计数器读数与每日汇总，按配置的每日上限计算剩余额度
+    let bandwidth_service = Arc::new(BandwidthService::from_settings(
+        state.bandwidth_counter.clone(),
+        Arc::new(BandwidthUsageRepositoryImpl::new(state.db_pool.clone())),
+        &settings.bandwidth,
+    ));
+
+    // 结果与爬取的标签、注释，供评审流程使用
+    let resource_tag_repo: Arc<dyn ResourceTagRepository> =
+        Arc::new(ResourceTagRepositoryImpl::new(state.db_pool.clone()));
+
     // Create Arc<CrawlRsState> for handlers that need unified state, and derive
     // CrawlHandlerState from it for crawl handlers (decoupled for testability).
     let app_state_arc = Arc::new(state.clone());
-    let crawl_handler_state = Arc::new(CrawlHandlerState::from_app_state(&app_state_arc));
+    let mut crawl_handler_state = CrawlHandlerState::from_app_state(&app_state_arc)
+        .with_url_blocklist(url_blocklist.clone())
+        .with_pricing_service(pricing.clone());
+    if let Some(plan_service) = &plan_service {
+        crawl_handler_state = crawl_handler_state.with_plan_service(plan_service.clone());
+    }
+    let crawl_handler_state = Arc::new(crawl_handler_state);
 
     // Auth state for middleware - wrap in Arc and set global state
     let auth_scope_service = state.auth_scope_service.as_ref().map(|arc| (**arc).clone());
@@ -144,6 +288,9 @@ pub fn create_protected_routes_with_state(state: &CrawlRsState, settings: Arc<Se
     let app: Router = Router::new()
         .route("/v1/scrape", post(scrape_handler::create_scrape))
         .route("/v1/scrape/{id}", get(scrape_handler::get_scrape_status))
+        .route("/v1/feeds", post(feed_handler::create_feed))
+        .route("/v1/feeds/{id}", get(feed_handler::get_feed))
+        .route("/v1/sitemap", post(sitemap_handler::parse_sitemap))
         .route(
             "/v1/extract",
             post(extract_handler::extract::<DatabaseGeoRestrictionRepository>),
@@ -156,14 +303,66 @@ pub fn create_protected_routes_with_state(state: &CrawlRsState, settings: Arc<Se
             "/v1/webhooks",
             get(webhook_handler::list_webhooks::<WebhookRepoImpl>),
         )
+        .route(
+            "/v1/webhooks/{id}/test",
+            post(webhook_handler::test_webhook::<WebhookRepoImpl>),
+        )
         .route("/v1/crawl", post(crawl_handler::create_crawl))
         .route("/v1/crawl/{id}", get(crawl_handler::get_crawl))
         .route(
             "/v1/crawl/{id}/results",
             get(crawl_handler::get_crawl_results),
         )
+        .route(
+            "/v1/crawl/{id}/results/search",
+            get(crawl_handler::search_crawl_results),
+        )
+        .route("/v1/crawl/{id}/graph", get(crawl_handler::get_crawl_graph))
+        .route(
+            "/v1/crawl/{id}/seo",
+            get(crawl_handler::get_crawl_seo_report),
+        )
+        .route(
+            "/v1/crawl/{id}/tags",
+            get(tag_handler::get_crawl_tags).post(tag_handler::add_crawl_tags),
+        )
+        .route(
+            "/v1/crawl/{id}/tags/{tag}",
+            delete(tag_handler::remove_crawl_tag),
+        )
+        .route(
+            "/v1/results/{id}/tags",
+            get(tag_handler::get_result_tags).post(tag_handler::add_result_tags),
+        )
+        .route(
+            "/v1/results/{id}/tags/{tag}",
+            delete(tag_handler::remove_result_tag),
+        )
         .route("/v1/crawl/{id}", delete(crawl_handler::cancel_crawl))
+        .route(
+            "/v1/crawl/{id}/data",
+            delete(data_handler::erase_crawl_data),
+        )
+        .route("/v1/compare", get(crawl_handler::compare_crawl_pages))
+        .route("/v1/assets/{*key}", get(asset_handler::get_asset))
         .route("/v1/search", post(search_handler::search))
+        .route("/v1/estimate", post(credits_handler::estimate))
+        .route("/v1/credits", get(credits_handler::get_balance))
+        .route(
+            "/v1/credits/transactions",
+            get(credits_handler::list_transactions),
+        )
+        .route(
+            "/v1/credits/usage/daily",
+            get(credits_handler::get_daily_usage),
+        )
+        .route(
+            "/v1/credits/alert",
+            get(credits_handler::get_low_balance_alert)
+                .put(credits_handler::set_low_balance_alert)
+                .delete(credits_handler::delete_low_balance_alert),
+        )
+        .route("/v1/usage", get(usage_handler::get_usage))
         .route("/v1/teams/me", get(team_handler::get_team_info))
         .route("/v1/teams/me/usage", get(team_handler::get_team_usage))
         .route(
@@ -174,8 +373,104 @@ pub fn create_protected_routes_with_state(state: &CrawlRsState, settings: Arc<Se
             "/v1/teams/geo-restrictions",
             put(team_handler::update_team_geo_restrictions::<DatabaseGeoRestrictionRepository>),
         )
+        .route(
+            "/v1/teams/{id}/capabilities",
+            get(team_handler::get_team_capabilities).put(team_handler::update_team_capabilities),
+        )
+        .route(
+            "/v1/teams/{id}/plan",
+            get(team_handler::get_team_plan).put(team_handler::update_team_plan),
+        )
+        .route("/v1/teams", post(team_member_handler::create_team))
+        .route(
+            "/v1/teams/{id}/members",
+            get(team_member_handler::list_team_members)
+                .post(team_member_handler::invite_team_member),
+        )
+        .route(
+            "/v1/teams/{id}/members/{user_id}",
+            delete(team_member_handler::remove_team_member),
+        )
+        .route(
+            "/v1/invitations/accept",
+            post(team_member_handler::accept_invitation),
+        )
+        .route("/v1/auth/oidc/login", get(sso_handler::oidc_login))
+        .route("/v1/auth/oidc/callback", get(sso_handler::oidc_callback))
+        .route("/v1/auth/logout", post(sso_handler::logout))
+        .route(
+            "/v1/keys/{id}/role",
+            get(api_key_handler::get_api_key_role).put(api_key_handler::update_api_key_role),
+        )
+        .route("/v1/exports", post(export_handler::create_export))
+        .route("/v1/exports/{id}", get(export_handler::get_export))
+        .route("/v1/data", delete(data_handler::erase_data))
         .route("/v1/audit/logs", get(audit_handler::get_audit_logs))
         .route("/v1/audit/denied", get(audit_handler::get_denied_requests))
+        .route(
+            "/v1/blocklist",
+            get(blocklist_handler::list_blocklist_entries)
+                .post(blocklist_handler::create_blocklist_entry),
+        )
+        .route(
+            "/v1/blocklist/{id}",
+            delete(blocklist_handler::delete_blocklist_entry),
+        )
+        .route(
+            "/v1/monitors",
+            get(monitor_handler::list_monitors).post(monitor_handler::create_monitor),
+        )
+        .route(
+            "/v1/monitors/{id}",
+            get(monitor_handler::get_monitor)
+                .put(monitor_handler::update_monitor)
+                .delete(monitor_handler::delete_monitor),
+        )
+        .route(
+            "/v1/notifications/email",
+            get(notification_handler::get_email_contacts)
+                .put(notification_handler::put_email_contacts)
+                .delete(notification_handler::delete_email_contacts),
+        )
+        .route(
+            "/v1/notifications/channels",
+            get(notification_handler::list_channels).post(notification_handler::create_channel),
+        )
+        .route(
+            "/v1/notifications/channels/{id}",
+            get(notification_handler::get_channel)
+                .put(notification_handler::update_channel)
+                .delete(notification_handler::delete_channel),
+        )
+        .route(
+            "/v1/notifications/deliveries",
+            get(notification_handler::list_deliveries),
+        )
+        .route(
+            "/admin/v1/engines/circuit-breakers",
+            get(engine_admin_handler::list_circuit_breakers),
+        )
+        .route(
+            "/admin/v1/engines/circuit-breakers/{engine}/reset",
+            post(engine_admin_handler::reset_circuit_breaker),
+        )
+        .route(
+            "/admin/v1/robots/{host}",
+            get(robots_admin_handler::get_robots_cache),
+        )
+        .route(
+            "/admin/v1/robots/{host}/refresh",
+            post(robots_admin_handler::refresh_robots_cache),
+        )
+        .route(
+            "/admin/v1/config/reload",
+            post(config_admin_handler::reload_config),
+        )
+        .route("/admin/v1/dlq", get(dlq_handler::list_dead_letters))
+        .route(
+            "/admin/v1/dlq/requeue",
+            post(dlq_handler::requeue_dead_letters),
+        )
         .layer(axum::middleware::from_fn(
             crate::presentation::middleware::auth_middleware::auth_middleware(),
         ))
@@ -191,6 +486,7 @@ pub fn create_protected_routes_with_state(state: &CrawlRsState, settings: Arc<Se
         .layer(Extension(crawl_repo))
         .layer(Extension(webhook_repo))
         .layer(Extension(webhook_event_repo))
+        .layer(Extension(state.webhook_service.clone()))
         .layer(Extension(search_engine_service))
         .layer(Extension(state.search_service.clone()))
         .layer(Extension(team_service))
@@ -198,9 +494,37 @@ pub fn create_protected_routes_with_state(state: &CrawlRsState, settings: Arc<Se
         .layer(Extension(crawl_handler_state)) // CrawlHandlerState for crawl handlers
         .layer(Extension(credits_repo))
         .layer(Extension(webhook_repo_impl))
-        .layer(Extension(geo_restriction_repo_impl));
-
-    app
+        .layer(Extension(geo_restriction_repo_impl))
+        .layer(Extension(url_blocklist))
+        .layer(Extension(pricing))
+        .layer(Extension(url_blocklist_repo))
+        .layer(Extension(team_capability_repo))
+        .layer(Extension(team_plan_repo))
+        .layer(Extension(team_membership))
+        .layer(Extension(storage_repo))
+        .layer(Extension(data_erasure))
+        .layer(Extension(dead_letter_repo))
+        .layer(Extension(resource_tag_repo))
+        .layer(Extension(bandwidth_service))
+        .layer(Extension(monitor_repo))
+        .layer(Extension(notification_contacts_repo))
+        .layer(Extension(notification_channel_repo))
+        .layer(Extension(state.engine_router.clone()))
+        .layer(Extension(state.robots_checker.clone()))
+        .layer(Extension(state.reloadable_settings.clone()));
+
+    let app = match plan_service {
+        Some(plan_service) => app.layer(Extension(plan_service)),
+        None => app,
+    };
+    let app = match oidc_service {
+        Some(oidc_service) => app.layer(Extension(oidc_service)),
+        None => app,
+    };
+    match state.auth_scope_service.clone() {
+        Some(scopes) => app.layer(Extension(scopes as Arc<dyn AuthScopeServiceTrait>)),
+        None => app,
+    }
 }
 
 /// Create v2 task routes using CrawlRsState.
@@ -215,6 +539,24 @@ pub fn create_v2_routes_with_state(state: &CrawlRsState) -> Router {
     let webhook_repo = state.webhook_repo.clone();
     let webhook_event_repo = state.webhook_event_repo();
     let team_semaphore = state.team_semaphore.clone();
+    let task_event_repo: Arc<dyn TaskEventRepository> =
+        Arc::new(TaskEventRepositoryImpl::new(state.db_pool.clone()));
+
+    // 抓取回放直接调用引擎，需要与 /v1/scrape 相同的黑名单、下载上限与额度检查
+    let settings = state.reloadable_settings.current();
+    let url_blocklist = Arc::new(
+        UrlBlocklistService::new(&settings.url_blocklist.patterns).with_repository(Arc::new(
+            UrlBlocklistRepositoryImpl::new(state.db_pool.clone()),
+        )),
+    );
+    let bandwidth_service = Arc::new(BandwidthService::from_settings(
+        state.bandwidth_counter.clone(),
+        Arc::new(BandwidthUsageRepositoryImpl::new(state.db_pool.clone())),
+        &settings.bandwidth,
+    ));
+    let pricing = Arc::new(PricingService::from_reloadable_settings(
+        state.reloadable_settings.clone(),
+    ));
 
     // Use new_for_middleware to ensure global cache is initialized
     let auth_state = Arc::new(AuthState::new_for_middleware(state.db_pool.clone(), None));
@@ -235,6 +577,12 @@ pub fn create_v2_routes_with_state(state: &CrawlRsState) -> Router {
         .layer(Extension(crawl_repo.clone()))
         .layer(Extension(webhook_repo.clone()))
         .layer(Extension(webhook_event_repo.clone()))
+        .layer(Extension(task_event_repo))
+        .layer(Extension(state.engine_router.clone()))
+        .layer(Extension(url_blocklist))
+        .layer(Extension(bandwidth_service))
+        .layer(Extension(pricing))
+        .layer(Extension(state.rate_limiting_service.clone()))
 }
 
 /// Build the complete API application router using CrawlRsState.
@@ -249,6 +597,7 @@ pub fn create_v2_routes_with_state(state: &CrawlRsState) -> Router {
 /// Returns the configured API router.
 pub fn build_api_app_with_state(state: &CrawlRsState, settings: Arc<Settings>) -> Router {
     let public_routes = create_public_routes(state);
+    let readiness_routes = create_readiness_routes(state, &settings);
     let protected_routes = create_protected_routes_with_state(state, settings.clone());
     let v2_routes = create_v2_routes_with_state(state);
 
@@ -268,6 +617,7 @@ pub fn build_api_app_with_state(state: &CrawlRsState, settings: Arc<Settings>) -
 
     let app = Router::new()
         .merge(public_routes)
+        .merge(readiness_routes)
         .merge(protected_routes)
         .merge(v2_routes);
 
@@ -284,6 +634,19 @@ pub fn build_api_app_with_state(state: &CrawlRsState, settings: Arc<Settings>) -
             .layer(Extension(state.crawl_repo.clone())),
     );
 
+    // GraphQL 查询接口：与 REST 共用认证中间件，查询按认证团队隔离
+    #[cfg(feature = "graphql")]
+    let app = app.merge(
+        crate::presentation::graphql::graphql_router(crate::presentation::graphql::build_schema(
+            state.crawl_repo.clone(),
+            state.task_repo.clone(),
+            state.result_repo.clone(),
+        ))
+        .layer(axum::middleware::from_fn(
+            crate::presentation::middleware::auth_middleware::auth_middleware(),
+        )),
+    );
+
     app.layer(cors_layer)
         // Security headers middleware - should be applied early in the middleware chain
         .layer(axum::middleware::from_fn(
@@ -329,7 +692,7 @@ mod tests {
     use crate::common::test_support::testcontainers_fixtures as tcf;
     use crate::di::modules::{
         CacheModule, DatabaseModule, EngineModule, HttpModule, InfrastructureModule,
-        RepositoryModule, ServiceModule, SettingsModule,
+        ReloadableSettingsModule, RepositoryModule, ServiceModule, SettingsModule,
     };
     use trait_kit::AsyncKit;
 
@@ -1104,6 +1467,8 @@ mod tests {
             .map_err(|e| anyhow::anyhow!("register CacheModule: {e}"))?;
         kit.register::<RepositoryModule>()
             .map_err(|e| anyhow::anyhow!("register RepositoryModule: {e}"))?;
+        kit.register::<ReloadableSettingsModule>()
+            .map_err(|e| anyhow::anyhow!("register ReloadableSettingsModule: {e}"))?;
         kit.register::<EngineModule>()
             .map_err(|e| anyhow::anyhow!("register EngineModule: {e}"))?;
         kit.register::<InfrastructureModule>()
@@ -11,28 +11,43 @@ use std::sync::Arc;
 use crate::application::use_cases::create_scrape::{CreateScrapeUseCase, CreateScrapeUseCaseTrait};
 use crate::bootstrap::infrastructure::InfrastructureComponents;
 use crate::bootstrap::infrastructure::Repositories;
+use crate::config::reload::ReloadableSettings;
 use crate::config::settings::Settings;
 use crate::domain::services::audit_service::{AuditService, AuditServiceTrait};
 use crate::domain::services::auth_scope_service::AuthScopeService;
 use crate::domain::services::extraction_service::{ExtractionService, ExtractionServiceTrait};
 use crate::domain::services::geo_location::GeoLocationService;
 use crate::domain::services::llm_service::{LLMService, LLMServiceTrait};
+use crate::domain::services::plan_service::PlanService;
+use crate::domain::services::pricing_service::PricingService;
 use crate::domain::services::rate_limiting_service::{
-    ConcurrencyConfig, ConcurrencyStrategy, RateLimitConfig, RateLimitStrategy, RateLimitingService,
+    ConcurrencyConfig, ConcurrencyStrategy, EndpointRateLimit, RateLimitConfig, RateLimitStrategy,
+    RateLimitingService,
 };
 use crate::domain::services::search_service::{SearchService, SearchServiceTrait};
 use crate::domain::services::team_service::TeamService;
-use crate::domain::services::webhook_service::{WebhookService, WebhookServiceImpl};
+use crate::domain::services::url_blocklist_service::UrlBlocklistService;
+use crate::domain::services::webhook_service::{
+    WebhookManagementServiceImpl, WebhookService, WebhookServiceImpl,
+};
 use crate::engines::engine_client::EngineClient;
 use crate::engines::router::EngineRouter;
 use crate::infrastructure::database::repositories::audit_log_repo_impl::AuditLogRepositoryImpl;
 use crate::infrastructure::database::repositories::auth_scope_repo_impl::AuthScopeRepositoryImpl;
+use crate::infrastructure::database::repositories::monitor_repo_impl::MonitorRepositoryImpl;
+use crate::infrastructure::database::repositories::task_event_repo_impl::TaskEventRepositoryImpl;
+use crate::infrastructure::database::repositories::url_blocklist_repo_impl::UrlBlocklistRepositoryImpl;
 use crate::infrastructure::geolocation::GeoLocationServiceImpl;
 use crate::infrastructure::services::limiteron_service::{LimiteronService, RateLimitingConfig};
 use crate::infrastructure::services::webhook_sender_impl::WebhookSenderImpl;
 use crate::presentation::middleware::auth_middleware::AuthRateLimiter;
 use crate::presentation::middleware::rate_limit_middleware::RateLimitMiddleware;
 use crate::presentation::middleware::team_semaphore::TeamSemaphore;
+#[cfg(feature = "queue-kafka")]
+use crate::queue::kafka_queue::KafkaTaskQueue;
+use crate::queue::priority_scheduler::PriorityScheduler;
+#[cfg(feature = "queue-rabbitmq")]
+use crate::queue::rabbitmq_queue::RabbitMqTaskQueue;
 use crate::queue::task_queue::{PostgresTaskQueue, TaskQueue};
 use crate::search::ab_test::SearchABTestEngine;
 use crate::search::aggregator::SearchAggregator;
@@ -87,6 +102,10 @@ pub struct ServicesComponents {
     pub backlog_worker: Arc<crate::workers::backlog_worker::BacklogWorker>,
     /// Expiration worker
     pub expiration_worker: Arc<crate::workers::expiration_worker::ExpirationWorker>,
+    /// Stalled task reaper
+    pub stalled_task_reaper: Arc<crate::workers::stalled_task_reaper::StalledTaskReaper>,
+    /// Monitor worker
+    pub monitor_worker: Arc<crate::workers::monitor_worker::MonitorWorker>,
 }
 
 /// Initialize rate limit middleware.
@@ -123,6 +142,8 @@ pub fn init_team_semaphore(default_team_limit: u64) -> Arc<TeamSemaphore> {
 ///
 /// * `repositories` - Application repositories
 /// * `settings` - Application settings
+/// * `reloadable_settings` - Hot-reloadable settings; when set, the global rate limit
+///   follows `[rate_limiting]` reloads
 ///
 /// # Returns
 ///
@@ -130,13 +151,23 @@ pub fn init_team_semaphore(default_team_limit: u64) -> Arc<TeamSemaphore> {
 pub async fn init_rate_limiting_service(
     repositories: &Repositories,
     settings: &Settings,
+    reloadable_settings: Option<Arc<ReloadableSettings>>,
 ) -> Arc<dyn RateLimitingService> {
+    let strategy = settings
+        .rate_limiting
+        .strategy
+        .parse::<RateLimitStrategy>()
+        .unwrap_or_else(|e| {
+            log::error!("{}, falling back to token bucket", e);
+            RateLimitStrategy::TokenBucket
+        });
+
     let rate_limit_config = RateLimitConfig {
-        strategy: RateLimitStrategy::TokenBucket,
+        strategy,
         requests_per_second: settings.rate_limiting.default_rpm / 60,
         requests_per_minute: settings.rate_limiting.default_rpm,
         requests_per_hour: settings.rate_limiting.default_rpm * 60,
-        bucket_capacity: Some(settings.rate_limiting.default_rpm),
+        bucket_capacity: Some(settings.rate_limiting.burst_size),
         enabled: settings.rate_limiting.enabled,
     };
 
@@ -163,6 +194,16 @@ pub async fn init_rate_limiting_service(
         concurrency: concurrency_config,
         backlog_process_interval_seconds: 30,
         rate_limit_ttl_seconds: 3600,
+        endpoint_limits: settings
+            .rate_limiting
+            .endpoints
+            .iter()
+            .map(|endpoint| EndpointRateLimit {
+                path: endpoint.path.clone(),
+                method: endpoint.method.clone(),
+                requests_per_minute: endpoint.requests_per_minute,
+            })
+            .collect(),
     };
 
     let service = LimiteronService::new(
@@ -174,6 +215,20 @@ pub async fn init_rate_limiting_service(
     .await
     .expect("Failed to create LimiteronService");
 
+    // 启用团队套餐时，未单独配置限流的团队按套餐的每分钟请求数与并发上限执行
+    let service = if settings.plans.enabled {
+        service.with_plan_service(Arc::new(PlanService::from_settings(
+            repositories.team_plan_repo.clone(),
+            &settings.plans,
+        )))
+    } else {
+        service
+    };
+    let service = match reloadable_settings {
+        Some(reloadable_settings) => service.with_reloadable_settings(reloadable_settings),
+        None => service,
+    };
+
     Arc::new(service)
 }
 
@@ -302,6 +357,59 @@ pub fn init_regex_cache() -> Arc<RegexCache> {
     Arc::new(RegexCache::new(Arc::new(cache)))
 }
 
+/// Initialize task queue.
+///
+/// Selects the backend configured in `[queue]`. Every backend keeps task state
+/// in the database, so `postgres_queue` is always used for persistence.
+/// When `[queue.priority]` is enabled, database dequeues use weighted priority lanes.
+///
+/// # Arguments
+///
+/// * `settings` - Application settings
+/// * `postgres_queue` - Database-backed queue
+///
+/// # Returns
+///
+/// Returns the task queue as trait object.
+pub async fn init_task_queue(
+    settings: &Settings,
+    postgres_queue: PostgresTaskQueue,
+) -> Arc<dyn TaskQueue> {
+    let postgres_queue = if settings.queue.priority.enabled {
+        info!(
+            "Using weighted priority scheduling, weights: {}:{}:{}",
+            settings.queue.priority.high_weight,
+            settings.queue.priority.normal_weight,
+            settings.queue.priority.low_weight
+        );
+        postgres_queue.with_scheduler(PriorityScheduler::from_settings(&settings.queue.priority))
+    } else {
+        postgres_queue
+    };
+
+    #[cfg(feature = "queue-kafka")]
+    if settings.queue.is_kafka() {
+        info!(
+            "Using Kafka task queue, brokers: {}",
+            settings.queue.kafka.brokers
+        );
+        return Arc::new(
+            KafkaTaskQueue::new(postgres_queue, &settings.queue.kafka)
+                .expect("Failed to create Kafka task queue"),
+        );
+    }
+    #[cfg(feature = "queue-rabbitmq")]
+    if settings.queue.is_rabbitmq() {
+        info!("Using RabbitMQ task queue");
+        return Arc::new(
+            RabbitMqTaskQueue::connect(postgres_queue, &settings.queue.rabbitmq)
+                .await
+                .expect("Failed to connect RabbitMQ task queue"),
+        );
+    }
+    Arc::new(postgres_queue)
+}
+
 /// Initialize all application services.
 ///
 /// # Arguments
@@ -310,6 +418,7 @@ pub fn init_regex_cache() -> Arc<RegexCache> {
 /// * `engine_router` - Engine router for creating use cases
 /// * `engine_client` - Engine client for scraping operations
 /// * `settings` - Application settings
+/// * `reloadable_settings` - Hot-reloadable settings shared with the engine router
 ///
 /// # Returns
 ///
@@ -320,6 +429,7 @@ pub async fn init_services(
     engine_client: Arc<EngineClient>,
     http_client: Arc<reqwest::Client>,
     settings: &Settings,
+    reloadable_settings: Option<Arc<ReloadableSettings>>,
 ) -> ServicesComponents {
     let repositories = &infrastructure.repositories;
 
@@ -330,7 +440,8 @@ pub async fn init_services(
     let team_semaphore = init_team_semaphore(settings.concurrency.default_team_limit as u64);
 
     // Initialize rate limiting service
-    let rate_limiting_service = init_rate_limiting_service(repositories, settings).await;
+    let rate_limiting_service =
+        init_rate_limiting_service(repositories, settings, reloadable_settings).await;
 
     // Initialize rate limit middleware
     let rate_limit_middleware = init_rate_limit_middleware(rate_limiting_service.clone());
@@ -344,11 +455,14 @@ pub async fn init_services(
         http_client.clone(),
         std::time::Duration::from_secs(10),
     ));
-    let webhook_service: Arc<WebhookServiceImpl> = Arc::new(WebhookServiceImpl::new(
-        webhook_sender.clone(),
-        settings.webhook.secret().to_string(),
-        repositories.webhook_event_repo.clone(),
-    ));
+    let webhook_service: Arc<WebhookServiceImpl> = Arc::new(
+        WebhookServiceImpl::new(
+            webhook_sender.clone(),
+            settings.webhook.secret().to_string(),
+            repositories.webhook_event_repo.clone(),
+        )
+        .with_webhook_repository(repositories.webhook_repo.clone()),
+    );
 
     // Initialize GeoLocationService
     let geo_location_service = Arc::new(GeoLocationServiceImpl::new(http_client.clone()));
@@ -360,11 +474,17 @@ pub async fn init_services(
     ));
 
     // Initialize robots checker (使用依赖注入的 HTTP_CLIENT + CacheService)
-    let robots_checker = Arc::new(RobotsChecker::new(
-        http_client.clone(),
-        Some(infrastructure.cache_service.clone()),
-        None,
-    ));
+    let robots_checker = Arc::new(
+        RobotsChecker::new(
+            http_client.clone(),
+            Some(infrastructure.cache_service.clone()),
+            None,
+        )
+        .with_ttl(
+            std::time::Duration::from_secs(settings.robots.ttl_seconds),
+            std::time::Duration::from_secs(settings.robots.error_ttl_seconds),
+        ),
+    );
 
     // Initialize search engine (for backward compatibility)
     let search_engine_service: Arc<dyn SearchEngine> = init_search_engine(
@@ -385,8 +505,16 @@ pub async fn init_services(
     let auth_scope_service = Some(init_auth_scope_service(infrastructure.db.inner().clone()));
 
     // Initialize task queue
-    let queue: Arc<dyn TaskQueue> =
-        Arc::new(PostgresTaskQueue::new(repositories.task_repo.clone()));
+    // 队列写入入队、加锁与结束事件，用于组装任务执行时间线
+    let task_event_repo = Arc::new(TaskEventRepositoryImpl::new(
+        infrastructure.db.inner().clone(),
+    ));
+    let queue = init_task_queue(
+        settings,
+        PostgresTaskQueue::new(repositories.task_repo.clone())
+            .with_event_repository(task_event_repo.clone()),
+    )
+    .await;
 
     // Initialize audit service
     let audit_repo = Arc::new(AuditLogRepositoryImpl::new(
@@ -404,11 +532,15 @@ pub async fn init_services(
     let regex_cache = init_regex_cache();
 
     // Initialize WebhookWorker
-    let webhook_worker = Arc::new(crate::workers::webhook_worker::WebhookWorker::new(
-        repositories.webhook_event_repo.clone(),
-        webhook_service.clone(),
-        crate::utils::retry_policy::RetryPolicy::default(),
-    ));
+    // 投递最终失败的事件同时向团队的邮件联系人与聊天频道发送 webhook.delivery_failed 通知
+    let webhook_worker = Arc::new(
+        crate::workers::webhook_worker::WebhookWorker::new(
+            repositories.webhook_event_repo.clone(),
+            webhook_service.clone(),
+            crate::utils::retry_policy::RetryPolicy::default(),
+        )
+        .with_notifier(repositories.notifier.clone()),
+    );
 
     // Initialize BacklogWorker
     let backlog_worker = Arc::new(crate::workers::backlog_worker::BacklogWorker::new(
@@ -423,6 +555,44 @@ pub async fn init_services(
         repositories.task_repo.clone(),
     ));
 
+    // Initialize StalledTaskReaper
+    let stalled_task_reaper = Arc::new(
+        crate::workers::stalled_task_reaper::StalledTaskReaper::new(repositories.task_repo.clone())
+            .with_event_repository(task_event_repo),
+    );
+
+    // Initialize MonitorWorker
+    // 页面变更监控：检查到变化时推送 monitor.changed 事件，每次成功检查按抓取价格扣费；
+    // URL 被拦截、robots.txt 禁止或余额不足时暂停监控
+    let monitor_worker = Arc::new(
+        crate::workers::monitor_worker::MonitorWorker::new(
+            Arc::new(MonitorRepositoryImpl::new(
+                infrastructure.db.inner().clone(),
+            )),
+            engine_client.clone(),
+            extraction_service.clone(),
+            std::time::Duration::from_secs(settings.timeouts.engines.default_timeout_seconds),
+        )
+        .with_webhook_management_service(Arc::new(
+            WebhookManagementServiceImpl::new(
+                repositories.webhook_repo.clone(),
+                repositories.webhook_event_repo.clone(),
+                webhook_service.clone(),
+            )
+            .with_notifier(repositories.notifier.clone()),
+        ))
+        .with_credits_repository(
+            repositories.credits_repo.clone(),
+            PricingService::from_settings(&settings.pricing),
+        )
+        .with_url_blocklist(
+            UrlBlocklistService::new(&settings.url_blocklist.patterns).with_repository(Arc::new(
+                UrlBlocklistRepositoryImpl::new(infrastructure.db.inner().clone()),
+            )),
+        )
+        .with_robots_checker(robots_checker.clone()),
+    );
+
     info!("Services initialized");
 
     ServicesComponents {
@@ -447,6 +617,8 @@ pub async fn init_services(
         webhook_worker,
         backlog_worker,
         expiration_worker,
+        stalled_task_reaper,
+        monitor_worker,
     }
 }
 
@@ -738,7 +910,7 @@ mod tests {
         };
         let repos = init_repositories(db.clone(), &settings);
 
-        let service = init_rate_limiting_service(&repos, &settings).await;
+        let service = init_rate_limiting_service(&repos, &settings, None).await;
         // Verify the service is usable (Arc strong count >= 1).
         assert!(Arc::strong_count(&service) >= 1);
     }
@@ -844,6 +1016,7 @@ mod tests {
             engine_client,
             infra.http_client.clone(),
             &settings,
+            None,
         )
         .await;
 
@@ -866,5 +1039,7 @@ mod tests {
         assert!(Arc::strong_count(&services.webhook_worker) >= 1);
         assert!(Arc::strong_count(&services.backlog_worker) >= 1);
         assert!(Arc::strong_count(&services.expiration_worker) >= 1);
+        assert!(Arc::strong_count(&services.stalled_task_reaper) >= 1);
+        assert!(Arc::strong_count(&services.monitor_worker) >= 1);
     }
 }
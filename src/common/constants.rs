@@ -87,6 +87,9 @@ pub mod server_config {
 
     /// CORS 缓存时间（秒）
     pub const CORS_MAX_AGE_SECS: u64 = 86400; // 24小时
+
+    /// 就绪检查中单个依赖的探测超时（秒）
+    pub const READINESS_CHECK_TIMEOUT_SECS: u64 = 3;
 }
 
 /// 爬虫任务常量 - 避免handler中的硬编码值
@@ -102,6 +105,15 @@ pub mod crawl_task {
 
     /// 最大轮询次数（防止过多数据库查询）
     pub const MAX_POLL_COUNT: u32 = 60;
+
+    /// 表示不背书链接的 rel 取值（开启 skip_nofollow_links 时不跟随）
+    pub const NOFOLLOW_LINK_RELS: [&str; 3] = ["nofollow", "ugc", "sponsored"];
+
+    /// 单个爬取任务从站点地图播种的最大 URL 数
+    pub const MAX_SITEMAP_SEED_URLS: usize = 1000;
+
+    /// 单个爬取任务最多抓取的站点地图文件数（含 sitemap index 展开的子地图）
+    pub const MAX_SITEMAP_FILES: usize = 10;
 }
 
 /// 数据库相关常量
@@ -441,6 +441,12 @@ impl From<crate::engines::engine_client::EngineError> for CrawlRsError {
             crate::engines::engine_client::EngineError::Expired => {
                 CrawlRsError::Timeout("Request expired".to_string())
             }
+            crate::engines::engine_client::EngineError::ResponseTooLarge(limit) => {
+                CrawlRsError::Engine(format!("Response body exceeds {} bytes", limit))
+            }
+            crate::engines::engine_client::EngineError::ContentTypeRejected(content_type) => {
+                CrawlRsError::Engine(format!("Content type not accepted: {}", content_type))
+            }
             crate::engines::engine_client::EngineError::AllEnginesFailed(msg) => {
                 CrawlRsError::Engine(format!("All engines failed: {}", msg))
             }
@@ -100,6 +100,7 @@ impl DatabaseSettings {
 /// * `host` - 服务器监听的主机地址，通常为 "0.0.0.0" 或 "127.0.0.1"
 /// * `port` - 服务器监听的端口号，默认 3000
 /// * `enable_port_detection` - 是否开启端口嗅探功能
+/// * `operator_token` - 运营方凭据（敏感信息，仅 crate 可见），未配置时运营接口一律拒绝
 #[derive(Debug, Clone, Deserialize, Serialize, confers::Config)]
 #[config(env_prefix = "CRAWLRS__SERVER__")]
 pub struct ServerSettings {
@@ -114,6 +115,21 @@ pub struct ServerSettings {
     /// 是否开启端口嗅探功能
     #[config(default = true)]
     pub enable_port_detection: bool,
+
+    /// 运营方凭据，调用方通过 `X-Operator-Token` 请求头提交
+    ///
+    /// 团队能力、团队套餐等跨团队的运营接口除 API Key 外还要求该凭据，
+    /// 团队自己的 Admin Key 无法调用。
+    pub(crate) operator_token: Option<String>,
+}
+
+impl ServerSettings {
+    /// 获取运营方凭据，空字符串视为未配置
+    pub fn operator_token(&self) -> Option<&str> {
+        self.operator_token
+            .as_deref()
+            .filter(|token| !token.is_empty())
+    }
 }
 
 /// 速率限制配置设置
@@ -124,6 +140,9 @@ pub struct ServerSettings {
 ///
 /// * `enabled` - 是否启用速率限制，默认 true
 /// * `default_rpm` - 默认每分钟请求数限制，默认 100
+/// * `burst_size` - 令牌桶突发容量，短时峰值最多放行的请求数，默认 20
+/// * `strategy` - 限流策略（token_bucket / fixed_window / sliding_window），默认 token_bucket
+/// * `endpoints` - 端点级限流规则，按 API Key + 路由独立计数，默认为空
 #[derive(Debug, Clone, Deserialize, Serialize, confers::Config)]
 #[config(env_prefix = "CRAWLRS__RATE_LIMITING__")]
 pub struct RateLimitingSettings {
@@ -139,9 +158,33 @@ pub struct RateLimitingSettings {
     #[config(default = 100)]
     pub default_limit: u32,
 
-    /// 突发请求数大小
+    /// 突发请求数大小（令牌桶容量，持续速率为 `default_rpm`）
     #[config(default = 20)]
     pub burst_size: u32,
+
+    /// 限流策略
+    #[config(default = "token_bucket".to_string())]
+    pub strategy: String,
+
+    /// 端点级限流规则，未配置时为空
+    #[serde(default)]
+    pub endpoints: Vec<EndpointRateLimitSettings>,
+}
+
+/// 端点级限流规则配置
+///
+/// `path` 以 `*` 结尾时按前缀匹配；`method` 为空时匹配所有 HTTP 方法。
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct EndpointRateLimitSettings {
+    /// 路由路径
+    pub path: String,
+
+    /// HTTP 方法
+    #[serde(default)]
+    pub method: Option<String>,
+
+    /// 每分钟请求数限制
+    pub requests_per_minute: u32,
 }
 
 /// 并发控制配置设置
@@ -159,7 +202,7 @@ pub struct ConcurrencySettings {
     #[config(default = 10)]
     pub default_team_limit: i64,
 
-    /// 任务锁持续时间（秒）
+    /// 任务锁持续时间（秒），执行期间按该时长的三分之一周期续期
     #[config(default = 300)]
     pub task_lock_duration_seconds: i64,
 }
@@ -238,6 +281,7 @@ mod tests {
             host: "127.0.0.1".to_string(),
             port: 8080,
             enable_port_detection: false,
+            operator_token: None,
         };
         assert_eq!(settings.host, "127.0.0.1");
         assert_eq!(settings.port, 8080);
@@ -253,11 +297,31 @@ mod tests {
             default_rpm: 200,
             default_limit: 150,
             burst_size: 50,
+            strategy: "sliding_window".to_string(),
+            endpoints: vec![],
         };
         assert!(!settings.enabled);
         assert_eq!(settings.default_rpm, 200);
         assert_eq!(settings.default_limit, 150);
         assert_eq!(settings.burst_size, 50);
+        assert_eq!(settings.strategy, "sliding_window");
+        assert!(settings.endpoints.is_empty());
+    }
+
+    #[test]
+    fn test_endpoint_rate_limit_settings_deserialize() {
+        let endpoints: Vec<super::EndpointRateLimitSettings> = serde_json::from_str(
+            r#"[
+                {"path": "/v1/search", "requests_per_minute": 30},
+                {"path": "/v1/scrape", "method": "POST", "requests_per_minute": 300}
+            ]"#,
+        )
+        .unwrap();
+        assert_eq!(endpoints.len(), 2);
+        assert_eq!(endpoints[0].path, "/v1/search");
+        assert!(endpoints[0].method.is_none());
+        assert_eq!(endpoints[1].method.as_deref(), Some("POST"));
+        assert_eq!(endpoints[1].requests_per_minute, 300);
     }
 
     // ========== ConcurrencySettings ==========
@@ -5,7 +5,7 @@
 
 //! 引擎配置
 //!
-//! 包含 FlareSolverr、Fire Engine 等抓取引擎的配置设置
+//! 包含 Reqwest、Playwright、FlareSolverr、Fire Engine 等抓取引擎的配置设置
 
 use serde::{Deserialize, Serialize};
 
@@ -47,6 +47,7 @@ pub struct FlareSolverrSettings {
 ///
 /// * `enabled` - 是否启用 Fire Engine CDP
 /// * `url` - Fire Engine CDP 服务器 URL
+/// * `endpoints` - 额外的 CDP 服务端点，与 `url` 一起轮询分担负载
 #[derive(Debug, Clone, Deserialize, Serialize, confers::Config)]
 #[config(env_prefix = "CRAWLRS__ENGINES__FIRE_CDP__")]
 pub struct FireCdpSettings {
@@ -57,6 +58,10 @@ pub struct FireCdpSettings {
     /// Fire Engine CDP 服务器 URL
     #[config(default = "http://localhost:8191/v1".to_string())]
     pub url: String,
+
+    /// 额外的 CDP 服务端点，与 `url` 一起轮询；连接失败的端点退避后自动重连
+    #[serde(default)]
+    pub endpoints: Vec<String>,
 }
 
 /// Fire Engine TLS 配置设置
@@ -79,6 +84,157 @@ pub struct FireTlsSettings {
     pub url: String,
 }
 
+/// Reqwest 引擎配置设置
+///
+/// 限制 HTTP 抓取时读取的响应体大小，避免超大下载占满内存，并选择连接使用的 IP 地址族
+///
+/// # 字段说明
+///
+/// * `max_response_bytes` - 响应体最大字节数
+/// * `truncate_oversized_responses` - 超出上限时截断（true）还是中止请求（false）
+/// * `address_family` - IP 地址族策略：ipv4 / ipv6 / happy_eyeballs
+#[derive(Debug, Clone, Deserialize, Serialize, confers::Config)]
+#[config(env_prefix = "CRAWLRS__ENGINES__REQWEST__")]
+pub struct ReqwestSettings {
+    /// 响应体最大字节数（默认 10 MiB）
+    #[config(default = 10_485_760)]
+    pub max_response_bytes: u64,
+
+    /// 超出上限时截断响应体并在结果元数据中标记 `truncated`，为 false 时中止请求
+    #[config(default = true)]
+    pub truncate_oversized_responses: bool,
+
+    /// IP 地址族策略（默认 ipv4，部署环境常无 IPv6 连通性），请求可通过 `address_family` 覆盖
+    #[config(default = "ipv4".to_string())]
+    pub address_family: String,
+}
+
+/// Playwright 引擎配置设置
+///
+/// 配置浏览器实例池：预热实例避免冷启动，按页数或内存增长回收实例
+///
+/// # 字段说明
+///
+/// * `max_instances` - 每个进程同时使用的最大浏览器实例数
+/// * `min_warm_instances` - 保持预热的空闲实例数
+/// * `max_pages_per_instance` - 单个实例处理多少个页面后回收（0 表示不限）
+/// * `max_memory_growth_mb` - JS 堆相对首个页面增长超过该值（MB）后回收（0 表示不限）
+/// * `idle_timeout_secs` - 空闲实例超时时间（秒）
+/// * `health_check_interval_secs` - 健康检查间隔（秒）
+#[derive(Debug, Clone, Deserialize, Serialize, confers::Config)]
+#[config(env_prefix = "CRAWLRS__ENGINES__PLAYWRIGHT__")]
+pub struct PlaywrightSettings {
+    /// 每个进程同时使用的最大浏览器实例数
+    #[config(default = 5)]
+    pub max_instances: usize,
+
+    /// 保持预热的空闲实例数
+    #[config(default = 1)]
+    pub min_warm_instances: usize,
+
+    /// 单个实例处理多少个页面后回收（0 表示不限）
+    #[config(default = 100)]
+    pub max_pages_per_instance: u64,
+
+    /// JS 堆相对首个页面增长超过该值（MB）后回收（0 表示不限）
+    #[config(default = 512)]
+    pub max_memory_growth_mb: u64,
+
+    /// 空闲实例超时时间（秒）
+    #[config(default = 300)]
+    pub idle_timeout_secs: u64,
+
+    /// 健康检查间隔（秒）
+    #[config(default = 60)]
+    pub health_check_interval_secs: u64,
+}
+
+/// 引擎启动预检配置
+///
+/// 启动时逐个验证已配置的引擎确实可用，未通过的引擎被标记为不可用，路由器不会选中
+///
+/// # 字段说明
+///
+/// * `enabled` - 是否在启动时执行预检
+/// * `test_url` - 预检时通过每个引擎抓取的测试页面（未配置时只做连通性检查）
+/// * `timeout_secs` - 单个引擎的预检超时时间（秒）
+#[derive(Debug, Clone, Deserialize, Serialize, confers::Config)]
+#[config(env_prefix = "CRAWLRS__ENGINES__PREFLIGHT__")]
+pub struct EnginePreflightSettings {
+    /// 是否在启动时执行预检
+    #[config(default = true)]
+    pub enabled: bool,
+
+    /// 预检时通过每个引擎抓取的测试页面
+    pub test_url: Option<String>,
+
+    /// 单个引擎的预检超时时间（秒）
+    #[config(default = 30)]
+    pub timeout_secs: u64,
+}
+
+/// 按域名固定引擎的规则
+///
+/// `domain` 为 `news.site.com` 时只匹配该主机；为 `*.example.com` 时匹配该域名及所有子域名。
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct DomainEngineOverrideSettings {
+    /// 域名模式
+    pub domain: String,
+
+    /// 引擎名称（如 `playwright`、`fire_tls`）
+    pub engine: String,
+}
+
+/// 按请求档位配置的引擎回退链
+///
+/// 请求通过 `options.engine_tier` 选择档位，路由器按对应列表的顺序依次尝试引擎；
+/// 列表为空的档位沿用路由器的启发式排序。
+#[derive(Debug, Clone, Deserialize, Serialize, confers::Config)]
+#[config(env_prefix = "CRAWLRS__ENGINES__TIERS__")]
+pub struct EngineTierSettings {
+    /// 未指定档位的请求使用的档位（cheap / standard / max）
+    #[config(default = "standard".to_string())]
+    pub default_tier: String,
+
+    /// `cheap` 档位的引擎顺序
+    #[config(default = vec!["reqwest".to_string()])]
+    pub cheap: Vec<String>,
+
+    /// `standard` 档位的引擎顺序
+    #[serde(default)]
+    pub standard: Vec<String>,
+
+    /// `max` 档位的引擎顺序
+    #[serde(default)]
+    pub max: Vec<String>,
+}
+
+/// 被拦截后的引擎升级配置
+///
+/// 响应被识别为反爬拦截页时，路由器在同一次请求内按 TLS 指纹 → 浏览器 → 浏览器 + 代理
+/// 的顺序换用更高档位的引擎重试。最后一步使用全局代理（`proxy.url`），未配置时跳过。
+#[derive(Debug, Clone, Deserialize, Serialize, confers::Config)]
+#[config(env_prefix = "CRAWLRS__ENGINES__BLOCK_ESCALATION__")]
+pub struct BlockEscalationSettings {
+    /// 是否在被拦截时升级引擎
+    #[config(default = true)]
+    pub enabled: bool,
+
+    /// 单次请求最多升级的次数
+    #[config(default = 3)]
+    pub max_escalations: u32,
+}
+
+/// 团队禁用的引擎
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct TeamEngineExclusionSettings {
+    /// 团队 ID
+    pub team_id: String,
+
+    /// 该团队的请求不会使用的引擎名称
+    pub engines: Vec<String>,
+}
+
 /// 引擎配置集合
 ///
 /// 包含所有抓取引擎的配置
@@ -93,4 +249,65 @@ pub struct EngineSettings {
 
     /// Fire Engine TLS 配置
     pub fire_tls: FireTlsSettings,
+
+    /// Reqwest 引擎配置
+    pub reqwest: ReqwestSettings,
+
+    /// Playwright 引擎配置
+    pub playwright: PlaywrightSettings,
+
+    /// 启动预检配置
+    pub preflight: EnginePreflightSettings,
+
+    /// 按域名固定引擎的规则，路由器在启发式选择之前查询
+    #[serde(default)]
+    pub domain_overrides: Vec<DomainEngineOverrideSettings>,
+
+    /// 按请求档位配置的引擎回退链
+    pub tiers: EngineTierSettings,
+
+    /// 被拦截后的引擎升级配置
+    pub block_escalation: BlockEscalationSettings,
+
+    /// 按团队禁用的引擎
+    #[serde(default)]
+    pub team_exclusions: Vec<TeamEngineExclusionSettings>,
+}
+
+impl EngineSettings {
+    /// 团队不能使用的引擎名称
+    pub fn excluded_engines_for(&self, team_id: &str) -> Vec<String> {
+        self.team_exclusions
+            .iter()
+            .filter(|exclusion| exclusion.team_id == team_id)
+            .flat_map(|exclusion| exclusion.engines.iter().cloned())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_excluded_engines_for_team() {
+        let settings = EngineSettings {
+            team_exclusions: vec![
+                TeamEngineExclusionSettings {
+                    team_id: "team-a".to_string(),
+                    engines: vec!["flaresolverr".to_string()],
+                },
+                TeamEngineExclusionSettings {
+                    team_id: "team-a".to_string(),
+                    engines: vec!["fire_cdp".to_string()],
+                },
+            ],
+            ..EngineSettings::default()
+        };
+        assert_eq!(
+            settings.excluded_engines_for("team-a"),
+            vec!["flaresolverr".to_string(), "fire_cdp".to_string()]
+        );
+        assert!(settings.excluded_engines_for("team-b").is_empty());
+    }
 }
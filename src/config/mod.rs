@@ -12,12 +12,15 @@ pub mod app;
 pub mod engines;
 pub mod llm;
 pub mod logging;
+pub mod reload;
 pub mod runtime;
 pub mod search;
+pub mod validation;
 
 // 重新导出子模块中的类型，保持向后兼容
 pub use app::ConcurrencySettings;
 pub use app::DatabaseSettings;
+pub use app::EndpointRateLimitSettings;
 pub use app::RateLimitingSettings;
 pub use app::ServerSettings;
 
@@ -30,12 +33,23 @@ pub use search::SearchSettings;
 
 pub use llm::LLMSettings;
 
+pub use reload::{ReloadReport, ReloadableSettings};
+
 pub use runtime::RuntimeConfig;
+
 pub use settings::{
     CacheSettings, ProxySettings, TimeoutSettings, WebhookSettings, WorkerCount, WorkerSettings,
 };
+pub use validation::{validate_settings, ConfigIssue, ConfigValidationError};
 
 // 主配置结构体
 pub mod settings;
+pub use settings::CreditAlertSettings;
+pub use settings::OidcSettings;
+pub use settings::PlanLimitSettings;
+pub use settings::PlanSettings;
+pub use settings::PricingSettings;
 pub use settings::Settings;
+pub use settings::StorageSettings;
 pub use settings::TrustedProxySettings;
+pub use settings::UrlBlocklistSettings;
@@ -0,0 +1,289 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! 配置热重载
+//!
+//! 运行中可以安全替换的配置子集（全局限流速率、引擎开关与路由规则、积分价格）保存在
+//! `ArcSwap<Settings>` 中，限流服务、引擎路由器与价格服务每次使用时读取当前快照，重载时
+//! 整体原子替换。其余配置（监听地址、数据库、worker 数量等）只在启动时生效，修改后需要
+//! 重启，重载报告会列出这些字段。
+
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+use arc_swap::ArcSwap;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::config::settings::Settings;
+
+/// 读取最新配置的函数（通常为 `bootstrap::config::load_settings`）
+pub type SettingsLoader = fn() -> anyhow::Result<Settings>;
+
+/// 重载时会生效的配置字段
+pub const RELOADABLE_FIELDS: &[&str] = &[
+    "rate_limiting.enabled",
+    "rate_limiting.default_rpm",
+    "rate_limiting.default_limit",
+    "rate_limiting.burst_size",
+    "engines.flaresolverr.enabled",
+    "engines.fire_cdp.enabled",
+    "engines.fire_tls.enabled",
+    "engines.domain_overrides",
+    "engines.tiers",
+    "pricing",
+];
+
+/// 一次重载的结果
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct ReloadReport {
+    /// 已生效的配置字段
+    pub reloaded: Vec<String>,
+    /// 已修改但需要重启才能生效的配置字段
+    pub restart_required: Vec<String>,
+}
+
+impl ReloadReport {
+    /// 配置没有任何变化
+    pub fn is_empty(&self) -> bool {
+        self.reloaded.is_empty() && self.restart_required.is_empty()
+    }
+}
+
+/// 将新配置中可热重载的字段合并到当前配置
+fn merge_reloadable(current: &Settings, loaded: &Settings) -> Settings {
+    let mut merged = current.clone();
+
+    merged.rate_limiting.enabled = loaded.rate_limiting.enabled;
+    merged.rate_limiting.default_rpm = loaded.rate_limiting.default_rpm;
+    merged.rate_limiting.default_limit = loaded.rate_limiting.default_limit;
+    merged.rate_limiting.burst_size = loaded.rate_limiting.burst_size;
+
+    merged.engines.flaresolverr.enabled = loaded.engines.flaresolverr.enabled;
+    merged.engines.fire_cdp.enabled = loaded.engines.fire_cdp.enabled;
+    merged.engines.fire_tls.enabled = loaded.engines.fire_tls.enabled;
+    merged.engines.domain_overrides = loaded.engines.domain_overrides.clone();
+    merged.engines.tiers = loaded.engines.tiers.clone();
+
+    merged.pricing = loaded.pricing.clone();
+    merged
+}
+
+/// 比较两份配置，返回取值不同的字段路径（最多展开到 `section.field` 两级）
+fn changed_fields(before: &Settings, after: &Settings) -> Vec<String> {
+    let (Ok(before), Ok(after)) = (serde_json::to_value(before), serde_json::to_value(after))
+    else {
+        return Vec::new();
+    };
+    let mut changed = Vec::new();
+    collect_changes(&before, &after, "", 2, &mut changed);
+    changed
+}
+
+fn collect_changes(before: &Value, after: &Value, path: &str, depth: usize, out: &mut Vec<String>) {
+    if before == after {
+        return;
+    }
+    match (before, after) {
+        (Value::Object(before), Value::Object(after)) if depth > 0 => {
+            let mut keys: Vec<&String> = before.keys().chain(after.keys()).collect();
+            keys.sort();
+            keys.dedup();
+            for key in keys {
+                let child = if path.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{}.{}", path, key)
+                };
+                collect_changes(
+                    before.get(key).unwrap_or(&Value::Null),
+                    after.get(key).unwrap_or(&Value::Null),
+                    &child,
+                    depth - 1,
+                    out,
+                );
+            }
+        }
+        _ => out.push(path.to_string()),
+    }
+}
+
+/// 可热重载的配置
+///
+/// 持有当前生效的配置快照。`reload` 读取最新配置，只替换可热重载的字段，
+/// 正在处理的请求继续使用它已取得的快照。
+pub struct ReloadableSettings {
+    current: ArcSwap<Settings>,
+    loader: Option<SettingsLoader>,
+    reload_lock: Mutex<()>,
+}
+
+impl ReloadableSettings {
+    /// 以启动时加载的配置创建
+    pub fn new(settings: Settings) -> Self {
+        Self {
+            current: ArcSwap::from_pointee(settings),
+            loader: None,
+            reload_lock: Mutex::new(()),
+        }
+    }
+
+    /// 设置读取最新配置的函数，`reload` 使用它重新加载配置文件与环境变量
+    pub fn with_loader(mut self, loader: SettingsLoader) -> Self {
+        self.loader = Some(loader);
+        self
+    }
+
+    /// 当前生效的配置快照
+    pub fn current(&self) -> Arc<Settings> {
+        self.current.load_full()
+    }
+
+    /// 应用新加载的配置：可热重载的字段立即生效，其余字段的修改只记录在报告中
+    pub fn apply(&self, loaded: Settings) -> ReloadReport {
+        let _guard = self
+            .reload_lock
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        let current = self.current.load_full();
+        let merged = merge_reloadable(&current, &loaded);
+        let report = ReloadReport {
+            reloaded: changed_fields(&current, &merged),
+            restart_required: changed_fields(&merged, &loaded),
+        };
+        if !report.reloaded.is_empty() {
+            self.current.store(Arc::new(merged));
+        }
+        report
+    }
+
+    /// 重新读取配置并应用
+    pub fn reload(&self) -> anyhow::Result<ReloadReport> {
+        let loader = self
+            .loader
+            .ok_or_else(|| anyhow::anyhow!("No settings loader configured"))?;
+        Ok(self.apply(loader()?))
+    }
+}
+
+impl fmt::Debug for ReloadableSettings {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ReloadableSettings")
+            .field("reloadable", &self.loader.is_some())
+            .finish_non_exhaustive()
+    }
+}
+
+/// 由配置快照派生的值
+///
+/// 按快照缓存构建结果，配置重载后在下一次读取时重新构建，避免每次使用都重新解析配置。
+pub struct DerivedSettings<T> {
+    settings: Arc<ReloadableSettings>,
+    build: fn(&Settings) -> T,
+    cache: ArcSwap<(Arc<Settings>, Arc<T>)>,
+}
+
+impl<T> DerivedSettings<T> {
+    /// 使用构建函数从当前配置派生值
+    pub fn new(settings: Arc<ReloadableSettings>, build: fn(&Settings) -> T) -> Self {
+        let snapshot = settings.current();
+        let value = Arc::new(build(&snapshot));
+        Self {
+            settings,
+            build,
+            cache: ArcSwap::from_pointee((snapshot, value)),
+        }
+    }
+
+    /// 当前配置对应的值
+    pub fn get(&self) -> Arc<T> {
+        let snapshot = self.settings.current();
+        let cached = self.cache.load();
+        if Arc::ptr_eq(&cached.0, &snapshot) {
+            return cached.1.clone();
+        }
+        let value = Arc::new((self.build)(&snapshot));
+        self.cache.store(Arc::new((snapshot, value.clone())));
+        value
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for DerivedSettings<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("DerivedSettings").field(&self.get()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bootstrap::config::load_settings;
+
+    #[test]
+    fn test_apply_swaps_reloadable_fields_only() {
+        let settings = load_settings().expect("Failed to load settings");
+        let reloadable = ReloadableSettings::new(settings.clone());
+        let before = reloadable.current();
+
+        let mut loaded = settings;
+        loaded.rate_limiting.default_rpm += 10;
+        loaded.engines.fire_tls.enabled = !loaded.engines.fire_tls.enabled;
+        loaded.pricing.scrape += 1;
+        loaded.server.port = loaded.server.port.wrapping_add(1);
+
+        let report = reloadable.apply(loaded.clone());
+        assert_eq!(
+            report.reloaded,
+            vec![
+                "engines.fire_tls".to_string(),
+                "pricing.scrape".to_string(),
+                "rate_limiting.default_rpm".to_string(),
+            ]
+        );
+        assert_eq!(report.restart_required, vec!["server.port".to_string()]);
+
+        let current = reloadable.current();
+        assert!(!Arc::ptr_eq(&before, &current));
+        assert_eq!(
+            current.rate_limiting.default_rpm,
+            loaded.rate_limiting.default_rpm
+        );
+        assert_eq!(current.pricing.scrape, loaded.pricing.scrape);
+        assert_eq!(current.server.port, before.server.port);
+    }
+
+    #[test]
+    fn test_apply_unchanged_settings_keeps_snapshot() {
+        let settings = load_settings().expect("Failed to load settings");
+        let reloadable = ReloadableSettings::new(settings.clone());
+        let before = reloadable.current();
+
+        let report = reloadable.apply(settings);
+        assert!(report.is_empty());
+        assert!(Arc::ptr_eq(&before, &reloadable.current()));
+    }
+
+    #[test]
+    fn test_reload_without_loader_fails() {
+        let settings = load_settings().expect("Failed to load settings");
+        assert!(ReloadableSettings::new(settings).reload().is_err());
+    }
+
+    #[test]
+    fn test_derived_settings_rebuilds_after_reload() {
+        let settings = load_settings().expect("Failed to load settings");
+        let reloadable = Arc::new(ReloadableSettings::new(settings.clone()));
+        let derived = DerivedSettings::new(reloadable.clone(), |s| s.pricing.scrape);
+
+        let first = derived.get();
+        assert!(Arc::ptr_eq(&first, &derived.get()));
+
+        let mut loaded = settings;
+        loaded.pricing.scrape += 5;
+        reloadable.apply(loaded.clone());
+        assert_eq!(*derived.get(), loaded.pricing.scrape);
+    }
+}
@@ -0,0 +1,676 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! 启动配置校验
+//!
+//! 启动时一次性检查 URL 格式、端口范围以及相互依赖的字段（如启用 OIDC 需要 IdP 地址），
+//! 汇总全部问题并在拒绝启动前输出，每条问题都给出对应的配置项与环境变量，避免无效配置
+//! 在运行时深处才以 panic 暴露。
+
+use std::fmt;
+use std::str::FromStr;
+
+use url::Url;
+
+use crate::config::settings::Settings;
+use crate::engines::engine_tier::EngineTier;
+
+/// 一条配置问题
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigIssue {
+    /// 配置项路径，如 `engines.flaresolverr.url`
+    pub field: String,
+    /// 问题描述与修正方式
+    pub message: String,
+}
+
+impl ConfigIssue {
+    /// 覆盖该配置项的环境变量名
+    pub fn env_var(&self) -> String {
+        let path = self.field.split('[').next().unwrap_or_default();
+        format!("CRAWLRS__{}", path.to_ascii_uppercase().replace('.', "__"))
+    }
+}
+
+impl fmt::Display for ConfigIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: {} (set `{}` in the config file or {})",
+            self.field,
+            self.message,
+            self.field,
+            self.env_var()
+        )
+    }
+}
+
+/// 配置校验失败，包含全部问题
+#[derive(Debug)]
+pub struct ConfigValidationError {
+    /// 发现的问题，按检查顺序排列
+    pub issues: Vec<ConfigIssue>,
+}
+
+impl fmt::Display for ConfigValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Invalid configuration ({} problem{}):",
+            self.issues.len(),
+            if self.issues.len() == 1 { "" } else { "s" }
+        )?;
+        for issue in &self.issues {
+            write!(f, "\n  - {}", issue)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ConfigValidationError {}
+
+/// 收集配置问题
+#[derive(Default)]
+struct Issues(Vec<ConfigIssue>);
+
+impl Issues {
+    fn push(&mut self, field: impl Into<String>, message: impl Into<String>) {
+        self.0.push(ConfigIssue {
+            field: field.into(),
+            message: message.into(),
+        });
+    }
+
+    fn require(&mut self, field: &str, value: &str) {
+        if value.trim().is_empty() {
+            self.push(field, "must not be empty");
+        }
+    }
+
+    /// 检查 URL 格式与协议
+    fn url(&mut self, field: impl Into<String>, value: &str, schemes: &[&str]) {
+        let field = field.into();
+        match Url::parse(value.trim()) {
+            Ok(url) if schemes.contains(&url.scheme()) => {}
+            Ok(url) => self.push(
+                field,
+                format!(
+                    "unsupported scheme `{}`, expected one of: {}",
+                    url.scheme(),
+                    schemes.join(", ")
+                ),
+            ),
+            // 不回显原值，连接串中可能带有密码
+            Err(e) => self.push(field, format!("is not a valid URL ({})", e)),
+        }
+    }
+}
+
+const HTTP: &[&str] = &["http", "https"];
+
+/// 校验配置，返回发现的全部问题
+///
+/// 生产环境要求配置数据库连接；开发与测试环境允许留空，以便在没有数据库时加载配置。
+pub fn validate_settings(
+    settings: &Settings,
+    is_production: bool,
+) -> Result<(), ConfigValidationError> {
+    let mut issues = Issues::default();
+
+    // 服务器
+    issues.require("server.host", &settings.server.host);
+    if settings.server.port == 0 {
+        issues.push("server.port", "must be between 1 and 65535");
+    }
+
+    // 数据库
+    let database_url = settings.database.url();
+    if database_url.trim().is_empty() {
+        if is_production {
+            issues.push("database.url", "is required in production");
+        }
+    } else {
+        issues.url(
+            "database.url",
+            database_url,
+            &["postgres", "postgresql", "sqlite"],
+        );
+    }
+    if let (Some(min), Some(max)) = (
+        settings.database.min_connections,
+        settings.database.max_connections,
+    ) {
+        if min > max {
+            issues.push(
+                "database.min_connections",
+                format!("({}) must not exceed max_connections ({})", min, max),
+            );
+        }
+    }
+
+    // CORS
+    for (index, origin) in settings
+        .cors
+        .allowed_origins
+        .split(',')
+        .map(str::trim)
+        .filter(|origin| !origin.is_empty() && *origin != "*")
+        .enumerate()
+    {
+        issues.url(format!("cors.allowed_origins[{}]", index), origin, HTTP);
+    }
+
+    // 限流
+    if settings.rate_limiting.enabled && settings.rate_limiting.default_rpm == 0 {
+        issues.push(
+            "rate_limiting.default_rpm",
+            "must be greater than 0 when rate limiting is enabled",
+        );
+    }
+
+    // 代理
+    if settings.proxy.enabled {
+        issues.url(
+            "proxy.url",
+            settings.proxy.url(),
+            &["http", "https", "socks5", "socks5h"],
+        );
+    }
+
+    // 引擎
+    let engines = &settings.engines;
+    if engines.flaresolverr.enabled {
+        issues.url("engines.flaresolverr.url", &engines.flaresolverr.url, HTTP);
+    }
+    if engines.fire_cdp.enabled {
+        let cdp = &["http", "https", "ws", "wss"];
+        issues.url("engines.fire_cdp.url", &engines.fire_cdp.url, cdp);
+        for (index, endpoint) in engines.fire_cdp.endpoints.iter().enumerate() {
+            issues.url(
+                format!("engines.fire_cdp.endpoints[{}]", index),
+                endpoint,
+                cdp,
+            );
+        }
+    }
+    if engines.fire_tls.enabled {
+        issues.url("engines.fire_tls.url", &engines.fire_tls.url, HTTP);
+    }
+    if let Some(test_url) = engines.preflight.test_url.as_deref() {
+        issues.url("engines.preflight.test_url", test_url, HTTP);
+    }
+    for (index, rule) in engines.domain_overrides.iter().enumerate() {
+        if rule.domain.trim().is_empty() || rule.engine.trim().is_empty() {
+            issues.push(
+                format!("engines.domain_overrides[{}]", index),
+                "both `domain` and `engine` are required",
+            );
+        }
+    }
+    if let Err(e) = EngineTier::from_str(&engines.tiers.default_tier) {
+        issues.push(
+            "engines.tiers.default_tier",
+            format!("{}, expected cheap, standard or max", e),
+        );
+    }
+
+    // LLM
+    if let Some(api_base_url) = settings
+        .llm
+        .api_base_url
+        .as_deref()
+        .filter(|url| !url.trim().is_empty())
+    {
+        issues.url("llm.api_base_url", api_base_url, HTTP);
+    }
+
+    // 搜索 A/B 测试
+    let weight = settings.search.variant_b_weight;
+    if !(0.0..=1.0).contains(&weight) {
+        issues.push(
+            "search.variant_b_weight",
+            format!("({}) must be between 0.0 and 1.0", weight),
+        );
+    }
+
+    // 可信代理
+    if settings.trusted_proxies.enabled {
+        for (index, proxy) in settings.trusted_proxies.proxies.iter().enumerate() {
+            if ipnetwork::IpNetwork::from_str(proxy.trim()).is_err() {
+                issues.push(
+                    format!("trusted_proxies.proxies[{}]", index),
+                    format!("`{}` is not an IP address or CIDR range", proxy),
+                );
+            }
+        }
+    }
+
+    // 存储
+    issues.require("storage.local_path", &settings.storage.local_path);
+    issues.require("storage.public_base_url", &settings.storage.public_base_url);
+
+    // 积分提醒
+    let relay_url = &settings.credit_alerts.email_relay_url;
+    if !relay_url.trim().is_empty() {
+        issues.url("credit_alerts.email_relay_url", relay_url, HTTP);
+    }
+
+    // OIDC：启用时 IdP、客户端与回调地址必须同时配置
+    let oidc = &settings.oidc;
+    if oidc.enabled {
+        for (field, value) in [
+            ("oidc.issuer_url", &oidc.issuer_url),
+            ("oidc.client_id", &oidc.client_id),
+            ("oidc.redirect_uri", &oidc.redirect_uri),
+        ] {
+            if value.trim().is_empty() {
+                issues.push(field, "is required when oidc.enabled = true");
+            }
+        }
+        if !oidc.issuer_url.trim().is_empty() {
+            issues.url("oidc.issuer_url", &oidc.issuer_url, HTTP);
+        }
+        if !oidc.redirect_uri.trim().is_empty() {
+            issues.url("oidc.redirect_uri", &oidc.redirect_uri, HTTP);
+        }
+    }
+
+    // 外部检索索引
+    let search_index = &settings.search_index;
+    if search_index.enabled {
+        issues.url("search_index.url", &search_index.url, HTTP);
+        let index = search_index.index.trim();
+        if index.is_empty()
+            || index.starts_with(['-', '_', '+'])
+            || index
+                .chars()
+                .any(|c| c.is_ascii_uppercase() || r#" \/*?"<>|,#:"#.contains(c))
+        {
+            issues.push(
+                "search_index.index",
+                "must be a lowercase index name without spaces or \\ / * ? \" < > | , # :",
+            );
+        }
+        if search_index.timeout_seconds == 0 {
+            issues.push("search_index.timeout_seconds", "must be greater than 0");
+        }
+        if search_index.batch_size == 0 {
+            issues.push("search_index.batch_size", "must be greater than 0");
+        }
+    }
+
+    // 邮件通知
+    let email = &settings.email;
+    if email.enabled {
+        if cfg!(not(feature = "notify-email")) {
+            issues.push("email.enabled", "requires the notify-email feature");
+        }
+        match email.provider.to_ascii_lowercase().as_str() {
+            "smtp" => issues.require("email.smtp_host", &email.smtp_host),
+            "ses" => {
+                if email.smtp_host.trim().is_empty() {
+                    issues.require("email.ses_region", &email.ses_region);
+                }
+            }
+            other => issues.push(
+                "email.provider",
+                format!("`{}` is not one of smtp, ses", other),
+            ),
+        }
+        if !matches!(
+            email.tls.to_ascii_lowercase().as_str(),
+            "starttls" | "tls" | "none"
+        ) {
+            issues.push(
+                "email.tls",
+                format!("`{}` is not one of starttls, tls, none", email.tls),
+            );
+        }
+        issues.require("email.from_address", &email.from_address);
+        if email.smtp_port == 0 {
+            issues.push("email.smtp_port", "must be greater than 0");
+        }
+        if email.timeout_seconds == 0 {
+            issues.push("email.timeout_seconds", "must be greater than 0");
+        }
+    }
+
+    // 任务队列
+    let queue = &settings.queue;
+    match queue.backend.to_ascii_lowercase().as_str() {
+        "postgres" => {}
+        "kafka" => {
+            if cfg!(not(feature = "queue-kafka")) {
+                issues.push("queue.backend", "`kafka` requires the queue-kafka feature");
+            }
+            issues.require("queue.kafka.brokers", &queue.kafka.brokers);
+            issues.require("queue.kafka.topic_prefix", &queue.kafka.topic_prefix);
+            issues.require("queue.kafka.group_id", &queue.kafka.group_id);
+            if queue.kafka.poll_timeout_ms == 0 {
+                issues.push("queue.kafka.poll_timeout_ms", "must be greater than 0");
+            }
+        }
+        "rabbitmq" => {
+            if cfg!(not(feature = "queue-rabbitmq")) {
+                issues.push(
+                    "queue.backend",
+                    "`rabbitmq` requires the queue-rabbitmq feature",
+                );
+            }
+            issues.url(
+                "queue.rabbitmq.url",
+                queue.rabbitmq.url(),
+                &["amqp", "amqps"],
+            );
+            issues.require("queue.rabbitmq.queue_prefix", &queue.rabbitmq.queue_prefix);
+            if queue.rabbitmq.prefetch_count == 0 {
+                issues.push("queue.rabbitmq.prefetch_count", "must be greater than 0");
+            }
+            if queue.rabbitmq.poll_timeout_ms == 0 {
+                issues.push("queue.rabbitmq.poll_timeout_ms", "must be greater than 0");
+            }
+        }
+        other => issues.push(
+            "queue.backend",
+            format!(
+                "unknown backend `{}`, expected postgres, kafka or rabbitmq",
+                other
+            ),
+        ),
+    }
+    let priority = &queue.priority;
+    if priority.enabled {
+        let weights = [
+            priority.high_weight,
+            priority.normal_weight,
+            priority.low_weight,
+        ];
+        if weights.iter().all(|weight| *weight == 0) {
+            issues.push(
+                "queue.priority.high_weight",
+                "at least one lane weight must be greater than 0",
+            );
+        }
+        if priority.aging_seconds == 0 {
+            issues.push("queue.priority.aging_seconds", "must be greater than 0");
+        }
+    }
+    let delayed = &queue.delayed;
+    if delayed.enabled {
+        match delayed.backend.to_ascii_lowercase().as_str() {
+            "memory" => {}
+            "redis" => {
+                if cfg!(not(feature = "queue-redis")) {
+                    issues.push(
+                        "queue.delayed.backend",
+                        "`redis` requires the queue-redis feature",
+                    );
+                }
+                issues.url(
+                    "queue.delayed.redis_url",
+                    delayed.redis_url(),
+                    &["redis", "rediss"],
+                );
+                issues.require("queue.delayed.redis_key", &delayed.redis_key);
+            }
+            other => issues.push(
+                "queue.delayed.backend",
+                format!("unknown backend `{}`, expected memory or redis", other),
+            ),
+        }
+        if delayed.batch_size == 0 {
+            issues.push("queue.delayed.batch_size", "must be greater than 0");
+        }
+        if delayed.max_sleep_ms == 0 {
+            issues.push("queue.delayed.max_sleep_ms", "must be greater than 0");
+        }
+    }
+    let result_stream = &queue.result_stream;
+    match result_stream.backend.to_ascii_lowercase().as_str() {
+        "memory" => {}
+        "redis" => {
+            if cfg!(not(feature = "queue-redis")) {
+                issues.push(
+                    "queue.result_stream.backend",
+                    "`redis` requires the queue-redis feature",
+                );
+            }
+            issues.url(
+                "queue.result_stream.redis_url",
+                result_stream.redis_url(),
+                &["redis", "rediss"],
+            );
+            issues.require(
+                "queue.result_stream.channel_prefix",
+                &result_stream.channel_prefix,
+            );
+        }
+        other => issues.push(
+            "queue.result_stream.backend",
+            format!("unknown backend `{}`, expected memory or redis", other),
+        ),
+    }
+    if result_stream.status_check_seconds == 0 {
+        issues.push(
+            "queue.result_stream.status_check_seconds",
+            "must be greater than 0",
+        );
+    }
+
+    let bandwidth = &settings.bandwidth;
+    if bandwidth.enabled {
+        match bandwidth.backend.to_ascii_lowercase().as_str() {
+            "memory" => {}
+            "redis" => {
+                if cfg!(not(feature = "queue-redis")) {
+                    issues.push(
+                        "bandwidth.backend",
+                        "`redis` requires the queue-redis feature",
+                    );
+                }
+                issues.url(
+                    "bandwidth.redis_url",
+                    bandwidth.redis_url(),
+                    &["redis", "rediss"],
+                );
+                issues.require("bandwidth.key_prefix", &bandwidth.key_prefix);
+            }
+            other => issues.push(
+                "bandwidth.backend",
+                format!("unknown backend `{}`, expected memory or redis", other),
+            ),
+        }
+        if bandwidth.rollup_interval_seconds == 0 {
+            issues.push(
+                "bandwidth.rollup_interval_seconds",
+                "must be greater than 0",
+            );
+        }
+        if bandwidth.counter_ttl_seconds <= bandwidth.rollup_interval_seconds {
+            issues.push(
+                "bandwidth.counter_ttl_seconds",
+                "must be greater than rollup_interval_seconds",
+            );
+        }
+        for cap in &bandwidth.team_caps {
+            if uuid::Uuid::parse_str(&cap.team_id).is_err() {
+                issues.push(
+                    "bandwidth.team_caps.team_id",
+                    format!("`{}` is not a valid team id", cap.team_id),
+                );
+            }
+        }
+    }
+
+    if issues.0.is_empty() {
+        Ok(())
+    } else {
+        Err(ConfigValidationError { issues: issues.0 })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bootstrap::config::load_settings;
+    use crate::config::settings::TeamBandwidthCapSettings;
+
+    fn fields(error: &ConfigValidationError) -> Vec<&str> {
+        error.issues.iter().map(|i| i.field.as_str()).collect()
+    }
+
+    #[test]
+    fn test_default_settings_are_valid_outside_production() {
+        let settings = load_settings().expect("Failed to load settings");
+        assert!(validate_settings(&settings, false).is_ok());
+    }
+
+    #[test]
+    fn test_validation_aggregates_all_problems() {
+        let mut settings = load_settings().expect("Failed to load settings");
+        settings.server.port = 0;
+        settings.database.url = "mysql://localhost/crawlrs".to_string();
+        settings.engines.flaresolverr.enabled = true;
+        settings.engines.flaresolverr.url = "not a url".to_string();
+        settings.engines.tiers.default_tier = "premium".to_string();
+        settings.oidc.enabled = true;
+        settings.oidc.issuer_url = "https://idp.example.com".to_string();
+        settings.oidc.client_id = String::new();
+        settings.oidc.redirect_uri =
+            "https://crawlrs.example.com/v1/auth/oidc/callback".to_string();
+
+        let error = validate_settings(&settings, false).unwrap_err();
+        assert_eq!(
+            fields(&error),
+            vec![
+                "server.port",
+                "database.url",
+                "engines.flaresolverr.url",
+                "engines.tiers.default_tier",
+                "oidc.client_id",
+            ]
+        );
+
+        let message = error.to_string();
+        assert!(message.starts_with("Invalid configuration (5 problems):"));
+        assert!(message.contains("CRAWLRS__ENGINES__FLARESOLVERR__URL"));
+    }
+
+    #[test]
+    fn test_database_url_required_in_production() {
+        let mut settings = load_settings().expect("Failed to load settings");
+        settings.database.url = String::new();
+
+        let error = validate_settings(&settings, true).unwrap_err();
+        assert!(fields(&error).contains(&"database.url"));
+    }
+
+    #[test]
+    fn test_broker_queue_backends_require_connection() {
+        let mut settings = load_settings().expect("Failed to load settings");
+        settings.queue.backend = "kafka".to_string();
+        settings.queue.kafka.brokers = String::new();
+
+        let error = validate_settings(&settings, false).unwrap_err();
+        assert!(fields(&error).contains(&"queue.kafka.brokers"));
+
+        settings.queue.backend = "rabbitmq".to_string();
+        settings.queue.rabbitmq.url = "http://rabbitmq:15672".to_string();
+        let error = validate_settings(&settings, false).unwrap_err();
+        assert!(fields(&error).contains(&"queue.rabbitmq.url"));
+
+        settings.queue.backend = "redis".to_string();
+        let error = validate_settings(&settings, false).unwrap_err();
+        assert_eq!(fields(&error), vec!["queue.backend"]);
+    }
+
+    #[test]
+    fn test_redis_delayed_index_requires_url() {
+        let mut settings = load_settings().expect("Failed to load settings");
+        settings.queue.delayed.backend = "redis".to_string();
+        settings.queue.delayed.redis_url = "http://redis:6379".to_string();
+
+        let error = validate_settings(&settings, false).unwrap_err();
+        assert!(fields(&error).contains(&"queue.delayed.redis_url"));
+
+        settings.queue.delayed.enabled = false;
+        assert!(validate_settings(&settings, false).is_ok());
+    }
+
+    #[test]
+    fn test_redis_result_stream_requires_url() {
+        let mut settings = load_settings().expect("Failed to load settings");
+        settings.queue.result_stream.backend = "redis".to_string();
+
+        let error = validate_settings(&settings, false).unwrap_err();
+        assert!(fields(&error).contains(&"queue.result_stream.redis_url"));
+
+        settings.queue.result_stream.backend = "kafka".to_string();
+        let error = validate_settings(&settings, false).unwrap_err();
+        assert!(fields(&error).contains(&"queue.result_stream.backend"));
+    }
+
+    #[test]
+    fn test_bandwidth_settings_validation() {
+        let mut settings = load_settings().expect("Failed to load settings");
+        settings.bandwidth.backend = "redis".to_string();
+        let error = validate_settings(&settings, false).unwrap_err();
+        assert!(fields(&error).contains(&"bandwidth.redis_url"));
+
+        settings.bandwidth.backend = "memory".to_string();
+        settings.bandwidth.team_caps = vec![TeamBandwidthCapSettings {
+            team_id: "team-a".to_string(),
+            daily_cap_bytes: 1024,
+        }];
+        let error = validate_settings(&settings, false).unwrap_err();
+        assert!(fields(&error).contains(&"bandwidth.team_caps.team_id"));
+
+        settings.bandwidth.enabled = false;
+        assert!(validate_settings(&settings, false).is_ok());
+    }
+
+    #[test]
+    fn test_enabled_search_index_requires_url_and_valid_index() {
+        let mut settings = load_settings().expect("Failed to load settings");
+        settings.search_index.enabled = true;
+        settings.search_index.index = "Crawl Results".to_string();
+
+        let error = validate_settings(&settings, false).unwrap_err();
+        let fields = fields(&error);
+        assert!(fields.contains(&"search_index.url"));
+        assert!(fields.contains(&"search_index.index"));
+
+        settings.search_index.url = "https://opensearch:9200".to_string();
+        settings.search_index.index = "crawlrs-results".to_string();
+        assert!(validate_settings(&settings, false).is_ok());
+    }
+
+    #[test]
+    fn test_enabled_email_requires_sender_settings() {
+        let mut settings = load_settings().expect("Failed to load settings");
+        settings.email.enabled = true;
+        settings.email.provider = "ses".to_string();
+        settings.email.tls = "ssl".to_string();
+
+        let error = validate_settings(&settings, false).unwrap_err();
+        let fields = fields(&error);
+        assert!(fields.contains(&"email.ses_region"));
+        assert!(fields.contains(&"email.tls"));
+        assert!(fields.contains(&"email.from_address"));
+        assert_eq!(
+            fields.contains(&"email.enabled"),
+            cfg!(not(feature = "notify-email"))
+        );
+    }
+
+    #[test]
+    fn test_env_var_for_indexed_field() {
+        let issue = ConfigIssue {
+            field: "engines.fire_cdp.endpoints[2]".to_string(),
+            message: String::new(),
+        };
+        assert_eq!(issue.env_var(), "CRAWLRS__ENGINES__FIRE_CDP__ENDPOINTS");
+    }
+}
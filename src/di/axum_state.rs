@@ -17,7 +17,10 @@ use std::sync::Arc;
 use trait_kit::{AsyncKit, AsyncReady};
 
 use crate::application::use_cases::create_scrape::CreateScrapeUseCaseTrait;
-use crate::di::modules::{EngineModule, InfrastructureModule, ModuleBuildError, ServiceModule};
+use crate::config::reload::ReloadableSettings;
+use crate::di::modules::{
+    EngineModule, InfrastructureModule, ModuleBuildError, ReloadableSettingsModule, ServiceModule,
+};
 use crate::domain::repositories::crawl_repository::CrawlRepository;
 use crate::domain::repositories::credits_repository::CreditsRepository;
 use crate::domain::repositories::geo_restriction_repository::GeoRestrictionRepository;
@@ -28,9 +31,11 @@ use crate::domain::repositories::webhook_event_repository::WebhookEventRepositor
 use crate::domain::repositories::webhook_repository::WebhookRepository;
 use crate::domain::services::audit_service::AuditServiceTrait;
 use crate::domain::services::auth_scope_service::AuthScopeService;
+use crate::domain::services::bandwidth_service::BandwidthCounter;
 use crate::domain::services::extraction_service::ExtractionServiceTrait;
 use crate::domain::services::geo_location::GeoLocationService;
 use crate::domain::services::llm_service::LLMServiceTrait;
+use crate::domain::services::notification_service::EventNotifier;
 use crate::domain::services::rate_limiting_service::RateLimitingService;
 use crate::domain::services::search_service::SearchServiceTrait;
 use crate::domain::services::team_service::TeamService;
@@ -38,6 +43,7 @@ use crate::domain::services::webhook_service::WebhookService;
 use crate::engines::engine_client::EngineClient;
 use crate::engines::router::EngineRouter;
 use crate::presentation::middleware::team_semaphore::TeamSemaphore;
+use crate::queue::result_stream::ResultStreamBus;
 use crate::queue::task_queue::TaskQueue;
 use crate::search::client::SearchClient;
 use crate::utils::regex_cache::RegexCache;
@@ -65,6 +71,12 @@ pub struct CrawlRsState {
     pub webhook_repo: Arc<dyn WebhookRepository>,
     /// Webhook event repository
     pub webhook_event_repo: Arc<dyn WebhookEventRepository>,
+    /// Event notifier for email contacts and chat channels
+    pub notifier: Arc<dyn EventNotifier>,
+    /// Bus carrying newly saved crawl results to `?follow=true` streams
+    pub result_stream: Arc<dyn ResultStreamBus>,
+    /// Counters of bytes downloaded per team and task
+    pub bandwidth_counter: Arc<dyn BandwidthCounter>,
     /// Tasks backlog repository
     pub tasks_backlog_repo: Arc<dyn TasksBacklogRepository>,
     /// Task queue
@@ -105,10 +117,15 @@ pub struct CrawlRsState {
     pub backlog_worker: Arc<crate::workers::backlog_worker::BacklogWorker>,
     /// Expiration worker
     pub expiration_worker: Arc<crate::workers::expiration_worker::ExpirationWorker>,
+    pub stalled_task_reaper: Arc<crate::workers::stalled_task_reaper::StalledTaskReaper>,
+    /// Monitor worker
+    pub monitor_worker: Arc<crate::workers::monitor_worker::MonitorWorker>,
     /// Geo location service
     pub geo_location_service: Arc<dyn GeoLocationService>,
     /// Geo restriction repository
     pub geo_restriction_repo: Arc<dyn GeoRestrictionRepository>,
+    /// Hot-reloadable settings shared with the rate limiter and engine router
+    pub reloadable_settings: Arc<ReloadableSettings>,
 }
 
 impl CrawlRsState {
@@ -127,6 +144,7 @@ impl CrawlRsState {
         let infra = kit.require::<InfrastructureModule>()?;
         let engines = kit.require::<EngineModule>()?;
         let services = kit.require::<ServiceModule>()?;
+        let reloadable_settings = kit.require::<ReloadableSettingsModule>()?;
 
         let search_client = Arc::new(SearchClient::new(engines.engine_client.clone()));
 
@@ -138,6 +156,9 @@ impl CrawlRsState {
             result_repo: infra.repositories.result_repo.clone(),
             webhook_repo: infra.repositories.webhook_repo.clone(),
             webhook_event_repo: infra.repositories.webhook_event_repo.clone(),
+            notifier: infra.repositories.notifier.clone(),
+            result_stream: infra.repositories.result_stream.clone(),
+            bandwidth_counter: infra.repositories.bandwidth_counter.clone(),
             tasks_backlog_repo: infra.repositories.tasks_backlog_repo.clone(),
             task_queue: services.queue.clone(),
             rate_limiting_service: services.rate_limiting_service.clone(),
@@ -158,8 +179,11 @@ impl CrawlRsState {
             webhook_worker: services.webhook_worker.clone(),
             backlog_worker: services.backlog_worker.clone(),
             expiration_worker: services.expiration_worker.clone(),
+            stalled_task_reaper: services.stalled_task_reaper.clone(),
+            monitor_worker: services.monitor_worker.clone(),
             geo_location_service: services.geo_location_service.clone(),
             geo_restriction_repo: infra.repositories.geo_restriction_repo.clone(),
+            reloadable_settings,
         })
     }
 }
@@ -223,6 +247,10 @@ pub trait CrawlRsStateExt {
     fn backlog_worker(&self) -> Arc<crate::workers::backlog_worker::BacklogWorker>;
     /// Get expiration worker
     fn expiration_worker(&self) -> Arc<crate::workers::expiration_worker::ExpirationWorker>;
+    /// Get stalled task reaper
+    fn stalled_task_reaper(&self) -> Arc<crate::workers::stalled_task_reaper::StalledTaskReaper>;
+    /// Get monitor worker
+    fn monitor_worker(&self) -> Arc<crate::workers::monitor_worker::MonitorWorker>;
     /// Get geo location service
     fn geo_location_service(&self) -> Arc<dyn GeoLocationService>;
     /// Get geo restriction repository
@@ -338,6 +366,14 @@ impl CrawlRsStateExt for CrawlRsState {
         self.expiration_worker.clone()
     }
 
+    fn stalled_task_reaper(&self) -> Arc<crate::workers::stalled_task_reaper::StalledTaskReaper> {
+        self.stalled_task_reaper.clone()
+    }
+
+    fn monitor_worker(&self) -> Arc<crate::workers::monitor_worker::MonitorWorker> {
+        self.monitor_worker.clone()
+    }
+
     fn geo_location_service(&self) -> Arc<dyn GeoLocationService> {
         self.geo_location_service.clone()
     }
@@ -456,6 +492,14 @@ impl CrawlRsStateExt for Arc<CrawlRsState> {
         self.as_ref().expiration_worker()
     }
 
+    fn stalled_task_reaper(&self) -> Arc<crate::workers::stalled_task_reaper::StalledTaskReaper> {
+        self.as_ref().stalled_task_reaper()
+    }
+
+    fn monitor_worker(&self) -> Arc<crate::workers::monitor_worker::MonitorWorker> {
+        self.as_ref().monitor_worker()
+    }
+
     fn geo_location_service(&self) -> Arc<dyn GeoLocationService> {
         self.as_ref().geo_location_service()
     }
@@ -471,7 +515,7 @@ mod tests {
     use crate::common::test_support::testcontainers_fixtures as tcf;
     use crate::di::modules::{
         CacheModule, DatabaseModule, EngineModule, HttpModule, InfrastructureModule,
-        RepositoryModule, ServiceModule, SettingsModule,
+        ReloadableSettingsModule, RepositoryModule, ServiceModule, SettingsModule,
     };
     use std::sync::Arc;
 
@@ -500,6 +544,8 @@ mod tests {
             .map_err(|e| anyhow::anyhow!("register CacheModule: {e}"))?;
         kit.register::<RepositoryModule>()
             .map_err(|e| anyhow::anyhow!("register RepositoryModule: {e}"))?;
+        kit.register::<ReloadableSettingsModule>()
+            .map_err(|e| anyhow::anyhow!("register ReloadableSettingsModule: {e}"))?;
         kit.register::<EngineModule>()
             .map_err(|e| anyhow::anyhow!("register EngineModule: {e}"))?;
         kit.register::<InfrastructureModule>()
@@ -612,6 +658,12 @@ mod tests {
         let expiration_worker = state.expiration_worker();
         assert!(Arc::strong_count(&expiration_worker) >= 2);
 
+        let stalled_task_reaper = state.stalled_task_reaper();
+        assert!(Arc::strong_count(&stalled_task_reaper) >= 2);
+
+        let monitor_worker = state.monitor_worker();
+        assert!(Arc::strong_count(&monitor_worker) >= 2);
+
         let geo_location: Arc<dyn GeoLocationService> = state.geo_location_service();
         assert!(Arc::strong_count(&geo_location) >= 2);
 
@@ -696,6 +748,8 @@ mod tests {
             .map_err(|e| anyhow::anyhow!("register CacheModule: {e}"))?;
         kit.register::<RepositoryModule>()
             .map_err(|e| anyhow::anyhow!("register RepositoryModule: {e}"))?;
+        kit.register::<ReloadableSettingsModule>()
+            .map_err(|e| anyhow::anyhow!("register ReloadableSettingsModule: {e}"))?;
         kit.register::<EngineModule>()
             .map_err(|e| anyhow::anyhow!("register EngineModule: {e}"))?;
         kit.register::<InfrastructureModule>()
@@ -12,11 +12,13 @@
 //!
 //! ```text
 //! SettingsModule (config: Arc<Settings>)
+//!   ├── ReloadableSettingsModule → Arc<ReloadableSettings>
 //!   ├── DatabaseModule → Arc<DatabasePool>
 //!   ├── HttpModule → Arc<reqwest::Client>
 //!   └── CacheModule → CacheComponents
 //!          ├── RepositoryModule → Repositories (depends: DatabaseModule)
-//!          └── EngineModule → EngineComponents (depends: HttpModule, SettingsModule)
+//!          └── EngineModule → EngineComponents
+//!                 (depends: HttpModule, SettingsModule, ReloadableSettingsModule)
 //!                 └── ServiceModule → ServicesComponents (depends: all above)
 //! ```
 
@@ -32,6 +34,7 @@ use trait_kit::TraitKitError;
 use crate::bootstrap::engines::EngineComponents;
 use crate::bootstrap::infrastructure::{InfrastructureComponents, Repositories};
 use crate::bootstrap::services::ServicesComponents;
+use crate::config::reload::ReloadableSettings;
 use crate::config::settings::Settings;
 use crate::infrastructure::database::dbnexus_connection::DatabasePool;
 use crate::infrastructure::oxcache::{ConcurrencyController, SearchCache};
@@ -93,6 +96,12 @@ pub struct CacheComponents {
 /// 从 kit 的 config store 读取预先加载的 Settings。
 pub struct SettingsModule;
 
+/// 可热重载配置模块 — 提供 `Arc<ReloadableSettings>`
+///
+/// 依赖 `SettingsModule`，以启动配置为初始快照，供限流服务、引擎路由器与价格服务读取
+/// 热重载后的配置。
+pub struct ReloadableSettingsModule;
+
 /// 数据库模块 — 提供 `Arc<DatabasePool>`
 ///
 /// 依赖 `SettingsModule`，使用 `init_database()` 创建连接池。
@@ -115,7 +124,8 @@ pub struct RepositoryModule;
 
 /// 引擎模块 — 提供 `EngineComponents`
 ///
-/// 依赖 `HttpModule` 和 `SettingsModule`，创建 EngineRouter + EngineClient。
+/// 依赖 `HttpModule`、`SettingsModule` 和 `ReloadableSettingsModule`，创建 EngineRouter +
+/// EngineClient。
 pub struct EngineModule;
 
 /// 基础设施模块 — 提供 `InfrastructureComponents`
@@ -126,8 +136,8 @@ pub struct InfrastructureModule;
 
 /// 服务模块 — 提供 `ServicesComponents`
 ///
-/// 依赖 `InfrastructureModule`、`EngineModule`、`SettingsModule`，
-/// 创建所有应用服务实例。
+/// 依赖 `InfrastructureModule`、`EngineModule`、`SettingsModule`、
+/// `ReloadableSettingsModule`，创建所有应用服务实例。
 pub struct ServiceModule;
 
 // =============================================================================
@@ -142,6 +152,15 @@ impl ModuleMeta for SettingsModule {
     }
 }
 
+impl ModuleMeta for ReloadableSettingsModule {
+    const NAME: &'static str = "reloadable-settings";
+
+    fn dependencies() -> &'static [(&'static str, TypeId)] {
+        static DEPS: [(&str, TypeId); 1] = [(SettingsModule::NAME, TypeId::of::<SettingsModule>())];
+        &DEPS
+    }
+}
+
 impl ModuleMeta for DatabaseModule {
     const NAME: &'static str = "database";
 
@@ -182,9 +201,13 @@ impl ModuleMeta for EngineModule {
     const NAME: &'static str = "engines";
 
     fn dependencies() -> &'static [(&'static str, TypeId)] {
-        static DEPS: [(&str, TypeId); 2] = [
+        static DEPS: [(&str, TypeId); 3] = [
             (HttpModule::NAME, TypeId::of::<HttpModule>()),
             (SettingsModule::NAME, TypeId::of::<SettingsModule>()),
+            (
+                ReloadableSettingsModule::NAME,
+                TypeId::of::<ReloadableSettingsModule>(),
+            ),
         ];
         &DEPS
     }
@@ -208,13 +231,17 @@ impl ModuleMeta for ServiceModule {
     const NAME: &'static str = "services";
 
     fn dependencies() -> &'static [(&'static str, TypeId)] {
-        static DEPS: [(&str, TypeId); 3] = [
+        static DEPS: [(&str, TypeId); 4] = [
             (
                 InfrastructureModule::NAME,
                 TypeId::of::<InfrastructureModule>(),
             ),
             (EngineModule::NAME, TypeId::of::<EngineModule>()),
             (SettingsModule::NAME, TypeId::of::<SettingsModule>()),
+            (
+                ReloadableSettingsModule::NAME,
+                TypeId::of::<ReloadableSettingsModule>(),
+            ),
         ];
         &DEPS
     }
@@ -248,6 +275,23 @@ impl AsyncAutoBuilder for SettingsModule {
     }
 }
 
+impl AsyncAutoBuilder for ReloadableSettingsModule {
+    type Capability = Arc<ReloadableSettings>;
+    type Error = ModuleBuildError;
+
+    fn build<'a>(
+        kit: &'a AsyncKit,
+    ) -> Pin<Box<dyn Future<Output = Result<Self::Capability, Self::Error>> + Send + 'a>> {
+        Box::pin(async move {
+            let settings = kit.require::<SettingsModule>()?;
+            Ok(Arc::new(
+                ReloadableSettings::new((*settings).clone())
+                    .with_loader(crate::bootstrap::config::load_settings),
+            ))
+        })
+    }
+}
+
 impl AsyncAutoBuilder for DatabaseModule {
     type Capability = Arc<DatabasePool>;
     type Error = ModuleBuildError;
@@ -274,7 +318,10 @@ impl AsyncAutoBuilder for HttpModule {
     ) -> Pin<Box<dyn Future<Output = Result<Self::Capability, Self::Error>> + Send + 'a>> {
         Box::pin(async move {
             let settings = kit.require::<SettingsModule>()?;
-            let client = crate::bootstrap::infrastructure::init_http_client(&settings, None)
+            // 进程内共享 DNS 缓存：主 client 与引擎按请求构建的 client 共用
+            let dns_cache =
+                crate::bootstrap::infrastructure::init_dns_cache_service(&settings).await;
+            let client = crate::bootstrap::infrastructure::init_http_client(&settings, dns_cache)
                 .map_err(|e| ModuleBuildError::HttpInit(e.to_string()))?;
             Ok(client)
         })
@@ -331,6 +378,7 @@ impl AsyncAutoBuilder for EngineModule {
     ) -> Pin<Box<dyn Future<Output = Result<Self::Capability, Self::Error>> + Send + 'a>> {
         Box::pin(async move {
             let settings = kit.require::<SettingsModule>()?;
+            let reloadable_settings = kit.require::<ReloadableSettingsModule>()?;
             let http_client = kit.require::<HttpModule>()?;
             // 仅在 proxy.enabled=true 时传递 Some(proxy_url)，否则传 None（架构 MEDIUM 5：
             // 用 Option<String> 替代空字符串 sentinel，API 语义更明确）。
@@ -341,10 +389,11 @@ impl AsyncAutoBuilder for EngineModule {
             } else {
                 None
             };
-            let engines = crate::bootstrap::engines::init_engine_components(
+            // 路由规则与引擎开关随配置热重载生效
+            let engines = crate::bootstrap::engines::init_reloadable_engine_components(
                 http_client,
                 proxy_url,
-                &settings.engines,
+                reloadable_settings,
                 // 注入 timeout（架构 MEDIUM 2：避免 ReqwestEngine 硬编码 30 秒）
                 settings.timeouts.engines.default_timeout_seconds,
             );
@@ -391,6 +440,7 @@ impl AsyncAutoBuilder for ServiceModule {
     ) -> Pin<Box<dyn Future<Output = Result<Self::Capability, Self::Error>> + Send + 'a>> {
         Box::pin(async move {
             let settings = kit.require::<SettingsModule>()?;
+            let reloadable_settings = kit.require::<ReloadableSettingsModule>()?;
             let infrastructure = kit.require::<InfrastructureModule>()?;
             let engines = kit.require::<EngineModule>()?;
 
@@ -400,6 +450,7 @@ impl AsyncAutoBuilder for ServiceModule {
                 engines.engine_client.clone(),
                 infrastructure.http_client.clone(),
                 &settings,
+                Some(reloadable_settings),
             )
             .await;
 
@@ -479,6 +530,8 @@ mod tests {
             .expect("Failed to register SettingsModule");
         kit.register::<HttpModule>()
             .expect("Failed to register HttpModule");
+        kit.register::<ReloadableSettingsModule>()
+            .expect("Failed to register ReloadableSettingsModule");
         kit.register::<EngineModule>()
             .expect("Failed to register EngineModule");
 
@@ -616,6 +669,7 @@ mod tests {
         kit.register::<HttpModule>().unwrap();
         kit.register::<CacheModule>().unwrap();
         kit.register::<RepositoryModule>().unwrap();
+        kit.register::<ReloadableSettingsModule>().unwrap();
         kit.register::<EngineModule>().unwrap();
         kit.register::<InfrastructureModule>().unwrap();
         kit.register::<ServiceModule>().unwrap();
@@ -651,6 +705,7 @@ mod tests {
         kit.register::<HttpModule>().unwrap();
         kit.register::<CacheModule>().unwrap();
         kit.register::<RepositoryModule>().unwrap();
+        kit.register::<ReloadableSettingsModule>().unwrap();
         kit.register::<EngineModule>().unwrap();
         kit.register::<InfrastructureModule>().unwrap();
         kit.register::<ServiceModule>().unwrap();
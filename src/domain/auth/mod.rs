@@ -44,6 +44,23 @@ pub enum ScopePermission {
     Admin,
 }
 
+/// Role of an API Key, derived from its scope flags
+///
+/// - `read_only`: can read statuses and results, cannot create work, manage
+///   webhooks or see billing
+/// - `member`: can also create scrapes/crawls/searches, manage webhooks and see billing
+/// - `admin`: can also manage keys, teams and limits
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+pub enum ApiKeyRole {
+    /// Read-only access
+    ReadOnly,
+    /// Regular team member
+    Member,
+    /// Administrator
+    Admin,
+}
+
 /// Audit log entry for authentication and authorization decisions
 ///
 /// # 安全提示
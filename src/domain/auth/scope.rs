@@ -5,7 +5,7 @@
 
 //! Implementation of [`ApiKeyScope`] and [`ScopePermission`].
 
-use super::{ApiKeyScope, ScopePermission};
+use super::{ApiKeyRole, ApiKeyScope, ScopePermission};
 
 impl Default for ApiKeyScope {
     fn default() -> Self {
@@ -93,6 +93,27 @@ impl ApiKeyScope {
         }
     }
 
+    /// Role granted by the scope flags
+    pub fn role(&self) -> ApiKeyRole {
+        if self.admin {
+            ApiKeyRole::Admin
+        } else if self.write {
+            ApiKeyRole::Member
+        } else {
+            ApiKeyRole::ReadOnly
+        }
+    }
+
+    /// Replace the permission flags with those of `role`, keeping the rate limits
+    pub fn with_role(self, role: ApiKeyRole) -> Self {
+        Self {
+            read: true,
+            write: role >= ApiKeyRole::Member,
+            admin: role == ApiKeyRole::Admin,
+            ..self
+        }
+    }
+
     /// Check if the scope allows the requested search count
     pub fn allows_search_count(&self, count: u32) -> bool {
         self.search_limit == u32::MAX || count <= self.search_limit
@@ -114,6 +135,27 @@ impl std::fmt::Display for ScopePermission {
     }
 }
 
+impl ApiKeyRole {
+    /// Scope permission a key needs to hold this role
+    pub fn permission(&self) -> ScopePermission {
+        match self {
+            ApiKeyRole::ReadOnly => ScopePermission::Read,
+            ApiKeyRole::Member => ScopePermission::Write,
+            ApiKeyRole::Admin => ScopePermission::Admin,
+        }
+    }
+}
+
+impl std::fmt::Display for ApiKeyRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ApiKeyRole::ReadOnly => write!(f, "read_only"),
+            ApiKeyRole::Member => write!(f, "member"),
+            ApiKeyRole::Admin => write!(f, "admin"),
+        }
+    }
+}
+
 impl From<ScopePermission> for ApiKeyScope {
     fn from(permission: ScopePermission) -> Self {
         match permission {
@@ -196,6 +238,20 @@ mod tests {
         assert_eq!(scope.scrape_limit, 100);
     }
 
+    #[test]
+    fn test_role_round_trips_through_scope_flags() {
+        assert_eq!(ApiKeyScope::default().role(), ApiKeyRole::ReadOnly);
+        assert_eq!(ApiKeyScope::full_access().role(), ApiKeyRole::Admin);
+        for role in [ApiKeyRole::ReadOnly, ApiKeyRole::Member, ApiKeyRole::Admin] {
+            let scope =
+                ApiKeyScope::with_custom_limits(false, false, true, 200, 100).with_role(role);
+            assert_eq!(scope.role(), role);
+            assert!(scope.has_permission(role.permission()));
+            assert_eq!(scope.search_limit, 200);
+            assert_eq!(scope.scrape_limit, 100);
+        }
+    }
+
     #[test]
     fn test_has_permission_read() {
         let read_only = ApiKeyScope::read_only();
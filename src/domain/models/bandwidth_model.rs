@@ -0,0 +1,86 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! Bandwidth model - bytes downloaded per team and UTC day
+//!
+//! Workers count the body bytes of every engine response into live counters,
+//! which are rolled up into one row per team and day. Counters only grow, so
+//! a stored row and a live reading of the same day are merged by keeping the
+//! larger values.
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+/// `failure_reason` of tasks rejected because the team's daily bandwidth cap is used up
+pub const BANDWIDTH_CAP_FAILURE_REASON: &str = "bandwidth_cap_exceeded";
+
+/// Bytes downloaded by a team during one UTC day
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub struct DailyBandwidthUsage {
+    /// UTC day
+    pub date: NaiveDate,
+    /// Response body bytes downloaded during the day
+    pub bytes_downloaded: u64,
+    /// Number of engine responses counted during the day
+    pub requests: u64,
+}
+
+impl DailyBandwidthUsage {
+    /// Usage of a day without any downloads
+    pub fn empty(date: NaiveDate) -> Self {
+        Self {
+            date,
+            bytes_downloaded: 0,
+            requests: 0,
+        }
+    }
+
+    /// Merge another reading of the same day, keeping the larger counts
+    pub fn merge(&mut self, other: &Self) {
+        self.bytes_downloaded = self.bytes_downloaded.max(other.bytes_downloaded);
+        self.requests = self.requests.max(other.requests);
+    }
+
+    /// Merge live readings into stored days, ordered by date
+    pub fn merge_days(stored: Vec<Self>, live: impl IntoIterator<Item = Self>) -> Vec<Self> {
+        let mut days: std::collections::BTreeMap<NaiveDate, Self> =
+            stored.into_iter().map(|day| (day.date, day)).collect();
+        for reading in live {
+            days.entry(reading.date)
+                .and_modify(|day| day.merge(&reading))
+                .or_insert(reading);
+        }
+        days.into_values().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn day(date: &str, bytes_downloaded: u64, requests: u64) -> DailyBandwidthUsage {
+        DailyBandwidthUsage {
+            date: date.parse().unwrap(),
+            bytes_downloaded,
+            requests,
+        }
+    }
+
+    #[test]
+    fn test_merge_days_keeps_larger_readings() {
+        let stored = vec![day("2025-03-02", 500, 5), day("2025-03-01", 100, 1)];
+        let live = vec![day("2025-03-02", 800, 4), day("2025-03-03", 10, 1)];
+
+        let days = DailyBandwidthUsage::merge_days(stored, live);
+        assert_eq!(
+            days,
+            vec![
+                day("2025-03-01", 100, 1),
+                day("2025-03-02", 800, 5),
+                day("2025-03-03", 10, 1),
+            ]
+        );
+    }
+}
@@ -0,0 +1,52 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! Crawl link model - an edge of the link graph discovered while crawling
+//!
+//! Every crawled HTML page records the links that fall within the crawl scope
+//! (include/exclude patterns, blocklist), so pages skipped because of depth or
+//! budget limits still appear as link targets. Only the first link between two
+//! pages is kept.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// A link from one page to another within a crawl
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CrawlLink {
+    /// Crawl the link was discovered in
+    pub crawl_id: Uuid,
+    /// Page containing the link
+    pub source_url: String,
+    /// Canonical URL the link points to
+    pub target_url: String,
+    /// Anchor text of the link (alt text for images)
+    pub anchor_text: Option<String>,
+    /// `rel` values of the link, e.g. `nofollow`
+    pub rel: Vec<String>,
+    /// When the link was first discovered
+    pub created_at: DateTime<Utc>,
+}
+
+impl CrawlLink {
+    /// Create a link discovered now
+    pub fn new(
+        crawl_id: Uuid,
+        source_url: impl Into<String>,
+        target_url: impl Into<String>,
+        anchor_text: Option<String>,
+        rel: Vec<String>,
+    ) -> Self {
+        Self {
+            crawl_id,
+            source_url: source_url.into(),
+            target_url: target_url.into(),
+            anchor_text,
+            rel,
+            created_at: Utc::now(),
+        }
+    }
+}
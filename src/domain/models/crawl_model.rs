@@ -142,10 +142,56 @@ impl Crawl {
     pub fn is_finished(&self) -> bool {
         matches!(
             self.status,
-            CrawlStatus::Completed | CrawlStatus::Failed | CrawlStatus::Cancelled
+            CrawlStatus::Completed
+                | CrawlStatus::CompletedWithLimit
+                | CrawlStatus::Failed
+                | CrawlStatus::Cancelled
         )
     }
 
+    /// Maximum number of pages (`limit` in the crawl config)
+    pub fn page_limit(&self) -> Option<u32> {
+        self.config
+            .get("limit")
+            .and_then(serde_json::Value::as_u64)
+            .map(|limit| limit.min(u32::MAX as u64) as u32)
+    }
+
+    /// Maximum wall-clock duration (`max_duration_seconds` in the crawl config)
+    pub fn max_duration(&self) -> Option<chrono::Duration> {
+        self.config
+            .get("max_duration_seconds")
+            .and_then(serde_json::Value::as_u64)
+            .map(|secs| chrono::Duration::seconds(secs.min(i64::MAX as u64) as i64))
+    }
+
+    /// Previous crawl this crawl re-crawls incrementally, read from the crawl config
+    pub fn previous_crawl_id(&self) -> Option<Uuid> {
+        self.config
+            .get("previous_crawl_id")
+            .and_then(serde_json::Value::as_str)
+            .and_then(|id| Uuid::parse_str(id).ok())
+    }
+
+    /// Number of pages that can still be queued under the page budget
+    ///
+    /// Returns `None` when the crawl has no page limit.
+    pub fn remaining_page_budget(&self) -> Option<usize> {
+        self.page_limit()
+            .map(|limit| (limit as i64 - self.total_tasks as i64).max(0) as usize)
+    }
+
+    /// Whether the page budget has been used up
+    pub fn page_limit_reached(&self) -> bool {
+        self.remaining_page_budget() == Some(0)
+    }
+
+    /// Whether the crawl has run longer than its time budget
+    pub fn duration_exceeded(&self, now: DateTime<Utc>) -> bool {
+        self.max_duration()
+            .is_some_and(|max_duration| now - self.created_at >= max_duration)
+    }
+
     /// Start the crawl
     pub fn start(&mut self) {
         self.status = CrawlStatus::Processing;
@@ -166,6 +212,13 @@ impl Crawl {
         self.updated_at = Utc::now();
     }
 
+    /// Complete the crawl because a page or time budget was exhausted
+    pub fn complete_with_limit(&mut self) {
+        self.status = CrawlStatus::CompletedWithLimit;
+        self.completed_at = Some(Utc::now());
+        self.updated_at = Utc::now();
+    }
+
     /// Cancel the crawl
     pub fn cancel(&mut self) {
         self.status = CrawlStatus::Cancelled;
@@ -196,6 +249,119 @@ impl Crawl {
         self.total_tasks = count;
         self.updated_at = Utc::now();
     }
+
+    /// Build the terminal summary for this crawl
+    pub fn summary(&self, finished_at: DateTime<Utc>, credits_consumed: i64) -> CrawlSummary {
+        CrawlSummary {
+            crawl_id: self.id,
+            team_id: self.team_id,
+            root_url: self.root_url.clone(),
+            status: self.status,
+            total_pages: self.total_tasks,
+            succeeded_pages: self.completed_tasks,
+            failed_pages: self.failed_tasks,
+            changed_pages: self.completed_tasks,
+            unchanged_pages: 0,
+            duration_ms: (finished_at - self.created_at).num_milliseconds().max(0),
+            credits_consumed,
+            results_url: format!("/v1/crawl/{}/results", self.id),
+            started_at: self.created_at,
+            finished_at,
+            latency: None,
+        }
+    }
+}
+
+/// Response time percentiles of a crawl's pages, in milliseconds
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub struct LatencyPercentiles {
+    /// Number of pages measured
+    pub samples: usize,
+    /// Median response time
+    pub p50_ms: i64,
+    /// 90th percentile
+    pub p90_ms: i64,
+    /// 95th percentile
+    pub p95_ms: i64,
+    /// 99th percentile
+    pub p99_ms: i64,
+    /// Slowest page
+    pub max_ms: i64,
+}
+
+impl LatencyPercentiles {
+    /// Nearest-rank percentiles of the given response times; `None` when there are none
+    pub fn from_samples(mut samples: Vec<i64>) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+        samples.sort_unstable();
+        let rank = |percentile: usize| {
+            let index = (percentile * samples.len()).div_ceil(100).max(1) - 1;
+            samples[index.min(samples.len() - 1)]
+        };
+        Some(Self {
+            samples: samples.len(),
+            p50_ms: rank(50),
+            p90_ms: rank(90),
+            p95_ms: rank(95),
+            p99_ms: rank(99),
+            max_ms: samples[samples.len() - 1],
+        })
+    }
+}
+
+/// Aggregated statistics for a finished crawl
+///
+/// Sent as the `crawl.summary` webhook payload.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CrawlSummary {
+    /// Crawl ID
+    pub crawl_id: Uuid,
+    /// Team ID
+    pub team_id: Uuid,
+    /// Root URL of the crawl
+    pub root_url: String,
+    /// Final status
+    pub status: CrawlStatus,
+    /// Total number of pages (sub-tasks)
+    pub total_pages: i32,
+    /// Pages scraped successfully
+    pub succeeded_pages: i32,
+    /// Pages that failed
+    pub failed_pages: i32,
+    /// Succeeded pages whose content changed since the previous crawl
+    pub changed_pages: i32,
+    /// Succeeded pages answered with `304 Not Modified` on an incremental re-crawl
+    pub unchanged_pages: i32,
+    /// Wall-clock duration from creation to completion
+    pub duration_ms: i64,
+    /// Credits consumed by the crawl and its sub-tasks
+    pub credits_consumed: i64,
+    /// Path of the paginated results endpoint
+    pub results_url: String,
+    /// When the crawl was created
+    pub started_at: DateTime<Utc>,
+    /// When the crawl finished
+    pub finished_at: DateTime<Utc>,
+    /// Response time percentiles of the succeeded pages
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latency: Option<LatencyPercentiles>,
+}
+
+impl CrawlSummary {
+    /// Split succeeded pages into changed and unchanged ones
+    pub fn with_unchanged_pages(mut self, unchanged_pages: i32) -> Self {
+        self.unchanged_pages = unchanged_pages.clamp(0, self.succeeded_pages.max(0));
+        self.changed_pages = self.succeeded_pages - self.unchanged_pages;
+        self
+    }
+
+    /// Attach response time percentiles computed from the pages' response times
+    pub fn with_latency(mut self, response_times: Vec<i64>) -> Self {
+        self.latency = LatencyPercentiles::from_samples(response_times);
+        self
+    }
 }
 
 /// Crawl status enumeration
@@ -209,6 +375,8 @@ pub enum CrawlStatus {
     Processing,
     /// Completed successfully
     Completed,
+    /// Stopped after exhausting its page or time budget
+    CompletedWithLimit,
     /// Failed
     Failed,
     /// Cancelled
@@ -221,6 +389,7 @@ impl fmt::Display for CrawlStatus {
             CrawlStatus::Queued => write!(f, "queued"),
             CrawlStatus::Processing => write!(f, "processing"),
             CrawlStatus::Completed => write!(f, "completed"),
+            CrawlStatus::CompletedWithLimit => write!(f, "completed_with_limit"),
             CrawlStatus::Failed => write!(f, "failed"),
             CrawlStatus::Cancelled => write!(f, "cancelled"),
         }
@@ -235,6 +404,7 @@ impl FromStr for CrawlStatus {
             "queued" => Ok(CrawlStatus::Queued),
             "processing" => Ok(CrawlStatus::Processing),
             "completed" => Ok(CrawlStatus::Completed),
+            "completed_with_limit" => Ok(CrawlStatus::CompletedWithLimit),
             "failed" => Ok(CrawlStatus::Failed),
             "cancelled" => Ok(CrawlStatus::Cancelled),
             _ => Err(format!("Invalid crawl status: {}", s)),
@@ -247,6 +417,88 @@ mod tests {
     use super::*;
     use std::str::FromStr;
 
+    // ========== Crawl::summary tests ==========
+
+    #[test]
+    fn test_crawl_summary_aggregates_counts_and_duration() {
+        let id = Uuid::new_v4();
+        let created_at = Utc::now() - chrono::Duration::seconds(90);
+        let crawl = Crawl::with_all_fields(
+            id,
+            Uuid::new_v4(),
+            "summary".to_string(),
+            "https://example.com".to_string(),
+            "https://example.com".to_string(),
+            CrawlStatus::Completed,
+            serde_json::json!({}),
+            10,
+            8,
+            2,
+            created_at,
+            created_at,
+            None,
+        );
+        let finished_at = created_at + chrono::Duration::seconds(90);
+
+        let summary = crawl.summary(finished_at, 42);
+
+        assert_eq!(summary.crawl_id, id);
+        assert_eq!(summary.total_pages, 10);
+        assert_eq!(summary.succeeded_pages, 8);
+        assert_eq!(summary.failed_pages, 2);
+        assert_eq!(summary.duration_ms, 90_000);
+        assert_eq!(summary.credits_consumed, 42);
+        assert_eq!(summary.results_url, format!("/v1/crawl/{}/results", id));
+        assert_eq!(summary.status, CrawlStatus::Completed);
+        assert_eq!(summary.changed_pages, 8);
+        assert_eq!(summary.unchanged_pages, 0);
+
+        let summary = summary.with_unchanged_pages(3);
+        assert_eq!(summary.changed_pages, 5);
+        assert_eq!(summary.unchanged_pages, 3);
+    }
+
+    #[test]
+    fn test_latency_percentiles_nearest_rank() {
+        assert!(LatencyPercentiles::from_samples(Vec::new()).is_none());
+
+        let samples: Vec<i64> = (1..=100).rev().map(|n| n * 10).collect();
+        let latency = LatencyPercentiles::from_samples(samples).unwrap();
+        assert_eq!(latency.samples, 100);
+        assert_eq!(latency.p50_ms, 500);
+        assert_eq!(latency.p90_ms, 900);
+        assert_eq!(latency.p95_ms, 950);
+        assert_eq!(latency.p99_ms, 990);
+        assert_eq!(latency.max_ms, 1000);
+
+        let single = LatencyPercentiles::from_samples(vec![42]).unwrap();
+        assert_eq!((single.p50_ms, single.p99_ms, single.max_ms), (42, 42, 42));
+    }
+
+    #[test]
+    fn test_previous_crawl_id_reads_config() {
+        let previous_id = Uuid::new_v4();
+        let crawl = Crawl::new(
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            "recrawl".to_string(),
+            "https://example.com".to_string(),
+            "https://example.com".to_string(),
+            serde_json::json!({"previous_crawl_id": previous_id}),
+        );
+        assert_eq!(crawl.previous_crawl_id(), Some(previous_id));
+
+        let crawl = Crawl::new(
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            "fresh".to_string(),
+            "https://example.com".to_string(),
+            "https://example.com".to_string(),
+            serde_json::json!({"previous_crawl_id": null}),
+        );
+        assert_eq!(crawl.previous_crawl_id(), None);
+    }
+
     // ========== Crawl::new tests ==========
 
     #[test]
@@ -379,6 +631,7 @@ mod tests {
         let mut crawl = make_crawl();
         for status in [
             CrawlStatus::Completed,
+            CrawlStatus::CompletedWithLimit,
             CrawlStatus::Failed,
             CrawlStatus::Cancelled,
         ] {
@@ -451,6 +704,66 @@ mod tests {
         assert!(crawl.updated_at >= before);
     }
 
+    #[test]
+    fn test_complete_with_limit_sets_status_and_completed_at() {
+        let mut crawl = make_crawl();
+        crawl.complete_with_limit();
+
+        assert_eq!(crawl.status, CrawlStatus::CompletedWithLimit);
+        assert!(crawl.completed_at.is_some());
+        assert!(crawl.is_finished());
+    }
+
+    // ========== page / time budget ==========
+
+    fn make_budget_crawl(config: serde_json::Value, total_tasks: i32) -> Crawl {
+        Crawl::with_all_fields(
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            "Budget".to_string(),
+            "https://example.com".to_string(),
+            "https://example.com".to_string(),
+            CrawlStatus::Processing,
+            config,
+            total_tasks,
+            0,
+            0,
+            Utc::now() - chrono::Duration::seconds(30),
+            Utc::now(),
+            None,
+        )
+    }
+
+    #[test]
+    fn test_page_budget() {
+        let unlimited = make_budget_crawl(serde_json::json!({"max_depth": 2}), 100);
+        assert_eq!(unlimited.page_limit(), None);
+        assert_eq!(unlimited.remaining_page_budget(), None);
+        assert!(!unlimited.page_limit_reached());
+
+        let partial = make_budget_crawl(serde_json::json!({"limit": 10}), 4);
+        assert_eq!(partial.remaining_page_budget(), Some(6));
+        assert!(!partial.page_limit_reached());
+
+        let exhausted = make_budget_crawl(serde_json::json!({"limit": 10}), 12);
+        assert_eq!(exhausted.remaining_page_budget(), Some(0));
+        assert!(exhausted.page_limit_reached());
+    }
+
+    #[test]
+    fn test_duration_budget() {
+        let now = Utc::now();
+        let unlimited = make_budget_crawl(serde_json::json!({}), 1);
+        assert!(!unlimited.duration_exceeded(now));
+
+        let within = make_budget_crawl(serde_json::json!({"max_duration_seconds": 60}), 1);
+        assert_eq!(within.max_duration(), Some(chrono::Duration::seconds(60)));
+        assert!(!within.duration_exceeded(now));
+
+        let exceeded = make_budget_crawl(serde_json::json!({"max_duration_seconds": 10}), 1);
+        assert!(exceeded.duration_exceeded(now));
+    }
+
     // ========== increment / set task counters ==========
 
     #[test]
@@ -502,6 +815,10 @@ mod tests {
         assert_eq!(CrawlStatus::Queued.to_string(), "queued");
         assert_eq!(CrawlStatus::Processing.to_string(), "processing");
         assert_eq!(CrawlStatus::Completed.to_string(), "completed");
+        assert_eq!(
+            CrawlStatus::CompletedWithLimit.to_string(),
+            "completed_with_limit"
+        );
         assert_eq!(CrawlStatus::Failed.to_string(), "failed");
         assert_eq!(CrawlStatus::Cancelled.to_string(), "cancelled");
     }
@@ -520,6 +837,10 @@ mod tests {
             CrawlStatus::from_str("completed").expect("valid"),
             CrawlStatus::Completed
         );
+        assert_eq!(
+            CrawlStatus::from_str("completed_with_limit").expect("valid"),
+            CrawlStatus::CompletedWithLimit
+        );
         assert_eq!(
             CrawlStatus::from_str("failed").expect("valid"),
             CrawlStatus::Failed
@@ -555,6 +876,7 @@ mod tests {
             CrawlStatus::Queued,
             CrawlStatus::Processing,
             CrawlStatus::Completed,
+            CrawlStatus::CompletedWithLimit,
             CrawlStatus::Failed,
             CrawlStatus::Cancelled,
         ] {
@@ -8,7 +8,7 @@
 //! This module contains the pure domain model for Credits,
 //! following Domain-Driven Design principles.
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
@@ -228,6 +228,98 @@ impl std::str::FromStr for CreditsTransactionType {
     }
 }
 
+/// Credits movement aggregated over one UTC day
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct DailyCreditsUsage {
+    /// UTC day
+    pub date: NaiveDate,
+    /// Credits deducted during the day
+    pub credits_used: i64,
+    /// Credits added during the day
+    pub credits_added: i64,
+    /// Number of transactions during the day
+    pub transactions: u64,
+}
+
+impl DailyCreditsUsage {
+    /// Aggregate transactions per UTC day, ordered by date
+    pub fn aggregate<'a>(
+        transactions: impl IntoIterator<Item = &'a CreditsTransaction>,
+    ) -> Vec<Self> {
+        let mut days: std::collections::BTreeMap<NaiveDate, Self> =
+            std::collections::BTreeMap::new();
+        for transaction in transactions {
+            let date = transaction.created_at.date_naive();
+            let day = days.entry(date).or_insert(Self {
+                date,
+                credits_used: 0,
+                credits_added: 0,
+                transactions: 0,
+            });
+            if transaction.is_deduction() {
+                day.credits_used -= transaction.amount;
+            } else {
+                day.credits_added += transaction.amount;
+            }
+            day.transactions += 1;
+        }
+        days.into_values().collect()
+    }
+}
+
+/// Low-balance alert configured for a team
+///
+/// The alert fires once when a deduction leaves the balance below `threshold`
+/// and re-arms only after the balance climbs back to [`Self::rearm_balance`],
+/// so a balance hovering around the threshold does not alert on every request.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct LowBalanceAlert {
+    /// Team the alert belongs to
+    pub team_id: Uuid,
+    /// Balance below which the alert fires
+    pub threshold: i64,
+    /// Optional email address notified alongside the `credits.low` webhook event
+    pub email: Option<String>,
+    /// Whether the alert has fired and not yet re-armed
+    pub alerted: bool,
+    /// When the alert last fired
+    pub alerted_at: Option<DateTime<Utc>>,
+}
+
+/// State change of a low-balance alert after a balance update
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LowBalanceTransition {
+    /// Balance dropped below the threshold while armed: notify
+    Triggered,
+    /// Balance recovered past the re-arm point: the next drop notifies again
+    Rearmed,
+}
+
+impl LowBalanceAlert {
+    /// Balance the team must reach before the alert re-arms
+    ///
+    /// `threshold` plus `rearm_margin_percent` of it, and always above the threshold.
+    pub fn rearm_balance(&self, rearm_margin_percent: u32) -> i64 {
+        let margin = self.threshold.saturating_mul(rearm_margin_percent as i64) / 100;
+        self.threshold.saturating_add(margin.max(1))
+    }
+
+    /// State change caused by the balance becoming `balance`, if any
+    pub fn transition(
+        &self,
+        balance: i64,
+        rearm_margin_percent: u32,
+    ) -> Option<LowBalanceTransition> {
+        if !self.alerted && balance < self.threshold {
+            Some(LowBalanceTransition::Triggered)
+        } else if self.alerted && balance >= self.rearm_balance(rearm_margin_percent) {
+            Some(LowBalanceTransition::Rearmed)
+        } else {
+            None
+        }
+    }
+}
+
 /// Credits domain errors
 #[derive(Debug, thiserror::Error)]
 pub enum CreditsError {
@@ -703,4 +795,68 @@ mod tests {
         let back: CreditsTransaction = serde_json::from_str(&json).expect("deserialize");
         assert_eq!(txn, back, "serde roundtrip should preserve transaction");
     }
+
+    // ========== DailyCreditsUsage::aggregate tests ==========
+
+    #[test]
+    fn test_daily_usage_aggregates_per_utc_day() {
+        let team_id = Uuid::new_v4();
+        let at = |value: &str| value.parse::<DateTime<Utc>>().unwrap();
+        let transaction = |amount: i64, created_at: DateTime<Utc>| {
+            CreditsTransaction::with_timestamp(
+                Uuid::new_v4(),
+                team_id,
+                amount,
+                CreditsTransactionType::Scrape,
+                "test".to_string(),
+                None,
+                created_at,
+            )
+        };
+        let transactions = vec![
+            transaction(-10, at("2025-03-02T08:00:00Z")),
+            transaction(-1, at("2025-03-01T23:59:59Z")),
+            transaction(-2, at("2025-03-01T00:00:00Z")),
+            transaction(500, at("2025-03-01T12:00:00Z")),
+        ];
+
+        let days = DailyCreditsUsage::aggregate(&transactions);
+        assert_eq!(days.len(), 2);
+        assert_eq!(days[0].date, NaiveDate::from_ymd_opt(2025, 3, 1).unwrap());
+        assert_eq!(days[0].credits_used, 3);
+        assert_eq!(days[0].credits_added, 500);
+        assert_eq!(days[0].transactions, 3);
+        assert_eq!(days[1].credits_used, 10);
+        assert_eq!(days[1].transactions, 1);
+    }
+
+    // ========== LowBalanceAlert tests ==========
+
+    #[test]
+    fn test_low_balance_alert_fires_once_and_rearms_with_margin() {
+        let mut alert = LowBalanceAlert {
+            team_id: Uuid::new_v4(),
+            threshold: 100,
+            email: None,
+            alerted: false,
+            alerted_at: None,
+        };
+        assert_eq!(alert.transition(150, 10), None);
+        assert_eq!(
+            alert.transition(99, 10),
+            Some(LowBalanceTransition::Triggered)
+        );
+
+        alert.alerted = true;
+        assert_eq!(alert.transition(50, 10), None);
+        assert_eq!(alert.transition(105, 10), None);
+        assert_eq!(alert.rearm_balance(10), 110);
+        assert_eq!(
+            alert.transition(110, 10),
+            Some(LowBalanceTransition::Rearmed)
+        );
+
+        alert.threshold = 5;
+        assert_eq!(alert.rearm_balance(0), 6);
+    }
 }
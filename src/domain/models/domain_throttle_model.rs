@@ -0,0 +1,285 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! Domain throttle model - adaptive per-domain backoff state
+//!
+//! When a target site answers with 429/503, dispatch for that domain is
+//! deferred with exponential backoff. The state is shared between workers
+//! (and the API, which reports it in crawl status) through the repository.
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+/// Backoff policy for adaptive domain throttling
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ThrottlePolicy {
+    /// Delay applied after the first throttling response (seconds)
+    pub base_delay_secs: u64,
+    /// Upper bound for the backoff delay (seconds); reaching it pauses the domain
+    pub max_delay_secs: u64,
+    /// Error streak is forgotten when the last error is older than this (seconds)
+    pub reset_after_secs: u64,
+}
+
+impl Default for ThrottlePolicy {
+    fn default() -> Self {
+        Self {
+            base_delay_secs: 2,
+            max_delay_secs: 300,
+            reset_after_secs: 600,
+        }
+    }
+}
+
+/// Adaptive throttle state for a single domain
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DomainThrottle {
+    /// Lower-cased host name
+    pub domain: String,
+    /// Number of consecutive throttling responses
+    pub consecutive_errors: u32,
+    /// Last throttling status code (429 or 503)
+    pub last_status: u16,
+    /// Dispatch for the domain is deferred until this instant
+    pub throttled_until: DateTime<Utc>,
+    /// When the state was last updated
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Throttle state as reported by the crawl status API
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DomainThrottleStatus {
+    /// Throttled domain
+    pub domain: String,
+    /// Number of consecutive throttling responses
+    pub consecutive_errors: u32,
+    /// Last throttling status code
+    pub last_status: u16,
+    /// Dispatch resumes at this instant
+    pub throttled_until: DateTime<Utc>,
+    /// Seconds until dispatch resumes
+    pub retry_in_seconds: i64,
+    /// Whether the backoff reached its maximum (domain paused)
+    pub paused: bool,
+}
+
+impl DomainThrottle {
+    /// Create an empty (inactive) throttle state for a domain
+    pub fn new(domain: impl Into<String>) -> Self {
+        let now = Utc::now();
+        Self {
+            domain: domain.into(),
+            consecutive_errors: 0,
+            last_status: 0,
+            throttled_until: now,
+            updated_at: now,
+        }
+    }
+
+    /// Whether an upstream status code signals that the site is throttling us
+    pub fn is_throttle_status(status: u16) -> bool {
+        matches!(status, 429 | 503)
+    }
+
+    /// Extract the throttling key (lower-cased host) from a URL
+    pub fn domain_of(url: &str) -> Option<String> {
+        Url::parse(url)
+            .ok()?
+            .host_str()
+            .map(|host| host.to_ascii_lowercase())
+    }
+
+    /// Record a throttling response and extend the backoff window
+    ///
+    /// The delay doubles with each consecutive error, starting at
+    /// `policy.base_delay_secs`, and honours a larger `Retry-After` from the
+    /// upstream. Both are capped at `policy.max_delay_secs`. Returns the
+    /// applied delay.
+    pub fn record_error(
+        &mut self,
+        status: u16,
+        retry_after_secs: Option<u64>,
+        policy: &ThrottlePolicy,
+        now: DateTime<Utc>,
+    ) -> Duration {
+        if self.consecutive_errors > 0
+            && now - self.updated_at > Duration::seconds(policy.reset_after_secs as i64)
+        {
+            self.consecutive_errors = 0;
+        }
+        self.consecutive_errors = self.consecutive_errors.saturating_add(1);
+
+        let exponent = (self.consecutive_errors - 1).min(32);
+        let backoff = policy.base_delay_secs.saturating_mul(1u64 << exponent);
+        let delay_secs = backoff
+            .max(retry_after_secs.unwrap_or(0))
+            .min(policy.max_delay_secs);
+        let delay = Duration::seconds(delay_secs as i64);
+
+        self.last_status = status;
+        self.throttled_until = self.throttled_until.max(now + delay);
+        self.updated_at = now;
+        delay
+    }
+
+    /// Whether dispatch is currently deferred
+    pub fn is_active(&self, now: DateTime<Utc>) -> bool {
+        self.throttled_until > now
+    }
+
+    /// Report the throttle state if it is currently active
+    pub fn status(
+        &self,
+        policy: &ThrottlePolicy,
+        now: DateTime<Utc>,
+    ) -> Option<DomainThrottleStatus> {
+        if !self.is_active(now) {
+            return None;
+        }
+        let retry_in = self.throttled_until - now;
+        Some(DomainThrottleStatus {
+            domain: self.domain.clone(),
+            consecutive_errors: self.consecutive_errors,
+            last_status: self.last_status,
+            throttled_until: self.throttled_until,
+            retry_in_seconds: (retry_in.num_milliseconds() + 999) / 1000,
+            paused: self.throttled_until - self.updated_at
+                >= Duration::seconds(policy.max_delay_secs as i64),
+        })
+    }
+}
+
+/// Parse a `Retry-After` header value (delta-seconds or HTTP-date) into seconds
+pub fn parse_retry_after(value: &str, now: DateTime<Utc>) -> Option<u64> {
+    let value = value.trim();
+    if let Ok(secs) = value.parse::<u64>() {
+        return Some(secs);
+    }
+    let at = DateTime::parse_from_rfc2822(value)
+        .ok()?
+        .with_timezone(&Utc);
+    Some((at - now).num_seconds().max(0) as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn t0() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap()
+    }
+
+    /// Inactive state stamped at `t0()` instead of the wall clock
+    fn throttle_at_t0() -> DomainThrottle {
+        DomainThrottle {
+            throttled_until: t0(),
+            updated_at: t0(),
+            ..DomainThrottle::new("example.com")
+        }
+    }
+
+    #[test]
+    fn test_is_throttle_status() {
+        assert!(DomainThrottle::is_throttle_status(429));
+        assert!(DomainThrottle::is_throttle_status(503));
+        assert!(!DomainThrottle::is_throttle_status(500));
+        assert!(!DomainThrottle::is_throttle_status(200));
+    }
+
+    #[test]
+    fn test_domain_of_lowercases_host() {
+        assert_eq!(
+            DomainThrottle::domain_of("https://Example.COM/path?q=1"),
+            Some("example.com".to_string())
+        );
+        assert_eq!(DomainThrottle::domain_of("not a url"), None);
+    }
+
+    #[test]
+    fn test_record_error_backs_off_exponentially_up_to_max() {
+        let policy = ThrottlePolicy::default();
+        let mut throttle = throttle_at_t0();
+        let now = t0();
+
+        let delays: Vec<i64> = (0..10)
+            .map(|_| throttle.record_error(429, None, &policy, now).num_seconds())
+            .collect();
+
+        assert_eq!(&delays[..5], &[2, 4, 8, 16, 32]);
+        assert_eq!(*delays.last().unwrap(), 300);
+        assert_eq!(throttle.consecutive_errors, 10);
+        assert_eq!(throttle.throttled_until, now + Duration::seconds(300));
+    }
+
+    #[test]
+    fn test_record_error_honours_larger_retry_after() {
+        let policy = ThrottlePolicy::default();
+        let mut throttle = throttle_at_t0();
+        let delay = throttle.record_error(503, Some(120), &policy, t0());
+        assert_eq!(delay.num_seconds(), 120);
+        assert_eq!(throttle.last_status, 503);
+
+        // Retry-After is capped by the policy maximum
+        let delay = throttle.record_error(503, Some(3600), &policy, t0());
+        assert_eq!(delay.num_seconds(), 300);
+    }
+
+    #[test]
+    fn test_record_error_resets_streak_after_quiet_period() {
+        let policy = ThrottlePolicy::default();
+        let mut throttle = throttle_at_t0();
+        throttle.record_error(429, None, &policy, t0());
+        throttle.record_error(429, None, &policy, t0());
+        assert_eq!(throttle.consecutive_errors, 2);
+
+        let later = t0() + Duration::seconds(policy.reset_after_secs as i64 + 1);
+        let delay = throttle.record_error(429, None, &policy, later);
+        assert_eq!(throttle.consecutive_errors, 1);
+        assert_eq!(delay.num_seconds(), policy.base_delay_secs as i64);
+    }
+
+    #[test]
+    fn test_status_reports_active_throttle_only() {
+        let policy = ThrottlePolicy::default();
+        let mut throttle = throttle_at_t0();
+        assert!(throttle.status(&policy, t0()).is_none());
+
+        throttle.record_error(429, None, &policy, t0());
+        let status = throttle
+            .status(&policy, t0() + Duration::milliseconds(500))
+            .unwrap();
+        assert_eq!(status.domain, "example.com");
+        assert_eq!(status.retry_in_seconds, 2);
+        assert!(!status.paused);
+
+        assert!(throttle
+            .status(&policy, t0() + Duration::seconds(2))
+            .is_none());
+    }
+
+    #[test]
+    fn test_status_paused_when_backoff_hits_max() {
+        let policy = ThrottlePolicy::default();
+        let mut throttle = throttle_at_t0();
+        throttle.record_error(429, Some(policy.max_delay_secs), &policy, t0());
+        assert!(throttle.status(&policy, t0()).unwrap().paused);
+    }
+
+    #[test]
+    fn test_parse_retry_after() {
+        assert_eq!(parse_retry_after("120", t0()), Some(120));
+        assert_eq!(
+            parse_retry_after("Wed, 01 Jan 2025 00:01:30 GMT", t0()),
+            Some(90)
+        );
+        assert_eq!(
+            parse_retry_after("Tue, 31 Dec 2024 23:00:00 GMT", t0()),
+            Some(0)
+        );
+        assert_eq!(parse_retry_after("soon", t0()), None);
+    }
+}
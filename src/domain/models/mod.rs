@@ -14,10 +14,22 @@
 /// - *_model.rs: 纯领域模型（无 ORM 注解）
 /// - *_domain.rs: 领域业务逻辑（枚举、错误类型）
 // Pure domain models (no ORM annotations)
+pub mod bandwidth_model;
+pub mod crawl_link_model;
 pub mod crawl_model;
 pub mod credits_model;
+pub mod domain_throttle_model;
+pub mod monitor_model;
+pub mod notification_channel_model;
+pub mod notification_contacts_model;
+pub mod resource_tag_model;
+pub mod result_destination_model;
+pub mod sso_model;
+pub mod task_event_model;
 pub mod task_model;
+pub mod team_member_model;
 pub mod team_model;
+pub mod url_blocklist_model;
 pub mod webhook_model;
 
 // Domain types (enums, errors)
@@ -29,12 +41,32 @@ pub mod scrape_result_entity;
 pub mod search_result;
 
 // Re-export pure domain models
-pub use crawl_model::{Crawl, CrawlStatus};
-pub use credits_model::{Credits, CreditsError, CreditsTransaction, CreditsTransactionType};
-pub use task_domain::{DomainError, TaskStatus, TaskType};
+pub use bandwidth_model::{DailyBandwidthUsage, BANDWIDTH_CAP_FAILURE_REASON};
+pub use crawl_link_model::CrawlLink;
+pub use crawl_model::{Crawl, CrawlStatus, CrawlSummary, LatencyPercentiles};
+pub use credits_model::{
+    Credits, CreditsError, CreditsTransaction, CreditsTransactionType, DailyCreditsUsage,
+    LowBalanceAlert, LowBalanceTransition,
+};
+pub use domain_throttle_model::{DomainThrottle, DomainThrottleStatus, ThrottlePolicy};
+pub use monitor_model::{Monitor, MonitorChange, MonitorMode};
+pub use notification_channel_model::{ChannelKind, NotificationChannel};
+pub use notification_contacts_model::NotificationContacts;
+pub use resource_tag_model::{normalize_tag, ResourceTags, TaggedResource};
+pub use result_destination_model::ResultDestination;
+pub use sso_model::{OidcLoginState, SsoSession};
+pub use task_domain::{DomainError, PriorityTier, TaskStatus, TaskType};
+pub use task_event_model::{TaskEvent, TaskEventType, TaskTimeline, TaskTimelineEntry};
 pub use task_model::Task;
-pub use team_model::{Team, TeamError};
-pub use webhook_model::{Webhook, WebhookError, WebhookEvent, WebhookEventType, WebhookStatus};
+pub use team_member_model::{normalize_email, TeamMember, TeamMemberStatus, TeamRole, User};
+pub use team_model::{
+    credit_period_start, PlanLimits, Team, TeamCapabilities, TeamError, TeamPlan,
+    TeamPlanAssignment,
+};
+pub use url_blocklist_model::{BlocklistEntry, BlocklistPatternType, UrlBlocklist};
+pub use webhook_model::{
+    DeliveryChannel, Webhook, WebhookError, WebhookEvent, WebhookEventType, WebhookStatus,
+};
 
 // Legacy re-exports for backward compatibility
 pub use scrape_result_entity::{Entity as ScrapeResultEntity, Model as ScrapeResult};
@@ -0,0 +1,339 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! Monitor model - recurring scrapes of a URL with change alerts
+//!
+//! A monitor re-scrapes its URL every `interval_seconds` and compares the
+//! result with the previous snapshot:
+//!
+//! - `hash` compares the visible text of the page.
+//! - `extract` compares the data extracted with CSS selector rules, so
+//!   unrelated parts of the page (ads, timestamps) do not trigger alerts.
+//!
+//! The first check only records the baseline. Every later change is sent as
+//! a `monitor.changed` webhook event carrying the diff.
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fmt;
+use std::str::FromStr;
+use uuid::Uuid;
+
+/// Shortest allowed interval between two checks
+pub const MIN_MONITOR_INTERVAL_SECONDS: i64 = 60;
+
+/// Longest allowed interval between two checks (30 days)
+pub const MAX_MONITOR_INTERVAL_SECONDS: i64 = 30 * 24 * 3600;
+
+/// How two snapshots of a monitored page are compared
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MonitorMode {
+    /// Hash of the visible page text
+    #[default]
+    Hash,
+    /// Data extracted with CSS selector rules
+    Extract,
+}
+
+impl MonitorMode {
+    /// String representation used for storage
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MonitorMode::Hash => "hash",
+            MonitorMode::Extract => "extract",
+        }
+    }
+}
+
+impl fmt::Display for MonitorMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for MonitorMode {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "hash" => Ok(MonitorMode::Hash),
+            "extract" => Ok(MonitorMode::Extract),
+            _ => Err(()),
+        }
+    }
+}
+
+/// A recurring scrape of a single URL
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Monitor {
+    /// Unique identifier for the monitor
+    pub id: Uuid,
+    /// Team that owns the monitor
+    pub team_id: Uuid,
+    /// URL to re-scrape
+    pub url: String,
+    /// Seconds between two checks
+    pub interval_seconds: i64,
+    /// How snapshots are compared
+    pub mode: MonitorMode,
+    /// CSS selector extraction rules used in `extract` mode
+    pub extraction_rules: Option<Value>,
+    /// Paused monitors are not checked
+    pub active: bool,
+    /// Hash of the latest snapshot
+    pub last_hash: Option<String>,
+    /// Latest snapshot, the baseline of the next diff (not exposed through the API)
+    #[serde(skip)]
+    pub last_snapshot: Option<String>,
+    /// Error of the latest check, cleared by the next successful check
+    pub last_error: Option<String>,
+    /// When the monitor was last checked
+    pub last_checked_at: Option<DateTime<Utc>>,
+    /// When a change was last detected
+    pub last_changed_at: Option<DateTime<Utc>>,
+    /// When the monitor is due for its next check
+    pub next_check_at: DateTime<Utc>,
+    /// When the monitor was created
+    pub created_at: DateTime<Utc>,
+    /// When the monitor was last updated
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Monitor {
+    /// Create a new active monitor, due for its first check immediately
+    pub fn new(
+        team_id: Uuid,
+        url: impl Into<String>,
+        interval_seconds: i64,
+        mode: MonitorMode,
+    ) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            team_id,
+            url: url.into().trim().to_string(),
+            interval_seconds,
+            mode,
+            extraction_rules: None,
+            active: true,
+            last_hash: None,
+            last_snapshot: None,
+            last_error: None,
+            last_checked_at: None,
+            last_changed_at: None,
+            next_check_at: now,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    /// Set the CSS selector extraction rules
+    pub fn with_extraction_rules(mut self, extraction_rules: Value) -> Self {
+        self.extraction_rules = Some(extraction_rules);
+        self
+    }
+
+    /// Validate the interval and the rules required by the mode
+    pub fn validate(&self) -> Result<(), String> {
+        if !(MIN_MONITOR_INTERVAL_SECONDS..=MAX_MONITOR_INTERVAL_SECONDS)
+            .contains(&self.interval_seconds)
+        {
+            return Err(format!(
+                "interval_seconds must be between {} and {}",
+                MIN_MONITOR_INTERVAL_SECONDS, MAX_MONITOR_INTERVAL_SECONDS
+            ));
+        }
+        if self.mode == MonitorMode::Extract {
+            let has_rules = self
+                .extraction_rules
+                .as_ref()
+                .and_then(Value::as_object)
+                .is_some_and(|rules| !rules.is_empty());
+            if !has_rules {
+                return Err("extract mode requires extraction_rules".to_string());
+            }
+        }
+        Ok(())
+    }
+
+    /// Interval between two checks
+    pub fn interval(&self) -> Duration {
+        Duration::seconds(self.interval_seconds)
+    }
+
+    /// Change the interval, rescheduling the next check from the last one
+    pub fn set_interval(&mut self, interval_seconds: i64) {
+        self.interval_seconds = interval_seconds;
+        self.next_check_at = self.last_checked_at.unwrap_or(self.created_at) + self.interval();
+        self.updated_at = Utc::now();
+    }
+
+    /// Pause or resume the monitor; a resumed monitor is checked right away
+    pub fn set_active(&mut self, active: bool) {
+        if active && !self.active {
+            self.next_check_at = Utc::now();
+        }
+        self.active = active;
+        self.updated_at = Utc::now();
+    }
+
+    /// Record a successful check
+    ///
+    /// Returns `true` when the snapshot differs from a previous one. The
+    /// first snapshot only establishes the baseline.
+    pub fn record_snapshot(
+        &mut self,
+        hash: String,
+        snapshot: String,
+        checked_at: DateTime<Utc>,
+    ) -> bool {
+        let changed = self
+            .last_hash
+            .as_ref()
+            .is_some_and(|last_hash| *last_hash != hash);
+        if changed {
+            self.last_changed_at = Some(checked_at);
+        }
+        self.last_hash = Some(hash);
+        self.last_snapshot = Some(snapshot);
+        self.last_error = None;
+        self.finish_check(checked_at);
+        changed
+    }
+
+    /// Record a failed check, keeping the previous snapshot as the baseline
+    pub fn record_failure(&mut self, error: impl Into<String>, checked_at: DateTime<Utc>) {
+        self.last_error = Some(error.into());
+        self.finish_check(checked_at);
+    }
+
+    fn finish_check(&mut self, checked_at: DateTime<Utc>) {
+        self.last_checked_at = Some(checked_at);
+        self.next_check_at = checked_at + self.interval();
+        self.updated_at = checked_at;
+    }
+}
+
+/// A change detected by a monitor check
+///
+/// Sent as the `monitor.changed` webhook payload.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MonitorChange {
+    /// Monitor ID
+    pub monitor_id: Uuid,
+    /// Team ID
+    pub team_id: Uuid,
+    /// Monitored URL
+    pub url: String,
+    /// How the snapshots were compared
+    pub mode: MonitorMode,
+    /// Hash of the previous snapshot
+    pub previous_hash: String,
+    /// Hash of the new snapshot
+    pub current_hash: String,
+    /// Lines added since the previous snapshot
+    pub added_lines: usize,
+    /// Lines removed since the previous snapshot
+    pub removed_lines: usize,
+    /// Unified diff between the two snapshots
+    pub diff: String,
+    /// Whether the diff was cut to fit the webhook payload
+    pub diff_truncated: bool,
+    /// When the change was detected
+    pub checked_at: DateTime<Utc>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn monitor(mode: MonitorMode) -> Monitor {
+        Monitor::new(Uuid::new_v4(), " https://example.com ", 300, mode)
+    }
+
+    #[test]
+    fn test_mode_roundtrip() {
+        for mode in [MonitorMode::Hash, MonitorMode::Extract] {
+            assert_eq!(mode.as_str().parse(), Ok(mode));
+        }
+        assert!("diff".parse::<MonitorMode>().is_err());
+    }
+
+    #[test]
+    fn test_validate_interval_and_rules() {
+        let hash = monitor(MonitorMode::Hash);
+        assert_eq!(hash.url, "https://example.com");
+        assert!(hash.validate().is_ok());
+
+        let mut too_short = monitor(MonitorMode::Hash);
+        too_short.interval_seconds = MIN_MONITOR_INTERVAL_SECONDS - 1;
+        assert!(too_short.validate().is_err());
+
+        let extract = monitor(MonitorMode::Extract);
+        assert!(extract.validate().is_err());
+        assert!(extract
+            .clone()
+            .with_extraction_rules(json!({}))
+            .validate()
+            .is_err());
+        assert!(extract
+            .with_extraction_rules(json!({"price": {"selector": ".price"}}))
+            .validate()
+            .is_ok());
+    }
+
+    #[test]
+    fn test_first_snapshot_is_baseline_then_changes_are_reported() {
+        let mut monitor = monitor(MonitorMode::Hash);
+        let checked_at = Utc::now();
+
+        assert!(!monitor.record_snapshot("a".into(), "one".into(), checked_at));
+        assert_eq!(monitor.next_check_at, checked_at + Duration::seconds(300));
+        assert!(monitor.last_changed_at.is_none());
+
+        assert!(!monitor.record_snapshot("a".into(), "one".into(), checked_at));
+        assert!(monitor.record_snapshot("b".into(), "two".into(), checked_at));
+        assert_eq!(monitor.last_changed_at, Some(checked_at));
+        assert_eq!(monitor.last_snapshot.as_deref(), Some("two"));
+    }
+
+    #[test]
+    fn test_failure_keeps_baseline_until_next_success() {
+        let mut monitor = monitor(MonitorMode::Hash);
+        let checked_at = Utc::now();
+        monitor.record_snapshot("a".into(), "one".into(), checked_at);
+
+        monitor.record_failure("timeout", checked_at);
+        assert_eq!(monitor.last_error.as_deref(), Some("timeout"));
+        assert_eq!(monitor.last_hash.as_deref(), Some("a"));
+
+        monitor.record_snapshot("a".into(), "one".into(), checked_at);
+        assert!(monitor.last_error.is_none());
+    }
+
+    #[test]
+    fn test_resume_schedules_an_immediate_check() {
+        let mut monitor = monitor(MonitorMode::Hash);
+        monitor.record_snapshot("a".into(), "one".into(), Utc::now());
+        monitor.set_active(false);
+        assert!(!monitor.active);
+
+        monitor.set_active(true);
+        assert!(monitor.active);
+        assert!(monitor.next_check_at <= Utc::now());
+    }
+
+    #[test]
+    fn test_snapshot_is_not_serialized() {
+        let mut monitor = monitor(MonitorMode::Hash);
+        monitor.record_snapshot("a".into(), "secret page".into(), Utc::now());
+        let value = serde_json::to_value(&monitor).unwrap();
+        assert!(value.get("last_snapshot").is_none());
+        assert_eq!(value["mode"], "hash");
+    }
+}
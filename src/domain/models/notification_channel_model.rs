@@ -0,0 +1,221 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! Notification channel model - chat channels a team pushes events to
+//!
+//! A channel is an incoming-webhook URL of a chat tool:
+//!
+//! - `slack` posts a `{"text": ...}` message (also understood by Mattermost
+//!   and Rocket.Chat).
+//! - `json` posts the event name, a summary and the full payload, for any
+//!   other tool or an automation endpoint.
+//!
+//! Unlike webhooks, channel messages are not signed. Failed posts are retried
+//! through the webhook delivery log.
+
+use super::webhook_model::{event_type_list, DeliveryChannel, WebhookEventType};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+use url::Url;
+use uuid::Uuid;
+
+/// Maximum length of a channel name
+pub const MAX_CHANNEL_NAME_LEN: usize = 100;
+
+/// Message format of a notification channel
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChannelKind {
+    /// Slack incoming webhook
+    Slack,
+    /// Generic JSON POST
+    Json,
+}
+
+impl ChannelKind {
+    /// String representation used for storage
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ChannelKind::Slack => "slack",
+            ChannelKind::Json => "json",
+        }
+    }
+}
+
+impl fmt::Display for ChannelKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for ChannelKind {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "slack" => Ok(ChannelKind::Slack),
+            "json" => Ok(ChannelKind::Json),
+            _ => Err(()),
+        }
+    }
+}
+
+impl From<ChannelKind> for DeliveryChannel {
+    fn from(kind: ChannelKind) -> Self {
+        match kind {
+            ChannelKind::Slack => DeliveryChannel::Slack,
+            ChannelKind::Json => DeliveryChannel::Json,
+        }
+    }
+}
+
+/// A chat channel that receives team events
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NotificationChannel {
+    /// Unique identifier for the channel
+    pub id: Uuid,
+    /// Team that owns the channel
+    pub team_id: Uuid,
+    /// Display name, e.g. `#crawl-alerts`
+    pub name: String,
+    /// Message format
+    pub kind: ChannelKind,
+    /// Incoming-webhook URL the messages are posted to
+    pub url: String,
+    /// Event types to notify about (empty means all events)
+    #[serde(with = "event_type_list")]
+    pub event_types: Vec<WebhookEventType>,
+    /// Disabled channels receive no messages
+    pub enabled: bool,
+    /// When the channel was created
+    pub created_at: DateTime<Utc>,
+    /// When the channel was last updated
+    pub updated_at: DateTime<Utc>,
+}
+
+impl NotificationChannel {
+    /// Create an enabled channel
+    pub fn new(
+        team_id: Uuid,
+        name: impl Into<String>,
+        kind: ChannelKind,
+        url: impl Into<String>,
+    ) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            team_id,
+            name: name.into().trim().to_string(),
+            kind,
+            url: url.into().trim().to_string(),
+            event_types: Vec::new(),
+            enabled: true,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    /// Replace the event type filter, dropping duplicates
+    pub fn set_event_types(&mut self, event_types: Vec<WebhookEventType>) {
+        self.event_types.clear();
+        for event_type in event_types {
+            if !self.event_types.contains(&event_type) {
+                self.event_types.push(event_type);
+            }
+        }
+    }
+
+    /// Validate the name and URL (Slack webhooks must use HTTPS)
+    pub fn validate(&self) -> Result<(), String> {
+        if self.name.is_empty() || self.name.chars().count() > MAX_CHANNEL_NAME_LEN {
+            return Err(format!(
+                "name must be between 1 and {} characters",
+                MAX_CHANNEL_NAME_LEN
+            ));
+        }
+        let url = Url::parse(&self.url).map_err(|e| format!("invalid url: {}", e))?;
+        match (self.kind, url.scheme()) {
+            (_, "https") | (ChannelKind::Json, "http") => Ok(()),
+            (ChannelKind::Slack, _) => Err("slack channels require an https url".to_string()),
+            (_, scheme) => Err(format!("unsupported url scheme: {}", scheme)),
+        }
+    }
+
+    /// Check whether the channel should receive the event type
+    pub fn accepts(&self, event_type: &WebhookEventType) -> bool {
+        self.enabled && (self.event_types.is_empty() || self.event_types.contains(event_type))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kind_roundtrip() {
+        for kind in [ChannelKind::Slack, ChannelKind::Json] {
+            assert_eq!(kind.as_str().parse::<ChannelKind>(), Ok(kind));
+        }
+        assert!("teams".parse::<ChannelKind>().is_err());
+    }
+
+    #[test]
+    fn test_validate_name_and_url() {
+        let team_id = Uuid::new_v4();
+        let slack = NotificationChannel::new(
+            team_id,
+            "#alerts",
+            ChannelKind::Slack,
+            "https://hooks.slack.com/services/T0/B0/x",
+        );
+        assert!(slack.validate().is_ok());
+
+        let plain_slack = NotificationChannel::new(
+            team_id,
+            "#alerts",
+            ChannelKind::Slack,
+            "http://hooks.example.com/x",
+        );
+        assert!(plain_slack.validate().is_err());
+
+        let json = NotificationChannel::new(
+            team_id,
+            "automation",
+            ChannelKind::Json,
+            "http://hooks.example.com/x",
+        );
+        assert!(json.validate().is_ok());
+
+        let unnamed = NotificationChannel::new(team_id, " ", ChannelKind::Json, "https://a.b/");
+        assert!(unnamed.validate().is_err());
+
+        let ftp = NotificationChannel::new(team_id, "ftp", ChannelKind::Json, "ftp://a.b/");
+        assert!(ftp.validate().is_err());
+    }
+
+    #[test]
+    fn test_accepts_respects_filter_and_enabled() {
+        let mut channel = NotificationChannel::new(
+            Uuid::new_v4(),
+            "#alerts",
+            ChannelKind::Slack,
+            "https://hooks.slack.com/services/T0/B0/x",
+        );
+        assert!(channel.accepts(&WebhookEventType::CrawlSummary));
+
+        channel.set_event_types(vec![
+            WebhookEventType::CreditsLow,
+            WebhookEventType::CreditsLow,
+        ]);
+        assert_eq!(channel.event_types.len(), 1);
+        assert!(!channel.accepts(&WebhookEventType::CrawlSummary));
+        assert!(channel.accepts(&WebhookEventType::CreditsLow));
+
+        channel.enabled = false;
+        assert!(!channel.accepts(&WebhookEventType::CreditsLow));
+    }
+}
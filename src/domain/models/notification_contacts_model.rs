@@ -0,0 +1,183 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! Notification contacts model - the email addresses a team notifies
+//!
+//! Each team has at most one set of contacts. Events such as crawl
+//! completion, low credit balance or failed webhook deliveries are emailed to
+//! every contact when the team is subscribed to the event type.
+
+use super::webhook_model::{event_type_list, WebhookEventType};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Maximum number of email addresses per team
+pub const MAX_NOTIFICATION_EMAILS: usize = 10;
+
+/// Check that an email address is plausible (`local@domain.tld`, no whitespace)
+pub fn is_valid_email(email: &str) -> bool {
+    email.len() <= 254
+        && !email.chars().any(char::is_whitespace)
+        && email.split_once('@').is_some_and(|(local, domain)| {
+            !local.is_empty() && domain.contains('.') && !domain.contains('@')
+        })
+}
+
+/// Email contacts of a team and the events they are notified about
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NotificationContacts {
+    /// Team that owns the contacts
+    pub team_id: Uuid,
+    /// Email addresses that receive notifications
+    pub emails: Vec<String>,
+    /// Event types to notify about (empty means all events)
+    #[serde(with = "event_type_list")]
+    pub event_types: Vec<WebhookEventType>,
+    /// Disabled contacts receive no notifications
+    pub enabled: bool,
+    /// When the contacts were created
+    pub created_at: DateTime<Utc>,
+    /// When the contacts were last updated
+    pub updated_at: DateTime<Utc>,
+}
+
+impl NotificationContacts {
+    /// Create enabled contacts, trimming and de-duplicating the addresses
+    pub fn new(team_id: Uuid, emails: Vec<String>, event_types: Vec<WebhookEventType>) -> Self {
+        let now = Utc::now();
+        let mut contacts = Self {
+            team_id,
+            emails: Vec::new(),
+            event_types: Vec::new(),
+            enabled: true,
+            created_at: now,
+            updated_at: now,
+        };
+        contacts.set_emails(emails);
+        contacts.set_event_types(event_types);
+        contacts
+    }
+
+    /// Replace the addresses, trimming and de-duplicating them case-insensitively
+    pub fn set_emails(&mut self, emails: Vec<String>) {
+        self.emails.clear();
+        for email in emails {
+            let email = email.trim().to_string();
+            if !self.emails.iter().any(|e| e.eq_ignore_ascii_case(&email)) {
+                self.emails.push(email);
+            }
+        }
+    }
+
+    /// Replace the event type filter, dropping duplicates
+    pub fn set_event_types(&mut self, event_types: Vec<WebhookEventType>) {
+        self.event_types.clear();
+        for event_type in event_types {
+            if !self.event_types.contains(&event_type) {
+                self.event_types.push(event_type);
+            }
+        }
+    }
+
+    /// Validate the number and format of the addresses
+    pub fn validate(&self) -> Result<(), String> {
+        if self.emails.is_empty() {
+            return Err("at least one email address is required".to_string());
+        }
+        if self.emails.len() > MAX_NOTIFICATION_EMAILS {
+            return Err(format!(
+                "at most {} email addresses are allowed",
+                MAX_NOTIFICATION_EMAILS
+            ));
+        }
+        if let Some(email) = self.emails.iter().find(|e| !is_valid_email(e)) {
+            return Err(format!("invalid email address: {}", email));
+        }
+        Ok(())
+    }
+
+    /// Check whether the contacts should be notified about the event type
+    pub fn accepts(&self, event_type: &WebhookEventType) -> bool {
+        self.enabled && (self.event_types.is_empty() || self.event_types.contains(event_type))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_valid_email() {
+        assert!(is_valid_email("ops@example.com"));
+        assert!(!is_valid_email("ops@example"));
+        assert!(!is_valid_email("@example.com"));
+        assert!(!is_valid_email("ops @example.com"));
+        assert!(!is_valid_email("ops@a@example.com"));
+    }
+
+    #[test]
+    fn test_new_trims_and_deduplicates() {
+        let contacts = NotificationContacts::new(
+            Uuid::new_v4(),
+            vec![
+                " ops@example.com ".to_string(),
+                "OPS@example.com".to_string(),
+                "dev@example.com".to_string(),
+            ],
+            vec![WebhookEventType::CreditsLow, WebhookEventType::CreditsLow],
+        );
+        assert_eq!(contacts.emails, vec!["ops@example.com", "dev@example.com"]);
+        assert_eq!(contacts.event_types, vec![WebhookEventType::CreditsLow]);
+        assert!(contacts.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_rejects_empty_too_many_and_invalid() {
+        let team_id = Uuid::new_v4();
+        assert!(NotificationContacts::new(team_id, vec![], vec![])
+            .validate()
+            .is_err());
+        assert!(
+            NotificationContacts::new(team_id, vec!["not-an-email".to_string()], vec![])
+                .validate()
+                .is_err()
+        );
+        let emails = (0..=MAX_NOTIFICATION_EMAILS)
+            .map(|i| format!("user{}@example.com", i))
+            .collect();
+        assert!(NotificationContacts::new(team_id, emails, vec![])
+            .validate()
+            .is_err());
+    }
+
+    #[test]
+    fn test_accepts_respects_filter_and_enabled() {
+        let mut contacts = NotificationContacts::new(
+            Uuid::new_v4(),
+            vec!["ops@example.com".to_string()],
+            vec![WebhookEventType::CrawlSummary],
+        );
+        assert!(contacts.accepts(&WebhookEventType::CrawlSummary));
+        assert!(!contacts.accepts(&WebhookEventType::CreditsLow));
+
+        contacts.set_event_types(vec![]);
+        assert!(contacts.accepts(&WebhookEventType::CreditsLow));
+
+        contacts.enabled = false;
+        assert!(!contacts.accepts(&WebhookEventType::CreditsLow));
+    }
+
+    #[test]
+    fn test_event_types_serialize_with_wire_names() {
+        let contacts = NotificationContacts::new(
+            Uuid::new_v4(),
+            vec!["ops@example.com".to_string()],
+            vec![WebhookEventType::WebhookDeliveryFailed],
+        );
+        let value = serde_json::to_value(&contacts).unwrap();
+        assert_eq!(value["event_types"][0], "webhook.delivery_failed");
+    }
+}
@@ -0,0 +1,263 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! Resource tag model - client labels and annotations on results and crawls
+//!
+//! Review workflows attach short tags (`reviewed`, `broken`, `relevant`) and
+//! free-form JSON annotations to scrape results and crawls. Tags are
+//! normalized to lowercase so filters match regardless of how clients spell
+//! them. Annotations are merged key by key; a `null` value removes a key.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::fmt;
+use std::str::FromStr;
+use uuid::Uuid;
+
+/// Maximum number of tags on one resource
+pub const MAX_TAGS_PER_RESOURCE: usize = 50;
+
+/// Maximum length of a tag in characters
+pub const MAX_TAG_CHARS: usize = 64;
+
+/// Maximum size of the serialized annotations in bytes
+pub const MAX_ANNOTATIONS_BYTES: usize = 16 * 1024;
+
+/// Kind of resource that can be tagged
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TaggedResource {
+    /// A scrape result
+    Result,
+    /// A crawl
+    Crawl,
+}
+
+impl TaggedResource {
+    /// String representation used for storage
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TaggedResource::Result => "result",
+            TaggedResource::Crawl => "crawl",
+        }
+    }
+}
+
+impl fmt::Display for TaggedResource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for TaggedResource {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "result" => Ok(TaggedResource::Result),
+            "crawl" => Ok(TaggedResource::Crawl),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Normalize a tag: trimmed and lowercased
+///
+/// Tags may contain letters, digits and `-`, `_`, `.`, `:`, `/`.
+pub fn normalize_tag(tag: &str) -> Result<String, String> {
+    let tag = tag.trim().to_lowercase();
+    if tag.is_empty() {
+        return Err("tags must not be empty".to_string());
+    }
+    if tag.chars().count() > MAX_TAG_CHARS {
+        return Err(format!(
+            "tag '{}' is longer than {} characters",
+            tag, MAX_TAG_CHARS
+        ));
+    }
+    if let Some(c) = tag
+        .chars()
+        .find(|c| !c.is_alphanumeric() && !matches!(c, '-' | '_' | '.' | ':' | '/'))
+    {
+        return Err(format!("tag '{}' contains invalid character '{}'", tag, c));
+    }
+    Ok(tag)
+}
+
+/// Tags and annotations attached to one result or crawl
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ResourceTags {
+    /// Kind of the tagged resource
+    pub resource_type: TaggedResource,
+    /// ID of the tagged result or crawl
+    pub resource_id: Uuid,
+    /// Team that owns the resource
+    pub team_id: Uuid,
+    /// Normalized tags in the order they were added
+    pub tags: Vec<String>,
+    /// Free-form JSON annotations
+    pub annotations: Map<String, Value>,
+    /// When the first tag or annotation was added
+    pub created_at: DateTime<Utc>,
+    /// When the tags or annotations last changed
+    pub updated_at: DateTime<Utc>,
+}
+
+impl ResourceTags {
+    /// Create an empty tag set for a resource
+    pub fn new(resource_type: TaggedResource, resource_id: Uuid, team_id: Uuid) -> Self {
+        let now = Utc::now();
+        Self {
+            resource_type,
+            resource_id,
+            team_id,
+            tags: Vec::new(),
+            annotations: Map::new(),
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    /// Add tags, ignoring ones already present
+    pub fn add_tags(&mut self, tags: &[String]) -> Result<(), String> {
+        for tag in tags {
+            let tag = normalize_tag(tag)?;
+            if !self.tags.contains(&tag) {
+                self.tags.push(tag);
+            }
+        }
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+
+    /// Remove a tag, returning whether it was present
+    pub fn remove_tag(&mut self, tag: &str) -> bool {
+        let tag = tag.trim().to_lowercase();
+        let before = self.tags.len();
+        self.tags.retain(|t| *t != tag);
+        let removed = self.tags.len() != before;
+        if removed {
+            self.updated_at = Utc::now();
+        }
+        removed
+    }
+
+    /// Merge annotations key by key; `null` values remove the key
+    pub fn merge_annotations(&mut self, annotations: Map<String, Value>) {
+        for (key, value) in annotations {
+            if value.is_null() {
+                self.annotations.remove(&key);
+            } else {
+                self.annotations.insert(key, value);
+            }
+        }
+        self.updated_at = Utc::now();
+    }
+
+    /// Whether the resource carries every one of `tags` (already normalized)
+    pub fn has_all(&self, tags: &[String]) -> bool {
+        tags.iter().all(|tag| self.tags.contains(tag))
+    }
+
+    /// Whether the resource has neither tags nor annotations
+    pub fn is_empty(&self) -> bool {
+        self.tags.is_empty() && self.annotations.is_empty()
+    }
+
+    /// Validate the number of tags and the size of the annotations
+    pub fn validate(&self) -> Result<(), String> {
+        if self.tags.len() > MAX_TAGS_PER_RESOURCE {
+            return Err(format!(
+                "a resource can have at most {} tags",
+                MAX_TAGS_PER_RESOURCE
+            ));
+        }
+        let size = serde_json::to_vec(&self.annotations)
+            .map(|bytes| bytes.len())
+            .unwrap_or(usize::MAX);
+        if size > MAX_ANNOTATIONS_BYTES {
+            return Err(format!(
+                "annotations must be at most {} bytes",
+                MAX_ANNOTATIONS_BYTES
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn empty_tags() -> ResourceTags {
+        ResourceTags::new(TaggedResource::Result, Uuid::new_v4(), Uuid::new_v4())
+    }
+
+    #[test]
+    fn test_resource_type_roundtrip() {
+        for resource in [TaggedResource::Result, TaggedResource::Crawl] {
+            assert_eq!(resource.as_str().parse::<TaggedResource>(), Ok(resource));
+        }
+        assert!("task".parse::<TaggedResource>().is_err());
+    }
+
+    #[test]
+    fn test_normalize_tag() {
+        assert_eq!(normalize_tag("  Reviewed ").unwrap(), "reviewed");
+        assert_eq!(normalize_tag("qa:batch-2/v1.0").unwrap(), "qa:batch-2/v1.0");
+        assert!(normalize_tag(" ").is_err());
+        assert!(normalize_tag("needs review").is_err());
+        assert!(normalize_tag(&"a".repeat(MAX_TAG_CHARS + 1)).is_err());
+    }
+
+    #[test]
+    fn test_add_and_remove_tags() {
+        let mut tags = empty_tags();
+        tags.add_tags(&["Relevant".to_string(), "broken".to_string()])
+            .unwrap();
+        tags.add_tags(&["relevant".to_string()]).unwrap();
+        assert_eq!(tags.tags, vec!["relevant", "broken"]);
+        assert!(tags.has_all(&["broken".to_string()]));
+        assert!(!tags.has_all(&["broken".to_string(), "reviewed".to_string()]));
+
+        assert!(tags.remove_tag("BROKEN"));
+        assert!(!tags.remove_tag("broken"));
+        assert_eq!(tags.tags, vec!["relevant"]);
+        assert!(tags.add_tags(&["bad tag".to_string()]).is_err());
+    }
+
+    #[test]
+    fn test_merge_annotations_removes_null_keys() {
+        let mut tags = empty_tags();
+        let Value::Object(first) = json!({"reviewer": "ana", "score": 3}) else {
+            unreachable!()
+        };
+        tags.merge_annotations(first);
+        let Value::Object(second) = json!({"reviewer": null, "score": 4}) else {
+            unreachable!()
+        };
+        tags.merge_annotations(second);
+        assert_eq!(Value::Object(tags.annotations.clone()), json!({"score": 4}));
+        assert!(!tags.is_empty());
+    }
+
+    #[test]
+    fn test_validate_limits() {
+        let mut tags = empty_tags();
+        let many: Vec<String> = (0..=MAX_TAGS_PER_RESOURCE)
+            .map(|i| format!("t{}", i))
+            .collect();
+        tags.add_tags(&many).unwrap();
+        assert!(tags.validate().is_err());
+
+        let mut tags = empty_tags();
+        let mut annotations = Map::new();
+        annotations.insert("note".to_string(), json!("x".repeat(MAX_ANNOTATIONS_BYTES)));
+        tags.merge_annotations(annotations);
+        assert!(tags.validate().is_err());
+    }
+}
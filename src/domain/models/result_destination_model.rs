@@ -0,0 +1,358 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! Result destination model - customer-owned storage for crawl results
+//!
+//! A crawl can name a destination where every result is uploaded as soon as
+//! it completes, for customers that must keep data in their own bucket:
+//!
+//! - `s3` uploads to an S3 (or S3-compatible) bucket with SigV4-signed
+//!   requests using the supplied credentials.
+//! - `presigned` uploads with `PUT {url}/{object}`, keeping the URL's query
+//!   string, for prefixes authorized by a SAS token or a signing proxy.
+//!
+//! Each result is stored as `{prefix}/{crawl_id}/{task_id}.json`. Credentials
+//! are only kept in the task payload; the crawl record and team exports store
+//! a redacted copy.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use url::Url;
+use uuid::Uuid;
+
+/// Placeholder replacing secrets in redacted destinations
+pub const REDACTED: &str = "***";
+
+/// Maximum length of an object key prefix
+pub const MAX_PREFIX_LEN: usize = 512;
+
+/// Content type of uploaded result objects
+pub const RESULT_OBJECT_CONTENT_TYPE: &str = "application/json";
+
+/// Where crawl results are uploaded
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ResultDestination {
+    /// S3 or S3-compatible bucket
+    S3 {
+        bucket: String,
+        region: String,
+        /// Custom endpoint for S3-compatible services (path-style addressing)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        endpoint: Option<String>,
+        /// Key prefix inside the bucket
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        prefix: Option<String>,
+        access_key_id: String,
+        secret_access_key: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        session_token: Option<String>,
+    },
+    /// Presigned or token-authorized URL prefix
+    Presigned {
+        url: String,
+        /// Extra headers sent with every upload (e.g. `x-ms-blob-type`)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        headers: Option<HashMap<String, String>>,
+    },
+}
+
+impl ResultDestination {
+    /// Validate the destination, returning a client-facing message on failure
+    pub fn validate(&self) -> Result<(), String> {
+        match self {
+            ResultDestination::S3 {
+                bucket,
+                region,
+                endpoint,
+                prefix,
+                access_key_id,
+                secret_access_key,
+                ..
+            } => {
+                if !is_valid_bucket_name(bucket) {
+                    return Err(format!("Invalid S3 bucket name '{}'", bucket));
+                }
+                if region.is_empty()
+                    || !region
+                        .chars()
+                        .all(|c| c.is_ascii_alphanumeric() || c == '-')
+                {
+                    return Err(format!("Invalid S3 region '{}'", region));
+                }
+                if access_key_id.trim().is_empty() || secret_access_key.trim().is_empty() {
+                    return Err(
+                        "S3 destination requires access_key_id and secret_access_key".to_string(),
+                    );
+                }
+                if let Some(endpoint) = endpoint {
+                    let parsed = Url::parse(endpoint)
+                        .map_err(|e| format!("Invalid S3 endpoint '{}': {}", endpoint, e))?;
+                    if !matches!(parsed.scheme(), "http" | "https") {
+                        return Err("S3 endpoint must use http or https".to_string());
+                    }
+                }
+                if let Some(prefix) = prefix {
+                    if prefix.len() > MAX_PREFIX_LEN {
+                        return Err(format!(
+                            "S3 prefix must be at most {} characters",
+                            MAX_PREFIX_LEN
+                        ));
+                    }
+                }
+                Ok(())
+            }
+            ResultDestination::Presigned { url, .. } => {
+                let parsed =
+                    Url::parse(url).map_err(|e| format!("Invalid destination URL: {}", e))?;
+                if parsed.scheme() != "https" {
+                    return Err("Presigned destination URL must use https".to_string());
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// User-supplied URL the worker connects to, which must pass SSRF checks
+    ///
+    /// The default AWS endpoint is not user-controlled and returns None.
+    pub fn target_url(&self) -> Option<&str> {
+        match self {
+            ResultDestination::S3 { endpoint, .. } => endpoint.as_deref(),
+            ResultDestination::Presigned { url, .. } => Some(url),
+        }
+    }
+
+    /// Copy with credentials, tokens and header values replaced by `***`
+    ///
+    /// The query string of a presigned URL carries its signature and is removed.
+    pub fn redacted(&self) -> Self {
+        match self {
+            ResultDestination::S3 {
+                bucket,
+                region,
+                endpoint,
+                prefix,
+                access_key_id,
+                session_token,
+                ..
+            } => ResultDestination::S3 {
+                bucket: bucket.clone(),
+                region: region.clone(),
+                endpoint: endpoint.clone(),
+                prefix: prefix.clone(),
+                access_key_id: access_key_id.clone(),
+                secret_access_key: REDACTED.to_string(),
+                session_token: session_token.as_ref().map(|_| REDACTED.to_string()),
+            },
+            ResultDestination::Presigned { url, headers } => ResultDestination::Presigned {
+                url: strip_query(url),
+                headers: headers.as_ref().map(|headers| {
+                    headers
+                        .keys()
+                        .map(|name| (name.clone(), REDACTED.to_string()))
+                        .collect()
+                }),
+            },
+        }
+    }
+
+    /// Redact the destination a task payload carries in `config.result_destination`
+    ///
+    /// Payloads keep the credentials for the worker, so any copy leaving the
+    /// service (exports, API responses) goes through this first. A destination
+    /// that does not parse is replaced by `***` as a whole.
+    pub fn redact_payload(payload: &mut serde_json::Value) {
+        let Some(destination) = payload
+            .get_mut("config")
+            .and_then(|config| config.get_mut("result_destination"))
+            .filter(|destination| !destination.is_null())
+        else {
+            return;
+        };
+        *destination = serde_json::from_value::<ResultDestination>(destination.clone())
+            .ok()
+            .and_then(|parsed| serde_json::to_value(parsed.redacted()).ok())
+            .unwrap_or_else(|| serde_json::Value::String(REDACTED.to_string()));
+    }
+
+    /// Object name of a result relative to the destination: `{crawl_id}/{task_id}.json`
+    pub fn result_object_name(crawl_id: Uuid, task_id: Uuid) -> String {
+        format!("{}/{}.json", crawl_id, task_id)
+    }
+
+    /// Full object key inside an S3 bucket, under the configured prefix
+    pub fn object_key(&self, name: &str) -> String {
+        let prefix = match self {
+            ResultDestination::S3 { prefix, .. } => prefix.as_deref().unwrap_or_default(),
+            ResultDestination::Presigned { .. } => "",
+        };
+        let prefix = prefix.trim_matches('/');
+        if prefix.is_empty() {
+            name.to_string()
+        } else {
+            format!("{}/{}", prefix, name)
+        }
+    }
+
+    /// Location of an uploaded object without any credentials, recorded on the result
+    pub fn location(&self, name: &str) -> String {
+        match self {
+            ResultDestination::S3 { bucket, .. } => {
+                format!("s3://{}/{}", bucket, self.object_key(name))
+            }
+            ResultDestination::Presigned { url, .. } => {
+                format!("{}/{}", strip_query(url).trim_end_matches('/'), name)
+            }
+        }
+    }
+}
+
+/// S3 bucket naming rules: 3-63 lowercase letters, digits, dots and hyphens,
+/// starting and ending with a letter or digit
+fn is_valid_bucket_name(bucket: &str) -> bool {
+    (3..=63).contains(&bucket.len())
+        && bucket
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '.' || c == '-')
+        && bucket
+            .chars()
+            .next()
+            .is_some_and(|c| c.is_ascii_alphanumeric())
+        && bucket
+            .chars()
+            .last()
+            .is_some_and(|c| c.is_ascii_alphanumeric())
+        && !bucket.contains("..")
+}
+
+/// Remove the query string and fragment of a URL
+fn strip_query(url: &str) -> String {
+    match Url::parse(url) {
+        Ok(mut parsed) => {
+            parsed.set_query(None);
+            parsed.set_fragment(None);
+            parsed.to_string()
+        }
+        Err(_) => url.split(['?', '#']).next().unwrap_or_default().to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn s3() -> ResultDestination {
+        serde_json::from_value(json!({
+            "type": "s3",
+            "bucket": "acme-results",
+            "region": "eu-central-1",
+            "prefix": "/crawls/",
+            "access_key_id": "AKIAEXAMPLE",
+            "secret_access_key": "secret",
+            "session_token": "token"
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_deserialize_and_validate() {
+        assert!(s3().validate().is_ok());
+
+        let presigned: ResultDestination = serde_json::from_value(json!({
+            "type": "presigned",
+            "url": "https://acct.blob.core.windows.net/results?sv=2024&sig=abc",
+            "headers": {"x-ms-blob-type": "BlockBlob"}
+        }))
+        .unwrap();
+        assert!(presigned.validate().is_ok());
+        assert_eq!(
+            presigned.target_url(),
+            Some("https://acct.blob.core.windows.net/results?sv=2024&sig=abc")
+        );
+        assert_eq!(s3().target_url(), None);
+
+        let insecure = ResultDestination::Presigned {
+            url: "http://example.com/upload".to_string(),
+            headers: None,
+        };
+        assert!(insecure.validate().is_err());
+
+        for bucket in ["ab", "Acme", "-acme", "acme..results", "acme_results"] {
+            let destination = ResultDestination::S3 {
+                bucket: bucket.to_string(),
+                region: "us-east-1".to_string(),
+                endpoint: None,
+                prefix: None,
+                access_key_id: "a".to_string(),
+                secret_access_key: "b".to_string(),
+                session_token: None,
+            };
+            assert!(destination.validate().is_err(), "{} is invalid", bucket);
+        }
+    }
+
+    #[test]
+    fn test_redacted_hides_secrets() {
+        let redacted = serde_json::to_value(s3().redacted()).unwrap();
+        assert_eq!(redacted["secret_access_key"], REDACTED);
+        assert_eq!(redacted["session_token"], REDACTED);
+        assert_eq!(redacted["access_key_id"], "AKIAEXAMPLE");
+
+        let presigned = ResultDestination::Presigned {
+            url: "https://uploads.example.com/acme/?token=abc".to_string(),
+            headers: Some(HashMap::from([(
+                "Authorization".to_string(),
+                "Bearer abc".to_string(),
+            )])),
+        }
+        .redacted();
+        let redacted = serde_json::to_value(presigned).unwrap();
+        assert_eq!(redacted["url"], "https://uploads.example.com/acme/");
+        assert_eq!(redacted["headers"]["Authorization"], REDACTED);
+    }
+
+    #[test]
+    fn test_redact_payload_hides_destination_secrets() {
+        let mut payload = json!({
+            "url": "https://example.com",
+            "config": {"result_destination": s3()},
+        });
+        ResultDestination::redact_payload(&mut payload);
+        let destination = &payload["config"]["result_destination"];
+        assert_eq!(destination["secret_access_key"], REDACTED);
+        assert_eq!(destination["bucket"], "acme-results");
+        assert_eq!(payload["url"], "https://example.com");
+
+        let mut malformed = json!({"config": {"result_destination": {"secret": "top-secret"}}});
+        ResultDestination::redact_payload(&mut malformed);
+        assert_eq!(malformed["config"]["result_destination"], REDACTED);
+
+        let mut without = json!({"config": {"result_destination": null}});
+        ResultDestination::redact_payload(&mut without);
+        assert!(without["config"]["result_destination"].is_null());
+    }
+
+    #[test]
+    fn test_object_key_and_location() {
+        let crawl_id = Uuid::nil();
+        let task_id = Uuid::new_v4();
+        let name = ResultDestination::result_object_name(crawl_id, task_id);
+        assert_eq!(name, format!("{}/{}.json", crawl_id, task_id));
+        assert_eq!(s3().object_key("a.json"), "crawls/a.json");
+        assert_eq!(s3().location("a.json"), "s3://acme-results/crawls/a.json");
+
+        let presigned = ResultDestination::Presigned {
+            url: "https://uploads.example.com/acme/?token=abc".to_string(),
+            headers: None,
+        };
+        assert_eq!(presigned.object_key("a.json"), "a.json");
+        assert_eq!(
+            presigned.location("a.json"),
+            "https://uploads.example.com/acme/a.json"
+        );
+    }
+}
@@ -31,6 +31,11 @@ pub struct Model {
     pub response_time_ms: i64,
     #[sea_orm(column_name = "created_at")]
     pub created_at: ChronoDateTime,
+    /// `ETag` response header, sent as `If-None-Match` on incremental re-crawls
+    pub etag: Option<String>,
+    /// `Last-Modified` response header, sent as `If-Modified-Since` on incremental re-crawls
+    #[sea_orm(column_name = "last_modified")]
+    pub last_modified: Option<String>,
 }
 
 impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,45 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! Single sign-on model - OIDC login state and session tokens
+//!
+//! A login starts by storing a one-time state together with the PKCE code
+//! verifier and nonce. The IdP callback consumes the state, and a successful
+//! login issues a session token for one team. Session tokens are stored as API
+//! keys with an expiry, so they authenticate exactly like API keys.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Pending OIDC login, consumed by the callback
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OidcLoginState {
+    /// Opaque `state` parameter sent to the IdP
+    pub state: String,
+    /// PKCE code verifier, never sent to the browser
+    pub code_verifier: String,
+    /// Nonce the ID token must echo back
+    pub nonce: String,
+    /// Team requested by the caller, if any
+    pub team_id: Option<Uuid>,
+    /// When the login was started
+    pub created_at: DateTime<Utc>,
+}
+
+/// Session issued by a successful SSO login
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SsoSession {
+    /// ID of the API key row backing the session
+    pub api_key_id: Uuid,
+    /// Team the session acts for
+    pub team_id: Uuid,
+    /// Signed-in user
+    pub user_id: Uuid,
+    /// Email address of the signed-in user
+    pub email: String,
+    /// When the session token stops working
+    pub expires_at: DateTime<Utc>,
+}
@@ -27,6 +27,10 @@ pub enum TaskType {
     Crawl,
     /// 内容提取任务，从已抓取的内容中提取特定信息
     Extract,
+    /// 团队数据导出任务，将团队数据打包为归档写入对象存储
+    Export,
+    /// 订阅源任务，解析 RSS/Atom 条目并可将条目链接作为抓取任务入队
+    Feed,
 }
 
 impl TaskType {
@@ -36,6 +40,8 @@ impl TaskType {
             TaskType::Scrape => "scrape",
             TaskType::Crawl => "crawl",
             TaskType::Extract => "extract",
+            TaskType::Export => "export",
+            TaskType::Feed => "feed",
         }
     }
 }
@@ -54,6 +60,8 @@ impl FromStr for TaskType {
             "scrape" => Ok(TaskType::Scrape),
             "crawl" => Ok(TaskType::Crawl),
             "extract" => Ok(TaskType::Extract),
+            "export" => Ok(TaskType::Export),
+            "feed" => Ok(TaskType::Feed),
             _ => Err(()),
         }
     }
@@ -107,6 +115,85 @@ impl FromStr for TaskStatus {
     }
 }
 
+/// 任务优先级档位
+///
+/// `Task.priority` 数值越小越先执行，按区间划分为三档：`<= 0` 为高、`1..=99` 为普通、
+/// `>= 100` 为低。提交任务时可按档位指定优先级，调度按档位加权轮转。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PriorityTier {
+    /// 高优先级，用户直接提交的任务默认在此档
+    High,
+    /// 普通优先级
+    Normal,
+    /// 低优先级，爬取子任务默认在此档
+    Low,
+}
+
+impl PriorityTier {
+    /// 按优先级从高到低排列
+    pub const ALL: [PriorityTier; 3] =
+        [PriorityTier::High, PriorityTier::Normal, PriorityTier::Low];
+
+    /// 按任务优先级划分档位
+    ///
+    /// MIRROR: `TaskRepositoryImpl::acquire_next_in_lane` 原生 SQL 中的
+    /// `CASE WHEN priority <= 0 ... WHEN priority < 100 ...`，修改时须同步。
+    pub fn for_priority(priority: i32) -> Self {
+        match priority {
+            p if p <= 0 => PriorityTier::High,
+            p if p < 100 => PriorityTier::Normal,
+            _ => PriorityTier::Low,
+        }
+    }
+
+    /// 按档位提交任务时写入的优先级数值
+    pub fn priority(&self) -> i32 {
+        match self {
+            PriorityTier::High => 0,
+            PriorityTier::Normal => 50,
+            PriorityTier::Low => 100,
+        }
+    }
+
+    /// 档位序号，高为 0
+    pub fn lane(&self) -> i32 {
+        match self {
+            PriorityTier::High => 0,
+            PriorityTier::Normal => 1,
+            PriorityTier::Low => 2,
+        }
+    }
+
+    /// 档位名称
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PriorityTier::High => "high",
+            PriorityTier::Normal => "normal",
+            PriorityTier::Low => "low",
+        }
+    }
+}
+
+impl fmt::Display for PriorityTier {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for PriorityTier {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "high" => Ok(PriorityTier::High),
+            "normal" => Ok(PriorityTier::Normal),
+            "low" => Ok(PriorityTier::Low),
+            _ => Err(()),
+        }
+    }
+}
+
 /// 领域错误类型
 ///
 /// 表示在领域层可能发生的各种错误情况，包括状态转换错误、
@@ -212,13 +299,21 @@ mod tests {
         assert_eq!(TaskType::Scrape.as_str(), "scrape");
         assert_eq!(TaskType::Crawl.as_str(), "crawl");
         assert_eq!(TaskType::Extract.as_str(), "extract");
+        assert_eq!(TaskType::Export.as_str(), "export");
+        assert_eq!(TaskType::Feed.as_str(), "feed");
     }
 
     // ========== TaskType Display tests ==========
 
     #[test]
     fn test_task_type_display_matches_as_str() {
-        for ty in [TaskType::Scrape, TaskType::Crawl, TaskType::Extract] {
+        for ty in [
+            TaskType::Scrape,
+            TaskType::Crawl,
+            TaskType::Extract,
+            TaskType::Export,
+            TaskType::Feed,
+        ] {
             assert_eq!(ty.to_string(), ty.as_str(), "Display should match as_str");
         }
     }
@@ -236,6 +331,11 @@ mod tests {
             TaskType::from_str("extract").expect("valid"),
             TaskType::Extract
         );
+        assert_eq!(
+            TaskType::from_str("export").expect("valid"),
+            TaskType::Export
+        );
+        assert_eq!(TaskType::from_str("feed").expect("valid"), TaskType::Feed);
     }
 
     #[test]
@@ -258,7 +358,13 @@ mod tests {
 
     #[test]
     fn test_task_type_serde_roundtrip() {
-        for ty in [TaskType::Scrape, TaskType::Crawl, TaskType::Extract] {
+        for ty in [
+            TaskType::Scrape,
+            TaskType::Crawl,
+            TaskType::Extract,
+            TaskType::Export,
+            TaskType::Feed,
+        ] {
             let json = serde_json::to_string(&ty).expect("serialize");
             let back: TaskType = serde_json::from_str(&json).expect("deserialize");
             assert_eq!(ty, back, "roundtrip should preserve: {}", json);
@@ -623,4 +729,30 @@ mod tests {
             "timeout should take precedence over connection"
         );
     }
+
+    // ========== PriorityTier tests ==========
+
+    #[test]
+    fn test_priority_tier_for_priority_boundaries() {
+        assert_eq!(PriorityTier::for_priority(-5), PriorityTier::High);
+        assert_eq!(PriorityTier::for_priority(0), PriorityTier::High);
+        assert_eq!(PriorityTier::for_priority(1), PriorityTier::Normal);
+        assert_eq!(PriorityTier::for_priority(99), PriorityTier::Normal);
+        assert_eq!(PriorityTier::for_priority(100), PriorityTier::Low);
+        assert_eq!(PriorityTier::for_priority(101), PriorityTier::Low);
+    }
+
+    #[test]
+    fn test_priority_tier_values_round_trip() {
+        for (lane, tier) in PriorityTier::ALL.into_iter().enumerate() {
+            assert_eq!(PriorityTier::for_priority(tier.priority()), tier);
+            assert_eq!(tier.lane(), lane as i32);
+            assert_eq!(PriorityTier::from_str(tier.as_str()), Ok(tier));
+            assert_eq!(
+                serde_json::to_value(tier).unwrap(),
+                serde_json::json!(tier.as_str())
+            );
+        }
+        assert!(PriorityTier::from_str("urgent").is_err());
+    }
 }
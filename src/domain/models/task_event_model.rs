@@ -0,0 +1,277 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! Task event model - lifecycle events used to assemble a task timeline
+//!
+//! The queue and the scrape worker append an event at every lifecycle step
+//! (queued, locked by a worker, engine attempt, retry, completion, ...).
+//! The API assembles them into a timeline for debugging slow or stuck jobs.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+use uuid::Uuid;
+
+use super::task_domain::TaskStatus;
+use super::task_model::Task;
+
+/// Lifecycle event type
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TaskEventType {
+    /// Task was enqueued
+    Queued,
+    /// Task was locked by a worker
+    Locked,
+    /// Dispatch was postponed (domain throttle or team concurrency limit)
+    Deferred,
+    /// One scrape attempt through the engine router
+    EngineAttempt,
+    /// Task was rescheduled for another attempt
+    Retried,
+    /// Task completed successfully
+    Completed,
+    /// Task failed permanently
+    Failed,
+    /// Task was cancelled
+    Cancelled,
+    /// Worker lost the task lock mid-execution and aborted
+    LeaseLost,
+}
+
+impl TaskEventType {
+    /// String representation used for storage
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TaskEventType::Queued => "queued",
+            TaskEventType::Locked => "locked",
+            TaskEventType::Deferred => "deferred",
+            TaskEventType::EngineAttempt => "engine_attempt",
+            TaskEventType::Retried => "retried",
+            TaskEventType::Completed => "completed",
+            TaskEventType::Failed => "failed",
+            TaskEventType::Cancelled => "cancelled",
+            TaskEventType::LeaseLost => "lease_lost",
+        }
+    }
+}
+
+impl fmt::Display for TaskEventType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for TaskEventType {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "queued" => Ok(TaskEventType::Queued),
+            "locked" => Ok(TaskEventType::Locked),
+            "deferred" => Ok(TaskEventType::Deferred),
+            "engine_attempt" => Ok(TaskEventType::EngineAttempt),
+            "retried" => Ok(TaskEventType::Retried),
+            "completed" => Ok(TaskEventType::Completed),
+            "failed" => Ok(TaskEventType::Failed),
+            "cancelled" => Ok(TaskEventType::Cancelled),
+            "lease_lost" => Ok(TaskEventType::LeaseLost),
+            _ => Err(()),
+        }
+    }
+}
+
+/// A single lifecycle event of a task
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TaskEvent {
+    /// Unique identifier for the event
+    pub id: Uuid,
+    /// Task the event belongs to
+    pub task_id: Uuid,
+    /// Event type
+    pub event_type: TaskEventType,
+    /// Worker that produced the event, if any
+    pub worker_id: Option<Uuid>,
+    /// Attempt number (task retry count when the event happened)
+    pub attempt: i32,
+    /// Duration of the step in milliseconds (engine attempts)
+    pub duration_ms: Option<i64>,
+    /// Free-form detail (status code, error message, reschedule time, ...)
+    pub message: Option<String>,
+    /// When the event happened
+    pub created_at: DateTime<Utc>,
+}
+
+impl TaskEvent {
+    /// Create a new event for a task
+    pub fn new(task_id: Uuid, event_type: TaskEventType) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            task_id,
+            event_type,
+            worker_id: None,
+            attempt: 0,
+            duration_ms: None,
+            message: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    /// Set the worker that produced the event
+    pub fn with_worker(mut self, worker_id: Uuid) -> Self {
+        self.worker_id = Some(worker_id);
+        self
+    }
+
+    /// Set the attempt number
+    pub fn with_attempt(mut self, attempt: i32) -> Self {
+        self.attempt = attempt;
+        self
+    }
+
+    /// Set the step duration
+    pub fn with_duration_ms(mut self, duration_ms: i64) -> Self {
+        self.duration_ms = Some(duration_ms);
+        self
+    }
+
+    /// Set the detail message
+    pub fn with_message(mut self, message: impl Into<String>) -> Self {
+        self.message = Some(message.into());
+        self
+    }
+}
+
+/// Timeline entry: an event plus its offset from the first event
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TaskTimelineEntry {
+    /// The recorded event
+    #[serde(flatten)]
+    pub event: TaskEvent,
+    /// Milliseconds since the first event of the task
+    pub elapsed_ms: i64,
+}
+
+/// Lifecycle of a task assembled from its events
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TaskTimeline {
+    /// Task ID
+    pub task_id: Uuid,
+    /// Current task status
+    pub status: TaskStatus,
+    /// Number of retries so far
+    pub retry_count: i32,
+    /// Events in chronological order
+    pub events: Vec<TaskTimelineEntry>,
+    /// Milliseconds between the first and the last event
+    pub total_duration_ms: i64,
+}
+
+impl TaskTimeline {
+    /// Assemble a timeline from a task and its (unordered) events
+    pub fn new(task: &Task, mut events: Vec<TaskEvent>) -> Self {
+        events.sort_by_key(|event| event.created_at);
+
+        let started = events.first().map(|event| event.created_at);
+        let entries: Vec<TaskTimelineEntry> = events
+            .into_iter()
+            .map(|event| TaskTimelineEntry {
+                elapsed_ms: started
+                    .map(|start| (event.created_at - start).num_milliseconds())
+                    .unwrap_or(0),
+                event,
+            })
+            .collect();
+        let total_duration_ms = entries.last().map(|entry| entry.elapsed_ms).unwrap_or(0);
+
+        Self {
+            task_id: task.id,
+            status: task.status,
+            retry_count: task.retry_count,
+            events: entries,
+            total_duration_ms,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::TaskType;
+    use chrono::Duration;
+
+    #[test]
+    fn test_task_event_type_roundtrip() {
+        for event_type in [
+            TaskEventType::Queued,
+            TaskEventType::Locked,
+            TaskEventType::Deferred,
+            TaskEventType::EngineAttempt,
+            TaskEventType::Retried,
+            TaskEventType::Completed,
+            TaskEventType::Failed,
+            TaskEventType::Cancelled,
+            TaskEventType::LeaseLost,
+        ] {
+            assert_eq!(event_type.as_str().parse(), Ok(event_type));
+        }
+        assert!("unknown".parse::<TaskEventType>().is_err());
+    }
+
+    #[test]
+    fn test_timeline_orders_events_and_computes_offsets() {
+        let task = Task::new(
+            Uuid::new_v4(),
+            TaskType::Scrape,
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            "https://example.com".to_string(),
+            serde_json::json!({}),
+        );
+        let t0 = Utc::now();
+        let mut queued = TaskEvent::new(task.id, TaskEventType::Queued);
+        queued.created_at = t0;
+        let mut locked = TaskEvent::new(task.id, TaskEventType::Locked).with_worker(Uuid::new_v4());
+        locked.created_at = t0 + Duration::milliseconds(1500);
+        let mut attempt = TaskEvent::new(task.id, TaskEventType::EngineAttempt)
+            .with_duration_ms(800)
+            .with_message("status 200");
+        attempt.created_at = t0 + Duration::milliseconds(2300);
+
+        let timeline = TaskTimeline::new(&task, vec![attempt, queued, locked]);
+
+        let types: Vec<TaskEventType> = timeline
+            .events
+            .iter()
+            .map(|entry| entry.event.event_type)
+            .collect();
+        assert_eq!(
+            types,
+            vec![
+                TaskEventType::Queued,
+                TaskEventType::Locked,
+                TaskEventType::EngineAttempt
+            ]
+        );
+        assert_eq!(timeline.events[1].elapsed_ms, 1500);
+        assert_eq!(timeline.total_duration_ms, 2300);
+    }
+
+    #[test]
+    fn test_timeline_without_events_is_empty() {
+        let task = Task::new(
+            Uuid::new_v4(),
+            TaskType::Scrape,
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            "https://example.com".to_string(),
+            serde_json::json!({}),
+        );
+        let timeline = TaskTimeline::new(&task, Vec::new());
+        assert!(timeline.events.is_empty());
+        assert_eq!(timeline.total_duration_ms, 0);
+    }
+}
@@ -27,7 +27,7 @@ pub struct Task {
     pub task_type: TaskType,
     /// Current status of the task
     pub status: TaskStatus,
-    /// Priority level (higher = more urgent)
+    /// Priority level (lower = more urgent), see [`PriorityTier`](super::PriorityTier)
     pub priority: i32,
     /// Team ID for multi-tenancy
     pub team_id: Uuid,
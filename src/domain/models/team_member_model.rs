@@ -0,0 +1,223 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! Team member model - users that belong to a team
+//!
+//! Users are identified by their (lowercased) email address. A user joins a
+//! team through an invitation: the membership is created as `invited` and
+//! becomes `active` once the invitation token is accepted. Every team keeps at
+//! least one active owner.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+use uuid::Uuid;
+
+/// Role of a user within a team
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TeamRole {
+    /// Manages the team and its members
+    Owner,
+    /// Manages members
+    Admin,
+    /// Regular member
+    #[default]
+    Member,
+}
+
+impl TeamRole {
+    /// String representation used for storage
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TeamRole::Owner => "owner",
+            TeamRole::Admin => "admin",
+            TeamRole::Member => "member",
+        }
+    }
+
+    /// Whether the role can manage the team's members
+    pub fn manages_members(&self) -> bool {
+        matches!(self, TeamRole::Owner | TeamRole::Admin)
+    }
+}
+
+impl fmt::Display for TeamRole {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for TeamRole {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "owner" => Ok(TeamRole::Owner),
+            "admin" => Ok(TeamRole::Admin),
+            "member" => Ok(TeamRole::Member),
+            other => Err(format!("Unknown team role '{}'", other)),
+        }
+    }
+}
+
+/// Whether a membership has been accepted
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TeamMemberStatus {
+    /// Invitation sent, not accepted yet
+    Invited,
+    /// Invitation accepted
+    Active,
+}
+
+impl TeamMemberStatus {
+    /// String representation used for storage
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TeamMemberStatus::Invited => "invited",
+            TeamMemberStatus::Active => "active",
+        }
+    }
+}
+
+impl fmt::Display for TeamMemberStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for TeamMemberStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "invited" => Ok(TeamMemberStatus::Invited),
+            "active" => Ok(TeamMemberStatus::Active),
+            other => Err(format!("Unknown team member status '{}'", other)),
+        }
+    }
+}
+
+/// A user identified by email
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct User {
+    /// Unique identifier for the user
+    pub id: Uuid,
+    /// Lowercased email address
+    pub email: String,
+    /// Display name
+    pub name: Option<String>,
+    /// When the user was created
+    pub created_at: DateTime<Utc>,
+}
+
+/// A user's membership in a team
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TeamMember {
+    /// Team the user belongs to
+    pub team_id: Uuid,
+    /// Member user ID
+    pub user_id: Uuid,
+    /// Member email address
+    pub email: String,
+    /// Member display name
+    pub name: Option<String>,
+    /// Role within the team
+    pub role: TeamRole,
+    /// Invitation state
+    pub status: TeamMemberStatus,
+    /// When the user was invited (or added directly)
+    pub invited_at: DateTime<Utc>,
+    /// When the invitation was accepted
+    pub joined_at: Option<DateTime<Utc>>,
+}
+
+impl TeamMember {
+    /// Create a pending membership for an invited user
+    pub fn invited(team_id: Uuid, user: &User, role: TeamRole) -> Self {
+        Self {
+            team_id,
+            user_id: user.id,
+            email: user.email.clone(),
+            name: user.name.clone(),
+            role,
+            status: TeamMemberStatus::Invited,
+            invited_at: Utc::now(),
+            joined_at: None,
+        }
+    }
+
+    /// Create an active membership, e.g. the owner of a new team
+    pub fn active(team_id: Uuid, user: &User, role: TeamRole) -> Self {
+        let now = Utc::now();
+        Self {
+            status: TeamMemberStatus::Active,
+            invited_at: now,
+            joined_at: Some(now),
+            ..Self::invited(team_id, user, role)
+        }
+    }
+
+    /// Whether the member is an accepted owner
+    pub fn is_active_owner(&self) -> bool {
+        self.role == TeamRole::Owner && self.status == TeamMemberStatus::Active
+    }
+}
+
+/// Normalize an email address for storage and lookup
+///
+/// Trims surrounding whitespace and lowercases the address. Returns `None`
+/// when it is not of the form `local@domain.tld`.
+pub fn normalize_email(email: &str) -> Option<String> {
+    let email = email.trim().to_lowercase();
+    let (local, domain) = email.split_once('@')?;
+    let valid = !local.is_empty()
+        && !domain.contains('@')
+        && domain
+            .split('.')
+            .all(|label| !label.is_empty() && !label.starts_with('-'))
+        && domain.contains('.')
+        && !email.chars().any(char::is_whitespace)
+        && email.len() <= 254;
+    valid.then_some(email)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_email() {
+        assert_eq!(
+            normalize_email("  Alice@Example.COM "),
+            Some("alice@example.com".to_string())
+        );
+        for invalid in [
+            "",
+            "alice",
+            "@example.com",
+            "alice@",
+            "alice@example",
+            "alice@@example.com",
+            "alice@example..com",
+            "al ice@example.com",
+        ] {
+            assert_eq!(normalize_email(invalid), None, "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn test_role_and_status_round_trip() {
+        for role in [TeamRole::Owner, TeamRole::Admin, TeamRole::Member] {
+            assert_eq!(role.as_str().parse::<TeamRole>(), Ok(role));
+        }
+        for status in [TeamMemberStatus::Invited, TeamMemberStatus::Active] {
+            assert_eq!(status.as_str().parse::<TeamMemberStatus>(), Ok(status));
+        }
+        assert!("superuser".parse::<TeamRole>().is_err());
+    }
+}
@@ -72,6 +72,95 @@ impl Team {
     }
 }
 
+/// 团队能力开关
+///
+/// 由管理员通过 admin API 授予，团队不能自行修改。
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct TeamCapabilities {
+    /// 是否允许爬取任务使用 `ignore_robots` 忽略 robots.txt
+    /// （用于团队抓取自有站点，默认关闭）
+    pub allow_ignore_robots: bool,
+}
+
+/// 团队套餐
+///
+/// 决定每月发放的积分以及限流、并发、爬取深度上限，由管理员通过 admin API 设置。
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "lowercase")]
+pub enum TeamPlan {
+    /// 免费套餐
+    #[default]
+    Free,
+    /// 专业套餐
+    Pro,
+    /// 企业套餐
+    Enterprise,
+}
+
+impl std::fmt::Display for TeamPlan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TeamPlan::Free => write!(f, "free"),
+            TeamPlan::Pro => write!(f, "pro"),
+            TeamPlan::Enterprise => write!(f, "enterprise"),
+        }
+    }
+}
+
+impl std::str::FromStr for TeamPlan {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "free" => Ok(TeamPlan::Free),
+            "pro" => Ok(TeamPlan::Pro),
+            "enterprise" => Ok(TeamPlan::Enterprise),
+            _ => Err(format!("Unknown team plan: {}", s)),
+        }
+    }
+}
+
+/// 套餐限制
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub struct PlanLimits {
+    /// 每月发放的积分：每月初余额不足该值时补足到该值，未用完的积分不累加
+    pub monthly_credits: i64,
+    /// 每分钟请求数
+    pub requests_per_minute: u32,
+    /// 同时执行的任务数
+    pub max_concurrency: u32,
+    /// 爬取的最大深度
+    pub max_crawl_depth: u32,
+}
+
+/// 团队的套餐及月度积分发放状态
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub struct TeamPlanAssignment {
+    /// 团队 ID
+    pub team_id: Uuid,
+    /// 套餐
+    pub plan: TeamPlan,
+    /// 最近一次发放月度积分的时间
+    pub credits_granted_at: Option<DateTime<Utc>>,
+}
+
+impl TeamPlanAssignment {
+    /// 本月（UTC）的月度积分是否尚未发放
+    pub fn credit_grant_due(&self, now: DateTime<Utc>) -> bool {
+        let period_start = credit_period_start(now);
+        self.credits_granted_at
+            .is_none_or(|granted_at| granted_at < period_start)
+    }
+}
+
+/// `now` 所在自然月（UTC）的起始时间，月度积分按该时间划分周期
+pub fn credit_period_start(now: DateTime<Utc>) -> DateTime<Utc> {
+    use chrono::{Datelike, TimeZone};
+    Utc.with_ymd_and_hms(now.year(), now.month(), 1, 0, 0, 0)
+        .single()
+        .unwrap_or(now)
+}
+
 /// 团队领域错误类型
 #[derive(Debug, thiserror::Error)]
 pub enum TeamError {
@@ -170,6 +259,52 @@ mod tests {
         assert_eq!(team, deserialized);
     }
 
+    #[test]
+    fn test_team_capabilities_default_denies_ignore_robots() {
+        let capabilities = TeamCapabilities::default();
+        assert!(!capabilities.allow_ignore_robots);
+
+        let json = serde_json::to_value(TeamCapabilities {
+            allow_ignore_robots: true,
+        })
+        .expect("serialize");
+        assert_eq!(json, serde_json::json!({"allow_ignore_robots": true}));
+    }
+
+    #[test]
+    fn test_team_plan_round_trip() {
+        for plan in [TeamPlan::Free, TeamPlan::Pro, TeamPlan::Enterprise] {
+            assert_eq!(plan.to_string().parse::<TeamPlan>().unwrap(), plan);
+            let json = serde_json::to_value(plan).expect("serialize");
+            assert_eq!(json, serde_json::json!(plan.to_string()));
+        }
+        assert_eq!("PRO".parse::<TeamPlan>().unwrap(), TeamPlan::Pro);
+        assert!("gold".parse::<TeamPlan>().is_err());
+        assert_eq!(TeamPlan::default(), TeamPlan::Free);
+    }
+
+    #[test]
+    fn test_credit_grant_due_once_per_calendar_month() {
+        let now = "2025-03-15T10:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        assert_eq!(
+            credit_period_start(now),
+            "2025-03-01T00:00:00Z".parse::<DateTime<Utc>>().unwrap()
+        );
+
+        let mut assignment = TeamPlanAssignment {
+            team_id: Uuid::new_v4(),
+            plan: TeamPlan::Free,
+            credits_granted_at: None,
+        };
+        assert!(assignment.credit_grant_due(now));
+
+        assignment.credits_granted_at = Some("2025-03-01T00:05:00Z".parse().unwrap());
+        assert!(!assignment.credit_grant_due(now));
+
+        assignment.credits_granted_at = Some("2025-02-28T23:59:59Z".parse().unwrap());
+        assert!(assignment.credit_grant_due(now));
+    }
+
     #[test]
     fn test_team_error_display() {
         let invalid = TeamError::InvalidName("bad".to_string());
@@ -0,0 +1,301 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! URL blocklist model - domains and URLs that must never be scraped
+//!
+//! Entries are either global (configured in settings or stored without a
+//! team) or scoped to a single team. They are enforced when a request is
+//! submitted and again when a crawl expands links.
+//!
+//! - `wildcard` patterns match the host: `example.com` blocks the domain and
+//!   all its subdomains, `*` matches any sequence of characters
+//!   (`*.example.com`, `ads.*.net`).
+//! - `regex` patterns match the full URL.
+
+use chrono::{DateTime, Utc};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+use url::Url;
+use uuid::Uuid;
+
+/// Prefix that marks a settings pattern as a regular expression
+pub const REGEX_PATTERN_PREFIX: &str = "regex:";
+
+/// How a blocklist pattern is matched
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BlocklistPatternType {
+    /// Host pattern with `*` wildcards
+    Wildcard,
+    /// Regular expression over the full URL
+    Regex,
+}
+
+impl BlocklistPatternType {
+    /// String representation used for storage
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BlocklistPatternType::Wildcard => "wildcard",
+            BlocklistPatternType::Regex => "regex",
+        }
+    }
+}
+
+impl fmt::Display for BlocklistPatternType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for BlocklistPatternType {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "wildcard" => Ok(BlocklistPatternType::Wildcard),
+            "regex" => Ok(BlocklistPatternType::Regex),
+            _ => Err(()),
+        }
+    }
+}
+
+/// A single blocklist entry
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BlocklistEntry {
+    /// Unique identifier for the entry
+    pub id: Uuid,
+    /// Team the entry applies to; `None` for a global entry
+    pub team_id: Option<Uuid>,
+    /// Pattern to match
+    pub pattern: String,
+    /// How the pattern is matched
+    pub pattern_type: BlocklistPatternType,
+    /// Why the entry exists (legal request, abuse report, ...)
+    pub reason: Option<String>,
+    /// When the entry was created
+    pub created_at: DateTime<Utc>,
+}
+
+impl BlocklistEntry {
+    /// Create a new entry
+    pub fn new(
+        team_id: Option<Uuid>,
+        pattern: impl Into<String>,
+        pattern_type: BlocklistPatternType,
+    ) -> Self {
+        let pattern = pattern.into().trim().to_string();
+        Self {
+            id: Uuid::new_v4(),
+            team_id,
+            pattern: match pattern_type {
+                BlocklistPatternType::Wildcard => pattern.to_ascii_lowercase(),
+                BlocklistPatternType::Regex => pattern,
+            },
+            pattern_type,
+            reason: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    /// Create a global entry from a settings pattern
+    ///
+    /// Patterns prefixed with `regex:` are regular expressions, everything
+    /// else is a wildcard host pattern.
+    pub fn from_setting(pattern: &str) -> Self {
+        match pattern.trim().strip_prefix(REGEX_PATTERN_PREFIX) {
+            Some(regex) => Self::new(None, regex, BlocklistPatternType::Regex),
+            None => Self::new(None, pattern, BlocklistPatternType::Wildcard),
+        }
+        .with_reason("configured in settings")
+    }
+
+    /// Set the reason
+    pub fn with_reason(mut self, reason: impl Into<String>) -> Self {
+        self.reason = Some(reason.into());
+        self
+    }
+
+    /// Whether the entry applies to every team
+    pub fn is_global(&self) -> bool {
+        self.team_id.is_none()
+    }
+
+    /// Validate the pattern
+    pub fn validate(&self) -> Result<(), String> {
+        self.compile().map(|_| ())
+    }
+
+    /// Compile the pattern into a regular expression
+    fn compile(&self) -> Result<Regex, String> {
+        if self.pattern.is_empty() {
+            return Err("pattern must not be empty".to_string());
+        }
+        match self.pattern_type {
+            BlocklistPatternType::Regex => Regex::new(&self.pattern).map_err(|e| e.to_string()),
+            BlocklistPatternType::Wildcard => {
+                if self.pattern.contains(['/', ':', '?', '#', ' ']) {
+                    return Err("wildcard pattern must be a host name".to_string());
+                }
+                let body = self
+                    .pattern
+                    .split('*')
+                    .map(regex::escape)
+                    .collect::<Vec<_>>()
+                    .join(".*");
+                // A plain domain also covers its subdomains
+                let expr = if self.pattern.contains('*') {
+                    format!("^{}$", body)
+                } else {
+                    format!(r"^(?:.+\.)?{}$", body)
+                };
+                Regex::new(&expr).map_err(|e| e.to_string())
+            }
+        }
+    }
+}
+
+/// Compiled blocklist used to check URLs
+#[derive(Debug, Clone, Default)]
+pub struct UrlBlocklist {
+    /// Entries with their compiled matchers
+    rules: Vec<(BlocklistEntry, Regex)>,
+}
+
+impl UrlBlocklist {
+    /// Compile entries, ignoring invalid patterns
+    pub fn new(entries: impl IntoIterator<Item = BlocklistEntry>) -> Self {
+        let rules = entries
+            .into_iter()
+            .filter_map(|entry| match entry.compile() {
+                Ok(regex) => Some((entry, regex)),
+                Err(e) => {
+                    log::warn!(
+                        "Ignoring invalid blocklist pattern '{}': {}",
+                        entry.pattern,
+                        e
+                    );
+                    None
+                }
+            })
+            .collect();
+        Self { rules }
+    }
+
+    /// Whether the blocklist has no entries
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// Find the first entry that blocks the URL
+    ///
+    /// Unparseable URLs never match; they are rejected by URL validation.
+    pub fn find_match(&self, url: &str) -> Option<&BlocklistEntry> {
+        if self.rules.is_empty() {
+            return None;
+        }
+        let parsed = Url::parse(url).ok()?;
+        let host = parsed
+            .host_str()
+            .map(|host| host.trim_end_matches('.').to_ascii_lowercase());
+
+        self.rules
+            .iter()
+            .find(|(entry, regex)| match entry.pattern_type {
+                BlocklistPatternType::Regex => regex.is_match(url),
+                BlocklistPatternType::Wildcard => {
+                    host.as_deref().is_some_and(|host| regex.is_match(host))
+                }
+            })
+            .map(|(entry, _)| entry)
+    }
+
+    /// Whether the URL is blocked
+    pub fn is_blocked(&self, url: &str) -> bool {
+        self.find_match(url).is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wildcard(pattern: &str) -> BlocklistEntry {
+        BlocklistEntry::new(None, pattern, BlocklistPatternType::Wildcard)
+    }
+
+    #[test]
+    fn test_pattern_type_roundtrip() {
+        for pattern_type in [BlocklistPatternType::Wildcard, BlocklistPatternType::Regex] {
+            assert_eq!(pattern_type.as_str().parse(), Ok(pattern_type));
+        }
+        assert!("glob".parse::<BlocklistPatternType>().is_err());
+    }
+
+    #[test]
+    fn test_plain_domain_blocks_subdomains() {
+        let blocklist = UrlBlocklist::new(vec![wildcard("Example.com")]);
+        assert!(blocklist.is_blocked("https://example.com/page"));
+        assert!(blocklist.is_blocked("https://www.EXAMPLE.com"));
+        assert!(!blocklist.is_blocked("https://myexample.com"));
+        assert!(!blocklist.is_blocked("https://example.com.evil.org"));
+    }
+
+    #[test]
+    fn test_wildcard_patterns() {
+        let blocklist = UrlBlocklist::new(vec![wildcard("*.tracker.io"), wildcard("ads.*.net")]);
+        assert!(blocklist.is_blocked("https://a.tracker.io"));
+        assert!(!blocklist.is_blocked("https://tracker.io"));
+        assert!(blocklist.is_blocked("http://ads.cdn.net/x.js"));
+        assert!(!blocklist.is_blocked("http://cdn.net"));
+    }
+
+    #[test]
+    fn test_regex_matches_full_url() {
+        let blocklist = UrlBlocklist::new(vec![BlocklistEntry::new(
+            None,
+            r"^https://docs\.example\.com/private/",
+            BlocklistPatternType::Regex,
+        )]);
+        assert!(blocklist.is_blocked("https://docs.example.com/private/a"));
+        assert!(!blocklist.is_blocked("https://docs.example.com/public/a"));
+    }
+
+    #[test]
+    fn test_from_setting_detects_regex_prefix() {
+        let entry = BlocklistEntry::from_setting("regex:.*\\.pdf$");
+        assert_eq!(entry.pattern_type, BlocklistPatternType::Regex);
+        assert_eq!(entry.pattern, ".*\\.pdf$");
+        assert!(entry.is_global());
+
+        let entry = BlocklistEntry::from_setting(" Example.com ");
+        assert_eq!(entry.pattern_type, BlocklistPatternType::Wildcard);
+        assert_eq!(entry.pattern, "example.com");
+    }
+
+    #[test]
+    fn test_invalid_patterns_are_rejected_and_ignored() {
+        let bad_regex = BlocklistEntry::new(None, "(", BlocklistPatternType::Regex);
+        assert!(bad_regex.validate().is_err());
+        assert!(wildcard("example.com/path").validate().is_err());
+        assert!(wildcard("").validate().is_err());
+
+        let blocklist = UrlBlocklist::new(vec![bad_regex, wildcard("example.com")]);
+        assert_eq!(
+            blocklist
+                .find_match("https://example.com")
+                .map(|e| e.pattern.as_str()),
+            Some("example.com")
+        );
+    }
+
+    #[test]
+    fn test_unparseable_url_is_not_matched() {
+        let blocklist = UrlBlocklist::new(vec![wildcard("*")]);
+        assert!(!blocklist.is_blocked("not a url"));
+        assert!(blocklist.is_blocked("https://anything.test"));
+    }
+}
@@ -10,8 +10,20 @@
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
 use uuid::Uuid;
 
+/// Headers managed by crawlrs that custom headers may not override
+const RESERVED_HEADERS: &[&str] = &[
+    "content-type",
+    "content-length",
+    "host",
+    "x-crawlrs-signature",
+    "x-crawlrs-timestamp",
+    "x-crawlrs-event-id",
+];
+
 /// Webhook domain model
 ///
 /// Represents a webhook endpoint for delivering event notifications.
@@ -24,21 +36,58 @@ pub struct Webhook {
     pub team_id: Uuid,
     /// Webhook endpoint URL
     pub url: String,
+    /// Subscribed event types (empty means all events)
+    #[serde(default, with = "event_type_list")]
+    pub event_types: Vec<WebhookEventType>,
+    /// Static headers sent with every delivery (e.g. receiver auth tokens)
+    #[serde(default)]
+    pub custom_headers: HashMap<String, String>,
+    /// Optional JSON payload template with `{{path}}` placeholders
+    #[serde(default)]
+    pub payload_template: Option<String>,
     /// When the webhook was created
     pub created_at: DateTime<Utc>,
 }
 
 impl Webhook {
-    /// Create a new webhook
+    /// Create a new webhook subscribed to all events
     pub fn new(id: Uuid, team_id: Uuid, url: String) -> Self {
         Self {
             id,
             team_id,
             url,
+            event_types: Vec::new(),
+            custom_headers: HashMap::new(),
+            payload_template: None,
             created_at: Utc::now(),
         }
     }
 
+    /// Attach static headers sent with every delivery
+    pub fn with_custom_headers(mut self, custom_headers: HashMap<String, String>) -> Self {
+        self.custom_headers = custom_headers;
+        self
+    }
+
+    /// Attach a payload template used to shape delivered events
+    pub fn with_payload_template(mut self, payload_template: Option<String>) -> Self {
+        self.payload_template = payload_template;
+        self
+    }
+
+    /// Restrict the webhook to the given event types
+    pub fn with_event_types(mut self, event_types: Vec<WebhookEventType>) -> Self {
+        self.event_types = event_types;
+        self
+    }
+
+    /// Check whether the webhook is subscribed to the given event type
+    ///
+    /// A webhook without an explicit filter receives every event.
+    pub fn accepts(&self, event_type: &WebhookEventType) -> bool {
+        self.event_types.is_empty() || self.event_types.contains(event_type)
+    }
+
     /// Validate the webhook URL
     pub fn validate_url(&self) -> Result<(), WebhookError> {
         // Basic URL validation
@@ -55,6 +104,147 @@ impl Webhook {
 
         Ok(())
     }
+
+    /// Validate custom headers and the payload template
+    ///
+    /// Custom headers may not override signature or transport headers,
+    /// and the template must be valid JSON.
+    pub fn validate_delivery_options(&self) -> Result<(), WebhookError> {
+        for name in self.custom_headers.keys() {
+            let lower = name.to_ascii_lowercase();
+            if name.is_empty()
+                || !name
+                    .bytes()
+                    .all(|b| b.is_ascii_alphanumeric() || b == b'-' || b == b'_')
+            {
+                return Err(WebhookError::InvalidConfig(format!(
+                    "invalid header name: {}",
+                    name
+                )));
+            }
+            if RESERVED_HEADERS.contains(&lower.as_str()) {
+                return Err(WebhookError::InvalidConfig(format!(
+                    "header {} is managed by crawlrs and cannot be overridden",
+                    name
+                )));
+            }
+        }
+        for value in self.custom_headers.values() {
+            if value.chars().any(|c| c.is_control()) {
+                return Err(WebhookError::InvalidConfig(
+                    "header values must not contain control characters".to_string(),
+                ));
+            }
+        }
+
+        if let Some(template) = &self.payload_template {
+            serde_json::from_str::<Value>(template).map_err(|e| {
+                WebhookError::InvalidConfig(format!("payload_template is not valid JSON: {}", e))
+            })?;
+        }
+
+        Ok(())
+    }
+
+    /// Shape an event payload with the webhook's template
+    ///
+    /// Placeholders use `{{path.to.field}}` against a context containing the
+    /// event payload fields plus `event` (the event type name). A string that
+    /// consists solely of one placeholder is replaced by the raw JSON value
+    /// (keeping numbers, objects, etc.); placeholders embedded in longer
+    /// strings are interpolated as text. Missing paths render as `null` / empty.
+    /// Without a template the payload is returned unchanged.
+    pub fn render_payload(
+        &self,
+        event_type: &WebhookEventType,
+        payload: &Value,
+    ) -> Result<Value, WebhookError> {
+        let template = match &self.payload_template {
+            Some(t) => t,
+            None => return Ok(payload.clone()),
+        };
+        let template: Value = serde_json::from_str(template).map_err(|e| {
+            WebhookError::InvalidConfig(format!("payload_template is not valid JSON: {}", e))
+        })?;
+
+        let mut context = match payload {
+            Value::Object(map) => map.clone(),
+            other => {
+                let mut map = serde_json::Map::new();
+                map.insert("data".to_string(), other.clone());
+                map
+            }
+        };
+        context.insert("event".to_string(), Value::String(event_type.to_string()));
+
+        Ok(render_template_value(&template, &Value::Object(context)))
+    }
+}
+
+/// Recursively substitute placeholders in a template value
+fn render_template_value(template: &Value, context: &Value) -> Value {
+    match template {
+        Value::String(s) => render_template_string(s, context),
+        Value::Array(items) => Value::Array(
+            items
+                .iter()
+                .map(|item| render_template_value(item, context))
+                .collect(),
+        ),
+        Value::Object(map) => Value::Object(
+            map.iter()
+                .map(|(k, v)| (k.clone(), render_template_value(v, context)))
+                .collect(),
+        ),
+        other => other.clone(),
+    }
+}
+
+fn render_template_string(s: &str, context: &Value) -> Value {
+    let trimmed = s.trim();
+    if let Some(path) = trimmed
+        .strip_prefix("{{")
+        .and_then(|rest| rest.strip_suffix("}}"))
+    {
+        if !path.contains("{{") && !path.contains("}}") {
+            return lookup_path(context, path.trim())
+                .cloned()
+                .unwrap_or(Value::Null);
+        }
+    }
+
+    let mut out = String::with_capacity(s.len());
+    let mut rest = s;
+    while let Some(start) = rest.find("{{") {
+        out.push_str(&rest[..start]);
+        let after = &rest[start + 2..];
+        match after.find("}}") {
+            Some(end) => {
+                match lookup_path(context, after[..end].trim()) {
+                    Some(Value::String(v)) => out.push_str(v),
+                    Some(Value::Null) | None => {}
+                    Some(v) => out.push_str(&v.to_string()),
+                }
+                rest = &after[end + 2..];
+            }
+            None => {
+                out.push_str(&rest[start..]);
+                rest = "";
+            }
+        }
+    }
+    out.push_str(rest);
+    Value::String(out)
+}
+
+fn lookup_path<'a>(context: &'a Value, path: &str) -> Option<&'a Value> {
+    path.split('.')
+        .filter(|segment| !segment.is_empty())
+        .try_fold(context, |value, segment| match value {
+            Value::Object(map) => map.get(segment),
+            Value::Array(items) => segment.parse::<usize>().ok().and_then(|i| items.get(i)),
+            _ => None,
+        })
 }
 
 /// Webhook event domain model
@@ -94,6 +284,9 @@ pub struct WebhookEvent {
     pub updated_at: DateTime<Utc>,
     /// When the event was delivered (if successful)
     pub delivered_at: Option<DateTime<Utc>>,
+    /// Channel the event is delivered through (webhook, email or a chat channel)
+    #[serde(default)]
+    pub channel: DeliveryChannel,
 }
 
 impl WebhookEvent {
@@ -124,9 +317,16 @@ impl WebhookEvent {
             created_at: now,
             updated_at: now,
             delivered_at: None,
+            channel: DeliveryChannel::Webhook,
         }
     }
 
+    /// Deliver the event through a notification channel instead of a webhook
+    pub fn with_channel(mut self, channel: DeliveryChannel) -> Self {
+        self.channel = channel;
+        self
+    }
+
     /// Create a webhook event with all fields (for mappers)
     #[allow(clippy::too_many_arguments)]
     pub fn with_all_fields(
@@ -164,6 +364,7 @@ impl WebhookEvent {
             created_at,
             updated_at,
             delivered_at,
+            channel: DeliveryChannel::Webhook,
         }
     }
 
@@ -230,10 +431,22 @@ pub enum WebhookEventType {
     CrawlCompleted,
     /// Crawl failed
     CrawlFailed,
+    /// Crawl finished, with aggregated page/credit statistics
+    CrawlSummary,
     /// Scrape completed successfully
     ScrapeCompleted,
     /// Scrape failed
     ScrapeFailed,
+    /// Team credit balance dropped below its low-balance alert threshold
+    CreditsLow,
+    /// Team data export archive is ready for download
+    ExportCompleted,
+    /// Team data export failed
+    ExportFailed,
+    /// A monitored page changed since its previous check
+    MonitorChanged,
+    /// A webhook event was given up after its last delivery attempt (email and chat channel notifications only)
+    WebhookDeliveryFailed,
     /// Custom event type
     Custom(String),
 }
@@ -243,8 +456,14 @@ impl std::fmt::Display for WebhookEventType {
         match self {
             WebhookEventType::CrawlCompleted => write!(f, "crawl.completed"),
             WebhookEventType::CrawlFailed => write!(f, "crawl.failed"),
+            WebhookEventType::CrawlSummary => write!(f, "crawl.summary"),
             WebhookEventType::ScrapeCompleted => write!(f, "scrape.completed"),
             WebhookEventType::ScrapeFailed => write!(f, "scrape.failed"),
+            WebhookEventType::CreditsLow => write!(f, "credits.low"),
+            WebhookEventType::ExportCompleted => write!(f, "export.completed"),
+            WebhookEventType::ExportFailed => write!(f, "export.failed"),
+            WebhookEventType::MonitorChanged => write!(f, "monitor.changed"),
+            WebhookEventType::WebhookDeliveryFailed => write!(f, "webhook.delivery_failed"),
             WebhookEventType::Custom(s) => write!(f, "{}", s),
         }
     }
@@ -257,13 +476,40 @@ impl std::str::FromStr for WebhookEventType {
         match s {
             "crawl.completed" => Ok(WebhookEventType::CrawlCompleted),
             "crawl.failed" => Ok(WebhookEventType::CrawlFailed),
+            "crawl.summary" => Ok(WebhookEventType::CrawlSummary),
             "scrape.completed" => Ok(WebhookEventType::ScrapeCompleted),
             "scrape.failed" => Ok(WebhookEventType::ScrapeFailed),
+            "credits.low" => Ok(WebhookEventType::CreditsLow),
+            "export.completed" => Ok(WebhookEventType::ExportCompleted),
+            "export.failed" => Ok(WebhookEventType::ExportFailed),
+            "monitor.changed" => Ok(WebhookEventType::MonitorChanged),
+            "webhook.delivery_failed" => Ok(WebhookEventType::WebhookDeliveryFailed),
             s => Ok(WebhookEventType::Custom(s.to_string())),
         }
     }
 }
 
+/// Serialize a list of event types using their wire names (`crawl.completed`, ...)
+pub(crate) mod event_type_list {
+    use super::WebhookEventType;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S: Serializer>(
+        event_types: &[WebhookEventType],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let names: Vec<String> = event_types.iter().map(|t| t.to_string()).collect();
+        names.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<WebhookEventType>, D::Error> {
+        let names = Vec::<String>::deserialize(deserializer)?;
+        Ok(names.iter().filter_map(|name| name.parse().ok()).collect())
+    }
+}
+
 /// Webhook status enumeration
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
 #[serde(rename_all = "snake_case")]
@@ -304,6 +550,49 @@ impl std::str::FromStr for WebhookStatus {
     }
 }
 
+/// Channel a webhook event is delivered through
+///
+/// Webhook events double as the delivery log of every notification channel:
+/// email and chat notifications are recorded and retried like webhooks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum DeliveryChannel {
+    /// Signed POST to a registered or task-level webhook
+    #[default]
+    Webhook,
+    /// Email to the team's notification contacts
+    Email,
+    /// Slack incoming webhook
+    Slack,
+    /// Generic JSON POST
+    Json,
+}
+
+impl std::fmt::Display for DeliveryChannel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DeliveryChannel::Webhook => write!(f, "webhook"),
+            DeliveryChannel::Email => write!(f, "email"),
+            DeliveryChannel::Slack => write!(f, "slack"),
+            DeliveryChannel::Json => write!(f, "json"),
+        }
+    }
+}
+
+impl std::str::FromStr for DeliveryChannel {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "webhook" => Ok(DeliveryChannel::Webhook),
+            "email" => Ok(DeliveryChannel::Email),
+            "slack" => Ok(DeliveryChannel::Slack),
+            "json" => Ok(DeliveryChannel::Json),
+            _ => Err(format!("Invalid delivery channel: {}", s)),
+        }
+    }
+}
+
 /// Webhook domain errors
 #[derive(Debug, thiserror::Error)]
 pub enum WebhookError {
@@ -318,6 +607,10 @@ pub enum WebhookError {
     /// Database error
     #[error("Database error: {0}")]
     DatabaseError(String),
+
+    /// Invalid headers or payload template
+    #[error("Invalid webhook configuration: {0}")]
+    InvalidConfig(String),
 }
 
 #[cfg(test)]
@@ -406,6 +699,138 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_webhook_without_filter_accepts_all_events() {
+        let webhook = Webhook::new(
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            "https://example.com/webhook".to_string(),
+        );
+        assert!(webhook.accepts(&WebhookEventType::CrawlCompleted));
+        assert!(webhook.accepts(&WebhookEventType::Custom("page.scraped".to_string())));
+    }
+
+    #[test]
+    fn test_webhook_with_filter_accepts_only_subscribed_events() {
+        let webhook = Webhook::new(
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            "https://example.com/webhook".to_string(),
+        )
+        .with_event_types(vec![
+            WebhookEventType::CrawlCompleted,
+            WebhookEventType::Custom("page.scraped".to_string()),
+        ]);
+        assert!(webhook.accepts(&WebhookEventType::CrawlCompleted));
+        assert!(webhook.accepts(&WebhookEventType::Custom("page.scraped".to_string())));
+        assert!(!webhook.accepts(&WebhookEventType::ScrapeFailed));
+    }
+
+    #[test]
+    fn test_validate_delivery_options_rejects_reserved_header() {
+        let webhook = Webhook::new(
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            "https://example.com/webhook".to_string(),
+        )
+        .with_custom_headers(HashMap::from([(
+            "X-Crawlrs-Signature".to_string(),
+            "forged".to_string(),
+        )]));
+        assert!(matches!(
+            webhook.validate_delivery_options(),
+            Err(WebhookError::InvalidConfig(_))
+        ));
+    }
+
+    #[test]
+    fn test_validate_delivery_options_accepts_auth_header_and_json_template() {
+        let webhook = Webhook::new(
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            "https://example.com/webhook".to_string(),
+        )
+        .with_custom_headers(HashMap::from([(
+            "Authorization".to_string(),
+            "Bearer abc".to_string(),
+        )]))
+        .with_payload_template(Some(r#"{"text": "{{event}}"}"#.to_string()));
+        assert!(webhook.validate_delivery_options().is_ok());
+    }
+
+    #[test]
+    fn test_validate_delivery_options_rejects_invalid_template() {
+        let webhook = Webhook::new(
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            "https://example.com/webhook".to_string(),
+        )
+        .with_payload_template(Some("{not json".to_string()));
+        assert!(webhook.validate_delivery_options().is_err());
+    }
+
+    #[test]
+    fn test_render_payload_without_template_is_identity() {
+        let webhook = Webhook::new(
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            "https://example.com/webhook".to_string(),
+        );
+        let payload = serde_json::json!({"task_id": "t1"});
+        let rendered = webhook
+            .render_payload(&WebhookEventType::ScrapeCompleted, &payload)
+            .unwrap();
+        assert_eq!(rendered, payload);
+    }
+
+    #[test]
+    fn test_render_payload_with_template() {
+        let template = r#"{
+            "text": "Task {{task_id}} {{status}} ({{event}})",
+            "attempts": "{{meta.attempts}}",
+            "raw": "{{meta}}",
+            "missing": "{{nope}}",
+            "list": ["{{url}}"]
+        }"#;
+        let webhook = Webhook::new(
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            "https://example.com/webhook".to_string(),
+        )
+        .with_payload_template(Some(template.to_string()));
+        let payload = serde_json::json!({
+            "task_id": "t1",
+            "status": "completed",
+            "url": "https://site.test",
+            "meta": {"attempts": 3}
+        });
+
+        let rendered = webhook
+            .render_payload(&WebhookEventType::CrawlCompleted, &payload)
+            .unwrap();
+
+        assert_eq!(rendered["text"], "Task t1 completed (crawl.completed)");
+        assert_eq!(rendered["attempts"], 3);
+        assert_eq!(rendered["raw"], serde_json::json!({"attempts": 3}));
+        assert_eq!(rendered["missing"], Value::Null);
+        assert_eq!(rendered["list"], serde_json::json!(["https://site.test"]));
+    }
+
+    #[test]
+    fn test_webhook_event_types_serialize_as_wire_names() {
+        let webhook = Webhook::new(
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            "https://example.com/webhook".to_string(),
+        )
+        .with_event_types(vec![WebhookEventType::ScrapeFailed]);
+        let json = serde_json::to_value(&webhook).unwrap();
+        assert_eq!(json["event_types"], serde_json::json!(["scrape.failed"]));
+
+        let back: Webhook = serde_json::from_value(json).unwrap();
+        assert_eq!(back.event_types, vec![WebhookEventType::ScrapeFailed]);
+    }
+
     // ========== WebhookEvent::new tests ==========
 
     #[test]
@@ -683,11 +1108,26 @@ mod tests {
             "crawl.completed"
         );
         assert_eq!(WebhookEventType::CrawlFailed.to_string(), "crawl.failed");
+        assert_eq!(WebhookEventType::CrawlSummary.to_string(), "crawl.summary");
         assert_eq!(
             WebhookEventType::ScrapeCompleted.to_string(),
             "scrape.completed"
         );
         assert_eq!(WebhookEventType::ScrapeFailed.to_string(), "scrape.failed");
+        assert_eq!(WebhookEventType::CreditsLow.to_string(), "credits.low");
+        assert_eq!(
+            WebhookEventType::ExportCompleted.to_string(),
+            "export.completed"
+        );
+        assert_eq!(WebhookEventType::ExportFailed.to_string(), "export.failed");
+        assert_eq!(
+            WebhookEventType::MonitorChanged.to_string(),
+            "monitor.changed"
+        );
+        assert_eq!(
+            WebhookEventType::WebhookDeliveryFailed.to_string(),
+            "webhook.delivery_failed"
+        );
         assert_eq!(
             WebhookEventType::Custom("custom.event".to_string()).to_string(),
             "custom.event"
@@ -704,6 +1144,10 @@ mod tests {
             WebhookEventType::from_str("crawl.failed").expect("valid"),
             WebhookEventType::CrawlFailed
         );
+        assert_eq!(
+            WebhookEventType::from_str("crawl.summary").expect("valid"),
+            WebhookEventType::CrawlSummary
+        );
         assert_eq!(
             WebhookEventType::from_str("scrape.completed").expect("valid"),
             WebhookEventType::ScrapeCompleted
@@ -712,6 +1156,26 @@ mod tests {
             WebhookEventType::from_str("scrape.failed").expect("valid"),
             WebhookEventType::ScrapeFailed
         );
+        assert_eq!(
+            WebhookEventType::from_str("credits.low").expect("valid"),
+            WebhookEventType::CreditsLow
+        );
+        assert_eq!(
+            WebhookEventType::from_str("export.completed").expect("valid"),
+            WebhookEventType::ExportCompleted
+        );
+        assert_eq!(
+            WebhookEventType::from_str("export.failed").expect("valid"),
+            WebhookEventType::ExportFailed
+        );
+        assert_eq!(
+            WebhookEventType::from_str("monitor.changed").expect("valid"),
+            WebhookEventType::MonitorChanged
+        );
+        assert_eq!(
+            WebhookEventType::from_str("webhook.delivery_failed").expect("valid"),
+            WebhookEventType::WebhookDeliveryFailed
+        );
     }
 
     #[test]
@@ -784,6 +1248,28 @@ mod tests {
         assert_eq!(WebhookStatus::default(), WebhookStatus::Pending);
     }
 
+    // ========== DeliveryChannel tests ==========
+
+    #[test]
+    fn test_delivery_channel_roundtrip_and_default() {
+        for channel in [
+            DeliveryChannel::Webhook,
+            DeliveryChannel::Email,
+            DeliveryChannel::Slack,
+            DeliveryChannel::Json,
+        ] {
+            assert_eq!(DeliveryChannel::from_str(&channel.to_string()), Ok(channel));
+        }
+        assert!(DeliveryChannel::from_str("sms").is_err());
+
+        let event = make_event();
+        assert_eq!(event.channel, DeliveryChannel::Webhook);
+        assert_eq!(
+            event.with_channel(DeliveryChannel::Slack).channel,
+            DeliveryChannel::Slack
+        );
+    }
+
     // ========== WebhookError tests ==========
 
     #[test]
@@ -45,6 +45,11 @@ pub trait AuthScopeRepository: Send + Sync {
         api_key_id: Uuid,
     ) -> Result<Option<ApiKeyScope>, RepositoryError>;
     async fn find_by_api_key(&self, key: &str) -> Result<Option<ApiKeyScope>, RepositoryError>;
+    /// Team that owns the API key, `None` if the key does not exist
+    async fn find_team_id_by_api_key_id(
+        &self,
+        api_key_id: Uuid,
+    ) -> Result<Option<Uuid>, RepositoryError>;
     async fn upsert(
         &self,
         api_key_id: Uuid,
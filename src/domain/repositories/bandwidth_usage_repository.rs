@@ -0,0 +1,30 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+use super::task_repository::RepositoryError;
+use crate::domain::models::DailyBandwidthUsage;
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use uuid::Uuid;
+
+/// 流量用量仓库特质
+///
+/// 存储团队每个 UTC 日的下载流量汇总
+#[async_trait]
+pub trait BandwidthUsageRepository: Send + Sync {
+    /// 写入团队某天的累计用量，已有记录时保留较大的值
+    async fn upsert_daily(
+        &self,
+        team_id: Uuid,
+        usage: &DailyBandwidthUsage,
+    ) -> Result<(), RepositoryError>;
+    /// 查询团队在 `[from, to]` 日期范围内的每日用量，按日期升序
+    async fn list_daily(
+        &self,
+        team_id: Uuid,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Result<Vec<DailyBandwidthUsage>, RepositoryError>;
+}
@@ -0,0 +1,24 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+use super::task_repository::RepositoryError;
+use crate::domain::models::CrawlLink;
+use async_trait::async_trait;
+use uuid::Uuid;
+
+/// 爬取链接仓库特质
+///
+/// 存储爬取过程中发现的页面链接，用于导出站点链接图
+#[async_trait]
+pub trait CrawlLinkRepository: Send + Sync {
+    /// 批量记录链接，已存在的（爬取、来源、目标）组合被忽略，返回新增条数
+    async fn record(&self, links: &[CrawlLink]) -> Result<u64, RepositoryError>;
+    /// 按发现顺序查询爬取的链接，最多返回 `limit` 条
+    async fn find_by_crawl(
+        &self,
+        crawl_id: Uuid,
+        limit: u64,
+    ) -> Result<Vec<CrawlLink>, RepositoryError>;
+}
@@ -88,6 +88,31 @@ pub trait CrawlRepository: Send + Sync {
     /// * `Err(RepositoryError)` - 更新失败时返回错误
     async fn update_status(&self, id: Uuid, status: CrawlStatus) -> Result<(), RepositoryError>;
 
+    /// 将尚未结束的爬取转为结束状态
+    ///
+    /// 多个 Worker 同时完成最后的任务时只有一个能完成转换，调用方据此保证
+    /// `crawl.summary` 等结束事件只发送一次。默认实现先读后写，不具备原子性，
+    /// 数据库实现应以单条条件更新覆盖。
+    ///
+    /// # 参数
+    ///
+    /// * `id` - 爬取任务的唯一标识符
+    /// * `status` - 结束状态
+    ///
+    /// # 返回值
+    ///
+    /// * `Ok(true)` - 本次调用完成了转换
+    /// * `Ok(false)` - 爬取不存在或已处于结束状态
+    /// * `Err(RepositoryError)` - 更新失败时返回错误
+    async fn mark_finished(&self, id: Uuid, status: CrawlStatus) -> Result<bool, RepositoryError> {
+        match self.find_by_id(id).await? {
+            Some(crawl) if !crawl.is_finished() => {
+                self.update_status(id, status).await.map(|_| true)
+            }
+            _ => Ok(false),
+        }
+    }
+
     /// 增加总任务计数
     ///
     /// # 参数
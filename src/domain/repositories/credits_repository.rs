@@ -4,10 +4,13 @@
 // See LICENSE file in the project root for full license information.
 
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use thiserror::Error;
 use uuid::Uuid;
 
-use crate::domain::models::{CreditsTransaction, CreditsTransactionType};
+use crate::domain::models::{
+    CreditsTransaction, CreditsTransactionType, DailyCreditsUsage, LowBalanceAlert,
+};
 
 #[derive(Error, Debug)]
 pub enum CreditsRepositoryError {
@@ -19,6 +22,28 @@ pub enum CreditsRepositoryError {
     CreditsNotFound(Uuid),
 }
 
+/// Filters for querying a team's credits transactions
+#[derive(Debug, Clone, Default)]
+pub struct CreditsTransactionFilter {
+    /// Only transactions created at or after this time
+    pub from: Option<DateTime<Utc>>,
+    /// Only transactions created before this time
+    pub to: Option<DateTime<Utc>>,
+    /// Only transactions of this type
+    pub transaction_type: Option<CreditsTransactionType>,
+}
+
+impl CreditsTransactionFilter {
+    /// Whether a transaction matches the filter
+    pub fn matches(&self, transaction: &CreditsTransaction) -> bool {
+        self.from.is_none_or(|from| transaction.created_at >= from)
+            && self.to.is_none_or(|to| transaction.created_at < to)
+            && self
+                .transaction_type
+                .is_none_or(|kind| transaction.transaction_type == kind)
+    }
+}
+
 #[async_trait]
 pub trait CreditsRepository: Send + Sync {
     /// Get credits balance for a team
@@ -57,4 +82,94 @@ pub trait CreditsRepository: Send + Sync {
         team_id: Uuid,
         initial_balance: i64,
     ) -> Result<i64, CreditsRepositoryError>;
+
+    /// Total credits deducted for transactions referencing any of `reference_ids`
+    ///
+    /// The default implementation scans the full transaction history; database
+    /// backed repositories should narrow the query instead.
+    async fn sum_deducted_for_references(
+        &self,
+        team_id: Uuid,
+        reference_ids: &[Uuid],
+    ) -> Result<i64, CreditsRepositoryError> {
+        if reference_ids.is_empty() {
+            return Ok(0);
+        }
+        let history = self.get_transaction_history(team_id, None).await?;
+        Ok(history
+            .iter()
+            .filter(|t| t.amount < 0)
+            .filter(|t| t.reference_id.is_some_and(|r| reference_ids.contains(&r)))
+            .map(|t| -t.amount)
+            .sum())
+    }
+
+    /// One page of a team's transactions matching `filter`, newest first, with the total count
+    ///
+    /// The default implementation filters the full transaction history in memory.
+    async fn query_transactions(
+        &self,
+        team_id: Uuid,
+        filter: &CreditsTransactionFilter,
+        limit: u64,
+        offset: u64,
+    ) -> Result<(Vec<CreditsTransaction>, u64), CreditsRepositoryError> {
+        let matching: Vec<CreditsTransaction> = self
+            .get_transaction_history(team_id, None)
+            .await?
+            .into_iter()
+            .filter(|t| filter.matches(t))
+            .collect();
+        let total = matching.len() as u64;
+        let page = matching
+            .into_iter()
+            .skip(offset as usize)
+            .take(limit as usize)
+            .collect();
+        Ok((page, total))
+    }
+
+    /// Daily totals of a team's transactions matching `filter`, ordered by date
+    ///
+    /// The default implementation aggregates the full transaction history in memory.
+    async fn daily_usage(
+        &self,
+        team_id: Uuid,
+        filter: &CreditsTransactionFilter,
+    ) -> Result<Vec<DailyCreditsUsage>, CreditsRepositoryError> {
+        let history = self.get_transaction_history(team_id, None).await?;
+        Ok(DailyCreditsUsage::aggregate(
+            history.iter().filter(|t| filter.matches(t)),
+        ))
+    }
+
+    /// Low-balance alert configured for a team, if any
+    ///
+    /// Repositories without alert storage report no alert.
+    async fn get_low_balance_alert(
+        &self,
+        _team_id: Uuid,
+    ) -> Result<Option<LowBalanceAlert>, CreditsRepositoryError> {
+        Ok(None)
+    }
+
+    /// Create or replace a team's low-balance alert, re-arming it
+    async fn set_low_balance_alert(
+        &self,
+        _team_id: Uuid,
+        _threshold: i64,
+        _email: Option<String>,
+    ) -> Result<LowBalanceAlert, CreditsRepositoryError> {
+        Err(CreditsRepositoryError::DatabaseError(
+            "Low-balance alerts are not supported by this repository".to_string(),
+        ))
+    }
+
+    /// Remove a team's low-balance alert, returning whether one existed
+    async fn delete_low_balance_alert(
+        &self,
+        _team_id: Uuid,
+    ) -> Result<bool, CreditsRepositoryError> {
+        Ok(false)
+    }
 }
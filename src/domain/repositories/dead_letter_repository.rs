@@ -0,0 +1,77 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! 死信队列仓库接口
+//!
+//! 重试耗尽、被标记为 `failed` 的任务即为死信，失败原因取自该任务最近一次
+//! `failed` 事件的描述。运维可以按团队、失败原因或任务 ID 浏览死信，并在故障
+//! 恢复后批量重新排队。
+
+use super::task_repository::RepositoryError;
+use crate::domain::models::TaskType;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// 死信过滤条件，各条件之间为“且”关系
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DeadLetterFilter {
+    /// 所属团队
+    pub team_id: Option<Uuid>,
+    /// 失败原因关键字（不区分大小写的子串匹配）
+    pub reason: Option<String>,
+    /// 指定的任务 ID
+    pub task_ids: Option<Vec<Uuid>>,
+}
+
+impl DeadLetterFilter {
+    /// 是否未设置任何过滤条件
+    pub fn is_empty(&self) -> bool {
+        self.team_id.is_none() && self.reason.is_none() && self.task_ids.is_none()
+    }
+}
+
+/// 死信任务
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DeadLetterTask {
+    /// 任务 ID
+    pub task_id: Uuid,
+    /// 所属团队
+    pub team_id: Uuid,
+    /// 任务类型
+    pub task_type: TaskType,
+    /// 目标 URL
+    pub url: String,
+    /// 所属爬取
+    pub crawl_id: Option<Uuid>,
+    /// 已尝试次数
+    pub attempt_count: i32,
+    /// 失败原因，没有失败事件时为空
+    pub reason: Option<String>,
+    /// 进入死信的时间
+    pub failed_at: DateTime<Utc>,
+}
+
+/// 死信队列仓库特质
+#[async_trait]
+pub trait DeadLetterRepository: Send + Sync {
+    /// 按进入死信时间倒序分页查询，返回当前页与符合条件的总数
+    async fn list(
+        &self,
+        filter: &DeadLetterFilter,
+        limit: u64,
+        offset: u64,
+    ) -> Result<(Vec<DeadLetterTask>, u64), RepositoryError>;
+
+    /// 将符合条件的死信重新排队，最多处理 `limit` 个，返回重新排队的任务 ID
+    ///
+    /// 重新排队会清零重试与尝试次数并释放锁，任务按原优先级立即可被领取
+    async fn requeue(
+        &self,
+        filter: &DeadLetterFilter,
+        limit: u64,
+    ) -> Result<Vec<Uuid>, RepositoryError>;
+}
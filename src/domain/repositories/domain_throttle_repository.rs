@@ -0,0 +1,20 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+use super::task_repository::RepositoryError;
+use crate::domain::models::DomainThrottle;
+use async_trait::async_trait;
+
+/// 域名节流仓库特质
+///
+/// 在多个 worker 与 API 进程之间共享按域名的自适应节流状态
+#[async_trait]
+pub trait DomainThrottleRepository: Send + Sync {
+    /// 根据域名查找节流状态
+    async fn find_by_domain(&self, domain: &str)
+        -> Result<Option<DomainThrottle>, RepositoryError>;
+    /// 写入（插入或覆盖）节流状态
+    async fn upsert(&self, throttle: &DomainThrottle) -> Result<(), RepositoryError>;
+}
@@ -10,11 +10,27 @@
 ///
 /// 包含的仓库接口：
 /// - 审计日志仓库（audit_log_repository）：管理审计日志的记录和查询
+/// - 流量用量仓库（bandwidth_usage_repository）：保存团队每日下载流量汇总
 /// - 积分仓库（credits_repository）：管理团队的积分余额和交易记录
+/// - 爬取链接仓库（crawl_link_repository）：记录爬取中发现的页面链接，用于导出链接图
 /// - 爬取任务仓库（crawl_repository）：管理爬取任务的持久化
+/// - 死信队列仓库（dead_letter_repository）：浏览重试耗尽的任务并批量重新排队
 /// - 爬取结果仓库（scrape_result_repository）：管理爬取结果的存储
+/// - 域名节流仓库（domain_throttle_repository）：共享按域名的自适应节流状态
 /// - 地理限制仓库（geo_restriction_repository）：管理团队的地理限制配置
+/// - 页面监控仓库（monitor_repository）：管理定期重新抓取并比较变更的监控
+/// - 通知频道仓库（notification_channel_repository）：管理团队接收事件消息的聊天频道
+/// - 通知联系人仓库（notification_contacts_repository）：管理团队接收邮件通知的联系人
+/// - 资源标签仓库（resource_tag_repository）：管理客户端为结果与爬取附加的标签和注释
+/// - 对象存储仓库（storage_repository）：保存下载模式抓取的二进制资源
+/// - 单点登录会话仓库（sso_session_repository）：管理 OIDC 登录状态与会话令牌
+/// - 任务事件仓库（task_event_repository）：记录任务生命周期事件，用于组装执行时间线
 /// - 任务仓库（task_repository）：管理任务的调度和执行
+/// - 团队能力仓库（team_capability_repository）：管理管理员授予团队的能力开关
+/// - 团队成员仓库（team_member_repository）：管理用户、团队成员与邀请
+/// - 团队套餐仓库（team_plan_repository）：管理团队套餐与月度积分发放状态
+/// - 团队仓库（team_repository）：管理团队基本信息
+/// - URL 黑名单仓库（url_blocklist_repository）：管理全局与团队级禁止抓取的域名/URL
 /// - Webhook事件仓库（webhook_event_repository）：管理Webhook事件的发送
 /// - Webhook仓库（webhook_repository）：管理Webhook配置
 ///
@@ -22,12 +38,27 @@
 /// 提高了系统的可测试性和可维护性.
 pub mod audit_log_repository;
 pub mod auth_scope_repository;
+pub mod bandwidth_usage_repository;
+pub mod crawl_link_repository;
 pub mod crawl_repository;
 pub mod credits_repository;
+pub mod dead_letter_repository;
+pub mod domain_throttle_repository;
 pub mod geo_restriction_repository;
+pub mod monitor_repository;
+pub mod notification_channel_repository;
+pub mod notification_contacts_repository;
+pub mod resource_tag_repository;
 pub mod scrape_result_repository;
+pub mod sso_session_repository;
+pub mod storage_repository;
+pub mod task_event_repository;
 pub mod task_repository;
 pub mod tasks_backlog_repository;
+pub mod team_capability_repository;
+pub mod team_member_repository;
+pub mod team_plan_repository;
 pub mod team_repository;
+pub mod url_blocklist_repository;
 pub mod webhook_event_repository;
 pub mod webhook_repository;
@@ -0,0 +1,33 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+use super::task_repository::RepositoryError;
+use crate::domain::models::Monitor;
+use async_trait::async_trait;
+use chrono::Duration;
+use uuid::Uuid;
+
+/// 页面监控仓库特质
+///
+/// 存储团队的监控配置与最近一次快照
+#[async_trait]
+pub trait MonitorRepository: Send + Sync {
+    /// 新增监控
+    async fn create(&self, monitor: &Monitor) -> Result<Monitor, RepositoryError>;
+    /// 按 ID 查询监控
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Monitor>, RepositoryError>;
+    /// 查询团队的全部监控
+    async fn find_by_team(&self, team_id: Uuid) -> Result<Vec<Monitor>, RepositoryError>;
+    /// 更新监控（配置或检查结果）
+    async fn update(&self, monitor: &Monitor) -> Result<Monitor, RepositoryError>;
+    /// 删除监控，返回是否存在
+    async fn delete(&self, id: Uuid) -> Result<bool, RepositoryError>;
+    /// 领取最多 `limit` 个已到期的启用监控
+    ///
+    /// 领取时将下次检查时间推后 `lease`，检查进程崩溃时监控会在租期结束后被重新领取，
+    /// 多个实例也不会重复检查同一监控
+    async fn claim_due(&self, limit: u64, lease: Duration)
+        -> Result<Vec<Monitor>, RepositoryError>;
+}
@@ -0,0 +1,35 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+use super::task_repository::RepositoryError;
+use crate::domain::models::NotificationChannel;
+use async_trait::async_trait;
+use uuid::Uuid;
+
+/// 通知频道仓库特质
+///
+/// 存储团队接收事件消息的聊天频道（Slack 或通用 JSON POST）
+#[async_trait]
+pub trait NotificationChannelRepository: Send + Sync {
+    /// 新增频道
+    async fn create(
+        &self,
+        channel: &NotificationChannel,
+    ) -> Result<NotificationChannel, RepositoryError>;
+    /// 按 ID 查询频道
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<NotificationChannel>, RepositoryError>;
+    /// 查询团队的全部频道
+    async fn find_by_team(
+        &self,
+        team_id: Uuid,
+    ) -> Result<Vec<NotificationChannel>, RepositoryError>;
+    /// 更新频道配置
+    async fn update(
+        &self,
+        channel: &NotificationChannel,
+    ) -> Result<NotificationChannel, RepositoryError>;
+    /// 删除频道，返回是否存在
+    async fn delete(&self, id: Uuid) -> Result<bool, RepositoryError>;
+}
@@ -0,0 +1,28 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+use super::task_repository::RepositoryError;
+use crate::domain::models::NotificationContacts;
+use async_trait::async_trait;
+use uuid::Uuid;
+
+/// 通知联系人仓库特质
+///
+/// 每个团队最多一组接收邮件通知的联系人
+#[async_trait]
+pub trait NotificationContactsRepository: Send + Sync {
+    /// 查询团队的通知联系人
+    async fn find_by_team(
+        &self,
+        team_id: Uuid,
+    ) -> Result<Option<NotificationContacts>, RepositoryError>;
+    /// 创建或替换团队的通知联系人
+    async fn upsert(
+        &self,
+        contacts: &NotificationContacts,
+    ) -> Result<NotificationContacts, RepositoryError>;
+    /// 删除团队的通知联系人，返回是否存在
+    async fn delete(&self, team_id: Uuid) -> Result<bool, RepositoryError>;
+}
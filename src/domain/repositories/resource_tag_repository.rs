@@ -0,0 +1,44 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+use super::task_repository::RepositoryError;
+use crate::domain::models::{ResourceTags, TaggedResource};
+use async_trait::async_trait;
+use uuid::Uuid;
+
+/// 资源标签仓库特质
+///
+/// 存储客户端为爬取结果与爬取附加的标签和注释
+#[async_trait]
+pub trait ResourceTagRepository: Send + Sync {
+    /// 查询资源的标签，没有标签时返回 `None`
+    async fn find(
+        &self,
+        resource_type: TaggedResource,
+        resource_id: Uuid,
+    ) -> Result<Option<ResourceTags>, RepositoryError>;
+    /// 批量查询资源的标签，只返回有标签的资源
+    async fn find_many(
+        &self,
+        resource_type: TaggedResource,
+        resource_ids: &[Uuid],
+    ) -> Result<Vec<ResourceTags>, RepositoryError>;
+    /// 保存资源的标签（不存在时新增，存在时覆盖）
+    async fn save(&self, tags: &ResourceTags) -> Result<ResourceTags, RepositoryError>;
+    /// 删除资源的全部标签，返回是否存在
+    async fn delete(
+        &self,
+        resource_type: TaggedResource,
+        resource_id: Uuid,
+    ) -> Result<bool, RepositoryError>;
+    /// 查询资源所属团队，资源不存在时返回 `None`
+    ///
+    /// 结果按其任务归属团队，爬取按爬取记录归属团队
+    async fn resource_team(
+        &self,
+        resource_type: TaggedResource,
+        resource_id: Uuid,
+    ) -> Result<Option<Uuid>, RepositoryError>;
+}
@@ -9,6 +9,40 @@ use async_trait::async_trait;
 
 use uuid::Uuid;
 
+/// 删除结果时的筛选条件
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ResultDeletionFilter {
+    /// 结果 URL 的匹配模式，`*` 匹配任意字符序列，其余字符按字面匹配整个 URL
+    pub url_pattern: Option<String>,
+    /// 只删除该爬取的结果
+    pub crawl_id: Option<Uuid>,
+}
+
+/// 将 `*` 通配模式转换为 SQL `LIKE` 模式（以 `\` 转义 `%`、`_` 与 `\`）
+pub fn url_pattern_to_like(pattern: &str) -> String {
+    let mut like = String::with_capacity(pattern.len());
+    for c in pattern.chars() {
+        match c {
+            '*' => like.push('%'),
+            '%' | '_' | '\\' => {
+                like.push('\\');
+                like.push(c);
+            }
+            c => like.push(c),
+        }
+    }
+    like
+}
+
+/// 全文检索命中的结果
+#[derive(Debug, Clone)]
+pub struct ResultSearchHit {
+    /// 命中的结果
+    pub result: ScrapeResult,
+    /// 相关度，越大越相关
+    pub rank: f32,
+}
+
 /// 爬取结果仓库特质
 ///
 /// 定义爬取结果数据访问接口
@@ -20,8 +54,49 @@ pub trait ScrapeResultRepository: Send + Sync {
     async fn find_by_task_id(&self, task_id: Uuid) -> Result<Option<ScrapeResult>>;
     /// 根据任务ID列表批量查找结果
     async fn find_by_task_ids(&self, task_ids: &[Uuid]) -> Result<Vec<ScrapeResult>>;
+    /// 查找某次爬取中指定 URL 的最新结果
+    ///
+    /// 增量重爬时用于读取上一次爬取保存的 ETag / Last-Modified
+    async fn find_latest_for_crawl_url(
+        &self,
+        crawl_id: Uuid,
+        url: &str,
+    ) -> Result<Option<ScrapeResult>>;
+    /// 统计任务列表中未变更（304 Not Modified）的结果数
+    async fn count_not_modified(&self, task_ids: &[Uuid]) -> Result<u64>;
+    /// 读取任务列表中成功（2xx / 3xx）结果的响应耗时（毫秒）
+    async fn response_times(&self, task_ids: &[Uuid]) -> Result<Vec<i64>>;
     /// 获取团队的平均响应时间
     ///
     /// 计算指定团队在过去30天内的平均响应时间
     async fn get_team_avg_response_time(&self, team_id: Uuid) -> Result<f64>;
+    /// 删除团队中符合条件的结果（含内容与截图），返回被删除的结果
+    async fn delete_for_team(
+        &self,
+        team_id: Uuid,
+        filter: &ResultDeletionFilter,
+    ) -> Result<Vec<ScrapeResult>>;
+    /// 在爬取的结果中全文检索，按相关度降序返回最多 `limit` 条
+    ///
+    /// `query` 使用 `websearch_to_tsquery` 语法（引号短语、`or`、`-` 排除）
+    async fn search_crawl_results(
+        &self,
+        crawl_id: Uuid,
+        query: &str,
+        limit: u64,
+    ) -> Result<Vec<ResultSearchHit>>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_url_pattern_to_like_escapes_wildcards() {
+        assert_eq!(
+            url_pattern_to_like("https://example.com/users/*"),
+            "https://example.com/users/%"
+        );
+        assert_eq!(url_pattern_to_like("a_b%c\\d"), "a\\_b\\%c\\\\d");
+    }
 }
@@ -0,0 +1,38 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+use super::task_repository::RepositoryError;
+use crate::domain::auth::ApiKeyScope;
+use crate::domain::models::{OidcLoginState, SsoSession};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+/// 单点登录会话仓库特质
+///
+/// 管理 OIDC 登录状态（oidc_login_states 表）与会话令牌；会话令牌以带过期时间的
+/// API Key 存储（api_keys 与 scopes 表），由鉴权中间件按 API Key 校验。
+#[async_trait]
+pub trait SsoSessionRepository: Send + Sync {
+    /// 保存待完成的登录状态
+    async fn save_login_state(&self, login: &OidcLoginState) -> Result<(), RepositoryError>;
+    /// 取出并删除登录状态，每个 state 只能使用一次
+    ///
+    /// 早于 `issued_after` 创建的状态视为过期，返回 `None` 并一并清理。
+    async fn take_login_state(
+        &self,
+        state: &str,
+        issued_after: DateTime<Utc>,
+    ) -> Result<Option<OidcLoginState>, RepositoryError>;
+    /// 创建会话令牌对应的 API Key 及其权限；`token_hash` 格式与 API Key 相同（`sha256:...`）
+    async fn create_session(
+        &self,
+        session: &SsoSession,
+        token_hash: &str,
+        scope: &ApiKeyScope,
+    ) -> Result<(), RepositoryError>;
+    /// 删除会话令牌，不是会话令牌（普通 API Key）或不存在时返回 `false`
+    async fn delete_session(&self, api_key_id: Uuid) -> Result<bool, RepositoryError>;
+}
@@ -0,0 +1,43 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+use super::task_repository::RepositoryError;
+use async_trait::async_trait;
+use uuid::Uuid;
+
+/// 存储中的对象
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StoredObject {
+    /// 原始字节
+    pub bytes: Vec<u8>,
+    /// 内容类型
+    pub content_type: String,
+}
+
+/// 二进制对象存储仓库特质
+///
+/// 保存下载模式抓取到的图片、PDF、压缩包等原始字节。对象按团队隔离：实现将对象保存在
+/// `{team_id}/{name}` 下，调用方只提供团队内的对象名，无法通过构造对象名读写其他团队的对象。
+#[async_trait]
+pub trait StorageRepository: Send + Sync {
+    /// 在团队命名空间下写入对象（同名覆盖），返回对象的访问 URL
+    async fn put(
+        &self,
+        team_id: Uuid,
+        name: &str,
+        bytes: &[u8],
+        content_type: &str,
+    ) -> Result<String, RepositoryError>;
+    /// 读取团队命名空间下的对象，不存在时返回 None
+    async fn get(&self, team_id: Uuid, name: &str)
+        -> Result<Option<StoredObject>, RepositoryError>;
+    /// 删除团队命名空间下的对象，返回对象是否存在
+    async fn delete(&self, team_id: Uuid, name: &str) -> Result<bool, RepositoryError>;
+}
+
+/// 对象在存储中的完整键 `{team_id}/{name}`，与访问 URL 中的路径一致
+pub fn team_storage_key(team_id: Uuid, name: &str) -> String {
+    format!("{}/{}", team_id, name)
+}
@@ -0,0 +1,20 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+use super::task_repository::RepositoryError;
+use crate::domain::models::TaskEvent;
+use async_trait::async_trait;
+use uuid::Uuid;
+
+/// 任务事件仓库特质
+///
+/// 由队列与抓取工作器写入任务生命周期事件，API 读取后组装执行时间线
+#[async_trait]
+pub trait TaskEventRepository: Send + Sync {
+    /// 追加一条任务事件
+    async fn record(&self, event: &TaskEvent) -> Result<(), RepositoryError>;
+    /// 按时间顺序查询任务的全部事件
+    async fn find_by_task_id(&self, task_id: Uuid) -> Result<Vec<TaskEvent>, RepositoryError>;
+}
@@ -3,7 +3,7 @@
 // Licensed under the Apache License, Version 2.0
 // See LICENSE file in the project root for full license information.
 
-use crate::domain::models::{Task, TaskStatus, TaskType};
+use crate::domain::models::{PriorityTier, Task, TaskStatus, TaskType};
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use sea_orm::DbErr;
@@ -60,6 +60,70 @@ pub trait TaskRepository: Send + Sync {
     async fn update(&self, task: &Task) -> Result<Task, RepositoryError>;
     /// 获取下一个待处理任务
     async fn acquire_next(&self, worker_id: Uuid) -> Result<Option<Task>, RepositoryError>;
+    /// 从指定优先级档位领取下一个排队任务
+    ///
+    /// 排队时间每满 `aging` 提升一个档位（最高为高优先级），同一档位内按入队时间先进先出。
+    /// 默认实现忽略档位，退化为 [`acquire_next`](Self::acquire_next)
+    async fn acquire_next_in_lane(
+        &self,
+        worker_id: Uuid,
+        _tier: PriorityTier,
+        _aging: chrono::Duration,
+    ) -> Result<Option<Task>, RepositoryError> {
+        self.acquire_next(worker_id).await
+    }
+    /// 按 ID 领取任务，仅当任务仍处于排队状态时加锁并返回
+    ///
+    /// 默认实现先读后写，不具备原子性，数据库实现应以单条条件更新覆盖
+    async fn acquire_by_id(
+        &self,
+        id: Uuid,
+        worker_id: Uuid,
+    ) -> Result<Option<Task>, RepositoryError> {
+        let Some(mut task) = self.find_by_id(id).await? else {
+            return Ok(None);
+        };
+        if task.status != TaskStatus::Queued {
+            return Ok(None);
+        }
+        task.start();
+        task.acquire_lock(worker_id, chrono::Duration::minutes(5));
+        self.update(&task).await.map(Some)
+    }
+    /// 续期任务锁，仅当任务仍在执行且锁由 `worker_id` 持有时延长锁过期时间
+    ///
+    /// 返回 `false` 表示锁已被其他 Worker 领取或任务已结束。
+    /// 默认实现先读后写，不具备原子性，数据库实现应以单条条件更新覆盖
+    async fn renew_lock(&self, id: Uuid, worker_id: Uuid) -> Result<bool, RepositoryError> {
+        let Some(mut task) = self.find_by_id(id).await? else {
+            return Ok(false);
+        };
+        if task.status != TaskStatus::Active || task.lock_token != Some(worker_id) {
+            return Ok(false);
+        }
+        task.acquire_lock(worker_id, chrono::Duration::minutes(5));
+        self.update(&task).await.map(|_| true)
+    }
+    /// 领取一个锁已过期但仍处于执行中的任务（原 Worker 崩溃），由 `worker_id` 加锁后返回
+    ///
+    /// 默认实现不支持按状态扫描，始终返回 `None`
+    async fn acquire_stalled(&self, _worker_id: Uuid) -> Result<Option<Task>, RepositoryError> {
+        Ok(None)
+    }
+    /// 提升到期的延迟任务：清除排队任务的 `scheduled_at`，使其立即可被领取
+    ///
+    /// 返回 `false` 表示任务已不在排队状态或没有延迟。
+    /// 默认实现先读后写，不具备原子性，数据库实现应以单条条件更新覆盖
+    async fn promote_scheduled(&self, id: Uuid) -> Result<bool, RepositoryError> {
+        let Some(mut task) = self.find_by_id(id).await? else {
+            return Ok(false);
+        };
+        if task.status != TaskStatus::Queued || task.scheduled_at.is_none() {
+            return Ok(false);
+        }
+        task.scheduled_at = None;
+        self.update(&task).await.map(|_| true)
+    }
     /// 标记任务已完成
     async fn mark_completed(&self, id: Uuid) -> Result<(), RepositoryError>;
     /// 标记任务已失败
@@ -0,0 +1,24 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+use super::task_repository::RepositoryError;
+use crate::domain::models::TeamCapabilities;
+use async_trait::async_trait;
+use uuid::Uuid;
+
+/// 团队能力仓库特质
+///
+/// 管理由管理员授予团队的能力开关（存储在 teams 表）
+#[async_trait]
+pub trait TeamCapabilityRepository: Send + Sync {
+    /// 查询团队能力，团队不存在时返回 `RepositoryError::NotFound`
+    async fn get_capabilities(&self, team_id: Uuid) -> Result<TeamCapabilities, RepositoryError>;
+    /// 更新团队能力，团队不存在时返回 `RepositoryError::NotFound`
+    async fn update_capabilities(
+        &self,
+        team_id: Uuid,
+        capabilities: &TeamCapabilities,
+    ) -> Result<TeamCapabilities, RepositoryError>;
+}
@@ -0,0 +1,46 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+use super::task_repository::RepositoryError;
+use crate::domain::models::{TeamMember, User};
+use async_trait::async_trait;
+use uuid::Uuid;
+
+/// 团队成员仓库特质
+///
+/// 管理用户（users 表）与团队成员关系及邀请（team_members 表）
+#[async_trait]
+pub trait TeamMemberRepository: Send + Sync {
+    /// 按邮箱查找用户，不存在时创建；`email` 须已规范化为小写
+    async fn find_or_create_user(&self, email: &str) -> Result<User, RepositoryError>;
+    /// 添加成员关系；`invitation_token_hash` 为待接受邀请的令牌摘要
+    async fn add_member(
+        &self,
+        member: &TeamMember,
+        invitation_token_hash: Option<&str>,
+    ) -> Result<(), RepositoryError>;
+    /// 查询团队中的某个成员
+    async fn find_member(
+        &self,
+        team_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<Option<TeamMember>, RepositoryError>;
+    /// 列出团队成员（含待接受的邀请），按邀请时间排序
+    async fn list_members(&self, team_id: Uuid) -> Result<Vec<TeamMember>, RepositoryError>;
+    /// 列出邮箱对应用户在所有团队中的成员关系；`email` 须已规范化为小写
+    async fn list_memberships_by_email(
+        &self,
+        email: &str,
+    ) -> Result<Vec<TeamMember>, RepositoryError>;
+    /// 移除成员或撤回邀请，成员不存在时返回 `false`
+    async fn remove_member(&self, team_id: Uuid, user_id: Uuid) -> Result<bool, RepositoryError>;
+    /// 接受令牌摘要对应的邀请：状态改为 active 并清空令牌摘要
+    ///
+    /// 没有对应的待接受邀请时返回 `None`。
+    async fn accept_invitation(
+        &self,
+        invitation_token_hash: &str,
+    ) -> Result<Option<TeamMember>, RepositoryError>;
+}
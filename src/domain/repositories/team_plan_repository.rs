@@ -0,0 +1,41 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+use super::task_repository::RepositoryError;
+use crate::domain::models::{TeamPlan, TeamPlanAssignment};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+/// 团队套餐仓库特质
+///
+/// 管理团队的套餐与月度积分发放状态（存储在 teams 表）
+#[async_trait]
+pub trait TeamPlanRepository: Send + Sync {
+    /// 查询团队套餐，团队不存在时返回 `RepositoryError::NotFound`
+    async fn get_plan(&self, team_id: Uuid) -> Result<TeamPlanAssignment, RepositoryError>;
+    /// 更新团队套餐，团队不存在时返回 `RepositoryError::NotFound`
+    async fn set_plan(
+        &self,
+        team_id: Uuid,
+        plan: TeamPlan,
+    ) -> Result<TeamPlanAssignment, RepositoryError>;
+    /// 查询 `period_start` 之后尚未发放月度积分的团队，最多返回 `limit` 个
+    async fn find_due_for_credit_grant(
+        &self,
+        period_start: DateTime<Utc>,
+        limit: u64,
+    ) -> Result<Vec<TeamPlanAssignment>, RepositoryError>;
+    /// 抢占团队本期的月度积分发放权
+    ///
+    /// 仅当团队在 `period_start` 之后尚未发放时将发放时间更新为 `granted_at` 并返回 `true`；
+    /// 已被其他实例抢占时返回 `false`。
+    async fn claim_credit_grant(
+        &self,
+        team_id: Uuid,
+        period_start: DateTime<Utc>,
+        granted_at: DateTime<Utc>,
+    ) -> Result<bool, RepositoryError>;
+}
@@ -0,0 +1,26 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+use super::task_repository::RepositoryError;
+use crate::domain::models::BlocklistEntry;
+use async_trait::async_trait;
+use uuid::Uuid;
+
+/// URL 黑名单仓库特质
+///
+/// 存储全局（team_id 为空）与团队级的黑名单条目
+#[async_trait]
+pub trait UrlBlocklistRepository: Send + Sync {
+    /// 新增黑名单条目
+    async fn create(&self, entry: &BlocklistEntry) -> Result<BlocklistEntry, RepositoryError>;
+    /// 按 ID 查询条目
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<BlocklistEntry>, RepositoryError>;
+    /// 查询全部条目
+    async fn find_all(&self) -> Result<Vec<BlocklistEntry>, RepositoryError>;
+    /// 查询对团队生效的条目（全局条目 + 该团队条目）
+    async fn find_for_team(&self, team_id: Uuid) -> Result<Vec<BlocklistEntry>, RepositoryError>;
+    /// 删除条目，返回是否存在
+    async fn delete(&self, id: Uuid) -> Result<bool, RepositoryError>;
+}
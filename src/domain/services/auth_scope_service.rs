@@ -38,6 +38,16 @@ pub trait AuthScopeServiceTrait: Send + Sync {
         team_default_scope: Option<ApiKeyScope>,
     ) -> Result<ApiKeyScope, AuthScopeServiceError>;
 
+    /// Scope of an API key owned by `team_id`
+    ///
+    /// Returns `ApiKeyNotFound` when the key does not exist or belongs to
+    /// another team, so callers cannot tell the two apart.
+    async fn get_team_key_scope(
+        &self,
+        team_id: Uuid,
+        api_key_id: Uuid,
+    ) -> Result<ApiKeyScope, AuthScopeServiceError>;
+
     async fn set_scope(
         &self,
         api_key_id: Uuid,
@@ -171,6 +181,25 @@ impl AuthScopeServiceTrait for AuthScopeService {
         }
     }
 
+    /// Get scope for an API Key after checking that it belongs to `team_id`
+    async fn get_team_key_scope(
+        &self,
+        team_id: Uuid,
+        api_key_id: Uuid,
+    ) -> Result<ApiKeyScope, AuthScopeServiceError> {
+        match self
+            .scope_repo
+            .find_team_id_by_api_key_id(api_key_id)
+            .await?
+        {
+            Some(owner) if owner == team_id => self.get_scope_for_key(api_key_id, None).await,
+            _ => {
+                debug!("API Key {} not found in team {}", api_key_id, team_id);
+                Err(AuthScopeServiceError::ApiKeyNotFound)
+            }
+        }
+    }
+
     /// Set scope for an API Key
     async fn set_scope(
         &self,
@@ -434,6 +463,12 @@ mod tests {
         ) -> Result<Option<ApiKeyScope>, RepositoryError> {
             Ok(None)
         }
+        async fn find_team_id_by_api_key_id(
+            &self,
+            _api_key_id: Uuid,
+        ) -> Result<Option<Uuid>, RepositoryError> {
+            Ok(None)
+        }
         async fn upsert(
             &self,
             _api_key_id: Uuid,
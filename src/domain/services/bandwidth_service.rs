@@ -0,0 +1,430 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! 流量统计服务
+//!
+//! Worker 每次取得引擎响应后把响应正文字节数累加到计数器：团队当日累计与任务累计。
+//! 汇总 Worker 定期取出有变化的团队当日累计写入数据库，`GET /v1/usage` 合并数据库
+//! 中的每日汇总与计数器中尚未汇总的当日读数。配置了下载上限时，团队当日下载量
+//! 达到上限后新任务直接失败。
+//!
+//! 计数器有两种实现：[`InMemoryBandwidthCounter`] 只在本进程内有效；
+//! Redis 实现位于基础设施层，多个进程共享。
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{NaiveDate, Utc};
+use log::warn;
+use parking_lot::Mutex;
+use uuid::Uuid;
+
+use crate::config::settings::BandwidthSettings;
+use crate::domain::models::DailyBandwidthUsage;
+use crate::domain::repositories::bandwidth_usage_repository::BandwidthUsageRepository;
+use crate::domain::repositories::task_repository::RepositoryError;
+
+/// 单次汇总从计数器取出的最大团队日数
+const ROLLUP_BATCH_SIZE: usize = 500;
+
+/// 下载流量计数器
+#[async_trait]
+pub trait BandwidthCounter: Send + Sync {
+    /// 把一次响应的字节数累加到团队当日与任务的计数，并标记团队当日有变化
+    async fn record(&self, team_id: Uuid, task_id: Uuid, date: NaiveDate, bytes: u64)
+        -> Result<()>;
+
+    /// 团队某天的累计，没有记录时为零
+    async fn team_usage(&self, team_id: Uuid, date: NaiveDate) -> Result<DailyBandwidthUsage>;
+
+    /// 任务的累计下载字节数，计数器已过期或没有记录时返回 `None`
+    async fn task_bytes(&self, task_id: Uuid) -> Result<Option<u64>>;
+
+    /// 取出并清除最多 `limit` 个有变化的团队日及其当前累计
+    async fn take_changed(&self, limit: usize) -> Result<Vec<(Uuid, DailyBandwidthUsage)>>;
+
+    /// 重新标记团队日有变化，写入数据库失败时下次汇总重试
+    async fn mark_changed(&self, team_id: Uuid, date: NaiveDate) -> Result<()>;
+}
+
+/// 进程内的下载流量计数器
+#[derive(Debug)]
+pub struct InMemoryBandwidthCounter {
+    state: Mutex<InMemoryState>,
+    /// 任务计数的保留时长
+    task_ttl: Duration,
+}
+
+#[derive(Debug, Default)]
+struct InMemoryState {
+    days: HashMap<(Uuid, NaiveDate), DailyBandwidthUsage>,
+    tasks: HashMap<Uuid, (u64, Instant)>,
+    changed: HashSet<(Uuid, NaiveDate)>,
+}
+
+impl InMemoryBandwidthCounter {
+    /// 创建计数器，任务计数在最后一次累加 `task_ttl` 后清除
+    pub fn new(task_ttl: Duration) -> Self {
+        Self {
+            state: Mutex::new(InMemoryState::default()),
+            task_ttl,
+        }
+    }
+}
+
+#[async_trait]
+impl BandwidthCounter for InMemoryBandwidthCounter {
+    async fn record(
+        &self,
+        team_id: Uuid,
+        task_id: Uuid,
+        date: NaiveDate,
+        bytes: u64,
+    ) -> Result<()> {
+        let mut state = self.state.lock();
+        let day = state
+            .days
+            .entry((team_id, date))
+            .or_insert_with(|| DailyBandwidthUsage::empty(date));
+        day.bytes_downloaded = day.bytes_downloaded.saturating_add(bytes);
+        day.requests += 1;
+        let task = state.tasks.entry(task_id).or_insert((0, Instant::now()));
+        task.0 = task.0.saturating_add(bytes);
+        task.1 = Instant::now();
+        state.changed.insert((team_id, date));
+        Ok(())
+    }
+
+    async fn team_usage(&self, team_id: Uuid, date: NaiveDate) -> Result<DailyBandwidthUsage> {
+        Ok(self
+            .state
+            .lock()
+            .days
+            .get(&(team_id, date))
+            .copied()
+            .unwrap_or_else(|| DailyBandwidthUsage::empty(date)))
+    }
+
+    async fn task_bytes(&self, task_id: Uuid) -> Result<Option<u64>> {
+        Ok(self
+            .state
+            .lock()
+            .tasks
+            .get(&task_id)
+            .filter(|(_, updated_at)| updated_at.elapsed() < self.task_ttl)
+            .map(|(bytes, _)| *bytes))
+    }
+
+    async fn take_changed(&self, limit: usize) -> Result<Vec<(Uuid, DailyBandwidthUsage)>> {
+        let mut state = self.state.lock();
+        let keys: Vec<(Uuid, NaiveDate)> = state.changed.iter().take(limit).copied().collect();
+        let mut changed = Vec::with_capacity(keys.len());
+        for key in keys {
+            state.changed.remove(&key);
+            if let Some(day) = state.days.get(&key) {
+                changed.push((key.0, *day));
+            }
+        }
+
+        // 已汇总的前几天不会再有新的累加，任务计数按保留时长清除
+        let yesterday = Utc::now().date_naive().pred_opt().unwrap_or(NaiveDate::MIN);
+        let InMemoryState {
+            days,
+            changed: pending,
+            tasks,
+        } = &mut *state;
+        days.retain(|key, _| key.1 >= yesterday || pending.contains(key));
+        let task_ttl = self.task_ttl;
+        tasks.retain(|_, (_, updated_at)| updated_at.elapsed() < task_ttl);
+        Ok(changed)
+    }
+
+    async fn mark_changed(&self, team_id: Uuid, date: NaiveDate) -> Result<()> {
+        self.state.lock().changed.insert((team_id, date));
+        Ok(())
+    }
+}
+
+/// 流量统计服务
+pub struct BandwidthService {
+    counter: Arc<dyn BandwidthCounter>,
+    repository: Arc<dyn BandwidthUsageRepository>,
+    /// 全局的每日下载上限（字节），0 表示不限制
+    daily_cap_bytes: u64,
+    /// 按团队覆盖的每日下载上限
+    team_caps: HashMap<Uuid, u64>,
+}
+
+impl BandwidthService {
+    /// 创建不限制下载量的服务
+    pub fn new(
+        counter: Arc<dyn BandwidthCounter>,
+        repository: Arc<dyn BandwidthUsageRepository>,
+    ) -> Self {
+        Self {
+            counter,
+            repository,
+            daily_cap_bytes: 0,
+            team_caps: HashMap::new(),
+        }
+    }
+
+    /// 从配置创建服务，团队 ID 无效的上限会被忽略
+    pub fn from_settings(
+        counter: Arc<dyn BandwidthCounter>,
+        repository: Arc<dyn BandwidthUsageRepository>,
+        settings: &BandwidthSettings,
+    ) -> Self {
+        let mut service = Self::new(counter, repository).with_daily_cap(settings.daily_cap_bytes);
+        for cap in &settings.team_caps {
+            match Uuid::parse_str(&cap.team_id) {
+                Ok(team_id) => service = service.with_team_cap(team_id, cap.daily_cap_bytes),
+                Err(_) => warn!("Ignoring bandwidth cap for invalid team id {}", cap.team_id),
+            }
+        }
+        service
+    }
+
+    /// 设置全局的每日下载上限，0 表示不限制
+    pub fn with_daily_cap(mut self, bytes: u64) -> Self {
+        self.daily_cap_bytes = bytes;
+        self
+    }
+
+    /// 设置团队的每日下载上限，0 表示不限制
+    pub fn with_team_cap(mut self, team_id: Uuid, bytes: u64) -> Self {
+        self.team_caps.insert(team_id, bytes);
+        self
+    }
+
+    /// 团队的每日下载上限，不限制时返回 `None`
+    pub fn daily_cap(&self, team_id: Uuid) -> Option<u64> {
+        let cap = self
+            .team_caps
+            .get(&team_id)
+            .copied()
+            .unwrap_or(self.daily_cap_bytes);
+        (cap > 0).then_some(cap)
+    }
+
+    /// 记录一次响应的下载字节数，计数失败只记录日志
+    pub async fn record(&self, team_id: Uuid, task_id: Uuid, bytes: u64) {
+        let today = Utc::now().date_naive();
+        if let Err(e) = self.counter.record(team_id, task_id, today, bytes).await {
+            warn!(
+                "Failed to record {} downloaded bytes for team {}: {:#}",
+                bytes, team_id, e
+            );
+        }
+    }
+
+    /// 团队当日的用量，合并计数器与已汇总的数据
+    ///
+    /// 计数器或数据库读取失败时使用另一方的读数。
+    pub async fn today(&self, team_id: Uuid) -> DailyBandwidthUsage {
+        let today = Utc::now().date_naive();
+        let mut usage = match self.counter.team_usage(team_id, today).await {
+            Ok(usage) => usage,
+            Err(e) => {
+                warn!(
+                    "Failed to read bandwidth counter for team {}: {:#}",
+                    team_id, e
+                );
+                DailyBandwidthUsage::empty(today)
+            }
+        };
+        match self.repository.list_daily(team_id, today, today).await {
+            Ok(stored) => stored.iter().for_each(|day| usage.merge(day)),
+            Err(e) => warn!("Failed to load bandwidth usage for team {}: {}", team_id, e),
+        }
+        usage
+    }
+
+    /// 团队当日下载量达到上限时返回上限，未设置上限时不查询用量
+    pub async fn cap_exceeded(&self, team_id: Uuid) -> Option<u64> {
+        let cap = self.daily_cap(team_id)?;
+        (self.today(team_id).await.bytes_downloaded >= cap).then_some(cap)
+    }
+
+    /// 任务的累计下载字节数
+    pub async fn task_bytes(&self, task_id: Uuid) -> Option<u64> {
+        match self.counter.task_bytes(task_id).await {
+            Ok(bytes) => bytes,
+            Err(e) => {
+                warn!(
+                    "Failed to read bandwidth counter for task {}: {:#}",
+                    task_id, e
+                );
+                None
+            }
+        }
+    }
+
+    /// 团队在 `[from, to]` 日期范围内的每日用量，包含计数器中尚未汇总的当日读数
+    pub async fn usage(
+        &self,
+        team_id: Uuid,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Result<Vec<DailyBandwidthUsage>, RepositoryError> {
+        let stored = self.repository.list_daily(team_id, from, to).await?;
+        let today = Utc::now().date_naive();
+        let live = if (from..=to).contains(&today) {
+            match self.counter.team_usage(team_id, today).await {
+                Ok(usage) if usage.requests > 0 => Some(usage),
+                Ok(_) => None,
+                Err(e) => {
+                    warn!(
+                        "Failed to read bandwidth counter for team {}: {:#}",
+                        team_id, e
+                    );
+                    None
+                }
+            }
+        } else {
+            None
+        };
+        Ok(DailyBandwidthUsage::merge_days(stored, live))
+    }
+
+    /// 把计数器中有变化的团队日写入数据库，返回写入的条数
+    ///
+    /// 写入失败的团队日重新标记，下次汇总重试；存在失败时返回错误。
+    pub async fn rollup(&self) -> Result<usize> {
+        let mut written = 0;
+        let mut failed = 0;
+        loop {
+            let changed = self.counter.take_changed(ROLLUP_BATCH_SIZE).await?;
+            let batch_len = changed.len();
+            for (team_id, usage) in changed {
+                match self.repository.upsert_daily(team_id, &usage).await {
+                    Ok(()) => written += 1,
+                    Err(e) => {
+                        failed += 1;
+                        warn!(
+                            "Failed to roll up bandwidth usage for team {} on {}: {}",
+                            team_id, usage.date, e
+                        );
+                        self.counter.mark_changed(team_id, usage.date).await?;
+                    }
+                }
+            }
+            if batch_len < ROLLUP_BATCH_SIZE || failed > 0 {
+                break;
+            }
+        }
+        if failed > 0 {
+            anyhow::bail!("{} bandwidth usage rows failed to roll up", failed);
+        }
+        Ok(written)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Default)]
+    struct MemoryUsageRepository {
+        rows: Mutex<HashMap<(Uuid, NaiveDate), DailyBandwidthUsage>>,
+    }
+
+    #[async_trait]
+    impl BandwidthUsageRepository for MemoryUsageRepository {
+        async fn upsert_daily(
+            &self,
+            team_id: Uuid,
+            usage: &DailyBandwidthUsage,
+        ) -> Result<(), RepositoryError> {
+            self.rows
+                .lock()
+                .entry((team_id, usage.date))
+                .and_modify(|row| row.merge(usage))
+                .or_insert(*usage);
+            Ok(())
+        }
+
+        async fn list_daily(
+            &self,
+            team_id: Uuid,
+            from: NaiveDate,
+            to: NaiveDate,
+        ) -> Result<Vec<DailyBandwidthUsage>, RepositoryError> {
+            let mut days: Vec<DailyBandwidthUsage> = self
+                .rows
+                .lock()
+                .iter()
+                .filter(|((team, date), _)| *team == team_id && (from..=to).contains(date))
+                .map(|(_, usage)| *usage)
+                .collect();
+            days.sort_by_key(|day| day.date);
+            Ok(days)
+        }
+    }
+
+    fn service() -> (BandwidthService, Arc<MemoryUsageRepository>) {
+        let repository = Arc::new(MemoryUsageRepository::default());
+        let service = BandwidthService::new(
+            Arc::new(InMemoryBandwidthCounter::new(Duration::from_secs(60))),
+            repository.clone(),
+        );
+        (service, repository)
+    }
+
+    #[tokio::test]
+    async fn test_record_counts_team_day_and_task() {
+        let (service, _) = service();
+        let team_id = Uuid::new_v4();
+        let task_id = Uuid::new_v4();
+
+        service.record(team_id, task_id, 1_000).await;
+        service.record(team_id, task_id, 500).await;
+        service.record(team_id, Uuid::new_v4(), 250).await;
+
+        let today = service.today(team_id).await;
+        assert_eq!(today.bytes_downloaded, 1_750);
+        assert_eq!(today.requests, 3);
+        assert_eq!(service.task_bytes(task_id).await, Some(1_500));
+        assert_eq!(service.task_bytes(Uuid::new_v4()).await, None);
+    }
+
+    #[tokio::test]
+    async fn test_rollup_writes_changed_days_once() {
+        let (service, repository) = service();
+        let team_id = Uuid::new_v4();
+        service.record(team_id, Uuid::new_v4(), 4_096).await;
+
+        assert_eq!(service.rollup().await.unwrap(), 1);
+        assert_eq!(service.rollup().await.unwrap(), 0);
+
+        let today = Utc::now().date_naive();
+        let stored = repository.list_daily(team_id, today, today).await.unwrap();
+        assert_eq!(stored.len(), 1);
+        assert_eq!(stored[0].bytes_downloaded, 4_096);
+
+        let days = service.usage(team_id, today, today).await.unwrap();
+        assert_eq!(days, stored);
+    }
+
+    #[tokio::test]
+    async fn test_cap_exceeded_uses_team_override() {
+        let (service, _) = service();
+        let capped = Uuid::new_v4();
+        let unlimited = Uuid::new_v4();
+        let service = service.with_daily_cap(1_000).with_team_cap(unlimited, 0);
+
+        service.record(capped, Uuid::new_v4(), 999).await;
+        assert_eq!(service.cap_exceeded(capped).await, None);
+        service.record(capped, Uuid::new_v4(), 1).await;
+        assert_eq!(service.cap_exceeded(capped).await, Some(1_000));
+
+        service.record(unlimited, Uuid::new_v4(), 5_000).await;
+        assert_eq!(service.daily_cap(unlimited), None);
+        assert_eq!(service.cap_exceeded(unlimited).await, None);
+    }
+}
@@ -0,0 +1,296 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! 聊天频道通知
+//!
+//! 聊天频道通知渠道：事件以消息形式推送到团队注册的频道（`POST /v1/notifications/channels`）。
+//! Slack 频道发送 `{"text": ...}` 消息，通用 JSON 频道发送事件名、摘要与完整负载。
+//! 投递记录的负载为渲染后的消息，通过 `WebhookSender` 以 HTTP POST 发送，不签名。
+
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use log::{debug, info};
+#[cfg(feature = "metrics")]
+use metrics::counter;
+use serde_json::{json, Value};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::domain::models::{ChannelKind, DeliveryChannel, WebhookEvent, WebhookEventType};
+use crate::domain::repositories::notification_channel_repository::NotificationChannelRepository;
+use crate::domain::services::notification_service::{describe_event, NotificationTransport};
+use crate::domain::services::webhook_sender::WebhookSender;
+
+/// 渲染发送到频道的消息体
+pub fn render_channel_message(
+    kind: ChannelKind,
+    event_type: &WebhookEventType,
+    payload: &Value,
+) -> Value {
+    let (title, summary) = describe_event(event_type, payload);
+    match kind {
+        ChannelKind::Slack => json!({
+            "text": format!("*[crawlrs] {}*\n{}", title, summary),
+        }),
+        ChannelKind::Json => json!({
+            "event": event_type.to_string(),
+            "title": title,
+            "summary": summary,
+            "payload": payload,
+        }),
+    }
+}
+
+/// 按团队频道订阅的事件类型推送聊天消息
+pub struct ChatNotificationService {
+    repository: Arc<dyn NotificationChannelRepository>,
+    sender: Arc<dyn WebhookSender>,
+}
+
+impl ChatNotificationService {
+    /// 创建聊天通知服务
+    pub fn new(
+        repository: Arc<dyn NotificationChannelRepository>,
+        sender: Arc<dyn WebhookSender>,
+    ) -> Self {
+        Self { repository, sender }
+    }
+}
+
+#[async_trait]
+impl NotificationTransport for ChatNotificationService {
+    fn handles(&self, channel: DeliveryChannel) -> bool {
+        matches!(channel, DeliveryChannel::Slack | DeliveryChannel::Json)
+    }
+
+    async fn route(
+        &self,
+        team_id: Uuid,
+        event_type: &WebhookEventType,
+        payload: &Value,
+    ) -> Result<Vec<WebhookEvent>> {
+        let channels = self.repository.find_by_team(team_id).await.map_err(|e| {
+            anyhow!(
+                "Failed to load notification channels of team {}: {}",
+                team_id,
+                e
+            )
+        })?;
+
+        let deliveries: Vec<WebhookEvent> = channels
+            .iter()
+            .filter(|c| c.accepts(event_type))
+            .map(|channel| {
+                WebhookEvent::new(
+                    Uuid::new_v4(),
+                    team_id,
+                    channel.id,
+                    event_type.clone(),
+                    render_channel_message(channel.kind, event_type, payload),
+                    channel.url.clone(),
+                )
+                .with_channel(channel.kind.into())
+            })
+            .collect();
+        if deliveries.is_empty() {
+            debug!(
+                "No notification channel of team {} receives {}",
+                team_id, event_type
+            );
+        }
+        Ok(deliveries)
+    }
+
+    async fn deliver(&self, delivery: &WebhookEvent) -> Result<()> {
+        let result = self
+            .sender
+            .send(&delivery.webhook_url, &delivery.payload, None)
+            .await;
+
+        #[cfg(feature = "metrics")]
+        counter!(
+            "chat_notifications_total",
+            "kind" => delivery.channel.to_string(),
+            "outcome" => if result.is_ok() { "sent" } else { "failed" }
+        )
+        .increment(1);
+
+        result.map_err(|e| {
+            anyhow!(
+                "Failed to post {} to notification channel {}: {}",
+                delivery.event_type,
+                delivery.webhook_id,
+                e
+            )
+        })?;
+        info!(
+            "Posted {} to notification channel {} of team {}",
+            delivery.event_type, delivery.webhook_id, delivery.team_id
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::NotificationChannel;
+    use crate::domain::repositories::task_repository::RepositoryError;
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+
+    struct StaticChannelRepository {
+        channels: Vec<NotificationChannel>,
+    }
+
+    #[async_trait]
+    impl NotificationChannelRepository for StaticChannelRepository {
+        async fn create(
+            &self,
+            channel: &NotificationChannel,
+        ) -> Result<NotificationChannel, RepositoryError> {
+            Ok(channel.clone())
+        }
+
+        async fn find_by_id(
+            &self,
+            id: Uuid,
+        ) -> Result<Option<NotificationChannel>, RepositoryError> {
+            Ok(self.channels.iter().find(|c| c.id == id).cloned())
+        }
+
+        async fn find_by_team(
+            &self,
+            team_id: Uuid,
+        ) -> Result<Vec<NotificationChannel>, RepositoryError> {
+            Ok(self
+                .channels
+                .iter()
+                .filter(|c| c.team_id == team_id)
+                .cloned()
+                .collect())
+        }
+
+        async fn update(
+            &self,
+            channel: &NotificationChannel,
+        ) -> Result<NotificationChannel, RepositoryError> {
+            Ok(channel.clone())
+        }
+
+        async fn delete(&self, _id: Uuid) -> Result<bool, RepositoryError> {
+            Ok(false)
+        }
+    }
+
+    /// Records every post and fails for URLs containing "broken"
+    #[derive(Default)]
+    struct RecordingSender {
+        posts: Mutex<Vec<(String, Value)>>,
+    }
+
+    #[async_trait]
+    impl WebhookSender for RecordingSender {
+        async fn send(
+            &self,
+            url: &str,
+            payload: &Value,
+            _headers: Option<&HashMap<String, String>>,
+        ) -> Result<()> {
+            if url.contains("broken") {
+                return Err(anyhow!("HTTP status 404"));
+            }
+            self.posts
+                .lock()
+                .unwrap()
+                .push((url.to_string(), payload.clone()));
+            Ok(())
+        }
+
+        async fn send_with_status(
+            &self,
+            url: &str,
+            payload: &Value,
+            headers: Option<&HashMap<String, String>>,
+        ) -> Result<u16> {
+            self.send(url, payload, headers).await.map(|_| 200)
+        }
+    }
+
+    #[test]
+    fn test_render_slack_and_json_messages() {
+        let payload = json!({"team_id": "t1", "balance": 40, "threshold": 50});
+        let slack =
+            render_channel_message(ChannelKind::Slack, &WebhookEventType::CreditsLow, &payload);
+        let text = slack["text"].as_str().unwrap();
+        assert!(text.starts_with("*[crawlrs] Credit balance is running low*\n"));
+        assert!(text.ends_with("is 40, below the alert threshold of 50."));
+
+        let generic =
+            render_channel_message(ChannelKind::Json, &WebhookEventType::CreditsLow, &payload);
+        assert_eq!(generic["event"], "credits.low");
+        assert_eq!(generic["title"], "Credit balance is running low");
+        assert_eq!(generic["payload"]["balance"], 40);
+    }
+
+    #[tokio::test]
+    async fn test_route_and_deliver_to_subscribed_channels() {
+        let team_id = Uuid::new_v4();
+        let slack = NotificationChannel::new(
+            team_id,
+            "#alerts",
+            ChannelKind::Slack,
+            "https://hooks.slack.com/services/T0/B0/x",
+        );
+        let mut other_events = NotificationChannel::new(
+            team_id,
+            "billing",
+            ChannelKind::Json,
+            "https://hooks.example.com/billing",
+        );
+        other_events.set_event_types(vec![WebhookEventType::CreditsLow]);
+        let broken = NotificationChannel::new(
+            team_id,
+            "broken",
+            ChannelKind::Json,
+            "https://hooks.example.com/broken",
+        );
+        let other_team = NotificationChannel::new(
+            Uuid::new_v4(),
+            "#other",
+            ChannelKind::Slack,
+            "https://hooks.slack.com/services/T1/B1/y",
+        );
+        let sender = Arc::new(RecordingSender::default());
+        let service = ChatNotificationService::new(
+            Arc::new(StaticChannelRepository {
+                channels: vec![slack, other_events, broken, other_team],
+            }),
+            sender.clone(),
+        );
+
+        let deliveries = service
+            .route(
+                team_id,
+                &WebhookEventType::MonitorChanged,
+                &json!({"url": "https://example.com"}),
+            )
+            .await
+            .unwrap();
+        assert_eq!(deliveries.len(), 2, "slack and broken channels subscribe");
+        assert_eq!(deliveries[0].channel, DeliveryChannel::Slack);
+        assert_eq!(deliveries[1].channel, DeliveryChannel::Json);
+
+        assert!(service.deliver(&deliveries[0]).await.is_ok());
+        assert!(service.deliver(&deliveries[1]).await.is_err());
+        let posts = sender.posts.lock().unwrap();
+        assert_eq!(posts.len(), 1);
+        assert_eq!(posts[0].0, "https://hooks.slack.com/services/T0/B0/x");
+        assert!(posts[0].1["text"]
+            .as_str()
+            .unwrap()
+            .contains("Monitored page changed"));
+    }
+}
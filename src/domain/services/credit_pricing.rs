@@ -0,0 +1,121 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! 积分计价规则
+//!
+//! 提交请求时的基础费用、worker 按功能收取的附加费用（截图、代理）以及 LLM token 的
+//! 折算规则集中在这里，扣费与费用预估共用同一套规则。价格可在 `[pricing]` 配置中调整，
+//! 按团队生效的价格表由 `PricingService` 提供；这里的常量是未配置时的默认值。
+
+use serde::{Deserialize, Serialize};
+
+use crate::common::constants::crawl_task::CRAWL_TASK_CREDITS_COST;
+
+/// 提交抓取请求时收取的基础积分
+pub const SCRAPE_BASE_CREDITS: i64 = 1;
+
+/// 截图的附加积分
+pub const SCREENSHOT_CREDITS: i64 = 2;
+
+/// 使用代理的附加积分（按页面收取，抓取失败也收取）
+pub const PROXY_CREDITS: i64 = 1;
+
+/// 每 1000 个 LLM token 折算的积分
+pub const CREDITS_PER_1000_TOKENS: i64 = 10;
+
+/// 预估时假设单次 LLM 调用消耗的 token 数（页面内容 + 输出），不含提示词本身
+pub const ESTIMATED_TOKENS_PER_LLM_CALL: u64 = 4000;
+
+/// 积分价格表
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PricingTable {
+    /// 提交抓取请求时收取的基础积分
+    pub scrape: i64,
+    /// 创建爬取时收取的固定积分
+    pub crawl: i64,
+    /// 截图的附加积分
+    pub screenshot: i64,
+    /// 使用代理的附加积分（按页面收取）
+    pub proxy: i64,
+    /// 每 1000 个 LLM token 折算的积分
+    pub credits_per_1000_tokens: i64,
+}
+
+impl Default for PricingTable {
+    fn default() -> Self {
+        Self {
+            scrape: SCRAPE_BASE_CREDITS,
+            crawl: CRAWL_TASK_CREDITS_COST,
+            screenshot: SCREENSHOT_CREDITS,
+            proxy: PROXY_CREDITS,
+            credits_per_1000_tokens: CREDITS_PER_1000_TOKENS,
+        }
+    }
+}
+
+impl PricingTable {
+    /// 截图与代理的附加积分
+    pub fn feature_credits(&self, screenshot: bool, proxy: bool) -> i64 {
+        let mut credits = 0;
+        if screenshot {
+            credits += self.screenshot;
+        }
+        if proxy {
+            credits += self.proxy;
+        }
+        credits
+    }
+
+    /// LLM token 折算的积分：向上取整，只要有用量且单价不为 0 至少收取 1 积分
+    pub fn token_credits(&self, total_tokens: u64) -> i64 {
+        if total_tokens == 0 || self.credits_per_1000_tokens <= 0 {
+            return 0;
+        }
+        let credits = total_tokens
+            .saturating_mul(self.credits_per_1000_tokens as u64)
+            .div_ceil(1000);
+        i64::try_from(credits).unwrap_or(i64::MAX).max(1)
+    }
+}
+
+/// 按提示词长度粗略估计一次 LLM 调用的 token 数（约 4 个字符一个 token）
+pub fn estimate_llm_call_tokens(prompt: Option<&str>) -> u64 {
+    let prompt_tokens = prompt.map_or(0, |prompt| prompt.chars().count().div_ceil(4) as u64);
+    ESTIMATED_TOKENS_PER_LLM_CALL + prompt_tokens
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_feature_and_token_credits() {
+        let pricing = PricingTable::default();
+        assert_eq!(pricing.feature_credits(false, false), 0);
+        assert_eq!(pricing.feature_credits(true, false), 2);
+        assert_eq!(pricing.feature_credits(true, true), 3);
+
+        assert_eq!(pricing.token_credits(0), 0);
+        assert_eq!(pricing.token_credits(1), 1);
+        assert_eq!(pricing.token_credits(150), 2);
+        assert_eq!(pricing.token_credits(1000), 10);
+        assert_eq!(pricing.token_credits(1001), 11);
+
+        let free_tokens = PricingTable {
+            credits_per_1000_tokens: 0,
+            ..pricing
+        };
+        assert_eq!(free_tokens.token_credits(5000), 0);
+
+        assert_eq!(
+            estimate_llm_call_tokens(None),
+            ESTIMATED_TOKENS_PER_LLM_CALL
+        );
+        assert_eq!(
+            estimate_llm_call_tokens(Some("summarize")),
+            ESTIMATED_TOKENS_PER_LLM_CALL + 3
+        );
+    }
+}
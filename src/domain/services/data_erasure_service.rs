@@ -0,0 +1,378 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! 数据删除（被遗忘权）
+//!
+//! 按 URL 模式或爬取删除团队已保存的抓取结果：结果行连同正文与截图一起删除，结果元数据
+//! 引用的对象存储对象（下载模式保存的资源、HAR 文档）随后从存储中删除。用于处理内容下架
+//! 与个人数据删除请求。任务记录（URL、状态、计费）保留用于审计，不在删除范围内。
+//!
+//! 下载模式的资源按内容摘要保存，团队内相同内容只有一份；删除资源时，其他仍引用相同内容的
+//! 结果也将无法再读取该资源。
+
+use std::collections::BTreeSet;
+use std::sync::Arc;
+
+use log::{error, info};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::models::ScrapeResult;
+use crate::domain::repositories::crawl_repository::CrawlRepository;
+use crate::domain::repositories::scrape_result_repository::{
+    ResultDeletionFilter, ScrapeResultRepository,
+};
+use crate::domain::repositories::storage_repository::StorageRepository;
+use crate::domain::repositories::task_repository::RepositoryError;
+
+/// URL 模式最大长度
+const MAX_URL_PATTERN_LENGTH: usize = 2048;
+
+/// 结果元数据中引用存储对象的字段
+const STORED_OBJECT_FIELDS: [&str; 4] = ["asset", "har", "mhtml", "pdf"];
+
+/// 数据删除错误
+#[derive(Debug, thiserror::Error)]
+pub enum DataErasureError {
+    /// URL 模式为空、过长或只包含通配符
+    #[error("Invalid URL pattern: {0}")]
+    InvalidPattern(String),
+    /// 爬取不存在或不属于该团队
+    #[error("Crawl not found")]
+    CrawlNotFound,
+    /// 仓库错误
+    #[error(transparent)]
+    Repository(#[from] RepositoryError),
+    /// 结果仓库错误
+    #[error(transparent)]
+    ResultRepository(#[from] anyhow::Error),
+}
+
+/// 删除报告
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ErasureReport {
+    /// 删除的结果数
+    pub results_deleted: usize,
+    /// 随结果删除的截图数
+    pub screenshots_deleted: usize,
+    /// 从存储中删除的对象数
+    pub storage_objects_deleted: usize,
+    /// 删除失败的存储对象键，可重试删除
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub storage_objects_failed: Vec<String>,
+    /// 被删除结果的 URL（去重）
+    pub urls: Vec<String>,
+}
+
+/// 校验 URL 模式
+fn validate_url_pattern(pattern: &str) -> Result<(), DataErasureError> {
+    if pattern.len() > MAX_URL_PATTERN_LENGTH {
+        return Err(DataErasureError::InvalidPattern(format!(
+            "pattern must be at most {} characters",
+            MAX_URL_PATTERN_LENGTH
+        )));
+    }
+    // 只有通配符的模式会删除团队的全部结果，必须至少包含一个字面字符
+    if pattern.chars().all(|c| c == '*' || c.is_whitespace()) {
+        return Err(DataErasureError::InvalidPattern(
+            "pattern must contain at least one non-wildcard character".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// 结果元数据引用的、位于团队命名空间下的存储对象名
+fn stored_object_names(team_id: Uuid, result: &ScrapeResult) -> Vec<String> {
+    let prefix = format!("{}/", team_id);
+    STORED_OBJECT_FIELDS
+        .iter()
+        .filter_map(|field| result.meta_data.get(field)?.get("storage_key")?.as_str())
+        .filter_map(|key| key.strip_prefix(&prefix))
+        .map(str::to_string)
+        .collect()
+}
+
+/// 数据删除服务
+pub struct DataErasureService {
+    results: Arc<dyn ScrapeResultRepository>,
+    crawls: Arc<dyn CrawlRepository>,
+    storage: Arc<dyn StorageRepository>,
+}
+
+impl DataErasureService {
+    /// 创建数据删除服务
+    pub fn new(
+        results: Arc<dyn ScrapeResultRepository>,
+        crawls: Arc<dyn CrawlRepository>,
+        storage: Arc<dyn StorageRepository>,
+    ) -> Self {
+        Self {
+            results,
+            crawls,
+            storage,
+        }
+    }
+
+    /// 删除团队中 URL 匹配模式的全部结果
+    pub async fn erase_by_url_pattern(
+        &self,
+        team_id: Uuid,
+        url_pattern: &str,
+    ) -> Result<ErasureReport, DataErasureError> {
+        validate_url_pattern(url_pattern)?;
+        self.erase(
+            team_id,
+            ResultDeletionFilter {
+                url_pattern: Some(url_pattern.to_string()),
+                crawl_id: None,
+            },
+        )
+        .await
+    }
+
+    /// 删除一次爬取的结果，提供 `url_pattern` 时只删除其中 URL 匹配的结果
+    pub async fn erase_crawl(
+        &self,
+        team_id: Uuid,
+        crawl_id: Uuid,
+        url_pattern: Option<&str>,
+    ) -> Result<ErasureReport, DataErasureError> {
+        if let Some(pattern) = url_pattern {
+            validate_url_pattern(pattern)?;
+        }
+        match self.crawls.find_by_id(crawl_id).await? {
+            Some(crawl) if crawl.team_id == team_id => {}
+            _ => return Err(DataErasureError::CrawlNotFound),
+        }
+        self.erase(
+            team_id,
+            ResultDeletionFilter {
+                url_pattern: url_pattern.map(str::to_string),
+                crawl_id: Some(crawl_id),
+            },
+        )
+        .await
+    }
+
+    /// 删除结果后删除其引用的存储对象；单个对象删除失败不影响其余对象
+    async fn erase(
+        &self,
+        team_id: Uuid,
+        filter: ResultDeletionFilter,
+    ) -> Result<ErasureReport, DataErasureError> {
+        let deleted = self.results.delete_for_team(team_id, &filter).await?;
+
+        let mut report = ErasureReport {
+            results_deleted: deleted.len(),
+            screenshots_deleted: deleted.iter().filter(|r| r.screenshot.is_some()).count(),
+            ..Default::default()
+        };
+        let urls: BTreeSet<&str> = deleted.iter().map(|r| r.url.as_str()).collect();
+        report.urls = urls.into_iter().map(str::to_string).collect();
+
+        let names: BTreeSet<String> = deleted
+            .iter()
+            .flat_map(|result| stored_object_names(team_id, result))
+            .collect();
+        for name in names {
+            match self.storage.delete(team_id, &name).await {
+                Ok(true) => report.storage_objects_deleted += 1,
+                Ok(false) => {}
+                Err(e) => {
+                    error!(
+                        "Failed to delete storage object {} of team {}: {}",
+                        name, team_id, e
+                    );
+                    report.storage_objects_failed.push(name);
+                }
+            }
+        }
+
+        info!(
+            "Erased {} result(s) and {} storage object(s) for team {}",
+            report.results_deleted, report.storage_objects_deleted, team_id
+        );
+        Ok(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::storage::LocalStorageRepository;
+    use crate::presentation::sdk::mocks::MockCrawlRepository;
+    use serde_json::json;
+    use std::sync::Mutex;
+
+    /// Returns the configured results as deleted and records the filter
+    #[derive(Default)]
+    struct DeletingResultRepo {
+        deleted: Vec<ScrapeResult>,
+        filters: Mutex<Vec<ResultDeletionFilter>>,
+    }
+
+    #[async_trait::async_trait]
+    impl ScrapeResultRepository for DeletingResultRepo {
+        async fn save(&self, _result: ScrapeResult) -> anyhow::Result<()> {
+            Ok(())
+        }
+        async fn find_by_task_id(&self, _task_id: Uuid) -> anyhow::Result<Option<ScrapeResult>> {
+            Ok(None)
+        }
+        async fn find_by_task_ids(&self, _task_ids: &[Uuid]) -> anyhow::Result<Vec<ScrapeResult>> {
+            Ok(vec![])
+        }
+        async fn find_latest_for_crawl_url(
+            &self,
+            _crawl_id: Uuid,
+            _url: &str,
+        ) -> anyhow::Result<Option<ScrapeResult>> {
+            Ok(None)
+        }
+        async fn count_not_modified(&self, _task_ids: &[Uuid]) -> anyhow::Result<u64> {
+            Ok(0)
+        }
+        async fn response_times(&self, _task_ids: &[Uuid]) -> anyhow::Result<Vec<i64>> {
+            Ok(Vec::new())
+        }
+        async fn get_team_avg_response_time(&self, _team_id: Uuid) -> anyhow::Result<f64> {
+            Ok(0.0)
+        }
+        async fn delete_for_team(
+            &self,
+            _team_id: Uuid,
+            filter: &ResultDeletionFilter,
+        ) -> anyhow::Result<Vec<ScrapeResult>> {
+            self.filters.lock().unwrap().push(filter.clone());
+            Ok(self.deleted.clone())
+        }
+
+        async fn search_crawl_results(
+            &self,
+            _crawl_id: Uuid,
+            _query: &str,
+            _limit: u64,
+        ) -> anyhow::Result<
+            Vec<crate::domain::repositories::scrape_result_repository::ResultSearchHit>,
+        > {
+            Ok(vec![])
+        }
+    }
+
+    fn result(url: &str, meta_data: serde_json::Value, screenshot: bool) -> ScrapeResult {
+        ScrapeResult {
+            id: Uuid::new_v4(),
+            task_id: Uuid::new_v4(),
+            url: url.to_string(),
+            status_code: 200,
+            content: "content".to_string(),
+            content_type: "text/html".to_string(),
+            headers: json!({}),
+            meta_data,
+            screenshot: screenshot.then(|| "base64".to_string()),
+            response_time_ms: 10,
+            created_at: chrono::Utc::now().naive_utc(),
+            etag: None,
+            last_modified: None,
+        }
+    }
+
+    #[test]
+    fn test_validate_url_pattern() {
+        assert!(validate_url_pattern("https://example.com/users/*").is_ok());
+        assert!(validate_url_pattern("").is_err());
+        assert!(validate_url_pattern("**").is_err());
+        assert!(validate_url_pattern(&"a".repeat(MAX_URL_PATTERN_LENGTH + 1)).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_erase_deletes_referenced_storage_objects() {
+        let dir = tempfile::tempdir().unwrap();
+        let storage = Arc::new(LocalStorageRepository::new(dir.path(), "/v1/assets"));
+        let team_id = Uuid::new_v4();
+        let other_team = Uuid::new_v4();
+        storage
+            .put(team_id, "abc", b"pdf", "application/pdf")
+            .await
+            .unwrap();
+        storage
+            .put(team_id, "har/t1.har", b"{}", "application/json")
+            .await
+            .unwrap();
+        storage
+            .put(other_team, "abc", b"pdf", "application/pdf")
+            .await
+            .unwrap();
+
+        let results = Arc::new(DeletingResultRepo {
+            deleted: vec![
+                result(
+                    "https://example.com/users/1",
+                    json!({
+                        "asset": { "storage_key": format!("{}/abc", team_id) },
+                        "har": { "storage_key": format!("{}/har/t1.har", team_id) },
+                    }),
+                    true,
+                ),
+                // 相同内容的资源只删除一次；其他团队的键被忽略
+                result(
+                    "https://example.com/users/1",
+                    json!({ "asset": { "storage_key": format!("{}/abc", team_id) } }),
+                    false,
+                ),
+                result(
+                    "https://example.com/users/2",
+                    json!({ "asset": { "storage_key": format!("{}/abc", other_team) } }),
+                    false,
+                ),
+            ],
+            ..Default::default()
+        });
+        let service = DataErasureService::new(
+            results.clone(),
+            Arc::new(MockCrawlRepository),
+            storage.clone(),
+        );
+
+        let report = service
+            .erase_by_url_pattern(team_id, "https://example.com/users/*")
+            .await
+            .unwrap();
+
+        assert_eq!(report.results_deleted, 3);
+        assert_eq!(report.screenshots_deleted, 1);
+        assert_eq!(report.storage_objects_deleted, 2);
+        assert!(report.storage_objects_failed.is_empty());
+        assert_eq!(
+            report.urls,
+            vec!["https://example.com/users/1", "https://example.com/users/2"]
+        );
+        assert!(storage.get(team_id, "abc").await.unwrap().is_none());
+        assert!(storage.get(team_id, "har/t1.har").await.unwrap().is_none());
+        assert!(storage.get(other_team, "abc").await.unwrap().is_some());
+        assert_eq!(
+            results.filters.lock().unwrap()[0].url_pattern.as_deref(),
+            Some("https://example.com/users/*")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_erase_crawl_requires_team_crawl() {
+        let dir = tempfile::tempdir().unwrap();
+        let results = Arc::new(DeletingResultRepo::default());
+        let service = DataErasureService::new(
+            results.clone(),
+            Arc::new(MockCrawlRepository),
+            Arc::new(LocalStorageRepository::new(dir.path(), "/v1/assets")),
+        );
+
+        let error = service
+            .erase_crawl(Uuid::new_v4(), Uuid::new_v4(), None)
+            .await
+            .unwrap_err();
+        assert!(matches!(error, DataErasureError::CrawlNotFound));
+        assert!(results.filters.lock().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,308 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! 邮件通知
+//!
+//! 邮件通知渠道：事件通过邮件发送给团队的通知联系人。`EmailSender` 抽象邮件投递
+//! （SMTP / Amazon SES 实现见 `infrastructure::services::smtp_email_sender`），
+//! `EmailNotificationService` 按团队联系人订阅的事件类型生成投递记录并发送邮件。
+//! 投递记录的 `webhook_url` 为收件人组成的 `mailto:` 地址，负载为原始事件负载。
+
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use log::{debug, info};
+#[cfg(feature = "metrics")]
+use metrics::counter;
+use serde_json::Value;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::domain::models::{DeliveryChannel, WebhookEvent, WebhookEventType};
+use crate::domain::repositories::notification_contacts_repository::NotificationContactsRepository;
+use crate::domain::services::notification_service::{describe_event, NotificationTransport};
+
+/// 待发送的邮件
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EmailMessage {
+    /// 收件人
+    pub to: Vec<String>,
+    /// 主题
+    pub subject: String,
+    /// 纯文本正文
+    pub text: String,
+}
+
+/// 邮件发送接口
+#[async_trait]
+pub trait EmailSender: Send + Sync {
+    /// 发送一封邮件（所有收件人共用一封）
+    async fn send(&self, message: &EmailMessage) -> Result<()>;
+}
+
+/// 渲染事件邮件的主题与正文，正文末尾附带完整的事件负载
+pub fn render_email(event_type: &WebhookEventType, payload: &Value) -> (String, String) {
+    let (title, summary) = describe_event(event_type, payload);
+    let details = serde_json::to_string_pretty(payload).unwrap_or_else(|_| payload.to_string());
+    (
+        format!("[crawlrs] {}", title),
+        format!("{}\n\nEvent: {}\n\n{}\n", summary, event_type, details),
+    )
+}
+
+/// 将收件人编码为投递记录的 `mailto:` 地址
+fn mailto(emails: &[String]) -> String {
+    format!("mailto:{}", emails.join(","))
+}
+
+/// 从投递记录的 `mailto:` 地址解析收件人
+fn recipients(url: &str) -> Vec<String> {
+    url.strip_prefix("mailto:")
+        .unwrap_or_default()
+        .split(',')
+        .filter(|email| !email.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// 按团队通知联系人发送事件邮件
+pub struct EmailNotificationService {
+    repository: Arc<dyn NotificationContactsRepository>,
+    sender: Arc<dyn EmailSender>,
+}
+
+impl EmailNotificationService {
+    /// 创建邮件通知服务
+    pub fn new(
+        repository: Arc<dyn NotificationContactsRepository>,
+        sender: Arc<dyn EmailSender>,
+    ) -> Self {
+        Self { repository, sender }
+    }
+}
+
+#[async_trait]
+impl NotificationTransport for EmailNotificationService {
+    fn handles(&self, channel: DeliveryChannel) -> bool {
+        channel == DeliveryChannel::Email
+    }
+
+    async fn route(
+        &self,
+        team_id: Uuid,
+        event_type: &WebhookEventType,
+        payload: &Value,
+    ) -> Result<Vec<WebhookEvent>> {
+        let contacts = self.repository.find_by_team(team_id).await.map_err(|e| {
+            anyhow!(
+                "Failed to load notification contacts of team {}: {}",
+                team_id,
+                e
+            )
+        })?;
+        let Some(contacts) = contacts.filter(|c| c.accepts(event_type)) else {
+            debug!("Team {} has no email contacts for {}", team_id, event_type);
+            return Ok(Vec::new());
+        };
+
+        // 联系人按团队保存，没有独立 ID
+        Ok(vec![WebhookEvent::new(
+            Uuid::new_v4(),
+            team_id,
+            Uuid::nil(),
+            event_type.clone(),
+            payload.clone(),
+            mailto(&contacts.emails),
+        )
+        .with_channel(DeliveryChannel::Email)])
+    }
+
+    async fn deliver(&self, delivery: &WebhookEvent) -> Result<()> {
+        let to = recipients(&delivery.webhook_url);
+        if to.is_empty() {
+            return Err(anyhow!("Email delivery {} has no recipients", delivery.id));
+        }
+        let (subject, text) = render_email(&delivery.event_type, &delivery.payload);
+        let message = EmailMessage { to, subject, text };
+        let result = self.sender.send(&message).await;
+
+        #[cfg(feature = "metrics")]
+        counter!(
+            "email_notifications_total",
+            "event" => delivery.event_type.to_string(),
+            "outcome" => if result.is_ok() { "sent" } else { "failed" }
+        )
+        .increment(1);
+
+        result.map_err(|e| {
+            anyhow!(
+                "Failed to email {} to team {}: {}",
+                delivery.event_type,
+                delivery.team_id,
+                e
+            )
+        })?;
+        info!(
+            "Emailed {} to {} contact(s) of team {}",
+            delivery.event_type,
+            message.to.len(),
+            delivery.team_id
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::NotificationContacts;
+    use crate::domain::repositories::task_repository::RepositoryError;
+    use serde_json::json;
+    use std::sync::Mutex;
+
+    struct StaticContactsRepository {
+        contacts: Option<NotificationContacts>,
+    }
+
+    #[async_trait]
+    impl NotificationContactsRepository for StaticContactsRepository {
+        async fn find_by_team(
+            &self,
+            team_id: Uuid,
+        ) -> Result<Option<NotificationContacts>, RepositoryError> {
+            Ok(self.contacts.clone().filter(|c| c.team_id == team_id))
+        }
+
+        async fn upsert(
+            &self,
+            contacts: &NotificationContacts,
+        ) -> Result<NotificationContacts, RepositoryError> {
+            Ok(contacts.clone())
+        }
+
+        async fn delete(&self, _team_id: Uuid) -> Result<bool, RepositoryError> {
+            Ok(false)
+        }
+    }
+
+    #[derive(Default)]
+    struct RecordingSender {
+        messages: Mutex<Vec<EmailMessage>>,
+    }
+
+    #[async_trait]
+    impl EmailSender for RecordingSender {
+        async fn send(&self, message: &EmailMessage) -> Result<()> {
+            self.messages.lock().unwrap().push(message.clone());
+            Ok(())
+        }
+    }
+
+    fn build_service(
+        contacts: Option<NotificationContacts>,
+    ) -> (EmailNotificationService, Arc<RecordingSender>) {
+        let sender = Arc::new(RecordingSender::default());
+        let service = EmailNotificationService::new(
+            Arc::new(StaticContactsRepository { contacts }),
+            sender.clone(),
+        );
+        (service, sender)
+    }
+
+    #[test]
+    fn test_render_credits_low() {
+        let (subject, text) = render_email(
+            &WebhookEventType::CreditsLow,
+            &json!({"team_id": "t1", "balance": 40, "threshold": 50}),
+        );
+        assert_eq!(subject, "[crawlrs] Credit balance is running low");
+        assert!(text.starts_with("The credit balance of team t1 is 40"));
+        assert!(text.contains("Event: credits.low"));
+        assert!(text.contains("\"threshold\": 50"));
+    }
+
+    #[test]
+    fn test_render_webhook_delivery_failed_and_custom() {
+        let (subject, text) = render_email(
+            &WebhookEventType::WebhookDeliveryFailed,
+            &json!({
+                "event_type": "crawl.completed",
+                "event_id": "e1",
+                "webhook_url": "https://hooks.example.com",
+                "attempts": 5,
+                "error": "HTTP status 500"
+            }),
+        );
+        assert_eq!(subject, "[crawlrs] Webhook delivery failed");
+        assert!(text.contains("to https://hooks.example.com was given up after 5 attempts"));
+
+        let (subject, _) = render_email(
+            &WebhookEventType::Custom("page.scraped".to_string()),
+            &json!({}),
+        );
+        assert_eq!(subject, "[crawlrs] Event page.scraped");
+    }
+
+    #[tokio::test]
+    async fn test_route_and_deliver_email_to_subscribed_contacts() {
+        let team_id = Uuid::new_v4();
+        let contacts = NotificationContacts::new(
+            team_id,
+            vec!["ops@example.com".to_string(), "dev@example.com".to_string()],
+            vec![WebhookEventType::CrawlSummary],
+        );
+        let (service, sender) = build_service(Some(contacts));
+
+        let deliveries = service
+            .route(
+                team_id,
+                &WebhookEventType::CrawlSummary,
+                &json!({"status": "completed"}),
+            )
+            .await
+            .unwrap();
+        assert_eq!(deliveries.len(), 1);
+        assert_eq!(deliveries[0].channel, DeliveryChannel::Email);
+        assert_eq!(
+            deliveries[0].webhook_url,
+            "mailto:ops@example.com,dev@example.com"
+        );
+
+        service.deliver(&deliveries[0]).await.unwrap();
+        let messages = sender.messages.lock().unwrap();
+        assert_eq!(messages.len(), 1);
+        assert_eq!(messages[0].to, vec!["ops@example.com", "dev@example.com"]);
+        assert_eq!(messages[0].subject, "[crawlrs] Crawl finished: completed");
+    }
+
+    #[tokio::test]
+    async fn test_route_skips_unsubscribed_disabled_and_unknown_teams() {
+        let team_id = Uuid::new_v4();
+        let mut contacts = NotificationContacts::new(
+            team_id,
+            vec!["ops@example.com".to_string()],
+            vec![WebhookEventType::CreditsLow],
+        );
+        let (service, _) = build_service(Some(contacts.clone()));
+        let event = WebhookEventType::CrawlSummary;
+        assert!(service
+            .route(team_id, &event, &json!({}))
+            .await
+            .unwrap()
+            .is_empty());
+        assert!(service
+            .route(Uuid::new_v4(), &WebhookEventType::CreditsLow, &json!({}))
+            .await
+            .unwrap()
+            .is_empty());
+
+        contacts.enabled = false;
+        let (disabled, _) = build_service(Some(contacts));
+        assert!(disabled
+            .route(team_id, &WebhookEventType::CreditsLow, &json!({}))
+            .await
+            .unwrap()
+            .is_empty());
+    }
+}
@@ -2,9 +2,10 @@
 // Licensed under the Apache License, Version 2.0
 // See LICENSE file in the project root for full license information.
 
-use crate::domain::services::extraction_utils::ExtractableRule;
+use crate::domain::services::extraction_utils::{ExtractableRule, ExtractionUtils};
 use crate::domain::services::llm_service::LLMServiceTrait;
 pub use crate::domain::services::llm_service::TokenUsage;
+use crate::utils::html_table::element_table_records;
 use anyhow::Result;
 use scraper::{ElementRef, Html, Selector};
 use serde::{Deserialize, Serialize};
@@ -13,6 +14,9 @@ use std::collections::HashMap;
 use std::sync::Arc;
 use url::Url;
 
+/// 选择器规则输出表格记录的 `output_format`
+pub const TABLE_OUTPUT_FORMAT: &str = "table";
+
 /// 提取规则
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ExtractionRule {
@@ -21,7 +25,8 @@ pub struct ExtractionRule {
     pub is_array: bool,
     pub use_llm: Option<bool>,         // New field to enable LLM extraction
     pub llm_prompt: Option<String>,    // Optional specific prompt for this rule
-    pub output_format: Option<String>, // "json" (default) or "plaintext"
+    pub output_format: Option<String>, // "json" (default), "plaintext", or "table" for selector rules
+    pub frame: Option<String>, // Apply to an iframe (name, URL or index) instead of the page
 }
 
 impl ExtractableRule for ExtractionRule {
@@ -132,10 +137,15 @@ impl ExtractionService {
             }
 
             if let Some(selector_str) = &rule.selector {
-                if let Ok(selector) = Selector::parse(selector_str) {
+                if let Ok(selector) = ExtractionUtils::parse_selector(selector_str) {
                     let document = Html::parse_document(html_content);
 
-                    if rule.is_array {
+                    if rule.output_format.as_deref() == Some(TABLE_OUTPUT_FORMAT) {
+                        result.insert(
+                            key.clone(),
+                            Self::extract_table_value(&document, &selector, rule.is_array),
+                        );
+                    } else if rule.is_array {
                         // Array extraction - 使用辅助方法优化
                         let mut values = Vec::new();
                         for element in document.select(&selector) {
@@ -167,6 +177,21 @@ impl ExtractionService {
         Ok(json!(result))
     }
 
+    /// 提取表格规则的值：选中的表格转换为记录数组
+    ///
+    /// `is_array` 时返回每个选中表格的记录数组，否则返回第一个表格的记录，
+    /// 没有表格时为 null。
+    fn extract_table_value(document: &Html, selector: &Selector, is_array: bool) -> Value {
+        let mut tables = document
+            .select(selector)
+            .filter_map(|element| element_table_records(element, true));
+        if is_array {
+            Value::Array(tables.collect())
+        } else {
+            tables.next().unwrap_or(Value::Null)
+        }
+    }
+
     /// 提取单个元素的属性值 - 消除深层嵌套
     ///
     /// 行为约定（与历史实现完全一致）：
@@ -233,7 +258,7 @@ impl ExtractionService {
                 // 1. Noise removal via CSS or selector
                 let content_to_process = if let Some(sel) = &rule.selector {
                     let document = Html::parse_document(html_content);
-                    if let Ok(selector) = Selector::parse(sel) {
+                    if let Ok(selector) = ExtractionUtils::parse_selector(sel) {
                         document
                             .select(&selector)
                             .map(|e| Self::get_clean_text(&e.html()))
@@ -271,10 +296,15 @@ impl ExtractionService {
             // 之前是 70+ 行内联重复逻辑（与上方 extract_with_selectors 完全一致），
             // 现在直接复用 Self::extract_element_value，行为与历史完全一致。
             if let Some(selector_str) = &rule.selector {
-                if let Ok(selector) = Selector::parse(selector_str) {
+                if let Ok(selector) = ExtractionUtils::parse_selector(selector_str) {
                     let document = Html::parse_document(html_content);
 
-                    if rule.is_array {
+                    if rule.output_format.as_deref() == Some(TABLE_OUTPUT_FORMAT) {
+                        result.insert(
+                            key.clone(),
+                            Self::extract_table_value(&document, &selector, rule.is_array),
+                        );
+                    } else if rule.is_array {
                         // Array extraction - 复用 extract_element_value（与 extract_with_selectors 一致）
                         let values: Vec<Value> = document
                             .select(&selector)
@@ -460,6 +490,7 @@ mod tests {
             use_llm: None,
             llm_prompt: None,
             output_format: None,
+            frame: None,
         }
     }
 
@@ -471,6 +502,7 @@ mod tests {
             use_llm: Some(true),
             llm_prompt: prompt.map(|s| s.to_string()),
             output_format: None,
+            frame: None,
         }
     }
 
@@ -759,6 +791,34 @@ mod tests {
         assert_eq!(arr[0], "/path");
     }
 
+    #[test]
+    fn test_extract_with_selectors_table_output_format() {
+        let html = r#"
+            <table id="plans"><tr><th>Plan</th><th>Price</th></tr><tr><td>Basic</td><td>$10</td></tr></table>
+            <div class="other"><table><tr><th>A</th></tr><tr><td>1</td></tr></table></div>
+        "#;
+        let mut rules = HashMap::new();
+        let mut plans = rule(Some("#plans"), None, false);
+        plans.output_format = Some(TABLE_OUTPUT_FORMAT.to_string());
+        rules.insert("plans".to_string(), plans);
+        let mut all = rule(Some("table, .other"), None, true);
+        all.output_format = Some(TABLE_OUTPUT_FORMAT.to_string());
+        rules.insert("all".to_string(), all);
+        let mut missing = rule(Some("p"), None, false);
+        missing.output_format = Some(TABLE_OUTPUT_FORMAT.to_string());
+        rules.insert("missing".to_string(), missing);
+
+        let mock = MockLLMService::new_success(json!({}), TokenUsage::default());
+        let service = ExtractionService::new(Arc::new(mock));
+        let result = service
+            .extract_with_selectors(html, &rules, None)
+            .expect("extract should succeed");
+
+        assert_eq!(result["plans"], json!([{"Plan": "Basic", "Price": "$10"}]));
+        assert_eq!(result["all"].as_array().unwrap().len(), 3);
+        assert_eq!(result["missing"], Value::Null);
+    }
+
     #[test]
     fn test_extract_with_selectors_multiple_rules() {
         let html = r#"
@@ -1072,6 +1132,7 @@ mod tests {
                 use_llm: Some(true),
                 llm_prompt: None,
                 output_format: None,
+                frame: None,
             },
         );
 
@@ -31,6 +31,21 @@ pub enum ExtractionUtilsError {
 pub struct ExtractionUtils;
 
 impl ExtractionUtils {
+    /// Parse a CSS selector, accepting the shadow-piercing combinators `>>>` and `/deep/`
+    ///
+    /// Browser engines serialize open shadow roots inline (`flatten_shadow_dom`), so
+    /// shadow content is an ordinary descendant of its host and piercing equals the
+    /// descendant combinator.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let selector = ExtractionUtils::parse_selector("product-card >>> .price")?;
+    /// ```
+    pub fn parse_selector(selector_str: &str) -> Result<Selector, ExtractionUtilsError> {
+        let pierced = selector_str.replace(">>>", " ").replace("/deep/", " ");
+        Selector::parse(&pierced).map_err(|e| ExtractionUtilsError::InvalidSelector(e.to_string()))
+    }
+
     /// Extract element attribute value with automatic URL joining for relative URLs
     ///
     /// This function consolidates the repeated pattern:
@@ -147,8 +162,7 @@ impl ExtractionUtils {
         base: Option<&Url>,
     ) -> Result<Value, ExtractionUtilsError> {
         let document = Html::parse_document(html);
-        let selector = Selector::parse(selector_str)
-            .map_err(|e| ExtractionUtilsError::InvalidSelector(e.to_string()))?;
+        let selector = Self::parse_selector(selector_str)?;
 
         if let Some(element) = document.select(&selector).next() {
             let value = attr.map_or_else(
@@ -198,6 +212,27 @@ mod tests {
     use super::*;
     use scraper::Html;
 
+    #[test]
+    fn test_parse_selector_pierces_shadow_roots() {
+        // Flattened output: the shadow root's content is inlined under its host
+        let html = r#"<product-card><span class="price">9.99</span></product-card>
+            <product-card><div class="price">19.99</div></product-card>"#;
+        let document = Html::parse_document(html);
+
+        let selector = ExtractionUtils::parse_selector("product-card >>> .price").unwrap();
+        let prices: Vec<String> = document
+            .select(&selector)
+            .map(|e| e.text().collect::<String>())
+            .collect();
+        assert_eq!(prices, vec!["9.99", "19.99"]);
+
+        assert!(ExtractionUtils::parse_selector("product-card /deep/ .price").is_ok());
+        assert!(matches!(
+            ExtractionUtils::parse_selector(">>>"),
+            Err(ExtractionUtilsError::InvalidSelector(_))
+        ));
+    }
+
     #[test]
     fn test_extract_element_value_with_href() {
         let html = r#"<a href="relative/path">Link</a>"#;
@@ -0,0 +1,326 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! 积分余额不足提醒
+//!
+//! 扣除积分后余额跌破团队设置的阈值时，`CreditsRepositoryImpl` 调用通知器：为订阅了
+//! `credits.low` 的 webhook 写入待投递事件（由 webhook worker 投递并按策略重试），
+//! 配置了邮件中继且团队设置了邮箱时，同时通过中继发送提醒邮件；配置了事件通知时，
+//! 还会通知团队的邮件联系人与聊天频道。
+
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use chrono::Utc;
+use log::{info, warn};
+use serde_json::{json, Value};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::domain::models::{LowBalanceAlert, WebhookEvent, WebhookEventType};
+use crate::domain::repositories::webhook_event_repository::WebhookEventRepository;
+use crate::domain::repositories::webhook_repository::WebhookRepository;
+use crate::domain::services::notification_service::EventNotifier;
+
+/// 余额不足提醒通知器
+#[async_trait]
+pub trait LowBalanceNotifier: Send + Sync {
+    /// 通知团队余额已跌破阈值
+    ///
+    /// # 参数
+    /// * `alert` - 团队的提醒设置
+    /// * `balance` - 扣除后的余额
+    async fn notify(&self, alert: &LowBalanceAlert, balance: i64) -> Result<()>;
+}
+
+/// 构造 `credits.low` 事件负载
+pub fn low_balance_payload(alert: &LowBalanceAlert, balance: i64) -> Value {
+    json!({
+        "event": WebhookEventType::CreditsLow.to_string(),
+        "team_id": alert.team_id,
+        "balance": balance,
+        "threshold": alert.threshold,
+        "timestamp": Utc::now().to_rfc3339(),
+    })
+}
+
+/// 邮件中继：以 JSON `{to, subject, text}` POST 到中继地址
+struct EmailRelay {
+    client: Arc<reqwest::Client>,
+    url: String,
+}
+
+/// 通过 webhook 事件与可选的邮件中继发送余额不足提醒
+pub struct LowBalanceAlertService {
+    webhook_repository: Arc<dyn WebhookRepository>,
+    event_repository: Arc<dyn WebhookEventRepository>,
+    email_relay: Option<EmailRelay>,
+    notifier: Option<Arc<dyn EventNotifier>>,
+}
+
+impl LowBalanceAlertService {
+    /// 创建提醒服务（只推送 webhook 事件）
+    pub fn new(
+        webhook_repository: Arc<dyn WebhookRepository>,
+        event_repository: Arc<dyn WebhookEventRepository>,
+    ) -> Self {
+        Self {
+            webhook_repository,
+            event_repository,
+            email_relay: None,
+            notifier: None,
+        }
+    }
+
+    /// 配置邮件中继，团队设置了邮箱时同时发送提醒邮件
+    pub fn with_email_relay(mut self, client: Arc<reqwest::Client>, url: String) -> Self {
+        self.email_relay = Some(EmailRelay { client, url });
+        self
+    }
+
+    /// 配置事件通知，同时通知订阅了 `credits.low` 的邮件联系人与聊天频道
+    pub fn with_notifier(mut self, notifier: Arc<dyn EventNotifier>) -> Self {
+        self.notifier = Some(notifier);
+        self
+    }
+
+    /// 为团队中订阅了 `credits.low` 的 webhook 写入待投递事件，返回写入数量
+    async fn queue_webhook_events(&self, team_id: Uuid, payload: &Value) -> Result<usize> {
+        let event_type = WebhookEventType::CreditsLow;
+        let webhooks = self
+            .webhook_repository
+            .find_by_team_id(team_id)
+            .await
+            .map_err(|e| anyhow!("Failed to list webhooks for team {}: {}", team_id, e))?;
+
+        let mut queued = 0;
+        for webhook in webhooks.iter().filter(|w| w.accepts(&event_type)) {
+            let rendered = match webhook.render_payload(&event_type, payload) {
+                Ok(rendered) => rendered,
+                Err(e) => {
+                    warn!("Failed to render payload for webhook {}: {}", webhook.id, e);
+                    continue;
+                }
+            };
+            let event = WebhookEvent::new(
+                Uuid::new_v4(),
+                team_id,
+                webhook.id,
+                event_type.clone(),
+                rendered,
+                webhook.url.clone(),
+            );
+            match self.event_repository.create(&event).await {
+                Ok(_) => queued += 1,
+                Err(e) => warn!(
+                    "Failed to queue {} for webhook {}: {}",
+                    event_type, webhook.id, e
+                ),
+            }
+        }
+        Ok(queued)
+    }
+
+    /// 通过邮件中继发送提醒邮件，失败只记录日志
+    async fn send_email(
+        &self,
+        relay: &EmailRelay,
+        to: &str,
+        alert: &LowBalanceAlert,
+        balance: i64,
+    ) {
+        let body = json!({
+            "to": to,
+            "subject": "crawlrs: credit balance is running low",
+            "text": format!(
+                "The credit balance of team {} is {}, below the alert threshold of {}.",
+                alert.team_id, balance, alert.threshold
+            ),
+        });
+        match relay.client.post(&relay.url).json(&body).send().await {
+            Ok(response) if response.status().is_success() => {}
+            Ok(response) => warn!(
+                "Email relay rejected low-balance alert for team {}: HTTP {}",
+                alert.team_id,
+                response.status()
+            ),
+            Err(e) => warn!(
+                "Failed to send low-balance email for team {}: {}",
+                alert.team_id, e
+            ),
+        }
+    }
+}
+
+#[async_trait]
+impl LowBalanceNotifier for LowBalanceAlertService {
+    async fn notify(&self, alert: &LowBalanceAlert, balance: i64) -> Result<()> {
+        let payload = low_balance_payload(alert, balance);
+        let queued = self.queue_webhook_events(alert.team_id, &payload).await?;
+        info!(
+            "Team {} balance {} fell below {}, queued {} credits.low events",
+            alert.team_id, balance, alert.threshold, queued
+        );
+
+        if let (Some(relay), Some(email)) = (&self.email_relay, &alert.email) {
+            self.send_email(relay, email, alert, balance).await;
+        }
+        if let Some(notifier) = &self.notifier {
+            if let Err(e) = notifier
+                .notify(alert.team_id, &WebhookEventType::CreditsLow, &payload)
+                .await
+            {
+                warn!("{}", e);
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::Webhook;
+    use crate::domain::repositories::task_repository::RepositoryError;
+    use std::sync::Mutex;
+
+    struct InMemoryWebhookRepository {
+        webhooks: Vec<Webhook>,
+    }
+
+    #[async_trait]
+    impl WebhookRepository for InMemoryWebhookRepository {
+        async fn create(&self, webhook: &Webhook) -> Result<Webhook, RepositoryError> {
+            Ok(webhook.clone())
+        }
+
+        async fn find_by_id(&self, id: Uuid) -> Result<Option<Webhook>, RepositoryError> {
+            Ok(self.webhooks.iter().find(|w| w.id == id).cloned())
+        }
+
+        async fn find_by_team_id(&self, team_id: Uuid) -> Result<Vec<Webhook>, RepositoryError> {
+            Ok(self
+                .webhooks
+                .iter()
+                .filter(|w| w.team_id == team_id)
+                .cloned()
+                .collect())
+        }
+    }
+
+    #[derive(Default)]
+    struct RecordingEventRepository {
+        events: Mutex<Vec<WebhookEvent>>,
+    }
+
+    #[async_trait]
+    impl WebhookEventRepository for RecordingEventRepository {
+        async fn create(&self, event: &WebhookEvent) -> Result<WebhookEvent, RepositoryError> {
+            self.events.lock().unwrap().push(event.clone());
+            Ok(event.clone())
+        }
+
+        async fn find_by_id(&self, _id: Uuid) -> Result<Option<WebhookEvent>, RepositoryError> {
+            Ok(None)
+        }
+
+        async fn find_pending(&self, _limit: u64) -> Result<Vec<WebhookEvent>, RepositoryError> {
+            Ok(Vec::new())
+        }
+
+        async fn find_by_team_id_paginated(
+            &self,
+            _team_id: Uuid,
+            _limit: u32,
+            _offset: u32,
+        ) -> Result<Vec<WebhookEvent>, RepositoryError> {
+            Ok(Vec::new())
+        }
+
+        async fn count_by_team_id(&self, _team_id: Uuid) -> Result<u64, RepositoryError> {
+            Ok(0)
+        }
+
+        async fn update(&self, event: &WebhookEvent) -> Result<WebhookEvent, RepositoryError> {
+            Ok(event.clone())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_notify_queues_events_for_subscribed_webhooks_only() {
+        let team_id = Uuid::new_v4();
+        let subscribed = Webhook::new(Uuid::new_v4(), team_id, "https://a.example".to_string());
+        let other = Webhook::new(Uuid::new_v4(), team_id, "https://b.example".to_string())
+            .with_event_types(vec![WebhookEventType::CrawlCompleted]);
+        let events = Arc::new(RecordingEventRepository::default());
+        let service = LowBalanceAlertService::new(
+            Arc::new(InMemoryWebhookRepository {
+                webhooks: vec![subscribed.clone(), other],
+            }),
+            events.clone(),
+        );
+
+        let alert = LowBalanceAlert {
+            team_id,
+            threshold: 100,
+            email: Some("ops@example.com".to_string()),
+            alerted: false,
+            alerted_at: None,
+        };
+        service.notify(&alert, 42).await.unwrap();
+
+        let events = events.events.lock().unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].webhook_id, subscribed.id);
+        assert_eq!(events[0].event_type, WebhookEventType::CreditsLow);
+        assert_eq!(events[0].payload["balance"], 42);
+        assert_eq!(events[0].payload["threshold"], 100);
+    }
+
+    #[derive(Default)]
+    struct RecordingNotifier {
+        notified: Mutex<Vec<(Uuid, WebhookEventType)>>,
+    }
+
+    #[async_trait]
+    impl EventNotifier for RecordingNotifier {
+        async fn notify(
+            &self,
+            team_id: Uuid,
+            event_type: &WebhookEventType,
+            _payload: &Value,
+        ) -> Result<usize> {
+            self.notified
+                .lock()
+                .unwrap()
+                .push((team_id, event_type.clone()));
+            Ok(1)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_notify_forwards_to_event_notifier() {
+        let team_id = Uuid::new_v4();
+        let notifier = Arc::new(RecordingNotifier::default());
+        let service = LowBalanceAlertService::new(
+            Arc::new(InMemoryWebhookRepository { webhooks: vec![] }),
+            Arc::new(RecordingEventRepository::default()),
+        )
+        .with_notifier(notifier.clone());
+
+        let alert = LowBalanceAlert {
+            team_id,
+            threshold: 100,
+            email: None,
+            alerted: false,
+            alerted_at: None,
+        };
+        service.notify(&alert, 42).await.unwrap();
+
+        assert_eq!(
+            *notifier.notified.lock().unwrap(),
+            vec![(team_id, WebhookEventType::CreditsLow)]
+        );
+    }
+}
@@ -11,14 +11,29 @@
 //! 包含的服务：
 //! - 认证范围服务（auth_scope_service）：处理 API Key 权限范围管理
 //! - 审计服务（audit_service）：处理认证和授权决策的审计日志
+//! - 流量统计服务（bandwidth_service）：统计团队与任务的下载流量，汇总每日用量并检查下载上限
+//! - 聊天通知服务（chat_notification_service）：向团队的 Slack 或通用 JSON 频道推送事件消息
+//! - 积分计价（credit_pricing）：扣费与费用预估共用的积分计价规则
+//! - 数据删除服务（data_erasure_service）：按 URL 模式或爬取删除已保存的结果与存储对象
+//! - 邮件通知服务（email_notification_service）：按团队通知联系人订阅的事件发送邮件
 //! - 提取服务（extraction_service）：处理内容提取和数据解析逻辑
 //! - 提取工具（extraction_utils）：消除提取逻辑重复的共享工具函数
 //! - 地理位置服务（geo_location）：提供IP地址地理位置查询的抽象接口
 //! - LLM服务（llm_service）：集成大语言模型进行智能处理
+//! - 余额不足提醒服务（low_balance_alert_service）：余额跌破阈值时推送 `credits.low` 事件与通知
+//! - 统一通知服务（notification_service）：通知渠道注册、按事件类型路由、与 webhook 共用投递记录与重试
+//! - OIDC 单点登录服务（oidc_service）：授权码 + PKCE 登录并签发绑定团队的会话令牌
+//! - 团队套餐服务（plan_service）：加载各套餐的限制并查询团队当前的套餐
+//! - 积分价格服务（pricing_service）：从配置加载全局与按团队覆盖的积分价格表
+//! - 外部检索索引（result_indexer）：将完成的结果写入 OpenSearch 等外部检索集群
+//! - 结果投递上传（result_uploader）：将爬取结果上传到客户指定的存储桶或预签名前缀
 //! - 重试处理器（retry_handler）：处理任务失败的重试逻辑
 //! - 搜索服务（search_service）：处理内容搜索和索引逻辑
+//! - 团队数据导出服务（team_export_service）：将团队数据打包为归档写入对象存储
+//! - 团队成员服务（team_membership_service）：创建团队、邀请与管理团队成员
 //! - 团队服务（team_service）：处理团队地理限制验证逻辑
 //! - 限流服务（rate_limiting_service）：处理请求限流逻辑
+//! - URL 黑名单服务（url_blocklist_service）：检查目标 URL 是否被全局或团队黑名单禁止
 //! - Webhook服务（webhook_service）：处理 Webhook 通知逻辑
 //!
 //! 领域服务与应用程序服务的区别在于：领域服务包含纯粹的业务逻辑，
@@ -27,14 +42,29 @@
 pub mod audit_log_builder;
 pub mod audit_service;
 pub mod auth_scope_service;
+pub mod bandwidth_service;
+pub mod chat_notification_service;
+pub mod credit_pricing;
+pub mod data_erasure_service;
+pub mod email_notification_service;
 pub mod extraction_service;
 pub mod extraction_utils;
 pub mod geo_location;
 pub mod llm_service;
+pub mod low_balance_alert_service;
+pub mod notification_service;
+pub mod oidc_service;
+pub mod plan_service;
+pub mod pricing_service;
 pub mod rate_limiting_service;
 pub mod relevance_scorer;
+pub mod result_indexer;
+pub mod result_uploader;
 pub mod retry_handler;
 pub mod search_service;
+pub mod team_export_service;
+pub mod team_membership_service;
 pub mod team_service;
+pub mod url_blocklist_service;
 pub mod webhook_sender;
 pub mod webhook_service;
@@ -0,0 +1,437 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! 统一事件通知
+//!
+//! 爬取结束、积分不足、页面变更、webhook 投递最终失败等事件除推送 webhook 外，还通过
+//! 团队配置的通知渠道发送：邮件联系人（`EmailNotificationService`）与聊天频道
+//! （`ChatNotificationService`，Slack 或通用 JSON POST）。
+//!
+//! 每个渠道实现 `NotificationTransport` 并注册到 `NotificationService`：
+//!
+//! - 路由：渠道按团队各投递目标订阅的事件类型（空表示全部事件）生成投递记录；
+//! - 投递记录：与 webhook 共用 `webhook_events` 表，`channel` 字段区分渠道，
+//!   因此投递历史、重试与死信对所有渠道一致；
+//! - 重试：首次投递失败的记录按 webhook 的退避规则进入 `failed` 状态，
+//!   由 webhook worker 通过 `EventNotifier::redeliver` 交回对应渠道重新投递。
+
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use log::{debug, error, warn};
+use serde_json::Value;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::domain::models::{DeliveryChannel, WebhookEvent, WebhookEventType};
+use crate::domain::repositories::webhook_event_repository::WebhookEventRepository;
+
+/// 事件通知接口
+#[async_trait]
+pub trait EventNotifier: Send + Sync {
+    /// 将事件通知团队
+    ///
+    /// # 参数
+    /// * `team_id` - 团队 ID
+    /// * `event_type` - 事件类型
+    /// * `payload` - 事件负载（与 webhook 负载相同）
+    ///
+    /// # 返回值
+    /// * `Ok(usize)` - 送达的投递数量（每个频道或每组邮件联系人计一次），团队未订阅该事件时为 0
+    /// * `Err` - 查询配置或发送失败
+    async fn notify(
+        &self,
+        team_id: Uuid,
+        event_type: &WebhookEventType,
+        payload: &Value,
+    ) -> Result<usize>;
+
+    /// 重新投递一条通知记录，由 webhook worker 重试非 webhook 渠道的记录时调用
+    ///
+    /// 默认实现不支持重新投递。
+    async fn redeliver(&self, delivery: &WebhookEvent) -> Result<()> {
+        Err(anyhow!(
+            "redelivery of {} notifications is not supported",
+            delivery.channel
+        ))
+    }
+}
+
+/// 通知渠道接口
+#[async_trait]
+pub trait NotificationTransport: Send + Sync {
+    /// 渠道是否负责该类投递记录
+    fn handles(&self, channel: DeliveryChannel) -> bool;
+
+    /// 为团队订阅了该事件类型的投递目标生成投递记录（尚未持久化）
+    ///
+    /// # 返回值
+    /// * `Ok(Vec<WebhookEvent>)` - 投递记录，`channel` 为本渠道，团队未订阅时为空
+    /// * `Err` - 查询团队配置失败
+    async fn route(
+        &self,
+        team_id: Uuid,
+        event_type: &WebhookEventType,
+        payload: &Value,
+    ) -> Result<Vec<WebhookEvent>>;
+
+    /// 投递一条记录
+    async fn deliver(&self, delivery: &WebhookEvent) -> Result<()>;
+}
+
+/// 读取负载中的字段，缺失时为 `unknown`
+fn field(payload: &Value, key: &str) -> String {
+    match payload.get(key) {
+        Some(Value::String(s)) => s.clone(),
+        Some(Value::Null) | None => "unknown".to_string(),
+        Some(other) => other.to_string(),
+    }
+}
+
+/// 生成事件的标题与一句话摘要，供邮件与聊天消息共用
+pub fn describe_event(event_type: &WebhookEventType, payload: &Value) -> (String, String) {
+    match event_type {
+        WebhookEventType::CrawlSummary => (
+            format!("Crawl finished: {}", field(payload, "status")),
+            format!(
+                "Crawl {} of {} finished ({}): {} of {} pages succeeded, {} credits consumed.",
+                field(payload, "crawl_id"),
+                field(payload, "root_url"),
+                field(payload, "status"),
+                field(payload, "succeeded_pages"),
+                field(payload, "total_pages"),
+                field(payload, "credits_consumed"),
+            ),
+        ),
+        WebhookEventType::CreditsLow => (
+            "Credit balance is running low".to_string(),
+            format!(
+                "The credit balance of team {} is {}, below the alert threshold of {}.",
+                field(payload, "team_id"),
+                field(payload, "balance"),
+                field(payload, "threshold"),
+            ),
+        ),
+        WebhookEventType::MonitorChanged => (
+            format!("Monitored page changed: {}", field(payload, "url")),
+            format!(
+                "{} changed since the previous check: {} lines added, {} lines removed.",
+                field(payload, "url"),
+                field(payload, "added_lines"),
+                field(payload, "removed_lines"),
+            ),
+        ),
+        WebhookEventType::WebhookDeliveryFailed => (
+            "Webhook delivery failed".to_string(),
+            format!(
+                "Delivery of {} event {} to {} was given up after {} attempts: {}",
+                field(payload, "event_type"),
+                field(payload, "event_id"),
+                field(payload, "webhook_url"),
+                field(payload, "attempts"),
+                field(payload, "error"),
+            ),
+        ),
+        other => (
+            format!("Event {}", other),
+            format!("Your team received a {} event.", other),
+        ),
+    }
+}
+
+/// 统一通知服务
+///
+/// 依次询问已注册的渠道路由事件，将每条投递记录写入投递记录仓库后立即投递，
+/// 并记录投递结果。单个渠道或记录失败只记录日志，不影响其他渠道。
+pub struct NotificationService {
+    /// 投递记录仓库（与 webhook 共用）
+    repository: Arc<dyn WebhookEventRepository>,
+    /// 已注册的渠道
+    transports: Vec<Arc<dyn NotificationTransport>>,
+}
+
+impl NotificationService {
+    /// 创建未注册任何渠道的通知服务
+    pub fn new(repository: Arc<dyn WebhookEventRepository>) -> Self {
+        Self {
+            repository,
+            transports: Vec::new(),
+        }
+    }
+
+    /// 注册通知渠道
+    pub fn register(mut self, transport: Arc<dyn NotificationTransport>) -> Self {
+        self.transports.push(transport);
+        self
+    }
+
+    /// 投递一条已持久化的记录并保存投递结果，返回是否送达
+    ///
+    /// 失败的记录按 `WebhookEvent::record_attempt` 的退避规则等待 webhook worker 重试。
+    async fn attempt(
+        &self,
+        transport: &dyn NotificationTransport,
+        delivery: &mut WebhookEvent,
+    ) -> bool {
+        let delivered = match transport.deliver(delivery).await {
+            Ok(()) => {
+                delivery.record_attempt(true, None, None);
+                true
+            }
+            Err(e) => {
+                warn!(
+                    "Failed to deliver {} notification {} for {}: {}",
+                    delivery.channel, delivery.id, delivery.event_type, e
+                );
+                delivery.record_attempt(false, None, Some(e.to_string()));
+                false
+            }
+        };
+        if let Err(e) = self.repository.update(delivery).await {
+            error!(
+                "Failed to update notification delivery {}: {}",
+                delivery.id, e
+            );
+        }
+        delivered
+    }
+}
+
+#[async_trait]
+impl EventNotifier for NotificationService {
+    async fn notify(
+        &self,
+        team_id: Uuid,
+        event_type: &WebhookEventType,
+        payload: &Value,
+    ) -> Result<usize> {
+        let mut delivered = 0;
+        for transport in &self.transports {
+            let deliveries = match transport.route(team_id, event_type, payload).await {
+                Ok(deliveries) => deliveries,
+                Err(e) => {
+                    warn!("{}", e);
+                    continue;
+                }
+            };
+            for mut delivery in deliveries {
+                // 无法写入投递记录时仍尽力投递一次，但不会重试
+                if let Err(e) = self.repository.create(&delivery).await {
+                    error!(
+                        "Failed to record {} notification for team {}: {}",
+                        delivery.channel, team_id, e
+                    );
+                    match transport.deliver(&delivery).await {
+                        Ok(()) => delivered += 1,
+                        Err(e) => warn!(
+                            "Failed to deliver {} notification for {}: {}",
+                            delivery.channel, event_type, e
+                        ),
+                    }
+                    continue;
+                }
+                if self.attempt(transport.as_ref(), &mut delivery).await {
+                    delivered += 1;
+                }
+            }
+        }
+        debug!(
+            "Notified team {} of {} through {} delivery(ies)",
+            team_id, event_type, delivered
+        );
+        Ok(delivered)
+    }
+
+    async fn redeliver(&self, delivery: &WebhookEvent) -> Result<()> {
+        let transport = self
+            .transports
+            .iter()
+            .find(|t| t.handles(delivery.channel))
+            .ok_or_else(|| {
+                anyhow!(
+                    "No notification transport for {} deliveries",
+                    delivery.channel
+                )
+            })?;
+        transport.deliver(delivery).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::WebhookStatus;
+    use crate::domain::repositories::task_repository::RepositoryError;
+    use serde_json::json;
+    use std::sync::Mutex;
+
+    /// In-memory delivery log
+    #[derive(Default)]
+    struct MemoryDeliveryLog {
+        events: Mutex<Vec<WebhookEvent>>,
+    }
+
+    #[async_trait]
+    impl WebhookEventRepository for MemoryDeliveryLog {
+        async fn create(&self, event: &WebhookEvent) -> Result<WebhookEvent, RepositoryError> {
+            self.events.lock().unwrap().push(event.clone());
+            Ok(event.clone())
+        }
+
+        async fn find_by_id(&self, id: Uuid) -> Result<Option<WebhookEvent>, RepositoryError> {
+            Ok(self
+                .events
+                .lock()
+                .unwrap()
+                .iter()
+                .find(|e| e.id == id)
+                .cloned())
+        }
+
+        async fn find_pending(&self, _limit: u64) -> Result<Vec<WebhookEvent>, RepositoryError> {
+            Ok(vec![])
+        }
+
+        async fn find_by_team_id_paginated(
+            &self,
+            _team_id: Uuid,
+            _limit: u32,
+            _offset: u32,
+        ) -> Result<Vec<WebhookEvent>, RepositoryError> {
+            Ok(vec![])
+        }
+
+        async fn count_by_team_id(&self, _team_id: Uuid) -> Result<u64, RepositoryError> {
+            Ok(0)
+        }
+
+        async fn update(&self, event: &WebhookEvent) -> Result<WebhookEvent, RepositoryError> {
+            let mut events = self.events.lock().unwrap();
+            if let Some(stored) = events.iter_mut().find(|e| e.id == event.id) {
+                *stored = event.clone();
+            }
+            Ok(event.clone())
+        }
+    }
+
+    /// Routes one delivery per target and fails every delivery when `fail` is set
+    struct FakeTransport {
+        channel: DeliveryChannel,
+        targets: usize,
+        fail: bool,
+    }
+
+    #[async_trait]
+    impl NotificationTransport for FakeTransport {
+        fn handles(&self, channel: DeliveryChannel) -> bool {
+            channel == self.channel
+        }
+
+        async fn route(
+            &self,
+            team_id: Uuid,
+            event_type: &WebhookEventType,
+            payload: &Value,
+        ) -> Result<Vec<WebhookEvent>> {
+            Ok((0..self.targets)
+                .map(|i| {
+                    WebhookEvent::new(
+                        Uuid::new_v4(),
+                        team_id,
+                        Uuid::new_v4(),
+                        event_type.clone(),
+                        payload.clone(),
+                        format!("https://hooks.example.com/{}", i),
+                    )
+                    .with_channel(self.channel)
+                })
+                .collect())
+        }
+
+        async fn deliver(&self, _delivery: &WebhookEvent) -> Result<()> {
+            if self.fail {
+                Err(anyhow!("channel unavailable"))
+            } else {
+                Ok(())
+            }
+        }
+    }
+
+    #[test]
+    fn test_describe_known_and_custom_events() {
+        let (title, summary) = describe_event(
+            &WebhookEventType::MonitorChanged,
+            &json!({"url": "https://example.com", "added_lines": 2}),
+        );
+        assert_eq!(title, "Monitored page changed: https://example.com");
+        assert!(summary.contains("2 lines added, unknown lines removed"));
+
+        let (title, _) = describe_event(
+            &WebhookEventType::Custom("page.scraped".to_string()),
+            &json!({}),
+        );
+        assert_eq!(title, "Event page.scraped");
+    }
+
+    #[tokio::test]
+    async fn test_notify_records_deliveries_and_schedules_retries() {
+        let log = Arc::new(MemoryDeliveryLog::default());
+        let service = NotificationService::new(log.clone())
+            .register(Arc::new(FakeTransport {
+                channel: DeliveryChannel::Slack,
+                targets: 2,
+                fail: false,
+            }))
+            .register(Arc::new(FakeTransport {
+                channel: DeliveryChannel::Email,
+                targets: 1,
+                fail: true,
+            }));
+
+        let delivered = service
+            .notify(Uuid::new_v4(), &WebhookEventType::CreditsLow, &json!({}))
+            .await
+            .unwrap();
+
+        assert_eq!(delivered, 2);
+        let events = log.events.lock().unwrap();
+        assert_eq!(events.len(), 3);
+        for event in events.iter() {
+            assert_eq!(event.attempt_count, 1);
+            assert_eq!(event.event_type, WebhookEventType::CreditsLow);
+        }
+        let failed: Vec<_> = events
+            .iter()
+            .filter(|e| e.status == WebhookStatus::Failed)
+            .collect();
+        assert_eq!(failed.len(), 1);
+        assert_eq!(failed[0].channel, DeliveryChannel::Email);
+        assert!(failed[0].next_retry_at.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_redeliver_uses_transport_of_the_channel() {
+        let service = NotificationService::new(Arc::new(MemoryDeliveryLog::default())).register(
+            Arc::new(FakeTransport {
+                channel: DeliveryChannel::Json,
+                targets: 1,
+                fail: false,
+            }),
+        );
+        let delivery = WebhookEvent::new(
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            WebhookEventType::MonitorChanged,
+            json!({}),
+            "https://hooks.example.com/0".to_string(),
+        );
+
+        assert!(service.redeliver(&delivery).await.is_err());
+        assert!(service
+            .redeliver(&delivery.with_channel(DeliveryChannel::Json))
+            .await
+            .is_ok());
+    }
+}
@@ -0,0 +1,594 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! OIDC 单点登录服务
+//!
+//! 授权码 + PKCE 流程：`begin_login` 生成 state、code_verifier 与 nonce 并返回 IdP 授权地址，
+//! `complete_login` 在回调中消费 state、用授权码与 code_verifier 换取 ID Token，校验后为
+//! 用户所在团队签发会话令牌。会话令牌以带过期时间的 API Key 存储，角色固定为 member。
+//!
+//! ID Token 直接通过 TLS 从 token 端点获取，按 OIDC Core 3.1.3.7 以 TLS 服务端校验代替签名校验，
+//! 仍校验 `iss`、`aud`、`exp` 与 `nonce`。
+
+use std::sync::Arc;
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use chrono::{DateTime, Duration, Utc};
+use rand::RngExt;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use tokio::sync::OnceCell;
+use uuid::Uuid;
+
+use crate::config::OidcSettings;
+use crate::domain::auth::{ApiKeyRole, ApiKeyScope};
+use crate::domain::models::{
+    normalize_email, OidcLoginState, SsoSession, TeamMember, TeamMemberStatus,
+};
+use crate::domain::repositories::sso_session_repository::SsoSessionRepository;
+use crate::domain::repositories::task_repository::RepositoryError;
+use crate::domain::repositories::team_member_repository::TeamMemberRepository;
+use crate::presentation::middleware::auth_middleware::invalidate_cache_by_api_key_id;
+
+/// 会话令牌前缀，便于在日志与密钥扫描中识别
+const SESSION_TOKEN_PREFIX: &str = "sso_";
+
+/// OIDC 登录错误
+#[derive(Debug, thiserror::Error)]
+pub enum OidcError {
+    /// state 不存在、已使用或已过期
+    #[error("Invalid or expired login state")]
+    InvalidState,
+    /// IdP 请求失败或返回了无效响应
+    #[error("Identity provider error: {0}")]
+    Provider(String),
+    /// ID Token 无法解析或校验失败
+    #[error("Invalid ID token: {0}")]
+    InvalidIdToken(String),
+    /// IdP 未验证用户邮箱
+    #[error("Email address is not verified by the identity provider")]
+    EmailNotVerified,
+    /// 用户不是目标团队的 active 成员
+    #[error("User is not an active member of the team")]
+    NotMember,
+    /// 用户属于多个团队，需要在登录时指定 team_id
+    #[error("User belongs to several teams, team_id is required")]
+    TeamRequired,
+    /// 仓库错误
+    #[error(transparent)]
+    Repository(#[from] RepositoryError),
+}
+
+/// 登录成功后签发的会话令牌
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SsoSessionToken {
+    /// 会话令牌明文，只在签发时返回一次，以 `Authorization: Bearer` 使用
+    pub token: String,
+    /// 令牌所属团队
+    pub team_id: Uuid,
+    /// 登录用户
+    pub user_id: Uuid,
+    /// 登录用户邮箱
+    pub email: String,
+    /// 令牌的角色
+    pub role: ApiKeyRole,
+    /// 过期时间
+    pub expires_at: DateTime<Utc>,
+}
+
+/// IdP 发现文档中用到的字段
+#[derive(Debug, Clone, Deserialize)]
+struct ProviderMetadata {
+    issuer: String,
+    authorization_endpoint: String,
+    token_endpoint: String,
+}
+
+/// token 端点响应中用到的字段
+#[derive(Debug, Deserialize)]
+struct TokenResponse {
+    id_token: String,
+}
+
+/// `aud` 可以是单个字符串或数组
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum Audience {
+    One(String),
+    Many(Vec<String>),
+}
+
+impl Audience {
+    fn contains(&self, client_id: &str) -> bool {
+        match self {
+            Audience::One(aud) => aud == client_id,
+            Audience::Many(auds) => auds.iter().any(|aud| aud == client_id),
+        }
+    }
+}
+
+/// ID Token 中用到的声明
+#[derive(Debug, Deserialize)]
+struct IdTokenClaims {
+    iss: String,
+    aud: Audience,
+    exp: i64,
+    nonce: Option<String>,
+    email: Option<String>,
+    email_verified: Option<bool>,
+}
+
+/// OIDC 单点登录服务
+pub struct OidcService {
+    settings: OidcSettings,
+    client: Arc<reqwest::Client>,
+    sessions: Arc<dyn SsoSessionRepository>,
+    members: Arc<dyn TeamMemberRepository>,
+    metadata: OnceCell<ProviderMetadata>,
+}
+
+impl OidcService {
+    pub fn new(
+        settings: OidcSettings,
+        client: Arc<reqwest::Client>,
+        sessions: Arc<dyn SsoSessionRepository>,
+        members: Arc<dyn TeamMemberRepository>,
+    ) -> Self {
+        Self {
+            settings,
+            client,
+            sessions,
+            members,
+            metadata: OnceCell::new(),
+        }
+    }
+
+    /// 发起登录，返回 IdP 授权地址；`team_id` 为登录后会话所属的团队
+    pub async fn begin_login(&self, team_id: Option<Uuid>) -> Result<String, OidcError> {
+        let metadata = self.metadata().await?;
+        let login = OidcLoginState {
+            state: generate_secret(),
+            code_verifier: generate_secret(),
+            nonce: generate_secret(),
+            team_id,
+            created_at: Utc::now(),
+        };
+        self.sessions.save_login_state(&login).await?;
+
+        let scope = self.settings.scopes.join(" ");
+        let url = url::Url::parse_with_params(
+            &metadata.authorization_endpoint,
+            [
+                ("response_type", "code"),
+                ("client_id", self.settings.client_id.as_str()),
+                ("redirect_uri", self.settings.redirect_uri.as_str()),
+                ("scope", scope.as_str()),
+                ("state", login.state.as_str()),
+                ("nonce", login.nonce.as_str()),
+                (
+                    "code_challenge",
+                    pkce_challenge(&login.code_verifier).as_str(),
+                ),
+                ("code_challenge_method", "S256"),
+            ],
+        )
+        .map_err(|e| OidcError::Provider(format!("invalid authorization endpoint: {}", e)))?;
+
+        Ok(url.into())
+    }
+
+    /// 完成登录：校验 state 与 ID Token，为用户所在团队签发会话令牌
+    pub async fn complete_login(
+        &self,
+        code: &str,
+        state: &str,
+    ) -> Result<SsoSessionToken, OidcError> {
+        let issued_after =
+            Utc::now() - Duration::seconds(self.settings.login_timeout_seconds as i64);
+        let login = self
+            .sessions
+            .take_login_state(state, issued_after)
+            .await?
+            .ok_or(OidcError::InvalidState)?;
+
+        let id_token = self.exchange_code(code, &login.code_verifier).await?;
+        let claims = decode_id_token(&id_token)?;
+        let email = self.verify_claims(&claims, &login.nonce, Utc::now())?;
+        let member = self.resolve_membership(&email, login.team_id).await?;
+
+        let token = format!("{}{}", SESSION_TOKEN_PREFIX, generate_secret());
+        let token_hash = format!("sha256:{}", hex::encode(Sha256::digest(token.as_bytes())));
+        let session = SsoSession {
+            api_key_id: Uuid::new_v4(),
+            team_id: member.team_id,
+            user_id: member.user_id,
+            email: member.email,
+            expires_at: Utc::now() + Duration::seconds(self.settings.session_ttl_seconds as i64),
+        };
+        let role = ApiKeyRole::Member;
+        let scope = ApiKeyScope::default().with_role(role);
+        self.sessions
+            .create_session(&session, &token_hash, &scope)
+            .await?;
+
+        Ok(SsoSessionToken {
+            token,
+            team_id: session.team_id,
+            user_id: session.user_id,
+            email: session.email,
+            role,
+            expires_at: session.expires_at,
+        })
+    }
+
+    /// 注销会话令牌；`api_key_id` 不是会话令牌时返回 `false`
+    pub async fn logout(&self, api_key_id: Uuid) -> Result<bool, OidcError> {
+        let removed = self.sessions.delete_session(api_key_id).await?;
+        if removed {
+            invalidate_cache_by_api_key_id(api_key_id).await;
+        }
+        Ok(removed)
+    }
+
+    /// 获取 IdP 发现文档，成功后缓存
+    async fn metadata(&self) -> Result<&ProviderMetadata, OidcError> {
+        self.metadata
+            .get_or_try_init(|| async {
+                let url = format!(
+                    "{}/.well-known/openid-configuration",
+                    self.settings.issuer_url.trim_end_matches('/')
+                );
+                let metadata: ProviderMetadata = self
+                    .client
+                    .get(&url)
+                    .send()
+                    .await
+                    .and_then(|response| response.error_for_status())
+                    .map_err(|e| OidcError::Provider(format!("discovery failed: {}", e)))?
+                    .json()
+                    .await
+                    .map_err(|e| {
+                        OidcError::Provider(format!("invalid discovery document: {}", e))
+                    })?;
+                if !same_issuer(&metadata.issuer, &self.settings.issuer_url) {
+                    return Err(OidcError::Provider(format!(
+                        "discovery issuer {} does not match the configured issuer",
+                        metadata.issuer
+                    )));
+                }
+                Ok(metadata)
+            })
+            .await
+    }
+
+    /// 用授权码与 code_verifier 换取 ID Token
+    async fn exchange_code(&self, code: &str, code_verifier: &str) -> Result<String, OidcError> {
+        let metadata = self.metadata().await?;
+        let body = {
+            let mut form = url::form_urlencoded::Serializer::new(String::new());
+            form.append_pair("grant_type", "authorization_code")
+                .append_pair("code", code)
+                .append_pair("redirect_uri", &self.settings.redirect_uri)
+                .append_pair("client_id", &self.settings.client_id)
+                .append_pair("code_verifier", code_verifier);
+            if let Some(secret) = self.settings.client_secret() {
+                form.append_pair("client_secret", secret);
+            }
+            form.finish()
+        };
+
+        let response: TokenResponse = self
+            .client
+            .post(&metadata.token_endpoint)
+            .header(
+                reqwest::header::CONTENT_TYPE,
+                "application/x-www-form-urlencoded",
+            )
+            .body(body)
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|e| OidcError::Provider(format!("token exchange failed: {}", e)))?
+            .json()
+            .await
+            .map_err(|e| OidcError::Provider(format!("invalid token response: {}", e)))?;
+
+        Ok(response.id_token)
+    }
+
+    /// 校验 ID Token 声明，返回规范化后的邮箱
+    fn verify_claims(
+        &self,
+        claims: &IdTokenClaims,
+        expected_nonce: &str,
+        now: DateTime<Utc>,
+    ) -> Result<String, OidcError> {
+        if !same_issuer(&claims.iss, &self.settings.issuer_url) {
+            return Err(OidcError::InvalidIdToken("unexpected issuer".to_string()));
+        }
+        if !claims.aud.contains(&self.settings.client_id) {
+            return Err(OidcError::InvalidIdToken("unexpected audience".to_string()));
+        }
+        if claims.exp <= now.timestamp() {
+            return Err(OidcError::InvalidIdToken("token expired".to_string()));
+        }
+        if claims.nonce.as_deref() != Some(expected_nonce) {
+            return Err(OidcError::InvalidIdToken("nonce mismatch".to_string()));
+        }
+        if claims.email_verified == Some(false) {
+            return Err(OidcError::EmailNotVerified);
+        }
+        claims
+            .email
+            .as_deref()
+            .and_then(normalize_email)
+            .ok_or_else(|| OidcError::InvalidIdToken("missing email claim".to_string()))
+    }
+
+    /// 确定会话所属团队：指定了团队时须是其 active 成员，否则用户须只属于一个团队
+    async fn resolve_membership(
+        &self,
+        email: &str,
+        team_id: Option<Uuid>,
+    ) -> Result<TeamMember, OidcError> {
+        let mut memberships: Vec<TeamMember> = self
+            .members
+            .list_memberships_by_email(email)
+            .await?
+            .into_iter()
+            .filter(|member| member.status == TeamMemberStatus::Active)
+            .collect();
+
+        match team_id {
+            Some(team_id) => memberships
+                .into_iter()
+                .find(|member| member.team_id == team_id)
+                .ok_or(OidcError::NotMember),
+            None if memberships.len() > 1 => Err(OidcError::TeamRequired),
+            None => memberships.pop().ok_or(OidcError::NotMember),
+        }
+    }
+}
+
+/// 生成 256 位随机值（十六进制），用作 state、nonce、code_verifier 与会话令牌
+fn generate_secret() -> String {
+    let bytes: [u8; 32] = rand::rng().random();
+    hex::encode(bytes)
+}
+
+/// PKCE S256 code_challenge
+fn pkce_challenge(code_verifier: &str) -> String {
+    URL_SAFE_NO_PAD.encode(Sha256::digest(code_verifier.as_bytes()))
+}
+
+/// 比较 issuer，忽略结尾的 `/`
+fn same_issuer(a: &str, b: &str) -> bool {
+    a.trim_end_matches('/') == b.trim_end_matches('/')
+}
+
+/// 解析 ID Token 的声明部分
+fn decode_id_token(id_token: &str) -> Result<IdTokenClaims, OidcError> {
+    let payload = id_token
+        .split('.')
+        .nth(1)
+        .ok_or_else(|| OidcError::InvalidIdToken("malformed token".to_string()))?;
+    let bytes = URL_SAFE_NO_PAD
+        .decode(payload.trim_end_matches('='))
+        .map_err(|e| OidcError::InvalidIdToken(e.to_string()))?;
+    serde_json::from_slice(&bytes).map_err(|e| OidcError::InvalidIdToken(e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::{TeamRole, User};
+    use async_trait::async_trait;
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct InMemorySessions {
+        logins: Mutex<Vec<OidcLoginState>>,
+    }
+
+    #[async_trait]
+    impl SsoSessionRepository for InMemorySessions {
+        async fn save_login_state(&self, login: &OidcLoginState) -> Result<(), RepositoryError> {
+            self.logins.lock().unwrap().push(login.clone());
+            Ok(())
+        }
+
+        async fn take_login_state(
+            &self,
+            state: &str,
+            issued_after: DateTime<Utc>,
+        ) -> Result<Option<OidcLoginState>, RepositoryError> {
+            let mut logins = self.logins.lock().unwrap();
+            let index = logins.iter().position(|login| login.state == state);
+            Ok(index
+                .map(|index| logins.remove(index))
+                .filter(|login| login.created_at >= issued_after))
+        }
+
+        async fn create_session(
+            &self,
+            _session: &SsoSession,
+            _token_hash: &str,
+            _scope: &ApiKeyScope,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn delete_session(&self, _api_key_id: Uuid) -> Result<bool, RepositoryError> {
+            Ok(false)
+        }
+    }
+
+    struct FixedMembers(Vec<TeamMember>);
+
+    #[async_trait]
+    impl TeamMemberRepository for FixedMembers {
+        async fn find_or_create_user(&self, _email: &str) -> Result<User, RepositoryError> {
+            Err(RepositoryError::NotFound)
+        }
+
+        async fn add_member(
+            &self,
+            _member: &TeamMember,
+            _invitation_token_hash: Option<&str>,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn find_member(
+            &self,
+            _team_id: Uuid,
+            _user_id: Uuid,
+        ) -> Result<Option<TeamMember>, RepositoryError> {
+            Ok(None)
+        }
+
+        async fn list_members(&self, _team_id: Uuid) -> Result<Vec<TeamMember>, RepositoryError> {
+            Ok(Vec::new())
+        }
+
+        async fn list_memberships_by_email(
+            &self,
+            email: &str,
+        ) -> Result<Vec<TeamMember>, RepositoryError> {
+            Ok(self
+                .0
+                .iter()
+                .filter(|m| m.email == email)
+                .cloned()
+                .collect())
+        }
+
+        async fn remove_member(
+            &self,
+            _team_id: Uuid,
+            _user_id: Uuid,
+        ) -> Result<bool, RepositoryError> {
+            Ok(false)
+        }
+
+        async fn accept_invitation(
+            &self,
+            _invitation_token_hash: &str,
+        ) -> Result<Option<TeamMember>, RepositoryError> {
+            Ok(None)
+        }
+    }
+
+    fn service(members: Vec<TeamMember>) -> OidcService {
+        let settings = OidcSettings {
+            issuer_url: "https://login.example.com/".to_string(),
+            client_id: "crawlrs".to_string(),
+            ..OidcSettings::default()
+        };
+        OidcService::new(
+            settings,
+            Arc::new(reqwest::Client::new()),
+            Arc::new(InMemorySessions::default()),
+            Arc::new(FixedMembers(members)),
+        )
+    }
+
+    fn claims(nonce: &str) -> IdTokenClaims {
+        IdTokenClaims {
+            iss: "https://login.example.com".to_string(),
+            aud: Audience::Many(vec!["other".to_string(), "crawlrs".to_string()]),
+            exp: Utc::now().timestamp() + 300,
+            nonce: Some(nonce.to_string()),
+            email: Some("Alice@Example.com".to_string()),
+            email_verified: Some(true),
+        }
+    }
+
+    #[test]
+    fn test_pkce_challenge_matches_rfc7636_example() {
+        assert_eq!(
+            pkce_challenge("dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk"),
+            "E9Melhoa2OwvFrEMTJguCHaoeK1t8URWbuGJSstw-cM"
+        );
+    }
+
+    #[test]
+    fn test_verify_claims() {
+        let service = service(Vec::new());
+        let now = Utc::now();
+        assert_eq!(
+            service.verify_claims(&claims("n1"), "n1", now).unwrap(),
+            "alice@example.com"
+        );
+        assert!(matches!(
+            service.verify_claims(&claims("n1"), "n2", now),
+            Err(OidcError::InvalidIdToken(_))
+        ));
+
+        let mut wrong_audience = claims("n1");
+        wrong_audience.aud = Audience::One("other".to_string());
+        assert!(service.verify_claims(&wrong_audience, "n1", now).is_err());
+
+        let mut expired = claims("n1");
+        expired.exp = now.timestamp() - 1;
+        assert!(service.verify_claims(&expired, "n1", now).is_err());
+
+        let mut unverified = claims("n1");
+        unverified.email_verified = Some(false);
+        assert!(matches!(
+            service.verify_claims(&unverified, "n1", now),
+            Err(OidcError::EmailNotVerified)
+        ));
+    }
+
+    #[test]
+    fn test_decode_id_token_payload() {
+        let payload = URL_SAFE_NO_PAD.encode(
+            r#"{"iss":"https://login.example.com","aud":"crawlrs","exp":1,"email":"a@b.co"}"#,
+        );
+        let claims = decode_id_token(&format!("eyJhbGciOiJSUzI1NiJ9.{}.sig", payload)).unwrap();
+        assert!(claims.aud.contains("crawlrs"));
+        assert_eq!(claims.email.as_deref(), Some("a@b.co"));
+        assert!(decode_id_token("not-a-jwt").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_resolve_membership_picks_the_active_team() {
+        let team_a = Uuid::new_v4();
+        let team_b = Uuid::new_v4();
+        let email = "alice@example.com";
+        let user = User {
+            id: Uuid::new_v4(),
+            email: email.to_string(),
+            name: None,
+            created_at: Utc::now(),
+        };
+        let service = service(vec![
+            TeamMember::active(team_a, &user, TeamRole::Owner),
+            TeamMember::invited(team_b, &user, TeamRole::Member),
+        ]);
+
+        let member = service.resolve_membership(email, None).await.unwrap();
+        assert_eq!(member.team_id, team_a);
+        assert!(matches!(
+            service.resolve_membership(email, Some(team_b)).await,
+            Err(OidcError::NotMember)
+        ));
+        assert!(matches!(
+            service.resolve_membership("bob@example.com", None).await,
+            Err(OidcError::NotMember)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_complete_login_rejects_unknown_state() {
+        let service = service(Vec::new());
+        assert!(matches!(
+            service.complete_login("code", "unknown").await,
+            Err(OidcError::InvalidState)
+        ));
+    }
+}
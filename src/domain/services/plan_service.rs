@@ -0,0 +1,183 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! 团队套餐服务
+//!
+//! 从 `[plans]` 配置加载各套餐的限制，并查询团队当前的套餐。限流服务、抓取 worker 与
+//! 爬取接口通过它取得团队生效的每分钟请求数、并发数与爬取深度上限。团队套餐查询结果
+//! 在内存中缓存一小段时间，避免每个请求都访问数据库。
+
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use dashmap::DashMap;
+use log::warn;
+use uuid::Uuid;
+
+use crate::config::settings::{PlanLimitSettings, PlanSettings};
+use crate::domain::models::{PlanLimits, TeamPlan};
+use crate::domain::repositories::task_repository::RepositoryError;
+use crate::domain::repositories::team_plan_repository::TeamPlanRepository;
+
+/// 团队套餐缓存时间
+const PLAN_CACHE_TTL: Duration = Duration::from_secs(60);
+
+/// 团队套餐服务
+pub struct PlanService {
+    /// 团队套餐仓库
+    repository: Arc<dyn TeamPlanRepository>,
+    /// 免费套餐限制
+    free: PlanLimits,
+    /// 专业套餐限制
+    pro: PlanLimits,
+    /// 企业套餐限制
+    enterprise: PlanLimits,
+    /// 团队套餐缓存：团队 ID -> (套餐, 查询时间)
+    cache: DashMap<Uuid, (TeamPlan, Instant)>,
+}
+
+impl PlanService {
+    /// 从配置创建套餐服务
+    pub fn from_settings(repository: Arc<dyn TeamPlanRepository>, settings: &PlanSettings) -> Self {
+        Self {
+            repository,
+            free: to_limits(&settings.free),
+            pro: to_limits(&settings.pro),
+            enterprise: to_limits(&settings.enterprise),
+            cache: DashMap::new(),
+        }
+    }
+
+    /// 套餐的限制
+    pub fn limits(&self, plan: TeamPlan) -> PlanLimits {
+        match plan {
+            TeamPlan::Free => self.free,
+            TeamPlan::Pro => self.pro,
+            TeamPlan::Enterprise => self.enterprise,
+        }
+    }
+
+    /// 团队当前的套餐
+    ///
+    /// 团队不存在或查询失败时按免费套餐处理（查询失败会记录警告且不缓存）。
+    pub async fn plan_for(&self, team_id: Uuid) -> TeamPlan {
+        if let Some(entry) = self.cache.get(&team_id) {
+            let (plan, fetched_at) = *entry;
+            if fetched_at.elapsed() < PLAN_CACHE_TTL {
+                return plan;
+            }
+        }
+
+        let plan = match self.repository.get_plan(team_id).await {
+            Ok(assignment) => assignment.plan,
+            Err(RepositoryError::NotFound) => TeamPlan::Free,
+            Err(e) => {
+                warn!("Failed to load plan for team {}: {}", team_id, e);
+                return TeamPlan::Free;
+            }
+        };
+        self.cache.insert(team_id, (plan, Instant::now()));
+        plan
+    }
+
+    /// 团队当前套餐的限制
+    pub async fn limits_for(&self, team_id: Uuid) -> PlanLimits {
+        self.limits(self.plan_for(team_id).await)
+    }
+
+    /// 团队套餐变更后清除缓存，使新的限制立即生效
+    pub fn invalidate(&self, team_id: Uuid) {
+        self.cache.remove(&team_id);
+    }
+}
+
+fn to_limits(settings: &PlanLimitSettings) -> PlanLimits {
+    PlanLimits {
+        monthly_credits: settings.monthly_credits,
+        requests_per_minute: settings.requests_per_minute,
+        max_concurrency: settings.max_concurrency,
+        max_crawl_depth: settings.max_crawl_depth,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::TeamPlanAssignment;
+    use async_trait::async_trait;
+    use chrono::{DateTime, Utc};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    struct FixedPlanRepository {
+        team_id: Uuid,
+        plan: TeamPlan,
+        lookups: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl TeamPlanRepository for FixedPlanRepository {
+        async fn get_plan(&self, team_id: Uuid) -> Result<TeamPlanAssignment, RepositoryError> {
+            self.lookups.fetch_add(1, Ordering::SeqCst);
+            if team_id != self.team_id {
+                return Err(RepositoryError::NotFound);
+            }
+            Ok(TeamPlanAssignment {
+                team_id,
+                plan: self.plan,
+                credits_granted_at: None,
+            })
+        }
+
+        async fn set_plan(
+            &self,
+            _team_id: Uuid,
+            _plan: TeamPlan,
+        ) -> Result<TeamPlanAssignment, RepositoryError> {
+            Err(RepositoryError::NotFound)
+        }
+
+        async fn find_due_for_credit_grant(
+            &self,
+            _period_start: DateTime<Utc>,
+            _limit: u64,
+        ) -> Result<Vec<TeamPlanAssignment>, RepositoryError> {
+            Ok(Vec::new())
+        }
+
+        async fn claim_credit_grant(
+            &self,
+            _team_id: Uuid,
+            _period_start: DateTime<Utc>,
+            _granted_at: DateTime<Utc>,
+        ) -> Result<bool, RepositoryError> {
+            Ok(false)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_limits_for_uses_team_plan_and_caches_lookups() {
+        let team_id = Uuid::new_v4();
+        let repository = Arc::new(FixedPlanRepository {
+            team_id,
+            plan: TeamPlan::Pro,
+            lookups: AtomicUsize::new(0),
+        });
+        let settings = PlanSettings::default();
+        let service = PlanService::from_settings(repository.clone(), &settings);
+
+        let limits = service.limits_for(team_id).await;
+        assert_eq!(limits.requests_per_minute, settings.pro.requests_per_minute);
+        assert_eq!(limits.max_concurrency, settings.pro.max_concurrency);
+        service.limits_for(team_id).await;
+        assert_eq!(repository.lookups.load(Ordering::SeqCst), 1);
+
+        service.invalidate(team_id);
+        service.plan_for(team_id).await;
+        assert_eq!(repository.lookups.load(Ordering::SeqCst), 2);
+
+        assert_eq!(service.plan_for(Uuid::new_v4()).await, TeamPlan::Free);
+        assert_eq!(service.limits(TeamPlan::Free).monthly_credits, 500);
+    }
+}
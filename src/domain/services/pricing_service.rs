@@ -0,0 +1,175 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! 积分价格服务
+//!
+//! 从 `[pricing]` 配置加载全局价格表与按团队的覆盖价格，扣费（提交请求、worker 附加费用）
+//! 与费用预估都通过它取得团队生效的价格表，运营方调整价格无需改动代码。
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use log::warn;
+use uuid::Uuid;
+
+use crate::config::reload::{DerivedSettings, ReloadableSettings};
+use crate::config::settings::{PricingSettings, TeamPricingOverrideSettings};
+use crate::domain::services::credit_pricing::PricingTable;
+
+/// 积分价格服务
+#[derive(Debug, Clone, Default)]
+pub struct PricingService {
+    /// 全局价格表
+    default_pricing: PricingTable,
+    /// 按团队覆盖后的价格表
+    team_pricing: HashMap<Uuid, PricingTable>,
+    /// 随配置热重载更新的价格表，设置后优先于上面的价格表
+    reloadable: Option<Arc<DerivedSettings<PricingService>>>,
+}
+
+impl PricingService {
+    /// 使用全局价格表创建价格服务
+    pub fn new(default_pricing: PricingTable) -> Self {
+        Self {
+            default_pricing,
+            team_pricing: HashMap::new(),
+            reloadable: None,
+        }
+    }
+
+    /// 从可热重载的配置加载价格表，`[pricing]` 重载后下一次扣费即使用新价格
+    pub fn from_reloadable_settings(settings: Arc<ReloadableSettings>) -> Self {
+        Self {
+            reloadable: Some(Arc::new(DerivedSettings::new(settings, |settings| {
+                Self::from_settings(&settings.pricing)
+            }))),
+            ..Self::default()
+        }
+    }
+
+    /// 从配置加载价格表
+    ///
+    /// 团队 ID 无法解析的覆盖项会被忽略并记录警告。
+    pub fn from_settings(settings: &PricingSettings) -> Self {
+        let default_pricing = PricingTable {
+            scrape: settings.scrape,
+            crawl: settings.crawl,
+            screenshot: settings.screenshot,
+            proxy: settings.proxy,
+            credits_per_1000_tokens: settings.credits_per_1000_tokens,
+        };
+
+        let mut service = Self::new(default_pricing);
+        for team_override in &settings.team_overrides {
+            match Uuid::parse_str(&team_override.team_id) {
+                Ok(team_id) => {
+                    let pricing = apply_override(default_pricing, team_override);
+                    service = service.with_team_pricing(team_id, pricing);
+                }
+                Err(e) => warn!(
+                    "Ignoring pricing override for invalid team id {}: {}",
+                    team_override.team_id, e
+                ),
+            }
+        }
+        service
+    }
+
+    /// 为团队设置单独的价格表
+    pub fn with_team_pricing(mut self, team_id: Uuid, pricing: PricingTable) -> Self {
+        self.team_pricing.insert(team_id, pricing);
+        self
+    }
+
+    /// 团队生效的价格表：有覆盖时使用覆盖后的价格，否则使用全局价格
+    pub fn pricing_for(&self, team_id: Uuid) -> PricingTable {
+        if let Some(reloadable) = &self.reloadable {
+            return reloadable.get().pricing_for(team_id);
+        }
+        self.team_pricing
+            .get(&team_id)
+            .copied()
+            .unwrap_or(self.default_pricing)
+    }
+}
+
+/// 在全局价格表上应用团队的覆盖项
+fn apply_override(base: PricingTable, team_override: &TeamPricingOverrideSettings) -> PricingTable {
+    PricingTable {
+        scrape: team_override.scrape.unwrap_or(base.scrape),
+        crawl: team_override.crawl.unwrap_or(base.crawl),
+        screenshot: team_override.screenshot.unwrap_or(base.screenshot),
+        proxy: team_override.proxy.unwrap_or(base.proxy),
+        credits_per_1000_tokens: team_override
+            .credits_per_1000_tokens
+            .unwrap_or(base.credits_per_1000_tokens),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_team_overrides_fall_back_to_global_prices() {
+        let team_id = Uuid::new_v4();
+        let settings = PricingSettings {
+            screenshot: 3,
+            team_overrides: vec![
+                TeamPricingOverrideSettings {
+                    team_id: team_id.to_string(),
+                    scrape: Some(0),
+                    ..Default::default()
+                },
+                TeamPricingOverrideSettings {
+                    team_id: "not-a-uuid".to_string(),
+                    scrape: Some(100),
+                    ..Default::default()
+                },
+            ],
+            ..PricingSettings::default()
+        };
+        let service = PricingService::from_settings(&settings);
+
+        let global = service.pricing_for(Uuid::new_v4());
+        assert_eq!(global.scrape, 1);
+        assert_eq!(global.screenshot, 3);
+
+        let team = service.pricing_for(team_id);
+        assert_eq!(team.scrape, 0);
+        assert_eq!(team.screenshot, 3);
+        assert_eq!(team.crawl, global.crawl);
+    }
+
+    #[test]
+    fn test_default_service_uses_builtin_prices() {
+        assert_eq!(
+            PricingService::default().pricing_for(Uuid::new_v4()),
+            PricingTable::default()
+        );
+        assert_eq!(
+            PricingService::from_settings(&PricingSettings::default()).pricing_for(Uuid::nil()),
+            PricingTable::default()
+        );
+    }
+
+    #[test]
+    fn test_reloadable_service_follows_reloaded_prices() {
+        let mut settings = crate::bootstrap::config::load_settings().unwrap();
+        let reloadable = Arc::new(ReloadableSettings::new(settings.clone()));
+        let service = PricingService::from_reloadable_settings(reloadable.clone());
+        assert_eq!(
+            service.pricing_for(Uuid::new_v4()).scrape,
+            settings.pricing.scrape
+        );
+
+        settings.pricing.scrape += 2;
+        reloadable.apply(settings.clone());
+        assert_eq!(
+            service.pricing_for(Uuid::new_v4()).scrape,
+            settings.pricing.scrape
+        );
+    }
+}
@@ -20,6 +20,26 @@ pub enum RateLimitStrategy {
     SlidingWindow,
 }
 
+impl std::str::FromStr for RateLimitStrategy {
+    type Err = String;
+
+    /// 解析配置中的策略名称（如 `token_bucket`、`sliding_window`）
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s
+            .trim()
+            .to_ascii_lowercase()
+            .replace(['-', '_'], "")
+            .as_str()
+        {
+            "tokenbucket" => Ok(RateLimitStrategy::TokenBucket),
+            "leakybucket" => Ok(RateLimitStrategy::LeakyBucket),
+            "fixedwindow" => Ok(RateLimitStrategy::FixedWindow),
+            "slidingwindow" | "slidingwindowlog" => Ok(RateLimitStrategy::SlidingWindow),
+            other => Err(format!("unknown rate limit strategy: {}", other)),
+        }
+    }
+}
+
 /// 并发控制策略
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ConcurrencyStrategy {
@@ -38,11 +58,11 @@ pub struct RateLimitConfig {
     pub strategy: RateLimitStrategy,
     /// 每秒允许的请求数
     pub requests_per_second: u32,
-    /// 每分钟允许的请求数
+    /// 每分钟允许的请求数（令牌桶的持续速率）
     pub requests_per_minute: u32,
     /// 每小时允许的请求数
     pub requests_per_hour: u32,
-    /// 令牌桶容量（如果使用令牌桶算法）
+    /// 令牌桶容量，即允许的突发请求数；未设置时等于每分钟请求数
     pub bucket_capacity: Option<u32>,
     /// 是否启用限流
     pub enabled: bool,
@@ -86,6 +106,13 @@ impl RateLimitConfig {
         }
         Ok(())
     }
+
+    /// 突发容量：令牌桶满时可连续放行的请求数
+    pub fn burst_capacity(&self) -> u32 {
+        self.bucket_capacity
+            .unwrap_or(self.requests_per_minute)
+            .max(1)
+    }
 }
 
 impl Default for RateLimitConfig {
@@ -101,6 +128,77 @@ impl Default for RateLimitConfig {
     }
 }
 
+/// 端点级限流规则
+///
+/// 为特定路由（可选限定 HTTP 方法）单独配置每分钟请求数，
+/// 按 API Key + 路由维度计数，不与全局限流共享额度。
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EndpointRateLimit {
+    /// 路由路径，以 `*` 结尾时按前缀匹配（如 `/v1/crawl/*`）
+    pub path: String,
+    /// HTTP 方法（如 `POST`），为空时匹配所有方法
+    #[serde(default)]
+    pub method: Option<String>,
+    /// 每分钟允许的请求数
+    pub requests_per_minute: u32,
+}
+
+impl EndpointRateLimit {
+    /// 创建不限定方法的端点规则
+    pub fn new(path: impl Into<String>, requests_per_minute: u32) -> Self {
+        Self {
+            path: path.into(),
+            method: None,
+            requests_per_minute,
+        }
+    }
+
+    /// 限定 HTTP 方法
+    pub fn with_method(mut self, method: impl Into<String>) -> Self {
+        self.method = Some(method.into());
+        self
+    }
+
+    /// 判断规则是否匹配请求
+    ///
+    /// 限定了方法的规则只在调用方提供了方法时才会命中。
+    pub fn matches(&self, method: Option<&str>, path: &str) -> bool {
+        if let Some(rule_method) = &self.method {
+            match method {
+                Some(m) if m.eq_ignore_ascii_case(rule_method) => {}
+                _ => return false,
+            }
+        }
+
+        match self.path.strip_suffix('*') {
+            Some(prefix) => path.starts_with(prefix),
+            None => path == self.path,
+        }
+    }
+
+    /// 规则的具体程度，用于在多条规则同时命中时选择最精确的一条
+    pub fn specificity(&self) -> (bool, bool, usize) {
+        (
+            !self.path.ends_with('*'),
+            self.method.is_some(),
+            self.path.len(),
+        )
+    }
+
+    /// 验证规则的有效性
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        if self.requests_per_minute == 0 {
+            return Err(ValidationError::ZeroRate(
+                "endpoint requests_per_minute cannot be zero",
+            ));
+        }
+        if !self.path.starts_with('/') {
+            return Err(ValidationError::InvalidEndpoint(self.path.clone()));
+        }
+        Ok(())
+    }
+}
+
 /// 并发控制配置
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ConcurrencyConfig {
@@ -194,6 +292,34 @@ pub trait RateLimitService: Send + Sync {
         endpoint: &str,
     ) -> Result<RateLimitResult, RateLimitingError>;
 
+    /// 按 HTTP 方法 + 路由检查 API 限流
+    ///
+    /// 默认忽略方法，直接委托给 [`RateLimitService::check_rate_limit`]；
+    /// 支持按方法配置端点规则的实现应覆盖此方法。
+    async fn check_rate_limit_for_method(
+        &self,
+        api_key: &str,
+        _method: &str,
+        endpoint: &str,
+    ) -> Result<RateLimitResult, RateLimitingError> {
+        self.check_rate_limit(api_key, endpoint).await
+    }
+
+    /// 按团队限流配置检查 API 限流
+    ///
+    /// 默认忽略团队，直接委托给 [`RateLimitService::check_rate_limit_for_method`]；
+    /// 支持团队级持续速率 / 突发容量配置的实现应覆盖此方法。
+    async fn check_team_rate_limit(
+        &self,
+        _team_id: Uuid,
+        api_key: &str,
+        method: &str,
+        endpoint: &str,
+    ) -> Result<RateLimitResult, RateLimitingError> {
+        self.check_rate_limit_for_method(api_key, method, endpoint)
+            .await
+    }
+
     /// 获取团队的限流配置
     async fn get_team_rate_limit_config(
         &self,
@@ -312,6 +438,9 @@ pub enum ValidationError {
 
     #[error("速率配置不一致: {0}")]
     InconsistentRates(String),
+
+    #[error("端点路径无效: {0}")]
+    InvalidEndpoint(String),
 }
 
 #[cfg(test)]
@@ -359,6 +488,23 @@ mod tests {
         assert_eq!(s1, s3);
     }
 
+    #[test]
+    fn test_rate_limit_strategy_from_str() {
+        assert_eq!(
+            "token_bucket".parse::<RateLimitStrategy>(),
+            Ok(RateLimitStrategy::TokenBucket)
+        );
+        assert_eq!(
+            "FixedWindow".parse::<RateLimitStrategy>(),
+            Ok(RateLimitStrategy::FixedWindow)
+        );
+        assert_eq!(
+            "sliding-window-log".parse::<RateLimitStrategy>(),
+            Ok(RateLimitStrategy::SlidingWindow)
+        );
+        assert!("unknown".parse::<RateLimitStrategy>().is_err());
+    }
+
     // ========== ConcurrencyStrategy tests ==========
 
     #[test]
@@ -522,6 +668,19 @@ mod tests {
         assert!(config.validate().is_ok());
     }
 
+    #[test]
+    fn test_rate_limit_config_burst_capacity_defaults_to_requests_per_minute() {
+        let mut config = RateLimitConfig {
+            requests_per_minute: 60,
+            bucket_capacity: Some(20),
+            ..RateLimitConfig::default()
+        };
+        assert_eq!(config.burst_capacity(), 20);
+
+        config.bucket_capacity = None;
+        assert_eq!(config.burst_capacity(), 60);
+    }
+
     #[test]
     fn test_rate_limit_config_validate_zero_bucket_capacity() {
         // Use consistent rates so validation reaches the bucket_capacity check
@@ -552,6 +711,66 @@ mod tests {
         assert_eq!(back.enabled, config.enabled);
     }
 
+    // ========== EndpointRateLimit tests ==========
+
+    #[test]
+    fn test_endpoint_rate_limit_exact_path_match() {
+        let rule = EndpointRateLimit::new("/v1/search", 30);
+        assert!(rule.matches(None, "/v1/search"));
+        assert!(rule.matches(Some("POST"), "/v1/search"));
+        assert!(!rule.matches(None, "/v1/search/extra"));
+        assert!(!rule.matches(None, "/v1/scrape"));
+    }
+
+    #[test]
+    fn test_endpoint_rate_limit_prefix_match() {
+        let rule = EndpointRateLimit::new("/v1/crawl/*", 60);
+        assert!(rule.matches(None, "/v1/crawl/abc"));
+        assert!(rule.matches(None, "/v1/crawl/abc/results"));
+        assert!(!rule.matches(None, "/v1/scrape/abc"));
+    }
+
+    #[test]
+    fn test_endpoint_rate_limit_method_is_case_insensitive() {
+        let rule = EndpointRateLimit::new("/v1/webhooks", 10).with_method("POST");
+        assert!(rule.matches(Some("post"), "/v1/webhooks"));
+        assert!(!rule.matches(Some("GET"), "/v1/webhooks"));
+        assert!(
+            !rule.matches(None, "/v1/webhooks"),
+            "method-scoped rule must not match when method is unknown"
+        );
+    }
+
+    #[test]
+    fn test_endpoint_rate_limit_specificity_prefers_exact_and_method() {
+        let prefix = EndpointRateLimit::new("/v1/*", 100);
+        let exact = EndpointRateLimit::new("/v1/search", 30);
+        let exact_method = EndpointRateLimit::new("/v1/search", 10).with_method("POST");
+        assert!(exact.specificity() > prefix.specificity());
+        assert!(exact_method.specificity() > exact.specificity());
+    }
+
+    #[test]
+    fn test_endpoint_rate_limit_validate() {
+        assert!(EndpointRateLimit::new("/v1/search", 30).validate().is_ok());
+        assert!(matches!(
+            EndpointRateLimit::new("/v1/search", 0).validate(),
+            Err(ValidationError::ZeroRate(_))
+        ));
+        assert!(matches!(
+            EndpointRateLimit::new("v1/search", 30).validate(),
+            Err(ValidationError::InvalidEndpoint(_))
+        ));
+    }
+
+    #[test]
+    fn test_endpoint_rate_limit_deserialize_without_method() {
+        let rule: EndpointRateLimit =
+            serde_json::from_str(r#"{"path":"/v1/scrape","requests_per_minute":300}"#)
+                .expect("deserialize");
+        assert_eq!(rule, EndpointRateLimit::new("/v1/scrape", 300));
+    }
+
     // ========== ConcurrencyConfig::default tests ==========
 
     #[test]
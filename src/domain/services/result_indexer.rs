@@ -0,0 +1,42 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! ResultIndexer - 抽象外部检索索引接口
+//!
+//! 启用 `search_index` 配置后，抓取工作器将每个完成的结果写入外部检索集群
+//! （OpenSearch / Elasticsearch），供外部检索界面查询整个爬取语料；
+//! `backfill_search_index` 工具使用同一接口回填历史结果。
+//! 具体的 HTTP 实现位于基础设施层。
+
+use anyhow::Result;
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::models::ScrapeResult;
+
+/// 待写入索引的结果及其归属
+#[derive(Debug, Clone)]
+pub struct IndexedResult {
+    /// 抓取结果（正文已解压）
+    pub result: ScrapeResult,
+    /// 结果所属团队
+    pub team_id: Uuid,
+    /// 结果所属爬取，单页抓取为 `None`
+    pub crawl_id: Option<Uuid>,
+}
+
+/// 外部检索索引接口
+#[async_trait]
+pub trait ResultIndexer: Send + Sync {
+    /// 确保索引存在，不存在时按字段映射创建
+    async fn ensure_index(&self) -> Result<()>;
+
+    /// 批量写入结果，以结果 ID 作为文档 ID，重复写入会覆盖旧文档
+    ///
+    /// # Returns
+    ///
+    /// 成功写入的文档数；部分文档失败时不返回错误
+    async fn index(&self, results: &[IndexedResult]) -> Result<usize>;
+}
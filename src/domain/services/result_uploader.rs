@@ -0,0 +1,39 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! ResultUploader - 抽象结果投递上传接口
+//!
+//! 爬取指定了结果投递目标（客户自有的 S3 存储桶或预签名前缀）时，
+//! 抓取工作器通过此接口将每个完成的结果上传到目标位置。
+//! 具体的 HTTP 与签名实现位于基础设施层。
+
+use anyhow::Result;
+use async_trait::async_trait;
+
+use crate::domain::models::ResultDestination;
+
+/// 结果投递上传接口
+#[async_trait]
+pub trait ResultUploader: Send + Sync {
+    /// 将对象上传到投递目标
+    ///
+    /// # Arguments
+    ///
+    /// * `destination` - 投递目标
+    /// * `name` - 相对目标的对象名（如 `{crawl_id}/{task_id}.json`）
+    /// * `body` - 对象内容
+    /// * `content_type` - 对象的 Content-Type
+    ///
+    /// # Returns
+    ///
+    /// 成功时返回不含凭证的对象位置
+    async fn upload(
+        &self,
+        destination: &ResultDestination,
+        name: &str,
+        body: Vec<u8>,
+        content_type: &str,
+    ) -> Result<String>;
+}
@@ -1641,6 +1641,17 @@ mod tests {
             timeouts: TimeoutSettings::default(),
             cache: CacheSettings::default(),
             trusted_proxies: TrustedProxySettings::default(),
+            url_blocklist: UrlBlocklistSettings::default(),
+            storage: StorageSettings::default(),
+            credit_alerts: CreditAlertSettings::default(),
+            email: EmailSettings::default(),
+            pricing: PricingSettings::default(),
+            plans: PlanSettings::default(),
+            oidc: OidcSettings::default(),
+            queue: QueueSettings::default(),
+            search_index: SearchIndexSettings::default(),
+            robots: RobotsSettings::default(),
+            bandwidth: BandwidthSettings::default(),
         }
     }
 
@@ -0,0 +1,378 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! 团队数据导出
+//!
+//! 汇总团队的爬取、任务、抓取结果、webhook 配置与积分流水，打包为 zip 归档写入团队的
+//! 对象存储命名空间，用于数据可携带与数据主体访问请求。导出由 `export` 类型的任务异步执行，
+//! 完成后通过 `export.completed` webhook 事件推送下载链接。
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use sha2::{Digest, Sha256};
+use std::io::{Cursor, Write};
+use std::sync::Arc;
+use uuid::Uuid;
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipWriter};
+
+use crate::domain::models::{
+    Crawl, CreditsTransaction, ResultDestination, ScrapeResult, Task, Webhook,
+};
+use crate::domain::repositories::crawl_repository::CrawlRepository;
+use crate::domain::repositories::credits_repository::CreditsRepository;
+use crate::domain::repositories::scrape_result_repository::ScrapeResultRepository;
+use crate::domain::repositories::storage_repository::{team_storage_key, StorageRepository};
+use crate::domain::repositories::task_repository::{TaskQueryParams, TaskRepository};
+use crate::domain::repositories::webhook_repository::WebhookRepository;
+
+/// 归档格式版本，写入 `manifest.json`
+pub const EXPORT_FORMAT_VERSION: u32 = 1;
+
+/// 归档的内容类型
+pub const EXPORT_CONTENT_TYPE: &str = "application/zip";
+
+/// 分页读取爬取与任务时的每页条数
+const PAGE_SIZE: u32 = 500;
+
+/// 导出归档在团队命名空间中的对象名
+pub fn export_object_name(export_id: Uuid) -> String {
+    format!("exports/{}.zip", export_id)
+}
+
+/// 导出的 webhook 配置隐藏自定义请求头的值（通常是接收方的认证令牌）
+fn redact_webhook(webhook: &Webhook) -> Webhook {
+    let mut webhook = webhook.clone();
+    for value in webhook.custom_headers.values_mut() {
+        *value = "[REDACTED]".to_string();
+    }
+    webhook
+}
+
+/// 导出的任务隐藏负载中结果投递目标的凭证（负载保留原始凭证供 worker 上传使用）
+fn redact_task(task: &Task) -> Task {
+    let mut task = task.clone();
+    ResultDestination::redact_payload(&mut task.payload);
+    task
+}
+
+/// 团队的全部可导出数据
+#[derive(Debug, Clone, Default)]
+pub struct TeamExportData {
+    /// 团队 ID
+    pub team_id: Uuid,
+    /// 爬取
+    pub crawls: Vec<Crawl>,
+    /// 任务（抓取、爬取子任务、提取与导出）
+    pub tasks: Vec<Task>,
+    /// 任务的抓取结果
+    pub results: Vec<ScrapeResult>,
+    /// webhook 配置
+    pub webhooks: Vec<Webhook>,
+    /// 积分流水
+    pub transactions: Vec<CreditsTransaction>,
+}
+
+/// 已写入存储的导出归档，保存在导出任务结果的 `meta_data.export`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TeamExport {
+    /// 导出 ID（即导出任务 ID）
+    pub export_id: Uuid,
+    /// 归档下载链接
+    pub download_url: String,
+    /// 归档的存储键
+    pub storage_key: String,
+    /// 归档字节数
+    pub size: usize,
+    /// 归档 SHA-256
+    pub sha256: String,
+    /// 各类数据的条数
+    pub counts: ExportCounts,
+}
+
+/// 归档中各类数据的条数
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExportCounts {
+    /// 爬取数
+    pub crawls: usize,
+    /// 任务数
+    pub tasks: usize,
+    /// 抓取结果数
+    pub results: usize,
+    /// webhook 数
+    pub webhooks: usize,
+    /// 积分流水条数
+    pub transactions: usize,
+}
+
+impl TeamExportData {
+    /// 各类数据的条数
+    pub fn counts(&self) -> ExportCounts {
+        ExportCounts {
+            crawls: self.crawls.len(),
+            tasks: self.tasks.len(),
+            results: self.results.len(),
+            webhooks: self.webhooks.len(),
+            transactions: self.transactions.len(),
+        }
+    }
+}
+
+/// 将导出数据打包为 zip 归档
+///
+/// 归档包含 `manifest.json`、`crawls.json`、`tasks.json`、`results.jsonl`（每行一条结果）、
+/// `webhooks.json` 与 `transactions.json`。
+pub fn build_archive(
+    data: &TeamExportData,
+    export_id: Uuid,
+    generated_at: DateTime<Utc>,
+) -> Result<Vec<u8>> {
+    let manifest = json!({
+        "format_version": EXPORT_FORMAT_VERSION,
+        "export_id": export_id,
+        "team_id": data.team_id,
+        "generated_at": generated_at.to_rfc3339(),
+        "counts": data.counts(),
+    });
+    let tasks: Vec<Task> = data.tasks.iter().map(redact_task).collect();
+    let webhooks: Vec<Webhook> = data.webhooks.iter().map(redact_webhook).collect();
+    let mut results = Vec::new();
+    for result in &data.results {
+        serde_json::to_writer(&mut results, result)?;
+        results.push(b'\n');
+    }
+
+    let files = [
+        ("manifest.json", serde_json::to_vec_pretty(&manifest)?),
+        ("crawls.json", serde_json::to_vec_pretty(&data.crawls)?),
+        ("tasks.json", serde_json::to_vec_pretty(&tasks)?),
+        ("results.jsonl", results),
+        ("webhooks.json", serde_json::to_vec_pretty(&webhooks)?),
+        (
+            "transactions.json",
+            serde_json::to_vec_pretty(&data.transactions)?,
+        ),
+    ];
+
+    let options = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+    let mut archive = ZipWriter::new(Cursor::new(Vec::new()));
+    for (name, bytes) in files {
+        archive.start_file(name, options)?;
+        archive.write_all(&bytes)?;
+    }
+    Ok(archive.finish()?.into_inner())
+}
+
+/// 团队数据导出服务
+pub struct TeamExportService {
+    task_repository: Arc<dyn TaskRepository>,
+    crawl_repository: Arc<dyn CrawlRepository>,
+    result_repository: Arc<dyn ScrapeResultRepository>,
+    webhook_repository: Arc<dyn WebhookRepository>,
+    credits_repository: Arc<dyn CreditsRepository>,
+    storage: Arc<dyn StorageRepository>,
+}
+
+impl TeamExportService {
+    /// 创建导出服务
+    pub fn new(
+        task_repository: Arc<dyn TaskRepository>,
+        crawl_repository: Arc<dyn CrawlRepository>,
+        result_repository: Arc<dyn ScrapeResultRepository>,
+        webhook_repository: Arc<dyn WebhookRepository>,
+        credits_repository: Arc<dyn CreditsRepository>,
+        storage: Arc<dyn StorageRepository>,
+    ) -> Self {
+        Self {
+            task_repository,
+            crawl_repository,
+            result_repository,
+            webhook_repository,
+            credits_repository,
+            storage,
+        }
+    }
+
+    /// 导出团队数据并写入存储，返回归档信息
+    pub async fn export(&self, team_id: Uuid, export_id: Uuid) -> Result<TeamExport> {
+        let data = self.collect(team_id).await?;
+        let archive = build_archive(&data, export_id, Utc::now())?;
+
+        let name = export_object_name(export_id);
+        let download_url = self
+            .storage
+            .put(team_id, &name, &archive, EXPORT_CONTENT_TYPE)
+            .await
+            .with_context(|| format!("Failed to store export {}", export_id))?;
+
+        Ok(TeamExport {
+            export_id,
+            download_url,
+            storage_key: team_storage_key(team_id, &name),
+            size: archive.len(),
+            sha256: hex::encode(Sha256::digest(&archive)),
+            counts: data.counts(),
+        })
+    }
+
+    /// 读取团队的全部可导出数据
+    pub async fn collect(&self, team_id: Uuid) -> Result<TeamExportData> {
+        let mut crawls = Vec::new();
+        loop {
+            let page = self
+                .crawl_repository
+                .find_by_team_id_paginated(team_id, PAGE_SIZE, crawls.len() as u32)
+                .await?;
+            let last_page = page.len() < PAGE_SIZE as usize;
+            crawls.extend(page);
+            if last_page {
+                break;
+            }
+        }
+
+        let mut tasks = Vec::new();
+        loop {
+            let (page, _) = self
+                .task_repository
+                .query_tasks(TaskQueryParams {
+                    team_id,
+                    limit: PAGE_SIZE,
+                    offset: tasks.len() as u32,
+                    ..Default::default()
+                })
+                .await?;
+            let last_page = page.len() < PAGE_SIZE as usize;
+            tasks.extend(page);
+            if last_page {
+                break;
+            }
+        }
+
+        let task_ids: Vec<Uuid> = tasks.iter().map(|task| task.id).collect();
+        let mut results = Vec::new();
+        for chunk in task_ids.chunks(PAGE_SIZE as usize) {
+            results.extend(self.result_repository.find_by_task_ids(chunk).await?);
+        }
+
+        let webhooks = self.webhook_repository.find_by_team_id(team_id).await?;
+        let transactions = self
+            .credits_repository
+            .get_transaction_history(team_id, None)
+            .await?;
+
+        Ok(TeamExportData {
+            team_id,
+            crawls,
+            tasks,
+            results,
+            webhooks,
+            transactions,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::TaskType;
+    use std::collections::HashMap;
+    use std::io::Read;
+
+    fn read_entry(archive: &[u8], name: &str) -> String {
+        let mut archive = zip::ZipArchive::new(Cursor::new(archive)).unwrap();
+        let mut contents = String::new();
+        archive
+            .by_name(name)
+            .unwrap()
+            .read_to_string(&mut contents)
+            .unwrap();
+        contents
+    }
+
+    #[test]
+    fn test_build_archive_contains_all_sections() {
+        let team_id = Uuid::new_v4();
+        let export_id = Uuid::new_v4();
+        let webhook = Webhook::new(Uuid::new_v4(), team_id, "https://hooks.example".to_string())
+            .with_custom_headers(HashMap::from([(
+                "Authorization".to_string(),
+                "Bearer secret".to_string(),
+            )]));
+        let data = TeamExportData {
+            team_id,
+            webhooks: vec![webhook],
+            ..Default::default()
+        };
+
+        let archive = build_archive(&data, export_id, Utc::now()).unwrap();
+
+        let manifest: serde_json::Value =
+            serde_json::from_str(&read_entry(&archive, "manifest.json")).unwrap();
+        assert_eq!(manifest["export_id"], export_id.to_string());
+        assert_eq!(manifest["team_id"], team_id.to_string());
+        assert_eq!(manifest["counts"]["webhooks"], 1);
+        assert_eq!(manifest["counts"]["results"], 0);
+        assert_eq!(read_entry(&archive, "crawls.json"), "[]");
+        assert_eq!(read_entry(&archive, "results.jsonl"), "");
+
+        // 自定义请求头只保留名称
+        let webhooks = read_entry(&archive, "webhooks.json");
+        assert!(webhooks.contains("Authorization"));
+        assert!(!webhooks.contains("Bearer secret"));
+    }
+
+    #[test]
+    fn test_build_archive_redacts_result_destination_credentials() {
+        let team_id = Uuid::new_v4();
+        let task = Task::new(
+            Uuid::new_v4(),
+            TaskType::Crawl,
+            team_id,
+            Uuid::new_v4(),
+            "https://example.com".to_string(),
+            json!({
+                "url": "https://example.com",
+                "config": {
+                    "result_destination": {
+                        "type": "s3",
+                        "bucket": "acme-results",
+                        "region": "eu-west-1",
+                        "access_key_id": "AKIAEXAMPLE",
+                        "secret_access_key": "top-secret",
+                        "session_token": "session-secret"
+                    }
+                }
+            }),
+        );
+        let data = TeamExportData {
+            team_id,
+            tasks: vec![task.clone()],
+            ..Default::default()
+        };
+
+        let archive = build_archive(&data, Uuid::new_v4(), Utc::now()).unwrap();
+
+        let tasks = read_entry(&archive, "tasks.json");
+        assert!(tasks.contains("acme-results"));
+        assert!(!tasks.contains("top-secret"));
+        assert!(!tasks.contains("session-secret"));
+        // 仓库中的任务负载不受影响
+        assert_eq!(
+            data.tasks[0].payload["config"]["result_destination"]["secret_access_key"],
+            "top-secret"
+        );
+    }
+
+    #[test]
+    fn test_export_object_name() {
+        let export_id = Uuid::new_v4();
+        assert_eq!(
+            export_object_name(export_id),
+            format!("exports/{}.zip", export_id)
+        );
+    }
+}
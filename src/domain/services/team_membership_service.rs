@@ -0,0 +1,431 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! 团队成员服务
+//!
+//! 创建团队、按邮箱邀请成员、列出与移除成员，以及接受邀请。邀请令牌只在创建邀请时
+//! 返回一次，数据库中只保存其 SHA-256 摘要；令牌的投递（邮件等）由调用方负责。
+//! 每个团队至少保留一个 active 的 owner。
+
+use std::sync::Arc;
+
+use rand::RngExt;
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+use crate::domain::models::{normalize_email, Team, TeamMember, TeamRole};
+use crate::domain::repositories::task_repository::RepositoryError;
+use crate::domain::repositories::team_member_repository::TeamMemberRepository;
+use crate::domain::repositories::team_repository::TeamRepository;
+
+/// 团队名称最大长度
+const MAX_TEAM_NAME_LENGTH: usize = 100;
+
+/// 团队成员服务错误
+#[derive(Debug, thiserror::Error)]
+pub enum TeamMembershipError {
+    /// 团队名称为空或过长
+    #[error("Invalid team name: {0}")]
+    InvalidTeamName(String),
+    /// 邮箱格式无效
+    #[error("Invalid email address: {0}")]
+    InvalidEmail(String),
+    /// 团队不存在
+    #[error("Team not found")]
+    TeamNotFound,
+    /// 用户已是团队成员或已被邀请
+    #[error("User is already a member of the team")]
+    AlreadyMember,
+    /// 成员不存在
+    #[error("Team member not found")]
+    MemberNotFound,
+    /// 不能移除团队最后一个 owner
+    #[error("Cannot remove the last owner of the team")]
+    LastOwner,
+    /// 邀请不存在或已被接受
+    #[error("Invitation not found or already accepted")]
+    InvitationNotFound,
+    /// 仓库错误
+    #[error(transparent)]
+    Repository(#[from] RepositoryError),
+}
+
+/// 新创建的邀请
+#[derive(Debug, Clone)]
+pub struct TeamInvitation {
+    /// 待接受的成员
+    pub member: TeamMember,
+    /// 邀请令牌明文，只在创建时返回一次
+    pub token: String,
+}
+
+/// 团队成员服务
+pub struct TeamMembershipService {
+    teams: Arc<dyn TeamRepository>,
+    members: Arc<dyn TeamMemberRepository>,
+}
+
+impl TeamMembershipService {
+    pub fn new(teams: Arc<dyn TeamRepository>, members: Arc<dyn TeamMemberRepository>) -> Self {
+        Self { teams, members }
+    }
+
+    /// 创建团队；提供 `owner_email` 时该用户直接成为 active 的 owner
+    pub async fn create_team(
+        &self,
+        name: &str,
+        owner_email: Option<&str>,
+    ) -> Result<(Team, Option<TeamMember>), TeamMembershipError> {
+        let name = name.trim();
+        if name.is_empty() || name.chars().count() > MAX_TEAM_NAME_LENGTH {
+            return Err(TeamMembershipError::InvalidTeamName(format!(
+                "name must be 1-{} characters",
+                MAX_TEAM_NAME_LENGTH
+            )));
+        }
+        let owner_email = owner_email
+            .map(|email| {
+                normalize_email(email)
+                    .ok_or_else(|| TeamMembershipError::InvalidEmail(email.to_string()))
+            })
+            .transpose()?;
+
+        let team = self
+            .teams
+            .create(&Team::new(Uuid::new_v4(), name.to_string()))
+            .await?;
+        let owner = match owner_email {
+            Some(email) => {
+                let user = self.members.find_or_create_user(&email).await?;
+                let owner = TeamMember::active(team.id, &user, TeamRole::Owner);
+                self.members.add_member(&owner, None).await?;
+                Some(owner)
+            }
+            None => None,
+        };
+        Ok((team, owner))
+    }
+
+    /// 邀请用户加入团队
+    pub async fn invite_member(
+        &self,
+        team_id: Uuid,
+        email: &str,
+        role: TeamRole,
+    ) -> Result<TeamInvitation, TeamMembershipError> {
+        let email = normalize_email(email)
+            .ok_or_else(|| TeamMembershipError::InvalidEmail(email.to_string()))?;
+        self.require_team(team_id).await?;
+
+        let user = self.members.find_or_create_user(&email).await?;
+        if self.members.find_member(team_id, user.id).await?.is_some() {
+            return Err(TeamMembershipError::AlreadyMember);
+        }
+
+        let token = generate_invitation_token();
+        let member = TeamMember::invited(team_id, &user, role);
+        self.members
+            .add_member(&member, Some(&hash_invitation_token(&token)))
+            .await?;
+        Ok(TeamInvitation { member, token })
+    }
+
+    /// 列出团队成员（含待接受的邀请）
+    pub async fn list_members(
+        &self,
+        team_id: Uuid,
+    ) -> Result<Vec<TeamMember>, TeamMembershipError> {
+        self.require_team(team_id).await?;
+        Ok(self.members.list_members(team_id).await?)
+    }
+
+    /// 查询团队成员（含待接受的邀请）
+    pub async fn find_member(
+        &self,
+        team_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<TeamMember, TeamMembershipError> {
+        self.members
+            .find_member(team_id, user_id)
+            .await?
+            .ok_or(TeamMembershipError::MemberNotFound)
+    }
+
+    /// 移除成员或撤回邀请
+    pub async fn remove_member(
+        &self,
+        team_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<(), TeamMembershipError> {
+        let member = self
+            .members
+            .find_member(team_id, user_id)
+            .await?
+            .ok_or(TeamMembershipError::MemberNotFound)?;
+        if member.is_active_owner() {
+            let owners = self
+                .members
+                .list_members(team_id)
+                .await?
+                .iter()
+                .filter(|m| m.is_active_owner())
+                .count();
+            if owners <= 1 {
+                return Err(TeamMembershipError::LastOwner);
+            }
+        }
+
+        if self.members.remove_member(team_id, user_id).await? {
+            Ok(())
+        } else {
+            Err(TeamMembershipError::MemberNotFound)
+        }
+    }
+
+    /// 接受邀请
+    pub async fn accept_invitation(&self, token: &str) -> Result<TeamMember, TeamMembershipError> {
+        self.members
+            .accept_invitation(&hash_invitation_token(token.trim()))
+            .await?
+            .ok_or(TeamMembershipError::InvitationNotFound)
+    }
+
+    async fn require_team(&self, team_id: Uuid) -> Result<(), TeamMembershipError> {
+        match self.teams.find_by_id(team_id).await? {
+            Some(_) => Ok(()),
+            None => Err(TeamMembershipError::TeamNotFound),
+        }
+    }
+}
+
+/// 生成 256 位随机邀请令牌（十六进制）
+fn generate_invitation_token() -> String {
+    let bytes: [u8; 32] = rand::rng().random();
+    hex::encode(bytes)
+}
+
+/// 邀请令牌的 SHA-256 摘要（十六进制），数据库中只保存摘要
+fn hash_invitation_token(token: &str) -> String {
+    hex::encode(Sha256::digest(token.as_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::{TeamMemberStatus, User};
+    use async_trait::async_trait;
+    use chrono::Utc;
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct InMemoryTeams {
+        teams: Mutex<HashMap<Uuid, Team>>,
+    }
+
+    #[async_trait]
+    impl TeamRepository for InMemoryTeams {
+        async fn create(&self, team: &Team) -> Result<Team, RepositoryError> {
+            self.teams.lock().unwrap().insert(team.id, team.clone());
+            Ok(team.clone())
+        }
+
+        async fn find_by_id(&self, id: Uuid) -> Result<Option<Team>, RepositoryError> {
+            Ok(self.teams.lock().unwrap().get(&id).cloned())
+        }
+    }
+
+    #[derive(Default)]
+    struct InMemoryMembers {
+        users: Mutex<HashMap<String, User>>,
+        members: Mutex<Vec<(TeamMember, Option<String>)>>,
+    }
+
+    #[async_trait]
+    impl TeamMemberRepository for InMemoryMembers {
+        async fn find_or_create_user(&self, email: &str) -> Result<User, RepositoryError> {
+            Ok(self
+                .users
+                .lock()
+                .unwrap()
+                .entry(email.to_string())
+                .or_insert_with(|| User {
+                    id: Uuid::new_v4(),
+                    email: email.to_string(),
+                    name: None,
+                    created_at: Utc::now(),
+                })
+                .clone())
+        }
+
+        async fn add_member(
+            &self,
+            member: &TeamMember,
+            invitation_token_hash: Option<&str>,
+        ) -> Result<(), RepositoryError> {
+            self.members
+                .lock()
+                .unwrap()
+                .push((member.clone(), invitation_token_hash.map(str::to_string)));
+            Ok(())
+        }
+
+        async fn find_member(
+            &self,
+            team_id: Uuid,
+            user_id: Uuid,
+        ) -> Result<Option<TeamMember>, RepositoryError> {
+            Ok(self
+                .members
+                .lock()
+                .unwrap()
+                .iter()
+                .find(|(m, _)| m.team_id == team_id && m.user_id == user_id)
+                .map(|(m, _)| m.clone()))
+        }
+
+        async fn list_members(&self, team_id: Uuid) -> Result<Vec<TeamMember>, RepositoryError> {
+            Ok(self
+                .members
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|(m, _)| m.team_id == team_id)
+                .map(|(m, _)| m.clone())
+                .collect())
+        }
+
+        async fn list_memberships_by_email(
+            &self,
+            email: &str,
+        ) -> Result<Vec<TeamMember>, RepositoryError> {
+            Ok(self
+                .members
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|(m, _)| m.email == email)
+                .map(|(m, _)| m.clone())
+                .collect())
+        }
+
+        async fn remove_member(
+            &self,
+            team_id: Uuid,
+            user_id: Uuid,
+        ) -> Result<bool, RepositoryError> {
+            let mut members = self.members.lock().unwrap();
+            let before = members.len();
+            members.retain(|(m, _)| !(m.team_id == team_id && m.user_id == user_id));
+            Ok(members.len() < before)
+        }
+
+        async fn accept_invitation(
+            &self,
+            invitation_token_hash: &str,
+        ) -> Result<Option<TeamMember>, RepositoryError> {
+            let mut members = self.members.lock().unwrap();
+            let Some((member, hash)) = members
+                .iter_mut()
+                .find(|(_, hash)| hash.as_deref() == Some(invitation_token_hash))
+            else {
+                return Ok(None);
+            };
+            *hash = None;
+            member.status = TeamMemberStatus::Active;
+            member.joined_at = Some(Utc::now());
+            Ok(Some(member.clone()))
+        }
+    }
+
+    fn make_service() -> TeamMembershipService {
+        TeamMembershipService::new(
+            Arc::new(InMemoryTeams::default()),
+            Arc::new(InMemoryMembers::default()),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_invite_and_accept_member() {
+        let service = make_service();
+        let (team, owner) = service
+            .create_team("Acme", Some("Owner@Acme.io"))
+            .await
+            .unwrap();
+        let owner = owner.unwrap();
+        assert_eq!(owner.email, "owner@acme.io");
+        assert!(owner.is_active_owner());
+
+        let invitation = service
+            .invite_member(team.id, "dev@acme.io", TeamRole::Member)
+            .await
+            .unwrap();
+        assert_eq!(invitation.member.status, TeamMemberStatus::Invited);
+        assert_eq!(invitation.token.len(), 64);
+        assert!(matches!(
+            service
+                .invite_member(team.id, "DEV@acme.io", TeamRole::Admin)
+                .await,
+            Err(TeamMembershipError::AlreadyMember)
+        ));
+
+        let accepted = service.accept_invitation(&invitation.token).await.unwrap();
+        assert_eq!(accepted.user_id, invitation.member.user_id);
+        assert_eq!(accepted.status, TeamMemberStatus::Active);
+        assert!(matches!(
+            service.accept_invitation(&invitation.token).await,
+            Err(TeamMembershipError::InvitationNotFound)
+        ));
+        assert_eq!(service.list_members(team.id).await.unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_last_owner_cannot_be_removed() {
+        let service = make_service();
+        let (team, owner) = service
+            .create_team("Acme", Some("owner@acme.io"))
+            .await
+            .unwrap();
+        let owner = owner.unwrap();
+        assert!(matches!(
+            service.remove_member(team.id, owner.user_id).await,
+            Err(TeamMembershipError::LastOwner)
+        ));
+
+        let invitation = service
+            .invite_member(team.id, "dev@acme.io", TeamRole::Member)
+            .await
+            .unwrap();
+        service
+            .remove_member(team.id, invitation.member.user_id)
+            .await
+            .unwrap();
+        assert!(matches!(
+            service
+                .remove_member(team.id, invitation.member.user_id)
+                .await,
+            Err(TeamMembershipError::MemberNotFound)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_rejects_invalid_input() {
+        let service = make_service();
+        assert!(matches!(
+            service.create_team("  ", None).await,
+            Err(TeamMembershipError::InvalidTeamName(_))
+        ));
+        assert!(matches!(
+            service.create_team("Acme", Some("not-an-email")).await,
+            Err(TeamMembershipError::InvalidEmail(_))
+        ));
+        assert!(matches!(
+            service
+                .invite_member(Uuid::new_v4(), "dev@acme.io", TeamRole::Member)
+                .await,
+            Err(TeamMembershipError::TeamNotFound)
+        ));
+    }
+}
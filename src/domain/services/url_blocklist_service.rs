@@ -0,0 +1,137 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! URL 黑名单服务
+//!
+//! 合并配置中的全局模式与仓库中的全局/团队条目，供请求提交（handler）与
+//! 爬取展开链接（worker）时检查目标 URL 是否被禁止抓取。
+
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::domain::models::{BlocklistEntry, UrlBlocklist};
+use crate::domain::repositories::task_repository::RepositoryError;
+use crate::domain::repositories::url_blocklist_repository::UrlBlocklistRepository;
+
+/// URL 黑名单服务
+#[derive(Clone, Default)]
+pub struct UrlBlocklistService {
+    /// 配置中的全局条目
+    configured_entries: Vec<BlocklistEntry>,
+    /// 黑名单仓库（可选，未配置时只使用配置中的模式）
+    repository: Option<Arc<dyn UrlBlocklistRepository>>,
+}
+
+impl UrlBlocklistService {
+    /// 根据配置模式创建服务
+    pub fn new(patterns: &[String]) -> Self {
+        Self {
+            configured_entries: patterns
+                .iter()
+                .filter(|pattern| !pattern.trim().is_empty())
+                .map(|pattern| BlocklistEntry::from_setting(pattern))
+                .collect(),
+            repository: None,
+        }
+    }
+
+    /// 设置黑名单仓库，启用数据库中的全局与团队条目
+    pub fn with_repository(mut self, repository: Arc<dyn UrlBlocklistRepository>) -> Self {
+        self.repository = Some(repository);
+        self
+    }
+
+    /// 加载对团队生效的黑名单
+    pub async fn blocklist_for_team(&self, team_id: Uuid) -> Result<UrlBlocklist, RepositoryError> {
+        let mut entries = self.configured_entries.clone();
+        if let Some(repository) = &self.repository {
+            entries.extend(repository.find_for_team(team_id).await?);
+        }
+        Ok(UrlBlocklist::new(entries))
+    }
+
+    /// 检查一组 URL，返回第一个被禁止的 URL 及命中的条目
+    pub async fn find_blocked<'a>(
+        &self,
+        team_id: Uuid,
+        urls: impl IntoIterator<Item = &'a str>,
+    ) -> Result<Option<(String, BlocklistEntry)>, RepositoryError> {
+        let blocklist = self.blocklist_for_team(team_id).await?;
+        Ok(urls.into_iter().find_map(|url| {
+            blocklist
+                .find_match(url)
+                .map(|entry| (url.to_string(), entry.clone()))
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::BlocklistPatternType;
+    use async_trait::async_trait;
+
+    struct FixedRepo {
+        entries: Vec<BlocklistEntry>,
+    }
+
+    #[async_trait]
+    impl UrlBlocklistRepository for FixedRepo {
+        async fn create(&self, entry: &BlocklistEntry) -> Result<BlocklistEntry, RepositoryError> {
+            Ok(entry.clone())
+        }
+        async fn find_by_id(&self, id: Uuid) -> Result<Option<BlocklistEntry>, RepositoryError> {
+            Ok(self.entries.iter().find(|e| e.id == id).cloned())
+        }
+        async fn find_all(&self) -> Result<Vec<BlocklistEntry>, RepositoryError> {
+            Ok(self.entries.clone())
+        }
+        async fn find_for_team(
+            &self,
+            team_id: Uuid,
+        ) -> Result<Vec<BlocklistEntry>, RepositoryError> {
+            Ok(self
+                .entries
+                .iter()
+                .filter(|e| e.team_id.is_none() || e.team_id == Some(team_id))
+                .cloned()
+                .collect())
+        }
+        async fn delete(&self, _id: Uuid) -> Result<bool, RepositoryError> {
+            Ok(false)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_configured_patterns_apply_without_repository() {
+        let service = UrlBlocklistService::new(&["blocked.com".to_string(), "  ".to_string()]);
+        let blocked = service
+            .find_blocked(Uuid::new_v4(), ["https://ok.com", "https://a.blocked.com"])
+            .await
+            .unwrap();
+        let (url, entry) = blocked.unwrap();
+        assert_eq!(url, "https://a.blocked.com");
+        assert!(entry.is_global());
+    }
+
+    #[tokio::test]
+    async fn test_team_entries_only_apply_to_their_team() {
+        let team_a = Uuid::new_v4();
+        let team_b = Uuid::new_v4();
+        let service = UrlBlocklistService::new(&[]).with_repository(Arc::new(FixedRepo {
+            entries: vec![BlocklistEntry::new(
+                Some(team_a),
+                "team-only.com",
+                BlocklistPatternType::Wildcard,
+            )],
+        }));
+
+        let a = service.blocklist_for_team(team_a).await.unwrap();
+        let b = service.blocklist_for_team(team_b).await.unwrap();
+        assert!(a.is_blocked("https://team-only.com"));
+        assert!(!b.is_blocked("https://team-only.com"));
+    }
+}
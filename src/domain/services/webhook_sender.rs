@@ -16,6 +16,15 @@ use async_trait::async_trait;
 use serde_json::Value;
 use std::collections::HashMap;
 
+/// Webhook 下游响应（用于同步测试投递）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WebhookDeliveryResponse {
+    /// 下游返回的 HTTP 状态码
+    pub status_code: u16,
+    /// 下游返回的响应体（可能被截断）
+    pub body: String,
+}
+
 /// WebhookSender trait - webhook HTTP 发送接口
 ///
 /// 此 trait 定义了发送 webhook HTTP 请求的最小接口。
@@ -81,4 +90,26 @@ pub trait WebhookSender: Send + Sync {
         payload: &Value,
         headers: Option<&HashMap<String, String>>,
     ) -> Result<u16>;
+
+    /// 发送 webhook 并返回下游的完整响应
+    ///
+    /// 与 `send_with_status` 不同，非 2xx 响应同样返回 `Ok`，由调用方自行判断。
+    /// 默认实现基于 `send_with_status`，响应体为空。
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(WebhookDeliveryResponse)` - 收到下游响应
+    /// * `Err(anyhow::Error)` - 请求未能完成（连接失败、超时等）
+    async fn send_with_response(
+        &self,
+        url: &str,
+        payload: &Value,
+        headers: Option<&HashMap<String, String>>,
+    ) -> Result<WebhookDeliveryResponse> {
+        let status_code = self.send_with_status(url, payload, headers).await?;
+        Ok(WebhookDeliveryResponse {
+            status_code,
+            body: String::new(),
+        })
+    }
 }
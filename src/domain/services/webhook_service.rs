@@ -13,6 +13,7 @@ use crate::domain::models::{Task, Webhook};
 use crate::domain::models::{WebhookEvent, WebhookEventType};
 use crate::domain::repositories::webhook_event_repository::WebhookEventRepository;
 use crate::domain::repositories::webhook_repository::WebhookRepository;
+use crate::domain::services::notification_service::EventNotifier;
 use crate::domain::services::webhook_sender::WebhookSender;
 // 架构 MEDIUM-1（审查 M1 折中说明）：本 import 引入 domain → infrastructure 的依赖箭头。
 // `constant_time_eq_str` 是无状态纯函数（无 I/O、无 DB、无全局状态），位于
@@ -25,10 +26,13 @@ use anyhow::{anyhow, Result};
 use async_trait::async_trait;
 use chrono::Utc;
 use hmac::{Hmac, KeyInit, Mac};
-use log::{error, info};
+use log::{debug, error, info, warn};
+use serde::{Deserialize, Serialize};
 use serde_json::json;
 use sha2::Sha256;
+use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::Instant;
 use uuid::Uuid;
 
 type HmacSha256 = Hmac<Sha256>;
@@ -44,6 +48,102 @@ pub trait WebhookService: Send + Sync {
 
     /// 触发任务失败 webhook
     async fn trigger_failure(&self, task: &Task, error_msg: String) -> Result<()>;
+
+    /// 向指定 webhook 同步发送一条签名的示例事件，返回下游响应
+    ///
+    /// 测试事件不写入事件仓库，也不参与重试。默认实现不支持测试投递。
+    async fn send_test_event(
+        &self,
+        webhook: &Webhook,
+        event_type: WebhookEventType,
+    ) -> Result<WebhookTestResult> {
+        let _ = (webhook, event_type);
+        Err(anyhow!(
+            "webhook test delivery is not supported by this service"
+        ))
+    }
+}
+
+/// Webhook 测试投递结果
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WebhookTestResult {
+    /// 测试事件 ID（同 `X-Crawlrs-Event-ID` 请求头）
+    pub event_id: Uuid,
+    /// 事件类型
+    pub event_type: String,
+    /// 下游返回的 HTTP 状态码
+    pub status_code: u16,
+    /// 往返耗时（毫秒）
+    pub latency_ms: u64,
+    /// 下游返回的响应体（可能被截断）
+    pub response_body: String,
+    /// 下游是否返回 2xx
+    pub success: bool,
+}
+
+/// 构造测试投递使用的示例负载
+///
+/// 字段与真实任务事件保持一致，并带有 `test: true` 标记便于接收方区分。
+pub fn sample_webhook_payload(
+    webhook_id: Uuid,
+    event_type: &WebhookEventType,
+) -> serde_json::Value {
+    let failed = matches!(
+        event_type,
+        WebhookEventType::CrawlFailed
+            | WebhookEventType::ScrapeFailed
+            | WebhookEventType::ExportFailed
+            | WebhookEventType::WebhookDeliveryFailed
+    );
+    let mut payload = json!({
+        "test": true,
+        "event": event_type.to_string(),
+        "webhook_id": webhook_id,
+        "task_id": Uuid::nil(),
+        "status": if failed { "failed" } else { "completed" },
+        "url": "https://example.com",
+        "timestamp": Utc::now().timestamp()
+    });
+    if failed {
+        payload["error"] = json!("This is a test event");
+    }
+    if *event_type == WebhookEventType::CrawlSummary {
+        payload["crawl_id"] = json!(Uuid::nil());
+        payload["total_pages"] = json!(0);
+        payload["succeeded_pages"] = json!(0);
+        payload["failed_pages"] = json!(0);
+        payload["changed_pages"] = json!(0);
+        payload["unchanged_pages"] = json!(0);
+        payload["duration_ms"] = json!(0);
+        payload["credits_consumed"] = json!(0);
+        payload["results_url"] = json!(format!("/v1/crawl/{}/results", Uuid::nil()));
+    }
+    if *event_type == WebhookEventType::CreditsLow {
+        payload["team_id"] = json!(Uuid::nil());
+        payload["balance"] = json!(0);
+        payload["threshold"] = json!(0);
+    }
+    if *event_type == WebhookEventType::ExportCompleted {
+        payload["export_id"] = json!(Uuid::nil());
+        payload["download_url"] = json!(format!(
+            "/v1/assets/{}/exports/{}.zip",
+            Uuid::nil(),
+            Uuid::nil()
+        ));
+        payload["size"] = json!(0);
+    }
+    if *event_type == WebhookEventType::MonitorChanged {
+        payload["monitor_id"] = json!(Uuid::nil());
+        payload["added_lines"] = json!(1);
+        payload["removed_lines"] = json!(1);
+        payload["diff"] = json!("@@ -1 +1 @@\n-Old content\n+New content");
+    }
+    if *event_type == WebhookEventType::WebhookDeliveryFailed {
+        payload["event_id"] = json!(Uuid::nil());
+        payload["event_type"] = json!(WebhookEventType::CrawlCompleted.to_string());
+        payload["attempts"] = json!(5);
+    }
+    payload
 }
 
 /// Webhook服务实现
@@ -54,6 +154,8 @@ pub struct WebhookServiceImpl {
     secret: String,
     /// Webhook 事件仓库
     repository: Arc<dyn WebhookEventRepository>,
+    /// Webhook 注册仓库（可选），用于投递时附加注册的自定义请求头
+    webhook_repository: Option<Arc<dyn WebhookRepository>>,
 }
 
 impl WebhookServiceImpl {
@@ -67,9 +169,65 @@ impl WebhookServiceImpl {
             webhook_sender,
             secret,
             repository,
+            webhook_repository: None,
+        }
+    }
+
+    /// 注入 webhook 注册仓库，使已注册 webhook 的投递携带其自定义请求头
+    pub fn with_webhook_repository(
+        mut self,
+        webhook_repository: Arc<dyn WebhookRepository>,
+    ) -> Self {
+        self.webhook_repository = Some(webhook_repository);
+        self
+    }
+
+    /// 查找事件所属 webhook 注册的自定义请求头
+    ///
+    /// 任务级 webhook（webhook_id 为 nil）没有注册信息；查询失败时仅记录日志，
+    /// 不阻塞投递。
+    async fn custom_headers_for(&self, webhook_id: Uuid) -> HashMap<String, String> {
+        let repo = match &self.webhook_repository {
+            Some(repo) if !webhook_id.is_nil() => repo,
+            _ => return HashMap::new(),
+        };
+        match repo.find_by_id(webhook_id).await {
+            Ok(Some(webhook)) => webhook.custom_headers,
+            Ok(None) => HashMap::new(),
+            Err(e) => {
+                error!("Failed to load webhook {} for delivery: {}", webhook_id, e);
+                HashMap::new()
+            }
         }
     }
 
+    /// 构造带签名的请求头
+    ///
+    /// 先放入自定义请求头，再写入签名相关头，保证签名头不会被覆盖。
+    fn signed_headers(
+        &self,
+        payload_str: &str,
+        event_id: Uuid,
+        custom_headers: &HashMap<String, String>,
+    ) -> HashMap<String, String> {
+        let timestamp = chrono::Utc::now().timestamp();
+        let signature = self.generate_signature(payload_str, timestamp);
+
+        let mut headers: HashMap<String, String> = custom_headers
+            .iter()
+            .filter(|(name, _)| {
+                !name.eq_ignore_ascii_case("content-type")
+                    && !name.to_ascii_lowercase().starts_with("x-crawlrs-")
+            })
+            .map(|(name, value)| (name.clone(), value.clone()))
+            .collect();
+        headers.insert("Content-Type".to_string(), "application/json".to_string());
+        headers.insert("X-Crawlrs-Signature".to_string(), signature);
+        headers.insert("X-Crawlrs-Timestamp".to_string(), timestamp.to_string());
+        headers.insert("X-Crawlrs-Event-ID".to_string(), event_id.to_string());
+        headers
+    }
+
     /// 为负载生成签名（包含时间戳以防止重放攻击）
     fn generate_signature(&self, payload: &str, timestamp: i64) -> String {
         let message = format!("{}.{}", timestamp, payload);
@@ -121,15 +279,9 @@ impl WebhookServiceImpl {
 #[async_trait]
 impl WebhookService for WebhookServiceImpl {
     async fn send_webhook(&self, event: &WebhookEvent) -> Result<()> {
-        let timestamp = chrono::Utc::now().timestamp();
         let payload_str = serde_json::to_string(&event.payload)?;
-        let signature = self.generate_signature(&payload_str, timestamp);
-
-        let mut headers = std::collections::HashMap::new();
-        headers.insert("Content-Type".to_string(), "application/json".to_string());
-        headers.insert("X-Crawlrs-Signature".to_string(), signature);
-        headers.insert("X-Crawlrs-Timestamp".to_string(), timestamp.to_string());
-        headers.insert("X-Crawlrs-Event-ID".to_string(), event.id.to_string());
+        let custom_headers = self.custom_headers_for(event.webhook_id).await;
+        let headers = self.signed_headers(&payload_str, event.id, &custom_headers);
 
         let payload = serde_json::from_str(&payload_str)?;
 
@@ -168,6 +320,43 @@ impl WebhookService for WebhookServiceImpl {
         self.send_task_webhook(task, webhook_url, event_type, Some(error_msg))
             .await
     }
+
+    async fn send_test_event(
+        &self,
+        webhook: &Webhook,
+        event_type: WebhookEventType,
+    ) -> Result<WebhookTestResult> {
+        let event_id = Uuid::new_v4();
+        let payload = webhook
+            .render_payload(
+                &event_type,
+                &sample_webhook_payload(webhook.id, &event_type),
+            )
+            .map_err(|e| anyhow!("Failed to render webhook payload: {}", e))?;
+        let payload_str = serde_json::to_string(&payload)?;
+        let headers = self.signed_headers(&payload_str, event_id, &webhook.custom_headers);
+
+        let started = Instant::now();
+        let response = self
+            .webhook_sender
+            .send_with_response(&webhook.url, &payload, Some(&headers))
+            .await?;
+        let latency_ms = started.elapsed().as_millis() as u64;
+
+        info!(
+            "Test event {} sent to webhook {} (status {}, {} ms)",
+            event_id, webhook.id, response.status_code, latency_ms
+        );
+
+        Ok(WebhookTestResult {
+            event_id,
+            event_type: event_type.to_string(),
+            status_code: response.status_code,
+            latency_ms,
+            success: (200..300).contains(&response.status_code),
+            response_body: response.body,
+        })
+    }
 }
 
 impl WebhookServiceImpl {
@@ -257,6 +446,25 @@ pub trait WebhookManagementService: Send + Sync {
         payload: serde_json::Value,
     ) -> Result<()>;
 
+    /// 向团队所有订阅了该事件类型的 webhook 分发事件
+    ///
+    /// 未订阅该事件类型的 webhook 会被跳过，单个 webhook 发送失败不影响其余 webhook。
+    ///
+    /// # 参数
+    /// * `team_id` - 团队 ID
+    /// * `event_type` - 事件类型
+    /// * `payload` - 事件负载（JSON）
+    ///
+    /// # 返回值
+    /// * `Ok(usize)` - 成功发送的 webhook 数量
+    /// * `Err` - 查询团队 webhook 失败
+    async fn dispatch_event(
+        &self,
+        team_id: Uuid,
+        event_type: WebhookEventType,
+        payload: serde_json::Value,
+    ) -> Result<usize>;
+
     /// 重试失败的 webhook 事件
     ///
     /// 从事件仓库中取出待处理事件，逐个尝试重新发送，
@@ -292,6 +500,8 @@ pub struct WebhookManagementServiceImpl {
     event_repository: Arc<dyn WebhookEventRepository>,
     /// Webhook 发送服务（复用现有签名+发送逻辑）
     webhook_service: Arc<dyn WebhookService>,
+    /// 事件通知（可选），分发事件时同时通知团队的邮件联系人与聊天频道
+    notifier: Option<Arc<dyn EventNotifier>>,
 }
 
 impl WebhookManagementServiceImpl {
@@ -305,8 +515,15 @@ impl WebhookManagementServiceImpl {
             webhook_repository,
             event_repository,
             webhook_service,
+            notifier: None,
         }
     }
+
+    /// 配置事件通知，`dispatch_event` 分发的事件同时通知团队的邮件联系人与聊天频道
+    pub fn with_notifier(mut self, notifier: Arc<dyn EventNotifier>) -> Self {
+        self.notifier = Some(notifier);
+        self
+    }
 }
 
 #[async_trait]
@@ -343,6 +560,19 @@ impl WebhookManagementService for WebhookManagementServiceImpl {
             .map_err(|e| anyhow!("Failed to find webhook {}: {}", webhook_id, e))?
             .ok_or_else(|| anyhow!("Webhook not found: {}", webhook_id))?;
 
+        if !webhook.accepts(&event_type) {
+            debug!(
+                "Webhook {} is not subscribed to {}, skipping",
+                webhook_id, event_type
+            );
+            return Ok(());
+        }
+
+        // 入库前按模板渲染，重试时重发同一份载荷，签名也覆盖渲染结果
+        let payload = webhook
+            .render_payload(&event_type, &payload)
+            .map_err(|e| anyhow!("Failed to render payload for webhook {}: {}", webhook_id, e))?;
+
         let event = WebhookEvent::new(
             Uuid::new_v4(),
             webhook.team_id,
@@ -366,6 +596,42 @@ impl WebhookManagementService for WebhookManagementServiceImpl {
         Ok(())
     }
 
+    async fn dispatch_event(
+        &self,
+        team_id: Uuid,
+        event_type: WebhookEventType,
+        payload: serde_json::Value,
+    ) -> Result<usize> {
+        let webhooks = self
+            .webhook_repository
+            .find_by_team_id(team_id)
+            .await
+            .map_err(|e| anyhow!("Failed to list webhooks for team {}: {}", team_id, e))?;
+
+        let mut delivered = 0;
+        for webhook in webhooks.iter().filter(|w| w.accepts(&event_type)) {
+            match self
+                .trigger_webhook(webhook.id, event_type.clone(), payload.clone())
+                .await
+            {
+                Ok(()) => delivered += 1,
+                Err(e) => error!(
+                    "Failed to dispatch {} to webhook {}: {}",
+                    event_type, webhook.id, e
+                ),
+            }
+        }
+
+        // 通知失败不影响 webhook 分发结果
+        if let Some(notifier) = &self.notifier {
+            if let Err(e) = notifier.notify(team_id, &event_type, &payload).await {
+                warn!("{}", e);
+            }
+        }
+
+        Ok(delivered)
+    }
+
     async fn retry_failed(&self, limit: u64) -> Result<u64> {
         let pending = self
             .event_repository
@@ -1023,6 +1289,165 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_send_webhook_merges_registered_custom_headers() {
+        use std::sync::Mutex;
+
+        #[derive(Default)]
+        struct HeaderCapturingSender {
+            captured: Mutex<Option<HashMap<String, String>>>,
+        }
+
+        #[async_trait]
+        impl WebhookSender for HeaderCapturingSender {
+            async fn send(
+                &self,
+                _url: &str,
+                _payload: &Value,
+                headers: Option<&HashMap<String, String>>,
+            ) -> Result<()> {
+                *self.captured.lock().unwrap() = headers.cloned();
+                Ok(())
+            }
+
+            async fn send_with_status(
+                &self,
+                _url: &str,
+                _payload: &Value,
+                _headers: Option<&HashMap<String, String>>,
+            ) -> Result<u16> {
+                Ok(200)
+            }
+        }
+
+        let team_id = Uuid::new_v4();
+        let webhook = Webhook::new(Uuid::new_v4(), team_id, "https://example.com/hook".into())
+            .with_custom_headers(HashMap::from([
+                ("Authorization".to_string(), "Bearer token".to_string()),
+                ("X-Crawlrs-Signature".to_string(), "forged".to_string()),
+            ]));
+        let sender = Arc::new(HeaderCapturingSender::default());
+        let service = make_service(
+            sender.clone(),
+            Arc::new(MockWebhookEventRepository::default()),
+            "mysecret",
+        )
+        .with_webhook_repository(Arc::new(MockWebhookRepository::with_webhooks(vec![
+            webhook.clone(),
+        ])));
+        let event = WebhookEvent::new(
+            Uuid::new_v4(),
+            team_id,
+            webhook.id,
+            WebhookEventType::ScrapeCompleted,
+            json!({"task_id": "abc"}),
+            webhook.url.clone(),
+        );
+
+        service.send_webhook(&event).await.expect("send ok");
+
+        let captured = sender.captured.lock().unwrap().clone().expect("headers");
+        assert_eq!(
+            captured.get("Authorization").map(|s| s.as_str()),
+            Some("Bearer token")
+        );
+        assert_ne!(
+            captured.get("X-Crawlrs-Signature").map(|s| s.as_str()),
+            Some("forged"),
+            "custom headers must not override the signature"
+        );
+    }
+
+    // ---- send_test_event ----
+    // ---- send_test_event ----
+
+    #[tokio::test]
+    async fn test_send_test_event_signs_and_does_not_persist() {
+        use crate::domain::services::webhook_sender::WebhookDeliveryResponse;
+        use std::sync::Mutex;
+
+        #[derive(Default)]
+        struct ResponseSender {
+            captured: Mutex<Option<(Value, HashMap<String, String>)>>,
+        }
+
+        #[async_trait]
+        impl WebhookSender for ResponseSender {
+            async fn send(
+                &self,
+                _url: &str,
+                _payload: &Value,
+                _headers: Option<&HashMap<String, String>>,
+            ) -> Result<()> {
+                Ok(())
+            }
+
+            async fn send_with_status(
+                &self,
+                _url: &str,
+                _payload: &Value,
+                _headers: Option<&HashMap<String, String>>,
+            ) -> Result<u16> {
+                Ok(200)
+            }
+
+            async fn send_with_response(
+                &self,
+                _url: &str,
+                payload: &Value,
+                headers: Option<&HashMap<String, String>>,
+            ) -> Result<WebhookDeliveryResponse> {
+                *self.captured.lock().unwrap() =
+                    Some((payload.clone(), headers.cloned().unwrap_or_default()));
+                Ok(WebhookDeliveryResponse {
+                    status_code: 503,
+                    body: "unavailable".to_string(),
+                })
+            }
+        }
+
+        let sender = Arc::new(ResponseSender::default());
+        let repo = Arc::new(MockWebhookEventRepository::default());
+        let service = make_service(sender.clone(), repo.clone(), "mysecret");
+        let webhook = Webhook::new(
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            "https://example.com/hook".to_string(),
+        );
+
+        let result = service
+            .send_test_event(&webhook, WebhookEventType::ScrapeFailed)
+            .await
+            .expect("test delivery should return downstream response");
+
+        assert_eq!(result.status_code, 503);
+        assert!(!result.success);
+        assert_eq!(result.response_body, "unavailable");
+        assert_eq!(result.event_type, "scrape.failed");
+
+        let (payload, headers) = sender.captured.lock().unwrap().clone().unwrap();
+        assert_eq!(payload["test"], true);
+        assert_eq!(payload["status"], "failed");
+        assert!(headers.contains_key("X-Crawlrs-Signature"));
+        assert_eq!(
+            headers.get("X-Crawlrs-Event-ID").map(|s| s.as_str()),
+            Some(result.event_id.to_string().as_str())
+        );
+        assert_eq!(
+            repo.created_count.load(Ordering::SeqCst),
+            0,
+            "test events must not be persisted"
+        );
+    }
+
+    #[test]
+    fn test_sample_webhook_payload_completed_has_no_error() {
+        let payload = sample_webhook_payload(Uuid::new_v4(), &WebhookEventType::CrawlCompleted);
+        assert_eq!(payload["event"], "crawl.completed");
+        assert_eq!(payload["status"], "completed");
+        assert!(payload.get("error").is_none());
+    }
+
     // ---- trigger_completion ----
 
     #[tokio::test]
@@ -1854,6 +2279,33 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_trigger_webhook_renders_payload_template() {
+        let team_id = Uuid::new_v4();
+        let webhook = make_test_webhook(team_id, "https://example.com/hook")
+            .with_payload_template(Some(r#"{"text": "{{event}} for {{task_id}}"}"#.to_string()));
+        let webhook_repo = Arc::new(MockWebhookRepository::with_webhooks(vec![webhook.clone()]));
+        let event_repo = Arc::new(ConfigurableWebhookEventRepository::default());
+        let webhook_service = Arc::new(MockWebhookService::default());
+        let service = make_management_service(webhook_repo, event_repo.clone(), webhook_service);
+
+        service
+            .trigger_webhook(
+                webhook.id,
+                WebhookEventType::ScrapeCompleted,
+                json!({"task_id": "abc"}),
+            )
+            .await
+            .expect("trigger ok");
+
+        let events = event_repo.events.lock().unwrap();
+        assert_eq!(
+            events[0].payload,
+            json!({"text": "scrape.completed for abc"}),
+            "stored payload should be the rendered template"
+        );
+    }
+
     #[tokio::test]
     async fn test_trigger_webhook_not_found_returns_error() {
         let webhook_repo = Arc::new(MockWebhookRepository::default());
@@ -1921,6 +2373,131 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_trigger_webhook_skips_unsubscribed_event_type() {
+        let team_id = Uuid::new_v4();
+        let webhook = make_test_webhook(team_id, "https://example.com/hook")
+            .with_event_types(vec![WebhookEventType::CrawlCompleted]);
+        let webhook_repo = Arc::new(MockWebhookRepository::with_webhooks(vec![webhook.clone()]));
+        let event_repo = Arc::new(ConfigurableWebhookEventRepository::default());
+        let webhook_service = Arc::new(MockWebhookService::default());
+        let service =
+            make_management_service(webhook_repo, event_repo.clone(), webhook_service.clone());
+
+        let result = service
+            .trigger_webhook(webhook.id, WebhookEventType::ScrapeFailed, json!({}))
+            .await;
+
+        assert!(
+            result.is_ok(),
+            "unsubscribed event should be skipped, not fail"
+        );
+        assert_eq!(webhook_service.send_count.load(Ordering::SeqCst), 0);
+        assert!(event_repo.events.lock().unwrap().is_empty());
+    }
+
+    // ---- dispatch_event ----
+
+    #[tokio::test]
+    async fn test_dispatch_event_only_sends_to_matching_webhooks() {
+        let team_id = Uuid::new_v4();
+        let all_events = make_test_webhook(team_id, "https://example.com/all");
+        let crawl_only = make_test_webhook(team_id, "https://example.com/crawl")
+            .with_event_types(vec![WebhookEventType::CrawlCompleted]);
+        let scrape_only = make_test_webhook(team_id, "https://example.com/scrape")
+            .with_event_types(vec![WebhookEventType::ScrapeFailed]);
+        let other_team = make_test_webhook(Uuid::new_v4(), "https://example.com/other");
+        let webhook_repo = Arc::new(MockWebhookRepository::with_webhooks(vec![
+            all_events,
+            crawl_only,
+            scrape_only,
+            other_team,
+        ]));
+        let event_repo = Arc::new(ConfigurableWebhookEventRepository::default());
+        let webhook_service = Arc::new(MockWebhookService::default());
+        let service =
+            make_management_service(webhook_repo, event_repo.clone(), webhook_service.clone());
+
+        let delivered = service
+            .dispatch_event(team_id, WebhookEventType::CrawlCompleted, json!({}))
+            .await
+            .expect("dispatch should succeed");
+
+        assert_eq!(
+            delivered, 2,
+            "only all-events and crawl-only webhooks match"
+        );
+        assert_eq!(webhook_service.send_count.load(Ordering::SeqCst), 2);
+        let urls: Vec<String> = event_repo
+            .events
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|e| e.webhook_url.clone())
+            .collect();
+        assert!(urls.contains(&"https://example.com/all".to_string()));
+        assert!(urls.contains(&"https://example.com/crawl".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_event_repo_failure_propagates() {
+        let webhook_repo: Arc<dyn WebhookRepository> = Arc::new(FailingWebhookRepository);
+        let event_repo = Arc::new(ConfigurableWebhookEventRepository::default());
+        let webhook_service = Arc::new(MockWebhookService::default());
+        let service = make_management_service(webhook_repo, event_repo, webhook_service);
+
+        let result = service
+            .dispatch_event(Uuid::new_v4(), WebhookEventType::CrawlCompleted, json!({}))
+            .await;
+
+        assert!(result.is_err());
+    }
+
+    /// Records every notification it is asked to send
+    #[derive(Default)]
+    struct RecordingNotifier {
+        notified: std::sync::Mutex<Vec<(Uuid, WebhookEventType)>>,
+    }
+
+    #[async_trait]
+    impl EventNotifier for RecordingNotifier {
+        async fn notify(
+            &self,
+            team_id: Uuid,
+            event_type: &WebhookEventType,
+            _payload: &Value,
+        ) -> Result<usize> {
+            self.notified
+                .lock()
+                .unwrap()
+                .push((team_id, event_type.clone()));
+            Ok(1)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_event_notifies_even_without_webhooks() {
+        let team_id = Uuid::new_v4();
+        let notifier = Arc::new(RecordingNotifier::default());
+        let service = WebhookManagementServiceImpl::new(
+            Arc::new(MockWebhookRepository::default()),
+            Arc::new(ConfigurableWebhookEventRepository::default()),
+            Arc::new(MockWebhookService::default()),
+        )
+        .with_notifier(notifier.clone());
+
+        let delivered = service
+            .dispatch_event(team_id, WebhookEventType::CrawlSummary, json!({}))
+            .await
+            .expect("dispatch should succeed");
+
+        assert_eq!(delivered, 0);
+        assert_eq!(
+            *notifier.notified.lock().unwrap(),
+            vec![(team_id, WebhookEventType::CrawlSummary)]
+        );
+    }
+
     // ---- retry_failed ----
 
     #[tokio::test]
@@ -3,7 +3,7 @@
 // Licensed under the Apache License, Version 2.0
 // See LICENSE file in the project root for full license information.
 
-use crate::domain::models::Webhook;
+use crate::domain::models::{Webhook, WebhookEventType};
 use crate::domain::repositories::task_repository::RepositoryError;
 use crate::domain::repositories::webhook_repository::WebhookRepository;
 use std::sync::Arc;
@@ -19,13 +19,23 @@ impl<R: WebhookRepository> CreateWebhookUseCase<R> {
     }
 
     pub async fn execute(&self, team_id: Uuid, url: String) -> Result<Webhook, RepositoryError> {
-        let now = chrono::Utc::now();
-        let webhook = Webhook {
-            id: Uuid::new_v4(),
-            team_id,
-            url,
-            created_at: now,
-        };
+        self.execute_with_event_types(team_id, url, Vec::new())
+            .await
+    }
+
+    /// 创建仅订阅指定事件类型的 webhook（空列表表示订阅全部事件）
+    pub async fn execute_with_event_types(
+        &self,
+        team_id: Uuid,
+        url: String,
+        event_types: Vec<WebhookEventType>,
+    ) -> Result<Webhook, RepositoryError> {
+        let webhook = Webhook::new(Uuid::new_v4(), team_id, url).with_event_types(event_types);
+        self.create(webhook).await
+    }
+
+    /// 持久化已构建好的 webhook（调用方负责校验自定义请求头与载荷模板）
+    pub async fn create(&self, webhook: Webhook) -> Result<Webhook, RepositoryError> {
         self.repo.create(&webhook).await?;
         Ok(webhook)
     }
@@ -134,6 +144,48 @@ mod tests {
         assert_ne!(w1.url, w2.url);
     }
 
+    #[tokio::test]
+    async fn test_execute_with_event_types_stores_filter() {
+        let repo = Arc::new(MockWebhookRepository::default());
+        let use_case = CreateWebhookUseCase::new(repo.clone());
+
+        let webhook = use_case
+            .execute_with_event_types(
+                Uuid::new_v4(),
+                "https://example.com/webhook".to_string(),
+                vec![WebhookEventType::CrawlCompleted],
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(webhook.event_types, vec![WebhookEventType::CrawlCompleted]);
+        assert!(!webhook.accepts(&WebhookEventType::ScrapeFailed));
+        assert_eq!(repo.created_count.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_create_persists_delivery_options() {
+        let repo = Arc::new(MockWebhookRepository::default());
+        let use_case = CreateWebhookUseCase::new(repo.clone());
+
+        let webhook = Webhook::new(
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            "https://example.com/webhook".to_string(),
+        )
+        .with_custom_headers(std::collections::HashMap::from([(
+            "Authorization".to_string(),
+            "Bearer token".to_string(),
+        )]))
+        .with_payload_template(Some(r#"{"text": "{{event}}"}"#.to_string()));
+
+        let created = use_case.create(webhook.clone()).await.unwrap();
+
+        assert_eq!(created.custom_headers, webhook.custom_headers);
+        assert_eq!(created.payload_template, webhook.payload_template);
+        assert_eq!(repo.created_count.load(Ordering::SeqCst), 1);
+    }
+
     // ---- execute failure propagation ----
 
     #[tokio::test]
@@ -0,0 +1,354 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! 无障碍（a11y）扫描
+//!
+//! 浏览器引擎在页面动作执行完后向页面注入 axe-core 并运行扫描（`formats: ["accessibility"]`），
+//! 将违规项（规则 ID、影响级别、命中元素的选择器）写入结果元数据，用于无障碍监控。
+//! axe-core 脚本从 `CRAWLRS_AXE_CORE_PATH` 指定的文件读取（默认
+//! `/usr/share/crawlrs/axe.min.js`），首次使用时加载并缓存。
+
+use once_cell::sync::OnceCell;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+/// 请求的 `formats` 中表示无障碍扫描的取值
+pub const ACCESSIBILITY_FORMAT: &str = "accessibility";
+
+/// 指定 axe-core 脚本路径的环境变量
+pub const AXE_CORE_PATH_ENV: &str = "CRAWLRS_AXE_CORE_PATH";
+
+/// 默认的 axe-core 脚本路径（Docker 镜像中的位置）
+pub const DEFAULT_AXE_CORE_PATH: &str = "/usr/share/crawlrs/axe.min.js";
+
+/// 单次扫描允许指定的最大规则标签数
+pub const MAX_ACCESSIBILITY_TAGS: usize = 16;
+
+/// 每条违规最多记录的元素数
+pub const MAX_NODES_PER_VIOLATION: usize = 50;
+
+/// 元素 HTML 片段的最大长度（字符）
+const MAX_NODE_HTML_CHARS: usize = 500;
+
+/// 已加载的 axe-core 脚本
+static AXE_SOURCE: OnceCell<String> = OnceCell::new();
+
+/// 请求的输出格式中是否包含无障碍扫描（大小写不敏感）
+pub fn requests_accessibility(formats: Option<&[String]>) -> bool {
+    formats.is_some_and(|formats| {
+        formats
+            .iter()
+            .any(|format| format.trim().eq_ignore_ascii_case(ACCESSIBILITY_FORMAT))
+    })
+}
+
+/// 是否为合法的 axe 规则标签（如 `wcag2aa`、`best-practice`、`EN-301-549`）
+pub fn is_valid_tag(tag: &str) -> bool {
+    let tag = tag.trim();
+    !tag.is_empty()
+        && tag.len() <= 32
+        && tag
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '.' || c == '_')
+}
+
+/// 读取 axe-core 脚本，成功后缓存，失败时下次调用重试
+pub fn axe_source() -> Result<&'static str, String> {
+    AXE_SOURCE
+        .get_or_try_init(|| {
+            let path = std::env::var(AXE_CORE_PATH_ENV)
+                .ok()
+                .filter(|path| !path.is_empty())
+                .unwrap_or_else(|| DEFAULT_AXE_CORE_PATH.to_string());
+            std::fs::read_to_string(&path)
+                .map_err(|e| format!("axe-core script not available at {}: {}", path, e))
+        })
+        .map(String::as_str)
+}
+
+/// 无障碍扫描配置
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AccessibilityOptions {
+    /// 只运行带这些标签的规则（如 `wcag2a`、`wcag2aa`），为空时运行 axe 的默认规则集
+    pub tags: Vec<String>,
+}
+
+impl AccessibilityOptions {
+    /// 由请求参数构建扫描配置，忽略非法标签并去重，最多保留 `MAX_ACCESSIBILITY_TAGS` 个
+    pub fn from_tags(tags: Option<&[String]>) -> Self {
+        let mut kept: Vec<String> = Vec::new();
+        for tag in tags.unwrap_or_default() {
+            let tag = tag.trim();
+            if is_valid_tag(tag) && !kept.iter().any(|t| t.eq_ignore_ascii_case(tag)) {
+                kept.push(tag.to_string());
+            }
+        }
+        kept.truncate(MAX_ACCESSIBILITY_TAGS);
+        Self { tags: kept }
+    }
+
+    /// 在页面中运行 axe 的脚本，返回 Promise，结果为精简后的 JSON
+    pub fn run_script(&self) -> String {
+        let options = if self.tags.is_empty() {
+            "{resultTypes: ['violations']}".to_string()
+        } else {
+            format!(
+                "{{runOnly: {{type: 'tag', values: {}}}, resultTypes: ['violations']}}",
+                serde_json::to_string(&self.tags).unwrap_or_else(|_| "[]".to_string())
+            )
+        };
+        format!(
+            r#"axe.run(document, {options}).then((r) => ({{
+    version: r.testEngine ? r.testEngine.version : null,
+    passes: r.passes.length,
+    incomplete: r.incomplete.length,
+    violations: r.violations.map((v) => ({{
+        id: v.id,
+        impact: v.impact,
+        description: v.description,
+        help: v.help,
+        help_url: v.helpUrl,
+        tags: v.tags,
+        nodes: v.nodes.map((n) => ({{
+            target: n.target,
+            html: n.html,
+            failure_summary: n.failureSummary,
+        }})),
+    }})),
+}}))"#
+        )
+    }
+}
+
+/// 违规命中的元素
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ViolationNode {
+    /// 元素的 CSS 选择器，嵌套 iframe 与 Shadow DOM 的各层以 ` >>> ` 分隔
+    pub selector: String,
+    /// 元素 HTML 片段（截断到 500 字符）
+    pub html: String,
+    /// 修复提示
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub failure_summary: Option<String>,
+}
+
+/// 一条违规的规则
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AccessibilityViolation {
+    /// 规则 ID（如 `color-contrast`、`image-alt`）
+    pub id: String,
+    /// 影响级别：minor / moderate / serious / critical
+    pub impact: Option<String>,
+    /// 规则说明
+    pub description: String,
+    /// 简短的修复建议
+    pub help: String,
+    /// 规则文档地址
+    pub help_url: Option<String>,
+    /// 规则标签（WCAG 级别等）
+    pub tags: Vec<String>,
+    /// 命中的元素总数
+    pub node_count: usize,
+    /// 命中的元素（最多 `MAX_NODES_PER_VIOLATION` 个）
+    pub nodes: Vec<ViolationNode>,
+}
+
+/// 页面的无障碍扫描结果
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AccessibilityReport {
+    /// axe-core 版本
+    pub engine_version: Option<String>,
+    /// 扫描时限定的规则标签
+    pub tags: Vec<String>,
+    /// 违规的规则
+    pub violations: Vec<AccessibilityViolation>,
+    /// 按影响级别统计的违规元素数
+    pub impact_counts: BTreeMap<String, usize>,
+    /// 通过的规则数
+    pub passes: usize,
+    /// 需人工复核的规则数
+    pub incomplete: usize,
+}
+
+#[derive(Deserialize)]
+struct AxeResults {
+    version: Option<String>,
+    #[serde(default)]
+    passes: usize,
+    #[serde(default)]
+    incomplete: usize,
+    #[serde(default)]
+    violations: Vec<AxeViolation>,
+}
+
+#[derive(Deserialize)]
+struct AxeViolation {
+    id: String,
+    impact: Option<String>,
+    #[serde(default)]
+    description: String,
+    #[serde(default)]
+    help: String,
+    help_url: Option<String>,
+    #[serde(default)]
+    tags: Vec<String>,
+    #[serde(default)]
+    nodes: Vec<AxeNode>,
+}
+
+#[derive(Deserialize)]
+struct AxeNode {
+    #[serde(default)]
+    target: Value,
+    #[serde(default)]
+    html: String,
+    failure_summary: Option<String>,
+}
+
+/// axe 的 target 为每层 frame 一个选择器，Shadow DOM 内的元素再嵌套一层数组
+fn target_selector(target: &Value) -> String {
+    fn collect<'a>(value: &'a Value, parts: &mut Vec<&'a str>) {
+        match value {
+            Value::String(selector) => parts.push(selector),
+            Value::Array(items) => items.iter().for_each(|item| collect(item, parts)),
+            _ => {}
+        }
+    }
+    let mut parts = Vec::new();
+    collect(target, &mut parts);
+    parts.join(" >>> ")
+}
+
+impl AccessibilityReport {
+    /// 由 [`AccessibilityOptions::run_script`] 的返回值构建扫描结果
+    pub fn from_axe_results(value: Value, options: &AccessibilityOptions) -> Result<Self, String> {
+        let results: AxeResults = serde_json::from_value(value)
+            .map_err(|e| format!("Invalid axe-core results: {}", e))?;
+
+        let mut impact_counts = BTreeMap::new();
+        let violations = results
+            .violations
+            .into_iter()
+            .map(|violation| {
+                let node_count = violation.nodes.len();
+                if let Some(impact) = &violation.impact {
+                    *impact_counts.entry(impact.clone()).or_default() += node_count;
+                }
+                AccessibilityViolation {
+                    id: violation.id,
+                    impact: violation.impact,
+                    description: violation.description,
+                    help: violation.help,
+                    help_url: violation.help_url,
+                    tags: violation.tags,
+                    node_count,
+                    nodes: violation
+                        .nodes
+                        .into_iter()
+                        .take(MAX_NODES_PER_VIOLATION)
+                        .map(|node| ViolationNode {
+                            selector: target_selector(&node.target),
+                            html: node.html.chars().take(MAX_NODE_HTML_CHARS).collect(),
+                            failure_summary: node.failure_summary,
+                        })
+                        .collect(),
+                }
+            })
+            .collect();
+
+        Ok(Self {
+            engine_version: results.version,
+            tags: options.tags.clone(),
+            violations,
+            impact_counts,
+            passes: results.passes,
+            incomplete: results.incomplete,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_requests_accessibility() {
+        assert!(requests_accessibility(Some(&[
+            "markdown".to_string(),
+            " Accessibility ".to_string()
+        ])));
+        assert!(!requests_accessibility(Some(&["html".to_string()])));
+        assert!(!requests_accessibility(None));
+    }
+
+    #[test]
+    fn test_options_from_tags_and_run_script() {
+        let tags = vec![
+            "wcag2a".to_string(),
+            " wcag2aa ".to_string(),
+            "WCAG2A".to_string(),
+            "bad tag');".to_string(),
+        ];
+        let options = AccessibilityOptions::from_tags(Some(&tags));
+        assert_eq!(options.tags, vec!["wcag2a", "wcag2aa"]);
+        assert!(options
+            .run_script()
+            .contains(r#"runOnly: {type: 'tag', values: ["wcag2a","wcag2aa"]}"#));
+
+        let default = AccessibilityOptions::from_tags(None);
+        assert!(default.tags.is_empty());
+        assert!(!default.run_script().contains("runOnly"));
+    }
+
+    #[test]
+    fn test_report_from_axe_results() {
+        let results = json!({
+            "version": "4.10.2",
+            "passes": 31,
+            "incomplete": 2,
+            "violations": [
+                {
+                    "id": "image-alt",
+                    "impact": "critical",
+                    "description": "Ensures <img> elements have alternate text",
+                    "help": "Images must have alternate text",
+                    "help_url": "https://dequeuniversity.com/rules/axe/4.10/image-alt",
+                    "tags": ["wcag2a", "wcag111"],
+                    "nodes": [
+                        {"target": ["img.hero"], "html": "<img class=\"hero\" src=\"a.png\">", "failure_summary": "Fix any of the following"},
+                        {"target": ["#checkout-frame", ["my-widget", "img"]], "html": "<img src=\"b.png\">"}
+                    ]
+                },
+                {
+                    "id": "color-contrast",
+                    "impact": "serious",
+                    "description": "Ensures sufficient contrast",
+                    "help": "Elements must have sufficient color contrast",
+                    "nodes": [{"target": [".muted"], "html": "<p class=\"muted\">x</p>"}]
+                }
+            ]
+        });
+        let options = AccessibilityOptions::from_tags(Some(&["wcag2a".to_string()]));
+
+        let report = AccessibilityReport::from_axe_results(results, &options).unwrap();
+        assert_eq!(report.engine_version.as_deref(), Some("4.10.2"));
+        assert_eq!(report.tags, vec!["wcag2a"]);
+        assert_eq!((report.passes, report.incomplete), (31, 2));
+        assert_eq!(report.violations.len(), 2);
+        let image_alt = &report.violations[0];
+        assert_eq!(image_alt.node_count, 2);
+        assert_eq!(image_alt.nodes[0].selector, "img.hero");
+        assert_eq!(
+            image_alt.nodes[1].selector,
+            "#checkout-frame >>> my-widget >>> img"
+        );
+        assert_eq!(report.impact_counts["critical"], 2);
+        assert_eq!(report.impact_counts["serious"], 1);
+
+        assert!(AccessibilityReport::from_axe_results(json!("oops"), &options).is_err());
+    }
+}
@@ -0,0 +1,86 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! 无限滚动自动加载
+//!
+//! 浏览器引擎执行完页面动作后反复滚动到页面底部，每次滚动后等待网络空闲，
+//! 直到页面高度不再增长（没有新内容加载）或达到滚动次数上限，从而抓取完全展开的页面。
+
+/// `options.scroll.mode` 目前唯一支持的取值
+pub const AUTO_SCROLL_MODE: &str = "auto";
+
+/// 默认最大滚动次数
+pub const DEFAULT_MAX_SCROLLS: u32 = 20;
+
+/// 允许的最大滚动次数
+pub const MAX_SCROLLS_LIMIT: u32 = 200;
+
+/// 默认的网络空闲判定时长（毫秒）
+pub const DEFAULT_IDLE_MS: u64 = 1000;
+
+/// 允许的最大网络空闲判定时长（毫秒）
+pub const MAX_IDLE_MS: u64 = 30_000;
+
+/// 是否为支持的滚动模式（大小写不敏感）
+pub fn is_supported_scroll_mode(mode: &str) -> bool {
+    mode.trim().eq_ignore_ascii_case(AUTO_SCROLL_MODE)
+}
+
+/// 自动滚动配置
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AutoScroll {
+    /// 最大滚动次数
+    pub max_scrolls: u32,
+    /// 滚动后网络持续空闲多久才视为加载完成（毫秒）
+    pub idle_ms: u64,
+}
+
+impl Default for AutoScroll {
+    fn default() -> Self {
+        Self {
+            max_scrolls: DEFAULT_MAX_SCROLLS,
+            idle_ms: DEFAULT_IDLE_MS,
+        }
+    }
+}
+
+impl AutoScroll {
+    /// 由请求参数构建滚动配置，模式不是 `auto` 时返回 None
+    ///
+    /// 超出上限的取值会被截断到上限。
+    pub fn from_parts(mode: &str, max_scrolls: Option<u32>, idle_ms: Option<u64>) -> Option<Self> {
+        if !is_supported_scroll_mode(mode) {
+            return None;
+        }
+        Some(Self {
+            max_scrolls: max_scrolls
+                .unwrap_or(DEFAULT_MAX_SCROLLS)
+                .min(MAX_SCROLLS_LIMIT),
+            idle_ms: idle_ms.unwrap_or(DEFAULT_IDLE_MS).min(MAX_IDLE_MS),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_parts_applies_defaults_and_limits() {
+        assert_eq!(
+            AutoScroll::from_parts("auto", None, None),
+            Some(AutoScroll::default())
+        );
+        assert_eq!(
+            AutoScroll::from_parts(" Auto ", Some(5000), Some(250)),
+            Some(AutoScroll {
+                max_scrolls: MAX_SCROLLS_LIMIT,
+                idle_ms: 250,
+            })
+        );
+        assert!(AutoScroll::from_parts("manual", Some(3), None).is_none());
+        assert!(!is_supported_scroll_mode(""));
+    }
+}
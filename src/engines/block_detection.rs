@@ -0,0 +1,252 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! 反爬拦截识别
+//!
+//! 根据状态码、响应头与正文特征判断响应是否为反爬拦截页（Cloudflare 质询、PerimeterX、
+//! Akamai、DataDome、验证码页面、403 拦截页），而不是目标页面本身。识别结果写入
+//! `ScrapeResponse::blocked_by`，worker 据此将任务标记为拦截失败。
+//!
+//! 只检查正文开头 [`SCAN_LIMIT`] 字节，拦截页通常很小，特征都在开头。
+
+use std::collections::HashMap;
+
+/// 被拦截任务在 payload 中记录的 `failure_reason`
+pub const BLOCKED_FAILURE_REASON: &str = "blocked";
+
+/// 正文特征扫描的最大字节数
+pub const SCAN_LIMIT: usize = 64 * 1024;
+
+/// 验证码特征只在小于该字节数的页面上视为拦截，避免把带验证码表单的普通页面误判
+const CAPTCHA_PAGE_LIMIT: usize = 16 * 1024;
+
+/// 拦截来源
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BlockedBy {
+    /// Cloudflare 质询或拦截页
+    Cloudflare,
+    /// PerimeterX (HUMAN) 验证页
+    PerimeterX,
+    /// Akamai Bot Manager 拒绝页
+    Akamai,
+    /// DataDome 验证页
+    DataDome,
+    /// 其他验证码页面（reCAPTCHA、hCaptcha、Turnstile）
+    Captcha,
+    /// 未识别厂商的 403 拦截页
+    AccessDenied,
+}
+
+impl BlockedBy {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Cloudflare => "cloudflare",
+            Self::PerimeterX => "perimeterx",
+            Self::Akamai => "akamai",
+            Self::DataDome => "datadome",
+            Self::Captcha => "captcha",
+            Self::AccessDenied => "access_denied",
+        }
+    }
+}
+
+impl std::fmt::Display for BlockedBy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+const CLOUDFLARE_MARKERS: &[&str] = &[
+    "cf-browser-verification",
+    "cf_chl_opt",
+    "<title>just a moment...</title>",
+    "attention required! | cloudflare",
+];
+
+const PERIMETERX_MARKERS: &[&str] = &[
+    "px-captcha",
+    "_pxappid",
+    "captcha.px-cdn.net",
+    "client.perimeterx.net",
+];
+
+const DATADOME_MARKERS: &[&str] = &["captcha-delivery.com"];
+
+const AKAMAI_MARKERS: &[&str] = &["errors.edgesuite.net", "reference&#32;&#35;"];
+
+const CAPTCHA_MARKERS: &[&str] = &[
+    "g-recaptcha",
+    "www.google.com/recaptcha/",
+    "h-captcha",
+    "hcaptcha.com/1/api.js",
+    "challenges.cloudflare.com/turnstile",
+];
+
+const ACCESS_DENIED_MARKERS: &[&str] = &[
+    "access denied",
+    "you have been blocked",
+    "request blocked",
+    "unusual traffic",
+    "automated requests",
+    "are you a robot",
+    "bot detected",
+];
+
+/// 按名称（不区分大小写）查找响应头
+fn header<'a>(headers: &'a HashMap<String, String>, name: &str) -> Option<&'a str> {
+    headers
+        .iter()
+        .find(|(key, _)| key.eq_ignore_ascii_case(name))
+        .map(|(_, value)| value.as_str())
+}
+
+fn has_header(headers: &HashMap<String, String>, name: &str) -> bool {
+    header(headers, name).is_some()
+}
+
+/// 正文开头的小写副本，截断位置落在字符边界上
+fn scan_window(content: &str) -> String {
+    let mut end = content.len().min(SCAN_LIMIT);
+    while !content.is_char_boundary(end) {
+        end -= 1;
+    }
+    content[..end].to_ascii_lowercase()
+}
+
+fn contains_any(body: &str, markers: &[&str]) -> bool {
+    markers.iter().any(|marker| body.contains(marker))
+}
+
+/// 判断响应是否为反爬拦截页，返回拦截来源
+///
+/// 厂商特征优先于通用特征：先识别 Cloudflare、PerimeterX、DataDome、Akamai，
+/// 再识别小页面上的验证码，最后把带拦截文案的 403 归为 `AccessDenied`。
+/// 2xx 响应只在正文带有质询脚本等强特征时才视为拦截。
+pub fn detect_block(
+    status_code: u16,
+    headers: &HashMap<String, String>,
+    content: &str,
+) -> Option<BlockedBy> {
+    let body = scan_window(content);
+    let rejected = status_code == 403 || status_code == 429 || status_code == 503;
+
+    let server = header(headers, "server")
+        .map(str::to_ascii_lowercase)
+        .unwrap_or_default();
+
+    if header(headers, "cf-mitigated").is_some_and(|v| v.eq_ignore_ascii_case("challenge"))
+        || (contains_any(&body, CLOUDFLARE_MARKERS) && (rejected || server == "cloudflare"))
+    {
+        return Some(BlockedBy::Cloudflare);
+    }
+    if contains_any(&body, PERIMETERX_MARKERS) && (rejected || content.len() < CAPTCHA_PAGE_LIMIT) {
+        return Some(BlockedBy::PerimeterX);
+    }
+    if (has_header(headers, "x-datadome") && rejected) || contains_any(&body, DATADOME_MARKERS) {
+        return Some(BlockedBy::DataDome);
+    }
+    if status_code == 403 && (server.contains("akamaighost") || contains_any(&body, AKAMAI_MARKERS))
+    {
+        return Some(BlockedBy::Akamai);
+    }
+    if contains_any(&body, CAPTCHA_MARKERS) && (rejected || content.len() < CAPTCHA_PAGE_LIMIT) {
+        return Some(BlockedBy::Captcha);
+    }
+    if status_code == 403 && contains_any(&body, ACCESS_DENIED_MARKERS) {
+        return Some(BlockedBy::AccessDenied);
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn headers(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_detect_cloudflare_challenge() {
+        let body = "<html><head><title>Just a moment...</title></head>\
+                    <script>window._cf_chl_opt={}</script></html>";
+        assert_eq!(
+            detect_block(403, &headers(&[("Server", "cloudflare")]), body),
+            Some(BlockedBy::Cloudflare)
+        );
+        assert_eq!(
+            detect_block(200, &headers(&[("cf-mitigated", "challenge")]), ""),
+            Some(BlockedBy::Cloudflare)
+        );
+    }
+
+    #[test]
+    fn test_detect_vendor_pages() {
+        assert_eq!(
+            detect_block(403, &HashMap::new(), r#"<div id="px-captcha"></div>"#),
+            Some(BlockedBy::PerimeterX)
+        );
+        assert_eq!(
+            detect_block(
+                403,
+                &HashMap::new(),
+                r#"<script src="https://ct.captcha-delivery.com/c.js"></script>"#
+            ),
+            Some(BlockedBy::DataDome)
+        );
+        assert_eq!(
+            detect_block(
+                403,
+                &headers(&[("Server", "AkamaiGHost")]),
+                "<h1>Access Denied</h1>"
+            ),
+            Some(BlockedBy::Akamai)
+        );
+    }
+
+    #[test]
+    fn test_detect_captcha_and_access_denied() {
+        assert_eq!(
+            detect_block(
+                200,
+                &HashMap::new(),
+                r#"<form><div class="g-recaptcha"></div></form>"#
+            ),
+            Some(BlockedBy::Captcha)
+        );
+        assert_eq!(
+            detect_block(403, &HashMap::new(), "<h1>You have been blocked</h1>"),
+            Some(BlockedBy::AccessDenied)
+        );
+    }
+
+    #[test]
+    fn test_normal_pages_are_not_blocked() {
+        assert_eq!(
+            detect_block(200, &headers(&[("Server", "nginx")]), "<h1>Hello</h1>"),
+            None
+        );
+        // 普通 403（如需要登录）不视为拦截
+        assert_eq!(
+            detect_block(403, &HashMap::new(), "<h1>Forbidden</h1>"),
+            None
+        );
+        // 大页面上的验证码表单不视为拦截
+        let page = format!(
+            "<html>{}<div class=\"g-recaptcha\"></div></html>",
+            "<p>content</p>".repeat(2000)
+        );
+        assert_eq!(detect_block(200, &HashMap::new(), &page), None);
+    }
+
+    #[test]
+    fn test_scan_window_respects_char_boundary() {
+        let content = "é".repeat(SCAN_LIMIT);
+        assert!(scan_window(&content).len() <= SCAN_LIMIT);
+    }
+}
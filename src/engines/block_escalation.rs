@@ -0,0 +1,224 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! 被拦截后的引擎升级
+//!
+//! 响应被识别为反爬拦截页（见 [`detect_block`](super::block_detection::detect_block)）时，
+//! 路由器在同一次请求内按 TLS 指纹 → 浏览器 → 浏览器 + 代理 的顺序换用更高档位的引擎
+//! 重试，直到响应不再被拦截或升级次数用尽。升级请求使用 `max` 档位的回退链，并排除
+//! 不具备该步骤能力的引擎。请求本身已满足的步骤会被跳过（如需要 JS 渲染的请求不再尝试
+//! TLS 指纹引擎），未配置代理或请求已指定代理时跳过最后一步。每次尝试都记录在响应的
+//! `escalations` 中。
+
+use crate::engines::block_detection::BlockedBy;
+use crate::engines::engine_client::InternalScrapeRequest;
+use crate::engines::engine_tier::EngineTier;
+
+/// 升级步骤
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EscalationStep {
+    /// 首次请求（未升级）
+    Initial,
+    /// 带浏览器 TLS 指纹的 HTTP 引擎
+    TlsFingerprint,
+    /// 完整浏览器渲染
+    Browser,
+    /// 通过代理的完整浏览器渲染
+    BrowserProxy,
+}
+
+impl EscalationStep {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Initial => "initial",
+            Self::TlsFingerprint => "tls_fingerprint",
+            Self::Browser => "browser",
+            Self::BrowserProxy => "browser_proxy",
+        }
+    }
+}
+
+/// 升级路径中的一次尝试
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EscalationAttempt {
+    /// 尝试所用的步骤
+    pub step: EscalationStep,
+    /// 响应仍被拦截时的拦截来源
+    pub blocked_by: Option<BlockedBy>,
+    /// 该步骤没有拿到响应时的错误信息
+    pub error: Option<String>,
+}
+
+impl EscalationAttempt {
+    /// 响应未被拦截
+    pub fn succeeded(step: EscalationStep) -> Self {
+        Self {
+            step,
+            blocked_by: None,
+            error: None,
+        }
+    }
+
+    /// 响应仍被拦截
+    pub fn blocked(step: EscalationStep, blocked_by: BlockedBy) -> Self {
+        Self {
+            step,
+            blocked_by: Some(blocked_by),
+            error: None,
+        }
+    }
+
+    /// 没有拿到响应
+    pub fn failed(step: EscalationStep, error: impl Into<String>) -> Self {
+        Self {
+            step,
+            blocked_by: None,
+            error: Some(error.into()),
+        }
+    }
+
+    /// 结果标签（`succeeded` / `blocked` / `failed`），用于指标
+    pub fn outcome(&self) -> &'static str {
+        if self.error.is_some() {
+            "failed"
+        } else if self.blocked_by.is_some() {
+            "blocked"
+        } else {
+            "succeeded"
+        }
+    }
+}
+
+/// 被拦截后的升级策略
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlockEscalation {
+    /// 单次请求最多升级的次数
+    pub max_escalations: usize,
+    /// 最后一步使用的代理，未配置时跳过该步骤
+    pub proxy_url: Option<String>,
+}
+
+impl BlockEscalation {
+    pub fn new(max_escalations: usize, proxy_url: Option<String>) -> Self {
+        Self {
+            max_escalations,
+            proxy_url: proxy_url.filter(|url| !url.is_empty()),
+        }
+    }
+
+    /// 请求可用的升级步骤，按升级顺序排列并已按次数上限截断
+    pub fn steps_for(&self, request: &InternalScrapeRequest) -> Vec<EscalationStep> {
+        let rendered = request.needs_js || request.needs_screenshot || !request.actions.is_empty();
+        let mut steps = Vec::with_capacity(3);
+        if !rendered && !request.needs_tls_fingerprint {
+            steps.push(EscalationStep::TlsFingerprint);
+        }
+        if !rendered {
+            steps.push(EscalationStep::Browser);
+        }
+        if request.proxy.is_none() && self.proxy_url.is_some() {
+            steps.push(EscalationStep::BrowserProxy);
+        }
+        steps.truncate(self.max_escalations);
+        steps
+    }
+
+    /// 按步骤构造升级后的请求
+    pub fn escalated_request(
+        &self,
+        request: &InternalScrapeRequest,
+        step: EscalationStep,
+    ) -> InternalScrapeRequest {
+        let mut escalated = request.clone();
+        escalated.engine_tier = Some(EngineTier::Max);
+        match step {
+            EscalationStep::Initial => {}
+            EscalationStep::TlsFingerprint => {
+                escalated.needs_tls_fingerprint = true;
+            }
+            EscalationStep::Browser => {
+                escalated.needs_js = true;
+                escalated.needs_tls_fingerprint = false;
+            }
+            EscalationStep::BrowserProxy => {
+                escalated.needs_js = true;
+                escalated.needs_tls_fingerprint = false;
+                escalated.proxy = self.proxy_url.clone();
+            }
+        }
+        escalated
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engines::engine_client::ScrapeRequest;
+
+    fn request() -> InternalScrapeRequest {
+        ScrapeRequest::new("https://example.com").to_internal()
+    }
+
+    #[test]
+    fn test_steps_follow_ladder_and_budget() {
+        let escalation = BlockEscalation::new(3, Some("http://proxy:8080".to_string()));
+        assert_eq!(
+            escalation.steps_for(&request()),
+            vec![
+                EscalationStep::TlsFingerprint,
+                EscalationStep::Browser,
+                EscalationStep::BrowserProxy
+            ]
+        );
+
+        let escalation = BlockEscalation::new(1, None);
+        assert_eq!(
+            escalation.steps_for(&request()),
+            vec![EscalationStep::TlsFingerprint]
+        );
+    }
+
+    #[test]
+    fn test_steps_skip_what_the_request_already_does() {
+        let escalation = BlockEscalation::new(3, Some(String::new()));
+        let mut rendered = request();
+        rendered.needs_js = true;
+        assert!(escalation.steps_for(&rendered).is_empty());
+
+        let escalation = BlockEscalation::new(3, Some("http://proxy:8080".to_string()));
+        rendered.proxy = Some("http://own-proxy:8080".to_string());
+        assert!(escalation.steps_for(&rendered).is_empty());
+    }
+
+    #[test]
+    fn test_escalated_request_sets_step_options() {
+        let escalation = BlockEscalation::new(3, Some("http://proxy:8080".to_string()));
+        let tls = escalation.escalated_request(&request(), EscalationStep::TlsFingerprint);
+        assert!(tls.needs_tls_fingerprint);
+        assert!(!tls.needs_js);
+        assert_eq!(tls.engine_tier, Some(EngineTier::Max));
+
+        let proxied = escalation.escalated_request(&tls, EscalationStep::BrowserProxy);
+        assert!(proxied.needs_js);
+        assert!(!proxied.needs_tls_fingerprint);
+        assert_eq!(proxied.proxy.as_deref(), Some("http://proxy:8080"));
+    }
+
+    #[test]
+    fn test_attempt_outcome() {
+        assert_eq!(
+            EscalationAttempt::succeeded(EscalationStep::Browser).outcome(),
+            "succeeded"
+        );
+        assert_eq!(
+            EscalationAttempt::blocked(EscalationStep::Initial, BlockedBy::Akamai).outcome(),
+            "blocked"
+        );
+        assert_eq!(
+            EscalationAttempt::failed(EscalationStep::TlsFingerprint, "no engine").outcome(),
+            "failed"
+        );
+    }
+}
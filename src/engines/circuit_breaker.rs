@@ -3,6 +3,7 @@
 // Licensed under the Apache License, Version 2.0
 // See LICENSE file in the project root for full license information.
 
+use chrono::{DateTime, Utc};
 #[cfg(feature = "metrics")]
 use metrics::{counter, gauge};
 use parking_lot::RwLock;
@@ -42,6 +43,10 @@ struct CircuitState {
     failure_timestamps: VecDeque<Instant>,
     /// 上次失败时间
     last_failure: Option<Instant>,
+    /// 上次失败的时间（UTC，用于对外展示）
+    last_failure_at: Option<DateTime<Utc>>,
+    /// 上次熔断（进入打开状态）的时间
+    last_trip_at: Option<DateTime<Utc>>,
     // Statistics
     /// 总请求数
     total_requests: u64,
@@ -49,6 +54,8 @@ struct CircuitState {
     total_failures: u64,
     /// 总成功数
     total_successes: u64,
+    /// 熔断次数
+    total_trips: u64,
 }
 
 /// 熔断器状态枚举
@@ -62,6 +69,16 @@ pub enum Status {
     HalfOpen,
 }
 
+impl Status {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Closed => "closed",
+            Self::Open => "open",
+            Self::HalfOpen => "half_open",
+        }
+    }
+}
+
 /// 熔断器统计信息
 #[derive(Clone, Debug, Default)]
 pub struct CircuitStats {
@@ -75,6 +92,23 @@ pub struct CircuitStats {
     pub total_failures: u64,
     /// 总成功数
     pub total_successes: u64,
+    /// 熔断次数（进入打开状态的次数）
+    pub total_trips: u64,
+}
+
+/// 单个引擎熔断器的状态快照
+#[derive(Clone, Debug)]
+pub struct CircuitSnapshot {
+    /// 引擎名称
+    pub engine_name: String,
+    /// 当前状态（打开状态超过恢复时间后报告为半开）
+    pub status: Status,
+    /// 统计信息
+    pub stats: CircuitStats,
+    /// 上次失败时间
+    pub last_failure_at: Option<DateTime<Utc>>,
+    /// 上次熔断时间
+    pub last_trip_at: Option<DateTime<Utc>>,
 }
 
 /// 熔断器
@@ -144,9 +178,12 @@ impl CircuitBreaker {
             status: Status::Closed,
             failure_timestamps: VecDeque::new(),
             last_failure: None,
+            last_failure_at: None,
+            last_trip_at: None,
             total_requests: 0,
             total_failures: 0,
             total_successes: 0,
+            total_trips: 0,
         }
     }
 
@@ -247,6 +284,7 @@ impl CircuitBreaker {
         state.total_requests += 1;
         state.total_failures += 1;
         state.last_failure = Some(now);
+        state.last_failure_at = Some(Utc::now());
         state.failure_timestamps.push_back(now);
 
         // 移除超出时间窗口的失败记录
@@ -269,12 +307,18 @@ impl CircuitBreaker {
             Status::Closed => {
                 if state.failure_timestamps.len() >= config.failure_threshold as usize {
                     state.status = Status::Open;
+                    state.total_trips += 1;
+                    state.last_trip_at = state.last_failure_at;
                     self.update_status_metric(engine_name, Status::Open);
+                    crate::infrastructure::metrics::record_circuit_breaker_trip(engine_name);
                 }
             }
             Status::HalfOpen => {
                 state.status = Status::Open;
+                state.total_trips += 1;
+                state.last_trip_at = state.last_failure_at;
                 self.update_status_metric(engine_name, Status::Open);
+                crate::infrastructure::metrics::record_circuit_breaker_trip(engine_name);
             }
             Status::Open => {}
         }
@@ -298,12 +342,64 @@ impl CircuitBreaker {
                 total_requests: state.total_requests,
                 total_failures: state.total_failures,
                 total_successes: state.total_successes,
+                total_trips: state.total_trips,
             }
         } else {
             CircuitStats::default()
         }
     }
 
+    /// 所有已记录引擎的熔断器状态快照（按引擎名称排序）
+    pub fn snapshot(&self) -> Vec<CircuitSnapshot> {
+        let states = self.states.read();
+        let mut snapshots: Vec<CircuitSnapshot> = states
+            .iter()
+            .map(|(engine_name, state)| {
+                let recovered = state.last_failure.is_some_and(|last_failure| {
+                    last_failure.elapsed() > self.get_config(engine_name).recovery_timeout
+                });
+                let status = match state.status {
+                    Status::Open if recovered => Status::HalfOpen,
+                    status => status,
+                };
+                CircuitSnapshot {
+                    engine_name: engine_name.clone(),
+                    status,
+                    stats: CircuitStats {
+                        is_open: status == Status::Open,
+                        failure_count: state.failure_timestamps.len() as u32,
+                        total_requests: state.total_requests,
+                        total_failures: state.total_failures,
+                        total_successes: state.total_successes,
+                        total_trips: state.total_trips,
+                    },
+                    last_failure_at: state.last_failure_at,
+                    last_trip_at: state.last_trip_at,
+                }
+            })
+            .collect();
+        snapshots.sort_by(|a, b| a.engine_name.cmp(&b.engine_name));
+        snapshots
+    }
+
+    /// 手动重置熔断器为关闭状态并清空时间窗口内的失败记录，累计统计保留
+    ///
+    /// # 返回值
+    ///
+    /// 引擎没有熔断记录时返回 false
+    pub fn reset(&self, engine_name: &str) -> bool {
+        let mut states = self.states.write();
+        let Some(state) = states.get_mut(engine_name) else {
+            return false;
+        };
+        state.status = Status::Closed;
+        state.failure_timestamps.clear();
+        state.last_failure = None;
+        self.update_status_metric(engine_name, Status::Closed);
+        log::info!("Circuit breaker for engine {} manually reset", engine_name);
+        true
+    }
+
     /// 更新状态指标
     ///
     /// # 参数
@@ -851,6 +947,7 @@ mod tests {
         let stats = cb.get_stats("lifecycle").await;
         assert!(stats.is_open);
         assert_eq!(stats.total_failures, 3);
+        assert_eq!(stats.total_trips, 2);
     }
 
     // === Status enum tests ===
@@ -970,4 +1067,36 @@ mod tests {
         // Second call: status is already HalfOpen, hits line 202
         assert!(!cb.is_open("engine"));
     }
+
+    #[test]
+    fn test_snapshot_and_manual_reset() {
+        let cb = CircuitBreaker::with_default_config(CircuitConfig {
+            failure_threshold: 2,
+            recovery_timeout: Duration::from_secs(60),
+            failure_window: Duration::from_secs(60),
+        });
+        cb.record_success("reqwest");
+        cb.record_failure("playwright");
+        cb.record_failure("playwright");
+        assert!(!cb.reset("unknown"));
+
+        let snapshot = cb.snapshot();
+        assert_eq!(snapshot.len(), 2);
+        assert_eq!(snapshot[0].engine_name, "playwright");
+        assert_eq!(snapshot[0].status, Status::Open);
+        assert_eq!(snapshot[0].stats.failure_count, 2);
+        assert_eq!(snapshot[0].stats.total_trips, 1);
+        assert!(snapshot[0].last_trip_at.is_some());
+        assert_eq!(snapshot[1].status.as_str(), "closed");
+        assert!(snapshot[1].last_trip_at.is_none());
+
+        assert!(cb.reset("playwright"));
+        assert!(!cb.is_open("playwright"));
+        let snapshot = cb.snapshot();
+        assert_eq!(snapshot[0].status, Status::Closed);
+        assert_eq!(snapshot[0].stats.failure_count, 0);
+        // 累计统计与上次熔断时间保留
+        assert_eq!(snapshot[0].stats.total_trips, 1);
+        assert!(snapshot[0].last_trip_at.is_some());
+    }
 }
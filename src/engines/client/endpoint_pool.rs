@@ -0,0 +1,266 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! 远程浏览器服务端点池
+//!
+//! FlareSolverr / Fire Engine CDP 等远程浏览器服务可以配置多个端点分担负载。
+//! 请求按轮询顺序选择端点；连接失败的端点按指数退避暂停使用，退避到期后
+//! 先发送存活探测（liveness ping），探测成功即恢复使用。远程 Chromium 重启后
+//! 无需重启进程即可自动重连。
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// 首次失败后的退避时长
+pub const DEFAULT_BASE_BACKOFF: Duration = Duration::from_secs(1);
+
+/// 退避时长上限
+pub const DEFAULT_MAX_BACKOFF: Duration = Duration::from_secs(30);
+
+/// 单个端点的状态
+#[derive(Debug)]
+struct EndpointState {
+    url: String,
+    /// 连续失败次数，0 表示端点可用
+    consecutive_failures: u32,
+    /// 退避结束时间
+    retry_at: Option<Instant>,
+}
+
+/// 本次请求可尝试的端点
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EndpointCandidate {
+    /// 端点序号
+    pub index: usize,
+    /// 端点 URL
+    pub url: String,
+    /// 端点处于退避期后首次使用，需先探测存活
+    pub needs_ping: bool,
+}
+
+/// 端点状态快照
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EndpointStatus {
+    /// 端点 URL
+    pub url: String,
+    /// 是否可用
+    pub healthy: bool,
+    /// 连续失败次数
+    pub consecutive_failures: u32,
+}
+
+/// 端点池
+#[derive(Debug)]
+pub struct EndpointPool {
+    endpoints: Vec<Mutex<EndpointState>>,
+    next: AtomicUsize,
+    base_backoff: Duration,
+    max_backoff: Duration,
+}
+
+impl EndpointPool {
+    /// 使用默认退避参数创建端点池（重复的 URL 会被忽略）
+    pub fn new(urls: Vec<String>) -> Self {
+        Self::with_backoff(urls, DEFAULT_BASE_BACKOFF, DEFAULT_MAX_BACKOFF)
+    }
+
+    /// 使用自定义退避参数创建端点池
+    pub fn with_backoff(urls: Vec<String>, base_backoff: Duration, max_backoff: Duration) -> Self {
+        let mut unique: Vec<String> = Vec::with_capacity(urls.len());
+        for url in urls {
+            if !unique.contains(&url) {
+                unique.push(url);
+            }
+        }
+        Self {
+            endpoints: unique
+                .into_iter()
+                .map(|url| {
+                    Mutex::new(EndpointState {
+                        url,
+                        consecutive_failures: 0,
+                        retry_at: None,
+                    })
+                })
+                .collect(),
+            next: AtomicUsize::new(0),
+            base_backoff,
+            max_backoff,
+        }
+    }
+
+    /// 端点数量
+    pub fn len(&self) -> usize {
+        self.endpoints.len()
+    }
+
+    /// 是否没有任何端点
+    pub fn is_empty(&self) -> bool {
+        self.endpoints.is_empty()
+    }
+
+    /// 按轮询顺序返回本次请求的候选端点
+    ///
+    /// 可用端点在前，退避已到期、需要先探测的端点在后；仍在退避期内的端点不返回。
+    pub fn candidates(&self) -> Vec<EndpointCandidate> {
+        if self.endpoints.is_empty() {
+            return Vec::new();
+        }
+        let now = Instant::now();
+        let start = self.next.fetch_add(1, Ordering::Relaxed) % self.endpoints.len();
+
+        let mut ready = Vec::new();
+        let mut recovering = Vec::new();
+        for offset in 0..self.endpoints.len() {
+            let index = (start + offset) % self.endpoints.len();
+            let state = self.lock(index);
+            match state.retry_at {
+                None => ready.push(EndpointCandidate {
+                    index,
+                    url: state.url.clone(),
+                    needs_ping: false,
+                }),
+                Some(retry_at) if retry_at <= now => recovering.push(EndpointCandidate {
+                    index,
+                    url: state.url.clone(),
+                    needs_ping: true,
+                }),
+                Some(_) => {}
+            }
+        }
+        ready.extend(recovering);
+        ready
+    }
+
+    /// 记录端点请求成功，恢复为可用
+    pub fn mark_success(&self, index: usize) {
+        if index >= self.endpoints.len() {
+            return;
+        }
+        let mut state = self.lock(index);
+        if state.consecutive_failures > 0 {
+            log::info!(
+                "Endpoint {} recovered after {} failures",
+                state.url,
+                state.consecutive_failures
+            );
+        }
+        state.consecutive_failures = 0;
+        state.retry_at = None;
+    }
+
+    /// 记录端点连接失败，返回本次的退避时长
+    pub fn mark_failure(&self, index: usize) -> Duration {
+        if index >= self.endpoints.len() {
+            return Duration::ZERO;
+        }
+        let mut state = self.lock(index);
+        state.consecutive_failures = state.consecutive_failures.saturating_add(1);
+        let backoff = self.backoff(state.consecutive_failures);
+        state.retry_at = Some(Instant::now() + backoff);
+        log::warn!(
+            "Endpoint {} unavailable ({} consecutive failures), retrying in {:?}",
+            state.url,
+            state.consecutive_failures,
+            backoff
+        );
+        backoff
+    }
+
+    /// 所有端点的状态快照
+    pub fn status(&self) -> Vec<EndpointStatus> {
+        (0..self.endpoints.len())
+            .map(|index| {
+                let state = self.lock(index);
+                EndpointStatus {
+                    url: state.url.clone(),
+                    healthy: state.retry_at.is_none(),
+                    consecutive_failures: state.consecutive_failures,
+                }
+            })
+            .collect()
+    }
+
+    /// 第 `failures` 次连续失败的退避时长：`base * 2^(failures - 1)`，不超过上限
+    fn backoff(&self, failures: u32) -> Duration {
+        let exponent = failures.saturating_sub(1).min(16);
+        self.base_backoff
+            .saturating_mul(1u32 << exponent)
+            .min(self.max_backoff)
+    }
+
+    fn lock(&self, index: usize) -> std::sync::MutexGuard<'_, EndpointState> {
+        self.endpoints[index]
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn urls(list: &[&str]) -> Vec<String> {
+        list.iter().map(|url| url.to_string()).collect()
+    }
+
+    #[test]
+    fn test_candidates_rotate_and_skip_backing_off_endpoints() {
+        let pool = EndpointPool::new(urls(&["http://a:8191", "http://b:8191", "http://a:8191"]));
+        assert_eq!(pool.len(), 2);
+
+        let first = pool.candidates();
+        let second = pool.candidates();
+        assert_eq!(first.len(), 2);
+        assert_ne!(first[0].url, second[0].url);
+        assert!(first.iter().all(|candidate| !candidate.needs_ping));
+
+        pool.mark_failure(0);
+        let candidates = pool.candidates();
+        assert_eq!(candidates.len(), 1);
+        assert_eq!(candidates[0].url, "http://b:8191");
+
+        let status = pool.status();
+        assert!(!status[0].healthy);
+        assert_eq!(status[0].consecutive_failures, 1);
+        assert!(status[1].healthy);
+    }
+
+    #[test]
+    fn test_expired_backoff_requires_ping_and_success_recovers() {
+        let pool = EndpointPool::with_backoff(
+            urls(&["http://a:8191", "http://b:8191"]),
+            Duration::ZERO,
+            Duration::ZERO,
+        );
+        pool.mark_failure(1);
+
+        // 已到期的端点排在可用端点之后，并需要先探测
+        for _ in 0..2 {
+            let candidates = pool.candidates();
+            assert_eq!(candidates.len(), 2);
+            assert_eq!(candidates[0].url, "http://a:8191");
+            assert!(!candidates[0].needs_ping);
+            assert!(candidates[1].needs_ping);
+        }
+
+        pool.mark_success(1);
+        assert!(pool.status().iter().all(|status| status.healthy));
+    }
+
+    #[test]
+    fn test_backoff_grows_exponentially_up_to_cap() {
+        let pool = EndpointPool::with_backoff(
+            urls(&["http://a:8191"]),
+            Duration::from_secs(1),
+            Duration::from_secs(10),
+        );
+        let backoffs: Vec<u64> = (0..5).map(|_| pool.mark_failure(0).as_secs()).collect();
+        assert_eq!(backoffs, vec![1, 2, 4, 8, 10]);
+        assert!(pool.candidates().is_empty());
+        assert_eq!(pool.mark_failure(7), Duration::ZERO);
+    }
+}
@@ -23,6 +23,10 @@ pub mod playwright_pool;
 #[cfg(feature = "engine-flaresolverr")]
 pub mod flare_solverr;
 
+/// 远程浏览器服务端点池（多端点轮询、失败退避与自动重连）
+#[cfg(feature = "engine-flaresolverr")]
+pub mod endpoint_pool;
+
 /// 共享的 FlareSolverr 类型定义
 #[cfg(feature = "engine-flaresolverr")]
 pub mod flaresolverr_types;
@@ -3,20 +3,61 @@
 // Licensed under the Apache License, Version 2.0
 // See LICENSE file in the project root for full license information.
 
+use crate::engines::accessibility::{axe_source, AccessibilityOptions, AccessibilityReport};
+use crate::engines::auto_scroll::AutoScroll;
 use crate::engines::browser_downloader::{BrowserDownloadConfig, BrowserDownloadManager};
-use crate::engines::client::playwright_pool::{get_global_pool, BrowserPool, BrowserPoolConfig};
+use crate::engines::client::playwright_pool::{
+    get_global_pool, init_global_pool, BrowserPool, BrowserPoolConfig,
+};
 use crate::engines::engine_client::{
-    EngineError, InternalPageAction, InternalScrapeRequest, InternalScrapeResponse,
-    InternalScreenshotConfig, ScraperEngine,
+    ActionErrorPolicy, EngineError, InternalPageAction, InternalScrapeRequest,
+    InternalScrapeResponse, InternalScreenshotConfig, ScraperEngine,
+};
+use crate::engines::har::HarRecorder;
+use crate::engines::iframe::{
+    inline_frames, is_same_origin, FrameContent, IframeCapture, IframeMode,
+};
+use crate::engines::pdf::{mm_to_inches, PdfOptions};
+use crate::engines::performance::{PageTimings, PAGE_TIMING_SCRIPT};
+use crate::engines::proxy_url::{
+    browser_proxy, proxy_server_arg, redact_proxy_url, ProxyCredentials,
+};
+use crate::engines::resource_blocking::ResourceBlocking;
+use crate::engines::screenshot::{
+    plan_segments, stitch_segments, PageMetrics, ScreenshotFormat, MAX_SEGMENT_HEIGHT,
 };
 use crate::engines::validators;
 use crate::infrastructure::services::config_service::BrowserConfigTrait;
 use async_trait::async_trait;
 use base64::{engine::general_purpose::STANDARD as BASE64, Engine as _};
-use chromiumoxide::cdp::browser_protocol::page::CaptureScreenshotFormat;
+use bytes::Bytes;
+use chromiumoxide::cdp::browser_protocol::fetch::{
+    AuthChallengeResponse, AuthChallengeResponseResponse, AuthChallengeSource,
+    ContinueRequestParams, ContinueWithAuthParams, EnableParams as FetchEnableParams,
+    EventAuthRequired, EventRequestPaused, FailRequestParams,
+};
+use chromiumoxide::cdp::browser_protocol::input::{
+    DispatchKeyEventParams, DispatchKeyEventType, DispatchMouseEventParams, DispatchMouseEventType,
+    MouseButton,
+};
+use chromiumoxide::cdp::browser_protocol::network::{
+    ErrorReason, EventLoadingFailed, EventLoadingFinished, EventRequestWillBeSent,
+    EventResponseReceived,
+};
+use chromiumoxide::cdp::browser_protocol::page::{
+    CaptureScreenshotFormat, CaptureSnapshotFormat, CaptureSnapshotParams, PrintToPdfParams,
+    Viewport,
+};
+use chromiumoxide::cdp::browser_protocol::target::{
+    BrowserContextId, CreateBrowserContextParams, CreateTargetParams, DisposeBrowserContextParams,
+};
+use chromiumoxide::cdp::js_protocol::runtime::EvaluateParams;
+use chromiumoxide::layout::Point;
+use chromiumoxide::page::Page;
 use chromiumoxide::{Browser, BrowserConfig};
 use futures::StreamExt;
 use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
 
@@ -204,8 +245,9 @@ impl PlaywrightBrowserManagerComponent {
             builder = builder.arg("--disable-gpu").arg("--disable-dev-shm-usage");
 
             if let Some(ref proxy) = proxy_url {
-                log::info!("Using proxy for Playwright: {}", proxy);
-                builder = builder.arg(format!("--proxy-server={}", proxy));
+                let server = proxy_server_arg(proxy);
+                log::info!("Using proxy for Playwright: {}", redact_proxy_url(&server));
+                builder = builder.arg(format!("--proxy-server={}", server));
             }
 
             Browser::launch(
@@ -310,12 +352,11 @@ impl PlaywrightEngine {
             return pool.clone();
         }
 
-        // 创建临时池（不推荐，应该使用全局池）
-        let config = BrowserPoolConfig::default();
+        // 全局池尚未初始化时使用默认配置初始化，保证所有请求共享同一个池
         let browser_config = Arc::new(
             crate::infrastructure::services::config_service::BrowserConfigComponent::default(),
         );
-        BrowserPool::new(config, browser_config)
+        init_global_pool(BrowserPoolConfig::default(), browser_config).clone()
     }
 }
 
@@ -325,8 +366,7 @@ impl Default for PlaywrightEngine {
     }
 }
 
-#[async_trait]
-impl ScraperEngine for PlaywrightEngine {
+impl PlaywrightEngine {
     /// 执行浏览器自动化抓取
     ///
     /// # 参数
@@ -337,7 +377,7 @@ impl ScraperEngine for PlaywrightEngine {
     ///
     /// * `Ok(InternalScrapeResponse)` - 抓取响应
     /// * `Err(EngineError)` - 抓取过程中出现的错误
-    async fn scrape(
+    async fn execute_scrape(
         &self,
         request: &InternalScrapeRequest,
     ) -> Result<InternalScrapeResponse, EngineError> {
@@ -349,8 +389,14 @@ impl ScraperEngine for PlaywrightEngine {
             .await
             .map_err(|e| EngineError::Other(format!("SSRF protection: {}", e)))?;
 
-        // Only run if specifically requested for JS or screenshot
-        if !request.needs_js && !request.needs_screenshot {
+        // Only run if specifically requested for JS, screenshot, HAR, MHTML, PDF or an accessibility scan
+        if !request.needs_js
+            && !request.needs_screenshot
+            && !request.capture_har
+            && !request.capture_mhtml
+            && request.pdf.is_none()
+            && request.accessibility.is_none()
+        {
             return Err(EngineError::AllEnginesFailed(
                 "PlaywrightEngine only supports JS and screenshot requests".to_string(),
             ));
@@ -365,14 +411,32 @@ impl ScraperEngine for PlaywrightEngine {
         // Wrap the entire operation in a timeout
         tokio::time::timeout(timeout_duration, async {
             // 从池中获取浏览器实例
-            let browser_instance = pool.acquire().await?;
+            let mut browser_instance = pool.acquire().await?;
             let browser = browser_instance.browser();
 
+            // 请求级代理通过独立的浏览器上下文生效；认证信息（请求级或启动时的全局代理）
+            // 在代理认证质询时提供
+            let request_proxy = request
+                .proxy
+                .as_deref()
+                .map(browser_proxy)
+                .transpose()
+                .map_err(|e| EngineError::Other(format!("Invalid proxy: {}", e)))?;
+            let proxy_context = match &request_proxy {
+                Some(proxy) => Some(ProxyBrowserContext::create(browser, &proxy.server).await?),
+                None => None,
+            };
+            let proxy_credentials = match request_proxy {
+                Some(proxy) => proxy.credentials,
+                None => pool.proxy_credentials(),
+            };
+
             // Create new page and navigate
-            let page: chromiumoxide::page::Page = browser
-                .new_page("about:blank")
-                .await
-                .map_err(|e| EngineError::BrowserError(e.to_string()))?;
+            let page: chromiumoxide::page::Page = match &proxy_context {
+                Some(context) => context.new_page().await,
+                None => browser.new_page("about:blank").await,
+            }
+            .map_err(|e| EngineError::BrowserError(e.to_string()))?;
 
             // Note: Page is intentionally not closed here to allow for reuse.
             // Browser will be closed when application shuts down.
@@ -391,6 +455,27 @@ impl ScraperEngine for PlaywrightEngine {
                 log::warn!("Custom headers are currently partially supported in PlaywrightEngine due to API constraints");
             }
 
+            // 资源拦截与代理认证同样需在导航前启用
+            let interception = if request.resource_blocking.is_some() || proxy_credentials.is_some() {
+                Some(
+                    start_request_interception(
+                        &page,
+                        request.resource_blocking.clone(),
+                        proxy_credentials,
+                    )
+                    .await?,
+                )
+            } else {
+                None
+            };
+
+            // HAR 记录需在导航前订阅网络事件
+            let har_capture = if request.capture_har {
+                Some(start_har_capture(&page).await?)
+            } else {
+                None
+            };
+
             // Navigate and wait for load
             // goto waits for the load event by default
             page.goto(&request.url).await
@@ -407,6 +492,12 @@ impl ScraperEngine for PlaywrightEngine {
                 .await
                 .map_err(|e| EngineError::BrowserError(e.to_string()))?;
 
+            // 性能计时在页面动作之前读取，反映页面本身的加载过程
+            let timings = read_page_timings(&page).await;
+            if timings.is_none() {
+                log::debug!("No performance timings available for {}", request.url);
+            }
+
             if content.contains("如果您在几秒钟内没有被重定向") || 
                content.contains("Having trouble accessing Google") ||
                content.contains("enablejs") {
@@ -415,68 +506,39 @@ impl ScraperEngine for PlaywrightEngine {
             }
 
             // 执行页面交互动作
-            for action in &request.actions {
-                match action {
-                    InternalPageAction::Wait { milliseconds } => {
-                        tokio::time::sleep(Duration::from_millis(*milliseconds)).await;
-                    }
-                    InternalPageAction::Click { selector } => {
-                        let element: chromiumoxide::element::Element = page
-                            .find_element(selector)
-                            .await
-                            .map_err(|e| {
-                                EngineError::BrowserError(format!(
-                                    "Click failed, element not found: {}",
-                                    e
-                                ))
-                            })?;
-                        element
-                            .click()
-                            .await
-                            .map_err(|e| EngineError::BrowserError(format!("Click failed: {}", e)))?;
-                    }
-                    InternalPageAction::Scroll { direction } => {
-                        let script = match direction.as_str() {
-                            "down" => "window.scrollBy(0, window.innerHeight);",
-                            "up" => "window.scrollBy(0, -window.innerHeight);",
-                            "bottom" => "window.scrollTo(0, document.body.scrollHeight);",
-                            "top" => "window.scrollTo(0, 0);",
-                            _ => "window.scrollBy(0, window.innerHeight);",
-                        };
-                        let _: chromiumoxide::js::EvaluationResult = page
-                            .evaluate(script)
-                            .await
-                            .map_err(|e| EngineError::BrowserError(format!("Scroll failed: {}", e)))?;
-                    }
-                    InternalPageAction::Screenshot { full_page: _ } => {
-                        // 此处动作生成的截图暂不直接返回，仅作为交互过程的一部分
-                        // 如果需要保存，可能需要额外的逻辑处理
-                    }
-                    InternalPageAction::Input { selector, text } => {
-                        let element: chromiumoxide::element::Element = page
-                            .find_element(selector)
-                            .await
-                            .map_err(|e| {
-                                EngineError::BrowserError(format!(
-                                    "Input failed, element not found: {}",
-                                    e
-                                ))
-                            })?;
-                        element
-                            .type_str(text)
-                            .await
-                            .map_err(|e| EngineError::BrowserError(format!("Input failed: {}", e)))?;
+            let mut evaluate_results = Vec::new();
+            for (index, action) in request.actions.iter().enumerate() {
+                if let Err(e) = perform_action(&page, action, &mut evaluate_results).await {
+                    let policy = request
+                        .action_error_policies
+                        .get(index)
+                        .copied()
+                        .unwrap_or_default();
+                    if policy == ActionErrorPolicy::Abort {
+                        return Err(e);
                     }
+                    log::warn!(
+                        "Action {} failed on {}, continuing: {}",
+                        index + 1,
+                        request.url,
+                        e
+                    );
                 }
             }
 
+            // 无限滚动：动作执行完后持续滚动到底部，直到没有新内容加载
+            if let Some(config) = request.auto_scroll {
+                let scrolls = auto_scroll(&page, config).await?;
+                log::debug!("Auto-scrolled {} {} time(s)", request.url, scrolls);
+            }
+
             // 同步等待
             if request.sync_wait_ms > 0 {
                 tokio::time::sleep(Duration::from_millis(request.sync_wait_ms as u64)).await;
             }
 
             // Get final URL after navigation (handles redirects)
-            let _final_url: String = page
+            let final_url: String = page
                 .url()
                 .await
                 .ok()
@@ -504,10 +566,35 @@ impl ScraperEngine for PlaywrightEngine {
             // For most scraping use cases, 200 is the expected success status
             let status_code = 200;
 
-            let content: String = page
-                .content()
-                .await
-                .map_err(|e| EngineError::BrowserError(e.to_string()))?;
+            let content: String = if request.flatten_shadow_dom {
+                let shadow_error =
+                    |e: String| EngineError::BrowserError(format!("Shadow DOM capture failed: {}", e));
+                page.evaluate(FLATTEN_SHADOW_DOM_SCRIPT)
+                    .await
+                    .map_err(|e| shadow_error(e.to_string()))?
+                    .into_value::<String>()
+                    .map_err(|e| shadow_error(e.to_string()))?
+            } else {
+                page.content()
+                    .await
+                    .map_err(|e| EngineError::BrowserError(e.to_string()))?
+            };
+
+            let frame_script = if request.flatten_shadow_dom {
+                FLATTEN_SHADOW_DOM_SCRIPT
+            } else {
+                FRAME_HTML_SCRIPT
+            };
+            let frames = match request.iframe_capture {
+                Some(capture) => capture_frames(&page, &final_url, capture, frame_script).await?,
+                None => Vec::new(),
+            };
+            let content = match request.iframe_capture {
+                Some(capture) if capture.mode == IframeMode::Inline => {
+                    inline_frames(&content, &frames)
+                }
+                _ => content,
+            };
 
             // Build headers from available document information
             let response_headers = {
@@ -525,18 +612,10 @@ impl ScraperEngine for PlaywrightEngine {
                     selector: None,
                     quality: Some(80),
                     format: Some("jpeg".to_string()),
+                    max_height: None,
                 });
-
-                let format = match config.format.as_deref() {
-                    Some("png") => CaptureScreenshotFormat::Png,
-                    _ => CaptureScreenshotFormat::Jpeg,
-                };
-
-                let params = chromiumoxide::page::ScreenshotParams::builder()
-                    .format(format)
-                    .quality(config.quality.unwrap_or(80) as i64)
-                    .full_page(config.full_page)
-                    .build();
+                let format = ScreenshotFormat::from_name(config.format.as_deref());
+                let quality = config.quality.unwrap_or(80);
 
                 let screenshot_bytes = if let Some(selector) = &config.selector {
                     // Find element and screenshot
@@ -545,16 +624,16 @@ impl ScraperEngine for PlaywrightEngine {
                         .await
                         .map_err(|e| EngineError::BrowserError(format!("Element not found: {}", e)))?;
 
-                    // Create new format instance for element screenshot since original was moved
-                    let element_format = match config.format.as_deref() {
-                        Some("png") => CaptureScreenshotFormat::Png,
-                        _ => CaptureScreenshotFormat::Jpeg,
-                    };
-
-                    element.screenshot(element_format).await
+                    element.screenshot(cdp_screenshot_format(format)).await
                         .map_err(|e| EngineError::BrowserError(format!("Element screenshot failed: {}", e)))?
+                } else if config.full_page {
+                    capture_full_page(&page, format, quality, config.max_height).await?
                 } else {
-                    // Page screenshot
+                    // Viewport screenshot
+                    let params = chromiumoxide::page::ScreenshotParams::builder()
+                        .format(cdp_screenshot_format(format))
+                        .quality(quality as i64)
+                        .build();
                     page.screenshot(params).await
                         .map_err(|e| EngineError::BrowserError(format!("Page screenshot failed: {}", e)))?
                 };
@@ -562,6 +641,51 @@ impl ScraperEngine for PlaywrightEngine {
                 screenshot = Some(BASE64.encode(screenshot_bytes));
             }
 
+            let mhtml = if request.capture_mhtml {
+                Some(capture_mhtml(&page).await?)
+            } else {
+                None
+            };
+
+            let pdf = match &request.pdf {
+                Some(options) => Some(print_pdf(&page, options).await?),
+                None => None,
+            };
+
+            let accessibility = match &request.accessibility {
+                Some(options) => Some(run_accessibility_scan(&page, options).await?),
+                None => None,
+            };
+
+            let har = har_capture.map(|(recorder, tasks)| {
+                for task in tasks {
+                    task.abort();
+                }
+                let recorder = recorder.lock().unwrap_or_else(|e| e.into_inner());
+                log::debug!("Captured {} network entries for {}", recorder.len(), request.url);
+                recorder.to_har(&request.url)
+            });
+
+            let blocked_requests = interception.and_then(|(blocked, tasks)| {
+                for task in tasks {
+                    task.abort();
+                }
+                request
+                    .resource_blocking
+                    .as_ref()
+                    .map(|_| blocked.load(Ordering::Relaxed))
+            });
+
+            // 记录页面 JS 堆大小，供浏览器池判断实例内存是否持续增长
+            let heap_size = page
+                .evaluate(PAGE_HEAP_SCRIPT)
+                .await
+                .ok()
+                .and_then(|result| result.into_value::<u64>().ok());
+            if let Some(bytes) = heap_size {
+                browser_instance.record_heap_size(bytes);
+            }
+
             // 关闭页面（但保留浏览器实例供复用）
             let _ = page.close().await;
 
@@ -575,11 +699,998 @@ impl ScraperEngine for PlaywrightEngine {
                 content_type: "text/html".to_string(),
                 headers: response_headers,
                 response_time_ms: start.elapsed().as_millis() as u64,
+                truncated: false,
+                raw_content: None,
+                http_version: None,
+                tls_profile: None,
+                har,
+                mhtml,
+                pdf,
+                blocked_requests,
+                evaluate_results,
+                frames,
+                accessibility,
+                timings,
+                ip_family: None,
+                escalations: Vec::new(),
             })
         })
             .await
             .map_err(|_| EngineError::Timeout(timeout_duration))?
     }
+}
+
+/// 读取页面的导航与绘制计时，浏览器不支持或求值失败时返回 None
+async fn read_page_timings(page: &Page) -> Option<PageTimings> {
+    let params = EvaluateParams::builder()
+        .expression(PAGE_TIMING_SCRIPT)
+        .await_promise(true)
+        .return_by_value(true)
+        .build()
+        .ok()?;
+    let response = page.execute(params).await.ok()?;
+    PageTimings::from_script_result(response.result.result.value.clone()?)
+}
+
+/// 注入 axe-core 并扫描页面的无障碍问题
+///
+/// 通过 CDP 求值注入脚本，不受页面 CSP 限制；扫描范围为主文档（含开放的 Shadow DOM）。
+async fn run_accessibility_scan(
+    page: &Page,
+    options: &AccessibilityOptions,
+) -> Result<AccessibilityReport, EngineError> {
+    let scan_error =
+        |e: String| EngineError::BrowserError(format!("Accessibility scan failed: {}", e));
+    let source = axe_source().map_err(scan_error)?;
+
+    let inject = EvaluateParams::builder()
+        .expression(source)
+        .build()
+        .map_err(scan_error)?;
+    page.execute(inject)
+        .await
+        .map_err(|e| scan_error(e.to_string()))?;
+    let params = EvaluateParams::builder()
+        .expression(options.run_script())
+        .await_promise(true)
+        .return_by_value(true)
+        .build()
+        .map_err(scan_error)?;
+    let response = page
+        .execute(params)
+        .await
+        .map_err(|e| scan_error(e.to_string()))?;
+    if let Some(exception) = &response.result.exception_details {
+        return Err(scan_error(exception.text.clone()));
+    }
+    let value = response.result.result.value.clone().unwrap_or_default();
+    AccessibilityReport::from_axe_results(value, options).map_err(scan_error)
+}
+
+/// 读取页面 JS 堆已用大小（字节）的脚本，非 Chromium 环境返回 0
+const PAGE_HEAP_SCRIPT: &str = "performance.memory ? performance.memory.usedJSHeapSize : 0";
+
+/// frame 中读取文档 HTML 的脚本
+const FRAME_HTML_SCRIPT: &str =
+    "document.documentElement ? document.documentElement.outerHTML : ''";
+
+/// 按组合树（composed tree）序列化文档的脚本
+///
+/// 开放的 shadow root 内容直接作为宿主元素的子节点输出，`<slot>` 替换为分配给它的节点，
+/// 使 Web Components 页面的内容对 CSS 选择器可见。封闭的 shadow root 无法访问。
+const FLATTEN_SHADOW_DOM_SCRIPT: &str = r#"(() => {
+    const VOID = new Set(['area', 'base', 'br', 'col', 'embed', 'hr', 'img', 'input', 'link', 'meta', 'source', 'track', 'wbr']);
+    const RAW = new Set(['script', 'style']);
+    const escapeText = (s) => s.replace(/&/g, '&amp;').replace(/</g, '&lt;').replace(/>/g, '&gt;');
+    const escapeAttr = (s) => s.replace(/&/g, '&amp;').replace(/"/g, '&quot;');
+    const serialize = (node) => {
+        if (node.nodeType === Node.TEXT_NODE) {
+            const parent = node.parentNode && node.parentNode.localName;
+            return RAW.has(parent) ? node.data : escapeText(node.data);
+        }
+        if (node.nodeType === Node.DOCUMENT_FRAGMENT_NODE) {
+            return Array.from(node.childNodes).map(serialize).join('');
+        }
+        if (node.nodeType !== Node.ELEMENT_NODE) {
+            return '';
+        }
+        const tag = node.localName;
+        if (tag === 'slot') {
+            const assigned = node.assignedNodes();
+            if (assigned.length) {
+                return assigned.map(serialize).join('');
+            }
+        }
+        const attrs = Array.from(node.attributes)
+            .map((a) => ` ${a.name}="${escapeAttr(a.value)}"`)
+            .join('');
+        if (VOID.has(tag)) {
+            return `<${tag}${attrs}>`;
+        }
+        const children = node.shadowRoot
+            ? [node.shadowRoot]
+            : tag === 'template' ? [node.content] : Array.from(node.childNodes);
+        return `<${tag}${attrs}>${children.map(serialize).join('')}</${tag}>`;
+    };
+    const root = document.documentElement;
+    return root ? '<!DOCTYPE html>' + serialize(root) : '';
+})()"#;
+
+/// 读取页面所有子 frame 的 HTML
+///
+/// 在各 frame 自己的执行上下文中求值，因此跨域 frame 也可读取；
+/// 未开启 `cross_origin` 时跳过与页面不同源的 frame。
+async fn capture_frames(
+    page: &Page,
+    page_url: &str,
+    capture: IframeCapture,
+    html_script: &str,
+) -> Result<Vec<FrameContent>, EngineError> {
+    let main_frame = page
+        .mainframe()
+        .await
+        .map_err(|e| EngineError::BrowserError(format!("Failed to list frames: {}", e)))?;
+    let frame_ids = page
+        .frames()
+        .await
+        .map_err(|e| EngineError::BrowserError(format!("Failed to list frames: {}", e)))?;
+
+    let mut frames = Vec::new();
+    for frame_id in frame_ids {
+        if main_frame.as_ref() == Some(&frame_id) {
+            continue;
+        }
+        let url = page
+            .frame_url(frame_id.clone())
+            .await
+            .ok()
+            .flatten()
+            .unwrap_or_default();
+        if !capture.cross_origin && !is_same_origin(page_url, &url) {
+            log::debug!("Skipping cross-origin frame {}", url);
+            continue;
+        }
+        let name = page
+            .frame_name(frame_id.clone())
+            .await
+            .ok()
+            .flatten()
+            .unwrap_or_default();
+        // frame 尚未创建执行上下文（如仍在加载）时跳过
+        let Some(context_id) = page
+            .frame_execution_context(frame_id.clone())
+            .await
+            .ok()
+            .flatten()
+        else {
+            log::debug!("Frame {} has no execution context yet", url);
+            continue;
+        };
+
+        let params = EvaluateParams::builder()
+            .expression(html_script)
+            .context_id(context_id)
+            .return_by_value(true)
+            .build()
+            .map_err(EngineError::BrowserError)?;
+        let html = match page.execute(params).await {
+            Ok(response) => response
+                .result
+                .result
+                .value
+                .as_ref()
+                .and_then(|value| value.as_str())
+                .unwrap_or_default()
+                .to_string(),
+            Err(e) => {
+                log::warn!("Failed to read frame {}: {}", url, e);
+                continue;
+            }
+        };
+        frames.push(FrameContent { name, url, html });
+    }
+    Ok(frames)
+}
+
+/// 执行单个页面交互动作，`Evaluate` 的返回值追加到 `evaluate_results`
+async fn perform_action(
+    page: &Page,
+    action: &InternalPageAction,
+    evaluate_results: &mut Vec<serde_json::Value>,
+) -> Result<(), EngineError> {
+    match action {
+        InternalPageAction::Wait { milliseconds } => {
+            tokio::time::sleep(Duration::from_millis(*milliseconds)).await;
+        }
+        InternalPageAction::Click { selector } => {
+            let element: chromiumoxide::element::Element =
+                page.find_element(selector).await.map_err(|e| {
+                    EngineError::BrowserError(format!("Click failed, element not found: {}", e))
+                })?;
+            element
+                .click()
+                .await
+                .map_err(|e| EngineError::BrowserError(format!("Click failed: {}", e)))?;
+        }
+        InternalPageAction::Scroll { direction } => {
+            let script = match direction.as_str() {
+                "down" => "window.scrollBy(0, window.innerHeight);",
+                "up" => "window.scrollBy(0, -window.innerHeight);",
+                "bottom" => "window.scrollTo(0, document.body.scrollHeight);",
+                "top" => "window.scrollTo(0, 0);",
+                _ => "window.scrollBy(0, window.innerHeight);",
+            };
+            let _: chromiumoxide::js::EvaluationResult = page
+                .evaluate(script)
+                .await
+                .map_err(|e| EngineError::BrowserError(format!("Scroll failed: {}", e)))?;
+        }
+        InternalPageAction::Screenshot { full_page: _ } => {
+            // 此处动作生成的截图暂不直接返回，仅作为交互过程的一部分
+            // 如果需要保存，可能需要额外的逻辑处理
+        }
+        InternalPageAction::Input { selector, text } => {
+            let element: chromiumoxide::element::Element =
+                page.find_element(selector).await.map_err(|e| {
+                    EngineError::BrowserError(format!("Input failed, element not found: {}", e))
+                })?;
+            element
+                .type_str(text)
+                .await
+                .map_err(|e| EngineError::BrowserError(format!("Input failed: {}", e)))?;
+        }
+        InternalPageAction::Evaluate { script } => {
+            let result: chromiumoxide::js::EvaluationResult = page
+                .evaluate(script.as_str())
+                .await
+                .map_err(|e| EngineError::BrowserError(format!("Evaluate failed: {}", e)))?;
+            // 无法序列化为 JSON 的返回值（如 undefined、DOM 节点）记为 null
+            evaluate_results.push(
+                result
+                    .into_value::<serde_json::Value>()
+                    .unwrap_or(serde_json::Value::Null),
+            );
+        }
+        InternalPageAction::WaitForSelector {
+            selector,
+            timeout_ms,
+            state,
+        } => {
+            wait_for_selector(page, selector, state, *timeout_ms).await?;
+        }
+        InternalPageAction::WaitForNavigation { timeout_ms } => {
+            let timeout = Duration::from_millis(*timeout_ms);
+            tokio::time::timeout(timeout, page.wait_for_navigation())
+                .await
+                .map_err(|_| EngineError::Timeout(timeout))?
+                .map_err(|e| {
+                    EngineError::BrowserError(format!("Wait for navigation failed: {}", e))
+                })?;
+        }
+        InternalPageAction::Select { selector, value } => {
+            let value = serde_json::to_string(value).unwrap_or_else(|_| "\"\"".to_string());
+            let body = format!(
+                "el.value = {value}; \
+                 el.dispatchEvent(new Event('input', {{ bubbles: true }})); \
+                 el.dispatchEvent(new Event('change', {{ bubbles: true }}));"
+            );
+            run_element_script(page, "Select", selector, &body).await?;
+        }
+        InternalPageAction::Check { selector, checked } => {
+            // 状态不同时才点击，保证框架监听的 click/change 事件都会触发
+            let body = format!("if (el.checked !== {checked}) {{ el.click(); }}");
+            run_element_script(page, "Check", selector, &body).await?;
+        }
+        InternalPageAction::PressKey { key } => {
+            let element = match page.find_element(":focus").await {
+                Ok(element) => element,
+                Err(_) => page
+                    .find_element("body")
+                    .await
+                    .map_err(|e| EngineError::BrowserError(format!("Press key failed: {}", e)))?,
+            };
+            element
+                .press_key(key)
+                .await
+                .map_err(|e| EngineError::BrowserError(format!("Press key failed: {}", e)))?;
+        }
+        InternalPageAction::Submit { selector } => {
+            let body = "const form = el.form || el; \
+                 if (typeof form.requestSubmit === 'function') { form.requestSubmit(); } \
+                 else { form.submit(); }";
+            run_element_script(page, "Submit", selector, body).await?;
+        }
+        InternalPageAction::Hover { selector } => {
+            let element = page.find_element(selector).await.map_err(|e| {
+                EngineError::BrowserError(format!("Hover failed, element not found: {}", e))
+            })?;
+            element
+                .hover()
+                .await
+                .map_err(|e| EngineError::BrowserError(format!("Hover failed: {}", e)))?;
+        }
+        InternalPageAction::Drag { from, to } => {
+            let start = element_center(page, "Drag", from).await?;
+            let end = element_center(page, "Drag", to).await?;
+            dispatch_mouse(page, DispatchMouseEventType::MouseMoved, start).await?;
+            dispatch_mouse(page, DispatchMouseEventType::MousePressed, start).await?;
+            // 分步移动，使依赖 mousemove 的拖拽库与 canvas 能跟踪轨迹
+            for step in 1..=DRAG_STEPS {
+                let ratio = step as f64 / DRAG_STEPS as f64;
+                let point = Point {
+                    x: start.x + (end.x - start.x) * ratio,
+                    y: start.y + (end.y - start.y) * ratio,
+                };
+                dispatch_mouse(page, DispatchMouseEventType::MouseMoved, point).await?;
+            }
+            dispatch_mouse(page, DispatchMouseEventType::MouseReleased, end).await?;
+        }
+        InternalPageAction::KeyDown { key } => {
+            dispatch_key(page, DispatchKeyEventType::KeyDown, key).await?;
+        }
+        InternalPageAction::KeyUp { key } => {
+            dispatch_key(page, DispatchKeyEventType::KeyUp, key).await?;
+        }
+    }
+    Ok(())
+}
+
+/// 拖拽时鼠标移动的分段数
+const DRAG_STEPS: u32 = 10;
+
+/// 元素滚动到可见区域后的可点击中心点
+async fn element_center(page: &Page, action: &str, selector: &str) -> Result<Point, EngineError> {
+    let element = page.find_element(selector).await.map_err(|e| {
+        EngineError::BrowserError(format!("{} failed, element not found: {}", action, e))
+    })?;
+    element
+        .scroll_into_view()
+        .await
+        .map_err(|e| EngineError::BrowserError(format!("{} failed: {}", action, e)))?;
+    element
+        .clickable_point()
+        .await
+        .map_err(|e| EngineError::BrowserError(format!("{} failed: {}", action, e)))
+}
+
+/// 以左键发送鼠标事件（`Input.dispatchMouseEvent`）
+async fn dispatch_mouse(
+    page: &Page,
+    kind: DispatchMouseEventType,
+    point: Point,
+) -> Result<(), EngineError> {
+    let params = DispatchMouseEventParams::builder()
+        .r#type(kind)
+        .x(point.x)
+        .y(point.y)
+        .button(MouseButton::Left)
+        .click_count(1)
+        .build()
+        .map_err(|e| EngineError::BrowserError(format!("Mouse event failed: {}", e)))?;
+    page.execute(params)
+        .await
+        .map_err(|e| EngineError::BrowserError(format!("Mouse event failed: {}", e)))?;
+    Ok(())
+}
+
+/// 发送原始键盘事件（`Input.dispatchKeyEvent`），单字符按键按下时同时输入该字符
+async fn dispatch_key(
+    page: &Page,
+    kind: DispatchKeyEventType,
+    key: &str,
+) -> Result<(), EngineError> {
+    let mut builder = DispatchKeyEventParams::builder()
+        .r#type(kind.clone())
+        .key(key);
+    if kind == DispatchKeyEventType::KeyDown && key.chars().count() == 1 {
+        builder = builder.text(key);
+    }
+    let params = builder
+        .build()
+        .map_err(|e| EngineError::BrowserError(format!("Key event failed: {}", e)))?;
+    page.execute(params)
+        .await
+        .map_err(|e| EngineError::BrowserError(format!("Key event failed: {}", e)))?;
+    Ok(())
+}
+
+/// 生成对选择器匹配的元素执行 `body` 的脚本，元素不存在时返回 false
+fn element_script(selector: &str, body: &str) -> String {
+    let selector = serde_json::to_string(selector).unwrap_or_else(|_| "\"\"".to_string());
+    format!(
+        "(() => {{ const el = document.querySelector({selector}); \
+         if (!el) {{ return false; }} {body} return true; }})()"
+    )
+}
+
+/// 在页面中执行表单操作脚本，元素不存在时返回 `EngineError::BrowserError`
+async fn run_element_script(
+    page: &Page,
+    action: &str,
+    selector: &str,
+    body: &str,
+) -> Result<(), EngineError> {
+    let found = page
+        .evaluate(element_script(selector, body).as_str())
+        .await
+        .map_err(|e| EngineError::BrowserError(format!("{} failed: {}", action, e)))?
+        .into_value::<bool>()
+        .unwrap_or(false);
+    if found {
+        Ok(())
+    } else {
+        Err(EngineError::BrowserError(format!(
+            "{} failed, element not found: {}",
+            action, selector
+        )))
+    }
+}
+
+/// 等待元素状态的轮询间隔
+const SELECTOR_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// 生成检查元素状态的脚本（attached / visible / hidden / detached）
+fn selector_state_script(selector: &str, state: &str) -> String {
+    // 通过 JSON 序列化转义选择器，避免拼接出非法脚本
+    let selector = serde_json::to_string(selector).unwrap_or_else(|_| "\"\"".to_string());
+    let state = serde_json::to_string(state).unwrap_or_else(|_| "\"visible\"".to_string());
+    format!(
+        r#"(() => {{
+    const el = document.querySelector({selector});
+    const visible = !!el && (() => {{
+        const style = window.getComputedStyle(el);
+        const rect = el.getBoundingClientRect();
+        return style.visibility !== 'hidden' && style.display !== 'none' && rect.width > 0 && rect.height > 0;
+    }})();
+    switch ({state}) {{
+        case 'attached': return !!el;
+        case 'detached': return !el;
+        case 'hidden': return !visible;
+        default: return visible;
+    }}
+}})()"#
+    )
+}
+
+/// 轮询等待选择器匹配的元素达到指定状态，超时返回 `EngineError::Timeout`
+async fn wait_for_selector(
+    page: &Page,
+    selector: &str,
+    state: &str,
+    timeout_ms: u64,
+) -> Result<(), EngineError> {
+    let timeout = Duration::from_millis(timeout_ms);
+    let script = selector_state_script(selector, state);
+    let deadline = Instant::now() + timeout;
+
+    loop {
+        let matched = page
+            .evaluate(script.as_str())
+            .await
+            .map_err(|e| EngineError::BrowserError(format!("Wait for selector failed: {}", e)))?
+            .into_value::<bool>()
+            .unwrap_or(false);
+        if matched {
+            return Ok(());
+        }
+        if Instant::now() >= deadline {
+            log::debug!(
+                "Timed out waiting for '{}' to be {} after {}ms",
+                selector,
+                state,
+                timeout_ms
+            );
+            return Err(EngineError::Timeout(timeout));
+        }
+        tokio::time::sleep(SELECTOR_POLL_INTERVAL).await;
+    }
+}
+
+/// 通过 CDP `Page.captureSnapshot` 将当前页面及其资源保存为 MHTML
+async fn capture_mhtml(page: &Page) -> Result<String, EngineError> {
+    let params = CaptureSnapshotParams {
+        format: Some(CaptureSnapshotFormat::Mhtml),
+    };
+    page.execute(params)
+        .await
+        .map(|response| response.result.data)
+        .map_err(|e| EngineError::BrowserError(format!("MHTML snapshot failed: {}", e)))
+}
+
+/// 通过 CDP `Page.printToPDF` 将当前页面打印为 PDF
+async fn print_pdf(page: &Page, options: &PdfOptions) -> Result<Bytes, EngineError> {
+    let (paper_width, paper_height) = options.paper_size.dimensions_inches();
+    let params = PrintToPdfParams {
+        landscape: Some(options.landscape),
+        print_background: Some(options.print_background),
+        paper_width: Some(paper_width),
+        paper_height: Some(paper_height),
+        margin_top: Some(mm_to_inches(options.margins.top)),
+        margin_right: Some(mm_to_inches(options.margins.right)),
+        margin_bottom: Some(mm_to_inches(options.margins.bottom)),
+        margin_left: Some(mm_to_inches(options.margins.left)),
+        ..Default::default()
+    };
+    page.pdf(params)
+        .await
+        .map(Bytes::from)
+        .map_err(|e| EngineError::BrowserError(format!("PDF rendering failed: {}", e)))
+}
+
+/// 读取整页截图所需页面尺寸的脚本（CSS 像素）
+const PAGE_METRICS_SCRIPT: &str = r#"(() => {
+    const el = document.scrollingElement || document.documentElement;
+    const body = document.body || el;
+    return {
+        width: Math.ceil(document.documentElement.clientWidth || window.innerWidth),
+        height: Math.ceil(Math.max(el.scrollHeight, body.scrollHeight)),
+        viewport_height: Math.ceil(window.innerHeight),
+        device_pixel_ratio: window.devicePixelRatio || 1,
+    };
+})()"#;
+
+/// 分段截取时滚动到每段顶部后等待懒加载内容渲染的时间
+const SEGMENT_SETTLE_DELAY: Duration = Duration::from_millis(100);
+
+fn cdp_screenshot_format(format: ScreenshotFormat) -> CaptureScreenshotFormat {
+    match format {
+        ScreenshotFormat::Png => CaptureScreenshotFormat::Png,
+        ScreenshotFormat::Jpeg => CaptureScreenshotFormat::Jpeg,
+        ScreenshotFormat::Webp => CaptureScreenshotFormat::Webp,
+    }
+}
+
+/// 截取页面指定区域（CSS 像素），区域可超出当前视口
+async fn capture_region(
+    page: &Page,
+    y: u32,
+    width: u32,
+    height: u32,
+    format: ScreenshotFormat,
+    quality: u8,
+) -> Result<Vec<u8>, EngineError> {
+    let params = chromiumoxide::page::ScreenshotParams::builder()
+        .format(cdp_screenshot_format(format))
+        .quality(quality as i64)
+        .clip(Viewport {
+            x: 0.0,
+            y: y as f64,
+            width: width as f64,
+            height: height as f64,
+            scale: 1.0,
+        })
+        .capture_beyond_viewport(true)
+        .build();
+    page.screenshot(params)
+        .await
+        .map_err(|e| EngineError::BrowserError(format!("Page screenshot failed: {}", e)))
+}
+
+/// 整页截图
+///
+/// 截取高度不超过 `MAX_SEGMENT_HEIGHT` 时一次截取；否则按视口高度逐段滚动截取 PNG，
+/// 拼接后按请求的格式与质量编码，避免超长页面截图失败或被裁剪。
+async fn capture_full_page(
+    page: &Page,
+    format: ScreenshotFormat,
+    quality: u8,
+    max_height: Option<u32>,
+) -> Result<Vec<u8>, EngineError> {
+    let measure_error =
+        |e: String| EngineError::BrowserError(format!("Failed to measure page: {}", e));
+    let metrics = page
+        .evaluate(PAGE_METRICS_SCRIPT)
+        .await
+        .map_err(|e| measure_error(e.to_string()))?
+        .into_value::<PageMetrics>()
+        .map_err(|e| measure_error(e.to_string()))?;
+    let height = metrics.capture_height(max_height, format);
+    if height < metrics.height {
+        log::debug!(
+            "Full-page screenshot cut off at {}px of {}px",
+            height,
+            metrics.height
+        );
+    }
+    if !metrics.needs_segments(height) {
+        return capture_region(page, 0, metrics.width, height, format, quality).await;
+    }
+
+    let segment_height = metrics.viewport_height.clamp(1, MAX_SEGMENT_HEIGHT);
+    let mut segments = Vec::new();
+    for segment in plan_segments(height, segment_height) {
+        // 滚动到该段触发懒加载内容，固定定位元素随滚动位置渲染
+        page.evaluate(format!("window.scrollTo(0, {})", segment.y).as_str())
+            .await
+            .map_err(|e| EngineError::BrowserError(format!("Scroll failed: {}", e)))?;
+        tokio::time::sleep(SEGMENT_SETTLE_DELAY).await;
+        segments.push(
+            capture_region(
+                page,
+                segment.y,
+                metrics.width,
+                segment.height,
+                ScreenshotFormat::Png,
+                100,
+            )
+            .await?,
+        );
+    }
+    let _ = page.evaluate("window.scrollTo(0, 0)").await;
+    log::debug!(
+        "Stitching {} screenshot segments ({}x{}px)",
+        segments.len(),
+        metrics.width,
+        height
+    );
+
+    // 解码与编码为 CPU 密集操作，避免阻塞异步运行时
+    tokio::task::spawn_blocking(move || stitch_segments(&segments, format, quality))
+        .await
+        .map_err(|e| EngineError::BrowserError(format!("Screenshot stitching failed: {}", e)))?
+        .map_err(|e| EngineError::BrowserError(format!("Screenshot stitching failed: {}", e)))
+}
+
+/// 自动滚动时检查页面状态的轮询间隔
+const SCROLL_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// 单次滚动后等待网络空闲的最长时间，避免持续轮询请求的页面无限等待
+const SCROLL_SETTLE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// 扩大资源计时缓冲区（默认仅 250 条）并返回当前页面高度
+const SCROLL_START_SCRIPT: &str = r#"(() => {
+    performance.setResourceTimingBufferSize(100000);
+    return (document.scrollingElement || document.documentElement).scrollHeight;
+})()"#;
+
+/// 滚动到页面底部
+const SCROLL_TO_BOTTOM_SCRIPT: &str = r#"(() => {
+    const el = document.scrollingElement || document.documentElement;
+    window.scrollTo(0, el.scrollHeight);
+    return el.scrollHeight;
+})()"#;
+
+/// 当前页面高度
+const SCROLL_HEIGHT_SCRIPT: &str =
+    "(document.scrollingElement || document.documentElement).scrollHeight";
+
+/// 页面活动签名：已完成的资源请求数与页面高度，任一变化即视为仍在加载
+const PAGE_ACTIVITY_SCRIPT: &str = r#"(() => {
+    const resources = performance.getEntriesByType('resource').length;
+    const height = (document.scrollingElement || document.documentElement).scrollHeight;
+    return `${resources}:${height}`;
+})()"#;
+
+async fn evaluate_scroll_script<T: serde::de::DeserializeOwned>(
+    page: &Page,
+    script: &str,
+) -> Result<T, EngineError> {
+    page.evaluate(script)
+        .await
+        .map_err(|e| EngineError::BrowserError(format!("Auto scroll failed: {}", e)))?
+        .into_value::<T>()
+        .map_err(|e| EngineError::BrowserError(format!("Auto scroll failed: {}", e)))
+}
+
+/// 反复滚动到页面底部，直到页面高度不再增长或达到滚动次数上限，返回实际滚动次数
+async fn auto_scroll(page: &Page, config: AutoScroll) -> Result<u32, EngineError> {
+    let idle = Duration::from_millis(config.idle_ms);
+    let mut height: u64 = evaluate_scroll_script(page, SCROLL_START_SCRIPT).await?;
+
+    for scroll in 1..=config.max_scrolls {
+        evaluate_scroll_script::<u64>(page, SCROLL_TO_BOTTOM_SCRIPT).await?;
+        wait_for_page_idle(page, idle).await?;
+
+        let new_height: u64 = evaluate_scroll_script(page, SCROLL_HEIGHT_SCRIPT).await?;
+        if new_height <= height {
+            return Ok(scroll);
+        }
+        height = new_height;
+    }
+    Ok(config.max_scrolls)
+}
+
+/// 等待页面在 `idle` 时长内没有新的网络请求完成且高度不变
+///
+/// 最多等待 `idle + SCROLL_SETTLE_TIMEOUT`，超时后按已加载的内容继续。
+async fn wait_for_page_idle(page: &Page, idle: Duration) -> Result<(), EngineError> {
+    let deadline = Instant::now() + idle + SCROLL_SETTLE_TIMEOUT;
+    let mut last: String = evaluate_scroll_script(page, PAGE_ACTIVITY_SCRIPT).await?;
+    let mut quiet_since = Instant::now();
+
+    while quiet_since.elapsed() < idle && Instant::now() < deadline {
+        tokio::time::sleep(SCROLL_POLL_INTERVAL).await;
+        let current: String = evaluate_scroll_script(page, PAGE_ACTIVITY_SCRIPT).await?;
+        if current != last {
+            last = current;
+            quiet_since = Instant::now();
+        }
+    }
+    Ok(())
+}
+
+/// 启用 CDP Fetch 拦截：按规则拦截或放行页面的每个请求，并在代理认证质询时提供认证信息
+///
+/// 返回已拦截的请求计数与处理任务，页面结束后需中止这些任务。
+async fn start_request_interception(
+    page: &Page,
+    rules: Option<ResourceBlocking>,
+    proxy_auth: Option<ProxyCredentials>,
+) -> Result<(Arc<AtomicU64>, Vec<tokio::task::JoinHandle<()>>), EngineError> {
+    let intercept_error = |e: chromiumoxide::error::CdpError| {
+        EngineError::BrowserError(format!("Failed to intercept requests: {}", e))
+    };
+
+    let mut paused = page
+        .event_listener::<EventRequestPaused>()
+        .await
+        .map_err(intercept_error)?;
+    let mut tasks = Vec::with_capacity(2);
+    let handle_auth_requests = proxy_auth.is_some();
+    if let Some(credentials) = proxy_auth {
+        let mut challenges = page
+            .event_listener::<EventAuthRequired>()
+            .await
+            .map_err(intercept_error)?;
+        let page = page.clone();
+        tasks.push(tokio::spawn(async move {
+            // 同一请求再次质询说明认证信息被拒绝，取消认证避免反复重试
+            let mut answered = std::collections::HashSet::new();
+            while let Some(event) = challenges.next().await {
+                let is_proxy = matches!(
+                    event.auth_challenge.source,
+                    Some(AuthChallengeSource::Proxy)
+                );
+                let response = if !is_proxy {
+                    // 站点自身的认证质询按浏览器默认行为处理
+                    AuthChallengeResponse {
+                        response: AuthChallengeResponseResponse::Default,
+                        username: None,
+                        password: None,
+                    }
+                } else if answered.insert(event.request_id.inner().clone()) {
+                    AuthChallengeResponse {
+                        response: AuthChallengeResponseResponse::ProvideCredentials,
+                        username: Some(credentials.username.clone()),
+                        password: Some(credentials.password.clone()),
+                    }
+                } else {
+                    log::warn!("Proxy rejected credentials for {}", event.request.url);
+                    AuthChallengeResponse {
+                        response: AuthChallengeResponseResponse::CancelAuth,
+                        username: None,
+                        password: None,
+                    }
+                };
+                if let Err(e) = page
+                    .execute(ContinueWithAuthParams::new(
+                        event.request_id.clone(),
+                        response,
+                    ))
+                    .await
+                {
+                    log::debug!(
+                        "Failed to answer auth challenge for {}: {}",
+                        event.request.url,
+                        e
+                    );
+                }
+            }
+        }));
+    }
+    page.execute(FetchEnableParams {
+        handle_auth_requests: Some(handle_auth_requests),
+        ..Default::default()
+    })
+    .await
+    .map_err(intercept_error)?;
+
+    let blocked = Arc::new(AtomicU64::new(0));
+    let counter = Arc::clone(&blocked);
+    let page = page.clone();
+    tasks.push(tokio::spawn(async move {
+        while let Some(event) = paused.next().await {
+            let request_id = event.request_id.clone();
+            let block = rules.as_ref().is_some_and(|rules| {
+                rules.should_block(event.resource_type.as_ref(), &event.request.url)
+            });
+            let result = if block {
+                counter.fetch_add(1, Ordering::Relaxed);
+                page.execute(FailRequestParams::new(
+                    request_id,
+                    ErrorReason::BlockedByClient,
+                ))
+                .await
+                .map(|_| ())
+            } else {
+                page.execute(ContinueRequestParams::new(request_id))
+                    .await
+                    .map(|_| ())
+            };
+            if let Err(e) = result {
+                log::debug!(
+                    "Failed to resolve paused request {}: {}",
+                    event.request.url,
+                    e
+                );
+            }
+        }
+    }));
+
+    Ok((blocked, tasks))
+}
+
+/// 使用指定代理的浏览器上下文
+///
+/// Chromium 只能在启动参数或浏览器上下文上设置代理，请求级代理为每个请求创建独立的
+/// 上下文。drop 时在后台销毁上下文及其中的页面（包括超时或出错提前返回的情况）。
+struct ProxyBrowserContext {
+    browser: Arc<Browser>,
+    id: Option<BrowserContextId>,
+}
+
+impl ProxyBrowserContext {
+    async fn create(browser: &Arc<Browser>, proxy_server: &str) -> Result<Self, EngineError> {
+        let response = browser
+            .execute(CreateBrowserContextParams {
+                proxy_server: Some(proxy_server.to_string()),
+                ..Default::default()
+            })
+            .await
+            .map_err(|e| {
+                EngineError::BrowserError(format!("Failed to create proxy browser context: {}", e))
+            })?;
+        Ok(Self {
+            browser: Arc::clone(browser),
+            id: Some(response.result.browser_context_id),
+        })
+    }
+
+    async fn new_page(&self) -> Result<Page, chromiumoxide::error::CdpError> {
+        let mut params = CreateTargetParams::new("about:blank");
+        params.browser_context_id = self.id.clone();
+        self.browser.new_page(params).await
+    }
+}
+
+impl Drop for ProxyBrowserContext {
+    fn drop(&mut self) {
+        let Some(id) = self.id.take() else {
+            return;
+        };
+        let browser = Arc::clone(&self.browser);
+        if let Ok(handle) = tokio::runtime::Handle::try_current() {
+            handle.spawn(async move {
+                if let Err(e) = browser.execute(DisposeBrowserContextParams::new(id)).await {
+                    log::debug!("Failed to dispose proxy browser context: {}", e);
+                }
+            });
+        }
+    }
+}
+
+/// 订阅页面的 CDP Network 事件，持续写入 HAR 记录器
+///
+/// 返回记录器与事件处理任务，结束记录时需中止这些任务。
+async fn start_har_capture(
+    page: &Page,
+) -> Result<(Arc<Mutex<HarRecorder>>, Vec<tokio::task::JoinHandle<()>>), EngineError> {
+    let recorder = Arc::new(Mutex::new(HarRecorder::new()));
+    let listen_error = |e: chromiumoxide::error::CdpError| {
+        EngineError::BrowserError(format!("Failed to subscribe to network events: {}", e))
+    };
+
+    let mut requests = page
+        .event_listener::<EventRequestWillBeSent>()
+        .await
+        .map_err(listen_error)?;
+    let mut responses = page
+        .event_listener::<EventResponseReceived>()
+        .await
+        .map_err(listen_error)?;
+    let mut finished = page
+        .event_listener::<EventLoadingFinished>()
+        .await
+        .map_err(listen_error)?;
+    let mut failed = page
+        .event_listener::<EventLoadingFailed>()
+        .await
+        .map_err(listen_error)?;
+
+    let har = Arc::clone(&recorder);
+    let request_task = tokio::spawn(async move {
+        while let Some(event) = requests.next().await {
+            let mut har = har.lock().unwrap_or_else(|e| e.into_inner());
+            let request_id = event.request_id.inner();
+            // 重定向复用 requestId，先补全上一跳的响应
+            if let Some(redirect) = &event.redirect_response {
+                har.record_response(
+                    request_id,
+                    redirect.status as u16,
+                    &redirect.status_text,
+                    &redirect.mime_type,
+                    redirect.protocol.as_deref(),
+                    redirect.headers.inner(),
+                );
+                har.record_finished(
+                    request_id,
+                    redirect.encoded_data_length,
+                    *event.timestamp.inner(),
+                );
+            }
+            har.record_request(
+                request_id,
+                &event.request.method,
+                &event.request.url,
+                event.request.headers.inner(),
+                *event.wall_time.inner(),
+                *event.timestamp.inner(),
+            );
+        }
+    });
+
+    let har = Arc::clone(&recorder);
+    let response_task = tokio::spawn(async move {
+        while let Some(event) = responses.next().await {
+            har.lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .record_response(
+                    event.request_id.inner(),
+                    event.response.status as u16,
+                    &event.response.status_text,
+                    &event.response.mime_type,
+                    event.response.protocol.as_deref(),
+                    event.response.headers.inner(),
+                );
+        }
+    });
+
+    let har = Arc::clone(&recorder);
+    let finished_task = tokio::spawn(async move {
+        while let Some(event) = finished.next().await {
+            har.lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .record_finished(
+                    event.request_id.inner(),
+                    event.encoded_data_length,
+                    *event.timestamp.inner(),
+                );
+        }
+    });
+
+    let har = Arc::clone(&recorder);
+    let failed_task = tokio::spawn(async move {
+        while let Some(event) = failed.next().await {
+            har.lock().unwrap_or_else(|e| e.into_inner()).record_failed(
+                event.request_id.inner(),
+                &event.error_text,
+                *event.timestamp.inner(),
+            );
+        }
+    });
+
+    Ok((
+        recorder,
+        vec![request_task, response_task, finished_task, failed_task],
+    ))
+}
+
+#[async_trait]
+impl ScraperEngine for PlaywrightEngine {
+    /// 执行浏览器自动化抓取并记录引擎指标
+    async fn scrape(
+        &self,
+        request: &InternalScrapeRequest,
+    ) -> Result<InternalScrapeResponse, EngineError> {
+        let started = Instant::now();
+        let result = self.execute_scrape(request).await;
+        crate::infrastructure::metrics::record_engine_request(
+            self.name(),
+            started.elapsed(),
+            result.as_ref().err().map(EngineError::kind),
+        );
+        result
+    }
 
     /// 计算对请求的支持分数
     ///
@@ -594,7 +1705,13 @@ impl ScraperEngine for PlaywrightEngine {
         if request.method != crate::engines::engine_client::HttpMethod::Get {
             return 0;
         }
-        if request.needs_js || request.needs_screenshot {
+        if request.needs_js
+            || request.needs_screenshot
+            || request.capture_har
+            || request.capture_mhtml
+            || request.pdf.is_some()
+            || request.accessibility.is_some()
+        {
             return 100;
         }
         10 // Can do it, but expensive
@@ -614,6 +1731,20 @@ impl ScraperEngine for PlaywrightEngine {
     fn supports_tls_fingerprint(&self) -> bool {
         false
     }
+
+    /// 启动预检：从浏览器池取出实例并完成一次 CDP 握手（Browser.getVersion）
+    async fn preflight(&self) -> Result<(), EngineError> {
+        let pool = self.get_or_init_pool();
+        let instance = pool.acquire().await?;
+        let version = instance
+            .browser()
+            .version()
+            .await
+            .map_err(|e| EngineError::BrowserError(format!("CDP handshake failed: {}", e)));
+        instance.release().await;
+        log::info!("Playwright preflight passed ({})", version?.product);
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -623,6 +1754,20 @@ mod tests {
     use std::collections::HashMap;
     use std::time::Duration;
 
+    #[test]
+    fn test_element_script_escapes_selector() {
+        let script = element_script(r#"input[name="q"]"#, "el.focus();");
+        assert!(script.contains(r#"document.querySelector("input[name=\"q\"]")"#));
+        assert!(script.contains("if (!el) { return false; } el.focus(); return true;"));
+    }
+
+    #[test]
+    fn test_selector_state_script_escapes_selector() {
+        let script = selector_state_script(r#"a[href="/x"]"#, "detached");
+        assert!(script.contains(r#"document.querySelector("a[href=\"/x\"]")"#));
+        assert!(script.contains(r#"switch ("detached")"#));
+    }
+
     #[test]
     fn test_support_score() {
         let engine = PlaywrightEngine::new();
@@ -644,6 +1789,22 @@ mod tests {
             actions: vec![],
             body: None,
             sync_wait_ms: 0,
+            content_type_filter: None,
+            http_protocol: crate::engines::engine_client::HttpProtocol::Auto,
+            tls_profile: None,
+            capture_har: false,
+            capture_mhtml: false,
+            pdf: None,
+            resource_blocking: None,
+            action_error_policies: Vec::new(),
+            iframe_capture: None,
+            flatten_shadow_dom: false,
+            auto_scroll: None,
+            accessibility: None,
+            engine_tier: None,
+            excluded_engines: Vec::new(),
+            resolve: HashMap::new(),
+            address_family: None,
         };
         assert_eq!(engine.support_score(&request_js), 100);
 
@@ -664,6 +1825,22 @@ mod tests {
             actions: vec![],
             body: None,
             sync_wait_ms: 0,
+            content_type_filter: None,
+            http_protocol: crate::engines::engine_client::HttpProtocol::Auto,
+            tls_profile: None,
+            capture_har: false,
+            capture_mhtml: false,
+            pdf: None,
+            resource_blocking: None,
+            action_error_policies: Vec::new(),
+            iframe_capture: None,
+            flatten_shadow_dom: false,
+            auto_scroll: None,
+            accessibility: None,
+            engine_tier: None,
+            excluded_engines: Vec::new(),
+            resolve: HashMap::new(),
+            address_family: None,
         };
         assert_eq!(engine.support_score(&request_screenshot), 100);
 
@@ -684,6 +1861,22 @@ mod tests {
             actions: vec![],
             body: None,
             sync_wait_ms: 0,
+            content_type_filter: None,
+            http_protocol: crate::engines::engine_client::HttpProtocol::Auto,
+            tls_profile: None,
+            capture_har: false,
+            capture_mhtml: false,
+            pdf: None,
+            resource_blocking: None,
+            action_error_policies: Vec::new(),
+            iframe_capture: None,
+            flatten_shadow_dom: false,
+            auto_scroll: None,
+            accessibility: None,
+            engine_tier: None,
+            excluded_engines: Vec::new(),
+            resolve: HashMap::new(),
+            address_family: None,
         };
         assert_eq!(engine.support_score(&request_basic), 10);
     }
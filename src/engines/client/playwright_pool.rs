@@ -12,12 +12,22 @@
 //!
 //! - 浏览器实例复用
 //! - 最大实例数限制
+//! - 预热实例（启动后保持若干空闲实例，避免冷启动）
+//! - 按页数或内存增长回收实例
 //! - 空闲实例自动清理
 //! - 健康检查机制
+//! - 池占用指标
 //! - 优雅关闭支持
 
+use crate::config::engines::PlaywrightSettings;
 use crate::engines::browser_downloader::{BrowserDownloadConfig, BrowserDownloadManager};
 use crate::engines::engine_client::EngineError;
+use crate::engines::proxy_url::{
+    browser_proxy, proxy_server_arg, redact_proxy_url, ProxyCredentials,
+};
+use crate::infrastructure::metrics::{
+    record_browser_pool_acquire, record_browser_pool_usage, record_browser_recycled,
+};
 use crate::infrastructure::services::config_service::BrowserConfigTrait;
 use chromiumoxide::{Browser, BrowserConfig};
 use futures::StreamExt;
@@ -45,6 +55,12 @@ pub struct BrowserPoolConfig {
     pub enable_reuse: bool,
     /// 浏览器启动参数
     pub browser_args: Vec<String>,
+    /// 保持预热的空闲实例数
+    pub min_warm_instances: usize,
+    /// 单个实例处理多少个页面后回收（0 表示不限）
+    pub max_pages_per_instance: u64,
+    /// JS 堆相对首个页面增长超过该值（MB）后回收（0 表示不限）
+    pub max_memory_growth_mb: u64,
 }
 
 impl Default for BrowserPoolConfig {
@@ -60,10 +76,54 @@ impl Default for BrowserPoolConfig {
                 "--disable-dev-shm-usage".to_string(),
                 "--no-sandbox".to_string(),
             ],
+            min_warm_instances: 1,
+            max_pages_per_instance: 100,
+            max_memory_growth_mb: 512,
         }
     }
 }
 
+impl BrowserPoolConfig {
+    /// 由 Playwright 引擎配置构建，未配置的字段使用默认值
+    pub fn from_settings(settings: &PlaywrightSettings) -> Self {
+        Self {
+            max_instances: settings.max_instances.max(1),
+            idle_timeout_secs: settings.idle_timeout_secs,
+            health_check_interval_secs: settings.health_check_interval_secs.max(1),
+            min_warm_instances: settings.min_warm_instances.min(settings.max_instances),
+            max_pages_per_instance: settings.max_pages_per_instance,
+            max_memory_growth_mb: settings.max_memory_growth_mb,
+            ..Self::default()
+        }
+    }
+
+    /// 实例归还时判断是否需要回收，返回回收原因
+    ///
+    /// `pages` 为实例已处理的页面数，`baseline_heap` 与 `heap` 为首个页面和本次页面的 JS 堆大小（字节）。
+    fn recycle_reason(
+        &self,
+        healthy: bool,
+        pages: u64,
+        baseline_heap: Option<u64>,
+        heap: Option<u64>,
+    ) -> Option<&'static str> {
+        if !healthy {
+            return Some("unhealthy");
+        }
+        if self.max_pages_per_instance > 0 && pages >= self.max_pages_per_instance {
+            return Some("page_limit");
+        }
+        if self.max_memory_growth_mb > 0 {
+            if let (Some(baseline), Some(heap)) = (baseline_heap, heap) {
+                if heap.saturating_sub(baseline) > self.max_memory_growth_mb * 1024 * 1024 {
+                    return Some("memory");
+                }
+            }
+        }
+        None
+    }
+}
+
 /// 浏览器池统计信息
 #[derive(Debug, Clone)]
 pub struct BrowserPoolStats {
@@ -77,6 +137,17 @@ pub struct BrowserPoolStats {
     pub max_instances: usize,
 }
 
+impl BrowserPoolStats {
+    /// 使用中实例占最大实例数的比例
+    pub fn utilization(&self) -> f64 {
+        if self.max_instances == 0 {
+            0.0
+        } else {
+            self.in_use_instances as f64 / self.max_instances as f64
+        }
+    }
+}
+
 /// 池化的浏览器实例
 struct PooledBrowser {
     /// 浏览器实例
@@ -89,6 +160,8 @@ struct PooledBrowser {
     use_count: AtomicU64,
     /// 是否健康
     is_healthy: AtomicBool,
+    /// 首个页面的 JS 堆大小（字节），0 表示尚未记录
+    baseline_heap: AtomicU64,
     /// 实例 ID
     instance_id: u64,
 }
@@ -102,6 +175,7 @@ impl PooledBrowser {
             last_used_at: std::sync::Mutex::new(now),
             use_count: AtomicU64::new(0),
             is_healthy: AtomicBool::new(true),
+            baseline_heap: AtomicU64::new(0),
             instance_id,
         }
     }
@@ -135,6 +209,17 @@ impl PooledBrowser {
     fn is_healthy(&self) -> bool {
         self.is_healthy.load(Ordering::Acquire)
     }
+
+    /// 首次调用时记录为基线，返回基线值
+    fn heap_baseline(&self, heap: u64) -> u64 {
+        match self
+            .baseline_heap
+            .compare_exchange(0, heap, Ordering::AcqRel, Ordering::Acquire)
+        {
+            Ok(_) => heap,
+            Err(baseline) => baseline,
+        }
+    }
 }
 
 impl std::fmt::Debug for PooledBrowser {
@@ -152,6 +237,8 @@ impl std::fmt::Debug for PooledBrowser {
 struct ReturnMessage {
     instance_id: u64,
     browser: Arc<Browser>,
+    /// 本次页面的 JS 堆大小（字节）
+    heap_bytes: Option<u64>,
 }
 
 /// 浏览器池内部状态
@@ -180,6 +267,8 @@ struct BrowserPoolState {
     return_sender: Mutex<Option<mpsc::Sender<ReturnMessage>>>,
     /// 关闭标志
     shutdown: AtomicBool,
+    /// 后台任务是否已启动
+    background_started: AtomicBool,
     /// 浏览器路径缓存
     browser_path: RwLock<Option<PathBuf>>,
 }
@@ -204,11 +293,13 @@ impl BrowserPoolState {
             return_task: Mutex::new(None),
             return_sender: Mutex::new(None),
             shutdown: AtomicBool::new(false),
+            background_started: AtomicBool::new(false),
             browser_path: RwLock::new(None),
         }
     }
 
-    async fn acquire(&self) -> Result<(u64, Arc<Browser>), EngineError> {
+    /// 获取实例，返回实例 ID、浏览器以及是否复用了预热实例
+    async fn acquire(&self) -> Result<(u64, Arc<Browser>, bool), EngineError> {
         // 检查是否已关闭
         if self.shutdown.load(Ordering::Acquire) {
             return Err(EngineError::Other(
@@ -216,8 +307,8 @@ impl BrowserPoolState {
             ));
         }
 
-        // 获取信号量许可
-        let _permit = self
+        // 获取信号量许可（限制同时使用的实例数），实例归还时再释放
+        let permit = self
             .semaphore
             .acquire()
             .await
@@ -226,12 +317,17 @@ impl BrowserPoolState {
         // 尝试从可用池中获取实例
         if self.config.enable_reuse {
             if let Some((id, browser)) = self.try_get_available().await {
-                return Ok((id, browser));
+                permit.forget();
+                self.report_usage().await;
+                return Ok((id, browser, true));
             }
         }
 
-        // 创建新实例
-        self.create_new_instance().await
+        // 创建新实例（失败时许可随 permit 一起释放）
+        let (id, browser) = self.create_new_instance().await?;
+        permit.forget();
+        self.report_usage().await;
+        Ok((id, browser, false))
     }
 
     async fn try_get_available(&self) -> Option<(u64, Arc<Browser>)> {
@@ -265,11 +361,8 @@ impl BrowserPoolState {
     }
 
     async fn create_new_instance(&self) -> Result<(u64, Arc<Browser>), EngineError> {
-        let instance_id = self.instance_counter.fetch_add(1, Ordering::Relaxed);
-        info!("Creating new browser instance {}", instance_id);
-
-        let browser = self.launch_browser().await?;
-        let pooled = Arc::new(PooledBrowser::new(browser.clone(), instance_id));
+        let (instance_id, pooled) = self.launch_pooled().await?;
+        let browser = pooled.browser.clone();
         pooled.touch();
 
         // 添加到使用中
@@ -278,8 +371,6 @@ impl BrowserPoolState {
             in_use.insert(instance_id, pooled);
         }
 
-        self.total_instances.fetch_add(1, Ordering::Relaxed);
-
         info!(
             "Browser instance {} created successfully (total: {})",
             instance_id,
@@ -289,7 +380,26 @@ impl BrowserPoolState {
         Ok((instance_id, browser))
     }
 
-    async fn return_instance(&self, instance_id: u64, browser: Arc<Browser>) {
+    /// 启动一个新的浏览器实例（不放入任何池中）
+    async fn launch_pooled(&self) -> Result<(u64, Arc<PooledBrowser>), EngineError> {
+        let instance_id = self.instance_counter.fetch_add(1, Ordering::Relaxed);
+        info!("Creating new browser instance {}", instance_id);
+
+        let browser = self.launch_browser().await?;
+        self.total_instances.fetch_add(1, Ordering::Relaxed);
+        Ok((
+            instance_id,
+            Arc::new(PooledBrowser::new(browser, instance_id)),
+        ))
+    }
+
+    async fn return_instance(&self, message: ReturnMessage) {
+        let ReturnMessage {
+            instance_id,
+            browser,
+            heap_bytes,
+        } = message;
+
         if self.shutdown.load(Ordering::Acquire) {
             debug!(
                 "Pool is shutting down, closing browser instance {}",
@@ -298,38 +408,94 @@ impl BrowserPoolState {
             return;
         }
 
-        // 从使用中移除
+        // 从使用中移除，实例不再占用许可
         let pooled = {
             let mut in_use = self.in_use.write().await;
             in_use.remove(&instance_id)
         };
+        self.semaphore.add_permits(1);
 
-        if let Some(pooled) = pooled {
-            // 检查浏览器是否仍然健康
-            let is_healthy = self.check_browser_health(&browser).await;
+        let Some(pooled) = pooled else {
+            // 实例不在使用中，可能是重复归还
+            warn!(
+                "Browser instance {} not found in use, ignoring return",
+                instance_id
+            );
+            return;
+        };
 
-            if is_healthy && self.config.enable_reuse && !self.shutdown.load(Ordering::Acquire) {
+        // 检查浏览器是否仍然健康，以及是否达到回收条件
+        let is_healthy = self.check_browser_health(&browser).await;
+        let baseline = heap_bytes.map(|heap| pooled.heap_baseline(heap));
+        let recycle = self.config.recycle_reason(
+            is_healthy,
+            pooled.use_count.load(Ordering::Relaxed),
+            baseline,
+            heap_bytes,
+        );
+
+        match recycle {
+            None if self.config.enable_reuse && !self.shutdown.load(Ordering::Acquire) => {
                 // 归还到可用池
                 let mut available = self.available.write().await;
                 available.insert(instance_id, pooled);
                 debug!("Browser instance {} returned to pool", instance_id);
-            } else {
-                // 不健康或禁用复用，关闭浏览器
+            }
+            reason => {
+                // 需要回收或禁用复用，关闭浏览器
                 self.total_instances.fetch_sub(1, Ordering::Relaxed);
-                self.semaphore.add_permits(1);
-                debug!(
-                    "Browser instance {} closed (unhealthy or reuse disabled)",
-                    instance_id
-                );
+                if let Some(reason) = reason {
+                    record_browser_recycled(reason);
+                    info!(
+                        "Recycled browser instance {} after {} pages ({})",
+                        instance_id,
+                        pooled.use_count.load(Ordering::Relaxed),
+                        reason
+                    );
+                } else {
+                    debug!("Browser instance {} closed (reuse disabled)", instance_id);
+                }
             }
-        } else {
-            // 实例不在使用中，可能是重复归还
-            warn!(
-                "Browser instance {} not found in use, ignoring return",
-                instance_id
-            );
-            self.semaphore.add_permits(1);
         }
+        self.report_usage().await;
+    }
+
+    /// 启动实例直到空闲实例数达到 `min_warm_instances`（不超过最大实例数）
+    async fn warm_up(&self) {
+        if !self.config.enable_reuse {
+            return;
+        }
+        loop {
+            if self.shutdown.load(Ordering::Acquire) {
+                return;
+            }
+            let available = self.available.read().await.len();
+            if available >= self.config.min_warm_instances
+                || self.total_instances.load(Ordering::Relaxed) >= self.config.max_instances
+            {
+                break;
+            }
+
+            match self.launch_pooled().await {
+                Ok((instance_id, pooled)) => {
+                    let mut available = self.available.write().await;
+                    available.insert(instance_id, pooled);
+                    debug!("Warmed up browser instance {}", instance_id);
+                }
+                Err(e) => {
+                    warn!("Failed to warm up browser instance: {}", e);
+                    break;
+                }
+            }
+        }
+        self.report_usage().await;
+    }
+
+    /// 上报池占用指标
+    async fn report_usage(&self) {
+        let in_use = self.in_use.read().await.len();
+        let available = self.available.read().await.len();
+        record_browser_pool_usage(in_use, available, self.config.max_instances);
     }
 
     async fn launch_browser(&self) -> Result<Arc<Browser>, EngineError> {
@@ -355,8 +521,9 @@ impl BrowserPoolState {
             }
 
             if let Some(ref proxy) = proxy_url {
-                info!("Using proxy for browser: {}", proxy);
-                builder = builder.arg(format!("--proxy-server={}", proxy));
+                let server = proxy_server_arg(proxy);
+                info!("Using proxy for browser: {}", redact_proxy_url(&server));
+                builder = builder.arg(format!("--proxy-server={}", server));
             }
 
             Browser::launch(
@@ -451,12 +618,16 @@ impl BrowserPoolState {
             }
         }
 
-        for id in to_remove {
+        // 保留预热实例
+        let removable = available
+            .len()
+            .saturating_sub(self.config.min_warm_instances);
+        for id in to_remove.into_iter().take(removable) {
             if let Some(pooled) = available.remove(&id) {
                 // 关闭浏览器
                 drop(pooled);
                 self.total_instances.fetch_sub(1, Ordering::Relaxed);
-                self.semaphore.add_permits(1);
+                record_browser_recycled("idle");
                 info!("Cleaned up idle browser instance {}", id);
             }
         }
@@ -477,7 +648,7 @@ impl BrowserPoolState {
             if let Some(pooled) = available.remove(&id) {
                 drop(pooled);
                 self.total_instances.fetch_sub(1, Ordering::Relaxed);
-                self.semaphore.add_permits(1);
+                record_browser_recycled("unhealthy");
                 warn!("Removed unhealthy browser instance {}", id);
             }
         }
@@ -510,6 +681,8 @@ pub struct BrowserInstance {
     instance_id: u64,
     /// 归还通道发送端
     return_sender: Option<mpsc::Sender<ReturnMessage>>,
+    /// 最近一个页面的 JS 堆大小（字节）
+    heap_bytes: Option<u64>,
 }
 
 impl BrowserInstance {
@@ -520,6 +693,11 @@ impl BrowserInstance {
             .expect("Browser instance already released")
     }
 
+    /// 记录页面的 JS 堆大小，归还时用于判断实例内存是否持续增长
+    pub fn record_heap_size(&mut self, bytes: u64) {
+        self.heap_bytes = Some(bytes);
+    }
+
     /// 手动释放实例（归还到池中）
     pub async fn release(mut self) {
         if let Some(browser) = self.browser.take() {
@@ -528,6 +706,7 @@ impl BrowserInstance {
                     .send(ReturnMessage {
                         instance_id: self.instance_id,
                         browser,
+                        heap_bytes: self.heap_bytes,
                     })
                     .await;
             }
@@ -539,14 +718,23 @@ impl Drop for BrowserInstance {
     fn drop(&mut self) {
         if let Some(browser) = self.browser.take() {
             if let Some(sender) = &self.return_sender {
-                // 尝试非阻塞发送，如果失败则直接丢弃浏览器
+                // 尝试非阻塞发送，通道已满时在后台等待发送，避免实例的许可丢失
                 match sender.try_send(ReturnMessage {
                     instance_id: self.instance_id,
                     browser,
+                    heap_bytes: self.heap_bytes,
                 }) {
                     Ok(_) => {}
-                    Err(mpsc::error::TrySendError::Full(_)) => {
-                        warn!("Return channel full, dropping browser instance");
+                    Err(mpsc::error::TrySendError::Full(message)) => {
+                        match tokio::runtime::Handle::try_current() {
+                            Ok(handle) => {
+                                let sender = sender.clone();
+                                handle.spawn(async move {
+                                    let _ = sender.send(message).await;
+                                });
+                            }
+                            Err(_) => warn!("Return channel full, dropping browser instance"),
+                        }
                     }
                     Err(mpsc::error::TrySendError::Closed(_)) => {
                         debug!("Return channel closed, dropping browser instance");
@@ -587,12 +775,25 @@ impl BrowserPool {
         Self { state }
     }
 
+    /// 启动时全局代理的认证信息
+    ///
+    /// `--proxy-server` 不接受内嵌的认证信息，页面收到代理认证质询时使用。
+    pub fn proxy_credentials(&self) -> Option<ProxyCredentials> {
+        let proxy_url = self.state.browser_config.get_proxy_url()?;
+        browser_proxy(&proxy_url).ok()?.credentials
+    }
+
     /// 获取浏览器实例
     ///
     /// 优先从池中获取可用实例，如果没有可用实例则创建新实例。
     /// 返回的 BrowserInstance 在 drop 时会自动归还到池中。
     pub async fn acquire(&self) -> Result<BrowserInstance, EngineError> {
-        let (instance_id, browser) = self.state.acquire().await?;
+        // 首次使用时启动归还、清理与预热任务
+        self.start_background_tasks().await;
+
+        let started = Instant::now();
+        let (instance_id, browser, warm) = self.state.acquire().await?;
+        record_browser_pool_acquire(started.elapsed(), warm);
 
         // 获取归还通道发送端
         let return_sender = {
@@ -604,13 +805,20 @@ impl BrowserPool {
             browser: Some(browser),
             instance_id,
             return_sender,
+            heap_bytes: None,
         })
     }
 
     /// 启动后台清理任务
     ///
-    /// 定期清理空闲实例和进行健康检查
+    /// 定期清理空闲实例、进行健康检查并补足预热实例。重复调用不会重复启动。
     pub async fn start_background_tasks(&self) {
+        if self.state.shutdown.load(Ordering::Acquire)
+            || self.state.background_started.swap(true, Ordering::AcqRel)
+        {
+            return;
+        }
+
         // 启动清理任务
         {
             let state = self.state.clone();
@@ -633,6 +841,9 @@ impl BrowserPool {
                     // 健康检查
                     state.health_check_all().await;
 
+                    // 补足预热实例
+                    state.warm_up().await;
+
                     debug!(
                         "Browser pool cleanup completed (total: {})",
                         state.total_instances.load(Ordering::Relaxed)
@@ -660,7 +871,7 @@ impl BrowserPool {
                     if state.shutdown.load(Ordering::Acquire) {
                         break;
                     }
-                    state.return_instance(msg.instance_id, msg.browser).await;
+                    state.return_instance(msg).await;
                 }
             });
 
@@ -671,6 +882,9 @@ impl BrowserPool {
 
     /// 停止后台任务
     pub async fn stop_background_tasks(&self) {
+        self.state
+            .background_started
+            .store(false, Ordering::Release);
         {
             let mut task = self.state.cleanup_task.lock().await;
             if let Some(handle) = task.take() {
@@ -739,10 +953,19 @@ pub fn get_global_pool() -> Option<&'static BrowserPool> {
 }
 
 /// 初始化全局浏览器池
-pub fn init_global_pool(config: BrowserPoolConfig, browser_config: Arc<dyn BrowserConfigTrait>) {
-    let pool = BrowserPool::new(config, browser_config);
-    let _ = GLOBAL_BROWSER_POOL.set(pool);
-    info!("Global browser pool initialized");
+///
+/// 已初始化时忽略传入的配置，返回现有的池。
+pub fn init_global_pool(
+    config: BrowserPoolConfig,
+    browser_config: Arc<dyn BrowserConfigTrait>,
+) -> &'static BrowserPool {
+    GLOBAL_BROWSER_POOL.get_or_init(|| {
+        info!(
+            "Global browser pool initialized (max {} instances, {} warm)",
+            config.max_instances, config.min_warm_instances
+        );
+        BrowserPool::new(config, browser_config)
+    })
 }
 
 /// 关闭全局浏览器池
@@ -767,6 +990,64 @@ mod tests {
         assert!(config.enable_reuse);
     }
 
+    #[test]
+    fn test_recycle_reason() {
+        let config = BrowserPoolConfig {
+            max_pages_per_instance: 3,
+            max_memory_growth_mb: 100,
+            ..BrowserPoolConfig::default()
+        };
+        let mb = 1024 * 1024;
+
+        assert_eq!(
+            config.recycle_reason(true, 2, Some(50 * mb), Some(120 * mb)),
+            None
+        );
+        assert_eq!(
+            config.recycle_reason(false, 1, None, None),
+            Some("unhealthy")
+        );
+        assert_eq!(
+            config.recycle_reason(true, 3, None, None),
+            Some("page_limit")
+        );
+        assert_eq!(
+            config.recycle_reason(true, 2, Some(50 * mb), Some(151 * mb)),
+            Some("memory")
+        );
+
+        let unlimited = BrowserPoolConfig {
+            max_pages_per_instance: 0,
+            max_memory_growth_mb: 0,
+            ..BrowserPoolConfig::default()
+        };
+        assert_eq!(
+            unlimited.recycle_reason(true, 10_000, Some(0), Some(u64::MAX)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_browser_pool_config_from_settings() {
+        let settings = PlaywrightSettings {
+            max_instances: 2,
+            min_warm_instances: 4,
+            max_pages_per_instance: 50,
+            max_memory_growth_mb: 256,
+            idle_timeout_secs: 120,
+            health_check_interval_secs: 0,
+        };
+        let config = BrowserPoolConfig::from_settings(&settings);
+        assert_eq!(config.max_instances, 2);
+        // 预热实例数不超过最大实例数
+        assert_eq!(config.min_warm_instances, 2);
+        assert_eq!(config.max_pages_per_instance, 50);
+        assert_eq!(config.max_memory_growth_mb, 256);
+        assert_eq!(config.idle_timeout_secs, 120);
+        assert_eq!(config.health_check_interval_secs, 1);
+        assert!(config.enable_reuse);
+    }
+
     #[test]
     fn test_browser_pool_stats() {
         let config = BrowserPoolConfig::default();
@@ -777,6 +1058,7 @@ mod tests {
         assert_eq!(stats.total_instances, 0);
         assert_eq!(stats.available_instances, 0);
         assert_eq!(stats.in_use_instances, 0);
+        assert_eq!(stats.utilization(), 0.0);
     }
 
     #[tokio::test]
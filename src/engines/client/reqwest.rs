@@ -4,19 +4,31 @@
 // See LICENSE file in the project root for full license information.
 
 use crate::engines::engine_client::{
-    EngineError, InternalScrapeRequest, InternalScrapeResponse, ScraperEngine,
+    AddressFamily, EngineError, HttpProtocol, InternalScrapeRequest, InternalScrapeResponse,
+    IpFamily, ScraperEngine,
 };
+use crate::engines::host_resolve::override_for;
+use crate::engines::performance::PageTimings;
+use crate::engines::proxy_url::{parse_proxy_url, redact_proxy_url};
+use crate::engines::shared::is_private_ip;
 use crate::engines::validators;
 use crate::utils::http_client::DEFAULT_USER_AGENT;
 use async_trait::async_trait;
-use log::error;
+use bytes::Bytes;
+use encoding_rs::{Encoding, UTF_8};
+use log::{error, warn};
 use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use std::collections::HashMap;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 
 /// 默认超时时间
 const DEFAULT_TIMEOUT_SECONDS: u64 = 30;
 
+/// 默认响应体大小上限（10 MiB）
+const DEFAULT_MAX_RESPONSE_BYTES: u64 = 10 * 1024 * 1024;
+
 /// 抓取引擎
 ///
 /// 基于reqwest实现的基本HTTP抓取引擎
@@ -30,6 +42,12 @@ pub struct ReqwestEngine {
     /// 引擎级请求超时（秒），用于 build_custom_client 构造临时 client（proxy/skip_tls 路径）
     /// 注入自 Settings.timeouts.engines.default_timeout_seconds（架构 MEDIUM：避免硬编码 30 秒）
     timeout_seconds: u64,
+    /// 响应体大小上限（字节），流式读取时强制执行
+    max_response_bytes: u64,
+    /// 超出上限时截断响应体（true）还是中止请求（false）
+    truncate_oversized: bool,
+    /// 引擎级 IP 地址族策略，请求未指定时使用
+    address_family: AddressFamily,
 }
 
 impl ReqwestEngine {
@@ -51,6 +69,9 @@ impl ReqwestEngine {
             proxy_url: None,
             proxy_client: None,
             timeout_seconds,
+            max_response_bytes: DEFAULT_MAX_RESPONSE_BYTES,
+            truncate_oversized: true,
+            address_family: AddressFamily::Ipv4,
         }
     }
 
@@ -81,8 +102,15 @@ impl ReqwestEngine {
         let (proxy_url, proxy_client) = if url.trim().is_empty() {
             (None, None)
         } else {
-            let client =
-                Self::build_custom_client(Some(&url), false, &http_client, timeout_seconds);
+            let client = Self::build_custom_client(
+                Some(&url),
+                false,
+                HttpProtocol::Auto,
+                &HashMap::new(),
+                AddressFamily::Ipv4,
+                &http_client,
+                timeout_seconds,
+            );
             (Some(url), Some(client))
         };
         Self {
@@ -90,44 +118,123 @@ impl ReqwestEngine {
             proxy_url,
             proxy_client,
             timeout_seconds,
+            max_response_bytes: DEFAULT_MAX_RESPONSE_BYTES,
+            truncate_oversized: true,
+            address_family: AddressFamily::Ipv4,
         }
     }
 
-    /// 构建自定义 reqwest::Client（统一处理 proxy + skip_tls）
+    /// 设置响应体大小上限
     ///
-    /// 与 init_http_client 保持一致：强制 IPv4 + dns_resolver（架构 HIGH：代理分支缺 dns_resolver）。
-    /// - `proxy_url`: 可选代理 URL（None 或空字符串表示不使用代理）
+    /// 注入自 `settings.engines.reqwest`。`truncate` 为 true 时超出部分被丢弃，
+    /// 响应标记为 `truncated`；为 false 时返回 [`EngineError::ResponseTooLarge`]。
+    pub fn with_response_limit(mut self, max_response_bytes: u64, truncate: bool) -> Self {
+        self.max_response_bytes = max_response_bytes;
+        self.truncate_oversized = truncate;
+        self
+    }
+
+    /// 设置引擎级 IP 地址族策略
+    ///
+    /// 注入自 `settings.engines.reqwest.address_family`。注入的 http_client 只走 IPv4，
+    /// 策略不是 `Ipv4` 时在此一次性重建默认 client 与代理 client，保留连接池。
+    pub fn with_address_family(mut self, family: AddressFamily) -> Self {
+        self.address_family = family;
+        if family == AddressFamily::Ipv4 {
+            return self;
+        }
+        let http_client = Arc::new(Self::build_custom_client(
+            None,
+            false,
+            HttpProtocol::Auto,
+            &HashMap::new(),
+            family,
+            &self.http_client,
+            self.timeout_seconds,
+        ));
+        if let Some(url) = self.proxy_url.as_deref() {
+            self.proxy_client = Some(Self::build_custom_client(
+                Some(url),
+                false,
+                HttpProtocol::Auto,
+                &HashMap::new(),
+                family,
+                &http_client,
+                self.timeout_seconds,
+            ));
+        }
+        self.http_client = http_client;
+        self
+    }
+
+    /// 构建自定义 reqwest::Client（统一处理 proxy + skip_tls + 主机名解析覆盖）
+    ///
+    /// 与 init_http_client 保持一致：按地址族设置 dns_resolver（架构 HIGH：代理分支缺 dns_resolver），
+    /// resolver 使用进程内共享的 DNS 缓存。
+    /// - `proxy_url`: 可选代理 URL（None 或空字符串表示不使用代理），支持 `http`、`https`、
+    ///   `socks5` 与 `socks5h`，认证信息写在 URL 中；HTTP 与 HTTPS 目标均经代理
     /// - `skip_tls`: true 时启用 `danger_accept_invalid_certs(true)`（仅开发环境，生产环境由
     ///   `ScrapeOptions::builder().skip_tls_verification(true)` 在 APP_ENVIRONMENT=production 时拒绝）
+    /// - `protocol`: 强制使用的 HTTP 协议版本，`Auto` 时按 ALPN 协商 HTTP/1.1 或 HTTP/2
+    /// - `resolve`: 主机名到 IP 的覆盖，命中的主机不走 DNS（经代理时由代理解析，覆盖不生效）
+    /// - `family`: IP 地址族策略，`Ipv4`（默认）与 `Ipv6` 只解析并绑定对应地址族，
+    ///   `HappyEyeballs` 解析全部地址并由连接器交错尝试
     /// - `timeout_seconds`: 请求超时（秒），从 Settings 注入避免硬编码
     ///   创建失败时 fallback 到注入的 http_client。
     fn build_custom_client(
         proxy_url: Option<&str>,
         skip_tls: bool,
+        protocol: HttpProtocol,
+        resolve: &HashMap<String, IpAddr>,
+        family: AddressFamily,
         fallback: &Arc<reqwest::Client>,
         timeout_seconds: u64,
     ) -> reqwest::Client {
-        // 强制 IPv4 + dns_resolver：与 init_http_client 保持一致
+        // 按地址族设置 dns_resolver：默认强制 IPv4，与 init_http_client 保持一致，
         // 避免代理路径下 DNS 解析仍走系统默认 getaddrinfo 返回 IPv6
         let mut builder = reqwest::Client::builder()
             .timeout(Duration::from_secs(timeout_seconds))
             .cookie_store(true)
-            .local_address(Some(std::net::Ipv4Addr::UNSPECIFIED.into()))
-            .dns_resolver(crate::infrastructure::dns::create_ipv4_only_resolver());
+            .dns_resolver(crate::infrastructure::dns::create_shared_family_resolver(
+                family,
+            ));
+        builder = match family {
+            AddressFamily::Ipv4 => builder.local_address(Some(Ipv4Addr::UNSPECIFIED.into())),
+            AddressFamily::Ipv6 => builder.local_address(Some(Ipv6Addr::UNSPECIFIED.into())),
+            AddressFamily::HappyEyeballs => builder,
+        };
 
         if skip_tls {
             builder = builder.danger_accept_invalid_certs(true);
         }
 
+        // 端口为 0 时 reqwest 使用 URL 中的端口
+        for (host, ip) in resolve {
+            builder = builder.resolve(host, SocketAddr::new(*ip, 0));
+        }
+
+        builder = match protocol {
+            HttpProtocol::Auto => builder,
+            HttpProtocol::Http1 => builder.http1_only(),
+            HttpProtocol::Http2 => builder.http2_prior_knowledge(),
+            #[cfg(feature = "http3")]
+            HttpProtocol::Http3 => builder.http3_prior_knowledge(),
+            // 未启用 http3 feature 时请求在 ensure_protocol_supported 中被拒绝
+            #[cfg(not(feature = "http3"))]
+            HttpProtocol::Http3 => builder,
+        };
+
         let effective_proxy = proxy_url.map(|s| s.trim()).filter(|s| !s.is_empty());
 
         match effective_proxy {
-            Some(url) => match reqwest::Proxy::http(url) {
+            Some(url) => match parse_proxy_url(url)
+                .and_then(|parsed| reqwest::Proxy::all(parsed).map_err(|e| e.to_string()))
+            {
                 Ok(proxy) => match builder.proxy(proxy).build() {
                     Ok(client) => {
                         log::debug!(
-                            "Using HTTP proxy: {} (skip_tls={}, timeout={}s)",
-                            url,
+                            "Using proxy: {} (skip_tls={}, timeout={}s)",
+                            redact_proxy_url(url),
                             skip_tls,
                             timeout_seconds
                         );
@@ -139,10 +246,7 @@ impl ReqwestEngine {
                     }
                 },
                 Err(e) => {
-                    log::warn!(
-                        "Failed to configure HTTP proxy: {}, using fallback client",
-                        e
-                    );
+                    log::warn!("Failed to configure proxy: {}, using fallback client", e);
                     (**fallback).clone()
                 }
             },
@@ -183,6 +287,9 @@ impl ReqwestEngine {
             return Self::build_custom_client(
                 proxy_url,
                 true,
+                HttpProtocol::Auto,
+                &HashMap::new(),
+                self.address_family,
                 &self.http_client,
                 self.timeout_seconds,
             );
@@ -202,6 +309,9 @@ impl ReqwestEngine {
             return Self::build_custom_client(
                 Some(url),
                 false,
+                HttpProtocol::Auto,
+                &HashMap::new(),
+                self.address_family,
                 &self.http_client,
                 self.timeout_seconds,
             );
@@ -213,10 +323,57 @@ impl ReqwestEngine {
             None => (*self.http_client).clone(),
         }
     }
+
+    /// 按请求选择 HTTP 客户端
+    ///
+    /// 指定协议版本、主机名解析覆盖或不同于引擎配置的地址族时与请求级代理一样构建临时
+    /// client（不缓存，这几类请求很少用），代理优先级不变：请求级代理 > 引擎级代理 > 无代理。
+    fn client_for_request(&self, request: &InternalScrapeRequest) -> reqwest::Client {
+        let family = request.address_family.unwrap_or(self.address_family);
+        if request.http_protocol == HttpProtocol::Auto
+            && request.resolve.is_empty()
+            && family == self.address_family
+        {
+            return self.get_client(&request.proxy, request.skip_tls_verification);
+        }
+
+        let proxy_url = request
+            .proxy
+            .as_deref()
+            .filter(|s| !s.trim().is_empty())
+            .or(self.proxy_url.as_deref());
+        Self::build_custom_client(
+            proxy_url,
+            request.skip_tls_verification,
+            request.http_protocol,
+            &request.resolve,
+            family,
+            &self.http_client,
+            self.timeout_seconds,
+        )
+    }
 }
 
-#[async_trait]
-impl ScraperEngine for ReqwestEngine {
+/// SSRF 校验：主机名被覆盖时校验覆盖地址，否则按 DNS 解析结果校验
+async fn ensure_url_allowed(request: &InternalScrapeRequest) -> Result<(), EngineError> {
+    match override_for(&request.url, &request.resolve) {
+        Some(ip) => {
+            if validators::is_internal_url(&request.url) || is_private_ip(ip) {
+                return Err(EngineError::Other(format!(
+                    "SSRF protection: resolve override {} for {} is not allowed",
+                    ip, request.url
+                )));
+            }
+            Ok(())
+        }
+        None => validators::validate_url(&request.url)
+            .await
+            .map(|_| ())
+            .map_err(|e| EngineError::Other(format!("SSRF protection: {}", e))),
+    }
+}
+
+impl ReqwestEngine {
     /// 执行HTTP抓取
     ///
     /// # 参数
@@ -227,14 +384,12 @@ impl ScraperEngine for ReqwestEngine {
     ///
     /// * `Ok(InternalScrapeResponse)` - 抓取响应
     /// * `Err(EngineError)` - 抓取过程中出现的错误
-    async fn scrape(
+    async fn execute_scrape(
         &self,
         request: &InternalScrapeRequest,
     ) -> Result<InternalScrapeResponse, EngineError> {
         // SSRF protection: validate all URLs to prevent access to internal services
-        validators::validate_url(&request.url)
-            .await
-            .map_err(|e| EngineError::Other(format!("SSRF protection: {}", e)))?;
+        ensure_url_allowed(request).await?;
 
         // Build headers
         let mut headers = HeaderMap::new();
@@ -247,9 +402,11 @@ impl ScraperEngine for ReqwestEngine {
             }
         }
 
+        ensure_protocol_supported(request.http_protocol)?;
+
         // Use shared HTTP client for connection reuse, with proxy support
         // 传入 skip_tls_verification 以支持开发环境跳过 TLS 验证（生产环境由 builder 拒绝）
-        let client = self.get_client(&request.proxy, request.skip_tls_verification);
+        let client = self.client_for_request(request);
 
         // Create request builder.
         //
@@ -285,6 +442,12 @@ impl ScraperEngine for ReqwestEngine {
         // Add custom headers
         request_builder = request_builder.headers(headers);
 
+        // HTTP/3 需要在请求上显式声明版本，客户端才会走 QUIC 连接
+        #[cfg(feature = "http3")]
+        if request.http_protocol == HttpProtocol::Http3 {
+            request_builder = request_builder.version(reqwest::Version::HTTP_3);
+        }
+
         if let Some(body) = &request.body {
             request_builder = request_builder.body(body.clone());
         }
@@ -315,7 +478,12 @@ impl ScraperEngine for ReqwestEngine {
             }
         };
 
+        let headers_after = start.elapsed();
         let status_code = response.status().as_u16();
+        // 实际协商的协议版本，便于排查目标站点在 h2/h3 下的差异
+        let http_version = format!("{:?}", response.version());
+        // 实际连接的地址族（经代理时为代理地址的地址族）
+        let ip_family = response.remote_addr().map(|addr| IpFamily::from(addr.ip()));
         let content_type = response
             .headers()
             .get("content-type")
@@ -330,6 +498,9 @@ impl ScraperEngine for ReqwestEngine {
             content_type
         };
 
+        // 内容类型不符合过滤条件时不读取响应体，节省带宽
+        check_content_type(request, status_code, &content_type)?;
+
         let mut response_headers = std::collections::HashMap::with_capacity(32);
         for (k, v) in response.headers() {
             if let Ok(v_str) = v.to_str() {
@@ -337,10 +508,11 @@ impl ScraperEngine for ReqwestEngine {
             }
         }
 
-        let content = response
-            .text()
-            .await
-            .map_err(|e| EngineError::RequestFailed(e.to_string()))?;
+        let (body, truncated) = self.read_body_limited(response, request).await?;
+        let timings = PageTimings::from_http(headers_after, start.elapsed());
+        let content = decode_body(&body, &content_type);
+        // 非文本内容（图片、PDF、压缩包等）保留原始字节，供下载模式存储
+        let raw_content = (!is_text_content_type(&content_type)).then(|| Bytes::from(body));
 
         // 同步等待
         if request.sync_wait_ms > 0 {
@@ -354,9 +526,132 @@ impl ScraperEngine for ReqwestEngine {
             content_type,
             headers: response_headers,
             response_time_ms: start.elapsed().as_millis() as u64,
+            truncated,
+            raw_content,
+            http_version: Some(http_version),
+            tls_profile: None,
+            har: None,
+            mhtml: None,
+            pdf: None,
+            blocked_requests: None,
+            evaluate_results: Vec::new(),
+            frames: Vec::new(),
+            accessibility: None,
+            timings: Some(timings),
+            ip_family,
+            escalations: Vec::new(),
         })
     }
 
+    /// 流式读取响应体，按 `max_response_bytes` 截断或中止
+    ///
+    /// 中止模式下 `Content-Length` 已超出上限时不读取响应体。
+    async fn read_body_limited(
+        &self,
+        mut response: reqwest::Response,
+        request: &InternalScrapeRequest,
+    ) -> Result<(Vec<u8>, bool), EngineError> {
+        let limit = self.max_response_bytes;
+        if !self.truncate_oversized && response.content_length().is_some_and(|len| len > limit) {
+            return Err(EngineError::ResponseTooLarge(limit));
+        }
+
+        let limit = usize::try_from(limit).unwrap_or(usize::MAX);
+        let mut body = Vec::new();
+        loop {
+            let chunk = match response.chunk().await {
+                Ok(Some(chunk)) => chunk,
+                Ok(None) => return Ok((body, false)),
+                Err(e) if e.is_timeout() => return Err(EngineError::Timeout(request.timeout)),
+                Err(e) => return Err(EngineError::RequestFailed(e.to_string())),
+            };
+
+            let remaining = limit - body.len();
+            if chunk.len() > remaining {
+                if !self.truncate_oversized {
+                    return Err(EngineError::ResponseTooLarge(self.max_response_bytes));
+                }
+                warn!(
+                    "Response body of {} exceeds {} bytes, truncating",
+                    request.url, self.max_response_bytes
+                );
+                body.extend_from_slice(&chunk[..remaining]);
+                return Ok((body, true));
+            }
+            body.extend_from_slice(&chunk);
+        }
+    }
+}
+
+/// 未启用 `http3` feature 时拒绝 HTTP/3 请求，避免静默降级
+fn ensure_protocol_supported(protocol: HttpProtocol) -> Result<(), EngineError> {
+    if protocol == HttpProtocol::Http3 && !cfg!(feature = "http3") {
+        return Err(EngineError::Other(
+            "HTTP/3 requested but the http3 feature is not enabled".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// 按请求的内容类型过滤条件校验响应（304 响应没有响应体，不做校验）
+fn check_content_type(
+    request: &InternalScrapeRequest,
+    status_code: u16,
+    content_type: &str,
+) -> Result<(), EngineError> {
+    match &request.content_type_filter {
+        Some(filter) if status_code != 304 && !filter.accepts(content_type) => {
+            Err(EngineError::ContentTypeRejected(content_type.to_string()))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Content-Type 是否为文本内容（HTML、纯文本、JSON、XML、JavaScript 等）
+fn is_text_content_type(content_type: &str) -> bool {
+    let mime = content_type
+        .split(';')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_ascii_lowercase();
+    mime.starts_with("text/")
+        || mime.ends_with("json")
+        || mime.ends_with("xml")
+        || mime.ends_with("javascript")
+}
+
+/// 按 Content-Type 中的 charset 解码响应体（默认 UTF-8，与 `Response::text` 一致）
+///
+/// 截断位置落在多字节字符中间时，残缺字符被替换为 U+FFFD。
+fn decode_body(body: &[u8], content_type: &str) -> String {
+    let encoding = content_type
+        .split(';')
+        .filter_map(|param| param.trim().split_once('='))
+        .find(|(name, _)| name.trim().eq_ignore_ascii_case("charset"))
+        .and_then(|(_, value)| Encoding::for_label(value.trim().trim_matches('"').as_bytes()))
+        .unwrap_or(UTF_8);
+    let (text, _, _) = encoding.decode(body);
+    text.into_owned()
+}
+
+#[async_trait]
+impl ScraperEngine for ReqwestEngine {
+    /// 执行HTTP抓取并记录引擎指标
+    async fn scrape(
+        &self,
+        request: &InternalScrapeRequest,
+    ) -> Result<InternalScrapeResponse, EngineError> {
+        let started = Instant::now();
+        let result = self.execute_scrape(request).await;
+        crate::infrastructure::metrics::record_engine_request(
+            self.name(),
+            started.elapsed(),
+            result.as_ref().err().map(EngineError::kind),
+        );
+        result
+    }
+
     /// 计算对请求的支持分数
     ///
     /// # 参数
@@ -367,6 +662,14 @@ impl ScraperEngine for ReqwestEngine {
     ///
     /// 支持分数（0-100），不支持JS和截图的请求返回100分
     fn support_score(&self, request: &InternalScrapeRequest) -> u8 {
+        // 无法观察页面子资源的网络请求，也无法渲染页面生成快照、PDF 或运行无障碍扫描
+        if request.capture_har
+            || request.capture_mhtml
+            || request.pdf.is_some()
+            || request.accessibility.is_some()
+        {
+            return 0;
+        }
         if request.needs_js || request.needs_screenshot {
             return 10; // Low priority for unsupported features
         }
@@ -381,13 +684,19 @@ impl ScraperEngine for ReqwestEngine {
     fn name(&self) -> &'static str {
         "reqwest"
     }
+
+    /// 自行建立连接，可直接连接覆盖的地址
+    fn supports_resolve_overrides(&self) -> bool {
+        true
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::engines::engine_client::{
-        HttpMethod, InternalScrapeRequest, InternalScreenshotConfig,
+        ContentTypeFilter, HttpMethod, HttpProtocol, InternalScrapeRequest,
+        InternalScreenshotConfig,
     };
     use std::collections::HashMap;
     use std::time::Duration;
@@ -420,6 +729,22 @@ mod tests {
             actions: Vec::new(),
             body: None,
             sync_wait_ms: 0,
+            content_type_filter: None,
+            http_protocol: HttpProtocol::Auto,
+            tls_profile: None,
+            capture_har: false,
+            capture_mhtml: false,
+            pdf: None,
+            resource_blocking: None,
+            action_error_policies: Vec::new(),
+            iframe_capture: None,
+            flatten_shadow_dom: false,
+            auto_scroll: None,
+            accessibility: None,
+            engine_tier: None,
+            excluded_engines: Vec::new(),
+            resolve: HashMap::new(),
+            address_family: None,
         }
     }
 
@@ -440,6 +765,22 @@ mod tests {
             actions: Vec::new(),
             body: None,
             sync_wait_ms: 0,
+            content_type_filter: None,
+            http_protocol: HttpProtocol::Auto,
+            tls_profile: None,
+            capture_har: false,
+            capture_mhtml: false,
+            pdf: None,
+            resource_blocking: None,
+            action_error_policies: Vec::new(),
+            iframe_capture: None,
+            flatten_shadow_dom: false,
+            auto_scroll: None,
+            accessibility: None,
+            engine_tier: None,
+            excluded_engines: Vec::new(),
+            resolve: HashMap::new(),
+            address_family: None,
         }
     }
 
@@ -456,6 +797,7 @@ mod tests {
                 selector: None,
                 quality: Some(80),
                 format: Some("jpeg".to_string()),
+                max_height: None,
             }),
             mobile: false,
             proxy: None,
@@ -465,6 +807,22 @@ mod tests {
             actions: Vec::new(),
             body: None,
             sync_wait_ms: 0,
+            content_type_filter: None,
+            http_protocol: HttpProtocol::Auto,
+            tls_profile: None,
+            capture_har: false,
+            capture_mhtml: false,
+            pdf: None,
+            resource_blocking: None,
+            action_error_policies: Vec::new(),
+            iframe_capture: None,
+            flatten_shadow_dom: false,
+            auto_scroll: None,
+            accessibility: None,
+            engine_tier: None,
+            excluded_engines: Vec::new(),
+            resolve: HashMap::new(),
+            address_family: None,
         }
     }
 
@@ -484,6 +842,37 @@ mod tests {
         assert_eq!(engine.name(), "reqwest");
     }
 
+    #[tokio::test]
+    async fn test_custom_client_routes_through_authenticated_socks5_proxy() {
+        use tokio::io::AsyncReadExt;
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let greeting = tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut buf = [0u8; 16];
+            let n = socket.read(&mut buf).await.unwrap();
+            buf[..n].to_vec()
+        });
+
+        let client = ReqwestEngine::build_custom_client(
+            Some(&format!("socks5h://user:pass@{}", addr)),
+            false,
+            HttpProtocol::Auto,
+            &HashMap::new(),
+            AddressFamily::Ipv4,
+            &create_test_client(),
+            5,
+        );
+        // 代理读取握手后断开连接，请求失败而不是绕过代理直连
+        assert!(client.get("https://example.com/").send().await.is_err());
+
+        let greeting = greeting.await.unwrap();
+        assert_eq!(greeting[0], 0x05);
+        // 客户端声明支持用户名/密码认证
+        assert!(greeting[2..].contains(&0x02));
+    }
+
     #[test]
     fn test_reqwest_engine_with_empty_proxy() {
         let client = create_test_client();
@@ -538,10 +927,65 @@ mod tests {
             actions: Vec::new(),
             body: Some("data".to_string()),
             sync_wait_ms: 0,
+            content_type_filter: None,
+            http_protocol: HttpProtocol::Auto,
+            tls_profile: None,
+            capture_har: false,
+            capture_mhtml: false,
+            pdf: None,
+            resource_blocking: None,
+            action_error_policies: Vec::new(),
+            iframe_capture: None,
+            flatten_shadow_dom: false,
+            auto_scroll: None,
+            accessibility: None,
+            engine_tier: None,
+            excluded_engines: Vec::new(),
+            resolve: HashMap::new(),
+            address_family: None,
         };
         assert_eq!(engine.support_score(&request), 100);
     }
 
+    #[test]
+    fn test_support_score_capture_har_returns_zero() {
+        let client = create_test_client();
+        let engine = ReqwestEngine::new(client);
+        let mut request = create_basic_request("https://example.com");
+        request.capture_har = true;
+        assert_eq!(engine.support_score(&request), 0);
+    }
+
+    #[test]
+    fn test_support_score_pdf_returns_zero() {
+        let client = create_test_client();
+        let engine = ReqwestEngine::new(client);
+        let mut request = create_basic_request("https://example.com");
+        request.pdf = Some(crate::engines::pdf::PdfOptions::default());
+        assert_eq!(engine.support_score(&request), 0);
+    }
+
+    #[tokio::test]
+    async fn test_ensure_url_allowed_checks_resolve_override() {
+        let mut request = create_basic_request("https://staging.example.com/page");
+        request.resolve.insert(
+            "staging.example.com".to_string(),
+            "203.0.113.5".parse().unwrap(),
+        );
+        // 覆盖命中时不做 DNS 解析，只校验覆盖地址
+        assert!(ensure_url_allowed(&request).await.is_ok());
+
+        request.resolve.insert(
+            "staging.example.com".to_string(),
+            "10.0.0.5".parse().unwrap(),
+        );
+        let err = ensure_url_allowed(&request).await.unwrap_err();
+        assert!(err.to_string().contains("SSRF protection"));
+
+        let engine = ReqwestEngine::new(create_test_client());
+        assert!(engine.supports_resolve_overrides());
+    }
+
     #[test]
     fn test_support_score_needs_js_returns_low() {
         let client = create_test_client();
@@ -580,6 +1024,22 @@ mod tests {
             actions: Vec::new(),
             body: None,
             sync_wait_ms: 0,
+            content_type_filter: None,
+            http_protocol: HttpProtocol::Auto,
+            tls_profile: None,
+            capture_har: false,
+            capture_mhtml: false,
+            pdf: None,
+            resource_blocking: None,
+            action_error_policies: Vec::new(),
+            iframe_capture: None,
+            flatten_shadow_dom: false,
+            auto_scroll: None,
+            accessibility: None,
+            engine_tier: None,
+            excluded_engines: Vec::new(),
+            resolve: HashMap::new(),
+            address_family: None,
         };
         assert_eq!(engine.support_score(&request), 10);
     }
@@ -604,6 +1064,22 @@ mod tests {
             actions: Vec::new(),
             body: None,
             sync_wait_ms: 0,
+            content_type_filter: None,
+            http_protocol: HttpProtocol::Auto,
+            tls_profile: None,
+            capture_har: false,
+            capture_mhtml: false,
+            pdf: None,
+            resource_blocking: None,
+            action_error_policies: Vec::new(),
+            iframe_capture: None,
+            flatten_shadow_dom: false,
+            auto_scroll: None,
+            accessibility: None,
+            engine_tier: None,
+            excluded_engines: Vec::new(),
+            resolve: HashMap::new(),
+            address_family: None,
         };
         // Mobile without JS should still get 100
         assert_eq!(engine.support_score(&request), 100);
@@ -729,6 +1205,22 @@ mod tests {
             actions: Vec::new(),
             body: Some("data".to_string()),
             sync_wait_ms: 0,
+            content_type_filter: None,
+            http_protocol: HttpProtocol::Auto,
+            tls_profile: None,
+            capture_har: false,
+            capture_mhtml: false,
+            pdf: None,
+            resource_blocking: None,
+            action_error_policies: Vec::new(),
+            iframe_capture: None,
+            flatten_shadow_dom: false,
+            auto_scroll: None,
+            accessibility: None,
+            engine_tier: None,
+            excluded_engines: Vec::new(),
+            resolve: HashMap::new(),
+            address_family: None,
         };
         let result = engine.scrape(&request).await;
         assert!(result.is_err());
@@ -754,6 +1246,22 @@ mod tests {
             actions: Vec::new(),
             body: None,
             sync_wait_ms: 0,
+            content_type_filter: None,
+            http_protocol: HttpProtocol::Auto,
+            tls_profile: None,
+            capture_har: false,
+            capture_mhtml: false,
+            pdf: None,
+            resource_blocking: None,
+            action_error_policies: Vec::new(),
+            iframe_capture: None,
+            flatten_shadow_dom: false,
+            auto_scroll: None,
+            accessibility: None,
+            engine_tier: None,
+            excluded_engines: Vec::new(),
+            resolve: HashMap::new(),
+            address_family: None,
         };
         let result = engine.scrape(&request).await;
         assert!(result.is_err());
@@ -839,7 +1347,7 @@ mod tests {
         ensure_debug_logger();
         let client = create_test_client();
         let engine = ReqwestEngine::new(client);
-        // Invalid proxy URL → reqwest::Proxy::http fails → log::warn! → fall back to injected client
+        // Invalid proxy URL → parse_proxy_url fails → log::warn! → fall back to injected client
         let _result = engine.get_client(&Some("://invalid".to_string()), false);
     }
 
@@ -957,4 +1465,127 @@ mod tests {
         // 验证不 panic + warn 日志输出
         let _result = engine.get_client(&Some("http://proxy:8080".to_string()), true);
     }
+
+    // === 响应体大小限制 ===
+
+    fn make_response(body: &'static str) -> reqwest::Response {
+        reqwest::Response::from(axum::http::Response::new(body))
+    }
+
+    #[tokio::test]
+    async fn test_read_body_limited_within_limit() {
+        let engine = ReqwestEngine::new(create_test_client()).with_response_limit(16, false);
+        let request = create_basic_request("https://example.com");
+        let (body, truncated) = engine
+            .read_body_limited(make_response("small body"), &request)
+            .await
+            .unwrap();
+        assert_eq!(body, b"small body");
+        assert!(!truncated);
+    }
+
+    #[tokio::test]
+    async fn test_read_body_limited_truncates_oversized_body() {
+        let engine = ReqwestEngine::new(create_test_client()).with_response_limit(5, true);
+        let request = create_basic_request("https://example.com");
+        let (body, truncated) = engine
+            .read_body_limited(make_response("0123456789"), &request)
+            .await
+            .unwrap();
+        assert_eq!(body, b"01234");
+        assert!(truncated);
+    }
+
+    #[tokio::test]
+    async fn test_read_body_limited_aborts_oversized_body() {
+        let engine = ReqwestEngine::new(create_test_client()).with_response_limit(5, false);
+        let request = create_basic_request("https://example.com");
+        let result = engine
+            .read_body_limited(make_response("0123456789"), &request)
+            .await;
+        assert!(matches!(result, Err(EngineError::ResponseTooLarge(5))));
+    }
+
+    #[test]
+    fn test_ensure_protocol_supported() {
+        assert!(ensure_protocol_supported(HttpProtocol::Auto).is_ok());
+        assert!(ensure_protocol_supported(HttpProtocol::Http1).is_ok());
+        assert!(ensure_protocol_supported(HttpProtocol::Http2).is_ok());
+        assert_eq!(
+            ensure_protocol_supported(HttpProtocol::Http3).is_ok(),
+            cfg!(feature = "http3")
+        );
+    }
+
+    #[test]
+    fn test_client_for_request_with_forced_protocol_no_panic() {
+        let engine = ReqwestEngine::with_proxy(create_test_client(), "http://proxy:8080");
+        for protocol in [
+            HttpProtocol::Http1,
+            HttpProtocol::Http2,
+            HttpProtocol::Http3,
+        ] {
+            let mut request = create_basic_request("https://example.com");
+            request.http_protocol = protocol;
+            let _client = engine.client_for_request(&request);
+        }
+    }
+
+    #[test]
+    fn test_with_address_family_and_request_override_no_panic() {
+        let engine = ReqwestEngine::with_proxy(create_test_client(), "http://proxy:8080")
+            .with_address_family(AddressFamily::HappyEyeballs);
+        assert_eq!(engine.address_family, AddressFamily::HappyEyeballs);
+        assert!(engine.proxy_client.is_some());
+
+        for family in [
+            AddressFamily::Ipv4,
+            AddressFamily::Ipv6,
+            AddressFamily::HappyEyeballs,
+        ] {
+            let mut request = create_basic_request("https://example.com");
+            request.address_family = Some(family);
+            let _client = engine.client_for_request(&request);
+        }
+    }
+
+    #[test]
+    fn test_check_content_type() {
+        let mut request = create_basic_request("https://example.com");
+        assert!(check_content_type(&request, 200, "image/png").is_ok());
+
+        request.content_type_filter = Some(ContentTypeFilter {
+            allowed: vec!["text/html".to_string()],
+            excluded: Vec::new(),
+        });
+        assert!(check_content_type(&request, 200, "text/html; charset=utf-8").is_ok());
+        assert!(check_content_type(&request, 304, "image/png").is_ok());
+        assert!(matches!(
+            check_content_type(&request, 200, "image/png"),
+            Err(EngineError::ContentTypeRejected(ct)) if ct == "image/png"
+        ));
+    }
+
+    #[test]
+    fn test_is_text_content_type() {
+        assert!(is_text_content_type("text/html; charset=utf-8"));
+        assert!(is_text_content_type("application/json"));
+        assert!(is_text_content_type("application/xhtml+xml"));
+        assert!(is_text_content_type("Application/JavaScript"));
+        assert!(!is_text_content_type("application/pdf"));
+        assert!(!is_text_content_type("image/png"));
+        assert!(!is_text_content_type("application/zip"));
+    }
+
+    #[test]
+    fn test_decode_body_uses_content_type_charset() {
+        let (gbk, _, _) = encoding_rs::GBK.encode("中文");
+        assert_eq!(decode_body(&gbk, "text/html; charset=\"GBK\""), "中文");
+        assert_eq!(decode_body("中文".as_bytes(), "text/html"), "中文");
+        // 截断在多字节字符中间
+        assert_eq!(
+            decode_body(&"中文".as_bytes()[..4], "text/html"),
+            "中\u{FFFD}"
+        );
+    }
 }
@@ -0,0 +1,144 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! 按域名指定抓取引擎
+//!
+//! 运维可以为有已知需求的站点固定引擎（如 `*.example.com -> playwright`、
+//! `news.site.com -> fire_tls`）。路由器在启发式选择之前先查询这些规则，命中的引擎
+//! 排在候选列表首位；该引擎熔断或被标记为不可用时，仍按启发式结果回退到其他引擎。
+
+/// 单条域名规则
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct DomainOverride {
+    /// 小写域名（不含 `*.` 前缀）
+    domain: String,
+    /// 是否为通配规则（`*.example.com`）
+    wildcard: bool,
+    /// 目标引擎名称
+    engine: String,
+}
+
+/// 域名到引擎的固定规则集合
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DomainOverrides {
+    rules: Vec<DomainOverride>,
+}
+
+impl DomainOverrides {
+    /// 由 `(域名模式, 引擎名称)` 列表构建规则，忽略域名或引擎为空的条目
+    ///
+    /// `news.site.com` 只匹配该主机；`*.example.com` 匹配 `example.com` 及其所有子域名。
+    pub fn new<I, D, E>(rules: I) -> Self
+    where
+        I: IntoIterator<Item = (D, E)>,
+        D: AsRef<str>,
+        E: AsRef<str>,
+    {
+        let rules = rules
+            .into_iter()
+            .filter_map(|(pattern, engine)| {
+                let pattern = pattern.as_ref().trim().to_ascii_lowercase();
+                let engine = engine.as_ref().trim().to_string();
+                let (domain, wildcard) = match pattern.strip_prefix("*.") {
+                    Some(domain) => (domain.trim_matches('.').to_string(), true),
+                    None => (pattern.trim_matches('.').to_string(), false),
+                };
+                (!domain.is_empty() && !engine.is_empty()).then_some(DomainOverride {
+                    domain,
+                    wildcard,
+                    engine,
+                })
+            })
+            .collect();
+        Self { rules }
+    }
+
+    /// 没有任何规则
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// 规则引用的引擎名称
+    pub fn engines(&self) -> impl Iterator<Item = &str> {
+        self.rules.iter().map(|rule| rule.engine.as_str())
+    }
+
+    /// 查找 URL 对应的固定引擎
+    ///
+    /// 精确主机规则优先于通配规则；多条通配规则命中时取域名最长（最具体）的一条。
+    pub fn engine_for(&self, url: &str) -> Option<&str> {
+        if self.rules.is_empty() {
+            return None;
+        }
+        let host = url::Url::parse(url)
+            .ok()?
+            .host_str()?
+            .trim_end_matches('.')
+            .to_ascii_lowercase();
+
+        if let Some(rule) = self
+            .rules
+            .iter()
+            .find(|rule| !rule.wildcard && rule.domain == host)
+        {
+            return Some(&rule.engine);
+        }
+        self.rules
+            .iter()
+            .filter(|rule| {
+                rule.wildcard
+                    && (host == rule.domain
+                        || host
+                            .strip_suffix(&rule.domain)
+                            .is_some_and(|prefix| prefix.ends_with('.')))
+            })
+            .max_by_key(|rule| rule.domain.len())
+            .map(|rule| rule.engine.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_engine_for_prefers_exact_then_most_specific_wildcard() {
+        let overrides = DomainOverrides::new([
+            ("*.example.com", "playwright"),
+            ("*.shop.example.com", "fire_cdp"),
+            ("News.Site.com", "fire_tls"),
+            ("*.site.com", "reqwest"),
+            ("", "reqwest"),
+            ("empty.com", " "),
+        ]);
+        assert_eq!(overrides.engines().count(), 4);
+
+        assert_eq!(
+            overrides.engine_for("https://example.com/a"),
+            Some("playwright")
+        );
+        assert_eq!(
+            overrides.engine_for("https://www.example.com/a"),
+            Some("playwright")
+        );
+        assert_eq!(
+            overrides.engine_for("https://eu.shop.example.com/cart"),
+            Some("fire_cdp")
+        );
+        assert_eq!(
+            overrides.engine_for("https://news.site.com/today"),
+            Some("fire_tls")
+        );
+        assert_eq!(
+            overrides.engine_for("https://blog.site.com/"),
+            Some("reqwest")
+        );
+        assert_eq!(overrides.engine_for("https://notexample.com/"), None);
+        assert_eq!(overrides.engine_for("not a url"), None);
+        assert!(DomainOverrides::default()
+            .engine_for("https://example.com")
+            .is_none());
+    }
+}
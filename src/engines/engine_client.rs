@@ -11,11 +11,22 @@
 
 #![allow(deprecated)]
 
+use crate::engines::accessibility::{AccessibilityOptions, AccessibilityReport};
+use crate::engines::auto_scroll::AutoScroll;
+use crate::engines::block_detection::{detect_block, BlockedBy};
+use crate::engines::block_escalation::EscalationAttempt;
+use crate::engines::engine_tier::EngineTier;
 use crate::engines::health_monitor::{AggregateHealthStatus, EngineHealthMonitor};
+use crate::engines::iframe::{FrameContent, IframeCapture};
+use crate::engines::pdf::PdfOptions;
+use crate::engines::performance::PageTimings;
+use crate::engines::resource_blocking::ResourceBlocking;
 use crate::engines::router::{EngineRouter, EngineRouterTrait};
 use crate::engines::validators::validate_url;
+use bytes::Bytes;
 use log::warn;
 use std::collections::HashMap;
+use std::net::IpAddr;
 use std::sync::Arc;
 use std::time::Duration;
 use thiserror::Error;
@@ -91,6 +102,8 @@ pub struct ScrapeOptions {
     pub sync_wait_ms: u32,
     /// Page actions to perform (clicks, scrolls, etc.)
     pub actions: Vec<PageAction>,
+    /// Failure policy per action, aligned with `actions` (missing entries abort)
+    pub action_error_policies: Vec<ActionErrorPolicy>,
     /// Screenshot configuration
     pub screenshot_config: Option<ScreenshotConfig>,
     /// Proxy URL (optional)
@@ -103,6 +116,36 @@ pub struct ScrapeOptions {
     pub needs_tls_fingerprint: bool,
     /// Force use of Fire Engine (CDP) for this request (default: false)
     pub use_fire_engine: bool,
+    /// Content types to accept; mismatched responses are aborted before the body is read
+    pub content_type_filter: Option<ContentTypeFilter>,
+    /// HTTP protocol version to use with the target (default: negotiated)
+    pub http_protocol: HttpProtocol,
+    /// Named browser TLS fingerprint profile for the TLS engine (e.g. `chrome-120`)
+    pub tls_profile: Option<String>,
+    /// Record network traffic as a HAR document (browser engines only, default: false)
+    pub capture_har: bool,
+    /// Save the rendered page as a single-file MHTML snapshot (browser engines only, default: false)
+    pub capture_mhtml: bool,
+    /// Print the rendered page to PDF (browser engines only)
+    pub pdf: Option<PdfOptions>,
+    /// Sub-resources to block while rendering (browser engines only)
+    pub resource_blocking: Option<ResourceBlocking>,
+    /// Capture the HTML of the page's iframes (browser engines only)
+    pub iframe_capture: Option<IframeCapture>,
+    /// Serialize open shadow roots inline into the captured HTML (browser engines only)
+    pub flatten_shadow_dom: bool,
+    /// Keep scrolling to the bottom until no new content loads (browser engines only)
+    pub auto_scroll: Option<AutoScroll>,
+    /// Run an axe-core accessibility scan on the rendered page (browser engines only)
+    pub accessibility: Option<AccessibilityOptions>,
+    /// Engine fallback chain to route through (default: the configured default tier)
+    pub engine_tier: Option<EngineTier>,
+    /// Engines that must not handle this request (e.g. excluded for the team)
+    pub excluded_engines: Vec<String>,
+    /// Host name overrides that bypass DNS (HTTP engine only, default: empty)
+    pub resolve: HashMap<String, IpAddr>,
+    /// IP address family used to connect (HTTP engine only, default: the engine setting)
+    pub address_family: Option<AddressFamily>,
 }
 
 impl Default for ScrapeOptions {
@@ -116,12 +159,28 @@ impl Default for ScrapeOptions {
             body: None,
             sync_wait_ms: 0,
             actions: Vec::new(),
+            action_error_policies: Vec::new(),
             screenshot_config: None,
             proxy: None,
             skip_tls_verification: false,
             headers: HashMap::new(),
             needs_tls_fingerprint: false,
             use_fire_engine: false,
+            content_type_filter: None,
+            http_protocol: HttpProtocol::Auto,
+            tls_profile: None,
+            capture_har: false,
+            capture_mhtml: false,
+            pdf: None,
+            resource_blocking: None,
+            iframe_capture: None,
+            flatten_shadow_dom: false,
+            auto_scroll: None,
+            accessibility: None,
+            engine_tier: None,
+            excluded_engines: Vec::new(),
+            resolve: HashMap::new(),
+            address_family: None,
         }
     }
 }
@@ -251,11 +310,138 @@ impl ScrapeOptionsBuilder {
         self
     }
 
+    pub fn content_type_filter(mut self, filter: ContentTypeFilter) -> Self {
+        self.0.content_type_filter = Some(filter);
+        self
+    }
+
+    pub fn http_protocol(mut self, protocol: HttpProtocol) -> Self {
+        self.0.http_protocol = protocol;
+        self
+    }
+
+    pub fn address_family(mut self, family: AddressFamily) -> Self {
+        self.0.address_family = Some(family);
+        self
+    }
+
+    pub fn tls_profile(mut self, profile: impl Into<String>) -> Self {
+        self.0.tls_profile = Some(profile.into());
+        self
+    }
+
+    pub fn capture_har(mut self, enabled: bool) -> Self {
+        self.0.capture_har = enabled;
+        self
+    }
+
+    pub fn capture_mhtml(mut self, enabled: bool) -> Self {
+        self.0.capture_mhtml = enabled;
+        self
+    }
+
+    pub fn pdf(mut self, options: PdfOptions) -> Self {
+        self.0.pdf = Some(options);
+        self
+    }
+
+    pub fn resource_blocking(mut self, blocking: ResourceBlocking) -> Self {
+        self.0.resource_blocking = Some(blocking);
+        self
+    }
+
+    pub fn iframe_capture(mut self, capture: IframeCapture) -> Self {
+        self.0.iframe_capture = Some(capture);
+        self
+    }
+
+    pub fn flatten_shadow_dom(mut self, enabled: bool) -> Self {
+        self.0.flatten_shadow_dom = enabled;
+        self
+    }
+
+    pub fn auto_scroll(mut self, config: AutoScroll) -> Self {
+        self.0.auto_scroll = Some(config);
+        self
+    }
+
+    pub fn accessibility(mut self, options: AccessibilityOptions) -> Self {
+        self.0.accessibility = Some(options);
+        self
+    }
+
+    pub fn resolve(mut self, overrides: HashMap<String, IpAddr>) -> Self {
+        self.0.resolve = overrides;
+        self
+    }
+
+    pub fn engine_tier(mut self, tier: EngineTier) -> Self {
+        self.0.engine_tier = Some(tier);
+        self
+    }
+
+    pub fn excluded_engines(mut self, engines: Vec<String>) -> Self {
+        self.0.excluded_engines = engines;
+        self
+    }
+
+    pub fn actions(mut self, actions: Vec<PageAction>, policies: Vec<ActionErrorPolicy>) -> Self {
+        self.0.actions = actions;
+        self.0.action_error_policies = policies;
+        self
+    }
+
     pub fn build(self) -> ScrapeOptions {
         self.0
     }
 }
 
+/// Content-Type allow/deny lists applied to responses.
+///
+/// Patterns are media types (`application/pdf`) or type wildcards (`image/*`),
+/// compared case-insensitively without parameters such as `charset`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ContentTypeFilter {
+    /// Accepted media types; empty accepts everything not excluded
+    pub allowed: Vec<String>,
+    /// Rejected media types, checked before `allowed`
+    pub excluded: Vec<String>,
+}
+
+impl ContentTypeFilter {
+    /// Whether a response with the given `Content-Type` header should be kept.
+    pub fn accepts(&self, content_type: &str) -> bool {
+        let media_type = content_type
+            .split(';')
+            .next()
+            .unwrap_or_default()
+            .trim()
+            .to_ascii_lowercase();
+        if self
+            .excluded
+            .iter()
+            .any(|pattern| media_type_matches(pattern, &media_type))
+        {
+            return false;
+        }
+        self.allowed.is_empty()
+            || self
+                .allowed
+                .iter()
+                .any(|pattern| media_type_matches(pattern, &media_type))
+    }
+}
+
+fn media_type_matches(pattern: &str, media_type: &str) -> bool {
+    let pattern = pattern.trim().to_ascii_lowercase();
+    match pattern.strip_suffix("/*") {
+        Some(prefix) => media_type
+            .split_once('/')
+            .is_some_and(|(top_level, _)| top_level == prefix),
+        None => pattern == "*/*" || pattern == media_type,
+    }
+}
+
 /// Page action to perform during scraping.
 #[derive(Debug, Clone)]
 pub enum PageAction {
@@ -267,8 +453,68 @@ pub enum PageAction {
     Scroll { direction: ScrollDirection },
     /// Input text into element
     Input { selector: String, text: String },
+    /// Run JavaScript in the page and capture its JSON result
+    Evaluate { script: String },
+    /// Wait until an element matching the selector reaches the given state
+    WaitForSelector {
+        selector: String,
+        timeout_ms: u64,
+        state: SelectorState,
+    },
+    /// Wait for the page to finish a navigation (e.g. after a click)
+    WaitForNavigation { timeout_ms: u64 },
+    /// Choose an option of a `<select>` element by value
+    Select { selector: String, value: String },
+    /// Check or uncheck a checkbox / radio button
+    Check { selector: String, checked: bool },
+    /// Press a key on the focused element (e.g. `Enter`, `Tab`)
+    PressKey { key: String },
+    /// Submit the form containing the element (or the form itself)
+    Submit { selector: String },
+    /// Move the mouse over an element
+    Hover { selector: String },
+    /// Drag with the left mouse button from one element to another
+    Drag { from: String, to: String },
+    /// Dispatch a raw `keydown` event
+    KeyDown { key: String },
+    /// Dispatch a raw `keyup` event
+    KeyUp { key: String },
+}
+
+/// What to do when a page action fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ActionErrorPolicy {
+    /// Fail the whole scrape
+    #[default]
+    Abort,
+    /// Log the failure and run the next action
+    Continue,
+}
+
+impl ActionErrorPolicy {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Abort => "abort",
+            Self::Continue => "continue",
+        }
+    }
+}
+
+impl std::str::FromStr for ActionErrorPolicy {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "abort" => Ok(Self::Abort),
+            "continue" => Ok(Self::Continue),
+            other => Err(format!("Unknown action error policy: {}", other)),
+        }
+    }
 }
 
+/// Default timeout for actions that wait on the page, in milliseconds.
+pub const DEFAULT_ACTION_TIMEOUT_MS: u64 = 30_000;
+
 /// Scroll direction for PageAction.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum ScrollDirection {
@@ -279,6 +525,45 @@ pub enum ScrollDirection {
     Top,
 }
 
+/// Element state awaited by `PageAction::WaitForSelector`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SelectorState {
+    /// Present in the DOM
+    Attached,
+    /// Present in the DOM and rendered with a non-empty box
+    #[default]
+    Visible,
+    /// Absent from the DOM or not rendered
+    Hidden,
+    /// Absent from the DOM
+    Detached,
+}
+
+impl SelectorState {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Attached => "attached",
+            Self::Visible => "visible",
+            Self::Hidden => "hidden",
+            Self::Detached => "detached",
+        }
+    }
+}
+
+impl std::str::FromStr for SelectorState {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "attached" => Ok(Self::Attached),
+            "visible" => Ok(Self::Visible),
+            "hidden" => Ok(Self::Hidden),
+            "detached" => Ok(Self::Detached),
+            other => Err(format!("Unknown selector state: {}", other)),
+        }
+    }
+}
+
 /// Screenshot configuration.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ScreenshotConfig {
@@ -286,10 +571,13 @@ pub struct ScreenshotConfig {
     pub full_page: bool,
     /// CSS selector for element-specific screenshot
     pub selector: Option<String>,
-    /// Image quality 1-100 (for JPEG, default: 80)
+    /// Image quality 1-100 (for JPEG and WebP, default: 80)
     pub quality: Option<u8>,
-    /// Image format (default: "jpeg")
+    /// Image format: "jpeg", "png" or "webp" (default: "jpeg")
     pub format: Option<String>,
+    /// Maximum height of a full-page capture in CSS pixels (default: 16384, max: 65535).
+    /// Taller pages are captured in viewport segments and stitched, then cut off here.
+    pub max_height: Option<u32>,
 }
 
 impl Default for ScreenshotConfig {
@@ -299,6 +587,7 @@ impl Default for ScreenshotConfig {
             selector: None,
             quality: Some(80),
             format: Some("jpeg".to_string()),
+            max_height: None,
         }
     }
 }
@@ -322,6 +611,36 @@ pub struct ScrapeResponse {
     pub response_time_ms: u64,
     /// Final URL after any redirects
     pub final_url: Option<String>,
+    /// Whether the body was cut off at the engine's response size limit
+    pub truncated: bool,
+    /// Raw body bytes for non-text content types (images, PDFs, archives)
+    pub raw_content: Option<Bytes>,
+    /// Negotiated HTTP protocol version (e.g. `HTTP/2.0`), when known
+    pub http_version: Option<String>,
+    /// TLS fingerprint profile used by the engine, when one was applied
+    pub tls_profile: Option<String>,
+    /// HAR 1.2 document of the page's network traffic (if requested)
+    pub har: Option<serde_json::Value>,
+    /// MHTML snapshot of the page with its resources inlined (if requested)
+    pub mhtml: Option<String>,
+    /// PDF rendering of the page (if requested)
+    pub pdf: Option<Bytes>,
+    /// Number of sub-resource requests blocked by the browser engine
+    pub blocked_requests: Option<u64>,
+    /// JSON values returned by `Evaluate` actions, in action order
+    pub evaluate_results: Vec<serde_json::Value>,
+    /// HTML of the page's iframes (if requested)
+    pub frames: Vec<FrameContent>,
+    /// axe-core accessibility scan of the page (if requested)
+    pub accessibility: Option<AccessibilityReport>,
+    /// Load timings of the page, as far as the engine can measure them
+    pub timings: Option<PageTimings>,
+    /// IP family of the address the response came from (HTTP engine only)
+    pub ip_family: Option<IpFamily>,
+    /// Anti-bot protection that served this response instead of the target page
+    pub blocked_by: Option<BlockedBy>,
+    /// Engine escalations made after blocked responses, starting with the initial attempt
+    pub escalations: Vec<EscalationAttempt>,
 }
 
 impl ScrapeResponse {
@@ -339,6 +658,21 @@ impl ScrapeResponse {
             headers: HashMap::new(),
             response_time_ms: 0,
             final_url: None,
+            truncated: false,
+            raw_content: None,
+            http_version: None,
+            tls_profile: None,
+            har: None,
+            mhtml: None,
+            pdf: None,
+            blocked_requests: None,
+            evaluate_results: Vec::new(),
+            frames: Vec::new(),
+            accessibility: None,
+            timings: None,
+            ip_family: None,
+            blocked_by: None,
+            escalations: Vec::new(),
         }
     }
 
@@ -346,6 +680,14 @@ impl ScrapeResponse {
     pub fn is_success(&self) -> bool {
         (200..300).contains(&self.status_code)
     }
+
+    /// Size of the downloaded body in bytes (raw bytes for binary content, text otherwise).
+    pub fn body_bytes(&self) -> u64 {
+        match &self.raw_content {
+            Some(raw) => raw.len() as u64,
+            None => self.content.len() as u64,
+        }
+    }
 }
 
 // === Internal Request/Response Types for Router ===
@@ -368,8 +710,24 @@ pub struct InternalScrapeRequest {
     pub needs_tls_fingerprint: bool,
     pub use_fire_engine: bool,
     pub actions: Vec<InternalPageAction>,
+    pub action_error_policies: Vec<ActionErrorPolicy>,
     pub body: Option<String>,
     pub sync_wait_ms: u32,
+    pub content_type_filter: Option<ContentTypeFilter>,
+    pub http_protocol: HttpProtocol,
+    pub tls_profile: Option<String>,
+    pub capture_har: bool,
+    pub capture_mhtml: bool,
+    pub pdf: Option<PdfOptions>,
+    pub resource_blocking: Option<ResourceBlocking>,
+    pub iframe_capture: Option<IframeCapture>,
+    pub flatten_shadow_dom: bool,
+    pub auto_scroll: Option<AutoScroll>,
+    pub accessibility: Option<AccessibilityOptions>,
+    pub engine_tier: Option<EngineTier>,
+    pub excluded_engines: Vec<String>,
+    pub resolve: HashMap<String, IpAddr>,
+    pub address_family: Option<AddressFamily>,
 }
 
 /// Internal screenshot configuration
@@ -380,17 +738,67 @@ pub struct InternalScreenshotConfig {
     pub selector: Option<String>,
     pub quality: Option<u8>,
     pub format: Option<String>,
+    pub max_height: Option<u32>,
 }
 
 /// Internal page action for engine operations
 #[derive(Debug, Clone)]
 #[allow(dead_code)]
 pub enum InternalPageAction {
-    Wait { milliseconds: u64 },
-    Click { selector: String },
-    Scroll { direction: String },
-    Input { selector: String, text: String },
-    Screenshot { full_page: bool },
+    Wait {
+        milliseconds: u64,
+    },
+    Click {
+        selector: String,
+    },
+    Scroll {
+        direction: String,
+    },
+    Input {
+        selector: String,
+        text: String,
+    },
+    Screenshot {
+        full_page: bool,
+    },
+    Evaluate {
+        script: String,
+    },
+    WaitForSelector {
+        selector: String,
+        timeout_ms: u64,
+        state: String,
+    },
+    WaitForNavigation {
+        timeout_ms: u64,
+    },
+    Select {
+        selector: String,
+        value: String,
+    },
+    Check {
+        selector: String,
+        checked: bool,
+    },
+    PressKey {
+        key: String,
+    },
+    Submit {
+        selector: String,
+    },
+    Hover {
+        selector: String,
+    },
+    Drag {
+        from: String,
+        to: String,
+    },
+    KeyDown {
+        key: String,
+    },
+    KeyUp {
+        key: String,
+    },
 }
 
 /// Internal response type for engine operations
@@ -403,6 +811,20 @@ pub struct InternalScrapeResponse {
     pub content_type: String,
     pub headers: HashMap<String, String>,
     pub response_time_ms: u64,
+    pub truncated: bool,
+    pub raw_content: Option<Bytes>,
+    pub http_version: Option<String>,
+    pub tls_profile: Option<String>,
+    pub har: Option<serde_json::Value>,
+    pub mhtml: Option<String>,
+    pub pdf: Option<Bytes>,
+    pub blocked_requests: Option<u64>,
+    pub evaluate_results: Vec<serde_json::Value>,
+    pub frames: Vec<FrameContent>,
+    pub accessibility: Option<AccessibilityReport>,
+    pub timings: Option<PageTimings>,
+    pub ip_family: Option<IpFamily>,
+    pub escalations: Vec<EscalationAttempt>,
 }
 
 /// Convert from public ScrapeRequest to internal format
@@ -436,6 +858,44 @@ impl ScrapeRequest {
                     selector: selector.clone(),
                     text: text.clone(),
                 },
+                PageAction::Evaluate { script } => InternalPageAction::Evaluate {
+                    script: script.clone(),
+                },
+                PageAction::WaitForSelector {
+                    selector,
+                    timeout_ms,
+                    state,
+                } => InternalPageAction::WaitForSelector {
+                    selector: selector.clone(),
+                    timeout_ms: *timeout_ms,
+                    state: state.as_str().to_string(),
+                },
+                PageAction::WaitForNavigation { timeout_ms } => {
+                    InternalPageAction::WaitForNavigation {
+                        timeout_ms: *timeout_ms,
+                    }
+                }
+                PageAction::Select { selector, value } => InternalPageAction::Select {
+                    selector: selector.clone(),
+                    value: value.clone(),
+                },
+                PageAction::Check { selector, checked } => InternalPageAction::Check {
+                    selector: selector.clone(),
+                    checked: *checked,
+                },
+                PageAction::PressKey { key } => InternalPageAction::PressKey { key: key.clone() },
+                PageAction::Submit { selector } => InternalPageAction::Submit {
+                    selector: selector.clone(),
+                },
+                PageAction::Hover { selector } => InternalPageAction::Hover {
+                    selector: selector.clone(),
+                },
+                PageAction::Drag { from, to } => InternalPageAction::Drag {
+                    from: from.clone(),
+                    to: to.clone(),
+                },
+                PageAction::KeyDown { key } => InternalPageAction::KeyDown { key: key.clone() },
+                PageAction::KeyUp { key } => InternalPageAction::KeyUp { key: key.clone() },
             })
             .collect();
 
@@ -448,6 +908,7 @@ impl ScrapeRequest {
                     selector: config.selector.clone(),
                     quality: config.quality,
                     format: config.format.clone(),
+                    max_height: config.max_height,
                 });
 
         InternalScrapeRequest {
@@ -464,8 +925,24 @@ impl ScrapeRequest {
             needs_tls_fingerprint: options.needs_tls_fingerprint,
             use_fire_engine: options.use_fire_engine,
             actions,
+            action_error_policies: options.action_error_policies.clone(),
             body: options.body.clone(),
             sync_wait_ms: options.sync_wait_ms,
+            content_type_filter: options.content_type_filter.clone(),
+            http_protocol: options.http_protocol,
+            tls_profile: options.tls_profile.clone(),
+            capture_har: options.capture_har,
+            capture_mhtml: options.capture_mhtml,
+            pdf: options.pdf,
+            resource_blocking: options.resource_blocking.clone(),
+            iframe_capture: options.iframe_capture,
+            flatten_shadow_dom: options.flatten_shadow_dom,
+            auto_scroll: options.auto_scroll,
+            accessibility: options.accessibility.clone(),
+            engine_tier: options.engine_tier,
+            excluded_engines: options.excluded_engines.clone(),
+            resolve: options.resolve.clone(),
+            address_family: options.address_family,
         }
     }
 }
@@ -477,6 +954,95 @@ pub enum HttpMethod {
     Post,
 }
 
+/// HTTP protocol version used to talk to the target.
+///
+/// `Auto` negotiates HTTP/1.1 or HTTP/2 via ALPN. The other variants force a
+/// single protocol; `Http3` (QUIC) requires the `http3` feature.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HttpProtocol {
+    #[default]
+    Auto,
+    Http1,
+    Http2,
+    Http3,
+}
+
+/// IP address family policy for outgoing connections.
+///
+/// `Ipv4` and `Ipv6` only connect over that family; `HappyEyeballs` resolves
+/// both and races them (RFC 8305), falling back when the first one stalls.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AddressFamily {
+    #[default]
+    Ipv4,
+    Ipv6,
+    HappyEyeballs,
+}
+
+impl AddressFamily {
+    /// Whether an address of the given family may be used under this policy.
+    pub fn allows(&self, ip: IpAddr) -> bool {
+        match self {
+            Self::Ipv4 => ip.is_ipv4(),
+            Self::Ipv6 => ip.is_ipv6(),
+            Self::HappyEyeballs => true,
+        }
+    }
+}
+
+impl std::str::FromStr for AddressFamily {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "ipv4" | "v4" | "ipv4_only" => Ok(Self::Ipv4),
+            "ipv6" | "v6" | "ipv6_only" => Ok(Self::Ipv6),
+            "happy_eyeballs" | "happy-eyeballs" | "dual" => Ok(Self::HappyEyeballs),
+            other => Err(format!("Unknown address family: {}", other)),
+        }
+    }
+}
+
+/// IP family of the address a response was received from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IpFamily {
+    Ipv4,
+    Ipv6,
+}
+
+impl IpFamily {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Ipv4 => "ipv4",
+            Self::Ipv6 => "ipv6",
+        }
+    }
+}
+
+impl From<IpAddr> for IpFamily {
+    fn from(ip: IpAddr) -> Self {
+        if ip.is_ipv4() {
+            Self::Ipv4
+        } else {
+            Self::Ipv6
+        }
+    }
+}
+
+impl std::str::FromStr for HttpProtocol {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "auto" => Ok(Self::Auto),
+            "http1" | "http/1.1" | "h1" => Ok(Self::Http1),
+            "http2" | "http/2" | "h2" => Ok(Self::Http2),
+            "http3" | "http/3" | "h3" => Ok(Self::Http3),
+            other => Err(format!("Unknown HTTP protocol: {}", other)),
+        }
+    }
+}
+
 /// Convert from internal ScrapeResponse to public format
 impl InternalScrapeResponse {
     #[inline]
@@ -489,6 +1055,21 @@ impl InternalScrapeResponse {
             headers: self.headers.clone(),
             response_time_ms: self.response_time_ms,
             final_url: Some(original_url.to_string()),
+            truncated: self.truncated,
+            raw_content: self.raw_content.clone(),
+            http_version: self.http_version.clone(),
+            tls_profile: self.tls_profile.clone(),
+            har: self.har.clone(),
+            mhtml: self.mhtml.clone(),
+            pdf: self.pdf.clone(),
+            blocked_requests: self.blocked_requests,
+            evaluate_results: self.evaluate_results.clone(),
+            frames: self.frames.clone(),
+            accessibility: self.accessibility.clone(),
+            timings: self.timings.clone(),
+            ip_family: self.ip_family,
+            blocked_by: detect_block(self.status_code, &self.headers, &self.content),
+            escalations: self.escalations.clone(),
         }
     }
 }
@@ -528,6 +1109,14 @@ pub enum EngineError {
     #[error("Request expired")]
     Expired,
 
+    /// Response body exceeded the configured size limit (bytes)
+    #[error("Response body exceeds {0} bytes")]
+    ResponseTooLarge(u64),
+
+    /// Response Content-Type rejected by the request's content type filter
+    #[error("Content type not accepted: {0}")]
+    ContentTypeRejected(String),
+
     /// Other error
     #[error("Other error: {0}")]
     Other(String),
@@ -569,9 +1158,29 @@ impl EngineError {
             Self::Internal(_) => false,
             Self::AllEnginesFailed(_) => false,
             Self::Expired => false,
+            Self::ResponseTooLarge(_) => false,
+            Self::ContentTypeRejected(_) => false,
             Self::Other(_) => false,
         }
     }
+
+    /// Stable, low-cardinality error kind used as a metrics label.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::RequestFailed(_) => "request_failed",
+            Self::Timeout(_) => "timeout",
+            Self::AllEnginesFailed(_) => "all_engines_failed",
+            Self::NoEnginesAvailable => "no_engines_available",
+            Self::InvalidUrl(_) => "invalid_url",
+            Self::SsrfProtection(_) => "ssrf_protection",
+            Self::BrowserError(_) => "browser_error",
+            Self::Expired => "expired",
+            Self::ResponseTooLarge(_) => "response_too_large",
+            Self::ContentTypeRejected(_) => "content_type_rejected",
+            Self::Other(_) => "other",
+            Self::Internal(_) => "internal",
+        }
+    }
 }
 
 use async_trait::async_trait;
@@ -605,6 +1214,22 @@ pub trait ScraperEngine: Send + Sync {
     fn supports_tls_fingerprint(&self) -> bool {
         false
     }
+
+    /// Check if the engine honors per-request host overrides (`resolve`)
+    ///
+    /// Returns true if the engine connects to the overridden address
+    /// instead of resolving the host name through DNS.
+    fn supports_resolve_overrides(&self) -> bool {
+        false
+    }
+
+    /// Verify at startup that the engine can actually serve requests
+    ///
+    /// Engines backed by a browser or a remote service override this to
+    /// check that it answers; the default treats the engine as always viable.
+    async fn preflight(&self) -> Result<(), EngineError> {
+        Ok(())
+    }
 }
 
 /// Health status of the engine system.
@@ -799,6 +1424,8 @@ fn convert_error(e: EngineError) -> EngineError {
         EngineError::SsrfProtection(msg) => EngineError::SsrfProtection(msg),
         EngineError::BrowserError(msg) => EngineError::BrowserError(msg),
         EngineError::Expired => EngineError::Internal("Request expired".to_string()),
+        EngineError::ResponseTooLarge(limit) => EngineError::ResponseTooLarge(limit),
+        EngineError::ContentTypeRejected(ct) => EngineError::ContentTypeRejected(ct),
         EngineError::Other(msg) => EngineError::Internal(msg),
         EngineError::NoEnginesAvailable => EngineError::NoEnginesAvailable,
         EngineError::InvalidUrl(msg) => EngineError::InvalidUrl(msg),
@@ -1019,6 +1646,7 @@ mod tests {
             selector: None,
             quality: Some(90),
             format: Some("png".to_string()),
+            max_height: None,
         };
         assert_ne!(c1, c3);
     }
@@ -1100,6 +1728,7 @@ mod tests {
                     selector: Some("#main".to_string()),
                     quality: Some(90),
                     format: Some("png".to_string()),
+                    max_height: None,
                 })
                 .build(),
         );
@@ -1151,12 +1780,15 @@ mod tests {
                 selector: "#field".to_string(),
                 text: "hello".to_string(),
             },
+            PageAction::Evaluate {
+                script: "document.title".to_string(),
+            },
         ];
 
         let request = ScrapeRequest::new("https://example.com").with_options(options);
         let internal = request.to_internal();
 
-        assert_eq!(internal.actions.len(), 7);
+        assert_eq!(internal.actions.len(), 8);
         // Verify Wait action
         match &internal.actions[0] {
             InternalPageAction::Wait { milliseconds } => assert_eq!(*milliseconds, 500),
@@ -1192,6 +1824,10 @@ mod tests {
             }
             other => panic!("Expected Input, got {:?}", other),
         }
+        match &internal.actions[7] {
+            InternalPageAction::Evaluate { script } => assert_eq!(script, "document.title"),
+            other => panic!("Expected Evaluate, got {:?}", other),
+        }
     }
 
     // === InternalScrapeResponse::to_public tests ===
@@ -1209,6 +1845,20 @@ mod tests {
                 h
             },
             response_time_ms: 42,
+            truncated: false,
+            raw_content: None,
+            http_version: None,
+            tls_profile: None,
+            har: None,
+            mhtml: None,
+            pdf: None,
+            blocked_requests: None,
+            evaluate_results: Vec::new(),
+            frames: Vec::new(),
+            accessibility: None,
+            timings: None,
+            ip_family: None,
+            escalations: Vec::new(),
         };
 
         let public = internal.to_public("https://example.com/page");
@@ -1223,10 +1873,157 @@ mod tests {
             public.final_url,
             Some("https://example.com/page".to_string())
         );
+        assert_eq!(public.blocked_by, None);
+
+        let mut challenge = internal;
+        challenge.status_code = 403;
+        challenge
+            .headers
+            .insert("cf-mitigated".to_string(), "challenge".to_string());
+        assert_eq!(
+            challenge.to_public("https://example.com/page").blocked_by,
+            Some(BlockedBy::Cloudflare)
+        );
     }
 
     // === EngineError tests ===
 
+    #[test]
+    fn test_engine_error_kind_labels() {
+        assert_eq!(
+            EngineError::RequestFailed("err".to_string()).kind(),
+            "request_failed"
+        );
+        assert_eq!(
+            EngineError::Timeout(Duration::from_secs(10)).kind(),
+            "timeout"
+        );
+        assert_eq!(
+            EngineError::BrowserError("crash".to_string()).kind(),
+            "browser_error"
+        );
+        assert_eq!(EngineError::Expired.kind(), "expired");
+        assert_eq!(
+            EngineError::ResponseTooLarge(1024).kind(),
+            "response_too_large"
+        );
+        assert_eq!(
+            EngineError::ContentTypeRejected("image/png".to_string()).kind(),
+            "content_type_rejected"
+        );
+    }
+
+    #[test]
+    fn test_http_protocol_from_str() {
+        assert_eq!("auto".parse::<HttpProtocol>(), Ok(HttpProtocol::Auto));
+        assert_eq!("HTTP1".parse::<HttpProtocol>(), Ok(HttpProtocol::Http1));
+        assert_eq!("h2".parse::<HttpProtocol>(), Ok(HttpProtocol::Http2));
+        assert_eq!("http/3".parse::<HttpProtocol>(), Ok(HttpProtocol::Http3));
+        assert!("spdy".parse::<HttpProtocol>().is_err());
+    }
+
+    #[test]
+    fn test_address_family_from_str_and_allows() {
+        assert_eq!("IPv4".parse::<AddressFamily>(), Ok(AddressFamily::Ipv4));
+        assert_eq!("v6".parse::<AddressFamily>(), Ok(AddressFamily::Ipv6));
+        assert_eq!(
+            "happy-eyeballs".parse::<AddressFamily>(),
+            Ok(AddressFamily::HappyEyeballs)
+        );
+        assert!("ipv5".parse::<AddressFamily>().is_err());
+
+        let v4: IpAddr = "203.0.113.5".parse().unwrap();
+        let v6: IpAddr = "2001:db8::1".parse().unwrap();
+        assert!(AddressFamily::Ipv4.allows(v4) && !AddressFamily::Ipv4.allows(v6));
+        assert!(AddressFamily::Ipv6.allows(v6) && !AddressFamily::Ipv6.allows(v4));
+        assert!(AddressFamily::HappyEyeballs.allows(v4) && AddressFamily::HappyEyeballs.allows(v6));
+        assert_eq!(IpFamily::from(v6).as_str(), "ipv6");
+    }
+
+    #[test]
+    fn test_selector_state_from_str() {
+        assert_eq!(
+            "Visible".parse::<SelectorState>(),
+            Ok(SelectorState::Visible)
+        );
+        assert_eq!(
+            "detached".parse::<SelectorState>(),
+            Ok(SelectorState::Detached)
+        );
+        assert!("focused".parse::<SelectorState>().is_err());
+        assert_eq!(SelectorState::default().as_str(), "visible");
+    }
+
+    #[test]
+    fn test_action_error_policy_from_str() {
+        assert_eq!(
+            "Continue".parse::<ActionErrorPolicy>(),
+            Ok(ActionErrorPolicy::Continue)
+        );
+        assert_eq!(
+            "abort".parse::<ActionErrorPolicy>(),
+            Ok(ActionErrorPolicy::Abort)
+        );
+        assert!("retry".parse::<ActionErrorPolicy>().is_err());
+        assert_eq!(ActionErrorPolicy::default(), ActionErrorPolicy::Abort);
+    }
+
+    #[test]
+    fn test_to_internal_keeps_form_actions_and_policies() {
+        let request = ScrapeRequest::new("https://example.com").with_options(
+            ScrapeOptions::builder()
+                .actions(
+                    vec![
+                        PageAction::Select {
+                            selector: "#country".to_string(),
+                            value: "de".to_string(),
+                        },
+                        PageAction::Submit {
+                            selector: "form".to_string(),
+                        },
+                    ],
+                    vec![ActionErrorPolicy::Continue, ActionErrorPolicy::Abort],
+                )
+                .build(),
+        );
+
+        let internal = request.to_internal();
+        assert!(matches!(
+            &internal.actions[0],
+            InternalPageAction::Select { selector, value } if selector == "#country" && value == "de"
+        ));
+        assert!(matches!(
+            &internal.actions[1],
+            InternalPageAction::Submit { selector } if selector == "form"
+        ));
+        assert_eq!(
+            internal.action_error_policies,
+            vec![ActionErrorPolicy::Continue, ActionErrorPolicy::Abort]
+        );
+    }
+
+    #[test]
+    fn test_content_type_filter_accepts() {
+        let filter = ContentTypeFilter::default();
+        assert!(filter.accepts("application/zip"));
+
+        let filter = ContentTypeFilter {
+            allowed: vec!["text/html".to_string(), "application/PDF".to_string()],
+            excluded: Vec::new(),
+        };
+        assert!(filter.accepts("text/html; charset=utf-8"));
+        assert!(filter.accepts("application/pdf"));
+        assert!(!filter.accepts("image/png"));
+
+        let filter = ContentTypeFilter {
+            allowed: vec!["image/*".to_string()],
+            excluded: vec!["image/svg+xml".to_string()],
+        };
+        assert!(filter.accepts("image/png"));
+        assert!(!filter.accepts("image/svg+xml"));
+        assert!(!filter.accepts("text/html"));
+    }
+
     #[test]
     fn test_engine_error_all_retryable_variants() {
         assert!(EngineError::RequestFailed("err".to_string()).is_retryable());
@@ -1528,6 +2325,20 @@ mod tests {
             content_type: "text/html".to_string(),
             headers: std::collections::HashMap::new(),
             response_time_ms: 150,
+            truncated: false,
+            raw_content: None,
+            http_version: None,
+            tls_profile: None,
+            har: None,
+            mhtml: None,
+            pdf: None,
+            blocked_requests: None,
+            evaluate_results: Vec::new(),
+            frames: Vec::new(),
+            accessibility: None,
+            timings: None,
+            ip_family: None,
+            escalations: Vec::new(),
         };
         let public = internal.to_public("https://example.com");
         assert_eq!(public.status_code, 200);
@@ -1550,6 +2361,20 @@ mod tests {
             content_type: "application/json".to_string(),
             headers,
             response_time_ms: 500,
+            truncated: false,
+            raw_content: None,
+            http_version: None,
+            tls_profile: None,
+            har: None,
+            mhtml: None,
+            pdf: None,
+            blocked_requests: None,
+            evaluate_results: Vec::new(),
+            frames: Vec::new(),
+            accessibility: None,
+            timings: None,
+            ip_family: None,
+            escalations: Vec::new(),
         };
         let public = internal.to_public("https://test.com/page");
         assert_eq!(public.status_code, 404);
@@ -1570,6 +2395,20 @@ mod tests {
             content_type: String::new(),
             headers: std::collections::HashMap::new(),
             response_time_ms: 0,
+            truncated: false,
+            raw_content: None,
+            http_version: None,
+            tls_profile: None,
+            har: None,
+            mhtml: None,
+            pdf: None,
+            blocked_requests: None,
+            evaluate_results: Vec::new(),
+            frames: Vec::new(),
+            accessibility: None,
+            timings: None,
+            ip_family: None,
+            escalations: Vec::new(),
         };
         let public = internal.to_public("");
         assert_eq!(public.status_code, 204);
@@ -1599,7 +2438,7 @@ mod tests {
     use async_trait::async_trait;
 
     enum MockRouteResult {
-        Success(InternalScrapeResponse),
+        Success(Box<InternalScrapeResponse>),
         Timeout,
         AllEnginesFailed(String),
     }
@@ -1612,14 +2451,28 @@ mod tests {
     impl MockEngineRouter {
         fn new_success() -> Self {
             Self {
-                result: MockRouteResult::Success(InternalScrapeResponse {
+                result: MockRouteResult::Success(Box::new(InternalScrapeResponse {
                     status_code: 200,
                     content: "test content".to_string(),
                     screenshot: None,
                     content_type: "text/html".to_string(),
                     headers: HashMap::new(),
                     response_time_ms: 100,
-                }),
+                    truncated: false,
+                    raw_content: None,
+                    http_version: None,
+                    tls_profile: None,
+                    har: None,
+                    mhtml: None,
+                    pdf: None,
+                    blocked_requests: None,
+                    evaluate_results: Vec::new(),
+                    frames: Vec::new(),
+                    accessibility: None,
+                    timings: None,
+                    ip_family: None,
+                    escalations: Vec::new(),
+                })),
                 engines: vec!["mock-engine".to_string()],
             }
         }
@@ -1646,7 +2499,7 @@ mod tests {
             _request: &InternalScrapeRequest,
         ) -> Result<InternalScrapeResponse, EngineError> {
             match &self.result {
-                MockRouteResult::Success(resp) => Ok(resp.clone()),
+                MockRouteResult::Success(resp) => Ok(resp.as_ref().clone()),
                 MockRouteResult::Timeout => Err(EngineError::Timeout(Duration::from_secs(30))),
                 MockRouteResult::AllEnginesFailed(msg) => {
                     Err(EngineError::AllEnginesFailed(msg.clone()))
@@ -1763,6 +2616,20 @@ mod tests {
                     content_type: "text/html".to_string(),
                     headers: HashMap::new(),
                     response_time_ms: 50,
+                    truncated: false,
+                    raw_content: None,
+                    http_version: None,
+                    tls_profile: None,
+                    har: None,
+                    mhtml: None,
+                    pdf: None,
+                    blocked_requests: None,
+                    evaluate_results: Vec::new(),
+                    frames: Vec::new(),
+                    accessibility: None,
+                    timings: None,
+                    ip_family: None,
+                    escalations: Vec::new(),
                 })
             }
             fn support_score(&self, _request: &InternalScrapeRequest) -> u8 {
@@ -1939,6 +2806,20 @@ mod tests {
                     content_type: "text/html".to_string(),
                     headers: HashMap::new(),
                     response_time_ms: 1,
+                    truncated: false,
+                    raw_content: None,
+                    http_version: None,
+                    tls_profile: None,
+                    har: None,
+                    mhtml: None,
+                    pdf: None,
+                    blocked_requests: None,
+                    evaluate_results: Vec::new(),
+                    frames: Vec::new(),
+                    accessibility: None,
+                    timings: None,
+                    ip_family: None,
+                    escalations: Vec::new(),
                 })
             }
             fn support_score(&self, _request: &InternalScrapeRequest) -> u8 {
@@ -1983,6 +2864,20 @@ mod tests {
                 content_type: "text/html".to_string(),
                 headers: HashMap::new(),
                 response_time_ms: 1,
+                truncated: false,
+                raw_content: None,
+                http_version: None,
+                tls_profile: None,
+                har: None,
+                mhtml: None,
+                pdf: None,
+                blocked_requests: None,
+                evaluate_results: Vec::new(),
+                frames: Vec::new(),
+                accessibility: None,
+                timings: None,
+                ip_family: None,
+                escalations: Vec::new(),
             })
         }
         fn support_score(&self, _request: &InternalScrapeRequest) -> u8 {
@@ -0,0 +1,117 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! 按请求档位配置的引擎回退链
+//!
+//! 请求通过 `options.engine_tier` 选择档位（`cheap` / `standard` / `max`），每个档位
+//! 对应配置中的有序引擎列表，路由器按列表顺序依次尝试。列表为空的档位沿用路由器的
+//! 启发式排序。团队被排除的引擎在任何档位下都不会被使用。
+
+use crate::config::engines::EngineTierSettings;
+
+/// 请求档位
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EngineTier {
+    /// 低成本：只使用轻量引擎
+    Cheap,
+    /// 标准
+    Standard,
+    /// 最高成功率：优先使用完整浏览器与反爬引擎
+    Max,
+}
+
+impl EngineTier {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Cheap => "cheap",
+            Self::Standard => "standard",
+            Self::Max => "max",
+        }
+    }
+}
+
+impl std::str::FromStr for EngineTier {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "cheap" => Ok(Self::Cheap),
+            "standard" => Ok(Self::Standard),
+            "max" => Ok(Self::Max),
+            other => Err(format!("Unknown engine tier: {}", other)),
+        }
+    }
+}
+
+/// 各档位的引擎回退链
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EngineTiers {
+    /// 未指定档位的请求使用的档位
+    pub default_tier: EngineTier,
+    /// `cheap` 档位的引擎顺序
+    pub cheap: Vec<String>,
+    /// `standard` 档位的引擎顺序
+    pub standard: Vec<String>,
+    /// `max` 档位的引擎顺序
+    pub max: Vec<String>,
+}
+
+impl Default for EngineTiers {
+    fn default() -> Self {
+        Self {
+            default_tier: EngineTier::Standard,
+            cheap: Vec::new(),
+            standard: Vec::new(),
+            max: Vec::new(),
+        }
+    }
+}
+
+impl EngineTiers {
+    /// 从配置构建各档位的回退链，无法识别的 `default_tier` 记录警告并回退到 `standard`
+    pub fn from_settings(settings: &EngineTierSettings) -> Self {
+        let default_tier = settings.default_tier.parse().unwrap_or_else(|e| {
+            log::warn!("{}, falling back to the standard tier", e);
+            EngineTier::Standard
+        });
+        Self {
+            default_tier,
+            cheap: settings.cheap.clone(),
+            standard: settings.standard.clone(),
+            max: settings.max.clone(),
+        }
+    }
+
+    /// 请求档位对应的引擎顺序，空列表表示使用启发式排序
+    pub fn chain(&self, tier: Option<EngineTier>) -> &[String] {
+        match tier.unwrap_or(self.default_tier) {
+            EngineTier::Cheap => &self.cheap,
+            EngineTier::Standard => &self.standard,
+            EngineTier::Max => &self.max,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chain_uses_default_tier_and_parses_names() {
+        let tiers = EngineTiers {
+            default_tier: EngineTier::Cheap,
+            cheap: vec!["reqwest".to_string()],
+            standard: Vec::new(),
+            max: vec!["fire_cdp".to_string(), "playwright".to_string()],
+        };
+        assert_eq!(tiers.chain(None), ["reqwest".to_string()]);
+        assert!(tiers.chain(Some(EngineTier::Standard)).is_empty());
+        assert_eq!(tiers.chain(Some(EngineTier::Max))[0], "fire_cdp");
+
+        assert_eq!(" MAX ".parse::<EngineTier>(), Ok(EngineTier::Max));
+        assert_eq!(EngineTier::Cheap.as_str(), "cheap");
+        assert!("premium".parse::<EngineTier>().is_err());
+    }
+}
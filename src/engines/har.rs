@@ -0,0 +1,363 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! HAR（HTTP Archive）记录
+//!
+//! 浏览器引擎在页面加载期间订阅 CDP Network 事件，由 [`HarRecorder`] 汇总为
+//! HAR 1.2 文档（`formats: ["har"]`），用于排查复杂页面的网络行为。
+//! 本模块只处理与 CDP 无关的普通数据，便于独立测试。
+
+use chrono::{DateTime, SecondsFormat, Utc};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+
+/// 请求的 `formats` 中表示 HAR 输出的取值
+pub const HAR_FORMAT: &str = "har";
+
+/// HAR 文档中唯一页面的 ID
+const PAGE_ID: &str = "page_1";
+
+/// 请求的输出格式中是否包含 HAR（大小写不敏感）
+pub fn requests_har(formats: Option<&[String]>) -> bool {
+    formats.is_some_and(|formats| {
+        formats
+            .iter()
+            .any(|format| format.trim().eq_ignore_ascii_case(HAR_FORMAT))
+    })
+}
+
+/// 单个网络请求的记录
+#[derive(Debug, Clone, Default)]
+struct HarEntry {
+    method: String,
+    url: String,
+    request_headers: Vec<(String, String)>,
+    /// 请求发出的墙钟时间（秒，Unix 时间戳）
+    wall_time: f64,
+    /// 请求发出的单调时间（秒）
+    started_at: f64,
+    finished_at: Option<f64>,
+    status: Option<u16>,
+    status_text: String,
+    http_version: String,
+    mime_type: String,
+    response_headers: Vec<(String, String)>,
+    body_size: Option<f64>,
+    error: Option<String>,
+}
+
+/// HAR 记录器
+///
+/// 以 CDP 的 requestId 关联同一请求的各个事件，按请求发出的顺序输出条目。
+#[derive(Debug, Default)]
+pub struct HarRecorder {
+    entries: Vec<HarEntry>,
+    index: HashMap<String, usize>,
+}
+
+impl HarRecorder {
+    /// 创建空的记录器
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 已记录的请求数
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// 是否尚未记录任何请求
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// 记录请求发出（`Network.requestWillBeSent`）
+    ///
+    /// 重定向会以相同的 requestId 再次发出请求，此时按新条目记录。
+    pub fn record_request(
+        &mut self,
+        request_id: &str,
+        method: &str,
+        url: &str,
+        headers: &Value,
+        wall_time: f64,
+        timestamp: f64,
+    ) {
+        self.index
+            .insert(request_id.to_string(), self.entries.len());
+        self.entries.push(HarEntry {
+            method: method.to_string(),
+            url: url.to_string(),
+            request_headers: header_pairs(headers),
+            wall_time,
+            started_at: timestamp,
+            ..Default::default()
+        });
+    }
+
+    /// 记录响应头到达（`Network.responseReceived`）
+    pub fn record_response(
+        &mut self,
+        request_id: &str,
+        status: u16,
+        status_text: &str,
+        mime_type: &str,
+        protocol: Option<&str>,
+        headers: &Value,
+    ) {
+        if let Some(entry) = self.entry_mut(request_id) {
+            entry.status = Some(status);
+            entry.status_text = status_text.to_string();
+            entry.mime_type = mime_type.to_string();
+            entry.http_version = protocol.map(http_version_label).unwrap_or_default();
+            entry.response_headers = header_pairs(headers);
+        }
+    }
+
+    /// 记录请求完成（`Network.loadingFinished`）
+    pub fn record_finished(&mut self, request_id: &str, encoded_data_length: f64, timestamp: f64) {
+        if let Some(entry) = self.entry_mut(request_id) {
+            entry.body_size = Some(encoded_data_length);
+            entry.finished_at = Some(timestamp);
+        }
+    }
+
+    /// 记录请求失败（`Network.loadingFailed`）
+    pub fn record_failed(&mut self, request_id: &str, error_text: &str, timestamp: f64) {
+        if let Some(entry) = self.entry_mut(request_id) {
+            entry.error = Some(error_text.to_string());
+            entry.finished_at = Some(timestamp);
+        }
+    }
+
+    /// 生成 HAR 1.2 文档
+    pub fn to_har(&self, page_url: &str) -> Value {
+        let page_started = self
+            .entries
+            .first()
+            .map(|entry| format_wall_time(entry.wall_time))
+            .unwrap_or_else(|| Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true));
+
+        let entries: Vec<Value> = self.entries.iter().map(entry_to_har).collect();
+
+        json!({
+            "log": {
+                "version": "1.2",
+                "creator": {
+                    "name": "crawlrs",
+                    "version": env!("CARGO_PKG_VERSION"),
+                },
+                "pages": [{
+                    "startedDateTime": page_started,
+                    "id": PAGE_ID,
+                    "title": page_url,
+                    "pageTimings": {},
+                }],
+                "entries": entries,
+            }
+        })
+    }
+
+    fn entry_mut(&mut self, request_id: &str) -> Option<&mut HarEntry> {
+        let index = *self.index.get(request_id)?;
+        self.entries.get_mut(index)
+    }
+}
+
+fn entry_to_har(entry: &HarEntry) -> Value {
+    // CDP 时间戳单位为秒，HAR 使用毫秒
+    let time = entry
+        .finished_at
+        .map(|finished| ((finished - entry.started_at) * 1000.0).max(0.0))
+        .unwrap_or(0.0);
+    let http_version = if entry.http_version.is_empty() {
+        "HTTP/1.1".to_string()
+    } else {
+        entry.http_version.clone()
+    };
+    let body_size = entry.body_size.map(|size| size as i64).unwrap_or(-1);
+
+    let mut har = json!({
+        "pageref": PAGE_ID,
+        "startedDateTime": format_wall_time(entry.wall_time),
+        "time": time,
+        "request": {
+            "method": entry.method,
+            "url": entry.url,
+            "httpVersion": http_version,
+            "headers": headers_to_har(&entry.request_headers),
+            "queryString": query_string(&entry.url),
+            "cookies": [],
+            "headersSize": -1,
+            "bodySize": -1,
+        },
+        "response": {
+            "status": entry.status.unwrap_or(0),
+            "statusText": entry.status_text,
+            "httpVersion": http_version,
+            "headers": headers_to_har(&entry.response_headers),
+            "cookies": [],
+            "content": {
+                "size": body_size.max(0),
+                "mimeType": entry.mime_type,
+            },
+            "redirectURL": redirect_url(&entry.response_headers),
+            "headersSize": -1,
+            "bodySize": body_size,
+        },
+        "cache": {},
+        "timings": {
+            "send": 0,
+            "wait": time,
+            "receive": 0,
+        },
+    });
+    if let Some(error) = &entry.error {
+        har["_error"] = json!(error);
+    }
+    har
+}
+
+/// CDP 的 headers 对象转换为键值对（多值头以换行分隔）
+fn header_pairs(headers: &Value) -> Vec<(String, String)> {
+    let Some(obj) = headers.as_object() else {
+        return Vec::new();
+    };
+    obj.iter()
+        .flat_map(|(name, value)| {
+            let value = value
+                .as_str()
+                .map(str::to_string)
+                .unwrap_or_else(|| value.to_string());
+            value
+                .split('\n')
+                .map(|v| (name.clone(), v.to_string()))
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+fn headers_to_har(headers: &[(String, String)]) -> Vec<Value> {
+    headers
+        .iter()
+        .map(|(name, value)| json!({ "name": name, "value": value }))
+        .collect()
+}
+
+fn query_string(url: &str) -> Vec<Value> {
+    url::Url::parse(url)
+        .map(|parsed| {
+            parsed
+                .query_pairs()
+                .map(|(name, value)| json!({ "name": name, "value": value }))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn redirect_url(headers: &[(String, String)]) -> String {
+    headers
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case("location"))
+        .map(|(_, value)| value.clone())
+        .unwrap_or_default()
+}
+
+/// CDP 协议名（`h2`、`http/1.1`、`h3`）转换为 HAR 的版本标记
+fn http_version_label(protocol: &str) -> String {
+    match protocol.to_ascii_lowercase().as_str() {
+        "h2" | "http/2.0" => "HTTP/2.0".to_string(),
+        "h3" | "http/3" | "http/3.0" => "HTTP/3.0".to_string(),
+        "http/1.0" => "HTTP/1.0".to_string(),
+        "http/1.1" => "HTTP/1.1".to_string(),
+        other => other.to_uppercase(),
+    }
+}
+
+fn format_wall_time(wall_time: f64) -> String {
+    let millis = (wall_time * 1000.0) as i64;
+    DateTime::<Utc>::from_timestamp_millis(millis)
+        .unwrap_or_else(Utc::now)
+        .to_rfc3339_opts(SecondsFormat::Millis, true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_har_recorder_builds_entries_in_request_order() {
+        let mut recorder = HarRecorder::new();
+        recorder.record_request(
+            "1",
+            "GET",
+            "https://example.com/?q=rust",
+            &json!({"Accept": "text/html"}),
+            1_700_000_000.0,
+            10.0,
+        );
+        recorder.record_request(
+            "2",
+            "GET",
+            "https://example.com/missing.js",
+            &json!({}),
+            1_700_000_000.1,
+            10.1,
+        );
+        recorder.record_response(
+            "1",
+            200,
+            "OK",
+            "text/html",
+            Some("h2"),
+            &json!({"content-type": "text/html", "set-cookie": "a=1\nb=2"}),
+        );
+        recorder.record_finished("1", 1024.0, 10.25);
+        recorder.record_failed("2", "net::ERR_NAME_NOT_RESOLVED", 10.2);
+        recorder.record_finished("unknown", 1.0, 11.0);
+
+        assert_eq!(recorder.len(), 2);
+        let har = recorder.to_har("https://example.com/?q=rust");
+        assert_eq!(har["log"]["version"], "1.2");
+        assert_eq!(
+            har["log"]["pages"][0]["startedDateTime"],
+            "2023-11-14T22:13:20.000Z"
+        );
+
+        let entries = har["log"]["entries"].as_array().unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0]["response"]["status"], 200);
+        assert_eq!(entries[0]["response"]["httpVersion"], "HTTP/2.0");
+        assert_eq!(entries[0]["response"]["bodySize"], 1024);
+        assert_eq!(entries[0]["time"], 250.0);
+        assert_eq!(entries[0]["request"]["queryString"][0]["value"], "rust");
+        assert_eq!(
+            entries[0]["response"]["headers"].as_array().unwrap().len(),
+            3
+        );
+
+        assert_eq!(entries[1]["response"]["status"], 0);
+        assert_eq!(entries[1]["_error"], "net::ERR_NAME_NOT_RESOLVED");
+    }
+
+    #[test]
+    fn test_requests_har() {
+        assert!(!requests_har(None));
+        assert!(!requests_har(Some(&["html".to_string()])));
+        assert!(requests_har(Some(&[
+            "markdown".to_string(),
+            "HAR".to_string()
+        ])));
+    }
+
+    #[test]
+    fn test_empty_recorder_produces_valid_document() {
+        let recorder = HarRecorder::new();
+        assert!(recorder.is_empty());
+        let har = recorder.to_har("https://example.com");
+        assert!(har["log"]["entries"].as_array().unwrap().is_empty());
+        assert_eq!(har["log"]["pages"][0]["title"], "https://example.com");
+    }
+}
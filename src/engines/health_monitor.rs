@@ -5,10 +5,10 @@
 
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
-use log::warn;
-use std::collections::HashMap;
+use log::{info, warn};
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use tokio::sync::RwLock;
 
 use crate::engines::engine_client::{
@@ -78,12 +78,27 @@ impl Default for HealthCheckConfig {
     }
 }
 
+/// 单个引擎的启动预检结果
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PreflightResult {
+    /// 引擎名称
+    pub engine_name: String,
+    /// 是否通过预检
+    pub passed: bool,
+    /// 预检耗时（毫秒）
+    pub duration_ms: u64,
+    /// 失败原因
+    pub error: Option<String>,
+}
+
 /// 引擎健康监控器
 pub struct EngineHealthMonitor {
     /// 引擎列表
     engines: Vec<Arc<dyn ScraperEngine>>,
     /// 健康状态
     health_status: Arc<RwLock<HashMap<String, HealthCheckInfo>>>,
+    /// 当前不可用的引擎（同步读取，供路由器选择引擎时跳过）
+    unhealthy_engines: Arc<parking_lot::RwLock<HashSet<String>>>,
     /// 配置
     config: HealthCheckConfig,
 }
@@ -96,6 +111,7 @@ impl EngineHealthMonitor {
         Self {
             engines,
             health_status: Arc::new(RwLock::new(health_status)),
+            unhealthy_engines: Arc::new(parking_lot::RwLock::new(HashSet::new())),
             config: HealthCheckConfig::default(),
         }
     }
@@ -110,6 +126,7 @@ impl EngineHealthMonitor {
         Self {
             engines,
             health_status: Arc::new(RwLock::new(health_status)),
+            unhealthy_engines: Arc::new(parking_lot::RwLock::new(HashSet::new())),
             config,
         }
     }
@@ -154,34 +171,121 @@ impl EngineHealthMonitor {
             let engine_name = engine.name().to_string();
             let health_info = self.check_engine_health(engine).await;
 
+            self.set_unhealthy(&engine_name, health_info.health == EngineHealth::Unhealthy);
             let mut status = self.health_status.write().await;
             status.insert(engine_name, health_info);
         }
     }
 
+    /// 引擎是否被标记为不可用
+    pub fn is_unhealthy(&self, engine_name: &str) -> bool {
+        self.unhealthy_engines.read().contains(engine_name)
+    }
+
+    /// 将引擎标记为不可用，直到后续健康检查恢复
+    pub async fn mark_unhealthy(&self, engine_name: &str, reason: String) {
+        self.set_unhealthy(engine_name, true);
+        let mut status = self.health_status.write().await;
+        let consecutive_failures = status
+            .get(engine_name)
+            .map(|info| info.consecutive_failures + 1)
+            .unwrap_or(1);
+        status.insert(
+            engine_name.to_string(),
+            HealthCheckInfo {
+                engine_name: engine_name.to_string(),
+                health: EngineHealth::Unhealthy,
+                last_check: Utc::now(),
+                consecutive_failures,
+                avg_response_time_ms: None,
+                error_message: Some(reason),
+            },
+        );
+    }
+
+    fn set_unhealthy(&self, engine_name: &str, unhealthy: bool) {
+        let mut engines = self.unhealthy_engines.write();
+        if unhealthy {
+            engines.insert(engine_name.to_string());
+        } else {
+            engines.remove(engine_name);
+        }
+    }
+
+    /// 启动预检：并发验证每个引擎确实可用，失败的引擎标记为不可用
+    ///
+    /// 先调用引擎自身的 [`ScraperEngine::preflight`]（CDP 握手、远程服务连通性等），
+    /// 配置了 `test_url` 时再通过该引擎抓取一次测试页面。
+    pub async fn run_preflight(
+        &self,
+        test_url: Option<&str>,
+        timeout: Duration,
+    ) -> Vec<PreflightResult> {
+        let checks = self
+            .engines
+            .iter()
+            .map(|engine| self.preflight_engine(engine, test_url, timeout));
+        let results = futures::future::join_all(checks).await;
+
+        for result in &results {
+            match &result.error {
+                None => info!(
+                    "Engine {} passed preflight in {}ms",
+                    result.engine_name, result.duration_ms
+                ),
+                Some(error) => {
+                    warn!(
+                        "Engine {} failed preflight and will not receive traffic: {}",
+                        result.engine_name, error
+                    );
+                    self.mark_unhealthy(&result.engine_name, error.clone())
+                        .await;
+                }
+            }
+        }
+        results
+    }
+
+    async fn preflight_engine(
+        &self,
+        engine: &Arc<dyn ScraperEngine>,
+        test_url: Option<&str>,
+        timeout: Duration,
+    ) -> PreflightResult {
+        let start_time = Instant::now();
+        let check = async {
+            engine.preflight().await?;
+            if let Some(url) = test_url {
+                let mut request = self.probe_request(url, timeout);
+                // 浏览器引擎只处理需要渲染的请求
+                request.needs_js = true;
+                let response = engine.scrape(&request).await?;
+                if response.status_code >= 500 {
+                    return Err(EngineError::Other(format!(
+                        "test fetch of {} returned HTTP {}",
+                        url, response.status_code
+                    )));
+                }
+            }
+            Ok(())
+        };
+        let outcome = tokio::time::timeout(timeout, check)
+            .await
+            .unwrap_or(Err(EngineError::Timeout(timeout)));
+
+        PreflightResult {
+            engine_name: engine.name().to_string(),
+            passed: outcome.is_ok(),
+            duration_ms: start_time.elapsed().as_millis() as u64,
+            error: outcome.err().map(|e| e.to_string()),
+        }
+    }
+
     /// 检查特定引擎的健康状态
     async fn check_engine_health(&self, engine: &Arc<dyn ScraperEngine>) -> HealthCheckInfo {
         let engine_name = engine.name().to_string();
-        let start_time = std::time::Instant::now();
-
-        // 创建测试请求
-        let test_request = InternalScrapeRequest {
-            url: self.config.target_url.clone(),
-            method: crate::engines::engine_client::HttpMethod::Get,
-            headers: std::collections::HashMap::new(),
-            timeout: self.config.timeout,
-            needs_js: false,
-            needs_screenshot: false,
-            screenshot_config: None,
-            mobile: false,
-            proxy: None,
-            skip_tls_verification: false,
-            needs_tls_fingerprint: false,
-            use_fire_engine: false,
-            actions: Vec::new(),
-            body: None,
-            sync_wait_ms: 0,
-        };
+        let start_time = Instant::now();
+        let test_request = self.probe_request(&self.config.target_url, self.config.timeout);
 
         match engine.scrape(&test_request).await {
             Ok(response) => {
@@ -227,6 +331,43 @@ impl EngineHealthMonitor {
         }
     }
 
+    /// 构建探测请求
+    fn probe_request(&self, url: &str, timeout: Duration) -> InternalScrapeRequest {
+        InternalScrapeRequest {
+            url: url.to_string(),
+            method: crate::engines::engine_client::HttpMethod::Get,
+            headers: HashMap::new(),
+            timeout,
+            needs_js: false,
+            needs_screenshot: false,
+            screenshot_config: None,
+            mobile: false,
+            proxy: None,
+            skip_tls_verification: false,
+            needs_tls_fingerprint: false,
+            use_fire_engine: false,
+            actions: Vec::new(),
+            body: None,
+            sync_wait_ms: 0,
+            content_type_filter: None,
+            http_protocol: crate::engines::engine_client::HttpProtocol::Auto,
+            tls_profile: None,
+            capture_har: false,
+            capture_mhtml: false,
+            pdf: None,
+            resource_blocking: None,
+            action_error_policies: Vec::new(),
+            iframe_capture: None,
+            flatten_shadow_dom: false,
+            auto_scroll: None,
+            accessibility: None,
+            engine_tier: None,
+            excluded_engines: Vec::new(),
+            resolve: HashMap::new(),
+            address_family: None,
+        }
+    }
+
     /// 根据响应时间和状态码确定健康状态
     fn determine_health_status(&self, response_time_ms: u64, status_code: u16) -> EngineHealth {
         if status_code >= 500 || response_time_ms > self.config.unhealthy_threshold_ms {
@@ -345,7 +486,8 @@ impl ScraperEngine for EngineHealthMonitor {
 mod tests {
     use super::*;
     use crate::engines::engine_client::{
-        EngineError, HttpMethod, InternalScrapeRequest, InternalScrapeResponse, ScraperEngine,
+        EngineError, HttpMethod, HttpProtocol, InternalScrapeRequest, InternalScrapeResponse,
+        ScraperEngine,
     };
     use async_trait::async_trait;
     use std::collections::HashMap;
@@ -382,6 +524,20 @@ mod tests {
                 content_type: "text/html".to_string(),
                 headers: HashMap::new(),
                 response_time_ms: 5,
+                truncated: false,
+                raw_content: None,
+                http_version: None,
+                tls_profile: None,
+                har: None,
+                mhtml: None,
+                pdf: None,
+                blocked_requests: None,
+                evaluate_results: Vec::new(),
+                frames: Vec::new(),
+                accessibility: None,
+                timings: None,
+                ip_family: None,
+                escalations: Vec::new(),
             })
         }
 
@@ -911,6 +1067,22 @@ mod tests {
             actions: Vec::new(),
             body: None,
             sync_wait_ms: 0,
+            content_type_filter: None,
+            http_protocol: HttpProtocol::Auto,
+            tls_profile: None,
+            capture_har: false,
+            capture_mhtml: false,
+            pdf: None,
+            resource_blocking: None,
+            action_error_policies: Vec::new(),
+            iframe_capture: None,
+            flatten_shadow_dom: false,
+            auto_scroll: None,
+            accessibility: None,
+            engine_tier: None,
+            excluded_engines: Vec::new(),
+            resolve: HashMap::new(),
+            address_family: None,
         };
 
         let result = monitor.scrape(&request).await;
@@ -942,6 +1114,22 @@ mod tests {
             actions: Vec::new(),
             body: None,
             sync_wait_ms: 0,
+            content_type_filter: None,
+            http_protocol: HttpProtocol::Auto,
+            tls_profile: None,
+            capture_har: false,
+            capture_mhtml: false,
+            pdf: None,
+            resource_blocking: None,
+            action_error_policies: Vec::new(),
+            iframe_capture: None,
+            flatten_shadow_dom: false,
+            auto_scroll: None,
+            accessibility: None,
+            engine_tier: None,
+            excluded_engines: Vec::new(),
+            resolve: HashMap::new(),
+            address_family: None,
         };
         assert_eq!(monitor.support_score(&request), 0);
     }
@@ -1026,6 +1214,20 @@ mod tests {
                         content_type: "text/html".to_string(),
                         headers: HashMap::new(),
                         response_time_ms: 5,
+                        truncated: false,
+                        raw_content: None,
+                        http_version: None,
+                        tls_profile: None,
+                        har: None,
+                        mhtml: None,
+                        pdf: None,
+                        blocked_requests: None,
+                        evaluate_results: Vec::new(),
+                        frames: Vec::new(),
+                        accessibility: None,
+                        timings: None,
+                        ip_family: None,
+                        escalations: Vec::new(),
                     })
                 }
             }
@@ -1072,4 +1274,34 @@ mod tests {
         assert!(health.error_message.is_none());
         assert!(health.avg_response_time_ms.is_some());
     }
+
+    #[tokio::test]
+    async fn test_run_preflight_marks_failed_engines_unhealthy() {
+        let engines: Vec<Arc<dyn ScraperEngine>> = vec![
+            Arc::new(MockOkEngine::new("ok_engine")),
+            Arc::new(MockFailEngine::new("fail_engine")),
+        ];
+        let monitor = EngineHealthMonitor::new_with_config(engines, test_config());
+
+        // 未配置测试页面时只运行引擎自身的预检，两个引擎都通过
+        let results = monitor.run_preflight(None, Duration::from_secs(5)).await;
+        assert!(results.iter().all(|result| result.passed));
+
+        let results = monitor
+            .run_preflight(Some("https://example.com"), Duration::from_secs(5))
+            .await;
+        assert!(results[0].passed);
+        assert!(!results[1].passed);
+        assert!(results[1]
+            .error
+            .as_deref()
+            .unwrap()
+            .contains("connection refused"));
+
+        assert!(monitor.is_unhealthy("fail_engine"));
+        assert!(!monitor.is_unhealthy("ok_engine"));
+        let available = monitor.get_available_engines().await;
+        assert_eq!(available.len(), 1);
+        assert_eq!(available[0].name(), "ok_engine");
+    }
 }
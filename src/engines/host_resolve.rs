@@ -0,0 +1,147 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! 按爬取覆盖主机名解析
+//!
+//! 爬取配置中的 `resolve`（如 `{"example.com": "203.0.113.5"}`）让指定主机名跳过 DNS，
+//! 直接连接给定地址，便于抓取尚未切换 DNS 的预发布环境。URL、`Host` 头与 TLS SNI
+//! 保持原主机名不变。覆盖地址同样受 SSRF 防护约束，不允许指向内网或保留地址；
+//! HTTP 引擎只走 IPv4（见 `Ipv4OnlyResolver`），覆盖地址也只接受 IPv4。
+
+use crate::engines::shared::is_private_ip;
+use std::collections::HashMap;
+use std::net::IpAddr;
+
+/// 单次爬取允许的覆盖条目上限
+pub const MAX_RESOLVE_OVERRIDES: usize = 50;
+
+/// 规范化主机名：去除首尾空白与末尾的点并转为小写，不合法时返回 None
+fn normalize_host(host: &str) -> Option<String> {
+    let host = host.trim().trim_end_matches('.').to_ascii_lowercase();
+    let valid = !host.is_empty()
+        && host.len() <= 253
+        && host.parse::<IpAddr>().is_err()
+        && host
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '.' || c == '_');
+    valid.then_some(host)
+}
+
+/// 解析并校验 `主机名 -> IP` 覆盖表
+///
+/// 主机名按小写存储；地址须为 IPv4，且不可为内网、回环、链路本地等保留地址（SSRF 防护）。
+pub fn parse_resolve_overrides(
+    raw: &HashMap<String, String>,
+) -> Result<HashMap<String, IpAddr>, String> {
+    if raw.len() > MAX_RESOLVE_OVERRIDES {
+        return Err(format!(
+            "resolve accepts at most {} hosts",
+            MAX_RESOLVE_OVERRIDES
+        ));
+    }
+    raw.iter()
+        .map(|(host, address)| {
+            let normalized =
+                normalize_host(host).ok_or_else(|| format!("invalid resolve host '{}'", host))?;
+            let ip: IpAddr = address
+                .trim()
+                .parse()
+                .map_err(|_| format!("invalid resolve address '{}' for {}", address, host))?;
+            if !ip.is_ipv4() {
+                return Err(format!(
+                    "resolve address {} for {} must be an IPv4 address",
+                    ip, host
+                ));
+            }
+            if is_private_ip(ip) {
+                return Err(format!(
+                    "resolve address {} for {} is not a public address",
+                    ip, host
+                ));
+            }
+            Ok((normalized, ip))
+        })
+        .collect()
+}
+
+/// 查找 URL 主机对应的覆盖地址
+pub fn override_for(url: &str, overrides: &HashMap<String, IpAddr>) -> Option<IpAddr> {
+    if overrides.is_empty() {
+        return None;
+    }
+    let parsed = url::Url::parse(url).ok()?;
+    let host = parsed
+        .host_str()?
+        .trim_end_matches('.')
+        .to_ascii_lowercase();
+    overrides.get(&host).copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn raw(entries: &[(&str, &str)]) -> HashMap<String, String> {
+        entries
+            .iter()
+            .map(|(host, ip)| (host.to_string(), ip.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_parse_resolve_overrides_normalizes_hosts() {
+        let overrides = parse_resolve_overrides(&raw(&[
+            ("Staging.Example.com.", "203.0.113.5"),
+            ("cdn.example.com", " 198.51.100.7 "),
+        ]))
+        .unwrap();
+        assert_eq!(
+            overrides.get("staging.example.com"),
+            Some(&"203.0.113.5".parse().unwrap())
+        );
+        assert_eq!(
+            overrides.get("cdn.example.com"),
+            Some(&"198.51.100.7".parse().unwrap())
+        );
+    }
+
+    #[test]
+    fn test_parse_resolve_overrides_rejects_invalid_entries() {
+        assert!(parse_resolve_overrides(&raw(&[("example.com", "not-an-ip")])).is_err());
+        assert!(parse_resolve_overrides(&raw(&[("", "203.0.113.5")])).is_err());
+        assert!(parse_resolve_overrides(&raw(&[("example.com:8080", "203.0.113.5")])).is_err());
+        assert!(parse_resolve_overrides(&raw(&[("203.0.113.9", "203.0.113.5")])).is_err());
+        assert!(parse_resolve_overrides(&raw(&[("example.com", "2606:4700::1111")])).is_err());
+
+        // SSRF：内网、回环与云元数据地址不可作为覆盖目标
+        for ip in [
+            "10.0.0.5",
+            "127.0.0.1",
+            "169.254.169.254",
+            "100.64.0.1",
+            "0.0.0.0",
+        ] {
+            let err = parse_resolve_overrides(&raw(&[("example.com", ip)])).unwrap_err();
+            assert!(err.contains("not a public address"), "{}", err);
+        }
+
+        let too_many: HashMap<String, String> = (0..=MAX_RESOLVE_OVERRIDES)
+            .map(|i| (format!("host{}.example.com", i), "203.0.113.5".to_string()))
+            .collect();
+        assert!(parse_resolve_overrides(&too_many).is_err());
+    }
+
+    #[test]
+    fn test_override_for_matches_url_host() {
+        let overrides = parse_resolve_overrides(&raw(&[("example.com", "203.0.113.5")])).unwrap();
+        assert_eq!(
+            override_for("https://EXAMPLE.com:8443/path?q=1", &overrides),
+            Some("203.0.113.5".parse().unwrap())
+        );
+        assert!(override_for("https://www.example.com/", &overrides).is_none());
+        assert!(override_for("not a url", &overrides).is_none());
+        assert!(override_for("https://example.com/", &HashMap::new()).is_none());
+    }
+}
@@ -0,0 +1,225 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! iframe 内容抓取
+//!
+//! 浏览器引擎渲染完成后枚举页面的子 frame 并读取其 HTML。同源 frame 默认抓取，
+//! 跨域 frame 需显式开启（通过 CDP 在 frame 的执行上下文中读取，不受同源策略限制）。
+//! `inline` 模式把 frame 内容合并进主文档，`separate` 模式只单独返回各 frame。
+
+use serde::Serialize;
+
+/// frame 内容的输出方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum IframeMode {
+    /// 合并进主文档，同时单独返回
+    Inline,
+    /// 只单独返回，主文档保持不变
+    #[default]
+    Separate,
+}
+
+impl IframeMode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Inline => "inline",
+            Self::Separate => "separate",
+        }
+    }
+}
+
+impl std::str::FromStr for IframeMode {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "inline" => Ok(Self::Inline),
+            "separate" => Ok(Self::Separate),
+            other => Err(format!("Unknown iframe mode: {}", other)),
+        }
+    }
+}
+
+/// iframe 抓取配置
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct IframeCapture {
+    /// 输出方式
+    pub mode: IframeMode,
+    /// 是否同时抓取跨域 frame
+    pub cross_origin: bool,
+}
+
+impl IframeCapture {
+    /// 由请求参数构建抓取配置，未指定模式时返回 None
+    pub fn from_parts(mode: Option<&str>, cross_origin: Option<bool>) -> Option<Self> {
+        let mode = mode?.parse().ok()?;
+        Some(Self {
+            mode,
+            cross_origin: cross_origin.unwrap_or(false),
+        })
+    }
+}
+
+/// 单个 frame 的抓取结果
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct FrameContent {
+    /// frame 的 name 属性（可能为空）
+    pub name: String,
+    /// frame 当前的 URL
+    pub url: String,
+    /// frame 文档的 HTML
+    pub html: String,
+}
+
+/// frame 是否与页面同源（`about:blank`、`srcdoc` 等继承父页面的来源）
+pub fn is_same_origin(page_url: &str, frame_url: &str) -> bool {
+    if frame_url.is_empty() || frame_url.starts_with("about:") {
+        return true;
+    }
+    match (url::Url::parse(page_url), url::Url::parse(frame_url)) {
+        (Ok(page), Ok(frame)) => page.origin() == frame.origin(),
+        _ => false,
+    }
+}
+
+/// 按名称、URL 或序号（从 0 开始）查找 frame
+pub fn find_frame<'a>(frames: &'a [FrameContent], target: &str) -> Option<&'a FrameContent> {
+    frames
+        .iter()
+        .find(|frame| !frame.name.is_empty() && frame.name == target)
+        .or_else(|| frames.iter().find(|frame| frame.url == target))
+        .or_else(|| target.parse::<usize>().ok().and_then(|i| frames.get(i)))
+}
+
+/// 将各 frame 的 body 以 `<div data-crawlrs-frame>` 包裹后插入主文档 `</body>` 之前
+pub fn inline_frames(html: &str, frames: &[FrameContent]) -> String {
+    if frames.is_empty() {
+        return html.to_string();
+    }
+
+    let mut blocks = String::new();
+    for (index, frame) in frames.iter().enumerate() {
+        blocks.push_str(&format!(
+            "<div data-crawlrs-frame=\"{}\" data-frame-name=\"{}\" data-frame-url=\"{}\">{}</div>",
+            index,
+            escape_attr(&frame.name),
+            escape_attr(&frame.url),
+            body_of(&frame.html)
+        ));
+    }
+
+    match rfind_ignore_case(html, "</body>") {
+        Some(pos) => format!("{}{}{}", &html[..pos], blocks, &html[pos..]),
+        None => format!("{}{}", html, blocks),
+    }
+}
+
+/// 文档 `<body>` 的内部 HTML，找不到 body 时返回整个文档
+fn body_of(html: &str) -> &str {
+    let lower = html.to_ascii_lowercase();
+    let Some(open) = lower.find("<body") else {
+        return html;
+    };
+    let Some(start) = lower[open..].find('>').map(|i| open + i + 1) else {
+        return html;
+    };
+    let end = lower.rfind("</body>").filter(|&end| end >= start);
+    &html[start..end.unwrap_or(html.len())]
+}
+
+fn rfind_ignore_case(haystack: &str, needle: &str) -> Option<usize> {
+    haystack.to_ascii_lowercase().rfind(needle)
+}
+
+fn escape_attr(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('"', "&quot;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frame(name: &str, url: &str, html: &str) -> FrameContent {
+        FrameContent {
+            name: name.to_string(),
+            url: url.to_string(),
+            html: html.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_inline_frames_inserts_bodies_before_closing_body() {
+        let frames = vec![
+            frame(
+                "comments",
+                "https://example.com/comments?a=1&b=2",
+                "<html><head></head><BODY class=\"x\"><p>Nice post</p></BODY></html>",
+            ),
+            frame("", "about:srcdoc", "<span>raw</span>"),
+        ];
+        let html = inline_frames("<html><body><h1>Post</h1></body></html>", &frames);
+
+        assert_eq!(
+            html,
+            "<html><body><h1>Post</h1>\
+             <div data-crawlrs-frame=\"0\" data-frame-name=\"comments\" \
+             data-frame-url=\"https://example.com/comments?a=1&amp;b=2\"><p>Nice post</p></div>\
+             <div data-crawlrs-frame=\"1\" data-frame-name=\"\" data-frame-url=\"about:srcdoc\">\
+             <span>raw</span></div></body></html>"
+        );
+        assert_eq!(inline_frames("<p>x</p>", &[]), "<p>x</p>");
+    }
+
+    #[test]
+    fn test_find_frame_by_name_url_or_index() {
+        let frames = vec![
+            frame("", "https://ads.example.net/slot", ""),
+            frame("checkout", "https://pay.example.com/form", ""),
+        ];
+        assert_eq!(
+            find_frame(&frames, "checkout").unwrap().url,
+            "https://pay.example.com/form"
+        );
+        assert_eq!(
+            find_frame(&frames, "https://ads.example.net/slot")
+                .unwrap()
+                .name,
+            ""
+        );
+        assert_eq!(find_frame(&frames, "1").unwrap().name, "checkout");
+        assert!(find_frame(&frames, "missing").is_none());
+    }
+
+    #[test]
+    fn test_same_origin_and_mode_parsing() {
+        assert!(is_same_origin(
+            "https://example.com/a",
+            "https://example.com/b"
+        ));
+        assert!(is_same_origin("https://example.com/a", "about:blank"));
+        assert!(!is_same_origin(
+            "https://example.com/a",
+            "https://cdn.example.com/b"
+        ));
+        assert!(!is_same_origin(
+            "https://example.com/a",
+            "http://example.com/a"
+        ));
+
+        assert_eq!(
+            IframeCapture::from_parts(Some("Inline"), None),
+            Some(IframeCapture {
+                mode: IframeMode::Inline,
+                cross_origin: false,
+            })
+        );
+        assert!(IframeCapture::from_parts(None, Some(true)).is_none());
+        assert!("nested".parse::<IframeMode>().is_err());
+    }
+}
@@ -0,0 +1,85 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! MHTML 单文件快照
+//!
+//! 浏览器引擎在页面动作执行完后通过 CDP `Page.captureSnapshot` 将渲染后的页面连同样式表、
+//! 图片与字体等资源打包为一个 MHTML 文件（`formats: ["mhtml"]`），文件保存到对象存储。
+//! 与只保存 HTML 相比，快照离线打开时保留页面原有的样式，适合高保真归档。
+
+/// 请求的 `formats` 中表示 MHTML 输出的取值
+pub const MHTML_FORMAT: &str = "mhtml";
+
+/// MHTML 文件的 Content-Type
+pub const MHTML_CONTENT_TYPE: &str = "multipart/related";
+
+/// 请求的输出格式中是否包含 MHTML（大小写不敏感）
+pub fn requests_mhtml(formats: Option<&[String]>) -> bool {
+    formats.is_some_and(|formats| {
+        formats
+            .iter()
+            .any(|format| format.trim().eq_ignore_ascii_case(MHTML_FORMAT))
+    })
+}
+
+/// 快照中打包的资源数（MIME 分段数减去主文档）
+///
+/// 从首部的 `boundary` 参数读取分隔符后统计分段；无法识别时返回 0。
+pub fn count_resources(mhtml: &str) -> usize {
+    let Some(boundary) = mhtml
+        .lines()
+        .take_while(|line| !line.trim().is_empty())
+        .find_map(|line| {
+            let start = line.find("boundary=")? + "boundary=".len();
+            let value = line[start..].split(';').next()?.trim().trim_matches('"');
+            (!value.is_empty()).then(|| value.to_string())
+        })
+    else {
+        return 0;
+    };
+    let delimiter = format!("--{}", boundary);
+    let parts = mhtml
+        .lines()
+        .filter(|line| line.trim_end() == delimiter)
+        .count();
+    parts.saturating_sub(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_requests_mhtml() {
+        assert!(requests_mhtml(Some(&[
+            "markdown".to_string(),
+            "MHTML".to_string()
+        ])));
+        assert!(!requests_mhtml(Some(&["html".to_string()])));
+        assert!(!requests_mhtml(None));
+    }
+
+    #[test]
+    fn test_count_resources() {
+        let snapshot = concat!(
+            "From: <Saved by Blink>\r\n",
+            "Subject: Example\r\n",
+            "MIME-Version: 1.0\r\n",
+            "Content-Type: multipart/related;\r\n",
+            "\ttype=\"text/html\";\r\n",
+            "\tboundary=\"----MultipartBoundary--abc----\"\r\n",
+            "\r\n",
+            "------MultipartBoundary--abc----\r\n",
+            "Content-Type: text/html\r\n\r\n<html></html>\r\n",
+            "------MultipartBoundary--abc----\r\n",
+            "Content-Type: text/css\r\n\r\nbody{}\r\n",
+            "------MultipartBoundary--abc----\r\n",
+            "Content-Type: image/png\r\n\r\niVBORw0KGgo=\r\n",
+            "------MultipartBoundary--abc------\r\n",
+        );
+        assert_eq!(count_resources(snapshot), 2);
+        assert_eq!(count_resources("<html></html>"), 0);
+    }
+}
@@ -7,11 +7,29 @@
 ///
 /// 提供各种网页爬取和抓取引擎的实现
 /// 包括不同的浏览器引擎、HTTP客户端和相关的支持组件
+pub mod accessibility;
+pub mod auto_scroll;
+pub mod block_detection;
+pub mod block_escalation;
 pub mod browser_downloader; // 新增：浏览器自动下载管理器
 pub mod circuit_breaker;
 pub mod client;
+pub mod domain_overrides;
+pub mod har;
 pub mod health_monitor;
+pub mod host_resolve;
+pub mod iframe;
+pub mod mhtml;
+pub mod pdf;
+pub mod performance;
+pub mod proxy_pool;
+pub mod proxy_providers;
+pub mod proxy_url;
+pub mod resource_blocking;
 pub mod router;
+pub mod routing_rules;
+pub mod screenshot;
+pub mod tls_profile;
 pub mod validators;
 
 // Shared validation utilities for SSRF protection
@@ -19,14 +37,23 @@ pub mod shared;
 
 // New unified EngineClient API
 pub mod engine_client;
+pub mod engine_tier;
 pub mod traits;
 
 pub use engine_client::{
-    EngineClient, EngineError, EngineHealthStatus, PageAction, ScrapeOptions, ScrapeRequest,
-    ScrapeResponse, ScreenshotConfig, ScrollDirection,
+    ActionErrorPolicy, AddressFamily, ContentTypeFilter, EngineClient, EngineError,
+    EngineHealthStatus, HttpProtocol, IpFamily, PageAction, ScrapeOptions, ScrapeRequest,
+    ScrapeResponse, ScreenshotConfig, ScrollDirection, SelectorState,
 };
 
+pub use accessibility::AccessibilityOptions;
+pub use auto_scroll::AutoScroll;
+pub use block_detection::BlockedBy;
 pub use engine_client::ScraperEngine;
+pub use engine_tier::EngineTier;
+pub use iframe::IframeCapture;
+pub use pdf::PdfOptions;
+pub use resource_blocking::ResourceBlocking;
 
 // 导出浏览器下载管理器
 pub use browser_downloader::{
@@ -0,0 +1,214 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! 页面打印为 PDF
+//!
+//! 浏览器引擎在页面动作执行完后通过 CDP `Page.printToPDF` 将渲染结果打印为 PDF
+//! （`formats: ["pdf"]`），文件保存到对象存储，用于归档与报表。
+//! 本模块只处理纸张、页边距等与 CDP 无关的配置，便于独立测试。
+
+use std::str::FromStr;
+
+/// 请求的 `formats` 中表示 PDF 输出的取值
+pub const PDF_FORMAT: &str = "pdf";
+
+/// 默认页边距（毫米），与 Chrome 打印的默认边距一致
+pub const DEFAULT_PDF_MARGIN_MM: f64 = 10.0;
+
+/// 允许的最大页边距（毫米）
+pub const MAX_PDF_MARGIN_MM: f64 = 100.0;
+
+/// 每英寸的毫米数
+const MM_PER_INCH: f64 = 25.4;
+
+/// 请求的输出格式中是否包含 PDF（大小写不敏感）
+pub fn requests_pdf(formats: Option<&[String]>) -> bool {
+    formats.is_some_and(|formats| {
+        formats
+            .iter()
+            .any(|format| format.trim().eq_ignore_ascii_case(PDF_FORMAT))
+    })
+}
+
+/// 纸张尺寸
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PaperSize {
+    #[default]
+    Letter,
+    Legal,
+    Tabloid,
+    A3,
+    A4,
+    A5,
+}
+
+impl PaperSize {
+    /// 纵向的宽与高（英寸）
+    pub fn dimensions_inches(&self) -> (f64, f64) {
+        match self {
+            PaperSize::Letter => (8.5, 11.0),
+            PaperSize::Legal => (8.5, 14.0),
+            PaperSize::Tabloid => (11.0, 17.0),
+            PaperSize::A3 => (297.0 / MM_PER_INCH, 420.0 / MM_PER_INCH),
+            PaperSize::A4 => (210.0 / MM_PER_INCH, 297.0 / MM_PER_INCH),
+            PaperSize::A5 => (148.0 / MM_PER_INCH, 210.0 / MM_PER_INCH),
+        }
+    }
+}
+
+impl FromStr for PaperSize {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "letter" => Ok(PaperSize::Letter),
+            "legal" => Ok(PaperSize::Legal),
+            "tabloid" => Ok(PaperSize::Tabloid),
+            "a3" => Ok(PaperSize::A3),
+            "a4" => Ok(PaperSize::A4),
+            "a5" => Ok(PaperSize::A5),
+            _ => Err(format!(
+                "Unknown paper size '{}', expected one of: letter, legal, tabloid, a3, a4, a5",
+                s
+            )),
+        }
+    }
+}
+
+/// 页边距（毫米）
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PdfMargins {
+    pub top: f64,
+    pub right: f64,
+    pub bottom: f64,
+    pub left: f64,
+}
+
+impl Default for PdfMargins {
+    fn default() -> Self {
+        Self {
+            top: DEFAULT_PDF_MARGIN_MM,
+            right: DEFAULT_PDF_MARGIN_MM,
+            bottom: DEFAULT_PDF_MARGIN_MM,
+            left: DEFAULT_PDF_MARGIN_MM,
+        }
+    }
+}
+
+/// PDF 打印配置
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PdfOptions {
+    /// 纸张尺寸（默认 Letter）
+    pub paper_size: PaperSize,
+    /// 是否横向打印
+    pub landscape: bool,
+    /// 页边距
+    pub margins: PdfMargins,
+    /// 是否打印背景色与背景图（默认打印，保留页面原貌）
+    pub print_background: bool,
+}
+
+impl Default for PdfOptions {
+    fn default() -> Self {
+        Self {
+            paper_size: PaperSize::default(),
+            landscape: false,
+            margins: PdfMargins::default(),
+            print_background: true,
+        }
+    }
+}
+
+impl PdfOptions {
+    /// 由请求参数构建打印配置
+    ///
+    /// 无法识别的纸张尺寸使用默认值；页边距截断到 0 至 `MAX_PDF_MARGIN_MM`。
+    /// `margins` 依次为上、右、下、左。
+    pub fn from_parts(
+        paper_size: Option<&str>,
+        landscape: Option<bool>,
+        margins: [Option<f64>; 4],
+        print_background: Option<bool>,
+    ) -> Self {
+        let margin = |value: Option<f64>| {
+            value
+                .filter(|v| v.is_finite())
+                .unwrap_or(DEFAULT_PDF_MARGIN_MM)
+                .clamp(0.0, MAX_PDF_MARGIN_MM)
+        };
+        let [top, right, bottom, left] = margins;
+        Self {
+            paper_size: paper_size.and_then(|p| p.parse().ok()).unwrap_or_default(),
+            landscape: landscape.unwrap_or(false),
+            margins: PdfMargins {
+                top: margin(top),
+                right: margin(right),
+                bottom: margin(bottom),
+                left: margin(left),
+            },
+            print_background: print_background.unwrap_or(true),
+        }
+    }
+}
+
+/// 毫米转换为英寸（CDP 的纸张与边距单位）
+pub fn mm_to_inches(mm: f64) -> f64 {
+    mm / MM_PER_INCH
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_requests_pdf() {
+        assert!(requests_pdf(Some(&[
+            "markdown".to_string(),
+            " PDF ".to_string()
+        ])));
+        assert!(!requests_pdf(Some(&["html".to_string()])));
+        assert!(!requests_pdf(None));
+    }
+
+    #[test]
+    fn test_paper_size_parsing_and_dimensions() {
+        assert_eq!("A4".parse::<PaperSize>(), Ok(PaperSize::A4));
+        assert_eq!(" letter ".parse::<PaperSize>(), Ok(PaperSize::Letter));
+        assert!("b5".parse::<PaperSize>().is_err());
+
+        let (width, height) = PaperSize::A4.dimensions_inches();
+        assert!((width - 8.27).abs() < 0.01);
+        assert!((height - 11.69).abs() < 0.01);
+        assert_eq!(PaperSize::Letter.dimensions_inches(), (8.5, 11.0));
+    }
+
+    #[test]
+    fn test_from_parts_applies_defaults_and_limits() {
+        assert_eq!(
+            PdfOptions::from_parts(None, None, [None; 4], None),
+            PdfOptions::default()
+        );
+
+        let options = PdfOptions::from_parts(
+            Some("a3"),
+            Some(true),
+            [Some(0.0), Some(500.0), Some(-5.0), Some(f64::NAN)],
+            Some(false),
+        );
+        assert_eq!(options.paper_size, PaperSize::A3);
+        assert!(options.landscape);
+        assert!(!options.print_background);
+        assert_eq!(
+            options.margins,
+            PdfMargins {
+                top: 0.0,
+                right: MAX_PDF_MARGIN_MM,
+                bottom: 0.0,
+                left: DEFAULT_PDF_MARGIN_MM,
+            }
+        );
+        assert!((mm_to_inches(25.4) - 1.0).abs() < f64::EPSILON);
+    }
+}
@@ -0,0 +1,167 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! 页面性能计时
+//!
+//! 浏览器引擎在页面加载后读取 Navigation Timing（DNS、连接、TLS、TTFB、DOMContentLoaded、load）
+//! 与 Paint Timing（FCP、LCP）；HTTP 引擎只能测得首字节时间与响应体下载时间。
+//! 计时写入结果元数据的 `performance` 字段，读取失败不影响抓取。
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::time::Duration;
+
+/// 在页面中读取导航与绘制计时的脚本，返回 Promise
+///
+/// LCP 只能通过 `PerformanceObserver`（`buffered: true`）读取，100 毫秒内没有记录时为 null。
+pub const PAGE_TIMING_SCRIPT: &str = r#"new Promise((resolve) => {
+    const nav = performance.getEntriesByType('navigation')[0];
+    const paint = performance.getEntriesByName('first-contentful-paint')[0];
+    const span = (start, end) => (end > 0 && end >= start ? end - start : null);
+    const timings = nav ? {
+        dns_ms: span(nav.domainLookupStart, nav.domainLookupEnd),
+        connect_ms: span(nav.connectStart, nav.connectEnd),
+        tls_ms: nav.secureConnectionStart > 0 ? span(nav.secureConnectionStart, nav.connectEnd) : null,
+        ttfb_ms: span(nav.startTime, nav.responseStart),
+        download_ms: span(nav.responseStart, nav.responseEnd),
+        dom_content_loaded_ms: span(nav.startTime, nav.domContentLoadedEventEnd),
+        load_ms: span(nav.startTime, nav.loadEventEnd),
+        transfer_size: nav.transferSize,
+    } : {};
+    timings.first_contentful_paint_ms = paint ? paint.startTime : null;
+    let done = false;
+    const finish = (lcp) => {
+        if (done) return;
+        done = true;
+        timings.largest_contentful_paint_ms = lcp;
+        resolve(timings);
+    };
+    try {
+        new PerformanceObserver((list) => {
+            const entries = list.getEntries();
+            const last = entries[entries.length - 1];
+            finish(last ? (last.renderTime || last.loadTime || last.startTime) : null);
+        }).observe({type: 'largest-contentful-paint', buffered: true});
+    } catch (e) {
+        finish(null);
+    }
+    setTimeout(() => finish(null), 100);
+})"#;
+
+/// 页面加载各阶段耗时（毫秒），引擎无法测得的阶段为空
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PageTimings {
+    /// DNS 解析
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dns_ms: Option<f64>,
+    /// TCP 连接（含 TLS 握手）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub connect_ms: Option<f64>,
+    /// TLS 握手
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tls_ms: Option<f64>,
+    /// 从发起请求到收到响应首字节
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ttfb_ms: Option<f64>,
+    /// 响应体下载
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub download_ms: Option<f64>,
+    /// 从导航开始到 DOMContentLoaded 结束
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dom_content_loaded_ms: Option<f64>,
+    /// 从导航开始到 load 事件结束
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub load_ms: Option<f64>,
+    /// 首次内容绘制（FCP）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_contentful_paint_ms: Option<f64>,
+    /// 最大内容绘制（LCP）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub largest_contentful_paint_ms: Option<f64>,
+    /// 主文档传输字节数（含响应头）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transfer_size: Option<u64>,
+}
+
+/// 丢弃负数与非有限值，保留一位小数
+fn sanitize(value: Option<f64>) -> Option<f64> {
+    value
+        .filter(|v| v.is_finite() && *v >= 0.0)
+        .map(|v| (v * 10.0).round() / 10.0)
+}
+
+fn millis(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+impl PageTimings {
+    /// HTTP 引擎的计时：`headers_after` 为收到响应头的耗时，`total` 为读完响应体的耗时
+    pub fn from_http(headers_after: Duration, total: Duration) -> Self {
+        Self {
+            ttfb_ms: sanitize(Some(millis(headers_after))),
+            download_ms: sanitize(Some(millis(total.saturating_sub(headers_after)))),
+            ..Default::default()
+        }
+    }
+
+    /// 由 [`PAGE_TIMING_SCRIPT`] 的返回值构建计时，无法识别时返回 None
+    pub fn from_script_result(value: Value) -> Option<Self> {
+        let timings: PageTimings = serde_json::from_value(value).ok()?;
+        let timings = Self {
+            dns_ms: sanitize(timings.dns_ms),
+            connect_ms: sanitize(timings.connect_ms),
+            tls_ms: sanitize(timings.tls_ms),
+            ttfb_ms: sanitize(timings.ttfb_ms),
+            download_ms: sanitize(timings.download_ms),
+            dom_content_loaded_ms: sanitize(timings.dom_content_loaded_ms),
+            load_ms: sanitize(timings.load_ms),
+            first_contentful_paint_ms: sanitize(timings.first_contentful_paint_ms),
+            largest_contentful_paint_ms: sanitize(timings.largest_contentful_paint_ms),
+            transfer_size: timings.transfer_size,
+        };
+        (timings != Self::default()).then_some(timings)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_from_http_splits_ttfb_and_download() {
+        let timings =
+            PageTimings::from_http(Duration::from_millis(120), Duration::from_millis(200));
+        assert_eq!(timings.ttfb_ms, Some(120.0));
+        assert_eq!(timings.download_ms, Some(80.0));
+        assert!(timings.load_ms.is_none());
+        assert_eq!(
+            serde_json::to_value(&timings).unwrap(),
+            json!({"ttfb_ms": 120.0, "download_ms": 80.0})
+        );
+    }
+
+    #[test]
+    fn test_from_script_result_sanitizes_values() {
+        let timings = PageTimings::from_script_result(json!({
+            "dns_ms": 12.345,
+            "connect_ms": -1.0,
+            "tls_ms": null,
+            "ttfb_ms": 85.25,
+            "load_ms": 1520.0,
+            "largest_contentful_paint_ms": 980.04,
+            "transfer_size": 14320
+        }))
+        .unwrap();
+        assert_eq!(timings.dns_ms, Some(12.3));
+        assert!(timings.connect_ms.is_none());
+        assert_eq!(timings.ttfb_ms, Some(85.3));
+        assert_eq!(timings.largest_contentful_paint_ms, Some(980.0));
+        assert_eq!(timings.transfer_size, Some(14320));
+
+        assert!(PageTimings::from_script_result(json!({})).is_none());
+        assert!(PageTimings::from_script_result(Value::Null).is_none());
+    }
+}
@@ -0,0 +1,165 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! 浏览器引擎的资源拦截规则
+//!
+//! 渲染页面时按资源类型（图片、字体、媒体、样式表）或域名（广告与追踪脚本）
+//! 拦截子资源请求，降低带宽占用并加快 JS 渲染。主文档请求永远不会被拦截。
+
+/// 可按类型拦截的资源
+pub const BLOCKABLE_RESOURCE_TYPES: &[&str] = &["image", "font", "media", "stylesheet"];
+
+/// 内置的广告与追踪域名（`block_ads` 启用时生效，子域名一并拦截）
+pub const AD_TRACKER_DOMAINS: &[&str] = &[
+    "doubleclick.net",
+    "googlesyndication.com",
+    "googleadservices.com",
+    "google-analytics.com",
+    "googletagmanager.com",
+    "googletagservices.com",
+    "adservice.google.com",
+    "amazon-adsystem.com",
+    "adnxs.com",
+    "criteo.com",
+    "criteo.net",
+    "taboola.com",
+    "outbrain.com",
+    "pubmatic.com",
+    "rubiconproject.com",
+    "moatads.com",
+    "scorecardresearch.com",
+    "quantserve.com",
+    "connect.facebook.net",
+    "hotjar.com",
+    "mixpanel.com",
+    "segment.io",
+];
+
+/// 是否为可拦截的资源类型名称（大小写不敏感）
+pub fn is_blockable_resource_type(name: &str) -> bool {
+    BLOCKABLE_RESOURCE_TYPES
+        .iter()
+        .any(|t| t.eq_ignore_ascii_case(name.trim()))
+}
+
+/// 资源拦截配置
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ResourceBlocking {
+    /// 拦截的资源类型（见 [`BLOCKABLE_RESOURCE_TYPES`]）
+    pub resource_types: Vec<String>,
+    /// 额外拦截的域名（含子域名）
+    pub blocked_domains: Vec<String>,
+    /// 是否拦截内置的广告与追踪域名
+    pub block_ads: bool,
+}
+
+impl ResourceBlocking {
+    /// 由请求参数构建拦截配置，未配置任何规则时返回 None
+    pub fn from_parts(
+        resource_types: Option<Vec<String>>,
+        blocked_domains: Option<Vec<String>>,
+        block_ads: Option<bool>,
+    ) -> Option<Self> {
+        let blocking = Self {
+            resource_types: resource_types.unwrap_or_default(),
+            blocked_domains: blocked_domains.unwrap_or_default(),
+            block_ads: block_ads.unwrap_or(false),
+        };
+        (!blocking.is_empty()).then_some(blocking)
+    }
+
+    /// 未配置任何拦截规则
+    pub fn is_empty(&self) -> bool {
+        self.resource_types.is_empty() && self.blocked_domains.is_empty() && !self.block_ads
+    }
+
+    /// 是否拦截图片、字体或媒体等大体积资源
+    pub fn blocks_media(&self) -> bool {
+        ["image", "font", "media"]
+            .iter()
+            .any(|t| self.blocks_type(t))
+    }
+
+    /// 是否拦截该资源类型（CDP ResourceType，如 `Image`、`Font`）
+    pub fn blocks_type(&self, resource_type: &str) -> bool {
+        self.resource_types
+            .iter()
+            .any(|t| t.trim().eq_ignore_ascii_case(resource_type))
+    }
+
+    /// 请求 URL 的主机是否命中拦截域名
+    pub fn blocks_url(&self, url: &str) -> bool {
+        let Some(host) = url::Url::parse(url)
+            .ok()
+            .and_then(|u| u.host_str().map(str::to_ascii_lowercase))
+        else {
+            return false;
+        };
+
+        let ad_domains: &[&str] = if self.block_ads {
+            AD_TRACKER_DOMAINS
+        } else {
+            &[]
+        };
+        ad_domains
+            .iter()
+            .copied()
+            .chain(self.blocked_domains.iter().map(String::as_str))
+            .any(|domain| host_matches(&host, domain))
+    }
+
+    /// 是否拦截该子资源请求
+    pub fn should_block(&self, resource_type: &str, url: &str) -> bool {
+        if resource_type.eq_ignore_ascii_case("document") {
+            return false;
+        }
+        self.blocks_type(resource_type) || self.blocks_url(url)
+    }
+}
+
+fn host_matches(host: &str, domain: &str) -> bool {
+    let domain = domain.trim().trim_start_matches("*.").trim_matches('.');
+    if domain.is_empty() {
+        return false;
+    }
+    host.eq_ignore_ascii_case(domain)
+        || host
+            .strip_suffix(&domain.to_ascii_lowercase())
+            .is_some_and(|prefix| prefix.ends_with('.'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_should_block_by_type_and_domain() {
+        let blocking = ResourceBlocking {
+            resource_types: vec!["image".to_string(), "Font".to_string()],
+            blocked_domains: vec!["cdn.example.net".to_string()],
+            block_ads: true,
+        };
+
+        assert!(blocking.should_block("Image", "https://example.com/logo.png"));
+        assert!(blocking.should_block("Font", "https://example.com/a.woff2"));
+        assert!(!blocking.should_block("Script", "https://example.com/app.js"));
+        assert!(blocking.should_block("Script", "https://www.googletagmanager.com/gtm.js"));
+        assert!(blocking.should_block("Script", "https://static.cdn.example.net/x.js"));
+        assert!(!blocking.should_block("Script", "https://notcdn.example.net/x.js"));
+        // 主文档不拦截
+        assert!(!blocking.should_block("Document", "https://doubleclick.net/"));
+        assert!(blocking.blocks_media());
+    }
+
+    #[test]
+    fn test_default_blocks_nothing() {
+        let blocking = ResourceBlocking::default();
+        assert!(blocking.is_empty());
+        assert!(ResourceBlocking::from_parts(Some(Vec::new()), None, Some(false)).is_none());
+        assert!(!blocking.should_block("Image", "https://doubleclick.net/pixel.gif"));
+        assert!(is_blockable_resource_type("Stylesheet"));
+        assert!(!is_blockable_resource_type("script"));
+    }
+}
@@ -9,10 +9,18 @@
 //! scraping engines based on request requirements.
 //! This is an internal implementation detail.
 
-use crate::engines::circuit_breaker::CircuitBreaker;
+use crate::config::reload::{DerivedSettings, ReloadableSettings};
+use crate::engines::block_detection::detect_block;
+use crate::engines::block_escalation::{BlockEscalation, EscalationAttempt, EscalationStep};
+use crate::engines::circuit_breaker::{CircuitBreaker, CircuitSnapshot, CircuitStats, Status};
+use crate::engines::domain_overrides::DomainOverrides;
 use crate::engines::engine_client::{
     EngineError, InternalScrapeRequest, InternalScrapeResponse, ScraperEngine,
 };
+use crate::engines::engine_tier::EngineTiers;
+use crate::engines::health_monitor::EngineHealthMonitor;
+use crate::engines::proxy_pool::ProxyPool;
+use crate::engines::routing_rules::RoutingRules;
 use crate::engines::validators::validate_url;
 use dashmap::DashMap;
 use log::{info, warn};
@@ -274,6 +282,16 @@ pub struct EngineRouter {
     race_mode_enabled: bool,
     /// 动态阈值因子 (根据历史数据调整)
     dynamic_threshold_factor: f64,
+    /// 健康监控器（被标记为不可用的引擎不参与路由）
+    health_monitor: Option<Arc<EngineHealthMonitor>>,
+    /// 域名固定引擎与档位回退链
+    rules: Arc<RoutingRules>,
+    /// 绑定可热重载配置后按当前配置读取的路由规则，优先于 `rules`
+    reloadable_rules: Option<DerivedSettings<RoutingRules>>,
+    /// 响应被拦截时的引擎升级策略，未设置时不升级
+    block_escalation: Option<BlockEscalation>,
+    /// 未指定代理的请求轮换使用的代理池
+    proxy_pool: Option<Arc<ProxyPool>>,
 }
 
 impl EngineRouter {
@@ -304,6 +322,11 @@ impl EngineRouter {
             feature_filter_enabled: true,  // 默认启用特征检测过滤
             race_mode_enabled: false,      // 默认禁用并发竞速模式
             dynamic_threshold_factor: 1.0, // 默认动态阈值因子
+            health_monitor: None,
+            rules: Arc::new(RoutingRules::default()),
+            reloadable_rules: None,
+            block_escalation: None,
+            proxy_pool: None,
         }
     }
 
@@ -340,6 +363,11 @@ impl EngineRouter {
             feature_filter_enabled: true,
             race_mode_enabled: false,
             dynamic_threshold_factor: 1.0,
+            health_monitor: None,
+            rules: Arc::new(RoutingRules::default()),
+            reloadable_rules: None,
+            block_escalation: None,
+            proxy_pool: None,
         }
     }
 
@@ -372,6 +400,87 @@ impl EngineRouter {
         self.strategy = strategy;
     }
 
+    /// 设置健康监控器，启动预检失败的引擎不再被选中
+    pub fn set_health_monitor(&mut self, health_monitor: Arc<EngineHealthMonitor>) {
+        self.health_monitor = Some(health_monitor);
+    }
+
+    /// 设置按域名固定的引擎规则，引用未注册引擎的规则会被忽略
+    pub fn set_domain_overrides(&mut self, overrides: DomainOverrides) {
+        for engine in overrides.engines() {
+            if !self.engines.iter().any(|e| e.name() == engine) {
+                warn!("Domain override references unknown engine {}", engine);
+            }
+        }
+        Arc::make_mut(&mut self.rules).domain_overrides = overrides;
+    }
+
+    /// 设置按请求档位配置的引擎回退链，引用未注册引擎的条目会被忽略
+    pub fn set_engine_tiers(&mut self, tiers: EngineTiers) {
+        for engine in tiers.cheap.iter().chain(&tiers.standard).chain(&tiers.max) {
+            if !self.engines.iter().any(|e| e.name() == engine) {
+                warn!("Engine tier references unknown engine {}", engine);
+            }
+        }
+        Arc::make_mut(&mut self.rules).tiers = tiers;
+    }
+
+    /// 设置响应被拦截时的引擎升级策略
+    pub fn set_block_escalation(&mut self, escalation: BlockEscalation) {
+        self.block_escalation = Some(escalation);
+    }
+
+    /// 设置代理池，未指定代理的请求按轮询使用池中可用的代理
+    pub fn set_proxy_pool(&mut self, pool: Arc<ProxyPool>) {
+        self.proxy_pool = Some(pool);
+    }
+
+    /// 绑定可热重载的配置，之后域名规则、档位回退链与引擎开关按当前配置生效
+    pub fn set_reloadable_settings(&mut self, settings: Arc<ReloadableSettings>) {
+        self.reloadable_rules = Some(DerivedSettings::new(settings, |settings| {
+            RoutingRules::from_settings(&settings.engines)
+        }));
+    }
+
+    /// 当前生效的路由规则
+    fn routing_rules(&self) -> Arc<RoutingRules> {
+        match &self.reloadable_rules {
+            Some(rules) => rules.get(),
+            None => self.rules.clone(),
+        }
+    }
+
+    /// 所有已注册引擎的熔断器状态，尚无请求记录的引擎报告为关闭
+    pub fn circuit_breaker_states(&self) -> Vec<CircuitSnapshot> {
+        let mut snapshots = self.circuit_breaker.snapshot();
+        for engine in &self.engines {
+            if !snapshots.iter().any(|s| s.engine_name == engine.name()) {
+                snapshots.push(CircuitSnapshot {
+                    engine_name: engine.name().to_string(),
+                    status: Status::Closed,
+                    stats: CircuitStats::default(),
+                    last_failure_at: None,
+                    last_trip_at: None,
+                });
+            }
+        }
+        snapshots.sort_by(|a, b| a.engine_name.cmp(&b.engine_name));
+        snapshots
+    }
+
+    /// 手动重置引擎的熔断器，引擎未注册时返回 false
+    pub fn reset_circuit_breaker(&self, engine_name: &str) -> bool {
+        if !self
+            .engines
+            .iter()
+            .any(|engine| engine.name() == engine_name)
+        {
+            return false;
+        }
+        self.circuit_breaker.reset(engine_name);
+        true
+    }
+
     /// 获取路由层指标
     pub fn metrics(&self) -> &Arc<RouterMetrics> {
         &self.metrics
@@ -389,6 +498,7 @@ impl EngineRouter {
     fn select_optimal_engines(
         &self,
         request: &InternalScrapeRequest,
+        rules: &RoutingRules,
     ) -> Vec<(f64, Arc<dyn ScraperEngine>)> {
         let mut candidates = Vec::new();
 
@@ -403,6 +513,20 @@ impl EngineRouter {
                 continue;
             }
 
+            if self
+                .health_monitor
+                .as_ref()
+                .is_some_and(|monitor| monitor.is_unhealthy(engine_name))
+            {
+                continue;
+            }
+
+            if request.excluded_engines.iter().any(|e| e == engine_name)
+                || rules.is_disabled(engine_name)
+            {
+                continue;
+            }
+
             // Feature detection filtering
             if self.feature_filter_enabled {
                 if let Some(reason) = self.should_filter_by_feature(request, engine) {
@@ -448,6 +572,74 @@ impl EngineRouter {
         scored_candidates
     }
 
+    /// 将域名规则固定的引擎移到候选列表首位
+    ///
+    /// 固定引擎不受支持分数与特征过滤限制，但熔断或被标记为不可用时不会被选中。
+    /// 返回实际生效的引擎名称。
+    fn apply_domain_override(
+        &self,
+        request: &InternalScrapeRequest,
+        rules: &RoutingRules,
+        candidates: &mut Vec<(f64, Arc<dyn ScraperEngine>)>,
+    ) -> Option<&'static str> {
+        let pinned = rules.domain_overrides.engine_for(&request.url)?;
+        let engine = self.engines.iter().find(|engine| engine.name() == pinned)?;
+        let engine_name = engine.name();
+
+        if request.excluded_engines.iter().any(|e| e == engine_name)
+            || rules.is_disabled(engine_name)
+        {
+            return None;
+        }
+
+        let unavailable = self.circuit_breaker.is_open(engine_name)
+            || self
+                .health_monitor
+                .as_ref()
+                .is_some_and(|monitor| monitor.is_unhealthy(engine_name));
+        if unavailable {
+            warn!(
+                "Domain override engine {} for {} is unavailable, falling back",
+                engine_name, request.url
+            );
+            return None;
+        }
+
+        let score = match candidates
+            .iter()
+            .position(|(_, candidate)| candidate.name() == engine_name)
+        {
+            Some(index) => candidates.remove(index).0,
+            None => 0.0,
+        };
+        candidates.insert(0, (score, Arc::clone(engine)));
+        log::debug!("Domain override pins {} to {}", request.url, engine_name);
+        Some(engine_name)
+    }
+
+    /// 按请求档位的回退链筛选并排序候选引擎
+    ///
+    /// 只保留回退链中的引擎并按链中顺序排列；档位未配置回退链时保持启发式排序并返回 false。
+    fn apply_engine_tier(
+        &self,
+        request: &InternalScrapeRequest,
+        rules: &RoutingRules,
+        candidates: &mut Vec<(f64, Arc<dyn ScraperEngine>)>,
+    ) -> bool {
+        let chain = rules.tiers.chain(request.engine_tier);
+        if chain.is_empty() {
+            return false;
+        }
+        candidates.retain(|(_, engine)| chain.iter().any(|name| name == engine.name()));
+        candidates.sort_by_key(|(_, engine)| {
+            chain
+                .iter()
+                .position(|name| name == engine.name())
+                .unwrap_or(usize::MAX)
+        });
+        true
+    }
+
     /// 特征检测过滤
     /// 根据请求特征直接过滤不适合的引擎（使用能力方法替代硬编码引擎名）
     fn should_filter_by_feature(
@@ -471,6 +663,46 @@ impl EngineRouter {
             ));
         }
 
+        // HAR 只能由可观察网络事件的浏览器引擎生成
+        if request.capture_har && engine.support_score(request) < 50 {
+            return Some(format!(
+                "Engine {} does not support HAR capture",
+                engine.name()
+            ));
+        }
+
+        // MHTML 快照只能由浏览器引擎生成
+        if request.capture_mhtml && engine.support_score(request) < 50 {
+            return Some(format!(
+                "Engine {} does not support MHTML snapshots",
+                engine.name()
+            ));
+        }
+
+        // PDF 只能由浏览器引擎打印
+        if request.pdf.is_some() && engine.support_score(request) < 50 {
+            return Some(format!(
+                "Engine {} does not support PDF rendering",
+                engine.name()
+            ));
+        }
+
+        // 无障碍扫描需在浏览器中运行 axe-core
+        if request.accessibility.is_some() && engine.support_score(request) < 50 {
+            return Some(format!(
+                "Engine {} does not support accessibility scans",
+                engine.name()
+            ));
+        }
+
+        // 主机名解析覆盖只有自行建立连接的 HTTP 引擎能够遵守
+        if !request.resolve.is_empty() && !engine.supports_resolve_overrides() {
+            return Some(format!(
+                "Engine {} does not support host resolve overrides",
+                engine.name()
+            ));
+        }
+
         // 如果明确需要 TLS 指纹，检查得分
         if request.needs_tls_fingerprint && engine.support_score(request) < 50 {
             return Some(format!(
@@ -617,13 +849,149 @@ impl EngineRouter {
             return Err(EngineError::SsrfProtection(e.to_string()));
         }
 
+        // 未指定代理的请求从代理池轮换选择出口
+        let rotated;
+        let request = match self.rotate_proxy(request) {
+            Some(proxied) => {
+                rotated = proxied;
+                &rotated
+            }
+            None => request,
+        };
+
         let timeout = request.timeout;
+        let deadline = Instant::now() + timeout;
 
         // Wrap the entire operation with timeout
-        tokio::time::timeout(timeout, self.route_internal(request))
+        let response = tokio::time::timeout(timeout, self.route_internal(request))
             .await
             .map_err(|_| EngineError::Timeout(timeout))
-            .and_then(|result| result)
+            .and_then(|result| result)?;
+
+        match &self.block_escalation {
+            Some(escalation) => Ok(self
+                .escalate_blocked(request, response, escalation, deadline)
+                .await),
+            None => Ok(response),
+        }
+    }
+
+    /// 为未指定代理的请求从代理池选择一个可用代理，不需要改写请求时返回 None
+    fn rotate_proxy(&self, request: &InternalScrapeRequest) -> Option<InternalScrapeRequest> {
+        if request
+            .proxy
+            .as_deref()
+            .is_some_and(|p| !p.trim().is_empty())
+        {
+            return None;
+        }
+        let pool = self.proxy_pool.as_ref()?;
+        let Some(proxy) = pool.select() else {
+            warn!("No active proxy in the pool, sending request without a pool proxy");
+            return None;
+        };
+        let mut proxied = request.clone();
+        proxied.proxy = Some(proxy);
+        Some(proxied)
+    }
+
+    /// 响应被拦截时按升级步骤换用更高档位的引擎重试
+    ///
+    /// 返回第一个未被拦截的响应；所有步骤都失败或仍被拦截时返回最后一个拿到的响应。
+    /// 每次尝试记录在响应的 `escalations` 中，未被拦截的首次响应原样返回。
+    /// 升级步骤共用请求的 `deadline`，每步只能使用剩余时间，超过截止时间后不再升级。
+    async fn escalate_blocked(
+        &self,
+        request: &InternalScrapeRequest,
+        mut response: InternalScrapeResponse,
+        escalation: &BlockEscalation,
+        deadline: Instant,
+    ) -> InternalScrapeResponse {
+        let Some(blocked_by) =
+            detect_block(response.status_code, &response.headers, &response.content)
+        else {
+            return response;
+        };
+
+        let mut attempts = vec![EscalationAttempt::blocked(
+            EscalationStep::Initial,
+            blocked_by,
+        )];
+        for step in escalation.steps_for(request) {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                warn!(
+                    "Request timeout for {} exhausted, skipping escalation to {}",
+                    request.url,
+                    step.as_str()
+                );
+                break;
+            }
+            let mut escalated = escalation.escalated_request(request, step);
+            escalated.timeout = escalated.timeout.min(remaining);
+            self.exclude_unfit_engines(step, &mut escalated);
+            info!(
+                "Response for {} is blocked, escalating to {}",
+                request.url,
+                step.as_str()
+            );
+
+            let result = tokio::time::timeout(escalated.timeout, self.route_internal(&escalated))
+                .await
+                .map_err(|_| EngineError::Timeout(escalated.timeout))
+                .and_then(|result| result);
+            let attempt = match result {
+                Ok(next) => {
+                    let attempt = match detect_block(next.status_code, &next.headers, &next.content)
+                    {
+                        Some(blocked_by) => EscalationAttempt::blocked(step, blocked_by),
+                        None => EscalationAttempt::succeeded(step),
+                    };
+                    response = next;
+                    attempt
+                }
+                Err(e) => {
+                    warn!(
+                        "Escalation step {} for {} failed: {}",
+                        step.as_str(),
+                        request.url,
+                        e
+                    );
+                    EscalationAttempt::failed(step, e.to_string())
+                }
+            };
+            crate::infrastructure::metrics::record_block_escalation(
+                step.as_str(),
+                attempt.outcome(),
+            );
+
+            let succeeded = attempt.outcome() == "succeeded";
+            attempts.push(attempt);
+            if succeeded {
+                break;
+            }
+        }
+
+        response.escalations = attempts;
+        response
+    }
+
+    /// 排除不具备升级步骤所需能力的引擎
+    ///
+    /// 浏览器步骤由特征过滤排除无法渲染的引擎；TLS 指纹步骤需额外排除不能模拟浏览器
+    /// 指纹的 HTTP 引擎，否则它们仍会以高分被选中。
+    fn exclude_unfit_engines(&self, step: EscalationStep, request: &mut InternalScrapeRequest) {
+        if step != EscalationStep::TlsFingerprint {
+            return;
+        }
+        for engine in &self.engines {
+            let name = engine.name();
+            if !engine.supports_tls_fingerprint()
+                && !request.excluded_engines.iter().any(|e| e == name)
+            {
+                request.excluded_engines.push(name.to_string());
+            }
+        }
     }
 
     /// Internal route implementation without timeout
@@ -634,7 +1002,20 @@ impl EngineRouter {
         let start_time = Instant::now();
 
         // 选择最优引擎
-        let mut candidates = self.select_optimal_engines(request);
+        let rules = self.routing_rules();
+        let mut candidates = self.select_optimal_engines(request, &rules);
+
+        // 轮询策略特殊处理
+        if self.strategy == LoadBalancingStrategy::RoundRobin && !candidates.is_empty() {
+            let start_index = self.get_next_round_robin_index(candidates.len());
+            candidates.rotate_left(start_index);
+        }
+
+        // 档位配置了回退链时按链中顺序依次尝试
+        let tiered = self.apply_engine_tier(request, &rules, &mut candidates);
+
+        // 域名规则固定的引擎优先于启发式选择
+        let pinned = self.apply_domain_override(request, &rules, &mut candidates);
 
         // 记录候选引擎数量
         self.metrics.record_candidates(candidates.len());
@@ -647,12 +1028,6 @@ impl EngineRouter {
             ));
         }
 
-        // 轮询策略特殊处理
-        if self.strategy == LoadBalancingStrategy::RoundRobin {
-            let start_index = self.get_next_round_robin_index(candidates.len());
-            candidates.rotate_left(start_index);
-        }
-
         info!(
             "Selected {} candidate engines using {:?} strategy",
             candidates.len(),
@@ -661,11 +1036,17 @@ impl EngineRouter {
 
         // 并发竞速模式
         if self.race_mode_enabled && candidates.len() > 1 {
-            return self.route_race_mode(request, candidates, start_time).await;
+            return self
+                .route_race_mode(request, candidates, pinned, start_time)
+                .await;
         }
 
-        // 传统顺序模式 (带 max_retries 限制)
-        let max_attempts = self.max_engine_attempts.max(1).min(candidates.len());
+        // 传统顺序模式 (带 max_retries 限制)，档位回退链中的引擎全部参与尝试
+        let max_attempts = if tiered {
+            candidates.len()
+        } else {
+            self.max_engine_attempts.max(1).min(candidates.len())
+        };
         let max_retries = self.max_retries.max(1);
         let mut total_attempts = 0;
         let mut last_error = None;
@@ -696,7 +1077,8 @@ impl EngineRouter {
                 method: request.method,
                 headers: request.headers.clone(),
                 timeout: remaining,
-                needs_js: request.needs_js,
+                // 固定引擎按站点需求完整渲染（浏览器引擎只处理需要渲染的请求）
+                needs_js: request.needs_js || pinned == Some(engine_name),
                 needs_screenshot: request.needs_screenshot,
                 screenshot_config: request.screenshot_config.clone(),
                 mobile: request.mobile,
@@ -707,6 +1089,22 @@ impl EngineRouter {
                 actions: request.actions.clone(),
                 body: request.body.clone(),
                 sync_wait_ms: request.sync_wait_ms,
+                content_type_filter: request.content_type_filter.clone(),
+                http_protocol: request.http_protocol,
+                tls_profile: request.tls_profile.clone(),
+                capture_har: request.capture_har,
+                capture_mhtml: request.capture_mhtml,
+                pdf: request.pdf,
+                resource_blocking: request.resource_blocking.clone(),
+                action_error_policies: request.action_error_policies.clone(),
+                iframe_capture: request.iframe_capture,
+                flatten_shadow_dom: request.flatten_shadow_dom,
+                auto_scroll: request.auto_scroll,
+                accessibility: request.accessibility.clone(),
+                engine_tier: request.engine_tier,
+                excluded_engines: request.excluded_engines.clone(),
+                resolve: request.resolve.clone(),
+                address_family: request.address_family,
             };
 
             let engine_start = Instant::now();
@@ -748,6 +1146,10 @@ impl EngineRouter {
                             "Engine {} failed with retryable error: {}, trying next engine",
                             engine_name, e
                         );
+                        crate::infrastructure::metrics::record_engine_fallback(
+                            engine_name,
+                            e.kind(),
+                        );
                         last_error = Some(e);
 
                         // 检查是否超过最大重试次数
@@ -783,6 +1185,7 @@ impl EngineRouter {
         &self,
         request: &InternalScrapeRequest,
         candidates: Vec<(f64, Arc<dyn ScraperEngine>)>,
+        pinned: Option<&'static str>,
         start_time: Instant,
     ) -> Result<InternalScrapeResponse, EngineError> {
         use futures::future;
@@ -820,7 +1223,7 @@ impl EngineRouter {
                 method: request.method,
                 headers: request.headers.clone(),
                 timeout: remaining_clone,
-                needs_js: request.needs_js,
+                needs_js: request.needs_js || pinned == Some(engine.name()),
                 needs_screenshot: request.needs_screenshot,
                 screenshot_config: request.screenshot_config.clone(),
                 mobile: request.mobile,
@@ -831,6 +1234,22 @@ impl EngineRouter {
                 actions: request.actions.clone(),
                 body: request.body.clone(),
                 sync_wait_ms: request.sync_wait_ms,
+                content_type_filter: request.content_type_filter.clone(),
+                http_protocol: request.http_protocol,
+                tls_profile: request.tls_profile.clone(),
+                capture_har: request.capture_har,
+                capture_mhtml: request.capture_mhtml,
+                pdf: request.pdf,
+                resource_blocking: request.resource_blocking.clone(),
+                action_error_policies: request.action_error_policies.clone(),
+                iframe_capture: request.iframe_capture,
+                flatten_shadow_dom: request.flatten_shadow_dom,
+                auto_scroll: request.auto_scroll,
+                accessibility: request.accessibility.clone(),
+                engine_tier: request.engine_tier,
+                excluded_engines: request.excluded_engines.clone(),
+                resolve: request.resolve.clone(),
+                address_family: request.address_family,
             };
 
             let race_future: std::pin::Pin<Box<dyn std::future::Future<Output = _> + Send>> =
@@ -917,7 +1336,7 @@ impl EngineRouter {
             return Err(EngineError::SsrfProtection(e.to_string()));
         }
 
-        let candidates = self.select_optimal_engines(request);
+        let candidates = self.select_optimal_engines(request, &self.routing_rules());
         if candidates.is_empty() {
             // 错误消息与 _route_impl 保持一致，避免 EngineRouterTrait::aggregate
             // 与 route 行为不一致（LSP）
@@ -1065,6 +1484,7 @@ impl EngineRouterTrait for EngineRouter {
 mod tests {
     use super::*;
     use crate::engines::client::reqwest::ReqwestEngine;
+    use crate::engines::engine_tier::EngineTier;
     use async_trait::async_trait;
     use std::collections::HashMap;
 
@@ -1105,6 +1525,20 @@ mod tests {
                         content_type: "text/html".to_string(),
                         headers: HashMap::new(),
                         response_time_ms: 10,
+                        truncated: false,
+                        raw_content: None,
+                        http_version: None,
+                        tls_profile: None,
+                        har: None,
+                        mhtml: None,
+                        pdf: None,
+                        blocked_requests: None,
+                        evaluate_results: Vec::new(),
+                        frames: Vec::new(),
+                        accessibility: None,
+                        timings: None,
+                        ip_family: None,
+                        escalations: Vec::new(),
                     })
                 } else {
                     Err(EngineError::Timeout(Duration::from_millis(10)))
@@ -1160,6 +1594,22 @@ mod tests {
             actions: Vec::new(),
             body: None,
             sync_wait_ms: 0,
+            content_type_filter: None,
+            http_protocol: crate::engines::engine_client::HttpProtocol::Auto,
+            tls_profile: None,
+            capture_har: false,
+            capture_mhtml: false,
+            pdf: None,
+            resource_blocking: None,
+            action_error_policies: Vec::new(),
+            iframe_capture: None,
+            flatten_shadow_dom: false,
+            auto_scroll: None,
+            accessibility: None,
+            engine_tier: None,
+            excluded_engines: Vec::new(),
+            resolve: HashMap::new(),
+            address_family: None,
         };
         let result = router.route(&request).await;
 
@@ -1205,6 +1655,20 @@ mod tests {
                 content_type: "text/html".to_string(),
                 headers: HashMap::new(),
                 response_time_ms: 10,
+                truncated: false,
+                raw_content: None,
+                http_version: None,
+                tls_profile: None,
+                har: None,
+                mhtml: None,
+                pdf: None,
+                blocked_requests: None,
+                evaluate_results: Vec::new(),
+                frames: Vec::new(),
+                accessibility: None,
+                timings: None,
+                ip_family: None,
+                escalations: Vec::new(),
             })
         }
 
@@ -1234,6 +1698,22 @@ mod tests {
             actions: Vec::new(),
             body: None,
             sync_wait_ms: 0,
+            content_type_filter: None,
+            http_protocol: crate::engines::engine_client::HttpProtocol::Auto,
+            tls_profile: None,
+            capture_har: false,
+            capture_mhtml: false,
+            pdf: None,
+            resource_blocking: None,
+            action_error_policies: Vec::new(),
+            iframe_capture: None,
+            flatten_shadow_dom: false,
+            auto_scroll: None,
+            accessibility: None,
+            engine_tier: None,
+            excluded_engines: Vec::new(),
+            resolve: HashMap::new(),
+            address_family: None,
         }
     }
 
@@ -1335,6 +1815,21 @@ mod tests {
         assert!(result.is_none());
     }
 
+    #[test]
+    fn test_should_filter_by_feature_resolve_overrides_unsupported() {
+        let engine: Arc<dyn ScraperEngine> = Arc::new(MockEngine {
+            engine_name: "browser",
+            score: 100,
+        });
+        let router = EngineRouter::new(vec![]);
+        let mut request = make_request();
+        request
+            .resolve
+            .insert("example.com".to_string(), "203.0.113.5".parse().unwrap());
+        let result = router.should_filter_by_feature(&request, &engine);
+        assert!(result.unwrap().contains("host resolve overrides"));
+    }
+
     // === sort_candidates_by_strategy tests ===
 
     #[test]
@@ -2055,6 +2550,20 @@ mod tests {
                     content_type: "text/html".to_string(),
                     headers: HashMap::new(),
                     response_time_ms: self.delay_ms,
+                    truncated: false,
+                    raw_content: None,
+                    http_version: None,
+                    tls_profile: None,
+                    har: None,
+                    mhtml: None,
+                    pdf: None,
+                    blocked_requests: None,
+                    evaluate_results: Vec::new(),
+                    frames: Vec::new(),
+                    accessibility: None,
+                    timings: None,
+                    ip_family: None,
+                    escalations: Vec::new(),
                 })
             }
             fn support_score(&self, _request: &InternalScrapeRequest) -> u8 {
@@ -2128,6 +2637,20 @@ mod tests {
                     content_type: "text/html".to_string(),
                     headers: HashMap::new(),
                     response_time_ms: 10,
+                    truncated: false,
+                    raw_content: None,
+                    http_version: None,
+                    tls_profile: None,
+                    har: None,
+                    mhtml: None,
+                    pdf: None,
+                    blocked_requests: None,
+                    evaluate_results: Vec::new(),
+                    frames: Vec::new(),
+                    accessibility: None,
+                    timings: None,
+                    ip_family: None,
+                    escalations: Vec::new(),
                 })
             }
             fn support_score(&self, _request: &InternalScrapeRequest) -> u8 {
@@ -2235,6 +2758,20 @@ mod tests {
                     content_type: "text/html".to_string(),
                     headers: HashMap::new(),
                     response_time_ms: 10,
+                    truncated: false,
+                    raw_content: None,
+                    http_version: None,
+                    tls_profile: None,
+                    har: None,
+                    mhtml: None,
+                    pdf: None,
+                    blocked_requests: None,
+                    evaluate_results: Vec::new(),
+                    frames: Vec::new(),
+                    accessibility: None,
+                    timings: None,
+                    ip_family: None,
+                    escalations: Vec::new(),
                 })
             }
             fn support_score(&self, _request: &InternalScrapeRequest) -> u8 {
@@ -2275,6 +2812,20 @@ mod tests {
                     content_type: "text/html".to_string(),
                     headers: HashMap::new(),
                     response_time_ms: 10,
+                    truncated: false,
+                    raw_content: None,
+                    http_version: None,
+                    tls_profile: None,
+                    har: None,
+                    mhtml: None,
+                    pdf: None,
+                    blocked_requests: None,
+                    evaluate_results: Vec::new(),
+                    frames: Vec::new(),
+                    accessibility: None,
+                    timings: None,
+                    ip_family: None,
+                    escalations: Vec::new(),
                 })
             }
             fn support_score(&self, _request: &InternalScrapeRequest) -> u8 {
@@ -2310,6 +2861,20 @@ mod tests {
                     content_type: "text/html".to_string(),
                     headers: HashMap::new(),
                     response_time_ms: 10,
+                    truncated: false,
+                    raw_content: None,
+                    http_version: None,
+                    tls_profile: None,
+                    har: None,
+                    mhtml: None,
+                    pdf: None,
+                    blocked_requests: None,
+                    evaluate_results: Vec::new(),
+                    frames: Vec::new(),
+                    accessibility: None,
+                    timings: None,
+                    ip_family: None,
+                    escalations: Vec::new(),
                 })
             }
             fn support_score(&self, _request: &InternalScrapeRequest) -> u8 {
@@ -2346,6 +2911,20 @@ mod tests {
                     content_type: "text/html".to_string(),
                     headers: HashMap::new(),
                     response_time_ms: 10,
+                    truncated: false,
+                    raw_content: None,
+                    http_version: None,
+                    tls_profile: None,
+                    har: None,
+                    mhtml: None,
+                    pdf: None,
+                    blocked_requests: None,
+                    evaluate_results: Vec::new(),
+                    frames: Vec::new(),
+                    accessibility: None,
+                    timings: None,
+                    ip_family: None,
+                    escalations: Vec::new(),
                 })
             }
             fn support_score(&self, _request: &InternalScrapeRequest) -> u8 {
@@ -2376,6 +2955,22 @@ mod tests {
             actions: Vec::new(),
             body: None,
             sync_wait_ms: 0,
+            content_type_filter: None,
+            http_protocol: crate::engines::engine_client::HttpProtocol::Auto,
+            tls_profile: None,
+            capture_har: false,
+            capture_mhtml: false,
+            pdf: None,
+            resource_blocking: None,
+            action_error_policies: Vec::new(),
+            iframe_capture: None,
+            flatten_shadow_dom: false,
+            auto_scroll: None,
+            accessibility: None,
+            engine_tier: None,
+            excluded_engines: Vec::new(),
+            resolve: HashMap::new(),
+            address_family: None,
         };
 
         // The low-score engine should be filtered out, leaving no candidates
@@ -2415,6 +3010,20 @@ mod tests {
                     content_type: "text/html".to_string(),
                     headers: HashMap::new(),
                     response_time_ms: 1,
+                    truncated: false,
+                    raw_content: None,
+                    http_version: None,
+                    tls_profile: None,
+                    har: None,
+                    mhtml: None,
+                    pdf: None,
+                    blocked_requests: None,
+                    evaluate_results: Vec::new(),
+                    frames: Vec::new(),
+                    accessibility: None,
+                    timings: None,
+                    ip_family: None,
+                    escalations: Vec::new(),
                 })
             }
             fn support_score(&self, _request: &InternalScrapeRequest) -> u8 {
@@ -2466,6 +3075,407 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_route_skips_engine_that_failed_preflight() {
+        struct UnreachableEngine;
+
+        #[async_trait]
+        impl ScraperEngine for UnreachableEngine {
+            async fn scrape(
+                &self,
+                _request: &InternalScrapeRequest,
+            ) -> Result<InternalScrapeResponse, EngineError> {
+                panic!("engine that failed preflight must not be routed to");
+            }
+            fn support_score(&self, _request: &InternalScrapeRequest) -> u8 {
+                100
+            }
+            fn name(&self) -> &'static str {
+                "unreachable"
+            }
+            async fn preflight(&self) -> Result<(), EngineError> {
+                Err(EngineError::Other("connection refused".to_string()))
+            }
+        }
+
+        let engines: Vec<Arc<dyn ScraperEngine>> = vec![
+            Arc::new(UnreachableEngine),
+            Arc::new(MockEngine {
+                engine_name: "fallback",
+                score: 10,
+            }),
+        ];
+        let monitor = Arc::new(EngineHealthMonitor::new(engines.clone()));
+        let results = monitor.run_preflight(None, Duration::from_secs(5)).await;
+        assert!(!results[0].passed);
+        assert!(results[1].passed);
+
+        let mut router = EngineRouter::new(engines);
+        router.set_health_monitor(monitor);
+        let response = router.route(&make_request()).await.unwrap();
+        assert_eq!(response.status_code, 200);
+    }
+
+    #[tokio::test]
+    async fn test_domain_override_pins_engine_before_heuristics() {
+        struct RecordingEngine {
+            seen_needs_js: Arc<parking_lot::Mutex<Vec<bool>>>,
+        }
+
+        #[async_trait]
+        impl ScraperEngine for RecordingEngine {
+            async fn scrape(
+                &self,
+                request: &InternalScrapeRequest,
+            ) -> Result<InternalScrapeResponse, EngineError> {
+                self.seen_needs_js.lock().push(request.needs_js);
+                Err(EngineError::RequestFailed(
+                    "pinned engine failed".to_string(),
+                ))
+            }
+            fn support_score(&self, _request: &InternalScrapeRequest) -> u8 {
+                0
+            }
+            fn name(&self) -> &'static str {
+                "pinned"
+            }
+        }
+
+        let seen_needs_js = Arc::new(parking_lot::Mutex::new(Vec::new()));
+        let engines: Vec<Arc<dyn ScraperEngine>> = vec![
+            Arc::new(MockEngine {
+                engine_name: "heuristic",
+                score: 100,
+            }),
+            Arc::new(RecordingEngine {
+                seen_needs_js: seen_needs_js.clone(),
+            }),
+        ];
+        let mut router = EngineRouter::new(engines);
+        router.set_domain_overrides(DomainOverrides::new([("*.example.com", "pinned")]));
+
+        // 固定引擎即使支持分数为 0 也会先被尝试，失败后回退到启发式候选
+        let response = router.route(&make_request()).await.unwrap();
+        assert_eq!(response.content, "mock");
+        assert_eq!(*seen_needs_js.lock(), vec![true]);
+
+        // 不匹配的域名不受影响
+        let mut request = make_request();
+        request.url = "http://example.org".to_string();
+        router.route(&request).await.unwrap();
+        assert_eq!(seen_needs_js.lock().len(), 1);
+    }
+
+    #[test]
+    fn test_reloadable_settings_toggle_engines_without_restart() {
+        let engines: Vec<Arc<dyn ScraperEngine>> = vec![
+            Arc::new(MockEngine {
+                engine_name: "flaresolverr",
+                score: 100,
+            }),
+            Arc::new(MockEngine {
+                engine_name: "reqwest",
+                score: 50,
+            }),
+        ];
+        let mut router = EngineRouter::new(engines);
+        let mut settings = crate::bootstrap::config::load_settings().unwrap();
+        settings.engines.flaresolverr.enabled = true;
+        let reloadable = Arc::new(ReloadableSettings::new(settings.clone()));
+        router.set_reloadable_settings(reloadable.clone());
+
+        let selected = |router: &EngineRouter| {
+            router
+                .select_optimal_engines(&make_request(), &router.routing_rules())
+                .iter()
+                .map(|(_, engine)| engine.name())
+                .collect::<Vec<_>>()
+        };
+        assert!(selected(&router).contains(&"flaresolverr"));
+
+        // 重载后关闭的引擎不再参与路由
+        settings.engines.flaresolverr.enabled = false;
+        reloadable.apply(settings);
+        assert_eq!(selected(&router), vec!["reqwest"]);
+    }
+
+    #[tokio::test]
+    async fn test_engine_tier_orders_chain_and_honors_exclusions() {
+        struct FailingEngine {
+            engine_name: &'static str,
+            attempts: Arc<parking_lot::Mutex<Vec<&'static str>>>,
+        }
+
+        #[async_trait]
+        impl ScraperEngine for FailingEngine {
+            async fn scrape(
+                &self,
+                _request: &InternalScrapeRequest,
+            ) -> Result<InternalScrapeResponse, EngineError> {
+                self.attempts.lock().push(self.engine_name);
+                Err(EngineError::RequestFailed("failed".to_string()))
+            }
+            fn support_score(&self, _request: &InternalScrapeRequest) -> u8 {
+                100
+            }
+            fn name(&self) -> &'static str {
+                self.engine_name
+            }
+        }
+
+        let attempts = Arc::new(parking_lot::Mutex::new(Vec::new()));
+        let engines: Vec<Arc<dyn ScraperEngine>> = vec![
+            Arc::new(FailingEngine {
+                engine_name: "first",
+                attempts: attempts.clone(),
+            }),
+            Arc::new(FailingEngine {
+                engine_name: "second",
+                attempts: attempts.clone(),
+            }),
+            Arc::new(MockEngine {
+                engine_name: "last",
+                score: 10,
+            }),
+        ];
+        let mut router = EngineRouter::new(engines);
+        router.set_max_engine_attempts(1);
+        router.set_engine_tiers(EngineTiers {
+            max: vec![
+                "second".to_string(),
+                "first".to_string(),
+                "last".to_string(),
+            ],
+            ..EngineTiers::default()
+        });
+
+        // 回退链中的引擎按配置顺序全部参与尝试，不受 max_engine_attempts 限制
+        let mut request = make_request();
+        request.engine_tier = Some(EngineTier::Max);
+        let response = router.route(&request).await.unwrap();
+        assert_eq!(response.content, "mock");
+        assert_eq!(*attempts.lock(), vec!["second", "first"]);
+
+        // 团队排除的引擎不会被尝试
+        attempts.lock().clear();
+        request.excluded_engines = vec!["second".to_string()];
+        router.route(&request).await.unwrap();
+        assert_eq!(*attempts.lock(), vec!["first"]);
+
+        // 未配置回退链的档位沿用启发式排序与尝试次数
+        attempts.lock().clear();
+        request.engine_tier = Some(EngineTier::Cheap);
+        request.excluded_engines = vec!["first".to_string(), "second".to_string()];
+        router.route(&request).await.unwrap();
+        assert!(attempts.lock().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_route_escalates_blocked_response_to_browser() {
+        struct LadderEngine {
+            engine_name: &'static str,
+            renders: bool,
+        }
+        #[async_trait]
+        impl ScraperEngine for LadderEngine {
+            async fn scrape(
+                &self,
+                request: &InternalScrapeRequest,
+            ) -> Result<InternalScrapeResponse, EngineError> {
+                let mut response = MockEngine {
+                    engine_name: self.engine_name,
+                    score: 100,
+                }
+                .scrape(request)
+                .await?;
+                if !self.renders {
+                    response.status_code = 403;
+                    response
+                        .headers
+                        .insert("cf-mitigated".to_string(), "challenge".to_string());
+                }
+                Ok(response)
+            }
+            fn support_score(&self, request: &InternalScrapeRequest) -> u8 {
+                if request.needs_js == self.renders {
+                    100
+                } else {
+                    10
+                }
+            }
+            fn name(&self) -> &'static str {
+                self.engine_name
+            }
+        }
+
+        let engines: Vec<Arc<dyn ScraperEngine>> = vec![
+            Arc::new(LadderEngine {
+                engine_name: "http",
+                renders: false,
+            }),
+            Arc::new(LadderEngine {
+                engine_name: "browser",
+                renders: true,
+            }),
+        ];
+        let router = EngineRouter::new(engines.clone());
+        let response = router.route(&make_request()).await.unwrap();
+        assert_eq!(response.status_code, 403);
+        assert!(response.escalations.is_empty());
+
+        // 没有引擎支持 TLS 指纹，该步骤失败后升级到浏览器
+        let mut router = EngineRouter::new(engines);
+        router.set_block_escalation(BlockEscalation::new(3, None));
+        let response = router.route(&make_request()).await.unwrap();
+        assert_eq!(response.status_code, 200);
+        let steps: Vec<_> = response
+            .escalations
+            .iter()
+            .map(|attempt| (attempt.step, attempt.outcome()))
+            .collect();
+        assert_eq!(
+            steps,
+            vec![
+                (EscalationStep::Initial, "blocked"),
+                (EscalationStep::TlsFingerprint, "failed"),
+                (EscalationStep::Browser, "succeeded"),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_route_escalation_shares_request_deadline() {
+        struct SlowEngine {
+            engine_name: &'static str,
+            renders: bool,
+            delay: Duration,
+        }
+        #[async_trait]
+        impl ScraperEngine for SlowEngine {
+            async fn scrape(
+                &self,
+                request: &InternalScrapeRequest,
+            ) -> Result<InternalScrapeResponse, EngineError> {
+                tokio::time::sleep(self.delay).await;
+                let mut response = MockEngine {
+                    engine_name: self.engine_name,
+                    score: 100,
+                }
+                .scrape(request)
+                .await?;
+                if !self.renders {
+                    response.status_code = 403;
+                    response
+                        .headers
+                        .insert("cf-mitigated".to_string(), "challenge".to_string());
+                }
+                Ok(response)
+            }
+            fn support_score(&self, request: &InternalScrapeRequest) -> u8 {
+                if request.needs_js == self.renders {
+                    100
+                } else {
+                    10
+                }
+            }
+            fn name(&self) -> &'static str {
+                self.engine_name
+            }
+        }
+
+        let engines: Vec<Arc<dyn ScraperEngine>> = vec![
+            Arc::new(SlowEngine {
+                engine_name: "http",
+                renders: false,
+                delay: Duration::from_millis(400),
+            }),
+            Arc::new(SlowEngine {
+                engine_name: "browser",
+                renders: true,
+                delay: Duration::from_millis(300),
+            }),
+        ];
+        let mut router = EngineRouter::new(engines);
+        router.set_block_escalation(BlockEscalation::new(3, None));
+        let mut request = make_request();
+        request.timeout = Duration::from_millis(500);
+
+        // 首次请求已用去大部分超时，浏览器步骤只能使用剩余时间
+        let response = router.route(&request).await.unwrap();
+        assert_eq!(response.status_code, 403);
+        let browser = response
+            .escalations
+            .iter()
+            .find(|attempt| attempt.step == EscalationStep::Browser)
+            .map(|attempt| attempt.outcome());
+        assert_ne!(browser, Some("succeeded"));
+    }
+
+    #[tokio::test]
+    async fn test_route_rotates_pool_proxies_for_requests_without_proxy() {
+        use crate::engines::proxy_pool::PoolProxy;
+
+        struct ProxyRecordingEngine {
+            proxies: Arc<parking_lot::Mutex<Vec<Option<String>>>>,
+        }
+        #[async_trait]
+        impl ScraperEngine for ProxyRecordingEngine {
+            async fn scrape(
+                &self,
+                request: &InternalScrapeRequest,
+            ) -> Result<InternalScrapeResponse, EngineError> {
+                self.proxies.lock().push(request.proxy.clone());
+                MockEngine {
+                    engine_name: "recording",
+                    score: 100,
+                }
+                .scrape(request)
+                .await
+            }
+            fn support_score(&self, _request: &InternalScrapeRequest) -> u8 {
+                100
+            }
+            fn name(&self) -> &'static str {
+                "recording"
+            }
+        }
+
+        let proxies = Arc::new(parking_lot::Mutex::new(Vec::new()));
+        let mut router = EngineRouter::new(vec![Arc::new(ProxyRecordingEngine {
+            proxies: proxies.clone(),
+        })]);
+        let pool = Arc::new(ProxyPool::new(
+            vec![
+                PoolProxy {
+                    url: "http://proxy-a:8080".to_string(),
+                    country: None,
+                },
+                PoolProxy {
+                    url: "http://proxy-b:8080".to_string(),
+                    country: None,
+                },
+            ],
+            1,
+        ));
+        router.set_proxy_pool(pool);
+
+        let mut request = make_request();
+        router.route(&request).await.unwrap();
+        router.route(&request).await.unwrap();
+        // 请求级代理不被替换
+        request.proxy = Some("http://own-proxy:8080".to_string());
+        router.route(&request).await.unwrap();
+
+        assert_eq!(
+            *proxies.lock(),
+            vec![
+                Some("http://proxy-a:8080".to_string()),
+                Some("http://proxy-b:8080".to_string()),
+                Some("http://own-proxy:8080".to_string()),
+            ]
+        );
+    }
+
     // === route_internal remaining=0 branch (line 690-692) ===
 
     #[tokio::test]
@@ -2628,6 +3638,20 @@ mod tests {
                     content_type: "text/html".to_string(),
                     headers: HashMap::new(),
                     response_time_ms: 5000,
+                    truncated: false,
+                    raw_content: None,
+                    http_version: None,
+                    tls_profile: None,
+                    har: None,
+                    mhtml: None,
+                    pdf: None,
+                    blocked_requests: None,
+                    evaluate_results: Vec::new(),
+                    frames: Vec::new(),
+                    accessibility: None,
+                    timings: None,
+                    ip_family: None,
+                    escalations: Vec::new(),
                 })
             }
             fn support_score(&self, _request: &InternalScrapeRequest) -> u8 {
@@ -2745,6 +3769,20 @@ mod tests_impl {
                     content_type: "text/html".to_string(),
                     headers: HashMap::new(),
                     response_time_ms: 100,
+                    truncated: false,
+                    raw_content: None,
+                    http_version: None,
+                    tls_profile: None,
+                    har: None,
+                    mhtml: None,
+                    pdf: None,
+                    blocked_requests: None,
+                    evaluate_results: Vec::new(),
+                    frames: Vec::new(),
+                    accessibility: None,
+                    timings: None,
+                    ip_family: None,
+                    escalations: Vec::new(),
                 })
             } else {
                 Ok(InternalScrapeResponse {
@@ -2754,6 +3792,20 @@ mod tests_impl {
                     content_type: "text/html".to_string(),
                     headers: HashMap::new(),
                     response_time_ms: 100,
+                    truncated: false,
+                    raw_content: None,
+                    http_version: None,
+                    tls_profile: None,
+                    har: None,
+                    mhtml: None,
+                    pdf: None,
+                    blocked_requests: None,
+                    evaluate_results: Vec::new(),
+                    frames: Vec::new(),
+                    accessibility: None,
+                    timings: None,
+                    ip_family: None,
+                    escalations: Vec::new(),
                 })
             }
         }
@@ -2780,6 +3832,20 @@ mod tests_impl {
                 content_type: "text/html".to_string(),
                 headers: HashMap::new(),
                 response_time_ms: 100,
+                truncated: false,
+                raw_content: None,
+                http_version: None,
+                tls_profile: None,
+                har: None,
+                mhtml: None,
+                pdf: None,
+                blocked_requests: None,
+                evaluate_results: Vec::new(),
+                frames: Vec::new(),
+                accessibility: None,
+                timings: None,
+                ip_family: None,
+                escalations: Vec::new(),
             }),
             10, // max_calls
         );
@@ -2795,6 +3861,20 @@ mod tests_impl {
                 content_type: "text/html".to_string(),
                 headers: HashMap::new(),
                 response_time_ms: 100,
+                truncated: false,
+                raw_content: None,
+                http_version: None,
+                tls_profile: None,
+                har: None,
+                mhtml: None,
+                pdf: None,
+                blocked_requests: None,
+                evaluate_results: Vec::new(),
+                frames: Vec::new(),
+                accessibility: None,
+                timings: None,
+                ip_family: None,
+                escalations: Vec::new(),
             }),
             10, // max_calls
         );
@@ -2817,6 +3897,22 @@ mod tests_impl {
             actions: Vec::new(),
             body: None,
             sync_wait_ms: 0,
+            content_type_filter: None,
+            http_protocol: crate::engines::engine_client::HttpProtocol::Auto,
+            tls_profile: None,
+            capture_har: false,
+            capture_mhtml: false,
+            pdf: None,
+            resource_blocking: None,
+            action_error_policies: Vec::new(),
+            iframe_capture: None,
+            flatten_shadow_dom: false,
+            auto_scroll: None,
+            accessibility: None,
+            engine_tier: None,
+            excluded_engines: Vec::new(),
+            resolve: HashMap::new(),
+            address_family: None,
         };
         let result = router.aggregate(&request).await;
 
@@ -2846,6 +3942,20 @@ mod tests_impl {
                 content_type: "text/html".to_string(),
                 headers: HashMap::new(),
                 response_time_ms: 100,
+                truncated: false,
+                raw_content: None,
+                http_version: None,
+                tls_profile: None,
+                har: None,
+                mhtml: None,
+                pdf: None,
+                blocked_requests: None,
+                evaluate_results: Vec::new(),
+                frames: Vec::new(),
+                accessibility: None,
+                timings: None,
+                ip_family: None,
+                escalations: Vec::new(),
             }),
             10, // max_calls
         );
@@ -2868,6 +3978,22 @@ mod tests_impl {
             actions: Vec::new(),
             body: None,
             sync_wait_ms: 0,
+            content_type_filter: None,
+            http_protocol: crate::engines::engine_client::HttpProtocol::Auto,
+            tls_profile: None,
+            capture_har: false,
+            capture_mhtml: false,
+            pdf: None,
+            resource_blocking: None,
+            action_error_policies: Vec::new(),
+            iframe_capture: None,
+            flatten_shadow_dom: false,
+            auto_scroll: None,
+            accessibility: None,
+            engine_tier: None,
+            excluded_engines: Vec::new(),
+            resolve: HashMap::new(),
+            address_family: None,
         };
         let result = router.aggregate(&request).await;
 
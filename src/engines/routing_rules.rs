@@ -0,0 +1,89 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! 可热重载的路由规则
+//!
+//! 域名固定引擎、档位回退链与配置中关闭的引擎都来自 `[engines]` 配置。路由器绑定
+//! 可热重载的配置后，每次路由读取当前配置对应的规则，运维修改这些配置无需重启。
+
+use crate::config::engines::EngineSettings;
+use crate::engines::domain_overrides::DomainOverrides;
+use crate::engines::engine_tier::EngineTiers;
+
+/// 路由器使用的规则集合
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RoutingRules {
+    /// 按域名固定的引擎
+    pub domain_overrides: DomainOverrides,
+    /// 按请求档位配置的引擎回退链
+    pub tiers: EngineTiers,
+    /// 配置中被关闭的引擎，不参与路由
+    pub disabled_engines: Vec<String>,
+}
+
+impl RoutingRules {
+    /// 从引擎配置构建路由规则
+    ///
+    /// 引擎开关只能关闭启动时已启用的引擎；启动时未启用的引擎没有被创建，重新打开开关
+    /// 需要重启。
+    pub fn from_settings(settings: &EngineSettings) -> Self {
+        let disabled_engines = [
+            ("flaresolverr", settings.flaresolverr.enabled),
+            ("fire_engine_cdp", settings.fire_cdp.enabled),
+            ("fire_engine_tls", settings.fire_tls.enabled),
+        ]
+        .into_iter()
+        .filter(|(_, enabled)| !enabled)
+        .map(|(engine, _)| engine.to_string())
+        .collect();
+
+        Self {
+            domain_overrides: DomainOverrides::new(
+                settings
+                    .domain_overrides
+                    .iter()
+                    .map(|rule| (&rule.domain, &rule.engine)),
+            ),
+            tiers: EngineTiers::from_settings(&settings.tiers),
+            disabled_engines,
+        }
+    }
+
+    /// 引擎是否被配置关闭
+    pub fn is_disabled(&self, engine_name: &str) -> bool {
+        self.disabled_engines.iter().any(|e| e == engine_name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bootstrap::config::load_settings;
+    use crate::config::engines::DomainEngineOverrideSettings;
+
+    #[test]
+    fn test_from_settings_collects_disabled_engines_and_overrides() {
+        let mut settings = load_settings().expect("Failed to load settings").engines;
+        settings.flaresolverr.enabled = false;
+        settings.fire_cdp.enabled = true;
+        settings.fire_tls.enabled = false;
+        settings.domain_overrides = vec![DomainEngineOverrideSettings {
+            domain: "*.example.com".to_string(),
+            engine: "fire_engine_cdp".to_string(),
+        }];
+
+        let rules = RoutingRules::from_settings(&settings);
+        assert!(rules.is_disabled("flaresolverr"));
+        assert!(rules.is_disabled("fire_engine_tls"));
+        assert!(!rules.is_disabled("fire_engine_cdp"));
+        assert!(!rules.is_disabled("reqwest"));
+        assert_eq!(
+            rules
+                .domain_overrides
+                .engine_for("https://news.example.com/a"),
+            Some("fire_engine_cdp")
+        );
+    }
+}
@@ -0,0 +1,271 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! 整页截图分段拼接
+//!
+//! CDP 一次截取超长页面时会因 GPU 纹理尺寸限制失败或被裁剪。页面高度超过
+//! `MAX_SEGMENT_HEIGHT` 时，浏览器引擎按视口高度分段滚动截取（PNG 无损），再在本地
+//! 拼接为一张图片并按请求的格式与质量编码。截图高度受 `max_height`、输出格式的最大尺寸
+//! 与 `MAX_STITCHED_PIXELS` 共同限制，超出部分被截断。
+
+use serde::Deserialize;
+
+/// 默认的最大截图高度（CSS 像素）
+pub const DEFAULT_MAX_SCREENSHOT_HEIGHT: u32 = 16_384;
+
+/// 允许的最大截图高度（CSS 像素）
+pub const MAX_SCREENSHOT_HEIGHT: u32 = 65_535;
+
+/// 不分段时单次截取的最大高度（CSS 像素）
+pub const MAX_SEGMENT_HEIGHT: u32 = 4_096;
+
+/// 拼接图像的最大像素数（RGBA 约 256 MB），避免超长页面耗尽内存
+pub const MAX_STITCHED_PIXELS: u64 = 64 * 1024 * 1024;
+
+/// 截图输出格式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScreenshotFormat {
+    Png,
+    Jpeg,
+    Webp,
+}
+
+impl ScreenshotFormat {
+    /// 按名称解析格式（大小写不敏感），未设置或无法识别时使用 JPEG
+    pub fn from_name(name: Option<&str>) -> Self {
+        match name.map(|n| n.trim().to_ascii_lowercase()).as_deref() {
+            Some("png") => Self::Png,
+            Some("webp") => Self::Webp,
+            _ => Self::Jpeg,
+        }
+    }
+
+    /// 是否为支持的格式名称
+    pub fn is_supported(name: &str) -> bool {
+        matches!(
+            name.trim().to_ascii_lowercase().as_str(),
+            "png" | "jpeg" | "jpg" | "webp"
+        )
+    }
+
+    /// 格式允许的最大边长（设备像素）
+    pub fn max_dimension(&self) -> u32 {
+        match self {
+            Self::Png | Self::Jpeg => 65_535,
+            Self::Webp => 16_383,
+        }
+    }
+}
+
+/// 截图前在页面中读取的尺寸（CSS 像素）
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+pub struct PageMetrics {
+    /// 视口宽度（不含滚动条）
+    pub width: u32,
+    /// 文档总高度
+    pub height: u32,
+    /// 视口高度
+    pub viewport_height: u32,
+    /// 设备像素比
+    pub device_pixel_ratio: f64,
+}
+
+impl PageMetrics {
+    /// 实际截取的高度：不超过页面高度、`max_height`、格式最大尺寸与像素上限
+    pub fn capture_height(&self, max_height: Option<u32>, format: ScreenshotFormat) -> u32 {
+        let dpr = self.device_pixel_ratio.clamp(0.5, 4.0);
+        let max_height = max_height
+            .unwrap_or(DEFAULT_MAX_SCREENSHOT_HEIGHT)
+            .clamp(1, MAX_SCREENSHOT_HEIGHT);
+        let format_limit = (format.max_dimension() as f64 / dpr).floor() as u64;
+        let row_pixels = (self.width.max(1) as f64 * dpr * dpr).ceil() as u64;
+        let pixel_limit = MAX_STITCHED_PIXELS / row_pixels;
+
+        (self.height as u64)
+            .min(max_height as u64)
+            .min(format_limit)
+            .min(pixel_limit)
+            .max(1) as u32
+    }
+
+    /// 是否需要分段截取
+    pub fn needs_segments(&self, capture_height: u32) -> bool {
+        capture_height > MAX_SEGMENT_HEIGHT
+    }
+}
+
+/// 一段截取区域（CSS 像素）
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CaptureSegment {
+    pub y: u32,
+    pub height: u32,
+}
+
+/// 将 `total_height` 按 `segment_height` 切分为连续的截取区域
+pub fn plan_segments(total_height: u32, segment_height: u32) -> Vec<CaptureSegment> {
+    let segment_height = segment_height.clamp(1, MAX_SEGMENT_HEIGHT);
+    (0..total_height)
+        .step_by(segment_height as usize)
+        .map(|y| CaptureSegment {
+            y,
+            height: segment_height.min(total_height - y),
+        })
+        .collect()
+}
+
+/// 将按顺序截取的 PNG 分段自上而下拼接，并按格式与质量编码
+///
+/// JPEG 使用 `quality`（1-100）；WebP 拼接结果为无损编码，`quality` 不生效。
+#[cfg(feature = "engine-playwright")]
+pub fn stitch_segments(
+    segments: &[Vec<u8>],
+    format: ScreenshotFormat,
+    quality: u8,
+) -> Result<Vec<u8>, image::ImageError> {
+    use image::codecs::jpeg::JpegEncoder;
+    use image::codecs::png::PngEncoder;
+    use image::codecs::webp::WebPEncoder;
+    use image::{imageops, DynamicImage, ImageFormat, RgbaImage};
+
+    let decoded = segments
+        .iter()
+        .map(|bytes| {
+            image::load_from_memory_with_format(bytes, ImageFormat::Png).map(|i| i.to_rgba8())
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let width = decoded.iter().map(|s| s.width()).max().unwrap_or(0);
+    let height = decoded.iter().map(|s| s.height()).sum();
+    let mut canvas = RgbaImage::new(width, height);
+    let mut y: i64 = 0;
+    for segment in &decoded {
+        imageops::replace(&mut canvas, segment, 0, y);
+        y += segment.height() as i64;
+    }
+    drop(decoded);
+
+    let mut out = Vec::new();
+    match format {
+        ScreenshotFormat::Png => canvas.write_with_encoder(PngEncoder::new(&mut out))?,
+        ScreenshotFormat::Jpeg => DynamicImage::ImageRgba8(canvas)
+            .to_rgb8()
+            .write_with_encoder(JpegEncoder::new_with_quality(
+                &mut out,
+                quality.clamp(1, 100),
+            ))?,
+        ScreenshotFormat::Webp => canvas.write_with_encoder(WebPEncoder::new_lossless(&mut out))?,
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn metrics(width: u32, height: u32, dpr: f64) -> PageMetrics {
+        PageMetrics {
+            width,
+            height,
+            viewport_height: 800,
+            device_pixel_ratio: dpr,
+        }
+    }
+
+    #[test]
+    fn test_format_from_name() {
+        assert_eq!(ScreenshotFormat::from_name(None), ScreenshotFormat::Jpeg);
+        assert_eq!(
+            ScreenshotFormat::from_name(Some("PNG")),
+            ScreenshotFormat::Png
+        );
+        assert_eq!(
+            ScreenshotFormat::from_name(Some("webp")),
+            ScreenshotFormat::Webp
+        );
+        assert!(ScreenshotFormat::is_supported("jpg"));
+        assert!(!ScreenshotFormat::is_supported("gif"));
+    }
+
+    #[test]
+    fn test_capture_height_limits() {
+        // 页面高度、max_height 与默认上限
+        assert_eq!(
+            metrics(1280, 3000, 1.0).capture_height(None, ScreenshotFormat::Png),
+            3000
+        );
+        assert_eq!(
+            metrics(1280, 50_000, 1.0).capture_height(None, ScreenshotFormat::Png),
+            DEFAULT_MAX_SCREENSHOT_HEIGHT
+        );
+        assert_eq!(
+            metrics(1280, 50_000, 1.0).capture_height(Some(40_000), ScreenshotFormat::Jpeg),
+            40_000
+        );
+        // WebP 最大边长 16383 设备像素，2 倍像素比时为 8191 CSS 像素
+        assert_eq!(
+            metrics(1280, 50_000, 2.0).capture_height(Some(40_000), ScreenshotFormat::Webp),
+            8_191
+        );
+        // 像素上限：1920 * 65535 超过 64M 像素
+        assert_eq!(
+            metrics(1920, 65_535, 1.0).capture_height(Some(65_535), ScreenshotFormat::Png),
+            (MAX_STITCHED_PIXELS / 1920) as u32
+        );
+    }
+
+    #[test]
+    fn test_plan_segments_covers_height() {
+        let segments = plan_segments(2000, 800);
+        assert_eq!(
+            segments,
+            vec![
+                CaptureSegment { y: 0, height: 800 },
+                CaptureSegment {
+                    y: 800,
+                    height: 800
+                },
+                CaptureSegment {
+                    y: 1600,
+                    height: 400
+                },
+            ]
+        );
+        assert_eq!(plan_segments(100, 0).len(), 100);
+        assert!(plan_segments(0, 800).is_empty());
+    }
+
+    #[cfg(feature = "engine-playwright")]
+    #[test]
+    fn test_stitch_segments_stacks_vertically() {
+        use image::{ImageFormat, Rgba, RgbaImage};
+        use std::io::Cursor;
+
+        let png = |color: [u8; 4], height: u32| {
+            let mut bytes = Vec::new();
+            RgbaImage::from_pixel(4, height, Rgba(color))
+                .write_to(&mut Cursor::new(&mut bytes), ImageFormat::Png)
+                .unwrap();
+            bytes
+        };
+        let segments = vec![png([255, 0, 0, 255], 3), png([0, 0, 255, 255], 2)];
+
+        let stitched = stitch_segments(&segments, ScreenshotFormat::Png, 80).unwrap();
+        let image = image::load_from_memory(&stitched).unwrap().to_rgba8();
+        assert_eq!((image.width(), image.height()), (4, 5));
+        assert_eq!(image.get_pixel(0, 2), &Rgba([255, 0, 0, 255]));
+        assert_eq!(image.get_pixel(0, 3), &Rgba([0, 0, 255, 255]));
+
+        let jpeg = stitch_segments(&segments, ScreenshotFormat::Jpeg, 50).unwrap();
+        assert_eq!(
+            image::guess_format(&jpeg).unwrap(),
+            image::ImageFormat::Jpeg
+        );
+        let webp = stitch_segments(&segments, ScreenshotFormat::Webp, 50).unwrap();
+        assert_eq!(
+            image::guess_format(&webp).unwrap(),
+            image::ImageFormat::WebP
+        );
+    }
+}
@@ -0,0 +1,81 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! TLS 指纹配置
+//!
+//! FireEngineTls（`FlareSolverrMode::Tls`）可按请求选择的浏览器 TLS 指纹。
+//! 每个配置包含 JA3 字符串与对应浏览器的 User-Agent，二者需保持一致，
+//! 否则握手指纹与请求头不符反而更容易被识别。
+
+/// 默认使用的指纹配置
+pub const DEFAULT_TLS_PROFILE: &str = "chrome-120";
+
+/// 浏览器 TLS 指纹配置
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TlsProfile {
+    /// 配置名称（`options.tls_profile` 的取值）
+    pub name: &'static str,
+    /// JA3 字符串（TLS 版本、密码套件、扩展、椭圆曲线、点格式）
+    pub ja3: &'static str,
+    /// 与指纹匹配的 User-Agent
+    pub user_agent: &'static str,
+}
+
+/// 内置指纹配置
+pub const TLS_PROFILES: &[TlsProfile] = &[
+    TlsProfile {
+        name: "chrome-120",
+        ja3: "771,4865-4866-4867-49195-49199-49196-49200-52393-52392-49171-49172-156-157-47-53,0-23-65281-10-11-35-16-5-13-18-51-45-43-27-17513-21,29-23-24,0",
+        user_agent: "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/120.0.0.0 Safari/537.36",
+    },
+    TlsProfile {
+        name: "edge-120",
+        ja3: "771,4865-4866-4867-49195-49199-49196-49200-52393-52392-49171-49172-156-157-47-53,0-23-65281-10-11-35-16-5-13-18-51-45-43-27-17513-21,29-23-24,0",
+        user_agent: "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/120.0.0.0 Safari/537.36 Edg/120.0.0.0",
+    },
+    TlsProfile {
+        name: "firefox-121",
+        ja3: "771,4865-4867-4866-49195-49199-52393-52392-49196-49200-49162-49161-49171-49172-156-157-47-53,0-23-65281-10-11-16-5-34-51-43-13-45-28-65037,29-23-24-25-256-257,0",
+        user_agent: "Mozilla/5.0 (Windows NT 10.0; Win64; x64; rv:121.0) Gecko/20100101 Firefox/121.0",
+    },
+    TlsProfile {
+        name: "safari-17",
+        ja3: "771,4865-4866-4867-49196-49195-52393-49200-49199-52392-49162-49161-49172-49171-157-156-53-47-49160-49170-10,0-23-65281-10-11-16-5-13-18-51-45-43-27-21,29-23-24-25,0",
+        user_agent: "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/17.2 Safari/605.1.15",
+    },
+];
+
+/// 按名称查找指纹配置（大小写不敏感）
+pub fn find_tls_profile(name: &str) -> Option<&'static TlsProfile> {
+    let name = name.trim();
+    TLS_PROFILES
+        .iter()
+        .find(|profile| profile.name.eq_ignore_ascii_case(name))
+}
+
+/// 所有内置配置名称，用于错误提示
+pub fn tls_profile_names() -> Vec<&'static str> {
+    TLS_PROFILES.iter().map(|profile| profile.name).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_tls_profile() {
+        assert_eq!(find_tls_profile("Firefox-121").unwrap().name, "firefox-121");
+        assert!(find_tls_profile("netscape-4").is_none());
+        assert!(find_tls_profile(DEFAULT_TLS_PROFILE).is_some());
+    }
+
+    #[test]
+    fn test_tls_profile_names_are_unique() {
+        let mut names = tls_profile_names();
+        names.sort_unstable();
+        names.dedup();
+        assert_eq!(names.len(), TLS_PROFILES.len());
+    }
+}
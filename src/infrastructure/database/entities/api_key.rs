@@ -18,6 +18,8 @@ pub struct Model {
     pub key_hash: Option<String>,
     pub created_at: ChronoDateTimeWithTimeZone,
     pub updated_at: Option<ChronoDateTimeWithTimeZone>,
+    /// Expiry of session keys issued by SSO login; `None` for regular API keys
+    pub expires_at: Option<ChronoDateTimeWithTimeZone>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -53,6 +55,7 @@ mod tests {
             key_hash: Some("sha256hash".to_string()),
             created_at: chrono::Utc::now().fixed_offset(),
             updated_at: None,
+            expires_at: None,
         }
     }
 
@@ -67,6 +70,7 @@ mod tests {
             key_hash: Some("hash123".to_string()),
             created_at: chrono::Utc::now().fixed_offset(),
             updated_at: None,
+            expires_at: None,
         };
         assert_eq!(model.id, id);
         assert_eq!(model.team_id, team_id);
@@ -123,6 +127,7 @@ mod tests {
             key_hash: ActiveValue::Set(None),
             created_at: ActiveValue::Set(chrono::Utc::now().fixed_offset()),
             updated_at: ActiveValue::Set(None),
+            expires_at: ActiveValue::Set(None),
         };
         assert_eq!(active.id.as_ref(), &id);
         assert_eq!(active.team_id.as_ref(), &team_id);
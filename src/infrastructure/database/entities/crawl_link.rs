@@ -0,0 +1,30 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// 爬取链接实体
+///
+/// 对应数据库中的 crawl_links 表
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "crawl_links")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub crawl_id: Uuid,
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub source_url: String,
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub target_url: String,
+    pub anchor_text: Option<String>,
+    pub rel: Option<String>,
+    pub created_at: DateTimeWithTimeZone,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
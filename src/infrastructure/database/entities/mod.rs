@@ -11,12 +11,24 @@
 pub mod api_key;
 pub mod auth;
 pub mod crawl;
+pub mod crawl_link;
+pub mod credit_alert;
 pub mod credits;
 pub mod credits_transactions;
+pub mod domain_throttle;
 pub mod geo_restriction_log;
+pub mod monitor;
+pub mod notification_channel;
+pub mod notification_contacts;
+pub mod resource_tag;
 pub mod scrape_result;
 pub mod task;
+pub mod task_event;
 pub mod tasks_backlog;
 pub mod team;
+pub mod team_bandwidth_usage;
+pub mod team_member;
+pub mod url_blocklist;
+pub mod user;
 pub mod webhook;
 pub mod webhook_event;
@@ -0,0 +1,37 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// 页面变更监控实体
+///
+/// 对应数据库中的 monitors 表
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "monitors")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+    pub team_id: Uuid,
+    pub url: String,
+    pub interval_seconds: i64,
+    pub mode: String,
+    pub extraction_rules: Option<Json>,
+    pub active: bool,
+    pub last_hash: Option<String>,
+    pub last_snapshot: Option<String>,
+    pub last_error: Option<String>,
+    pub last_checked_at: Option<DateTimeWithTimeZone>,
+    pub last_changed_at: Option<DateTimeWithTimeZone>,
+    pub next_check_at: DateTimeWithTimeZone,
+    pub created_at: DateTimeWithTimeZone,
+    pub updated_at: DateTimeWithTimeZone,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,30 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// 资源标签实体
+///
+/// 对应数据库中的 resource_tags 表
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "resource_tags")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub resource_type: String,
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub resource_id: Uuid,
+    pub team_id: Uuid,
+    pub tags: Json,
+    pub annotations: Json,
+    pub created_at: DateTimeWithTimeZone,
+    pub updated_at: DateTimeWithTimeZone,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
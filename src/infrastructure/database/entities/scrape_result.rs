@@ -21,6 +21,10 @@ pub struct Model {
     pub headers: Option<Json>,
     pub meta_data: Option<Json>,
     pub screenshot: Option<String>,
+    pub etag: Option<String>,
+    pub last_modified: Option<String>,
+    pub content_encoding: Option<String>,
+    pub content_compressed: Option<Vec<u8>>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -21,6 +21,9 @@ pub struct Model {
     pub ip_whitelist: Option<Json>,
     pub domain_blacklist: Option<Json>,
     pub enable_geo_restrictions: bool,
+    pub allow_ignore_robots: bool,
+    pub plan: String,
+    pub plan_credits_granted_at: Option<ChronoDateTimeWithTimeZone>,
     pub created_at: ChronoDateTimeWithTimeZone,
     pub updated_at: ChronoDateTimeWithTimeZone,
 }
@@ -105,6 +108,9 @@ mod tests {
             ip_whitelist: Some(serde_json::json!(["127.0.0.1"])),
             domain_blacklist: Some(serde_json::json!(["spam.com"])),
             enable_geo_restrictions: true,
+            allow_ignore_robots: false,
+            plan: "pro".to_string(),
+            plan_credits_granted_at: None,
             created_at: chrono::Utc::now().fixed_offset(),
             updated_at: chrono::Utc::now().fixed_offset(),
         }
@@ -121,6 +127,9 @@ mod tests {
             ip_whitelist: None,
             domain_blacklist: None,
             enable_geo_restrictions: false,
+            allow_ignore_robots: false,
+            plan: "free".to_string(),
+            plan_credits_granted_at: None,
             created_at: chrono::Utc::now().fixed_offset(),
             updated_at: chrono::Utc::now().fixed_offset(),
         };
@@ -129,6 +138,7 @@ mod tests {
         assert!(model.allowed_countries.is_none());
         assert!(model.blocked_countries.is_none());
         assert!(!model.enable_geo_restrictions);
+        assert_eq!(model.plan, "free");
     }
 
     #[test]
@@ -179,6 +189,9 @@ mod tests {
             ip_whitelist: ActiveValue::Set(None),
             domain_blacklist: ActiveValue::Set(None),
             enable_geo_restrictions: ActiveValue::Set(false),
+            allow_ignore_robots: ActiveValue::Set(false),
+            plan: ActiveValue::Set("free".to_string()),
+            plan_credits_granted_at: ActiveValue::Set(None),
             created_at: ActiveValue::Set(chrono::Utc::now().fixed_offset()),
             updated_at: ActiveValue::Set(chrono::Utc::now().fixed_offset()),
         };
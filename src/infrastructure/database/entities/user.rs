@@ -0,0 +1,41 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// 用户实体
+///
+/// 对应数据库中的 users 表，以小写邮箱唯一标识用户
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "users")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+    #[sea_orm(unique)]
+    pub email: String,
+    pub name: Option<String>,
+    pub created_at: DateTimeWithTimeZone,
+    pub updated_at: DateTimeWithTimeZone,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        has_many = "super::team_member::Entity",
+        from = "Column::Id",
+        to = "super::team_member::Column::UserId"
+    )]
+    TeamMembers,
+}
+
+impl Related<super::team_member::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TeamMembers.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -13,6 +13,12 @@ pub struct Model {
     pub id: Uuid,
     pub team_id: Uuid,
     pub url: String,
+    /// 订阅的事件类型（JSON 字符串数组），NULL 表示订阅全部事件
+    pub event_types: Option<Json>,
+    /// 每次投递附带的自定义请求头（JSON 对象），NULL 表示无
+    pub custom_headers: Option<Json>,
+    /// 载荷模板（JSON 文本，支持 `{{path}}` 占位符），NULL 表示发送原始载荷
+    pub payload_template: Option<String>,
     pub created_at: DateTimeWithTimeZone,
 }
 
@@ -46,6 +52,9 @@ mod tests {
             id: Uuid::new_v4(),
             team_id: Uuid::new_v4(),
             url: "https://example.com/webhook".to_string(),
+            event_types: None,
+            custom_headers: None,
+            payload_template: None,
             created_at: chrono::Utc::now().fixed_offset(),
         }
     }
@@ -58,6 +67,9 @@ mod tests {
             id,
             team_id,
             url: "https://hook.example.com/cb".to_string(),
+            event_types: Some(serde_json::json!(["crawl.completed"])),
+            custom_headers: Some(serde_json::json!({"Authorization": "Bearer t"})),
+            payload_template: Some(r#"{"text": "{{event}}"}"#.to_string()),
             created_at: chrono::Utc::now().fixed_offset(),
         };
         assert_eq!(model.id, id);
@@ -100,6 +112,9 @@ mod tests {
             id: ActiveValue::Set(id),
             team_id: ActiveValue::Set(Uuid::new_v4()),
             url: ActiveValue::Set("https://new.com/hook".to_string()),
+            event_types: ActiveValue::Set(None),
+            custom_headers: ActiveValue::Set(None),
+            payload_template: ActiveValue::Set(None),
             created_at: ActiveValue::Set(chrono::Utc::now().fixed_offset()),
         };
         assert_eq!(active.id.as_ref(), &id);
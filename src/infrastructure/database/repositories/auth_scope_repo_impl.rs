@@ -70,6 +70,19 @@ impl AuthScopeRepository for AuthScopeRepositoryImpl {
         }
     }
 
+    async fn find_team_id_by_api_key_id(
+        &self,
+        api_key_id: Uuid,
+    ) -> Result<Option<Uuid>, RepositoryError> {
+        let session = self.pool.get_session("admin").await?;
+
+        let conn = session.connection()?;
+
+        let api_key = ApiKeyEntity::find_by_id(api_key_id).one(conn).await?;
+
+        Ok(api_key.map(|key| key.team_id))
+    }
+
     async fn upsert(
         &self,
         api_key_id: Uuid,
@@ -190,6 +203,21 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_find_team_id_by_api_key_id_returns_none_for_unknown() {
+        let repo = AuthScopeRepositoryImpl::new(create_test_db_pool());
+        let result = repo.find_team_id_by_api_key_id(Uuid::new_v4()).await;
+        assert!(
+            result.is_ok(),
+            "find_team_id_by_api_key_id failed: {:?}",
+            result.err()
+        );
+        assert!(
+            result.unwrap().is_none(),
+            "unknown api_key_id should have no team"
+        );
+    }
+
     #[tokio::test]
     async fn test_find_by_api_key_returns_none_for_unknown() {
         let repo = AuthScopeRepositoryImpl::new(create_test_db_pool());
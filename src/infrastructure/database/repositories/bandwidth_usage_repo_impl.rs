@@ -0,0 +1,111 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! Bandwidth usage repository implementation using Sea-ORM
+
+use crate::domain::models::DailyBandwidthUsage;
+use crate::domain::repositories::bandwidth_usage_repository::BandwidthUsageRepository;
+use crate::domain::repositories::task_repository::RepositoryError;
+use crate::infrastructure::database::entities::team_bandwidth_usage;
+use async_trait::async_trait;
+use chrono::{NaiveDate, Utc};
+use dbnexus::DbPool;
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, DatabaseBackend, EntityTrait, QueryFilter, QueryOrder, Statement,
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Bandwidth usage repository implementation using Sea-ORM
+#[derive(Clone)]
+pub struct BandwidthUsageRepositoryImpl {
+    /// Database pool
+    pool: Arc<DbPool>,
+}
+
+impl BandwidthUsageRepositoryImpl {
+    /// Create new bandwidth usage repository instance
+    pub fn new(pool: Arc<DbPool>) -> Self {
+        Self { pool }
+    }
+}
+
+fn to_domain(entity: team_bandwidth_usage::Model) -> DailyBandwidthUsage {
+    DailyBandwidthUsage {
+        date: entity.day,
+        bytes_downloaded: entity.bytes_downloaded.max(0) as u64,
+        requests: entity.requests.max(0) as u64,
+    }
+}
+
+#[async_trait]
+impl BandwidthUsageRepository for BandwidthUsageRepositoryImpl {
+    async fn upsert_daily(
+        &self,
+        team_id: Uuid,
+        usage: &DailyBandwidthUsage,
+    ) -> Result<(), RepositoryError> {
+        let session = self
+            .pool
+            .get_session("admin")
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        let conn = session
+            .connection()
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        // Counters only grow, so a restarted counter must not lower a stored day
+        let stmt = Statement::from_sql_and_values(
+            DatabaseBackend::Postgres,
+            r#"INSERT INTO team_bandwidth_usage
+                   (team_id, day, bytes_downloaded, requests, updated_at)
+               VALUES ($1, $2, $3, $4, $5)
+               ON CONFLICT (team_id, day) DO UPDATE
+               SET bytes_downloaded = GREATEST(team_bandwidth_usage.bytes_downloaded, EXCLUDED.bytes_downloaded),
+                   requests = GREATEST(team_bandwidth_usage.requests, EXCLUDED.requests),
+                   updated_at = EXCLUDED.updated_at"#,
+            [
+                team_id.into(),
+                usage.date.into(),
+                (usage.bytes_downloaded.min(i64::MAX as u64) as i64).into(),
+                (usage.requests.min(i64::MAX as u64) as i64).into(),
+                Utc::now().into(),
+            ],
+        );
+        conn.execute_raw(stmt)
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+        Ok(())
+    }
+
+    async fn list_daily(
+        &self,
+        team_id: Uuid,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Result<Vec<DailyBandwidthUsage>, RepositoryError> {
+        let session = self
+            .pool
+            .get_session("admin")
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        let entities = team_bandwidth_usage::Entity::find()
+            .filter(team_bandwidth_usage::Column::TeamId.eq(team_id))
+            .filter(team_bandwidth_usage::Column::Day.gte(from))
+            .filter(team_bandwidth_usage::Column::Day.lte(to))
+            .order_by_asc(team_bandwidth_usage::Column::Day)
+            .all(
+                session
+                    .connection()
+                    .map_err(|e| RepositoryError::Database(e.into()))?,
+            )
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        Ok(entities.into_iter().map(to_domain).collect())
+    }
+}
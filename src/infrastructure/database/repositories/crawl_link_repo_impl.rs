@@ -0,0 +1,130 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! Crawl link repository implementation using Sea-ORM with Mapper
+
+use crate::domain::models::CrawlLink;
+use crate::domain::repositories::crawl_link_repository::CrawlLinkRepository;
+use crate::domain::repositories::task_repository::RepositoryError;
+use crate::infrastructure::database::entities::crawl_link;
+use crate::infrastructure::persistence::mappers::CrawlLinkMapper;
+use async_trait::async_trait;
+use dbnexus::DbPool;
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, DatabaseBackend, EntityTrait, QueryFilter, QueryOrder,
+    QuerySelect, Statement, Value,
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Rows per INSERT statement (6 parameters each, well below the Postgres limit)
+const INSERT_BATCH_SIZE: usize = 500;
+
+/// Crawl link repository implementation using Sea-ORM
+#[derive(Clone)]
+pub struct CrawlLinkRepositoryImpl {
+    /// Database pool
+    pool: Arc<DbPool>,
+}
+
+impl CrawlLinkRepositoryImpl {
+    /// Create new crawl link repository instance
+    pub fn new(pool: Arc<DbPool>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl CrawlLinkRepository for CrawlLinkRepositoryImpl {
+    async fn record(&self, links: &[CrawlLink]) -> Result<u64, RepositoryError> {
+        if links.is_empty() {
+            return Ok(0);
+        }
+
+        let session = self
+            .pool
+            .get_session("admin")
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+        let conn = session
+            .connection()
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        // Links already recorded for the crawl keep their first anchor text and rel
+        let mut inserted = 0;
+        for batch in links.chunks(INSERT_BATCH_SIZE) {
+            let mut placeholders = Vec::with_capacity(batch.len());
+            let mut values: Vec<Value> = Vec::with_capacity(batch.len() * 6);
+            for (i, link) in batch.iter().enumerate() {
+                let base = i * 6;
+                placeholders.push(format!(
+                    "(${}, ${}, ${}, ${}, ${}, ${})",
+                    base + 1,
+                    base + 2,
+                    base + 3,
+                    base + 4,
+                    base + 5,
+                    base + 6
+                ));
+                let entity = CrawlLinkMapper::to_entity(link);
+                values.push(entity.crawl_id.into());
+                values.push(entity.source_url.into());
+                values.push(entity.target_url.into());
+                values.push(entity.anchor_text.into());
+                values.push(entity.rel.into());
+                values.push(entity.created_at.into());
+            }
+            let stmt = Statement::from_sql_and_values(
+                DatabaseBackend::Postgres,
+                format!(
+                    r#"INSERT INTO crawl_links
+                           (crawl_id, source_url, target_url, anchor_text, rel, created_at)
+                       VALUES {}
+                       ON CONFLICT (crawl_id, source_url, target_url) DO NOTHING"#,
+                    placeholders.join(", ")
+                ),
+                values,
+            );
+            let result = conn
+                .execute_raw(stmt)
+                .await
+                .map_err(|e| RepositoryError::Database(e.into()))?;
+            inserted += result.rows_affected();
+        }
+
+        Ok(inserted)
+    }
+
+    async fn find_by_crawl(
+        &self,
+        crawl_id: Uuid,
+        limit: u64,
+    ) -> Result<Vec<CrawlLink>, RepositoryError> {
+        let session = self
+            .pool
+            .get_session("admin")
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        let entities = crawl_link::Entity::find()
+            .filter(crawl_link::Column::CrawlId.eq(crawl_id))
+            .order_by_asc(crawl_link::Column::CreatedAt)
+            .order_by_asc(crawl_link::Column::SourceUrl)
+            .order_by_asc(crawl_link::Column::TargetUrl)
+            .limit(limit)
+            .all(
+                session
+                    .connection()
+                    .map_err(|e| RepositoryError::Database(e.into()))?,
+            )
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        Ok(entities
+            .into_iter()
+            .map(CrawlLinkMapper::to_domain)
+            .collect())
+    }
+}
@@ -13,8 +13,8 @@ use crate::infrastructure::persistence::mappers::CrawlMapper;
 use async_trait::async_trait;
 use dbnexus::DbPool;
 use sea_orm::{
-    ActiveModelTrait, ColumnTrait, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder,
-    QuerySelect,
+    sea_query::Expr, ActiveModelTrait, ColumnTrait, EntityTrait, PaginatorTrait, QueryFilter,
+    QueryOrder, QuerySelect,
 };
 use std::sync::Arc;
 use uuid::Uuid;
@@ -192,6 +192,37 @@ impl CrawlRepository for CrawlRepositoryImpl {
         Ok(())
     }
 
+    async fn mark_finished(&self, id: Uuid, status: CrawlStatus) -> Result<bool, RepositoryError> {
+        let session = self
+            .pool
+            .get_session("admin")
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        let conn = session
+            .connection()
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        // Single conditional UPDATE so concurrent finishers cannot both win
+        let finished = [
+            CrawlStatus::Completed,
+            CrawlStatus::CompletedWithLimit,
+            CrawlStatus::Failed,
+            CrawlStatus::Cancelled,
+        ]
+        .map(|status| status.to_string());
+        let result = crawl::Entity::update_many()
+            .col_expr(crawl::Column::Status, Expr::value(status.to_string()))
+            .col_expr(crawl::Column::UpdatedAt, Expr::value(chrono::Utc::now()))
+            .filter(crawl::Column::Id.eq(id))
+            .filter(crawl::Column::Status.is_not_in(finished))
+            .exec(conn)
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        Ok(result.rows_affected > 0)
+    }
+
     async fn increment_total_tasks(&self, id: Uuid) -> Result<(), RepositoryError> {
         let session = self
             .pool
@@ -427,6 +458,43 @@ mod tests {
         assert_eq!(found.status, CrawlStatus::Completed);
     }
 
+    #[tokio::test]
+    async fn test_mark_finished_with_real_db_wins_once_under_race() {
+        let repo = Arc::new(CrawlRepositoryImpl::new(create_test_db_pool()));
+        let crawl = make_test_crawl();
+        repo.create(&crawl).await.expect("create failed");
+
+        // Two workers finishing the last tasks at the same time
+        let (first, second) = tokio::join!(
+            {
+                let repo = repo.clone();
+                tokio::spawn(
+                    async move { repo.mark_finished(crawl.id, CrawlStatus::Completed).await },
+                )
+            },
+            {
+                let repo = repo.clone();
+                tokio::spawn(
+                    async move { repo.mark_finished(crawl.id, CrawlStatus::Completed).await },
+                )
+            }
+        );
+        let wins = [first.unwrap().unwrap(), second.unwrap().unwrap()];
+        assert_eq!(wins.iter().filter(|won| **won).count(), 1);
+
+        // A finished crawl is never moved to another terminal status
+        assert!(!repo
+            .mark_finished(crawl.id, CrawlStatus::Failed)
+            .await
+            .expect("mark_finished failed"));
+        let found = repo
+            .find_by_id(crawl.id)
+            .await
+            .expect("find_by_id failed")
+            .expect("crawl should exist");
+        assert_eq!(found.status, CrawlStatus::Completed);
+    }
+
     #[tokio::test]
     async fn test_increment_total_tasks_with_real_db_succeeds() {
         let repo = CrawlRepositoryImpl::new(create_test_db_pool());
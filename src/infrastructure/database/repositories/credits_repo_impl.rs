@@ -8,29 +8,130 @@
 use async_trait::async_trait;
 use chrono::Utc;
 use dbnexus::DbPool;
+use log::warn;
 use sea_orm::{
-    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseBackend, EntityTrait, QueryFilter,
-    QueryOrder, QuerySelect, Set, Statement,
+    sea_query::Expr, ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseBackend, EntityTrait,
+    PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, Select, Set, Statement, Value,
 };
 use std::sync::Arc;
 use uuid::Uuid;
 
 use crate::common::time_utils;
-use crate::domain::models::{CreditsTransaction, CreditsTransactionType};
-use crate::domain::repositories::credits_repository::{CreditsRepository, CreditsRepositoryError};
-use crate::infrastructure::database::entities::{credits, credits_transactions};
-use crate::infrastructure::persistence::mappers::CreditsTransactionMapper;
+use crate::domain::models::{
+    CreditsTransaction, CreditsTransactionType, DailyCreditsUsage, LowBalanceAlert,
+    LowBalanceTransition,
+};
+use crate::domain::repositories::credits_repository::{
+    CreditsRepository, CreditsRepositoryError, CreditsTransactionFilter,
+};
+use crate::domain::services::low_balance_alert_service::LowBalanceNotifier;
+use crate::infrastructure::database::entities::{credit_alert, credits, credits_transactions};
+use crate::infrastructure::persistence::mappers::{
+    CreditsTransactionMapper, LowBalanceAlertMapper,
+};
 
 pub struct CreditsRepositoryImpl {
     pool: Arc<DbPool>,
+    /// 余额不足提醒通知器，未设置时不检查提醒阈值
+    low_balance_notifier: Option<Arc<dyn LowBalanceNotifier>>,
+    /// 提醒触发后，余额需回升到阈值之上该百分比才重新生效
+    rearm_margin_percent: u32,
 }
 
 impl CreditsRepositoryImpl {
     pub fn new(pool: Arc<DbPool>) -> Self {
-        Self { pool }
+        Self {
+            pool,
+            low_balance_notifier: None,
+            rearm_margin_percent: 0,
+        }
+    }
+
+    /// 启用余额不足提醒：扣除后余额跌破团队阈值时调用 `notifier`
+    pub fn with_low_balance_alerts(
+        mut self,
+        notifier: Arc<dyn LowBalanceNotifier>,
+        rearm_margin_percent: u32,
+    ) -> Self {
+        self.low_balance_notifier = Some(notifier);
+        self.rearm_margin_percent = rearm_margin_percent;
+        self
+    }
+
+    /// 余额变化后更新团队的提醒状态，跌破阈值时在后台发送提醒
+    ///
+    /// 状态以 `alerted` 为条件更新（比较并交换），多个实例并发扣费时只有一个会发送提醒。
+    async fn refresh_low_balance_alert(&self, team_id: Uuid) -> Result<(), CreditsRepositoryError> {
+        let Some(notifier) = &self.low_balance_notifier else {
+            return Ok(());
+        };
+        let Some(alert) = self.get_low_balance_alert(team_id).await? else {
+            return Ok(());
+        };
+        let balance = self.get_balance(team_id).await?;
+        let Some(transition) = alert.transition(balance, self.rearm_margin_percent) else {
+            return Ok(());
+        };
+        let triggered = transition == LowBalanceTransition::Triggered;
+
+        let session = self
+            .pool
+            .get_session("admin")
+            .await
+            .map_err(|e| CreditsRepositoryError::DatabaseError(e.to_string()))?;
+
+        let conn = session
+            .connection()
+            .map_err(|e| CreditsRepositoryError::DatabaseError(e.to_string()))?;
+
+        let now = Utc::now().with_timezone(&time_utils::UTC_OFFSET);
+        let mut update = credit_alert::Entity::update_many()
+            .col_expr(credit_alert::Column::Alerted, Expr::value(triggered))
+            .col_expr(credit_alert::Column::UpdatedAt, Expr::value(now))
+            .filter(credit_alert::Column::TeamId.eq(team_id))
+            .filter(credit_alert::Column::Alerted.eq(!triggered));
+        if triggered {
+            update = update.col_expr(credit_alert::Column::AlertedAt, Expr::value(now));
+        }
+        let result = update
+            .exec(conn)
+            .await
+            .map_err(|e| CreditsRepositoryError::DatabaseError(e.to_string()))?;
+
+        if triggered && result.rows_affected == 1 {
+            let notifier = notifier.clone();
+            tokio::spawn(async move {
+                if let Err(e) = notifier.notify(&alert, balance).await {
+                    warn!(
+                        "Failed to send low-balance alert for team {}: {}",
+                        team_id, e
+                    );
+                }
+            });
+        }
+        Ok(())
     }
 }
 
+/// 团队交易记录查询，附加时间范围与类型过滤
+fn filtered_transactions(
+    team_id: Uuid,
+    filter: &CreditsTransactionFilter,
+) -> Select<credits_transactions::Entity> {
+    let mut query = credits_transactions::Entity::find()
+        .filter(credits_transactions::Column::TeamId.eq(team_id));
+    if let Some(from) = filter.from {
+        query = query.filter(credits_transactions::Column::CreatedAt.gte(from));
+    }
+    if let Some(to) = filter.to {
+        query = query.filter(credits_transactions::Column::CreatedAt.lt(to));
+    }
+    if let Some(kind) = filter.transaction_type {
+        query = query.filter(credits_transactions::Column::TransactionType.eq(kind.to_string()));
+    }
+    query
+}
+
 #[async_trait]
 impl CreditsRepository for CreditsRepositoryImpl {
     async fn get_balance(&self, team_id: Uuid) -> Result<i64, CreditsRepositoryError> {
@@ -97,6 +198,14 @@ impl CreditsRepository for CreditsRepositoryImpl {
             .await
             .map_err(|e| CreditsRepositoryError::DatabaseError(e.to_string()))?;
 
+        // 提醒失败不影响扣费结果
+        if let Err(e) = self.refresh_low_balance_alert(team_id).await {
+            warn!(
+                "Failed to check low-balance alert for team {}: {}",
+                team_id, e
+            );
+        }
+
         Ok(())
     }
 
@@ -151,6 +260,13 @@ impl CreditsRepository for CreditsRepositoryImpl {
                 let new_balance: i64 = row
                     .try_get_by_index(0)
                     .map_err(|e| CreditsRepositoryError::DatabaseError(e.to_string()))?;
+                // 充值后余额回升到重新生效点时恢复提醒
+                if let Err(e) = self.refresh_low_balance_alert(team_id).await {
+                    warn!(
+                        "Failed to check low-balance alert for team {}: {}",
+                        team_id, e
+                    );
+                }
                 Ok(new_balance)
             }
             None => Err(CreditsRepositoryError::DatabaseError(
@@ -190,6 +306,141 @@ impl CreditsRepository for CreditsRepositoryImpl {
         Ok(CreditsTransactionMapper::to_domain_list(transactions))
     }
 
+    async fn sum_deducted_for_references(
+        &self,
+        team_id: Uuid,
+        reference_ids: &[Uuid],
+    ) -> Result<i64, CreditsRepositoryError> {
+        if reference_ids.is_empty() {
+            return Ok(0);
+        }
+
+        let session = self
+            .pool
+            .get_session("admin")
+            .await
+            .map_err(|e| CreditsRepositoryError::DatabaseError(e.to_string()))?;
+
+        let conn = session
+            .connection()
+            .map_err(|e| CreditsRepositoryError::DatabaseError(e.to_string()))?;
+
+        // 扣费记录以负数存储（见 deduct_credits_safe），只取引用命中的扣费行
+        let amounts: Vec<i64> = credits_transactions::Entity::find()
+            .select_only()
+            .column(credits_transactions::Column::Amount)
+            .filter(credits_transactions::Column::TeamId.eq(team_id))
+            .filter(credits_transactions::Column::ReferenceId.is_in(reference_ids.iter().copied()))
+            .filter(credits_transactions::Column::Amount.lt(0))
+            .into_tuple()
+            .all(conn)
+            .await
+            .map_err(|e| CreditsRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(amounts.into_iter().map(|amount| -amount).sum())
+    }
+
+    async fn query_transactions(
+        &self,
+        team_id: Uuid,
+        filter: &CreditsTransactionFilter,
+        limit: u64,
+        offset: u64,
+    ) -> Result<(Vec<CreditsTransaction>, u64), CreditsRepositoryError> {
+        let session = self
+            .pool
+            .get_session("admin")
+            .await
+            .map_err(|e| CreditsRepositoryError::DatabaseError(e.to_string()))?;
+
+        let conn = session
+            .connection()
+            .map_err(|e| CreditsRepositoryError::DatabaseError(e.to_string()))?;
+
+        let total = filtered_transactions(team_id, filter)
+            .count(conn)
+            .await
+            .map_err(|e| CreditsRepositoryError::DatabaseError(e.to_string()))?;
+
+        let transactions = filtered_transactions(team_id, filter)
+            .order_by_desc(credits_transactions::Column::CreatedAt)
+            .limit(limit)
+            .offset(offset)
+            .all(conn)
+            .await
+            .map_err(|e| CreditsRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok((
+            CreditsTransactionMapper::to_domain_list(transactions),
+            total,
+        ))
+    }
+
+    async fn daily_usage(
+        &self,
+        team_id: Uuid,
+        filter: &CreditsTransactionFilter,
+    ) -> Result<Vec<DailyCreditsUsage>, CreditsRepositoryError> {
+        let session = self
+            .pool
+            .get_session("admin")
+            .await
+            .map_err(|e| CreditsRepositoryError::DatabaseError(e.to_string()))?;
+
+        let conn = session
+            .connection()
+            .map_err(|e| CreditsRepositoryError::DatabaseError(e.to_string()))?;
+
+        // 按 UTC 日期聚合；过滤条件全部参数化
+        let mut conditions = vec!["team_id = $1".to_string()];
+        let mut values: Vec<Value> = vec![team_id.into()];
+        if let Some(from) = filter.from {
+            values.push(from.into());
+            conditions.push(format!("created_at >= ${}", values.len()));
+        }
+        if let Some(to) = filter.to {
+            values.push(to.into());
+            conditions.push(format!("created_at < ${}", values.len()));
+        }
+        if let Some(kind) = filter.transaction_type {
+            values.push(kind.to_string().into());
+            conditions.push(format!("transaction_type = ${}", values.len()));
+        }
+        let stmt = Statement::from_sql_and_values(
+            DatabaseBackend::Postgres,
+            format!(
+                r#"SELECT (created_at AT TIME ZONE 'UTC')::date AS day,
+                          COALESCE(SUM(CASE WHEN amount < 0 THEN -amount ELSE 0 END), 0)::BIGINT,
+                          COALESCE(SUM(CASE WHEN amount > 0 THEN amount ELSE 0 END), 0)::BIGINT,
+                          COUNT(*)::BIGINT
+                   FROM credits_transactions
+                   WHERE {}
+                   GROUP BY day
+                   ORDER BY day"#,
+                conditions.join(" AND ")
+            ),
+            values,
+        );
+
+        let rows = conn
+            .query_all_raw(stmt)
+            .await
+            .map_err(|e| CreditsRepositoryError::DatabaseError(e.to_string()))?;
+
+        rows.iter()
+            .map(|row| {
+                let transactions: i64 = row.try_get_by_index(3)?;
+                Ok(DailyCreditsUsage {
+                    date: row.try_get_by_index(0)?,
+                    credits_used: row.try_get_by_index(1)?,
+                    credits_added: row.try_get_by_index(2)?,
+                    transactions: transactions.max(0) as u64,
+                })
+            })
+            .collect::<Result<Vec<_>, sea_orm::DbErr>>()
+            .map_err(|e| CreditsRepositoryError::DatabaseError(e.to_string()))
+    }
+
     async fn initialize_team_credits(
         &self,
         team_id: Uuid,
@@ -232,6 +483,93 @@ impl CreditsRepository for CreditsRepositoryImpl {
 
         Ok(initial_balance)
     }
+
+    async fn get_low_balance_alert(
+        &self,
+        team_id: Uuid,
+    ) -> Result<Option<LowBalanceAlert>, CreditsRepositoryError> {
+        let session = self
+            .pool
+            .get_session("admin")
+            .await
+            .map_err(|e| CreditsRepositoryError::DatabaseError(e.to_string()))?;
+
+        let conn = session
+            .connection()
+            .map_err(|e| CreditsRepositoryError::DatabaseError(e.to_string()))?;
+
+        let alert = credit_alert::Entity::find_by_id(team_id)
+            .one(conn)
+            .await
+            .map_err(|e| CreditsRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(alert.map(LowBalanceAlertMapper::to_domain))
+    }
+
+    async fn set_low_balance_alert(
+        &self,
+        team_id: Uuid,
+        threshold: i64,
+        email: Option<String>,
+    ) -> Result<LowBalanceAlert, CreditsRepositoryError> {
+        let session = self
+            .pool
+            .get_session("admin")
+            .await
+            .map_err(|e| CreditsRepositoryError::DatabaseError(e.to_string()))?;
+
+        let conn = session
+            .connection()
+            .map_err(|e| CreditsRepositoryError::DatabaseError(e.to_string()))?;
+
+        // 修改阈值后重新生效，下次跌破新阈值时再提醒
+        let stmt = Statement::from_sql_and_values(
+            DatabaseBackend::Postgres,
+            r#"INSERT INTO credit_alerts (team_id, threshold, email, alerted, alerted_at)
+               VALUES ($1, $2, $3, FALSE, NULL)
+               ON CONFLICT (team_id) DO UPDATE
+               SET threshold = EXCLUDED.threshold,
+                   email = EXCLUDED.email,
+                   alerted = FALSE,
+                   alerted_at = NULL,
+                   updated_at = NOW()"#,
+            [team_id.into(), threshold.into(), email.clone().into()],
+        );
+
+        conn.execute_raw(stmt)
+            .await
+            .map_err(|e| CreditsRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(LowBalanceAlert {
+            team_id,
+            threshold,
+            email,
+            alerted: false,
+            alerted_at: None,
+        })
+    }
+
+    async fn delete_low_balance_alert(
+        &self,
+        team_id: Uuid,
+    ) -> Result<bool, CreditsRepositoryError> {
+        let session = self
+            .pool
+            .get_session("admin")
+            .await
+            .map_err(|e| CreditsRepositoryError::DatabaseError(e.to_string()))?;
+
+        let conn = session
+            .connection()
+            .map_err(|e| CreditsRepositoryError::DatabaseError(e.to_string()))?;
+
+        let result = credit_alert::Entity::delete_by_id(team_id)
+            .exec(conn)
+            .await
+            .map_err(|e| CreditsRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(result.rows_affected > 0)
+    }
 }
 
 #[cfg(test)]
@@ -346,6 +684,29 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_query_transactions_and_daily_usage_empty_for_unknown_team() {
+        let repo = CreditsRepositoryImpl::new(create_test_db_pool());
+        let filter = CreditsTransactionFilter {
+            from: Some(Utc::now() - chrono::Duration::days(7)),
+            to: Some(Utc::now()),
+            transaction_type: Some(CreditsTransactionType::Scrape),
+        };
+
+        let (transactions, total) = repo
+            .query_transactions(Uuid::new_v4(), &filter, 20, 0)
+            .await
+            .expect("query_transactions failed");
+        assert!(transactions.is_empty());
+        assert_eq!(total, 0);
+
+        let days = repo
+            .daily_usage(Uuid::new_v4(), &filter)
+            .await
+            .expect("daily_usage failed");
+        assert!(days.is_empty());
+    }
+
     #[tokio::test]
     async fn test_initialize_team_credits_succeeds() {
         let repo = CreditsRepositoryImpl::new(create_test_db_pool());
@@ -409,6 +770,16 @@ mod tests {
     // reference_id / zero amount / quoted description / type variants)
     // ============================================================
 
+    #[tokio::test]
+    async fn test_sum_deducted_for_references_empty_is_zero() {
+        let repo = CreditsRepositoryImpl::new(create_test_db_pool());
+        let total = repo
+            .sum_deducted_for_references(Uuid::new_v4(), &[])
+            .await
+            .expect("empty references should not query");
+        assert_eq!(total, 0);
+    }
+
     #[tokio::test]
     async fn test_get_transaction_history_with_no_limit() {
         let repo = CreditsRepositoryImpl::new(create_test_db_pool());
@@ -468,6 +839,88 @@ mod tests {
         );
     }
 
+    /// 记录提醒次数的通知器
+    #[derive(Default)]
+    struct CountingNotifier {
+        count: std::sync::atomic::AtomicUsize,
+    }
+
+    #[async_trait]
+    impl LowBalanceNotifier for CountingNotifier {
+        async fn notify(&self, _alert: &LowBalanceAlert, _balance: i64) -> anyhow::Result<()> {
+            self.count.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_low_balance_alert_fires_once_until_rearmed() {
+        let notifier = Arc::new(CountingNotifier::default());
+        let repo = CreditsRepositoryImpl::new(create_test_db_pool())
+            .with_low_balance_alerts(notifier.clone(), 10);
+        let team_id = Uuid::new_v4();
+        repo.initialize_team_credits(team_id, 120)
+            .await
+            .expect("initialize failed");
+        repo.set_low_balance_alert(team_id, 100, None)
+            .await
+            .expect("set alert failed");
+
+        for _ in 0..3 {
+            repo.deduct_credits(
+                team_id,
+                10,
+                CreditsTransactionType::Scrape,
+                "alert test".to_string(),
+                None,
+            )
+            .await
+            .expect("deduct failed");
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+        assert_eq!(notifier.count.load(std::sync::atomic::Ordering::SeqCst), 1);
+        let alert = repo.get_low_balance_alert(team_id).await.unwrap().unwrap();
+        assert!(alert.alerted);
+
+        // 回升到 100 不足以重新生效，回升到 110 后才会再次提醒
+        repo.add_credits(
+            team_id,
+            10,
+            CreditsTransactionType::Refund,
+            "r".to_string(),
+            None,
+        )
+        .await
+        .expect("add failed");
+        assert!(
+            repo.get_low_balance_alert(team_id)
+                .await
+                .unwrap()
+                .unwrap()
+                .alerted
+        );
+        repo.add_credits(
+            team_id,
+            10,
+            CreditsTransactionType::Refund,
+            "r".to_string(),
+            None,
+        )
+        .await
+        .expect("add failed");
+        assert!(
+            !repo
+                .get_low_balance_alert(team_id)
+                .await
+                .unwrap()
+                .unwrap()
+                .alerted
+        );
+
+        assert!(repo.delete_low_balance_alert(team_id).await.unwrap());
+        assert!(repo.get_low_balance_alert(team_id).await.unwrap().is_none());
+    }
+
     #[tokio::test]
     async fn test_deduct_credits_with_reference_id() {
         let repo = CreditsRepositoryImpl::new(create_test_db_pool());
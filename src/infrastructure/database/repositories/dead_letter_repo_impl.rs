@@ -0,0 +1,315 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! Dead-letter repository implementation over failed tasks
+//!
+//! Dead letters are rows of `tasks` with `status = 'failed'`; the reason is the
+//! message of the task's latest `failed` event.
+
+use crate::domain::models::TaskType;
+use crate::domain::repositories::dead_letter_repository::{
+    DeadLetterFilter, DeadLetterRepository, DeadLetterTask,
+};
+use crate::domain::repositories::task_repository::RepositoryError;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use dbnexus::DbPool;
+use sea_orm::{ConnectionTrait, DatabaseBackend, QueryResult, Statement, Value};
+use std::str::FromStr;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Latest failure event of each task, joined as `e`
+const LATEST_FAILURE_JOIN: &str = r#"LEFT JOIN LATERAL (
+                   SELECT message, created_at FROM task_events
+                   WHERE task_id = t.id AND event_type = 'failed'
+                   ORDER BY created_at DESC
+                   LIMIT 1
+               ) e ON TRUE"#;
+
+/// Dead-letter repository implementation using raw SQL
+#[derive(Clone)]
+pub struct DeadLetterRepositoryImpl {
+    /// Database pool
+    pool: Arc<DbPool>,
+}
+
+impl DeadLetterRepositoryImpl {
+    /// Create new dead-letter repository instance
+    pub fn new(pool: Arc<DbPool>) -> Self {
+        Self { pool }
+    }
+
+    fn to_domain(row: &QueryResult) -> Result<DeadLetterTask, sea_orm::DbErr> {
+        let task_type: String = row.try_get("", "task_type")?;
+        Ok(DeadLetterTask {
+            task_id: row.try_get("", "id")?,
+            team_id: row.try_get("", "team_id")?,
+            task_type: TaskType::from_str(&task_type)
+                .map_err(|_| sea_orm::DbErr::Custom(format!("Unknown task type: {}", task_type)))?,
+            url: row.try_get("", "url")?,
+            crawl_id: row.try_get("", "crawl_id")?,
+            attempt_count: row.try_get("", "attempt_count")?,
+            reason: row.try_get("", "reason")?,
+            failed_at: row.try_get::<DateTime<Utc>>("", "failed_at")?,
+        })
+    }
+}
+
+/// Build the parameterized WHERE conditions for a filter, appending bound values
+fn filter_conditions(filter: &DeadLetterFilter, values: &mut Vec<Value>) -> Vec<String> {
+    let mut conditions = vec!["t.status = 'failed'".to_string()];
+    if let Some(team_id) = filter.team_id {
+        values.push(team_id.into());
+        conditions.push(format!("t.team_id = ${}", values.len()));
+    }
+    if let Some(reason) = &filter.reason {
+        values.push(reason.clone().into());
+        conditions.push(format!(
+            "strpos(lower(e.message), lower(${})) > 0",
+            values.len()
+        ));
+    }
+    if let Some(task_ids) = &filter.task_ids {
+        if task_ids.is_empty() {
+            conditions.push("FALSE".to_string());
+        } else {
+            let placeholders: Vec<String> = task_ids
+                .iter()
+                .map(|id| {
+                    values.push((*id).into());
+                    format!("${}", values.len())
+                })
+                .collect();
+            conditions.push(format!("t.id IN ({})", placeholders.join(", ")));
+        }
+    }
+    conditions
+}
+
+#[async_trait]
+impl DeadLetterRepository for DeadLetterRepositoryImpl {
+    async fn list(
+        &self,
+        filter: &DeadLetterFilter,
+        limit: u64,
+        offset: u64,
+    ) -> Result<(Vec<DeadLetterTask>, u64), RepositoryError> {
+        let session = self
+            .pool
+            .get_session("admin")
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        let conn = session
+            .connection()
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        let mut values = Vec::new();
+        let conditions = filter_conditions(filter, &mut values).join(" AND ");
+
+        let count_stmt = Statement::from_sql_and_values(
+            DatabaseBackend::Postgres,
+            format!(
+                r#"SELECT COUNT(*)::BIGINT AS total
+                   FROM tasks t
+                   {}
+                   WHERE {}"#,
+                LATEST_FAILURE_JOIN, conditions
+            ),
+            values.clone(),
+        );
+        let total: i64 = conn
+            .query_one_raw(count_stmt)
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?
+            .map(|row| row.try_get("", "total"))
+            .transpose()
+            .map_err(|e| RepositoryError::Database(e.into()))?
+            .unwrap_or(0);
+
+        values.push((limit as i64).into());
+        let limit_param = values.len();
+        values.push((offset as i64).into());
+        let offset_param = values.len();
+        // Tasks failed before events were recorded fall back to their completion time
+        let stmt = Statement::from_sql_and_values(
+            DatabaseBackend::Postgres,
+            format!(
+                r#"SELECT t.id, t.team_id, t.task_type, t.url, t.crawl_id, t.attempt_count,
+                          e.message AS reason,
+                          COALESCE(e.created_at, t.completed_at, t.updated_at) AS failed_at
+                   FROM tasks t
+                   {}
+                   WHERE {}
+                   ORDER BY failed_at DESC, t.id
+                   LIMIT ${} OFFSET ${}"#,
+                LATEST_FAILURE_JOIN, conditions, limit_param, offset_param
+            ),
+            values,
+        );
+        let rows = conn
+            .query_all_raw(stmt)
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        let tasks = rows
+            .iter()
+            .map(Self::to_domain)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+        Ok((tasks, total.max(0) as u64))
+    }
+
+    async fn requeue(
+        &self,
+        filter: &DeadLetterFilter,
+        limit: u64,
+    ) -> Result<Vec<Uuid>, RepositoryError> {
+        let session = self
+            .pool
+            .get_session("admin")
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        let conn = session
+            .connection()
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        let mut values = Vec::new();
+        let conditions = filter_conditions(filter, &mut values).join(" AND ");
+        values.push((limit as i64).into());
+        let limit_param = values.len();
+
+        // Reset the task to a fresh queued state and record a queued event in the
+        // same statement so the timeline shows where the retry came from.
+        let stmt = Statement::from_sql_and_values(
+            DatabaseBackend::Postgres,
+            format!(
+                r#"WITH requeued AS (
+                       UPDATE tasks
+                       SET status = 'queued',
+                           retry_count = 0,
+                           attempt_count = 0,
+                           scheduled_at = NULL,
+                           expires_at = NULL,
+                           started_at = NULL,
+                           completed_at = NULL,
+                           lock_token = NULL,
+                           lock_expires_at = NULL,
+                           updated_at = NOW()
+                       WHERE status = 'failed' AND id IN (
+                           SELECT t.id FROM tasks t
+                           {}
+                           WHERE {}
+                           ORDER BY t.updated_at ASC
+                           LIMIT ${}
+                           FOR UPDATE OF t SKIP LOCKED
+                       )
+                       RETURNING id
+                   )
+                   INSERT INTO task_events (task_id, event_type, message)
+                   SELECT id, 'queued', 'Requeued from dead-letter queue' FROM requeued
+                   RETURNING task_id"#,
+                LATEST_FAILURE_JOIN, conditions, limit_param
+            ),
+            values,
+        );
+        let rows = conn
+            .query_all_raw(stmt)
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        rows.iter()
+            .map(|row| row.try_get::<Uuid>("", "task_id"))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| RepositoryError::Database(e.into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::test_helpers::create_test_db_pool;
+    use crate::domain::models::{Task, TaskStatus};
+    use crate::domain::repositories::task_repository::TaskRepository;
+    use crate::infrastructure::database::repositories::task_repo_impl::TaskRepositoryImpl;
+
+    #[test]
+    fn test_filter_conditions_binds_every_filter() {
+        let mut values = Vec::new();
+        let filter = DeadLetterFilter {
+            team_id: Some(Uuid::new_v4()),
+            reason: Some("timeout".to_string()),
+            task_ids: Some(vec![Uuid::new_v4(), Uuid::new_v4()]),
+        };
+
+        let conditions = filter_conditions(&filter, &mut values);
+
+        assert_eq!(
+            conditions,
+            vec![
+                "t.status = 'failed'",
+                "t.team_id = $1",
+                "strpos(lower(e.message), lower($2)) > 0",
+                "t.id IN ($3, $4)",
+            ]
+        );
+        assert_eq!(values.len(), 4);
+    }
+
+    #[test]
+    fn test_filter_conditions_empty_task_ids_match_nothing() {
+        let mut values = Vec::new();
+        let filter = DeadLetterFilter {
+            task_ids: Some(vec![]),
+            ..Default::default()
+        };
+
+        let conditions = filter_conditions(&filter, &mut values);
+
+        assert_eq!(conditions, vec!["t.status = 'failed'", "FALSE"]);
+        assert!(values.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_list_and_requeue_with_real_db() {
+        let pool = create_test_db_pool();
+        let tasks = TaskRepositoryImpl::new(pool.clone(), chrono::Duration::minutes(5));
+        let repo = DeadLetterRepositoryImpl::new(pool);
+        let mut task = Task::new(
+            Uuid::new_v4(),
+            TaskType::Scrape,
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            "http://example.com/dead-letter".to_string(),
+            serde_json::json!({}),
+        );
+        task.attempt_count = 4;
+        task.status = TaskStatus::Failed;
+        tasks.create(&task).await.expect("create failed");
+
+        let filter = DeadLetterFilter {
+            team_id: Some(task.team_id),
+            ..Default::default()
+        };
+        let (listed, total) = repo.list(&filter, 10, 0).await.expect("list failed");
+        assert_eq!(total, 1);
+        assert_eq!(listed[0].task_id, task.id);
+        assert_eq!(listed[0].attempt_count, 4);
+        assert!(listed[0].reason.is_none());
+
+        let requeued = repo.requeue(&filter, 10).await.expect("requeue failed");
+        assert_eq!(requeued, vec![task.id]);
+        let stored = tasks
+            .find_by_id(task.id)
+            .await
+            .expect("find_by_id failed")
+            .expect("task should exist");
+        assert_eq!(stored.status, TaskStatus::Queued);
+        assert_eq!(stored.attempt_count, 0);
+        assert_eq!(repo.list(&filter, 10, 0).await.expect("list failed").1, 0);
+    }
+}
@@ -0,0 +1,97 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! Domain throttle repository implementation using Sea-ORM with Mapper
+
+use crate::domain::models::DomainThrottle;
+use crate::domain::repositories::domain_throttle_repository::DomainThrottleRepository;
+use crate::domain::repositories::task_repository::RepositoryError;
+use crate::infrastructure::database::entities::domain_throttle;
+use crate::infrastructure::persistence::mappers::DomainThrottleMapper;
+use async_trait::async_trait;
+use dbnexus::DbPool;
+use sea_orm::ActiveValue::{Set, Unchanged};
+use sea_orm::EntityTrait;
+use std::sync::Arc;
+
+/// Domain throttle repository implementation using Sea-ORM
+#[derive(Clone)]
+pub struct DomainThrottleRepositoryImpl {
+    /// Database pool
+    pool: Arc<DbPool>,
+}
+
+impl DomainThrottleRepositoryImpl {
+    /// Create new domain throttle repository instance
+    pub fn new(pool: Arc<DbPool>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl DomainThrottleRepository for DomainThrottleRepositoryImpl {
+    async fn find_by_domain(
+        &self,
+        domain: &str,
+    ) -> Result<Option<DomainThrottle>, RepositoryError> {
+        let session = self
+            .pool
+            .get_session("admin")
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        let entity = domain_throttle::Entity::find_by_id(domain.to_string())
+            .one(
+                session
+                    .connection()
+                    .map_err(|e| RepositoryError::Database(e.into()))?,
+            )
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        Ok(entity.map(DomainThrottleMapper::to_domain))
+    }
+
+    async fn upsert(&self, throttle: &DomainThrottle) -> Result<(), RepositoryError> {
+        let session = self
+            .pool
+            .get_session("admin")
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        let conn = session
+            .connection()
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        let existing = domain_throttle::Entity::find_by_id(throttle.domain.clone())
+            .one(conn)
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        let entity = DomainThrottleMapper::to_entity(throttle);
+        let mut active_model = domain_throttle::ActiveModel {
+            domain: Set(entity.domain),
+            consecutive_errors: Set(entity.consecutive_errors),
+            last_status: Set(entity.last_status),
+            throttled_until: Set(entity.throttled_until),
+            updated_at: Set(entity.updated_at),
+        };
+
+        if existing.is_some() {
+            active_model.domain = Unchanged(throttle.domain.clone());
+            domain_throttle::Entity::update(active_model)
+                .exec(conn)
+                .await
+                .map_err(|e| RepositoryError::Database(e.into()))?;
+        } else {
+            domain_throttle::Entity::insert(active_model)
+                .exec(conn)
+                .await
+                .map_err(|e| RepositoryError::Database(e.into()))?;
+        }
+
+        Ok(())
+    }
+}
@@ -9,13 +9,28 @@ pub mod audit_log_repo_impl;
 /// 提供领域仓库接口的具体实现
 /// 包括各种实体仓库的数据库实现
 pub mod auth_scope_repo_impl;
+pub mod bandwidth_usage_repo_impl;
+pub mod crawl_link_repo_impl;
 pub mod crawl_repo_impl;
 pub mod credits_repo_impl;
 pub mod database_geo_restriction_repo;
+pub mod dead_letter_repo_impl;
+pub mod domain_throttle_repo_impl;
 pub mod geo_restriction_repo_impl;
 pub mod macros;
+pub mod monitor_repo_impl;
+pub mod notification_channel_repo_impl;
+pub mod notification_contacts_repo_impl;
+pub mod resource_tag_repo_impl;
 pub mod scrape_result_repo_impl;
+pub mod sso_session_repo_impl;
+pub mod task_event_repo_impl;
 pub mod task_repo_impl;
 pub mod tasks_backlog_repo_impl;
+pub mod team_capability_repo_impl;
+pub mod team_member_repo_impl;
+pub mod team_plan_repo_impl;
+pub mod team_repo_impl;
+pub mod url_blocklist_repo_impl;
 pub mod webhook_event_repo_impl;
 pub mod webhook_repo_impl;
@@ -0,0 +1,214 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! Monitor repository implementation using Sea-ORM with Mapper
+
+use crate::domain::models::Monitor;
+use crate::domain::repositories::monitor_repository::MonitorRepository;
+use crate::domain::repositories::task_repository::RepositoryError;
+use crate::infrastructure::database::entities::monitor;
+use crate::infrastructure::persistence::mappers::MonitorMapper;
+use async_trait::async_trait;
+use chrono::Duration;
+use dbnexus::DbPool;
+use sea_orm::ActiveValue::{Set, Unchanged};
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, DatabaseBackend, EntityTrait, FromQueryResult, QueryFilter,
+    QueryOrder, Statement,
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Monitor repository implementation using Sea-ORM
+#[derive(Clone)]
+pub struct MonitorRepositoryImpl {
+    /// Database pool
+    pool: Arc<DbPool>,
+}
+
+impl MonitorRepositoryImpl {
+    /// Create new monitor repository instance
+    pub fn new(pool: Arc<DbPool>) -> Self {
+        Self { pool }
+    }
+
+    fn to_active_model(monitor: &Monitor) -> monitor::ActiveModel {
+        let entity = MonitorMapper::to_entity(monitor);
+        monitor::ActiveModel {
+            id: Set(entity.id),
+            team_id: Set(entity.team_id),
+            url: Set(entity.url),
+            interval_seconds: Set(entity.interval_seconds),
+            mode: Set(entity.mode),
+            extraction_rules: Set(entity.extraction_rules),
+            active: Set(entity.active),
+            last_hash: Set(entity.last_hash),
+            last_snapshot: Set(entity.last_snapshot),
+            last_error: Set(entity.last_error),
+            last_checked_at: Set(entity.last_checked_at),
+            last_changed_at: Set(entity.last_changed_at),
+            next_check_at: Set(entity.next_check_at),
+            created_at: Set(entity.created_at),
+            updated_at: Set(entity.updated_at),
+        }
+    }
+}
+
+#[async_trait]
+impl MonitorRepository for MonitorRepositoryImpl {
+    async fn create(&self, monitor: &Monitor) -> Result<Monitor, RepositoryError> {
+        let session = self
+            .pool
+            .get_session("admin")
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        monitor::Entity::insert(Self::to_active_model(monitor))
+            .exec(
+                session
+                    .connection()
+                    .map_err(|e| RepositoryError::Database(e.into()))?,
+            )
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        Ok(monitor.clone())
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Monitor>, RepositoryError> {
+        let session = self
+            .pool
+            .get_session("admin")
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        let entity = monitor::Entity::find_by_id(id)
+            .one(
+                session
+                    .connection()
+                    .map_err(|e| RepositoryError::Database(e.into()))?,
+            )
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        Ok(entity.and_then(MonitorMapper::to_domain))
+    }
+
+    async fn find_by_team(&self, team_id: Uuid) -> Result<Vec<Monitor>, RepositoryError> {
+        let session = self
+            .pool
+            .get_session("admin")
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        let entities = monitor::Entity::find()
+            .filter(monitor::Column::TeamId.eq(team_id))
+            .order_by_asc(monitor::Column::CreatedAt)
+            .all(
+                session
+                    .connection()
+                    .map_err(|e| RepositoryError::Database(e.into()))?,
+            )
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        Ok(entities
+            .into_iter()
+            .filter_map(MonitorMapper::to_domain)
+            .collect())
+    }
+
+    async fn update(&self, monitor: &Monitor) -> Result<Monitor, RepositoryError> {
+        let session = self
+            .pool
+            .get_session("admin")
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        let mut active_model = Self::to_active_model(monitor);
+        active_model.id = Unchanged(monitor.id);
+        monitor::Entity::update(active_model)
+            .exec(
+                session
+                    .connection()
+                    .map_err(|e| RepositoryError::Database(e.into()))?,
+            )
+            .await
+            .map_err(|e| match e {
+                sea_orm::DbErr::RecordNotUpdated => RepositoryError::NotFound,
+                e => RepositoryError::Database(e.into()),
+            })?;
+
+        Ok(monitor.clone())
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<bool, RepositoryError> {
+        let session = self
+            .pool
+            .get_session("admin")
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        let result = monitor::Entity::delete_by_id(id)
+            .exec(
+                session
+                    .connection()
+                    .map_err(|e| RepositoryError::Database(e.into()))?,
+            )
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        Ok(result.rows_affected > 0)
+    }
+
+    /// Raw SQL is required because sea-orm doesn't support `FOR UPDATE SKIP LOCKED`.
+    async fn claim_due(
+        &self,
+        limit: u64,
+        lease: Duration,
+    ) -> Result<Vec<Monitor>, RepositoryError> {
+        let session = self
+            .pool
+            .get_session("admin")
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        let conn = session
+            .connection()
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        // Pushing next_check_at forward is the claim: concurrent workers skip
+        // the locked rows, and a crashed check is retried once the lease ends.
+        let stmt = Statement::from_sql_and_values(
+            DatabaseBackend::Postgres,
+            r#"UPDATE monitors
+               SET next_check_at = NOW() + ($1 * INTERVAL '1 second')
+               WHERE id IN (
+                   SELECT id FROM monitors
+                   WHERE active AND next_check_at <= NOW()
+                   ORDER BY next_check_at ASC
+                   LIMIT $2
+                   FOR UPDATE SKIP LOCKED
+               )
+               RETURNING *"#,
+            [lease.num_seconds().into(), (limit as i64).into()],
+        );
+        let rows = conn
+            .query_all_raw(stmt)
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        rows.iter()
+            .map(|row| monitor::Model::from_query_result(row, ""))
+            .collect::<Result<Vec<_>, _>>()
+            .map(|entities| {
+                entities
+                    .into_iter()
+                    .filter_map(MonitorMapper::to_domain)
+                    .collect()
+            })
+            .map_err(|e| RepositoryError::Database(e.into()))
+    }
+}
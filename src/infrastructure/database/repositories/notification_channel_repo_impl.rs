@@ -0,0 +1,164 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! Notification channel repository implementation using Sea-ORM with Mapper
+
+use crate::domain::models::NotificationChannel;
+use crate::domain::repositories::notification_channel_repository::NotificationChannelRepository;
+use crate::domain::repositories::task_repository::RepositoryError;
+use crate::infrastructure::database::entities::notification_channel;
+use crate::infrastructure::persistence::mappers::NotificationChannelMapper;
+use async_trait::async_trait;
+use dbnexus::DbPool;
+use sea_orm::ActiveValue::{Set, Unchanged};
+use sea_orm::{ColumnTrait, EntityTrait, QueryFilter, QueryOrder};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Notification channel repository implementation using Sea-ORM
+#[derive(Clone)]
+pub struct NotificationChannelRepositoryImpl {
+    /// Database pool
+    pool: Arc<DbPool>,
+}
+
+impl NotificationChannelRepositoryImpl {
+    /// Create new notification channel repository instance
+    pub fn new(pool: Arc<DbPool>) -> Self {
+        Self { pool }
+    }
+
+    fn to_active_model(channel: &NotificationChannel) -> notification_channel::ActiveModel {
+        let entity = NotificationChannelMapper::to_entity(channel);
+        notification_channel::ActiveModel {
+            id: Set(entity.id),
+            team_id: Set(entity.team_id),
+            name: Set(entity.name),
+            kind: Set(entity.kind),
+            url: Set(entity.url),
+            event_types: Set(entity.event_types),
+            enabled: Set(entity.enabled),
+            created_at: Set(entity.created_at),
+            updated_at: Set(entity.updated_at),
+        }
+    }
+}
+
+#[async_trait]
+impl NotificationChannelRepository for NotificationChannelRepositoryImpl {
+    async fn create(
+        &self,
+        channel: &NotificationChannel,
+    ) -> Result<NotificationChannel, RepositoryError> {
+        let session = self
+            .pool
+            .get_session("admin")
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        notification_channel::Entity::insert(Self::to_active_model(channel))
+            .exec(
+                session
+                    .connection()
+                    .map_err(|e| RepositoryError::Database(e.into()))?,
+            )
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        Ok(channel.clone())
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<NotificationChannel>, RepositoryError> {
+        let session = self
+            .pool
+            .get_session("admin")
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        let entity = notification_channel::Entity::find_by_id(id)
+            .one(
+                session
+                    .connection()
+                    .map_err(|e| RepositoryError::Database(e.into()))?,
+            )
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        Ok(entity.and_then(NotificationChannelMapper::to_domain))
+    }
+
+    async fn find_by_team(
+        &self,
+        team_id: Uuid,
+    ) -> Result<Vec<NotificationChannel>, RepositoryError> {
+        let session = self
+            .pool
+            .get_session("admin")
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        let entities = notification_channel::Entity::find()
+            .filter(notification_channel::Column::TeamId.eq(team_id))
+            .order_by_asc(notification_channel::Column::CreatedAt)
+            .all(
+                session
+                    .connection()
+                    .map_err(|e| RepositoryError::Database(e.into()))?,
+            )
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        Ok(entities
+            .into_iter()
+            .filter_map(NotificationChannelMapper::to_domain)
+            .collect())
+    }
+
+    async fn update(
+        &self,
+        channel: &NotificationChannel,
+    ) -> Result<NotificationChannel, RepositoryError> {
+        let session = self
+            .pool
+            .get_session("admin")
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        let mut active_model = Self::to_active_model(channel);
+        active_model.id = Unchanged(channel.id);
+        notification_channel::Entity::update(active_model)
+            .exec(
+                session
+                    .connection()
+                    .map_err(|e| RepositoryError::Database(e.into()))?,
+            )
+            .await
+            .map_err(|e| match e {
+                sea_orm::DbErr::RecordNotUpdated => RepositoryError::NotFound,
+                e => RepositoryError::Database(e.into()),
+            })?;
+
+        Ok(channel.clone())
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<bool, RepositoryError> {
+        let session = self
+            .pool
+            .get_session("admin")
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        let result = notification_channel::Entity::delete_by_id(id)
+            .exec(
+                session
+                    .connection()
+                    .map_err(|e| RepositoryError::Database(e.into()))?,
+            )
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        Ok(result.rows_affected > 0)
+    }
+}
@@ -0,0 +1,121 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! Notification contacts repository implementation using Sea-ORM with Mapper
+
+use crate::domain::models::NotificationContacts;
+use crate::domain::repositories::notification_contacts_repository::NotificationContactsRepository;
+use crate::domain::repositories::task_repository::RepositoryError;
+use crate::infrastructure::database::entities::notification_contacts;
+use crate::infrastructure::persistence::mappers::NotificationContactsMapper;
+use async_trait::async_trait;
+use dbnexus::DbPool;
+use sea_orm::{ConnectionTrait, DatabaseBackend, EntityTrait, FromQueryResult, Statement};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Notification contacts repository implementation using Sea-ORM
+#[derive(Clone)]
+pub struct NotificationContactsRepositoryImpl {
+    /// Database pool
+    pool: Arc<DbPool>,
+}
+
+impl NotificationContactsRepositoryImpl {
+    /// Create new notification contacts repository instance
+    pub fn new(pool: Arc<DbPool>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl NotificationContactsRepository for NotificationContactsRepositoryImpl {
+    async fn find_by_team(
+        &self,
+        team_id: Uuid,
+    ) -> Result<Option<NotificationContacts>, RepositoryError> {
+        let session = self
+            .pool
+            .get_session("admin")
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        let entity = notification_contacts::Entity::find_by_id(team_id)
+            .one(
+                session
+                    .connection()
+                    .map_err(|e| RepositoryError::Database(e.into()))?,
+            )
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        Ok(entity.map(NotificationContactsMapper::to_domain))
+    }
+
+    async fn upsert(
+        &self,
+        contacts: &NotificationContacts,
+    ) -> Result<NotificationContacts, RepositoryError> {
+        let session = self
+            .pool
+            .get_session("admin")
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        let conn = session
+            .connection()
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        // Replacing the contacts keeps the original created_at
+        let entity = NotificationContactsMapper::to_entity(contacts);
+        let stmt = Statement::from_sql_and_values(
+            DatabaseBackend::Postgres,
+            r#"INSERT INTO notification_contacts
+                   (team_id, emails, event_types, enabled, created_at, updated_at)
+               VALUES ($1, $2, $3, $4, $5, $5)
+               ON CONFLICT (team_id) DO UPDATE
+               SET emails = EXCLUDED.emails,
+                   event_types = EXCLUDED.event_types,
+                   enabled = EXCLUDED.enabled,
+                   updated_at = EXCLUDED.updated_at
+               RETURNING *"#,
+            [
+                entity.team_id.into(),
+                entity.emails.into(),
+                entity.event_types.into(),
+                entity.enabled.into(),
+                entity.updated_at.into(),
+            ],
+        );
+        let row = conn
+            .query_one_raw(stmt)
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?
+            .ok_or(RepositoryError::NotFound)?;
+
+        notification_contacts::Model::from_query_result(&row, "")
+            .map(NotificationContactsMapper::to_domain)
+            .map_err(|e| RepositoryError::Database(e.into()))
+    }
+
+    async fn delete(&self, team_id: Uuid) -> Result<bool, RepositoryError> {
+        let session = self
+            .pool
+            .get_session("admin")
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        let result = notification_contacts::Entity::delete_by_id(team_id)
+            .exec(
+                session
+                    .connection()
+                    .map_err(|e| RepositoryError::Database(e.into()))?,
+            )
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        Ok(result.rows_affected > 0)
+    }
+}
@@ -0,0 +1,200 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! Resource tag repository implementation using Sea-ORM with Mapper
+
+use crate::domain::models::{ResourceTags, TaggedResource};
+use crate::domain::repositories::resource_tag_repository::ResourceTagRepository;
+use crate::domain::repositories::task_repository::RepositoryError;
+use crate::infrastructure::database::entities::resource_tag;
+use crate::infrastructure::persistence::mappers::ResourceTagMapper;
+use async_trait::async_trait;
+use dbnexus::DbPool;
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, DatabaseBackend, EntityTrait, FromQueryResult, QueryFilter,
+    Statement,
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Resource tag repository implementation using Sea-ORM
+#[derive(Clone)]
+pub struct ResourceTagRepositoryImpl {
+    /// Database pool
+    pool: Arc<DbPool>,
+}
+
+impl ResourceTagRepositoryImpl {
+    /// Create new resource tag repository instance
+    pub fn new(pool: Arc<DbPool>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ResourceTagRepository for ResourceTagRepositoryImpl {
+    async fn find(
+        &self,
+        resource_type: TaggedResource,
+        resource_id: Uuid,
+    ) -> Result<Option<ResourceTags>, RepositoryError> {
+        let session = self
+            .pool
+            .get_session("admin")
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        let entity =
+            resource_tag::Entity::find_by_id((resource_type.as_str().to_string(), resource_id))
+                .one(
+                    session
+                        .connection()
+                        .map_err(|e| RepositoryError::Database(e.into()))?,
+                )
+                .await
+                .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        Ok(entity.and_then(ResourceTagMapper::to_domain))
+    }
+
+    async fn find_many(
+        &self,
+        resource_type: TaggedResource,
+        resource_ids: &[Uuid],
+    ) -> Result<Vec<ResourceTags>, RepositoryError> {
+        if resource_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let session = self
+            .pool
+            .get_session("admin")
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        let entities = resource_tag::Entity::find()
+            .filter(resource_tag::Column::ResourceType.eq(resource_type.as_str()))
+            .filter(resource_tag::Column::ResourceId.is_in(resource_ids.to_vec()))
+            .all(
+                session
+                    .connection()
+                    .map_err(|e| RepositoryError::Database(e.into()))?,
+            )
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        Ok(entities
+            .into_iter()
+            .filter_map(ResourceTagMapper::to_domain)
+            .collect())
+    }
+
+    async fn save(&self, tags: &ResourceTags) -> Result<ResourceTags, RepositoryError> {
+        let session = self
+            .pool
+            .get_session("admin")
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        let conn = session
+            .connection()
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        // Replacing the tags keeps the original created_at
+        let entity = ResourceTagMapper::to_entity(tags);
+        let stmt = Statement::from_sql_and_values(
+            DatabaseBackend::Postgres,
+            r#"INSERT INTO resource_tags
+                   (resource_type, resource_id, team_id, tags, annotations, created_at, updated_at)
+               VALUES ($1, $2, $3, $4, $5, $6, $7)
+               ON CONFLICT (resource_type, resource_id) DO UPDATE
+               SET tags = EXCLUDED.tags,
+                   annotations = EXCLUDED.annotations,
+                   updated_at = EXCLUDED.updated_at
+               RETURNING *"#,
+            [
+                entity.resource_type.into(),
+                entity.resource_id.into(),
+                entity.team_id.into(),
+                entity.tags.into(),
+                entity.annotations.into(),
+                entity.created_at.into(),
+                entity.updated_at.into(),
+            ],
+        );
+        let row = conn
+            .query_one_raw(stmt)
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?
+            .ok_or(RepositoryError::NotFound)?;
+
+        resource_tag::Model::from_query_result(&row, "")
+            .map_err(|e| RepositoryError::Database(e.into()))
+            .and_then(|entity| {
+                ResourceTagMapper::to_domain(entity).ok_or(RepositoryError::NotFound)
+            })
+    }
+
+    async fn delete(
+        &self,
+        resource_type: TaggedResource,
+        resource_id: Uuid,
+    ) -> Result<bool, RepositoryError> {
+        let session = self
+            .pool
+            .get_session("admin")
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        let result =
+            resource_tag::Entity::delete_by_id((resource_type.as_str().to_string(), resource_id))
+                .exec(
+                    session
+                        .connection()
+                        .map_err(|e| RepositoryError::Database(e.into()))?,
+                )
+                .await
+                .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        Ok(result.rows_affected > 0)
+    }
+
+    async fn resource_team(
+        &self,
+        resource_type: TaggedResource,
+        resource_id: Uuid,
+    ) -> Result<Option<Uuid>, RepositoryError> {
+        let session = self
+            .pool
+            .get_session("admin")
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        let conn = session
+            .connection()
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        let sql = match resource_type {
+            TaggedResource::Result => {
+                r#"SELECT t.team_id FROM scrape_results sr
+                   JOIN tasks t ON sr.task_id = t.id
+                   WHERE sr.id = $1"#
+            }
+            TaggedResource::Crawl => "SELECT team_id FROM crawls WHERE id = $1",
+        };
+        let row = conn
+            .query_one_raw(Statement::from_sql_and_values(
+                DatabaseBackend::Postgres,
+                sql,
+                [resource_id.into()],
+            ))
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        row.map(|row| row.try_get::<Uuid>("", "team_id"))
+            .transpose()
+            .map_err(|e| RepositoryError::Database(e.into()))
+    }
+}
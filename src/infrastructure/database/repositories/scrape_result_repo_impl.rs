@@ -6,27 +6,51 @@
 //! ScrapeResult repository implementation using dbnexus
 
 use crate::domain::models::ScrapeResult;
-use crate::domain::repositories::scrape_result_repository::ScrapeResultRepository;
+use crate::domain::repositories::scrape_result_repository::{
+    url_pattern_to_like, ResultDeletionFilter, ResultSearchHit, ScrapeResultRepository,
+};
+use crate::domain::services::result_indexer::IndexedResult;
 use crate::infrastructure::database::entities::scrape_result as db_entity;
+use crate::infrastructure::database::entities::task as task_entity;
+use crate::utils::content_compression::{decompress_content, ContentCompression, ZSTD_ENCODING};
+use crate::utils::search_snippet::searchable_text;
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use dbnexus::DbPool;
+use log::warn;
 use sea_orm::{
-    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseBackend, EntityTrait, QueryFilter,
-    QueryResult, Set, Statement,
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseBackend, EntityTrait, FromQueryResult,
+    PaginatorTrait, QueryFilter, QueryOrder, QueryResult, QuerySelect, Set, Statement, Value,
 };
 use std::sync::Arc;
 use uuid::Uuid;
 
+/// Text search configuration; `simple` lowercases words without language-specific stemming
+const SEARCH_CONFIG: &str = "simple";
+
 /// ScrapeResult repository implementation using dbnexus
 pub struct ScrapeResultRepositoryImpl {
     /// Database pool
     pool: Arc<DbPool>,
+    /// Body compression applied on save (None stores bodies as plain text)
+    compression: Option<ContentCompression>,
 }
 
 impl ScrapeResultRepositoryImpl {
     /// Create new ScrapeResult repository instance
     pub fn new(pool: Arc<DbPool>) -> Self {
-        Self { pool }
+        Self {
+            pool,
+            compression: None,
+        }
+    }
+
+    /// Compress result bodies with zstd on save
+    ///
+    /// Reads decompress transparently whether or not compression is enabled.
+    pub fn with_compression(mut self, compression: ContentCompression) -> Self {
+        self.compression = Some(compression);
+        self
     }
 
     /// Get database pool reference
@@ -34,6 +58,88 @@ impl ScrapeResultRepositoryImpl {
         &self.pool
     }
 
+    /// Load a batch of results for the external search index backfill
+    ///
+    /// Results are returned in `(created_at, id)` order together with the team
+    /// and crawl of their task; pass the last result's key as `after` to fetch
+    /// the next batch. `crawl_id` restricts the batch to one crawl.
+    pub async fn find_for_indexing(
+        &self,
+        crawl_id: Option<Uuid>,
+        after: Option<(DateTime<Utc>, Uuid)>,
+        limit: u64,
+    ) -> anyhow::Result<Vec<IndexedResult>> {
+        let session = self
+            .pool
+            .get_session("admin")
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to get session: {}", e))?;
+
+        let conn = session
+            .connection()
+            .map_err(|e| anyhow::anyhow!("Failed to get connection: {}", e))?;
+
+        let mut conditions = Vec::new();
+        let mut values: Vec<Value> = Vec::new();
+        if let Some(crawl_id) = crawl_id {
+            values.push(crawl_id.into());
+            conditions.push(format!("t.crawl_id = ${}", values.len()));
+        }
+        if let Some((created_at, id)) = after {
+            values.push(created_at.into());
+            values.push(id.into());
+            conditions.push(format!(
+                "(sr.created_at, sr.id) > (${}, ${})",
+                values.len() - 1,
+                values.len()
+            ));
+        }
+        values.push((limit as i64).into());
+        let filter = if conditions.is_empty() {
+            String::new()
+        } else {
+            format!("WHERE {}", conditions.join(" AND "))
+        };
+
+        let stmt = Statement::from_sql_and_values(
+            DatabaseBackend::Postgres,
+            format!(
+                r#"SELECT sr.*, t.team_id AS index_team_id, t.crawl_id AS index_crawl_id
+                   FROM scrape_results sr
+                   JOIN tasks t ON sr.task_id = t.id
+                   {filter}
+                   ORDER BY sr.created_at, sr.id
+                   LIMIT ${limit}"#,
+                filter = filter,
+                limit = values.len()
+            ),
+            values,
+        );
+
+        let rows = conn
+            .query_all_raw(stmt)
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to load results for indexing: {}", e))?;
+
+        rows.iter()
+            .map(|row| {
+                let team_id = row
+                    .try_get::<Uuid>("", "index_team_id")
+                    .map_err(|e| anyhow::anyhow!("Failed to read result team: {}", e))?;
+                let crawl_id = row
+                    .try_get::<Option<Uuid>>("", "index_crawl_id")
+                    .map_err(|e| anyhow::anyhow!("Failed to read result crawl: {}", e))?;
+                let model = db_entity::Model::from_query_result(row, "")
+                    .map_err(|e| anyhow::anyhow!("Failed to read result: {}", e))?;
+                Ok(IndexedResult {
+                    result: Self::decode(model)?,
+                    team_id,
+                    crawl_id,
+                })
+            })
+            .collect()
+    }
+
     /// Convert domain model to database active model
     fn to_active_model(result: &ScrapeResult) -> db_entity::ActiveModel {
         use chrono::FixedOffset;
@@ -52,9 +158,52 @@ impl ScrapeResultRepositoryImpl {
                 .created_at
                 .and_utc()
                 .with_timezone(&FixedOffset::east_opt(0).unwrap())),
+            etag: Set(result.etag.clone()),
+            last_modified: Set(result.last_modified.clone()),
+            content_encoding: Set(None),
+            content_compressed: Set(None),
         }
     }
 
+    /// Convert domain model to database active model, compressing the body when enabled
+    fn to_stored_model(&self, result: &ScrapeResult) -> anyhow::Result<db_entity::ActiveModel> {
+        let mut active_model = Self::to_active_model(result);
+        let Some(compression) = &self.compression else {
+            return Ok(active_model);
+        };
+        if let Some(compressed) = compression
+            .compress(&result.content)
+            .map_err(|e| anyhow::anyhow!("Failed to compress content: {}", e))?
+        {
+            active_model.content = Set(String::new());
+            active_model.content_encoding = Set(Some(ZSTD_ENCODING.to_string()));
+            active_model.content_compressed = Set(Some(compressed));
+        }
+        Ok(active_model)
+    }
+
+    /// Statement that writes the full-text search vector of a saved result
+    ///
+    /// The text is extracted here rather than by a trigger because compressed
+    /// bodies are not readable from SQL. The URL is weighted above the body.
+    fn search_index_statement(result: &ScrapeResult) -> Statement {
+        Statement::from_sql_and_values(
+            DatabaseBackend::Postgres,
+            format!(
+                r#"UPDATE scrape_results
+                   SET search_vector = setweight(to_tsvector('{config}', $2), 'A')
+                       || setweight(to_tsvector('{config}', $3), 'B')
+                   WHERE id = $1"#,
+                config = SEARCH_CONFIG
+            ),
+            [
+                result.id.into(),
+                result.url.clone().into(),
+                searchable_text(&result.content, &result.content_type).into(),
+            ],
+        )
+    }
+
     /// Convert database model to domain model
     fn to_domain(model: db_entity::Model) -> ScrapeResult {
         ScrapeResult {
@@ -69,7 +218,20 @@ impl ScrapeResultRepositoryImpl {
             screenshot: model.screenshot,
             response_time_ms: model.response_time_ms,
             created_at: model.created_at.naive_utc(),
+            etag: model.etag,
+            last_modified: model.last_modified,
+        }
+    }
+
+    /// Convert database model to domain model, decompressing an encoded body
+    fn decode(mut model: db_entity::Model) -> anyhow::Result<ScrapeResult> {
+        if let Some(encoding) = model.content_encoding.take() {
+            let compressed = model.content_compressed.take().unwrap_or_default();
+            model.content = decompress_content(&encoding, &compressed).map_err(|e| {
+                anyhow::anyhow!("Failed to decode content of result {}: {}", model.id, e)
+            })?;
         }
+        Ok(Self::to_domain(model))
     }
 }
 
@@ -86,13 +248,21 @@ impl ScrapeResultRepository for ScrapeResultRepositoryImpl {
             .connection()
             .map_err(|e| anyhow::anyhow!("Failed to get connection: {}", e))?;
 
-        let active_model = Self::to_active_model(&result);
+        let active_model = self.to_stored_model(&result)?;
 
         active_model
             .insert(conn)
             .await
             .map_err(|e| anyhow::anyhow!("Failed to insert: {}", e))?;
 
+        // 索引失败不影响结果保存，只是该结果无法被检索
+        if let Err(e) = conn
+            .execute_raw(Self::search_index_statement(&result))
+            .await
+        {
+            warn!("Failed to index result {} for search: {}", result.id, e);
+        }
+
         Ok(())
     }
 
@@ -113,7 +283,7 @@ impl ScrapeResultRepository for ScrapeResultRepositoryImpl {
             .await
             .map_err(|e| anyhow::anyhow!("Failed to find: {}", e))?;
 
-        Ok(result.map(Self::to_domain))
+        result.map(Self::decode).transpose()
     }
 
     async fn find_by_task_ids(&self, task_ids: &[Uuid]) -> anyhow::Result<Vec<ScrapeResult>> {
@@ -137,7 +307,95 @@ impl ScrapeResultRepository for ScrapeResultRepositoryImpl {
             .await
             .map_err(|e| anyhow::anyhow!("Failed to find: {}", e))?;
 
-        Ok(results.into_iter().map(Self::to_domain).collect())
+        results.into_iter().map(Self::decode).collect()
+    }
+
+    async fn find_latest_for_crawl_url(
+        &self,
+        crawl_id: Uuid,
+        url: &str,
+    ) -> anyhow::Result<Option<ScrapeResult>> {
+        let session = self
+            .pool
+            .get_session("admin")
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to get session: {}", e))?;
+
+        let conn = session
+            .connection()
+            .map_err(|e| anyhow::anyhow!("Failed to get connection: {}", e))?;
+
+        let task_ids: Vec<Uuid> = task_entity::Entity::find()
+            .select_only()
+            .column(task_entity::Column::Id)
+            .filter(task_entity::Column::CrawlId.eq(crawl_id))
+            .filter(task_entity::Column::Url.eq(url))
+            .into_tuple()
+            .all(conn)
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to find crawl tasks: {}", e))?;
+
+        if task_ids.is_empty() {
+            return Ok(None);
+        }
+
+        let result = db_entity::Entity::find()
+            .filter(db_entity::Column::TaskId.is_in(task_ids))
+            .order_by_desc(db_entity::Column::CreatedAt)
+            .one(conn)
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to find: {}", e))?;
+
+        result.map(Self::decode).transpose()
+    }
+
+    async fn count_not_modified(&self, task_ids: &[Uuid]) -> anyhow::Result<u64> {
+        if task_ids.is_empty() {
+            return Ok(0);
+        }
+
+        let session = self
+            .pool
+            .get_session("admin")
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to get session: {}", e))?;
+
+        let conn = session
+            .connection()
+            .map_err(|e| anyhow::anyhow!("Failed to get connection: {}", e))?;
+
+        db_entity::Entity::find()
+            .filter(db_entity::Column::TaskId.is_in(task_ids.to_vec()))
+            .filter(db_entity::Column::StatusCode.eq(304))
+            .count(conn)
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to count: {}", e))
+    }
+
+    async fn response_times(&self, task_ids: &[Uuid]) -> anyhow::Result<Vec<i64>> {
+        if task_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let session = self
+            .pool
+            .get_session("admin")
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to get session: {}", e))?;
+
+        let conn = session
+            .connection()
+            .map_err(|e| anyhow::anyhow!("Failed to get connection: {}", e))?;
+
+        db_entity::Entity::find()
+            .select_only()
+            .column(db_entity::Column::ResponseTimeMs)
+            .filter(db_entity::Column::TaskId.is_in(task_ids.to_vec()))
+            .filter(db_entity::Column::StatusCode.between(200, 399))
+            .into_tuple()
+            .all(conn)
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to load response times: {}", e))
     }
 
     async fn get_team_avg_response_time(&self, team_id: Uuid) -> anyhow::Result<f64> {
@@ -175,6 +433,108 @@ impl ScrapeResultRepository for ScrapeResultRepositoryImpl {
 
         Ok(avg)
     }
+
+    async fn delete_for_team(
+        &self,
+        team_id: Uuid,
+        filter: &ResultDeletionFilter,
+    ) -> anyhow::Result<Vec<ScrapeResult>> {
+        let session = self
+            .pool
+            .get_session("admin")
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to get session: {}", e))?;
+
+        let conn = session
+            .connection()
+            .map_err(|e| anyhow::anyhow!("Failed to get connection: {}", e))?;
+
+        // 结果表没有 team_id，经由任务归属限定团队；过滤条件全部参数化
+        let mut conditions = vec![
+            "sr.task_id = t.id".to_string(),
+            "t.team_id = $1".to_string(),
+        ];
+        let mut values: Vec<Value> = vec![team_id.into()];
+        if let Some(pattern) = &filter.url_pattern {
+            values.push(url_pattern_to_like(pattern).into());
+            conditions.push(format!("sr.url LIKE ${} ESCAPE '\\'", values.len()));
+        }
+        if let Some(crawl_id) = filter.crawl_id {
+            values.push(crawl_id.into());
+            conditions.push(format!("t.crawl_id = ${}", values.len()));
+        }
+        let stmt = Statement::from_sql_and_values(
+            DatabaseBackend::Postgres,
+            format!(
+                r#"DELETE FROM scrape_results sr
+                   USING tasks t
+                   WHERE {}
+                   RETURNING sr.*"#,
+                conditions.join(" AND ")
+            ),
+            values,
+        );
+
+        let rows = conn
+            .query_all_raw(stmt)
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to delete: {}", e))?;
+
+        rows.iter()
+            .map(|row| {
+                db_entity::Model::from_query_result(row, "")
+                    .map_err(|e| anyhow::anyhow!("Failed to read deleted result: {}", e))
+                    .and_then(Self::decode)
+            })
+            .collect()
+    }
+
+    async fn search_crawl_results(
+        &self,
+        crawl_id: Uuid,
+        query: &str,
+        limit: u64,
+    ) -> anyhow::Result<Vec<ResultSearchHit>> {
+        let session = self
+            .pool
+            .get_session("admin")
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to get session: {}", e))?;
+
+        let conn = session
+            .connection()
+            .map_err(|e| anyhow::anyhow!("Failed to get connection: {}", e))?;
+
+        let stmt = Statement::from_sql_and_values(
+            DatabaseBackend::Postgres,
+            format!(
+                r#"SELECT sr.*, ts_rank_cd(sr.search_vector, q) AS search_rank
+                   FROM scrape_results sr
+                   JOIN tasks t ON sr.task_id = t.id,
+                        websearch_to_tsquery('{config}', $2) q
+                   WHERE t.crawl_id = $1 AND sr.search_vector @@ q
+                   ORDER BY search_rank DESC, sr.created_at DESC
+                   LIMIT $3"#,
+                config = SEARCH_CONFIG
+            ),
+            [crawl_id.into(), query.into(), (limit as i64).into()],
+        );
+
+        let rows = conn
+            .query_all_raw(stmt)
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to search results: {}", e))?;
+
+        rows.iter()
+            .map(|row| {
+                let rank = row.try_get::<f32>("", "search_rank").unwrap_or_default();
+                db_entity::Model::from_query_result(row, "")
+                    .map_err(|e| anyhow::anyhow!("Failed to read search hit: {}", e))
+                    .and_then(Self::decode)
+                    .map(|result| ResultSearchHit { result, rank })
+            })
+            .collect()
+    }
 }
 
 #[cfg(test)]
@@ -198,6 +558,8 @@ mod tests {
             created_at: chrono::DateTime::from_timestamp(1_700_000_000, 0)
                 .expect("valid timestamp")
                 .naive_utc(),
+            etag: Some("\"v1\"".to_string()),
+            last_modified: Some("Wed, 21 Oct 2015 07:28:00 GMT".to_string()),
         }
     }
 
@@ -218,6 +580,10 @@ mod tests {
             headers: Some(serde_json::json!({"x-custom": "value"})),
             meta_data: Some(serde_json::json!({"source": "test"})),
             screenshot: None,
+            etag: None,
+            last_modified: None,
+            content_encoding: None,
+            content_compressed: None,
         }
     }
 
@@ -234,6 +600,19 @@ mod tests {
 
     // ========== pure conversion functions ==========
 
+    #[test]
+    fn test_search_index_statement_uses_extracted_text() {
+        let result = sample_scrape_result();
+        let stmt = ScrapeResultRepositoryImpl::search_index_statement(&result);
+
+        assert!(stmt.sql.contains("to_tsvector('simple', $2), 'A'"));
+        assert!(stmt.sql.contains("to_tsvector('simple', $3), 'B'"));
+        let values = stmt.values.expect("bound values").0;
+        assert_eq!(values.len(), 3);
+        assert_eq!(values[1], Value::from(result.url.clone()));
+        assert_eq!(values[2], Value::from("hello".to_string()));
+    }
+
     #[test]
     fn test_to_active_model_converts_all_fields() {
         let result = sample_scrape_result();
@@ -312,6 +691,10 @@ mod tests {
             headers: active.headers.unwrap(),
             meta_data: active.meta_data.unwrap(),
             screenshot: active.screenshot.unwrap(),
+            etag: active.etag.unwrap(),
+            last_modified: active.last_modified.unwrap(),
+            content_encoding: active.content_encoding.unwrap(),
+            content_compressed: active.content_compressed.unwrap(),
         };
         let roundtrip = ScrapeResultRepositoryImpl::to_domain(model);
         assert_eq!(roundtrip.id, original.id);
@@ -319,6 +702,8 @@ mod tests {
         assert_eq!(roundtrip.content, original.content);
         assert_eq!(roundtrip.headers, original.headers);
         assert_eq!(roundtrip.meta_data, original.meta_data);
+        assert_eq!(roundtrip.etag, original.etag);
+        assert_eq!(roundtrip.last_modified, original.last_modified);
     }
 
     // ========== CRUD against real DB ==========
@@ -347,6 +732,16 @@ mod tests {
         assert_eq!(found.response_time_ms, result.response_time_ms);
     }
 
+    #[tokio::test]
+    async fn test_find_for_indexing_returns_empty_for_unknown_crawl() {
+        let repo = ScrapeResultRepositoryImpl::new(create_test_db_pool());
+        let batch = repo
+            .find_for_indexing(Some(Uuid::new_v4()), Some((Utc::now(), Uuid::new_v4())), 10)
+            .await
+            .expect("find_for_indexing failed");
+        assert!(batch.is_empty());
+    }
+
     #[tokio::test]
     async fn test_find_by_task_id_returns_none_for_unknown() {
         let repo = ScrapeResultRepositoryImpl::new(create_test_db_pool());
@@ -401,6 +796,18 @@ mod tests {
         assert_eq!(result.unwrap(), 0.0);
     }
 
+    #[tokio::test]
+    async fn test_delete_for_team_returns_empty_for_unknown_team() {
+        let repo = ScrapeResultRepositoryImpl::new(create_test_db_pool());
+        let filter = ResultDeletionFilter {
+            url_pattern: Some("https://example.com/*".to_string()),
+            crawl_id: None,
+        };
+        let result = repo.delete_for_team(Uuid::new_v4(), &filter).await;
+        assert!(result.is_ok(), "delete_for_team failed: {:?}", result.err());
+        assert!(result.unwrap().is_empty());
+    }
+
     // ========== additional boundary variants ==========
 
     #[tokio::test]
@@ -598,4 +1005,62 @@ mod tests {
         assert!(model.headers.is_some());
         assert!(model.meta_data.is_some());
     }
+
+    // ========== content compression ==========
+
+    #[test]
+    fn test_to_stored_model_compresses_large_content() {
+        let repo = ScrapeResultRepositoryImpl::new(create_test_db_pool())
+            .with_compression(ContentCompression::default());
+        let mut result = sample_scrape_result();
+        result.content = "<p>repeated paragraph</p>".repeat(100);
+
+        let active = repo.to_stored_model(&result).unwrap();
+        assert_eq!(active.content.clone().unwrap(), "");
+        assert_eq!(
+            active.content_encoding.clone().unwrap().as_deref(),
+            Some(ZSTD_ENCODING)
+        );
+        let compressed = active.content_compressed.clone().unwrap().unwrap();
+        assert!(compressed.len() < result.content.len());
+
+        let mut model = sample_db_model();
+        model.content = active.content.unwrap();
+        model.content_encoding = active.content_encoding.unwrap();
+        model.content_compressed = Some(compressed);
+        let decoded = ScrapeResultRepositoryImpl::decode(model).unwrap();
+        assert_eq!(decoded.content, result.content);
+    }
+
+    #[test]
+    fn test_to_stored_model_keeps_short_or_uncompressed_content() {
+        let result = sample_scrape_result();
+        let compressing = ScrapeResultRepositoryImpl::new(create_test_db_pool())
+            .with_compression(ContentCompression::default());
+        let active = compressing.to_stored_model(&result).unwrap();
+        assert_eq!(active.content.unwrap(), result.content);
+        assert_eq!(active.content_encoding.unwrap(), None);
+
+        let plain = ScrapeResultRepositoryImpl::new(create_test_db_pool());
+        let mut large = sample_scrape_result();
+        large.content = "x".repeat(10_000);
+        let active = plain.to_stored_model(&large).unwrap();
+        assert_eq!(active.content.unwrap(), large.content);
+        assert_eq!(active.content_compressed.unwrap(), None);
+    }
+
+    #[test]
+    fn test_decode_rejects_corrupt_content() {
+        let mut model = sample_db_model();
+        model.content_encoding = Some(ZSTD_ENCODING.to_string());
+        model.content_compressed = Some(b"garbage".to_vec());
+        assert!(ScrapeResultRepositoryImpl::decode(model).is_err());
+
+        // 未编码的结果原样返回
+        let model = sample_db_model();
+        assert_eq!(
+            ScrapeResultRepositoryImpl::decode(model).unwrap().content,
+            "not found"
+        );
+    }
 }
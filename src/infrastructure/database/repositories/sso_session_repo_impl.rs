@@ -0,0 +1,244 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! SSO session repository implementation using Sea-ORM
+
+use crate::common::time_utils;
+use crate::domain::auth::ApiKeyScope;
+use crate::domain::models::{OidcLoginState, SsoSession};
+use crate::domain::repositories::sso_session_repository::SsoSessionRepository;
+use crate::domain::repositories::task_repository::RepositoryError;
+use async_trait::async_trait;
+use chrono::{DateTime, FixedOffset, Utc};
+use dbnexus::DbPool;
+use sea_orm::{ConnectionTrait, DatabaseBackend, DbErr, QueryResult, Statement};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// SSO session repository backed by `oidc_login_states`, `api_keys` and `scopes`
+#[derive(Clone)]
+pub struct SsoSessionRepositoryImpl {
+    /// Database pool
+    pool: Arc<DbPool>,
+}
+
+impl SsoSessionRepositoryImpl {
+    /// Create new SSO session repository instance
+    pub fn new(pool: Arc<DbPool>) -> Self {
+        Self { pool }
+    }
+}
+
+fn to_login_state(
+    row: &QueryResult,
+    created_at: DateTime<FixedOffset>,
+) -> Result<OidcLoginState, DbErr> {
+    Ok(OidcLoginState {
+        state: row.try_get_by_index(0)?,
+        code_verifier: row.try_get_by_index(1)?,
+        nonce: row.try_get_by_index(2)?,
+        team_id: row.try_get_by_index(3)?,
+        created_at: created_at.with_timezone(&Utc),
+    })
+}
+
+#[async_trait]
+impl SsoSessionRepository for SsoSessionRepositoryImpl {
+    async fn save_login_state(&self, login: &OidcLoginState) -> Result<(), RepositoryError> {
+        let session = self
+            .pool
+            .get_session("admin")
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+        let conn = session
+            .connection()
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        let stmt = Statement::from_sql_and_values(
+            DatabaseBackend::Postgres,
+            r#"INSERT INTO oidc_login_states (state, code_verifier, nonce, team_id, created_at)
+               VALUES ($1, $2, $3, $4, $5)"#,
+            [
+                login.state.clone().into(),
+                login.code_verifier.clone().into(),
+                login.nonce.clone().into(),
+                login.team_id.into(),
+                login
+                    .created_at
+                    .with_timezone(&time_utils::UTC_OFFSET)
+                    .into(),
+            ],
+        );
+        conn.execute_raw(stmt)
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        Ok(())
+    }
+
+    async fn take_login_state(
+        &self,
+        state: &str,
+        issued_after: DateTime<Utc>,
+    ) -> Result<Option<OidcLoginState>, RepositoryError> {
+        let session = self
+            .pool
+            .get_session("admin")
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+        let conn = session
+            .connection()
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+        let issued_after = issued_after.with_timezone(&time_utils::UTC_OFFSET);
+
+        // 删除即消费：并发回调时只有一个请求能取到状态
+        let stmt = Statement::from_sql_and_values(
+            DatabaseBackend::Postgres,
+            r#"DELETE FROM oidc_login_states
+               WHERE state = $1
+               RETURNING state, code_verifier, nonce, team_id, created_at"#,
+            [state.into()],
+        );
+        let row = conn
+            .query_one_raw(stmt)
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        // 顺带清理未完成的过期登录
+        let stmt = Statement::from_sql_and_values(
+            DatabaseBackend::Postgres,
+            "DELETE FROM oidc_login_states WHERE created_at < $1",
+            [issued_after.into()],
+        );
+        conn.execute_raw(stmt)
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        let Some(row) = row else {
+            return Ok(None);
+        };
+        let created_at: DateTime<FixedOffset> = row
+            .try_get_by_index(4)
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+        if created_at < issued_after {
+            return Ok(None);
+        }
+
+        let login =
+            to_login_state(&row, created_at).map_err(|e| RepositoryError::Database(e.into()))?;
+
+        Ok(Some(login))
+    }
+
+    async fn create_session(
+        &self,
+        session: &SsoSession,
+        token_hash: &str,
+        scope: &ApiKeyScope,
+    ) -> Result<(), RepositoryError> {
+        let db_session = self
+            .pool
+            .get_session("admin")
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+        let conn = db_session
+            .connection()
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        // 单条语句同时写入 API Key 与权限，会话令牌不会出现没有角色的中间状态
+        let stmt = Statement::from_sql_and_values(
+            DatabaseBackend::Postgres,
+            r#"WITH session_key AS (
+                   INSERT INTO api_keys (id, team_id, key, key_hash, updated_at, expires_at)
+                   VALUES ($1, $2, $3, $4, NOW(), $5)
+                   RETURNING id
+               )
+               INSERT INTO scopes (api_key_id, read, write, admin, search_limit, scrape_limit)
+               SELECT id, $6, $7, $8, $9, $10 FROM session_key"#,
+            [
+                session.api_key_id.into(),
+                session.team_id.into(),
+                format!("sso:{}:{}", session.user_id, session.api_key_id).into(),
+                token_hash.into(),
+                session
+                    .expires_at
+                    .with_timezone(&time_utils::UTC_OFFSET)
+                    .into(),
+                scope.read.into(),
+                scope.write.into(),
+                scope.admin.into(),
+                (scope.search_limit as i32).into(),
+                (scope.scrape_limit as i32).into(),
+            ],
+        );
+        conn.execute_raw(stmt)
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        Ok(())
+    }
+
+    async fn delete_session(&self, api_key_id: Uuid) -> Result<bool, RepositoryError> {
+        let session = self
+            .pool
+            .get_session("admin")
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+        let conn = session
+            .connection()
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        // 只删除带过期时间的会话令牌，普通 API Key 不受影响
+        let stmt = Statement::from_sql_and_values(
+            DatabaseBackend::Postgres,
+            r#"WITH session_key AS (
+                   DELETE FROM api_keys
+                   WHERE id = $1 AND expires_at IS NOT NULL
+                   RETURNING id
+               ),
+               session_scope AS (
+                   DELETE FROM scopes WHERE api_key_id IN (SELECT id FROM session_key)
+               )
+               SELECT COUNT(*)::BIGINT FROM session_key"#,
+            [api_key_id.into()],
+        );
+        let deleted: i64 = conn
+            .query_one_raw(stmt)
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?
+            .map(|row| row.try_get_by_index(0))
+            .transpose()
+            .map_err(|e| RepositoryError::Database(e.into()))?
+            .unwrap_or(0);
+
+        Ok(deleted > 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::test_helpers::create_test_db_pool;
+
+    #[tokio::test]
+    async fn test_take_unknown_login_state_returns_none() {
+        let repo = SsoSessionRepositoryImpl::new(create_test_db_pool());
+        let login = repo
+            .take_login_state("unknown-state", Utc::now() - chrono::Duration::minutes(10))
+            .await
+            .expect("take should not fail");
+        assert!(login.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_delete_unknown_session_returns_false() {
+        let repo = SsoSessionRepositoryImpl::new(create_test_db_pool());
+        let deleted = repo
+            .delete_session(Uuid::new_v4())
+            .await
+            .expect("delete should not fail");
+        assert!(!deleted);
+    }
+}
@@ -0,0 +1,90 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! Task event repository implementation using Sea-ORM with Mapper
+
+use crate::domain::models::TaskEvent;
+use crate::domain::repositories::task_event_repository::TaskEventRepository;
+use crate::domain::repositories::task_repository::RepositoryError;
+use crate::infrastructure::database::entities::task_event;
+use crate::infrastructure::persistence::mappers::TaskEventMapper;
+use async_trait::async_trait;
+use dbnexus::DbPool;
+use sea_orm::ActiveValue::Set;
+use sea_orm::{ColumnTrait, EntityTrait, QueryFilter, QueryOrder};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Task event repository implementation using Sea-ORM
+#[derive(Clone)]
+pub struct TaskEventRepositoryImpl {
+    /// Database pool
+    pool: Arc<DbPool>,
+}
+
+impl TaskEventRepositoryImpl {
+    /// Create new task event repository instance
+    pub fn new(pool: Arc<DbPool>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl TaskEventRepository for TaskEventRepositoryImpl {
+    async fn record(&self, event: &TaskEvent) -> Result<(), RepositoryError> {
+        let session = self
+            .pool
+            .get_session("admin")
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        let entity = TaskEventMapper::to_entity(event);
+        let active_model = task_event::ActiveModel {
+            id: Set(entity.id),
+            task_id: Set(entity.task_id),
+            event_type: Set(entity.event_type),
+            worker_id: Set(entity.worker_id),
+            attempt: Set(entity.attempt),
+            duration_ms: Set(entity.duration_ms),
+            message: Set(entity.message),
+            created_at: Set(entity.created_at),
+        };
+
+        task_event::Entity::insert(active_model)
+            .exec(
+                session
+                    .connection()
+                    .map_err(|e| RepositoryError::Database(e.into()))?,
+            )
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        Ok(())
+    }
+
+    async fn find_by_task_id(&self, task_id: Uuid) -> Result<Vec<TaskEvent>, RepositoryError> {
+        let session = self
+            .pool
+            .get_session("admin")
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        let entities = task_event::Entity::find()
+            .filter(task_event::Column::TaskId.eq(task_id))
+            .order_by_asc(task_event::Column::CreatedAt)
+            .all(
+                session
+                    .connection()
+                    .map_err(|e| RepositoryError::Database(e.into()))?,
+            )
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        Ok(entities
+            .into_iter()
+            .filter_map(TaskEventMapper::to_domain)
+            .collect())
+    }
+}
@@ -8,7 +8,7 @@
 //! This implementation uses the Mapper pattern to convert between
 //! domain models and database entities, following clean architecture principles.
 
-use crate::domain::models::{Task, TaskStatus};
+use crate::domain::models::{PriorityTier, Task, TaskStatus};
 use crate::domain::repositories::task_repository::{
     RepositoryError, TaskQueryParams, TaskRepository,
 };
@@ -151,7 +151,7 @@ impl TaskRepository for TaskRepositoryImpl {
 
         let lock_seconds = self.lock_duration.num_seconds();
 
-        // Step 1 — Normal path: highest-priority queued task.
+        // Step 1 — Normal path: highest-priority queued task that is due.
         // Uses partial index idx_tasks_acquire_queued for Index Scan with LIMIT 1.
         let stmt_queued = Statement::from_sql_and_values(
             DatabaseBackend::Postgres,
@@ -164,6 +164,7 @@ impl TaskRepository for TaskRepositoryImpl {
                WHERE id = (
                    SELECT id FROM tasks
                    WHERE status = 'queued'
+                     AND (scheduled_at IS NULL OR scheduled_at <= NOW())
                    ORDER BY priority ASC, created_at ASC
                    FOR UPDATE SKIP LOCKED
                    LIMIT 1
@@ -221,6 +222,233 @@ impl TaskRepository for TaskRepositoryImpl {
         }
     }
 
+    async fn acquire_next_in_lane(
+        &self,
+        worker_id: Uuid,
+        tier: PriorityTier,
+        aging: Duration,
+    ) -> Result<Option<Task>, RepositoryError> {
+        let session = self
+            .pool
+            .get_session("admin")
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        let conn = session
+            .connection()
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        // Effective lane = native lane (MIRROR: PriorityTier::for_priority) minus one
+        // per full `aging` interval spent queued, floored at the high lane.
+        // Scans idx_tasks_queued_created_at in FIFO order and stops at the first match.
+        let stmt = Statement::from_sql_and_values(
+            DatabaseBackend::Postgres,
+            r#"UPDATE tasks
+               SET status = 'active',
+                   started_at = NOW(),
+                   lock_token = $1,
+                   lock_expires_at = NOW() + ($2 * INTERVAL '1 second'),
+                   updated_at = NOW()
+               WHERE id = (
+                   SELECT id FROM tasks
+                   WHERE status = 'queued'
+                     AND (scheduled_at IS NULL OR scheduled_at <= NOW())
+                     AND GREATEST(
+                         CASE WHEN priority <= 0 THEN 0 WHEN priority < 100 THEN 1 ELSE 2 END
+                             - FLOOR(EXTRACT(EPOCH FROM (NOW() - created_at)) / $3)::INT,
+                         0
+                     ) = $4
+                   ORDER BY created_at ASC
+                   FOR UPDATE SKIP LOCKED
+                   LIMIT 1
+               )
+               RETURNING *"#,
+            [
+                worker_id.into(),
+                self.lock_duration.num_seconds().into(),
+                aging.num_seconds().max(1).into(),
+                tier.lane().into(),
+            ],
+        );
+
+        let row: Option<sea_orm::QueryResult> = conn
+            .query_one_raw(stmt)
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        match row {
+            Some(row) => {
+                let entity = task_entity::Model::from_query_result(&row, "")
+                    .map_err(|e| RepositoryError::Database(e.into()))?;
+                Ok(Some(TaskMapper::to_domain(entity)))
+            }
+            None => Ok(None),
+        }
+    }
+
+    async fn acquire_by_id(
+        &self,
+        id: Uuid,
+        worker_id: Uuid,
+    ) -> Result<Option<Task>, RepositoryError> {
+        let session = self
+            .pool
+            .get_session("admin")
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        let conn = session
+            .connection()
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        // Same SET clause as acquire_next; the status guard makes the claim atomic,
+        // so a task delivered twice is handed to at most one worker.
+        let stmt = Statement::from_sql_and_values(
+            DatabaseBackend::Postgres,
+            r#"UPDATE tasks
+               SET status = 'active',
+                   started_at = NOW(),
+                   lock_token = $1,
+                   lock_expires_at = NOW() + ($2 * INTERVAL '1 second'),
+                   updated_at = NOW()
+               WHERE id = $3
+                 AND status = 'queued'
+                 AND (scheduled_at IS NULL OR scheduled_at <= NOW())
+               RETURNING *"#,
+            [
+                worker_id.into(),
+                self.lock_duration.num_seconds().into(),
+                id.into(),
+            ],
+        );
+
+        let row: Option<sea_orm::QueryResult> = conn
+            .query_one_raw(stmt)
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        match row {
+            Some(row) => {
+                let entity = task_entity::Model::from_query_result(&row, "")
+                    .map_err(|e| RepositoryError::Database(e.into()))?;
+                Ok(Some(TaskMapper::to_domain(entity)))
+            }
+            None => Ok(None),
+        }
+    }
+
+    async fn renew_lock(&self, id: Uuid, worker_id: Uuid) -> Result<bool, RepositoryError> {
+        let session = self
+            .pool
+            .get_session("admin")
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        let conn = session
+            .connection()
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        // The lock token guard fails once the stale-recovery path of acquire_next
+        // has handed the task to another worker, which is how a lost lease shows up.
+        let stmt = Statement::from_sql_and_values(
+            DatabaseBackend::Postgres,
+            r#"UPDATE tasks
+               SET lock_expires_at = NOW() + ($2 * INTERVAL '1 second'),
+                   updated_at = NOW()
+               WHERE id = $1 AND status = 'active' AND lock_token = $3
+               RETURNING id"#,
+            [
+                id.into(),
+                self.lock_duration.num_seconds().into(),
+                worker_id.into(),
+            ],
+        );
+
+        let row: Option<sea_orm::QueryResult> = conn
+            .query_one_raw(stmt)
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        Ok(row.is_some())
+    }
+
+    async fn acquire_stalled(&self, worker_id: Uuid) -> Result<Option<Task>, RepositoryError> {
+        let session = self
+            .pool
+            .get_session("admin")
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        let conn = session
+            .connection()
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        // Same candidates as the recovery path of acquire_next, but status and
+        // started_at are left untouched: the reaper only takes the lock so it can
+        // requeue or fail the task without racing a worker that recovers it.
+        let stmt = Statement::from_sql_and_values(
+            DatabaseBackend::Postgres,
+            r#"UPDATE tasks
+               SET lock_token = $1,
+                   lock_expires_at = NOW() + ($2 * INTERVAL '1 second'),
+                   updated_at = NOW()
+               WHERE id = (
+                   SELECT id FROM tasks
+                   WHERE status = 'active' AND lock_expires_at < NOW()
+                   ORDER BY lock_expires_at ASC
+                   FOR UPDATE SKIP LOCKED
+                   LIMIT 1
+               )
+               RETURNING *"#,
+            [worker_id.into(), self.lock_duration.num_seconds().into()],
+        );
+
+        let row: Option<sea_orm::QueryResult> = conn
+            .query_one_raw(stmt)
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        match row {
+            Some(row) => {
+                let entity = task_entity::Model::from_query_result(&row, "")
+                    .map_err(|e| RepositoryError::Database(e.into()))?;
+                Ok(Some(TaskMapper::to_domain(entity)))
+            }
+            None => Ok(None),
+        }
+    }
+
+    async fn promote_scheduled(&self, id: Uuid) -> Result<bool, RepositoryError> {
+        let session = self
+            .pool
+            .get_session("admin")
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        let conn = session
+            .connection()
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        // Clearing scheduled_at makes the task acquirable even when the database
+        // clock lags behind the dispatcher that decided it was due.
+        let stmt = Statement::from_sql_and_values(
+            DatabaseBackend::Postgres,
+            r#"UPDATE tasks
+               SET scheduled_at = NULL,
+                   updated_at = NOW()
+               WHERE id = $1 AND status = 'queued' AND scheduled_at IS NOT NULL
+               RETURNING id"#,
+            [id.into()],
+        );
+
+        let row: Option<sea_orm::QueryResult> = conn
+            .query_one_raw(stmt)
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        Ok(row.is_some())
+    }
+
     async fn mark_completed(&self, id: Uuid) -> Result<(), RepositoryError> {
         let session = self
             .pool
@@ -788,6 +1016,90 @@ mod tests {
         assert!(acquired_task.started_at.is_some());
     }
 
+    #[tokio::test]
+    async fn test_renew_lock_with_real_db_only_for_lock_holder() {
+        let repo = TaskRepositoryImpl::new(create_test_db_pool(), Duration::minutes(5));
+        let mut task = make_test_task();
+        let worker = Uuid::new_v4();
+        task.start();
+        task.acquire_lock(worker, Duration::seconds(1));
+        repo.create(&task).await.expect("create failed");
+
+        assert!(repo
+            .renew_lock(task.id, worker)
+            .await
+            .expect("renew failed"));
+        let renewed = repo
+            .find_by_id(task.id)
+            .await
+            .expect("find_by_id failed")
+            .expect("task should exist");
+        assert!(renewed.lock_expires_at.unwrap() > Utc::now() + Duration::minutes(4));
+
+        assert!(!repo
+            .renew_lock(task.id, Uuid::new_v4())
+            .await
+            .expect("renew failed"));
+        repo.mark_completed(task.id).await.expect("complete failed");
+        assert!(!repo
+            .renew_lock(task.id, worker)
+            .await
+            .expect("renew failed"));
+    }
+
+    #[tokio::test]
+    async fn test_scheduled_task_is_claimable_only_once_promoted() {
+        let repo = TaskRepositoryImpl::new(create_test_db_pool(), Duration::minutes(5));
+        let mut task = make_test_task();
+        task.scheduled_at = Some(Utc::now() + Duration::hours(1));
+        repo.create(&task).await.expect("create failed");
+
+        let worker = Uuid::new_v4();
+        assert!(repo
+            .acquire_by_id(task.id, worker)
+            .await
+            .expect("acquire_by_id failed")
+            .is_none());
+        assert!(repo
+            .promote_scheduled(task.id)
+            .await
+            .expect("promote failed"));
+        assert!(!repo
+            .promote_scheduled(task.id)
+            .await
+            .expect("promote failed"));
+        let claimed = repo
+            .acquire_by_id(task.id, worker)
+            .await
+            .expect("acquire_by_id failed")
+            .expect("promoted task should be claimable");
+        assert!(claimed.scheduled_at.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_acquire_stalled_with_real_db_takes_over_expired_lock() {
+        // 与 acquire_next 测试共用锁：过期锁任务也会被 acquire_next 的回收路径领取
+        let _guard = acquire_next_test_mutex().lock().await;
+        let repo = TaskRepositoryImpl::new(create_test_db_pool(), Duration::minutes(5));
+        let mut task = make_test_task();
+        task.start();
+        task.acquire_lock(Uuid::new_v4(), Duration::minutes(-1));
+        repo.create(&task).await.expect("create failed");
+
+        let reaper = Uuid::new_v4();
+        let mut reaped = None;
+        while let Some(stalled) = repo.acquire_stalled(reaper).await.expect("acquire failed") {
+            if stalled.id == task.id {
+                reaped = Some(stalled);
+                break;
+            }
+        }
+        let reaped = reaped.expect("stalled task should be acquired");
+        assert_eq!(reaped.status, TaskStatus::Active);
+        assert_eq!(reaped.lock_token, Some(reaper));
+        assert!(reaped.lock_expires_at.unwrap() > Utc::now());
+    }
+
     #[tokio::test]
     async fn test_mark_completed_with_real_db_succeeds() {
         let repo = TaskRepositoryImpl::new(create_test_db_pool(), Duration::minutes(5));
@@ -0,0 +1,117 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! Team capability repository implementation using Sea-ORM
+
+use crate::domain::models::TeamCapabilities;
+use crate::domain::repositories::task_repository::RepositoryError;
+use crate::domain::repositories::team_capability_repository::TeamCapabilityRepository;
+use crate::infrastructure::database::entities::team;
+use async_trait::async_trait;
+use dbnexus::DbPool;
+use sea_orm::ActiveValue::Set;
+use sea_orm::{ActiveModelTrait, EntityTrait};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Team capability repository implementation backed by the `teams` table
+#[derive(Clone)]
+pub struct TeamCapabilityRepositoryImpl {
+    /// Database pool
+    pool: Arc<DbPool>,
+}
+
+impl TeamCapabilityRepositoryImpl {
+    /// Create new team capability repository instance
+    pub fn new(pool: Arc<DbPool>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl TeamCapabilityRepository for TeamCapabilityRepositoryImpl {
+    async fn get_capabilities(&self, team_id: Uuid) -> Result<TeamCapabilities, RepositoryError> {
+        let session = self
+            .pool
+            .get_session("admin")
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        let model = team::Entity::find_by_id(team_id)
+            .one(
+                session
+                    .connection()
+                    .map_err(|e| RepositoryError::Database(e.into()))?,
+            )
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?
+            .ok_or(RepositoryError::NotFound)?;
+
+        Ok(TeamCapabilities {
+            allow_ignore_robots: model.allow_ignore_robots,
+        })
+    }
+
+    async fn update_capabilities(
+        &self,
+        team_id: Uuid,
+        capabilities: &TeamCapabilities,
+    ) -> Result<TeamCapabilities, RepositoryError> {
+        let session = self
+            .pool
+            .get_session("admin")
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+        let conn = session
+            .connection()
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        let model = team::Entity::find_by_id(team_id)
+            .one(conn)
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?
+            .ok_or(RepositoryError::NotFound)?;
+
+        let mut active_model: team::ActiveModel = model.into();
+        active_model.allow_ignore_robots = Set(capabilities.allow_ignore_robots);
+        active_model.updated_at = Set(chrono::Utc::now().into());
+        active_model
+            .update(conn)
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        Ok(*capabilities)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::test_helpers::create_test_db_pool;
+
+    #[tokio::test]
+    async fn test_get_capabilities_returns_not_found_for_unknown_team() {
+        let repo = TeamCapabilityRepositoryImpl::new(create_test_db_pool());
+        let result = repo.get_capabilities(Uuid::new_v4()).await;
+        assert!(
+            matches!(result, Err(RepositoryError::NotFound)),
+            "expected NotFound, got {:?}",
+            result
+        );
+    }
+
+    #[tokio::test]
+    async fn test_update_capabilities_returns_not_found_for_unknown_team() {
+        let repo = TeamCapabilityRepositoryImpl::new(create_test_db_pool());
+        let result = repo
+            .update_capabilities(Uuid::new_v4(), &TeamCapabilities::default())
+            .await;
+        assert!(
+            matches!(result, Err(RepositoryError::NotFound)),
+            "expected NotFound, got {:?}",
+            result
+        );
+    }
+}
@@ -0,0 +1,327 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! Team member repository implementation using Sea-ORM
+
+use crate::common::time_utils;
+use crate::domain::models::{TeamMember, TeamMemberStatus, TeamRole, User};
+use crate::domain::repositories::task_repository::RepositoryError;
+use crate::domain::repositories::team_member_repository::TeamMemberRepository;
+use crate::infrastructure::database::entities::{team_member, user};
+use async_trait::async_trait;
+use chrono::Utc;
+use dbnexus::DbPool;
+use log::warn;
+use sea_orm::sea_query::Expr;
+use sea_orm::ActiveValue::Set;
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, DatabaseBackend, EntityTrait, QueryFilter, QueryOrder, Statement,
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Team member repository implementation backed by the `users` and `team_members` tables
+#[derive(Clone)]
+pub struct TeamMemberRepositoryImpl {
+    /// Database pool
+    pool: Arc<DbPool>,
+}
+
+impl TeamMemberRepositoryImpl {
+    /// Create new team member repository instance
+    pub fn new(pool: Arc<DbPool>) -> Self {
+        Self { pool }
+    }
+}
+
+fn to_user(model: user::Model) -> User {
+    User {
+        id: model.id,
+        email: model.email,
+        name: model.name,
+        created_at: model.created_at.with_timezone(&Utc),
+    }
+}
+
+/// Convert a `team_members` row and its user into a member; unknown values fall back
+/// to the least privileged role and to `invited`
+fn to_member(model: team_member::Model, user: user::Model) -> TeamMember {
+    let role = model.role.parse::<TeamRole>().unwrap_or_else(|e| {
+        warn!("{} for user {}, treating it as member", e, model.user_id);
+        TeamRole::Member
+    });
+    let status = model
+        .status
+        .parse::<TeamMemberStatus>()
+        .unwrap_or_else(|e| {
+            warn!("{} for user {}, treating it as invited", e, model.user_id);
+            TeamMemberStatus::Invited
+        });
+    TeamMember {
+        team_id: model.team_id,
+        user_id: model.user_id,
+        email: user.email,
+        name: user.name,
+        role,
+        status,
+        invited_at: model.invited_at.with_timezone(&Utc),
+        joined_at: model
+            .joined_at
+            .map(|joined_at| joined_at.with_timezone(&Utc)),
+    }
+}
+
+#[async_trait]
+impl TeamMemberRepository for TeamMemberRepositoryImpl {
+    async fn find_or_create_user(&self, email: &str) -> Result<User, RepositoryError> {
+        let session = self
+            .pool
+            .get_session("admin")
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+        let conn = session
+            .connection()
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        // 并发邀请同一邮箱时只创建一个用户
+        let stmt = Statement::from_sql_and_values(
+            DatabaseBackend::Postgres,
+            r#"INSERT INTO users (id, email)
+               VALUES ($1, $2)
+               ON CONFLICT (email) DO NOTHING"#,
+            [Uuid::new_v4().into(), email.into()],
+        );
+        conn.execute_raw(stmt)
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        let model = user::Entity::find()
+            .filter(user::Column::Email.eq(email))
+            .one(conn)
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?
+            .ok_or(RepositoryError::NotFound)?;
+
+        Ok(to_user(model))
+    }
+
+    async fn add_member(
+        &self,
+        member: &TeamMember,
+        invitation_token_hash: Option<&str>,
+    ) -> Result<(), RepositoryError> {
+        let session = self
+            .pool
+            .get_session("admin")
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        let active_model = team_member::ActiveModel {
+            team_id: Set(member.team_id),
+            user_id: Set(member.user_id),
+            role: Set(member.role.to_string()),
+            status: Set(member.status.to_string()),
+            invitation_token_hash: Set(invitation_token_hash.map(str::to_string)),
+            invited_at: Set(member.invited_at.with_timezone(&time_utils::UTC_OFFSET)),
+            joined_at: Set(member
+                .joined_at
+                .map(|joined_at| joined_at.with_timezone(&time_utils::UTC_OFFSET))),
+        };
+
+        team_member::Entity::insert(active_model)
+            .exec_without_returning(
+                session
+                    .connection()
+                    .map_err(|e| RepositoryError::Database(e.into()))?,
+            )
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        Ok(())
+    }
+
+    async fn find_member(
+        &self,
+        team_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<Option<TeamMember>, RepositoryError> {
+        let session = self
+            .pool
+            .get_session("admin")
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        let row = team_member::Entity::find_by_id((team_id, user_id))
+            .find_also_related(user::Entity)
+            .one(
+                session
+                    .connection()
+                    .map_err(|e| RepositoryError::Database(e.into()))?,
+            )
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        Ok(row.and_then(|(member, user)| user.map(|user| to_member(member, user))))
+    }
+
+    async fn list_members(&self, team_id: Uuid) -> Result<Vec<TeamMember>, RepositoryError> {
+        let session = self
+            .pool
+            .get_session("admin")
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        let rows = team_member::Entity::find()
+            .filter(team_member::Column::TeamId.eq(team_id))
+            .find_also_related(user::Entity)
+            .order_by_asc(team_member::Column::InvitedAt)
+            .all(
+                session
+                    .connection()
+                    .map_err(|e| RepositoryError::Database(e.into()))?,
+            )
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        Ok(rows
+            .into_iter()
+            .filter_map(|(member, user)| user.map(|user| to_member(member, user)))
+            .collect())
+    }
+
+    async fn list_memberships_by_email(
+        &self,
+        email: &str,
+    ) -> Result<Vec<TeamMember>, RepositoryError> {
+        let session = self
+            .pool
+            .get_session("admin")
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        let rows = team_member::Entity::find()
+            .find_also_related(user::Entity)
+            .filter(user::Column::Email.eq(email))
+            .order_by_asc(team_member::Column::InvitedAt)
+            .all(
+                session
+                    .connection()
+                    .map_err(|e| RepositoryError::Database(e.into()))?,
+            )
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        Ok(rows
+            .into_iter()
+            .filter_map(|(member, user)| user.map(|user| to_member(member, user)))
+            .collect())
+    }
+
+    async fn remove_member(&self, team_id: Uuid, user_id: Uuid) -> Result<bool, RepositoryError> {
+        let session = self
+            .pool
+            .get_session("admin")
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        let result = team_member::Entity::delete_by_id((team_id, user_id))
+            .exec(
+                session
+                    .connection()
+                    .map_err(|e| RepositoryError::Database(e.into()))?,
+            )
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        Ok(result.rows_affected > 0)
+    }
+
+    async fn accept_invitation(
+        &self,
+        invitation_token_hash: &str,
+    ) -> Result<Option<TeamMember>, RepositoryError> {
+        let session = self
+            .pool
+            .get_session("admin")
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+        let conn = session
+            .connection()
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        let Some((member, Some(user))) = team_member::Entity::find()
+            .filter(team_member::Column::InvitationTokenHash.eq(invitation_token_hash))
+            .filter(team_member::Column::Status.eq(TeamMemberStatus::Invited.as_str()))
+            .find_also_related(user::Entity)
+            .one(conn)
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?
+        else {
+            return Ok(None);
+        };
+
+        // 条件更新：令牌只能使用一次，并发接受时只有一个成功
+        let joined_at = Utc::now().with_timezone(&time_utils::UTC_OFFSET);
+        let result = team_member::Entity::update_many()
+            .col_expr(
+                team_member::Column::Status,
+                Expr::value(TeamMemberStatus::Active.as_str()),
+            )
+            .col_expr(
+                team_member::Column::InvitationTokenHash,
+                Expr::value(Option::<String>::None),
+            )
+            .col_expr(team_member::Column::JoinedAt, Expr::value(joined_at))
+            .filter(team_member::Column::TeamId.eq(member.team_id))
+            .filter(team_member::Column::UserId.eq(member.user_id))
+            .filter(team_member::Column::InvitationTokenHash.eq(invitation_token_hash))
+            .exec(conn)
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+        if result.rows_affected != 1 {
+            return Ok(None);
+        }
+
+        let mut accepted = to_member(member, user);
+        accepted.status = TeamMemberStatus::Active;
+        accepted.joined_at = Some(joined_at.with_timezone(&Utc));
+        Ok(Some(accepted))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::test_helpers::create_test_db_pool;
+
+    #[tokio::test]
+    async fn test_accept_unknown_invitation_returns_none() {
+        let repo = TeamMemberRepositoryImpl::new(create_test_db_pool());
+        let accepted = repo
+            .accept_invitation("0000000000000000000000000000000000000000000000000000000000000000")
+            .await
+            .expect("accept should not fail");
+        assert!(accepted.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_list_members_of_unknown_team_is_empty() {
+        let repo = TeamMemberRepositoryImpl::new(create_test_db_pool());
+        let members = repo
+            .list_members(Uuid::new_v4())
+            .await
+            .expect("list should not fail");
+        assert!(members.is_empty());
+    }
+    #[tokio::test]
+    async fn test_list_memberships_of_unknown_email_is_empty() {
+        let repo = TeamMemberRepositoryImpl::new(create_test_db_pool());
+        let memberships = repo
+            .list_memberships_by_email("nobody@example.invalid")
+            .await
+            .expect("list should not fail");
+        assert!(memberships.is_empty());
+    }
+}
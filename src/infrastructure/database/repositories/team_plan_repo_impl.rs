@@ -0,0 +1,199 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! Team plan repository implementation using Sea-ORM
+
+use crate::common::time_utils;
+use crate::domain::models::{TeamPlan, TeamPlanAssignment};
+use crate::domain::repositories::task_repository::RepositoryError;
+use crate::domain::repositories::team_plan_repository::TeamPlanRepository;
+use crate::infrastructure::database::entities::team;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use dbnexus::DbPool;
+use log::warn;
+use sea_orm::sea_query::{Condition, Expr};
+use sea_orm::ActiveValue::Set;
+use sea_orm::{ActiveModelTrait, ColumnTrait, EntityTrait, QueryFilter, QueryOrder, QuerySelect};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Team plan repository implementation backed by the `teams` table
+#[derive(Clone)]
+pub struct TeamPlanRepositoryImpl {
+    /// Database pool
+    pool: Arc<DbPool>,
+}
+
+impl TeamPlanRepositoryImpl {
+    /// Create new team plan repository instance
+    pub fn new(pool: Arc<DbPool>) -> Self {
+        Self { pool }
+    }
+}
+
+/// Convert a `teams` row into a plan assignment; unknown plans fall back to free
+fn to_assignment(model: &team::Model) -> TeamPlanAssignment {
+    let plan = model.plan.parse::<TeamPlan>().unwrap_or_else(|e| {
+        warn!("{} for team {}, treating it as free", e, model.id);
+        TeamPlan::Free
+    });
+    TeamPlanAssignment {
+        team_id: model.id,
+        plan,
+        credits_granted_at: model
+            .plan_credits_granted_at
+            .map(|granted_at| granted_at.with_timezone(&Utc)),
+    }
+}
+
+/// Teams whose monthly credits have not been granted since `period_start`
+fn grant_due(period_start: DateTime<Utc>) -> Condition {
+    Condition::any()
+        .add(team::Column::PlanCreditsGrantedAt.is_null())
+        .add(
+            team::Column::PlanCreditsGrantedAt
+                .lt(period_start.with_timezone(&time_utils::UTC_OFFSET)),
+        )
+}
+
+#[async_trait]
+impl TeamPlanRepository for TeamPlanRepositoryImpl {
+    async fn get_plan(&self, team_id: Uuid) -> Result<TeamPlanAssignment, RepositoryError> {
+        let session = self
+            .pool
+            .get_session("admin")
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        let model = team::Entity::find_by_id(team_id)
+            .one(
+                session
+                    .connection()
+                    .map_err(|e| RepositoryError::Database(e.into()))?,
+            )
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?
+            .ok_or(RepositoryError::NotFound)?;
+
+        Ok(to_assignment(&model))
+    }
+
+    async fn set_plan(
+        &self,
+        team_id: Uuid,
+        plan: TeamPlan,
+    ) -> Result<TeamPlanAssignment, RepositoryError> {
+        let session = self
+            .pool
+            .get_session("admin")
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+        let conn = session
+            .connection()
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        let model = team::Entity::find_by_id(team_id)
+            .one(conn)
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?
+            .ok_or(RepositoryError::NotFound)?;
+
+        let mut active_model: team::ActiveModel = model.into();
+        active_model.plan = Set(plan.to_string());
+        active_model.updated_at = Set(Utc::now().into());
+        let model = active_model
+            .update(conn)
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        Ok(to_assignment(&model))
+    }
+
+    async fn find_due_for_credit_grant(
+        &self,
+        period_start: DateTime<Utc>,
+        limit: u64,
+    ) -> Result<Vec<TeamPlanAssignment>, RepositoryError> {
+        let session = self
+            .pool
+            .get_session("admin")
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        let models = team::Entity::find()
+            .filter(grant_due(period_start))
+            .order_by_asc(team::Column::CreatedAt)
+            .limit(limit)
+            .all(
+                session
+                    .connection()
+                    .map_err(|e| RepositoryError::Database(e.into()))?,
+            )
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        Ok(models.iter().map(to_assignment).collect())
+    }
+
+    async fn claim_credit_grant(
+        &self,
+        team_id: Uuid,
+        period_start: DateTime<Utc>,
+        granted_at: DateTime<Utc>,
+    ) -> Result<bool, RepositoryError> {
+        let session = self
+            .pool
+            .get_session("admin")
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        // 条件更新：只有本期尚未发放时才写入发放时间，多个实例并发时只有一个成功
+        let result = team::Entity::update_many()
+            .col_expr(
+                team::Column::PlanCreditsGrantedAt,
+                Expr::value(granted_at.with_timezone(&time_utils::UTC_OFFSET)),
+            )
+            .filter(team::Column::Id.eq(team_id))
+            .filter(grant_due(period_start))
+            .exec(
+                session
+                    .connection()
+                    .map_err(|e| RepositoryError::Database(e.into()))?,
+            )
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        Ok(result.rows_affected == 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::test_helpers::create_test_db_pool;
+
+    #[tokio::test]
+    async fn test_get_plan_returns_not_found_for_unknown_team() {
+        let repo = TeamPlanRepositoryImpl::new(create_test_db_pool());
+        let result = repo.get_plan(Uuid::new_v4()).await;
+        assert!(
+            matches!(result, Err(RepositoryError::NotFound)),
+            "expected NotFound, got {:?}",
+            result
+        );
+    }
+
+    #[tokio::test]
+    async fn test_claim_credit_grant_for_unknown_team_claims_nothing() {
+        let repo = TeamPlanRepositoryImpl::new(create_test_db_pool());
+        let now = Utc::now();
+        let claimed = repo
+            .claim_credit_grant(Uuid::new_v4(), now, now)
+            .await
+            .expect("claim should not fail");
+        assert!(!claimed);
+    }
+}
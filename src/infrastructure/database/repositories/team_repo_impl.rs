@@ -0,0 +1,115 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! Team repository implementation using Sea-ORM
+
+use crate::common::time_utils;
+use crate::domain::models::Team;
+use crate::domain::repositories::task_repository::RepositoryError;
+use crate::domain::repositories::team_repository::TeamRepository;
+use crate::infrastructure::database::entities::team;
+use async_trait::async_trait;
+use chrono::Utc;
+use dbnexus::DbPool;
+use sea_orm::ActiveValue::Set;
+use sea_orm::{ActiveModelTrait, EntityTrait};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Team repository implementation backed by the `teams` table
+#[derive(Clone)]
+pub struct TeamRepositoryImpl {
+    /// Database pool
+    pool: Arc<DbPool>,
+}
+
+impl TeamRepositoryImpl {
+    /// Create new team repository instance
+    pub fn new(pool: Arc<DbPool>) -> Self {
+        Self { pool }
+    }
+}
+
+fn to_team(model: team::Model) -> Team {
+    Team::with_timestamps(
+        model.id,
+        model.name,
+        model.created_at.with_timezone(&Utc),
+        model.updated_at.with_timezone(&Utc),
+    )
+}
+
+#[async_trait]
+impl TeamRepository for TeamRepositoryImpl {
+    async fn create(&self, team: &Team) -> Result<Team, RepositoryError> {
+        let session = self
+            .pool
+            .get_session("admin")
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        // 地理限制、能力与套餐字段使用数据库默认值
+        let active_model = team::ActiveModel {
+            id: Set(team.id),
+            name: Set(team.name.clone()),
+            created_at: Set(team.created_at.with_timezone(&time_utils::UTC_OFFSET)),
+            updated_at: Set(team.updated_at.with_timezone(&time_utils::UTC_OFFSET)),
+            ..Default::default()
+        };
+        let model = active_model
+            .insert(
+                session
+                    .connection()
+                    .map_err(|e| RepositoryError::Database(e.into()))?,
+            )
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        Ok(to_team(model))
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Team>, RepositoryError> {
+        let session = self
+            .pool
+            .get_session("admin")
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        let model = team::Entity::find_by_id(id)
+            .one(
+                session
+                    .connection()
+                    .map_err(|e| RepositoryError::Database(e.into()))?,
+            )
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        Ok(model.map(to_team))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::test_helpers::create_test_db_pool;
+
+    #[tokio::test]
+    async fn test_create_then_find_team() {
+        let repo = TeamRepositoryImpl::new(create_test_db_pool());
+        let team = Team::new(Uuid::new_v4(), "Repo Test Team".to_string());
+
+        let created = repo.create(&team).await.expect("create should succeed");
+        assert_eq!(created.id, team.id);
+        assert_eq!(created.name, "Repo Test Team");
+
+        let found = repo
+            .find_by_id(team.id)
+            .await
+            .expect("find should succeed")
+            .expect("team should exist");
+        assert_eq!(found.name, "Repo Test Team");
+        assert!(repo.find_by_id(Uuid::new_v4()).await.unwrap().is_none());
+    }
+}
@@ -0,0 +1,153 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! URL blocklist repository implementation using Sea-ORM with Mapper
+
+use crate::domain::models::BlocklistEntry;
+use crate::domain::repositories::task_repository::RepositoryError;
+use crate::domain::repositories::url_blocklist_repository::UrlBlocklistRepository;
+use crate::infrastructure::database::entities::url_blocklist;
+use crate::infrastructure::persistence::mappers::UrlBlocklistMapper;
+use async_trait::async_trait;
+use dbnexus::DbPool;
+use sea_orm::ActiveValue::Set;
+use sea_orm::{ColumnTrait, Condition, EntityTrait, QueryFilter, QueryOrder};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// URL blocklist repository implementation using Sea-ORM
+#[derive(Clone)]
+pub struct UrlBlocklistRepositoryImpl {
+    /// Database pool
+    pool: Arc<DbPool>,
+}
+
+impl UrlBlocklistRepositoryImpl {
+    /// Create new URL blocklist repository instance
+    pub fn new(pool: Arc<DbPool>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl UrlBlocklistRepository for UrlBlocklistRepositoryImpl {
+    async fn create(&self, entry: &BlocklistEntry) -> Result<BlocklistEntry, RepositoryError> {
+        let session = self
+            .pool
+            .get_session("admin")
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        let entity = UrlBlocklistMapper::to_entity(entry);
+        let active_model = url_blocklist::ActiveModel {
+            id: Set(entity.id),
+            team_id: Set(entity.team_id),
+            pattern: Set(entity.pattern),
+            pattern_type: Set(entity.pattern_type),
+            reason: Set(entity.reason),
+            created_at: Set(entity.created_at),
+        };
+
+        url_blocklist::Entity::insert(active_model)
+            .exec(
+                session
+                    .connection()
+                    .map_err(|e| RepositoryError::Database(e.into()))?,
+            )
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        Ok(entry.clone())
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<BlocklistEntry>, RepositoryError> {
+        let session = self
+            .pool
+            .get_session("admin")
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        let entity = url_blocklist::Entity::find_by_id(id)
+            .one(
+                session
+                    .connection()
+                    .map_err(|e| RepositoryError::Database(e.into()))?,
+            )
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        Ok(entity.and_then(UrlBlocklistMapper::to_domain))
+    }
+
+    async fn find_all(&self) -> Result<Vec<BlocklistEntry>, RepositoryError> {
+        let session = self
+            .pool
+            .get_session("admin")
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        let entities = url_blocklist::Entity::find()
+            .order_by_asc(url_blocklist::Column::CreatedAt)
+            .all(
+                session
+                    .connection()
+                    .map_err(|e| RepositoryError::Database(e.into()))?,
+            )
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        Ok(entities
+            .into_iter()
+            .filter_map(UrlBlocklistMapper::to_domain)
+            .collect())
+    }
+
+    async fn find_for_team(&self, team_id: Uuid) -> Result<Vec<BlocklistEntry>, RepositoryError> {
+        let session = self
+            .pool
+            .get_session("admin")
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        let entities = url_blocklist::Entity::find()
+            .filter(
+                Condition::any()
+                    .add(url_blocklist::Column::TeamId.is_null())
+                    .add(url_blocklist::Column::TeamId.eq(team_id)),
+            )
+            .order_by_asc(url_blocklist::Column::CreatedAt)
+            .all(
+                session
+                    .connection()
+                    .map_err(|e| RepositoryError::Database(e.into()))?,
+            )
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        Ok(entities
+            .into_iter()
+            .filter_map(UrlBlocklistMapper::to_domain)
+            .collect())
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<bool, RepositoryError> {
+        let session = self
+            .pool
+            .get_session("admin")
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        let result = url_blocklist::Entity::delete_by_id(id)
+            .exec(
+                session
+                    .connection()
+                    .map_err(|e| RepositoryError::Database(e.into()))?,
+            )
+            .await
+            .map_err(|e| RepositoryError::Database(e.into()))?;
+
+        Ok(result.rows_affected > 0)
+    }
+}
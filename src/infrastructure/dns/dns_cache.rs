@@ -7,11 +7,27 @@
 
 use crate::infrastructure::oxcache::{generate_dns_key, DnsCache, DnsCacheEntry};
 use log::{debug, warn};
+use once_cell::sync::OnceCell;
 use std::net::IpAddr;
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::net::lookup_host;
 
+/// 进程内共享的 DNS 缓存，启动时安装一次，供各引擎的 resolver 与 SSRF 校验共用
+static SHARED_DNS_CACHE: OnceCell<Arc<DnsCacheService>> = OnceCell::new();
+
+/// 安装进程内共享的 DNS 缓存
+///
+/// 只有第一次安装生效，已安装时返回 false。
+pub fn install_shared_dns_cache(cache: Arc<DnsCacheService>) -> bool {
+    SHARED_DNS_CACHE.set(cache).is_ok()
+}
+
+/// 获取进程内共享的 DNS 缓存，未安装（缓存关闭）时返回 None
+pub fn shared_dns_cache() -> Option<Arc<DnsCacheService>> {
+    SHARED_DNS_CACHE.get().cloned()
+}
+
 /// 线程安全的DNS缓存（使用 oxcache）
 #[derive(Debug, Clone)]
 pub struct DnsCacheService {
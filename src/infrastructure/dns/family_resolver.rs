@@ -0,0 +1,167 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! 按地址族过滤的 DNS resolver.
+//!
+//! 默认的 [`Ipv4OnlyResolver`](super::Ipv4OnlyResolver) 只返回 IPv4 地址。部分目标站点
+//! 只在 IPv6 上可用，或需要双栈回退，本 resolver 按 [`AddressFamily`] 过滤解析结果：
+//! `Ipv6` 只保留 IPv6，`HappyEyeballs` 保留全部地址，由 hyper 的连接器按
+//! RFC 8305 交错尝试两个地址族。
+
+use std::net::{IpAddr, SocketAddr};
+use std::sync::Arc;
+
+use reqwest::dns::{Addrs, Name, Resolve, Resolving};
+
+use super::dns_cache::DnsCacheService;
+use crate::engines::engine_client::AddressFamily;
+
+/// 按地址族过滤解析结果的 resolver.
+///
+/// 与 `Ipv4OnlyResolver` 一样优先查 DNS 缓存，未命中或缓存失败时走系统 DNS。
+#[derive(Debug, Clone)]
+pub struct AddressFamilyResolver {
+    family: AddressFamily,
+    dns_cache: Option<Arc<DnsCacheService>>,
+}
+
+impl AddressFamilyResolver {
+    /// 创建无缓存的 resolver.
+    pub fn new(family: AddressFamily) -> Self {
+        Self {
+            family,
+            dns_cache: None,
+        }
+    }
+
+    /// 创建带 DNS 缓存的 resolver.
+    pub fn with_cache(family: AddressFamily, dns_cache: Arc<DnsCacheService>) -> Self {
+        Self {
+            family,
+            dns_cache: Some(dns_cache),
+        }
+    }
+}
+
+/// 按地址族过滤，端口置 0（reqwest 会根据 scheme 替换为正确端口）
+fn filter_addrs(family: AddressFamily, ips: impl IntoIterator<Item = IpAddr>) -> Vec<SocketAddr> {
+    ips.into_iter()
+        .filter(|ip| family.allows(*ip))
+        .map(|ip| SocketAddr::new(ip, 0))
+        .collect()
+}
+
+impl Resolve for AddressFamilyResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        let host = name.as_str().to_string();
+        let family = self.family;
+        let cache = self.dns_cache.clone();
+        Box::pin(async move {
+            if let Some(cache) = cache {
+                match cache.lookup_host(&host, 0).await {
+                    Ok(ips) => {
+                        let addrs = filter_addrs(family, ips);
+                        log::debug!(
+                            "AddressFamilyResolver ({:?}) resolved {} -> {} addrs (cached)",
+                            family,
+                            host,
+                            addrs.len()
+                        );
+                        return Ok(Box::new(addrs.into_iter()) as Addrs);
+                    }
+                    Err(e) => {
+                        log::warn!(
+                            "DNS cache lookup failed for {}: {}, falling back to system DNS",
+                            host,
+                            e
+                        );
+                    }
+                }
+            }
+
+            let resolved = tokio::net::lookup_host(format!("{}:0", host))
+                .await
+                .map_err(|e| Box::new(e) as Box<dyn std::error::Error + Send + Sync>)?;
+            let addrs = filter_addrs(family, resolved.map(|addr| addr.ip()));
+            // 安全：只输出数量，不输出 IP 地址本身
+            log::debug!(
+                "AddressFamilyResolver ({:?}) resolved {} -> {} addrs",
+                family,
+                host,
+                addrs.len()
+            );
+            Ok(Box::new(addrs.into_iter()) as Addrs)
+        })
+    }
+}
+
+/// 创建指定地址族的 resolver，使用进程内共享 DNS 缓存（未安装时不带缓存）.
+///
+/// `Ipv4` 返回 [`Ipv4OnlyResolver`](super::Ipv4OnlyResolver)，与默认 HTTP 客户端保持一致。
+pub fn create_shared_family_resolver(family: AddressFamily) -> Arc<dyn Resolve> {
+    if family == AddressFamily::Ipv4 {
+        return super::ipv4_resolver::create_shared_ipv4_only_resolver();
+    }
+    match super::dns_cache::shared_dns_cache() {
+        Some(cache) => Arc::new(AddressFamilyResolver::with_cache(family, cache)),
+        None => Arc::new(AddressFamilyResolver::new(family)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_filter_addrs_by_family() {
+        let ips: Vec<IpAddr> = vec![
+            "203.0.113.5".parse().unwrap(),
+            "2001:db8::1".parse().unwrap(),
+        ];
+        let v6 = filter_addrs(AddressFamily::Ipv6, ips.clone());
+        assert_eq!(v6.len(), 1);
+        assert!(v6[0].is_ipv6());
+        assert_eq!(v6[0].port(), 0);
+
+        let v4 = filter_addrs(AddressFamily::Ipv4, ips.clone());
+        assert_eq!(v4.len(), 1);
+        assert!(v4[0].is_ipv4());
+
+        assert_eq!(filter_addrs(AddressFamily::HappyEyeballs, ips).len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_resolve_ipv6_only_with_cache() {
+        let cache = Arc::new(
+            oxcache::Cache::builder()
+                .capacity(100)
+                .ttl(std::time::Duration::from_secs(300))
+                .build()
+                .await
+                .unwrap(),
+        );
+        let dns_cache = Arc::new(DnsCacheService::new(cache, 300));
+        let v4: IpAddr = "203.0.113.5".parse().unwrap();
+        let v6: IpAddr = "2001:db8::1".parse().unwrap();
+        dns_cache.set("example.com", 0, vec![v4, v6], 300).await;
+
+        let resolver = AddressFamilyResolver::with_cache(AddressFamily::Ipv6, dns_cache.clone());
+        let addrs: Vec<_> = resolver
+            .resolve(Name::from_str("example.com").unwrap())
+            .await
+            .unwrap()
+            .collect();
+        assert_eq!(addrs, vec![SocketAddr::new(v6, 0)]);
+
+        let resolver = AddressFamilyResolver::with_cache(AddressFamily::HappyEyeballs, dns_cache);
+        let addrs: Vec<_> = resolver
+            .resolve(Name::from_str("example.com").unwrap())
+            .await
+            .unwrap()
+            .collect();
+        assert_eq!(addrs.len(), 2);
+    }
+}
@@ -114,6 +114,14 @@ pub fn create_ipv4_only_resolver_with_cache(dns_cache: Arc<DnsCacheService>) ->
     Arc::new(Ipv4OnlyResolver::with_cache(dns_cache))
 }
 
+/// 创建使用进程内共享 DNS 缓存的 IPv4-only resolver，未安装共享缓存时不带缓存.
+pub fn create_shared_ipv4_only_resolver() -> Arc<dyn Resolve> {
+    match super::dns_cache::shared_dns_cache() {
+        Some(cache) => create_ipv4_only_resolver_with_cache(cache),
+        None => create_ipv4_only_resolver(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
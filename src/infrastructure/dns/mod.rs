@@ -5,9 +5,12 @@
 
 /// DNS基础设施模块
 pub mod dns_cache;
+pub mod family_resolver;
 pub mod ipv4_resolver;
 
-pub use dns_cache::{DnsCacheService, DnsCacheStats};
+pub use dns_cache::{install_shared_dns_cache, shared_dns_cache, DnsCacheService, DnsCacheStats};
+pub use family_resolver::{create_shared_family_resolver, AddressFamilyResolver};
 pub use ipv4_resolver::{
-    create_ipv4_only_resolver, create_ipv4_only_resolver_with_cache, Ipv4OnlyResolver,
+    create_ipv4_only_resolver, create_ipv4_only_resolver_with_cache,
+    create_shared_ipv4_only_resolver, Ipv4OnlyResolver,
 };
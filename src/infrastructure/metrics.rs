@@ -11,3 +11,186 @@
 /// 该模块已统一到 observability/metrics,此处为向后兼容提供的重导出
 #[cfg(feature = "metrics")]
 pub use crate::infrastructure::observability::metrics::init_metrics;
+
+#[cfg(feature = "metrics")]
+use metrics::{counter, gauge, histogram};
+use std::time::Duration;
+
+/// 记录一次引擎抓取请求的耗时与结果
+///
+/// 按引擎名（`reqwest` / `playwright` / `fire_engine_tls` / `fire_engine_cdp` 等）
+/// 打标签；`error_kind` 为 `None` 表示成功。未启用 `metrics` 特性时为空操作。
+#[cfg_attr(not(feature = "metrics"), allow(unused_variables))]
+pub fn record_engine_request(
+    engine: &'static str,
+    duration: Duration,
+    error_kind: Option<&'static str>,
+) {
+    #[cfg(feature = "metrics")]
+    {
+        let outcome = if error_kind.is_some() {
+            "failure"
+        } else {
+            "success"
+        };
+        histogram!(
+            "engine_request_duration_seconds",
+            "engine" => engine,
+            "outcome" => outcome
+        )
+        .record(duration.as_secs_f64());
+        counter!("engine_requests_total", "engine" => engine, "outcome" => outcome).increment(1);
+        if let Some(kind) = error_kind {
+            counter!("engine_errors_total", "engine" => engine, "kind" => kind).increment(1);
+        }
+    }
+}
+
+/// 记录一次路由降级：`engine` 失败后路由切换到下一个候选引擎
+#[cfg_attr(not(feature = "metrics"), allow(unused_variables))]
+pub fn record_engine_fallback(engine: &str, error_kind: &'static str) {
+    #[cfg(feature = "metrics")]
+    counter!(
+        "engine_router_fallbacks_total",
+        "engine" => engine.to_string(),
+        "kind" => error_kind
+    )
+    .increment(1);
+}
+
+/// 记录一次熔断器跳闸（状态切换为 Open）
+#[cfg_attr(not(feature = "metrics"), allow(unused_variables))]
+pub fn record_circuit_breaker_trip(engine: &str) {
+    #[cfg(feature = "metrics")]
+    counter!("circuit_breaker_trips_total", "engine" => engine.to_string()).increment(1);
+}
+
+/// 记录浏览器池的实例占用：使用中与空闲实例数，以及占实例上限的比例
+#[cfg_attr(not(feature = "metrics"), allow(unused_variables))]
+pub fn record_browser_pool_usage(in_use: usize, available: usize, max_instances: usize) {
+    #[cfg(feature = "metrics")]
+    {
+        gauge!("browser_pool_instances", "state" => "in_use").set(in_use as f64);
+        gauge!("browser_pool_instances", "state" => "available").set(available as f64);
+        let utilization = if max_instances == 0 {
+            0.0
+        } else {
+            in_use as f64 / max_instances as f64
+        };
+        gauge!("browser_pool_utilization_ratio").set(utilization);
+    }
+}
+
+/// 记录一次从浏览器池获取实例的耗时，`warm` 表示复用了已启动的实例
+#[cfg_attr(not(feature = "metrics"), allow(unused_variables))]
+pub fn record_browser_pool_acquire(duration: Duration, warm: bool) {
+    #[cfg(feature = "metrics")]
+    histogram!(
+        "browser_pool_acquire_duration_seconds",
+        "source" => if warm { "warm" } else { "cold" }
+    )
+    .record(duration.as_secs_f64());
+}
+
+/// 记录一次浏览器实例回收（`page_limit` / `memory` / `unhealthy` / `idle`）
+#[cfg_attr(not(feature = "metrics"), allow(unused_variables))]
+pub fn record_browser_recycled(reason: &'static str) {
+    #[cfg(feature = "metrics")]
+    counter!("browser_pool_recycled_total", "reason" => reason).increment(1);
+}
+
+/// 记录一次 robots.txt 缓存查询（`memory_hit` / `shared_hit` / `miss`）
+#[cfg_attr(not(feature = "metrics"), allow(unused_variables))]
+pub fn record_robots_cache_lookup(result: &'static str) {
+    #[cfg(feature = "metrics")]
+    counter!("robots_cache_lookups_total", "result" => result).increment(1);
+}
+
+/// 记录一次 robots.txt 抓取失败（重试耗尽后按允许全部处理）
+pub fn record_robots_fetch_error() {
+    #[cfg(feature = "metrics")]
+    counter!("robots_fetch_errors_total").increment(1);
+}
+
+/// 记录一次被拦截后的引擎升级尝试，`outcome` 为 `succeeded` / `blocked` / `failed`
+#[cfg_attr(not(feature = "metrics"), allow(unused_variables))]
+pub fn record_block_escalation(step: &'static str, outcome: &'static str) {
+    #[cfg(feature = "metrics")]
+    counter!("engine_block_escalations_total", "step" => step, "outcome" => outcome).increment(1);
+}
+
+/// 记录一次代理健康检查，`result` 为 `healthy` 或失败原因（`unreachable` /
+/// `country_mismatch` / `banned`）；拿到出口 IP 时同时记录延迟
+#[cfg_attr(not(feature = "metrics"), allow(unused_variables))]
+pub fn record_proxy_health_check(result: &'static str, latency: Option<Duration>) {
+    #[cfg(feature = "metrics")]
+    {
+        counter!("proxy_health_checks_total", "result" => result).increment(1);
+        if let Some(latency) = latency {
+            histogram!("proxy_probe_latency_seconds").record(latency.as_secs_f64());
+        }
+    }
+}
+
+/// 记录代理池中可用与不可用的代理数
+#[cfg_attr(not(feature = "metrics"), allow(unused_variables))]
+pub fn record_proxy_pool_size(active: usize, inactive: usize) {
+    #[cfg(feature = "metrics")]
+    {
+        gauge!("proxy_pool_proxies", "state" => "active").set(active as f64);
+        gauge!("proxy_pool_proxies", "state" => "inactive").set(inactive as f64);
+    }
+}
+
+/// 记录代理提供商刷新结果
+#[cfg_attr(not(feature = "metrics"), allow(unused_variables))]
+pub fn record_proxy_provider_refresh(provider: &str, success: bool) {
+    #[cfg(feature = "metrics")]
+    {
+        let outcome = if success { "success" } else { "failure" };
+        counter!(
+            "proxy_provider_refreshes_total",
+            "provider" => provider.to_string(),
+            "outcome" => outcome
+        )
+        .increment(1);
+    }
+}
+
+/// 记录引擎响应的下载字节数
+#[cfg_attr(not(feature = "metrics"), allow(unused_variables))]
+pub fn record_bandwidth_downloaded(bytes: u64) {
+    #[cfg(feature = "metrics")]
+    counter!("bandwidth_downloaded_bytes_total").increment(bytes);
+}
+
+/// 记录因团队达到每日下载上限而失败的任务
+pub fn record_bandwidth_cap_rejection() {
+    #[cfg(feature = "metrics")]
+    counter!("bandwidth_cap_rejections_total").increment(1);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_engine_metric_helpers_without_recorder_are_noops() {
+        record_engine_request("reqwest", Duration::from_millis(120), None);
+        record_engine_request("playwright", Duration::from_secs(2), Some("timeout"));
+        record_engine_fallback("reqwest", "request_failed");
+        record_circuit_breaker_trip("fire_engine_tls");
+        record_browser_pool_usage(2, 1, 5);
+        record_browser_pool_usage(0, 0, 0);
+        record_browser_pool_acquire(Duration::from_millis(3), true);
+        record_browser_recycled("page_limit");
+        record_robots_cache_lookup("memory_hit");
+        record_robots_fetch_error();
+        record_block_escalation("browser", "succeeded");
+        record_proxy_health_check("healthy", Some(Duration::from_millis(80)));
+        record_proxy_health_check("unreachable", None);
+        record_proxy_pool_size(2, 1);
+        record_proxy_provider_refresh("webshare", true);
+        record_proxy_provider_refresh("brightdata", false);
+    }
+}
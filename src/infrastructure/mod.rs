@@ -14,6 +14,7 @@
 /// - 指标（metrics）：提供系统监控和性能指标收集
 /// - 安全（security）：提供安全相关的功能，如API Key哈希
 /// - 缓存（oxcache）：基于 oxcache 组件的统一缓存实现
+/// - 对象存储（storage）：保存下载模式抓取的二进制资源
 ///
 /// 基础设施层遵循依赖倒置原则，依赖于领域层的抽象接口，
 /// 确保领域层保持纯粹的业务逻辑，不受技术实现的影响。
@@ -28,3 +29,4 @@ pub mod persistence;
 pub mod security;
 pub use database::repositories;
 pub mod services;
+pub mod storage;
@@ -319,6 +319,56 @@ pub fn init_metrics() {
         "crawl_duration_seconds",
         "Duration of crawl tasks in seconds"
     );
+    describe_histogram!(
+        "worker_hot_path_duration_seconds",
+        "Per-task state operation latency on the worker hot path, labelled by operation"
+    );
+
+    // Adaptive Domain Throttling Metrics
+    describe_counter!(
+        "domain_throttle_events_total",
+        "Total number of upstream 429/503 responses that extended a domain throttle, labelled by status"
+    );
+    describe_counter!(
+        "domain_throttle_deferred_tasks_total",
+        "Total number of tasks deferred because their target domain was throttled"
+    );
+
+    // Engine Metrics
+    describe_histogram!(
+        "engine_request_duration_seconds",
+        "Scrape request latency per engine in seconds, labelled by engine and outcome"
+    );
+    describe_counter!(
+        "engine_requests_total",
+        "Total number of scrape requests per engine, labelled by engine and outcome"
+    );
+    describe_counter!(
+        "engine_errors_total",
+        "Total number of failed scrape requests per engine, labelled by engine and error kind"
+    );
+    describe_counter!(
+        "engine_router_fallbacks_total",
+        "Total number of times the router fell back to the next engine after a retryable error"
+    );
+
+    // Browser Pool Metrics
+    describe_gauge!(
+        "browser_pool_instances",
+        "Number of pooled browser instances, labelled by state (in_use or available)"
+    );
+    describe_gauge!(
+        "browser_pool_utilization_ratio",
+        "Share of the browser pool's instance limit currently in use (0.0 to 1.0)"
+    );
+    describe_histogram!(
+        "browser_pool_acquire_duration_seconds",
+        "Time to obtain a browser from the pool, labelled by source (warm or cold)"
+    );
+    describe_counter!(
+        "browser_pool_recycled_total",
+        "Total number of pooled browser instances closed, labelled by reason"
+    );
 
     // Circuit Breaker Metrics
     describe_counter!(
@@ -333,6 +383,10 @@ pub fn init_metrics() {
         "circuit_breaker_successes_total",
         "Total number of successful requests recorded by circuit breaker"
     );
+    describe_counter!(
+        "circuit_breaker_trips_total",
+        "Total number of times a circuit breaker transitioned to Open"
+    );
     describe_counter!(
         "circuit_breaker_rejected_total",
         "Total number of requests rejected by open circuit breaker"
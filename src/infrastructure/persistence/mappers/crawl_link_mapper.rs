@@ -0,0 +1,81 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! Crawl link Mapper - converts between CrawlLink and database entity
+
+use crate::common::time_utils::{from_db_datetime, to_db_datetime};
+use crate::domain::models::CrawlLink;
+use crate::infrastructure::database::entities::crawl_link;
+
+/// Mapper for converting between CrawlLink domain model and database entity
+pub struct CrawlLinkMapper;
+
+impl CrawlLinkMapper {
+    /// Convert database entity to domain model
+    pub fn to_domain(entity: crawl_link::Model) -> CrawlLink {
+        CrawlLink {
+            crawl_id: entity.crawl_id,
+            source_url: entity.source_url,
+            target_url: entity.target_url,
+            anchor_text: entity.anchor_text,
+            rel: entity
+                .rel
+                .map(|rel| rel.split_whitespace().map(str::to_string).collect())
+                .unwrap_or_default(),
+            created_at: from_db_datetime(entity.created_at),
+        }
+    }
+
+    /// Convert domain model to database entity (rel values are stored space separated, NULL when empty)
+    pub fn to_entity(domain: &CrawlLink) -> crawl_link::Model {
+        crawl_link::Model {
+            crawl_id: domain.crawl_id,
+            source_url: domain.source_url.clone(),
+            target_url: domain.target_url.clone(),
+            anchor_text: domain.anchor_text.clone(),
+            rel: (!domain.rel.is_empty()).then(|| domain.rel.join(" ")),
+            created_at: to_db_datetime(domain.created_at),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    #[test]
+    fn test_crawl_link_mapper_roundtrip() {
+        let domain = CrawlLink::new(
+            Uuid::new_v4(),
+            "https://example.com/",
+            "https://example.com/about",
+            Some("About us".to_string()),
+            vec!["nofollow".to_string(), "noopener".to_string()],
+        );
+
+        let entity = CrawlLinkMapper::to_entity(&domain);
+        assert_eq!(entity.rel.as_deref(), Some("nofollow noopener"));
+
+        let back_to_domain = CrawlLinkMapper::to_domain(entity);
+        assert_eq!(domain.rel, back_to_domain.rel);
+        assert_eq!(domain.anchor_text, back_to_domain.anchor_text);
+        assert_eq!(domain.target_url, back_to_domain.target_url);
+    }
+
+    #[test]
+    fn test_empty_rel_is_stored_as_null() {
+        let domain = CrawlLink::new(
+            Uuid::new_v4(),
+            "https://example.com/",
+            "https://example.com/about",
+            None,
+            vec![],
+        );
+        let entity = CrawlLinkMapper::to_entity(&domain);
+        assert!(entity.rel.is_none());
+        assert!(CrawlLinkMapper::to_domain(entity).rel.is_empty());
+    }
+}
@@ -201,6 +201,7 @@ mod tests {
             CrawlStatus::Queued,
             CrawlStatus::Processing,
             CrawlStatus::Completed,
+            CrawlStatus::CompletedWithLimit,
             CrawlStatus::Failed,
             CrawlStatus::Cancelled,
         ];
@@ -6,8 +6,8 @@
 //! Credits Mapper - converts between Credits domain model and database entity
 
 use crate::common::time_utils::{from_db_datetime, to_db_datetime};
-use crate::domain::models::{Credits, CreditsTransaction, CreditsTransactionType};
-use crate::infrastructure::database::entities::{credits, credits_transactions};
+use crate::domain::models::{Credits, CreditsTransaction, CreditsTransactionType, LowBalanceAlert};
+use crate::infrastructure::database::entities::{credit_alert, credits, credits_transactions};
 
 /// Mapper for converting between Credits domain model and database entity
 pub struct CreditsMapper;
@@ -83,6 +83,22 @@ impl CreditsTransactionMapper {
     }
 }
 
+/// Mapper for converting a credit_alerts row into the LowBalanceAlert domain model
+pub struct LowBalanceAlertMapper;
+
+impl LowBalanceAlertMapper {
+    /// Convert database entity to domain model
+    pub fn to_domain(entity: credit_alert::Model) -> LowBalanceAlert {
+        LowBalanceAlert {
+            team_id: entity.team_id,
+            threshold: entity.threshold,
+            email: entity.email,
+            alerted: entity.alerted,
+            alerted_at: entity.alerted_at.map(from_db_datetime),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -150,6 +166,26 @@ mod tests {
         assert_eq!(domains[1].balance(), 200);
     }
 
+    #[test]
+    fn test_low_balance_alert_mapper_to_domain() {
+        let now_db = to_db_datetime(Utc::now());
+        let entity = credit_alert::Model {
+            team_id: Uuid::new_v4(),
+            threshold: 500,
+            email: Some("ops@example.com".to_string()),
+            alerted: true,
+            alerted_at: Some(now_db),
+            created_at: now_db,
+            updated_at: now_db,
+        };
+        let alert = LowBalanceAlertMapper::to_domain(entity.clone());
+        assert_eq!(alert.team_id, entity.team_id);
+        assert_eq!(alert.threshold, 500);
+        assert_eq!(alert.email.as_deref(), Some("ops@example.com"));
+        assert!(alert.alerted);
+        assert!(alert.alerted_at.is_some());
+    }
+
     #[test]
     fn test_credits_mapper_to_domain_list_empty() {
         let domains = CreditsMapper::to_domain_list(vec![]);
@@ -0,0 +1,56 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! Domain Throttle Mapper - converts between DomainThrottle domain model and database entity
+
+use crate::common::time_utils::{from_db_datetime, to_db_datetime};
+use crate::domain::models::DomainThrottle;
+use crate::infrastructure::database::entities::domain_throttle;
+
+/// Mapper for converting between DomainThrottle domain model and database entity
+pub struct DomainThrottleMapper;
+
+impl DomainThrottleMapper {
+    /// Convert database entity to domain model
+    pub fn to_domain(entity: domain_throttle::Model) -> DomainThrottle {
+        DomainThrottle {
+            domain: entity.domain,
+            consecutive_errors: entity.consecutive_errors.max(0) as u32,
+            last_status: u16::try_from(entity.last_status).unwrap_or(0),
+            throttled_until: from_db_datetime(entity.throttled_until),
+            updated_at: from_db_datetime(entity.updated_at),
+        }
+    }
+
+    /// Convert domain model to database entity
+    pub fn to_entity(domain: &DomainThrottle) -> domain_throttle::Model {
+        domain_throttle::Model {
+            domain: domain.domain.clone(),
+            consecutive_errors: domain.consecutive_errors.min(i32::MAX as u32) as i32,
+            last_status: i32::from(domain.last_status),
+            throttled_until: to_db_datetime(domain.throttled_until),
+            updated_at: to_db_datetime(domain.updated_at),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::ThrottlePolicy;
+    use chrono::Utc;
+
+    #[test]
+    fn test_domain_throttle_mapper_roundtrip() {
+        let mut domain = DomainThrottle::new("example.com");
+        domain.record_error(429, Some(30), &ThrottlePolicy::default(), Utc::now());
+
+        let entity = DomainThrottleMapper::to_entity(&domain);
+        assert_eq!(entity.last_status, 429);
+
+        let back_to_domain = DomainThrottleMapper::to_domain(entity);
+        assert_eq!(domain, back_to_domain);
+    }
+}
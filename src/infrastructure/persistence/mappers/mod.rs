@@ -9,13 +9,29 @@
 //! - Pure domain models (in domain/models/)
 //! - Database entities (in infrastructure/database/entities/)
 
+pub mod crawl_link_mapper;
 pub mod crawl_mapper;
 pub mod credits_mapper;
+pub mod domain_throttle_mapper;
+pub mod monitor_mapper;
+pub mod notification_channel_mapper;
+pub mod notification_contacts_mapper;
+pub mod resource_tag_mapper;
+pub mod task_event_mapper;
 pub mod task_mapper;
+pub mod url_blocklist_mapper;
 pub mod webhook_mapper;
 
 // Re-export mappers
+pub use crawl_link_mapper::CrawlLinkMapper;
 pub use crawl_mapper::CrawlMapper;
-pub use credits_mapper::{CreditsMapper, CreditsTransactionMapper};
+pub use credits_mapper::{CreditsMapper, CreditsTransactionMapper, LowBalanceAlertMapper};
+pub use domain_throttle_mapper::DomainThrottleMapper;
+pub use monitor_mapper::MonitorMapper;
+pub use notification_channel_mapper::NotificationChannelMapper;
+pub use notification_contacts_mapper::NotificationContactsMapper;
+pub use resource_tag_mapper::ResourceTagMapper;
+pub use task_event_mapper::TaskEventMapper;
 pub use task_mapper::TaskMapper;
+pub use url_blocklist_mapper::UrlBlocklistMapper;
 pub use webhook_mapper::{WebhookEventMapper, WebhookMapper};
@@ -0,0 +1,97 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! Monitor Mapper - converts between Monitor domain model and database entity
+
+use crate::common::time_utils::{from_db_datetime, to_db_datetime};
+use crate::domain::models::{Monitor, MonitorMode};
+use crate::infrastructure::database::entities::monitor;
+
+/// Mapper for converting between Monitor domain model and database entity
+pub struct MonitorMapper;
+
+impl MonitorMapper {
+    /// Convert database entity to domain model
+    ///
+    /// Returns `None` when the stored mode is unknown.
+    pub fn to_domain(entity: monitor::Model) -> Option<Monitor> {
+        let mode = entity.mode.parse::<MonitorMode>().ok()?;
+        Some(Monitor {
+            id: entity.id,
+            team_id: entity.team_id,
+            url: entity.url,
+            interval_seconds: entity.interval_seconds,
+            mode,
+            extraction_rules: entity.extraction_rules,
+            active: entity.active,
+            last_hash: entity.last_hash,
+            last_snapshot: entity.last_snapshot,
+            last_error: entity.last_error,
+            last_checked_at: entity.last_checked_at.map(from_db_datetime),
+            last_changed_at: entity.last_changed_at.map(from_db_datetime),
+            next_check_at: from_db_datetime(entity.next_check_at),
+            created_at: from_db_datetime(entity.created_at),
+            updated_at: from_db_datetime(entity.updated_at),
+        })
+    }
+
+    /// Convert domain model to database entity
+    pub fn to_entity(domain: &Monitor) -> monitor::Model {
+        monitor::Model {
+            id: domain.id,
+            team_id: domain.team_id,
+            url: domain.url.clone(),
+            interval_seconds: domain.interval_seconds,
+            mode: domain.mode.as_str().to_string(),
+            extraction_rules: domain.extraction_rules.clone(),
+            active: domain.active,
+            last_hash: domain.last_hash.clone(),
+            last_snapshot: domain.last_snapshot.clone(),
+            last_error: domain.last_error.clone(),
+            last_checked_at: domain.last_checked_at.map(to_db_datetime),
+            last_changed_at: domain.last_changed_at.map(to_db_datetime),
+            next_check_at: to_db_datetime(domain.next_check_at),
+            created_at: to_db_datetime(domain.created_at),
+            updated_at: to_db_datetime(domain.updated_at),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use uuid::Uuid;
+
+    #[test]
+    fn test_monitor_mapper_roundtrip() {
+        let mut domain = Monitor::new(
+            Uuid::new_v4(),
+            "https://example.com/pricing",
+            3600,
+            MonitorMode::Extract,
+        )
+        .with_extraction_rules(serde_json::json!({"price": {"selector": ".price"}}));
+        domain.record_snapshot("abc".to_string(), "{}".to_string(), Utc::now());
+
+        let entity = MonitorMapper::to_entity(&domain);
+        assert_eq!(entity.mode, "extract");
+
+        let back_to_domain = MonitorMapper::to_domain(entity).unwrap();
+        assert_eq!(domain, back_to_domain);
+    }
+
+    #[test]
+    fn test_monitor_mapper_skips_unknown_mode() {
+        let mut entity = MonitorMapper::to_entity(&Monitor::new(
+            Uuid::new_v4(),
+            "https://example.com",
+            300,
+            MonitorMode::Hash,
+        ));
+        entity.mode = "visual".to_string();
+        assert!(MonitorMapper::to_domain(entity).is_none());
+    }
+}
@@ -0,0 +1,110 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! Notification channel Mapper - converts between NotificationChannel and database entity
+
+use crate::common::time_utils::{from_db_datetime, to_db_datetime};
+use crate::domain::models::{ChannelKind, NotificationChannel, WebhookEventType};
+use crate::infrastructure::database::entities::notification_channel;
+use serde_json::Value;
+
+/// Mapper for converting between NotificationChannel domain model and database entity
+pub struct NotificationChannelMapper;
+
+impl NotificationChannelMapper {
+    /// Convert database entity to domain model
+    ///
+    /// Returns `None` when the stored kind is unknown.
+    pub fn to_domain(entity: notification_channel::Model) -> Option<NotificationChannel> {
+        let kind = entity.kind.parse::<ChannelKind>().ok()?;
+        let event_types = entity
+            .event_types
+            .as_ref()
+            .and_then(Value::as_array)
+            .map(|names| {
+                names
+                    .iter()
+                    .filter_map(Value::as_str)
+                    .filter_map(|name| name.parse::<WebhookEventType>().ok())
+                    .collect()
+            })
+            .unwrap_or_default();
+        Some(NotificationChannel {
+            id: entity.id,
+            team_id: entity.team_id,
+            name: entity.name,
+            kind,
+            url: entity.url,
+            event_types,
+            enabled: entity.enabled,
+            created_at: from_db_datetime(entity.created_at),
+            updated_at: from_db_datetime(entity.updated_at),
+        })
+    }
+
+    /// Convert domain model to database entity (an empty event filter is stored as NULL)
+    pub fn to_entity(domain: &NotificationChannel) -> notification_channel::Model {
+        notification_channel::Model {
+            id: domain.id,
+            team_id: domain.team_id,
+            name: domain.name.clone(),
+            kind: domain.kind.as_str().to_string(),
+            url: domain.url.clone(),
+            event_types: (!domain.event_types.is_empty()).then(|| {
+                Value::from(
+                    domain
+                        .event_types
+                        .iter()
+                        .map(|t| t.to_string())
+                        .collect::<Vec<_>>(),
+                )
+            }),
+            enabled: domain.enabled,
+            created_at: to_db_datetime(domain.created_at),
+            updated_at: to_db_datetime(domain.updated_at),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    #[test]
+    fn test_notification_channel_mapper_roundtrip() {
+        let mut domain = NotificationChannel::new(
+            Uuid::new_v4(),
+            "#crawl-alerts",
+            ChannelKind::Slack,
+            "https://hooks.slack.com/services/T0/B0/x",
+        );
+        domain.set_event_types(vec![WebhookEventType::MonitorChanged]);
+
+        let entity = NotificationChannelMapper::to_entity(&domain);
+        assert_eq!(entity.kind, "slack");
+        assert_eq!(
+            entity.event_types,
+            Some(serde_json::json!(["monitor.changed"]))
+        );
+
+        let back_to_domain = NotificationChannelMapper::to_domain(entity).unwrap();
+        assert_eq!(domain, back_to_domain);
+    }
+
+    #[test]
+    fn test_unknown_kind_is_skipped() {
+        let domain = NotificationChannel::new(
+            Uuid::new_v4(),
+            "automation",
+            ChannelKind::Json,
+            "https://hooks.example.com/crawlrs",
+        );
+        let mut entity = NotificationChannelMapper::to_entity(&domain);
+        assert!(entity.event_types.is_none());
+        entity.kind = "teams".to_string();
+        assert!(NotificationChannelMapper::to_domain(entity).is_none());
+    }
+}
@@ -0,0 +1,105 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! Notification contacts Mapper - converts between NotificationContacts and database entity
+
+use crate::common::time_utils::{from_db_datetime, to_db_datetime};
+use crate::domain::models::{NotificationContacts, WebhookEventType};
+use crate::infrastructure::database::entities::notification_contacts;
+use serde_json::Value;
+
+/// Mapper for converting between NotificationContacts domain model and database entity
+pub struct NotificationContactsMapper;
+
+impl NotificationContactsMapper {
+    /// Convert database entity to domain model
+    pub fn to_domain(entity: notification_contacts::Model) -> NotificationContacts {
+        NotificationContacts {
+            team_id: entity.team_id,
+            emails: Self::strings(Some(&entity.emails)),
+            event_types: Self::strings(entity.event_types.as_ref())
+                .iter()
+                .filter_map(|name| name.parse::<WebhookEventType>().ok())
+                .collect(),
+            enabled: entity.enabled,
+            created_at: from_db_datetime(entity.created_at),
+            updated_at: from_db_datetime(entity.updated_at),
+        }
+    }
+
+    /// Convert domain model to database entity (an empty event filter is stored as NULL)
+    pub fn to_entity(domain: &NotificationContacts) -> notification_contacts::Model {
+        notification_contacts::Model {
+            team_id: domain.team_id,
+            emails: Value::from(domain.emails.clone()),
+            event_types: (!domain.event_types.is_empty()).then(|| {
+                Value::from(
+                    domain
+                        .event_types
+                        .iter()
+                        .map(|t| t.to_string())
+                        .collect::<Vec<_>>(),
+                )
+            }),
+            enabled: domain.enabled,
+            created_at: to_db_datetime(domain.created_at),
+            updated_at: to_db_datetime(domain.updated_at),
+        }
+    }
+
+    /// Read a JSON string array (other values are ignored)
+    fn strings(value: Option<&Value>) -> Vec<String> {
+        value
+            .and_then(Value::as_array)
+            .map(|items| {
+                items
+                    .iter()
+                    .filter_map(|item| item.as_str().map(str::to_string))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    #[test]
+    fn test_notification_contacts_mapper_roundtrip() {
+        let domain = NotificationContacts::new(
+            Uuid::new_v4(),
+            vec!["ops@example.com".to_string()],
+            vec![
+                WebhookEventType::CrawlSummary,
+                WebhookEventType::WebhookDeliveryFailed,
+            ],
+        );
+
+        let entity = NotificationContactsMapper::to_entity(&domain);
+        assert_eq!(
+            entity.event_types,
+            Some(serde_json::json!([
+                "crawl.summary",
+                "webhook.delivery_failed"
+            ]))
+        );
+
+        let back_to_domain = NotificationContactsMapper::to_domain(entity);
+        assert_eq!(domain, back_to_domain);
+    }
+
+    #[test]
+    fn test_empty_event_filter_is_stored_as_null() {
+        let domain =
+            NotificationContacts::new(Uuid::new_v4(), vec!["ops@example.com".to_string()], vec![]);
+        let entity = NotificationContactsMapper::to_entity(&domain);
+        assert!(entity.event_types.is_none());
+        assert!(NotificationContactsMapper::to_domain(entity)
+            .event_types
+            .is_empty());
+    }
+}
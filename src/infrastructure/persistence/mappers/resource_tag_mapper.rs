@@ -0,0 +1,94 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! Resource tag Mapper - converts between ResourceTags and database entity
+
+use crate::common::time_utils::{from_db_datetime, to_db_datetime};
+use crate::domain::models::{ResourceTags, TaggedResource};
+use crate::infrastructure::database::entities::resource_tag;
+use serde_json::Value;
+
+/// Mapper for converting between ResourceTags domain model and database entity
+pub struct ResourceTagMapper;
+
+impl ResourceTagMapper {
+    /// Convert database entity to domain model
+    ///
+    /// Returns `None` when the stored resource type is unknown. Malformed tag
+    /// or annotation values are read as empty.
+    pub fn to_domain(entity: resource_tag::Model) -> Option<ResourceTags> {
+        let resource_type = entity.resource_type.parse::<TaggedResource>().ok()?;
+        let tags = match entity.tags {
+            Value::Array(tags) => tags
+                .into_iter()
+                .filter_map(|tag| tag.as_str().map(str::to_string))
+                .collect(),
+            _ => Vec::new(),
+        };
+        let annotations = match entity.annotations {
+            Value::Object(annotations) => annotations,
+            _ => Default::default(),
+        };
+        Some(ResourceTags {
+            resource_type,
+            resource_id: entity.resource_id,
+            team_id: entity.team_id,
+            tags,
+            annotations,
+            created_at: from_db_datetime(entity.created_at),
+            updated_at: from_db_datetime(entity.updated_at),
+        })
+    }
+
+    /// Convert domain model to database entity
+    pub fn to_entity(domain: &ResourceTags) -> resource_tag::Model {
+        resource_tag::Model {
+            resource_type: domain.resource_type.as_str().to_string(),
+            resource_id: domain.resource_id,
+            team_id: domain.team_id,
+            tags: Value::from(domain.tags.clone()),
+            annotations: Value::Object(domain.annotations.clone()),
+            created_at: to_db_datetime(domain.created_at),
+            updated_at: to_db_datetime(domain.updated_at),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use uuid::Uuid;
+
+    #[test]
+    fn test_resource_tag_mapper_roundtrip() {
+        let mut domain = ResourceTags::new(TaggedResource::Crawl, Uuid::new_v4(), Uuid::new_v4());
+        domain
+            .add_tags(&["reviewed".to_string(), "relevant".to_string()])
+            .unwrap();
+        let Value::Object(annotations) = json!({"reviewer": "ana"}) else {
+            unreachable!()
+        };
+        domain.merge_annotations(annotations);
+
+        let entity = ResourceTagMapper::to_entity(&domain);
+        assert_eq!(entity.resource_type, "crawl");
+        assert_eq!(entity.tags, json!(["reviewed", "relevant"]));
+
+        let back_to_domain = ResourceTagMapper::to_domain(entity).unwrap();
+        assert_eq!(domain, back_to_domain);
+    }
+
+    #[test]
+    fn test_resource_tag_mapper_skips_unknown_type() {
+        let mut entity = ResourceTagMapper::to_entity(&ResourceTags::new(
+            TaggedResource::Result,
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+        ));
+        entity.resource_type = "task".to_string();
+        assert!(ResourceTagMapper::to_domain(entity).is_none());
+    }
+}
@@ -0,0 +1,75 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! Task Event Mapper - converts between TaskEvent domain model and database entity
+
+use crate::common::time_utils::{from_db_datetime, to_db_datetime};
+use crate::domain::models::{TaskEvent, TaskEventType};
+use crate::infrastructure::database::entities::task_event;
+
+/// Mapper for converting between TaskEvent domain model and database entity
+pub struct TaskEventMapper;
+
+impl TaskEventMapper {
+    /// Convert database entity to domain model
+    ///
+    /// Returns `None` when the stored event type is unknown.
+    pub fn to_domain(entity: task_event::Model) -> Option<TaskEvent> {
+        let event_type = entity.event_type.parse::<TaskEventType>().ok()?;
+        Some(TaskEvent {
+            id: entity.id,
+            task_id: entity.task_id,
+            event_type,
+            worker_id: entity.worker_id,
+            attempt: entity.attempt,
+            duration_ms: entity.duration_ms,
+            message: entity.message,
+            created_at: from_db_datetime(entity.created_at),
+        })
+    }
+
+    /// Convert domain model to database entity
+    pub fn to_entity(domain: &TaskEvent) -> task_event::Model {
+        task_event::Model {
+            id: domain.id,
+            task_id: domain.task_id,
+            event_type: domain.event_type.as_str().to_string(),
+            worker_id: domain.worker_id,
+            attempt: domain.attempt,
+            duration_ms: domain.duration_ms,
+            message: domain.message.clone(),
+            created_at: to_db_datetime(domain.created_at),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    #[test]
+    fn test_task_event_mapper_roundtrip() {
+        let domain = TaskEvent::new(Uuid::new_v4(), TaskEventType::EngineAttempt)
+            .with_worker(Uuid::new_v4())
+            .with_attempt(1)
+            .with_duration_ms(420)
+            .with_message("status 200");
+
+        let entity = TaskEventMapper::to_entity(&domain);
+        assert_eq!(entity.event_type, "engine_attempt");
+
+        let back_to_domain = TaskEventMapper::to_domain(entity).unwrap();
+        assert_eq!(domain, back_to_domain);
+    }
+
+    #[test]
+    fn test_task_event_mapper_skips_unknown_type() {
+        let mut entity =
+            TaskEventMapper::to_entity(&TaskEvent::new(Uuid::new_v4(), TaskEventType::Queued));
+        entity.event_type = "teleported".to_string();
+        assert!(TaskEventMapper::to_domain(entity).is_none());
+    }
+}
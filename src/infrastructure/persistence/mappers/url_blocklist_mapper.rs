@@ -0,0 +1,75 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! URL Blocklist Mapper - converts between BlocklistEntry domain model and database entity
+
+use crate::common::time_utils::{from_db_datetime, to_db_datetime};
+use crate::domain::models::{BlocklistEntry, BlocklistPatternType};
+use crate::infrastructure::database::entities::url_blocklist;
+
+/// Mapper for converting between BlocklistEntry domain model and database entity
+pub struct UrlBlocklistMapper;
+
+impl UrlBlocklistMapper {
+    /// Convert database entity to domain model
+    ///
+    /// Returns `None` when the stored pattern type is unknown.
+    pub fn to_domain(entity: url_blocklist::Model) -> Option<BlocklistEntry> {
+        let pattern_type = entity.pattern_type.parse::<BlocklistPatternType>().ok()?;
+        Some(BlocklistEntry {
+            id: entity.id,
+            team_id: entity.team_id,
+            pattern: entity.pattern,
+            pattern_type,
+            reason: entity.reason,
+            created_at: from_db_datetime(entity.created_at),
+        })
+    }
+
+    /// Convert domain model to database entity
+    pub fn to_entity(domain: &BlocklistEntry) -> url_blocklist::Model {
+        url_blocklist::Model {
+            id: domain.id,
+            team_id: domain.team_id,
+            pattern: domain.pattern.clone(),
+            pattern_type: domain.pattern_type.as_str().to_string(),
+            reason: domain.reason.clone(),
+            created_at: to_db_datetime(domain.created_at),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    #[test]
+    fn test_url_blocklist_mapper_roundtrip() {
+        let domain = BlocklistEntry::new(
+            Some(Uuid::new_v4()),
+            r"^https://example\.com/private/",
+            BlocklistPatternType::Regex,
+        )
+        .with_reason("legal request");
+
+        let entity = UrlBlocklistMapper::to_entity(&domain);
+        assert_eq!(entity.pattern_type, "regex");
+
+        let back_to_domain = UrlBlocklistMapper::to_domain(entity).unwrap();
+        assert_eq!(domain, back_to_domain);
+    }
+
+    #[test]
+    fn test_url_blocklist_mapper_skips_unknown_type() {
+        let mut entity = UrlBlocklistMapper::to_entity(&BlocklistEntry::new(
+            None,
+            "example.com",
+            BlocklistPatternType::Wildcard,
+        ));
+        entity.pattern_type = "glob".to_string();
+        assert!(UrlBlocklistMapper::to_domain(entity).is_none());
+    }
+}
@@ -11,6 +11,7 @@ use crate::common::time_utils::{
 use crate::domain::models::{Webhook, WebhookEvent, WebhookEventType, WebhookStatus};
 use crate::infrastructure::database::entities::{webhook, webhook_event};
 use sea_orm::ActiveValue::{Set, Unchanged};
+use std::collections::HashMap;
 use uuid::Uuid;
 
 /// Mapper for converting between Webhook domain model and database entity
@@ -23,6 +24,9 @@ impl WebhookMapper {
             id: entity.id,
             team_id: entity.team_id,
             url: entity.url,
+            event_types: Self::parse_event_types(entity.event_types),
+            custom_headers: Self::parse_custom_headers(entity.custom_headers),
+            payload_template: entity.payload_template,
             created_at: from_db_datetime(entity.created_at),
         }
     }
@@ -33,6 +37,9 @@ impl WebhookMapper {
             id: domain.id,
             team_id: domain.team_id,
             url: domain.url.clone(),
+            event_types: Self::event_types_to_json(&domain.event_types),
+            custom_headers: Self::custom_headers_to_json(&domain.custom_headers),
+            payload_template: domain.payload_template.clone(),
             created_at: to_db_datetime(domain.created_at),
         }
     }
@@ -41,6 +48,60 @@ impl WebhookMapper {
     pub fn to_domain_list(entities: Vec<webhook::Model>) -> Vec<Webhook> {
         entities.into_iter().map(Self::to_domain).collect()
     }
+
+    /// Parse the stored event type filter (NULL or non-array means all events)
+    fn parse_event_types(value: Option<serde_json::Value>) -> Vec<WebhookEventType> {
+        value
+            .as_ref()
+            .and_then(|v| v.as_array())
+            .map(|items| {
+                items
+                    .iter()
+                    .filter_map(|item| item.as_str())
+                    .map(WebhookEventMapper::parse_event_type)
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Convert the event type filter to JSON (empty filter is stored as NULL)
+    fn event_types_to_json(event_types: &[WebhookEventType]) -> Option<serde_json::Value> {
+        if event_types.is_empty() {
+            return None;
+        }
+        Some(serde_json::Value::Array(
+            event_types
+                .iter()
+                .map(|t| serde_json::Value::String(t.to_string()))
+                .collect(),
+        ))
+    }
+
+    /// Parse stored custom headers (non-string values are ignored)
+    fn parse_custom_headers(value: Option<serde_json::Value>) -> HashMap<String, String> {
+        value
+            .as_ref()
+            .and_then(|v| v.as_object())
+            .map(|map| {
+                map.iter()
+                    .filter_map(|(k, v)| v.as_str().map(|v| (k.clone(), v.to_string())))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Convert custom headers to JSON (no headers is stored as NULL)
+    fn custom_headers_to_json(headers: &HashMap<String, String>) -> Option<serde_json::Value> {
+        if headers.is_empty() {
+            return None;
+        }
+        Some(serde_json::Value::Object(
+            headers
+                .iter()
+                .map(|(k, v)| (k.clone(), serde_json::Value::String(v.clone())))
+                .collect(),
+        ))
+    }
 }
 
 /// Mapper for converting between WebhookEvent domain model and database entity
@@ -67,6 +128,7 @@ impl WebhookEventMapper {
             from_db_datetime(entity.updated_at),
             from_db_datetime_opt(entity.delivered_at),
         )
+        .with_channel(entity.channel.parse().unwrap_or_default())
     }
 
     /// Convert domain model to database entity
@@ -88,6 +150,7 @@ impl WebhookEventMapper {
             created_at: to_db_datetime(domain.created_at),
             updated_at: to_db_datetime(domain.updated_at),
             delivered_at: to_db_datetime_opt(domain.delivered_at),
+            channel: domain.channel.to_string(),
         }
     }
 
@@ -119,6 +182,7 @@ impl WebhookEventMapper {
             created_at: Unchanged(entity.created_at),
             updated_at: Set(entity.updated_at),
             delivered_at: Set(entity.delivered_at),
+            channel: Set(entity.channel),
         }
     }
 
@@ -152,6 +216,7 @@ impl WebhookEventMapper {
 mod tests {
     use super::*;
     use crate::common::time_utils::to_db_datetime;
+    use crate::domain::models::DeliveryChannel;
     use chrono::Utc;
     use uuid::Uuid;
 
@@ -162,14 +227,66 @@ mod tests {
             id: Uuid::new_v4(),
             team_id: Uuid::new_v4(),
             url: "https://example.com/webhook".to_string(),
+            event_types: vec![],
+            custom_headers: HashMap::new(),
+            payload_template: None,
             created_at: now,
         };
 
         let entity = WebhookMapper::to_entity(&domain);
+        assert!(entity.event_types.is_none());
+        assert!(entity.custom_headers.is_none());
         let back_to_domain = WebhookMapper::to_domain(entity);
 
         assert_eq!(domain.id, back_to_domain.id);
         assert_eq!(domain.url, back_to_domain.url);
+        assert!(back_to_domain.event_types.is_empty());
+    }
+
+    #[test]
+    fn test_webhook_mapper_event_types_roundtrip() {
+        let domain = Webhook::new(
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            "https://example.com/webhook".to_string(),
+        )
+        .with_event_types(vec![
+            WebhookEventType::CrawlCompleted,
+            WebhookEventType::Custom("page.scraped".to_string()),
+        ]);
+
+        let entity = WebhookMapper::to_entity(&domain);
+        assert_eq!(
+            entity.event_types,
+            Some(serde_json::json!(["crawl.completed", "page.scraped"]))
+        );
+
+        let back_to_domain = WebhookMapper::to_domain(entity);
+        assert_eq!(back_to_domain.event_types, domain.event_types);
+    }
+
+    #[test]
+    fn test_webhook_mapper_delivery_options_roundtrip() {
+        let domain = Webhook::new(
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            "https://example.com/webhook".to_string(),
+        )
+        .with_custom_headers(HashMap::from([(
+            "Authorization".to_string(),
+            "Bearer secret".to_string(),
+        )]))
+        .with_payload_template(Some(r#"{"text": "{{event}}"}"#.to_string()));
+
+        let entity = WebhookMapper::to_entity(&domain);
+        assert_eq!(
+            entity.custom_headers,
+            Some(serde_json::json!({"Authorization": "Bearer secret"}))
+        );
+
+        let back_to_domain = WebhookMapper::to_domain(entity);
+        assert_eq!(back_to_domain.custom_headers, domain.custom_headers);
+        assert_eq!(back_to_domain.payload_template, domain.payload_template);
     }
 
     #[test]
@@ -202,6 +319,26 @@ mod tests {
         assert_eq!(domain.status, back_to_domain.status);
     }
 
+    #[test]
+    fn test_webhook_event_mapper_channel_roundtrip() {
+        let domain = WebhookEvent::new(
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            WebhookEventType::CreditsLow,
+            serde_json::json!({"text": "low"}),
+            "https://hooks.slack.com/services/T0/B0/x".to_string(),
+        )
+        .with_channel(DeliveryChannel::Slack);
+
+        let entity = WebhookEventMapper::to_entity(&domain);
+        assert_eq!(entity.channel, "slack");
+        assert_eq!(
+            WebhookEventMapper::to_domain(entity).channel,
+            DeliveryChannel::Slack
+        );
+    }
+
     #[test]
     fn test_webhook_mapper_to_domain_list() {
         let now_db = to_db_datetime(Utc::now());
@@ -210,12 +347,18 @@ mod tests {
                 id: Uuid::new_v4(),
                 team_id: Uuid::new_v4(),
                 url: "https://a.com/hook".to_string(),
+                event_types: None,
+                custom_headers: None,
+                payload_template: None,
                 created_at: now_db,
             },
             webhook::Model {
                 id: Uuid::new_v4(),
                 team_id: Uuid::new_v4(),
                 url: "https://b.com/hook".to_string(),
+                event_types: None,
+                custom_headers: None,
+                payload_template: None,
                 created_at: now_db,
             },
         ];
@@ -257,6 +400,7 @@ mod tests {
                 created_at: now_db,
                 updated_at: now_db,
                 delivered_at: None,
+                channel: "webhook".to_string(),
             },
             webhook_event::Model {
                 id: Uuid::new_v4(),
@@ -275,6 +419,7 @@ mod tests {
                 created_at: now_db,
                 updated_at: now_db,
                 delivered_at: Some(now_db),
+                channel: "webhook".to_string(),
             },
         ];
 
@@ -311,6 +456,7 @@ mod tests {
             created_at: now_db,
             updated_at: now_db,
             delivered_at: None,
+            channel: "webhook".to_string(),
         };
 
         let domain = WebhookEventMapper::to_domain(entity);
@@ -342,6 +488,7 @@ mod tests {
             created_at: now_db,
             updated_at: now_db,
             delivered_at: None,
+            channel: "webhook".to_string(),
         };
 
         let domain = WebhookEventMapper::to_domain(entity);
@@ -384,6 +531,7 @@ mod tests {
                 created_at: now_db,
                 updated_at: now_db,
                 delivered_at: None,
+                channel: "webhook".to_string(),
             };
 
             let domain = WebhookEventMapper::to_domain(entity);
@@ -403,8 +551,17 @@ mod tests {
         let event_types = vec![
             ("crawl.completed", WebhookEventType::CrawlCompleted),
             ("crawl.failed", WebhookEventType::CrawlFailed),
+            ("crawl.summary", WebhookEventType::CrawlSummary),
             ("scrape.completed", WebhookEventType::ScrapeCompleted),
             ("scrape.failed", WebhookEventType::ScrapeFailed),
+            ("credits.low", WebhookEventType::CreditsLow),
+            ("export.completed", WebhookEventType::ExportCompleted),
+            ("export.failed", WebhookEventType::ExportFailed),
+            ("monitor.changed", WebhookEventType::MonitorChanged),
+            (
+                "webhook.delivery_failed",
+                WebhookEventType::WebhookDeliveryFailed,
+            ),
         ];
 
         for (type_str, expected_type) in event_types {
@@ -425,6 +582,7 @@ mod tests {
                 created_at: now_db,
                 updated_at: now_db,
                 delivered_at: None,
+                channel: "webhook".to_string(),
             };
 
             let domain = WebhookEventMapper::to_domain(entity);
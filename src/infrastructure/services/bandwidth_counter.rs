@@ -0,0 +1,238 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! 下载流量计数器
+//!
+//! `RedisBandwidthCounter` 把团队当日累计存放在哈希 `{key_prefix}:team:{team_id}:{date}`
+//! （字段 `bytes` 与 `requests`），任务累计存放在 `{key_prefix}:task:{task_id}`，有变化的
+//! 团队日记录在集合 `{key_prefix}:changed` 中，由汇总 Worker 取出写入数据库。
+//! 计数器按配置的保留时长过期，多个 Worker 进程与 API 进程共享同一份计数。
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::config::settings::BandwidthSettings;
+use crate::domain::services::bandwidth_service::{BandwidthCounter, InMemoryBandwidthCounter};
+
+#[cfg(feature = "queue-redis")]
+mod redis_counter {
+    use anyhow::Result;
+    use async_trait::async_trait;
+    use chrono::NaiveDate;
+    use log::warn;
+    use uuid::Uuid;
+
+    use crate::config::settings::BandwidthSettings;
+    use crate::domain::models::DailyBandwidthUsage;
+    use crate::domain::services::bandwidth_service::BandwidthCounter;
+
+    /// Redis 实现的下载流量计数器
+    pub struct RedisBandwidthCounter {
+        client: redis::Client,
+        /// 首次访问时建立的连接
+        connection: tokio::sync::OnceCell<redis::aio::ConnectionManager>,
+        key_prefix: String,
+        counter_ttl_seconds: u64,
+        task_ttl_seconds: u64,
+    }
+
+    impl RedisBandwidthCounter {
+        /// 创建计数器，连接在首次访问时建立
+        ///
+        /// # 参数
+        ///
+        /// * `settings` - 流量统计配置
+        ///
+        /// # 返回值
+        ///
+        /// * `Ok(RedisBandwidthCounter)` - 创建成功
+        /// * `Err(anyhow::Error)` - 连接地址无效
+        pub fn new(settings: &BandwidthSettings) -> Result<Self> {
+            Ok(Self {
+                client: redis::Client::open(settings.redis_url())?,
+                connection: tokio::sync::OnceCell::new(),
+                key_prefix: settings.key_prefix.clone(),
+                counter_ttl_seconds: settings.counter_ttl_seconds,
+                task_ttl_seconds: settings.task_ttl_seconds,
+            })
+        }
+
+        async fn connection(&self) -> Result<redis::aio::ConnectionManager> {
+            Ok(self
+                .connection
+                .get_or_try_init(|| self.client.get_connection_manager())
+                .await?
+                .clone())
+        }
+
+        fn team_key(&self, team_id: Uuid, date: NaiveDate) -> String {
+            format!("{}:team:{}:{}", self.key_prefix, team_id, date)
+        }
+
+        fn task_key(&self, task_id: Uuid) -> String {
+            format!("{}:task:{}", self.key_prefix, task_id)
+        }
+
+        fn changed_key(&self) -> String {
+            format!("{}:changed", self.key_prefix)
+        }
+    }
+
+    /// 有变化的团队日在集合中的成员：`{team_id}:{date}`
+    fn changed_member(team_id: Uuid, date: NaiveDate) -> String {
+        format!("{}:{}", team_id, date)
+    }
+
+    fn parse_changed_member(member: &str) -> Option<(Uuid, NaiveDate)> {
+        let (team_id, date) = member.split_once(':')?;
+        Some((Uuid::parse_str(team_id).ok()?, date.parse().ok()?))
+    }
+
+    fn to_usage(
+        date: NaiveDate,
+        (bytes, requests): (Option<u64>, Option<u64>),
+    ) -> DailyBandwidthUsage {
+        DailyBandwidthUsage {
+            date,
+            bytes_downloaded: bytes.unwrap_or(0),
+            requests: requests.unwrap_or(0),
+        }
+    }
+
+    #[async_trait]
+    impl BandwidthCounter for RedisBandwidthCounter {
+        async fn record(
+            &self,
+            team_id: Uuid,
+            task_id: Uuid,
+            date: NaiveDate,
+            bytes: u64,
+        ) -> Result<()> {
+            let mut connection = self.connection().await?;
+            let team_key = self.team_key(team_id, date);
+            let task_key = self.task_key(task_id);
+            redis::pipe()
+                .atomic()
+                .hincr(&team_key, "bytes", bytes)
+                .ignore()
+                .hincr(&team_key, "requests", 1)
+                .ignore()
+                .expire(&team_key, self.counter_ttl_seconds as i64)
+                .ignore()
+                .incr(&task_key, bytes)
+                .ignore()
+                .expire(&task_key, self.task_ttl_seconds as i64)
+                .ignore()
+                .sadd(self.changed_key(), changed_member(team_id, date))
+                .ignore()
+                .query_async::<()>(&mut connection)
+                .await?;
+            Ok(())
+        }
+
+        async fn team_usage(&self, team_id: Uuid, date: NaiveDate) -> Result<DailyBandwidthUsage> {
+            let mut connection = self.connection().await?;
+            let counts: (Option<u64>, Option<u64>) = redis::cmd("HMGET")
+                .arg(self.team_key(team_id, date))
+                .arg("bytes")
+                .arg("requests")
+                .query_async(&mut connection)
+                .await?;
+            Ok(to_usage(date, counts))
+        }
+
+        async fn task_bytes(&self, task_id: Uuid) -> Result<Option<u64>> {
+            let mut connection = self.connection().await?;
+            Ok(redis::cmd("GET")
+                .arg(self.task_key(task_id))
+                .query_async(&mut connection)
+                .await?)
+        }
+
+        async fn take_changed(&self, limit: usize) -> Result<Vec<(Uuid, DailyBandwidthUsage)>> {
+            let mut connection = self.connection().await?;
+            let members: Vec<String> = redis::cmd("SPOP")
+                .arg(self.changed_key())
+                .arg(limit)
+                .query_async(&mut connection)
+                .await?;
+            let keys: Vec<(Uuid, NaiveDate)> = members
+                .iter()
+                .filter_map(|member| {
+                    let key = parse_changed_member(member);
+                    if key.is_none() {
+                        warn!("Dropping malformed bandwidth counter member {}", member);
+                    }
+                    key
+                })
+                .collect();
+            if keys.is_empty() {
+                return Ok(Vec::new());
+            }
+
+            let mut pipe = redis::pipe();
+            for (team_id, date) in &keys {
+                pipe.cmd("HMGET")
+                    .arg(self.team_key(*team_id, *date))
+                    .arg("bytes")
+                    .arg("requests");
+            }
+            let counts: Vec<(Option<u64>, Option<u64>)> = pipe.query_async(&mut connection).await?;
+            Ok(keys
+                .into_iter()
+                .zip(counts)
+                // 已过期的计数器没有可写入的数据
+                .filter(|(_, (bytes, _))| bytes.is_some())
+                .map(|((team_id, date), counts)| (team_id, to_usage(date, counts)))
+                .collect())
+        }
+
+        async fn mark_changed(&self, team_id: Uuid, date: NaiveDate) -> Result<()> {
+            let mut connection = self.connection().await?;
+            redis::cmd("SADD")
+                .arg(self.changed_key())
+                .arg(changed_member(team_id, date))
+                .query_async::<()>(&mut connection)
+                .await?;
+            Ok(())
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn test_changed_member_roundtrip() {
+            let team_id = Uuid::new_v4();
+            let date = NaiveDate::from_ymd_opt(2025, 3, 1).unwrap();
+            let member = changed_member(team_id, date);
+            assert_eq!(parse_changed_member(&member), Some((team_id, date)));
+            assert_eq!(parse_changed_member("not-a-team:2025-03-01"), None);
+        }
+    }
+}
+
+#[cfg(feature = "queue-redis")]
+pub use redis_counter::RedisBandwidthCounter;
+
+/// 从配置创建计数器，`redis` 计数器需要启用 `queue-redis` 特性
+pub fn bandwidth_counter_from_settings(
+    settings: &BandwidthSettings,
+) -> anyhow::Result<Arc<dyn BandwidthCounter>> {
+    if !settings.is_redis() {
+        return Ok(Arc::new(InMemoryBandwidthCounter::new(
+            Duration::from_secs(settings.task_ttl_seconds),
+        )));
+    }
+    #[cfg(feature = "queue-redis")]
+    {
+        Ok(Arc::new(RedisBandwidthCounter::new(settings)?))
+    }
+    #[cfg(not(feature = "queue-redis"))]
+    {
+        anyhow::bail!("the redis bandwidth counter requires the queue-redis feature")
+    }
+}
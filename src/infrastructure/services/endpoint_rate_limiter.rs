@@ -0,0 +1,219 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! 端点级限流器
+//!
+//! 按 API Key + 路由规则维护独立的令牌桶，使不同端点可以配置不同的
+//! 每分钟请求数（如 `/v1/search` 30 RPM、`/v1/scrape` 300 RPM）。
+//! 使用内存存储，不依赖 Redis。
+
+use std::time::{Duration, Instant};
+
+use dashmap::DashMap;
+
+use crate::domain::services::rate_limiting_service::{EndpointRateLimit, RateLimitResult};
+
+/// 单个令牌桶的状态
+#[derive(Debug, Clone, Copy)]
+struct Bucket {
+    /// 当前可用令牌数
+    tokens: f64,
+    /// 上次补充令牌的时间
+    last_refill: Instant,
+}
+
+/// 端点级限流器
+///
+/// 规则按具体程度排序，请求只消耗最精确匹配规则的额度。
+#[derive(Debug, Default)]
+pub struct EndpointRateLimiter {
+    /// 端点规则（按具体程度降序）
+    rules: Vec<EndpointRateLimit>,
+    /// 令牌桶，键为 `{api_key}|{rule_index}`
+    buckets: DashMap<String, Bucket>,
+}
+
+impl EndpointRateLimiter {
+    /// 创建端点限流器，无效规则会被忽略
+    pub fn new(rules: Vec<EndpointRateLimit>) -> Self {
+        let mut rules: Vec<EndpointRateLimit> = rules
+            .into_iter()
+            .filter(|rule| match rule.validate() {
+                Ok(()) => true,
+                Err(e) => {
+                    log::warn!("Ignoring invalid endpoint rate limit {:?}: {}", rule, e);
+                    false
+                }
+            })
+            .collect();
+        rules.sort_by_key(|rule| std::cmp::Reverse(rule.specificity()));
+
+        Self {
+            rules,
+            buckets: DashMap::new(),
+        }
+    }
+
+    /// 是否配置了端点规则
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// 查找匹配请求的规则
+    pub fn resolve(&self, method: Option<&str>, path: &str) -> Option<&EndpointRateLimit> {
+        self.rules.iter().find(|rule| rule.matches(method, path))
+    }
+
+    /// 检查端点限流
+    ///
+    /// 没有匹配的规则时返回 `None`，由调用方继续走全局限流。
+    pub fn check(
+        &self,
+        api_key: &str,
+        method: Option<&str>,
+        path: &str,
+    ) -> Option<RateLimitResult> {
+        let (index, rule) = self
+            .rules
+            .iter()
+            .enumerate()
+            .find(|(_, rule)| rule.matches(method, path))?;
+
+        let capacity = rule.requests_per_minute as f64;
+        let refill_per_second = capacity / 60.0;
+        let now = Instant::now();
+
+        let mut bucket = self
+            .buckets
+            .entry(format!("{}|{}", api_key, index))
+            .or_insert(Bucket {
+                tokens: capacity,
+                last_refill: now,
+            });
+
+        let elapsed = now.duration_since(bucket.last_refill).as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed * refill_per_second).min(capacity);
+        bucket.last_refill = now;
+
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            Some(RateLimitResult::Allowed)
+        } else {
+            let wait = ((1.0 - bucket.tokens) / refill_per_second).ceil() as u64;
+            Some(RateLimitResult::RetryAfter {
+                retry_after_seconds: wait.max(1),
+            })
+        }
+    }
+
+    /// 清理闲置超过 `idle` 的令牌桶，返回清理数量
+    pub fn cleanup_idle(&self, idle: Duration) -> u64 {
+        let before = self.buckets.len();
+        let now = Instant::now();
+        self.buckets
+            .retain(|_, bucket| now.duration_since(bucket.last_refill) < idle);
+        (before - self.buckets.len()) as u64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn limiter() -> EndpointRateLimiter {
+        EndpointRateLimiter::new(vec![
+            EndpointRateLimit::new("/v1/*", 100),
+            EndpointRateLimit::new("/v1/search", 2),
+            EndpointRateLimit::new("/v1/webhooks", 1).with_method("POST"),
+        ])
+    }
+
+    #[test]
+    fn test_unmatched_path_returns_none() {
+        let limiter = limiter();
+        assert!(limiter.check("key", None, "/health").is_none());
+    }
+
+    #[test]
+    fn test_resolve_picks_most_specific_rule() {
+        let limiter = limiter();
+        assert_eq!(
+            limiter
+                .resolve(None, "/v1/search")
+                .unwrap()
+                .requests_per_minute,
+            2
+        );
+        assert_eq!(
+            limiter
+                .resolve(Some("POST"), "/v1/webhooks")
+                .unwrap()
+                .requests_per_minute,
+            1
+        );
+        assert_eq!(
+            limiter
+                .resolve(Some("GET"), "/v1/webhooks")
+                .unwrap()
+                .requests_per_minute,
+            100
+        );
+    }
+
+    #[test]
+    fn test_bucket_exhaustion_returns_retry_after() {
+        let limiter = limiter();
+        assert_eq!(
+            limiter.check("key", None, "/v1/search"),
+            Some(RateLimitResult::Allowed)
+        );
+        assert_eq!(
+            limiter.check("key", None, "/v1/search"),
+            Some(RateLimitResult::Allowed)
+        );
+        match limiter.check("key", None, "/v1/search") {
+            Some(RateLimitResult::RetryAfter {
+                retry_after_seconds,
+            }) => assert!((1..=30).contains(&retry_after_seconds)),
+            other => panic!("Expected RetryAfter, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_buckets_are_isolated_per_api_key_and_route() {
+        let limiter = limiter();
+        limiter.check("key-a", None, "/v1/search");
+        limiter.check("key-a", None, "/v1/search");
+        assert!(matches!(
+            limiter.check("key-a", None, "/v1/search"),
+            Some(RateLimitResult::RetryAfter { .. })
+        ));
+        assert_eq!(
+            limiter.check("key-b", None, "/v1/search"),
+            Some(RateLimitResult::Allowed)
+        );
+        assert_eq!(
+            limiter.check("key-a", None, "/v1/scrape"),
+            Some(RateLimitResult::Allowed)
+        );
+    }
+
+    #[test]
+    fn test_invalid_rules_are_ignored() {
+        let limiter = EndpointRateLimiter::new(vec![
+            EndpointRateLimit::new("/v1/search", 0),
+            EndpointRateLimit::new("no-slash", 10),
+        ]);
+        assert!(limiter.is_empty());
+    }
+
+    #[test]
+    fn test_cleanup_idle_removes_stale_buckets() {
+        let limiter = limiter();
+        limiter.check("key", None, "/v1/search");
+        assert_eq!(limiter.cleanup_idle(Duration::from_secs(3600)), 0);
+        assert_eq!(limiter.cleanup_idle(Duration::ZERO), 1);
+    }
+}
@@ -8,22 +8,30 @@
 //! 使用 limiteron 库实现速率限制、并发控制和配额管理功能
 
 use std::sync::Arc;
+use std::time::Duration;
 
 use ahash::AHashMap;
 use async_trait::async_trait;
 use chrono::Utc;
+use dashmap::DashMap;
 use limiteron::prelude::*;
 use limiteron::storage::{BanStorage, MemoryBanStorage, MemoryStorage, Storage};
 use log::{debug, warn};
 
+use crate::config::reload::ReloadableSettings;
 use crate::domain::repositories::{
     credits_repository::CreditsRepository, task_repository::TaskRepository,
     tasks_backlog_repository::TasksBacklogRepository,
 };
+use crate::domain::services::plan_service::PlanService;
 use crate::domain::services::rate_limiting_service::{
-    BacklogService, ConcurrencyConfig, ConcurrencyControlService, ConcurrencyResult, QuotaService,
-    RateLimitConfig, RateLimitResult, RateLimitService, RateLimitingError, RateLimitingService,
+    BacklogService, ConcurrencyConfig, ConcurrencyControlService, ConcurrencyResult,
+    EndpointRateLimit, QuotaService, RateLimitConfig, RateLimitResult, RateLimitService,
+    RateLimitStrategy, RateLimitingError, RateLimitingService,
 };
+use crate::infrastructure::services::endpoint_rate_limiter::EndpointRateLimiter;
+use crate::infrastructure::services::token_bucket_rate_limiter::TokenBucketRateLimiter;
+use crate::infrastructure::services::window_rate_limiter::WindowRateLimiter;
 
 /// 限流服务配置
 #[derive(Debug, Clone)]
@@ -36,6 +44,8 @@ pub struct RateLimitingConfig {
     pub backlog_process_interval_seconds: u64,
     /// 限流记录过期时间（秒）
     pub rate_limit_ttl_seconds: u64,
+    /// 端点级限流规则
+    pub endpoint_limits: Vec<EndpointRateLimit>,
 }
 
 impl Default for RateLimitingConfig {
@@ -45,6 +55,7 @@ impl Default for RateLimitingConfig {
             concurrency: ConcurrencyConfig::default(),
             backlog_process_interval_seconds: 30,
             rate_limit_ttl_seconds: 3600,
+            endpoint_limits: Vec::new(),
         }
     }
 }
@@ -58,12 +69,24 @@ pub struct LimiteronService {
     governor: Arc<Governor>,
     /// 限流服务配置
     config: RateLimitingConfig,
+    /// 端点级限流器
+    endpoint_limiter: Arc<EndpointRateLimiter>,
+    /// 窗口计数限流器（策略为 FixedWindow / SlidingWindow 时替代 Governor）
+    window_limiter: Option<Arc<WindowRateLimiter>>,
+    /// 令牌桶限流器（策略为 TokenBucket 或团队有覆盖配置时使用）
+    token_bucket_limiter: Arc<TokenBucketRateLimiter>,
+    /// 团队级限流覆盖配置（持续速率 + 突发容量）
+    team_rate_limits: Arc<DashMap<uuid::Uuid, RateLimitConfig>>,
     /// 任务仓库
     task_repository: Arc<dyn TaskRepository>,
     /// 积压任务仓库
     tasks_backlog_repository: Arc<dyn TasksBacklogRepository>,
     /// 积分仓库
     credits_repository: Arc<dyn CreditsRepository>,
+    /// 团队套餐服务（可选，启用后未单独配置限流的团队按套餐限流与限制并发）
+    plan_service: Option<Arc<PlanService>>,
+    /// 可热重载的配置（可选，设置后全局速率与开关按当前配置生效）
+    reloadable_settings: Option<Arc<ReloadableSettings>>,
 }
 
 impl LimiteronService {
@@ -91,12 +114,78 @@ impl LimiteronService {
             .await
             .map_err(|e| RateLimitingError::ConfigurationError(e.to_string()))?;
 
+        let endpoint_limiter = Arc::new(EndpointRateLimiter::new(config.endpoint_limits.clone()));
+        let window_limiter = WindowRateLimiter::new(
+            config.rate_limit.strategy,
+            config.rate_limit.requests_per_minute,
+            Duration::from_secs(60),
+        )
+        .map(Arc::new);
+
         Ok(Self {
             governor: Arc::new(governor),
+            endpoint_limiter,
+            window_limiter,
+            token_bucket_limiter: Arc::new(TokenBucketRateLimiter::new()),
+            team_rate_limits: Arc::new(DashMap::new()),
             config,
             task_repository,
             tasks_backlog_repository,
             credits_repository,
+            plan_service: None,
+            reloadable_settings: None,
+        })
+    }
+
+    /// 启用团队套餐：未单独配置限流的团队按套餐的每分钟请求数限流，并按套餐限制并发
+    pub fn with_plan_service(mut self, plan_service: Arc<PlanService>) -> Self {
+        self.plan_service = Some(plan_service);
+        self
+    }
+
+    /// 绑定可热重载的配置：`rate_limiting` 的开关、每分钟请求数与突发容量在重载后立即生效
+    ///
+    /// 限流策略与端点规则在启动时确定，修改后需要重启。
+    pub fn with_reloadable_settings(mut self, settings: Arc<ReloadableSettings>) -> Self {
+        self.reloadable_settings = Some(settings);
+        self
+    }
+
+    /// 当前生效的全局限流配置
+    fn global_rate_limit(&self) -> RateLimitConfig {
+        let Some(settings) = &self.reloadable_settings else {
+            return self.config.rate_limit.clone();
+        };
+        let rate_limiting = &settings.current().rate_limiting;
+        let rpm = rate_limiting.default_rpm.max(1);
+        RateLimitConfig {
+            requests_per_second: (rpm / 60).max(1),
+            requests_per_minute: rpm,
+            requests_per_hour: rpm.saturating_mul(60),
+            bucket_capacity: Some(rate_limiting.burst_size),
+            enabled: rate_limiting.enabled,
+            ..self.config.rate_limit.clone()
+        }
+    }
+
+    /// 团队生效的限流配置：单独配置优先，其次为团队套餐；两者都没有时返回 `None`
+    async fn team_rate_limit_config(&self, team_id: uuid::Uuid) -> Option<RateLimitConfig> {
+        if let Some(config) = self.team_rate_limits.get(&team_id) {
+            return Some(config.value().clone());
+        }
+        let plan_service = self.plan_service.as_ref()?;
+        let rpm = plan_service
+            .limits_for(team_id)
+            .await
+            .requests_per_minute
+            .max(1);
+        let global = self.global_rate_limit();
+        Some(RateLimitConfig {
+            requests_per_second: (rpm / 60).max(1),
+            requests_per_minute: rpm,
+            requests_per_hour: rpm.saturating_mul(60),
+            bucket_capacity: Some(global.burst_capacity().min(rpm)),
+            ..global
         })
     }
 
@@ -153,13 +242,18 @@ impl LimiteronService {
     }
 
     /// 构建请求上下文
-    fn build_request_context(&self, api_key: &str, endpoint: &str) -> RequestContext {
+    fn build_request_context(
+        &self,
+        api_key: &str,
+        method: Option<&str>,
+        endpoint: &str,
+    ) -> RequestContext {
         RequestContext {
             ip: None,
             user_id: Some(api_key.to_string()), // 使用 API Key 作为 user_id
             api_key: Some(api_key.to_string()),
             path: endpoint.to_string(),
-            method: "GET".to_string(),
+            method: method.unwrap_or("GET").to_string(),
             headers: AHashMap::new(),
             query_params: AHashMap::new(),
             client_ip: None,
@@ -167,28 +261,80 @@ impl LimiteronService {
             device_id: None,
         }
     }
-}
 
-#[async_trait]
-impl RateLimitService for LimiteronService {
-    async fn check_rate_limit(
+    /// 检查 API 限流（端点规则优先，其次团队 / 全局规则）
+    async fn check_rate_limit_with_method(
         &self,
+        team_id: Option<uuid::Uuid>,
         api_key: &str,
+        method: Option<&str>,
         endpoint: &str,
     ) -> Result<RateLimitResult, RateLimitingError> {
         debug!(
-            "LimiteronService: Checking rate limit for API key: {}..., endpoint: {}",
+            "LimiteronService: Checking rate limit for API key: {}..., endpoint: {} {}",
             &api_key[..std::cmp::min(8, api_key.len())],
+            method.unwrap_or("*"),
             endpoint
         );
 
-        if !self.config.rate_limit.enabled {
-            debug!("LimiteronService: Rate limiting is disabled globally");
+        let team_config = match team_id {
+            Some(id) => self.team_rate_limit_config(id).await,
+            None => None,
+        };
+        let global = self.global_rate_limit();
+        let rate_limit = team_config.as_ref().unwrap_or(&global);
+
+        if !rate_limit.enabled {
+            debug!("LimiteronService: Rate limiting is disabled");
             return Ok(RateLimitResult::Allowed);
         }
 
+        // 端点级规则：按 API Key + 路由独立计数
+        if let Some(result) = self.endpoint_limiter.check(api_key, method, endpoint) {
+            if result != RateLimitResult::Allowed {
+                warn!(
+                    "LimiteronService: Endpoint rate limit exceeded for API key: {}..., endpoint: {}",
+                    &api_key[..std::cmp::min(8, api_key.len())],
+                    endpoint
+                );
+                return Ok(result);
+            }
+        }
+
+        // 令牌桶：突发容量内的短时峰值直接放行，长期速率受每分钟请求数约束。
+        // 团队覆盖配置总是按令牌桶执行。
+        if team_config.is_some() || rate_limit.strategy == RateLimitStrategy::TokenBucket {
+            let result = self.token_bucket_limiter.check(
+                api_key,
+                rate_limit.burst_capacity(),
+                rate_limit.requests_per_minute,
+            );
+            if result != RateLimitResult::Allowed {
+                warn!(
+                    "LimiteronService: Token bucket rate limit exceeded for API key: {}... (burst {}, {} rpm)",
+                    &api_key[..std::cmp::min(8, api_key.len())],
+                    rate_limit.burst_capacity(),
+                    rate_limit.requests_per_minute
+                );
+            }
+            return Ok(result);
+        }
+
+        // 窗口类策略：按 API Key 在窗口内计数
+        if let Some(window_limiter) = &self.window_limiter {
+            let result = window_limiter.check(api_key);
+            if result != RateLimitResult::Allowed {
+                warn!(
+                    "LimiteronService: {:?} rate limit exceeded for API key: {}...",
+                    window_limiter.strategy(),
+                    &api_key[..std::cmp::min(8, api_key.len())]
+                );
+            }
+            return Ok(result);
+        }
+
         // 构建请求上下文
-        let context = self.build_request_context(api_key, endpoint);
+        let context = self.build_request_context(api_key, method, endpoint);
 
         // 使用 Governor 检查限流
         match self.governor.check(&context).await {
@@ -227,28 +373,80 @@ impl RateLimitService for LimiteronService {
             }
         }
     }
+}
+
+#[async_trait]
+impl RateLimitService for LimiteronService {
+    async fn check_rate_limit(
+        &self,
+        api_key: &str,
+        endpoint: &str,
+    ) -> Result<RateLimitResult, RateLimitingError> {
+        self.check_rate_limit_with_method(None, api_key, None, endpoint)
+            .await
+    }
+
+    async fn check_rate_limit_for_method(
+        &self,
+        api_key: &str,
+        method: &str,
+        endpoint: &str,
+    ) -> Result<RateLimitResult, RateLimitingError> {
+        self.check_rate_limit_with_method(None, api_key, Some(method), endpoint)
+            .await
+    }
+
+    async fn check_team_rate_limit(
+        &self,
+        team_id: uuid::Uuid,
+        api_key: &str,
+        method: &str,
+        endpoint: &str,
+    ) -> Result<RateLimitResult, RateLimitingError> {
+        self.check_rate_limit_with_method(Some(team_id), api_key, Some(method), endpoint)
+            .await
+    }
 
     async fn get_team_rate_limit_config(
         &self,
-        _team_id: uuid::Uuid,
+        team_id: uuid::Uuid,
     ) -> Result<RateLimitConfig, RateLimitingError> {
-        Ok(self.config.rate_limit.clone())
+        Ok(self
+            .team_rate_limit_config(team_id)
+            .await
+            .unwrap_or_else(|| self.global_rate_limit()))
     }
 
     async fn update_team_rate_limit_config(
         &self,
-        _team_id: uuid::Uuid,
-        _config: RateLimitConfig,
+        team_id: uuid::Uuid,
+        config: RateLimitConfig,
     ) -> Result<(), RateLimitingError> {
-        // 目前不支持动态更新配置
-        // 未来可以添加动态配置更新逻辑
+        // 团队覆盖配置保存在内存中，按持续速率 + 突发容量执行令牌桶限流
+        if config.requests_per_minute == 0 {
+            return Err(RateLimitingError::ConfigurationError(
+                "requests_per_minute cannot be zero".to_string(),
+            ));
+        }
+        if config.bucket_capacity == Some(0) {
+            return Err(RateLimitingError::ConfigurationError(
+                "bucket_capacity cannot be zero".to_string(),
+            ));
+        }
+        self.team_rate_limits.insert(team_id, config);
         Ok(())
     }
 
     async fn cleanup_expired_rate_limits(&self) -> Result<u64, RateLimitingError> {
         // Limiteron 使用内存/数据库存储，自动处理过期
-        // 这里返回 0 表示没有需要清理的记录
-        Ok(0)
+        // 只需清理闲置的端点令牌桶和窗口计数
+        let idle = Duration::from_secs(self.config.rate_limit_ttl_seconds);
+        let mut removed = self.endpoint_limiter.cleanup_idle(idle);
+        removed += self.token_bucket_limiter.cleanup_idle(idle);
+        if let Some(window_limiter) = &self.window_limiter {
+            removed += window_limiter.cleanup_idle(idle);
+        }
+        Ok(removed)
     }
 }
 
@@ -281,13 +479,17 @@ impl ConcurrencyControlService for LimiteronService {
             }
         };
 
-        // 检查团队当前并发数
+        // 检查团队当前并发数（启用套餐时按团队套餐的并发上限）
         let current_concurrency = self.get_team_current_concurrency(team_id).await?;
+        let max_concurrency = match &self.plan_service {
+            Some(plan_service) => plan_service.limits_for(team_id).await.max_concurrency,
+            None => self.config.concurrency.max_concurrent_per_team,
+        };
 
-        if current_concurrency < self.config.concurrency.max_concurrent_per_team {
+        if current_concurrency < max_concurrency {
             debug!(
                 "LimiteronService: Team {} concurrency check passed (current: {}, max: {})",
-                team_id, current_concurrency, self.config.concurrency.max_concurrent_per_team
+                team_id, current_concurrency, max_concurrency
             );
             Ok(ConcurrencyResult::Allowed)
         } else {
@@ -629,6 +831,7 @@ mod tests {
     use crate::domain::models::credits_model::{CreditsTransaction, CreditsTransactionType};
     use crate::domain::models::task_domain::{TaskStatus, TaskType};
     use crate::domain::models::task_model::Task;
+    use crate::domain::models::{TeamPlan, TeamPlanAssignment};
     use crate::domain::repositories::credits_repository::{
         CreditsRepository, CreditsRepositoryError,
     };
@@ -638,8 +841,10 @@ mod tests {
     use crate::domain::repositories::tasks_backlog_repository::{
         TasksBacklog, TasksBacklogRepository, TasksBacklogStatus,
     };
+    use crate::domain::repositories::team_plan_repository::TeamPlanRepository;
     use crate::domain::services::rate_limiting_service::{
         BacklogService, ConcurrencyControlService, QuotaService, RateLimitService,
+        RateLimitStrategy,
     };
     use chrono::Utc;
     use std::collections::HashSet;
@@ -1080,11 +1285,14 @@ mod tests {
     async fn test_check_rate_limit_enabled_fails_open_to_allowed() {
         // SOURCE LIMITATION: build_request_context sets ip=None, client_ip=None,
         // empty headers → Governor cannot extract identifier → Err → fail-open → Allowed.
+        let mut config = RateLimitingConfig::default();
+        config.rate_limit.strategy = RateLimitStrategy::LeakyBucket;
+
         let service = make_service_with_mocks(
             Arc::new(MockTaskRepository::with_no_task()),
             Arc::new(MockBacklogRepository::new()),
             Arc::new(MockCreditsRepository::with_balance(100)),
-            RateLimitingConfig::default(),
+            config,
         )
         .await;
 
@@ -1093,6 +1301,343 @@ mod tests {
         assert_eq!(result.unwrap(), RateLimitResult::Allowed);
     }
 
+    #[tokio::test]
+    async fn test_check_rate_limit_endpoint_rule_throttles_matching_route() {
+        let config = RateLimitingConfig {
+            endpoint_limits: vec![EndpointRateLimit::new("/v1/search", 1)],
+            ..RateLimitingConfig::default()
+        };
+
+        let service = make_service_with_mocks(
+            Arc::new(MockTaskRepository::with_no_task()),
+            Arc::new(MockBacklogRepository::new()),
+            Arc::new(MockCreditsRepository::with_balance(100)),
+            config,
+        )
+        .await;
+
+        assert_eq!(
+            service.check_rate_limit("k", "/v1/search").await.unwrap(),
+            RateLimitResult::Allowed
+        );
+        assert!(matches!(
+            service.check_rate_limit("k", "/v1/search").await.unwrap(),
+            RateLimitResult::RetryAfter { .. }
+        ));
+        // Other routes keep using the global bucket
+        assert_eq!(
+            service.check_rate_limit("k", "/v1/scrape").await.unwrap(),
+            RateLimitResult::Allowed
+        );
+    }
+
+    #[tokio::test]
+    async fn test_check_rate_limit_for_method_applies_method_scoped_rule() {
+        let config = RateLimitingConfig {
+            endpoint_limits: vec![EndpointRateLimit::new("/v1/webhooks", 1).with_method("POST")],
+            ..RateLimitingConfig::default()
+        };
+
+        let service = make_service_with_mocks(
+            Arc::new(MockTaskRepository::with_no_task()),
+            Arc::new(MockBacklogRepository::new()),
+            Arc::new(MockCreditsRepository::with_balance(100)),
+            config,
+        )
+        .await;
+
+        for _ in 0..3 {
+            assert_eq!(
+                service
+                    .check_rate_limit_for_method("k", "GET", "/v1/webhooks")
+                    .await
+                    .unwrap(),
+                RateLimitResult::Allowed
+            );
+        }
+        assert_eq!(
+            service
+                .check_rate_limit_for_method("k", "POST", "/v1/webhooks")
+                .await
+                .unwrap(),
+            RateLimitResult::Allowed
+        );
+        assert!(matches!(
+            service
+                .check_rate_limit_for_method("k", "POST", "/v1/webhooks")
+                .await
+                .unwrap(),
+            RateLimitResult::RetryAfter { .. }
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_check_rate_limit_window_strategies_enforce_requests_per_minute() {
+        for strategy in [
+            RateLimitStrategy::FixedWindow,
+            RateLimitStrategy::SlidingWindow,
+        ] {
+            let mut config = RateLimitingConfig::default();
+            config.rate_limit.strategy = strategy;
+            config.rate_limit.requests_per_minute = 2;
+
+            let service = make_service_with_mocks(
+                Arc::new(MockTaskRepository::with_no_task()),
+                Arc::new(MockBacklogRepository::new()),
+                Arc::new(MockCreditsRepository::with_balance(100)),
+                config,
+            )
+            .await;
+
+            for _ in 0..2 {
+                assert_eq!(
+                    service.check_rate_limit("k", "/v1/scrape").await.unwrap(),
+                    RateLimitResult::Allowed
+                );
+            }
+            match service.check_rate_limit("k", "/v1/scrape").await.unwrap() {
+                RateLimitResult::RetryAfter {
+                    retry_after_seconds,
+                } => assert!((1..=60).contains(&retry_after_seconds)),
+                other => panic!("{:?}: expected RetryAfter, got {:?}", strategy, other),
+            }
+            assert_eq!(
+                service
+                    .check_rate_limit("other", "/v1/scrape")
+                    .await
+                    .unwrap(),
+                RateLimitResult::Allowed
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_check_rate_limit_token_bucket_allows_burst_then_throttles() {
+        let mut config = RateLimitingConfig::default();
+        config.rate_limit.requests_per_minute = 60;
+        config.rate_limit.bucket_capacity = Some(5);
+
+        let service = make_service_with_mocks(
+            Arc::new(MockTaskRepository::with_no_task()),
+            Arc::new(MockBacklogRepository::new()),
+            Arc::new(MockCreditsRepository::with_balance(100)),
+            config,
+        )
+        .await;
+
+        for _ in 0..5 {
+            assert_eq!(
+                service.check_rate_limit("k", "/v1/scrape").await.unwrap(),
+                RateLimitResult::Allowed
+            );
+        }
+        assert_eq!(
+            service.check_rate_limit("k", "/v1/scrape").await.unwrap(),
+            RateLimitResult::RetryAfter {
+                retry_after_seconds: 1
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_reloaded_settings_change_global_rate_limit() {
+        let mut settings = crate::bootstrap::config::load_settings().unwrap();
+        settings.rate_limiting.enabled = true;
+        settings.rate_limiting.default_rpm = 60;
+        settings.rate_limiting.burst_size = 2;
+        let reloadable = Arc::new(ReloadableSettings::new(settings.clone()));
+
+        let service = make_service_with_mocks(
+            Arc::new(MockTaskRepository::with_no_task()),
+            Arc::new(MockBacklogRepository::new()),
+            Arc::new(MockCreditsRepository::with_balance(100)),
+            RateLimitingConfig::default(),
+        )
+        .await
+        .with_reloadable_settings(reloadable.clone());
+
+        for _ in 0..2 {
+            assert_eq!(
+                service.check_rate_limit("k", "/v1/scrape").await.unwrap(),
+                RateLimitResult::Allowed
+            );
+        }
+        assert_ne!(
+            service.check_rate_limit("k", "/v1/scrape").await.unwrap(),
+            RateLimitResult::Allowed
+        );
+
+        // 关闭限流无需重启
+        settings.rate_limiting.enabled = false;
+        reloadable.apply(settings);
+        assert_eq!(
+            service.check_rate_limit("k", "/v1/scrape").await.unwrap(),
+            RateLimitResult::Allowed
+        );
+    }
+
+    #[tokio::test]
+    async fn test_team_rate_limit_override_sets_sustained_rate_and_burst() {
+        let service = make_service_with_mocks(
+            Arc::new(MockTaskRepository::with_no_task()),
+            Arc::new(MockBacklogRepository::new()),
+            Arc::new(MockCreditsRepository::with_balance(100)),
+            RateLimitingConfig::default(),
+        )
+        .await;
+
+        let team_id = Uuid::new_v4();
+        let team_config = RateLimitConfig {
+            requests_per_minute: 6,
+            bucket_capacity: Some(2),
+            ..RateLimitConfig::default()
+        };
+        service
+            .update_team_rate_limit_config(team_id, team_config)
+            .await
+            .unwrap();
+
+        let got = service.get_team_rate_limit_config(team_id).await.unwrap();
+        assert_eq!(got.requests_per_minute, 6);
+        assert_eq!(got.burst_capacity(), 2);
+
+        for _ in 0..2 {
+            assert_eq!(
+                service
+                    .check_team_rate_limit(team_id, "team-key", "POST", "/v1/scrape")
+                    .await
+                    .unwrap(),
+                RateLimitResult::Allowed
+            );
+        }
+        match service
+            .check_team_rate_limit(team_id, "team-key", "POST", "/v1/scrape")
+            .await
+            .unwrap()
+        {
+            RateLimitResult::RetryAfter {
+                retry_after_seconds,
+            } => assert!((1..=10).contains(&retry_after_seconds)),
+            other => panic!("expected RetryAfter, got {:?}", other),
+        }
+
+        // 其他团队仍使用全局配置
+        assert_eq!(
+            service
+                .check_team_rate_limit(Uuid::new_v4(), "other-key", "POST", "/v1/scrape")
+                .await
+                .unwrap(),
+            RateLimitResult::Allowed
+        );
+    }
+
+    struct FreePlanRepository;
+
+    #[async_trait]
+    impl TeamPlanRepository for FreePlanRepository {
+        async fn get_plan(&self, team_id: Uuid) -> Result<TeamPlanAssignment, RepositoryError> {
+            Ok(TeamPlanAssignment {
+                team_id,
+                plan: TeamPlan::Free,
+                credits_granted_at: None,
+            })
+        }
+
+        async fn set_plan(
+            &self,
+            team_id: Uuid,
+            _plan: TeamPlan,
+        ) -> Result<TeamPlanAssignment, RepositoryError> {
+            self.get_plan(team_id).await
+        }
+
+        async fn find_due_for_credit_grant(
+            &self,
+            _period_start: chrono::DateTime<Utc>,
+            _limit: u64,
+        ) -> Result<Vec<TeamPlanAssignment>, RepositoryError> {
+            Ok(Vec::new())
+        }
+
+        async fn claim_credit_grant(
+            &self,
+            _team_id: Uuid,
+            _period_start: chrono::DateTime<Utc>,
+            _granted_at: chrono::DateTime<Utc>,
+        ) -> Result<bool, RepositoryError> {
+            Ok(false)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_plan_limits_apply_to_teams_without_override() {
+        let settings = crate::config::settings::PlanSettings::default();
+        let service = make_service_with_mocks(
+            Arc::new(MockTaskRepository::with_no_task()),
+            Arc::new(MockBacklogRepository::new()),
+            Arc::new(MockCreditsRepository::with_balance(100)),
+            RateLimitingConfig::default(),
+        )
+        .await
+        .with_plan_service(Arc::new(PlanService::from_settings(
+            Arc::new(FreePlanRepository),
+            &settings,
+        )));
+        let team_id = Uuid::new_v4();
+        let free_rpm = settings.free.requests_per_minute;
+
+        let got = service.get_team_rate_limit_config(team_id).await.unwrap();
+        assert_eq!(got.requests_per_minute, free_rpm);
+        assert_eq!(got.burst_capacity(), free_rpm);
+
+        for _ in 0..free_rpm {
+            assert_eq!(
+                service
+                    .check_team_rate_limit(team_id, "free-key", "POST", "/v1/scrape")
+                    .await
+                    .unwrap(),
+                RateLimitResult::Allowed
+            );
+        }
+        assert_ne!(
+            service
+                .check_team_rate_limit(team_id, "free-key", "POST", "/v1/scrape")
+                .await
+                .unwrap(),
+            RateLimitResult::Allowed
+        );
+
+        // 单独配置的团队限流优先于套餐
+        service
+            .update_team_rate_limit_config(team_id, RateLimitConfig::default())
+            .await
+            .unwrap();
+        let got = service.get_team_rate_limit_config(team_id).await.unwrap();
+        assert_eq!(got.requests_per_minute, 100);
+    }
+
+    #[tokio::test]
+    async fn test_update_team_rate_limit_config_rejects_zero_burst() {
+        let service = make_service_with_mocks(
+            Arc::new(MockTaskRepository::with_no_task()),
+            Arc::new(MockBacklogRepository::new()),
+            Arc::new(MockCreditsRepository::with_balance(100)),
+            RateLimitingConfig::default(),
+        )
+        .await;
+
+        let config = RateLimitConfig {
+            bucket_capacity: Some(0),
+            ..RateLimitConfig::default()
+        };
+        assert!(matches!(
+            service
+                .update_team_rate_limit_config(Uuid::new_v4(), config)
+                .await,
+            Err(RateLimitingError::ConfigurationError(_))
+        ));
+    }
+
     #[tokio::test]
     async fn test_get_team_rate_limit_config_returns_clone() {
         let config = RateLimitingConfig::default();
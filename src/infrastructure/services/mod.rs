@@ -7,6 +7,13 @@
 ///
 /// 提供基础设施层的服务实现
 /// 包括限流服务等核心功能
+pub mod bandwidth_counter;
 pub mod config_service;
+pub mod endpoint_rate_limiter;
 pub mod limiteron_service;
+pub mod opensearch_indexer;
+pub mod result_uploader_impl;
+pub mod smtp_email_sender;
+pub mod token_bucket_rate_limiter;
 pub mod webhook_sender_impl;
+pub mod window_rate_limiter;
@@ -0,0 +1,477 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! HttpResultUploader - 使用 reqwest 的结果投递上传实现
+//!
+//! S3 目标使用 AWS Signature Version 4 签名的 `PUT Object` 请求：未配置 endpoint 时
+//! 使用 AWS 虚拟主机风格地址（存储桶名含 `.` 时改用路径风格，避免 TLS 证书不匹配），
+//! 配置 endpoint 时（MinIO、R2 等兼容服务）使用路径风格地址。
+//! 预签名目标将对象名追加到 URL 路径后，保留查询串并附带配置的请求头。
+
+use crate::domain::models::ResultDestination;
+use crate::domain::services::result_uploader::ResultUploader;
+use crate::utils::http_client::create_http_client;
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, KeyInit, Mac};
+use reqwest::Client;
+use sha2::{Digest, Sha256};
+use std::sync::Arc;
+use std::time::Duration;
+use url::Url;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// 上传超时时间（秒）
+const UPLOAD_TIMEOUT_SECS: u64 = 60;
+
+/// 错误信息中保留的最大响应体长度（字节）
+const MAX_ERROR_BODY_LEN: usize = 200;
+
+/// 结果投递上传实现
+#[derive(Clone)]
+pub struct HttpResultUploader {
+    /// HTTP 客户端
+    client: Arc<Client>,
+    /// 请求超时时间
+    timeout: Duration,
+}
+
+impl HttpResultUploader {
+    /// 创建新的 HttpResultUploader
+    pub fn new(client: Arc<Client>, timeout: Duration) -> Self {
+        Self { client, timeout }
+    }
+
+    /// 使用默认配置创建 HttpResultUploader
+    pub fn with_default_config() -> Self {
+        Self::new(
+            create_http_client(),
+            Duration::from_secs(UPLOAD_TIMEOUT_SECS),
+        )
+    }
+
+    /// 构建上传请求
+    fn build_request(
+        &self,
+        destination: &ResultDestination,
+        name: &str,
+        body: Vec<u8>,
+        content_type: &str,
+        now: DateTime<Utc>,
+    ) -> Result<reqwest::RequestBuilder> {
+        match destination {
+            ResultDestination::S3 {
+                access_key_id,
+                secret_access_key,
+                session_token,
+                region,
+                ..
+            } => {
+                let url = s3_object_url(destination, name)?;
+                let payload_hash = hex::encode(Sha256::digest(&body));
+                let amz_date = now.format("%Y%m%dT%H%M%SZ").to_string();
+                let authorization = sigv4_authorization(
+                    &SigningRequest {
+                        method: "PUT",
+                        url: &url,
+                        payload_hash: &payload_hash,
+                        amz_date: &amz_date,
+                        session_token: session_token.as_deref(),
+                    },
+                    access_key_id,
+                    secret_access_key,
+                    region,
+                )?;
+
+                let mut request = self
+                    .client
+                    .put(url)
+                    .header("Authorization", authorization)
+                    .header("x-amz-content-sha256", payload_hash)
+                    .header("x-amz-date", amz_date)
+                    .header("Content-Type", content_type);
+                if let Some(token) = session_token {
+                    request = request.header("x-amz-security-token", token);
+                }
+                Ok(request.body(body))
+            }
+            ResultDestination::Presigned { headers, .. } => {
+                let url = presigned_object_url(destination, name)?;
+                let mut request = self.client.put(url).header("Content-Type", content_type);
+                for (key, value) in headers.iter().flatten() {
+                    request = request.header(key.as_str(), value.as_str());
+                }
+                Ok(request.body(body))
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl ResultUploader for HttpResultUploader {
+    async fn upload(
+        &self,
+        destination: &ResultDestination,
+        name: &str,
+        body: Vec<u8>,
+        content_type: &str,
+    ) -> Result<String> {
+        let response = self
+            .build_request(destination, name, body, content_type, Utc::now())?
+            .timeout(self.timeout)
+            .send()
+            .await
+            .map_err(|e| anyhow!("Failed to upload result: {}", e))?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let mut body = response.text().await.unwrap_or_default();
+            if body.len() > MAX_ERROR_BODY_LEN {
+                let mut end = MAX_ERROR_BODY_LEN;
+                while !body.is_char_boundary(end) {
+                    end -= 1;
+                }
+                body.truncate(end);
+            }
+            return Err(anyhow!(
+                "Result upload failed with status {}: {}",
+                status.as_u16(),
+                body
+            ));
+        }
+        Ok(destination.location(name))
+    }
+}
+
+/// 需要签名的请求要素
+struct SigningRequest<'a> {
+    method: &'a str,
+    url: &'a Url,
+    payload_hash: &'a str,
+    amz_date: &'a str,
+    session_token: Option<&'a str>,
+}
+
+/// S3 对象的请求地址
+fn s3_object_url(destination: &ResultDestination, name: &str) -> Result<Url> {
+    let ResultDestination::S3 {
+        bucket,
+        region,
+        endpoint,
+        ..
+    } = destination
+    else {
+        return Err(anyhow!("Not an S3 destination"));
+    };
+    let key = uri_encode_path(&destination.object_key(name));
+    let url = match endpoint {
+        Some(endpoint) => format!("{}/{}/{}", endpoint.trim_end_matches('/'), bucket, key),
+        None if bucket.contains('.') => {
+            format!("https://s3.{}.amazonaws.com/{}/{}", region, bucket, key)
+        }
+        None => format!("https://{}.s3.{}.amazonaws.com/{}", bucket, region, key),
+    };
+    Url::parse(&url).map_err(|e| anyhow!("Invalid S3 object URL: {}", e))
+}
+
+/// 预签名目标中对象的请求地址：对象名追加到路径后，保留查询串
+fn presigned_object_url(destination: &ResultDestination, name: &str) -> Result<Url> {
+    let ResultDestination::Presigned { url, .. } = destination else {
+        return Err(anyhow!("Not a presigned destination"));
+    };
+    let mut url = Url::parse(url).map_err(|e| anyhow!("Invalid destination URL: {}", e))?;
+    let path = format!(
+        "{}/{}",
+        url.path().trim_end_matches('/'),
+        uri_encode_path(name)
+    );
+    url.set_path(&path);
+    url.set_fragment(None);
+    Ok(url)
+}
+
+/// 按 SigV4 规则编码对象路径：保留非保留字符与 `/`，其余字节编码为 `%XX`
+fn uri_encode_path(path: &str) -> String {
+    let mut encoded = String::with_capacity(path.len());
+    for byte in path.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' | b'/' => {
+                encoded.push(byte as char)
+            }
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
+
+/// HMAC-SHA256
+fn hmac_sha256(key: &[u8], data: &str) -> Result<Vec<u8>> {
+    let mut mac =
+        HmacSha256::new_from_slice(key).map_err(|e| anyhow!("Invalid HMAC key: {}", e))?;
+    mac.update(data.as_bytes());
+    Ok(mac.finalize().into_bytes().to_vec())
+}
+
+/// SigV4 签名密钥：依次以日期、区域、服务与 `aws4_request` 派生
+fn signing_key(
+    secret_access_key: &str,
+    date: &str,
+    region: &str,
+    service: &str,
+) -> Result<Vec<u8>> {
+    let date_key = hmac_sha256(format!("AWS4{}", secret_access_key).as_bytes(), date)?;
+    let region_key = hmac_sha256(&date_key, region)?;
+    let service_key = hmac_sha256(&region_key, service)?;
+    hmac_sha256(&service_key, "aws4_request")
+}
+
+/// 计算 S3 请求的 `Authorization` 头
+///
+/// 签名的请求头为 host、x-amz-content-sha256、x-amz-date 与（使用临时凭证时）
+/// x-amz-security-token；请求不带查询参数。
+fn sigv4_authorization(
+    request: &SigningRequest<'_>,
+    access_key_id: &str,
+    secret_access_key: &str,
+    region: &str,
+) -> Result<String> {
+    let host = match (request.url.host_str(), request.url.port()) {
+        (Some(host), Some(port)) => format!("{}:{}", host, port),
+        (Some(host), None) => host.to_string(),
+        (None, _) => return Err(anyhow!("S3 object URL has no host")),
+    };
+
+    let mut headers = vec![
+        ("host", host),
+        ("x-amz-content-sha256", request.payload_hash.to_string()),
+        ("x-amz-date", request.amz_date.to_string()),
+    ];
+    if let Some(token) = request.session_token {
+        headers.push(("x-amz-security-token", token.to_string()));
+    }
+    let canonical_headers: String = headers
+        .iter()
+        .map(|(name, value)| format!("{}:{}\n", name, value.trim()))
+        .collect();
+    let signed_headers = headers
+        .iter()
+        .map(|(name, _)| *name)
+        .collect::<Vec<_>>()
+        .join(";");
+
+    let canonical_request = format!(
+        "{}\n{}\n{}\n{}\n{}\n{}",
+        request.method,
+        request.url.path(),
+        request.url.query().unwrap_or_default(),
+        canonical_headers,
+        signed_headers,
+        request.payload_hash
+    );
+
+    let date = &request.amz_date[..8];
+    let scope = format!("{}/{}/s3/aws4_request", date, region);
+    let string_to_sign = format!(
+        "AWS4-HMAC-SHA256\n{}\n{}\n{}",
+        request.amz_date,
+        scope,
+        hex::encode(Sha256::digest(canonical_request.as_bytes()))
+    );
+    let key = signing_key(secret_access_key, date, region, "s3")?;
+    let signature = hex::encode(hmac_sha256(&key, &string_to_sign)?);
+
+    Ok(format!(
+        "AWS4-HMAC-SHA256 Credential={}/{}, SignedHeaders={}, Signature={}",
+        access_key_id, scope, signed_headers, signature
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use std::collections::HashMap;
+    use wiremock::matchers::{header, header_exists, method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn s3_destination(bucket: &str, endpoint: Option<String>) -> ResultDestination {
+        ResultDestination::S3 {
+            bucket: bucket.to_string(),
+            region: "us-east-1".to_string(),
+            endpoint,
+            prefix: Some("exports/".to_string()),
+            access_key_id: "AKIDEXAMPLE".to_string(),
+            secret_access_key: "wJalrXUtnFEMI/K7MDENG+bPxRfiCYEXAMPLEKEY".to_string(),
+            session_token: None,
+        }
+    }
+
+    #[test]
+    fn test_signing_key_matches_aws_example() {
+        // Example from the AWS SigV4 documentation ("Deriving the signing key")
+        let key = signing_key(
+            "wJalrXUtnFEMI/K7MDENG+bPxRfiCYEXAMPLEKEY",
+            "20120215",
+            "us-east-1",
+            "iam",
+        )
+        .unwrap();
+        assert_eq!(
+            hex::encode(key),
+            "f4780e2d9f65fa895f9c67b32ce1baf0b0d8a43505a000a1a9e090d414db404d"
+        );
+    }
+
+    #[test]
+    fn test_object_urls() {
+        let url = s3_object_url(&s3_destination("acme", None), "c/t 1.json").unwrap();
+        assert_eq!(
+            url.as_str(),
+            "https://acme.s3.us-east-1.amazonaws.com/exports/c/t%201.json"
+        );
+        let url = s3_object_url(&s3_destination("acme.results", None), "a.json").unwrap();
+        assert_eq!(
+            url.as_str(),
+            "https://s3.us-east-1.amazonaws.com/acme.results/exports/a.json"
+        );
+        let url = s3_object_url(
+            &s3_destination("acme", Some("http://minio:9000/".to_string())),
+            "a.json",
+        )
+        .unwrap();
+        assert_eq!(url.as_str(), "http://minio:9000/acme/exports/a.json");
+
+        let presigned = ResultDestination::Presigned {
+            url: "https://acct.blob.core.windows.net/results/?sv=2024&sig=a%2Bb".to_string(),
+            headers: None,
+        };
+        let url = presigned_object_url(&presigned, "c/t.json").unwrap();
+        assert_eq!(
+            url.as_str(),
+            "https://acct.blob.core.windows.net/results/c/t.json?sv=2024&sig=a%2Bb"
+        );
+    }
+
+    #[test]
+    fn test_sigv4_authorization_format() {
+        let url = Url::parse("https://acme.s3.us-east-1.amazonaws.com/exports/a.json").unwrap();
+        let payload_hash = hex::encode(Sha256::digest(b""));
+        let request = SigningRequest {
+            method: "PUT",
+            url: &url,
+            payload_hash: &payload_hash,
+            amz_date: "20240101T000000Z",
+            session_token: Some("token"),
+        };
+        let authorization =
+            sigv4_authorization(&request, "AKIDEXAMPLE", "secret", "us-east-1").unwrap();
+        assert!(authorization.starts_with(
+            "AWS4-HMAC-SHA256 Credential=AKIDEXAMPLE/20240101/us-east-1/s3/aws4_request, \
+             SignedHeaders=host;x-amz-content-sha256;x-amz-date;x-amz-security-token, Signature="
+        ));
+        let signature = authorization.rsplit('=').next().unwrap();
+        assert_eq!(signature.len(), 64);
+        // Signing is deterministic for the same request
+        assert_eq!(
+            authorization,
+            sigv4_authorization(&request, "AKIDEXAMPLE", "secret", "us-east-1").unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_upload_to_s3_compatible_endpoint() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("PUT"))
+            .and(path("/acme/exports/c/t.json"))
+            .and(header_exists("authorization"))
+            .and(header_exists("x-amz-date"))
+            .and(header("content-type", "application/json"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let uploader = HttpResultUploader::with_default_config();
+        let location = uploader
+            .upload(
+                &s3_destination("acme", Some(mock_server.uri())),
+                "c/t.json",
+                b"{}".to_vec(),
+                "application/json",
+            )
+            .await
+            .unwrap();
+        assert_eq!(location, "s3://acme/exports/c/t.json");
+    }
+
+    #[tokio::test]
+    async fn test_upload_to_presigned_prefix() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("PUT"))
+            .and(path("/results/c/t.json"))
+            .and(query_param("sig", "abc"))
+            .and(header("x-ms-blob-type", "BlockBlob"))
+            .respond_with(ResponseTemplate::new(201))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("PUT"))
+            .and(path("/results/c/denied.json"))
+            .respond_with(ResponseTemplate::new(403).set_body_string("AuthorizationFailure"))
+            .mount(&mock_server)
+            .await;
+
+        let destination = ResultDestination::Presigned {
+            url: format!("{}/results?sig=abc", mock_server.uri()),
+            headers: Some(HashMap::from([(
+                "x-ms-blob-type".to_string(),
+                "BlockBlob".to_string(),
+            )])),
+        };
+        let uploader = HttpResultUploader::with_default_config();
+        let location = uploader
+            .upload(&destination, "c/t.json", b"{}".to_vec(), "application/json")
+            .await
+            .unwrap();
+        assert_eq!(location, format!("{}/results/c/t.json", mock_server.uri()));
+
+        let error = uploader
+            .upload(
+                &destination,
+                "c/denied.json",
+                b"{}".to_vec(),
+                "application/json",
+            )
+            .await
+            .unwrap_err();
+        assert!(error.to_string().contains("status 403"));
+        assert!(error.to_string().contains("AuthorizationFailure"));
+    }
+
+    #[test]
+    fn test_build_request_sets_s3_headers() {
+        let uploader = HttpResultUploader::with_default_config();
+        let now = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let request = uploader
+            .build_request(
+                &s3_destination("acme", None),
+                "a.json",
+                b"{}".to_vec(),
+                "application/json",
+                now,
+            )
+            .unwrap()
+            .build()
+            .unwrap();
+        assert_eq!(request.method(), reqwest::Method::PUT);
+        assert_eq!(request.headers()["x-amz-date"], "20240101T000000Z");
+        assert_eq!(
+            request.headers()["x-amz-content-sha256"],
+            hex::encode(Sha256::digest(b"{}")).as_str()
+        );
+    }
+}
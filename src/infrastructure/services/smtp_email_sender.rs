@@ -0,0 +1,165 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! SmtpEmailSender - 基于 lettre 的 SMTP / Amazon SES 邮件发送实现
+//!
+//! Amazon SES 通过其 SMTP 接口发送，`smtp_host` 为空时按 `ses_region` 推导端点。
+//! 需要启用 `notify-email` 特性；未启用时 `build_email_sender` 在邮件通知开启时返回错误。
+
+use crate::config::settings::EmailSettings;
+use crate::domain::services::email_notification_service::EmailSender;
+use anyhow::Result;
+use std::sync::Arc;
+
+#[cfg(feature = "notify-email")]
+use crate::domain::services::email_notification_service::EmailMessage;
+#[cfg(feature = "notify-email")]
+use anyhow::anyhow;
+#[cfg(feature = "notify-email")]
+use async_trait::async_trait;
+#[cfg(feature = "notify-email")]
+use lettre::{
+    message::{header::ContentType, Mailbox},
+    transport::smtp::authentication::Credentials,
+    AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor,
+};
+#[cfg(feature = "notify-email")]
+use std::time::Duration;
+
+/// SMTP 邮件发送器
+///
+/// 每封邮件的所有收件人共用一次 SMTP 事务。
+#[cfg(feature = "notify-email")]
+pub struct SmtpEmailSender {
+    /// SMTP 连接池
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+    /// 发件人
+    from: Mailbox,
+}
+
+#[cfg(feature = "notify-email")]
+impl SmtpEmailSender {
+    /// 根据邮件配置创建发送器
+    ///
+    /// # 错误
+    /// SMTP 地址无效、加密方式未知或发件人地址无法解析时返回错误
+    pub fn from_settings(settings: &EmailSettings) -> Result<Self> {
+        let host = settings.resolved_smtp_host();
+        let builder = match settings.tls.to_ascii_lowercase().as_str() {
+            "starttls" => AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(&host)?,
+            "tls" => AsyncSmtpTransport::<Tokio1Executor>::relay(&host)?,
+            "none" => AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(&host),
+            other => return Err(anyhow!("Unknown email TLS mode: {}", other)),
+        };
+        let mut builder = builder
+            .port(settings.smtp_port)
+            .timeout(Some(Duration::from_secs(settings.timeout_seconds)));
+        if !settings.smtp_username.is_empty() {
+            builder = builder.credentials(Credentials::new(
+                settings.smtp_username.clone(),
+                settings.smtp_password().unwrap_or_default().to_string(),
+            ));
+        }
+        let from = settings
+            .from_address
+            .parse::<Mailbox>()
+            .map_err(|e| anyhow!("Invalid email from_address: {}", e))?;
+
+        Ok(Self {
+            transport: builder.build(),
+            from,
+        })
+    }
+}
+
+#[cfg(feature = "notify-email")]
+#[async_trait]
+impl EmailSender for SmtpEmailSender {
+    async fn send(&self, message: &EmailMessage) -> Result<()> {
+        let mut builder = Message::builder()
+            .from(self.from.clone())
+            .subject(message.subject.clone())
+            .header(ContentType::TEXT_PLAIN);
+        for to in &message.to {
+            let mailbox = to
+                .parse::<Mailbox>()
+                .map_err(|e| anyhow!("Invalid recipient {}: {}", to, e))?;
+            builder = builder.to(mailbox);
+        }
+        let email = builder
+            .body(message.text.clone())
+            .map_err(|e| anyhow!("Failed to build email: {}", e))?;
+
+        self.transport
+            .send(email)
+            .await
+            .map_err(|e| anyhow!("Failed to send email: {}", e))?;
+        Ok(())
+    }
+}
+
+/// 根据配置创建邮件发送器
+///
+/// # 返回值
+/// * `Ok(Some(sender))` - 邮件通知已开启
+/// * `Ok(None)` - 邮件通知未开启
+/// * `Err` - 配置无效，或未启用 `notify-email` 特性
+pub fn build_email_sender(settings: &EmailSettings) -> Result<Option<Arc<dyn EmailSender>>> {
+    if !settings.enabled {
+        return Ok(None);
+    }
+
+    #[cfg(feature = "notify-email")]
+    {
+        let sender: Arc<dyn EmailSender> = Arc::new(SmtpEmailSender::from_settings(settings)?);
+        Ok(Some(sender))
+    }
+    #[cfg(not(feature = "notify-email"))]
+    {
+        Err(anyhow::anyhow!(
+            "email notifications require the notify-email feature"
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_disabled_settings_build_no_sender() {
+        let settings = EmailSettings::default();
+        assert!(build_email_sender(&settings).unwrap().is_none());
+    }
+
+    #[cfg(feature = "notify-email")]
+    #[tokio::test]
+    async fn test_build_sender_rejects_invalid_from_address() {
+        let settings = EmailSettings {
+            enabled: true,
+            provider: "ses".to_string(),
+            ses_region: "us-east-1".to_string(),
+            from_address: "not an address".to_string(),
+            ..EmailSettings::default()
+        };
+        assert!(build_email_sender(&settings).is_err());
+
+        let settings = EmailSettings {
+            from_address: "crawlrs <alerts@example.com>".to_string(),
+            ..settings
+        };
+        assert!(build_email_sender(&settings).unwrap().is_some());
+    }
+
+    #[cfg(not(feature = "notify-email"))]
+    #[test]
+    fn test_enabled_settings_require_feature() {
+        let settings = EmailSettings {
+            enabled: true,
+            ..EmailSettings::default()
+        };
+        assert!(build_email_sender(&settings).is_err());
+    }
+}
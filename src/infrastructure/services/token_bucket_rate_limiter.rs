@@ -0,0 +1,172 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! 令牌桶限流器
+//!
+//! 实现 `RateLimitStrategy::TokenBucket`：桶容量为突发容量，按每分钟请求数
+//! 连续补充令牌。短时间的突发请求可以用完桶内令牌，长期速率仍受持续速率约束。
+//! 按 key 在内存中计数，不依赖 Redis。
+
+use std::time::{Duration, Instant};
+
+use dashmap::DashMap;
+
+use crate::domain::services::rate_limiting_service::RateLimitResult;
+
+/// 单个令牌桶的状态
+#[derive(Debug, Clone, Copy)]
+struct Bucket {
+    /// 当前可用令牌数
+    tokens: f64,
+    /// 上次补充令牌的时间
+    last_refill: Instant,
+}
+
+/// 令牌桶限流器
+///
+/// 容量与速率在每次检查时传入，使不同团队可以使用不同的持续速率和突发容量。
+#[derive(Debug, Default)]
+pub struct TokenBucketRateLimiter {
+    /// 各 key 的令牌桶
+    buckets: DashMap<String, Bucket>,
+}
+
+impl TokenBucketRateLimiter {
+    /// 创建令牌桶限流器
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 检查并消耗一个令牌
+    ///
+    /// * `burst_capacity` - 桶容量（突发请求数）
+    /// * `requests_per_minute` - 持续速率（每分钟补充的令牌数）
+    pub fn check(
+        &self,
+        key: &str,
+        burst_capacity: u32,
+        requests_per_minute: u32,
+    ) -> RateLimitResult {
+        self.check_at(key, burst_capacity, requests_per_minute, Instant::now())
+    }
+
+    /// 以指定时间点检查并消耗一个令牌
+    fn check_at(
+        &self,
+        key: &str,
+        burst_capacity: u32,
+        requests_per_minute: u32,
+        now: Instant,
+    ) -> RateLimitResult {
+        let capacity = f64::from(burst_capacity.max(1));
+        let refill_per_second = f64::from(requests_per_minute.max(1)) / 60.0;
+
+        let mut bucket = self.buckets.entry(key.to_string()).or_insert(Bucket {
+            tokens: capacity,
+            last_refill: now,
+        });
+
+        let elapsed = now
+            .saturating_duration_since(bucket.last_refill)
+            .as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed * refill_per_second).min(capacity);
+        bucket.last_refill = now;
+
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            RateLimitResult::Allowed
+        } else {
+            let wait = ((1.0 - bucket.tokens) / refill_per_second).ceil() as u64;
+            RateLimitResult::RetryAfter {
+                retry_after_seconds: wait.max(1),
+            }
+        }
+    }
+
+    /// 清理闲置超过 `idle` 的令牌桶，返回清理数量
+    pub fn cleanup_idle(&self, idle: Duration) -> u64 {
+        let before = self.buckets.len();
+        let now = Instant::now();
+        self.buckets
+            .retain(|_, bucket| now.saturating_duration_since(bucket.last_refill) < idle);
+        (before - self.buckets.len()) as u64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn allowed(limiter: &TokenBucketRateLimiter, at: Instant, n: usize) -> usize {
+        (0..n)
+            .filter(|_| limiter.check_at("key", 20, 60, at) == RateLimitResult::Allowed)
+            .count()
+    }
+
+    #[test]
+    fn test_burst_up_to_capacity_then_rejects() {
+        let limiter = TokenBucketRateLimiter::new();
+        let t0 = Instant::now();
+        assert_eq!(allowed(&limiter, t0, 25), 20);
+        assert_eq!(
+            limiter.check_at("key", 20, 60, t0),
+            RateLimitResult::RetryAfter {
+                retry_after_seconds: 1
+            }
+        );
+    }
+
+    #[test]
+    fn test_sustained_rate_enforced_after_burst() {
+        let limiter = TokenBucketRateLimiter::new();
+        let t0 = Instant::now();
+        assert_eq!(allowed(&limiter, t0, 20), 20);
+
+        // 60 RPM：每秒补充一个令牌，十秒后只放行十个请求
+        assert_eq!(allowed(&limiter, t0 + Duration::from_secs(10), 20), 10);
+    }
+
+    #[test]
+    fn test_refill_is_capped_at_burst_capacity() {
+        let limiter = TokenBucketRateLimiter::new();
+        let t0 = Instant::now();
+        assert_eq!(allowed(&limiter, t0, 20), 20);
+        assert_eq!(allowed(&limiter, t0 + Duration::from_secs(3600), 50), 20);
+    }
+
+    #[test]
+    fn test_retry_after_reflects_sustained_rate() {
+        let limiter = TokenBucketRateLimiter::new();
+        let t0 = Instant::now();
+        assert_eq!(limiter.check_at("key", 1, 6, t0), RateLimitResult::Allowed);
+        // 6 RPM：每十秒补充一个令牌
+        assert_eq!(
+            limiter.check_at("key", 1, 6, t0 + Duration::from_secs(4)),
+            RateLimitResult::RetryAfter {
+                retry_after_seconds: 6
+            }
+        );
+    }
+
+    #[test]
+    fn test_keys_are_isolated() {
+        let limiter = TokenBucketRateLimiter::new();
+        let t0 = Instant::now();
+        assert_eq!(limiter.check_at("a", 1, 60, t0), RateLimitResult::Allowed);
+        assert!(matches!(
+            limiter.check_at("a", 1, 60, t0),
+            RateLimitResult::RetryAfter { .. }
+        ));
+        assert_eq!(limiter.check_at("b", 1, 60, t0), RateLimitResult::Allowed);
+    }
+
+    #[test]
+    fn test_cleanup_idle_removes_stale_buckets() {
+        let limiter = TokenBucketRateLimiter::new();
+        limiter.check("key", 20, 60);
+        assert_eq!(limiter.cleanup_idle(Duration::from_secs(3600)), 0);
+        assert_eq!(limiter.cleanup_idle(Duration::ZERO), 1);
+    }
+}
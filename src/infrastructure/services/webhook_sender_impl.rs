@@ -8,7 +8,7 @@
 //! 此模块提供基于 reqwest 的 WebhookSender 实现。
 //! 支持超时控制、错误处理和响应状态检查。
 
-use crate::domain::services::webhook_sender::WebhookSender;
+use crate::domain::services::webhook_sender::{WebhookDeliveryResponse, WebhookSender};
 use crate::utils::http_client::create_http_client;
 use anyhow::{anyhow, Result};
 use async_trait::async_trait;
@@ -22,6 +22,9 @@ use std::time::Duration;
 /// Webhook 发送超时时间（秒）
 const WEBHOOK_TIMEOUT_SECS: u64 = 10;
 
+/// 同步测试投递时保留的最大响应体长度（字节）
+const MAX_RESPONSE_BODY_LEN: usize = 2048;
+
 /// WebhookSender 实现 - 使用 reqwest 发送 HTTP 请求
 ///
 /// 此实现使用 reqwest 库发送简单的 HTTP POST 请求，
@@ -152,6 +155,33 @@ impl WebhookSender for WebhookSenderImpl {
             ))
         }
     }
+
+    async fn send_with_response(
+        &self,
+        url: &str,
+        payload: &Value,
+        headers: Option<&HashMap<String, String>>,
+    ) -> Result<WebhookDeliveryResponse> {
+        let request_builder = self.build_request(url, payload, headers).await?;
+
+        let response = request_builder
+            .timeout(self.timeout)
+            .send()
+            .await
+            .map_err(|e| anyhow!("Failed to send webhook request: {}", e))?;
+
+        let status_code = response.status().as_u16();
+        let mut body = response.text().await.unwrap_or_default();
+        if body.len() > MAX_RESPONSE_BODY_LEN {
+            let mut end = MAX_RESPONSE_BODY_LEN;
+            while !body.is_char_boundary(end) {
+                end -= 1;
+            }
+            body.truncate(end);
+        }
+
+        Ok(WebhookDeliveryResponse { status_code, body })
+    }
 }
 
 #[cfg(test)]
@@ -342,4 +372,54 @@ mod tests {
         // Verify the error contains the status code
         assert!(error_msg.contains("500"));
     }
+
+    #[tokio::test]
+    async fn test_send_with_response_returns_non_success_status_and_body() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/webhook"))
+            .respond_with(ResponseTemplate::new(422).set_body_string("bad payload"))
+            .mount(&mock_server)
+            .await;
+
+        let sender = WebhookSenderImpl::new(
+            Arc::new(Client::builder().build().unwrap()),
+            Duration::from_secs(5),
+        );
+
+        let webhook_url = format!("{}/webhook", mock_server.uri());
+        let response = sender
+            .send_with_response(&webhook_url, &json!({"test": "data"}), None)
+            .await
+            .expect("non-2xx should still be Ok");
+
+        assert_eq!(response.status_code, 422);
+        assert_eq!(response.body, "bad payload");
+    }
+
+    #[tokio::test]
+    async fn test_send_with_response_truncates_body() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/webhook"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("x".repeat(5000)))
+            .mount(&mock_server)
+            .await;
+
+        let sender = WebhookSenderImpl::new(
+            Arc::new(Client::builder().build().unwrap()),
+            Duration::from_secs(5),
+        );
+
+        let webhook_url = format!("{}/webhook", mock_server.uri());
+        let response = sender
+            .send_with_response(&webhook_url, &json!({}), None)
+            .await
+            .unwrap();
+
+        assert_eq!(response.status_code, 200);
+        assert_eq!(response.body.len(), MAX_RESPONSE_BODY_LEN);
+    }
 }
@@ -0,0 +1,242 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! 窗口计数限流器
+//!
+//! 实现 `RateLimitStrategy::FixedWindow` 与 `RateLimitStrategy::SlidingWindow`
+//! （滑动窗口日志）两种策略，按 key 在内存中计数，不依赖 Redis。
+//!
+//! - 固定窗口：窗口从该 key 的首个请求开始计时，窗口内最多 `limit` 个请求，
+//!   窗口边界处允许短时间内出现两倍突发。
+//! - 滑动窗口日志：记录最近 `window` 内每个请求的时间戳，任意长度为 `window`
+//!   的区间内都不会超过 `limit` 个请求。
+
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+use dashmap::DashMap;
+
+use crate::domain::services::rate_limiting_service::{RateLimitResult, RateLimitStrategy};
+
+/// 单个 key 的窗口状态
+#[derive(Debug, Clone)]
+enum WindowState {
+    /// 固定窗口：窗口起点 + 已用次数
+    Fixed { window_start: Instant, count: u32 },
+    /// 滑动窗口日志：窗口内请求时间戳（升序）
+    Log(VecDeque<Instant>),
+}
+
+impl WindowState {
+    /// 最近一次活动时间，用于清理闲置 key
+    fn last_seen(&self) -> Option<Instant> {
+        match self {
+            WindowState::Fixed { window_start, .. } => Some(*window_start),
+            WindowState::Log(log) => log.back().copied(),
+        }
+    }
+}
+
+/// 窗口计数限流器
+#[derive(Debug)]
+pub struct WindowRateLimiter {
+    /// 限流策略（仅 FixedWindow / SlidingWindow）
+    strategy: RateLimitStrategy,
+    /// 窗口内允许的请求数
+    limit: u32,
+    /// 窗口长度
+    window: Duration,
+    /// 各 key 的窗口状态
+    state: DashMap<String, WindowState>,
+}
+
+impl WindowRateLimiter {
+    /// 创建窗口限流器
+    ///
+    /// 仅支持窗口类策略，其他策略返回 `None`。
+    pub fn new(strategy: RateLimitStrategy, limit: u32, window: Duration) -> Option<Self> {
+        match strategy {
+            RateLimitStrategy::FixedWindow | RateLimitStrategy::SlidingWindow => Some(Self {
+                strategy,
+                limit: limit.max(1),
+                window,
+                state: DashMap::new(),
+            }),
+            RateLimitStrategy::TokenBucket | RateLimitStrategy::LeakyBucket => None,
+        }
+    }
+
+    /// 当前使用的策略
+    pub fn strategy(&self) -> RateLimitStrategy {
+        self.strategy
+    }
+
+    /// 检查并记录一次请求
+    pub fn check(&self, key: &str) -> RateLimitResult {
+        self.check_at(key, Instant::now())
+    }
+
+    /// 以指定时间点检查并记录一次请求
+    fn check_at(&self, key: &str, now: Instant) -> RateLimitResult {
+        let mut entry = self
+            .state
+            .entry(key.to_string())
+            .or_insert_with(|| match self.strategy {
+                RateLimitStrategy::FixedWindow => WindowState::Fixed {
+                    window_start: now,
+                    count: 0,
+                },
+                _ => WindowState::Log(VecDeque::new()),
+            });
+
+        match &mut *entry {
+            WindowState::Fixed {
+                window_start,
+                count,
+            } => {
+                if now.saturating_duration_since(*window_start) >= self.window {
+                    *window_start = now;
+                    *count = 0;
+                }
+
+                if *count < self.limit {
+                    *count += 1;
+                    RateLimitResult::Allowed
+                } else {
+                    let reset_at = *window_start + self.window;
+                    Self::retry_after(reset_at.saturating_duration_since(now))
+                }
+            }
+            WindowState::Log(log) => {
+                while let Some(oldest) = log.front() {
+                    if now.saturating_duration_since(*oldest) >= self.window {
+                        log.pop_front();
+                    } else {
+                        break;
+                    }
+                }
+
+                if (log.len() as u32) < self.limit {
+                    log.push_back(now);
+                    RateLimitResult::Allowed
+                } else {
+                    // 最早的请求滑出窗口后才有空位
+                    let oldest = *log.front().expect("log is full, so it is not empty");
+                    let free_at = oldest + self.window;
+                    Self::retry_after(free_at.saturating_duration_since(now))
+                }
+            }
+        }
+    }
+
+    /// 将等待时长换算为向上取整的秒数（至少 1 秒）
+    fn retry_after(wait: Duration) -> RateLimitResult {
+        let seconds = wait.as_secs() + u64::from(wait.subsec_nanos() > 0);
+        RateLimitResult::RetryAfter {
+            retry_after_seconds: seconds.max(1),
+        }
+    }
+
+    /// 清理闲置超过 `idle` 的 key，返回清理数量
+    pub fn cleanup_idle(&self, idle: Duration) -> u64 {
+        let before = self.state.len();
+        let now = Instant::now();
+        self.state.retain(|_, state| {
+            state
+                .last_seen()
+                .is_some_and(|seen| now.saturating_duration_since(seen) < idle.max(self.window))
+        });
+        (before - self.state.len()) as u64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WINDOW: Duration = Duration::from_secs(60);
+
+    fn allowed(limiter: &WindowRateLimiter, at: Instant, n: usize) -> usize {
+        (0..n)
+            .filter(|_| limiter.check_at("key", at) == RateLimitResult::Allowed)
+            .count()
+    }
+
+    #[test]
+    fn test_non_window_strategies_are_rejected() {
+        assert!(WindowRateLimiter::new(RateLimitStrategy::TokenBucket, 5, WINDOW).is_none());
+        assert!(WindowRateLimiter::new(RateLimitStrategy::LeakyBucket, 5, WINDOW).is_none());
+    }
+
+    #[test]
+    fn test_fixed_window_limits_within_window() {
+        let limiter = WindowRateLimiter::new(RateLimitStrategy::FixedWindow, 3, WINDOW).unwrap();
+        let t0 = Instant::now();
+        assert_eq!(allowed(&limiter, t0, 5), 3);
+        assert_eq!(
+            limiter.check_at("key", t0 + Duration::from_secs(20)),
+            RateLimitResult::RetryAfter {
+                retry_after_seconds: 40
+            }
+        );
+        assert_eq!(allowed(&limiter, t0 + WINDOW, 1), 1);
+    }
+
+    #[test]
+    fn test_sliding_window_retry_after_tracks_oldest_request() {
+        let limiter = WindowRateLimiter::new(RateLimitStrategy::SlidingWindow, 2, WINDOW).unwrap();
+        let t0 = Instant::now();
+        assert_eq!(allowed(&limiter, t0, 1), 1);
+        assert_eq!(allowed(&limiter, t0 + Duration::from_secs(30), 1), 1);
+        assert_eq!(
+            limiter.check_at("key", t0 + Duration::from_millis(45_500)),
+            RateLimitResult::RetryAfter {
+                retry_after_seconds: 15
+            }
+        );
+        // 首个请求滑出窗口后释放一个名额
+        assert_eq!(allowed(&limiter, t0 + WINDOW, 2), 1);
+    }
+
+    #[test]
+    fn test_burst_at_window_boundary_fixed_vs_sliding() {
+        let fixed = WindowRateLimiter::new(RateLimitStrategy::FixedWindow, 5, WINDOW).unwrap();
+        let sliding = WindowRateLimiter::new(RateLimitStrategy::SlidingWindow, 5, WINDOW).unwrap();
+        let t0 = Instant::now();
+        let late = t0 + Duration::from_secs(59);
+        let next = t0 + WINDOW;
+
+        // 开窗后一个请求，窗口末尾突发四个
+        for limiter in [&fixed, &sliding] {
+            assert_eq!(allowed(limiter, t0, 1), 1);
+            assert_eq!(allowed(limiter, late, 4), 4);
+        }
+
+        // 固定窗口在边界处重置，一秒内共放行 9 个请求
+        assert_eq!(allowed(&fixed, next, 5), 5);
+        // 滑动窗口只释放滑出窗口的那一个名额
+        assert_eq!(allowed(&sliding, next, 5), 1);
+    }
+
+    #[test]
+    fn test_keys_are_isolated() {
+        let limiter = WindowRateLimiter::new(RateLimitStrategy::SlidingWindow, 1, WINDOW).unwrap();
+        let t0 = Instant::now();
+        assert_eq!(limiter.check_at("a", t0), RateLimitResult::Allowed);
+        assert!(matches!(
+            limiter.check_at("a", t0),
+            RateLimitResult::RetryAfter { .. }
+        ));
+        assert_eq!(limiter.check_at("b", t0), RateLimitResult::Allowed);
+    }
+
+    #[test]
+    fn test_cleanup_idle_keeps_active_keys() {
+        let limiter = WindowRateLimiter::new(RateLimitStrategy::FixedWindow, 1, WINDOW).unwrap();
+        limiter.check("key");
+        assert_eq!(limiter.cleanup_idle(Duration::from_secs(3600)), 0);
+        assert_eq!(limiter.state.len(), 1);
+    }
+}
@@ -0,0 +1,219 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! Local filesystem storage repository
+
+use crate::domain::repositories::storage_repository::{
+    team_storage_key, StorageRepository, StoredObject,
+};
+use crate::domain::repositories::task_repository::RepositoryError;
+use async_trait::async_trait;
+use std::path::{Component, Path, PathBuf};
+use uuid::Uuid;
+
+/// Suffix of the sidecar file holding an object's content type
+const CONTENT_TYPE_SUFFIX: &str = ".content-type";
+
+/// Storage repository backed by a local directory
+///
+/// Objects are written to `{root}/{team_id}/{name}` with the content type in a sidecar
+/// file, and exposed as `{public_base_url}/{team_id}/{name}`.
+#[derive(Debug, Clone)]
+pub struct LocalStorageRepository {
+    /// Root directory for stored objects
+    root: PathBuf,
+    /// Base URL under which stored objects are served
+    public_base_url: String,
+}
+
+impl LocalStorageRepository {
+    /// Create new local storage repository instance
+    pub fn new(root: impl Into<PathBuf>, public_base_url: impl Into<String>) -> Self {
+        Self {
+            root: root.into(),
+            public_base_url: public_base_url.into().trim_end_matches('/').to_string(),
+        }
+    }
+
+    /// Resolve an object name to a path inside the team's directory, rejecting
+    /// absolute names and `..`
+    fn object_path(&self, team_id: Uuid, name: &str) -> Result<PathBuf, RepositoryError> {
+        let relative = Path::new(name);
+        let valid = !name.is_empty()
+            && relative
+                .components()
+                .all(|component| matches!(component, Component::Normal(_)));
+        if !valid {
+            return Err(RepositoryError::Database(anyhow::anyhow!(
+                "Invalid storage object name: {}",
+                name
+            )));
+        }
+        Ok(self.root.join(team_id.to_string()).join(relative))
+    }
+
+    fn content_type_path(path: &Path) -> PathBuf {
+        let mut name = path.as_os_str().to_owned();
+        name.push(CONTENT_TYPE_SUFFIX);
+        PathBuf::from(name)
+    }
+}
+
+fn io_error(err: std::io::Error) -> RepositoryError {
+    RepositoryError::Database(err.into())
+}
+
+#[async_trait]
+impl StorageRepository for LocalStorageRepository {
+    async fn put(
+        &self,
+        team_id: Uuid,
+        name: &str,
+        bytes: &[u8],
+        content_type: &str,
+    ) -> Result<String, RepositoryError> {
+        let path = self.object_path(team_id, name)?;
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent).await.map_err(io_error)?;
+        }
+        tokio::fs::write(&path, bytes).await.map_err(io_error)?;
+        tokio::fs::write(Self::content_type_path(&path), content_type)
+            .await
+            .map_err(io_error)?;
+
+        Ok(format!(
+            "{}/{}",
+            self.public_base_url,
+            team_storage_key(team_id, name)
+        ))
+    }
+
+    async fn get(
+        &self,
+        team_id: Uuid,
+        name: &str,
+    ) -> Result<Option<StoredObject>, RepositoryError> {
+        let path = self.object_path(team_id, name)?;
+        let bytes = match tokio::fs::read(&path).await {
+            Ok(bytes) => bytes,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(io_error(e)),
+        };
+        let content_type = tokio::fs::read_to_string(Self::content_type_path(&path))
+            .await
+            .unwrap_or_else(|_| "application/octet-stream".to_string());
+
+        Ok(Some(StoredObject {
+            bytes,
+            content_type,
+        }))
+    }
+
+    async fn delete(&self, team_id: Uuid, name: &str) -> Result<bool, RepositoryError> {
+        let path = self.object_path(team_id, name)?;
+        let existed = match tokio::fs::remove_file(&path).await {
+            Ok(()) => true,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => false,
+            Err(e) => return Err(io_error(e)),
+        };
+        match tokio::fs::remove_file(Self::content_type_path(&path)).await {
+            Ok(()) => {}
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+            Err(e) => return Err(io_error(e)),
+        }
+        Ok(existed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_put_and_get_roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        let repo = LocalStorageRepository::new(dir.path(), "https://assets.example.com/");
+        let team_id = Uuid::new_v4();
+
+        let url = repo
+            .put(team_id, "abc123", b"%PDF-1.7", "application/pdf")
+            .await
+            .unwrap();
+        assert_eq!(
+            url,
+            format!("https://assets.example.com/{}/abc123", team_id)
+        );
+        assert!(dir.path().join(team_id.to_string()).join("abc123").exists());
+
+        let object = repo.get(team_id, "abc123").await.unwrap().unwrap();
+        assert_eq!(object.bytes, b"%PDF-1.7");
+        assert_eq!(object.content_type, "application/pdf");
+        assert!(repo.get(team_id, "missing").await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_teams_are_isolated() {
+        let dir = tempfile::tempdir().unwrap();
+        let repo = LocalStorageRepository::new(dir.path(), "/v1/assets");
+        let (team_a, team_b) = (Uuid::new_v4(), Uuid::new_v4());
+
+        repo.put(team_a, "report.pdf", b"a", "application/pdf")
+            .await
+            .unwrap();
+        repo.put(team_b, "report.pdf", b"b", "application/pdf")
+            .await
+            .unwrap();
+        assert_eq!(
+            repo.get(team_a, "report.pdf").await.unwrap().unwrap().bytes,
+            b"a"
+        );
+        assert_eq!(
+            repo.get(team_b, "report.pdf").await.unwrap().unwrap().bytes,
+            b"b"
+        );
+
+        // 对象名中嵌入其他团队的 ID 也只会落在本团队目录下
+        let crafted = format!("{}/report.pdf", team_b);
+        assert!(repo.get(team_a, &crafted).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_rejects_names_outside_team_directory() {
+        let dir = tempfile::tempdir().unwrap();
+        let repo = LocalStorageRepository::new(dir.path(), "/v1/assets");
+        let team_id = Uuid::new_v4();
+
+        for name in ["../escape", "/etc/passwd", "har/../../escape", ""] {
+            assert!(
+                repo.put(team_id, name, b"x", "text/plain").await.is_err(),
+                "{}",
+                name
+            );
+            assert!(repo.get(team_id, name).await.is_err(), "{}", name);
+            assert!(repo.delete(team_id, name).await.is_err(), "{}", name);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_delete_removes_object_and_content_type() {
+        let dir = tempfile::tempdir().unwrap();
+        let repo = LocalStorageRepository::new(dir.path(), "/v1/assets");
+        let team_id = Uuid::new_v4();
+
+        repo.put(team_id, "har/task.har", b"{}", "application/json")
+            .await
+            .unwrap();
+        assert!(repo.delete(team_id, "har/task.har").await.unwrap());
+        assert!(repo.get(team_id, "har/task.har").await.unwrap().is_none());
+        assert!(!dir
+            .path()
+            .join(team_id.to_string())
+            .join("har/task.har.content-type")
+            .exists());
+
+        // 重复删除视为对象不存在
+        assert!(!repo.delete(team_id, "har/task.har").await.unwrap());
+    }
+}
@@ -0,0 +1,12 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! 对象存储实现
+//!
+//! 保存下载模式抓取的二进制资源（图片、PDF、压缩包等）。
+
+pub mod local_storage;
+
+pub use local_storage::LocalStorageRepository;
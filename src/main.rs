@@ -27,6 +27,18 @@ fn parse_service_type(arg: Option<&str>) -> Result<ServiceType, String> {
     }
 }
 
+/// Whether the command line asks to print the effective configuration.
+///
+/// `--print-config` dumps the merged configuration (secrets redacted) and exits
+/// without starting a service.
+fn has_print_config_flag<I, S>(args: I) -> bool
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    args.into_iter().any(|arg| arg.as_ref() == "--print-config")
+}
+
 impl ServiceType {
     /// Parse service type from command line arguments.
     fn from_args() -> Self {
@@ -49,9 +61,10 @@ mod app {
     use crawlrs::bootstrap::routes::build_api_app_with_state;
     use crawlrs::di::modules::{
         CacheModule, DatabaseModule, EngineModule, HttpModule, InfrastructureModule,
-        RepositoryModule, ServiceModule, SettingsModule,
+        ReloadableSettingsModule, RepositoryModule, ServiceModule, SettingsModule,
     };
     use crawlrs::di::{CrawlRsState, CrawlRsStateExt};
+    use crawlrs::domain::services::result_indexer::ResultIndexer;
     use crawlrs::workers::manager::{WorkerManager, WorkerManagerConfig};
     use crawlrs::workers::{AbstractWorker, Worker};
     use std::env;
@@ -59,6 +72,146 @@ mod app {
     use tokio::net::TcpListener;
     use trait_kit::AsyncKit;
 
+    /// Build the plan service when team plans are enabled.
+    fn build_plan_service(
+        app_state: &CrawlRsState,
+        settings: &crawlrs::config::settings::Settings,
+    ) -> Option<Arc<crawlrs::domain::services::plan_service::PlanService>> {
+        settings.plans.enabled.then(|| {
+            let repository = Arc::new(
+                crawlrs::infrastructure::database::repositories::team_plan_repo_impl::TeamPlanRepositoryImpl::new(
+                    app_state.db_pool.clone(),
+                ),
+            );
+            Arc::new(crawlrs::domain::services::plan_service::PlanService::from_settings(
+                repository,
+                &settings.plans,
+            ))
+        })
+    }
+
+    /// Build the bandwidth service when bandwidth accounting is enabled.
+    fn build_bandwidth_service(
+        app_state: &CrawlRsState,
+        settings: &crawlrs::config::settings::Settings,
+    ) -> Option<Arc<crawlrs::domain::services::bandwidth_service::BandwidthService>> {
+        settings.bandwidth.enabled.then(|| {
+            let repository = Arc::new(
+                crawlrs::infrastructure::database::repositories::bandwidth_usage_repo_impl::BandwidthUsageRepositoryImpl::new(
+                    app_state.db_pool.clone(),
+                ),
+            );
+            Arc::new(
+                crawlrs::domain::services::bandwidth_service::BandwidthService::from_settings(
+                    app_state.bandwidth_counter.clone(),
+                    repository,
+                    &settings.bandwidth,
+                ),
+            )
+        })
+    }
+
+    /// Start rolling up the bandwidth counters into the daily usage table.
+    fn spawn_bandwidth_rollup_worker(
+        settings: &crawlrs::config::settings::Settings,
+        bandwidth_service: Arc<crawlrs::domain::services::bandwidth_service::BandwidthService>,
+    ) {
+        let bandwidth_rollup_worker = AbstractWorker::new(
+            Arc::new(
+                crawlrs::workers::bandwidth_rollup_worker::BandwidthRollupWorker::new(
+                    bandwidth_service,
+                ),
+            ),
+            std::time::Duration::from_secs(settings.bandwidth.rollup_interval_seconds.max(1)),
+        );
+        tokio::spawn(async move {
+            bandwidth_rollup_worker.run().await;
+        });
+    }
+
+    /// Start the monthly plan credit worker.
+    fn spawn_plan_credit_worker(
+        app_state: &CrawlRsState,
+        settings: &crawlrs::config::settings::Settings,
+        plan_service: Arc<crawlrs::domain::services::plan_service::PlanService>,
+    ) {
+        let plan_repository = Arc::new(
+            crawlrs::infrastructure::database::repositories::team_plan_repo_impl::TeamPlanRepositoryImpl::new(
+                app_state.db_pool.clone(),
+            ),
+        );
+        let plan_credit_worker = AbstractWorker::new(
+            Arc::new(crawlrs::workers::plan_credit_worker::PlanCreditWorker::new(
+                plan_repository,
+                app_state.credits_repo(),
+                plan_service,
+            )),
+            std::time::Duration::from_secs(settings.plans.grant_interval_seconds),
+        );
+        tokio::spawn(async move {
+            plan_credit_worker.run().await;
+        });
+    }
+
+    /// Start the proxy pool health checks when a pool is configured.
+    fn spawn_proxy_health_worker(
+        engines: &crawlrs::bootstrap::engines::EngineComponents,
+        settings: &crawlrs::config::settings::Settings,
+        http_client: Arc<reqwest::Client>,
+    ) {
+        let Some(pool) = engines.proxy_pool.clone() else {
+            return;
+        };
+        let health_check = &settings.proxy.health_check;
+        if !health_check.enabled {
+            log::info!("Proxy health checks disabled");
+            return;
+        }
+        let geo_location = Arc::new(
+            crawlrs::infrastructure::geolocation::GeoLocationServiceImpl::new(http_client),
+        );
+        let proxy_health_worker = AbstractWorker::new(
+            Arc::new(
+                crawlrs::workers::proxy_health_worker::ProxyHealthWorker::new(
+                    pool,
+                    health_check.clone(),
+                )
+                .with_geo_location(geo_location),
+            ),
+            std::time::Duration::from_secs(health_check.interval_seconds.max(1)),
+        );
+        tokio::spawn(async move {
+            proxy_health_worker.run().await;
+        });
+    }
+
+    /// Start refreshing provider proxies into the pool when providers are configured.
+    fn spawn_proxy_provider_worker(
+        engines: &crawlrs::bootstrap::engines::EngineComponents,
+        settings: &crawlrs::config::settings::Settings,
+        http_client: Arc<reqwest::Client>,
+    ) {
+        let Some(pool) = engines.proxy_pool.clone() else {
+            return;
+        };
+        let providers = crawlrs::engines::proxy_providers::build_providers(
+            &settings.proxy.providers,
+            http_client,
+        );
+        if providers.is_empty() {
+            return;
+        }
+        let proxy_provider_worker = AbstractWorker::new(
+            Arc::new(
+                crawlrs::workers::proxy_provider_worker::ProxyProviderWorker::new(pool, providers),
+            ),
+            std::time::Duration::from_secs(settings.proxy.provider_refresh_seconds.max(1)),
+        );
+        tokio::spawn(async move {
+            proxy_provider_worker.run().await;
+        });
+    }
+
     /// Start the API service.
     async fn start_api_service(
         app_state: &CrawlRsState,
@@ -93,6 +246,29 @@ mod app {
             expiration_worker.run().await;
         });
 
+        // Start stalled task reaper
+        let stalled_task_reaper = AbstractWorker::new(
+            app_state.stalled_task_reaper(),
+            std::time::Duration::from_secs(settings.timeouts.workers.reaper_interval_seconds),
+        );
+        tokio::spawn(async move {
+            stalled_task_reaper.run().await;
+        });
+
+        // Start monitor worker
+        let monitor_worker = AbstractWorker::new(
+            app_state.monitor_worker(),
+            std::time::Duration::from_secs(settings.timeouts.workers.monitor_interval_seconds),
+        );
+        tokio::spawn(async move {
+            monitor_worker.run().await;
+        });
+
+        // Start monthly plan credit worker (多实例并发发放由条件更新保证只发放一次)
+        if let Some(plan_service) = build_plan_service(app_state, &settings) {
+            spawn_plan_credit_worker(app_state, &settings, plan_service);
+        }
+
         // Build API app with dependencies
         let app = build_api_app_with_state(app_state, settings.clone());
 
@@ -149,7 +325,113 @@ mod app {
             default_concurrency_limit: settings.concurrency.default_team_limit as usize,
         };
 
-        let mut worker_manager = WorkerManager::new(deps, config);
+        // 爬取结束时向团队注册的 webhook 推送 crawl.summary 汇总事件，同时通知团队的邮件联系人与聊天频道
+        let webhook_management_service = Arc::new(
+            crawlrs::domain::services::webhook_service::WebhookManagementServiceImpl::new(
+                app_state.webhook_repo.clone(),
+                app_state.webhook_event_repo.clone(),
+                app_state.webhook_service(),
+            )
+            .with_notifier(app_state.notifier.clone()),
+        );
+        // 上游返回 429/503 时按域名自适应退避，节流状态存储在数据库中供 API 查询
+        let domain_throttle_repository = Arc::new(
+            crawlrs::infrastructure::database::repositories::domain_throttle_repo_impl::DomainThrottleRepositoryImpl::new(
+                app_state.db_pool.clone(),
+            ),
+        );
+        // 记录引擎尝试、推迟、重试与结束事件，供 GET /v2/tasks/{id}/timeline 查询
+        let task_event_repository = Arc::new(
+            crawlrs::infrastructure::database::repositories::task_event_repo_impl::TaskEventRepositoryImpl::new(
+                app_state.db_pool.clone(),
+            ),
+        );
+        // 展开链接时过滤全局与团队级 URL 黑名单条目（配置中的模式由 worker 自行加载）
+        let url_blocklist_repository = Arc::new(
+            crawlrs::infrastructure::database::repositories::url_blocklist_repo_impl::UrlBlocklistRepositoryImpl::new(
+                app_state.db_pool.clone(),
+            ),
+        );
+        // 记录爬取中页面之间的链接，供 GET /v1/crawl/{id}/graph 导出站点链接图
+        let crawl_link_repository = Arc::new(
+            crawlrs::infrastructure::database::repositories::crawl_link_repo_impl::CrawlLinkRepositoryImpl::new(
+                app_state.db_pool.clone(),
+            ),
+        );
+        // 下载模式保存的二进制资源，与 GET /v1/assets/{key} 共用同一存储目录
+        let storage_repository = Arc::new(
+            crawlrs::infrastructure::storage::LocalStorageRepository::new(
+                &settings.storage.local_path,
+                &settings.storage.public_base_url,
+            ),
+        );
+        // 团队数据导出任务将归档写入同一存储，下载链接同样由 GET /v1/assets/{key} 提供
+        let team_export_service = Arc::new(
+            crawlrs::domain::services::team_export_service::TeamExportService::new(
+                app_state.task_repo(),
+                app_state.crawl_repo(),
+                app_state.result_repo(),
+                app_state.webhook_repo.clone(),
+                app_state.credits_repo(),
+                storage_repository.clone(),
+            ),
+        );
+        let mut worker_manager = WorkerManager::new(deps, config)
+            .with_webhook_management_service(webhook_management_service)
+            .with_domain_throttle_repository(domain_throttle_repository)
+            .with_task_event_repository(task_event_repository)
+            .with_url_blocklist_repository(url_blocklist_repository)
+            .with_storage_repository(storage_repository)
+            .with_crawl_link_repository(crawl_link_repository)
+            // 爬取配置了 result_destination 时，将结果上传到客户自有的存储桶或预签名前缀
+            .with_result_uploader(Arc::new(
+                crawlrs::infrastructure::services::result_uploader_impl::HttpResultUploader::with_default_config(),
+            ))
+            // 保存爬取结果后通知 API 进程，供 ?follow=true 实时推送
+            .with_result_stream(app_state.result_stream.clone())
+            .with_team_export_service(team_export_service)
+            .with_pricing_service(
+                crawlrs::domain::services::pricing_service::PricingService::from_reloadable_settings(
+                    app_state.reloadable_settings.clone(),
+                ),
+            );
+        // 启用套餐时按团队套餐限制抓取并发
+        let plan_service = build_plan_service(app_state, &settings);
+        if let Some(plan_service) = &plan_service {
+            worker_manager = worker_manager.with_plan_service(plan_service.clone());
+        }
+        // 统计每个响应的下载字节数，团队达到每日下载上限后拒绝新任务
+        let bandwidth_service = build_bandwidth_service(app_state, &settings);
+        if let Some(bandwidth_service) = &bandwidth_service {
+            worker_manager = worker_manager.with_bandwidth_service(bandwidth_service.clone());
+        }
+        // 推迟与重试的任务按到期时间索引，到期时立即提升并唤醒空闲 worker
+        if settings.queue.delayed.enabled {
+            match crawlrs::queue::DelayedTaskScheduler::from_settings(
+                &settings.queue.delayed,
+                app_state.task_repo(),
+            )
+            .await
+            {
+                Ok(scheduler) => {
+                    worker_manager = worker_manager.with_delayed_scheduler(Arc::new(scheduler));
+                }
+                Err(e) => log::warn!(
+                    "Delayed task scheduler unavailable, falling back to polling: {}",
+                    e
+                ),
+            }
+        }
+        // 启用外部检索索引时，将保存的结果同步写入 OpenSearch
+        if settings.search_index.enabled {
+            let indexer = crawlrs::infrastructure::services::opensearch_indexer::OpenSearchResultIndexer::from_settings(
+                &settings.search_index,
+            );
+            if let Err(e) = indexer.ensure_index().await {
+                log::warn!("Search index not ready, indexing may fail: {}", e);
+            }
+            worker_manager = worker_manager.with_result_indexer(Arc::new(indexer));
+        }
 
         // Start workers
         let worker_count = settings.workers.count.resolve();
@@ -174,6 +456,34 @@ mod app {
             expiration_worker.run().await;
         });
 
+        // Start stalled task reaper
+        let stalled_task_reaper = AbstractWorker::new(
+            app_state.stalled_task_reaper(),
+            std::time::Duration::from_secs(settings.timeouts.workers.reaper_interval_seconds),
+        );
+        tokio::spawn(async move {
+            stalled_task_reaper.run().await;
+        });
+
+        // Start monitor worker
+        let monitor_worker = AbstractWorker::new(
+            app_state.monitor_worker(),
+            std::time::Duration::from_secs(settings.timeouts.workers.monitor_interval_seconds),
+        );
+        tokio::spawn(async move {
+            monitor_worker.run().await;
+        });
+
+        // Start monthly plan credit worker
+        if let Some(plan_service) = plan_service {
+            spawn_plan_credit_worker(app_state, &settings, plan_service);
+        }
+
+        // Start bandwidth rollup worker
+        if let Some(bandwidth_service) = bandwidth_service {
+            spawn_bandwidth_rollup_worker(&settings, bandwidth_service);
+        }
+
         // Keep the main thread alive
         tokio::signal::ctrl_c().await?;
         log::info!("Shutting down worker service...");
@@ -216,6 +526,8 @@ mod app {
             .map_err(|e| anyhow::anyhow!("register CacheModule: {e}"))?;
         kit.register::<RepositoryModule>()
             .map_err(|e| anyhow::anyhow!("register RepositoryModule: {e}"))?;
+        kit.register::<ReloadableSettingsModule>()
+            .map_err(|e| anyhow::anyhow!("register ReloadableSettingsModule: {e}"))?;
         kit.register::<EngineModule>()
             .map_err(|e| anyhow::anyhow!("register EngineModule: {e}"))?;
         kit.register::<InfrastructureModule>()
@@ -234,7 +546,23 @@ mod app {
 
         log::info!("Application dependencies initialized successfully");
 
-        // 5. Start service based on type
+        // 5. Validate engines before accepting work; failed engines are never routed to
+        let engines = kit
+            .require::<EngineModule>()
+            .map_err(|e| anyhow::anyhow!("require EngineModule: {e}"))?;
+        crawlrs::bootstrap::engines::run_engine_preflight(&engines, &settings.engines.preflight)
+            .await;
+        spawn_proxy_provider_worker(&engines, &settings, http_client.clone());
+        spawn_proxy_health_worker(&engines, &settings, http_client.clone());
+
+        // 6. Reload rate limits, engine routing and pricing on config file change or SIGHUP
+        crawlrs::bootstrap::config_watcher::spawn_config_watcher(
+            app_state.reloadable_settings.clone(),
+            crawlrs::config::settings::config_files(&crawlrs::config::settings::config_profile()),
+            crawlrs::bootstrap::config_watcher::DEFAULT_POLL_INTERVAL,
+        );
+
+        // 7. Start service based on type
         match ServiceType::from_args() {
             ServiceType::Api => {
                 start_api_service(&app_state, settings).await?;
@@ -250,6 +578,10 @@ mod app {
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
+    if has_print_config_flag(std::env::args().skip(1)) {
+        print!("{}", crawlrs::bootstrap::config::print_config()?);
+        return Ok(());
+    }
     app::run().await
 }
 
@@ -257,6 +589,14 @@ async fn main() -> anyhow::Result<()> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn tc_has_print_config_flag() {
+        assert!(has_print_config_flag(["--print-config"]));
+        assert!(has_print_config_flag(["worker", "--print-config"]));
+        assert!(!has_print_config_flag(["api"]));
+        assert!(!has_print_config_flag(Vec::<String>::new()));
+    }
+
     #[test]
     fn tc_parse_service_type_api() {
         let result = parse_service_type(Some("api"));
@@ -7,4 +7,5 @@
 ///
 /// 提供从HTTP请求中提取数据的工具
 /// 用于解析和验证请求中的参数和数据
+pub mod role;
 pub mod team_id;
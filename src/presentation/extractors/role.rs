@@ -0,0 +1,245 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+use crate::config::settings::Settings;
+use crate::domain::auth::ApiKeyRole;
+use crate::infrastructure::security::constant_time_eq_str;
+use crate::presentation::handlers::response_builder::errors;
+use crate::presentation::middleware::auth_middleware::AuthState;
+use axum::extract::FromRequestParts;
+use axum::http::request::Parts;
+use axum::response::Response;
+use std::sync::Arc;
+
+/// 运营方凭据请求头
+pub const OPERATOR_TOKEN_HEADER: &str = "x-operator-token";
+
+/// 要求调用方 API Key 至少具有成员角色，可创建任务、管理 webhook、查看计费
+#[derive(Debug, Clone)]
+pub struct RequireMember(pub AuthState);
+
+/// 要求调用方 API Key 具有管理员角色，可管理 Key 与限额
+#[derive(Debug, Clone)]
+pub struct RequireAdmin(pub AuthState);
+
+/// 要求调用方除 API Key 外还提交运营方凭据，用于团队能力、套餐等跨团队的运营接口
+///
+/// 团队 Admin Key 只能管理本团队，不能据此调整任意团队的能力或套餐。
+#[derive(Debug, Clone)]
+pub struct RequireOperator(pub AuthState);
+
+/// 调用方是否提交了有效的运营方凭据，缺少或不符时为 `false` 而不拒绝请求
+///
+/// 用于同时服务团队与运营方的接口：团队只能操作本团队的数据，运营方可跨团队或操作全局数据。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OperatorCredential(pub bool);
+
+/// 从鉴权中间件注入的 AuthState 校验角色
+///
+/// 未经鉴权返回 401，角色不足返回 403。
+fn require_role(parts: &Parts, role: ApiKeyRole) -> Result<AuthState, Box<Response>> {
+    let auth_state = parts
+        .extensions
+        .get::<AuthState>()
+        .cloned()
+        .ok_or_else(|| Box::new(errors::unauthorized("Authentication required")))?;
+
+    if auth_state.scope.has_permission(role.permission()) {
+        Ok(auth_state)
+    } else {
+        Err(Box::new(errors::forbidden(format!(
+            "{} role required",
+            role
+        ))))
+    }
+}
+
+/// `X-Operator-Token` 请求头是否与配置的运营方凭据一致，未配置凭据时始终为 false
+fn has_operator_token(parts: &Parts) -> bool {
+    let expected = parts
+        .extensions
+        .get::<Arc<Settings>>()
+        .and_then(|settings| settings.server.operator_token().map(str::to_string));
+    let provided = parts
+        .headers
+        .get(OPERATOR_TOKEN_HEADER)
+        .and_then(|value| value.to_str().ok());
+
+    match (expected, provided) {
+        (Some(expected), Some(provided)) => constant_time_eq_str(&expected, provided),
+        _ => false,
+    }
+}
+
+/// 校验 `X-Operator-Token` 请求头与配置的运营方凭据一致
+///
+/// 未经鉴权返回 401；未配置凭据、缺少或凭据不符返回 403。
+fn require_operator(parts: &Parts) -> Result<AuthState, Box<Response>> {
+    let auth_state = parts
+        .extensions
+        .get::<AuthState>()
+        .cloned()
+        .ok_or_else(|| Box::new(errors::unauthorized("Authentication required")))?;
+
+    if has_operator_token(parts) {
+        Ok(auth_state)
+    } else {
+        Err(Box::new(errors::forbidden("Operator credential required")))
+    }
+}
+
+impl<S: Send + Sync> FromRequestParts<S> for RequireMember {
+    type Rejection = Response;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        require_role(parts, ApiKeyRole::Member)
+            .map(Self)
+            .map_err(|response| *response)
+    }
+}
+
+impl<S: Send + Sync> FromRequestParts<S> for RequireAdmin {
+    type Rejection = Response;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        require_role(parts, ApiKeyRole::Admin)
+            .map(Self)
+            .map_err(|response| *response)
+    }
+}
+
+impl<S: Send + Sync> FromRequestParts<S> for RequireOperator {
+    type Rejection = Response;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        require_operator(parts)
+            .map(Self)
+            .map_err(|response| *response)
+    }
+}
+
+impl<S: Send + Sync> FromRequestParts<S> for OperatorCredential {
+    type Rejection = std::convert::Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        Ok(Self(has_operator_token(parts)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::test_helpers::create_test_db_pool;
+    use crate::domain::auth::ApiKeyScope;
+    use axum::http::{Request, StatusCode};
+    use uuid::Uuid;
+
+    fn parts_with_scope(scope: Option<ApiKeyScope>) -> Parts {
+        let (mut parts, _) = Request::builder().uri("/").body(()).unwrap().into_parts();
+        if let Some(scope) = scope {
+            let auth_state =
+                AuthState::new(create_test_db_pool(), Uuid::new_v4(), Uuid::new_v4(), scope);
+            parts.extensions.insert(auth_state);
+        }
+        parts
+    }
+
+    #[tokio::test]
+    async fn test_role_extractors_check_scope() {
+        let member = ApiKeyScope::default().with_role(ApiKeyRole::Member);
+
+        let mut parts = parts_with_scope(Some(member));
+        assert!(RequireMember::from_request_parts(&mut parts, &())
+            .await
+            .is_ok());
+        let rejection = RequireAdmin::from_request_parts(&mut parts, &())
+            .await
+            .unwrap_err();
+        assert_eq!(rejection.status(), StatusCode::FORBIDDEN);
+
+        let mut parts = parts_with_scope(Some(ApiKeyScope::read_only()));
+        let rejection = RequireMember::from_request_parts(&mut parts, &())
+            .await
+            .unwrap_err();
+        assert_eq!(rejection.status(), StatusCode::FORBIDDEN);
+
+        let mut parts = parts_with_scope(None);
+        let rejection = RequireMember::from_request_parts(&mut parts, &())
+            .await
+            .unwrap_err();
+        assert_eq!(rejection.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn test_operator_extractor_requires_operator_token() {
+        let mut settings = Settings::default();
+        settings.server.operator_token = Some("operator-secret".to_string());
+        let settings = Arc::new(settings);
+        let admin = ApiKeyScope::default().with_role(ApiKeyRole::Admin);
+
+        // 团队 Admin Key 未提交运营方凭据
+        let mut parts = parts_with_scope(Some(admin.clone()));
+        parts.extensions.insert(settings.clone());
+        let rejection = RequireOperator::from_request_parts(&mut parts, &())
+            .await
+            .unwrap_err();
+        assert_eq!(rejection.status(), StatusCode::FORBIDDEN);
+
+        let mut parts = parts_with_scope(Some(admin.clone()));
+        parts.extensions.insert(settings.clone());
+        parts
+            .headers
+            .insert(OPERATOR_TOKEN_HEADER, "wrong".parse().unwrap());
+        let rejection = RequireOperator::from_request_parts(&mut parts, &())
+            .await
+            .unwrap_err();
+        assert_eq!(rejection.status(), StatusCode::FORBIDDEN);
+
+        let mut parts = parts_with_scope(Some(admin.clone()));
+        parts.extensions.insert(settings);
+        parts
+            .headers
+            .insert(OPERATOR_TOKEN_HEADER, "operator-secret".parse().unwrap());
+        assert!(RequireOperator::from_request_parts(&mut parts, &())
+            .await
+            .is_ok());
+
+        // 未配置凭据时一律拒绝
+        let mut parts = parts_with_scope(Some(admin));
+        parts.extensions.insert(Arc::new(Settings::default()));
+        parts
+            .headers
+            .insert(OPERATOR_TOKEN_HEADER, "operator-secret".parse().unwrap());
+        let rejection = RequireOperator::from_request_parts(&mut parts, &())
+            .await
+            .unwrap_err();
+        assert_eq!(rejection.status(), StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test]
+    async fn test_operator_credential_reports_without_rejecting() {
+        let mut settings = Settings::default();
+        settings.server.operator_token = Some("operator-secret".to_string());
+        let settings = Arc::new(settings);
+        let admin = ApiKeyScope::default().with_role(ApiKeyRole::Admin);
+
+        let mut parts = parts_with_scope(Some(admin.clone()));
+        parts.extensions.insert(settings.clone());
+        let credential = OperatorCredential::from_request_parts(&mut parts, &())
+            .await
+            .unwrap();
+        assert_eq!(credential, OperatorCredential(false));
+
+        let mut parts = parts_with_scope(Some(admin));
+        parts.extensions.insert(settings);
+        parts
+            .headers
+            .insert(OPERATOR_TOKEN_HEADER, "operator-secret".parse().unwrap());
+        let credential = OperatorCredential::from_request_parts(&mut parts, &())
+            .await
+            .unwrap();
+        assert_eq!(credential, OperatorCredential(true));
+    }
+}
@@ -0,0 +1,469 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! GraphQL 查询接口（`graphql` 特性）
+//!
+//! `POST /v1/graphql` 以只读方式暴露爬取、任务与抓取结果，支持嵌套查询
+//! （爬取 → 任务 → 结果）、过滤与分页，便于前端一次请求取回所需字段。
+//! 与 REST 接口共用认证中间件，所有查询按认证团队隔离；
+//! 查询深度与复杂度设有上限，防止单个请求放大为大量数据库查询。
+
+pub mod query;
+pub mod types;
+
+use std::fmt::Display;
+use std::sync::Arc;
+
+use async_graphql::dataloader::DataLoader;
+use async_graphql::{EmptyMutation, EmptySubscription, Schema};
+use async_graphql_axum::{GraphQLRequest, GraphQLResponse};
+use axum::{routing::post, Extension, Router};
+use uuid::Uuid;
+
+use crate::domain::repositories::crawl_repository::CrawlRepository;
+use crate::domain::repositories::scrape_result_repository::ScrapeResultRepository;
+use crate::domain::repositories::task_repository::TaskRepository;
+use crate::presentation::middleware::auth_middleware::AuthState;
+
+use self::query::QueryRoot;
+use self::types::ResultLoader;
+
+/// 单页最大条数
+pub const MAX_PAGE_SIZE: u32 = 100;
+
+/// 查询最大嵌套深度
+pub const MAX_QUERY_DEPTH: usize = 10;
+
+/// 查询最大复杂度（列表字段按页大小乘以子字段复杂度计）
+pub const MAX_QUERY_COMPLEXITY: usize = 5000;
+
+/// crawlrs GraphQL schema
+pub type CrawlrsSchema = Schema<QueryRoot, EmptyMutation, EmptySubscription>;
+
+/// 当前请求所属团队，由认证信息注入
+#[derive(Debug, Clone, Copy)]
+pub struct TeamScope(pub Uuid);
+
+/// 将请求的页大小限制在 1..=MAX_PAGE_SIZE
+pub fn page_size(limit: u32) -> u32 {
+    limit.clamp(1, MAX_PAGE_SIZE)
+}
+
+/// 记录内部错误，只向客户端返回通用信息
+fn internal_error(e: impl Display) -> async_graphql::Error {
+    log::error!("GraphQL query failed: {}", e);
+    async_graphql::Error::new("Internal server error")
+}
+
+/// 构建 schema
+pub fn build_schema(
+    crawl_repo: Arc<dyn CrawlRepository>,
+    task_repo: Arc<dyn TaskRepository>,
+    result_repo: Arc<dyn ScrapeResultRepository>,
+) -> CrawlrsSchema {
+    Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+        .data(crawl_repo)
+        .data(task_repo)
+        // 未启用缓存：只合并并发加载，不在请求之间共享数据
+        .data(DataLoader::new(
+            ResultLoader::new(result_repo),
+            tokio::spawn,
+        ))
+        .limit_depth(MAX_QUERY_DEPTH)
+        .limit_complexity(MAX_QUERY_COMPLEXITY)
+        .finish()
+}
+
+/// GraphQL 查询处理器
+pub async fn graphql_handler(
+    Extension(schema): Extension<CrawlrsSchema>,
+    Extension(auth_state): Extension<AuthState>,
+    request: GraphQLRequest,
+) -> GraphQLResponse {
+    let request = request.into_inner().data(TeamScope(auth_state.team_id));
+    schema.execute(request).await.into()
+}
+
+/// GraphQL 路由，调用方负责叠加认证中间件
+pub fn graphql_router(schema: CrawlrsSchema) -> Router {
+    Router::new()
+        .route("/v1/graphql", post(graphql_handler))
+        .layer(Extension(schema))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::scrape_result::ScrapeResult;
+    use crate::domain::models::{Crawl, CrawlStatus, Task, TaskStatus, TaskType};
+    use crate::domain::repositories::scrape_result_repository::ResultDeletionFilter;
+    use crate::domain::repositories::task_repository::{RepositoryError, TaskQueryParams};
+    use async_graphql::Request;
+    use async_trait::async_trait;
+    use serde_json::{json, Value};
+    use std::collections::HashSet;
+
+    struct MockCrawlRepository {
+        crawls: Vec<Crawl>,
+    }
+
+    #[async_trait]
+    impl CrawlRepository for MockCrawlRepository {
+        async fn create(&self, crawl: &Crawl) -> Result<Crawl, RepositoryError> {
+            Ok(crawl.clone())
+        }
+        async fn find_by_id(&self, id: Uuid) -> Result<Option<Crawl>, RepositoryError> {
+            Ok(self.crawls.iter().find(|c| c.id == id).cloned())
+        }
+        async fn update(&self, crawl: &Crawl) -> Result<Crawl, RepositoryError> {
+            Ok(crawl.clone())
+        }
+        async fn increment_completed_tasks(&self, _id: Uuid) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+        async fn increment_failed_tasks(&self, _id: Uuid) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+        async fn update_status(
+            &self,
+            _id: Uuid,
+            _status: CrawlStatus,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+        async fn increment_total_tasks(&self, _id: Uuid) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+        async fn find_by_team_id_paginated(
+            &self,
+            team_id: Uuid,
+            limit: u32,
+            offset: u32,
+        ) -> Result<Vec<Crawl>, RepositoryError> {
+            Ok(self
+                .crawls
+                .iter()
+                .filter(|c| c.team_id == team_id)
+                .skip(offset as usize)
+                .take(limit as usize)
+                .cloned()
+                .collect())
+        }
+        async fn count_by_team_id(&self, team_id: Uuid) -> Result<u64, RepositoryError> {
+            Ok(self.crawls.iter().filter(|c| c.team_id == team_id).count() as u64)
+        }
+    }
+
+    struct MockTaskRepository {
+        tasks: Vec<Task>,
+    }
+
+    #[async_trait]
+    impl TaskRepository for MockTaskRepository {
+        async fn create(&self, task: &Task) -> Result<Task, RepositoryError> {
+            Ok(task.clone())
+        }
+        async fn find_by_id(&self, id: Uuid) -> Result<Option<Task>, RepositoryError> {
+            Ok(self.tasks.iter().find(|t| t.id == id).cloned())
+        }
+        async fn update(&self, task: &Task) -> Result<Task, RepositoryError> {
+            Ok(task.clone())
+        }
+        async fn acquire_next(&self, _worker_id: Uuid) -> Result<Option<Task>, RepositoryError> {
+            Ok(None)
+        }
+        async fn mark_completed(&self, _id: Uuid) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+        async fn mark_failed(&self, _id: Uuid) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+        async fn mark_cancelled(&self, _id: Uuid) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+        async fn exists_by_url(&self, _url: &str) -> Result<bool, RepositoryError> {
+            Ok(false)
+        }
+        async fn find_existing_urls(
+            &self,
+            _urls: &[String],
+        ) -> Result<HashSet<String>, RepositoryError> {
+            Ok(HashSet::new())
+        }
+        async fn reset_stuck_tasks(
+            &self,
+            _timeout: chrono::Duration,
+        ) -> Result<u64, RepositoryError> {
+            Ok(0)
+        }
+        async fn cancel_tasks_by_crawl_id(&self, _crawl_id: Uuid) -> Result<u64, RepositoryError> {
+            Ok(0)
+        }
+        async fn expire_tasks(&self) -> Result<u64, RepositoryError> {
+            Ok(0)
+        }
+        async fn find_by_crawl_id(&self, crawl_id: Uuid) -> Result<Vec<Task>, RepositoryError> {
+            Ok(self
+                .tasks
+                .iter()
+                .filter(|t| t.crawl_id == Some(crawl_id))
+                .cloned()
+                .collect())
+        }
+        async fn query_tasks(
+            &self,
+            params: TaskQueryParams,
+        ) -> Result<(Vec<Task>, u64), RepositoryError> {
+            let matching: Vec<Task> = self
+                .tasks
+                .iter()
+                .filter(|t| t.team_id == params.team_id)
+                .filter(|t| params.crawl_id.is_none() || t.crawl_id == params.crawl_id)
+                .filter(|t| {
+                    params
+                        .statuses
+                        .as_ref()
+                        .is_none_or(|statuses| statuses.contains(&t.status))
+                })
+                .cloned()
+                .collect();
+            let total = matching.len() as u64;
+            let page = matching
+                .into_iter()
+                .skip(params.offset as usize)
+                .take(params.limit as usize)
+                .collect();
+            Ok((page, total))
+        }
+        async fn batch_cancel(
+            &self,
+            _task_ids: Vec<Uuid>,
+            _team_id: Uuid,
+            _force: bool,
+        ) -> Result<(Vec<Uuid>, Vec<(Uuid, String)>), RepositoryError> {
+            Ok((vec![], vec![]))
+        }
+    }
+
+    struct MockScrapeResultRepository {
+        results: Vec<ScrapeResult>,
+    }
+
+    #[async_trait]
+    impl ScrapeResultRepository for MockScrapeResultRepository {
+        async fn save(&self, _result: ScrapeResult) -> anyhow::Result<()> {
+            Ok(())
+        }
+        async fn find_by_task_id(&self, task_id: Uuid) -> anyhow::Result<Option<ScrapeResult>> {
+            Ok(self.results.iter().find(|r| r.task_id == task_id).cloned())
+        }
+        async fn find_by_task_ids(&self, task_ids: &[Uuid]) -> anyhow::Result<Vec<ScrapeResult>> {
+            Ok(self
+                .results
+                .iter()
+                .filter(|r| task_ids.contains(&r.task_id))
+                .cloned()
+                .collect())
+        }
+        async fn find_latest_for_crawl_url(
+            &self,
+            _crawl_id: Uuid,
+            _url: &str,
+        ) -> anyhow::Result<Option<ScrapeResult>> {
+            Ok(None)
+        }
+        async fn count_not_modified(&self, _task_ids: &[Uuid]) -> anyhow::Result<u64> {
+            Ok(0)
+        }
+        async fn response_times(&self, _task_ids: &[Uuid]) -> anyhow::Result<Vec<i64>> {
+            Ok(Vec::new())
+        }
+        async fn get_team_avg_response_time(&self, _team_id: Uuid) -> anyhow::Result<f64> {
+            Ok(0.0)
+        }
+        async fn delete_for_team(
+            &self,
+            _team_id: Uuid,
+            _filter: &ResultDeletionFilter,
+        ) -> anyhow::Result<Vec<ScrapeResult>> {
+            Ok(vec![])
+        }
+
+        async fn search_crawl_results(
+            &self,
+            _crawl_id: Uuid,
+            _query: &str,
+            _limit: u64,
+        ) -> anyhow::Result<
+            Vec<crate::domain::repositories::scrape_result_repository::ResultSearchHit>,
+        > {
+            Ok(vec![])
+        }
+    }
+
+    fn make_result(task_id: Uuid, content: &str) -> ScrapeResult {
+        ScrapeResult {
+            id: Uuid::new_v4(),
+            task_id,
+            url: "https://example.com/a".to_string(),
+            status_code: 200,
+            content: content.to_string(),
+            content_type: "text/html".to_string(),
+            headers: json!({}),
+            meta_data: json!({}),
+            screenshot: None,
+            response_time_ms: 12,
+            created_at: chrono::Utc::now().naive_utc(),
+            etag: None,
+            last_modified: None,
+        }
+    }
+
+    struct Fixture {
+        schema: CrawlrsSchema,
+        team_id: Uuid,
+        crawl_id: Uuid,
+        other_crawl_id: Uuid,
+    }
+
+    fn fixture() -> Fixture {
+        let team_id = Uuid::new_v4();
+        let other_team_id = Uuid::new_v4();
+        let crawl = Crawl::new(
+            Uuid::new_v4(),
+            team_id,
+            "docs".to_string(),
+            "https://example.com".to_string(),
+            "https://example.com".to_string(),
+            json!({}),
+        );
+        let other_crawl = Crawl::new(
+            Uuid::new_v4(),
+            other_team_id,
+            "other".to_string(),
+            "https://other.example.com".to_string(),
+            "https://other.example.com".to_string(),
+            json!({}),
+        );
+
+        let mut completed = Task::new(
+            Uuid::new_v4(),
+            TaskType::Scrape,
+            team_id,
+            Uuid::new_v4(),
+            "https://example.com/a".to_string(),
+            json!({}),
+        );
+        completed.crawl_id = Some(crawl.id);
+        completed.status = TaskStatus::Completed;
+        let mut queued = completed.clone();
+        queued.id = Uuid::new_v4();
+        queued.status = TaskStatus::Queued;
+        let mut foreign = completed.clone();
+        foreign.id = Uuid::new_v4();
+        foreign.team_id = other_team_id;
+        foreign.crawl_id = Some(other_crawl.id);
+
+        let results = vec![make_result(completed.id, "# A")];
+        let schema = build_schema(
+            Arc::new(MockCrawlRepository {
+                crawls: vec![crawl.clone(), other_crawl.clone()],
+            }),
+            Arc::new(MockTaskRepository {
+                tasks: vec![completed, queued, foreign],
+            }),
+            Arc::new(MockScrapeResultRepository { results }),
+        );
+        Fixture {
+            schema,
+            team_id,
+            crawl_id: crawl.id,
+            other_crawl_id: other_crawl.id,
+        }
+    }
+
+    async fn execute(fixture: &Fixture, query: String) -> async_graphql::Response {
+        fixture
+            .schema
+            .execute(Request::new(query).data(TeamScope(fixture.team_id)))
+            .await
+    }
+
+    #[tokio::test]
+    async fn test_nested_crawl_tasks_and_results() {
+        let fixture = fixture();
+        let query = format!(
+            r#"{{ crawl(id: "{}") {{
+                name
+                tasks(filter: {{ statuses: ["completed"] }}) {{
+                    totalCount
+                    hasMore
+                    nodes {{ status result {{ content statusCode }} }}
+                }}
+            }} }}"#,
+            fixture.crawl_id
+        );
+
+        let response = execute(&fixture, query).await;
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+        let data = response.data.into_json().unwrap();
+        assert_eq!(data["crawl"]["name"], "docs");
+        assert_eq!(
+            data["crawl"]["tasks"],
+            json!({
+                "totalCount": 1,
+                "hasMore": false,
+                "nodes": [{"status": "completed", "result": {"content": "# A", "statusCode": 200}}]
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_queries_are_scoped_to_team() {
+        let fixture = fixture();
+        let query = format!(
+            r#"{{ crawl(id: "{}") {{ id }} crawls {{ totalCount }} tasks {{ totalCount }} }}"#,
+            fixture.other_crawl_id
+        );
+
+        let response = execute(&fixture, query).await;
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+        let data = response.data.into_json().unwrap();
+        assert_eq!(data["crawl"], Value::Null);
+        assert_eq!(data["crawls"]["totalCount"], 1);
+        assert_eq!(data["tasks"]["totalCount"], 2);
+    }
+
+    #[tokio::test]
+    async fn test_unknown_status_filter_is_rejected() {
+        let fixture = fixture();
+        let query = r#"{ tasks(filter: { statuses: ["done"] }) { totalCount } }"#.to_string();
+
+        let response = execute(&fixture, query).await;
+        assert_eq!(response.errors.len(), 1);
+        assert!(response.errors[0]
+            .message
+            .contains("unknown task status `done`"));
+    }
+
+    #[tokio::test]
+    async fn test_overly_complex_query_is_rejected() {
+        let fixture = fixture();
+        let query = r#"{ crawls(limit: 100) { nodes { tasks(limit: 100) {
+            nodes { result { content } crawl { name } }
+        } } } }"#
+            .to_string();
+
+        let response = execute(&fixture, query).await;
+        assert!(!response.errors.is_empty());
+    }
+
+    #[test]
+    fn test_page_size_is_clamped() {
+        assert_eq!(page_size(0), 1);
+        assert_eq!(page_size(20), 20);
+        assert_eq!(page_size(10_000), MAX_PAGE_SIZE);
+    }
+}
@@ -0,0 +1,79 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! GraphQL 查询根
+
+use std::sync::Arc;
+
+use async_graphql::{Context, Object, Result};
+use uuid::Uuid;
+
+use crate::domain::repositories::crawl_repository::CrawlRepository;
+use crate::domain::repositories::task_repository::TaskRepository;
+
+use super::types::{
+    find_crawl, query_tasks, CrawlConnection, CrawlNode, TaskConnection, TaskFilter, TaskNode,
+};
+use super::{internal_error, page_size, TeamScope};
+
+/// 查询根，所有查询都限定在认证团队内
+pub struct QueryRoot;
+
+#[Object]
+impl QueryRoot {
+    /// 按 ID 查询爬取
+    async fn crawl(&self, ctx: &Context<'_>, id: Uuid) -> Result<Option<CrawlNode>> {
+        find_crawl(ctx, id).await
+    }
+
+    /// 团队的爬取，按创建时间倒序
+    #[graphql(complexity = "page_size(limit) as usize * child_complexity")]
+    async fn crawls(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(default = 20)] limit: u32,
+        #[graphql(default)] offset: u32,
+    ) -> Result<CrawlConnection> {
+        let TeamScope(team_id) = *ctx.data::<TeamScope>()?;
+        let repo = ctx.data::<Arc<dyn CrawlRepository>>()?;
+        let page = page_size(limit);
+        let crawls = repo
+            .find_by_team_id_paginated(team_id, page, offset)
+            .await
+            .map_err(internal_error)?;
+        let total_count = repo
+            .count_by_team_id(team_id)
+            .await
+            .map_err(internal_error)?;
+        Ok(CrawlConnection {
+            has_more: u64::from(offset) + u64::from(page) < total_count,
+            nodes: crawls.into_iter().map(CrawlNode).collect(),
+            total_count,
+        })
+    }
+
+    /// 按 ID 查询任务
+    async fn task(&self, ctx: &Context<'_>, id: Uuid) -> Result<Option<TaskNode>> {
+        let TeamScope(team_id) = *ctx.data::<TeamScope>()?;
+        let task = ctx
+            .data::<Arc<dyn TaskRepository>>()?
+            .find_by_id(id)
+            .await
+            .map_err(internal_error)?;
+        Ok(task.filter(|task| task.team_id == team_id).map(TaskNode))
+    }
+
+    /// 团队的任务，按创建时间倒序
+    #[graphql(complexity = "page_size(limit) as usize * child_complexity")]
+    async fn tasks(
+        &self,
+        ctx: &Context<'_>,
+        filter: Option<TaskFilter>,
+        #[graphql(default = 20)] limit: u32,
+        #[graphql(default)] offset: u32,
+    ) -> Result<TaskConnection> {
+        query_tasks(ctx, filter.unwrap_or_default(), limit, offset).await
+    }
+}
@@ -0,0 +1,353 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! GraphQL 对象类型
+//!
+//! 对领域模型做只读包装，字段名按 GraphQL 惯例转为 camelCase；
+//! 状态与类型以 REST 接口相同的 snake_case 字符串返回。
+
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use async_graphql::dataloader::{DataLoader, Loader};
+use async_graphql::{Context, Error, InputObject, Json, Object, Result, SimpleObject};
+use chrono::{DateTime, NaiveDateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::models::scrape_result::ScrapeResult;
+use crate::domain::models::{Crawl, Task, TaskStatus, TaskType};
+use crate::domain::repositories::crawl_repository::CrawlRepository;
+use crate::domain::repositories::scrape_result_repository::ScrapeResultRepository;
+use crate::domain::repositories::task_repository::{TaskQueryParams, TaskRepository};
+
+use super::{internal_error, page_size, TeamScope};
+
+/// 任务过滤条件
+#[derive(Debug, Default, InputObject)]
+pub struct TaskFilter {
+    /// 任务状态：queued、active、completed、failed、cancelled
+    pub statuses: Option<Vec<String>>,
+    /// 任务类型：scrape、crawl、extract、export
+    pub task_types: Option<Vec<String>>,
+    /// 所属爬取
+    pub crawl_id: Option<Uuid>,
+    /// 创建时间下限
+    pub created_after: Option<DateTime<Utc>>,
+    /// 创建时间上限
+    pub created_before: Option<DateTime<Utc>>,
+}
+
+fn parse_all<T: FromStr>(values: Option<Vec<String>>, kind: &str) -> Result<Option<Vec<T>>> {
+    values
+        .map(|values| {
+            values
+                .iter()
+                .map(|value| {
+                    T::from_str(value)
+                        .map_err(|_| Error::new(format!("unknown {} `{}`", kind, value)))
+                })
+                .collect()
+        })
+        .transpose()
+}
+
+impl TaskFilter {
+    /// 转换为仓库查询参数，团队由认证信息决定
+    pub fn into_params(self, team_id: Uuid, limit: u32, offset: u32) -> Result<TaskQueryParams> {
+        Ok(TaskQueryParams {
+            team_id,
+            statuses: parse_all::<TaskStatus>(self.statuses, "task status")?,
+            task_types: parse_all::<TaskType>(self.task_types, "task type")?,
+            crawl_id: self.crawl_id,
+            created_after: self.created_after,
+            created_before: self.created_before,
+            limit: page_size(limit),
+            offset,
+            ..Default::default()
+        })
+    }
+}
+
+/// 分页的任务列表
+#[derive(SimpleObject)]
+pub struct TaskConnection {
+    /// 当前页的任务
+    pub nodes: Vec<TaskNode>,
+    /// 满足条件的任务总数
+    pub total_count: u64,
+    /// 是否还有下一页
+    pub has_more: bool,
+}
+
+/// 分页的爬取列表
+#[derive(SimpleObject)]
+pub struct CrawlConnection {
+    /// 当前页的爬取
+    pub nodes: Vec<CrawlNode>,
+    /// 团队的爬取总数
+    pub total_count: u64,
+    /// 是否还有下一页
+    pub has_more: bool,
+}
+
+/// 按过滤条件查询一页任务
+pub async fn query_tasks(
+    ctx: &Context<'_>,
+    filter: TaskFilter,
+    limit: u32,
+    offset: u32,
+) -> Result<TaskConnection> {
+    let TeamScope(team_id) = *ctx.data::<TeamScope>()?;
+    let params = filter.into_params(team_id, limit, offset)?;
+    let page = params.limit;
+    let (tasks, total_count) = ctx
+        .data::<Arc<dyn TaskRepository>>()?
+        .query_tasks(params)
+        .await
+        .map_err(internal_error)?;
+    Ok(TaskConnection {
+        has_more: u64::from(offset) + u64::from(page) < total_count,
+        nodes: tasks.into_iter().map(TaskNode).collect(),
+        total_count,
+    })
+}
+
+/// 查询团队的爬取，不属于该团队时视为不存在
+pub async fn find_crawl(ctx: &Context<'_>, id: Uuid) -> Result<Option<CrawlNode>> {
+    let TeamScope(team_id) = *ctx.data::<TeamScope>()?;
+    let crawl = ctx
+        .data::<Arc<dyn CrawlRepository>>()?
+        .find_by_id(id)
+        .await
+        .map_err(internal_error)?;
+    Ok(crawl
+        .filter(|crawl| crawl.team_id == team_id)
+        .map(CrawlNode))
+}
+
+/// 爬取
+pub struct CrawlNode(pub Crawl);
+
+#[Object(name = "Crawl")]
+impl CrawlNode {
+    async fn id(&self) -> Uuid {
+        self.0.id
+    }
+
+    async fn name(&self) -> &str {
+        &self.0.name
+    }
+
+    async fn root_url(&self) -> &str {
+        &self.0.root_url
+    }
+
+    async fn url(&self) -> &str {
+        &self.0.url
+    }
+
+    /// queued、processing、completed、completed_with_limit、failed、cancelled
+    async fn status(&self) -> String {
+        self.0.status.to_string()
+    }
+
+    async fn config(&self) -> Json<serde_json::Value> {
+        Json(self.0.config().clone())
+    }
+
+    async fn total_tasks(&self) -> i32 {
+        self.0.total_tasks()
+    }
+
+    async fn completed_tasks(&self) -> i32 {
+        self.0.completed_tasks()
+    }
+
+    async fn failed_tasks(&self) -> i32 {
+        self.0.failed_tasks()
+    }
+
+    async fn created_at(&self) -> DateTime<Utc> {
+        self.0.created_at
+    }
+
+    async fn updated_at(&self) -> DateTime<Utc> {
+        self.0.updated_at
+    }
+
+    async fn completed_at(&self) -> Option<DateTime<Utc>> {
+        self.0.completed_at
+    }
+
+    /// 爬取的子任务
+    #[graphql(complexity = "page_size(limit) as usize * child_complexity")]
+    async fn tasks(
+        &self,
+        ctx: &Context<'_>,
+        filter: Option<TaskFilter>,
+        #[graphql(default = 20)] limit: u32,
+        #[graphql(default)] offset: u32,
+    ) -> Result<TaskConnection> {
+        let filter = TaskFilter {
+            crawl_id: Some(self.0.id),
+            ..filter.unwrap_or_default()
+        };
+        query_tasks(ctx, filter, limit, offset).await
+    }
+}
+
+/// 任务
+pub struct TaskNode(pub Task);
+
+#[Object(name = "Task")]
+impl TaskNode {
+    async fn id(&self) -> Uuid {
+        self.0.id
+    }
+
+    /// scrape、crawl、extract、export
+    async fn task_type(&self) -> &'static str {
+        self.0.task_type.as_str()
+    }
+
+    /// queued、active、completed、failed、cancelled
+    async fn status(&self) -> String {
+        self.0.status.to_string()
+    }
+
+    async fn priority(&self) -> i32 {
+        self.0.priority
+    }
+
+    async fn url(&self) -> &str {
+        &self.0.url
+    }
+
+    async fn crawl_id(&self) -> Option<Uuid> {
+        self.0.crawl_id
+    }
+
+    async fn attempt_count(&self) -> i32 {
+        self.0.attempt_count
+    }
+
+    async fn created_at(&self) -> DateTime<Utc> {
+        self.0.created_at
+    }
+
+    async fn started_at(&self) -> Option<DateTime<Utc>> {
+        self.0.started_at
+    }
+
+    async fn completed_at(&self) -> Option<DateTime<Utc>> {
+        self.0.completed_at
+    }
+
+    /// 所属爬取
+    async fn crawl(&self, ctx: &Context<'_>) -> Result<Option<CrawlNode>> {
+        match self.0.crawl_id {
+            Some(crawl_id) => find_crawl(ctx, crawl_id).await,
+            None => Ok(None),
+        }
+    }
+
+    /// 任务的抓取结果，同一请求内的多个任务合并为一次查询
+    async fn result(&self, ctx: &Context<'_>) -> Result<Option<ResultNode>> {
+        let result = ctx
+            .data::<DataLoader<ResultLoader>>()?
+            .load_one(self.0.id)
+            .await
+            .map_err(internal_error)?;
+        Ok(result.map(ResultNode))
+    }
+}
+
+/// 抓取结果
+pub struct ResultNode(pub ScrapeResult);
+
+#[Object(name = "ScrapeResult")]
+impl ResultNode {
+    async fn id(&self) -> Uuid {
+        self.0.id
+    }
+
+    async fn task_id(&self) -> Uuid {
+        self.0.task_id
+    }
+
+    async fn url(&self) -> &str {
+        &self.0.url
+    }
+
+    async fn status_code(&self) -> i32 {
+        self.0.status_code
+    }
+
+    async fn content(&self) -> &str {
+        &self.0.content
+    }
+
+    async fn content_type(&self) -> &str {
+        &self.0.content_type
+    }
+
+    async fn headers(&self) -> Json<serde_json::Value> {
+        Json(self.0.headers.clone())
+    }
+
+    async fn metadata(&self) -> Json<serde_json::Value> {
+        Json(self.0.meta_data.clone())
+    }
+
+    async fn screenshot(&self) -> Option<&str> {
+        self.0.screenshot.as_deref()
+    }
+
+    async fn response_time_ms(&self) -> i64 {
+        self.0.response_time_ms
+    }
+
+    async fn created_at(&self) -> NaiveDateTime {
+        self.0.created_at
+    }
+}
+
+/// 按任务 ID 批量加载抓取结果，同一任务有多条结果时取最新一条
+pub struct ResultLoader {
+    result_repo: Arc<dyn ScrapeResultRepository>,
+}
+
+impl ResultLoader {
+    pub fn new(result_repo: Arc<dyn ScrapeResultRepository>) -> Self {
+        Self { result_repo }
+    }
+}
+
+impl Loader<Uuid> for ResultLoader {
+    type Value = ScrapeResult;
+    type Error = Arc<anyhow::Error>;
+
+    async fn load(
+        &self,
+        keys: &[Uuid],
+    ) -> std::result::Result<HashMap<Uuid, ScrapeResult>, Self::Error> {
+        let results = self
+            .result_repo
+            .find_by_task_ids(keys)
+            .await
+            .map_err(Arc::new)?;
+        let mut latest: HashMap<Uuid, ScrapeResult> = HashMap::new();
+        for result in results {
+            match latest.get(&result.task_id) {
+                Some(existing) if existing.created_at >= result.created_at => {}
+                _ => {
+                    latest.insert(result.task_id, result);
+                }
+            }
+        }
+        Ok(latest)
+    }
+}
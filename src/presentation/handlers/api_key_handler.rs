@@ -0,0 +1,277 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! API Key 角色管理接口
+//!
+//! 查看与修改 API Key 的角色（read_only / member / admin），仅 Admin 角色可访问，
+//! 且只能操作本团队的 Key，其他团队的 Key 一律返回 404。
+//! 修改角色会保留 Key 原有的 search / scrape 限额，并立即失效该 Key 的鉴权缓存。
+
+use crate::domain::auth::{ApiKeyRole, ApiKeyScope};
+use crate::domain::services::audit_service::AuditServiceTrait;
+use crate::domain::services::auth_scope_service::{AuthScopeServiceError, AuthScopeServiceTrait};
+use crate::presentation::extractors::role::RequireAdmin;
+use crate::presentation::handlers::response_builder::{errors, success_response};
+use axum::{
+    extract::{Extension, Path},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use log::error;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// 修改 API Key 角色请求
+#[derive(Debug, Deserialize)]
+pub struct UpdateApiKeyRoleRequest {
+    /// 新角色
+    pub role: ApiKeyRole,
+}
+
+/// API Key 角色响应
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApiKeyRoleResponse {
+    /// API Key ID
+    pub api_key_id: Uuid,
+    /// 由权限标志推导出的角色
+    pub role: ApiKeyRole,
+    /// 读权限
+    pub read: bool,
+    /// 写权限
+    pub write: bool,
+    /// 管理权限
+    pub admin: bool,
+}
+
+impl ApiKeyRoleResponse {
+    fn new(api_key_id: Uuid, scope: &ApiKeyScope) -> Self {
+        Self {
+            api_key_id,
+            role: scope.role(),
+            read: scope.read,
+            write: scope.write,
+            admin: scope.admin,
+        }
+    }
+}
+
+/// 查看 API Key 的角色，没有权限记录的 Key 为只读
+pub async fn get_api_key_role(
+    RequireAdmin(auth_state): RequireAdmin,
+    Extension(scopes): Extension<Arc<dyn AuthScopeServiceTrait>>,
+    Path(api_key_id): Path<Uuid>,
+) -> impl IntoResponse {
+    match scopes
+        .get_team_key_scope(auth_state.team_id, api_key_id)
+        .await
+    {
+        Ok(scope) => success_response(StatusCode::OK, ApiKeyRoleResponse::new(api_key_id, &scope)),
+        Err(AuthScopeServiceError::ApiKeyNotFound) => errors::not_found("API key not found"),
+        Err(e) => {
+            error!("Failed to load scope for API key {}: {}", api_key_id, e);
+            errors::internal_server_error("Failed to load API key role")
+        }
+    }
+}
+
+/// 修改 API Key 的角色
+pub async fn update_api_key_role(
+    RequireAdmin(auth_state): RequireAdmin,
+    Extension(scopes): Extension<Arc<dyn AuthScopeServiceTrait>>,
+    audit_service: Option<Extension<Arc<dyn AuditServiceTrait>>>,
+    Path(api_key_id): Path<Uuid>,
+    Json(request): Json<UpdateApiKeyRoleRequest>,
+) -> impl IntoResponse {
+    let current = match scopes
+        .get_team_key_scope(auth_state.team_id, api_key_id)
+        .await
+    {
+        Ok(scope) => scope,
+        Err(AuthScopeServiceError::ApiKeyNotFound) => {
+            return errors::not_found("API key not found");
+        }
+        Err(e) => {
+            error!("Failed to load scope for API key {}: {}", api_key_id, e);
+            return errors::internal_server_error("Failed to load API key role");
+        }
+    };
+
+    let scope = match scopes
+        .set_scope(api_key_id, current.with_role(request.role))
+        .await
+    {
+        Ok(scope) => scope,
+        Err(e) => {
+            error!("Failed to update role for API key {}: {}", api_key_id, e);
+            return errors::internal_server_error("Failed to update API key role");
+        }
+    };
+
+    if let Some(Extension(audit_service)) = audit_service {
+        if let Err(e) = audit_service
+            .log_allow(
+                "api_key.role.update".to_string(),
+                auth_state.api_key_id,
+                auth_state.team_id,
+                auth_state.scope.clone(),
+            )
+            .await
+        {
+            error!("Failed to audit API key role update: {}", e);
+        }
+    }
+
+    success_response(StatusCode::OK, ApiKeyRoleResponse::new(api_key_id, &scope))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::test_helpers::create_test_db_pool;
+    use crate::presentation::middleware::auth_middleware::AuthState;
+    use async_trait::async_trait;
+    use axum::body::to_bytes;
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct MockScopes {
+        scopes: Mutex<HashMap<Uuid, ApiKeyScope>>,
+        /// API Key ID -> 所属团队
+        owners: Mutex<HashMap<Uuid, Uuid>>,
+    }
+
+    impl MockScopes {
+        fn with_key(api_key_id: Uuid, team_id: Uuid) -> Self {
+            let scopes = Self::default();
+            scopes.owners.lock().unwrap().insert(api_key_id, team_id);
+            scopes
+        }
+    }
+
+    #[async_trait]
+    impl AuthScopeServiceTrait for MockScopes {
+        async fn get_scope_for_key(
+            &self,
+            api_key_id: Uuid,
+            team_default_scope: Option<ApiKeyScope>,
+        ) -> Result<ApiKeyScope, AuthScopeServiceError> {
+            Ok(self
+                .scopes
+                .lock()
+                .unwrap()
+                .get(&api_key_id)
+                .cloned()
+                .or(team_default_scope)
+                .unwrap_or_default())
+        }
+
+        async fn get_team_key_scope(
+            &self,
+            team_id: Uuid,
+            api_key_id: Uuid,
+        ) -> Result<ApiKeyScope, AuthScopeServiceError> {
+            if self.owners.lock().unwrap().get(&api_key_id) != Some(&team_id) {
+                return Err(AuthScopeServiceError::ApiKeyNotFound);
+            }
+            self.get_scope_for_key(api_key_id, None).await
+        }
+
+        async fn set_scope(
+            &self,
+            api_key_id: Uuid,
+            scope: ApiKeyScope,
+        ) -> Result<ApiKeyScope, AuthScopeServiceError> {
+            self.scopes
+                .lock()
+                .unwrap()
+                .insert(api_key_id, scope.clone());
+            Ok(scope)
+        }
+
+        async fn delete_scope(&self, api_key_id: Uuid) -> Result<bool, AuthScopeServiceError> {
+            Ok(self.scopes.lock().unwrap().remove(&api_key_id).is_some())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_admin_changes_api_key_role() {
+        let team_id = Uuid::new_v4();
+        let api_key_id = Uuid::new_v4();
+        let scopes = Arc::new(MockScopes::with_key(api_key_id, team_id));
+        let admin = AuthState::new(
+            create_test_db_pool(),
+            team_id,
+            Uuid::new_v4(),
+            ApiKeyScope::full_access(),
+        );
+
+        let response = update_api_key_role(
+            RequireAdmin(admin.clone()),
+            Extension(scopes.clone() as Arc<dyn AuthScopeServiceTrait>),
+            None,
+            Path(api_key_id),
+            Json(UpdateApiKeyRoleRequest {
+                role: ApiKeyRole::Member,
+            }),
+        )
+        .await
+        .into_response();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let stored = scopes.get_scope_for_key(api_key_id, None).await.unwrap();
+        assert_eq!(stored.role(), ApiKeyRole::Member);
+        assert!(stored.write && !stored.admin);
+
+        let response = get_api_key_role(
+            RequireAdmin(admin),
+            Extension(scopes as Arc<dyn AuthScopeServiceTrait>),
+            Path(api_key_id),
+        )
+        .await
+        .into_response();
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["data"]["role"], "member");
+    }
+
+    #[tokio::test]
+    async fn test_admin_cannot_manage_other_team_key() {
+        let api_key_id = Uuid::new_v4();
+        let scopes = Arc::new(MockScopes::with_key(api_key_id, Uuid::new_v4()));
+        // 另一个团队的 Admin Key
+        let other_admin = AuthState::new(
+            create_test_db_pool(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            ApiKeyScope::full_access(),
+        );
+
+        let response = update_api_key_role(
+            RequireAdmin(other_admin.clone()),
+            Extension(scopes.clone() as Arc<dyn AuthScopeServiceTrait>),
+            None,
+            Path(api_key_id),
+            Json(UpdateApiKeyRoleRequest {
+                role: ApiKeyRole::Admin,
+            }),
+        )
+        .await
+        .into_response();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        assert!(scopes.scopes.lock().unwrap().is_empty());
+
+        let response = get_api_key_role(
+            RequireAdmin(other_admin),
+            Extension(scopes as Arc<dyn AuthScopeServiceTrait>),
+            Path(api_key_id),
+        )
+        .await
+        .into_response();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+}
@@ -0,0 +1,115 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! 下载资源访问接口
+//!
+//! 返回下载模式（scrape `download` / crawl `download_assets`）保存到对象存储的原始字节。
+//! 对象键为 `{team_id}/{name}`，只能访问当前团队命名空间下的资源。
+
+use crate::domain::repositories::storage_repository::StorageRepository;
+use crate::presentation::handlers::response_builder::errors;
+use crate::presentation::middleware::auth_middleware::AuthState;
+use axum::{
+    extract::{Extension, Path},
+    http::header,
+    response::{IntoResponse, Response},
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// 从对象键中取出团队内的对象名
+///
+/// 键不属于该团队或含相对路径段时返回 None。
+fn team_object_name(key: &str, team_id: Uuid) -> Option<&str> {
+    let (team, name) = key.split_once('/')?;
+    let owned = team.parse::<Uuid>().is_ok_and(|team| team == team_id);
+    let valid = name
+        .split('/')
+        .all(|segment| !segment.is_empty() && segment != "." && segment != "..");
+    (owned && valid).then_some(name)
+}
+
+/// 下载资源处理器
+///
+/// 以存储时记录的 Content-Type 返回资源字节，其他团队的资源按不存在处理。
+pub async fn get_asset(
+    Extension(auth_state): Extension<AuthState>,
+    Extension(storage): Extension<Arc<dyn StorageRepository>>,
+    Path(key): Path<String>,
+) -> Response {
+    let Some(name) = team_object_name(&key, auth_state.team_id) else {
+        return errors::not_found("Asset not found");
+    };
+
+    // 读取同样限定在当前团队命名空间内，不依赖键前缀的校验
+    match storage.get(auth_state.team_id, name).await {
+        Ok(Some(object)) => {
+            ([(header::CONTENT_TYPE, object.content_type)], object.bytes).into_response()
+        }
+        Ok(None) => errors::not_found("Asset not found"),
+        Err(e) => errors::internal_server_error(e.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::test_helpers::create_test_db_pool;
+    use crate::domain::auth::ApiKeyScope;
+    use crate::infrastructure::storage::LocalStorageRepository;
+    use axum::http::StatusCode;
+
+    #[test]
+    fn test_team_object_name() {
+        let team = Uuid::new_v4();
+        let key = |rest: &str| format!("{}/{}", team, rest);
+        assert_eq!(team_object_name(&key("abc"), team), Some("abc"));
+        assert_eq!(team_object_name(&key("har/t.har"), team), Some("har/t.har"));
+        assert_eq!(team_object_name(&team.to_string(), team), None);
+        assert_eq!(team_object_name(&key(""), team), None);
+        assert_eq!(team_object_name(&key("../other/abc"), team), None);
+        assert_eq!(team_object_name("other/abc", team), None);
+        assert_eq!(
+            team_object_name(&format!("{}/abc", Uuid::new_v4()), team),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_asset_returns_own_team_asset_only() {
+        let dir = tempfile::tempdir().unwrap();
+        let storage: Arc<dyn StorageRepository> =
+            Arc::new(LocalStorageRepository::new(dir.path(), "/v1/assets"));
+        let auth = AuthState::new(
+            create_test_db_pool(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            ApiKeyScope::default(),
+        );
+        let other_team = Uuid::new_v4();
+        let own_key = format!("{}/abc", auth.team_id);
+        let other_key = format!("{}/abc", other_team);
+        storage
+            .put(auth.team_id, "abc", b"\x89PNG", "image/png")
+            .await
+            .unwrap();
+        storage
+            .put(other_team, "abc", b"\x89PNG", "image/png")
+            .await
+            .unwrap();
+
+        let response = get_asset(
+            Extension(auth.clone()),
+            Extension(storage.clone()),
+            Path(own_key),
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[header::CONTENT_TYPE], "image/png");
+
+        let response = get_asset(Extension(auth), Extension(storage), Path(other_key)).await;
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+}
@@ -0,0 +1,415 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! URL 黑名单管理接口
+//!
+//! 管理数据库中的全局与团队黑名单条目，仅 Admin 权限可访问。
+//! 团队只能查看和管理本团队的条目；全局条目与跨团队访问需要运营方凭据。
+//! 配置文件中的 `url_blocklist.patterns` 为只读全局条目，不经由此接口管理。
+
+use crate::domain::auth::ScopePermission;
+use crate::domain::models::{BlocklistEntry, BlocklistPatternType};
+use crate::domain::repositories::url_blocklist_repository::UrlBlocklistRepository;
+use crate::presentation::extractors::role::OperatorCredential;
+use crate::presentation::handlers::response_builder::{errors, success_response};
+use crate::presentation::middleware::auth_middleware::AuthState;
+use axum::{
+    extract::{Extension, Path, Query},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// 黑名单列表查询参数
+#[derive(Debug, Deserialize)]
+pub struct BlocklistQuery {
+    /// 只返回对该团队生效的条目（全局 + 团队），为空时返回全部条目
+    ///
+    /// 非运营方调用时只能查询本团队，为空时默认本团队。
+    pub team_id: Option<Uuid>,
+}
+
+/// 创建黑名单条目请求
+#[derive(Debug, Deserialize)]
+pub struct CreateBlocklistEntryRequest {
+    /// 团队 ID，为空表示全局条目
+    ///
+    /// 全局条目与其他团队的条目仅运营方可创建，非运营方调用时为空默认本团队。
+    pub team_id: Option<Uuid>,
+    /// 匹配模式
+    pub pattern: String,
+    /// 匹配方式，默认 `wildcard`
+    pub pattern_type: Option<BlocklistPatternType>,
+    /// 加入黑名单的原因
+    pub reason: Option<String>,
+}
+
+/// 黑名单列表响应数据传输对象
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BlocklistResponseDto {
+    /// 条目列表
+    pub entries: Vec<BlocklistEntry>,
+}
+
+/// 校验 Admin 权限
+fn require_admin(auth_state: &AuthState) -> Result<(), Box<Response>> {
+    if auth_state.scope.has_permission(ScopePermission::Admin) {
+        Ok(())
+    } else {
+        Err(Box::new(errors::forbidden(
+            "Admin permission required to manage the URL blocklist",
+        )))
+    }
+}
+
+/// 解析请求实际作用的团队，非运营方只能作用于本团队
+///
+/// 运营方原样返回请求中的团队（`None` 表示全局）；非运营方请求其他团队时返回 403。
+fn resolve_team(
+    auth_state: &AuthState,
+    is_operator: bool,
+    requested: Option<Uuid>,
+) -> Result<Option<Uuid>, Box<Response>> {
+    if is_operator {
+        return Ok(requested);
+    }
+    match requested {
+        None => Ok(Some(auth_state.team_id)),
+        Some(team_id) if team_id == auth_state.team_id => Ok(Some(team_id)),
+        Some(_) => Err(Box::new(errors::forbidden(
+            "Operator credential required to manage another team's blocklist",
+        ))),
+    }
+}
+
+/// 列出黑名单条目
+pub async fn list_blocklist_entries(
+    Extension(auth_state): Extension<AuthState>,
+    Extension(repo): Extension<Arc<dyn UrlBlocklistRepository>>,
+    OperatorCredential(is_operator): OperatorCredential,
+    Query(query): Query<BlocklistQuery>,
+) -> impl IntoResponse {
+    if let Err(response) = require_admin(&auth_state) {
+        return *response;
+    }
+    let team_id = match resolve_team(&auth_state, is_operator, query.team_id) {
+        Ok(team_id) => team_id,
+        Err(response) => return *response,
+    };
+
+    let result = match team_id {
+        Some(team_id) => repo.find_for_team(team_id).await,
+        None => repo.find_all().await,
+    };
+    match result {
+        Ok(entries) => success_response(StatusCode::OK, BlocklistResponseDto { entries }),
+        Err(e) => errors::internal_server_error(e.to_string()),
+    }
+}
+
+/// 创建黑名单条目
+pub async fn create_blocklist_entry(
+    Extension(auth_state): Extension<AuthState>,
+    Extension(repo): Extension<Arc<dyn UrlBlocklistRepository>>,
+    OperatorCredential(is_operator): OperatorCredential,
+    Json(payload): Json<CreateBlocklistEntryRequest>,
+) -> impl IntoResponse {
+    if let Err(response) = require_admin(&auth_state) {
+        return *response;
+    }
+    let team_id = match resolve_team(&auth_state, is_operator, payload.team_id) {
+        Ok(team_id) => team_id,
+        Err(response) => return *response,
+    };
+
+    let mut entry = BlocklistEntry::new(
+        team_id,
+        payload.pattern,
+        payload
+            .pattern_type
+            .unwrap_or(BlocklistPatternType::Wildcard),
+    );
+    if let Some(reason) = payload.reason.filter(|r| !r.trim().is_empty()) {
+        entry = entry.with_reason(reason);
+    }
+    if let Err(e) = entry.validate() {
+        return errors::bad_request(format!("Invalid blocklist pattern: {}", e));
+    }
+
+    match repo.create(&entry).await {
+        Ok(created) => success_response(StatusCode::CREATED, created),
+        Err(e) => errors::internal_server_error(e.to_string()),
+    }
+}
+
+/// 删除黑名单条目
+///
+/// 非运营方只能删除本团队的条目，全局条目与其他团队的条目返回 404。
+pub async fn delete_blocklist_entry(
+    Extension(auth_state): Extension<AuthState>,
+    Extension(repo): Extension<Arc<dyn UrlBlocklistRepository>>,
+    OperatorCredential(is_operator): OperatorCredential,
+    Path(id): Path<Uuid>,
+) -> impl IntoResponse {
+    if let Err(response) = require_admin(&auth_state) {
+        return *response;
+    }
+
+    if !is_operator {
+        match repo.find_by_id(id).await {
+            Ok(Some(entry)) if entry.team_id == Some(auth_state.team_id) => {}
+            Ok(_) => return errors::not_found("Blocklist entry not found"),
+            Err(e) => return errors::internal_server_error(e.to_string()),
+        }
+    }
+
+    match repo.delete(id).await {
+        Ok(true) => StatusCode::NO_CONTENT.into_response(),
+        Ok(false) => errors::not_found("Blocklist entry not found"),
+        Err(e) => errors::internal_server_error(e.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::test_helpers::create_test_db_pool;
+    use crate::domain::auth::ApiKeyScope;
+    use crate::domain::repositories::task_repository::RepositoryError;
+    use async_trait::async_trait;
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct MockBlocklistRepo {
+        entries: Mutex<Vec<BlocklistEntry>>,
+    }
+
+    #[async_trait]
+    impl UrlBlocklistRepository for MockBlocklistRepo {
+        async fn create(&self, entry: &BlocklistEntry) -> Result<BlocklistEntry, RepositoryError> {
+            self.entries.lock().unwrap().push(entry.clone());
+            Ok(entry.clone())
+        }
+        async fn find_by_id(&self, id: Uuid) -> Result<Option<BlocklistEntry>, RepositoryError> {
+            Ok(self
+                .entries
+                .lock()
+                .unwrap()
+                .iter()
+                .find(|e| e.id == id)
+                .cloned())
+        }
+        async fn find_all(&self) -> Result<Vec<BlocklistEntry>, RepositoryError> {
+            Ok(self.entries.lock().unwrap().clone())
+        }
+        async fn find_for_team(
+            &self,
+            team_id: Uuid,
+        ) -> Result<Vec<BlocklistEntry>, RepositoryError> {
+            Ok(self
+                .entries
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|e| e.team_id.is_none() || e.team_id == Some(team_id))
+                .cloned()
+                .collect())
+        }
+        async fn delete(&self, id: Uuid) -> Result<bool, RepositoryError> {
+            let mut entries = self.entries.lock().unwrap();
+            let before = entries.len();
+            entries.retain(|e| e.id != id);
+            Ok(entries.len() != before)
+        }
+    }
+
+    fn auth_state(scope: ApiKeyScope) -> AuthState {
+        AuthState::new(create_test_db_pool(), Uuid::new_v4(), Uuid::new_v4(), scope)
+    }
+
+    fn create_request(
+        pattern: &str,
+        pattern_type: Option<BlocklistPatternType>,
+    ) -> Json<CreateBlocklistEntryRequest> {
+        Json(CreateBlocklistEntryRequest {
+            team_id: None,
+            pattern: pattern.to_string(),
+            pattern_type,
+            reason: Some("abuse report".to_string()),
+        })
+    }
+
+    #[tokio::test]
+    async fn test_non_admin_is_forbidden() {
+        let repo: Arc<dyn UrlBlocklistRepository> = Arc::new(MockBlocklistRepo::default());
+        let response = create_blocklist_entry(
+            Extension(auth_state(ApiKeyScope::default())),
+            Extension(repo.clone()),
+            OperatorCredential(false),
+            create_request("example.com", None),
+        )
+        .await
+        .into_response();
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        assert!(repo.find_all().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_create_list_and_delete_entry() {
+        let repo: Arc<dyn UrlBlocklistRepository> = Arc::new(MockBlocklistRepo::default());
+        let admin = auth_state(ApiKeyScope::full_access());
+
+        let response = create_blocklist_entry(
+            Extension(admin.clone()),
+            Extension(repo.clone()),
+            OperatorCredential(false),
+            create_request("Example.com", None),
+        )
+        .await
+        .into_response();
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let entries = repo.find_all().await.unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].pattern, "example.com");
+        assert_eq!(entries[0].team_id, Some(admin.team_id));
+        assert_eq!(entries[0].reason.as_deref(), Some("abuse report"));
+
+        let response = list_blocklist_entries(
+            Extension(admin.clone()),
+            Extension(repo.clone()),
+            OperatorCredential(false),
+            Query(BlocklistQuery { team_id: None }),
+        )
+        .await
+        .into_response();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let response = delete_blocklist_entry(
+            Extension(admin.clone()),
+            Extension(repo.clone()),
+            OperatorCredential(false),
+            Path(entries[0].id),
+        )
+        .await
+        .into_response();
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+        let response = delete_blocklist_entry(
+            Extension(admin),
+            Extension(repo),
+            OperatorCredential(false),
+            Path(entries[0].id),
+        )
+        .await
+        .into_response();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_invalid_pattern_is_rejected() {
+        let repo: Arc<dyn UrlBlocklistRepository> = Arc::new(MockBlocklistRepo::default());
+        let response = create_blocklist_entry(
+            Extension(auth_state(ApiKeyScope::full_access())),
+            Extension(repo.clone()),
+            OperatorCredential(false),
+            create_request("(unclosed", Some(BlocklistPatternType::Regex)),
+        )
+        .await
+        .into_response();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        assert!(repo.find_all().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_global_and_cross_team_entries_require_operator() {
+        let repo: Arc<dyn UrlBlocklistRepository> = Arc::new(MockBlocklistRepo::default());
+        let admin = auth_state(ApiKeyScope::full_access());
+        let other_team = Uuid::new_v4();
+
+        let global = BlocklistEntry::new(
+            None,
+            "global.com".to_string(),
+            BlocklistPatternType::Wildcard,
+        );
+        let foreign = BlocklistEntry::new(
+            Some(other_team),
+            "foreign.com".to_string(),
+            BlocklistPatternType::Wildcard,
+        );
+        repo.create(&global).await.unwrap();
+        repo.create(&foreign).await.unwrap();
+
+        // 团队 Admin 不能为其他团队创建条目
+        let mut request = create_request("evil.com", None);
+        request.team_id = Some(other_team);
+        let response = create_blocklist_entry(
+            Extension(admin.clone()),
+            Extension(repo.clone()),
+            OperatorCredential(false),
+            request,
+        )
+        .await
+        .into_response();
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+        // 也不能查看其他团队的条目
+        let response = list_blocklist_entries(
+            Extension(admin.clone()),
+            Extension(repo.clone()),
+            OperatorCredential(false),
+            Query(BlocklistQuery {
+                team_id: Some(other_team),
+            }),
+        )
+        .await
+        .into_response();
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+        // 也不能删除全局条目或其他团队的条目
+        for id in [global.id, foreign.id] {
+            let response = delete_blocklist_entry(
+                Extension(admin.clone()),
+                Extension(repo.clone()),
+                OperatorCredential(false),
+                Path(id),
+            )
+            .await
+            .into_response();
+            assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        }
+        assert_eq!(repo.find_all().await.unwrap().len(), 2);
+
+        // 运营方可以创建全局条目并删除任意条目
+        let response = create_blocklist_entry(
+            Extension(admin.clone()),
+            Extension(repo.clone()),
+            OperatorCredential(true),
+            create_request("spam.com", None),
+        )
+        .await
+        .into_response();
+        assert_eq!(response.status(), StatusCode::CREATED);
+        assert!(repo
+            .find_all()
+            .await
+            .unwrap()
+            .iter()
+            .any(|e| e.pattern == "spam.com" && e.team_id.is_none()));
+
+        let response = delete_blocklist_entry(
+            Extension(admin),
+            Extension(repo.clone()),
+            OperatorCredential(true),
+            Path(foreign.id),
+        )
+        .await
+        .into_response();
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+    }
+}
@@ -0,0 +1,139 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! 配置热重载接口
+//!
+//! 重新读取配置文件与环境变量，立即应用可热重载的配置（全局限流速率、引擎开关与路由
+//! 规则、积分价格），并返回已生效与需要重启的字段。只重载处理该请求的进程，worker 进程
+//! 由配置文件监视或 SIGHUP 触发重载。配置作用于所有团队，仅运营方可访问。
+
+use crate::config::ReloadableSettings;
+use crate::domain::services::audit_service::AuditServiceTrait;
+use crate::presentation::extractors::role::RequireOperator;
+use crate::presentation::handlers::response_builder::{errors, success_response};
+use axum::{extract::Extension, http::StatusCode, response::IntoResponse};
+use log::{error, info, warn};
+use std::sync::Arc;
+
+/// 重新加载配置
+pub async fn reload_config(
+    RequireOperator(auth_state): RequireOperator,
+    Extension(settings): Extension<Arc<ReloadableSettings>>,
+    audit_service: Option<Extension<Arc<dyn AuditServiceTrait>>>,
+) -> impl IntoResponse {
+    let report = match settings.reload() {
+        Ok(report) => report,
+        Err(e) => {
+            warn!("Configuration reload rejected: {:#}", e);
+            return errors::unprocessable_entity(format!("Failed to reload configuration: {}", e));
+        }
+    };
+
+    info!(
+        "Configuration reloaded via admin API: reloaded={:?}, restart_required={:?}",
+        report.reloaded, report.restart_required
+    );
+
+    if let Some(Extension(audit_service)) = audit_service {
+        if let Err(e) = audit_service
+            .log_allow(
+                "config.reload".to_string(),
+                auth_state.api_key_id,
+                auth_state.team_id,
+                auth_state.scope.clone(),
+            )
+            .await
+        {
+            error!("Failed to audit configuration reload: {}", e);
+        }
+    }
+
+    success_response(StatusCode::OK, report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bootstrap::config::load_settings;
+    use crate::common::test_helpers::create_test_db_pool;
+    use crate::config::settings::Settings;
+    use crate::domain::auth::ApiKeyScope;
+    use crate::presentation::extractors::role::OPERATOR_TOKEN_HEADER;
+    use crate::presentation::middleware::auth_middleware::AuthState;
+    use axum::body::Body;
+    use axum::http::Request;
+    use tower::ServiceExt;
+    use uuid::Uuid;
+
+    const TEST_OPERATOR_TOKEN: &str = "test-operator-token";
+
+    fn admin_state() -> AuthState {
+        AuthState::new(
+            create_test_db_pool(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            ApiKeyScope::full_access(),
+        )
+    }
+
+    fn operator() -> RequireOperator {
+        RequireOperator(admin_state())
+    }
+
+    #[tokio::test]
+    async fn test_reload_config_requires_operator() {
+        let mut operator_settings = Settings::default();
+        operator_settings.server.operator_token = Some(TEST_OPERATOR_TOKEN.to_string());
+        let app = axum::Router::new()
+            .route(
+                "/admin/v1/config/reload",
+                axum::routing::post(reload_config),
+            )
+            .layer(Extension(Arc::new(
+                ReloadableSettings::new(load_settings().unwrap()).with_loader(load_settings),
+            )))
+            .layer(Extension(Arc::new(operator_settings)))
+            .layer(Extension(admin_state()));
+        let request = |token: Option<&str>| {
+            let mut builder = Request::builder()
+                .method("POST")
+                .uri("/admin/v1/config/reload");
+            if let Some(token) = token {
+                builder = builder.header(OPERATOR_TOKEN_HEADER, token);
+            }
+            builder.body(Body::empty()).unwrap()
+        };
+
+        // 团队的 Admin Key 不能重载所有团队共用的配置
+        let response = app.clone().oneshot(request(None)).await.unwrap();
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+        let response = app
+            .oneshot(request(Some(TEST_OPERATOR_TOKEN)))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_reload_config_without_loader_is_rejected() {
+        let settings = ReloadableSettings::new(load_settings().unwrap());
+
+        let response = reload_config(operator(), Extension(Arc::new(settings)), None)
+            .await
+            .into_response();
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    #[tokio::test]
+    async fn test_reload_config_returns_report() {
+        let settings = ReloadableSettings::new(load_settings().unwrap()).with_loader(load_settings);
+
+        let response = reload_config(operator(), Extension(Arc::new(settings)), None)
+            .await
+            .into_response();
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+}
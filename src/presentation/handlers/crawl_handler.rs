@@ -4,28 +4,48 @@
 // See LICENSE file in the project root for full license information.
 
 use axum::{
-    extract::{ConnectInfo, Extension, Path},
-    http::StatusCode,
-    response::IntoResponse,
+    extract::{ConnectInfo, Extension, Path, Query},
+    http::{header, StatusCode},
+    response::{
+        sse::{Event, KeepAlive, Sse},
+        IntoResponse, Response,
+    },
     Json,
 };
+use chrono::Utc;
+use futures::stream::{BoxStream, StreamExt};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
 use std::net::SocketAddr;
 use std::sync::Arc;
 use uuid::Uuid;
 
 use crate::application::dto::crawl_request::CrawlRequestDto;
 use crate::application::use_cases::crawl_use_case::CrawlUseCaseError;
-use crate::common::constants::crawl_task::CRAWL_TASK_CREDITS_COST;
 use crate::common::constants::crawl_task::DEFAULT_TIMEOUT_MS;
+use crate::domain::models::scrape_result::ScrapeResult;
+use crate::domain::models::{
+    Crawl, DomainThrottle, DomainThrottleStatus, TaggedResource, ThrottlePolicy,
+};
+use crate::domain::repositories::task_repository::RepositoryError;
+use crate::engines::host_resolve::{override_for, parse_resolve_overrides};
+use crate::engines::proxy_url::redact_proxy_url;
 use crate::presentation::handlers::extract_task_ids;
 use crate::presentation::handlers::response_builder::errors;
 use crate::presentation::handlers::response_builder::{error_response, success_response};
+use crate::presentation::handlers::tag_handler::parse_tag_filter;
 use crate::presentation::handlers::task_handler::handle_sync_wait_and_get_status;
 use crate::presentation::handlers::task_handler::SyncWaitResult;
+use crate::presentation::helpers::blocklist_helper::check_url_blocklist;
 use crate::presentation::helpers::rate_limit_helper::check_rate_limit;
-use crate::presentation::helpers::ssrf::validate_url;
+use crate::presentation::helpers::ssrf::{is_internal_url, validate_proxy_url, validate_url};
 use crate::presentation::middleware::auth_middleware::AuthState;
 use crate::presentation::state::CrawlHandlerState;
+use crate::queue::result_stream::CrawlStreamEvent;
+use crate::utils::link_graph::{GraphFormat, LinkGraph};
+use crate::utils::search_snippet::{build_snippet, query_terms, searchable_text};
+use crate::utils::seo_audit::{audit_page, SeoAudit, SeoReport};
+use crate::utils::text_diff::{diff_text, html_to_text};
 use log::error;
 
 /// 创建新的爬取任务
@@ -43,6 +63,22 @@ pub async fn create_crawl(
         return errors::unprocessable_entity("max_depth must be between 0 and 5");
     }
 
+    if let Err(e) = payload.config.validate_proxy() {
+        return errors::unprocessable_entity(e);
+    }
+
+    // 启用团队套餐时，爬取深度受团队套餐限制
+    if let Some(plan_service) = &state.plan_service {
+        let plan = plan_service.plan_for(team_id).await;
+        let max_crawl_depth = plan_service.limits(plan).max_crawl_depth;
+        if payload.config.max_depth > max_crawl_depth {
+            return errors::forbidden(format!(
+                "max_depth {} exceeds the {} plan limit of {}",
+                payload.config.max_depth, plan, max_crawl_depth
+            ));
+        }
+    }
+
     // 1. 检查限流（架构 MEDIUM-1：限流必须在 SSRF 之前，避免恶意请求触发异步 DNS 解析消耗资源）
     // 性能 LOW-1：直接传 `Uuid`（实现 Display），由 helper 内部按需 to_string，
     // 消除 handler 中的中间变量分配。
@@ -56,34 +92,57 @@ pub async fn create_crawl(
         return response;
     }
 
-    // 2. SSRF 验证 - 使用完整的异步 DNS 验证
-    match validate_url(&payload.url).await {
-        Ok(validated) => {
-            log::debug!(
-                "URL passed SSRF validation url={} team_id={} resolved_ips={:?}",
-                payload.url,
-                team_id,
-                validated.resolved_ips
-            );
+    // 2. SSRF 验证 - 使用完整的异步 DNS 验证；根 URL 的主机名被 `resolve` 覆盖时不走 DNS
+    // （预发布主机名可能没有公网解析），覆盖地址已在解析覆盖表时校验为公网地址
+    let resolve = match &payload.config.resolve {
+        Some(resolve) => match parse_resolve_overrides(resolve) {
+            Ok(resolve) => resolve,
+            Err(e) => return errors::bad_request(e),
+        },
+        None => HashMap::new(),
+    };
+    if let Some(ip) = override_for(&payload.url, &resolve) {
+        if is_internal_url(&payload.url) {
+            return errors::bad_request(format!(
+                "SSRF protection: {} is an internal URL",
+                payload.url
+            ));
         }
-        Err(e) => {
-            log::warn!(
-                "SSRF attack attempt blocked url={} team_id={} api_key_id={} error={}",
-                payload.url,
-                team_id,
-                auth_state.api_key_id,
-                e
-            );
-            return errors::bad_request(format!("SSRF protection: {}", e));
+        log::debug!(
+            "Crawl root host resolved by override url={} ip={} team_id={}",
+            payload.url,
+            ip,
+            team_id
+        );
+    } else {
+        match validate_url(&payload.url).await {
+            Ok(validated) => {
+                log::debug!(
+                    "URL passed SSRF validation url={} team_id={} resolved_ips={:?}",
+                    payload.url,
+                    team_id,
+                    validated.resolved_ips
+                );
+            }
+            Err(e) => {
+                log::warn!(
+                    "SSRF attack attempt blocked url={} team_id={} api_key_id={} error={}",
+                    payload.url,
+                    team_id,
+                    auth_state.api_key_id,
+                    e
+                );
+                return errors::bad_request(format!("SSRF protection: {}", e));
+            }
         }
     }
 
     // 2.5 SSRF 防护 (CWE-918)：验证 config.proxy 不指向内部网络
     if let Some(ref proxy_url) = payload.config.proxy {
-        if let Err(e) = validate_url(proxy_url).await {
+        if let Err(e) = validate_proxy_url(proxy_url).await {
             log::warn!(
                 "SSRF via proxy blocked proxy={} team_id={} api_key_id={} error={}",
-                proxy_url,
+                redact_proxy_url(proxy_url),
                 team_id,
                 auth_state.api_key_id,
                 e
@@ -92,12 +151,64 @@ pub async fn create_crawl(
         }
     }
 
+    // 2.55 SSRF 防护：结果投递目标（自定义 S3 endpoint 或预签名 URL）不得指向内部网络
+    if let Some(destination) = &payload.config.result_destination {
+        if let Some(target_url) = destination.target_url() {
+            if let Err(e) = validate_url(target_url).await {
+                log::warn!(
+                    "SSRF via result destination blocked destination={:?} team_id={} api_key_id={} error={}",
+                    destination.redacted().target_url(),
+                    team_id,
+                    auth_state.api_key_id,
+                    e
+                );
+                return errors::bad_request(format!(
+                    "SSRF protection: result destination rejected: {}",
+                    e
+                ));
+            }
+        }
+    }
+
+    // 2.6 URL 黑名单（全局 + 团队）
+    if let Some(url_blocklist) = &state.url_blocklist {
+        if let Err(response) =
+            check_url_blocklist(url_blocklist, team_id, [payload.url.as_str()]).await
+        {
+            return response;
+        }
+    }
+
+    // 2.7 忽略 robots.txt 需要团队能力授权，并记录审计日志
+    if payload.config.ignore_robots == Some(true) {
+        if let Err(response) = authorize_ignore_robots(&state, &auth_state, &payload.url).await {
+            return response;
+        }
+    }
+
+    // 2.8 预演：只发现链接并估算成本，不创建任务、不扣除积分
+    if payload.dry_run == Some(true) {
+        let Some(dry_run) = state.create_dry_run_use_case() else {
+            return error_response(
+                StatusCode::SERVICE_UNAVAILABLE,
+                "Crawl dry run is not available",
+            );
+        };
+        return match dry_run.execute(team_id, &payload).await {
+            Ok(result) => success_response(StatusCode::OK, result),
+            Err(e) => {
+                let (status, msg): (StatusCode, String) = e.into();
+                error_response(status, msg)
+            }
+        };
+    }
+
     // 3. 检查配额
     if let Err(e) = state
         .rate_limiting_service
         .check_and_deduct_quota(
             team_id,
-            CRAWL_TASK_CREDITS_COST,
+            state.pricing.pricing_for(team_id).crawl,
             crate::domain::models::CreditsTransactionType::Crawl,
             format!("Crawl URL: {}", payload.url),
             None,
@@ -169,6 +280,83 @@ pub async fn create_crawl(
     }
 }
 
+/// 审计日志中 `ignore_robots` 的动作名
+const IGNORE_ROBOTS_AUDIT_ACTION: &str = "crawl.ignore_robots";
+
+/// 校验团队是否具备 `allow_ignore_robots` 能力，并将每次使用写入审计日志
+///
+/// 未配置能力仓库或团队不存在时视为未授权；审计日志写入失败时拒绝请求，
+/// 保证每次忽略 robots.txt 都有审计记录。
+async fn authorize_ignore_robots(
+    state: &CrawlHandlerState,
+    auth_state: &AuthState,
+    url: &str,
+) -> Result<(), Response> {
+    let allowed = match &state.team_capability_repo {
+        Some(repo) => match repo.get_capabilities(auth_state.team_id).await {
+            Ok(capabilities) => capabilities.allow_ignore_robots,
+            Err(RepositoryError::NotFound) => false,
+            Err(e) => {
+                error!(
+                    "Failed to load capabilities for team {}: {}",
+                    auth_state.team_id, e
+                );
+                return Err(errors::internal_server_error(
+                    "Failed to verify team capabilities",
+                ));
+            }
+        },
+        None => false,
+    };
+
+    if !allowed {
+        log::warn!(
+            "ignore_robots denied url={} team_id={} api_key_id={}",
+            url,
+            auth_state.team_id,
+            auth_state.api_key_id
+        );
+        if let Some(audit_service) = &state.audit_service {
+            let _ = audit_service
+                .log_deny(
+                    IGNORE_ROBOTS_AUDIT_ACTION.to_string(),
+                    Some(auth_state.api_key_id),
+                    Some(auth_state.team_id),
+                    format!("Team lacks allow_ignore_robots capability (url: {})", url),
+                    Some(auth_state.scope.clone()),
+                )
+                .await;
+        }
+        return Err(errors::forbidden(
+            "ignore_robots requires the allow_ignore_robots team capability",
+        ));
+    }
+
+    log::warn!(
+        "robots.txt will be ignored url={} team_id={} api_key_id={}",
+        url,
+        auth_state.team_id,
+        auth_state.api_key_id
+    );
+    if let Some(audit_service) = &state.audit_service {
+        if let Err(e) = audit_service
+            .log_allow(
+                IGNORE_ROBOTS_AUDIT_ACTION.to_string(),
+                auth_state.api_key_id,
+                auth_state.team_id,
+                auth_state.scope.clone(),
+            )
+            .await
+        {
+            error!("Failed to audit ignore_robots for {}: {}", url, e);
+            return Err(errors::internal_server_error(
+                "Failed to record audit log for ignore_robots",
+            ));
+        }
+    }
+    Ok(())
+}
+
 /// 获取爬取任务详情
 pub async fn get_crawl(
     Extension(state): Extension<Arc<CrawlHandlerState>>,
@@ -179,7 +367,10 @@ pub async fn get_crawl(
     let use_case = state.create_use_case();
 
     match use_case.get_crawl(crawl_id, team_id).await {
-        Ok(Some(crawl)) => success_response(StatusCode::OK, crawl),
+        Ok(Some(crawl)) => {
+            let throttle = crawl_throttle_status(&state, &crawl).await;
+            success_response(StatusCode::OK, CrawlStatusResponse { crawl, throttle })
+        }
         Ok(None) => errors::not_found("Crawl not found"),
         Err(e) => {
             let (status, msg): (StatusCode, String) = e.into();
@@ -188,22 +379,633 @@ pub async fn get_crawl(
     }
 }
 
+/// 爬取状态响应：爬取信息 + 目标域名的自适应节流状态
+#[derive(Debug, Serialize)]
+struct CrawlStatusResponse {
+    #[serde(flatten)]
+    crawl: Crawl,
+    /// 目标域名当前处于节流期时返回
+    #[serde(skip_serializing_if = "Option::is_none")]
+    throttle: Option<DomainThrottleStatus>,
+}
+
+/// 查询爬取根域名的节流状态，查询失败时不影响状态接口
+async fn crawl_throttle_status(
+    state: &CrawlHandlerState,
+    crawl: &Crawl,
+) -> Option<DomainThrottleStatus> {
+    let repo = state.domain_throttle_repo.as_ref()?;
+    let domain = DomainThrottle::domain_of(&crawl.root_url)?;
+    match repo.find_by_domain(&domain).await {
+        Ok(throttle) => throttle?.status(&ThrottlePolicy::default(), Utc::now()),
+        Err(e) => {
+            error!("Failed to load domain throttle for {}: {}", domain, e);
+            None
+        }
+    }
+}
+
+/// 爬取结果查询参数
+#[derive(Debug, Default, Deserialize)]
+pub struct CrawlResultsQuery {
+    /// 以 SSE 持续推送结果，直到爬取结束
+    pub follow: Option<bool>,
+    /// 逗号分隔的标签，只返回带有全部标签的结果
+    pub tags: Option<String>,
+}
+
+/// 解析标签过滤参数，未指定标签时返回空列表
+///
+/// 指定了标签但未配置标签仓库时返回 503。
+fn tag_filter(state: &CrawlHandlerState, raw: Option<&str>) -> Result<Vec<String>, Box<Response>> {
+    let tags = parse_tag_filter(raw).map_err(|e| Box::new(errors::bad_request(e)))?;
+    if !tags.is_empty() && state.resource_tag_repo.is_none() {
+        return Err(Box::new(error_response(
+            StatusCode::SERVICE_UNAVAILABLE,
+            "Tag filtering is not available",
+        )));
+    }
+    Ok(tags)
+}
+
+/// 返回 `result_ids` 中带有全部 `tags` 的结果 ID
+async fn results_with_tags(
+    state: &CrawlHandlerState,
+    result_ids: &[Uuid],
+    tags: &[String],
+) -> Result<HashSet<Uuid>, Box<Response>> {
+    let Some(repo) = state.resource_tag_repo.as_ref() else {
+        return Ok(HashSet::new());
+    };
+    match repo.find_many(TaggedResource::Result, result_ids).await {
+        Ok(found) => Ok(found
+            .into_iter()
+            .filter(|t| t.has_all(tags))
+            .map(|t| t.resource_id)
+            .collect()),
+        Err(e) => {
+            error!("Failed to load result tags: {}", e);
+            Err(Box::new(errors::internal_server_error(
+                "Failed to load result tags",
+            )))
+        }
+    }
+}
+
 /// 获取爬取任务结果
+///
+/// `follow=true` 时以 SSE 推送：先发送已保存的结果，随后每保存一个结果推送一个
+/// `result` 事件，爬取结束后发送 `done` 事件并关闭连接。
+/// `tags=a,b` 时只返回带有全部标签的结果，不能与 `follow` 同时使用。
 pub async fn get_crawl_results(
     Extension(state): Extension<Arc<CrawlHandlerState>>,
     Extension(auth_state): Extension<AuthState>,
     Path(crawl_id): Path<Uuid>,
+    Query(query): Query<CrawlResultsQuery>,
+) -> Response {
+    let team_id = auth_state.team_id;
+    let tags = match tag_filter(&state, query.tags.as_deref()) {
+        Ok(tags) => tags,
+        Err(response) => return *response,
+    };
+    if query.follow.unwrap_or(false) {
+        if !tags.is_empty() {
+            return errors::bad_request("tags cannot be combined with follow");
+        }
+        return follow_crawl_results(state, crawl_id, team_id).await;
+    }
+
+    let use_case = state.create_use_case();
+    let mut results = match use_case.get_crawl_results(crawl_id, team_id).await {
+        Ok(results) => results,
+        Err(e) => {
+            let (status, msg): (StatusCode, String) = e.into();
+            return error_response(status, msg);
+        }
+    };
+    if !tags.is_empty() {
+        let ids: Vec<Uuid> = results.iter().map(|r| r.id).collect();
+        let matching = match results_with_tags(&state, &ids, &tags).await {
+            Ok(matching) => matching,
+            Err(response) => return *response,
+        };
+        results.retain(|r| matching.contains(&r.id));
+    }
+    success_response(StatusCode::OK, results)
+}
+
+/// 全文检索默认返回的结果数
+const DEFAULT_SEARCH_LIMIT: u64 = 20;
+
+/// 全文检索最多返回的结果数
+const MAX_SEARCH_LIMIT: u64 = 100;
+
+/// 全文检索查询参数
+#[derive(Debug, Default, Deserialize)]
+pub struct ResultSearchQuery {
+    /// 检索词，支持引号短语、`or` 与 `-` 排除
+    pub q: Option<String>,
+    /// 返回的最大结果数，默认 20，最大 100
+    pub limit: Option<u64>,
+    /// 逗号分隔的标签，只返回带有全部标签的命中
+    pub tags: Option<String>,
+}
+
+/// 全文检索命中项
+#[derive(Debug, Serialize)]
+struct ResultSearchHitResponse {
+    result_id: Uuid,
+    task_id: Uuid,
+    url: String,
+    status_code: i32,
+    rank: f32,
+    /// 命中片段，已做 HTML 转义，命中词以 `<b>` 标记
+    snippet: String,
+    created_at: chrono::NaiveDateTime,
+}
+
+/// 全文检索响应
+#[derive(Debug, Serialize)]
+struct ResultSearchResponse {
+    query: String,
+    hits: Vec<ResultSearchHitResponse>,
+}
+
+/// 在爬取的结果中全文检索，按相关度返回命中片段
+pub async fn search_crawl_results(
+    Extension(state): Extension<Arc<CrawlHandlerState>>,
+    Extension(auth_state): Extension<AuthState>,
+    Path(crawl_id): Path<Uuid>,
+    Query(query): Query<ResultSearchQuery>,
+) -> Response {
+    let q = query.q.unwrap_or_default();
+    let limit = query
+        .limit
+        .unwrap_or(DEFAULT_SEARCH_LIMIT)
+        .clamp(1, MAX_SEARCH_LIMIT);
+    let tags = match tag_filter(&state, query.tags.as_deref()) {
+        Ok(tags) => tags,
+        Err(response) => return *response,
+    };
+    // 按标签过滤时先取最多的命中，过滤后再截断
+    let fetch_limit = if tags.is_empty() {
+        limit
+    } else {
+        MAX_SEARCH_LIMIT
+    };
+    let use_case = state.create_use_case();
+
+    let mut hits = match use_case
+        .search_crawl_results(crawl_id, auth_state.team_id, &q, fetch_limit)
+        .await
+    {
+        Ok(hits) => hits,
+        Err(e) => {
+            let (status, msg): (StatusCode, String) = e.into();
+            return error_response(status, msg);
+        }
+    };
+    if !tags.is_empty() {
+        let ids: Vec<Uuid> = hits.iter().map(|hit| hit.result.id).collect();
+        let matching = match results_with_tags(&state, &ids, &tags).await {
+            Ok(matching) => matching,
+            Err(response) => return *response,
+        };
+        hits.retain(|hit| matching.contains(&hit.result.id));
+        hits.truncate(limit as usize);
+    }
+
+    let terms = query_terms(&q);
+    let hits = hits
+        .into_iter()
+        .map(|hit| {
+            let text = searchable_text(&hit.result.content, &hit.result.content_type);
+            ResultSearchHitResponse {
+                result_id: hit.result.id,
+                task_id: hit.result.task_id,
+                url: hit.result.url,
+                status_code: hit.result.status_code,
+                rank: hit.rank,
+                snippet: build_snippet(&text, &terms),
+                created_at: hit.result.created_at,
+            }
+        })
+        .collect();
+    success_response(
+        StatusCode::OK,
+        ResultSearchResponse {
+            query: q.trim().to_string(),
+            hits,
+        },
+    )
+}
+
+/// 结果推送通道缓冲的事件数，客户端读取过慢时推送任务等待
+const FOLLOW_CHANNEL_CAPACITY: usize = 64;
+
+/// 补发已保存结果时每批加载的任务数，避免一次读入整个爬取的结果
+const FOLLOW_BACKFILL_PAGE_SIZE: usize = 100;
+
+/// 建立结果推送的 SSE 连接
+async fn follow_crawl_results(
+    state: Arc<CrawlHandlerState>,
+    crawl_id: Uuid,
+    team_id: Uuid,
+) -> Response {
+    let Some(bus) = state.result_stream.clone() else {
+        return error_response(
+            StatusCode::SERVICE_UNAVAILABLE,
+            "Result streaming is not available",
+        );
+    };
+
+    let use_case = state.create_use_case();
+    match use_case.get_crawl(crawl_id, team_id).await {
+        Ok(Some(_)) => {}
+        Ok(None) => return errors::not_found("Crawl not found"),
+        Err(e) => {
+            let (status, msg): (StatusCode, String) = e.into();
+            return error_response(status, msg);
+        }
+    }
+
+    // 先订阅再读取已保存的结果，两者之间保存的结果不会遗漏
+    let events = match bus.subscribe(crawl_id).await {
+        Ok(events) => events,
+        Err(e) => {
+            error!(
+                "Failed to subscribe to results of crawl {}: {}",
+                crawl_id, e
+            );
+            return error_response(
+                StatusCode::SERVICE_UNAVAILABLE,
+                "Result streaming is not available",
+            );
+        }
+    };
+
+    let (sender, receiver) = tokio::sync::mpsc::channel(FOLLOW_CHANNEL_CAPACITY);
+    tokio::spawn(stream_crawl_results(
+        state, crawl_id, team_id, events, sender,
+    ));
+    let stream = futures::stream::unfold(receiver, |mut receiver| async move {
+        receiver
+            .recv()
+            .await
+            .map(|event| (Ok::<_, std::convert::Infallible>(event), receiver))
+    });
+    Sse::new(stream)
+        .keep_alive(KeepAlive::default())
+        .into_response()
+}
+
+/// 结果推送任务，客户端断开时退出
+///
+/// 每个任务的结果只推送一次：补发已保存的结果后转发总线事件；收到结束事件或定期检查
+/// 发现爬取已结束时，补发遗漏的结果并发送 `done` 事件。
+async fn stream_crawl_results(
+    state: Arc<CrawlHandlerState>,
+    crawl_id: Uuid,
+    team_id: Uuid,
+    mut events: BoxStream<'static, CrawlStreamEvent>,
+    sender: tokio::sync::mpsc::Sender<Event>,
+) {
+    let use_case = state.create_use_case();
+    let mut sent = HashSet::new();
+    if !send_missing_results(&state, crawl_id, &mut sent, &sender).await {
+        return;
+    }
+
+    let mut status_check = tokio::time::interval(state.result_stream_status_check);
+    status_check.tick().await;
+    let status = loop {
+        tokio::select! {
+            _ = sender.closed() => return,
+            event = events.next() => match event {
+                Some(CrawlStreamEvent::Result { task_id, .. }) => {
+                    if sent.contains(&task_id) {
+                        continue;
+                    }
+                    match state.scrape_result_repo.find_by_task_id(task_id).await {
+                        Ok(Some(result)) => {
+                            if sent.insert(task_id)
+                                && sender.send(result_event(&result)).await.is_err()
+                            {
+                                return;
+                            }
+                        }
+                        Ok(None) => {}
+                        Err(e) => error!(
+                            "Failed to load result of task {} in crawl {}: {}",
+                            task_id, crawl_id, e
+                        ),
+                    }
+                }
+                Some(CrawlStreamEvent::Finished { status }) => break status,
+                // 总线连接断开，改为只依赖定期检查
+                None => events = futures::stream::pending().boxed(),
+            },
+            _ = status_check.tick() => match use_case.get_crawl(crawl_id, team_id).await {
+                Ok(Some(crawl)) if crawl.is_finished() => break crawl.status,
+                Ok(Some(_)) => {}
+                Ok(None) => return,
+                Err(e) => error!("Failed to check status of crawl {}: {}", crawl_id, e),
+            },
+        }
+    };
+
+    if send_missing_results(&state, crawl_id, &mut sent, &sender).await {
+        let done = Event::default()
+            .event("done")
+            .json_data(serde_json::json!({ "status": status }))
+            .unwrap_or_else(|_| Event::default().event("done"));
+        let _ = sender.send(done).await;
+    }
+}
+
+/// 推送尚未发送的已保存结果，客户端已断开时返回 false
+///
+/// 只加载结果尚未推送的任务，并按批读取结果，已推送的结果不会重复加载。
+/// 调用方须已校验爬取归属。
+async fn send_missing_results(
+    state: &CrawlHandlerState,
+    crawl_id: Uuid,
+    sent: &mut HashSet<Uuid>,
+    sender: &tokio::sync::mpsc::Sender<Event>,
+) -> bool {
+    let task_ids: Vec<Uuid> = match state.task_repo.find_by_crawl_id(crawl_id).await {
+        Ok(tasks) => tasks
+            .into_iter()
+            .map(|task| task.id)
+            .filter(|task_id| !sent.contains(task_id))
+            .collect(),
+        Err(e) => {
+            error!("Failed to load tasks of crawl {}: {}", crawl_id, e);
+            return true;
+        }
+    };
+    for page in task_ids.chunks(FOLLOW_BACKFILL_PAGE_SIZE) {
+        let results = match state.scrape_result_repo.find_by_task_ids(page).await {
+            Ok(results) => results,
+            Err(e) => {
+                error!("Failed to load results of crawl {}: {}", crawl_id, e);
+                return true;
+            }
+        };
+        for result in results {
+            if sent.insert(result.task_id) && sender.send(result_event(&result)).await.is_err() {
+                return false;
+            }
+        }
+    }
+    true
+}
+
+/// 单个结果的 SSE 事件，事件 ID 为结果 ID
+fn result_event(result: &ScrapeResult) -> Event {
+    Event::default()
+        .event("result")
+        .id(result.id.to_string())
+        .json_data(result)
+        .unwrap_or_else(|_| Event::default().event("result").id(result.id.to_string()))
+}
+
+/// 链接图导出的最大链接数，超出部分不导出并标记为截断
+const MAX_GRAPH_EDGES: u64 = 100_000;
+
+/// 链接图导出截断时，GraphML 与 DOT 格式通过该响应头标记
+const GRAPH_TRUNCATED_HEADER: &str = "x-graph-truncated";
+
+/// 链接图查询参数
+#[derive(Debug, Deserialize)]
+pub struct GraphQuery {
+    /// 导出格式，默认 `json`
+    pub format: Option<GraphFormat>,
+}
+
+/// 导出爬取中记录的页面链接图
+///
+/// `json` 格式返回节点与边（统一响应信封），`graphml` 与 `dot` 格式以附件形式返回文件。
+pub async fn get_crawl_graph(
+    Extension(state): Extension<Arc<CrawlHandlerState>>,
+    Extension(auth_state): Extension<AuthState>,
+    Path(crawl_id): Path<Uuid>,
+    Query(query): Query<GraphQuery>,
 ) -> impl IntoResponse {
     let team_id = auth_state.team_id;
     let use_case = state.create_use_case();
 
-    match use_case.get_crawl_results(crawl_id, team_id).await {
-        Ok(results) => success_response(StatusCode::OK, results),
+    match use_case.get_crawl(crawl_id, team_id).await {
+        Ok(Some(_)) => {}
+        Ok(None) => return errors::not_found("Crawl not found"),
         Err(e) => {
             let (status, msg): (StatusCode, String) = e.into();
-            error_response(status, msg)
+            return error_response(status, msg);
+        }
+    }
+
+    // 多取一条用于判断是否截断
+    let mut links = match &state.crawl_link_repo {
+        Some(repo) => match repo.find_by_crawl(crawl_id, MAX_GRAPH_EDGES + 1).await {
+            Ok(links) => links,
+            Err(e) => {
+                error!("Failed to load links of crawl {}: {}", crawl_id, e);
+                return errors::internal_server_error("Failed to load crawl links");
+            }
+        },
+        None => Vec::new(),
+    };
+    let truncated = links.len() as u64 > MAX_GRAPH_EDGES;
+    links.truncate(MAX_GRAPH_EDGES as usize);
+    let graph = LinkGraph::from_links(links, truncated);
+
+    let format = query.format.unwrap_or_default();
+    let (body, extension) = match format {
+        GraphFormat::Json => return success_response(StatusCode::OK, graph),
+        GraphFormat::Graphml => (graph.to_graphml(), "graphml"),
+        GraphFormat::Dot => (graph.to_dot(), "dot"),
+    };
+    let mut response = (
+        [
+            (header::CONTENT_TYPE, format.content_type().to_string()),
+            (
+                header::CONTENT_DISPOSITION,
+                format!("attachment; filename=\"crawl-{}.{}\"", crawl_id, extension),
+            ),
+        ],
+        body,
+    )
+        .into_response();
+    if truncated {
+        response.headers_mut().insert(
+            GRAPH_TRUNCATED_HEADER,
+            header::HeaderValue::from_static("true"),
+        );
+    }
+    response
+}
+
+/// 结果的 SEO 审计：优先使用抓取时保存的 `seo` 审计，否则按保存的 HTML 审计
+///
+/// 只审计成功的 HTML 页面。
+fn result_seo_audit(result: &ScrapeResult) -> Option<SeoAudit> {
+    if !(200..300).contains(&result.status_code) || !result.content_type.contains("html") {
+        return None;
+    }
+    result
+        .meta_data
+        .get("seo")
+        .and_then(|seo| serde_json::from_value(seo.clone()).ok())
+        .or_else(|| (!result.content.is_empty()).then(|| audit_page(&result.content, &result.url)))
+}
+
+/// 爬取级 SEO 报告
+///
+/// 汇总爬取中各 HTML 页面的审计：问题分布、重复的标题与描述、平均字数、
+/// 图片 alt 覆盖率与链接数，以及存在问题的页面列表。
+pub async fn get_crawl_seo_report(
+    Extension(state): Extension<Arc<CrawlHandlerState>>,
+    Extension(auth_state): Extension<AuthState>,
+    Path(crawl_id): Path<Uuid>,
+) -> impl IntoResponse {
+    let use_case = state.create_use_case();
+    let results = match use_case
+        .get_crawl_results(crawl_id, auth_state.team_id)
+        .await
+    {
+        Ok(results) => results,
+        Err(e) => {
+            let (status, msg): (StatusCode, String) = e.into();
+            return error_response(status, msg);
+        }
+    };
+
+    let audits = results
+        .iter()
+        .filter_map(|result| Some((result.url.clone(), result_seo_audit(result)?)));
+    success_response(StatusCode::OK, SeoReport::from_audits(audits))
+}
+
+/// 页面对比查询参数
+#[derive(Debug, Deserialize)]
+pub struct CompareQuery {
+    /// 页面 URL
+    pub url: String,
+    /// 基准爬取 ID
+    pub from: Uuid,
+    /// 对比爬取 ID
+    pub to: Uuid,
+    /// 对比格式，默认 `text`
+    pub format: Option<CompareFormat>,
+}
+
+/// 页面对比格式
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CompareFormat {
+    /// 提取 HTML 正文文本后按行对比
+    #[default]
+    Text,
+    /// 按行对比原始内容
+    Html,
+}
+
+/// 对比中的页面版本
+#[derive(Debug, Serialize)]
+struct PageVersion {
+    crawl_id: Uuid,
+    result_id: Uuid,
+    status_code: i32,
+    /// 增量重爬中返回 304 的版本
+    unchanged: bool,
+    captured_at: chrono::NaiveDateTime,
+}
+
+impl PageVersion {
+    fn new(crawl_id: Uuid, result: &ScrapeResult) -> Self {
+        Self {
+            crawl_id,
+            result_id: result.id,
+            status_code: result.status_code,
+            unchanged: result.status_code == 304,
+            captured_at: result.created_at,
+        }
+    }
+}
+
+/// 页面对比响应
+#[derive(Debug, Serialize)]
+struct CompareResponse {
+    url: String,
+    format: CompareFormat,
+    from: PageVersion,
+    to: PageVersion,
+    changed: bool,
+    added_lines: usize,
+    removed_lines: usize,
+    /// unified 格式的差异，内容相同时为空
+    diff: String,
+}
+
+/// 按对比格式取出页面内容
+fn comparable_content(result: &ScrapeResult, format: CompareFormat) -> String {
+    match format {
+        CompareFormat::Text if result.content_type.contains("html") => {
+            html_to_text(&result.content)
+        }
+        _ => result.content.clone(),
+    }
+}
+
+/// 对比同一 URL 在两次爬取中保存的内容
+pub async fn compare_crawl_pages(
+    Extension(state): Extension<Arc<CrawlHandlerState>>,
+    Extension(auth_state): Extension<AuthState>,
+    Query(query): Query<CompareQuery>,
+) -> impl IntoResponse {
+    let team_id = auth_state.team_id;
+    let use_case = state.create_use_case();
+
+    let mut versions = Vec::with_capacity(2);
+    for crawl_id in [query.from, query.to] {
+        match use_case
+            .get_page_version(crawl_id, team_id, &query.url)
+            .await
+        {
+            Ok(Some(result)) => versions.push(result),
+            Ok(None) => {
+                return errors::not_found(format!(
+                    "No result for {} in crawl {}",
+                    query.url, crawl_id
+                ))
+            }
+            Err(e) => {
+                let (status, msg): (StatusCode, String) = e.into();
+                return error_response(status, msg);
+            }
         }
     }
+    let (from, to) = (&versions[0], &versions[1]);
+
+    let format = query.format.unwrap_or_default();
+    let diff = diff_text(
+        &comparable_content(from, format),
+        &comparable_content(to, format),
+    );
+
+    success_response(
+        StatusCode::OK,
+        CompareResponse {
+            url: query.url,
+            format,
+            from: PageVersion::new(query.from, from),
+            to: PageVersion::new(query.to, to),
+            changed: diff.has_changes(),
+            added_lines: diff.added_lines,
+            removed_lines: diff.removed_lines,
+            diff: diff.unified,
+        },
+    )
 }
 
 /// 取消进行中的爬取任务
@@ -386,6 +1188,24 @@ mod tests {
             proxy: None,
             headers: None,
             extraction_rules: None,
+            skip_nofollow_links: None,
+            ignore_robots: None,
+            ignore_sitemap: None,
+            limit: None,
+            max_duration_seconds: None,
+            previous_crawl_id: None,
+            allow_subdomains: None,
+            allow_external_domains: None,
+            ignore_query_params: None,
+            ignore_all_query_params: None,
+            max_query_variants_per_path: None,
+            download_assets: None,
+            allowed_content_types: None,
+            excluded_content_types: None,
+            result_destination: None,
+            keep_local_results: None,
+            formats: None,
+            resolve: None,
         };
         // Handler checks: payload.config.max_depth > 5
         assert!(config.max_depth <= 5, "max_depth of 5 should pass");
@@ -403,6 +1223,24 @@ mod tests {
             proxy: None,
             headers: None,
             extraction_rules: None,
+            skip_nofollow_links: None,
+            ignore_robots: None,
+            ignore_sitemap: None,
+            limit: None,
+            max_duration_seconds: None,
+            previous_crawl_id: None,
+            allow_subdomains: None,
+            allow_external_domains: None,
+            ignore_query_params: None,
+            ignore_all_query_params: None,
+            max_query_variants_per_path: None,
+            download_assets: None,
+            allowed_content_types: None,
+            excluded_content_types: None,
+            result_destination: None,
+            keep_local_results: None,
+            formats: None,
+            resolve: None,
         };
         // Handler checks: payload.config.max_depth > 5
         assert!(config.max_depth > 5, "max_depth of 6 should fail");
@@ -420,6 +1258,24 @@ mod tests {
             proxy: None,
             headers: None,
             extraction_rules: None,
+            skip_nofollow_links: None,
+            ignore_robots: None,
+            ignore_sitemap: None,
+            limit: None,
+            max_duration_seconds: None,
+            previous_crawl_id: None,
+            allow_subdomains: None,
+            allow_external_domains: None,
+            ignore_query_params: None,
+            ignore_all_query_params: None,
+            max_query_variants_per_path: None,
+            download_assets: None,
+            allowed_content_types: None,
+            excluded_content_types: None,
+            result_destination: None,
+            keep_local_results: None,
+            formats: None,
+            resolve: None,
         };
         assert!(config.max_depth <= 5);
     }
@@ -438,6 +1294,24 @@ mod tests {
             proxy: Some("http://proxy:8080".to_string()),
             headers: Some(serde_json::json!({"Accept": "text/html"})),
             extraction_rules: None,
+            skip_nofollow_links: None,
+            ignore_robots: None,
+            ignore_sitemap: None,
+            limit: None,
+            max_duration_seconds: None,
+            previous_crawl_id: None,
+            allow_subdomains: None,
+            allow_external_domains: None,
+            ignore_query_params: None,
+            ignore_all_query_params: None,
+            max_query_variants_per_path: None,
+            download_assets: None,
+            allowed_content_types: None,
+            excluded_content_types: None,
+            result_destination: None,
+            keep_local_results: None,
+            formats: None,
+            resolve: None,
         };
         let cloned = config.clone();
         assert_eq!(cloned.max_depth, 3);
@@ -461,6 +1335,24 @@ mod tests {
             proxy: None,
             headers: None,
             extraction_rules: None,
+            skip_nofollow_links: None,
+            ignore_robots: None,
+            ignore_sitemap: None,
+            limit: None,
+            max_duration_seconds: None,
+            previous_crawl_id: None,
+            allow_subdomains: None,
+            allow_external_domains: None,
+            ignore_query_params: None,
+            ignore_all_query_params: None,
+            max_query_variants_per_path: None,
+            download_assets: None,
+            allowed_content_types: None,
+            excluded_content_types: None,
+            result_destination: None,
+            keep_local_results: None,
+            formats: None,
+            resolve: None,
         };
         let json = serde_json::to_string(&config).unwrap();
         let deserialized: CrawlConfigDto = serde_json::from_str(&json).unwrap();
@@ -482,6 +1374,24 @@ mod tests {
             proxy: None,
             headers: None,
             extraction_rules: None,
+            skip_nofollow_links: None,
+            ignore_robots: None,
+            ignore_sitemap: None,
+            limit: None,
+            max_duration_seconds: None,
+            previous_crawl_id: None,
+            allow_subdomains: None,
+            allow_external_domains: None,
+            ignore_query_params: None,
+            ignore_all_query_params: None,
+            max_query_variants_per_path: None,
+            download_assets: None,
+            allowed_content_types: None,
+            excluded_content_types: None,
+            result_destination: None,
+            keep_local_results: None,
+            formats: None,
+            resolve: None,
         };
         let debug = format!("{:?}", config);
         assert!(debug.contains("CrawlConfigDto"));
@@ -507,9 +1417,29 @@ mod tests {
                 proxy: None,
                 headers: None,
                 extraction_rules: None,
+                skip_nofollow_links: None,
+                ignore_robots: None,
+                ignore_sitemap: None,
+                limit: None,
+                max_duration_seconds: None,
+                previous_crawl_id: None,
+                allow_subdomains: None,
+                allow_external_domains: None,
+                ignore_query_params: None,
+                ignore_all_query_params: None,
+                max_query_variants_per_path: None,
+                download_assets: None,
+                allowed_content_types: None,
+                excluded_content_types: None,
+                result_destination: None,
+                keep_local_results: None,
+                formats: None,
+                resolve: None,
             },
             sync_wait_ms: Some(5000),
             expires_at: None,
+            dry_run: None,
+            priority: None,
         };
         assert!(dto.validate().is_ok());
     }
@@ -530,9 +1460,29 @@ mod tests {
                 proxy: None,
                 headers: None,
                 extraction_rules: None,
+                skip_nofollow_links: None,
+                ignore_robots: None,
+                ignore_sitemap: None,
+                limit: None,
+                max_duration_seconds: None,
+                previous_crawl_id: None,
+                allow_subdomains: None,
+                allow_external_domains: None,
+                ignore_query_params: None,
+                ignore_all_query_params: None,
+                max_query_variants_per_path: None,
+                download_assets: None,
+                allowed_content_types: None,
+                excluded_content_types: None,
+                result_destination: None,
+                keep_local_results: None,
+                formats: None,
+                resolve: None,
             },
             sync_wait_ms: None,
             expires_at: None,
+            dry_run: None,
+            priority: None,
         };
         assert!(dto.validate().is_err());
     }
@@ -553,9 +1503,29 @@ mod tests {
                 proxy: None,
                 headers: None,
                 extraction_rules: None,
+                skip_nofollow_links: None,
+                ignore_robots: None,
+                ignore_sitemap: None,
+                limit: None,
+                max_duration_seconds: None,
+                previous_crawl_id: None,
+                allow_subdomains: None,
+                allow_external_domains: None,
+                ignore_query_params: None,
+                ignore_all_query_params: None,
+                max_query_variants_per_path: None,
+                download_assets: None,
+                allowed_content_types: None,
+                excluded_content_types: None,
+                result_destination: None,
+                keep_local_results: None,
+                formats: None,
+                resolve: None,
             },
             sync_wait_ms: Some(30001),
             expires_at: None,
+            dry_run: None,
+            priority: None,
         };
         assert!(dto.validate().is_err());
     }
@@ -576,9 +1546,29 @@ mod tests {
                 proxy: None,
                 headers: None,
                 extraction_rules: None,
+                skip_nofollow_links: None,
+                ignore_robots: None,
+                ignore_sitemap: None,
+                limit: None,
+                max_duration_seconds: None,
+                previous_crawl_id: None,
+                allow_subdomains: None,
+                allow_external_domains: None,
+                ignore_query_params: None,
+                ignore_all_query_params: None,
+                max_query_variants_per_path: None,
+                download_assets: None,
+                allowed_content_types: None,
+                excluded_content_types: None,
+                result_destination: None,
+                keep_local_results: None,
+                formats: None,
+                resolve: None,
             },
             sync_wait_ms: Some(0),
             expires_at: None,
+            dry_run: None,
+            priority: None,
         };
         assert!(dto.validate().is_ok());
     }
@@ -689,7 +1679,10 @@ mod tests {
 
     #[test]
     fn test_crawl_task_credits_cost_value() {
-        assert_eq!(CRAWL_TASK_CREDITS_COST, 10);
+        assert_eq!(
+            crate::common::constants::crawl_task::CRAWL_TASK_CREDITS_COST,
+            10
+        );
     }
 
     #[test]
@@ -821,6 +1814,7 @@ mod tests {
         let config: CrawlConfigDto = serde_json::from_str(json).unwrap();
         assert!(config.headers.is_some());
         assert_eq!(config.proxy.as_deref(), Some("socks5://proxy:1080"));
+        assert!(config.validate_proxy().is_ok());
     }
 
     // ========== CrawlUseCaseError Display trait ==========
@@ -968,9 +1962,29 @@ mod tests {
                 proxy: None,
                 headers: None,
                 extraction_rules: None,
+                skip_nofollow_links: None,
+                ignore_robots: None,
+                ignore_sitemap: None,
+                limit: None,
+                max_duration_seconds: None,
+                previous_crawl_id: None,
+                allow_subdomains: None,
+                allow_external_domains: None,
+                ignore_query_params: None,
+                ignore_all_query_params: None,
+                max_query_variants_per_path: None,
+                download_assets: None,
+                allowed_content_types: None,
+                excluded_content_types: None,
+                result_destination: None,
+                keep_local_results: None,
+                formats: None,
+                resolve: None,
             },
             sync_wait_ms: Some(5000),
             expires_at: None,
+            dry_run: None,
+            priority: None,
         };
         let json = serde_json::to_string(&dto).unwrap();
         // Note: validated_url has #[serde(skip)] so it won't appear in JSON
@@ -997,9 +2011,29 @@ mod tests {
                 proxy: None,
                 headers: None,
                 extraction_rules: None,
+                skip_nofollow_links: None,
+                ignore_robots: None,
+                ignore_sitemap: None,
+                limit: None,
+                max_duration_seconds: None,
+                previous_crawl_id: None,
+                allow_subdomains: None,
+                allow_external_domains: None,
+                ignore_query_params: None,
+                ignore_all_query_params: None,
+                max_query_variants_per_path: None,
+                download_assets: None,
+                allowed_content_types: None,
+                excluded_content_types: None,
+                result_destination: None,
+                keep_local_results: None,
+                formats: None,
+                resolve: None,
             },
             sync_wait_ms: None,
             expires_at: None,
+            dry_run: None,
+            priority: None,
         };
         let json = serde_json::to_string(&dto).unwrap();
         assert!(!json.contains("validated_url"));
@@ -1013,15 +2047,27 @@ mod tests {
     // these tests focus on handler-specific concerns.
 
     use crate::domain::auth::ApiKeyScope;
+    use crate::domain::auth::AuditLogEntry;
     use crate::domain::models::scrape_result::ScrapeResult;
-    use crate::domain::models::{Crawl, CrawlStatus, Task, TaskStatus, TaskType, Webhook};
+    use crate::domain::models::ResourceTags;
+    use crate::domain::models::TeamCapabilities;
+    use crate::domain::models::{
+        Crawl, CrawlLink, CrawlStatus, Task, TaskStatus, TaskType, Webhook,
+    };
+    use crate::domain::repositories::crawl_link_repository::CrawlLinkRepository;
     use crate::domain::repositories::crawl_repository::CrawlRepository;
+    use crate::domain::repositories::domain_throttle_repository::DomainThrottleRepository;
     use crate::domain::repositories::geo_restriction_repository::{
         GeoRestrictionRepository, GeoRestrictionRepositoryError,
     };
-    use crate::domain::repositories::scrape_result_repository::ScrapeResultRepository;
+    use crate::domain::repositories::resource_tag_repository::ResourceTagRepository;
+    use crate::domain::repositories::scrape_result_repository::{
+        ResultSearchHit, ScrapeResultRepository,
+    };
     use crate::domain::repositories::task_repository::{TaskQueryParams, TaskRepository};
+    use crate::domain::repositories::team_capability_repository::TeamCapabilityRepository;
     use crate::domain::repositories::webhook_repository::WebhookRepository;
+    use crate::domain::services::audit_service::{AuditServiceError, AuditServiceTrait};
     use crate::domain::services::geo_location::{GeoLocation, GeoLocationService};
     use crate::domain::services::rate_limiting_service::{
         BacklogService, ConcurrencyConfig, ConcurrencyControlService, ConcurrencyResult,
@@ -1029,6 +2075,9 @@ mod tests {
         RateLimitingService,
     };
     use crate::domain::services::team_service::{TeamGeoRestrictions, TeamService};
+    use crate::domain::services::url_blocklist_service::UrlBlocklistService;
+    use crate::queue::result_stream::{InMemoryResultStreamBus, ResultStreamBus};
+    use crate::utils::seo_audit::SeoIssue;
     use async_trait::async_trait;
     use std::collections::HashSet;
     use std::net::IpAddr;
@@ -1299,6 +2348,13 @@ mod tests {
                 find_should_fail: true,
             }
         }
+
+        fn with_results(results: Vec<ScrapeResult>) -> Self {
+            Self {
+                results,
+                find_should_fail: false,
+            }
+        }
     }
 
     #[async_trait]
@@ -1323,9 +2379,65 @@ mod tests {
                 .collect())
         }
 
+        async fn find_latest_for_crawl_url(
+            &self,
+            crawl_id: Uuid,
+            url: &str,
+        ) -> anyhow::Result<Option<ScrapeResult>> {
+            // 测试中用 task_id 表示结果所属的爬取
+            Ok(self
+                .results
+                .iter()
+                .find(|r| r.task_id == crawl_id && r.url == url)
+                .cloned())
+        }
+
+        async fn count_not_modified(&self, _task_ids: &[Uuid]) -> anyhow::Result<u64> {
+            Ok(0)
+        }
+
+        async fn response_times(&self, _task_ids: &[Uuid]) -> anyhow::Result<Vec<i64>> {
+            Ok(Vec::new())
+        }
+
         async fn get_team_avg_response_time(&self, _team_id: Uuid) -> anyhow::Result<f64> {
             Ok(0.0)
         }
+
+        async fn delete_for_team(
+            &self,
+            _team_id: Uuid,
+            _filter: &crate::domain::repositories::scrape_result_repository::ResultDeletionFilter,
+        ) -> anyhow::Result<Vec<ScrapeResult>> {
+            Ok(vec![])
+        }
+
+        /// Matches results whose content contains every query word
+        async fn search_crawl_results(
+            &self,
+            _crawl_id: Uuid,
+            query: &str,
+            limit: u64,
+        ) -> anyhow::Result<Vec<ResultSearchHit>> {
+            if self.find_should_fail {
+                return Err(anyhow::anyhow!("search_crawl_results failed"));
+            }
+            Ok(self
+                .results
+                .iter()
+                .filter(|r| {
+                    let content = r.content.to_lowercase();
+                    query
+                        .split_whitespace()
+                        .all(|word| content.contains(&word.to_lowercase()))
+                })
+                .take(limit as usize)
+                .map(|r| ResultSearchHit {
+                    result: r.clone(),
+                    rank: 1.0,
+                })
+                .collect())
+        }
     }
 
     // --- MockGeoRestrictionRepository ---
@@ -1611,9 +2723,29 @@ mod tests {
                 proxy: None,
                 headers: None,
                 extraction_rules: None,
+                skip_nofollow_links: None,
+                ignore_robots: None,
+                ignore_sitemap: None,
+                limit: None,
+                max_duration_seconds: None,
+                previous_crawl_id: None,
+                allow_subdomains: None,
+                allow_external_domains: None,
+                ignore_query_params: None,
+                ignore_all_query_params: None,
+                max_query_variants_per_path: None,
+                download_assets: None,
+                allowed_content_types: None,
+                excluded_content_types: None,
+                result_destination: None,
+                keep_local_results: None,
+                formats: None,
+                resolve: None,
             },
             sync_wait_ms,
             expires_at: None,
+            dry_run: None,
+            priority: None,
         }
     }
 
@@ -1674,7 +2806,172 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn test_create_crawl_success_sync_wait_empty_tasks() {
+    async fn test_create_crawl_blocked_url_forbidden() {
+        let state = build_handler_state(
+            MockCrawlRepository::new(),
+            MockTaskRepository::new(),
+            MockScrapeResultRepository::new(),
+            MockGeoRestrictionRepository::new(),
+            MockRateLimitingService::new_allowed(),
+        );
+        let state = Arc::new((*state).clone().with_url_blocklist(Arc::new(
+            UrlBlocklistService::new(&["example.com".to_string()]),
+        )));
+        let auth = make_auth_state();
+        let payload = make_crawl_request_dto("https://example.com", 2, Some(0), None);
+
+        let response = create_crawl(
+            Extension(state),
+            Extension(auth),
+            ConnectInfo(make_socket_addr()),
+            Json(payload),
+        )
+        .await
+        .into_response();
+
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+
+    /// Capability repository returning a fixed `allow_ignore_robots` flag.
+    struct FixedCapabilityRepo(bool);
+
+    #[async_trait]
+    impl TeamCapabilityRepository for FixedCapabilityRepo {
+        async fn get_capabilities(
+            &self,
+            _team_id: Uuid,
+        ) -> Result<TeamCapabilities, RepositoryError> {
+            Ok(TeamCapabilities {
+                allow_ignore_robots: self.0,
+            })
+        }
+
+        async fn update_capabilities(
+            &self,
+            _team_id: Uuid,
+            capabilities: &TeamCapabilities,
+        ) -> Result<TeamCapabilities, RepositoryError> {
+            Ok(*capabilities)
+        }
+    }
+
+    /// Audit service that records (action, allowed) for each decision.
+    #[derive(Default)]
+    struct RecordingAuditService {
+        decisions: Mutex<Vec<(String, bool)>>,
+    }
+
+    #[async_trait]
+    impl AuditServiceTrait for RecordingAuditService {
+        async fn log(&self, _entry: AuditLogEntry) -> Result<(), AuditServiceError> {
+            Ok(())
+        }
+
+        async fn log_allow(
+            &self,
+            action: String,
+            _api_key_id: Uuid,
+            _team_id: Uuid,
+            _scope: ApiKeyScope,
+        ) -> Result<(), AuditServiceError> {
+            self.decisions.lock().unwrap().push((action, true));
+            Ok(())
+        }
+
+        async fn log_deny(
+            &self,
+            action: String,
+            _api_key_id: Option<Uuid>,
+            _team_id: Option<Uuid>,
+            _reason: String,
+            _scope: Option<ApiKeyScope>,
+        ) -> Result<(), AuditServiceError> {
+            self.decisions.lock().unwrap().push((action, false));
+            Ok(())
+        }
+
+        async fn get_logs_for_key(
+            &self,
+            _api_key_id: Uuid,
+            _limit: u64,
+            _offset: u64,
+        ) -> Result<Vec<AuditLogEntry>, AuditServiceError> {
+            Ok(Vec::new())
+        }
+
+        async fn get_logs_for_team(
+            &self,
+            _team_id: Uuid,
+            _limit: u64,
+            _offset: u64,
+        ) -> Result<Vec<AuditLogEntry>, AuditServiceError> {
+            Ok(Vec::new())
+        }
+
+        async fn get_denied_requests(
+            &self,
+            _api_key_id: Uuid,
+            _limit: u64,
+        ) -> Result<Vec<AuditLogEntry>, AuditServiceError> {
+            Ok(Vec::new())
+        }
+    }
+
+    async fn create_crawl_ignoring_robots(
+        capability: Option<bool>,
+        audit: Arc<RecordingAuditService>,
+    ) -> StatusCode {
+        let state = build_handler_state(
+            MockCrawlRepository::new(),
+            MockTaskRepository::new(),
+            MockScrapeResultRepository::new(),
+            MockGeoRestrictionRepository::new(),
+            MockRateLimitingService::new_allowed(),
+        );
+        let mut state = (*state).clone().with_audit_service(audit);
+        if let Some(allowed) = capability {
+            state = state.with_team_capability_repo(Arc::new(FixedCapabilityRepo(allowed)));
+        }
+        let mut payload = make_crawl_request_dto("https://example.com", 2, Some(0), None);
+        payload.config.ignore_robots = Some(true);
+
+        create_crawl(
+            Extension(Arc::new(state)),
+            Extension(make_auth_state()),
+            ConnectInfo(make_socket_addr()),
+            Json(payload),
+        )
+        .await
+        .into_response()
+        .status()
+    }
+
+    #[tokio::test]
+    async fn test_create_crawl_ignore_robots_requires_capability() {
+        for capability in [None, Some(false)] {
+            let audit = Arc::new(RecordingAuditService::default());
+            let status = create_crawl_ignoring_robots(capability, audit.clone()).await;
+            assert_eq!(status, StatusCode::FORBIDDEN, "capability={:?}", capability);
+            assert_eq!(
+                *audit.decisions.lock().unwrap(),
+                vec![(IGNORE_ROBOTS_AUDIT_ACTION.to_string(), false)]
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_create_crawl_ignore_robots_with_capability_is_audited() {
+        let audit = Arc::new(RecordingAuditService::default());
+        let status = create_crawl_ignoring_robots(Some(true), audit.clone()).await;
+        assert_eq!(status, StatusCode::CREATED);
+        assert_eq!(
+            *audit.decisions.lock().unwrap(),
+            vec![(IGNORE_ROBOTS_AUDIT_ACTION.to_string(), true)]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_create_crawl_success_sync_wait_empty_tasks() {
         let state = build_handler_state(
             MockCrawlRepository::new(),
             MockTaskRepository::new(),
@@ -1828,6 +3125,35 @@ mod tests {
         assert_eq!(response.status(), StatusCode::BAD_REQUEST);
     }
 
+    #[tokio::test]
+    async fn test_create_crawl_private_resolve_override_returns_bad_request() {
+        let state = build_handler_state(
+            MockCrawlRepository::new(),
+            MockTaskRepository::new(),
+            MockScrapeResultRepository::new(),
+            MockGeoRestrictionRepository::new(),
+            MockRateLimitingService::new_allowed(),
+        );
+        let auth = make_auth_state();
+        // 覆盖地址指向内网，不能借此绕过 SSRF 防护
+        let mut payload = make_crawl_request_dto("https://staging.example.com", 2, Some(0), None);
+        payload.config.resolve = Some(HashMap::from([(
+            "staging.example.com".to_string(),
+            "169.254.169.254".to_string(),
+        )]));
+
+        let response = create_crawl(
+            Extension(state),
+            Extension(auth),
+            ConnectInfo(make_socket_addr()),
+            Json(payload),
+        )
+        .await
+        .into_response();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
     #[tokio::test]
     async fn test_create_crawl_rate_limited_returns_too_many_requests() {
         let state = build_handler_state(
@@ -1948,6 +3274,55 @@ mod tests {
         assert_eq!(response.status(), StatusCode::OK);
     }
 
+    #[tokio::test]
+    async fn test_get_crawl_reports_active_domain_throttle() {
+        struct ThrottledRepo;
+        #[async_trait]
+        impl DomainThrottleRepository for ThrottledRepo {
+            async fn find_by_domain(
+                &self,
+                domain: &str,
+            ) -> Result<Option<DomainThrottle>, RepositoryError> {
+                let mut throttle = DomainThrottle::new(domain);
+                throttle.record_error(429, Some(60), &ThrottlePolicy::default(), Utc::now());
+                Ok(Some(throttle))
+            }
+            async fn upsert(&self, _throttle: &DomainThrottle) -> Result<(), RepositoryError> {
+                Ok(())
+            }
+        }
+
+        let team_id = Uuid::new_v4();
+        let crawl = make_crawl(team_id, CrawlStatus::Processing);
+        let crawl_id = crawl.id;
+        let state = build_handler_state(
+            MockCrawlRepository::with_crawl(crawl),
+            MockTaskRepository::new(),
+            MockScrapeResultRepository::new(),
+            MockGeoRestrictionRepository::new(),
+            MockRateLimitingService::new_allowed(),
+        );
+        let state = Arc::new(
+            (*state)
+                .clone()
+                .with_domain_throttle_repo(Arc::new(ThrottledRepo)),
+        );
+        let auth = make_auth_state_with_team(team_id);
+
+        let response = get_crawl(Extension(state), Extension(auth), Path(crawl_id))
+            .await
+            .into_response();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["data"]["id"], crawl_id.to_string());
+        assert_eq!(json["data"]["throttle"]["domain"], "example.com");
+        assert_eq!(json["data"]["throttle"]["last_status"], 429);
+    }
+
     #[tokio::test]
     async fn test_get_crawl_not_found_returns_404() {
         let state = build_handler_state(
@@ -2022,9 +3397,14 @@ mod tests {
         );
         let auth = make_auth_state_with_team(team_id);
 
-        let response = get_crawl_results(Extension(state), Extension(auth), Path(crawl_id))
-            .await
-            .into_response();
+        let response = get_crawl_results(
+            Extension(state),
+            Extension(auth),
+            Path(crawl_id),
+            Query(CrawlResultsQuery::default()),
+        )
+        .await
+        .into_response();
 
         assert_eq!(response.status(), StatusCode::OK);
     }
@@ -2040,9 +3420,14 @@ mod tests {
         );
         let auth = make_auth_state();
 
-        let response = get_crawl_results(Extension(state), Extension(auth), Path(Uuid::new_v4()))
-            .await
-            .into_response();
+        let response = get_crawl_results(
+            Extension(state),
+            Extension(auth),
+            Path(Uuid::new_v4()),
+            Query(CrawlResultsQuery::default()),
+        )
+        .await
+        .into_response();
 
         assert_eq!(response.status(), StatusCode::NOT_FOUND);
     }
@@ -2062,11 +3447,663 @@ mod tests {
         );
         let auth = make_auth_state_with_team(team_id);
 
-        let response = get_crawl_results(Extension(state), Extension(auth), Path(crawl_id))
+        let response = get_crawl_results(
+            Extension(state),
+            Extension(auth),
+            Path(crawl_id),
+            Query(CrawlResultsQuery::default()),
+        )
+        .await
+        .into_response();
+
+        assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    }
+
+    fn follow_query() -> Query<CrawlResultsQuery> {
+        Query(CrawlResultsQuery {
+            follow: Some(true),
+            tags: None,
+        })
+    }
+
+    #[tokio::test]
+    async fn test_follow_crawl_results_without_bus_returns_503() {
+        let team_id = Uuid::new_v4();
+        let crawl = make_crawl(team_id, CrawlStatus::Processing);
+        let crawl_id = crawl.id;
+        let state = build_handler_state(
+            MockCrawlRepository::with_crawl(crawl),
+            MockTaskRepository::new(),
+            MockScrapeResultRepository::new(),
+            MockGeoRestrictionRepository::new(),
+            MockRateLimitingService::new_allowed(),
+        );
+
+        let response = get_crawl_results(
+            Extension(state),
+            Extension(make_auth_state_with_team(team_id)),
+            Path(crawl_id),
+            follow_query(),
+        )
+        .await;
+
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+    }
+
+    #[tokio::test]
+    async fn test_follow_finished_crawl_streams_saved_results_then_done() {
+        let team_id = Uuid::new_v4();
+        let crawl = make_crawl(team_id, CrawlStatus::Completed);
+        let crawl_id = crawl.id;
+        let task = make_task(crawl_id, team_id, TaskStatus::Completed);
+        let result = make_page_result(task.id, "<p>saved</p>");
+        let state = build_handler_state(
+            MockCrawlRepository::with_crawl(crawl),
+            MockTaskRepository::with_tasks(vec![task]),
+            MockScrapeResultRepository::with_results(vec![result.clone()]),
+            MockGeoRestrictionRepository::new(),
+            MockRateLimitingService::new_allowed(),
+        );
+        let state = Arc::new(Arc::try_unwrap(state).ok().unwrap().with_result_stream(
+            Arc::new(InMemoryResultStreamBus::new()),
+            std::time::Duration::from_millis(10),
+        ));
+
+        let response = get_crawl_results(
+            Extension(state),
+            Extension(make_auth_state_with_team(team_id)),
+            Path(crawl_id),
+            follow_query(),
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers()[header::CONTENT_TYPE],
+            "text/event-stream"
+        );
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body = String::from_utf8(body.to_vec()).unwrap();
+        let result_at = body.find("event: result").expect("result event");
+        let done_at = body.find("event: done").expect("done event");
+        assert!(result_at < done_at);
+        assert!(body.contains(&format!("id: {}", result.id)));
+        assert!(body.contains(r#"{"status":"completed"}"#));
+    }
+
+    #[tokio::test]
+    async fn test_follow_crawl_results_ends_on_finished_event() {
+        let team_id = Uuid::new_v4();
+        let crawl = make_crawl(team_id, CrawlStatus::Processing);
+        let crawl_id = crawl.id;
+        let bus = Arc::new(InMemoryResultStreamBus::new());
+        let state = build_handler_state(
+            MockCrawlRepository::with_crawl(crawl),
+            MockTaskRepository::new(),
+            MockScrapeResultRepository::new(),
+            MockGeoRestrictionRepository::new(),
+            MockRateLimitingService::new_allowed(),
+        );
+        let state = Arc::new(
+            Arc::try_unwrap(state)
+                .ok()
+                .unwrap()
+                .with_result_stream(bus.clone(), std::time::Duration::from_secs(60)),
+        );
+
+        let response = get_crawl_results(
+            Extension(state),
+            Extension(make_auth_state_with_team(team_id)),
+            Path(crawl_id),
+            follow_query(),
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::OK);
+
+        bus.publish(
+            crawl_id,
+            &CrawlStreamEvent::Finished {
+                status: CrawlStatus::Cancelled,
+            },
+        )
+        .await
+        .unwrap();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body = String::from_utf8(body.to_vec()).unwrap();
+        assert!(body.contains("event: done"));
+        assert!(body.contains(r#"{"status":"cancelled"}"#));
+    }
+
+    /// Serves a fixed set of result tags
+    struct StaticTagRepository {
+        tags: Vec<ResourceTags>,
+    }
+
+    #[async_trait]
+    impl ResourceTagRepository for StaticTagRepository {
+        async fn find(
+            &self,
+            resource_type: TaggedResource,
+            resource_id: Uuid,
+        ) -> Result<Option<ResourceTags>, RepositoryError> {
+            Ok(self
+                .tags
+                .iter()
+                .find(|t| t.resource_type == resource_type && t.resource_id == resource_id)
+                .cloned())
+        }
+        async fn find_many(
+            &self,
+            resource_type: TaggedResource,
+            resource_ids: &[Uuid],
+        ) -> Result<Vec<ResourceTags>, RepositoryError> {
+            Ok(self
+                .tags
+                .iter()
+                .filter(|t| {
+                    t.resource_type == resource_type && resource_ids.contains(&t.resource_id)
+                })
+                .cloned()
+                .collect())
+        }
+        async fn save(&self, tags: &ResourceTags) -> Result<ResourceTags, RepositoryError> {
+            Ok(tags.clone())
+        }
+        async fn delete(
+            &self,
+            _resource_type: TaggedResource,
+            _resource_id: Uuid,
+        ) -> Result<bool, RepositoryError> {
+            Ok(false)
+        }
+        async fn resource_team(
+            &self,
+            _resource_type: TaggedResource,
+            _resource_id: Uuid,
+        ) -> Result<Option<Uuid>, RepositoryError> {
+            Ok(None)
+        }
+    }
+
+    fn tagged(result_id: Uuid, team_id: Uuid, tags: &[&str]) -> ResourceTags {
+        let mut resource = ResourceTags::new(TaggedResource::Result, result_id, team_id);
+        let tags: Vec<String> = tags.iter().map(|t| t.to_string()).collect();
+        resource.add_tags(&tags).unwrap();
+        resource
+    }
+
+    fn tags_query(tags: &str) -> Query<CrawlResultsQuery> {
+        Query(CrawlResultsQuery {
+            follow: None,
+            tags: Some(tags.to_string()),
+        })
+    }
+
+    #[tokio::test]
+    async fn test_get_crawl_results_filters_by_tags() {
+        let team_id = Uuid::new_v4();
+        let crawl = make_crawl(team_id, CrawlStatus::Completed);
+        let crawl_id = crawl.id;
+        let task = make_task(crawl_id, team_id, TaskStatus::Completed);
+        let reviewed = make_page_result(task.id, "<p>reviewed</p>");
+        let broken = make_page_result(task.id, "<p>broken</p>");
+        let untagged = make_page_result(task.id, "<p>untagged</p>");
+        let state = build_handler_state(
+            MockCrawlRepository::with_crawl(crawl),
+            MockTaskRepository::with_tasks(vec![task]),
+            MockScrapeResultRepository::with_results(vec![
+                reviewed.clone(),
+                broken.clone(),
+                untagged,
+            ]),
+            MockGeoRestrictionRepository::new(),
+            MockRateLimitingService::new_allowed(),
+        );
+        let tag_repo = Arc::new(StaticTagRepository {
+            tags: vec![
+                tagged(reviewed.id, team_id, &["reviewed", "relevant"]),
+                tagged(broken.id, team_id, &["broken", "relevant"]),
+            ],
+        });
+        let state = Arc::new(
+            Arc::try_unwrap(state)
+                .ok()
+                .unwrap()
+                .with_resource_tag_repo(tag_repo),
+        );
+
+        let response = get_crawl_results(
+            Extension(state),
+            Extension(make_auth_state_with_team(team_id)),
+            Path(crawl_id),
+            tags_query("Relevant, reviewed"),
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        let results = json["data"].as_array().unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0]["id"], reviewed.id.to_string());
+    }
+
+    #[tokio::test]
+    async fn test_get_crawl_results_tag_filter_without_repo_returns_503() {
+        let state = build_handler_state(
+            MockCrawlRepository::new(),
+            MockTaskRepository::new(),
+            MockScrapeResultRepository::new(),
+            MockGeoRestrictionRepository::new(),
+            MockRateLimitingService::new_allowed(),
+        );
+
+        let response = get_crawl_results(
+            Extension(state),
+            Extension(make_auth_state()),
+            Path(Uuid::new_v4()),
+            tags_query("reviewed"),
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+    }
+
+    #[tokio::test]
+    async fn test_get_crawl_results_rejects_invalid_tags_and_follow() {
+        let state = build_handler_state(
+            MockCrawlRepository::new(),
+            MockTaskRepository::new(),
+            MockScrapeResultRepository::new(),
+            MockGeoRestrictionRepository::new(),
+            MockRateLimitingService::new_allowed(),
+        );
+        let state = Arc::new(
+            Arc::try_unwrap(state)
+                .ok()
+                .unwrap()
+                .with_resource_tag_repo(Arc::new(StaticTagRepository { tags: Vec::new() })),
+        );
+
+        let response = get_crawl_results(
+            Extension(state.clone()),
+            Extension(make_auth_state()),
+            Path(Uuid::new_v4()),
+            tags_query("needs review"),
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        let response = get_crawl_results(
+            Extension(state),
+            Extension(make_auth_state()),
+            Path(Uuid::new_v4()),
+            Query(CrawlResultsQuery {
+                follow: Some(true),
+                tags: Some("reviewed".to_string()),
+            }),
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_search_crawl_results_returns_ranked_snippets() {
+        let team_id = Uuid::new_v4();
+        let crawl = make_crawl(team_id, CrawlStatus::Completed);
+        let crawl_id = crawl.id;
+        let hit = make_page_result(crawl_id, "<h1>Plans</h1><p>The basic plan costs $10</p>");
+        let miss = make_page_result(crawl_id, "<p>About us</p>");
+        let state = build_handler_state(
+            MockCrawlRepository::with_crawl(crawl),
+            MockTaskRepository::new(),
+            MockScrapeResultRepository::with_results(vec![hit.clone(), miss]),
+            MockGeoRestrictionRepository::new(),
+            MockRateLimitingService::new_allowed(),
+        );
+
+        let response = search_crawl_results(
+            Extension(state),
+            Extension(make_auth_state_with_team(team_id)),
+            Path(crawl_id),
+            Query(ResultSearchQuery {
+                q: Some(" basic ".to_string()),
+                limit: None,
+                tags: None,
+            }),
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["data"]["query"], "basic");
+        let hits = json["data"]["hits"].as_array().unwrap();
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0]["result_id"], hit.id.to_string());
+        assert_eq!(hits[0]["snippet"], "Plans The <b>basic</b> plan costs $10");
+    }
+
+    #[tokio::test]
+    async fn test_search_crawl_results_requires_query() {
+        let team_id = Uuid::new_v4();
+        let crawl = make_crawl(team_id, CrawlStatus::Completed);
+        let crawl_id = crawl.id;
+        let state = build_handler_state(
+            MockCrawlRepository::with_crawl(crawl),
+            MockTaskRepository::new(),
+            MockScrapeResultRepository::new(),
+            MockGeoRestrictionRepository::new(),
+            MockRateLimitingService::new_allowed(),
+        );
+
+        let response = search_crawl_results(
+            Extension(state),
+            Extension(make_auth_state_with_team(team_id)),
+            Path(crawl_id),
+            Query(ResultSearchQuery::default()),
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    // ========== get_crawl_graph tests ==========
+
+    /// Returns the stored links of any crawl, up to the limit
+    struct StaticLinkRepository {
+        links: Vec<CrawlLink>,
+    }
+
+    #[async_trait]
+    impl CrawlLinkRepository for StaticLinkRepository {
+        async fn record(&self, _links: &[CrawlLink]) -> Result<u64, RepositoryError> {
+            Ok(0)
+        }
+
+        async fn find_by_crawl(
+            &self,
+            _crawl_id: Uuid,
+            limit: u64,
+        ) -> Result<Vec<CrawlLink>, RepositoryError> {
+            Ok(self.links.iter().take(limit as usize).cloned().collect())
+        }
+    }
+
+    fn graph_state(crawl: Crawl) -> Arc<CrawlHandlerState> {
+        let state = build_handler_state(
+            MockCrawlRepository::with_crawl(crawl.clone()),
+            MockTaskRepository::new(),
+            MockScrapeResultRepository::new(),
+            MockGeoRestrictionRepository::new(),
+            MockRateLimitingService::new_allowed(),
+        );
+        let links = vec![
+            CrawlLink::new(
+                crawl.id,
+                "https://example.com/",
+                "https://example.com/about",
+                Some("About".to_string()),
+                vec![],
+            ),
+            CrawlLink::new(
+                crawl.id,
+                "https://example.com/about",
+                "https://example.com/",
+                None,
+                vec!["nofollow".to_string()],
+            ),
+        ];
+        Arc::new(
+            (*state)
+                .clone()
+                .with_crawl_link_repo(Arc::new(StaticLinkRepository { links })),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_get_crawl_graph_json() {
+        let team_id = Uuid::new_v4();
+        let crawl = make_crawl(team_id, CrawlStatus::Completed);
+        let crawl_id = crawl.id;
+        let state = graph_state(crawl);
+
+        let response = get_crawl_graph(
+            Extension(state),
+            Extension(make_auth_state_with_team(team_id)),
+            Path(crawl_id),
+            Query(GraphQuery { format: None }),
+        )
+        .await
+        .into_response();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["data"]["nodes"].as_array().unwrap().len(), 2);
+        assert_eq!(json["data"]["edges"][0]["anchor_text"], "About");
+        assert_eq!(json["data"]["edges"][1]["rel"][0], "nofollow");
+        assert_eq!(json["data"]["truncated"], false);
+    }
+
+    #[tokio::test]
+    async fn test_get_crawl_graph_file_formats() {
+        let team_id = Uuid::new_v4();
+        let crawl = make_crawl(team_id, CrawlStatus::Completed);
+        let crawl_id = crawl.id;
+        let state = graph_state(crawl);
+
+        for (format, content_type, needle) in [
+            (
+                GraphFormat::Graphml,
+                "application/graphml+xml",
+                "<graph id=\"crawl\" edgedefault=\"directed\">",
+            ),
+            (
+                GraphFormat::Dot,
+                "text/vnd.graphviz",
+                "\"https://example.com/\" -> \"https://example.com/about\" [label=\"About\"];",
+            ),
+        ] {
+            let response = get_crawl_graph(
+                Extension(state.clone()),
+                Extension(make_auth_state_with_team(team_id)),
+                Path(crawl_id),
+                Query(GraphQuery {
+                    format: Some(format),
+                }),
+            )
             .await
             .into_response();
 
-        assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+            assert_eq!(response.status(), StatusCode::OK);
+            assert_eq!(response.headers()[header::CONTENT_TYPE], content_type);
+            assert!(response.headers()[header::CONTENT_DISPOSITION]
+                .to_str()
+                .unwrap()
+                .contains(&format!("crawl-{}.", crawl_id)));
+            assert!(response.headers().get(GRAPH_TRUNCATED_HEADER).is_none());
+            let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+                .await
+                .unwrap();
+            assert!(String::from_utf8(body.to_vec()).unwrap().contains(needle));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_crawl_graph_wrong_team_returns_404() {
+        let crawl = make_crawl(Uuid::new_v4(), CrawlStatus::Completed);
+        let crawl_id = crawl.id;
+        let state = graph_state(crawl);
+
+        let response = get_crawl_graph(
+            Extension(state),
+            Extension(make_auth_state_with_team(Uuid::new_v4())),
+            Path(crawl_id),
+            Query(GraphQuery { format: None }),
+        )
+        .await
+        .into_response();
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    // ========== get_crawl_seo_report tests ==========
+
+    #[tokio::test]
+    async fn test_get_crawl_seo_report_aggregates_pages() {
+        let team_id = Uuid::new_v4();
+        let crawl = make_crawl(team_id, CrawlStatus::Completed);
+        let crawl_id = crawl.id;
+        let task = make_task(crawl_id, team_id, TaskStatus::Completed);
+        let mut audited = make_page_result(task.id, "");
+        let stored = SeoAudit {
+            title: Some("Pricing".to_string()),
+            word_count: 20,
+            issues: vec![SeoIssue::TitleTooShort, SeoIssue::MissingH1],
+            ..Default::default()
+        };
+        audited.meta_data = serde_json::json!({ "seo": stored });
+        let mut unaudited = make_page_result(task.id, "<title>Pricing</title><h1>Plans</h1>");
+        unaudited.url = "https://example.com/plans".to_string();
+        let mut json_page = make_page_result(task.id, "{}");
+        json_page.content_type = "application/json".to_string();
+        let state = build_handler_state(
+            MockCrawlRepository::with_crawl(crawl),
+            MockTaskRepository::with_tasks(vec![task]),
+            MockScrapeResultRepository::with_results(vec![audited, unaudited, json_page]),
+            MockGeoRestrictionRepository::new(),
+            MockRateLimitingService::new_allowed(),
+        );
+
+        let response = get_crawl_seo_report(
+            Extension(state),
+            Extension(make_auth_state_with_team(team_id)),
+            Path(crawl_id),
+        )
+        .await
+        .into_response();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["data"]["pages"], 2);
+        assert_eq!(json["data"]["issue_counts"]["title_too_short"], 2);
+        assert_eq!(json["data"]["issue_counts"]["missing_h1"], 1);
+        assert_eq!(json["data"]["duplicate_titles"][0]["value"], "Pricing");
+    }
+
+    // ========== compare_crawl_pages tests ==========
+
+    fn make_page_result(crawl_id: Uuid, content: &str) -> ScrapeResult {
+        ScrapeResult {
+            id: Uuid::new_v4(),
+            task_id: crawl_id,
+            url: "https://example.com/pricing".to_string(),
+            status_code: 200,
+            content: content.to_string(),
+            content_type: "text/html".to_string(),
+            headers: serde_json::json!({}),
+            meta_data: serde_json::json!({}),
+            screenshot: None,
+            response_time_ms: 10,
+            created_at: Utc::now().naive_utc(),
+            etag: None,
+            last_modified: None,
+        }
+    }
+
+    fn compare_query(from: Uuid, to: Uuid, url: &str) -> Query<CompareQuery> {
+        Query(CompareQuery {
+            url: url.to_string(),
+            from,
+            to,
+            format: None,
+        })
+    }
+
+    #[tokio::test]
+    async fn test_compare_crawl_pages_returns_diff() {
+        let team_id = Uuid::new_v4();
+        let crawl = make_crawl(team_id, CrawlStatus::Completed);
+        let (from_id, to_id) = (crawl.id, Uuid::new_v4());
+        let before = make_page_result(from_id, "<p>Basic</p><p>$10</p>");
+        let after = make_page_result(to_id, "<p>Basic</p><p>$12</p>");
+        let state = build_handler_state(
+            MockCrawlRepository::with_crawl(crawl),
+            MockTaskRepository::new(),
+            MockScrapeResultRepository::with_results(vec![before.clone(), after.clone()]),
+            MockGeoRestrictionRepository::new(),
+            MockRateLimitingService::new_allowed(),
+        );
+
+        let response = compare_crawl_pages(
+            Extension(state),
+            Extension(make_auth_state_with_team(team_id)),
+            compare_query(from_id, to_id, &before.url),
+        )
+        .await
+        .into_response();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["data"]["changed"], true);
+        assert_eq!(json["data"]["format"], "text");
+        assert_eq!(json["data"]["added_lines"], 1);
+        assert_eq!(json["data"]["removed_lines"], 1);
+        assert_eq!(
+            json["data"]["diff"],
+            "@@ -1,2 +1,2 @@\n Basic\n-$10\n+$12\n"
+        );
+        assert_eq!(json["data"]["from"]["result_id"], before.id.to_string());
+        assert_eq!(json["data"]["to"]["crawl_id"], to_id.to_string());
+    }
+
+    #[tokio::test]
+    async fn test_compare_crawl_pages_missing_page_returns_404() {
+        let team_id = Uuid::new_v4();
+        let crawl = make_crawl(team_id, CrawlStatus::Completed);
+        let crawl_id = crawl.id;
+        let state = build_handler_state(
+            MockCrawlRepository::with_crawl(crawl),
+            MockTaskRepository::new(),
+            MockScrapeResultRepository::new(),
+            MockGeoRestrictionRepository::new(),
+            MockRateLimitingService::new_allowed(),
+        );
+
+        let response = compare_crawl_pages(
+            Extension(state),
+            Extension(make_auth_state_with_team(team_id)),
+            compare_query(crawl_id, crawl_id, "https://example.com/missing"),
+        )
+        .await
+        .into_response();
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[test]
+    fn test_comparable_content_extracts_text_from_html() {
+        let page = make_page_result(Uuid::new_v4(), "<h1>Plans</h1><p>Basic</p>");
+        assert_eq!(
+            comparable_content(&page, CompareFormat::Text),
+            "Plans\nBasic"
+        );
+        assert_eq!(comparable_content(&page, CompareFormat::Html), page.content);
     }
 
     // ========== cancel_crawl tests ==========
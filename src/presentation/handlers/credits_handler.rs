@@ -0,0 +1,419 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! 积分相关接口
+//!
+//! 余额、交易记录与按日汇总供团队自行对账；费用预估在提交请求前计算消耗；
+//! 余额不足提醒在扣费后余额跌破阈值时推送 `credits.low` 事件。
+
+use axum::{
+    extract::{Extension, Query},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::application::use_cases::estimate_credits::{estimate_credits, EstimateRequestDto};
+use crate::common::constants::server_config;
+use crate::domain::models::notification_contacts_model::is_valid_email;
+use crate::domain::models::{CreditsTransaction, CreditsTransactionType, DailyCreditsUsage};
+use crate::domain::repositories::credits_repository::{
+    CreditsRepository, CreditsTransactionFilter,
+};
+use crate::domain::services::pricing_service::PricingService;
+use crate::presentation::handlers::response_builder::{
+    errors, success_response, success_response_with_meta, PaginationMeta,
+};
+use crate::presentation::middleware::auth_middleware::AuthState;
+
+/// 交易记录查询参数
+#[derive(Debug, Default, Deserialize)]
+pub struct CreditsTransactionsQuery {
+    /// 起始时间（含），RFC 3339
+    pub from: Option<DateTime<Utc>>,
+    /// 结束时间（不含），RFC 3339
+    pub to: Option<DateTime<Utc>>,
+    /// 交易类型
+    #[serde(rename = "type")]
+    pub transaction_type: Option<String>,
+    /// 页码（从 1 开始）
+    pub page: Option<u32>,
+    /// 每页条数
+    pub per_page: Option<u32>,
+}
+
+impl CreditsTransactionsQuery {
+    /// 校验并转换为仓库过滤条件
+    fn filter(&self) -> Result<CreditsTransactionFilter, String> {
+        if let (Some(from), Some(to)) = (self.from, self.to) {
+            if from >= to {
+                return Err("from must be earlier than to".to_string());
+            }
+        }
+        let transaction_type = self
+            .transaction_type
+            .as_deref()
+            .map(str::parse::<CreditsTransactionType>)
+            .transpose()?;
+        Ok(CreditsTransactionFilter {
+            from: self.from,
+            to: self.to,
+            transaction_type,
+        })
+    }
+}
+
+/// 设置余额不足提醒的请求
+#[derive(Debug, Deserialize)]
+pub struct LowBalanceAlertRequestDto {
+    /// 余额低于该值时提醒，必须大于 0
+    pub threshold: i64,
+    /// 同时接收提醒邮件的邮箱（可选）
+    pub email: Option<String>,
+}
+
+impl LowBalanceAlertRequestDto {
+    /// 校验阈值与邮箱格式
+    fn validate(&self) -> Result<(), String> {
+        if self.threshold <= 0 {
+            return Err("threshold must be greater than 0".to_string());
+        }
+        if let Some(email) = &self.email {
+            if !is_valid_email(email) {
+                return Err(format!("Invalid email address: {}", email));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// 积分余额响应
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreditsBalanceResponseDto {
+    /// 团队ID
+    pub team_id: Uuid,
+    /// 当前余额
+    pub balance: i64,
+}
+
+/// 交易记录响应
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreditsTransactionsResponseDto {
+    /// 交易记录，按时间倒序
+    pub transactions: Vec<CreditsTransaction>,
+}
+
+/// 按日汇总响应
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DailyCreditsUsageResponseDto {
+    /// 每日汇总，按日期升序
+    pub days: Vec<DailyCreditsUsage>,
+    /// 范围内扣除的积分合计
+    pub total_used: i64,
+    /// 范围内增加的积分合计
+    pub total_added: i64,
+}
+
+/// 查询团队积分余额
+pub async fn get_balance(
+    Extension(credits_repo): Extension<Arc<dyn CreditsRepository>>,
+    Extension(auth_state): Extension<AuthState>,
+) -> impl IntoResponse {
+    match credits_repo.get_balance(auth_state.team_id).await {
+        Ok(balance) => success_response(
+            StatusCode::OK,
+            CreditsBalanceResponseDto {
+                team_id: auth_state.team_id,
+                balance,
+            },
+        ),
+        Err(e) => errors::internal_server_error(e.to_string()),
+    }
+}
+
+/// 分页查询团队积分交易记录，可按时间范围与类型过滤
+pub async fn list_transactions(
+    Extension(credits_repo): Extension<Arc<dyn CreditsRepository>>,
+    Extension(auth_state): Extension<AuthState>,
+    Query(query): Query<CreditsTransactionsQuery>,
+) -> impl IntoResponse {
+    let filter = match query.filter() {
+        Ok(filter) => filter,
+        Err(message) => return errors::bad_request(message),
+    };
+    let page = query.page.unwrap_or(1).max(1);
+    let per_page = query
+        .per_page
+        .unwrap_or(server_config::DEFAULT_PAGE_LIMIT)
+        .clamp(1, server_config::MAX_PAGE_LIMIT);
+    let offset = (page as u64 - 1) * per_page as u64;
+
+    match credits_repo
+        .query_transactions(auth_state.team_id, &filter, per_page as u64, offset)
+        .await
+    {
+        Ok((transactions, total)) => success_response_with_meta(
+            StatusCode::OK,
+            CreditsTransactionsResponseDto { transactions },
+            PaginationMeta::new(page, per_page, total),
+        ),
+        Err(e) => errors::internal_server_error(e.to_string()),
+    }
+}
+
+/// 按 UTC 日期汇总团队积分的扣除与增加，可按时间范围与类型过滤
+pub async fn get_daily_usage(
+    Extension(credits_repo): Extension<Arc<dyn CreditsRepository>>,
+    Extension(auth_state): Extension<AuthState>,
+    Query(query): Query<CreditsTransactionsQuery>,
+) -> impl IntoResponse {
+    let filter = match query.filter() {
+        Ok(filter) => filter,
+        Err(message) => return errors::bad_request(message),
+    };
+
+    match credits_repo.daily_usage(auth_state.team_id, &filter).await {
+        Ok(days) => {
+            let total_used = days.iter().map(|day| day.credits_used).sum();
+            let total_added = days.iter().map(|day| day.credits_added).sum();
+            success_response(
+                StatusCode::OK,
+                DailyCreditsUsageResponseDto {
+                    days,
+                    total_used,
+                    total_added,
+                },
+            )
+        }
+        Err(e) => errors::internal_server_error(e.to_string()),
+    }
+}
+
+/// 查询团队的余额不足提醒设置
+pub async fn get_low_balance_alert(
+    Extension(credits_repo): Extension<Arc<dyn CreditsRepository>>,
+    Extension(auth_state): Extension<AuthState>,
+) -> impl IntoResponse {
+    match credits_repo.get_low_balance_alert(auth_state.team_id).await {
+        Ok(Some(alert)) => success_response(StatusCode::OK, alert),
+        Ok(None) => errors::not_found("No low-balance alert configured"),
+        Err(e) => errors::internal_server_error(e.to_string()),
+    }
+}
+
+/// 设置团队的余额不足提醒；修改后提醒重新生效
+pub async fn set_low_balance_alert(
+    Extension(credits_repo): Extension<Arc<dyn CreditsRepository>>,
+    Extension(auth_state): Extension<AuthState>,
+    Json(payload): Json<LowBalanceAlertRequestDto>,
+) -> impl IntoResponse {
+    if let Err(message) = payload.validate() {
+        return errors::bad_request(message);
+    }
+
+    match credits_repo
+        .set_low_balance_alert(auth_state.team_id, payload.threshold, payload.email)
+        .await
+    {
+        Ok(alert) => success_response(StatusCode::OK, alert),
+        Err(e) => errors::internal_server_error(e.to_string()),
+    }
+}
+
+/// 删除团队的余额不足提醒
+pub async fn delete_low_balance_alert(
+    Extension(credits_repo): Extension<Arc<dyn CreditsRepository>>,
+    Extension(auth_state): Extension<AuthState>,
+) -> impl IntoResponse {
+    match credits_repo
+        .delete_low_balance_alert(auth_state.team_id)
+        .await
+    {
+        Ok(true) => StatusCode::NO_CONTENT.into_response(),
+        Ok(false) => errors::not_found("No low-balance alert configured"),
+        Err(e) => errors::internal_server_error(e.to_string()),
+    }
+}
+
+/// 预估抓取、爬取或提取请求将消耗的积分，不创建任务也不扣费
+///
+/// 按调用方团队生效的价格表计算。
+pub async fn estimate(
+    Extension(pricing): Extension<Arc<PricingService>>,
+    Extension(auth_state): Extension<AuthState>,
+    Json(payload): Json<EstimateRequestDto>,
+) -> impl IntoResponse {
+    let pricing = pricing.pricing_for(auth_state.team_id);
+    success_response(StatusCode::OK, estimate_credits(&payload, &pricing))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::test_helpers::create_test_db_pool;
+    use crate::domain::auth::ApiKeyScope;
+    use crate::domain::repositories::credits_repository::CreditsRepositoryError;
+    use async_trait::async_trait;
+
+    /// 只提供交易历史的内存仓库，查询与汇总走 trait 的默认实现
+    struct InMemoryCreditsRepository {
+        history: Vec<CreditsTransaction>,
+    }
+
+    #[async_trait]
+    impl CreditsRepository for InMemoryCreditsRepository {
+        async fn get_balance(&self, _team_id: Uuid) -> Result<i64, CreditsRepositoryError> {
+            Ok(self.history.iter().map(|t| t.amount).sum())
+        }
+        async fn deduct_credits(
+            &self,
+            _team_id: Uuid,
+            _amount: i64,
+            _transaction_type: CreditsTransactionType,
+            _description: String,
+            _reference_id: Option<Uuid>,
+        ) -> Result<(), CreditsRepositoryError> {
+            Ok(())
+        }
+        async fn add_credits(
+            &self,
+            _team_id: Uuid,
+            _amount: i64,
+            _transaction_type: CreditsTransactionType,
+            _description: String,
+            _reference_id: Option<Uuid>,
+        ) -> Result<i64, CreditsRepositoryError> {
+            Ok(0)
+        }
+        async fn get_transaction_history(
+            &self,
+            _team_id: Uuid,
+            _limit: Option<u32>,
+        ) -> Result<Vec<CreditsTransaction>, CreditsRepositoryError> {
+            Ok(self.history.clone())
+        }
+        async fn initialize_team_credits(
+            &self,
+            _team_id: Uuid,
+            _initial_balance: i64,
+        ) -> Result<i64, CreditsRepositoryError> {
+            Ok(0)
+        }
+    }
+
+    fn repo() -> Arc<dyn CreditsRepository> {
+        let transaction = |amount: i64, kind: CreditsTransactionType, created_at: &str| {
+            CreditsTransaction::with_timestamp(
+                Uuid::new_v4(),
+                Uuid::nil(),
+                amount,
+                kind,
+                "test".to_string(),
+                None,
+                created_at.parse().unwrap(),
+            )
+        };
+        Arc::new(InMemoryCreditsRepository {
+            history: vec![
+                transaction(-10, CreditsTransactionType::Crawl, "2025-03-03T10:00:00Z"),
+                transaction(-1, CreditsTransactionType::Scrape, "2025-03-02T10:00:00Z"),
+                transaction(-1, CreditsTransactionType::Scrape, "2025-03-01T10:00:00Z"),
+                transaction(
+                    100,
+                    CreditsTransactionType::Subscription,
+                    "2025-03-01T09:00:00Z",
+                ),
+            ],
+        })
+    }
+
+    fn auth_state() -> AuthState {
+        AuthState::new(
+            create_test_db_pool(),
+            Uuid::new_v4(),
+            Uuid::nil(),
+            ApiKeyScope::default(),
+        )
+    }
+
+    async fn body(response: axum::response::Response) -> serde_json::Value {
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        serde_json::from_slice(&bytes).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_list_transactions_filters_and_paginates() {
+        let query = CreditsTransactionsQuery {
+            transaction_type: Some("scrape".to_string()),
+            per_page: Some(1),
+            ..Default::default()
+        };
+        let response = list_transactions(Extension(repo()), Extension(auth_state()), Query(query))
+            .await
+            .into_response();
+        assert_eq!(response.status(), StatusCode::OK);
+        let json = body(response).await;
+        assert_eq!(json["data"]["transactions"].as_array().unwrap().len(), 1);
+        assert_eq!(json["meta"]["total_items"], 2);
+        assert_eq!(json["meta"]["has_next"], true);
+
+        let query = CreditsTransactionsQuery {
+            transaction_type: Some("bogus".to_string()),
+            ..Default::default()
+        };
+        let response = list_transactions(Extension(repo()), Extension(auth_state()), Query(query))
+            .await
+            .into_response();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[test]
+    fn test_low_balance_alert_request_validation() {
+        let request = |threshold: i64, email: Option<&str>| LowBalanceAlertRequestDto {
+            threshold,
+            email: email.map(str::to_string),
+        };
+        assert!(request(100, None).validate().is_ok());
+        assert!(request(100, Some("ops@example.com")).validate().is_ok());
+        assert!(request(0, None).validate().is_err());
+        assert!(request(100, Some("ops")).validate().is_err());
+        assert!(request(100, Some("ops@localhost")).validate().is_err());
+        assert!(request(100, Some("a b@example.com")).validate().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_daily_usage_aggregates_range() {
+        let query = CreditsTransactionsQuery {
+            from: Some("2025-03-01T00:00:00Z".parse().unwrap()),
+            to: Some("2025-03-03T00:00:00Z".parse().unwrap()),
+            ..Default::default()
+        };
+        let response = get_daily_usage(Extension(repo()), Extension(auth_state()), Query(query))
+            .await
+            .into_response();
+        assert_eq!(response.status(), StatusCode::OK);
+        let json = body(response).await;
+        assert_eq!(json["data"]["days"].as_array().unwrap().len(), 2);
+        assert_eq!(json["data"]["days"][0]["date"], "2025-03-01");
+        assert_eq!(json["data"]["total_used"], 2);
+        assert_eq!(json["data"]["total_added"], 100);
+
+        let query = CreditsTransactionsQuery {
+            from: Some("2025-03-03T00:00:00Z".parse().unwrap()),
+            to: Some("2025-03-01T00:00:00Z".parse().unwrap()),
+            ..Default::default()
+        };
+        let response = get_daily_usage(Extension(repo()), Extension(auth_state()), Query(query))
+            .await
+            .into_response();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+}
@@ -0,0 +1,129 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! 数据删除接口
+//!
+//! 按 URL 模式或爬取删除团队已保存的抓取结果、截图与存储对象，返回删除报告，用于处理
+//! 内容下架与个人数据删除请求。仅 Admin 角色可访问。
+
+use crate::domain::services::audit_service::AuditServiceTrait;
+use crate::domain::services::data_erasure_service::{DataErasureError, DataErasureService};
+use crate::presentation::extractors::role::RequireAdmin;
+use crate::presentation::handlers::response_builder::{errors, success_response};
+use crate::presentation::middleware::auth_middleware::AuthState;
+use axum::{
+    extract::{Extension, Path, Query},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use log::error;
+use serde::Deserialize;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// 数据删除查询参数
+#[derive(Debug, Deserialize)]
+pub struct EraseDataQuery {
+    /// 结果 URL 的匹配模式，`*` 匹配任意字符序列
+    pub url_pattern: Option<String>,
+}
+
+fn erasure_error_response(error: DataErasureError) -> Response {
+    match error {
+        DataErasureError::InvalidPattern(_) => errors::bad_request(error.to_string()),
+        DataErasureError::CrawlNotFound => errors::not_found(error.to_string()),
+        DataErasureError::Repository(_) | DataErasureError::ResultRepository(_) => {
+            error!("Data erasure failed: {}", error);
+            errors::internal_server_error("Failed to erase data")
+        }
+    }
+}
+
+/// 记录数据删除的审计日志
+async fn audit(
+    audit_service: Option<Extension<Arc<dyn AuditServiceTrait>>>,
+    action: &str,
+    auth_state: &AuthState,
+) {
+    if let Some(Extension(audit_service)) = audit_service {
+        if let Err(e) = audit_service
+            .log_allow(
+                action.to_string(),
+                auth_state.api_key_id,
+                auth_state.team_id,
+                auth_state.scope.clone(),
+            )
+            .await
+        {
+            error!("Failed to audit {}: {}", action, e);
+        }
+    }
+}
+
+/// 删除团队中 URL 匹配模式的全部结果
+pub async fn erase_data(
+    RequireAdmin(auth_state): RequireAdmin,
+    Extension(service): Extension<Arc<DataErasureService>>,
+    audit_service: Option<Extension<Arc<dyn AuditServiceTrait>>>,
+    Query(query): Query<EraseDataQuery>,
+) -> impl IntoResponse {
+    let Some(url_pattern) = query.url_pattern else {
+        return errors::bad_request("url_pattern is required");
+    };
+
+    match service
+        .erase_by_url_pattern(auth_state.team_id, &url_pattern)
+        .await
+    {
+        Ok(report) => {
+            audit(audit_service, "data.erase", &auth_state).await;
+            success_response(StatusCode::OK, report)
+        }
+        Err(e) => erasure_error_response(e),
+    }
+}
+
+/// 删除一次爬取的结果，可用 `url_pattern` 只删除其中 URL 匹配的结果
+pub async fn erase_crawl_data(
+    RequireAdmin(auth_state): RequireAdmin,
+    Extension(service): Extension<Arc<DataErasureService>>,
+    audit_service: Option<Extension<Arc<dyn AuditServiceTrait>>>,
+    Path(crawl_id): Path<Uuid>,
+    Query(query): Query<EraseDataQuery>,
+) -> impl IntoResponse {
+    match service
+        .erase_crawl(auth_state.team_id, crawl_id, query.url_pattern.as_deref())
+        .await
+    {
+        Ok(report) => {
+            audit(audit_service, "crawl.data.erase", &auth_state).await;
+            success_response(StatusCode::OK, report)
+        }
+        Err(e) => erasure_error_response(e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_erasure_error_status_codes() {
+        let cases = [
+            (
+                DataErasureError::InvalidPattern("empty".to_string()),
+                StatusCode::BAD_REQUEST,
+            ),
+            (DataErasureError::CrawlNotFound, StatusCode::NOT_FOUND),
+            (
+                DataErasureError::ResultRepository(anyhow::anyhow!("db down")),
+                StatusCode::INTERNAL_SERVER_ERROR,
+            ),
+        ];
+        for (error, status) in cases {
+            assert_eq!(erasure_error_response(error).status(), status);
+        }
+    }
+}
@@ -0,0 +1,730 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! 抓取回放调试接口
+//!
+//! 使用与原任务完全相同的参数，通过指定引擎重新执行一次抓取，并与原任务的执行记录并排对比
+//! （状态码、耗时、内容长度、引擎日志），用于排查“昨天为什么失败”一类问题。
+//!
+//! 回放结果不会保存。回放与 `/v1/scrape` 一样经过 URL 黑名单与每日下载上限检查，按抓取价格
+//! 扣除额度，下载量计入团队用量。回放直接调用引擎而不经过路由器，因此不会影响熔断器状态
+//! 与引擎统计。
+
+use crate::domain::models::{
+    CreditsTransactionType, ScrapeResult, Task, TaskEvent, TaskEventType, TaskStatus, TaskType,
+};
+use crate::domain::repositories::scrape_result_repository::ScrapeResultRepository;
+use crate::domain::repositories::task_event_repository::TaskEventRepository;
+use crate::domain::repositories::task_repository::TaskRepository;
+use crate::domain::services::bandwidth_service::BandwidthService;
+use crate::domain::services::pricing_service::PricingService;
+use crate::domain::services::rate_limiting_service::RateLimitingService;
+use crate::domain::services::url_blocklist_service::UrlBlocklistService;
+use crate::engines::engine_client::{
+    EngineError, InternalScrapeResponse, ScrapeRequest, ScraperEngine,
+};
+use crate::engines::router::EngineRouter;
+use crate::presentation::errors::CrawlRsError;
+use crate::presentation::handlers::response_builder::{errors, ApiResponse};
+use crate::presentation::helpers::blocklist_helper::check_url_blocklist;
+use crate::presentation::helpers::ssrf::{is_internal_proxy_url, validate_url};
+use crate::presentation::middleware::auth_middleware::AuthState;
+use crate::workers::scrape_worker::ScrapeWorker;
+use axum::{
+    extract::{Extension, Path},
+    response::{IntoResponse, Response},
+    Json,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use std::time::Instant;
+use uuid::Uuid;
+
+/// 回放请求
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ReplayRequestDto {
+    /// 回放使用的引擎名称（如 `reqwest`、`playwright`）
+    pub engine: String,
+}
+
+/// 一条执行日志
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReplayLogDto {
+    /// 事件类型（如 `engine_attempt`、`failed`）
+    pub event: String,
+    /// 事件详情（状态码、错误信息等）
+    pub message: Option<String>,
+    /// 该步骤耗时（毫秒）
+    pub duration_ms: Option<i64>,
+    /// 事件发生时间
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<TaskEvent> for ReplayLogDto {
+    fn from(event: TaskEvent) -> Self {
+        Self {
+            event: event.event_type.as_str().to_string(),
+            message: event.message,
+            duration_ms: event.duration_ms,
+            created_at: event.created_at,
+        }
+    }
+}
+
+/// 一次执行的摘要
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReplayRunDto {
+    /// 原任务：是否成功完成；回放：引擎是否返回了响应
+    pub success: bool,
+    /// HTTP 状态码
+    pub status_code: Option<u16>,
+    /// 抓取耗时（毫秒）
+    pub duration_ms: Option<i64>,
+    /// 内容长度（字节）
+    pub content_length: Option<usize>,
+    /// 失败原因
+    pub error: Option<String>,
+    /// 按时间顺序排列的引擎日志
+    pub logs: Vec<ReplayLogDto>,
+}
+
+impl ReplayRunDto {
+    /// 由原任务、其抓取结果与生命周期事件组装原始执行记录
+    fn from_task(task: &Task, result: Option<&ScrapeResult>, mut events: Vec<TaskEvent>) -> Self {
+        events.sort_by_key(|event| event.created_at);
+
+        // 没有保存结果时（如任务失败），以各次引擎尝试的耗时之和作为抓取耗时
+        let engine_time: i64 = events
+            .iter()
+            .filter(|event| event.event_type == TaskEventType::EngineAttempt)
+            .filter_map(|event| event.duration_ms)
+            .sum();
+        let error = (task.status == TaskStatus::Failed).then(|| {
+            task.payload
+                .get("error")
+                .and_then(|e| e.as_str())
+                .unwrap_or("Task failed")
+                .to_string()
+        });
+
+        Self {
+            success: task.status == TaskStatus::Completed,
+            status_code: result.map(|r| r.status_code as u16),
+            duration_ms: result
+                .map(|r| r.response_time_ms)
+                .or((engine_time > 0).then_some(engine_time)),
+            content_length: result.map(|r| r.content.len()),
+            error,
+            logs: events.into_iter().map(ReplayLogDto::from).collect(),
+        }
+    }
+
+    /// 由回放时的引擎响应组装回放记录
+    fn from_replay(
+        engine: &str,
+        started_at: DateTime<Utc>,
+        duration_ms: i64,
+        response: &Result<InternalScrapeResponse, EngineError>,
+    ) -> Self {
+        let message = match response {
+            Ok(response) => format!("{}: HTTP {}", engine, response.status_code),
+            Err(e) => format!("{}: {}", engine, e),
+        };
+        Self {
+            success: response.is_ok(),
+            status_code: response.as_ref().ok().map(|r| r.status_code),
+            duration_ms: Some(duration_ms),
+            content_length: response.as_ref().ok().map(|r| r.content.len()),
+            error: response.as_ref().err().map(|e| e.to_string()),
+            logs: vec![ReplayLogDto {
+                event: TaskEventType::EngineAttempt.as_str().to_string(),
+                message: Some(message),
+                duration_ms: Some(duration_ms),
+                created_at: started_at,
+            }],
+        }
+    }
+}
+
+/// 回放响应：原任务与回放的并排对比
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReplayResponseDto {
+    /// 被回放的任务 ID
+    pub task_id: Uuid,
+    /// 抓取的 URL
+    pub url: String,
+    /// 回放使用的引擎
+    pub engine: String,
+    /// 原任务的执行记录
+    pub original: ReplayRunDto,
+    /// 本次回放的执行记录
+    pub replay: ReplayRunDto,
+}
+
+/// 抓取回放处理器
+///
+/// 用原任务的参数通过指定引擎重新抓取，返回原任务与回放的对比，不保存结果。
+/// 只能回放当前团队的抓取任务。
+///
+/// 回放不经过任务队列，因此在调用引擎前执行与 `/v1/scrape` 相同的 URL 黑名单、
+/// 每日下载上限与额度检查。
+#[allow(clippy::too_many_arguments)]
+pub async fn replay_task(
+    Extension(auth_state): Extension<AuthState>,
+    Extension(task_repo): Extension<Arc<dyn TaskRepository>>,
+    Extension(result_repo): Extension<Arc<dyn ScrapeResultRepository>>,
+    Extension(task_event_repo): Extension<Arc<dyn TaskEventRepository>>,
+    Extension(router): Extension<Arc<EngineRouter>>,
+    Extension(url_blocklist): Extension<Arc<UrlBlocklistService>>,
+    Extension(bandwidth): Extension<Arc<BandwidthService>>,
+    Extension(rate_limiting_service): Extension<Arc<dyn RateLimitingService>>,
+    Extension(pricing): Extension<Arc<PricingService>>,
+    Path(task_id): Path<Uuid>,
+    Json(payload): Json<ReplayRequestDto>,
+) -> Response {
+    let prepared = match prepare_replay(
+        &auth_state,
+        task_repo.as_ref(),
+        result_repo.as_ref(),
+        task_event_repo.as_ref(),
+        &router,
+        task_id,
+        &payload.engine,
+    )
+    .await
+    {
+        Ok(prepared) => prepared,
+        Err(e) => return e.into_response(),
+    };
+    let PreparedReplay {
+        task,
+        engine,
+        request,
+        original,
+    } = prepared;
+    let team_id = task.team_id;
+
+    if let Err(response) = check_replay_allowed(
+        &url_blocklist,
+        &bandwidth,
+        rate_limiting_service.as_ref(),
+        &pricing,
+        &task,
+        &request.url,
+    )
+    .await
+    {
+        return response;
+    }
+
+    let (replay, bytes_downloaded) = run_replay(engine, &request).await;
+    if let Some(bytes) = bytes_downloaded {
+        // 回放不属于任何任务，单独的 ID 避免计入原任务的下载量
+        bandwidth.record(team_id, Uuid::new_v4(), bytes).await;
+    }
+    log::info!(
+        "Replayed task {} with engine {} (success: {})",
+        task.id,
+        payload.engine,
+        replay.success
+    );
+
+    Json(ApiResponse::success(ReplayResponseDto {
+        task_id: task.id,
+        url: task.url,
+        engine: payload.engine,
+        original,
+        replay,
+    }))
+    .into_response()
+}
+
+/// 调用引擎前执行与 `/v1/scrape` 相同的检查：URL 黑名单、每日下载上限，最后扣除抓取额度
+///
+/// # 返回值
+///
+/// * `Ok(())` - 允许回放，额度已扣除
+/// * `Err(Response)` - URL 被拦截返回 403，达到下载上限返回 429，额度不足返回 402
+async fn check_replay_allowed(
+    url_blocklist: &UrlBlocklistService,
+    bandwidth: &BandwidthService,
+    rate_limiting_service: &dyn RateLimitingService,
+    pricing: &PricingService,
+    task: &Task,
+    url: &str,
+) -> Result<(), Response> {
+    let team_id = task.team_id;
+    check_url_blocklist(url_blocklist, team_id, [url]).await?;
+    if let Some(cap) = bandwidth.cap_exceeded(team_id).await {
+        log::warn!(
+            "Replay rejected by daily bandwidth cap team_id={} task_id={} cap={}",
+            team_id,
+            task.id,
+            cap
+        );
+        return Err(errors::too_many_requests(format!(
+            "Daily bandwidth cap of {} bytes exceeded",
+            cap
+        )));
+    }
+    if let Err(e) = rate_limiting_service
+        .check_and_deduct_quota(
+            team_id,
+            pricing.pricing_for(team_id).scrape,
+            CreditsTransactionType::Scrape,
+            format!("Replay task: {}", task.id),
+            None,
+        )
+        .await
+    {
+        log::error!("Quota check failed for team {}: {}", team_id, e);
+        return Err(errors::payment_required(e.to_string()));
+    }
+    Ok(())
+}
+
+/// 通过校验、等待执行的回放
+struct PreparedReplay {
+    task: Task,
+    engine: Arc<dyn ScraperEngine>,
+    request: ScrapeRequest,
+    original: ReplayRunDto,
+}
+
+/// 加载待回放的任务与引擎，做 SSRF 校验并组装原任务的执行记录
+async fn prepare_replay(
+    auth_state: &AuthState,
+    task_repo: &dyn TaskRepository,
+    result_repo: &dyn ScrapeResultRepository,
+    task_event_repo: &dyn TaskEventRepository,
+    router: &EngineRouter,
+    task_id: Uuid,
+    engine_name: &str,
+) -> Result<PreparedReplay, CrawlRsError> {
+    let task = task_repo
+        .find_by_id(task_id)
+        .await?
+        .filter(|task| task.team_id == auth_state.team_id)
+        .ok_or_else(|| CrawlRsError::NotFound(format!("Task {} not found", task_id)))?;
+    if task.task_type != TaskType::Scrape {
+        return Err(CrawlRsError::Validation(
+            "Only scrape tasks can be replayed".to_string(),
+        ));
+    }
+
+    let engine = router
+        .get_engines()
+        .iter()
+        .find(|engine| engine.name() == engine_name)
+        .cloned()
+        .ok_or_else(|| {
+            CrawlRsError::Validation(format!(
+                "Unknown engine '{}', expected one of: {}",
+                engine_name,
+                router.registered_engines().join(", ")
+            ))
+        })?;
+
+    let request = ScrapeWorker::build_scrape_request(&task)
+        .map_err(|e| CrawlRsError::Validation(format!("Task payload cannot be replayed: {}", e)))?;
+
+    // 回放绕过了 EngineClient，需要在这里重新做 SSRF 校验
+    if let Err(e) = validate_url(&request.url).await {
+        return Err(CrawlRsError::Validation(format!(
+            "URL rejected by SSRF protection: {}",
+            e
+        )));
+    }
+    if request
+        .options
+        .proxy
+        .as_deref()
+        .is_some_and(is_internal_proxy_url)
+    {
+        return Err(CrawlRsError::Validation(
+            "Proxy rejected by SSRF protection".to_string(),
+        ));
+    }
+
+    let result = result_repo.find_by_task_id(task.id).await?;
+    let events = task_event_repo.find_by_task_id(task.id).await?;
+    let original = ReplayRunDto::from_task(&task, result.as_ref(), events);
+
+    Ok(PreparedReplay {
+        task,
+        engine,
+        request,
+        original,
+    })
+}
+
+/// 直接通过引擎执行一次抓取，超过请求超时时间视为超时
+///
+/// 同时返回下载的字节数，引擎未返回响应时为 `None`。
+async fn run_replay(
+    engine: Arc<dyn ScraperEngine>,
+    request: &ScrapeRequest,
+) -> (ReplayRunDto, Option<u64>) {
+    let internal = request.to_internal();
+    let started_at = Utc::now();
+    let started = Instant::now();
+    let response = tokio::time::timeout(internal.timeout, engine.scrape(&internal))
+        .await
+        .unwrap_or(Err(EngineError::Timeout(internal.timeout)));
+    let bytes_downloaded = response.as_ref().ok().map(|r| match &r.raw_content {
+        Some(raw) => raw.len() as u64,
+        None => r.content.len() as u64,
+    });
+    let run = ReplayRunDto::from_replay(
+        engine.name(),
+        started_at,
+        started.elapsed().as_millis() as i64,
+        &response,
+    );
+    (run, bytes_downloaded)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::DailyBandwidthUsage;
+    use crate::domain::repositories::bandwidth_usage_repository::BandwidthUsageRepository;
+    use crate::domain::repositories::task_repository::RepositoryError;
+    use crate::domain::services::bandwidth_service::InMemoryBandwidthCounter;
+    use crate::domain::services::rate_limiting_service::{
+        BacklogService, ConcurrencyConfig, ConcurrencyControlService, ConcurrencyResult,
+        QuotaService, RateLimitConfig, RateLimitResult, RateLimitService, RateLimitingError,
+    };
+    use crate::engines::engine_client::InternalScrapeRequest;
+    use async_trait::async_trait;
+    use axum::http::StatusCode;
+    use chrono::NaiveDate;
+    use std::collections::HashMap;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    struct FixedEngine {
+        status_code: Option<u16>,
+    }
+
+    #[async_trait]
+    impl ScraperEngine for FixedEngine {
+        async fn scrape(
+            &self,
+            _request: &InternalScrapeRequest,
+        ) -> Result<InternalScrapeResponse, EngineError> {
+            match self.status_code {
+                Some(status_code) => Ok(InternalScrapeResponse {
+                    status_code,
+                    content: "<html>ok</html>".to_string(),
+                    screenshot: None,
+                    content_type: "text/html".to_string(),
+                    headers: HashMap::new(),
+                    response_time_ms: 5,
+                    truncated: false,
+                    raw_content: None,
+                    http_version: None,
+                    tls_profile: None,
+                    har: None,
+                    mhtml: None,
+                    pdf: None,
+                    blocked_requests: None,
+                    evaluate_results: Vec::new(),
+                    frames: Vec::new(),
+                    accessibility: None,
+                    timings: None,
+                    ip_family: None,
+                    escalations: Vec::new(),
+                }),
+                None => Err(EngineError::RequestFailed("connection reset".to_string())),
+            }
+        }
+        fn support_score(&self, _request: &InternalScrapeRequest) -> u8 {
+            100
+        }
+        fn name(&self) -> &'static str {
+            "fixed"
+        }
+    }
+
+    #[test]
+    fn test_original_run_from_failed_task_events() {
+        let mut task = Task::new(
+            Uuid::new_v4(),
+            TaskType::Scrape,
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            "https://example.com".to_string(),
+            serde_json::json!({"error": "All engines failed"}),
+        );
+        task.status = TaskStatus::Failed;
+        let started = Utc::now();
+        let event = |event_type, offset_secs, duration_ms: Option<i64>, message: &str| {
+            let mut event = TaskEvent::new(task.id, event_type).with_message(message);
+            event.duration_ms = duration_ms;
+            event.created_at = started + chrono::Duration::seconds(offset_secs);
+            event
+        };
+        let events = vec![
+            event(TaskEventType::Failed, 2, None, "All engines failed"),
+            event(TaskEventType::EngineAttempt, 0, Some(1200), "timeout"),
+            event(TaskEventType::EngineAttempt, 1, Some(300), "HTTP 503"),
+        ];
+
+        let run = ReplayRunDto::from_task(&task, None, events);
+        assert!(!run.success);
+        assert_eq!(run.status_code, None);
+        assert_eq!(run.duration_ms, Some(1500));
+        assert_eq!(run.error.as_deref(), Some("All engines failed"));
+        let logs: Vec<_> = run.logs.iter().map(|log| log.event.as_str()).collect();
+        assert_eq!(logs, ["engine_attempt", "engine_attempt", "failed"]);
+    }
+
+    #[tokio::test]
+    async fn test_run_replay_reports_response_and_error() {
+        let request = ScrapeRequest::new("https://example.com");
+
+        let (run, bytes) = run_replay(
+            Arc::new(FixedEngine {
+                status_code: Some(200),
+            }),
+            &request,
+        )
+        .await;
+        assert_eq!(bytes, Some(15));
+        assert!(run.success);
+        assert_eq!(run.status_code, Some(200));
+        assert_eq!(run.content_length, Some(15));
+        assert_eq!(run.logs[0].message.as_deref(), Some("fixed: HTTP 200"));
+
+        let (run, bytes) = run_replay(Arc::new(FixedEngine { status_code: None }), &request).await;
+        assert_eq!(bytes, None);
+        assert!(!run.success);
+        assert_eq!(run.status_code, None);
+        assert!(run.error.unwrap().contains("connection reset"));
+    }
+
+    struct EmptyUsageRepository;
+
+    #[async_trait]
+    impl BandwidthUsageRepository for EmptyUsageRepository {
+        async fn upsert_daily(
+            &self,
+            _team_id: Uuid,
+            _usage: &DailyBandwidthUsage,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn list_daily(
+            &self,
+            _team_id: Uuid,
+            _from: NaiveDate,
+            _to: NaiveDate,
+        ) -> Result<Vec<DailyBandwidthUsage>, RepositoryError> {
+            Ok(Vec::new())
+        }
+    }
+
+    /// 记录扣费次数的限流服务，`quota_should_fail` 时模拟额度不足
+    #[derive(Default)]
+    struct CountingQuota {
+        quota_should_fail: bool,
+        charges: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl RateLimitService for CountingQuota {
+        async fn check_rate_limit(
+            &self,
+            _api_key: &str,
+            _endpoint: &str,
+        ) -> Result<RateLimitResult, RateLimitingError> {
+            Ok(RateLimitResult::Allowed)
+        }
+
+        async fn get_team_rate_limit_config(
+            &self,
+            _team_id: Uuid,
+        ) -> Result<RateLimitConfig, RateLimitingError> {
+            Ok(RateLimitConfig::default())
+        }
+
+        async fn update_team_rate_limit_config(
+            &self,
+            _team_id: Uuid,
+            _config: RateLimitConfig,
+        ) -> Result<(), RateLimitingError> {
+            Ok(())
+        }
+
+        async fn cleanup_expired_rate_limits(&self) -> Result<u64, RateLimitingError> {
+            Ok(0)
+        }
+    }
+
+    #[async_trait]
+    impl ConcurrencyControlService for CountingQuota {
+        async fn check_team_concurrency(
+            &self,
+            _team_id: Uuid,
+            _task_id: Uuid,
+        ) -> Result<ConcurrencyResult, RateLimitingError> {
+            Ok(ConcurrencyResult::Allowed)
+        }
+
+        async fn release_team_concurrency_slot(
+            &self,
+            _team_id: Uuid,
+            _task_id: Uuid,
+        ) -> Result<(), RateLimitingError> {
+            Ok(())
+        }
+
+        async fn get_team_current_concurrency(
+            &self,
+            _team_id: Uuid,
+        ) -> Result<u32, RateLimitingError> {
+            Ok(0)
+        }
+
+        async fn get_team_concurrency_config(
+            &self,
+            _team_id: Uuid,
+        ) -> Result<ConcurrencyConfig, RateLimitingError> {
+            Ok(ConcurrencyConfig::default())
+        }
+
+        async fn update_team_concurrency_config(
+            &self,
+            _team_id: Uuid,
+            _config: ConcurrencyConfig,
+        ) -> Result<(), RateLimitingError> {
+            Ok(())
+        }
+    }
+
+    #[async_trait]
+    impl BacklogService for CountingQuota {
+        async fn process_backlog_tasks(&self, _team_id: Uuid) -> Result<u32, RateLimitingError> {
+            Ok(0)
+        }
+    }
+
+    #[async_trait]
+    impl QuotaService for CountingQuota {
+        async fn check_and_deduct_quota(
+            &self,
+            _team_id: Uuid,
+            _amount: i64,
+            _transaction_type: CreditsTransactionType,
+            _description: String,
+            _reference_id: Option<Uuid>,
+        ) -> Result<(), RateLimitingError> {
+            if self.quota_should_fail {
+                return Err(RateLimitingError::CreditsError);
+            }
+            self.charges.fetch_add(1, Ordering::SeqCst);
+            Ok(())
+        }
+
+        async fn get_quota_balance(&self, _team_id: Uuid) -> Result<i64, RateLimitingError> {
+            Ok(1000)
+        }
+    }
+
+    impl RateLimitingService for CountingQuota {}
+
+    fn bandwidth() -> BandwidthService {
+        BandwidthService::new(
+            Arc::new(InMemoryBandwidthCounter::new(Duration::from_secs(60))),
+            Arc::new(EmptyUsageRepository),
+        )
+    }
+
+    fn scrape_task() -> Task {
+        Task::new(
+            Uuid::new_v4(),
+            TaskType::Scrape,
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            "https://blocked.com/page".to_string(),
+            serde_json::json!({}),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_replay_guard_rejects_blocked_url_without_charging() {
+        let task = scrape_task();
+        let quota = CountingQuota::default();
+        let blocklist = UrlBlocklistService::new(&["blocked.com".to_string()]);
+
+        let response = check_replay_allowed(
+            &blocklist,
+            &bandwidth(),
+            &quota,
+            &PricingService::default(),
+            &task,
+            &task.url,
+        )
+        .await
+        .unwrap_err();
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        assert_eq!(quota.charges.load(Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test]
+    async fn test_replay_guard_checks_bandwidth_cap_and_credits() {
+        let task = scrape_task();
+        let blocklist = UrlBlocklistService::default();
+
+        let capped = bandwidth().with_daily_cap(10);
+        capped.record(task.team_id, Uuid::new_v4(), 10).await;
+        let quota = CountingQuota::default();
+        let response = check_replay_allowed(
+            &blocklist,
+            &capped,
+            &quota,
+            &PricingService::default(),
+            &task,
+            &task.url,
+        )
+        .await
+        .unwrap_err();
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(quota.charges.load(Ordering::SeqCst), 0);
+
+        let broke = CountingQuota {
+            quota_should_fail: true,
+            ..Default::default()
+        };
+        let response = check_replay_allowed(
+            &blocklist,
+            &bandwidth(),
+            &broke,
+            &PricingService::default(),
+            &task,
+            &task.url,
+        )
+        .await
+        .unwrap_err();
+        assert_eq!(response.status(), StatusCode::PAYMENT_REQUIRED);
+
+        let quota = CountingQuota::default();
+        assert!(check_replay_allowed(
+            &blocklist,
+            &bandwidth(),
+            &quota,
+            &PricingService::default(),
+            &task,
+            &task.url,
+        )
+        .await
+        .is_ok());
+        assert_eq!(quota.charges.load(Ordering::SeqCst), 1);
+    }
+}
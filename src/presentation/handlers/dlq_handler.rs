@@ -0,0 +1,420 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! 死信队列管理接口
+//!
+//! 浏览重试耗尽的任务，并在故障恢复后按过滤条件或任务 ID 批量重新排队，
+//! 可跨团队操作，仅运营方可访问。
+
+use crate::common::constants::server_config;
+use crate::domain::repositories::dead_letter_repository::{
+    DeadLetterFilter, DeadLetterRepository, DeadLetterTask,
+};
+use crate::presentation::extractors::role::RequireOperator;
+use crate::presentation::handlers::response_builder::{
+    errors, success_response, success_response_with_meta, PaginationMeta,
+};
+use axum::{
+    extract::{Extension, Query},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// 单次请求最多重新排队的任务数
+pub const MAX_REQUEUE_PER_REQUEST: u64 = 1000;
+
+/// 死信列表查询参数
+#[derive(Debug, Default, Deserialize)]
+pub struct DeadLetterQuery {
+    /// 所属团队
+    pub team_id: Option<Uuid>,
+    /// 失败原因关键字
+    pub reason: Option<String>,
+    /// 页码（从 1 开始）
+    pub page: Option<u32>,
+    /// 每页条数
+    pub per_page: Option<u32>,
+}
+
+/// 死信列表响应
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeadLetterListResponseDto {
+    /// 死信任务
+    pub tasks: Vec<DeadLetterTask>,
+}
+
+/// 批量重新排队请求，`task_ids`、`team_id`、`reason` 至少提供一项
+#[derive(Debug, Default, Clone, Deserialize)]
+pub struct RequeueDeadLettersRequestDto {
+    /// 指定的任务 ID
+    pub task_ids: Option<Vec<Uuid>>,
+    /// 所属团队
+    pub team_id: Option<Uuid>,
+    /// 失败原因关键字
+    pub reason: Option<String>,
+    /// 最多重新排队的任务数，默认且最大为 1000
+    pub limit: Option<u64>,
+}
+
+/// 批量重新排队响应
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RequeueDeadLettersResponseDto {
+    /// 重新排队的任务数
+    pub requeued: usize,
+    /// 重新排队的任务 ID
+    pub task_ids: Vec<Uuid>,
+}
+
+/// 空白的原因关键字视为未设置
+fn normalize_reason(reason: Option<String>) -> Option<String> {
+    reason
+        .map(|reason| reason.trim().to_string())
+        .filter(|reason| !reason.is_empty())
+}
+
+/// 分页浏览死信，可按团队与失败原因过滤
+pub async fn list_dead_letters(
+    RequireOperator(_auth_state): RequireOperator,
+    Extension(repo): Extension<Arc<dyn DeadLetterRepository>>,
+    Query(query): Query<DeadLetterQuery>,
+) -> impl IntoResponse {
+    let page = query.page.unwrap_or(1).max(1);
+    let per_page = query
+        .per_page
+        .unwrap_or(server_config::DEFAULT_PAGE_LIMIT)
+        .clamp(1, server_config::MAX_PAGE_LIMIT);
+    let offset = (page as u64 - 1) * per_page as u64;
+    let filter = DeadLetterFilter {
+        team_id: query.team_id,
+        reason: normalize_reason(query.reason),
+        task_ids: None,
+    };
+
+    match repo.list(&filter, per_page as u64, offset).await {
+        Ok((tasks, total)) => success_response_with_meta(
+            StatusCode::OK,
+            DeadLetterListResponseDto { tasks },
+            PaginationMeta::new(page, per_page, total),
+        ),
+        Err(e) => errors::internal_server_error(e.to_string()),
+    }
+}
+
+/// 按过滤条件或任务 ID 批量重新排队死信
+pub async fn requeue_dead_letters(
+    RequireOperator(auth_state): RequireOperator,
+    Extension(repo): Extension<Arc<dyn DeadLetterRepository>>,
+    Json(payload): Json<RequeueDeadLettersRequestDto>,
+) -> impl IntoResponse {
+    let filter = DeadLetterFilter {
+        team_id: payload.team_id,
+        reason: normalize_reason(payload.reason),
+        task_ids: payload.task_ids,
+    };
+    // 不允许无条件地把所有死信重新排队
+    if filter.is_empty() {
+        return errors::bad_request("Provide task_ids, team_id or reason to select dead letters");
+    }
+    if let Some(task_ids) = &filter.task_ids {
+        if task_ids.is_empty() {
+            return errors::bad_request("task_ids must not be empty");
+        }
+        if task_ids.len() as u64 > MAX_REQUEUE_PER_REQUEST {
+            return errors::bad_request(format!(
+                "At most {} task_ids can be requeued per request",
+                MAX_REQUEUE_PER_REQUEST
+            ));
+        }
+    }
+    let limit = payload
+        .limit
+        .unwrap_or(MAX_REQUEUE_PER_REQUEST)
+        .clamp(1, MAX_REQUEUE_PER_REQUEST);
+
+    match repo.requeue(&filter, limit).await {
+        Ok(task_ids) => {
+            log::info!(
+                "{} dead-letter tasks requeued by API key {}",
+                task_ids.len(),
+                auth_state.api_key_id
+            );
+            success_response(
+                StatusCode::OK,
+                RequeueDeadLettersResponseDto {
+                    requeued: task_ids.len(),
+                    task_ids,
+                },
+            )
+        }
+        Err(e) => errors::internal_server_error(e.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::test_helpers::create_test_db_pool;
+    use crate::config::settings::Settings;
+    use crate::domain::auth::ApiKeyScope;
+    use crate::domain::models::TaskType;
+    use crate::domain::repositories::task_repository::RepositoryError;
+    use crate::presentation::extractors::role::OPERATOR_TOKEN_HEADER;
+    use crate::presentation::middleware::auth_middleware::AuthState;
+    use async_trait::async_trait;
+    use axum::body::Body;
+    use axum::http::Request;
+    use chrono::Utc;
+    use std::sync::Mutex;
+    use tower::ServiceExt;
+
+    const TEST_OPERATOR_TOKEN: &str = "test-operator-token";
+
+    /// Filters an in-memory list of dead letters and records requeue calls.
+    struct InMemoryDeadLetterRepository {
+        tasks: Vec<DeadLetterTask>,
+        requeued_with: Mutex<Vec<(DeadLetterFilter, u64)>>,
+    }
+
+    impl InMemoryDeadLetterRepository {
+        fn matching(&self, filter: &DeadLetterFilter) -> Vec<DeadLetterTask> {
+            self.tasks
+                .iter()
+                .filter(|task| filter.team_id.is_none_or(|team_id| task.team_id == team_id))
+                .filter(|task| {
+                    filter.reason.as_ref().is_none_or(|reason| {
+                        task.reason
+                            .as_ref()
+                            .is_some_and(|r| r.to_lowercase().contains(&reason.to_lowercase()))
+                    })
+                })
+                .filter(|task| {
+                    filter
+                        .task_ids
+                        .as_ref()
+                        .is_none_or(|ids| ids.contains(&task.task_id))
+                })
+                .cloned()
+                .collect()
+        }
+    }
+
+    #[async_trait]
+    impl DeadLetterRepository for InMemoryDeadLetterRepository {
+        async fn list(
+            &self,
+            filter: &DeadLetterFilter,
+            limit: u64,
+            offset: u64,
+        ) -> Result<(Vec<DeadLetterTask>, u64), RepositoryError> {
+            let matching = self.matching(filter);
+            let total = matching.len() as u64;
+            let page = matching
+                .into_iter()
+                .skip(offset as usize)
+                .take(limit as usize)
+                .collect();
+            Ok((page, total))
+        }
+
+        async fn requeue(
+            &self,
+            filter: &DeadLetterFilter,
+            limit: u64,
+        ) -> Result<Vec<Uuid>, RepositoryError> {
+            self.requeued_with
+                .lock()
+                .unwrap()
+                .push((filter.clone(), limit));
+            Ok(self
+                .matching(filter)
+                .into_iter()
+                .take(limit as usize)
+                .map(|task| task.task_id)
+                .collect())
+        }
+    }
+
+    fn dead_letter(team_id: Uuid, reason: &str) -> DeadLetterTask {
+        DeadLetterTask {
+            task_id: Uuid::new_v4(),
+            team_id,
+            task_type: TaskType::Scrape,
+            url: "https://example.com".to_string(),
+            crawl_id: None,
+            attempt_count: 4,
+            reason: Some(reason.to_string()),
+            failed_at: Utc::now(),
+        }
+    }
+
+    fn auth_state(scope: ApiKeyScope) -> AuthState {
+        AuthState::new(create_test_db_pool(), Uuid::new_v4(), Uuid::new_v4(), scope)
+    }
+
+    async fn body(response: axum::response::Response) -> serde_json::Value {
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        serde_json::from_slice(&bytes).unwrap()
+    }
+
+    fn repo(team_id: Uuid) -> Arc<InMemoryDeadLetterRepository> {
+        Arc::new(InMemoryDeadLetterRepository {
+            tasks: vec![
+                dead_letter(team_id, "Engine timeout after 30s"),
+                dead_letter(team_id, "HTTP 503 from target"),
+                dead_letter(Uuid::new_v4(), "Engine timeout after 30s"),
+            ],
+            requeued_with: Mutex::new(vec![]),
+        })
+    }
+
+    /// 挂载死信路由，并模拟鉴权中间件注入调用方的 AuthState
+    fn dlq_router(auth_state: AuthState, repo: Arc<dyn DeadLetterRepository>) -> axum::Router {
+        let mut settings = Settings::default();
+        settings.server.operator_token = Some(TEST_OPERATOR_TOKEN.to_string());
+        axum::Router::new()
+            .route("/admin/v1/dlq", axum::routing::get(list_dead_letters))
+            .route(
+                "/admin/v1/dlq/requeue",
+                axum::routing::post(requeue_dead_letters),
+            )
+            .layer(Extension(repo))
+            .layer(Extension(Arc::new(settings)))
+            .layer(Extension(auth_state))
+    }
+
+    fn dlq_request(method: &str, uri: &str, operator_token: Option<&str>) -> Request<Body> {
+        let mut builder = Request::builder()
+            .method(method)
+            .uri(uri)
+            .header("content-type", "application/json");
+        if let Some(token) = operator_token {
+            builder = builder.header(OPERATOR_TOKEN_HEADER, token);
+        }
+        let body = if method == "POST" {
+            Body::from(format!(r#"{{"team_id":"{}"}}"#, Uuid::new_v4()))
+        } else {
+            Body::empty()
+        };
+        builder.body(body).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_dlq_endpoints_require_operator() {
+        let repo: Arc<dyn DeadLetterRepository> = repo(Uuid::new_v4());
+        // 团队的 Admin Key 不能浏览或重新排队其他团队的死信
+        let admin = auth_state(ApiKeyScope::full_access());
+
+        for (method, uri) in [("GET", "/admin/v1/dlq"), ("POST", "/admin/v1/dlq/requeue")] {
+            let response = dlq_router(admin.clone(), repo.clone())
+                .oneshot(dlq_request(method, uri, None))
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+            let response = dlq_router(admin.clone(), repo.clone())
+                .oneshot(dlq_request(method, uri, Some(TEST_OPERATOR_TOKEN)))
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_list_dead_letters_filters_and_paginates() {
+        let team_id = Uuid::new_v4();
+        let repo: Arc<dyn DeadLetterRepository> = repo(team_id);
+        let query = DeadLetterQuery {
+            reason: Some("TIMEOUT".to_string()),
+            per_page: Some(1),
+            ..Default::default()
+        };
+        let response = list_dead_letters(
+            RequireOperator(auth_state(ApiKeyScope::full_access())),
+            Extension(repo.clone()),
+            Query(query),
+        )
+        .await
+        .into_response();
+        assert_eq!(response.status(), StatusCode::OK);
+        let json = body(response).await;
+        assert_eq!(json["data"]["tasks"].as_array().unwrap().len(), 1);
+        assert_eq!(json["meta"]["total_items"], 2);
+        assert_eq!(json["meta"]["has_next"], true);
+
+        let query = DeadLetterQuery {
+            team_id: Some(team_id),
+            ..Default::default()
+        };
+        let response = list_dead_letters(
+            RequireOperator(auth_state(ApiKeyScope::full_access())),
+            Extension(repo),
+            Query(query),
+        )
+        .await
+        .into_response();
+        let json = body(response).await;
+        assert_eq!(json["meta"]["total_items"], 2);
+    }
+
+    #[tokio::test]
+    async fn test_requeue_requires_a_selector() {
+        let repo = repo(Uuid::new_v4());
+        for payload in [
+            RequeueDeadLettersRequestDto::default(),
+            RequeueDeadLettersRequestDto {
+                reason: Some("  ".to_string()),
+                ..Default::default()
+            },
+            RequeueDeadLettersRequestDto {
+                task_ids: Some(vec![]),
+                ..Default::default()
+            },
+        ] {
+            let response = requeue_dead_letters(
+                RequireOperator(auth_state(ApiKeyScope::full_access())),
+                Extension(repo.clone() as Arc<dyn DeadLetterRepository>),
+                Json(payload),
+            )
+            .await
+            .into_response();
+            assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        }
+        assert!(repo.requeued_with.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_requeue_by_team_and_reason() {
+        let team_id = Uuid::new_v4();
+        let repo = repo(team_id);
+        let response = requeue_dead_letters(
+            RequireOperator(auth_state(ApiKeyScope::full_access())),
+            Extension(repo.clone() as Arc<dyn DeadLetterRepository>),
+            Json(RequeueDeadLettersRequestDto {
+                team_id: Some(team_id),
+                reason: Some("timeout".to_string()),
+                limit: Some(5000),
+                ..Default::default()
+            }),
+        )
+        .await
+        .into_response();
+        assert_eq!(response.status(), StatusCode::OK);
+        let json = body(response).await;
+        assert_eq!(json["data"]["requeued"], 1);
+        assert_eq!(
+            json["data"]["task_ids"][0],
+            repo.tasks[0].task_id.to_string()
+        );
+
+        let calls = repo.requeued_with.lock().unwrap();
+        assert_eq!(calls[0].1, MAX_REQUEUE_PER_REQUEST);
+    }
+}
@@ -0,0 +1,233 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! 引擎熔断器管理接口
+//!
+//! 查看各引擎熔断器的状态并手动重置，熔断器是所有团队共用的，仅运营方可访问。
+//! 熔断器状态保存在进程内存中，接口读取和重置的是处理该请求的进程的引擎路由器。
+
+use crate::engines::circuit_breaker::CircuitSnapshot;
+use crate::engines::router::EngineRouter;
+use crate::presentation::extractors::role::RequireOperator;
+use crate::presentation::handlers::response_builder::{errors, success_response};
+use axum::{
+    extract::{Extension, Path},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+/// 单个引擎的熔断器状态
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CircuitBreakerDto {
+    /// 引擎名称
+    pub engine: String,
+    /// 状态：`closed` / `open` / `half_open`
+    pub state: String,
+    /// 时间窗口内的失败次数
+    pub failure_count: u32,
+    /// 总请求数
+    pub total_requests: u64,
+    /// 总失败数
+    pub total_failures: u64,
+    /// 熔断次数
+    pub total_trips: u64,
+    /// 上次失败时间
+    pub last_failure_at: Option<DateTime<Utc>>,
+    /// 上次熔断时间
+    pub last_trip_at: Option<DateTime<Utc>>,
+}
+
+impl From<CircuitSnapshot> for CircuitBreakerDto {
+    fn from(snapshot: CircuitSnapshot) -> Self {
+        Self {
+            engine: snapshot.engine_name,
+            state: snapshot.status.as_str().to_string(),
+            failure_count: snapshot.stats.failure_count,
+            total_requests: snapshot.stats.total_requests,
+            total_failures: snapshot.stats.total_failures,
+            total_trips: snapshot.stats.total_trips,
+            last_failure_at: snapshot.last_failure_at,
+            last_trip_at: snapshot.last_trip_at,
+        }
+    }
+}
+
+/// 熔断器列表响应
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CircuitBreakersResponseDto {
+    /// 各引擎的熔断器状态
+    pub circuit_breakers: Vec<CircuitBreakerDto>,
+}
+
+/// 列出所有引擎的熔断器状态
+pub async fn list_circuit_breakers(
+    RequireOperator(_auth_state): RequireOperator,
+    Extension(router): Extension<Arc<EngineRouter>>,
+) -> impl IntoResponse {
+    let circuit_breakers = router
+        .circuit_breaker_states()
+        .into_iter()
+        .map(CircuitBreakerDto::from)
+        .collect();
+    success_response(
+        StatusCode::OK,
+        CircuitBreakersResponseDto { circuit_breakers },
+    )
+}
+
+/// 手动重置引擎的熔断器，返回重置后的状态
+pub async fn reset_circuit_breaker(
+    RequireOperator(auth_state): RequireOperator,
+    Extension(router): Extension<Arc<EngineRouter>>,
+    Path(engine): Path<String>,
+) -> impl IntoResponse {
+    if !router.reset_circuit_breaker(&engine) {
+        return errors::not_found(format!("Engine {} is not registered", engine));
+    }
+    log::info!(
+        "Circuit breaker for engine {} reset by API key {}",
+        engine,
+        auth_state.api_key_id
+    );
+    match router
+        .circuit_breaker_states()
+        .into_iter()
+        .find(|snapshot| snapshot.engine_name == engine)
+    {
+        Some(snapshot) => success_response(StatusCode::OK, CircuitBreakerDto::from(snapshot)),
+        None => errors::not_found(format!("Engine {} is not registered", engine)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::test_helpers::create_test_db_pool;
+    use crate::config::settings::Settings;
+    use crate::domain::auth::ApiKeyScope;
+    use crate::engines::engine_client::{
+        EngineError, InternalScrapeRequest, InternalScrapeResponse, ScraperEngine,
+    };
+    use crate::presentation::extractors::role::OPERATOR_TOKEN_HEADER;
+    use crate::presentation::middleware::auth_middleware::AuthState;
+    use async_trait::async_trait;
+    use axum::body::Body;
+    use axum::http::Request;
+    use tower::ServiceExt;
+    use uuid::Uuid;
+
+    const TEST_OPERATOR_TOKEN: &str = "test-operator-token";
+
+    struct NamedEngine;
+
+    #[async_trait]
+    impl ScraperEngine for NamedEngine {
+        async fn scrape(
+            &self,
+            _request: &InternalScrapeRequest,
+        ) -> Result<InternalScrapeResponse, EngineError> {
+            Err(EngineError::Other("unused".to_string()))
+        }
+        fn support_score(&self, _request: &InternalScrapeRequest) -> u8 {
+            100
+        }
+        fn name(&self) -> &'static str {
+            "playwright"
+        }
+    }
+
+    fn auth_state(scope: ApiKeyScope) -> AuthState {
+        AuthState::new(create_test_db_pool(), Uuid::new_v4(), Uuid::new_v4(), scope)
+    }
+
+    /// 挂载熔断器路由，并模拟鉴权中间件注入调用方的 AuthState
+    fn admin_router(auth_state: AuthState, router: Arc<EngineRouter>) -> axum::Router {
+        let mut settings = Settings::default();
+        settings.server.operator_token = Some(TEST_OPERATOR_TOKEN.to_string());
+        axum::Router::new()
+            .route(
+                "/admin/v1/engines/circuit-breakers",
+                axum::routing::get(list_circuit_breakers),
+            )
+            .route(
+                "/admin/v1/engines/circuit-breakers/{engine}/reset",
+                axum::routing::post(reset_circuit_breaker),
+            )
+            .layer(Extension(router))
+            .layer(Extension(Arc::new(settings)))
+            .layer(Extension(auth_state))
+    }
+
+    fn request(method: &str, uri: &str, operator_token: Option<&str>) -> Request<Body> {
+        let mut builder = Request::builder().method(method).uri(uri);
+        if let Some(token) = operator_token {
+            builder = builder.header(OPERATOR_TOKEN_HEADER, token);
+        }
+        builder.body(Body::empty()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_circuit_breaker_endpoints_require_operator() {
+        let router = Arc::new(EngineRouter::new(vec![Arc::new(NamedEngine)]));
+        // 团队的 Admin Key 不能查看或重置所有团队共用的熔断器
+        let admin = auth_state(ApiKeyScope::full_access());
+
+        for (method, uri) in [
+            ("GET", "/admin/v1/engines/circuit-breakers"),
+            (
+                "POST",
+                "/admin/v1/engines/circuit-breakers/playwright/reset",
+            ),
+        ] {
+            let response = admin_router(admin.clone(), router.clone())
+                .oneshot(request(method, uri, None))
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+            let response = admin_router(admin.clone(), router.clone())
+                .oneshot(request(method, uri, Some(TEST_OPERATOR_TOKEN)))
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_list_and_reset_circuit_breakers() {
+        let router = Arc::new(EngineRouter::new(vec![Arc::new(NamedEngine)]));
+        let operator = auth_state(ApiKeyScope::full_access());
+
+        let response =
+            list_circuit_breakers(RequireOperator(operator.clone()), Extension(router.clone()))
+                .await
+                .into_response();
+        assert_eq!(response.status(), StatusCode::OK);
+        let states = router.circuit_breaker_states();
+        assert_eq!(states.len(), 1);
+        assert_eq!(states[0].status.as_str(), "closed");
+
+        let response = reset_circuit_breaker(
+            RequireOperator(operator.clone()),
+            Extension(router.clone()),
+            Path("playwright".to_string()),
+        )
+        .await
+        .into_response();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let response = reset_circuit_breaker(
+            RequireOperator(operator),
+            Extension(router),
+            Path("missing".to_string()),
+        )
+        .await
+        .into_response();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+}
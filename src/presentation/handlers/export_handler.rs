@@ -0,0 +1,206 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! 团队数据导出接口
+//!
+//! 发起导出会创建一个 `export` 任务，由 worker 异步打包团队的爬取、结果、webhook 配置与
+//! 积分流水。归档就绪后通过 `export.completed` 事件推送下载链接，也可轮询导出状态获取。
+//! 仅 Admin 角色可访问。
+
+use crate::domain::models::{Task, TaskStatus, TaskType};
+use crate::domain::repositories::scrape_result_repository::ScrapeResultRepository;
+use crate::domain::repositories::task_repository::TaskRepository;
+use crate::domain::services::audit_service::AuditServiceTrait;
+use crate::domain::services::team_export_service::TeamExport;
+use crate::presentation::extractors::role::RequireAdmin;
+use crate::presentation::handlers::response_builder::{errors, success_response};
+use crate::queue::task_queue::TaskQueue;
+use axum::{
+    extract::{Extension, Path},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use chrono::{DateTime, Utc};
+use log::error;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// 导出状态响应
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportStatusResponse {
+    /// 导出 ID
+    pub export_id: Uuid,
+    /// 任务状态（queued / active / completed / failed / cancelled）
+    pub status: String,
+    /// 发起时间
+    pub created_at: DateTime<Utc>,
+    /// 完成时间
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub completed_at: Option<DateTime<Utc>>,
+    /// 已完成导出的归档信息
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub export: Option<TeamExport>,
+}
+
+impl ExportStatusResponse {
+    fn new(task: &Task, export: Option<TeamExport>) -> Self {
+        Self {
+            export_id: task.id,
+            status: task.status.to_string(),
+            created_at: task.created_at,
+            completed_at: task.completed_at,
+            export,
+        }
+    }
+}
+
+/// 导出任务的状态查询地址，同时作为任务 URL
+fn export_status_path(export_id: Uuid) -> String {
+    format!("/v1/exports/{}", export_id)
+}
+
+/// 发起团队数据导出
+pub async fn create_export(
+    RequireAdmin(auth_state): RequireAdmin,
+    Extension(queue): Extension<Arc<dyn TaskQueue>>,
+    audit_service: Option<Extension<Arc<dyn AuditServiceTrait>>>,
+) -> impl IntoResponse {
+    let now = Utc::now();
+    let export_id = Uuid::new_v4();
+    let task = Task {
+        id: export_id,
+        task_type: TaskType::Export,
+        status: TaskStatus::Queued,
+        priority: 0,
+        team_id: auth_state.team_id,
+        api_key_id: auth_state.api_key_id,
+        url: export_status_path(export_id),
+        payload: json!({}),
+        retry_count: 0,
+        attempt_count: 0,
+        max_retries: 0,
+        scheduled_at: None,
+        expires_at: None,
+        created_at: now,
+        started_at: None,
+        completed_at: None,
+        crawl_id: None,
+        updated_at: now,
+        lock_token: None,
+        lock_expires_at: None,
+    };
+
+    let task = match queue.enqueue(task).await {
+        Ok(task) => task,
+        Err(e) => {
+            error!(
+                "Failed to enqueue export for team {}: {}",
+                auth_state.team_id, e
+            );
+            return errors::internal_server_error("Failed to start export");
+        }
+    };
+
+    if let Some(Extension(audit_service)) = audit_service {
+        if let Err(e) = audit_service
+            .log_allow(
+                "team.export.create".to_string(),
+                auth_state.api_key_id,
+                auth_state.team_id,
+                auth_state.scope.clone(),
+            )
+            .await
+        {
+            error!("Failed to audit export creation: {}", e);
+        }
+    }
+
+    success_response(StatusCode::ACCEPTED, ExportStatusResponse::new(&task, None))
+}
+
+/// 查询导出状态，完成后返回下载链接
+pub async fn get_export(
+    RequireAdmin(auth_state): RequireAdmin,
+    Extension(task_repository): Extension<Arc<dyn TaskRepository>>,
+    Extension(result_repository): Extension<Arc<dyn ScrapeResultRepository>>,
+    Path(export_id): Path<Uuid>,
+) -> impl IntoResponse {
+    let task = match task_repository.find_by_id(export_id).await {
+        Ok(Some(task))
+            if task.team_id == auth_state.team_id && task.task_type == TaskType::Export =>
+        {
+            task
+        }
+        Ok(_) => return errors::not_found("Export not found"),
+        Err(e) => {
+            error!("Failed to load export {}: {}", export_id, e);
+            return errors::internal_server_error("Failed to load export");
+        }
+    };
+
+    let export = if task.status == TaskStatus::Completed {
+        match result_repository.find_by_task_id(task.id).await {
+            Ok(result) => result.and_then(|result| {
+                serde_json::from_value(result.meta_data.get("export")?.clone()).ok()
+            }),
+            Err(e) => {
+                error!("Failed to load result for export {}: {}", task.id, e);
+                return errors::internal_server_error("Failed to load export");
+            }
+        }
+    } else {
+        None
+    };
+
+    success_response(StatusCode::OK, ExportStatusResponse::new(&task, export))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::test_helpers::create_test_db_pool;
+    use crate::domain::auth::ApiKeyScope;
+    use crate::presentation::middleware::auth_middleware::AuthState;
+    use crate::presentation::sdk::mocks::MockTaskQueue;
+    use axum::body::to_bytes;
+
+    #[tokio::test]
+    async fn test_create_export_queues_export_task() {
+        let auth = AuthState::new(
+            create_test_db_pool(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            ApiKeyScope::full_access(),
+        );
+
+        let response = create_export(
+            RequireAdmin(auth),
+            Extension(Arc::new(MockTaskQueue) as Arc<dyn TaskQueue>),
+            None,
+        )
+        .await
+        .into_response();
+        assert_eq!(response.status(), StatusCode::ACCEPTED);
+
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(body["data"]["status"], "queued");
+        assert!(body["data"].get("export").is_none());
+        assert!(body["data"]["export_id"]
+            .as_str()
+            .is_some_and(|id| id.parse::<Uuid>().is_ok()));
+    }
+
+    #[test]
+    fn test_export_status_path() {
+        let export_id = Uuid::new_v4();
+        assert_eq!(
+            export_status_path(export_id),
+            format!("/v1/exports/{}", export_id)
+        );
+    }
+}
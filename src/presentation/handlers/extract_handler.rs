@@ -19,8 +19,10 @@ use crate::domain::models::{Task, TaskStatus, TaskType};
 use crate::domain::repositories::geo_restriction_repository::GeoRestrictionRepository;
 use crate::domain::repositories::task_repository::TaskRepository;
 use crate::domain::services::team_service::TeamService;
+use crate::domain::services::url_blocklist_service::UrlBlocklistService;
 use crate::presentation::handlers::response_builder::{error_response, ApiResponse};
 use crate::presentation::handlers::task_handler::wait_for_tasks_completion;
+use crate::presentation::helpers::blocklist_helper::check_url_blocklist;
 use crate::presentation::helpers::ssrf::validate_url;
 use crate::presentation::middleware::auth_middleware::AuthState;
 use crate::queue::task_queue::TaskQueue;
@@ -43,6 +45,7 @@ pub async fn extract<GR>(
     Extension(task_repository): Extension<Arc<dyn TaskRepository>>,
     Extension(geo_restriction_repo): Extension<Arc<GR>>,
     Extension(team_service): Extension<Arc<TeamService>>,
+    Extension(url_blocklist): Extension<Arc<UrlBlocklistService>>,
     Extension(auth_state): Extension<AuthState>,
     ConnectInfo(addr): ConnectInfo<SocketAddr>,
     Json(payload): Json<ExtractRequestDto>,
@@ -82,6 +85,17 @@ where
         }
     }
 
+    // URL 黑名单（全局 + 团队）
+    if let Err(response) = check_url_blocklist(
+        &url_blocklist,
+        team_id,
+        payload.urls.iter().map(String::as_str),
+    )
+    .await
+    {
+        return response;
+    }
+
     // 检查地理限制
     let client_ip = addr.ip().to_string();
 
@@ -386,6 +400,7 @@ mod tests {
                 use_llm: None,
                 llm_prompt: None,
                 output_format: None,
+                frame: None,
             },
         );
         let dto = ExtractRequestDto {
@@ -1066,6 +1081,7 @@ mod tests {
             Extension(task_repo),
             Extension(geo_repo),
             Extension(team_service),
+            Extension(Arc::new(UrlBlocklistService::default())),
             Extension(make_test_auth_state()),
             ConnectInfo(make_addr()),
             Json(payload),
@@ -1102,6 +1118,7 @@ mod tests {
             Extension(task_repo),
             Extension(geo_repo),
             Extension(team_service),
+            Extension(Arc::new(UrlBlocklistService::default())),
             Extension(make_test_auth_state()),
             ConnectInfo(make_addr()),
             Json(payload),
@@ -1128,6 +1145,7 @@ mod tests {
             Extension(task_repo),
             Extension(geo_repo),
             Extension(team_service),
+            Extension(Arc::new(UrlBlocklistService::default())),
             Extension(make_test_auth_state()),
             ConnectInfo(make_addr()),
             Json(make_valid_payload()),
@@ -1164,6 +1182,7 @@ mod tests {
             Extension(task_repo),
             Extension(geo_repo),
             Extension(team_service),
+            Extension(Arc::new(UrlBlocklistService::default())),
             Extension(make_test_auth_state()),
             ConnectInfo(make_addr()),
             Json(payload),
@@ -1201,6 +1220,7 @@ mod tests {
             Extension(task_repo),
             Extension(geo_repo),
             Extension(team_service),
+            Extension(Arc::new(UrlBlocklistService::default())),
             Extension(make_test_auth_state()),
             ConnectInfo(make_addr()),
             Json(make_valid_payload()),
@@ -1243,6 +1263,7 @@ mod tests {
             Extension(task_repo),
             Extension(geo_repo),
             Extension(team_service),
+            Extension(Arc::new(UrlBlocklistService::default())),
             Extension(make_test_auth_state()),
             ConnectInfo(make_addr()),
             Json(make_valid_payload()),
@@ -1273,6 +1294,7 @@ mod tests {
             Extension(task_repo),
             Extension(geo_repo),
             Extension(team_service),
+            Extension(Arc::new(UrlBlocklistService::default())),
             Extension(make_test_auth_state()),
             ConnectInfo(make_addr()),
             Json(payload),
@@ -1312,6 +1334,7 @@ mod tests {
             Extension(task_repo),
             Extension(geo_repo),
             Extension(team_service),
+            Extension(Arc::new(UrlBlocklistService::default())),
             Extension(make_test_auth_state()),
             ConnectInfo(make_addr()),
             Json(payload),
@@ -1342,6 +1365,7 @@ mod tests {
             Extension(task_repo),
             Extension(geo_repo),
             Extension(team_service),
+            Extension(Arc::new(UrlBlocklistService::default())),
             Extension(make_test_auth_state()),
             ConnectInfo(make_addr()),
             Json(make_valid_payload()),
@@ -1378,6 +1402,7 @@ mod tests {
             Extension(task_repo),
             Extension(geo_repo),
             Extension(team_service),
+            Extension(Arc::new(UrlBlocklistService::default())),
             Extension(make_test_auth_state()),
             ConnectInfo(make_addr()),
             Json(make_valid_payload()),
@@ -1416,6 +1441,7 @@ mod tests {
             Extension(task_repo),
             Extension(geo_repo),
             Extension(team_service),
+            Extension(Arc::new(UrlBlocklistService::default())),
             Extension(make_test_auth_state()),
             ConnectInfo(make_addr()),
             Json(make_valid_payload()),
@@ -1446,6 +1472,7 @@ mod tests {
             Extension(task_repo),
             Extension(geo_repo.clone()),
             Extension(team_service),
+            Extension(Arc::new(UrlBlocklistService::default())),
             Extension(make_test_auth_state()),
             ConnectInfo(make_addr()),
             Json(make_valid_payload()),
@@ -1484,6 +1511,7 @@ mod tests {
             Extension(task_repo),
             Extension(geo_repo.clone()),
             Extension(team_service),
+            Extension(Arc::new(UrlBlocklistService::default())),
             Extension(make_test_auth_state()),
             ConnectInfo(make_addr()),
             Json(make_valid_payload()),
@@ -1528,6 +1556,7 @@ mod tests {
             Extension(task_repo),
             Extension(geo_repo),
             Extension(team_service),
+            Extension(Arc::new(UrlBlocklistService::default())),
             Extension(make_test_auth_state()),
             ConnectInfo(make_addr()),
             Json(payload),
@@ -1594,6 +1623,7 @@ mod tests {
             Extension(task_repo),
             Extension(geo_repo),
             Extension(team_service),
+            Extension(Arc::new(UrlBlocklistService::default())),
             Extension(make_test_auth_state()),
             ConnectInfo(make_addr()),
             Json(payload),
@@ -1674,6 +1704,7 @@ mod tests {
             Extension(task_repo),
             Extension(geo_repo),
             Extension(team_service),
+            Extension(Arc::new(UrlBlocklistService::default())),
             Extension(make_test_auth_state()),
             ConnectInfo(make_addr()),
             Json(payload),
@@ -1702,6 +1733,7 @@ mod tests {
             Extension(task_repo),
             Extension(geo_repo),
             Extension(team_service),
+            Extension(Arc::new(UrlBlocklistService::default())),
             Extension(make_test_auth_state()),
             ConnectInfo(make_addr()),
             Json(make_valid_payload()),
@@ -1740,6 +1772,7 @@ mod tests {
             Extension(task_repo),
             Extension(geo_repo),
             Extension(team_service),
+            Extension(Arc::new(UrlBlocklistService::default())),
             Extension(make_test_auth_state()),
             ConnectInfo(make_addr()),
             Json(payload),
@@ -1778,6 +1811,7 @@ mod tests {
             Extension(task_repo),
             Extension(geo_repo),
             Extension(team_service),
+            Extension(Arc::new(UrlBlocklistService::default())),
             Extension(make_test_auth_state()),
             ConnectInfo(make_addr()),
             Json(make_valid_payload()),
@@ -1813,6 +1847,7 @@ mod tests {
             Extension(task_repo),
             Extension(geo_repo),
             Extension(team_service),
+            Extension(Arc::new(UrlBlocklistService::default())),
             Extension(make_test_auth_state()),
             ConnectInfo(make_addr()),
             Json(make_valid_payload()),
@@ -1853,6 +1888,7 @@ mod tests {
             Extension(task_repo),
             Extension(geo_repo),
             Extension(team_service),
+            Extension(Arc::new(UrlBlocklistService::default())),
             Extension(make_test_auth_state()),
             ConnectInfo(make_addr()),
             Json(payload),
@@ -1883,6 +1919,7 @@ mod tests {
             Extension(task_repo),
             Extension(geo_repo),
             Extension(team_service),
+            Extension(Arc::new(UrlBlocklistService::default())),
             Extension(make_test_auth_state()),
             ConnectInfo(make_addr()),
             Json(make_valid_payload()),
@@ -0,0 +1,262 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! RSS / Atom 订阅源接口
+//!
+//! 提交订阅源会创建一个 `feed` 任务，由 worker 抓取并解析条目（标题、链接、发布时间、内容）。
+//! 请求 `enqueue_items` 时，条目链接会作为抓取任务入队，每个条目按抓取价格扣费。
+//! 解析结果通过查询订阅源任务获取。
+
+use crate::application::dto::feed_request::FeedRequestDto;
+use crate::domain::models::{CreditsTransactionType, Task, TaskStatus, TaskType};
+use crate::domain::repositories::scrape_result_repository::ScrapeResultRepository;
+use crate::domain::repositories::task_repository::TaskRepository;
+use crate::domain::services::pricing_service::PricingService;
+use crate::domain::services::rate_limiting_service::RateLimitingService;
+use crate::domain::services::url_blocklist_service::UrlBlocklistService;
+use crate::presentation::handlers::response_builder::{errors, success_response};
+use crate::presentation::helpers::blocklist_helper::check_url_blocklist;
+use crate::presentation::helpers::rate_limit_helper::check_rate_limit;
+use crate::presentation::helpers::ssrf::validate_url;
+use crate::presentation::middleware::auth_middleware::AuthState;
+use crate::queue::task_queue::TaskQueue;
+use axum::{
+    extract::{Extension, Json, Path},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use chrono::{DateTime, Utc};
+use log::error;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::sync::Arc;
+use uuid::Uuid;
+use validator::Validate;
+
+/// 订阅源任务状态响应
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FeedStatusResponse {
+    /// 订阅源任务 ID
+    pub id: Uuid,
+    /// 订阅源地址
+    pub url: String,
+    /// 任务状态（queued / active / completed / failed / cancelled）
+    pub status: String,
+    /// 提交时间
+    pub created_at: DateTime<Utc>,
+    /// 完成时间
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub completed_at: Option<DateTime<Utc>>,
+    /// 解析出的订阅源（格式、标题、条目）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub feed: Option<Value>,
+    /// 由条目链接创建的抓取任务 ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enqueued_task_ids: Option<Vec<Uuid>>,
+}
+
+impl FeedStatusResponse {
+    fn new(task: &Task, meta_data: Option<&Value>) -> Self {
+        Self {
+            id: task.id,
+            url: task.url.clone(),
+            status: task.status.to_string(),
+            created_at: task.created_at,
+            completed_at: task.completed_at,
+            feed: meta_data.and_then(|meta| meta.get("feed")).cloned(),
+            enqueued_task_ids: meta_data
+                .and_then(|meta| meta.get("enqueued_task_ids"))
+                .and_then(|ids| serde_json::from_value(ids.clone()).ok()),
+        }
+    }
+}
+
+/// 校验订阅源请求参数
+fn validate_feed_request(payload: &FeedRequestDto) -> Result<(), String> {
+    payload.validate().map_err(|e| e.to_string())?;
+    if payload.item_formats.is_some() && !payload.enqueue_items {
+        return Err("item_formats requires enqueue_items".to_string());
+    }
+    Ok(())
+}
+
+/// 提交订阅源解析任务
+pub async fn create_feed(
+    Extension(queue): Extension<Arc<dyn TaskQueue>>,
+    Extension(rate_limiting_service): Extension<Arc<dyn RateLimitingService>>,
+    Extension(pricing): Extension<Arc<PricingService>>,
+    Extension(url_blocklist): Extension<Arc<UrlBlocklistService>>,
+    Extension(auth_state): Extension<AuthState>,
+    Json(payload): Json<FeedRequestDto>,
+) -> impl IntoResponse {
+    let team_id = auth_state.team_id;
+
+    if let Err(message) = validate_feed_request(&payload) {
+        return errors::unprocessable_entity(message);
+    }
+
+    if let Err(response) = check_rate_limit(
+        rate_limiting_service.as_ref(),
+        auth_state.api_key_id,
+        "/v1/feeds",
+    )
+    .await
+    {
+        return response;
+    }
+
+    if let Err(e) = validate_url(&payload.url).await {
+        log::warn!(
+            "SSRF attack attempt blocked url={} team_id={} api_key_id={} error={}",
+            payload.url,
+            team_id,
+            auth_state.api_key_id,
+            e
+        );
+        return errors::bad_request(format!("SSRF protection: {}", e));
+    }
+
+    if let Err(response) =
+        check_url_blocklist(&url_blocklist, team_id, [payload.url.as_str()]).await
+    {
+        return response;
+    }
+
+    if let Err(e) = rate_limiting_service
+        .check_and_deduct_quota(
+            team_id,
+            pricing.pricing_for(team_id).scrape,
+            CreditsTransactionType::Scrape,
+            format!("Feed URL: {}", payload.url),
+            None,
+        )
+        .await
+    {
+        error!("Quota check failed for team {}: {}", team_id, e);
+        return errors::payment_required(e.to_string());
+    }
+
+    let now = Utc::now();
+    let task = Task {
+        id: Uuid::new_v4(),
+        task_type: TaskType::Feed,
+        status: TaskStatus::Queued,
+        priority: 0,
+        team_id,
+        api_key_id: auth_state.api_key_id,
+        url: payload.url.clone(),
+        payload: serde_json::to_value(&payload).unwrap_or_default(),
+        retry_count: 0,
+        attempt_count: 0,
+        max_retries: 3,
+        scheduled_at: None,
+        expires_at: None,
+        created_at: now,
+        started_at: None,
+        completed_at: None,
+        crawl_id: None,
+        updated_at: now,
+        lock_token: None,
+        lock_expires_at: None,
+    };
+
+    match queue.enqueue(task).await {
+        Ok(task) => success_response(StatusCode::CREATED, FeedStatusResponse::new(&task, None)),
+        Err(e) => {
+            error!("Failed to enqueue feed for team {}: {}", team_id, e);
+            errors::internal_server_error(e.to_string())
+        }
+    }
+}
+
+/// 查询订阅源任务，完成后返回解析出的条目
+pub async fn get_feed(
+    Extension(task_repository): Extension<Arc<dyn TaskRepository>>,
+    Extension(result_repository): Extension<Arc<dyn ScrapeResultRepository>>,
+    Extension(auth_state): Extension<AuthState>,
+    Path(feed_id): Path<Uuid>,
+) -> impl IntoResponse {
+    let task = match task_repository.find_by_id(feed_id).await {
+        Ok(Some(task))
+            if task.team_id == auth_state.team_id && task.task_type == TaskType::Feed =>
+        {
+            task
+        }
+        Ok(_) => return errors::not_found("Feed not found"),
+        Err(e) => {
+            error!("Failed to load feed {}: {}", feed_id, e);
+            return errors::internal_server_error("Failed to load feed");
+        }
+    };
+
+    let result = if task.status == TaskStatus::Completed {
+        match result_repository.find_by_task_id(task.id).await {
+            Ok(result) => result,
+            Err(e) => {
+                error!("Failed to load result for feed {}: {}", task.id, e);
+                return errors::internal_server_error("Failed to load feed");
+            }
+        }
+    } else {
+        None
+    };
+
+    success_response(
+        StatusCode::OK,
+        FeedStatusResponse::new(&task, result.as_ref().map(|r| &r.meta_data)),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn feed_request(value: Value) -> FeedRequestDto {
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn test_validate_feed_request() {
+        assert!(validate_feed_request(&feed_request(
+            json!({"url": "https://example.com/feed.xml", "enqueue_items": true, "item_formats": ["markdown"]})
+        ))
+        .is_ok());
+        assert!(validate_feed_request(&feed_request(
+            json!({"url": "https://example.com/feed.xml", "max_items": 101})
+        ))
+        .is_err());
+        assert!(validate_feed_request(&feed_request(
+            json!({"url": "https://example.com/feed.xml", "item_formats": ["markdown"]})
+        ))
+        .is_err());
+    }
+
+    #[test]
+    fn test_feed_status_response_reads_result_meta_data() {
+        let mut task = Task::new(
+            Uuid::new_v4(),
+            TaskType::Feed,
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            "https://example.com/feed.xml".to_string(),
+            json!({}),
+        );
+        task.status = TaskStatus::Completed;
+        let item_task = Uuid::new_v4();
+        let meta_data = json!({
+            "feed": {"kind": "atom", "title": "News", "items": []},
+            "enqueued_task_ids": [item_task]
+        });
+
+        let response = FeedStatusResponse::new(&task, Some(&meta_data));
+        assert_eq!(response.status, "completed");
+        assert_eq!(response.feed.unwrap()["kind"], "atom");
+        assert_eq!(response.enqueued_task_ids, Some(vec![item_task]));
+
+        let pending = FeedStatusResponse::new(&task, None);
+        assert!(pending.feed.is_none() && pending.enqueued_task_ids.is_none());
+    }
+}
@@ -0,0 +1,290 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! 就绪检查（readiness probe）
+//!
+//! `/health` 只表示进程存活；`/health/ready` 逐个探测外部依赖，
+//! 任一关键依赖不可用时返回 503，供 Kubernetes readiness probe 摘除流量。
+//!
+//! 探测范围仅限当前部署实际使用的依赖：PostgreSQL（关键）以及已启用的
+//! 浏览器/反爬服务端点（FlareSolverr、Fire Engine CDP/TLS，非关键）。
+//! 缓存与限流均为进程内实现，抓取结果存于 PostgreSQL，因此没有需要探测的
+//! Redis 或对象存储。
+
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use axum::{http::StatusCode, response::IntoResponse, Extension, Json};
+use dbnexus::DbPool;
+use futures::future::join_all;
+use sea_orm::ConnectionTrait;
+use serde::{Deserialize, Serialize};
+
+use crate::common::constants::server_config::READINESS_CHECK_TIMEOUT_SECS;
+use crate::config::settings::Settings;
+
+/// 单个依赖的状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DependencyStatus {
+    /// 依赖可用
+    Up,
+    /// 依赖不可用或超时
+    Down,
+}
+
+/// 单个依赖的检查结果
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DependencyCheck {
+    /// 依赖名称
+    pub name: String,
+    /// 检查结果
+    pub status: DependencyStatus,
+    /// 是否为关键依赖（不可用时整体返回 503）
+    pub critical: bool,
+    /// 探测耗时（毫秒）
+    pub latency_ms: u64,
+    /// 失败原因
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// 就绪检查响应
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReadinessReport {
+    /// 整体状态：`ready` 或 `not_ready`
+    pub status: String,
+    /// 各依赖的检查结果
+    pub checks: Vec<DependencyCheck>,
+}
+
+impl ReadinessReport {
+    /// 汇总依赖检查结果，只有关键依赖影响整体状态
+    pub fn from_checks(checks: Vec<DependencyCheck>) -> Self {
+        let ready = checks
+            .iter()
+            .all(|check| !check.critical || check.status == DependencyStatus::Up);
+        Self {
+            status: if ready { "ready" } else { "not_ready" }.to_string(),
+            checks,
+        }
+    }
+
+    /// 是否就绪
+    pub fn is_ready(&self) -> bool {
+        self.status == "ready"
+    }
+}
+
+/// 通过 HTTP 探测的外部端点
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpDependency {
+    /// 依赖名称
+    pub name: String,
+    /// 探测 URL
+    pub url: String,
+    /// 是否为关键依赖
+    pub critical: bool,
+}
+
+/// 就绪检查所需的依赖句柄
+#[derive(Clone)]
+pub struct ReadinessState {
+    /// 数据库连接池
+    db_pool: Arc<DbPool>,
+    /// 探测用 HTTP 客户端
+    http_client: reqwest::Client,
+    /// 需要探测的 HTTP 端点
+    http_dependencies: Vec<HttpDependency>,
+    /// 单个依赖的探测超时
+    timeout: Duration,
+}
+
+impl ReadinessState {
+    /// 根据配置创建就绪检查状态，仅探测已启用的引擎端点
+    pub fn from_settings(db_pool: Arc<DbPool>, settings: &Settings) -> Self {
+        let engines = &settings.engines;
+        let http_dependencies = [
+            (
+                "flaresolverr",
+                engines.flaresolverr.enabled,
+                &engines.flaresolverr.url,
+            ),
+            (
+                "fire_engine_cdp",
+                engines.fire_cdp.enabled,
+                &engines.fire_cdp.url,
+            ),
+            (
+                "fire_engine_tls",
+                engines.fire_tls.enabled,
+                &engines.fire_tls.url,
+            ),
+        ]
+        .into_iter()
+        .filter(|(_, enabled, _)| *enabled)
+        .map(|(name, _, url)| HttpDependency {
+            name: name.to_string(),
+            url: url.clone(),
+            critical: false,
+        })
+        .collect();
+
+        Self {
+            db_pool,
+            http_client: reqwest::Client::new(),
+            http_dependencies,
+            timeout: Duration::from_secs(READINESS_CHECK_TIMEOUT_SECS),
+        }
+    }
+
+    /// 并发探测所有依赖
+    pub async fn check(&self) -> ReadinessReport {
+        let database = self.check_database();
+        let endpoints = join_all(
+            self.http_dependencies
+                .iter()
+                .map(|dependency| self.check_http(dependency)),
+        );
+        let (database, endpoints) = tokio::join!(database, endpoints);
+
+        let mut checks = vec![database];
+        checks.extend(endpoints);
+        ReadinessReport::from_checks(checks)
+    }
+
+    /// 执行 `SELECT 1` 确认数据库可用
+    async fn check_database(&self) -> DependencyCheck {
+        let started = Instant::now();
+        let result = tokio::time::timeout(self.timeout, async {
+            let session = self
+                .db_pool
+                .get_session("admin")
+                .await
+                .map_err(|e| e.to_string())?;
+            let conn = session.connection().map_err(|e| e.to_string())?;
+            conn.execute_unprepared("SELECT 1")
+                .await
+                .map(|_| ())
+                .map_err(|e| e.to_string())
+        })
+        .await;
+
+        Self::to_check("database", true, started, self.flatten(result))
+    }
+
+    /// 请求端点，收到任意 HTTP 响应即视为可达
+    async fn check_http(&self, dependency: &HttpDependency) -> DependencyCheck {
+        let started = Instant::now();
+        let result = tokio::time::timeout(self.timeout, async {
+            self.http_client
+                .get(&dependency.url)
+                .send()
+                .await
+                .map(|_| ())
+                .map_err(|e| e.to_string())
+        })
+        .await;
+
+        Self::to_check(
+            &dependency.name,
+            dependency.critical,
+            started,
+            self.flatten(result),
+        )
+    }
+
+    /// 将超时错误折叠为普通失败
+    fn flatten(
+        &self,
+        result: Result<Result<(), String>, tokio::time::error::Elapsed>,
+    ) -> Result<(), String> {
+        result.unwrap_or_else(|_| Err(format!("timed out after {:?}", self.timeout)))
+    }
+
+    fn to_check(
+        name: &str,
+        critical: bool,
+        started: Instant,
+        result: Result<(), String>,
+    ) -> DependencyCheck {
+        let latency_ms = started.elapsed().as_millis() as u64;
+        match result {
+            Ok(()) => DependencyCheck {
+                name: name.to_string(),
+                status: DependencyStatus::Up,
+                critical,
+                latency_ms,
+                error: None,
+            },
+            Err(error) => {
+                log::warn!("Readiness check for {} failed: {}", name, error);
+                DependencyCheck {
+                    name: name.to_string(),
+                    status: DependencyStatus::Down,
+                    critical,
+                    latency_ms,
+                    error: Some(error),
+                }
+            }
+        }
+    }
+}
+
+/// 就绪检查端点（readiness probe）
+///
+/// 全部关键依赖可用时返回 200，否则返回 503；响应体包含每个依赖的状态。
+pub async fn ready_check(Extension(state): Extension<Arc<ReadinessState>>) -> impl IntoResponse {
+    let report = state.check().await;
+    let status = if report.is_ready() {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+    (status, Json(report))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(name: &str, status: DependencyStatus, critical: bool) -> DependencyCheck {
+        DependencyCheck {
+            name: name.to_string(),
+            status,
+            critical,
+            latency_ms: 1,
+            error: None,
+        }
+    }
+
+    #[test]
+    fn test_report_ready_when_critical_dependencies_are_up() {
+        let report = ReadinessReport::from_checks(vec![
+            check("database", DependencyStatus::Up, true),
+            check("flaresolverr", DependencyStatus::Down, false),
+        ]);
+        assert!(report.is_ready());
+        assert_eq!(report.status, "ready");
+    }
+
+    #[test]
+    fn test_report_not_ready_when_critical_dependency_is_down() {
+        let report = ReadinessReport::from_checks(vec![
+            check("database", DependencyStatus::Down, true),
+            check("flaresolverr", DependencyStatus::Up, false),
+        ]);
+        assert!(!report.is_ready());
+        assert_eq!(report.status, "not_ready");
+    }
+
+    #[test]
+    fn test_dependency_check_serialization() {
+        let value = serde_json::to_value(check("database", DependencyStatus::Up, true)).unwrap();
+        assert_eq!(value["status"], "up");
+        assert_eq!(value["critical"], true);
+        assert!(value.get("error").is_none());
+    }
+}
@@ -7,15 +7,35 @@
 ///
 /// 包含各个API端点的具体处理逻辑
 /// 每个处理器负责处理特定类型的HTTP请求并返回响应
+pub mod api_key_handler;
+pub mod asset_handler;
 pub mod audit_handler;
+pub mod blocklist_handler;
+pub mod config_admin_handler;
 pub mod crawl_handler;
+pub mod credits_handler;
+pub mod data_handler;
+pub mod debug_handler;
+pub mod dlq_handler;
+pub mod engine_admin_handler;
+pub mod export_handler;
 pub mod extract_handler;
+pub mod feed_handler;
+pub mod health_handler;
 pub mod metrics_handler;
+pub mod monitor_handler;
+pub mod notification_handler;
 pub mod response_builder;
+pub mod robots_admin_handler;
 pub mod scrape_handler;
 pub mod search_handler;
+pub mod sitemap_handler;
+pub mod sso_handler;
+pub mod tag_handler;
 pub mod task_handler;
 pub mod team_handler;
+pub mod team_member_handler;
+pub mod usage_handler;
 pub mod webhook_handler;
 
 use crate::domain::models::Task;
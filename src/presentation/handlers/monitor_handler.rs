@@ -0,0 +1,465 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! 页面变更监控接口
+//!
+//! 监控按固定间隔重新抓取 URL，与上一次快照比较（页面文本哈希或 CSS 选择器提取结果），
+//! 发生变化时推送附带差异的 `monitor.changed` webhook 事件。监控归属于调用方团队。
+
+use crate::domain::models::{Monitor, MonitorMode};
+use crate::domain::repositories::monitor_repository::MonitorRepository;
+use crate::domain::services::extraction_service::ExtractionRule;
+use crate::domain::services::url_blocklist_service::UrlBlocklistService;
+use crate::presentation::handlers::response_builder::{errors, success_response};
+use crate::presentation::helpers::blocklist_helper::check_url_blocklist;
+use crate::presentation::helpers::ssrf::validate_url;
+use crate::presentation::middleware::auth_middleware::AuthState;
+use axum::{
+    extract::{Extension, Path},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use log::error;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// 每个团队最多创建的监控数
+const MAX_MONITORS_PER_TEAM: usize = 100;
+
+/// 创建监控请求
+#[derive(Debug, Deserialize)]
+pub struct CreateMonitorRequest {
+    /// 监控的 URL
+    pub url: String,
+    /// 检查间隔（秒）
+    pub interval_seconds: i64,
+    /// 比较方式，默认 `hash`
+    pub mode: Option<MonitorMode>,
+    /// `extract` 模式的 CSS 选择器提取规则
+    pub extraction_rules: Option<HashMap<String, ExtractionRule>>,
+}
+
+/// 更新监控请求，未提供的字段保持不变
+#[derive(Debug, Deserialize)]
+pub struct UpdateMonitorRequest {
+    /// 检查间隔（秒）
+    pub interval_seconds: Option<i64>,
+    /// 暂停（false）或恢复（true）监控
+    pub active: Option<bool>,
+}
+
+/// 监控列表响应数据传输对象
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MonitorListResponseDto {
+    /// 监控列表
+    pub monitors: Vec<Monitor>,
+}
+
+/// 校验提取规则，监控只支持 CSS 选择器规则（不调用 LLM）
+fn extraction_rules_value(rules: HashMap<String, ExtractionRule>) -> Result<Value, String> {
+    for (name, rule) in &rules {
+        if rule.use_llm == Some(true) {
+            return Err(format!(
+                "rule '{}': monitors do not support LLM rules",
+                name
+            ));
+        }
+        if rule.selector.as_deref().is_none_or(|s| s.trim().is_empty()) {
+            return Err(format!("rule '{}': selector is required", name));
+        }
+    }
+    serde_json::to_value(rules).map_err(|e| e.to_string())
+}
+
+/// 查询调用方团队的监控，其他团队的监控视为不存在
+async fn find_team_monitor(
+    repo: &dyn MonitorRepository,
+    team_id: Uuid,
+    id: Uuid,
+) -> Result<Monitor, Response> {
+    match repo.find_by_id(id).await {
+        Ok(Some(monitor)) if monitor.team_id == team_id => Ok(monitor),
+        Ok(_) => Err(errors::not_found("Monitor not found")),
+        Err(e) => {
+            error!("Failed to load monitor {}: {}", id, e);
+            Err(errors::internal_server_error("Failed to load monitor"))
+        }
+    }
+}
+
+/// 创建监控，首次检查立即执行并作为比较基线
+pub async fn create_monitor(
+    Extension(auth_state): Extension<AuthState>,
+    Extension(repo): Extension<Arc<dyn MonitorRepository>>,
+    Extension(url_blocklist): Extension<Arc<UrlBlocklistService>>,
+    Json(payload): Json<CreateMonitorRequest>,
+) -> impl IntoResponse {
+    let mut monitor = Monitor::new(
+        auth_state.team_id,
+        payload.url,
+        payload.interval_seconds,
+        payload.mode.unwrap_or_default(),
+    );
+    if let Some(rules) = payload.extraction_rules {
+        match extraction_rules_value(rules) {
+            Ok(rules) => monitor = monitor.with_extraction_rules(rules),
+            Err(e) => return errors::bad_request(format!("Invalid extraction rules: {}", e)),
+        }
+    }
+    if let Err(e) = monitor.validate() {
+        return errors::bad_request(format!("Invalid monitor: {}", e));
+    }
+    if let Err(e) = validate_url(&monitor.url).await {
+        return errors::bad_request(format!("Invalid URL: {}", e));
+    }
+    if let Err(response) =
+        check_url_blocklist(&url_blocklist, auth_state.team_id, [monitor.url.as_str()]).await
+    {
+        return response;
+    }
+
+    match repo.find_by_team(auth_state.team_id).await {
+        Ok(existing) if existing.len() >= MAX_MONITORS_PER_TEAM => {
+            return errors::bad_request(format!(
+                "A team can have at most {} monitors",
+                MAX_MONITORS_PER_TEAM
+            ));
+        }
+        Ok(_) => {}
+        Err(e) => return errors::internal_server_error(e.to_string()),
+    }
+
+    match repo.create(&monitor).await {
+        Ok(created) => success_response(StatusCode::CREATED, created),
+        Err(e) => errors::internal_server_error(e.to_string()),
+    }
+}
+
+/// 列出调用方团队的监控
+pub async fn list_monitors(
+    Extension(auth_state): Extension<AuthState>,
+    Extension(repo): Extension<Arc<dyn MonitorRepository>>,
+) -> impl IntoResponse {
+    match repo.find_by_team(auth_state.team_id).await {
+        Ok(monitors) => success_response(StatusCode::OK, MonitorListResponseDto { monitors }),
+        Err(e) => errors::internal_server_error(e.to_string()),
+    }
+}
+
+/// 查询监控详情（包含最近一次检查的状态）
+pub async fn get_monitor(
+    Extension(auth_state): Extension<AuthState>,
+    Extension(repo): Extension<Arc<dyn MonitorRepository>>,
+    Path(id): Path<Uuid>,
+) -> impl IntoResponse {
+    match find_team_monitor(repo.as_ref(), auth_state.team_id, id).await {
+        Ok(monitor) => success_response(StatusCode::OK, monitor),
+        Err(response) => response,
+    }
+}
+
+/// 修改检查间隔，或暂停、恢复监控
+pub async fn update_monitor(
+    Extension(auth_state): Extension<AuthState>,
+    Extension(repo): Extension<Arc<dyn MonitorRepository>>,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<UpdateMonitorRequest>,
+) -> impl IntoResponse {
+    let mut monitor = match find_team_monitor(repo.as_ref(), auth_state.team_id, id).await {
+        Ok(monitor) => monitor,
+        Err(response) => return response,
+    };
+    if let Some(interval_seconds) = payload.interval_seconds {
+        monitor.set_interval(interval_seconds);
+    }
+    if let Some(active) = payload.active {
+        monitor.set_active(active);
+    }
+    if let Err(e) = monitor.validate() {
+        return errors::bad_request(format!("Invalid monitor: {}", e));
+    }
+
+    match repo.update(&monitor).await {
+        Ok(updated) => success_response(StatusCode::OK, updated),
+        Err(e) => errors::internal_server_error(e.to_string()),
+    }
+}
+
+/// 删除监控
+pub async fn delete_monitor(
+    Extension(auth_state): Extension<AuthState>,
+    Extension(repo): Extension<Arc<dyn MonitorRepository>>,
+    Path(id): Path<Uuid>,
+) -> impl IntoResponse {
+    if let Err(response) = find_team_monitor(repo.as_ref(), auth_state.team_id, id).await {
+        return response;
+    }
+
+    match repo.delete(id).await {
+        Ok(true) => StatusCode::NO_CONTENT.into_response(),
+        Ok(false) => errors::not_found("Monitor not found"),
+        Err(e) => errors::internal_server_error(e.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::test_helpers::create_test_db_pool;
+    use crate::domain::auth::ApiKeyScope;
+    use crate::domain::repositories::task_repository::RepositoryError;
+    use async_trait::async_trait;
+    use serde_json::json;
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct MockMonitorRepo {
+        monitors: Mutex<Vec<Monitor>>,
+    }
+
+    #[async_trait]
+    impl MonitorRepository for MockMonitorRepo {
+        async fn create(&self, monitor: &Monitor) -> Result<Monitor, RepositoryError> {
+            self.monitors.lock().unwrap().push(monitor.clone());
+            Ok(monitor.clone())
+        }
+        async fn find_by_id(&self, id: Uuid) -> Result<Option<Monitor>, RepositoryError> {
+            Ok(self
+                .monitors
+                .lock()
+                .unwrap()
+                .iter()
+                .find(|m| m.id == id)
+                .cloned())
+        }
+        async fn find_by_team(&self, team_id: Uuid) -> Result<Vec<Monitor>, RepositoryError> {
+            Ok(self
+                .monitors
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|m| m.team_id == team_id)
+                .cloned()
+                .collect())
+        }
+        async fn update(&self, monitor: &Monitor) -> Result<Monitor, RepositoryError> {
+            let mut monitors = self.monitors.lock().unwrap();
+            let stored = monitors
+                .iter_mut()
+                .find(|m| m.id == monitor.id)
+                .ok_or(RepositoryError::NotFound)?;
+            *stored = monitor.clone();
+            Ok(monitor.clone())
+        }
+        async fn delete(&self, id: Uuid) -> Result<bool, RepositoryError> {
+            let mut monitors = self.monitors.lock().unwrap();
+            let before = monitors.len();
+            monitors.retain(|m| m.id != id);
+            Ok(monitors.len() != before)
+        }
+        async fn claim_due(
+            &self,
+            _limit: u64,
+            _lease: chrono::Duration,
+        ) -> Result<Vec<Monitor>, RepositoryError> {
+            Ok(vec![])
+        }
+    }
+
+    fn auth_state(team_id: Uuid) -> AuthState {
+        AuthState::new(
+            create_test_db_pool(),
+            team_id,
+            Uuid::new_v4(),
+            ApiKeyScope::default(),
+        )
+    }
+
+    fn blocklist() -> Extension<Arc<UrlBlocklistService>> {
+        Extension(Arc::new(UrlBlocklistService::new(&[
+            "93.184.216.35".to_string()
+        ])))
+    }
+
+    fn create_request(value: Value) -> Json<CreateMonitorRequest> {
+        Json(serde_json::from_value(value).unwrap())
+    }
+
+    #[test]
+    fn test_extraction_rules_must_use_selectors() {
+        let rules: HashMap<String, ExtractionRule> = serde_json::from_value(json!({
+            "summary": {"is_array": false, "use_llm": true, "llm_prompt": "Summarize"}
+        }))
+        .unwrap();
+        assert!(extraction_rules_value(rules).is_err());
+
+        let rules: HashMap<String, ExtractionRule> = serde_json::from_value(json!({
+            "price": {"selector": ".price", "is_array": false}
+        }))
+        .unwrap();
+        assert_eq!(
+            extraction_rules_value(rules).unwrap()["price"]["selector"],
+            ".price"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_create_validates_interval_and_url() {
+        let repo: Arc<dyn MonitorRepository> = Arc::new(MockMonitorRepo::default());
+        let team_id = Uuid::new_v4();
+
+        let response = create_monitor(
+            Extension(auth_state(team_id)),
+            Extension(repo.clone()),
+            blocklist(),
+            create_request(json!({"url": "https://93.184.216.34/", "interval_seconds": 5})),
+        )
+        .await
+        .into_response();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        let response = create_monitor(
+            Extension(auth_state(team_id)),
+            Extension(repo.clone()),
+            blocklist(),
+            create_request(json!({"url": "http://127.0.0.1/admin", "interval_seconds": 300})),
+        )
+        .await
+        .into_response();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        let response = create_monitor(
+            Extension(auth_state(team_id)),
+            Extension(repo.clone()),
+            blocklist(),
+            create_request(json!({
+                "url": "https://93.184.216.34/",
+                "interval_seconds": 300,
+                "mode": "extract"
+            })),
+        )
+        .await
+        .into_response();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        assert!(repo.find_by_team(team_id).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_create_update_and_delete_monitor() {
+        let repo: Arc<dyn MonitorRepository> = Arc::new(MockMonitorRepo::default());
+        let team_id = Uuid::new_v4();
+
+        let response = create_monitor(
+            Extension(auth_state(team_id)),
+            Extension(repo.clone()),
+            blocklist(),
+            create_request(json!({
+                "url": "https://93.184.216.34/pricing",
+                "interval_seconds": 3600,
+                "mode": "extract",
+                "extraction_rules": {"price": {"selector": ".price", "is_array": false}}
+            })),
+        )
+        .await
+        .into_response();
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let monitors = repo.find_by_team(team_id).await.unwrap();
+        assert_eq!(monitors.len(), 1);
+        assert_eq!(monitors[0].mode, MonitorMode::Extract);
+        let id = monitors[0].id;
+
+        let response = update_monitor(
+            Extension(auth_state(team_id)),
+            Extension(repo.clone()),
+            Path(id),
+            Json(UpdateMonitorRequest {
+                interval_seconds: Some(600),
+                active: Some(false),
+            }),
+        )
+        .await
+        .into_response();
+        assert_eq!(response.status(), StatusCode::OK);
+        let stored = repo.find_by_id(id).await.unwrap().unwrap();
+        assert_eq!(stored.interval_seconds, 600);
+        assert!(!stored.active);
+
+        let response = update_monitor(
+            Extension(auth_state(team_id)),
+            Extension(repo.clone()),
+            Path(id),
+            Json(UpdateMonitorRequest {
+                interval_seconds: Some(1),
+                active: None,
+            }),
+        )
+        .await
+        .into_response();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        let response = delete_monitor(
+            Extension(auth_state(team_id)),
+            Extension(repo.clone()),
+            Path(id),
+        )
+        .await
+        .into_response();
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+        assert!(repo.find_by_id(id).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_blocked_url_is_rejected() {
+        let repo: Arc<dyn MonitorRepository> = Arc::new(MockMonitorRepo::default());
+        let response = create_monitor(
+            Extension(auth_state(Uuid::new_v4())),
+            Extension(repo),
+            blocklist(),
+            create_request(json!({
+                "url": "https://93.184.216.35/",
+                "interval_seconds": 300
+            })),
+        )
+        .await
+        .into_response();
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test]
+    async fn test_other_team_monitor_is_not_found() {
+        let repo: Arc<dyn MonitorRepository> = Arc::new(MockMonitorRepo::default());
+        let monitor = Monitor::new(
+            Uuid::new_v4(),
+            "https://93.184.216.34/",
+            300,
+            MonitorMode::Hash,
+        );
+        repo.create(&monitor).await.unwrap();
+
+        let response = get_monitor(
+            Extension(auth_state(Uuid::new_v4())),
+            Extension(repo.clone()),
+            Path(monitor.id),
+        )
+        .await
+        .into_response();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+        let response = delete_monitor(
+            Extension(auth_state(Uuid::new_v4())),
+            Extension(repo.clone()),
+            Path(monitor.id),
+        )
+        .await
+        .into_response();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        assert!(repo.find_by_id(monitor.id).await.unwrap().is_some());
+    }
+}
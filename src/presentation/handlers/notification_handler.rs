@@ -0,0 +1,754 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! 通知渠道接口
+//!
+//! 团队配置接收事件的邮件联系人与聊天频道，以及各自订阅的事件类型（空表示全部事件）。
+//! 邮件需要服务端开启 `[email]` 邮件通知，未开启时配置会保存但不会发送邮件；
+//! 聊天频道为 Slack incoming webhook 或通用 JSON POST 地址。
+//! 所有渠道（含 webhook）的投递记录可通过 `GET /v1/notifications/deliveries` 查询。
+
+use crate::common::constants::server_config;
+use crate::domain::models::{
+    ChannelKind, DeliveryChannel, NotificationChannel, NotificationContacts, WebhookEvent,
+    WebhookEventType, WebhookStatus,
+};
+use crate::domain::repositories::notification_channel_repository::NotificationChannelRepository;
+use crate::domain::repositories::notification_contacts_repository::NotificationContactsRepository;
+use crate::domain::repositories::webhook_event_repository::WebhookEventRepository;
+use crate::presentation::handlers::response_builder::{
+    errors, success_response, success_response_with_meta, PaginationMeta,
+};
+use crate::presentation::helpers::ssrf::validate_url;
+use crate::presentation::middleware::auth_middleware::AuthState;
+use axum::{
+    extract::{Extension, Path, Query},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use chrono::{DateTime, Utc};
+use log::error;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// 每个团队最多注册的聊天频道数
+const MAX_CHANNELS_PER_TEAM: usize = 20;
+
+/// 设置通知联系人请求
+#[derive(Debug, Deserialize)]
+pub struct UpdateNotificationContactsRequest {
+    /// 接收通知的邮箱
+    pub emails: Vec<String>,
+    /// 订阅的事件类型，未提供时保持不变（新建时为全部事件）
+    pub event_types: Option<Vec<String>>,
+    /// 暂停（false）或恢复（true）邮件通知，未提供时保持不变
+    pub enabled: Option<bool>,
+}
+
+/// 注册聊天频道请求
+#[derive(Debug, Deserialize)]
+pub struct CreateChannelRequest {
+    /// 频道名称，如 `#crawl-alerts`
+    pub name: String,
+    /// 消息格式：`slack` 或 `json`
+    pub kind: ChannelKind,
+    /// 频道的 incoming webhook 地址
+    pub url: String,
+    /// 订阅的事件类型，未提供时为全部事件
+    pub event_types: Option<Vec<String>>,
+}
+
+/// 更新聊天频道请求，未提供的字段保持不变
+#[derive(Debug, Deserialize)]
+pub struct UpdateChannelRequest {
+    /// 频道名称
+    pub name: Option<String>,
+    /// 频道的 incoming webhook 地址
+    pub url: Option<String>,
+    /// 订阅的事件类型
+    pub event_types: Option<Vec<String>>,
+    /// 暂停（false）或恢复（true）推送
+    pub enabled: Option<bool>,
+}
+
+/// 聊天频道列表响应数据传输对象
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChannelListResponseDto {
+    /// 频道列表
+    pub channels: Vec<NotificationChannel>,
+}
+
+/// 投递记录查询参数
+#[derive(Debug, Default, Deserialize)]
+pub struct DeliveriesQuery {
+    /// 页码（从 1 开始）
+    pub page: Option<u32>,
+    /// 每页条数
+    pub per_page: Option<u32>,
+}
+
+/// 一条投递记录
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NotificationDeliveryDto {
+    /// 投递记录 ID（webhook 投递时同 `X-Crawlrs-Event-ID` 请求头）
+    pub id: Uuid,
+    /// 投递渠道
+    pub channel: DeliveryChannel,
+    /// 事件类型
+    pub event_type: String,
+    /// 投递目标：webhook 或频道地址，邮件为 `mailto:` 地址
+    pub target: String,
+    /// 投递状态
+    pub status: WebhookStatus,
+    /// 已尝试次数
+    pub attempt_count: i32,
+    /// 最近一次失败原因
+    pub error_message: Option<String>,
+    /// 下次重试时间
+    pub next_retry_at: Option<DateTime<Utc>>,
+    /// 创建时间
+    pub created_at: DateTime<Utc>,
+    /// 送达时间
+    pub delivered_at: Option<DateTime<Utc>>,
+}
+
+impl From<WebhookEvent> for NotificationDeliveryDto {
+    fn from(event: WebhookEvent) -> Self {
+        Self {
+            id: event.id,
+            channel: event.channel,
+            event_type: event.event_type.to_string(),
+            target: event.webhook_url,
+            status: event.status,
+            attempt_count: event.attempt_count,
+            error_message: event.error_message,
+            next_retry_at: event.next_retry_at,
+            created_at: event.created_at,
+            delivered_at: event.delivered_at,
+        }
+    }
+}
+
+/// 投递记录列表响应
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeliveryListResponseDto {
+    /// 投递记录，按创建时间倒序
+    pub deliveries: Vec<NotificationDeliveryDto>,
+}
+
+/// 将请求中的事件类型名称解析为领域事件类型，空白名称视为校验错误
+fn parse_event_types(names: &[String]) -> Result<Vec<WebhookEventType>, Box<Response>> {
+    names
+        .iter()
+        .map(|name| {
+            let name = name.trim();
+            if name.is_empty() {
+                return Err(Box::new(errors::bad_request(
+                    "event_types must not contain empty values",
+                )));
+            }
+            name.parse::<WebhookEventType>()
+                .map_err(|e| Box::new(errors::bad_request(format!("Invalid event type: {}", e))))
+        })
+        .collect()
+}
+
+/// 查询调用方团队的通知联系人
+pub async fn get_email_contacts(
+    Extension(auth_state): Extension<AuthState>,
+    Extension(repo): Extension<Arc<dyn NotificationContactsRepository>>,
+) -> impl IntoResponse {
+    match repo.find_by_team(auth_state.team_id).await {
+        Ok(Some(contacts)) => success_response(StatusCode::OK, contacts),
+        Ok(None) => errors::not_found("No email notification contacts configured"),
+        Err(e) => {
+            error!(
+                "Failed to load notification contacts of team {}: {}",
+                auth_state.team_id, e
+            );
+            errors::internal_server_error("Failed to load notification contacts")
+        }
+    }
+}
+
+/// 设置调用方团队的通知联系人
+pub async fn put_email_contacts(
+    Extension(auth_state): Extension<AuthState>,
+    Extension(repo): Extension<Arc<dyn NotificationContactsRepository>>,
+    Json(payload): Json<UpdateNotificationContactsRequest>,
+) -> impl IntoResponse {
+    let event_types = match payload.event_types.as_deref().map(parse_event_types) {
+        Some(Ok(event_types)) => Some(event_types),
+        Some(Err(response)) => return *response,
+        None => None,
+    };
+
+    let mut contacts = match repo.find_by_team(auth_state.team_id).await {
+        Ok(Some(contacts)) => contacts,
+        Ok(None) => NotificationContacts::new(auth_state.team_id, Vec::new(), Vec::new()),
+        Err(e) => return errors::internal_server_error(e.to_string()),
+    };
+    contacts.set_emails(payload.emails);
+    if let Some(event_types) = event_types {
+        contacts.set_event_types(event_types);
+    }
+    if let Some(enabled) = payload.enabled {
+        contacts.enabled = enabled;
+    }
+    if let Err(e) = contacts.validate() {
+        return errors::bad_request(format!("Invalid notification contacts: {}", e));
+    }
+
+    match repo.upsert(&contacts).await {
+        Ok(saved) => success_response(StatusCode::OK, saved),
+        Err(e) => errors::internal_server_error(e.to_string()),
+    }
+}
+
+/// 删除调用方团队的通知联系人，停止邮件通知
+pub async fn delete_email_contacts(
+    Extension(auth_state): Extension<AuthState>,
+    Extension(repo): Extension<Arc<dyn NotificationContactsRepository>>,
+) -> impl IntoResponse {
+    match repo.delete(auth_state.team_id).await {
+        Ok(true) => StatusCode::NO_CONTENT.into_response(),
+        Ok(false) => errors::not_found("No email notification contacts configured"),
+        Err(e) => errors::internal_server_error(e.to_string()),
+    }
+}
+
+/// 校验频道配置与地址（拒绝指向内网的地址）
+async fn validate_channel(channel: &NotificationChannel) -> Result<(), Response> {
+    if let Err(e) = channel.validate() {
+        return Err(errors::bad_request(format!(
+            "Invalid notification channel: {}",
+            e
+        )));
+    }
+    if let Err(e) = validate_url(&channel.url).await {
+        return Err(errors::bad_request(format!("Invalid URL: {}", e)));
+    }
+    Ok(())
+}
+
+/// 查询调用方团队的频道，其他团队的频道视为不存在
+async fn find_team_channel(
+    repo: &dyn NotificationChannelRepository,
+    team_id: Uuid,
+    id: Uuid,
+) -> Result<NotificationChannel, Response> {
+    match repo.find_by_id(id).await {
+        Ok(Some(channel)) if channel.team_id == team_id => Ok(channel),
+        Ok(_) => Err(errors::not_found("Notification channel not found")),
+        Err(e) => {
+            error!("Failed to load notification channel {}: {}", id, e);
+            Err(errors::internal_server_error(
+                "Failed to load notification channel",
+            ))
+        }
+    }
+}
+
+/// 注册聊天频道
+pub async fn create_channel(
+    Extension(auth_state): Extension<AuthState>,
+    Extension(repo): Extension<Arc<dyn NotificationChannelRepository>>,
+    Json(payload): Json<CreateChannelRequest>,
+) -> impl IntoResponse {
+    let mut channel =
+        NotificationChannel::new(auth_state.team_id, payload.name, payload.kind, payload.url);
+    if let Some(names) = payload.event_types {
+        match parse_event_types(&names) {
+            Ok(event_types) => channel.set_event_types(event_types),
+            Err(response) => return *response,
+        }
+    }
+    if let Err(response) = validate_channel(&channel).await {
+        return response;
+    }
+
+    match repo.find_by_team(auth_state.team_id).await {
+        Ok(existing) if existing.len() >= MAX_CHANNELS_PER_TEAM => {
+            return errors::bad_request(format!(
+                "A team can have at most {} notification channels",
+                MAX_CHANNELS_PER_TEAM
+            ));
+        }
+        Ok(_) => {}
+        Err(e) => return errors::internal_server_error(e.to_string()),
+    }
+
+    match repo.create(&channel).await {
+        Ok(created) => success_response(StatusCode::CREATED, created),
+        Err(e) => errors::internal_server_error(e.to_string()),
+    }
+}
+
+/// 列出调用方团队的聊天频道
+pub async fn list_channels(
+    Extension(auth_state): Extension<AuthState>,
+    Extension(repo): Extension<Arc<dyn NotificationChannelRepository>>,
+) -> impl IntoResponse {
+    match repo.find_by_team(auth_state.team_id).await {
+        Ok(channels) => success_response(StatusCode::OK, ChannelListResponseDto { channels }),
+        Err(e) => errors::internal_server_error(e.to_string()),
+    }
+}
+
+/// 查询聊天频道详情
+pub async fn get_channel(
+    Extension(auth_state): Extension<AuthState>,
+    Extension(repo): Extension<Arc<dyn NotificationChannelRepository>>,
+    Path(id): Path<Uuid>,
+) -> impl IntoResponse {
+    match find_team_channel(repo.as_ref(), auth_state.team_id, id).await {
+        Ok(channel) => success_response(StatusCode::OK, channel),
+        Err(response) => response,
+    }
+}
+
+/// 修改聊天频道的名称、地址、订阅事件，或暂停、恢复推送
+pub async fn update_channel(
+    Extension(auth_state): Extension<AuthState>,
+    Extension(repo): Extension<Arc<dyn NotificationChannelRepository>>,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<UpdateChannelRequest>,
+) -> impl IntoResponse {
+    let mut channel = match find_team_channel(repo.as_ref(), auth_state.team_id, id).await {
+        Ok(channel) => channel,
+        Err(response) => return response,
+    };
+    if let Some(name) = payload.name {
+        channel.name = name.trim().to_string();
+    }
+    if let Some(url) = payload.url {
+        channel.url = url.trim().to_string();
+    }
+    if let Some(names) = payload.event_types {
+        match parse_event_types(&names) {
+            Ok(event_types) => channel.set_event_types(event_types),
+            Err(response) => return *response,
+        }
+    }
+    if let Some(enabled) = payload.enabled {
+        channel.enabled = enabled;
+    }
+    if let Err(response) = validate_channel(&channel).await {
+        return response;
+    }
+    channel.updated_at = Utc::now();
+
+    match repo.update(&channel).await {
+        Ok(updated) => success_response(StatusCode::OK, updated),
+        Err(e) => errors::internal_server_error(e.to_string()),
+    }
+}
+
+/// 删除聊天频道
+pub async fn delete_channel(
+    Extension(auth_state): Extension<AuthState>,
+    Extension(repo): Extension<Arc<dyn NotificationChannelRepository>>,
+    Path(id): Path<Uuid>,
+) -> impl IntoResponse {
+    if let Err(response) = find_team_channel(repo.as_ref(), auth_state.team_id, id).await {
+        return response;
+    }
+
+    match repo.delete(id).await {
+        Ok(true) => StatusCode::NO_CONTENT.into_response(),
+        Ok(false) => errors::not_found("Notification channel not found"),
+        Err(e) => errors::internal_server_error(e.to_string()),
+    }
+}
+
+/// 分页查询调用方团队的投递记录，包括 webhook、邮件与聊天频道
+pub async fn list_deliveries(
+    Extension(auth_state): Extension<AuthState>,
+    Extension(repo): Extension<Arc<dyn WebhookEventRepository>>,
+    Query(query): Query<DeliveriesQuery>,
+) -> impl IntoResponse {
+    let page = query.page.unwrap_or(1).max(1);
+    let per_page = query
+        .per_page
+        .unwrap_or(server_config::DEFAULT_PAGE_LIMIT)
+        .clamp(1, server_config::MAX_PAGE_LIMIT);
+    let offset = (page - 1).saturating_mul(per_page);
+
+    let total = match repo.count_by_team_id(auth_state.team_id).await {
+        Ok(total) => total,
+        Err(e) => return errors::internal_server_error(e.to_string()),
+    };
+    match repo
+        .find_by_team_id_paginated(auth_state.team_id, per_page, offset)
+        .await
+    {
+        Ok(events) => success_response_with_meta(
+            StatusCode::OK,
+            DeliveryListResponseDto {
+                deliveries: events.into_iter().map(Into::into).collect(),
+            },
+            PaginationMeta::new(page, per_page, total),
+        ),
+        Err(e) => errors::internal_server_error(e.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::test_helpers::create_test_db_pool;
+    use crate::domain::auth::ApiKeyScope;
+    use crate::domain::repositories::task_repository::RepositoryError;
+    use async_trait::async_trait;
+    use serde_json::json;
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct MockContactsRepo {
+        contacts: Mutex<Vec<NotificationContacts>>,
+    }
+
+    #[async_trait]
+    impl NotificationContactsRepository for MockContactsRepo {
+        async fn find_by_team(
+            &self,
+            team_id: Uuid,
+        ) -> Result<Option<NotificationContacts>, RepositoryError> {
+            Ok(self
+                .contacts
+                .lock()
+                .unwrap()
+                .iter()
+                .find(|c| c.team_id == team_id)
+                .cloned())
+        }
+        async fn upsert(
+            &self,
+            contacts: &NotificationContacts,
+        ) -> Result<NotificationContacts, RepositoryError> {
+            let mut stored = self.contacts.lock().unwrap();
+            stored.retain(|c| c.team_id != contacts.team_id);
+            stored.push(contacts.clone());
+            Ok(contacts.clone())
+        }
+        async fn delete(&self, team_id: Uuid) -> Result<bool, RepositoryError> {
+            let mut stored = self.contacts.lock().unwrap();
+            let before = stored.len();
+            stored.retain(|c| c.team_id != team_id);
+            Ok(stored.len() != before)
+        }
+    }
+
+    fn auth_state(team_id: Uuid) -> AuthState {
+        AuthState::new(
+            create_test_db_pool(),
+            team_id,
+            Uuid::new_v4(),
+            ApiKeyScope::default(),
+        )
+    }
+
+    fn put_request(value: serde_json::Value) -> Json<UpdateNotificationContactsRequest> {
+        Json(serde_json::from_value(value).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_put_get_and_delete_contacts() {
+        let repo: Arc<dyn NotificationContactsRepository> = Arc::new(MockContactsRepo::default());
+        let team_id = Uuid::new_v4();
+
+        let response = get_email_contacts(Extension(auth_state(team_id)), Extension(repo.clone()))
+            .await
+            .into_response();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+        let response = put_email_contacts(
+            Extension(auth_state(team_id)),
+            Extension(repo.clone()),
+            put_request(json!({
+                "emails": ["ops@example.com"],
+                "event_types": ["credits.low", "webhook.delivery_failed"]
+            })),
+        )
+        .await
+        .into_response();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        // Omitted event_types keep the stored filter
+        let response = put_email_contacts(
+            Extension(auth_state(team_id)),
+            Extension(repo.clone()),
+            put_request(
+                json!({"emails": ["ops@example.com", "dev@example.com"], "enabled": false}),
+            ),
+        )
+        .await
+        .into_response();
+        assert_eq!(response.status(), StatusCode::OK);
+        let stored = repo.find_by_team(team_id).await.unwrap().unwrap();
+        assert_eq!(stored.emails.len(), 2);
+        assert_eq!(stored.event_types.len(), 2);
+        assert!(!stored.enabled);
+
+        let response =
+            delete_email_contacts(Extension(auth_state(team_id)), Extension(repo.clone()))
+                .await
+                .into_response();
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+        assert!(repo.find_by_team(team_id).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_put_rejects_invalid_contacts() {
+        let repo: Arc<dyn NotificationContactsRepository> = Arc::new(MockContactsRepo::default());
+        let team_id = Uuid::new_v4();
+
+        for body in [
+            json!({"emails": []}),
+            json!({"emails": ["not-an-email"]}),
+            json!({"emails": ["ops@example.com"], "event_types": [" "]}),
+        ] {
+            let response = put_email_contacts(
+                Extension(auth_state(team_id)),
+                Extension(repo.clone()),
+                put_request(body),
+            )
+            .await
+            .into_response();
+            assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        }
+        assert!(repo.find_by_team(team_id).await.unwrap().is_none());
+    }
+
+    #[derive(Default)]
+    struct MockChannelRepo {
+        channels: Mutex<Vec<NotificationChannel>>,
+    }
+
+    #[async_trait]
+    impl NotificationChannelRepository for MockChannelRepo {
+        async fn create(
+            &self,
+            channel: &NotificationChannel,
+        ) -> Result<NotificationChannel, RepositoryError> {
+            self.channels.lock().unwrap().push(channel.clone());
+            Ok(channel.clone())
+        }
+        async fn find_by_id(
+            &self,
+            id: Uuid,
+        ) -> Result<Option<NotificationChannel>, RepositoryError> {
+            Ok(self
+                .channels
+                .lock()
+                .unwrap()
+                .iter()
+                .find(|c| c.id == id)
+                .cloned())
+        }
+        async fn find_by_team(
+            &self,
+            team_id: Uuid,
+        ) -> Result<Vec<NotificationChannel>, RepositoryError> {
+            Ok(self
+                .channels
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|c| c.team_id == team_id)
+                .cloned()
+                .collect())
+        }
+        async fn update(
+            &self,
+            channel: &NotificationChannel,
+        ) -> Result<NotificationChannel, RepositoryError> {
+            let mut channels = self.channels.lock().unwrap();
+            let stored = channels
+                .iter_mut()
+                .find(|c| c.id == channel.id)
+                .ok_or(RepositoryError::NotFound)?;
+            *stored = channel.clone();
+            Ok(channel.clone())
+        }
+        async fn delete(&self, id: Uuid) -> Result<bool, RepositoryError> {
+            let mut channels = self.channels.lock().unwrap();
+            let before = channels.len();
+            channels.retain(|c| c.id != id);
+            Ok(channels.len() != before)
+        }
+    }
+
+    fn create_channel_request(value: serde_json::Value) -> Json<CreateChannelRequest> {
+        Json(serde_json::from_value(value).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_create_channel_validates_url() {
+        let repo: Arc<dyn NotificationChannelRepository> = Arc::new(MockChannelRepo::default());
+        let team_id = Uuid::new_v4();
+
+        for body in [
+            json!({"name": "#alerts", "kind": "slack", "url": "http://93.184.216.34/hook"}),
+            json!({"name": "internal", "kind": "json", "url": "http://127.0.0.1/hook"}),
+            json!({"name": "", "kind": "json", "url": "https://93.184.216.34/hook"}),
+        ] {
+            let response = create_channel(
+                Extension(auth_state(team_id)),
+                Extension(repo.clone()),
+                create_channel_request(body),
+            )
+            .await
+            .into_response();
+            assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        }
+        assert!(repo.find_by_team(team_id).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_create_update_and_delete_channel() {
+        let repo: Arc<dyn NotificationChannelRepository> = Arc::new(MockChannelRepo::default());
+        let team_id = Uuid::new_v4();
+
+        let response = create_channel(
+            Extension(auth_state(team_id)),
+            Extension(repo.clone()),
+            create_channel_request(json!({
+                "name": "#crawl-alerts",
+                "kind": "slack",
+                "url": "https://93.184.216.34/services/T0/B0/x",
+                "event_types": ["crawl.summary"]
+            })),
+        )
+        .await
+        .into_response();
+        assert_eq!(response.status(), StatusCode::CREATED);
+        let channels = repo.find_by_team(team_id).await.unwrap();
+        assert_eq!(channels.len(), 1);
+        assert_eq!(channels[0].kind, ChannelKind::Slack);
+        let id = channels[0].id;
+
+        // Another team cannot see the channel
+        let response = get_channel(
+            Extension(auth_state(Uuid::new_v4())),
+            Extension(repo.clone()),
+            Path(id),
+        )
+        .await
+        .into_response();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+        let response = update_channel(
+            Extension(auth_state(team_id)),
+            Extension(repo.clone()),
+            Path(id),
+            Json(UpdateChannelRequest {
+                name: None,
+                url: None,
+                event_types: Some(vec![]),
+                enabled: Some(false),
+            }),
+        )
+        .await
+        .into_response();
+        assert_eq!(response.status(), StatusCode::OK);
+        let stored = repo.find_by_id(id).await.unwrap().unwrap();
+        assert!(stored.event_types.is_empty());
+        assert!(!stored.enabled);
+
+        let response = delete_channel(
+            Extension(auth_state(team_id)),
+            Extension(repo.clone()),
+            Path(id),
+        )
+        .await
+        .into_response();
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+        assert!(repo.find_by_id(id).await.unwrap().is_none());
+    }
+
+    /// Delivery log that records the requested page and returns its events
+    #[derive(Default)]
+    struct MockDeliveryLog {
+        events: Vec<WebhookEvent>,
+        pages: Mutex<Vec<(Uuid, u32, u32)>>,
+    }
+
+    #[async_trait]
+    impl WebhookEventRepository for MockDeliveryLog {
+        async fn create(&self, event: &WebhookEvent) -> Result<WebhookEvent, RepositoryError> {
+            Ok(event.clone())
+        }
+        async fn find_by_id(&self, _id: Uuid) -> Result<Option<WebhookEvent>, RepositoryError> {
+            Ok(None)
+        }
+        async fn find_pending(&self, _limit: u64) -> Result<Vec<WebhookEvent>, RepositoryError> {
+            Ok(vec![])
+        }
+        async fn find_by_team_id_paginated(
+            &self,
+            team_id: Uuid,
+            limit: u32,
+            offset: u32,
+        ) -> Result<Vec<WebhookEvent>, RepositoryError> {
+            self.pages.lock().unwrap().push((team_id, limit, offset));
+            Ok(self
+                .events
+                .iter()
+                .filter(|e| e.team_id == team_id)
+                .cloned()
+                .collect())
+        }
+        async fn count_by_team_id(&self, team_id: Uuid) -> Result<u64, RepositoryError> {
+            Ok(self.events.iter().filter(|e| e.team_id == team_id).count() as u64)
+        }
+        async fn update(&self, event: &WebhookEvent) -> Result<WebhookEvent, RepositoryError> {
+            Ok(event.clone())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_list_deliveries_pages_team_log() {
+        let team_id = Uuid::new_v4();
+        let delivery = WebhookEvent::new(
+            Uuid::new_v4(),
+            team_id,
+            Uuid::new_v4(),
+            WebhookEventType::CreditsLow,
+            json!({"text": "low"}),
+            "https://hooks.slack.com/services/T0/B0/x".to_string(),
+        )
+        .with_channel(DeliveryChannel::Slack);
+        let log = Arc::new(MockDeliveryLog {
+            events: vec![delivery.clone()],
+            ..Default::default()
+        });
+
+        let response = list_deliveries(
+            Extension(auth_state(team_id)),
+            Extension(log.clone() as Arc<dyn WebhookEventRepository>),
+            Query(DeliveriesQuery {
+                page: Some(2),
+                per_page: Some(10),
+            }),
+        )
+        .await
+        .into_response();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(*log.pages.lock().unwrap(), vec![(team_id, 10, 10)]);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        let deliveries = body["data"]["deliveries"].as_array().unwrap();
+        assert_eq!(deliveries.len(), 1);
+        assert_eq!(deliveries[0]["channel"], "slack");
+        assert_eq!(deliveries[0]["event_type"], "credits.low");
+        assert_eq!(deliveries[0]["status"], "pending");
+    }
+}
@@ -0,0 +1,293 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! robots.txt 缓存管理接口
+//!
+//! 查看某个主机缓存的 robots.txt 条目与缓存命中统计，并强制重新抓取。缓存由所有团队共用，仅运营方可访问。
+//! 条目列表读取的是处理该请求的进程的内存缓存；刷新同时清除共享缓存中的条目。
+
+use crate::presentation::extractors::role::RequireOperator;
+use crate::presentation::handlers::response_builder::{errors, success_response};
+use crate::utils::robots::{RobotsCacheCounters, RobotsCacheEntry, RobotsCheckerTrait};
+use axum::{
+    extract::{Extension, Path},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+/// 单个 robots.txt 缓存条目
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RobotsCacheEntryDto {
+    /// robots.txt 地址（含 scheme 与端口）
+    pub robots_url: String,
+    /// 来源：`fetched` / `missing` / `fetch_failed` / `shared_cache`
+    pub status: String,
+    /// 内容字节数
+    pub content_length: usize,
+    /// 写入缓存的时间
+    pub cached_at: DateTime<Utc>,
+    /// 剩余有效期（秒）
+    pub expires_in_seconds: u64,
+}
+
+impl From<RobotsCacheEntry> for RobotsCacheEntryDto {
+    fn from(entry: RobotsCacheEntry) -> Self {
+        Self {
+            robots_url: entry.robots_url,
+            status: entry.status.as_str().to_string(),
+            content_length: entry.content_length,
+            cached_at: entry.cached_at,
+            expires_in_seconds: entry.expires_in.as_secs(),
+        }
+    }
+}
+
+/// 缓存统计
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RobotsCacheStatsDto {
+    /// 缓存命中次数（内存或共享缓存）
+    pub hits: u64,
+    /// 内存缓存未命中次数
+    pub misses: u64,
+    /// 抓取失败次数
+    pub fetch_errors: u64,
+}
+
+impl From<RobotsCacheCounters> for RobotsCacheStatsDto {
+    fn from(counters: RobotsCacheCounters) -> Self {
+        Self {
+            hits: counters.hits,
+            misses: counters.misses,
+            fetch_errors: counters.fetch_errors,
+        }
+    }
+}
+
+/// 主机的 robots.txt 缓存响应
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RobotsCacheResponseDto {
+    /// 主机名
+    pub host: String,
+    /// 缓存条目
+    pub entries: Vec<RobotsCacheEntryDto>,
+    /// 本进程的缓存统计
+    pub stats: RobotsCacheStatsDto,
+}
+
+/// 校验并规范化主机名，不合法时返回 None
+fn normalize_host(host: &str) -> Option<String> {
+    let host = host.trim().trim_end_matches('.').to_ascii_lowercase();
+    let parsed = url::Url::parse(&format!("https://{}/", host)).ok()?;
+    (parsed.host_str() == Some(host.as_str()) && parsed.port().is_none()).then_some(host)
+}
+
+fn cache_response(
+    host: String,
+    entries: Vec<RobotsCacheEntry>,
+    robots_checker: &Arc<dyn RobotsCheckerTrait>,
+) -> RobotsCacheResponseDto {
+    RobotsCacheResponseDto {
+        host,
+        entries: entries.into_iter().map(RobotsCacheEntryDto::from).collect(),
+        stats: robots_checker.cache_counters().into(),
+    }
+}
+
+/// 查看主机缓存的 robots.txt 条目
+pub async fn get_robots_cache(
+    RequireOperator(_auth_state): RequireOperator,
+    Extension(robots_checker): Extension<Arc<dyn RobotsCheckerTrait>>,
+    Path(host): Path<String>,
+) -> impl IntoResponse {
+    let Some(host) = normalize_host(&host) else {
+        return errors::bad_request(format!("Invalid host: {}", host));
+    };
+
+    let entries = robots_checker.cached_entries(&host).await;
+    success_response(
+        StatusCode::OK,
+        cache_response(host, entries, &robots_checker),
+    )
+}
+
+/// 丢弃主机缓存的 robots.txt 并重新抓取，返回刷新后的条目
+pub async fn refresh_robots_cache(
+    RequireOperator(auth_state): RequireOperator,
+    Extension(robots_checker): Extension<Arc<dyn RobotsCheckerTrait>>,
+    Path(host): Path<String>,
+) -> impl IntoResponse {
+    let Some(host) = normalize_host(&host) else {
+        return errors::bad_request(format!("Invalid host: {}", host));
+    };
+
+    match robots_checker.refresh(&host).await {
+        Ok(entries) => {
+            log::info!(
+                "robots.txt cache for {} refreshed by API key {}",
+                host,
+                auth_state.api_key_id
+            );
+            success_response(
+                StatusCode::OK,
+                cache_response(host, entries, &robots_checker),
+            )
+        }
+        Err(e) => errors::bad_request(format!("Failed to refresh robots.txt for {}: {}", host, e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::test_helpers::create_test_db_pool;
+    use crate::config::settings::Settings;
+    use crate::domain::auth::ApiKeyScope;
+    use crate::presentation::extractors::role::OPERATOR_TOKEN_HEADER;
+    use crate::presentation::middleware::auth_middleware::AuthState;
+    use crate::utils::robots::RobotsFetchStatus;
+    use async_trait::async_trait;
+    use axum::body::Body;
+    use axum::http::Request;
+    use std::time::Duration;
+    use tower::ServiceExt;
+    use uuid::Uuid;
+
+    const TEST_OPERATOR_TOKEN: &str = "test-operator-token";
+
+    struct CachedRobotsChecker;
+
+    #[async_trait]
+    impl RobotsCheckerTrait for CachedRobotsChecker {
+        async fn is_allowed(&self, _url_str: &str, _user_agent: &str) -> anyhow::Result<bool> {
+            Ok(true)
+        }
+        async fn get_crawl_delay(
+            &self,
+            _url_str: &str,
+            _user_agent: &str,
+        ) -> anyhow::Result<Option<Duration>> {
+            Ok(None)
+        }
+        async fn cached_entries(&self, host: &str) -> Vec<RobotsCacheEntry> {
+            vec![RobotsCacheEntry {
+                robots_url: format!("https://{}:443/robots.txt", host),
+                status: RobotsFetchStatus::Fetched,
+                content_length: 42,
+                cached_at: Utc::now(),
+                expires_in: Duration::from_secs(1800),
+            }]
+        }
+        fn cache_counters(&self) -> RobotsCacheCounters {
+            RobotsCacheCounters {
+                hits: 3,
+                misses: 1,
+                fetch_errors: 0,
+            }
+        }
+    }
+
+    fn auth_state(scope: ApiKeyScope) -> AuthState {
+        AuthState::new(create_test_db_pool(), Uuid::new_v4(), Uuid::new_v4(), scope)
+    }
+
+    fn checker() -> Arc<dyn RobotsCheckerTrait> {
+        Arc::new(CachedRobotsChecker)
+    }
+
+    #[test]
+    fn test_normalize_host() {
+        assert_eq!(
+            normalize_host("Example.COM."),
+            Some("example.com".to_string())
+        );
+        assert!(normalize_host("example.com:8080").is_none());
+        assert!(normalize_host("example.com/path").is_none());
+        assert!(normalize_host("").is_none());
+    }
+
+    /// 挂载 robots.txt 缓存路由，并模拟鉴权中间件注入调用方的 AuthState
+    fn robots_router(auth_state: AuthState) -> axum::Router {
+        let mut settings = Settings::default();
+        settings.server.operator_token = Some(TEST_OPERATOR_TOKEN.to_string());
+        axum::Router::new()
+            .route(
+                "/admin/v1/robots/{host}",
+                axum::routing::get(get_robots_cache),
+            )
+            .route(
+                "/admin/v1/robots/{host}/refresh",
+                axum::routing::post(refresh_robots_cache),
+            )
+            .layer(Extension(checker()))
+            .layer(Extension(Arc::new(settings)))
+            .layer(Extension(auth_state))
+    }
+
+    #[tokio::test]
+    async fn test_robots_cache_endpoints_require_operator() {
+        // 团队的 Admin Key 不能查看或刷新所有团队共用的缓存
+        let admin = auth_state(ApiKeyScope::full_access());
+
+        for (method, uri) in [
+            ("GET", "/admin/v1/robots/example.com"),
+            ("POST", "/admin/v1/robots/example.com/refresh"),
+        ] {
+            let request = |token: Option<&str>| {
+                let mut builder = Request::builder().method(method).uri(uri);
+                if let Some(token) = token {
+                    builder = builder.header(OPERATOR_TOKEN_HEADER, token);
+                }
+                builder.body(Body::empty()).unwrap()
+            };
+
+            let response = robots_router(admin.clone())
+                .oneshot(request(None))
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+            let response = robots_router(admin.clone())
+                .oneshot(request(Some(TEST_OPERATOR_TOKEN)))
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_robots_cache_lists_entries() {
+        let response = get_robots_cache(
+            RequireOperator(auth_state(ApiKeyScope::full_access())),
+            Extension(checker()),
+            Path("Example.com".to_string()),
+        )
+        .await
+        .into_response();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["data"]["host"], "example.com");
+        assert_eq!(
+            json["data"]["entries"][0]["robots_url"],
+            "https://example.com:443/robots.txt"
+        );
+        assert_eq!(json["data"]["entries"][0]["status"], "fetched");
+        assert_eq!(json["data"]["stats"]["hits"], 3);
+
+        let response = get_robots_cache(
+            RequireOperator(auth_state(ApiKeyScope::full_access())),
+            Extension(checker()),
+            Path("bad host".to_string()),
+        )
+        .await
+        .into_response();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+}
@@ -19,10 +19,11 @@ use crate::{
         services::rate_limiting_service::RateLimitingService,
         services::search_service::{SearchQuery, SearchServiceError, SearchServiceTrait},
     },
+    engines::proxy_url::redact_proxy_url,
     presentation::handlers::response_builder::{error_response, success_response},
     presentation::handlers::task_handler::wait_for_tasks_completion,
     presentation::helpers::rate_limit_helper::check_rate_limit,
-    presentation::helpers::ssrf::validate_url,
+    presentation::helpers::ssrf::validate_proxy_url,
     presentation::middleware::auth_middleware::AuthState,
 };
 
@@ -52,6 +53,10 @@ pub async fn search(
         .sync_wait_ms
         .unwrap_or(crawl_task::DEFAULT_TIMEOUT_MS as u32);
 
+    if let Some(Err(e)) = payload.crawl_config.as_ref().map(|c| c.validate_proxy()) {
+        return error_response(StatusCode::UNPROCESSABLE_ENTITY, e);
+    }
+
     // 将 DTO 转换为领域参数
     let search_query = SearchQuery {
         query: payload.query,
@@ -81,10 +86,10 @@ pub async fn search(
     // 通过内部代理隧道访问云元数据服务等内部资源。
     if let Some(ref config) = search_query.crawl_config {
         if let Some(ref proxy_url) = config.proxy {
-            if let Err(e) = validate_url(proxy_url).await {
+            if let Err(e) = validate_proxy_url(proxy_url).await {
                 log::warn!(
                     "SSRF via proxy blocked proxy={} team_id={} api_key_id={} error={}",
-                    proxy_url,
+                    redact_proxy_url(proxy_url),
                     team_id,
                     api_key_id,
                     e
@@ -525,6 +530,24 @@ mod tests {
                 proxy: None,
                 headers: None,
                 extraction_rules: None,
+                skip_nofollow_links: None,
+                ignore_robots: None,
+                ignore_sitemap: None,
+                limit: None,
+                max_duration_seconds: None,
+                previous_crawl_id: None,
+                allow_subdomains: None,
+                allow_external_domains: None,
+                ignore_query_params: None,
+                ignore_all_query_params: None,
+                max_query_variants_per_path: None,
+                download_assets: None,
+                allowed_content_types: None,
+                excluded_content_types: None,
+                result_destination: None,
+                keep_local_results: None,
+                formats: None,
+                resolve: None,
             }),
             crawl_results: None,
             sync_wait_ms: None,
@@ -576,6 +599,24 @@ mod tests {
                 proxy: Some("http://proxy:8080".to_string()),
                 headers: Some(serde_json::json!({"Accept": "text/html"})),
                 extraction_rules: None,
+                skip_nofollow_links: None,
+                ignore_robots: None,
+                ignore_sitemap: None,
+                limit: None,
+                max_duration_seconds: None,
+                previous_crawl_id: None,
+                allow_subdomains: None,
+                allow_external_domains: None,
+                ignore_query_params: None,
+                ignore_all_query_params: None,
+                max_query_variants_per_path: None,
+                download_assets: None,
+                allowed_content_types: None,
+                excluded_content_types: None,
+                result_destination: None,
+                keep_local_results: None,
+                formats: None,
+                resolve: None,
             }),
             crawl_results: None,
             sync_wait_ms: None,
@@ -916,6 +957,24 @@ mod tests {
                 proxy: None,
                 headers: None,
                 extraction_rules: Some(std::collections::HashMap::new()),
+                skip_nofollow_links: None,
+                ignore_robots: None,
+                ignore_sitemap: None,
+                limit: None,
+                max_duration_seconds: None,
+                previous_crawl_id: None,
+                allow_subdomains: None,
+                allow_external_domains: None,
+                ignore_query_params: None,
+                ignore_all_query_params: None,
+                max_query_variants_per_path: None,
+                download_assets: None,
+                allowed_content_types: None,
+                excluded_content_types: None,
+                result_destination: None,
+                keep_local_results: None,
+                formats: None,
+                resolve: None,
             }),
             crawl_results: None,
             sync_wait_ms: None,
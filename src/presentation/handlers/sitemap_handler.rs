@@ -0,0 +1,167 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! 站点地图解析接口
+//!
+//! 同步抓取站点地图并递归展开站点地图索引，返回带 lastmod / changefreq / priority 的
+//! URL 列表，可直接用于播种爬取。请求按一次抓取计费，文件数与 URL 数受请求上限约束。
+
+use crate::application::dto::sitemap_request::{SitemapRequestDto, SitemapResponseDto};
+use crate::domain::models::{CreditsTransactionType, UrlBlocklist};
+use crate::domain::services::pricing_service::PricingService;
+use crate::domain::services::rate_limiting_service::RateLimitingService;
+use crate::domain::services::url_blocklist_service::UrlBlocklistService;
+use crate::engines::engine_client::EngineClient;
+use crate::engines::router::EngineRouter;
+use crate::presentation::handlers::response_builder::{error_response, errors, success_response};
+use crate::presentation::helpers::blocklist_helper::check_url_blocklist;
+use crate::presentation::helpers::rate_limit_helper::check_rate_limit;
+use crate::presentation::helpers::ssrf::validate_url;
+use crate::presentation::middleware::auth_middleware::AuthState;
+use crate::utils::sitemap::{collect_sitemap, SitemapCollection};
+use axum::{
+    extract::{Extension, Json},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use log::error;
+use std::sync::Arc;
+use validator::Validate;
+
+/// 移除命中黑名单的条目，返回移除数
+fn remove_blocked(collection: &mut SitemapCollection, blocklist: &UrlBlocklist) -> usize {
+    let before = collection.urls.len();
+    collection
+        .urls
+        .retain(|url| !blocklist.is_blocked(&url.loc));
+    before - collection.urls.len()
+}
+
+/// 抓取并解析站点地图
+pub async fn parse_sitemap(
+    Extension(router): Extension<Arc<EngineRouter>>,
+    Extension(rate_limiting_service): Extension<Arc<dyn RateLimitingService>>,
+    Extension(pricing): Extension<Arc<PricingService>>,
+    Extension(url_blocklist): Extension<Arc<UrlBlocklistService>>,
+    Extension(auth_state): Extension<AuthState>,
+    Json(payload): Json<SitemapRequestDto>,
+) -> impl IntoResponse {
+    let team_id = auth_state.team_id;
+
+    if let Err(e) = payload.validate() {
+        return errors::unprocessable_entity(e.to_string());
+    }
+
+    if let Err(response) = check_rate_limit(
+        rate_limiting_service.as_ref(),
+        auth_state.api_key_id,
+        "/v1/sitemap",
+    )
+    .await
+    {
+        return response;
+    }
+
+    if let Err(e) = validate_url(&payload.url).await {
+        log::warn!(
+            "SSRF attack attempt blocked url={} team_id={} api_key_id={} error={}",
+            payload.url,
+            team_id,
+            auth_state.api_key_id,
+            e
+        );
+        return errors::bad_request(format!("SSRF protection: {}", e));
+    }
+
+    if let Err(response) =
+        check_url_blocklist(&url_blocklist, team_id, [payload.url.as_str()]).await
+    {
+        return response;
+    }
+    // 黑名单加载失败时拒绝请求，与 check_url_blocklist 一致
+    let blocklist = match url_blocklist.blocklist_for_team(team_id).await {
+        Ok(blocklist) => blocklist,
+        Err(e) => {
+            error!("Failed to load URL blocklist for team {}: {}", team_id, e);
+            return errors::internal_server_error("Failed to check URL blocklist");
+        }
+    };
+
+    if let Err(e) = rate_limiting_service
+        .check_and_deduct_quota(
+            team_id,
+            pricing.pricing_for(team_id).scrape,
+            CreditsTransactionType::Scrape,
+            format!("Sitemap URL: {}", payload.url),
+            None,
+        )
+        .await
+    {
+        error!("Quota check failed for team {}: {}", team_id, e);
+        return errors::payment_required(e.to_string());
+    }
+
+    let engine_client = EngineClient::with_router(router);
+    let mut collection = collect_sitemap(&engine_client, &payload.url, payload.limits()).await;
+    if let Some(error) = collection
+        .sitemaps
+        .first()
+        .and_then(|root| root.error.as_ref())
+    {
+        return error_response(
+            StatusCode::BAD_GATEWAY,
+            format!("Failed to fetch sitemap {}: {}", payload.url, error),
+        );
+    }
+    let blocked = remove_blocked(&mut collection, &blocklist);
+
+    success_response(
+        StatusCode::OK,
+        SitemapResponseDto {
+            url: payload.url,
+            urls: collection.urls,
+            sitemaps: collection.sitemaps,
+            truncated: collection.truncated,
+            blocked,
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::BlocklistEntry;
+    use crate::utils::sitemap::SitemapUrl;
+
+    fn url(loc: &str) -> SitemapUrl {
+        SitemapUrl {
+            loc: loc.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_remove_blocked_entries() {
+        let mut collection = SitemapCollection {
+            urls: vec![
+                url("https://example.com/a"),
+                url("https://example.com/private/b"),
+                url("https://example.com/c"),
+            ],
+            ..Default::default()
+        };
+        let blocklist = UrlBlocklist::new(vec![BlocklistEntry::from_setting(
+            r"regex:^https://example\.com/private/",
+        )]);
+
+        assert_eq!(remove_blocked(&mut collection, &blocklist), 1);
+        let locs: Vec<&str> = collection.urls.iter().map(|u| u.loc.as_str()).collect();
+        assert_eq!(locs, vec!["https://example.com/a", "https://example.com/c"]);
+        assert_eq!(
+            remove_blocked(&mut collection, &UrlBlocklist::new(Vec::new())),
+            0
+        );
+    }
+}
@@ -0,0 +1,162 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! OIDC 单点登录接口
+//!
+//! 登录与回调无需 API Key：登录重定向到 IdP，回调校验后返回绑定团队的会话令牌。
+//! 会话令牌以 `Authorization: Bearer` 使用，可通过注销接口提前失效。未启用 OIDC 时返回 404。
+
+use crate::domain::services::oidc_service::{OidcError, OidcService};
+use crate::presentation::handlers::response_builder::{error_response, errors, success_response};
+use crate::presentation::middleware::auth_middleware::AuthState;
+use axum::{
+    extract::{Extension, Query},
+    http::StatusCode,
+    response::{IntoResponse, Redirect, Response},
+};
+use log::{error, warn};
+use serde::Deserialize;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// 发起登录的查询参数
+#[derive(Debug, Deserialize)]
+pub struct OidcLoginQuery {
+    /// 登录后会话所属的团队；用户只属于一个团队时可省略
+    pub team_id: Option<Uuid>,
+}
+
+/// IdP 回调的查询参数
+#[derive(Debug, Deserialize)]
+pub struct OidcCallbackQuery {
+    /// 授权码
+    pub code: Option<String>,
+    /// 发起登录时生成的 state
+    pub state: Option<String>,
+    /// IdP 返回的错误码
+    pub error: Option<String>,
+    /// IdP 返回的错误描述
+    pub error_description: Option<String>,
+}
+
+fn oidc_disabled() -> Response {
+    errors::not_found("OIDC login is not enabled")
+}
+
+fn oidc_error_response(error: OidcError) -> Response {
+    match error {
+        OidcError::InvalidState | OidcError::InvalidIdToken(_) | OidcError::TeamRequired => {
+            errors::bad_request(error.to_string())
+        }
+        OidcError::EmailNotVerified | OidcError::NotMember => errors::forbidden(error.to_string()),
+        OidcError::Provider(_) => {
+            error!("OIDC provider request failed: {}", error);
+            error_response(StatusCode::BAD_GATEWAY, "Identity provider request failed")
+        }
+        OidcError::Repository(e) => {
+            error!("OIDC login repository error: {}", e);
+            errors::internal_server_error("Failed to complete OIDC login")
+        }
+    }
+}
+
+/// 发起登录，重定向到 IdP
+pub async fn oidc_login(
+    oidc: Option<Extension<Arc<OidcService>>>,
+    Query(query): Query<OidcLoginQuery>,
+) -> impl IntoResponse {
+    let Some(Extension(oidc)) = oidc else {
+        return oidc_disabled();
+    };
+
+    match oidc.begin_login(query.team_id).await {
+        Ok(authorization_url) => Redirect::to(&authorization_url).into_response(),
+        Err(e) => oidc_error_response(e),
+    }
+}
+
+/// IdP 回调，返回会话令牌
+pub async fn oidc_callback(
+    oidc: Option<Extension<Arc<OidcService>>>,
+    Query(query): Query<OidcCallbackQuery>,
+) -> impl IntoResponse {
+    let Some(Extension(oidc)) = oidc else {
+        return oidc_disabled();
+    };
+
+    if let Some(error) = query.error {
+        warn!(
+            "OIDC login rejected by identity provider: {} {}",
+            error,
+            query.error_description.as_deref().unwrap_or_default()
+        );
+        return errors::bad_request(format!("Identity provider returned error: {}", error));
+    }
+    let (Some(code), Some(state)) = (query.code, query.state) else {
+        return errors::bad_request("Missing code or state");
+    };
+
+    match oidc.complete_login(&code, &state).await {
+        Ok(session) => success_response(StatusCode::OK, session),
+        Err(e) => oidc_error_response(e),
+    }
+}
+
+/// 注销当前会话令牌
+pub async fn logout(
+    Extension(auth_state): Extension<AuthState>,
+    oidc: Option<Extension<Arc<OidcService>>>,
+) -> impl IntoResponse {
+    let Some(Extension(oidc)) = oidc else {
+        return oidc_disabled();
+    };
+
+    match oidc.logout(auth_state.api_key_id).await {
+        Ok(true) => StatusCode::NO_CONTENT.into_response(),
+        Ok(false) => errors::bad_request("Only SSO session tokens can be logged out"),
+        Err(e) => oidc_error_response(e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::test_helpers::create_test_db_pool;
+    use crate::domain::auth::ApiKeyScope;
+
+    #[tokio::test]
+    async fn test_sso_endpoints_return_not_found_when_disabled() {
+        let response = oidc_login(None, Query(OidcLoginQuery { team_id: None }))
+            .await
+            .into_response();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+        let auth_state = AuthState::new(
+            create_test_db_pool(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            ApiKeyScope::default(),
+        );
+        let response = logout(Extension(auth_state), None).await.into_response();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[test]
+    fn test_oidc_error_status_codes() {
+        let cases = [
+            (OidcError::InvalidState, StatusCode::BAD_REQUEST),
+            (OidcError::TeamRequired, StatusCode::BAD_REQUEST),
+            (OidcError::NotMember, StatusCode::FORBIDDEN),
+            (OidcError::EmailNotVerified, StatusCode::FORBIDDEN),
+            (
+                OidcError::Provider("timeout".to_string()),
+                StatusCode::BAD_GATEWAY,
+            ),
+        ];
+        for (error, status) in cases {
+            assert_eq!(oidc_error_response(error).status(), status);
+        }
+    }
+}
@@ -0,0 +1,489 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! 结果与爬取的标签和注释接口
+//!
+//! 客户端为爬取结果（`/v1/results/{id}/tags`）与爬取（`/v1/crawl/{id}/tags`）附加标签和
+//! 任意 JSON 注释，供下游审核流程记录 reviewed、broken、relevant 等状态。
+//! `GET /v1/crawl/{id}/results` 与结果检索接口可通过 `tags` 参数按标签过滤结果。
+//! 资源归属于调用方团队，其他团队的资源视为不存在。
+
+use crate::domain::models::{normalize_tag, ResourceTags, TaggedResource};
+use crate::domain::repositories::resource_tag_repository::ResourceTagRepository;
+use crate::presentation::handlers::response_builder::{errors, success_response};
+use crate::presentation::middleware::auth_middleware::AuthState;
+use axum::{
+    extract::{Extension, Path},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use log::error;
+use serde::Deserialize;
+use serde_json::{Map, Value};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// 添加标签与注释请求
+#[derive(Debug, Default, Deserialize)]
+pub struct AddTagsRequest {
+    /// 要添加的标签，已存在的标签被忽略
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// 要合并的注释，值为 `null` 的键被删除
+    pub annotations: Option<Map<String, Value>>,
+}
+
+/// 解析以逗号分隔的标签过滤参数，空参数返回空列表
+pub(crate) fn parse_tag_filter(raw: Option<&str>) -> Result<Vec<String>, String> {
+    let mut tags = Vec::new();
+    for tag in raw.unwrap_or_default().split(',') {
+        if tag.trim().is_empty() {
+            continue;
+        }
+        let tag = normalize_tag(tag)?;
+        if !tags.contains(&tag) {
+            tags.push(tag);
+        }
+    }
+    Ok(tags)
+}
+
+/// 资源的显示名，用于错误信息
+fn resource_name(resource_type: TaggedResource) -> &'static str {
+    match resource_type {
+        TaggedResource::Result => "Result",
+        TaggedResource::Crawl => "Crawl",
+    }
+}
+
+/// 查询调用方团队资源的标签，资源不存在或属于其他团队时返回 404
+async fn load_team_tags(
+    repo: &dyn ResourceTagRepository,
+    resource_type: TaggedResource,
+    resource_id: Uuid,
+    team_id: Uuid,
+) -> Result<ResourceTags, Response> {
+    match repo.resource_team(resource_type, resource_id).await {
+        Ok(Some(owner)) if owner == team_id => {}
+        Ok(_) => {
+            return Err(errors::not_found(format!(
+                "{} not found",
+                resource_name(resource_type)
+            )))
+        }
+        Err(e) => {
+            error!(
+                "Failed to load owner of {} {}: {}",
+                resource_type, resource_id, e
+            );
+            return Err(errors::internal_server_error("Failed to load tags"));
+        }
+    }
+
+    match repo.find(resource_type, resource_id).await {
+        Ok(Some(tags)) => Ok(tags),
+        Ok(None) => Ok(ResourceTags::new(resource_type, resource_id, team_id)),
+        Err(e) => {
+            error!(
+                "Failed to load tags of {} {}: {}",
+                resource_type, resource_id, e
+            );
+            Err(errors::internal_server_error("Failed to load tags"))
+        }
+    }
+}
+
+/// 保存标签，标签与注释都为空时删除记录
+async fn store_tags(repo: &dyn ResourceTagRepository, tags: ResourceTags) -> Response {
+    let (resource_type, resource_id) = (tags.resource_type, tags.resource_id);
+    let result = if tags.is_empty() {
+        repo.delete(resource_type, resource_id).await.map(|_| tags)
+    } else {
+        repo.save(&tags).await
+    };
+    match result {
+        Ok(tags) => success_response(StatusCode::OK, tags),
+        Err(e) => {
+            error!(
+                "Failed to save tags of {} {}: {}",
+                resource_type, resource_id, e
+            );
+            errors::internal_server_error("Failed to save tags")
+        }
+    }
+}
+
+async fn get_tags(
+    repo: &dyn ResourceTagRepository,
+    resource_type: TaggedResource,
+    resource_id: Uuid,
+    team_id: Uuid,
+) -> Response {
+    match load_team_tags(repo, resource_type, resource_id, team_id).await {
+        Ok(tags) => success_response(StatusCode::OK, tags),
+        Err(response) => response,
+    }
+}
+
+async fn add_tags(
+    repo: &dyn ResourceTagRepository,
+    resource_type: TaggedResource,
+    resource_id: Uuid,
+    team_id: Uuid,
+    payload: AddTagsRequest,
+) -> Response {
+    if payload.tags.is_empty() && payload.annotations.is_none() {
+        return errors::bad_request("Provide tags or annotations");
+    }
+    let mut tags = match load_team_tags(repo, resource_type, resource_id, team_id).await {
+        Ok(tags) => tags,
+        Err(response) => return response,
+    };
+    if let Err(e) = tags.add_tags(&payload.tags) {
+        return errors::bad_request(format!("Invalid tags: {}", e));
+    }
+    if let Some(annotations) = payload.annotations {
+        tags.merge_annotations(annotations);
+    }
+    if let Err(e) = tags.validate() {
+        return errors::bad_request(format!("Invalid tags: {}", e));
+    }
+    store_tags(repo, tags).await
+}
+
+async fn remove_tag(
+    repo: &dyn ResourceTagRepository,
+    resource_type: TaggedResource,
+    resource_id: Uuid,
+    team_id: Uuid,
+    tag: &str,
+) -> Response {
+    let mut tags = match load_team_tags(repo, resource_type, resource_id, team_id).await {
+        Ok(tags) => tags,
+        Err(response) => return response,
+    };
+    if !tags.remove_tag(tag) {
+        return errors::not_found("Tag not found");
+    }
+    store_tags(repo, tags).await
+}
+
+/// 查询结果的标签与注释
+pub async fn get_result_tags(
+    Extension(auth_state): Extension<AuthState>,
+    Extension(repo): Extension<Arc<dyn ResourceTagRepository>>,
+    Path(id): Path<Uuid>,
+) -> impl IntoResponse {
+    get_tags(
+        repo.as_ref(),
+        TaggedResource::Result,
+        id,
+        auth_state.team_id,
+    )
+    .await
+}
+
+/// 为结果添加标签并合并注释
+pub async fn add_result_tags(
+    Extension(auth_state): Extension<AuthState>,
+    Extension(repo): Extension<Arc<dyn ResourceTagRepository>>,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<AddTagsRequest>,
+) -> impl IntoResponse {
+    add_tags(
+        repo.as_ref(),
+        TaggedResource::Result,
+        id,
+        auth_state.team_id,
+        payload,
+    )
+    .await
+}
+
+/// 移除结果的一个标签
+pub async fn remove_result_tag(
+    Extension(auth_state): Extension<AuthState>,
+    Extension(repo): Extension<Arc<dyn ResourceTagRepository>>,
+    Path((id, tag)): Path<(Uuid, String)>,
+) -> impl IntoResponse {
+    remove_tag(
+        repo.as_ref(),
+        TaggedResource::Result,
+        id,
+        auth_state.team_id,
+        &tag,
+    )
+    .await
+}
+
+/// 查询爬取的标签与注释
+pub async fn get_crawl_tags(
+    Extension(auth_state): Extension<AuthState>,
+    Extension(repo): Extension<Arc<dyn ResourceTagRepository>>,
+    Path(id): Path<Uuid>,
+) -> impl IntoResponse {
+    get_tags(repo.as_ref(), TaggedResource::Crawl, id, auth_state.team_id).await
+}
+
+/// 为爬取添加标签并合并注释
+pub async fn add_crawl_tags(
+    Extension(auth_state): Extension<AuthState>,
+    Extension(repo): Extension<Arc<dyn ResourceTagRepository>>,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<AddTagsRequest>,
+) -> impl IntoResponse {
+    add_tags(
+        repo.as_ref(),
+        TaggedResource::Crawl,
+        id,
+        auth_state.team_id,
+        payload,
+    )
+    .await
+}
+
+/// 移除爬取的一个标签
+pub async fn remove_crawl_tag(
+    Extension(auth_state): Extension<AuthState>,
+    Extension(repo): Extension<Arc<dyn ResourceTagRepository>>,
+    Path((id, tag)): Path<(Uuid, String)>,
+) -> impl IntoResponse {
+    remove_tag(
+        repo.as_ref(),
+        TaggedResource::Crawl,
+        id,
+        auth_state.team_id,
+        &tag,
+    )
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::test_helpers::create_test_db_pool;
+    use crate::domain::auth::ApiKeyScope;
+    use crate::domain::repositories::task_repository::RepositoryError;
+    use async_trait::async_trait;
+    use serde_json::json;
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct MockTagRepo {
+        owners: HashMap<(TaggedResource, Uuid), Uuid>,
+        tags: Mutex<Vec<ResourceTags>>,
+    }
+
+    #[async_trait]
+    impl ResourceTagRepository for MockTagRepo {
+        async fn find(
+            &self,
+            resource_type: TaggedResource,
+            resource_id: Uuid,
+        ) -> Result<Option<ResourceTags>, RepositoryError> {
+            Ok(self
+                .tags
+                .lock()
+                .unwrap()
+                .iter()
+                .find(|t| t.resource_type == resource_type && t.resource_id == resource_id)
+                .cloned())
+        }
+        async fn find_many(
+            &self,
+            resource_type: TaggedResource,
+            resource_ids: &[Uuid],
+        ) -> Result<Vec<ResourceTags>, RepositoryError> {
+            Ok(self
+                .tags
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|t| {
+                    t.resource_type == resource_type && resource_ids.contains(&t.resource_id)
+                })
+                .cloned()
+                .collect())
+        }
+        async fn save(&self, tags: &ResourceTags) -> Result<ResourceTags, RepositoryError> {
+            let mut stored = self.tags.lock().unwrap();
+            stored.retain(|t| {
+                t.resource_type != tags.resource_type || t.resource_id != tags.resource_id
+            });
+            stored.push(tags.clone());
+            Ok(tags.clone())
+        }
+        async fn delete(
+            &self,
+            resource_type: TaggedResource,
+            resource_id: Uuid,
+        ) -> Result<bool, RepositoryError> {
+            let mut stored = self.tags.lock().unwrap();
+            let before = stored.len();
+            stored.retain(|t| t.resource_type != resource_type || t.resource_id != resource_id);
+            Ok(stored.len() != before)
+        }
+        async fn resource_team(
+            &self,
+            resource_type: TaggedResource,
+            resource_id: Uuid,
+        ) -> Result<Option<Uuid>, RepositoryError> {
+            Ok(self.owners.get(&(resource_type, resource_id)).copied())
+        }
+    }
+
+    fn auth_state(team_id: Uuid) -> AuthState {
+        AuthState::new(
+            create_test_db_pool(),
+            team_id,
+            Uuid::new_v4(),
+            ApiKeyScope::default(),
+        )
+    }
+
+    async fn body_json(response: Response) -> Value {
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        serde_json::from_slice(&body).unwrap()
+    }
+
+    #[test]
+    fn test_parse_tag_filter() {
+        assert_eq!(
+            parse_tag_filter(Some("Reviewed, relevant,,reviewed")).unwrap(),
+            vec!["reviewed", "relevant"]
+        );
+        assert!(parse_tag_filter(None).unwrap().is_empty());
+        assert!(parse_tag_filter(Some("needs review")).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_add_and_remove_result_tags() {
+        let team_id = Uuid::new_v4();
+        let result_id = Uuid::new_v4();
+        let mut mock = MockTagRepo::default();
+        mock.owners
+            .insert((TaggedResource::Result, result_id), team_id);
+        let repo: Arc<dyn ResourceTagRepository> = Arc::new(mock);
+
+        let payload: AddTagsRequest = serde_json::from_value(json!({
+            "tags": ["Reviewed", "relevant"],
+            "annotations": {"reviewer": "ana"}
+        }))
+        .unwrap();
+        let response = add_result_tags(
+            Extension(auth_state(team_id)),
+            Extension(repo.clone()),
+            Path(result_id),
+            Json(payload),
+        )
+        .await
+        .into_response();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = body_json(response).await;
+        assert_eq!(body["data"]["tags"], json!(["reviewed", "relevant"]));
+        assert_eq!(body["data"]["annotations"]["reviewer"], "ana");
+
+        let response = remove_result_tag(
+            Extension(auth_state(team_id)),
+            Extension(repo.clone()),
+            Path((result_id, "relevant".to_string())),
+        )
+        .await
+        .into_response();
+        assert_eq!(response.status(), StatusCode::OK);
+        let stored = repo
+            .find(TaggedResource::Result, result_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(stored.tags, vec!["reviewed"]);
+
+        let response = remove_result_tag(
+            Extension(auth_state(team_id)),
+            Extension(repo.clone()),
+            Path((result_id, "relevant".to_string())),
+        )
+        .await
+        .into_response();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_tags_of_other_teams_are_not_found() {
+        let crawl_id = Uuid::new_v4();
+        let mut mock = MockTagRepo::default();
+        mock.owners
+            .insert((TaggedResource::Crawl, crawl_id), Uuid::new_v4());
+        let repo: Arc<dyn ResourceTagRepository> = Arc::new(mock);
+
+        let response = get_crawl_tags(
+            Extension(auth_state(Uuid::new_v4())),
+            Extension(repo.clone()),
+            Path(crawl_id),
+        )
+        .await
+        .into_response();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+        let payload: AddTagsRequest = serde_json::from_value(json!({"tags": ["broken"]})).unwrap();
+        let response = add_crawl_tags(
+            Extension(auth_state(Uuid::new_v4())),
+            Extension(repo.clone()),
+            Path(crawl_id),
+            Json(payload),
+        )
+        .await
+        .into_response();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        assert!(repo
+            .find(TaggedResource::Crawl, crawl_id)
+            .await
+            .unwrap()
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn test_clearing_annotations_deletes_record() {
+        let team_id = Uuid::new_v4();
+        let crawl_id = Uuid::new_v4();
+        let mut mock = MockTagRepo::default();
+        mock.owners
+            .insert((TaggedResource::Crawl, crawl_id), team_id);
+        let repo: Arc<dyn ResourceTagRepository> = Arc::new(mock);
+
+        for annotations in [json!({"note": "check pagination"}), json!({"note": null})] {
+            let payload: AddTagsRequest =
+                serde_json::from_value(json!({ "annotations": annotations })).unwrap();
+            let response = add_crawl_tags(
+                Extension(auth_state(team_id)),
+                Extension(repo.clone()),
+                Path(crawl_id),
+                Json(payload),
+            )
+            .await
+            .into_response();
+            assert_eq!(response.status(), StatusCode::OK);
+        }
+        assert!(repo
+            .find(TaggedResource::Crawl, crawl_id)
+            .await
+            .unwrap()
+            .is_none());
+
+        let response = add_crawl_tags(
+            Extension(auth_state(team_id)),
+            Extension(repo.clone()),
+            Path(crawl_id),
+            Json(AddTagsRequest::default()),
+        )
+        .await
+        .into_response();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+}
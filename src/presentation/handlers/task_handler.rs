@@ -13,8 +13,9 @@ use crate::application::dto::task_query_request::{
 };
 use crate::common::constants::crawl_task;
 use crate::common::constants::server_config;
-use crate::domain::models::TaskStatus;
+use crate::domain::models::{TaskStatus, TaskTimeline};
 use crate::domain::repositories::scrape_result_repository::ScrapeResultRepository;
+use crate::domain::repositories::task_event_repository::TaskEventRepository;
 use crate::domain::repositories::task_repository::{TaskQueryParams, TaskRepository};
 use crate::infrastructure::repositories::scrape_result_repo_impl::ScrapeResultRepositoryImpl;
 use crate::presentation::errors::CrawlRsError;
@@ -22,7 +23,10 @@ use crate::presentation::handlers::extract_task_ids;
 use crate::presentation::handlers::response_builder::ApiResponse;
 use crate::presentation::middleware::auth_middleware::AuthState;
 use anyhow;
-use axum::{extract::Extension, Json};
+use axum::{
+    extract::{Extension, Path},
+    Json,
+};
 use chrono::{TimeZone, Utc};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
@@ -540,6 +544,27 @@ pub async fn cancel_tasks<T: TaskRepository>(
     })))
 }
 
+/// 任务执行时间线处理器
+///
+/// 按时间顺序返回任务的生命周期事件（入队 → 被 worker 加锁 → 各次引擎尝试及耗时 →
+/// 重试 → 结束），用于排查缓慢或卡住的任务。只能查询当前团队的任务。
+pub async fn get_task_timeline(
+    Extension(auth_state): Extension<AuthState>,
+    Extension(task_repo): Extension<Arc<dyn TaskRepository>>,
+    Extension(task_event_repo): Extension<Arc<dyn TaskEventRepository>>,
+    Path(task_id): Path<uuid::Uuid>,
+) -> Result<Json<ApiResponse<TaskTimeline>>, CrawlRsError> {
+    let task = task_repo
+        .find_by_id(task_id)
+        .await?
+        .filter(|task| task.team_id == auth_state.team_id)
+        .ok_or_else(|| CrawlRsError::NotFound(format!("Task {} not found", task_id)))?;
+
+    let events = task_event_repo.find_by_task_id(task.id).await?;
+
+    Ok(Json(ApiResponse::success(TaskTimeline::new(&task, events))))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1258,6 +1283,8 @@ mod tests {
             screenshot: None,
             response_time_ms: 150,
             created_at: chrono::Utc::now().naive_utc(),
+            etag: None,
+            last_modified: None,
         }
     }
 
@@ -1555,8 +1582,14 @@ mod tests {
             unreachable!("create not expected in task_handler tests")
         }
 
-        async fn find_by_id(&self, _id: Uuid) -> Result<Option<Task>, RepositoryError> {
-            Ok(None)
+        async fn find_by_id(&self, id: Uuid) -> Result<Option<Task>, RepositoryError> {
+            Ok(self
+                .query_tasks_data
+                .lock()
+                .unwrap()
+                .iter()
+                .find(|task| task.id == id)
+                .cloned())
         }
 
         async fn update(&self, _task: &Task) -> Result<Task, RepositoryError> {
@@ -2347,4 +2380,85 @@ mod tests {
 
         assert!(result.is_ok(), "should return Ok on timeout");
     }
+
+    // ========== get_task_timeline handler tests ==========
+
+    /// In-memory `TaskEventRepository` returning a fixed event list.
+    struct MockTaskEventRepository {
+        events: Vec<crate::domain::models::TaskEvent>,
+    }
+
+    #[async_trait]
+    impl TaskEventRepository for MockTaskEventRepository {
+        async fn record(
+            &self,
+            _event: &crate::domain::models::TaskEvent,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn find_by_task_id(
+            &self,
+            task_id: Uuid,
+        ) -> Result<Vec<crate::domain::models::TaskEvent>, RepositoryError> {
+            Ok(self
+                .events
+                .iter()
+                .filter(|event| event.task_id == task_id)
+                .cloned()
+                .collect())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_task_timeline_returns_events_for_own_task() {
+        use crate::domain::models::{TaskEvent, TaskEventType};
+
+        let auth = make_test_auth_state();
+        let mut task = make_test_task(Uuid::new_v4(), TaskStatus::Completed);
+        task.team_id = auth.team_id;
+        let task_repo: Arc<dyn TaskRepository> =
+            Arc::new(MockTaskRepository::with_query_data(vec![task.clone()], 1));
+        let event_repo: Arc<dyn TaskEventRepository> = Arc::new(MockTaskEventRepository {
+            events: vec![
+                TaskEvent::new(task.id, TaskEventType::Queued),
+                TaskEvent::new(task.id, TaskEventType::EngineAttempt).with_duration_ms(350),
+                TaskEvent::new(Uuid::new_v4(), TaskEventType::Queued),
+            ],
+        });
+
+        let response = get_task_timeline(
+            Extension(auth),
+            Extension(task_repo),
+            Extension(event_repo),
+            Path(task.id),
+        )
+        .await
+        .expect("timeline should be returned");
+
+        let timeline = response.data.as_ref().expect("response data");
+        assert_eq!(timeline.task_id, task.id);
+        assert_eq!(timeline.events.len(), 2);
+        assert_eq!(timeline.events[1].event.duration_ms, Some(350));
+    }
+
+    #[tokio::test]
+    async fn test_get_task_timeline_hides_other_team_task() {
+        let auth = make_test_auth_state();
+        let task = make_test_task(Uuid::new_v4(), TaskStatus::Completed);
+        let task_repo: Arc<dyn TaskRepository> =
+            Arc::new(MockTaskRepository::with_query_data(vec![task.clone()], 1));
+        let event_repo: Arc<dyn TaskEventRepository> =
+            Arc::new(MockTaskEventRepository { events: vec![] });
+
+        let result = get_task_timeline(
+            Extension(auth),
+            Extension(task_repo),
+            Extension(event_repo),
+            Path(task.id),
+        )
+        .await;
+
+        assert!(matches!(result, Err(CrawlRsError::NotFound(_))));
+    }
 }
@@ -6,16 +6,27 @@
 use crate::application::dto::geo_restriction_request::{
     TeamGeoRestrictionsResponse, UpdateTeamGeoRestrictionsRequest,
 };
+use crate::domain::models::{PlanLimits, TeamCapabilities, TeamPlan, TeamPlanAssignment};
 use crate::domain::repositories::credits_repository::CreditsRepository;
 use crate::domain::repositories::geo_restriction_repository::GeoRestrictionRepository;
 use crate::domain::repositories::scrape_result_repository::ScrapeResultRepository;
-use crate::domain::repositories::task_repository::TaskRepository;
+use crate::domain::repositories::task_repository::{RepositoryError, TaskRepository};
+use crate::domain::repositories::team_capability_repository::TeamCapabilityRepository;
+use crate::domain::repositories::team_plan_repository::TeamPlanRepository;
+use crate::domain::services::audit_service::AuditServiceTrait;
+use crate::domain::services::plan_service::PlanService;
 use crate::domain::services::team_service::TeamGeoRestrictions;
+use crate::presentation::extractors::role::RequireOperator;
 use crate::presentation::handlers::response_builder::{
-    error_codes, error_response_with_code, ApiResponse,
+    error_codes, error_response_with_code, errors, ApiResponse,
 };
 use crate::presentation::middleware::auth_middleware::AuthState;
-use axum::{extract::Extension, http::StatusCode, response::IntoResponse, Json};
+use axum::{
+    extract::{Extension, Path},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
 use log::error;
 use std::sync::Arc;
 use uuid::Uuid;
@@ -221,6 +232,181 @@ where
     }
 }
 
+/// 团队能力响应
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct TeamCapabilitiesResponse {
+    /// 团队 ID
+    pub team_id: Uuid,
+    /// 能力开关
+    #[serde(flatten)]
+    pub capabilities: TeamCapabilities,
+}
+
+/// 查询团队能力（仅运营方）
+pub async fn get_team_capabilities(
+    RequireOperator(_auth_state): RequireOperator,
+    Extension(repo): Extension<Arc<dyn TeamCapabilityRepository>>,
+    Path(team_id): Path<Uuid>,
+) -> impl IntoResponse {
+    match repo.get_capabilities(team_id).await {
+        Ok(capabilities) => Json(ApiResponse::success(TeamCapabilitiesResponse {
+            team_id,
+            capabilities,
+        }))
+        .into_response(),
+        Err(RepositoryError::NotFound) => errors::not_found("Team not found"),
+        Err(e) => {
+            error!("Failed to get team capabilities: {:?}", e);
+            errors::internal_server_error("Failed to get team capabilities")
+        }
+    }
+}
+
+/// 更新团队能力（仅运营方），变更写入审计日志
+pub async fn update_team_capabilities(
+    RequireOperator(auth_state): RequireOperator,
+    Extension(repo): Extension<Arc<dyn TeamCapabilityRepository>>,
+    audit_service: Option<Extension<Arc<dyn AuditServiceTrait>>>,
+    Path(team_id): Path<Uuid>,
+    Json(request): Json<TeamCapabilities>,
+) -> impl IntoResponse {
+    match repo.update_capabilities(team_id, &request).await {
+        Ok(capabilities) => {
+            log::warn!(
+                "Team {} capabilities updated by api_key_id={}: allow_ignore_robots={}",
+                team_id,
+                auth_state.api_key_id,
+                capabilities.allow_ignore_robots
+            );
+            if let Some(Extension(audit_service)) = audit_service {
+                if let Err(e) = audit_service
+                    .log_allow(
+                        "team.capabilities.update".to_string(),
+                        auth_state.api_key_id,
+                        team_id,
+                        auth_state.scope.clone(),
+                    )
+                    .await
+                {
+                    error!("Failed to audit team capabilities update: {}", e);
+                }
+            }
+            Json(ApiResponse::success(TeamCapabilitiesResponse {
+                team_id,
+                capabilities,
+            }))
+            .into_response()
+        }
+        Err(RepositoryError::NotFound) => errors::not_found("Team not found"),
+        Err(e) => {
+            error!("Failed to update team capabilities: {:?}", e);
+            errors::internal_server_error("Failed to update team capabilities")
+        }
+    }
+}
+
+/// 团队套餐响应
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct TeamPlanResponse {
+    /// 团队套餐与本月积分发放时间
+    #[serde(flatten)]
+    pub assignment: TeamPlanAssignment,
+    /// 套餐限制；未启用套餐功能时为空
+    pub limits: Option<PlanLimits>,
+}
+
+/// 更新团队套餐请求
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct UpdateTeamPlanRequest {
+    /// 新套餐
+    pub plan: TeamPlan,
+}
+
+fn team_plan_response(
+    assignment: TeamPlanAssignment,
+    plan_service: Option<&PlanService>,
+) -> TeamPlanResponse {
+    TeamPlanResponse {
+        limits: plan_service.map(|service| service.limits(assignment.plan)),
+        assignment,
+    }
+}
+
+/// 查询团队套餐（仅运营方）
+pub async fn get_team_plan(
+    RequireOperator(_auth_state): RequireOperator,
+    Extension(repo): Extension<Arc<dyn TeamPlanRepository>>,
+    plan_service: Option<Extension<Arc<PlanService>>>,
+    Path(team_id): Path<Uuid>,
+) -> impl IntoResponse {
+    match repo.get_plan(team_id).await {
+        Ok(assignment) => Json(ApiResponse::success(team_plan_response(
+            assignment,
+            plan_service
+                .as_ref()
+                .map(|Extension(service)| service.as_ref()),
+        )))
+        .into_response(),
+        Err(RepositoryError::NotFound) => errors::not_found("Team not found"),
+        Err(e) => {
+            error!("Failed to get team plan: {:?}", e);
+            errors::internal_server_error("Failed to get team plan")
+        }
+    }
+}
+
+/// 更新团队套餐（仅运营方），变更写入审计日志
+///
+/// 新套餐的限制在本实例立即生效，其他实例在套餐缓存过期后（约一分钟）生效；
+/// 月度积分在下一个自然月按新套餐发放。
+pub async fn update_team_plan(
+    RequireOperator(auth_state): RequireOperator,
+    Extension(repo): Extension<Arc<dyn TeamPlanRepository>>,
+    plan_service: Option<Extension<Arc<PlanService>>>,
+    audit_service: Option<Extension<Arc<dyn AuditServiceTrait>>>,
+    Path(team_id): Path<Uuid>,
+    Json(request): Json<UpdateTeamPlanRequest>,
+) -> impl IntoResponse {
+    match repo.set_plan(team_id, request.plan).await {
+        Ok(assignment) => {
+            log::warn!(
+                "Team {} plan changed to {} by api_key_id={}",
+                team_id,
+                assignment.plan,
+                auth_state.api_key_id
+            );
+            if let Some(Extension(plan_service)) = &plan_service {
+                plan_service.invalidate(team_id);
+            }
+            if let Some(Extension(audit_service)) = audit_service {
+                if let Err(e) = audit_service
+                    .log_allow(
+                        "team.plan.update".to_string(),
+                        auth_state.api_key_id,
+                        team_id,
+                        auth_state.scope.clone(),
+                    )
+                    .await
+                {
+                    error!("Failed to audit team plan update: {}", e);
+                }
+            }
+            Json(ApiResponse::success(team_plan_response(
+                assignment,
+                plan_service
+                    .as_ref()
+                    .map(|Extension(service)| service.as_ref()),
+            )))
+            .into_response()
+        }
+        Err(RepositoryError::NotFound) => errors::not_found("Team not found"),
+        Err(e) => {
+            error!("Failed to update team plan: {:?}", e);
+            errors::internal_server_error("Failed to update team plan")
+        }
+    }
+}
+
 /// 验证IP地址或CIDR表示法格式
 fn is_valid_ip_or_cidr(input: &str) -> bool {
     // 检查是否是有效的IP地址
@@ -1085,12 +1271,47 @@ mod tests {
             Ok(vec![])
         }
 
+        async fn find_latest_for_crawl_url(
+            &self,
+            _crawl_id: Uuid,
+            _url: &str,
+        ) -> anyhow::Result<Option<ScrapeResult>> {
+            Ok(None)
+        }
+
+        async fn count_not_modified(&self, _task_ids: &[Uuid]) -> anyhow::Result<u64> {
+            Ok(0)
+        }
+
+        async fn response_times(&self, _task_ids: &[Uuid]) -> anyhow::Result<Vec<i64>> {
+            Ok(Vec::new())
+        }
+
         async fn get_team_avg_response_time(&self, _team_id: Uuid) -> anyhow::Result<f64> {
             if self.should_fail {
                 return Err(anyhow::anyhow!("get_team_avg_response_time failed"));
             }
             Ok(self.avg_response_time)
         }
+
+        async fn delete_for_team(
+            &self,
+            _team_id: Uuid,
+            _filter: &crate::domain::repositories::scrape_result_repository::ResultDeletionFilter,
+        ) -> anyhow::Result<Vec<ScrapeResult>> {
+            Ok(vec![])
+        }
+
+        async fn search_crawl_results(
+            &self,
+            _crawl_id: Uuid,
+            _query: &str,
+            _limit: u64,
+        ) -> anyhow::Result<
+            Vec<crate::domain::repositories::scrape_result_repository::ResultSearchHit>,
+        > {
+            Ok(vec![])
+        }
     }
 
     // ========== MockGeoRestrictionRepository ==========
@@ -1803,4 +2024,354 @@ mod tests {
             "error message should mention invalid IP address"
         );
     }
+
+    // ========== Team capabilities ==========
+
+    /// Capability repo backed by a single optional team
+    struct MockCapabilityRepo {
+        team_id: Uuid,
+        capabilities: Mutex<TeamCapabilities>,
+    }
+
+    #[async_trait]
+    impl TeamCapabilityRepository for MockCapabilityRepo {
+        async fn get_capabilities(
+            &self,
+            team_id: Uuid,
+        ) -> Result<TeamCapabilities, RepositoryError> {
+            if team_id != self.team_id {
+                return Err(RepositoryError::NotFound);
+            }
+            Ok(*self.capabilities.lock().unwrap())
+        }
+
+        async fn update_capabilities(
+            &self,
+            team_id: Uuid,
+            capabilities: &TeamCapabilities,
+        ) -> Result<TeamCapabilities, RepositoryError> {
+            if team_id != self.team_id {
+                return Err(RepositoryError::NotFound);
+            }
+            *self.capabilities.lock().unwrap() = *capabilities;
+            Ok(*capabilities)
+        }
+    }
+
+    fn make_capability_repo(team_id: Uuid) -> Arc<dyn TeamCapabilityRepository> {
+        Arc::new(MockCapabilityRepo {
+            team_id,
+            capabilities: Mutex::new(TeamCapabilities::default()),
+        })
+    }
+
+    fn make_admin_auth_state() -> AuthState {
+        AuthState::new(
+            make_test_db_pool(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            ApiKeyScope::full_access(),
+        )
+    }
+
+    const TEST_OPERATOR_TOKEN: &str = "test-operator-token";
+
+    /// 为运营接口路由配置运营方凭据，并模拟鉴权中间件注入调用方的 AuthState
+    fn with_operator_layers(router: axum::Router, auth_state: AuthState) -> axum::Router {
+        let mut settings = crate::config::settings::Settings::default();
+        settings.server.operator_token = Some(TEST_OPERATOR_TOKEN.to_string());
+        router
+            .layer(Extension(Arc::new(settings)))
+            .layer(Extension(auth_state))
+    }
+
+    fn capability_router(
+        auth_state: AuthState,
+        repo: Arc<dyn TeamCapabilityRepository>,
+    ) -> axum::Router {
+        let router = axum::Router::new()
+            .route(
+                "/v1/teams/{id}/capabilities",
+                axum::routing::get(get_team_capabilities).put(update_team_capabilities),
+            )
+            .layer(Extension(repo));
+        with_operator_layers(router, auth_state)
+    }
+
+    fn operator_request(
+        method: &str,
+        uri: String,
+        operator_token: Option<&str>,
+        body: Option<&'static str>,
+    ) -> axum::http::Request<axum::body::Body> {
+        let mut builder = axum::http::Request::builder()
+            .method(method)
+            .uri(uri)
+            .header("content-type", "application/json");
+        if let Some(token) = operator_token {
+            builder = builder.header(
+                crate::presentation::extractors::role::OPERATOR_TOKEN_HEADER,
+                token,
+            );
+        }
+        let body = body.map_or_else(axum::body::Body::empty, axum::body::Body::from);
+        builder.body(body).unwrap()
+    }
+
+    fn capability_request(
+        method: &str,
+        team_id: Uuid,
+        operator_token: Option<&str>,
+    ) -> axum::http::Request<axum::body::Body> {
+        let body = (method == "PUT").then_some(r#"{"allow_ignore_robots":true}"#);
+        operator_request(
+            method,
+            format!("/v1/teams/{}/capabilities", team_id),
+            operator_token,
+            body,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_team_capabilities_reject_team_admin_key() {
+        use tower::ServiceExt;
+
+        let team_id = Uuid::new_v4();
+        let repo = make_capability_repo(team_id);
+        // 团队自己的 Admin Key 不能调整任何团队（包括本团队）的能力
+        let mut admin = make_admin_auth_state();
+        admin.team_id = team_id;
+
+        for method in ["GET", "PUT"] {
+            let response = capability_router(admin.clone(), repo.clone())
+                .oneshot(capability_request(method, team_id, None))
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        }
+        assert!(
+            !repo
+                .get_capabilities(team_id)
+                .await
+                .unwrap()
+                .allow_ignore_robots
+        );
+
+        let response = capability_router(admin, repo.clone())
+            .oneshot(capability_request(
+                "PUT",
+                team_id,
+                Some(TEST_OPERATOR_TOKEN),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert!(
+            repo.get_capabilities(team_id)
+                .await
+                .unwrap()
+                .allow_ignore_robots
+        );
+    }
+
+    #[tokio::test]
+    async fn test_operator_updates_team_capabilities() {
+        let team_id = Uuid::new_v4();
+        let repo = make_capability_repo(team_id);
+        let admin = make_admin_auth_state();
+
+        let response = update_team_capabilities(
+            RequireOperator(admin.clone()),
+            Extension(repo.clone()),
+            None,
+            Path(team_id),
+            Json(TeamCapabilities {
+                allow_ignore_robots: true,
+            }),
+        )
+        .await
+        .into_response();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let response = get_team_capabilities(
+            RequireOperator(admin.clone()),
+            Extension(repo.clone()),
+            Path(team_id),
+        )
+        .await
+        .into_response();
+        assert_eq!(response.status(), StatusCode::OK);
+        let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(json["data"]["team_id"], team_id.to_string());
+        assert_eq!(json["data"]["allow_ignore_robots"], true);
+
+        let response = get_team_capabilities(
+            RequireOperator(admin),
+            Extension(repo),
+            Path(Uuid::new_v4()),
+        )
+        .await
+        .into_response();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    // ========== Team plans ==========
+
+    /// Plan repo backed by a single team
+    struct MockPlanRepo {
+        assignment: Mutex<TeamPlanAssignment>,
+    }
+
+    #[async_trait]
+    impl TeamPlanRepository for MockPlanRepo {
+        async fn get_plan(&self, team_id: Uuid) -> Result<TeamPlanAssignment, RepositoryError> {
+            let assignment = *self.assignment.lock().unwrap();
+            if team_id != assignment.team_id {
+                return Err(RepositoryError::NotFound);
+            }
+            Ok(assignment)
+        }
+
+        async fn set_plan(
+            &self,
+            team_id: Uuid,
+            plan: TeamPlan,
+        ) -> Result<TeamPlanAssignment, RepositoryError> {
+            let mut assignment = self.assignment.lock().unwrap();
+            if team_id != assignment.team_id {
+                return Err(RepositoryError::NotFound);
+            }
+            assignment.plan = plan;
+            Ok(*assignment)
+        }
+
+        async fn find_due_for_credit_grant(
+            &self,
+            _period_start: chrono::DateTime<chrono::Utc>,
+            _limit: u64,
+        ) -> Result<Vec<TeamPlanAssignment>, RepositoryError> {
+            Ok(Vec::new())
+        }
+
+        async fn claim_credit_grant(
+            &self,
+            _team_id: Uuid,
+            _period_start: chrono::DateTime<chrono::Utc>,
+            _granted_at: chrono::DateTime<chrono::Utc>,
+        ) -> Result<bool, RepositoryError> {
+            Ok(false)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_operator_updates_team_plan() {
+        let team_id = Uuid::new_v4();
+        let repo: Arc<dyn TeamPlanRepository> = Arc::new(MockPlanRepo {
+            assignment: Mutex::new(TeamPlanAssignment {
+                team_id,
+                plan: TeamPlan::Free,
+                credits_granted_at: None,
+            }),
+        });
+        let plan_service = Arc::new(PlanService::from_settings(
+            repo.clone(),
+            &crate::config::settings::PlanSettings::default(),
+        ));
+        assert_eq!(plan_service.plan_for(team_id).await, TeamPlan::Free);
+
+        let response = update_team_plan(
+            RequireOperator(make_admin_auth_state()),
+            Extension(repo.clone()),
+            Some(Extension(plan_service.clone())),
+            None,
+            Path(team_id),
+            Json(UpdateTeamPlanRequest {
+                plan: TeamPlan::Pro,
+            }),
+        )
+        .await
+        .into_response();
+        assert_eq!(response.status(), StatusCode::OK);
+        let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(json["data"]["team_id"], team_id.to_string());
+        assert_eq!(json["data"]["plan"], "pro");
+        assert_eq!(
+            json["data"]["limits"]["max_concurrency"],
+            plan_service.limits(TeamPlan::Pro).max_concurrency
+        );
+        // 缓存已失效，新套餐立即生效
+        assert_eq!(plan_service.plan_for(team_id).await, TeamPlan::Pro);
+
+        let response = get_team_plan(
+            RequireOperator(make_admin_auth_state()),
+            Extension(repo),
+            None,
+            Path(Uuid::new_v4()),
+        )
+        .await
+        .into_response();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_team_plan_rejects_team_admin_key() {
+        use tower::ServiceExt;
+
+        let team_id = Uuid::new_v4();
+        let repo: Arc<dyn TeamPlanRepository> = Arc::new(MockPlanRepo {
+            assignment: Mutex::new(TeamPlanAssignment {
+                team_id,
+                plan: TeamPlan::Free,
+                credits_granted_at: None,
+            }),
+        });
+        let router = |auth_state: AuthState| {
+            let router = axum::Router::new()
+                .route(
+                    "/v1/teams/{id}/plan",
+                    axum::routing::get(get_team_plan).put(update_team_plan),
+                )
+                .layer(Extension(repo.clone()));
+            with_operator_layers(router, auth_state)
+        };
+        // 团队自己的 Admin Key 不能给本团队升级套餐
+        let mut admin = make_admin_auth_state();
+        admin.team_id = team_id;
+        let uri = format!("/v1/teams/{}/plan", team_id);
+
+        let response = router(admin.clone())
+            .oneshot(operator_request("GET", uri.clone(), None, None))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+        let response = router(admin.clone())
+            .oneshot(operator_request(
+                "PUT",
+                uri.clone(),
+                None,
+                Some(r#"{"plan":"enterprise"}"#),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        assert_eq!(repo.get_plan(team_id).await.unwrap().plan, TeamPlan::Free);
+
+        let response = router(admin)
+            .oneshot(operator_request(
+                "PUT",
+                uri,
+                Some(TEST_OPERATOR_TOKEN),
+                Some(r#"{"plan":"enterprise"}"#),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            repo.get_plan(team_id).await.unwrap().plan,
+            TeamPlan::Enterprise
+        );
+    }
 }
@@ -0,0 +1,648 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! 团队与成员管理接口
+//!
+//! 创建团队仅 Admin 权限可访问；成员列表对本团队的 API Key 开放，邀请与移除成员需要
+//! 本团队 API Key 的 Write 权限，涉及 owner / admin 角色时需要 Admin 权限。
+//! 管理其他团队的成员需要运营方凭据。
+
+use crate::domain::auth::ScopePermission;
+use crate::domain::models::{Team, TeamMember, TeamRole};
+use crate::domain::services::audit_service::AuditServiceTrait;
+use crate::domain::services::team_membership_service::{
+    TeamMembershipError, TeamMembershipService,
+};
+use crate::presentation::extractors::role::OperatorCredential;
+use crate::presentation::handlers::response_builder::{error_response, errors, success_response};
+use crate::presentation::middleware::auth_middleware::AuthState;
+use axum::{
+    extract::{Extension, Path},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use log::error;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// 创建团队请求
+#[derive(Debug, Deserialize)]
+pub struct CreateTeamRequest {
+    /// 团队名称
+    pub name: String,
+    /// 团队 owner 的邮箱，直接成为 active 成员
+    pub owner_email: Option<String>,
+}
+
+/// 创建团队响应
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateTeamResponse {
+    /// 新团队
+    pub team: Team,
+    /// 团队 owner
+    pub owner: Option<TeamMember>,
+}
+
+/// 邀请成员请求
+#[derive(Debug, Deserialize)]
+pub struct InviteMemberRequest {
+    /// 被邀请人邮箱
+    pub email: String,
+    /// 成员角色，默认 `member`
+    #[serde(default)]
+    pub role: TeamRole,
+}
+
+/// 邀请成员响应
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InviteMemberResponse {
+    /// 待接受的成员
+    pub member: TeamMember,
+    /// 邀请令牌，只返回这一次，由调用方发送给被邀请人
+    pub invitation_token: String,
+}
+
+/// 成员列表响应
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TeamMembersResponse {
+    /// 团队 ID
+    pub team_id: Uuid,
+    /// 成员（含待接受的邀请）
+    pub members: Vec<TeamMember>,
+}
+
+/// 接受邀请请求
+#[derive(Debug, Deserialize)]
+pub struct AcceptInvitationRequest {
+    /// 邀请令牌
+    pub token: String,
+}
+
+/// 校验调用方可访问该团队：运营方可访问任意团队，否则只能以所需权限访问 API Key 所属团队
+fn require_team_access(
+    auth_state: &AuthState,
+    is_operator: bool,
+    team_id: Uuid,
+    permission: ScopePermission,
+) -> Result<(), Box<Response>> {
+    if is_operator || (auth_state.team_id == team_id && auth_state.scope.has_permission(permission))
+    {
+        Ok(())
+    } else {
+        Err(Box::new(errors::forbidden(
+            "Not allowed to manage members of this team",
+        )))
+    }
+}
+
+/// 管理该角色的成员所需的权限：owner / admin 需要 Admin，普通成员需要 Write
+fn permission_for_role(role: TeamRole) -> ScopePermission {
+    if role.manages_members() {
+        ScopePermission::Admin
+    } else {
+        ScopePermission::Write
+    }
+}
+
+fn membership_error_response(error: TeamMembershipError) -> Response {
+    match error {
+        TeamMembershipError::InvalidTeamName(_) | TeamMembershipError::InvalidEmail(_) => {
+            errors::bad_request(error.to_string())
+        }
+        TeamMembershipError::TeamNotFound
+        | TeamMembershipError::MemberNotFound
+        | TeamMembershipError::InvitationNotFound => errors::not_found(error.to_string()),
+        TeamMembershipError::AlreadyMember | TeamMembershipError::LastOwner => {
+            error_response(StatusCode::CONFLICT, error.to_string())
+        }
+        TeamMembershipError::Repository(e) => {
+            error!("Team membership repository error: {}", e);
+            errors::internal_server_error("Failed to manage team members")
+        }
+    }
+}
+
+/// 记录成员变更的审计日志
+async fn audit(
+    audit_service: Option<Extension<Arc<dyn AuditServiceTrait>>>,
+    action: &str,
+    auth_state: &AuthState,
+    team_id: Uuid,
+) {
+    if let Some(Extension(audit_service)) = audit_service {
+        if let Err(e) = audit_service
+            .log_allow(
+                action.to_string(),
+                auth_state.api_key_id,
+                team_id,
+                auth_state.scope.clone(),
+            )
+            .await
+        {
+            error!("Failed to audit {}: {}", action, e);
+        }
+    }
+}
+
+/// 创建团队（仅 Admin）
+pub async fn create_team(
+    Extension(auth_state): Extension<AuthState>,
+    Extension(service): Extension<Arc<TeamMembershipService>>,
+    audit_service: Option<Extension<Arc<dyn AuditServiceTrait>>>,
+    Json(request): Json<CreateTeamRequest>,
+) -> impl IntoResponse {
+    if !auth_state.scope.has_permission(ScopePermission::Admin) {
+        return errors::forbidden("Admin permission required to create teams");
+    }
+
+    match service
+        .create_team(&request.name, request.owner_email.as_deref())
+        .await
+    {
+        Ok((team, owner)) => {
+            audit(audit_service, "team.create", &auth_state, team.id).await;
+            success_response(StatusCode::CREATED, CreateTeamResponse { team, owner })
+        }
+        Err(e) => membership_error_response(e),
+    }
+}
+
+/// 列出团队成员
+pub async fn list_team_members(
+    Extension(auth_state): Extension<AuthState>,
+    Extension(service): Extension<Arc<TeamMembershipService>>,
+    OperatorCredential(is_operator): OperatorCredential,
+    Path(team_id): Path<Uuid>,
+) -> impl IntoResponse {
+    if let Err(response) =
+        require_team_access(&auth_state, is_operator, team_id, ScopePermission::Read)
+    {
+        return *response;
+    }
+
+    match service.list_members(team_id).await {
+        Ok(members) => success_response(StatusCode::OK, TeamMembersResponse { team_id, members }),
+        Err(e) => membership_error_response(e),
+    }
+}
+
+/// 按邮箱邀请成员，邀请 owner / admin 需要 Admin 权限
+pub async fn invite_team_member(
+    Extension(auth_state): Extension<AuthState>,
+    Extension(service): Extension<Arc<TeamMembershipService>>,
+    audit_service: Option<Extension<Arc<dyn AuditServiceTrait>>>,
+    OperatorCredential(is_operator): OperatorCredential,
+    Path(team_id): Path<Uuid>,
+    Json(request): Json<InviteMemberRequest>,
+) -> impl IntoResponse {
+    if let Err(response) = require_team_access(
+        &auth_state,
+        is_operator,
+        team_id,
+        permission_for_role(request.role),
+    ) {
+        return *response;
+    }
+
+    match service
+        .invite_member(team_id, &request.email, request.role)
+        .await
+    {
+        Ok(invitation) => {
+            audit(audit_service, "team.member.invite", &auth_state, team_id).await;
+            success_response(
+                StatusCode::CREATED,
+                InviteMemberResponse {
+                    member: invitation.member,
+                    invitation_token: invitation.token,
+                },
+            )
+        }
+        Err(e) => membership_error_response(e),
+    }
+}
+
+/// 移除成员或撤回邀请，移除 owner / admin 需要 Admin 权限
+pub async fn remove_team_member(
+    Extension(auth_state): Extension<AuthState>,
+    Extension(service): Extension<Arc<TeamMembershipService>>,
+    audit_service: Option<Extension<Arc<dyn AuditServiceTrait>>>,
+    OperatorCredential(is_operator): OperatorCredential,
+    Path((team_id, user_id)): Path<(Uuid, Uuid)>,
+) -> impl IntoResponse {
+    if let Err(response) =
+        require_team_access(&auth_state, is_operator, team_id, ScopePermission::Write)
+    {
+        return *response;
+    }
+    // 先确认调用方可访问该团队，再按被移除成员的角色校验权限，避免泄露其他团队的成员
+    let member = match service.find_member(team_id, user_id).await {
+        Ok(member) => member,
+        Err(e) => return membership_error_response(e),
+    };
+    if let Err(response) = require_team_access(
+        &auth_state,
+        is_operator,
+        team_id,
+        permission_for_role(member.role),
+    ) {
+        return *response;
+    }
+
+    match service.remove_member(team_id, user_id).await {
+        Ok(()) => {
+            audit(audit_service, "team.member.remove", &auth_state, team_id).await;
+            StatusCode::NO_CONTENT.into_response()
+        }
+        Err(e) => membership_error_response(e),
+    }
+}
+
+/// 接受邀请，令牌本身即凭证
+pub async fn accept_invitation(
+    Extension(service): Extension<Arc<TeamMembershipService>>,
+    Json(request): Json<AcceptInvitationRequest>,
+) -> impl IntoResponse {
+    match service.accept_invitation(&request.token).await {
+        Ok(member) => success_response(StatusCode::OK, member),
+        Err(e) => membership_error_response(e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::test_helpers::create_test_db_pool;
+    use crate::domain::auth::ApiKeyScope;
+    use crate::domain::models::User;
+    use crate::domain::repositories::task_repository::RepositoryError;
+    use crate::domain::repositories::team_member_repository::TeamMemberRepository;
+    use crate::domain::repositories::team_repository::TeamRepository;
+    use async_trait::async_trait;
+    use axum::body::to_bytes;
+    use chrono::Utc;
+    use std::sync::Mutex;
+
+    /// Team repository where every team exists
+    struct AnyTeam;
+
+    #[async_trait]
+    impl TeamRepository for AnyTeam {
+        async fn create(&self, team: &Team) -> Result<Team, RepositoryError> {
+            Ok(team.clone())
+        }
+
+        async fn find_by_id(&self, id: Uuid) -> Result<Option<Team>, RepositoryError> {
+            Ok(Some(Team::new(id, "Team".to_string())))
+        }
+    }
+
+    #[derive(Default)]
+    struct MockMemberRepo {
+        members: Mutex<Vec<TeamMember>>,
+    }
+
+    #[async_trait]
+    impl TeamMemberRepository for MockMemberRepo {
+        async fn find_or_create_user(&self, email: &str) -> Result<User, RepositoryError> {
+            Ok(User {
+                id: Uuid::new_v4(),
+                email: email.to_string(),
+                name: None,
+                created_at: Utc::now(),
+            })
+        }
+
+        async fn add_member(
+            &self,
+            member: &TeamMember,
+            _invitation_token_hash: Option<&str>,
+        ) -> Result<(), RepositoryError> {
+            self.members.lock().unwrap().push(member.clone());
+            Ok(())
+        }
+
+        async fn find_member(
+            &self,
+            team_id: Uuid,
+            user_id: Uuid,
+        ) -> Result<Option<TeamMember>, RepositoryError> {
+            Ok(self
+                .members
+                .lock()
+                .unwrap()
+                .iter()
+                .find(|m| m.team_id == team_id && m.user_id == user_id)
+                .cloned())
+        }
+
+        async fn list_members(&self, team_id: Uuid) -> Result<Vec<TeamMember>, RepositoryError> {
+            Ok(self
+                .members
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|m| m.team_id == team_id)
+                .cloned()
+                .collect())
+        }
+
+        async fn list_memberships_by_email(
+            &self,
+            email: &str,
+        ) -> Result<Vec<TeamMember>, RepositoryError> {
+            Ok(self
+                .members
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|m| m.email == email)
+                .cloned()
+                .collect())
+        }
+
+        async fn remove_member(
+            &self,
+            team_id: Uuid,
+            user_id: Uuid,
+        ) -> Result<bool, RepositoryError> {
+            let mut members = self.members.lock().unwrap();
+            let before = members.len();
+            members.retain(|m| !(m.team_id == team_id && m.user_id == user_id));
+            Ok(members.len() != before)
+        }
+
+        async fn accept_invitation(
+            &self,
+            _invitation_token_hash: &str,
+        ) -> Result<Option<TeamMember>, RepositoryError> {
+            Ok(None)
+        }
+    }
+
+    fn make_service() -> Arc<TeamMembershipService> {
+        Arc::new(TeamMembershipService::new(
+            Arc::new(AnyTeam),
+            Arc::new(MockMemberRepo::default()),
+        ))
+    }
+
+    fn auth_state(team_id: Uuid, scope: ApiKeyScope) -> AuthState {
+        AuthState::new(create_test_db_pool(), team_id, Uuid::new_v4(), scope)
+    }
+
+    fn write_scope() -> ApiKeyScope {
+        ApiKeyScope {
+            write: true,
+            ..ApiKeyScope::default()
+        }
+    }
+
+    fn invite(email: &str) -> Json<InviteMemberRequest> {
+        invite_as(email, TeamRole::Member)
+    }
+
+    fn invite_as(email: &str, role: TeamRole) -> Json<InviteMemberRequest> {
+        Json(InviteMemberRequest {
+            email: email.to_string(),
+            role,
+        })
+    }
+
+    #[tokio::test]
+    async fn test_create_team_requires_admin() {
+        let response = create_team(
+            Extension(auth_state(Uuid::new_v4(), write_scope())),
+            Extension(make_service()),
+            None,
+            Json(CreateTeamRequest {
+                name: "Acme".to_string(),
+                owner_email: None,
+            }),
+        )
+        .await
+        .into_response();
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+        let response = create_team(
+            Extension(auth_state(Uuid::new_v4(), ApiKeyScope::full_access())),
+            Extension(make_service()),
+            None,
+            Json(CreateTeamRequest {
+                name: "Acme".to_string(),
+                owner_email: Some("owner@acme.io".to_string()),
+            }),
+        )
+        .await
+        .into_response();
+        assert_eq!(response.status(), StatusCode::CREATED);
+        let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(json["data"]["team"]["name"], "Acme");
+        assert_eq!(json["data"]["owner"]["role"], "owner");
+        assert_eq!(json["data"]["owner"]["status"], "active");
+    }
+
+    #[tokio::test]
+    async fn test_team_key_manages_only_its_own_members() {
+        let service = make_service();
+        let team_id = Uuid::new_v4();
+
+        let response = invite_team_member(
+            Extension(auth_state(Uuid::new_v4(), write_scope())),
+            Extension(service.clone()),
+            None,
+            OperatorCredential(false),
+            Path(team_id),
+            invite("dev@acme.io"),
+        )
+        .await
+        .into_response();
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+        let response = invite_team_member(
+            Extension(auth_state(team_id, write_scope())),
+            Extension(service.clone()),
+            None,
+            OperatorCredential(false),
+            Path(team_id),
+            invite("Dev@Acme.io"),
+        )
+        .await
+        .into_response();
+        assert_eq!(response.status(), StatusCode::CREATED);
+        let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(json["data"]["member"]["email"], "dev@acme.io");
+        assert_eq!(json["data"]["member"]["status"], "invited");
+        assert_eq!(json["data"]["invitation_token"].as_str().unwrap().len(), 64);
+        let user_id: Uuid = json["data"]["member"]["user_id"]
+            .as_str()
+            .unwrap()
+            .parse()
+            .unwrap();
+
+        let response = invite_team_member(
+            Extension(auth_state(team_id, write_scope())),
+            Extension(service.clone()),
+            None,
+            OperatorCredential(false),
+            Path(team_id),
+            invite("not-an-email"),
+        )
+        .await
+        .into_response();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        let response = list_team_members(
+            Extension(auth_state(team_id, ApiKeyScope::default())),
+            Extension(service.clone()),
+            OperatorCredential(false),
+            Path(team_id),
+        )
+        .await
+        .into_response();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let response = remove_team_member(
+            Extension(auth_state(team_id, ApiKeyScope::default())),
+            Extension(service.clone()),
+            None,
+            OperatorCredential(false),
+            Path((team_id, user_id)),
+        )
+        .await
+        .into_response();
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+        let response = remove_team_member(
+            Extension(auth_state(team_id, write_scope())),
+            Extension(service.clone()),
+            None,
+            OperatorCredential(false),
+            Path((team_id, user_id)),
+        )
+        .await
+        .into_response();
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+        assert!(service.list_members(team_id).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_accept_unknown_invitation_is_not_found() {
+        let response = accept_invitation(
+            Extension(make_service()),
+            Json(AcceptInvitationRequest {
+                token: "unknown".to_string(),
+            }),
+        )
+        .await
+        .into_response();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_admin_scope_of_another_team_is_forbidden() {
+        let service = make_service();
+        let team_id = Uuid::new_v4();
+        let other_admin = auth_state(Uuid::new_v4(), ApiKeyScope::full_access());
+
+        let response = list_team_members(
+            Extension(other_admin.clone()),
+            Extension(service.clone()),
+            OperatorCredential(false),
+            Path(team_id),
+        )
+        .await
+        .into_response();
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+        let response = invite_team_member(
+            Extension(other_admin.clone()),
+            Extension(service.clone()),
+            None,
+            OperatorCredential(false),
+            Path(team_id),
+            invite_as("intruder@evil.io", TeamRole::Owner),
+        )
+        .await
+        .into_response();
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        assert!(service.list_members(team_id).await.unwrap().is_empty());
+
+        // 运营方可以管理任意团队
+        let response = invite_team_member(
+            Extension(other_admin),
+            Extension(service.clone()),
+            None,
+            OperatorCredential(true),
+            Path(team_id),
+            invite_as("owner@acme.io", TeamRole::Owner),
+        )
+        .await
+        .into_response();
+        assert_eq!(response.status(), StatusCode::CREATED);
+    }
+
+    #[tokio::test]
+    async fn test_privileged_roles_require_admin_scope() {
+        let service = make_service();
+        let team_id = Uuid::new_v4();
+
+        // Write 权限不能邀请 owner / admin
+        for role in [TeamRole::Owner, TeamRole::Admin] {
+            let response = invite_team_member(
+                Extension(auth_state(team_id, write_scope())),
+                Extension(service.clone()),
+                None,
+                OperatorCredential(false),
+                Path(team_id),
+                invite_as("boss@acme.io", role),
+            )
+            .await
+            .into_response();
+            assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        }
+        assert!(service.list_members(team_id).await.unwrap().is_empty());
+
+        let response = invite_team_member(
+            Extension(auth_state(team_id, ApiKeyScope::full_access())),
+            Extension(service.clone()),
+            None,
+            OperatorCredential(false),
+            Path(team_id),
+            invite_as("boss@acme.io", TeamRole::Admin),
+        )
+        .await
+        .into_response();
+        assert_eq!(response.status(), StatusCode::CREATED);
+        let user_id = service.list_members(team_id).await.unwrap()[0].user_id;
+
+        // Write 权限也不能移除 admin
+        let response = remove_team_member(
+            Extension(auth_state(team_id, write_scope())),
+            Extension(service.clone()),
+            None,
+            OperatorCredential(false),
+            Path((team_id, user_id)),
+        )
+        .await
+        .into_response();
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+        let response = remove_team_member(
+            Extension(auth_state(team_id, ApiKeyScope::full_access())),
+            Extension(service.clone()),
+            None,
+            OperatorCredential(false),
+            Path((team_id, user_id)),
+        )
+        .await
+        .into_response();
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+    }
+}
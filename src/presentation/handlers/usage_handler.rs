@@ -0,0 +1,258 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! 下载流量用量接口
+//!
+//! 返回团队按 UTC 日汇总的下载字节数与响应数，当日读数包含计数器中尚未汇总的部分；
+//! 配置了每日下载上限时同时返回上限与当日剩余额度。
+
+use axum::{
+    extract::{Extension, Query},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use chrono::{Days, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::domain::models::DailyBandwidthUsage;
+use crate::domain::services::bandwidth_service::BandwidthService;
+use crate::presentation::handlers::response_builder::{errors, success_response};
+use crate::presentation::middleware::auth_middleware::AuthState;
+
+/// 未指定起始日期时查询的天数（含当日）
+const DEFAULT_USAGE_DAYS: u64 = 30;
+
+/// 单次查询允许的最大天数
+const MAX_USAGE_DAYS: i64 = 366;
+
+/// 用量查询参数
+#[derive(Debug, Default, Deserialize)]
+pub struct UsageQuery {
+    /// 起始日期（含），`YYYY-MM-DD`，默认为结束日期前 29 天
+    pub from: Option<NaiveDate>,
+    /// 结束日期（含），`YYYY-MM-DD`，默认为当日（UTC）
+    pub to: Option<NaiveDate>,
+}
+
+impl UsageQuery {
+    /// 校验并解析日期范围
+    fn range(&self, today: NaiveDate) -> Result<(NaiveDate, NaiveDate), String> {
+        let to = self.to.unwrap_or(today);
+        let from = match self.from {
+            Some(from) => from,
+            None => to
+                .checked_sub_days(Days::new(DEFAULT_USAGE_DAYS - 1))
+                .unwrap_or(NaiveDate::MIN),
+        };
+        if from > to {
+            return Err("from must not be later than to".to_string());
+        }
+        if (to - from).num_days() >= MAX_USAGE_DAYS {
+            return Err(format!(
+                "date range must not exceed {} days",
+                MAX_USAGE_DAYS
+            ));
+        }
+        Ok((from, to))
+    }
+}
+
+/// 下载流量用量响应
+#[derive(Debug, Serialize)]
+pub struct UsageResponseDto {
+    /// 团队 ID
+    pub team_id: Uuid,
+    /// 起始日期（含）
+    pub from: NaiveDate,
+    /// 结束日期（含）
+    pub to: NaiveDate,
+    /// 有下载记录的日期，按日期升序
+    pub days: Vec<DailyBandwidthUsage>,
+    /// 范围内的下载字节数合计
+    pub bytes_downloaded: u64,
+    /// 范围内的响应数合计
+    pub requests: u64,
+    /// 当日（UTC）的用量
+    pub today: DailyBandwidthUsage,
+    /// 每日下载上限（字节），未设置时为 `null`
+    pub daily_cap_bytes: Option<u64>,
+    /// 当日剩余的下载额度（字节），未设置上限时为 `null`
+    pub remaining_bytes: Option<u64>,
+    /// 当日下载量是否已达到上限
+    pub cap_exceeded: bool,
+}
+
+/// 查询团队的下载流量用量
+pub async fn get_usage(
+    Extension(bandwidth_service): Extension<Arc<BandwidthService>>,
+    Extension(auth_state): Extension<AuthState>,
+    Query(query): Query<UsageQuery>,
+) -> impl IntoResponse {
+    let (from, to) = match query.range(Utc::now().date_naive()) {
+        Ok(range) => range,
+        Err(message) => return errors::bad_request(message),
+    };
+
+    let team_id = auth_state.team_id;
+    let days = match bandwidth_service.usage(team_id, from, to).await {
+        Ok(days) => days,
+        Err(e) => return errors::internal_server_error(e.to_string()),
+    };
+    let today = bandwidth_service.today(team_id).await;
+    let daily_cap_bytes = bandwidth_service.daily_cap(team_id);
+
+    success_response(
+        StatusCode::OK,
+        UsageResponseDto {
+            team_id,
+            from,
+            to,
+            bytes_downloaded: days.iter().map(|day| day.bytes_downloaded).sum(),
+            requests: days.iter().map(|day| day.requests).sum(),
+            days,
+            today,
+            daily_cap_bytes,
+            remaining_bytes: daily_cap_bytes.map(|cap| cap.saturating_sub(today.bytes_downloaded)),
+            cap_exceeded: daily_cap_bytes.is_some_and(|cap| today.bytes_downloaded >= cap),
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::test_helpers::create_test_db_pool;
+    use crate::domain::auth::ApiKeyScope;
+    use crate::domain::repositories::bandwidth_usage_repository::BandwidthUsageRepository;
+    use crate::domain::repositories::task_repository::RepositoryError;
+    use crate::domain::services::bandwidth_service::InMemoryBandwidthCounter;
+    use async_trait::async_trait;
+    use std::time::Duration;
+
+    /// 返回固定历史记录的内存仓库
+    struct FixedUsageRepository {
+        days: Vec<DailyBandwidthUsage>,
+    }
+
+    #[async_trait]
+    impl BandwidthUsageRepository for FixedUsageRepository {
+        async fn upsert_daily(
+            &self,
+            _team_id: Uuid,
+            _usage: &DailyBandwidthUsage,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn list_daily(
+            &self,
+            _team_id: Uuid,
+            from: NaiveDate,
+            to: NaiveDate,
+        ) -> Result<Vec<DailyBandwidthUsage>, RepositoryError> {
+            Ok(self
+                .days
+                .iter()
+                .filter(|day| day.date >= from && day.date <= to)
+                .copied()
+                .collect())
+        }
+    }
+
+    fn service(days: Vec<DailyBandwidthUsage>) -> BandwidthService {
+        BandwidthService::new(
+            Arc::new(InMemoryBandwidthCounter::new(Duration::from_secs(60))),
+            Arc::new(FixedUsageRepository { days }),
+        )
+    }
+
+    fn auth_state() -> AuthState {
+        AuthState::new(
+            create_test_db_pool(),
+            Uuid::new_v4(),
+            Uuid::nil(),
+            ApiKeyScope::default(),
+        )
+    }
+
+    async fn body(response: axum::response::Response) -> serde_json::Value {
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        serde_json::from_slice(&bytes).unwrap()
+    }
+
+    #[test]
+    fn test_usage_query_range() {
+        let today: NaiveDate = "2025-03-31".parse().unwrap();
+        let query = UsageQuery::default();
+        assert_eq!(
+            query.range(today),
+            Ok(("2025-03-02".parse().unwrap(), today))
+        );
+
+        let query = UsageQuery {
+            from: Some("2025-03-05".parse().unwrap()),
+            to: Some("2025-03-01".parse().unwrap()),
+        };
+        assert!(query.range(today).is_err());
+
+        let query = UsageQuery {
+            from: Some("2024-01-01".parse().unwrap()),
+            to: None,
+        };
+        assert!(query.range(today).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_get_usage_merges_live_counter() {
+        let today = Utc::now().date_naive();
+        let yesterday = today.pred_opt().unwrap();
+        let auth_state = auth_state();
+        let team_id = auth_state.team_id;
+        let service = service(vec![DailyBandwidthUsage {
+            date: yesterday,
+            bytes_downloaded: 1_000,
+            requests: 2,
+        }])
+        .with_team_cap(team_id, 1_000);
+        service.record(team_id, Uuid::new_v4(), 300).await;
+
+        let response = get_usage(
+            Extension(Arc::new(service)),
+            Extension(auth_state),
+            Query(UsageQuery::default()),
+        )
+        .await
+        .into_response();
+        assert_eq!(response.status(), StatusCode::OK);
+        let json = body(response).await;
+        assert_eq!(json["data"]["days"].as_array().unwrap().len(), 2);
+        assert_eq!(json["data"]["bytes_downloaded"], 1_300);
+        assert_eq!(json["data"]["requests"], 3);
+        assert_eq!(json["data"]["today"]["bytes_downloaded"], 300);
+        assert_eq!(json["data"]["daily_cap_bytes"], 1_000);
+        assert_eq!(json["data"]["remaining_bytes"], 700);
+        assert_eq!(json["data"]["cap_exceeded"], false);
+    }
+
+    #[tokio::test]
+    async fn test_get_usage_rejects_invalid_range() {
+        let query = UsageQuery {
+            from: Some("2025-03-05".parse().unwrap()),
+            to: Some("2025-03-01".parse().unwrap()),
+        };
+        let response = get_usage(
+            Extension(Arc::new(service(Vec::new()))),
+            Extension(auth_state()),
+            Query(query),
+        )
+        .await
+        .into_response();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+}
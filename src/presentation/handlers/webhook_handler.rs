@@ -4,17 +4,17 @@
 // See LICENSE file in the project root for full license information.
 
 use crate::application::dto::webhook_request::{
-    CreateWebhookRequest, WebhookListResponse, WebhookResponse,
+    CreateWebhookRequest, TestWebhookRequest, WebhookListResponse, WebhookResponse,
 };
 use crate::config::settings::Settings;
-use crate::domain::models::Webhook;
+use crate::domain::models::{Webhook, WebhookEventType};
 use crate::domain::repositories::webhook_repository::WebhookRepository;
 use crate::domain::services::rate_limiting_service::RateLimitingService;
 // 架构 MEDIUM-2：domain 层提供 `verify_webhook_signature_from_parts`（timestamp 解析 +
 // HMAC 验证 + 时间戳窗口检查），presentation 层仅负责 HTTP header → &str 提取。
 // 之前的 `verify_webhook_signature_from_headers` 跨层混合 HTTP 解析 + 域逻辑，违反 SRP。
 use crate::domain::services::webhook_service::{
-    verify_webhook_signature_from_parts, WEBHOOK_AUTH_FAILED,
+    verify_webhook_signature_from_parts, WebhookService, WebhookTestResult, WEBHOOK_AUTH_FAILED,
 };
 use crate::domain::use_cases::create_webhook::CreateWebhookUseCase;
 // 架构 MEDIUM-2：与 crawl/scrape handler 统一使用 `presentation::helpers::ssrf::validate_url`。
@@ -26,9 +26,11 @@ use crate::presentation::helpers::rate_limit_helper::check_rate_limit_as_app_err
 use crate::presentation::helpers::ssrf::validate_url;
 use crate::presentation::middleware::auth_middleware::AuthState;
 use axum::body::Bytes;
+use axum::extract::Path;
 use axum::http::{HeaderMap, StatusCode};
 use axum::{Extension, Json};
 use std::sync::Arc;
+use uuid::Uuid;
 
 /// Webhook 签名验证相关的 HTTP 头名称（HTTP 协议层常量）
 const SIGNATURE_HEADER: &str = "X-Crawlrs-Signature";
@@ -68,6 +70,25 @@ fn verify_webhook_signature_from_headers(
         .map_err(|_| auth_error())
 }
 
+/// 将请求中的事件类型名称解析为领域事件类型
+///
+/// 未知名称按自定义事件处理（如 `page.scraped`），空白名称视为校验错误。
+fn parse_event_types(names: &[String]) -> Result<Vec<WebhookEventType>, CrawlRsError> {
+    names
+        .iter()
+        .map(|name| {
+            let name = name.trim();
+            if name.is_empty() {
+                return Err(CrawlRsError::Validation(
+                    "event_types must not contain empty values".to_string(),
+                ));
+            }
+            name.parse::<WebhookEventType>()
+                .map_err(|e| CrawlRsError::Validation(format!("invalid event type: {}", e)))
+        })
+        .collect()
+}
+
 pub async fn create_webhook<R: WebhookRepository>(
     Extension(repo): Extension<Arc<R>>,
     Extension(rate_limiting_service): Extension<Arc<dyn RateLimitingService>>,
@@ -123,8 +144,20 @@ pub async fn create_webhook<R: WebhookRepository>(
         }
     }
 
+    // 4. 解析事件类型过滤（空列表表示订阅全部事件）
+    let event_types = parse_event_types(&payload.event_types)?;
+
+    // 5. 自定义请求头与载荷模板（不可覆盖签名头，模板须为合法 JSON）
+    let webhook = Webhook::new(Uuid::new_v4(), team_id, payload.url)
+        .with_event_types(event_types)
+        .with_custom_headers(payload.custom_headers)
+        .with_payload_template(payload.payload_template);
+    webhook
+        .validate_delivery_options()
+        .map_err(|e| CrawlRsError::Validation(e.to_string()))?;
+
     let use_case = CreateWebhookUseCase::new(repo);
-    let webhook = use_case.execute(team_id, payload.url).await?;
+    let webhook = use_case.create(webhook).await?;
     Ok((StatusCode::CREATED, Json(webhook)))
 }
 
@@ -143,6 +176,7 @@ pub async fn list_webhooks<R: WebhookRepository>(
             id: w.id,
             team_id: w.team_id,
             url: w.url,
+            event_types: w.event_types.iter().map(|t| t.to_string()).collect(),
             created_at: w.created_at,
             is_active: true,
             secret: None,
@@ -155,6 +189,69 @@ pub async fn list_webhooks<R: WebhookRepository>(
     })))
 }
 
+/// 向已注册的 webhook 发送一条签名的示例事件
+///
+/// 同步返回下游的状态码、耗时与响应体，便于用户在运行 crawl 前验证接收端。
+/// 测试事件不写入事件表、不重试；webhook 不属于当前团队时返回 404。
+pub async fn test_webhook<R: WebhookRepository>(
+    Extension(repo): Extension<Arc<R>>,
+    Extension(webhook_service): Extension<Arc<dyn WebhookService>>,
+    Extension(rate_limiting_service): Extension<Arc<dyn RateLimitingService>>,
+    Extension(auth_state): Extension<AuthState>,
+    Path(webhook_id): Path<Uuid>,
+    body: Bytes,
+) -> Result<Json<ApiResponse<WebhookTestResult>>, CrawlRsError> {
+    check_rate_limit_as_app_error(
+        rate_limiting_service.as_ref(),
+        auth_state.api_key_id,
+        "/v1/webhooks/test",
+    )
+    .await?;
+
+    let webhook = repo
+        .find_by_id(webhook_id)
+        .await?
+        .filter(|w| w.team_id == auth_state.team_id)
+        .ok_or_else(|| CrawlRsError::NotFound("Webhook not found".to_string()))?;
+
+    // 请求体可省略
+    let payload: TestWebhookRequest = if body.is_empty() {
+        TestWebhookRequest::default()
+    } else {
+        serde_json::from_slice(&body)
+            .map_err(|e| CrawlRsError::Validation(format!("invalid JSON payload: {}", e)))?
+    };
+
+    let event_type = match payload.event_type {
+        Some(name) => parse_event_types(&[name])?.remove(0),
+        None => webhook
+            .event_types
+            .first()
+            .cloned()
+            .unwrap_or(WebhookEventType::ScrapeCompleted),
+    };
+
+    // 投递前重新做 SSRF 校验，防止注册后 DNS 被改指向内网
+    if let Err(e) = validate_url(&webhook.url).await {
+        log::warn!(
+            "Webhook test blocked by SSRF validation webhook_id={} team_id={} error={}",
+            webhook.id,
+            webhook.team_id,
+            e
+        );
+        return Err(CrawlRsError::Validation(
+            "Invalid webhook URL: potential security risk detected".to_string(),
+        ));
+    }
+
+    let result = webhook_service
+        .send_test_event(&webhook, event_type)
+        .await
+        .map_err(|e| CrawlRsError::Network(format!("webhook test delivery failed: {}", e)))?;
+
+    Ok(Json(ApiResponse::success(result)))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -238,6 +335,9 @@ mod tests {
     fn test_create_webhook_request_serialization() {
         let req = CreateWebhookRequest {
             url: "https://example.com/hook".to_string(),
+            event_types: vec![],
+            custom_headers: Default::default(),
+            payload_template: None,
         };
         let json = serde_json::to_string(&req).unwrap();
         let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
@@ -248,12 +348,35 @@ mod tests {
     fn test_create_webhook_request_round_trip() {
         let original = CreateWebhookRequest {
             url: "https://my.webhook.site/abc123".to_string(),
+            event_types: vec![],
+            custom_headers: Default::default(),
+            payload_template: None,
         };
         let json = serde_json::to_string(&original).unwrap();
         let deserialized: CreateWebhookRequest = serde_json::from_str(&json).unwrap();
         assert_eq!(deserialized.url, original.url);
     }
 
+    #[test]
+    fn test_create_webhook_request_with_event_types() {
+        let json = r#"{"url":"https://example.com/webhook","event_types":["crawl.completed","page.scraped"]}"#;
+        let req: CreateWebhookRequest = serde_json::from_str(json).unwrap();
+        let parsed = parse_event_types(&req.event_types).unwrap();
+        assert_eq!(
+            parsed,
+            vec![
+                WebhookEventType::CrawlCompleted,
+                WebhookEventType::Custom("page.scraped".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_event_types_rejects_blank_names() {
+        let result = parse_event_types(&["  ".to_string()]);
+        assert!(matches!(result, Err(CrawlRsError::Validation(_))));
+    }
+
     // ========== Webhook to WebhookResponse mapping ==========
 
     #[test]
@@ -264,12 +387,16 @@ mod tests {
             id: webhook_id,
             team_id,
             url: "https://example.com/hook".to_string(),
+            event_types: vec![],
+            custom_headers: Default::default(),
+            payload_template: None,
             created_at: Utc::now(),
         };
         let response = WebhookResponse {
             id: webhook.id,
             team_id: webhook.team_id,
             url: webhook.url.clone(),
+            event_types: vec![],
             created_at: webhook.created_at,
             is_active: true,
             secret: None,
@@ -287,6 +414,7 @@ mod tests {
             id: Uuid::new_v4(),
             team_id: Uuid::new_v4(),
             url: "https://example.com/hook".to_string(),
+            event_types: vec![],
             created_at: Utc::now(),
             is_active: true,
             secret: Some("secret123".to_string()),
@@ -304,6 +432,7 @@ mod tests {
             id: Uuid::new_v4(),
             team_id: Uuid::new_v4(),
             url: "https://example.com/hook".to_string(),
+            event_types: vec![],
             created_at: Utc::now(),
             is_active: false,
             secret: None,
@@ -334,6 +463,7 @@ mod tests {
             id: Uuid::new_v4(),
             team_id: Uuid::new_v4(),
             url: "https://hook1.example.com".to_string(),
+            event_types: vec![],
             created_at: Utc::now(),
             is_active: true,
             secret: None,
@@ -342,6 +472,7 @@ mod tests {
             id: Uuid::new_v4(),
             team_id: Uuid::new_v4(),
             url: "https://hook2.example.com".to_string(),
+            event_types: vec![],
             created_at: Utc::now(),
             is_active: false,
             secret: None,
@@ -365,6 +496,7 @@ mod tests {
                 id: Uuid::new_v4(),
                 team_id: Uuid::new_v4(),
                 url: "https://example.com".to_string(),
+                event_types: vec![],
                 created_at: Utc::now(),
                 is_active: true,
                 secret: None,
@@ -621,6 +753,77 @@ mod tests {
     // 组合 trait（向后兼容，空实现即可）
     impl RateLimitingService for MockRateLimitingService {}
 
+    /// `WebhookService` mock：仅用于 test_webhook handler 测试，不应被调用
+    struct UnreachableWebhookService;
+
+    #[async_trait]
+    impl WebhookService for UnreachableWebhookService {
+        async fn send_webhook(
+            &self,
+            _event: &crate::domain::models::WebhookEvent,
+        ) -> anyhow::Result<()> {
+            unreachable!("send_webhook should not be called")
+        }
+
+        async fn trigger_completion(
+            &self,
+            _task: &crate::domain::models::Task,
+        ) -> anyhow::Result<()> {
+            unreachable!("trigger_completion should not be called")
+        }
+
+        async fn trigger_failure(
+            &self,
+            _task: &crate::domain::models::Task,
+            _error_msg: String,
+        ) -> anyhow::Result<()> {
+            unreachable!("trigger_failure should not be called")
+        }
+    }
+
+    // ========== test_webhook handler tests ==========
+
+    #[tokio::test]
+    async fn test_test_webhook_unknown_id_returns_not_found() {
+        let repo = Arc::new(MockWebhookRepository::new());
+        let webhook_service: Arc<dyn WebhookService> = Arc::new(UnreachableWebhookService);
+        let rate_limit = Arc::new(MockRateLimitingService::new_allowed());
+        let auth = make_test_auth_state();
+
+        let result = test_webhook::<MockWebhookRepository>(
+            Extension(repo),
+            Extension(webhook_service),
+            Extension(rate_limit as Arc<dyn RateLimitingService>),
+            Extension(auth),
+            Path(Uuid::new_v4()),
+            Bytes::new(),
+        )
+        .await;
+
+        assert!(matches!(result, Err(CrawlRsError::NotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_test_webhook_rate_limited() {
+        let repo = Arc::new(MockWebhookRepository::new());
+        let webhook_service: Arc<dyn WebhookService> = Arc::new(UnreachableWebhookService);
+        let rate_limit = Arc::new(MockRateLimitingService::new_denied("too many"));
+        let auth = make_test_auth_state();
+
+        let result = test_webhook::<MockWebhookRepository>(
+            Extension(repo),
+            Extension(webhook_service),
+            Extension(rate_limit as Arc<dyn RateLimitingService>),
+            Extension(auth),
+            Path(Uuid::new_v4()),
+            Bytes::new(),
+        )
+        .await;
+
+        assert!(result.is_err());
+        assert!(!matches!(result, Err(CrawlRsError::NotFound(_))));
+    }
+
     // ========== create_webhook handler tests ==========
 
     #[tokio::test]
@@ -631,6 +834,9 @@ mod tests {
         let team_id = auth.team_id;
         let payload = CreateWebhookRequest {
             url: "https://example.com/webhook".to_string(),
+            event_types: vec![],
+            custom_headers: Default::default(),
+            payload_template: None,
         };
         let payload_bytes = serde_json::to_vec(&payload).expect("serialize payload");
         let settings = make_test_settings_with_secret(TEST_WEBHOOK_SECRET);
@@ -663,6 +869,9 @@ mod tests {
         let auth = make_test_auth_state();
         let payload = CreateWebhookRequest {
             url: "http://127.0.0.1:8080".to_string(),
+            event_types: vec![],
+            custom_headers: Default::default(),
+            payload_template: None,
         };
         let payload_bytes = serde_json::to_vec(&payload).expect("serialize payload");
         let settings = make_test_settings_with_secret(TEST_WEBHOOK_SECRET);
@@ -694,6 +903,45 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_create_webhook_rejects_reserved_custom_header() {
+        let repo = Arc::new(MockWebhookRepository::new());
+        let rate_limit = Arc::new(MockRateLimitingService::new_allowed());
+        let auth = make_test_auth_state();
+        let payload = CreateWebhookRequest {
+            url: "https://example.com/webhook".to_string(),
+            event_types: vec![],
+            custom_headers: std::collections::HashMap::from([(
+                "X-Crawlrs-Signature".to_string(),
+                "forged".to_string(),
+            )]),
+            payload_template: None,
+        };
+        let payload_bytes = serde_json::to_vec(&payload).expect("serialize payload");
+        let settings = make_test_settings_with_secret(TEST_WEBHOOK_SECRET);
+        let headers = make_signed_headers(
+            TEST_WEBHOOK_SECRET,
+            std::str::from_utf8(&payload_bytes).expect("utf8"),
+        );
+
+        let result = create_webhook(
+            Extension(repo),
+            Extension(rate_limit as Arc<dyn RateLimitingService>),
+            Extension(auth),
+            Extension(settings),
+            headers,
+            Bytes::from(payload_bytes),
+        )
+        .await;
+
+        match result.unwrap_err() {
+            CrawlRsError::Validation(msg) => {
+                assert!(msg.contains("X-Crawlrs-Signature"), "got: {}", msg);
+            }
+            other => panic!("expected CrawlRsError::Validation, got {:?}", other),
+        }
+    }
+
     #[tokio::test]
     async fn test_create_webhook_rate_limit_exceeded() {
         let repo = Arc::new(MockWebhookRepository::new());
@@ -701,6 +949,9 @@ mod tests {
         let auth = make_test_auth_state();
         let payload = CreateWebhookRequest {
             url: "https://example.com/webhook".to_string(),
+            event_types: vec![],
+            custom_headers: Default::default(),
+            payload_template: None,
         };
         let payload_bytes = serde_json::to_vec(&payload).expect("serialize payload");
         let settings = make_test_settings_with_secret(TEST_WEBHOOK_SECRET);
@@ -741,6 +992,9 @@ mod tests {
         let auth = make_test_auth_state();
         let payload = CreateWebhookRequest {
             url: "https://example.com/webhook".to_string(),
+            event_types: vec![],
+            custom_headers: Default::default(),
+            payload_template: None,
         };
         let payload_bytes = serde_json::to_vec(&payload).expect("serialize payload");
         let settings = make_test_settings_with_secret(TEST_WEBHOOK_SECRET);
@@ -886,6 +1140,9 @@ mod tests {
         let auth = make_test_auth_state();
         let payload = CreateWebhookRequest {
             url: "https://example.com/webhook".to_string(),
+            event_types: vec![],
+            custom_headers: Default::default(),
+            payload_template: None,
         };
         let payload_bytes = serde_json::to_vec(&payload).expect("serialize payload");
         let settings = make_test_settings_with_secret(TEST_WEBHOOK_SECRET);
@@ -935,6 +1192,9 @@ mod tests {
         let auth = make_test_auth_state();
         let payload = CreateWebhookRequest {
             url: "https://example.com/webhook".to_string(),
+            event_types: vec![],
+            custom_headers: Default::default(),
+            payload_template: None,
         };
         let payload_bytes = serde_json::to_vec(&payload).expect("serialize payload");
         let settings = make_test_settings_with_secret(TEST_WEBHOOK_SECRET);
@@ -978,6 +1238,9 @@ mod tests {
         let auth = make_test_auth_state();
         let payload = CreateWebhookRequest {
             url: "https://example.com/webhook".to_string(),
+            event_types: vec![],
+            custom_headers: Default::default(),
+            payload_template: None,
         };
         let payload_bytes = serde_json::to_vec(&payload).expect("serialize payload");
         let settings = make_test_settings_with_secret(TEST_WEBHOOK_SECRET);
@@ -1020,6 +1283,9 @@ mod tests {
         let auth = make_test_auth_state();
         let payload = CreateWebhookRequest {
             url: "https://example.com/webhook".to_string(),
+            event_types: vec![],
+            custom_headers: Default::default(),
+            payload_template: None,
         };
         let payload_bytes = serde_json::to_vec(&payload).expect("serialize payload");
         let settings = make_test_settings_with_secret(TEST_WEBHOOK_SECRET);
@@ -1065,6 +1331,9 @@ mod tests {
         let auth = make_test_auth_state();
         let payload = CreateWebhookRequest {
             url: "https://example.com/webhook".to_string(),
+            event_types: vec![],
+            custom_headers: Default::default(),
+            payload_template: None,
         };
         let payload_bytes = serde_json::to_vec(&payload).expect("serialize payload");
         let settings = make_test_settings_with_secret(TEST_WEBHOOK_SECRET);
@@ -1104,6 +1373,9 @@ mod tests {
         let auth = make_test_auth_state();
         let payload = CreateWebhookRequest {
             url: "https://example.com/webhook".to_string(),
+            event_types: vec![],
+            custom_headers: Default::default(),
+            payload_template: None,
         };
         let payload_bytes = serde_json::to_vec(&payload).expect("serialize payload");
         let settings = make_test_settings_with_secret(TEST_WEBHOOK_SECRET);
@@ -1145,6 +1417,9 @@ mod tests {
         let auth = make_test_auth_state();
         let payload = CreateWebhookRequest {
             url: "https://example.com/webhook".to_string(),
+            event_types: vec![],
+            custom_headers: Default::default(),
+            payload_template: None,
         };
         let payload_bytes = serde_json::to_vec(&payload).expect("serialize payload");
 
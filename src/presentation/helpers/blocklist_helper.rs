@@ -0,0 +1,86 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! URL blocklist checking helper
+//!
+//! Provides a unified blocklist check used by the scrape, crawl and extract
+//! handlers before a task is enqueued.
+
+use crate::domain::services::url_blocklist_service::UrlBlocklistService;
+use crate::presentation::handlers::response_builder::errors;
+use axum::response::Response;
+use log::{error, warn};
+use uuid::Uuid;
+
+/// Reject the request if any of the URLs is on the team's blocklist.
+///
+/// Unlike the rate limit check this helper is **fail-closed**: blocklist
+/// entries exist for legal and abuse reasons, so a failure to load them
+/// rejects the request instead of letting it through.
+///
+/// # Arguments
+///
+/// * `service` - The URL blocklist service
+/// * `team_id` - Team submitting the request
+/// * `urls` - Target URLs of the request
+///
+/// # Returns
+///
+/// * `Ok(())` - No URL is blocked
+/// * `Err(Response)` - 403 when a URL is blocked, 500 when the blocklist cannot be loaded
+pub async fn check_url_blocklist<'a>(
+    service: &UrlBlocklistService,
+    team_id: Uuid,
+    urls: impl IntoIterator<Item = &'a str>,
+) -> Result<(), Response> {
+    match service.find_blocked(team_id, urls).await {
+        Ok(None) => Ok(()),
+        Ok(Some((url, entry))) => {
+            warn!(
+                "Blocked URL rejected url={} team_id={} pattern={} entry_id={}",
+                url, team_id, entry.pattern, entry.id
+            );
+            Err(errors::forbidden(format!(
+                "URL is blocked: {} matches blocklist pattern '{}'",
+                url, entry.pattern
+            )))
+        }
+        Err(e) => {
+            error!("Failed to load URL blocklist for team {}: {}", team_id, e);
+            Err(errors::internal_server_error(
+                "Failed to check URL blocklist",
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::http::StatusCode;
+
+    #[tokio::test]
+    async fn test_allows_urls_not_on_blocklist() {
+        let service = UrlBlocklistService::new(&["blocked.com".to_string()]);
+        assert!(
+            check_url_blocklist(&service, Uuid::new_v4(), ["https://example.com"])
+                .await
+                .is_ok()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_rejects_blocked_url_with_forbidden() {
+        let service = UrlBlocklistService::new(&["blocked.com".to_string()]);
+        let response = check_url_blocklist(
+            &service,
+            Uuid::new_v4(),
+            ["https://example.com", "https://www.blocked.com/page"],
+        )
+        .await
+        .unwrap_err();
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+}
@@ -7,5 +7,6 @@
 //!
 //! Provides shared utility functions used across handlers.
 
+pub mod blocklist_helper;
 pub mod rate_limit_helper;
 pub mod ssrf;
@@ -839,6 +839,9 @@ async fn auth_middleware_inner(req: axum::http::Request<Body>, next: Next) -> Re
     // Check cache first before database query
     if let Some(auth_state) = try_get_cached_auth(&state, &token_hash).await {
         inject_auth_state(&mut req, auth_state.clone(), &token_hash);
+        if let Err(status) = enforce_request_role(&mut req).await {
+            return status.into_response();
+        }
         return next.run(req).await;
     }
 
@@ -872,9 +875,22 @@ async fn auth_middleware_inner(req: axum::http::Request<Body>, next: Next) -> Re
 
     debug!("API Key authentication successful");
 
+    if let Err(status) = enforce_request_role(&mut req).await {
+        return status.into_response();
+    }
+
     next.run(req).await
 }
 
+/// Enforce the role required by the endpoint once the key has been authenticated
+///
+/// Takes `&mut` so the returned future stays `Send` (`Body` is not `Sync`).
+async fn enforce_request_role(req: &mut Request<Body>) -> Result<(), StatusCode> {
+    let path = req.uri().path().to_string();
+    let method = req.method().to_string();
+    enforce_required_scope(req.extensions(), &path, &method).await
+}
+
 /// Wrapper function for middleware registration
 pub fn auth_middleware() -> impl Fn(
     axum::http::Request<Body>,
@@ -1030,6 +1046,14 @@ fn check_key_expiration(key: &api_key::Model) -> Result<(), StatusCode> {
         return Err(StatusCode::UNAUTHORIZED);
     }
 
+    // Session tokens issued by SSO login carry an explicit expiry
+    if let Some(expires_at) = key.expires_at {
+        if expires_at <= chrono::Utc::now() {
+            debug!("API key {} expired at {}", key.id, expires_at);
+            return Err(StatusCode::UNAUTHORIZED);
+        }
+    }
+
     // Check expiration based on update time
     if let Some(updated_at) = key.updated_at {
         let now = chrono::Utc::now();
@@ -1165,43 +1189,58 @@ fn get_client_ip(
 /// * `Err(StatusCode)` - If scope validation fails
 pub async fn scope_middleware(req: Request<Body>, next: Next) -> Result<Response, StatusCode> {
     let path = req.uri().path().to_string();
-    let method = req.method().clone();
+    let method = req.method().to_string();
+    enforce_required_scope(req.extensions(), &path, &method).await?;
 
+    Ok(next.run(req).await)
+}
+
+/// Check the authenticated key against the scope its role needs for the endpoint
+///
+/// Denials are logged to the audit service when one is available in the extensions.
+async fn enforce_required_scope(
+    extensions: &axum::http::Extensions,
+    path: &str,
+    method: &str,
+) -> Result<(), StatusCode> {
     // Determine required scope based on endpoint
-    let required_scope = determine_required_scope(&path, method.as_ref());
-
-    if let Some(required) = required_scope {
-        let auth_state = req
-            .extensions()
-            .get::<AuthState>()
-            .ok_or(StatusCode::UNAUTHORIZED)?;
-
-        if !auth_state.scope.has_permission(required) {
-            warn!(
-                "Scope denied: API Key {} lacks {:?} for {} {}",
-                auth_state.api_key_id, required, method, path
-            );
+    let Some(required) = determine_required_scope(path, method) else {
+        return Ok(());
+    };
 
-            // Log scope denial to audit service
-            if let Some(audit_service) = req.extensions().get::<Arc<dyn AuditServiceTrait>>() {
-                let api_key_scope: ApiKeyScope = required.into();
-                let reason = format!("Missing required scope: {:?}", required);
-                let _ = audit_service
-                    .log_deny(
-                        "scope.denied".to_string(),
-                        Some(auth_state.api_key_id),
-                        Some(auth_state.team_id),
-                        reason,
-                        Some(api_key_scope),
-                    )
-                    .await;
-            }
+    let auth_state = extensions
+        .get::<AuthState>()
+        .ok_or(StatusCode::UNAUTHORIZED)?;
 
-            return Err(StatusCode::FORBIDDEN);
-        }
+    if auth_state.scope.has_permission(required) {
+        return Ok(());
     }
 
-    Ok(next.run(req).await)
+    warn!(
+        "Scope denied: API Key {} ({}) lacks {:?} for {} {}",
+        auth_state.api_key_id,
+        auth_state.scope.role(),
+        required,
+        method,
+        path
+    );
+
+    // Log scope denial to audit service
+    if let Some(audit_service) = extensions.get::<Arc<dyn AuditServiceTrait>>() {
+        let api_key_scope: ApiKeyScope = required.into();
+        let reason = format!("Missing required scope: {:?}", required);
+        let _ = audit_service
+            .log_deny(
+                "scope.denied".to_string(),
+                Some(auth_state.api_key_id),
+                Some(auth_state.team_id),
+                reason,
+                Some(api_key_scope),
+            )
+            .await;
+    }
+
+    Err(StatusCode::FORBIDDEN)
 }
 
 /// Check if a path matches a prefix exactly or has a slash after the prefix
@@ -1217,6 +1256,37 @@ fn determine_required_scope(path: &str, method: &str) -> Option<ScopePermission>
         return Some(ScopePermission::Admin);
     }
 
+    // Admin role: key management, limits and operational endpoints
+    if is_path_prefix(path, "/admin")
+        || is_path_prefix(path, "/v1/keys")
+        || is_path_prefix(path, "/v1/audit")
+        || is_path_prefix(path, "/v1/blocklist")
+        || is_path_prefix(path, "/v1/exports")
+        || is_path_prefix(path, "/v1/data")
+        || is_path_prefix(path, "/v1/notifications/email")
+        || (path.starts_with("/v1/crawl/") && path.ends_with("/data"))
+    {
+        return Some(ScopePermission::Admin);
+    }
+
+    // Member role: billing and usage, webhooks and their delivery log, monitors and chat
+    // channels are hidden from read-only keys
+    if is_path_prefix(path, "/v1/credits")
+        || is_path_prefix(path, "/v1/usage")
+        || is_path_prefix(path, "/v1/teams/me")
+        || is_path_prefix(path, "/v1/webhooks")
+        || is_path_prefix(path, "/v1/monitors")
+        || is_path_prefix(path, "/v1/notifications/channels")
+        || is_path_prefix(path, "/v1/notifications/deliveries")
+    {
+        return Some(ScopePermission::Write);
+    }
+
+    // The invitation token is the credential, and estimates create nothing
+    if method == "POST" && (path == "/v1/invitations/accept" || path == "/v1/estimate") {
+        return None;
+    }
+
     // Write endpoints (POST, PUT, PATCH, DELETE)
     if method == "POST" || method == "PUT" || method == "PATCH" || method == "DELETE" {
         // POST to /v1/search and /v1/scrape are write operations
@@ -1325,6 +1395,15 @@ mod tests {
             }
             Ok(self.scope.clone())
         }
+        async fn find_team_id_by_api_key_id(
+            &self,
+            _api_key_id: Uuid,
+        ) -> Result<Option<Uuid>, RepositoryError> {
+            if self.should_error {
+                return Err(RepositoryError::NotFound("mock error".to_string()));
+            }
+            Ok(None)
+        }
         async fn upsert(
             &self,
             _api_key_id: Uuid,
@@ -1604,6 +1683,7 @@ mod tests {
             key_hash,
             created_at: make_fixed_time("2025-01-15T12:00:00+00:00"),
             updated_at: updated_days_ago.map(make_days_ago),
+            expires_at: None,
         }
     }
 
@@ -1841,6 +1921,15 @@ mod tests {
         assert!(check_key_expiration(&key).is_ok());
     }
 
+    #[test]
+    fn test_check_key_expiration_session_expiry() {
+        let mut key = make_key_model(Some("sha256:somehash".to_string()), Some(0));
+        key.expires_at = Some(make_days_ago(-1));
+        assert!(check_key_expiration(&key).is_ok());
+        key.expires_at = Some(make_days_ago(1));
+        assert!(check_key_expiration(&key).is_err());
+    }
+
     // ===== extract_bearer_token tests =====
 
     #[test]
@@ -1958,6 +2047,66 @@ mod tests {
         assert_eq!(determine_required_scope("/v1/crawl", "GET"), None);
     }
 
+    #[test]
+    fn test_determine_required_scope_roles() {
+        // Admin: key management, limits and operational endpoints
+        for path in [
+            "/v1/keys/123/role",
+            "/v1/audit/logs",
+            "/v1/blocklist",
+            "/v1/exports/123",
+            "/v1/data",
+            "/v1/crawl/123/data",
+            "/v1/notifications/email",
+            "/admin/v1/engines",
+        ] {
+            assert_eq!(
+                determine_required_scope(path, "GET"),
+                Some(ScopePermission::Admin)
+            );
+        }
+        // Member: billing, usage, webhooks, monitors and chat channels are hidden from
+        // read-only keys even for GET
+        for path in [
+            "/v1/credits",
+            "/v1/credits/transactions",
+            "/v1/usage",
+            "/v1/teams/me",
+            "/v1/webhooks",
+            "/v1/monitors",
+            "/v1/monitors/123",
+            "/v1/notifications/channels",
+            "/v1/notifications/channels/123",
+            "/v1/notifications/deliveries",
+        ] {
+            assert_eq!(
+                determine_required_scope(path, "GET"),
+                Some(ScopePermission::Write)
+            );
+        }
+        for method in ["POST", "PUT", "DELETE"] {
+            assert_eq!(
+                determine_required_scope("/v1/monitors/123", method),
+                Some(ScopePermission::Write)
+            );
+        }
+        // Read-only keys can still check statuses, results and estimates
+        assert_eq!(
+            determine_required_scope("/v1/crawl/123/results", "GET"),
+            None
+        );
+        assert_eq!(determine_required_scope("/v1/estimate", "POST"), None);
+        assert_eq!(
+            determine_required_scope("/v1/invitations/accept", "POST"),
+            None
+        );
+        assert_eq!(
+            determine_required_scope("/v1/keys-list", "GET"),
+            None,
+            "prefix matching must stop at path boundaries"
+        );
+    }
+
     #[test]
     fn test_determine_required_scope_put_delete_patch_write() {
         assert_eq!(
@@ -79,7 +79,25 @@ pub async fn distributed_rate_limit_middleware(
     );
 
     debug!("DistributedRateLimitMiddleware: Calling rate_limiting_service.check_rate_limit()");
-    match rate_limiting_service.check_rate_limit(&api_key, path).await {
+    // 已认证请求按团队限流配置（持续速率 + 突发容量）检查
+    let result = match request.extensions().get::<AuthState>() {
+        Some(auth_state) => {
+            rate_limiting_service
+                .check_team_rate_limit(
+                    auth_state.team_id,
+                    &api_key,
+                    request.method().as_str(),
+                    path,
+                )
+                .await
+        }
+        None => {
+            rate_limiting_service
+                .check_rate_limit_for_method(&api_key, request.method().as_str(), path)
+                .await
+        }
+    };
+    match result {
         Ok(RateLimitResult::Allowed) => {
             debug!("DistributedRateLimitMiddleware: Rate limit check passed");
             Ok(next.run(request).await)
@@ -143,6 +161,8 @@ mod tests {
     /// enabling tests to assert whether the middleware consulted the service.
     struct MockRateLimitingService {
         call_count: Arc<AtomicUsize>,
+        /// Number of calls routed through `check_team_rate_limit`.
+        team_call_count: Arc<AtomicUsize>,
         result: RateLimitResult,
         /// When `true`, `check_rate_limit` returns `RateLimitingError::DatabaseError`.
         should_error: bool,
@@ -152,6 +172,7 @@ mod tests {
         fn new(result: RateLimitResult) -> Self {
             Self {
                 call_count: Arc::new(AtomicUsize::new(0)),
+                team_call_count: Arc::new(AtomicUsize::new(0)),
                 result,
                 should_error: false,
             }
@@ -160,6 +181,7 @@ mod tests {
         fn with_error() -> Self {
             Self {
                 call_count: Arc::new(AtomicUsize::new(0)),
+                team_call_count: Arc::new(AtomicUsize::new(0)),
                 result: RateLimitResult::Allowed,
                 should_error: true,
             }
@@ -180,6 +202,17 @@ mod tests {
             Ok(self.result.clone())
         }
 
+        async fn check_team_rate_limit(
+            &self,
+            _team_id: Uuid,
+            api_key: &str,
+            _method: &str,
+            endpoint: &str,
+        ) -> Result<RateLimitResult, RateLimitingError> {
+            self.team_call_count.fetch_add(1, Ordering::SeqCst);
+            self.check_rate_limit(api_key, endpoint).await
+        }
+
         async fn get_team_rate_limit_config(
             &self,
             _team_id: Uuid,
@@ -358,6 +391,8 @@ mod tests {
         let response = app.oneshot(request).await.expect("oneshot should succeed");
         assert_eq!(response.status(), StatusCode::OK);
         assert_eq!(service.call_count.load(Ordering::SeqCst), 1);
+        // Without AuthState there is no team to resolve a team override for
+        assert_eq!(service.team_call_count.load(Ordering::SeqCst), 0);
     }
 
     #[tokio::test]
@@ -414,6 +449,11 @@ mod tests {
             1,
             "service should be called with api_key_id from AuthState"
         );
+        assert_eq!(
+            service.team_call_count.load(Ordering::SeqCst),
+            1,
+            "authenticated requests should use the team rate limit"
+        );
     }
 
     #[tokio::test]
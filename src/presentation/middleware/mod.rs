@@ -16,11 +16,19 @@ pub mod team_semaphore;
 pub mod team_semaphore_middleware;
 
 /// Public endpoints that don't require authentication or rate limiting
-pub const PUBLIC_ENDPOINTS: &[&str] = &["/health", "/metrics", "/v1/version"];
+pub const PUBLIC_ENDPOINTS: &[&str] = &[
+    "/health",
+    "/health/ready",
+    "/metrics",
+    "/v1/version",
+    "/v1/auth/oidc/login",
+    "/v1/auth/oidc/callback",
+];
 
 /// Endpoints excluded from rate limiting
 pub const RATE_LIMIT_EXCLUDED_ENDPOINTS: &[&str] = &[
     "/health",
+    "/health/ready",
     "/metrics",
     "/v1/version",
     "/v1/extract",
@@ -317,7 +317,7 @@ pub async fn rate_limit_middleware(
 
     // 调用服务检查速率限制
     match rate_limiting_service
-        .check_rate_limit(&api_key, endpoint)
+        .check_rate_limit_for_method(&api_key, req.method().as_str(), endpoint)
         .await
     {
         Ok(RateLimitResult::Denied { reason }) => {
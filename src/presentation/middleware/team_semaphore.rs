@@ -15,6 +15,8 @@ use uuid::Uuid;
 pub struct TeamSemaphore {
     /// 存储每队的信号量
     semaphores: Arc<DashMap<Uuid, Arc<Semaphore>>>,
+    /// 单独设置了并发数的团队（如按团队套餐）
+    team_permits: Arc<DashMap<Uuid, usize>>,
     /// 默认并发数
     default_permits: usize,
 }
@@ -32,10 +34,28 @@ impl TeamSemaphore {
     pub fn new(default_permits: usize) -> Self {
         Self {
             semaphores: Arc::new(DashMap::new()),
+            team_permits: Arc::new(DashMap::new()),
             default_permits,
         }
     }
 
+    /// 设置指定团队的并发许可数
+    ///
+    /// 许可数变化时为该团队换用新的信号量：已发放的许可仍在旧信号量上释放，
+    /// 切换期间团队的实际并发可能短暂超过新的许可数。
+    ///
+    /// # 参数
+    ///
+    /// * `team_id` - 团队的唯一标识符
+    /// * `permits` - 并发许可数
+    pub fn set_team_permits(&self, team_id: Uuid, permits: usize) {
+        if self.team_permits.insert(team_id, permits) == Some(permits) {
+            return;
+        }
+        self.semaphores
+            .insert(team_id, Arc::new(Semaphore::new(permits)));
+    }
+
     /// 获取指定团队的信号量许可
     ///
     /// 如果该团队的信号量不存在，则会创建一个新的。
@@ -81,7 +101,13 @@ impl TeamSemaphore {
     fn get_or_create(&self, team_id: Uuid) -> Arc<Semaphore> {
         self.semaphores
             .entry(team_id)
-            .or_insert_with(|| Arc::new(Semaphore::new(self.default_permits)))
+            .or_insert_with(|| {
+                let permits = self
+                    .team_permits
+                    .get(&team_id)
+                    .map_or(self.default_permits, |permits| *permits);
+                Arc::new(Semaphore::new(permits))
+            })
             .clone()
     }
 }
@@ -239,4 +265,26 @@ mod tests {
         assert!(cloned.semaphores.contains_key(&team_id));
         assert!(Arc::ptr_eq(&sem.semaphores, &cloned.semaphores));
     }
+
+    #[test]
+    fn test_set_team_permits_overrides_default_for_team_only() {
+        let sem = TeamSemaphore::new(3);
+        let team = Uuid::new_v4();
+        sem.set_team_permits(team, 1);
+
+        let permit = sem.try_acquire(team);
+        assert!(permit.is_some());
+        assert!(sem.try_acquire(team).is_none());
+
+        // 许可数不变时保留原信号量
+        sem.set_team_permits(team, 1);
+        assert!(sem.try_acquire(team).is_none());
+
+        sem.set_team_permits(team, 2);
+        assert!(sem.try_acquire(team).is_some());
+
+        let other = Uuid::new_v4();
+        let permits: Vec<_> = (0..3).filter_map(|_| sem.try_acquire(other)).collect();
+        assert_eq!(permits.len(), 3);
+    }
 }
@@ -11,11 +11,14 @@ use crate::infrastructure::repositories::database_geo_restriction_repo::Database
 use crate::infrastructure::repositories::task_repo_impl::TaskRepositoryImpl;
 use crate::infrastructure::repositories::webhook_repo_impl::WebhookRepoImpl;
 use crate::presentation::handlers::{
-    audit_handler, crawl_handler, extract_handler, metrics_handler, scrape_handler, search_handler,
-    task_handler, team_handler, webhook_handler,
+    api_key_handler, asset_handler, audit_handler, blocklist_handler, crawl_handler,
+    credits_handler, data_handler, export_handler, extract_handler, feed_handler, metrics_handler,
+    monitor_handler, notification_handler, scrape_handler, search_handler, sitemap_handler,
+    sso_handler, tag_handler, task_handler, team_handler, team_member_handler, usage_handler,
+    webhook_handler,
 };
 use axum::{
-    routing::{get, post, put},
+    routing::{delete, get, post, put},
     Json, Router,
 };
 use serde_json::json;
@@ -32,6 +35,9 @@ pub fn routes() -> Router {
         .route("/v1/version", get(version))
         .route("/v1/scrape", post(scrape_handler::create_scrape))
         .route("/v1/scrape/{id}", get(scrape_handler::get_scrape_status))
+        .route("/v1/feeds", post(feed_handler::create_feed))
+        .route("/v1/feeds/{id}", get(feed_handler::get_feed))
+        .route("/v1/sitemap", post(sitemap_handler::parse_sitemap))
         .route(
             "/v1/scrape/{id}/_cancel",
             post(scrape_handler::cancel_scrape),
@@ -44,14 +50,66 @@ pub fn routes() -> Router {
             "/v1/webhooks",
             post(webhook_handler::create_webhook::<WebhookRepoImpl>),
         )
+        .route(
+            "/v1/webhooks/{id}/test",
+            post(webhook_handler::test_webhook::<WebhookRepoImpl>),
+        )
         .route("/v1/crawl", post(crawl_handler::create_crawl))
         .route("/v1/crawl/{id}", get(crawl_handler::get_crawl))
         .route(
             "/v1/crawl/{id}/results",
             get(crawl_handler::get_crawl_results),
         )
+        .route(
+            "/v1/crawl/{id}/results/search",
+            get(crawl_handler::search_crawl_results),
+        )
+        .route("/v1/crawl/{id}/graph", get(crawl_handler::get_crawl_graph))
+        .route(
+            "/v1/crawl/{id}/seo",
+            get(crawl_handler::get_crawl_seo_report),
+        )
+        .route(
+            "/v1/crawl/{id}/tags",
+            get(tag_handler::get_crawl_tags).post(tag_handler::add_crawl_tags),
+        )
+        .route(
+            "/v1/crawl/{id}/tags/{tag}",
+            delete(tag_handler::remove_crawl_tag),
+        )
+        .route(
+            "/v1/results/{id}/tags",
+            get(tag_handler::get_result_tags).post(tag_handler::add_result_tags),
+        )
+        .route(
+            "/v1/results/{id}/tags/{tag}",
+            delete(tag_handler::remove_result_tag),
+        )
         .route("/v1/crawl/{id}/_cancel", post(crawl_handler::cancel_crawl))
+        .route(
+            "/v1/crawl/{id}/data",
+            delete(data_handler::erase_crawl_data),
+        )
+        .route("/v1/compare", get(crawl_handler::compare_crawl_pages))
+        .route("/v1/assets/{*key}", get(asset_handler::get_asset))
         .route("/v1/search", post(search_handler::search))
+        .route("/v1/estimate", post(credits_handler::estimate))
+        .route("/v1/credits", get(credits_handler::get_balance))
+        .route(
+            "/v1/credits/transactions",
+            get(credits_handler::list_transactions),
+        )
+        .route(
+            "/v1/credits/usage/daily",
+            get(credits_handler::get_daily_usage),
+        )
+        .route(
+            "/v1/credits/alert",
+            get(credits_handler::get_low_balance_alert)
+                .put(credits_handler::set_low_balance_alert)
+                .delete(credits_handler::delete_low_balance_alert),
+        )
+        .route("/v1/usage", get(usage_handler::get_usage))
         .route(
             "/v1/teams/geo-restrictions",
             get(team_handler::get_team_geo_restrictions::<DatabaseGeoRestrictionRepository>),
@@ -60,8 +118,79 @@ pub fn routes() -> Router {
             "/v1/teams/geo-restrictions",
             put(team_handler::update_team_geo_restrictions::<DatabaseGeoRestrictionRepository>),
         )
+        .route(
+            "/v1/teams/{id}/capabilities",
+            get(team_handler::get_team_capabilities).put(team_handler::update_team_capabilities),
+        )
+        .route(
+            "/v1/teams/{id}/plan",
+            get(team_handler::get_team_plan).put(team_handler::update_team_plan),
+        )
+        .route("/v1/teams", post(team_member_handler::create_team))
+        .route(
+            "/v1/teams/{id}/members",
+            get(team_member_handler::list_team_members)
+                .post(team_member_handler::invite_team_member),
+        )
+        .route(
+            "/v1/teams/{id}/members/{user_id}",
+            delete(team_member_handler::remove_team_member),
+        )
+        .route(
+            "/v1/invitations/accept",
+            post(team_member_handler::accept_invitation),
+        )
+        .route("/v1/auth/oidc/login", get(sso_handler::oidc_login))
+        .route("/v1/auth/oidc/callback", get(sso_handler::oidc_callback))
+        .route("/v1/auth/logout", post(sso_handler::logout))
+        .route(
+            "/v1/keys/{id}/role",
+            get(api_key_handler::get_api_key_role).put(api_key_handler::update_api_key_role),
+        )
+        .route("/v1/exports", post(export_handler::create_export))
+        .route("/v1/exports/{id}", get(export_handler::get_export))
+        .route("/v1/data", delete(data_handler::erase_data))
         .route("/v1/audit/logs", get(audit_handler::get_audit_logs))
         .route("/v1/audit/denied", get(audit_handler::get_denied_requests))
+        .route(
+            "/v1/blocklist",
+            get(blocklist_handler::list_blocklist_entries)
+                .post(blocklist_handler::create_blocklist_entry),
+        )
+        .route(
+            "/v1/blocklist/{id}",
+            delete(blocklist_handler::delete_blocklist_entry),
+        )
+        .route(
+            "/v1/monitors",
+            get(monitor_handler::list_monitors).post(monitor_handler::create_monitor),
+        )
+        .route(
+            "/v1/monitors/{id}",
+            get(monitor_handler::get_monitor)
+                .put(monitor_handler::update_monitor)
+                .delete(monitor_handler::delete_monitor),
+        )
+        .route(
+            "/v1/notifications/email",
+            get(notification_handler::get_email_contacts)
+                .put(notification_handler::put_email_contacts)
+                .delete(notification_handler::delete_email_contacts),
+        )
+        .route(
+            "/v1/notifications/channels",
+            get(notification_handler::list_channels).post(notification_handler::create_channel),
+        )
+        .route(
+            "/v1/notifications/channels/{id}",
+            get(notification_handler::get_channel)
+                .put(notification_handler::update_channel)
+                .delete(notification_handler::delete_channel),
+        )
+        .route(
+            "/v1/notifications/deliveries",
+            get(notification_handler::list_deliveries),
+        )
         .route(
             "/v1/tasks/_query",
             post(task_handler::query_tasks::<TaskRepositoryImpl>),
@@ -70,14 +199,17 @@ pub fn routes() -> Router {
             "/v1/tasks/_cancel",
             post(task_handler::cancel_tasks::<TaskRepositoryImpl>),
         )
+        .route(
+            "/v2/tasks/{id}/timeline",
+            get(task_handler::get_task_timeline),
+        )
 }
 
 /// 健康检查端点（liveness probe）
 ///
 /// 这是 Kubernetes liveness probe — 总是返回 200 OK + "healthy"，
 /// 表示进程存活。不检查依赖（数据库、缓存）— 避免依赖短暂故障导致 pod 重启。
-/// 如需 readiness probe（检查依赖是否就绪），应单独实现 /ready endpoint
-/// 注入 AppState 检查数据库连接池、缓存可用性等。
+/// 依赖就绪检查见 `/health/ready`（`health_handler::ready_check`）。
 ///
 /// # 返回值
 ///
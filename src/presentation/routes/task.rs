@@ -3,11 +3,11 @@
 // Licensed under the Apache License, Version 2.0
 // See LICENSE file in the project root for full license information.
 
-use axum::routing::post;
+use axum::routing::{get, post};
 use axum::Router;
 
 use crate::infrastructure::repositories::task_repo_impl::TaskRepositoryImpl;
-use crate::presentation::handlers::task_handler;
+use crate::presentation::handlers::{debug_handler, task_handler};
 
 /// 创建任务相关路由
 ///
@@ -19,6 +19,8 @@ use crate::presentation::handlers::task_handler;
 ///
 /// - POST /v1/tasks/_query - 复杂查询使用 POST + _query 后缀
 /// - POST /v1/tasks/_cancel - 批量取消操作使用 POST + _cancel 后缀
+/// - GET /v2/tasks/{id}/timeline - 任务执行时间线
+/// - POST /v1/debug/replay/{task_id} - 用指定引擎回放抓取任务并对比结果
 pub fn task_routes() -> Router {
     Router::new()
         .route(
@@ -29,6 +31,14 @@ pub fn task_routes() -> Router {
             "/v1/tasks/_cancel",
             post(task_handler::cancel_tasks::<TaskRepositoryImpl>),
         )
+        .route(
+            "/v2/tasks/{id}/timeline",
+            get(task_handler::get_task_timeline),
+        )
+        .route(
+            "/v1/debug/replay/{task_id}",
+            post(debug_handler::replay_task),
+        )
 }
 
 #[cfg(test)]
@@ -30,16 +30,30 @@
 //! ```
 
 use std::sync::Arc;
+use std::time::Duration;
 
+use crate::application::use_cases::crawl_dry_run::CrawlDryRunUseCase;
 use crate::application::use_cases::crawl_use_case::CrawlUseCase;
 use crate::di::{CrawlRsState, CrawlRsStateExt};
 use crate::domain::repositories::{
-    crawl_repository::CrawlRepository, geo_restriction_repository::GeoRestrictionRepository,
+    crawl_link_repository::CrawlLinkRepository, crawl_repository::CrawlRepository,
+    domain_throttle_repository::DomainThrottleRepository,
+    geo_restriction_repository::GeoRestrictionRepository,
+    resource_tag_repository::ResourceTagRepository,
     scrape_result_repository::ScrapeResultRepository, task_repository::TaskRepository,
-    webhook_repository::WebhookRepository,
+    team_capability_repository::TeamCapabilityRepository, webhook_repository::WebhookRepository,
 };
+use crate::domain::services::audit_service::AuditServiceTrait;
+use crate::domain::services::plan_service::PlanService;
+use crate::domain::services::pricing_service::PricingService;
 use crate::domain::services::rate_limiting_service::RateLimitingService;
 use crate::domain::services::team_service::TeamService;
+use crate::domain::services::url_blocklist_service::UrlBlocklistService;
+use crate::infrastructure::database::repositories::crawl_link_repo_impl::CrawlLinkRepositoryImpl;
+use crate::infrastructure::database::repositories::domain_throttle_repo_impl::DomainThrottleRepositoryImpl;
+use crate::infrastructure::database::repositories::resource_tag_repo_impl::ResourceTagRepositoryImpl;
+use crate::infrastructure::database::repositories::team_capability_repo_impl::TeamCapabilityRepositoryImpl;
+use crate::queue::result_stream::ResultStreamBus;
 
 /// Trait for handler state access.
 ///
@@ -138,6 +152,28 @@ pub struct CrawlHandlerState {
     pub team_service: Arc<TeamService>,
     /// Rate limiting service
     pub rate_limiting_service: Arc<dyn RateLimitingService>,
+    /// Domain throttle repository (optional, reports adaptive throttling in crawl status)
+    pub domain_throttle_repo: Option<Arc<dyn DomainThrottleRepository>>,
+    /// URL blocklist (optional, rejects crawls of blocked domains at submission)
+    pub url_blocklist: Option<Arc<UrlBlocklistService>>,
+    /// Team capability repository (optional, gates `ignore_robots`; without it the option is rejected)
+    pub team_capability_repo: Option<Arc<dyn TeamCapabilityRepository>>,
+    /// Audit service (optional, records every use of `ignore_robots`)
+    pub audit_service: Option<Arc<dyn AuditServiceTrait>>,
+    /// Dry-run use case (optional, serves `dry_run` crawl requests; without it they are rejected)
+    pub dry_run: Option<CrawlDryRunUseCase>,
+    /// Pricing service (defaults to the built-in prices when not configured)
+    pub pricing: Arc<PricingService>,
+    /// Plan service (optional, caps `max_depth` by the team's plan when plans are enabled)
+    pub plan_service: Option<Arc<PlanService>>,
+    /// Crawl link repository (optional, serves the crawl link graph; without it the graph is empty)
+    pub crawl_link_repo: Option<Arc<dyn CrawlLinkRepository>>,
+    /// Result stream bus (optional, serves `?follow=true`; without it following is unavailable)
+    pub result_stream: Option<Arc<dyn ResultStreamBus>>,
+    /// Interval between crawl status checks while following results
+    pub result_stream_status_check: Duration,
+    /// Resource tag repository (optional, serves `tags` result filters; without it they are rejected)
+    pub resource_tag_repo: Option<Arc<dyn ResourceTagRepository>>,
 }
 
 impl CrawlHandlerState {
@@ -160,7 +196,92 @@ impl CrawlHandlerState {
             geo_restriction_repo,
             team_service,
             rate_limiting_service,
-        }
+            domain_throttle_repo: None,
+            url_blocklist: None,
+            team_capability_repo: None,
+            audit_service: None,
+            dry_run: None,
+            pricing: Arc::new(PricingService::default()),
+            plan_service: None,
+            crawl_link_repo: None,
+            result_stream: None,
+            result_stream_status_check: Duration::from_secs(15),
+            resource_tag_repo: None,
+        }
+    }
+
+    /// Attach a domain throttle repository so crawl status reports throttling.
+    pub fn with_domain_throttle_repo(
+        mut self,
+        domain_throttle_repo: Arc<dyn DomainThrottleRepository>,
+    ) -> Self {
+        self.domain_throttle_repo = Some(domain_throttle_repo);
+        self
+    }
+
+    /// Attach a URL blocklist so crawl submissions of blocked URLs are rejected.
+    pub fn with_url_blocklist(mut self, url_blocklist: Arc<UrlBlocklistService>) -> Self {
+        self.url_blocklist = Some(url_blocklist);
+        self
+    }
+
+    /// Attach a team capability repository so capable teams may ignore robots.txt.
+    pub fn with_team_capability_repo(
+        mut self,
+        team_capability_repo: Arc<dyn TeamCapabilityRepository>,
+    ) -> Self {
+        self.team_capability_repo = Some(team_capability_repo);
+        self
+    }
+
+    /// Attach an audit service for compliance-relevant crawl options.
+    pub fn with_audit_service(mut self, audit_service: Arc<dyn AuditServiceTrait>) -> Self {
+        self.audit_service = Some(audit_service);
+        self
+    }
+
+    /// Attach the dry-run use case so `dry_run` crawl requests can be served.
+    pub fn with_dry_run(mut self, dry_run: CrawlDryRunUseCase) -> Self {
+        self.dry_run = Some(dry_run);
+        self
+    }
+
+    /// Use the configured pricing table for crawl charges and dry-run estimates.
+    pub fn with_pricing_service(mut self, pricing: Arc<PricingService>) -> Self {
+        self.pricing = pricing;
+        self
+    }
+
+    /// Attach the plan service so crawl depth is capped by the team's plan.
+    pub fn with_plan_service(mut self, plan_service: Arc<PlanService>) -> Self {
+        self.plan_service = Some(plan_service);
+        self
+    }
+
+    /// Attach a crawl link repository so the crawl link graph can be exported.
+    pub fn with_crawl_link_repo(mut self, crawl_link_repo: Arc<dyn CrawlLinkRepository>) -> Self {
+        self.crawl_link_repo = Some(crawl_link_repo);
+        self
+    }
+
+    /// Attach a result stream bus so results can be followed while a crawl runs.
+    pub fn with_result_stream(
+        mut self,
+        result_stream: Arc<dyn ResultStreamBus>,
+        status_check: Duration,
+    ) -> Self {
+        self.result_stream = Some(result_stream);
+        self.result_stream_status_check = status_check;
+        self
+    }
+
+    /// Attach a resource tag repository so results can be filtered by tags.
+    pub fn with_resource_tag_repo(
+        mut self,
+        resource_tag_repo: Arc<dyn ResourceTagRepository>,
+    ) -> Self {
+        self.resource_tag_repo = Some(resource_tag_repo);
+        self
     }
 
     /// Create CrawlHandlerState from CrawlRsState.
@@ -176,6 +297,36 @@ impl CrawlHandlerState {
             geo_restriction_repo: app_state.geo_restriction_repo.clone(),
             team_service: app_state.team_service.clone(),
             rate_limiting_service: app_state.rate_limiting_service.clone(),
+            domain_throttle_repo: Some(Arc::new(DomainThrottleRepositoryImpl::new(
+                app_state.db_pool.clone(),
+            ))),
+            url_blocklist: None,
+            team_capability_repo: Some(Arc::new(TeamCapabilityRepositoryImpl::new(
+                app_state.db_pool.clone(),
+            ))),
+            audit_service: Some(app_state.audit_service.clone()),
+            dry_run: Some(CrawlDryRunUseCase::new(
+                app_state.engine_client.clone(),
+                app_state.robots_checker.clone(),
+                app_state.regex_cache.clone(),
+            )),
+            pricing: Arc::new(PricingService::default()),
+            plan_service: None,
+            crawl_link_repo: Some(Arc::new(CrawlLinkRepositoryImpl::new(
+                app_state.db_pool.clone(),
+            ))),
+            result_stream: Some(app_state.result_stream.clone()),
+            result_stream_status_check: Duration::from_secs(
+                app_state
+                    .reloadable_settings
+                    .current()
+                    .queue
+                    .result_stream
+                    .status_check_seconds,
+            ),
+            resource_tag_repo: Some(Arc::new(ResourceTagRepositoryImpl::new(
+                app_state.db_pool.clone(),
+            ))),
         }
     }
 
@@ -193,6 +344,18 @@ impl CrawlHandlerState {
             self.team_service.clone(),
         )
     }
+
+    /// Create the dry-run use case with the pricing and URL blocklist attached, if configured.
+    pub fn create_dry_run_use_case(&self) -> Option<CrawlDryRunUseCase> {
+        let dry_run = self
+            .dry_run
+            .clone()?
+            .with_pricing_service(self.pricing.clone());
+        Some(match &self.url_blocklist {
+            Some(url_blocklist) => dry_run.with_url_blocklist(url_blocklist.clone()),
+            None => dry_run,
+        })
+    }
 }
 
 impl HandlerState for CrawlHandlerState {
@@ -379,9 +542,41 @@ mod tests {
         async fn find_by_task_ids(&self, _task_ids: &[Uuid]) -> anyhow::Result<Vec<ScrapeResult>> {
             Ok(vec![])
         }
+        async fn find_latest_for_crawl_url(
+            &self,
+            _crawl_id: Uuid,
+            _url: &str,
+        ) -> anyhow::Result<Option<ScrapeResult>> {
+            Ok(None)
+        }
+        async fn count_not_modified(&self, _task_ids: &[Uuid]) -> anyhow::Result<u64> {
+            Ok(0)
+        }
+        async fn response_times(&self, _task_ids: &[Uuid]) -> anyhow::Result<Vec<i64>> {
+            Ok(Vec::new())
+        }
         async fn get_team_avg_response_time(&self, _team_id: Uuid) -> anyhow::Result<f64> {
             Ok(0.0)
         }
+
+        async fn delete_for_team(
+            &self,
+            _team_id: Uuid,
+            _filter: &crate::domain::repositories::scrape_result_repository::ResultDeletionFilter,
+        ) -> anyhow::Result<Vec<ScrapeResult>> {
+            Ok(vec![])
+        }
+
+        async fn search_crawl_results(
+            &self,
+            _crawl_id: Uuid,
+            _query: &str,
+            _limit: u64,
+        ) -> anyhow::Result<
+            Vec<crate::domain::repositories::scrape_result_repository::ResultSearchHit>,
+        > {
+            Ok(vec![])
+        }
     }
 
     struct MockGeoRestrictionRepository;
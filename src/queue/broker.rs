@@ -0,0 +1,89 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! 消息中间件队列后端共用的优先级档位与任务消息
+//!
+//! 中间件只负责分发任务 ID，任务本身与状态始终保存在数据库中。
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+pub use crate::domain::models::PriorityTier;
+use crate::domain::models::Task;
+
+impl PriorityTier {
+    /// 档位对应的主题或队列名
+    pub fn topic(&self, prefix: &str) -> String {
+        format!("{}.{}", prefix, self.as_str())
+    }
+}
+
+/// 投递到消息中间件的任务消息，只携带定位任务所需的信息
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TaskMessage {
+    /// 任务 ID
+    pub task_id: Uuid,
+    /// 所属团队，同时作为消息 key，保证同一团队的任务落在同一分区
+    pub team_id: Uuid,
+    /// 任务优先级
+    pub priority: i32,
+}
+
+impl TaskMessage {
+    /// 从任务构造消息
+    pub fn from_task(task: &Task) -> Self {
+        Self {
+            task_id: task.id,
+            team_id: task.team_id,
+            priority: task.priority,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::TaskType;
+
+    #[test]
+    fn test_tier_topics_use_prefix() {
+        let topics: Vec<String> = PriorityTier::ALL
+            .iter()
+            .map(|tier| tier.topic("crawlrs.tasks"))
+            .collect();
+        assert_eq!(
+            topics,
+            vec![
+                "crawlrs.tasks.high",
+                "crawlrs.tasks.normal",
+                "crawlrs.tasks.low"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_task_message_round_trip() {
+        let mut task = Task::new(
+            Uuid::new_v4(),
+            TaskType::Scrape,
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            "https://example.com".to_string(),
+            serde_json::json!({}),
+        );
+        task.priority = 100;
+
+        let message = TaskMessage::from_task(&task);
+        let bytes = serde_json::to_vec(&message).unwrap();
+        let decoded: TaskMessage = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(decoded, message);
+        assert_eq!(decoded.task_id, task.id);
+        assert_eq!(decoded.team_id, task.team_id);
+        assert_eq!(
+            PriorityTier::for_priority(decoded.priority),
+            PriorityTier::Low
+        );
+    }
+}
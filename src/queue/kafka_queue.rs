@@ -0,0 +1,275 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! Kafka 任务队列
+//!
+//! 任务先写入数据库，再把任务 ID 按优先级投递到 `{topic_prefix}.high`、`.normal`、
+//! `.low` 三个主题。所有 Worker 属于同一消费组，出队时按 high → normal → low 顺序
+//! 拉取，拉到的任务通过 [`TaskRepository::acquire_by_id`] 原子领取；已不在排队状态
+//! 的任务（重复投递、已取消）直接跳过。
+//!
+//! 偏移量关闭自动提交，在任务处理结束后手动提交：同一 Worker 下一次出队，或经由
+//! 队列完成、失败、取消任务时提交。进程崩溃后未提交的消息会被重新投递。
+//!
+//! Kafka 中暂无消息时回退到数据库领取，重试、延后以及锁过期的任务由此继续执行，
+//! 投递失败的任务也不会丢失。
+//!
+//! [`TaskRepository::acquire_by_id`]: crate::domain::repositories::task_repository::TaskRepository::acquire_by_id
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use log::{debug, warn};
+use parking_lot::Mutex;
+use rdkafka::config::ClientConfig;
+use rdkafka::consumer::{CommitMode, Consumer, StreamConsumer};
+use rdkafka::error::KafkaError;
+use rdkafka::message::Message;
+use rdkafka::producer::{FutureProducer, FutureRecord};
+use rdkafka::{Offset, TopicPartitionList};
+use uuid::Uuid;
+
+use super::broker::{PriorityTier, TaskMessage};
+use super::task_queue::{PostgresTaskQueue, QueueError, TaskQueue};
+use crate::config::settings::KafkaQueueSettings;
+use crate::domain::models::Task;
+
+impl From<KafkaError> for QueueError {
+    fn from(err: KafkaError) -> Self {
+        QueueError::Broker(err.to_string())
+    }
+}
+
+/// 已领取但尚未提交的消息位置
+#[derive(Debug, Clone)]
+struct PendingOffset {
+    task_id: Uuid,
+    tier: PriorityTier,
+    topic: String,
+    partition: i32,
+    offset: i64,
+}
+
+/// Kafka 任务队列实现
+pub struct KafkaTaskQueue {
+    /// 负责任务持久化、数据库回退领取与事件记录
+    inner: PostgresTaskQueue,
+    producer: FutureProducer,
+    consumers: Vec<(PriorityTier, StreamConsumer)>,
+    topic_prefix: String,
+    poll_timeout: Duration,
+    produce_timeout: Duration,
+    /// 按 Worker 记录正在处理的消息，处理结束后提交
+    in_flight: Mutex<HashMap<Uuid, PendingOffset>>,
+}
+
+impl KafkaTaskQueue {
+    /// 创建 Kafka 任务队列
+    ///
+    /// # 参数
+    ///
+    /// * `inner` - 数据库任务队列，任务状态始终以数据库为准
+    /// * `settings` - Kafka 配置
+    ///
+    /// # 返回值
+    ///
+    /// * `Ok(KafkaTaskQueue)` - 生产者创建成功且消费者已订阅各档位主题
+    /// * `Err(QueueError)` - 客户端配置无效
+    pub fn new(
+        inner: PostgresTaskQueue,
+        settings: &KafkaQueueSettings,
+    ) -> Result<Self, QueueError> {
+        // 幂等生产者 + acks=all，broker 故障切换时既不丢消息也不重复写入
+        let producer: FutureProducer = ClientConfig::new()
+            .set("bootstrap.servers", &settings.brokers)
+            .set("enable.idempotence", "true")
+            .set("acks", "all")
+            .create()?;
+
+        let mut consumers = Vec::with_capacity(PriorityTier::ALL.len());
+        for tier in PriorityTier::ALL {
+            let consumer: StreamConsumer = ClientConfig::new()
+                .set("bootstrap.servers", &settings.brokers)
+                .set("group.id", &settings.group_id)
+                .set("enable.auto.commit", "false")
+                .set("auto.offset.reset", "earliest")
+                .create()?;
+            let topic = tier.topic(&settings.topic_prefix);
+            consumer.subscribe(&[topic.as_str()])?;
+            consumers.push((tier, consumer));
+        }
+
+        Ok(Self {
+            inner,
+            producer,
+            consumers,
+            topic_prefix: settings.topic_prefix.clone(),
+            poll_timeout: Duration::from_millis(settings.poll_timeout_ms),
+            produce_timeout: Duration::from_millis(settings.produce_timeout_ms),
+            in_flight: Mutex::new(HashMap::new()),
+        })
+    }
+
+    fn consumer(&self, tier: PriorityTier) -> &StreamConsumer {
+        self.consumers
+            .iter()
+            .find(|(t, _)| *t == tier)
+            .map(|(_, consumer)| consumer)
+            .expect("a consumer is created for every tier")
+    }
+
+    /// 提交消息位置，提交失败只记录日志，消息可能在重平衡后重复投递
+    fn commit(&self, pending: &PendingOffset) {
+        let mut partitions = TopicPartitionList::new();
+        let result = partitions
+            .add_partition_offset(
+                &pending.topic,
+                pending.partition,
+                Offset::Offset(pending.offset + 1),
+            )
+            .and_then(|_| {
+                self.consumer(pending.tier)
+                    .commit(&partitions, CommitMode::Async)
+            });
+        if let Err(e) = result {
+            warn!(
+                "Failed to commit offset {} of {}[{}]: {}",
+                pending.offset, pending.topic, pending.partition, e
+            );
+        }
+    }
+
+    /// Worker 开始下一次出队时，上一条消息已处理完毕
+    fn commit_worker(&self, worker_id: Uuid) {
+        let pending = self.in_flight.lock().remove(&worker_id);
+        if let Some(pending) = pending {
+            self.commit(&pending);
+        }
+    }
+
+    fn commit_task(&self, task_id: Uuid) {
+        let pending = {
+            let mut in_flight = self.in_flight.lock();
+            let worker_id = in_flight
+                .iter()
+                .find(|(_, pending)| pending.task_id == task_id)
+                .map(|(worker_id, _)| *worker_id);
+            worker_id.and_then(|worker_id| in_flight.remove(&worker_id))
+        };
+        if let Some(pending) = pending {
+            self.commit(&pending);
+        }
+    }
+
+    /// 从一个档位拉取并领取任务，档位内暂无可领取的任务时返回 `None`
+    async fn poll_tier(
+        &self,
+        tier: PriorityTier,
+        worker_id: Uuid,
+    ) -> Result<Option<Task>, QueueError> {
+        let consumer = self.consumer(tier);
+        loop {
+            // BorrowedMessage 不能跨 await 持有，先取出位置与内容
+            let (pending, payload) = {
+                let message = match tokio::time::timeout(self.poll_timeout, consumer.recv()).await {
+                    Err(_) => return Ok(None),
+                    Ok(Err(e)) => {
+                        warn!("Failed to poll {} tier: {}", tier.as_str(), e);
+                        return Ok(None);
+                    }
+                    Ok(Ok(message)) => message,
+                };
+                let pending = PendingOffset {
+                    task_id: Uuid::nil(),
+                    tier,
+                    topic: message.topic().to_string(),
+                    partition: message.partition(),
+                    offset: message.offset(),
+                };
+                let payload = message
+                    .payload()
+                    .and_then(|payload| serde_json::from_slice::<TaskMessage>(payload).ok());
+                (pending, payload)
+            };
+            let Some(payload) = payload else {
+                warn!(
+                    "Skipping malformed message at {}[{}]@{}",
+                    pending.topic, pending.partition, pending.offset
+                );
+                self.commit(&pending);
+                continue;
+            };
+
+            match self.inner.claim(payload.task_id, worker_id).await? {
+                Some(task) => {
+                    self.in_flight.lock().insert(
+                        worker_id,
+                        PendingOffset {
+                            task_id: task.id,
+                            ..pending
+                        },
+                    );
+                    return Ok(Some(task));
+                }
+                None => {
+                    debug!("Task {} is no longer queued, skipping", payload.task_id);
+                    self.commit(&pending);
+                }
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl TaskQueue for KafkaTaskQueue {
+    /// 入队任务：写入数据库后投递到对应档位的主题
+    ///
+    /// 投递失败只记录日志，任务仍会经由数据库回退路径被领取
+    async fn enqueue(&self, task: Task) -> Result<Task, QueueError> {
+        let created = self.inner.enqueue(task).await?;
+        let topic = PriorityTier::for_priority(created.priority).topic(&self.topic_prefix);
+        let payload = serde_json::to_vec(&TaskMessage::from_task(&created))
+            .map_err(|e| QueueError::Broker(e.to_string()))?;
+        let key = created.team_id.to_string();
+        let record = FutureRecord::to(&topic).key(&key).payload(&payload);
+        if let Err((e, _)) = self.producer.send(record, self.produce_timeout).await {
+            warn!("Failed to publish task {} to {}: {}", created.id, topic, e);
+        }
+        Ok(created)
+    }
+
+    /// 出队任务：按档位拉取，Kafka 暂无消息时回退到数据库领取
+    async fn dequeue(&self, worker_id: Uuid) -> Result<Option<Task>, QueueError> {
+        self.commit_worker(worker_id);
+        for tier in PriorityTier::ALL {
+            if let Some(task) = self.poll_tier(tier, worker_id).await? {
+                return Ok(Some(task));
+            }
+        }
+        self.inner.dequeue(worker_id).await
+    }
+
+    /// 完成任务并提交对应消息
+    async fn complete(&self, task_id: Uuid) -> Result<(), QueueError> {
+        self.inner.complete(task_id).await?;
+        self.commit_task(task_id);
+        Ok(())
+    }
+
+    /// 失败任务并提交对应消息，重试由数据库回退路径领取
+    async fn fail(&self, task_id: Uuid) -> Result<(), QueueError> {
+        self.inner.fail(task_id).await?;
+        self.commit_task(task_id);
+        Ok(())
+    }
+
+    /// 取消任务并提交对应消息
+    async fn cancel(&self, task_id: Uuid) -> Result<(), QueueError> {
+        self.inner.cancel(task_id).await?;
+        self.commit_task(task_id);
+        Ok(())
+    }
+}
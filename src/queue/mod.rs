@@ -13,4 +13,38 @@
 /// 提供统一的任务队列接口，负责任务的排队、调度和执行管理。
 pub mod task_queue;
 
+/// 加权优先级调度
+pub mod priority_scheduler;
+
+/// 延迟任务调度
+pub mod scheduler;
+
+/// 消息中间件后端共用的优先级档位与任务消息
+pub mod broker;
+
+/// 爬取结果实时推送
+pub mod result_stream;
+
+/// Kafka 任务队列后端
+#[cfg(feature = "queue-kafka")]
+pub mod kafka_queue;
+
+/// RabbitMQ 任务队列后端
+#[cfg(feature = "queue-rabbitmq")]
+pub mod rabbitmq_queue;
+
+pub use self::broker::{PriorityTier, TaskMessage};
+#[cfg(feature = "queue-kafka")]
+pub use self::kafka_queue::KafkaTaskQueue;
+pub use self::priority_scheduler::PriorityScheduler;
+#[cfg(feature = "queue-rabbitmq")]
+pub use self::rabbitmq_queue::RabbitMqTaskQueue;
+#[cfg(feature = "queue-redis")]
+pub use self::result_stream::RedisResultStreamBus;
+pub use self::result_stream::{
+    result_stream_from_settings, CrawlStreamEvent, InMemoryResultStreamBus, ResultStreamBus,
+};
+#[cfg(feature = "queue-redis")]
+pub use self::scheduler::RedisDueIndex;
+pub use self::scheduler::{DelayedTaskScheduler, DueIndex, InMemoryDueIndex};
 pub use self::task_queue::{PostgresTaskQueue, QueueError, TaskQueue};
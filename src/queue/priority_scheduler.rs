@@ -0,0 +1,121 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! 加权优先级调度
+//!
+//! 每次出队按权重轮转选出首选档位，首选档位没有任务时按高 → 普通 → 低依次尝试其余档位。
+//! 默认权重 6:3:1，即繁忙时每 10 次出队中高、普通、低档位分别优先 6、3、1 次，
+//! 低优先级任务不会被完全饿死；排队时间每满 `aging` 再提升一个档位。
+
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use crate::config::settings::PrioritySchedulingSettings;
+use crate::domain::models::PriorityTier;
+
+/// 加权优先级调度器
+#[derive(Debug)]
+pub struct PriorityScheduler {
+    /// 高、普通、低档位的权重
+    weights: [u32; 3],
+    /// 排队时间每满该时长提升一个档位
+    aging: chrono::Duration,
+    /// 出队计数，用于加权轮转
+    counter: AtomicU64,
+}
+
+impl PriorityScheduler {
+    /// 创建调度器，权重全为 0 时按 1:1:1 处理
+    ///
+    /// # 参数
+    ///
+    /// * `weights` - 高、普通、低档位的权重
+    /// * `aging` - 老化间隔
+    pub fn new(weights: [u32; 3], aging: chrono::Duration) -> Self {
+        let weights = if weights.iter().all(|w| *w == 0) {
+            [1, 1, 1]
+        } else {
+            weights
+        };
+        Self {
+            weights,
+            aging,
+            counter: AtomicU64::new(0),
+        }
+    }
+
+    /// 从配置创建调度器
+    pub fn from_settings(settings: &PrioritySchedulingSettings) -> Self {
+        Self::new(
+            [
+                settings.high_weight,
+                settings.normal_weight,
+                settings.low_weight,
+            ],
+            chrono::Duration::seconds(settings.aging_seconds as i64),
+        )
+    }
+
+    /// 老化间隔
+    pub fn aging(&self) -> chrono::Duration {
+        self.aging
+    }
+
+    /// 本次出队尝试的档位顺序：首选档位在前，其余按优先级从高到低
+    pub fn next_order(&self) -> [PriorityTier; 3] {
+        let total: u64 = self.weights.iter().map(|w| u64::from(*w)).sum();
+        let mut slot = self.counter.fetch_add(1, Ordering::Relaxed) % total;
+        let mut preferred = PriorityTier::High;
+        for (tier, weight) in PriorityTier::ALL.into_iter().zip(self.weights) {
+            if slot < u64::from(weight) {
+                preferred = tier;
+                break;
+            }
+            slot -= u64::from(weight);
+        }
+
+        let mut order = [preferred; 3];
+        let rest = PriorityTier::ALL
+            .into_iter()
+            .filter(|tier| *tier != preferred);
+        for (position, tier) in rest.enumerate() {
+            order[position + 1] = tier;
+        }
+        order
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn preferred_counts(scheduler: &PriorityScheduler, rounds: usize) -> [usize; 3] {
+        let mut counts = [0; 3];
+        for _ in 0..rounds {
+            counts[scheduler.next_order()[0].lane() as usize] += 1;
+        }
+        counts
+    }
+
+    #[test]
+    fn test_next_order_follows_weights() {
+        let scheduler = PriorityScheduler::new([6, 3, 1], chrono::Duration::seconds(300));
+        assert_eq!(preferred_counts(&scheduler, 100), [60, 30, 10]);
+    }
+
+    #[test]
+    fn test_next_order_falls_back_by_priority() {
+        let scheduler = PriorityScheduler::new([0, 0, 1], chrono::Duration::seconds(300));
+        assert_eq!(
+            scheduler.next_order(),
+            [PriorityTier::Low, PriorityTier::High, PriorityTier::Normal]
+        );
+    }
+
+    #[test]
+    fn test_all_zero_weights_rotate_evenly() {
+        let scheduler = PriorityScheduler::new([0, 0, 0], chrono::Duration::seconds(300));
+        assert_eq!(preferred_counts(&scheduler, 9), [3, 3, 3]);
+    }
+}
@@ -0,0 +1,376 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! RabbitMQ 任务队列
+//!
+//! 任务先写入数据库，再把任务 ID 按优先级发布到 `{queue_prefix}.high`、`.normal`、
+//! `.low` 三个持久化队列。发布开启 publisher confirms，broker 确认落盘后才算投递成功。
+//! 每个队列注册一个消费者，broker 按 `prefetch_count` 主动推送消息；出队时按
+//! high → normal → low 顺序取出已推送的消息，并通过数据库原子领取任务。
+//!
+//! 消息在任务处理结束后才确认：同一 Worker 下一次出队，或经由队列完成、取消任务时
+//! ack；经由队列标记失败的任务 nack 且不重新入队，由死信交换机 `{queue_prefix}.dlx`
+//! 转入 `{queue_prefix}.dead`，无法解析的消息同样进入死信队列。连接断开时未确认的
+//! 消息由 broker 重新投递，已不在排队状态的任务会被跳过。
+//!
+//! 各队列暂无消息时回退到数据库领取，重试、延后以及锁过期的任务由此继续执行。
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use futures::StreamExt;
+use lapin::acker::Acker;
+use lapin::options::{
+    BasicAckOptions, BasicConsumeOptions, BasicNackOptions, BasicPublishOptions, BasicQosOptions,
+    ConfirmSelectOptions, ExchangeDeclareOptions, QueueBindOptions, QueueDeclareOptions,
+};
+use lapin::types::{AMQPValue, FieldTable};
+use lapin::{BasicProperties, Channel, Connection, ConnectionProperties, Consumer, ExchangeKind};
+use log::{debug, info, warn};
+use parking_lot::Mutex;
+use uuid::Uuid;
+
+use super::broker::{PriorityTier, TaskMessage};
+use super::task_queue::{PostgresTaskQueue, QueueError, TaskQueue};
+use crate::config::settings::RabbitMqQueueSettings;
+use crate::domain::models::Task;
+
+impl From<lapin::Error> for QueueError {
+    fn from(err: lapin::Error) -> Self {
+        QueueError::Broker(err.to_string())
+    }
+}
+
+/// 死信交换机名称
+pub fn dead_letter_exchange(prefix: &str) -> String {
+    format!("{}.dlx", prefix)
+}
+
+/// 死信队列名称
+pub fn dead_letter_queue(prefix: &str) -> String {
+    format!("{}.dead", prefix)
+}
+
+/// 优先级队列的声明参数，被拒绝的消息转入死信交换机
+fn queue_arguments(prefix: &str) -> FieldTable {
+    let mut arguments = FieldTable::default();
+    arguments.insert(
+        "x-dead-letter-exchange".into(),
+        AMQPValue::LongString(dead_letter_exchange(prefix).into()),
+    );
+    arguments
+}
+
+/// 已领取但尚未确认的消息
+struct PendingDelivery {
+    task_id: Uuid,
+    acker: Acker,
+}
+
+/// RabbitMQ 任务队列实现
+pub struct RabbitMqTaskQueue {
+    /// 负责任务持久化、数据库回退领取与事件记录
+    inner: PostgresTaskQueue,
+    /// 保持连接存活，连接关闭后通道与消费者随之失效
+    _connection: Connection,
+    channel: Channel,
+    consumers: Vec<(PriorityTier, tokio::sync::Mutex<Consumer>)>,
+    queue_prefix: String,
+    poll_timeout: Duration,
+    /// 按 Worker 记录正在处理的消息，处理结束后确认
+    in_flight: Mutex<HashMap<Uuid, PendingDelivery>>,
+}
+
+impl RabbitMqTaskQueue {
+    /// 连接 RabbitMQ 并声明交换机与队列
+    ///
+    /// # 参数
+    ///
+    /// * `inner` - 数据库任务队列，任务状态始终以数据库为准
+    /// * `settings` - RabbitMQ 配置
+    ///
+    /// # 返回值
+    ///
+    /// * `Ok(RabbitMqTaskQueue)` - 连接成功且已开始消费各优先级队列
+    /// * `Err(QueueError)` - 连接或声明失败
+    pub async fn connect(
+        inner: PostgresTaskQueue,
+        settings: &RabbitMqQueueSettings,
+    ) -> Result<Self, QueueError> {
+        let prefix = settings.queue_prefix.as_str();
+        let connection =
+            Connection::connect(settings.url(), ConnectionProperties::default()).await?;
+        let channel = connection.create_channel().await?;
+        channel
+            .confirm_select(ConfirmSelectOptions::default())
+            .await?;
+        channel
+            .basic_qos(settings.prefetch_count, BasicQosOptions::default())
+            .await?;
+
+        let dead_letter_exchange = dead_letter_exchange(prefix);
+        let dead_letter_queue = dead_letter_queue(prefix);
+        channel
+            .exchange_declare(
+                &dead_letter_exchange,
+                ExchangeKind::Fanout,
+                ExchangeDeclareOptions {
+                    durable: true,
+                    ..Default::default()
+                },
+                FieldTable::default(),
+            )
+            .await?;
+        channel
+            .queue_declare(
+                &dead_letter_queue,
+                QueueDeclareOptions {
+                    durable: true,
+                    ..Default::default()
+                },
+                FieldTable::default(),
+            )
+            .await?;
+        channel
+            .queue_bind(
+                &dead_letter_queue,
+                &dead_letter_exchange,
+                "",
+                QueueBindOptions::default(),
+                FieldTable::default(),
+            )
+            .await?;
+
+        let mut consumers = Vec::with_capacity(PriorityTier::ALL.len());
+        for tier in PriorityTier::ALL {
+            let queue = tier.topic(prefix);
+            channel
+                .queue_declare(
+                    &queue,
+                    QueueDeclareOptions {
+                        durable: true,
+                        ..Default::default()
+                    },
+                    queue_arguments(prefix),
+                )
+                .await?;
+            let consumer = channel
+                .basic_consume(
+                    &queue,
+                    &format!("crawlrs-{}-{}", tier.as_str(), Uuid::new_v4()),
+                    BasicConsumeOptions::default(),
+                    FieldTable::default(),
+                )
+                .await?;
+            consumers.push((tier, tokio::sync::Mutex::new(consumer)));
+        }
+        info!("Consuming RabbitMQ task queues with prefix {}", prefix);
+
+        Ok(Self {
+            inner,
+            _connection: connection,
+            channel,
+            consumers,
+            queue_prefix: settings.queue_prefix.clone(),
+            poll_timeout: Duration::from_millis(settings.poll_timeout_ms),
+            in_flight: Mutex::new(HashMap::new()),
+        })
+    }
+
+    fn consumer(&self, tier: PriorityTier) -> &tokio::sync::Mutex<Consumer> {
+        self.consumers
+            .iter()
+            .find(|(t, _)| *t == tier)
+            .map(|(_, consumer)| consumer)
+            .expect("a consumer is created for every tier")
+    }
+
+    /// 确认消息；`dead_letter` 为 true 时拒绝且不重新入队，消息转入死信队列
+    async fn settle(&self, acker: &Acker, dead_letter: bool) {
+        let result = if dead_letter {
+            acker
+                .nack(BasicNackOptions {
+                    requeue: false,
+                    ..Default::default()
+                })
+                .await
+        } else {
+            acker.ack(BasicAckOptions::default()).await
+        };
+        if let Err(e) = result {
+            warn!("Failed to settle RabbitMQ delivery: {}", e);
+        }
+    }
+
+    /// Worker 开始下一次出队时，上一条消息已处理完毕
+    async fn settle_worker(&self, worker_id: Uuid) {
+        let pending = self.in_flight.lock().remove(&worker_id);
+        if let Some(pending) = pending {
+            self.settle(&pending.acker, false).await;
+        }
+    }
+
+    async fn settle_task(&self, task_id: Uuid, dead_letter: bool) {
+        let pending = {
+            let mut in_flight = self.in_flight.lock();
+            let worker_id = in_flight
+                .iter()
+                .find(|(_, pending)| pending.task_id == task_id)
+                .map(|(worker_id, _)| *worker_id);
+            worker_id.and_then(|worker_id| in_flight.remove(&worker_id))
+        };
+        if let Some(pending) = pending {
+            self.settle(&pending.acker, dead_letter).await;
+        }
+    }
+
+    /// 从一个优先级队列取出并领取任务，队列内暂无可领取的任务时返回 `None`
+    async fn poll_tier(
+        &self,
+        tier: PriorityTier,
+        worker_id: Uuid,
+    ) -> Result<Option<Task>, QueueError> {
+        loop {
+            let delivery = {
+                let mut consumer = self.consumer(tier).lock().await;
+                match tokio::time::timeout(self.poll_timeout, consumer.next()).await {
+                    Err(_) => return Ok(None),
+                    Ok(None) => {
+                        warn!("RabbitMQ consumer for {} tier is closed", tier.as_str());
+                        return Ok(None);
+                    }
+                    Ok(Some(Err(e))) => {
+                        warn!("Failed to receive from {} tier: {}", tier.as_str(), e);
+                        return Ok(None);
+                    }
+                    Ok(Some(Ok(delivery))) => delivery,
+                }
+            };
+
+            let Ok(message) = serde_json::from_slice::<TaskMessage>(&delivery.data) else {
+                warn!(
+                    "Dead-lettering malformed message {} from {} tier",
+                    delivery.delivery_tag,
+                    tier.as_str()
+                );
+                self.settle(&delivery.acker, true).await;
+                continue;
+            };
+
+            match self.inner.claim(message.task_id, worker_id).await? {
+                Some(task) => {
+                    self.in_flight.lock().insert(
+                        worker_id,
+                        PendingDelivery {
+                            task_id: task.id,
+                            acker: delivery.acker,
+                        },
+                    );
+                    return Ok(Some(task));
+                }
+                None => {
+                    debug!("Task {} is no longer queued, skipping", message.task_id);
+                    self.settle(&delivery.acker, false).await;
+                }
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl TaskQueue for RabbitMqTaskQueue {
+    /// 入队任务：写入数据库后发布到对应优先级队列，等待 broker 确认
+    ///
+    /// 发布失败或被 broker 拒绝只记录日志，任务仍会经由数据库回退路径被领取
+    async fn enqueue(&self, task: Task) -> Result<Task, QueueError> {
+        let created = self.inner.enqueue(task).await?;
+        let queue = PriorityTier::for_priority(created.priority).topic(&self.queue_prefix);
+        let payload = serde_json::to_vec(&TaskMessage::from_task(&created))
+            .map_err(|e| QueueError::Broker(e.to_string()))?;
+        let properties = BasicProperties::default()
+            .with_delivery_mode(2)
+            .with_content_type("application/json".into());
+
+        let confirmation = match self
+            .channel
+            .basic_publish(
+                "",
+                &queue,
+                BasicPublishOptions::default(),
+                &payload,
+                properties,
+            )
+            .await
+        {
+            Ok(pending) => pending.await,
+            Err(e) => Err(e),
+        };
+        match confirmation {
+            Ok(confirmation) if confirmation.is_nack() => {
+                warn!("RabbitMQ rejected task {} on {}", created.id, queue);
+            }
+            Ok(_) => {}
+            Err(e) => warn!("Failed to publish task {} to {}: {}", created.id, queue, e),
+        }
+        Ok(created)
+    }
+
+    /// 出队任务：按优先级取出推送的消息，各队列暂无消息时回退到数据库领取
+    async fn dequeue(&self, worker_id: Uuid) -> Result<Option<Task>, QueueError> {
+        self.settle_worker(worker_id).await;
+        for tier in PriorityTier::ALL {
+            if let Some(task) = self.poll_tier(tier, worker_id).await? {
+                return Ok(Some(task));
+            }
+        }
+        self.inner.dequeue(worker_id).await
+    }
+
+    /// 完成任务并确认对应消息
+    async fn complete(&self, task_id: Uuid) -> Result<(), QueueError> {
+        self.inner.complete(task_id).await?;
+        self.settle_task(task_id, false).await;
+        Ok(())
+    }
+
+    /// 失败任务，对应消息转入死信队列
+    async fn fail(&self, task_id: Uuid) -> Result<(), QueueError> {
+        self.inner.fail(task_id).await?;
+        self.settle_task(task_id, true).await;
+        Ok(())
+    }
+
+    /// 取消任务并确认对应消息
+    async fn cancel(&self, task_id: Uuid) -> Result<(), QueueError> {
+        self.inner.cancel(task_id).await?;
+        self.settle_task(task_id, false).await;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lapin::types::ShortString;
+
+    #[test]
+    fn test_dead_letter_names_use_prefix() {
+        assert_eq!(dead_letter_exchange("crawlrs.tasks"), "crawlrs.tasks.dlx");
+        assert_eq!(dead_letter_queue("crawlrs.tasks"), "crawlrs.tasks.dead");
+    }
+
+    #[test]
+    fn test_priority_queues_dead_letter_to_exchange() {
+        let arguments = queue_arguments("crawlrs.tasks");
+        let exchange = arguments
+            .inner()
+            .get(&ShortString::from("x-dead-letter-exchange"));
+        assert_eq!(
+            exchange,
+            Some(&AMQPValue::LongString("crawlrs.tasks.dlx".into()))
+        );
+    }
+}
@@ -0,0 +1,263 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! 爬取结果实时推送总线
+//!
+//! Worker 保存爬取结果后向总线发布 [`CrawlStreamEvent::Result`]，爬取结束时发布
+//! [`CrawlStreamEvent::Finished`]；API 进程按爬取订阅，并通过
+//! `GET /v1/crawl/{id}/results?follow=true` 以 SSE 推送给客户端，下游流水线无需
+//! 等待爬取结束即可开始处理。
+//!
+//! 总线只传递结果 ID，结果内容由 API 进程从数据库读取。消息不持久化，订阅之前
+//! 发布的事件不会重放，订阅方需先读取已保存的结果再转发实时事件。
+//!
+//! 总线有两种实现：[`InMemoryResultStreamBus`] 只在 API 与 Worker 同进程运行时
+//! 可用；`RedisResultStreamBus` 基于 Redis 发布/订阅，每个爬取使用独立频道。
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use futures::stream::{BoxStream, StreamExt};
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use tokio::sync::broadcast;
+use uuid::Uuid;
+
+use super::task_queue::QueueError;
+use crate::config::settings::ResultStreamSettings;
+use crate::domain::models::CrawlStatus;
+
+/// 进程内总线每个爬取缓存的事件数，订阅方落后更多时丢弃最旧的事件
+const IN_MEMORY_CHANNEL_CAPACITY: usize = 256;
+
+/// 爬取结果推送事件
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum CrawlStreamEvent {
+    /// 新保存了一个结果
+    Result { task_id: Uuid, result_id: Uuid },
+    /// 爬取已结束，不会再有新结果
+    Finished { status: CrawlStatus },
+}
+
+/// 爬取结果推送总线
+#[async_trait]
+pub trait ResultStreamBus: Send + Sync {
+    /// 向爬取的订阅方发布事件，没有订阅方时事件被丢弃
+    async fn publish(&self, crawl_id: Uuid, event: &CrawlStreamEvent) -> Result<(), QueueError>;
+
+    /// 订阅爬取的事件，只接收订阅之后发布的事件
+    async fn subscribe(
+        &self,
+        crawl_id: Uuid,
+    ) -> Result<BoxStream<'static, CrawlStreamEvent>, QueueError>;
+}
+
+/// 进程内的推送总线
+#[derive(Debug, Default)]
+pub struct InMemoryResultStreamBus {
+    channels: Mutex<HashMap<Uuid, broadcast::Sender<CrawlStreamEvent>>>,
+}
+
+impl InMemoryResultStreamBus {
+    /// 创建总线
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl ResultStreamBus for InMemoryResultStreamBus {
+    async fn publish(&self, crawl_id: Uuid, event: &CrawlStreamEvent) -> Result<(), QueueError> {
+        let mut channels = self.channels.lock();
+        if let Some(sender) = channels.get(&crawl_id) {
+            // 所有订阅方都已断开时发送失败，顺带清理频道
+            let delivered = sender.send(event.clone()).is_ok();
+            if !delivered || matches!(event, CrawlStreamEvent::Finished { .. }) {
+                channels.remove(&crawl_id);
+            }
+        }
+        Ok(())
+    }
+
+    async fn subscribe(
+        &self,
+        crawl_id: Uuid,
+    ) -> Result<BoxStream<'static, CrawlStreamEvent>, QueueError> {
+        let receiver = self
+            .channels
+            .lock()
+            .entry(crawl_id)
+            .or_insert_with(|| broadcast::channel(IN_MEMORY_CHANNEL_CAPACITY).0)
+            .subscribe();
+        let stream = futures::stream::unfold(receiver, |mut receiver| async move {
+            loop {
+                match receiver.recv().await {
+                    Ok(event) => return Some((event, receiver)),
+                    // 落后的订阅方跳过丢失的事件，结束时会补发遗漏的结果
+                    Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                    Err(broadcast::error::RecvError::Closed) => return None,
+                }
+            }
+        });
+        Ok(stream.boxed())
+    }
+}
+
+/// Redis 发布/订阅实现的推送总线，频道为 `{channel_prefix}:{crawl_id}`
+#[cfg(feature = "queue-redis")]
+pub struct RedisResultStreamBus {
+    client: redis::Client,
+    /// 发布用连接，首次发布时建立
+    connection: tokio::sync::OnceCell<redis::aio::ConnectionManager>,
+    channel_prefix: String,
+}
+
+#[cfg(feature = "queue-redis")]
+impl RedisResultStreamBus {
+    /// 创建总线，连接在首次发布或订阅时建立
+    ///
+    /// # 参数
+    ///
+    /// * `settings` - 结果推送配置
+    ///
+    /// # 返回值
+    ///
+    /// * `Ok(RedisResultStreamBus)` - 创建成功
+    /// * `Err(QueueError)` - 连接地址无效
+    pub fn new(settings: &ResultStreamSettings) -> Result<Self, QueueError> {
+        Ok(Self {
+            client: redis::Client::open(settings.redis_url())?,
+            connection: tokio::sync::OnceCell::new(),
+            channel_prefix: settings.channel_prefix.clone(),
+        })
+    }
+
+    fn channel(&self, crawl_id: Uuid) -> String {
+        format!("{}:{}", self.channel_prefix, crawl_id)
+    }
+}
+
+#[cfg(feature = "queue-redis")]
+#[async_trait]
+impl ResultStreamBus for RedisResultStreamBus {
+    async fn publish(&self, crawl_id: Uuid, event: &CrawlStreamEvent) -> Result<(), QueueError> {
+        let payload =
+            serde_json::to_string(event).map_err(|e| QueueError::Broker(e.to_string()))?;
+        let mut connection = self
+            .connection
+            .get_or_try_init(|| self.client.get_connection_manager())
+            .await?
+            .clone();
+        redis::cmd("PUBLISH")
+            .arg(self.channel(crawl_id))
+            .arg(payload)
+            .query_async::<()>(&mut connection)
+            .await?;
+        Ok(())
+    }
+
+    async fn subscribe(
+        &self,
+        crawl_id: Uuid,
+    ) -> Result<BoxStream<'static, CrawlStreamEvent>, QueueError> {
+        let mut pubsub = self.client.get_async_pubsub().await?;
+        pubsub.subscribe(self.channel(crawl_id)).await?;
+        let stream = pubsub.into_on_message().filter_map(|message| async move {
+            let payload: String = message.get_payload().ok()?;
+            serde_json::from_str(&payload).ok()
+        });
+        Ok(stream.boxed())
+    }
+}
+
+/// 从配置创建推送总线，`redis` 总线需要启用 `queue-redis` 特性
+pub fn result_stream_from_settings(
+    settings: &ResultStreamSettings,
+) -> Result<Arc<dyn ResultStreamBus>, QueueError> {
+    if !settings.is_redis() {
+        return Ok(Arc::new(InMemoryResultStreamBus::new()));
+    }
+    #[cfg(feature = "queue-redis")]
+    {
+        Ok(Arc::new(RedisResultStreamBus::new(settings)?))
+    }
+    #[cfg(not(feature = "queue-redis"))]
+    {
+        Err(QueueError::Broker(
+            "the redis result stream requires the queue-redis feature".to_string(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_event_serialization() {
+        let task_id = Uuid::new_v4();
+        let result_id = Uuid::new_v4();
+        let event = CrawlStreamEvent::Result { task_id, result_id };
+        let value = serde_json::to_value(&event).unwrap();
+        assert_eq!(value["type"], "result");
+        assert_eq!(value["result_id"], result_id.to_string());
+        assert_eq!(
+            serde_json::from_value::<CrawlStreamEvent>(value).unwrap(),
+            event
+        );
+
+        let finished = CrawlStreamEvent::Finished {
+            status: CrawlStatus::Completed,
+        };
+        let value = serde_json::to_value(&finished).unwrap();
+        assert_eq!(value["type"], "finished");
+        assert_eq!(
+            serde_json::from_value::<CrawlStreamEvent>(value).unwrap(),
+            finished
+        );
+    }
+
+    #[tokio::test]
+    async fn test_in_memory_bus_delivers_events_per_crawl() {
+        let bus = InMemoryResultStreamBus::new();
+        let crawl_id = Uuid::new_v4();
+        let other_crawl = Uuid::new_v4();
+        let mut events = bus.subscribe(crawl_id).await.unwrap();
+
+        // 订阅之前或发往其他爬取的事件不会收到
+        let result = CrawlStreamEvent::Result {
+            task_id: Uuid::new_v4(),
+            result_id: Uuid::new_v4(),
+        };
+        bus.publish(other_crawl, &result).await.unwrap();
+        bus.publish(crawl_id, &result).await.unwrap();
+        let finished = CrawlStreamEvent::Finished {
+            status: CrawlStatus::Completed,
+        };
+        bus.publish(crawl_id, &finished).await.unwrap();
+
+        assert_eq!(events.next().await, Some(result));
+        assert_eq!(events.next().await, Some(finished));
+        // 结束事件之后频道被移除，流随之结束
+        assert_eq!(events.next().await, None);
+        assert!(bus.channels.lock().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_in_memory_bus_drops_channel_without_subscribers() {
+        let bus = InMemoryResultStreamBus::new();
+        let crawl_id = Uuid::new_v4();
+        drop(bus.subscribe(crawl_id).await.unwrap());
+
+        let event = CrawlStreamEvent::Result {
+            task_id: Uuid::new_v4(),
+            result_id: Uuid::new_v4(),
+        };
+        bus.publish(crawl_id, &event).await.unwrap();
+        assert!(bus.channels.lock().is_empty());
+    }
+}
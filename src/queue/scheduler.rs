@@ -0,0 +1,496 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! 延迟任务调度
+//!
+//! 重试退避、域名节流与并发受限推迟的任务会带上 `scheduled_at` 重新排队，领取时
+//! 跳过尚未到期的任务。Worker 空闲时每秒轮询一次数据库，延迟任务最多晚一个轮询
+//! 周期才被执行。
+//!
+//! 调度器把延迟任务的到期时间记录在按时间排序的索引中，休眠到最早的到期时间，
+//! 到期后立即清除任务的 `scheduled_at` 并唤醒等待中的 Worker。新记录的任务早于
+//! 当前最早到期时间时调度器会被提前唤醒，空闲期间不再访问数据库。
+//!
+//! 索引有两种实现：[`InMemoryDueIndex`] 只记录本进程推迟的任务；`RedisDueIndex`
+//! 存放在 Redis 有序集合中，多个 Worker 进程共享，到期任务由 Lua 脚本原子取出，
+//! 每个任务只会被一个调度器提升。
+
+use std::collections::BTreeSet;
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use log::{debug, warn};
+use parking_lot::Mutex;
+use tokio::sync::Notify;
+use uuid::Uuid;
+
+use super::task_queue::QueueError;
+use crate::config::settings::DelayedSchedulingSettings;
+use crate::domain::repositories::task_repository::TaskRepository;
+
+/// 延迟任务的到期时间索引
+#[async_trait]
+pub trait DueIndex: Send + Sync {
+    /// 记录任务的到期时间，重复记录时以最后一次为准
+    async fn insert(&self, task_id: Uuid, due_at: DateTime<Utc>) -> Result<(), QueueError>;
+
+    /// 最早的到期时间，索引为空时返回 `None`
+    async fn next_due(&self) -> Result<Option<DateTime<Utc>>, QueueError>;
+
+    /// 取出并移除不晚于 `now` 到期的任务，最多 `limit` 个
+    async fn take_due(&self, now: DateTime<Utc>, limit: usize) -> Result<Vec<Uuid>, QueueError>;
+}
+
+/// 进程内的到期时间索引
+#[derive(Debug, Default)]
+pub struct InMemoryDueIndex {
+    entries: Mutex<BTreeSet<(DateTime<Utc>, Uuid)>>,
+}
+
+impl InMemoryDueIndex {
+    /// 创建空索引
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl DueIndex for InMemoryDueIndex {
+    async fn insert(&self, task_id: Uuid, due_at: DateTime<Utc>) -> Result<(), QueueError> {
+        let mut entries = self.entries.lock();
+        entries.retain(|(_, id)| *id != task_id);
+        entries.insert((due_at, task_id));
+        Ok(())
+    }
+
+    async fn next_due(&self) -> Result<Option<DateTime<Utc>>, QueueError> {
+        Ok(self.entries.lock().first().map(|(due_at, _)| *due_at))
+    }
+
+    async fn take_due(&self, now: DateTime<Utc>, limit: usize) -> Result<Vec<Uuid>, QueueError> {
+        let mut entries = self.entries.lock();
+        let mut due = Vec::new();
+        while due.len() < limit {
+            match entries.first() {
+                Some((due_at, _)) if *due_at <= now => {
+                    if let Some((_, task_id)) = entries.pop_first() {
+                        due.push(task_id);
+                    }
+                }
+                _ => break,
+            }
+        }
+        Ok(due)
+    }
+}
+
+/// Redis 有序集合实现的到期时间索引，分数为到期时间的毫秒时间戳
+#[cfg(feature = "queue-redis")]
+pub struct RedisDueIndex {
+    connection: redis::aio::ConnectionManager,
+    key: String,
+}
+
+#[cfg(feature = "queue-redis")]
+impl From<redis::RedisError> for QueueError {
+    fn from(err: redis::RedisError) -> Self {
+        QueueError::Broker(err.to_string())
+    }
+}
+
+/// 原子地取出并删除到期成员，多个调度器并发执行时每个任务只返回一次
+#[cfg(feature = "queue-redis")]
+const TAKE_DUE_SCRIPT: &str = r#"
+local due = redis.call('ZRANGEBYSCORE', KEYS[1], '-inf', ARGV[1], 'LIMIT', 0, ARGV[2])
+if #due > 0 then
+    redis.call('ZREM', KEYS[1], unpack(due))
+end
+return due
+"#;
+
+#[cfg(feature = "queue-redis")]
+impl RedisDueIndex {
+    /// 连接 Redis 并创建索引
+    ///
+    /// # 参数
+    ///
+    /// * `settings` - 延迟任务调度配置
+    ///
+    /// # 返回值
+    ///
+    /// * `Ok(RedisDueIndex)` - 连接成功
+    /// * `Err(QueueError)` - 连接地址无效或 Redis 不可达
+    pub async fn connect(settings: &DelayedSchedulingSettings) -> Result<Self, QueueError> {
+        let client = redis::Client::open(settings.redis_url())?;
+        let connection = client.get_connection_manager().await?;
+        Ok(Self {
+            connection,
+            key: settings.redis_key.clone(),
+        })
+    }
+}
+
+#[cfg(feature = "queue-redis")]
+#[async_trait]
+impl DueIndex for RedisDueIndex {
+    async fn insert(&self, task_id: Uuid, due_at: DateTime<Utc>) -> Result<(), QueueError> {
+        let mut connection = self.connection.clone();
+        redis::cmd("ZADD")
+            .arg(&self.key)
+            .arg(due_at.timestamp_millis())
+            .arg(task_id.to_string())
+            .query_async::<()>(&mut connection)
+            .await?;
+        Ok(())
+    }
+
+    async fn next_due(&self) -> Result<Option<DateTime<Utc>>, QueueError> {
+        let mut connection = self.connection.clone();
+        let first: Vec<(String, i64)> = redis::cmd("ZRANGE")
+            .arg(&self.key)
+            .arg(0)
+            .arg(0)
+            .arg("WITHSCORES")
+            .query_async(&mut connection)
+            .await?;
+        Ok(first
+            .first()
+            .and_then(|(_, score)| DateTime::from_timestamp_millis(*score)))
+    }
+
+    async fn take_due(&self, now: DateTime<Utc>, limit: usize) -> Result<Vec<Uuid>, QueueError> {
+        let mut connection = self.connection.clone();
+        let members: Vec<String> = redis::Script::new(TAKE_DUE_SCRIPT)
+            .key(&self.key)
+            .arg(now.timestamp_millis())
+            .arg(limit)
+            .invoke_async(&mut connection)
+            .await?;
+        Ok(members
+            .iter()
+            .filter_map(|member| match Uuid::parse_str(member) {
+                Ok(task_id) => Some(task_id),
+                Err(_) => {
+                    warn!("Dropping malformed scheduled task id {}", member);
+                    None
+                }
+            })
+            .collect())
+    }
+}
+
+/// 延迟任务调度器
+pub struct DelayedTaskScheduler {
+    index: Arc<dyn DueIndex>,
+    repository: Arc<dyn TaskRepository>,
+    /// 单次提升的最大任务数
+    batch_size: usize,
+    /// 两次检查之间的最长等待时间
+    max_sleep: Duration,
+    /// 新记录的任务可能早于当前等待的到期时间，唤醒调度器重新计算
+    rescheduled: Notify,
+    /// 有任务被提升时唤醒等待中的 Worker
+    promoted: Notify,
+}
+
+impl DelayedTaskScheduler {
+    /// 创建调度器
+    ///
+    /// # 参数
+    ///
+    /// * `index` - 到期时间索引
+    /// * `repository` - 任务仓库，用于提升到期任务
+    /// * `batch_size` - 单次提升的最大任务数
+    /// * `max_sleep` - 两次检查之间的最长等待时间
+    pub fn new(
+        index: Arc<dyn DueIndex>,
+        repository: Arc<dyn TaskRepository>,
+        batch_size: usize,
+        max_sleep: Duration,
+    ) -> Self {
+        Self {
+            index,
+            repository,
+            batch_size: batch_size.max(1),
+            max_sleep,
+            rescheduled: Notify::new(),
+            promoted: Notify::new(),
+        }
+    }
+
+    /// 从配置创建调度器，`redis` 索引需要启用 `queue-redis` 特性
+    pub async fn from_settings(
+        settings: &DelayedSchedulingSettings,
+        repository: Arc<dyn TaskRepository>,
+    ) -> Result<Self, QueueError> {
+        #[cfg(feature = "queue-redis")]
+        let index: Arc<dyn DueIndex> = if settings.is_redis() {
+            Arc::new(RedisDueIndex::connect(settings).await?)
+        } else {
+            Arc::new(InMemoryDueIndex::new())
+        };
+        #[cfg(not(feature = "queue-redis"))]
+        let index: Arc<dyn DueIndex> = if settings.is_redis() {
+            return Err(QueueError::Broker(
+                "the redis due-time index requires the queue-redis feature".to_string(),
+            ));
+        } else {
+            Arc::new(InMemoryDueIndex::new())
+        };
+        Ok(Self::new(
+            index,
+            repository,
+            settings.batch_size as usize,
+            Duration::from_millis(settings.max_sleep_ms),
+        ))
+    }
+
+    /// 记录延迟任务的到期时间，失败时任务仍会在到期后经由数据库轮询被领取
+    pub async fn schedule(&self, task_id: Uuid, due_at: DateTime<Utc>) {
+        match self.index.insert(task_id, due_at).await {
+            Ok(()) => self.rescheduled.notify_one(),
+            Err(e) => warn!("Failed to schedule task {} at {}: {}", task_id, due_at, e),
+        }
+    }
+
+    /// 等待下一次有任务被提升，最多等待 `timeout`
+    pub async fn wait_for_promotion(&self, timeout: Duration) {
+        let _ = tokio::time::timeout(timeout, self.promoted.notified()).await;
+    }
+
+    /// 提升所有已到期的任务，返回被提升的任务数
+    pub async fn promote_due(&self) -> Result<usize, QueueError> {
+        let mut promoted = 0;
+        loop {
+            let due = self.index.take_due(Utc::now(), self.batch_size).await?;
+            let batch_len = due.len();
+            for task_id in due {
+                match self.repository.promote_scheduled(task_id).await {
+                    Ok(true) => promoted += 1,
+                    // 任务已被取消、提前领取或再次推迟
+                    Ok(false) => debug!("Scheduled task {} is no longer waiting", task_id),
+                    Err(e) => warn!("Failed to promote scheduled task {}: {}", task_id, e),
+                }
+            }
+            if batch_len < self.batch_size {
+                break;
+            }
+        }
+        if promoted > 0 {
+            debug!("Promoted {} scheduled tasks", promoted);
+            self.promoted.notify_waiters();
+        }
+        Ok(promoted)
+    }
+
+    /// 距离下一次检查的等待时间
+    async fn next_wait(&self) -> Duration {
+        match self.index.next_due().await {
+            Ok(Some(due_at)) => (due_at - Utc::now())
+                .to_std()
+                .unwrap_or(Duration::ZERO)
+                .min(self.max_sleep),
+            Ok(None) => self.max_sleep,
+            Err(e) => {
+                warn!("Failed to read the next scheduled task: {}", e);
+                self.max_sleep
+            }
+        }
+    }
+
+    /// 运行调度循环
+    pub async fn run(&self) {
+        loop {
+            if let Err(e) = self.promote_due().await {
+                warn!("Failed to promote scheduled tasks: {}", e);
+            }
+            let wait = self.next_wait().await;
+            tokio::select! {
+                _ = tokio::time::sleep(wait) => {}
+                _ = self.rescheduled.notified() => {}
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::{Task, TaskStatus, TaskType};
+    use crate::domain::repositories::task_repository::{RepositoryError, TaskQueryParams};
+    use std::collections::{HashMap, HashSet};
+
+    /// Keeps tasks in memory so the default `promote_scheduled` can run.
+    #[derive(Default)]
+    struct InMemoryTaskRepository {
+        tasks: Mutex<HashMap<Uuid, Task>>,
+    }
+
+    #[async_trait]
+    impl TaskRepository for InMemoryTaskRepository {
+        async fn create(&self, task: &Task) -> Result<Task, RepositoryError> {
+            self.tasks.lock().insert(task.id, task.clone());
+            Ok(task.clone())
+        }
+        async fn find_by_id(&self, id: Uuid) -> Result<Option<Task>, RepositoryError> {
+            Ok(self.tasks.lock().get(&id).cloned())
+        }
+        async fn update(&self, task: &Task) -> Result<Task, RepositoryError> {
+            self.tasks.lock().insert(task.id, task.clone());
+            Ok(task.clone())
+        }
+        async fn acquire_next(&self, _worker_id: Uuid) -> Result<Option<Task>, RepositoryError> {
+            Ok(None)
+        }
+        async fn mark_completed(&self, _id: Uuid) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+        async fn mark_failed(&self, _id: Uuid) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+        async fn mark_cancelled(&self, _id: Uuid) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+        async fn exists_by_url(&self, _url: &str) -> Result<bool, RepositoryError> {
+            Ok(false)
+        }
+        async fn find_existing_urls(
+            &self,
+            _urls: &[String],
+        ) -> Result<HashSet<String>, RepositoryError> {
+            Ok(HashSet::new())
+        }
+        async fn reset_stuck_tasks(
+            &self,
+            _timeout: chrono::Duration,
+        ) -> Result<u64, RepositoryError> {
+            Ok(0)
+        }
+        async fn cancel_tasks_by_crawl_id(&self, _crawl_id: Uuid) -> Result<u64, RepositoryError> {
+            Ok(0)
+        }
+        async fn expire_tasks(&self) -> Result<u64, RepositoryError> {
+            Ok(0)
+        }
+        async fn find_by_crawl_id(&self, _crawl_id: Uuid) -> Result<Vec<Task>, RepositoryError> {
+            Ok(vec![])
+        }
+        async fn query_tasks(
+            &self,
+            _params: TaskQueryParams,
+        ) -> Result<(Vec<Task>, u64), RepositoryError> {
+            Ok((vec![], 0))
+        }
+        async fn batch_cancel(
+            &self,
+            _task_ids: Vec<Uuid>,
+            _team_id: Uuid,
+            _force: bool,
+        ) -> Result<(Vec<Uuid>, Vec<(Uuid, String)>), RepositoryError> {
+            Ok((vec![], vec![]))
+        }
+    }
+
+    async fn deferred_task(repo: &InMemoryTaskRepository, due_at: DateTime<Utc>) -> Uuid {
+        let mut task = Task::new(
+            Uuid::new_v4(),
+            TaskType::Scrape,
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            "https://example.com".to_string(),
+            serde_json::json!({}),
+        );
+        task.scheduled_at = Some(due_at);
+        repo.create(&task).await.unwrap();
+        task.id
+    }
+
+    #[tokio::test]
+    async fn test_in_memory_index_takes_due_entries_in_order() {
+        let index = InMemoryDueIndex::new();
+        let now = Utc::now();
+        let (first, second, later) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        index
+            .insert(second, now - chrono::Duration::seconds(1))
+            .await
+            .unwrap();
+        index
+            .insert(first, now - chrono::Duration::seconds(2))
+            .await
+            .unwrap();
+        index
+            .insert(later, now + chrono::Duration::minutes(1))
+            .await
+            .unwrap();
+        // Re-scheduling replaces the previous due time
+        index
+            .insert(later, now + chrono::Duration::minutes(2))
+            .await
+            .unwrap();
+
+        assert_eq!(index.take_due(now, 10).await.unwrap(), vec![first, second]);
+        assert_eq!(
+            index.next_due().await.unwrap(),
+            Some(now + chrono::Duration::minutes(2))
+        );
+        assert!(index.take_due(now, 10).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_promote_due_clears_schedule_and_wakes_workers() {
+        let repo = Arc::new(InMemoryTaskRepository::default());
+        let due = deferred_task(&repo, Utc::now() - chrono::Duration::seconds(1)).await;
+        let pending = deferred_task(&repo, Utc::now() + chrono::Duration::hours(1)).await;
+        let scheduler = Arc::new(DelayedTaskScheduler::new(
+            Arc::new(InMemoryDueIndex::new()),
+            repo.clone(),
+            1,
+            Duration::from_secs(1),
+        ));
+        scheduler.schedule(due, Utc::now()).await;
+        scheduler
+            .schedule(pending, Utc::now() + chrono::Duration::hours(1))
+            .await;
+
+        let waiter = {
+            let scheduler = scheduler.clone();
+            tokio::spawn(async move { scheduler.wait_for_promotion(Duration::from_secs(5)).await })
+        };
+        tokio::task::yield_now().await;
+
+        assert_eq!(scheduler.promote_due().await.unwrap(), 1);
+        waiter.await.unwrap();
+        let tasks = repo.tasks.lock();
+        assert!(tasks[&due].scheduled_at.is_none());
+        assert_eq!(tasks[&due].status, TaskStatus::Queued);
+        assert!(tasks[&pending].scheduled_at.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_run_promotes_exactly_when_due() {
+        let repo = Arc::new(InMemoryTaskRepository::default());
+        let due_at = Utc::now() + chrono::Duration::milliseconds(100);
+        let task_id = deferred_task(&repo, due_at).await;
+        let scheduler = Arc::new(DelayedTaskScheduler::new(
+            Arc::new(InMemoryDueIndex::new()),
+            repo.clone(),
+            100,
+            Duration::from_secs(60),
+        ));
+        let runner = {
+            let scheduler = scheduler.clone();
+            tokio::spawn(async move { scheduler.run().await })
+        };
+        tokio::task::yield_now().await;
+        scheduler.schedule(task_id, due_at).await;
+
+        scheduler.wait_for_promotion(Duration::from_secs(5)).await;
+        assert!(repo.tasks.lock()[&task_id].scheduled_at.is_none());
+        assert!(Utc::now() >= due_at);
+        runner.abort();
+    }
+}
@@ -3,10 +3,12 @@
 // Licensed under the Apache License, Version 2.0
 // See LICENSE file in the project root for full license information.
 
-use crate::domain::models::Task;
+use super::priority_scheduler::PriorityScheduler;
+use crate::domain::models::{Task, TaskEvent, TaskEventType};
+use crate::domain::repositories::task_event_repository::TaskEventRepository;
 use crate::domain::repositories::task_repository::TaskRepository;
 use async_trait::async_trait;
-use log::debug;
+use log::{debug, warn};
 use std::sync::Arc;
 use thiserror::Error;
 use uuid::Uuid;
@@ -21,6 +23,10 @@ pub enum QueueError {
     /// 队列为空
     #[error("Queue empty")]
     Empty,
+
+    /// 消息中间件错误
+    #[error("Broker error: {0}")]
+    Broker(String),
 }
 
 /// 任务队列特质
@@ -44,6 +50,10 @@ pub trait TaskQueue: Send + Sync {
 pub struct PostgresTaskQueue {
     /// 任务仓库
     pub repository: Arc<dyn TaskRepository>,
+    /// 任务事件仓库（可选），记录入队、加锁与结束等生命周期事件
+    event_repository: Option<Arc<dyn TaskEventRepository>>,
+    /// 加权优先级调度器（可选），未设置时严格按优先级领取
+    scheduler: Option<PriorityScheduler>,
 }
 
 impl PostgresTaskQueue {
@@ -57,7 +67,67 @@ impl PostgresTaskQueue {
     ///
     /// 返回新的PostgreSQL任务队列实例
     pub fn new(repository: Arc<dyn TaskRepository>) -> Self {
-        Self { repository }
+        Self {
+            repository,
+            event_repository: None,
+            scheduler: None,
+        }
+    }
+
+    /// 注入任务事件仓库，启用任务执行时间线记录
+    pub fn with_event_repository(mut self, event_repository: Arc<dyn TaskEventRepository>) -> Self {
+        self.event_repository = Some(event_repository);
+        self
+    }
+
+    /// 启用加权优先级调度，按档位轮转领取并对排队过久的任务老化提升
+    pub fn with_scheduler(mut self, scheduler: PriorityScheduler) -> Self {
+        self.scheduler = Some(scheduler);
+        self
+    }
+
+    /// 按调度器给出的档位顺序领取任务，各档位都没有排队任务时回收锁过期的任务
+    async fn acquire_scheduled(
+        &self,
+        scheduler: &PriorityScheduler,
+        worker_id: Uuid,
+    ) -> Result<Option<Task>, QueueError> {
+        for tier in scheduler.next_order() {
+            let task = self
+                .repository
+                .acquire_next_in_lane(worker_id, tier, scheduler.aging())
+                .await?;
+            if task.is_some() {
+                return Ok(task);
+            }
+        }
+        Ok(self.repository.acquire_next(worker_id).await?)
+    }
+
+    /// 记录任务事件，写入失败只记录日志，不影响队列操作
+    async fn record_event(&self, event: TaskEvent) {
+        if let Some(event_repository) = &self.event_repository {
+            if let Err(e) = event_repository.record(&event).await {
+                warn!(
+                    "Failed to record {} event for task {}: {}",
+                    event.event_type, event.task_id, e
+                );
+            }
+        }
+    }
+
+    /// 领取消息中间件投递的任务，任务已不在排队状态时返回 `None`
+    pub async fn claim(&self, task_id: Uuid, worker_id: Uuid) -> Result<Option<Task>, QueueError> {
+        let task = self.repository.acquire_by_id(task_id, worker_id).await?;
+        if let Some(task) = &task {
+            self.record_event(
+                TaskEvent::new(task.id, TaskEventType::Locked)
+                    .with_worker(worker_id)
+                    .with_attempt(task.attempt_count),
+            )
+            .await;
+        }
+        Ok(task)
     }
 }
 
@@ -75,6 +145,8 @@ impl TaskQueue for PostgresTaskQueue {
     /// * `Err(QueueError)` - 入队失败
     async fn enqueue(&self, task: Task) -> Result<Task, QueueError> {
         let created = self.repository.create(&task).await?;
+        self.record_event(TaskEvent::new(created.id, TaskEventType::Queued))
+            .await;
         Ok(created)
     }
 
@@ -91,8 +163,19 @@ impl TaskQueue for PostgresTaskQueue {
     /// * `Err(QueueError)` - 出队失败
     async fn dequeue(&self, worker_id: Uuid) -> Result<Option<Task>, QueueError> {
         debug!("worker_id={}", worker_id);
-        let task = self.repository.acquire_next(worker_id).await?;
+        let task = match &self.scheduler {
+            Some(scheduler) => self.acquire_scheduled(scheduler, worker_id).await?,
+            None => self.repository.acquire_next(worker_id).await?,
+        };
         debug!("has_task={:?}", task.is_some());
+        if let Some(task) = &task {
+            self.record_event(
+                TaskEvent::new(task.id, TaskEventType::Locked)
+                    .with_worker(worker_id)
+                    .with_attempt(task.attempt_count),
+            )
+            .await;
+        }
         Ok(task)
     }
 
@@ -108,6 +191,8 @@ impl TaskQueue for PostgresTaskQueue {
     /// * `Err(QueueError)` - 失败
     async fn complete(&self, task_id: Uuid) -> Result<(), QueueError> {
         self.repository.mark_completed(task_id).await?;
+        self.record_event(TaskEvent::new(task_id, TaskEventType::Completed))
+            .await;
         Ok(())
     }
 
@@ -123,6 +208,8 @@ impl TaskQueue for PostgresTaskQueue {
     /// * `Err(QueueError)` - 失败
     async fn fail(&self, task_id: Uuid) -> Result<(), QueueError> {
         self.repository.mark_failed(task_id).await?;
+        self.record_event(TaskEvent::new(task_id, TaskEventType::Failed))
+            .await;
         Ok(())
     }
 
@@ -138,6 +225,8 @@ impl TaskQueue for PostgresTaskQueue {
     /// * `Err(QueueError)` - 失败
     async fn cancel(&self, task_id: Uuid) -> Result<(), QueueError> {
         self.repository.mark_cancelled(task_id).await?;
+        self.record_event(TaskEvent::new(task_id, TaskEventType::Cancelled))
+            .await;
         Ok(())
     }
 }
@@ -168,7 +257,7 @@ impl<T: TaskQueue + ?Sized> TaskQueue for Arc<T> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::domain::models::{Task, TaskType};
+    use crate::domain::models::{PriorityTier, Task, TaskType};
     use crate::domain::repositories::task_repository::{
         RepositoryError, TaskQueryParams, TaskRepository,
     };
@@ -198,6 +287,8 @@ mod tests {
         next_task: parking_lot::Mutex<Option<Task>>,
         /// Task returned by create (defaults to the input task).
         created_task: parking_lot::Mutex<Option<Task>>,
+        /// Tiers passed to acquire_next_in_lane, in call order.
+        lane_calls: parking_lot::Mutex<Vec<PriorityTier>>,
     }
 
     impl MockTaskRepository {
@@ -213,6 +304,7 @@ mod tests {
                 should_fail: false,
                 next_task: parking_lot::Mutex::new(None),
                 created_task: parking_lot::Mutex::new(None),
+                lane_calls: parking_lot::Mutex::new(Vec::new()),
             }
         }
 
@@ -277,6 +369,37 @@ mod tests {
             Ok(self.next_task.lock().take())
         }
 
+        async fn acquire_next_in_lane(
+            &self,
+            worker_id: Uuid,
+            tier: PriorityTier,
+            _aging: ChronoDuration,
+        ) -> Result<Option<Task>, RepositoryError> {
+            self.lane_calls.lock().push(tier);
+            *self.last_worker_id.lock() = Some(worker_id);
+            let mut next_task = self.next_task.lock();
+            if next_task
+                .as_ref()
+                .is_some_and(|task| PriorityTier::for_priority(task.priority) == tier)
+            {
+                return Ok(next_task.take());
+            }
+            Ok(None)
+        }
+
+        async fn acquire_by_id(
+            &self,
+            id: Uuid,
+            worker_id: Uuid,
+        ) -> Result<Option<Task>, RepositoryError> {
+            *self.last_worker_id.lock() = Some(worker_id);
+            let mut next_task = self.next_task.lock();
+            if next_task.as_ref().is_some_and(|task| task.id == id) {
+                return Ok(next_task.take());
+            }
+            Ok(None)
+        }
+
         async fn mark_completed(&self, task_id: Uuid) -> Result<(), RepositoryError> {
             self.complete_calls.fetch_add(1, Ordering::SeqCst);
             *self.last_task_id.lock() = Some(task_id);
@@ -719,4 +842,118 @@ mod tests {
         queue.cancel(Uuid::new_v4()).await.unwrap();
         assert_eq!(mock.cancel_calls.load(Ordering::SeqCst), 1);
     }
+
+    // ========== task events ==========
+
+    /// Mock TaskEventRepository that keeps recorded events in memory.
+    #[derive(Default)]
+    struct MockTaskEventRepository {
+        events: parking_lot::Mutex<Vec<TaskEvent>>,
+        should_fail: bool,
+    }
+
+    #[async_trait]
+    impl TaskEventRepository for MockTaskEventRepository {
+        async fn record(&self, event: &TaskEvent) -> Result<(), RepositoryError> {
+            if self.should_fail {
+                return Err(db_error());
+            }
+            self.events.lock().push(event.clone());
+            Ok(())
+        }
+
+        async fn find_by_task_id(&self, task_id: Uuid) -> Result<Vec<TaskEvent>, RepositoryError> {
+            Ok(self
+                .events
+                .lock()
+                .iter()
+                .filter(|event| event.task_id == task_id)
+                .cloned()
+                .collect())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_queue_records_lifecycle_events() {
+        let task = sample_task();
+        let mock = Arc::new(MockTaskRepository::with_next_task(task.clone()));
+        let events = Arc::new(MockTaskEventRepository::default());
+        let queue = make_queue(mock).with_event_repository(events.clone());
+        let worker_id = Uuid::new_v4();
+
+        queue.enqueue(task.clone()).await.unwrap();
+        queue.dequeue(worker_id).await.unwrap();
+        queue.complete(task.id).await.unwrap();
+
+        let recorded = events.find_by_task_id(task.id).await.unwrap();
+        let types: Vec<TaskEventType> = recorded.iter().map(|e| e.event_type).collect();
+        assert_eq!(
+            types,
+            vec![
+                TaskEventType::Queued,
+                TaskEventType::Locked,
+                TaskEventType::Completed
+            ]
+        );
+        assert_eq!(recorded[1].worker_id, Some(worker_id));
+    }
+
+    #[tokio::test]
+    async fn test_queue_event_failure_does_not_fail_operation() {
+        let mock = Arc::new(MockTaskRepository::new());
+        let events = Arc::new(MockTaskEventRepository {
+            should_fail: true,
+            ..Default::default()
+        });
+        let queue = make_queue(mock).with_event_repository(events);
+        assert!(queue.enqueue(sample_task()).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_claim_records_lock_event_only_for_claimed_task() {
+        let task = sample_task();
+        let mock = Arc::new(MockTaskRepository::with_next_task(task.clone()));
+        let events = Arc::new(MockTaskEventRepository::default());
+        let queue = make_queue(mock.clone()).with_event_repository(events.clone());
+        let worker_id = Uuid::new_v4();
+
+        let claimed = queue.claim(task.id, worker_id).await.unwrap();
+        assert_eq!(claimed.map(|t| t.id), Some(task.id));
+        assert_eq!(*mock.last_worker_id.lock(), Some(worker_id));
+
+        // A redelivered task that was already claimed is skipped.
+        assert!(queue.claim(task.id, worker_id).await.unwrap().is_none());
+        let recorded = events.find_by_task_id(task.id).await.unwrap();
+        assert_eq!(recorded.len(), 1);
+        assert_eq!(recorded[0].event_type, TaskEventType::Locked);
+        assert_eq!(mock.dequeue_calls.load(Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test]
+    async fn test_scheduled_dequeue_tries_preferred_lane_first() {
+        let mut task = sample_task();
+        task.priority = 50;
+        let mock = Arc::new(MockTaskRepository::with_next_task(task.clone()));
+        let scheduler = PriorityScheduler::new([0, 0, 1], ChronoDuration::seconds(300));
+        let queue = make_queue(mock.clone()).with_scheduler(scheduler);
+
+        let dequeued = queue.dequeue(Uuid::new_v4()).await.unwrap();
+        assert_eq!(dequeued.map(|t| t.id), Some(task.id));
+        assert_eq!(
+            *mock.lane_calls.lock(),
+            vec![PriorityTier::Low, PriorityTier::High, PriorityTier::Normal]
+        );
+        assert_eq!(mock.dequeue_calls.load(Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test]
+    async fn test_scheduled_dequeue_recovers_stale_tasks_when_lanes_empty() {
+        let mock = Arc::new(MockTaskRepository::new());
+        let scheduler = PriorityScheduler::new([6, 3, 1], ChronoDuration::seconds(300));
+        let queue = make_queue(mock.clone()).with_scheduler(scheduler);
+
+        assert!(queue.dequeue(Uuid::new_v4()).await.unwrap().is_none());
+        assert_eq!(mock.lane_calls.lock().len(), 3);
+        assert_eq!(mock.dequeue_calls.load(Ordering::SeqCst), 1);
+    }
 }
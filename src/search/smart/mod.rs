@@ -13,7 +13,7 @@ use crate::domain::models::search_result::SearchResult;
 use crate::domain::services::rate_limiting_service::{RateLimitResult, RateLimitingService};
 use crate::domain::services::relevance_scorer::{DateParserComponent, RelevanceScorer};
 use crate::engines::engine_client::{
-    EngineClient, EngineError, HttpMethod, PageAction, ScrapeOptions, ScrapeRequest,
+    EngineClient, EngineError, HttpMethod, HttpProtocol, PageAction, ScrapeOptions, ScrapeRequest,
     ScrollDirection,
 };
 use crate::search::engine_trait::{SearchEngine, SearchRequest};
@@ -601,6 +601,22 @@ impl SmartSearchEngine {
                 use_fire_engine: needs_js,
                 actions,
                 sync_wait_ms: if needs_js { 10000 } else { 0 },
+                content_type_filter: None,
+                http_protocol: HttpProtocol::Auto,
+                tls_profile: None,
+                capture_har: false,
+                capture_mhtml: false,
+                pdf: None,
+                resource_blocking: None,
+                action_error_policies: Vec::new(),
+                iframe_capture: None,
+                flatten_shadow_dom: false,
+                auto_scroll: None,
+                accessibility: None,
+                engine_tier: None,
+                excluded_engines: Vec::new(),
+                resolve: HashMap::new(),
+                address_family: None,
             },
         }
     }
@@ -3014,6 +3030,20 @@ mod tests_ext {
                     content_type: "text/html".to_string(),
                     headers: HashMap::new(),
                     response_time_ms: 0,
+                    truncated: false,
+                    raw_content: None,
+                    http_version: None,
+                    tls_profile: None,
+                    har: None,
+                    mhtml: None,
+                    pdf: None,
+                    blocked_requests: None,
+                    evaluate_results: Vec::new(),
+                    frames: Vec::new(),
+                    accessibility: None,
+                    timings: None,
+                    ip_family: None,
+                    escalations: Vec::new(),
                 }),
                 MockScrapeBehavior::ShortHtml => Ok(InternalScrapeResponse {
                     status_code: 200,
@@ -3022,6 +3052,20 @@ mod tests_ext {
                     content_type: "text/html".to_string(),
                     headers: HashMap::new(),
                     response_time_ms: 0,
+                    truncated: false,
+                    raw_content: None,
+                    http_version: None,
+                    tls_profile: None,
+                    har: None,
+                    mhtml: None,
+                    pdf: None,
+                    blocked_requests: None,
+                    evaluate_results: Vec::new(),
+                    frames: Vec::new(),
+                    accessibility: None,
+                    timings: None,
+                    ip_family: None,
+                    escalations: Vec::new(),
                 }),
                 MockScrapeBehavior::RetryableError => Err(EngineError::RequestFailed(
                     "mock retryable failure".to_string(),
@@ -3042,6 +3086,20 @@ mod tests_ext {
                             content_type: "text/html".to_string(),
                             headers: HashMap::new(),
                             response_time_ms: 0,
+                            truncated: false,
+                            raw_content: None,
+                            http_version: None,
+                            tls_profile: None,
+                            har: None,
+                            mhtml: None,
+                            pdf: None,
+                            blocked_requests: None,
+                            evaluate_results: Vec::new(),
+                            frames: Vec::new(),
+                            accessibility: None,
+                            timings: None,
+                            ip_family: None,
+                            escalations: Vec::new(),
                         })
                     }
                 }
@@ -3054,6 +3112,20 @@ mod tests_ext {
                         content_type: "text/html".to_string(),
                         headers: HashMap::new(),
                         response_time_ms: 3000,
+                        truncated: false,
+                        raw_content: None,
+                        http_version: None,
+                        tls_profile: None,
+                        har: None,
+                        mhtml: None,
+                        pdf: None,
+                        blocked_requests: None,
+                        evaluate_results: Vec::new(),
+                        frames: Vec::new(),
+                        accessibility: None,
+                        timings: None,
+                        ip_family: None,
+                        escalations: Vec::new(),
                     })
                 }
             }
@@ -0,0 +1,102 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! 抓取结果正文压缩
+//!
+//! 开启 `[storage] compress_content` 后，结果仓储在写入前以 zstd 压缩 HTML 等正文，
+//! 读取时透明解压；`content_encoding` 列记录编码，未压缩的旧数据不受影响。
+
+use std::io;
+
+/// `content_encoding` 列中表示 zstd 压缩的取值
+pub const ZSTD_ENCODING: &str = "zstd";
+
+/// 默认压缩级别（zstd 的默认值，兼顾速度与压缩率）
+pub const DEFAULT_COMPRESSION_LEVEL: i32 = 3;
+
+/// 默认的最小压缩长度（字节），更短的正文压缩收益不足
+pub const DEFAULT_MIN_COMPRESS_BYTES: usize = 1024;
+
+/// 正文压缩配置
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ContentCompression {
+    /// zstd 压缩级别（1-22）
+    pub level: i32,
+    /// 短于该长度的正文不压缩
+    pub min_bytes: usize,
+}
+
+impl Default for ContentCompression {
+    fn default() -> Self {
+        Self {
+            level: DEFAULT_COMPRESSION_LEVEL,
+            min_bytes: DEFAULT_MIN_COMPRESS_BYTES,
+        }
+    }
+}
+
+impl ContentCompression {
+    /// 创建压缩配置，级别截断到 zstd 支持的 1-22
+    pub fn new(level: i32, min_bytes: usize) -> Self {
+        Self {
+            level: level.clamp(1, 22),
+            min_bytes,
+        }
+    }
+
+    /// 压缩正文
+    ///
+    /// 正文短于 `min_bytes` 或压缩后没有变小时返回 None，按原文保存。
+    pub fn compress(&self, content: &str) -> io::Result<Option<Vec<u8>>> {
+        if content.len() < self.min_bytes.max(1) {
+            return Ok(None);
+        }
+        let compressed = zstd::encode_all(content.as_bytes(), self.level)?;
+        Ok((compressed.len() < content.len()).then_some(compressed))
+    }
+}
+
+/// 按 `content_encoding` 解压正文
+pub fn decompress_content(encoding: &str, bytes: &[u8]) -> io::Result<String> {
+    if encoding != ZSTD_ENCODING {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("Unsupported content encoding '{}'", encoding),
+        ));
+    }
+    let decoded = zstd::decode_all(bytes)?;
+    String::from_utf8(decoded).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compress_roundtrip() {
+        let html = "<div class=\"item\">hello</div>".repeat(200);
+        let compression = ContentCompression::default();
+        let compressed = compression.compress(&html).unwrap().unwrap();
+        assert!(compressed.len() * 5 < html.len());
+        assert_eq!(
+            decompress_content(ZSTD_ENCODING, &compressed).unwrap(),
+            html
+        );
+    }
+
+    #[test]
+    fn test_short_content_is_not_compressed() {
+        let compression = ContentCompression::new(50, 1024);
+        assert_eq!(compression.level, 22);
+        assert!(compression.compress("<p>short</p>").unwrap().is_none());
+        assert!(compression.compress("").unwrap().is_none());
+    }
+
+    #[test]
+    fn test_decompress_rejects_unknown_encoding_and_bad_data() {
+        assert!(decompress_content("gzip", b"data").is_err());
+        assert!(decompress_content(ZSTD_ENCODING, b"not zstd").is_err());
+    }
+}
@@ -0,0 +1,341 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! RSS / Atom 订阅源解析
+//!
+//! 解析 RSS 2.0、RSS 1.0（RDF）与 Atom 文档中的条目（标题、链接、发布时间、内容），
+//! 供 `feed` 任务监控发布内容，并可将条目链接作为抓取任务入队。
+//! 与站点地图解析一致，只按标签提取所需字段，不校验文档结构。
+
+use chrono::DateTime;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::Serialize;
+use url::Url;
+
+/// 单个订阅源最多解析的条目数
+pub const MAX_FEED_ITEMS: usize = 500;
+
+/// RSS `<item>` 条目
+static RSS_ITEM_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?is)<item\b[^>]*>(.*?)</item>").expect("valid rss item regex"));
+
+/// Atom `<entry>` 条目
+static ATOM_ENTRY_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?is)<entry\b[^>]*>(.*?)</entry>").expect("valid atom entry regex"));
+
+/// Atom `<link>` 元素的属性
+static ATOM_LINK_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?is)<link\b([^>]*)>").expect("valid atom link regex"));
+
+/// XML 属性
+static ATTR_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"(?s)([\w:-]+)\s*=\s*(?:"([^"]*)"|'([^']*)')"#).expect("valid attribute regex")
+});
+
+/// 订阅源格式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FeedKind {
+    /// RSS 2.0 或 RSS 1.0（RDF）
+    Rss,
+    /// Atom 1.0
+    Atom,
+}
+
+/// 订阅源条目
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct FeedItem {
+    /// 标题
+    pub title: Option<String>,
+    /// 条目链接，已按订阅源地址解析为绝对地址
+    pub link: Option<String>,
+    /// 发布时间，可识别时为 RFC 3339 格式，否则保留原文
+    pub published: Option<String>,
+    /// 正文（`content:encoded` / `content`，缺失时为摘要），可能包含 HTML
+    pub content: Option<String>,
+    /// 条目标识（RSS `guid` / Atom `id`）
+    pub id: Option<String>,
+}
+
+/// 解析后的订阅源
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Feed {
+    /// 订阅源格式
+    pub kind: FeedKind,
+    /// 订阅源标题
+    pub title: Option<String>,
+    /// 条目，按文档中的顺序
+    pub items: Vec<FeedItem>,
+}
+
+/// 内容类型是否为订阅源（RSS / Atom）
+pub fn is_feed_content_type(content_type: &str) -> bool {
+    let content_type = content_type.to_ascii_lowercase();
+    content_type.contains("rss+xml") || content_type.contains("atom+xml")
+}
+
+/// 解析订阅源文档，不是 RSS 或 Atom 时返回 None
+///
+/// `base_url` 为订阅源地址，用于解析相对的条目链接。
+pub fn parse_feed(content: &str, base_url: Option<&str>) -> Option<Feed> {
+    let lower = content.to_ascii_lowercase();
+    let base = base_url.and_then(|u| Url::parse(u).ok());
+    let (kind, items) = if lower.contains("<rss") || lower.contains("<rdf:rdf") {
+        (FeedKind::Rss, rss_items(content))
+    } else if lower.contains("<feed") {
+        (FeedKind::Atom, atom_items(content))
+    } else {
+        return None;
+    };
+
+    // 订阅源标题为第一个条目之前的 <title>
+    let first_item = lower
+        .find(if kind == FeedKind::Rss {
+            "<item"
+        } else {
+            "<entry"
+        })
+        .unwrap_or(content.len());
+    let title = element(&content[..first_item], "title").and_then(|(_, text)| xml_text(text));
+
+    let items = items
+        .into_iter()
+        .map(|mut item| {
+            item.link = item.link.map(|link| resolve(&link, base.as_ref()));
+            item
+        })
+        .collect();
+    Some(Feed { kind, title, items })
+}
+
+fn rss_items(content: &str) -> Vec<FeedItem> {
+    RSS_ITEM_RE
+        .captures_iter(content)
+        .take(MAX_FEED_ITEMS)
+        .filter_map(|caps| caps.get(1))
+        .map(|item| {
+            let item = item.as_str();
+            FeedItem {
+                title: text_of(item, &["title"]),
+                link: text_of(item, &["link"]),
+                published: text_of(item, &["pubDate", "dc:date"]).map(normalize_date),
+                content: text_of(item, &["content:encoded", "description"]),
+                id: text_of(item, &["guid"]),
+            }
+        })
+        .collect()
+}
+
+fn atom_items(content: &str) -> Vec<FeedItem> {
+    ATOM_ENTRY_RE
+        .captures_iter(content)
+        .take(MAX_FEED_ITEMS)
+        .filter_map(|caps| caps.get(1))
+        .map(|entry| {
+            let entry = entry.as_str();
+            FeedItem {
+                title: text_of(entry, &["title"]),
+                link: atom_link(entry),
+                published: text_of(entry, &["published", "updated"]).map(normalize_date),
+                content: text_of(entry, &["content", "summary"]),
+                id: text_of(entry, &["id"]),
+            }
+        })
+        .collect()
+}
+
+/// Atom 条目的页面链接：`rel="alternate"` 或未指定 rel 的 `<link href>`
+fn atom_link(entry: &str) -> Option<String> {
+    ATOM_LINK_RE.captures_iter(entry).find_map(|caps| {
+        let mut rel = None;
+        let mut href = None;
+        for attr in ATTR_RE.captures_iter(caps.get(1)?.as_str()) {
+            let value = attr.get(2).or_else(|| attr.get(3))?.as_str();
+            match attr.get(1)?.as_str() {
+                "rel" => rel = Some(value),
+                "href" => href = Some(value),
+                _ => {}
+            }
+        }
+        if rel.is_none_or(|rel| rel.eq_ignore_ascii_case("alternate")) {
+            href.map(|href| html_escape::decode_html_entities(href.trim()).into_owned())
+        } else {
+            None
+        }
+    })
+}
+
+/// 依次查找候选元素，返回第一个非空的文本
+fn text_of(block: &str, names: &[&str]) -> Option<String> {
+    names
+        .iter()
+        .find_map(|name| element(block, name).and_then(|(_, text)| xml_text(text)))
+}
+
+/// 查找第一个名为 `name` 的元素（忽略大小写），返回属性部分与内容
+fn element<'a>(block: &'a str, name: &str) -> Option<(&'a str, &'a str)> {
+    let lower = block.to_ascii_lowercase();
+    let open = format!("<{}", name.to_ascii_lowercase());
+    let close = format!("</{}>", name.to_ascii_lowercase());
+    let mut from = 0;
+    while let Some(pos) = lower[from..].find(&open) {
+        let after = from + pos + open.len();
+        let next = *lower.as_bytes().get(after)?;
+        if next == b'>' || next == b'/' || next.is_ascii_whitespace() {
+            let tag_end = after + lower[after..].find('>')?;
+            let attrs = &block[after..tag_end];
+            if attrs.ends_with('/') {
+                return Some((attrs, ""));
+            }
+            let content_end = tag_end + 1 + lower[tag_end + 1..].find(&close)?;
+            return Some((attrs, &block[tag_end + 1..content_end]));
+        }
+        from = after;
+    }
+    None
+}
+
+/// 元素文本：CDATA 原样保留，其余部分还原 XML / HTML 实体；空文本返回 None
+fn xml_text(raw: &str) -> Option<String> {
+    let mut text = String::new();
+    let mut rest = raw;
+    while let Some(start) = rest.find("<![CDATA[") {
+        text.push_str(&html_escape::decode_html_entities(&rest[..start]));
+        let cdata = &rest[start + "<![CDATA[".len()..];
+        match cdata.find("]]>") {
+            Some(end) => {
+                text.push_str(&cdata[..end]);
+                rest = &cdata[end + "]]>".len()..];
+            }
+            None => {
+                text.push_str(cdata);
+                rest = "";
+            }
+        }
+    }
+    text.push_str(&html_escape::decode_html_entities(rest));
+    let text = text.trim();
+    (!text.is_empty()).then(|| text.to_string())
+}
+
+/// RSS 的 RFC 2822 与 Atom 的 RFC 3339 时间统一为 RFC 3339
+fn normalize_date(date: String) -> String {
+    DateTime::parse_from_rfc2822(&date)
+        .or_else(|_| DateTime::parse_from_rfc3339(&date))
+        .map(|parsed| parsed.to_rfc3339())
+        .unwrap_or(date)
+}
+
+fn resolve(link: &str, base: Option<&Url>) -> String {
+    base.and_then(|base| base.join(link).ok())
+        .map(|url| url.to_string())
+        .unwrap_or_else(|| link.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_feed_content_type() {
+        assert!(is_feed_content_type("application/rss+xml; charset=utf-8"));
+        assert!(is_feed_content_type("application/atom+xml"));
+        assert!(!is_feed_content_type("text/html"));
+    }
+
+    #[test]
+    fn test_parse_rss() {
+        let xml = r#"<?xml version="1.0"?>
+<rss version="2.0" xmlns:content="http://purl.org/rss/1.0/modules/content/">
+  <channel>
+    <title>Example &amp; Co blog</title>
+    <link>https://example.com/</link>
+    <item>
+      <title><![CDATA[Release <1.0>]]></title>
+      <link>/posts/1?a=1&amp;b=2</link>
+      <pubDate>Tue, 10 Jun 2025 04:00:00 GMT</pubDate>
+      <description>Short</description>
+      <content:encoded><![CDATA[<p>Full text</p>]]></content:encoded>
+      <guid isPermaLink="false">post-1</guid>
+    </item>
+    <item>
+      <title>Second</title>
+      <description>&lt;b&gt;Only a summary&lt;/b&gt;</description>
+      <pubDate>not a date</pubDate>
+    </item>
+  </channel>
+</rss>"#;
+
+        let feed = parse_feed(xml, Some("https://example.com/feed.xml")).unwrap();
+        assert_eq!(feed.kind, FeedKind::Rss);
+        assert_eq!(feed.title.as_deref(), Some("Example & Co blog"));
+        assert_eq!(feed.items.len(), 2);
+        assert_eq!(
+            feed.items[0],
+            FeedItem {
+                title: Some("Release <1.0>".to_string()),
+                link: Some("https://example.com/posts/1?a=1&b=2".to_string()),
+                published: Some("2025-06-10T04:00:00+00:00".to_string()),
+                content: Some("<p>Full text</p>".to_string()),
+                id: Some("post-1".to_string()),
+            }
+        );
+        assert_eq!(feed.items[1].link, None);
+        assert_eq!(feed.items[1].published.as_deref(), Some("not a date"));
+        assert_eq!(
+            feed.items[1].content.as_deref(),
+            Some("<b>Only a summary</b>")
+        );
+    }
+
+    #[test]
+    fn test_parse_atom() {
+        let xml = r#"<?xml version="1.0" encoding="utf-8"?>
+<feed xmlns="http://www.w3.org/2005/Atom">
+  <title type="text">Example feed</title>
+  <link rel="self" href="https://example.com/atom.xml"/>
+  <entry>
+    <title>Atom entry</title>
+    <link rel="edit" href="https://example.com/edit/1"/>
+    <link rel="alternate" type="text/html" href="https://example.com/entries/1"/>
+    <id>urn:uuid:1225c695</id>
+    <updated>2025-06-10T12:00:00Z</updated>
+    <summary>Summary only</summary>
+  </entry>
+  <entry>
+    <title>Relative</title>
+    <link href='entries/2'/>
+    <published>2025-06-11T08:30:00+02:00</published>
+    <content type="html">&lt;p&gt;Body&lt;/p&gt;</content>
+  </entry>
+</feed>"#;
+
+        let feed = parse_feed(xml, Some("https://example.com/atom.xml")).unwrap();
+        assert_eq!(feed.kind, FeedKind::Atom);
+        assert_eq!(feed.title.as_deref(), Some("Example feed"));
+        assert_eq!(
+            feed.items[0].link.as_deref(),
+            Some("https://example.com/entries/1")
+        );
+        assert_eq!(
+            feed.items[0].published.as_deref(),
+            Some("2025-06-10T12:00:00+00:00")
+        );
+        assert_eq!(feed.items[0].content.as_deref(), Some("Summary only"));
+        assert_eq!(feed.items[0].id.as_deref(), Some("urn:uuid:1225c695"));
+        assert_eq!(
+            feed.items[1].link.as_deref(),
+            Some("https://example.com/entries/2")
+        );
+        assert_eq!(feed.items[1].content.as_deref(), Some("<p>Body</p>"));
+    }
+
+    #[test]
+    fn test_non_feed_documents_are_rejected() {
+        assert!(parse_feed("<html><body>feed me</body></html>", None).is_none());
+        assert!(parse_feed("{\"items\": []}", None).is_none());
+    }
+}
@@ -0,0 +1,527 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! HTML 表格提取
+//!
+//! 识别页面中的 `<table>`，按 `rowspan` / `colspan` 展开为规则的行列网格，
+//! 再以表头为键转换为记录数组（`formats: ["tables"]`），也可导出为 CSV。
+//! 表头取自 `<thead>` 或全部由 `<th>` 组成的首行；没有表头标记时可推断首行为表头。
+//! 提取规则设置 `output_format: "table"` 时，选中的表格同样转换为记录数组。
+
+use scraper::{ElementRef, Html, Selector};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+
+/// 请求表格提取的格式名
+pub const TABLES_FORMAT: &str = "tables";
+
+/// 每个页面最多提取的表格数
+pub const MAX_TABLES: usize = 100;
+
+/// 单个表格最多展开的单元格数，超出的行被丢弃
+pub const MAX_TABLE_CELLS: usize = 100_000;
+
+/// HTML 规范允许的最大 colspan
+const MAX_COLSPAN: usize = 1000;
+
+/// `formats` 是否请求表格提取
+pub fn requests_tables(formats: Option<&[String]>) -> bool {
+    formats.is_some_and(|formats| {
+        formats
+            .iter()
+            .any(|format| format.trim().eq_ignore_ascii_case(TABLES_FORMAT))
+    })
+}
+
+/// 表格提取配置
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct TableOptions {
+    /// 没有表头标记时是否推断首行为表头（默认 true）
+    pub infer_headers: bool,
+    /// 是否同时返回 CSV 文本（默认 false）
+    pub csv: bool,
+}
+
+impl Default for TableOptions {
+    fn default() -> Self {
+        Self {
+            infer_headers: true,
+            csv: false,
+        }
+    }
+}
+
+/// 表头来源
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HeaderSource {
+    /// `<thead>` 或 `<th>` 行
+    Markup,
+    /// 由首行推断
+    Inferred,
+    /// 没有表头，按列序号生成 `column_1`、`column_2`…
+    Generated,
+}
+
+/// 展开后的表格
+#[derive(Debug, Clone, PartialEq)]
+pub struct HtmlTable {
+    /// `<caption>` 文本
+    pub caption: Option<String>,
+    /// 每列的表头，多行表头以 ` / ` 连接
+    pub headers: Vec<String>,
+    /// 表头来源
+    pub header_source: HeaderSource,
+    /// 数据行，每行与表头等宽
+    pub rows: Vec<Vec<String>>,
+}
+
+/// 展开前的单元格
+struct RawCell {
+    text: String,
+    header: bool,
+    rowspan: usize,
+    colspan: usize,
+}
+
+/// 展开前的行
+struct RawRow {
+    cells: Vec<RawCell>,
+    in_thead: bool,
+}
+
+impl HtmlTable {
+    /// 解析一个 `<table>` 元素，嵌套表格不计入外层表格
+    ///
+    /// 表格没有任何单元格时返回 None。
+    pub fn from_element(table: ElementRef, infer_headers: bool) -> Option<Self> {
+        let mut caption = None;
+        let mut raw_rows = Vec::new();
+        for child in table.children().filter_map(ElementRef::wrap) {
+            match child.value().name() {
+                "caption" => caption = Some(cell_text(child)).filter(|c| !c.is_empty()),
+                "tr" => raw_rows.push(raw_row(child, false)),
+                section @ ("thead" | "tbody" | "tfoot") => {
+                    for row in child
+                        .children()
+                        .filter_map(ElementRef::wrap)
+                        .filter(|e| e.value().name() == "tr")
+                    {
+                        raw_rows.push(raw_row(row, section == "thead"));
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let (grid, header_rows) = expand_grid(&raw_rows);
+        let width = grid.iter().map(Vec::len).max().unwrap_or(0);
+        if width == 0 {
+            return None;
+        }
+        let mut grid: Vec<Vec<String>> = grid
+            .into_iter()
+            .map(|row| {
+                let mut row: Vec<String> = row.into_iter().map(Option::unwrap_or_default).collect();
+                row.resize(width, String::new());
+                row
+            })
+            .collect();
+
+        // 丢弃展开后全为空的行
+        let mut header_rows = header_rows;
+        let mut index = 0;
+        grid.retain(|row| {
+            let keep = row.iter().any(|cell| !cell.is_empty());
+            if !keep && index < header_rows {
+                header_rows -= 1;
+            }
+            index += 1;
+            keep
+        });
+
+        let (headers, header_source) = if header_rows > 0 {
+            let header_grid: Vec<Vec<String>> = grid.drain(..header_rows).collect();
+            (merge_header_rows(&header_grid, width), HeaderSource::Markup)
+        } else if infer_headers && grid.len() > 1 && looks_like_header(&grid[0]) {
+            (grid.remove(0), HeaderSource::Inferred)
+        } else {
+            (
+                (1..=width).map(|i| format!("column_{}", i)).collect(),
+                HeaderSource::Generated,
+            )
+        };
+
+        Some(Self {
+            caption,
+            headers,
+            header_source,
+            rows: grid,
+        })
+    }
+
+    /// 记录使用的键：空表头按列序号命名，重复表头追加 `_2`、`_3`…
+    pub fn record_keys(&self) -> Vec<String> {
+        let mut keys: Vec<String> = Vec::with_capacity(self.headers.len());
+        for (i, header) in self.headers.iter().enumerate() {
+            let base = if header.is_empty() {
+                format!("column_{}", i + 1)
+            } else {
+                header.clone()
+            };
+            let mut key = base.clone();
+            let mut n = 2;
+            while keys.contains(&key) {
+                key = format!("{}_{}", base, n);
+                n += 1;
+            }
+            keys.push(key);
+        }
+        keys
+    }
+
+    /// 以表头为键的记录数组
+    pub fn records(&self) -> Vec<Value> {
+        let keys = self.record_keys();
+        self.rows
+            .iter()
+            .map(|row| {
+                let record: Map<String, Value> = keys
+                    .iter()
+                    .zip(row)
+                    .map(|(key, value)| (key.clone(), Value::String(value.clone())))
+                    .collect();
+                Value::Object(record)
+            })
+            .collect()
+    }
+
+    /// 导出为 CSV（RFC 4180），首行为表头
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::new();
+        for row in std::iter::once(&self.headers).chain(&self.rows) {
+            let line: Vec<String> = row.iter().map(|field| csv_field(field)).collect();
+            csv.push_str(&line.join(","));
+            csv.push_str("\r\n");
+        }
+        csv
+    }
+
+    /// 写入结果元数据的 JSON 表示
+    pub fn to_json(&self, options: &TableOptions) -> Value {
+        let mut value = json!({
+            "caption": self.caption,
+            "headers": self.headers,
+            "header_source": self.header_source,
+            "records": self.records(),
+        });
+        if options.csv {
+            value["csv"] = Value::String(self.to_csv());
+        }
+        value
+    }
+}
+
+/// 提取 HTML 文档中的全部表格（含嵌套表格），最多 `MAX_TABLES` 个
+pub fn extract_tables(html: &str, infer_headers: bool) -> Vec<HtmlTable> {
+    let document = Html::parse_document(html);
+    let selector = Selector::parse("table").expect("valid selector");
+    document
+        .select(&selector)
+        .filter_map(|table| HtmlTable::from_element(table, infer_headers))
+        .filter(|table| !table.rows.is_empty())
+        .take(MAX_TABLES)
+        .collect()
+}
+
+/// 提取表格并转换为写入结果元数据的 JSON 数组
+pub fn tables_to_json(html: &str, options: &TableOptions) -> Value {
+    Value::Array(
+        extract_tables(html, options.infer_headers)
+            .iter()
+            .map(|table| table.to_json(options))
+            .collect(),
+    )
+}
+
+/// 将选中的元素转换为表格记录：元素本身不是表格时使用其中的第一个表格
+///
+/// 元素中没有表格时返回 None。
+pub fn element_table_records(element: ElementRef, infer_headers: bool) -> Option<Value> {
+    let table = if element.value().name() == "table" {
+        element
+    } else {
+        let selector = Selector::parse("table").expect("valid selector");
+        element.select(&selector).next()?
+    };
+    let table = HtmlTable::from_element(table, infer_headers)?;
+    Some(Value::Array(table.records()))
+}
+
+fn raw_row(row: ElementRef, in_thead: bool) -> RawRow {
+    let cells = row
+        .children()
+        .filter_map(ElementRef::wrap)
+        .filter(|cell| matches!(cell.value().name(), "td" | "th"))
+        .map(|cell| RawCell {
+            text: cell_text(cell),
+            header: cell.value().name() == "th",
+            rowspan: span(cell, "rowspan"),
+            colspan: span(cell, "colspan").min(MAX_COLSPAN),
+        })
+        .collect();
+    RawRow { cells, in_thead }
+}
+
+/// 读取 span 属性，缺失或无效时为 1；`rowspan="0"` 表示延伸到表格末尾，以 0 表示
+fn span(cell: ElementRef, name: &str) -> usize {
+    match cell.value().attr(name).map(|v| v.trim().parse::<usize>()) {
+        Some(Ok(0)) if name == "rowspan" => 0,
+        Some(Ok(n)) if n > 0 => n,
+        _ => 1,
+    }
+}
+
+/// 按 rowspan / colspan 展开为网格，返回网格与表头行数
+///
+/// 表头行为开头连续的 `<thead>` 行或全部由 `<th>` 组成的行。
+fn expand_grid(rows: &[RawRow]) -> (Vec<Vec<Option<String>>>, usize) {
+    let mut grid: Vec<Vec<Option<String>>> = (0..rows.len()).map(|_| Vec::new()).collect();
+    let mut cells = 0usize;
+    let mut kept = rows.len();
+    'rows: for (r, row) in rows.iter().enumerate() {
+        let mut col = 0;
+        for cell in &row.cells {
+            while grid[r].get(col).is_some_and(Option::is_some) {
+                col += 1;
+            }
+            let remaining = rows.len() - r;
+            let rowspan = match cell.rowspan {
+                0 => remaining,
+                n => n.min(remaining),
+            };
+            cells += rowspan * cell.colspan;
+            if cells > MAX_TABLE_CELLS {
+                kept = r;
+                break 'rows;
+            }
+            for target in grid.iter_mut().skip(r).take(rowspan) {
+                if target.len() < col + cell.colspan {
+                    target.resize(col + cell.colspan, None);
+                }
+                for slot in &mut target[col..col + cell.colspan] {
+                    *slot = Some(cell.text.clone());
+                }
+            }
+            col += cell.colspan;
+        }
+    }
+    grid.truncate(kept);
+
+    let header_rows = rows
+        .iter()
+        .take(kept)
+        .take_while(|row| {
+            !row.cells.is_empty() && (row.in_thead || row.cells.iter().all(|cell| cell.header))
+        })
+        .count();
+    // 整个表格都是表头行时不视为表头，避免丢失全部数据
+    let header_rows = if header_rows == kept { 0 } else { header_rows };
+    (grid, header_rows)
+}
+
+/// 合并多行表头：同一列中不同的非空文本以 ` / ` 连接
+fn merge_header_rows(header_rows: &[Vec<String>], width: usize) -> Vec<String> {
+    (0..width)
+        .map(|col| {
+            let mut parts: Vec<&str> = Vec::new();
+            for row in header_rows {
+                let text = row[col].as_str();
+                if !text.is_empty() && !parts.contains(&text) {
+                    parts.push(text);
+                }
+            }
+            parts.join(" / ")
+        })
+        .collect()
+}
+
+/// 首行是否像表头：每格都非空、互不相同且不是数字
+fn looks_like_header(row: &[String]) -> bool {
+    row.iter()
+        .enumerate()
+        .all(|(i, cell)| !cell.is_empty() && !row[..i].contains(cell) && !is_numeric(cell))
+}
+
+fn is_numeric(text: &str) -> bool {
+    let digits: String = text
+        .chars()
+        .filter(|c| !matches!(c, ',' | ' ' | '%' | '$' | '€' | '£' | '¥'))
+        .collect();
+    !digits.is_empty() && digits.parse::<f64>().is_ok()
+}
+
+/// 单元格文本：空白合并为单个空格，跳过嵌套表格、脚本与样式
+fn cell_text(element: ElementRef) -> String {
+    let mut text = String::new();
+    collect_text(element, &mut text);
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn collect_text(element: ElementRef, out: &mut String) {
+    for child in element.children() {
+        if let Some(text) = child.value().as_text() {
+            out.push_str(text);
+        } else if let Some(child) = ElementRef::wrap(child) {
+            match child.value().name() {
+                "table" | "script" | "style" => {}
+                "br" => out.push(' '),
+                _ => {
+                    collect_text(child, out);
+                    out.push(' ');
+                }
+            }
+        }
+    }
+}
+
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn single_table(html: &str) -> HtmlTable {
+        let mut tables = extract_tables(html, true);
+        assert_eq!(tables.len(), 1);
+        tables.remove(0)
+    }
+
+    #[test]
+    fn test_requests_tables() {
+        assert!(requests_tables(Some(&[
+            "markdown".to_string(),
+            " Tables".to_string()
+        ])));
+        assert!(!requests_tables(Some(&["html".to_string()])));
+        assert!(!requests_tables(None));
+    }
+
+    #[test]
+    fn test_thead_headers_and_records() {
+        let table = single_table(
+            "<table><caption>Plans</caption>
+               <thead><tr><th>Plan</th><th>Price</th></tr></thead>
+               <tbody><tr><td>Basic</td><td>$10</td></tr>
+                      <tr><td>Pro <b>plus</b></td><td>$20</td></tr></tbody>
+             </table>",
+        );
+        assert_eq!(table.caption.as_deref(), Some("Plans"));
+        assert_eq!(table.header_source, HeaderSource::Markup);
+        assert_eq!(table.headers, vec!["Plan", "Price"]);
+        assert_eq!(
+            table.records(),
+            vec![
+                json!({"Plan": "Basic", "Price": "$10"}),
+                json!({"Plan": "Pro plus", "Price": "$20"}),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_rowspan_and_colspan_are_expanded() {
+        let table = single_table(
+            "<table>
+               <tr><th rowspan=2>Region</th><th colspan=2>Sales</th></tr>
+               <tr><th>Q1</th><th>Q2</th></tr>
+               <tr><td rowspan=2>EU</td><td>1</td><td>2</td></tr>
+               <tr><td colspan=2>n/a</td></tr>
+             </table>",
+        );
+        assert_eq!(table.headers, vec!["Region", "Sales / Q1", "Sales / Q2"]);
+        assert_eq!(
+            table.rows,
+            vec![vec!["EU", "1", "2"], vec!["EU", "n/a", "n/a"]]
+        );
+    }
+
+    #[test]
+    fn test_header_inference() {
+        let html =
+            "<table><tr><td>Name</td><td>Age</td></tr><tr><td>Ana</td><td>31</td></tr></table>";
+        let table = single_table(html);
+        assert_eq!(table.header_source, HeaderSource::Inferred);
+        assert_eq!(table.records(), vec![json!({"Name": "Ana", "Age": "31"})]);
+
+        let table = extract_tables(html, false).remove(0);
+        assert_eq!(table.header_source, HeaderSource::Generated);
+        assert_eq!(table.headers, vec!["column_1", "column_2"]);
+        assert_eq!(table.rows.len(), 2);
+
+        // 数字首行不是表头
+        let table = single_table(
+            "<table><tr><td>2023</td><td>5</td></tr><tr><td>2024</td><td>7</td></tr></table>",
+        );
+        assert_eq!(table.header_source, HeaderSource::Generated);
+    }
+
+    #[test]
+    fn test_nested_tables_and_ragged_rows() {
+        let tables = extract_tables(
+            "<table><tr><th>Outer</th><th>Extra</th></tr>
+               <tr><td>cell<table><tr><td>inner</td></tr><tr><td>x</td></tr></table></td></tr>
+             </table>",
+            true,
+        );
+        assert_eq!(tables.len(), 2);
+        assert_eq!(tables[0].rows, vec![vec!["cell", ""]]);
+        assert_eq!(tables[1].rows, vec![vec!["x"]]);
+    }
+
+    #[test]
+    fn test_duplicate_and_empty_headers_get_unique_keys() {
+        let table = single_table(
+            "<table><tr><th>Name</th><th></th><th>Name</th></tr><tr><td>a</td><td>b</td><td>c</td></tr></table>",
+        );
+        assert_eq!(table.record_keys(), vec!["Name", "column_2", "Name_2"]);
+    }
+
+    #[test]
+    fn test_csv_quotes_fields() {
+        let table = single_table(
+            "<table><tr><th>Name</th><th>Note</th></tr><tr><td>Ana</td><td>says \"hi\", twice</td></tr></table>",
+        );
+        assert_eq!(
+            table.to_csv(),
+            "Name,Note\r\nAna,\"says \"\"hi\"\", twice\"\r\n"
+        );
+        let value = table.to_json(&TableOptions {
+            csv: true,
+            ..Default::default()
+        });
+        assert_eq!(value["header_source"], "markup");
+        assert!(value["csv"].as_str().unwrap().starts_with("Name,Note"));
+    }
+
+    #[test]
+    fn test_element_table_records_uses_first_descendant_table() {
+        let document = Html::parse_document(
+            "<div id=prices><table><tr><th>A</th></tr><tr><td>1</td></tr></table></div>",
+        );
+        let selector = Selector::parse("#prices").unwrap();
+        let element = document.select(&selector).next().unwrap();
+        assert_eq!(
+            element_table_records(element, true),
+            Some(json!([{"A": "1"}]))
+        );
+    }
+}
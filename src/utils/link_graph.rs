@@ -0,0 +1,291 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! 链接图导出
+//!
+//! 将爬取中记录的页面链接组装为有向图，并渲染为 JSON、GraphML（Gephi、yEd、NetworkX）
+//! 或 Graphviz DOT 格式，供分析站点结构与内部链接。
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::domain::models::CrawlLink;
+
+/// 链接图导出格式
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum GraphFormat {
+    /// 节点与边的 JSON 对象
+    #[default]
+    Json,
+    /// GraphML XML
+    Graphml,
+    /// Graphviz DOT
+    Dot,
+}
+
+impl GraphFormat {
+    /// 文件格式的 Content-Type（JSON 格式使用统一响应信封）
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            GraphFormat::Json => "application/json",
+            GraphFormat::Graphml => "application/graphml+xml",
+            GraphFormat::Dot => "text/vnd.graphviz",
+        }
+    }
+}
+
+/// 链接图中的页面
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct GraphNode {
+    pub url: String,
+    /// 指向该页面的链接数
+    pub in_degree: usize,
+    /// 该页面包含的链接数
+    pub out_degree: usize,
+}
+
+/// 链接图中的链接
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct GraphEdge {
+    pub source: String,
+    pub target: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub anchor_text: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub rel: Vec<String>,
+}
+
+/// 爬取的链接图
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct LinkGraph {
+    /// 按首次出现顺序排列的页面
+    pub nodes: Vec<GraphNode>,
+    pub edges: Vec<GraphEdge>,
+    /// 链接数超过导出上限、只包含部分链接时为 true
+    pub truncated: bool,
+}
+
+impl LinkGraph {
+    /// 由爬取链接组装链接图
+    pub fn from_links(links: Vec<CrawlLink>, truncated: bool) -> Self {
+        let mut nodes: Vec<GraphNode> = Vec::new();
+        let mut index: HashMap<String, usize> = HashMap::new();
+        let mut node_index = |url: &str, nodes: &mut Vec<GraphNode>| -> usize {
+            *index.entry(url.to_string()).or_insert_with(|| {
+                nodes.push(GraphNode {
+                    url: url.to_string(),
+                    in_degree: 0,
+                    out_degree: 0,
+                });
+                nodes.len() - 1
+            })
+        };
+
+        let mut edges = Vec::with_capacity(links.len());
+        for link in links {
+            let source = node_index(&link.source_url, &mut nodes);
+            nodes[source].out_degree += 1;
+            let target = node_index(&link.target_url, &mut nodes);
+            nodes[target].in_degree += 1;
+            edges.push(GraphEdge {
+                source: link.source_url,
+                target: link.target_url,
+                anchor_text: link.anchor_text,
+                rel: link.rel,
+            });
+        }
+
+        Self {
+            nodes,
+            edges,
+            truncated,
+        }
+    }
+
+    /// 渲染为 GraphML，节点 ID 为 `n{序号}`，URL、锚文本与 rel 作为属性
+    pub fn to_graphml(&self) -> String {
+        let ids: HashMap<&str, usize> = self
+            .nodes
+            .iter()
+            .enumerate()
+            .map(|(i, node)| (node.url.as_str(), i))
+            .collect();
+
+        let mut out = String::from(concat!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n",
+            "<graphml xmlns=\"http://graphml.graphdrawing.org/xmlns\">\n",
+            "  <key id=\"url\" for=\"node\" attr.name=\"url\" attr.type=\"string\"/>\n",
+            "  <key id=\"in_degree\" for=\"node\" attr.name=\"in_degree\" attr.type=\"int\"/>\n",
+            "  <key id=\"out_degree\" for=\"node\" attr.name=\"out_degree\" attr.type=\"int\"/>\n",
+            "  <key id=\"anchor_text\" for=\"edge\" attr.name=\"anchor_text\" attr.type=\"string\"/>\n",
+            "  <key id=\"rel\" for=\"edge\" attr.name=\"rel\" attr.type=\"string\"/>\n",
+            "  <graph id=\"crawl\" edgedefault=\"directed\">\n",
+        ));
+        for (i, node) in self.nodes.iter().enumerate() {
+            out.push_str(&format!(
+                "    <node id=\"n{}\"><data key=\"url\">{}</data><data key=\"in_degree\">{}</data><data key=\"out_degree\">{}</data></node>\n",
+                i,
+                escape_xml(&node.url),
+                node.in_degree,
+                node.out_degree
+            ));
+        }
+        for (i, edge) in self.edges.iter().enumerate() {
+            out.push_str(&format!(
+                "    <edge id=\"e{}\" source=\"n{}\" target=\"n{}\">",
+                i,
+                ids[edge.source.as_str()],
+                ids[edge.target.as_str()]
+            ));
+            if let Some(anchor_text) = &edge.anchor_text {
+                out.push_str(&format!(
+                    "<data key=\"anchor_text\">{}</data>",
+                    escape_xml(anchor_text)
+                ));
+            }
+            if !edge.rel.is_empty() {
+                out.push_str(&format!(
+                    "<data key=\"rel\">{}</data>",
+                    escape_xml(&edge.rel.join(" "))
+                ));
+            }
+            out.push_str("</edge>\n");
+        }
+        out.push_str("  </graph>\n</graphml>\n");
+        out
+    }
+
+    /// 渲染为 Graphviz DOT，节点以 URL 命名，锚文本作为边标签
+    pub fn to_dot(&self) -> String {
+        let mut out = String::from("digraph crawl {\n");
+        for node in &self.nodes {
+            out.push_str(&format!("  \"{}\";\n", escape_dot(&node.url)));
+        }
+        for edge in &self.edges {
+            let mut attrs = Vec::new();
+            if let Some(anchor_text) = &edge.anchor_text {
+                attrs.push(format!("label=\"{}\"", escape_dot(anchor_text)));
+            }
+            if !edge.rel.is_empty() {
+                attrs.push(format!("rel=\"{}\"", escape_dot(&edge.rel.join(" "))));
+            }
+            out.push_str(&format!(
+                "  \"{}\" -> \"{}\"",
+                escape_dot(&edge.source),
+                escape_dot(&edge.target)
+            ));
+            if !attrs.is_empty() {
+                out.push_str(&format!(" [{}]", attrs.join(", ")));
+            }
+            out.push_str(";\n");
+        }
+        out.push_str("}\n");
+        out
+    }
+}
+
+/// 转义 XML 文本与属性中的特殊字符
+fn escape_xml(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c if (c as u32) < 0x20 && !matches!(c, '\t' | '\n' | '\r') => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// 转义 DOT 双引号字符串中的特殊字符
+fn escape_dot(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace(['\n', '\r'], " ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    fn sample_graph() -> LinkGraph {
+        let crawl_id = Uuid::new_v4();
+        LinkGraph::from_links(
+            vec![
+                CrawlLink::new(
+                    crawl_id,
+                    "https://example.com/",
+                    "https://example.com/about",
+                    Some("About \"us\" & team".to_string()),
+                    vec![],
+                ),
+                CrawlLink::new(
+                    crawl_id,
+                    "https://example.com/",
+                    "https://example.com/blog?a=1&b=2",
+                    None,
+                    vec!["nofollow".to_string()],
+                ),
+                CrawlLink::new(
+                    crawl_id,
+                    "https://example.com/about",
+                    "https://example.com/",
+                    Some("Home".to_string()),
+                    vec![],
+                ),
+            ],
+            false,
+        )
+    }
+
+    #[test]
+    fn test_from_links_counts_degrees() {
+        let graph = sample_graph();
+        assert_eq!(graph.nodes.len(), 3);
+        assert_eq!(graph.edges.len(), 3);
+        assert_eq!(graph.nodes[0].url, "https://example.com/");
+        assert_eq!(graph.nodes[0].out_degree, 2);
+        assert_eq!(graph.nodes[0].in_degree, 1);
+        assert_eq!(graph.nodes[2].in_degree, 1);
+        assert_eq!(graph.nodes[2].out_degree, 0);
+
+        let json = serde_json::to_value(&graph).unwrap();
+        assert_eq!(json["edges"][1]["rel"][0], "nofollow");
+        assert!(json["edges"][1].get("anchor_text").is_none());
+        assert_eq!(json["truncated"], false);
+    }
+
+    #[test]
+    fn test_to_graphml_escapes_values() {
+        let graphml = sample_graph().to_graphml();
+        assert!(graphml.starts_with("<?xml"));
+        assert!(graphml.contains("<data key=\"url\">https://example.com/blog?a=1&amp;b=2</data>"));
+        assert!(graphml.contains(
+            "<edge id=\"e0\" source=\"n0\" target=\"n1\"><data key=\"anchor_text\">About &quot;us&quot; &amp; team</data></edge>"
+        ));
+        assert!(graphml.contains("<data key=\"rel\">nofollow</data>"));
+        assert!(graphml.trim_end().ends_with("</graphml>"));
+    }
+
+    #[test]
+    fn test_to_dot_quotes_urls_and_labels() {
+        let dot = sample_graph().to_dot();
+        assert!(dot.starts_with("digraph crawl {\n"));
+        assert!(dot.contains(
+            "  \"https://example.com/\" -> \"https://example.com/about\" [label=\"About \\\"us\\\" & team\"];\n"
+        ));
+        assert!(dot.contains(
+            "  \"https://example.com/\" -> \"https://example.com/blog?a=1&b=2\" [rel=\"nofollow\"];\n"
+        ));
+        assert!(dot.ends_with("}\n"));
+    }
+}
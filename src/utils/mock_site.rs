@@ -0,0 +1,452 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! 可声明式配置的 mock 目标站点
+//!
+//! 为集成测试与压测提供确定性的抓取目标，替代直接访问 example.com / httpbin.org：
+//! - 任意路由的静态响应（状态码、Content-Type、自定义响应头）
+//! - 按路由注入响应延迟
+//! - 自定义 robots.txt
+//! - 反爬挑战页（模拟 Cloudflare "Just a moment..." 拦截页）
+//! - 多跳重定向链
+//! - 页面间互相链接的站点图（用于 crawl 深度/去重测试）
+//!
+//! 站点绑定到 `127.0.0.1` 的随机端口，`MockSiteHandle` drop 时自动关闭。
+//!
+//! # 使用示例
+//!
+//! ```ignore
+//! use crawlrs::utils::mock_site::{MockResponse, MockSite};
+//! use std::time::Duration;
+//!
+//! let site = MockSite::new()
+//!     .robots_txt("User-agent: *\nDisallow: /private")
+//!     .route("/", MockResponse::html("<a href=\"/slow\">slow</a>"))
+//!     .route("/slow", MockResponse::html("ok").with_delay(Duration::from_millis(200)))
+//!     .challenge_page("/protected")
+//!     .redirect_chain("/start", 3, "/")
+//!     .start()
+//!     .await?;
+//!
+//! let url = site.url("/slow");
+//! ```
+//!
+//! # 可见性门禁
+//!
+//! 与 `presentation::sdk::mocks` 一致，仅在 `cfg(test)` 或启用 `test-mocks` feature 时编译。
+
+use axum::body::Body;
+use axum::extract::State;
+use axum::http::{header, HeaderName, HeaderValue, Request, Response, StatusCode};
+use axum::Router;
+use dashmap::DashMap;
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::net::TcpListener;
+use tokio::sync::oneshot;
+use tokio::task::JoinHandle;
+
+/// 模拟反爬挑战页的响应体
+const CHALLENGE_BODY: &str = "<!DOCTYPE html><html><head><title>Just a moment...</title></head>\
+<body><div id=\"cf-challenge-running\">Checking your browser before accessing the site.</div>\
+<script src=\"/cdn-cgi/challenge-platform/h/b/orchestrate/jsch/v1\"></script></body></html>";
+
+/// 单个路由的静态响应定义
+#[derive(Debug, Clone)]
+pub struct MockResponse {
+    status: StatusCode,
+    content_type: String,
+    body: String,
+    headers: Vec<(String, String)>,
+    delay: Option<Duration>,
+}
+
+impl MockResponse {
+    /// 200 OK 的 HTML 响应
+    pub fn html(body: impl Into<String>) -> Self {
+        Self {
+            status: StatusCode::OK,
+            content_type: "text/html; charset=utf-8".to_string(),
+            body: body.into(),
+            headers: Vec::new(),
+            delay: None,
+        }
+    }
+
+    /// 200 OK 的纯文本响应
+    pub fn text(body: impl Into<String>) -> Self {
+        Self {
+            content_type: "text/plain; charset=utf-8".to_string(),
+            ..Self::html(body)
+        }
+    }
+
+    /// 指向 `location` 的 302 重定向
+    pub fn redirect(location: impl Into<String>) -> Self {
+        Self::html(String::new())
+            .with_status(StatusCode::FOUND)
+            .with_header(header::LOCATION.as_str(), location)
+    }
+
+    /// 覆盖状态码
+    pub fn with_status(mut self, status: StatusCode) -> Self {
+        self.status = status;
+        self
+    }
+
+    /// 覆盖 Content-Type
+    pub fn with_content_type(mut self, content_type: impl Into<String>) -> Self {
+        self.content_type = content_type.into();
+        self
+    }
+
+    /// 追加响应头
+    pub fn with_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// 在返回响应前等待指定时长（模拟慢站点 / 超时）
+    pub fn with_delay(mut self, delay: Duration) -> Self {
+        self.delay = Some(delay);
+        self
+    }
+
+    fn to_response(&self) -> Response<Body> {
+        let mut response = Response::new(Body::from(self.body.clone()));
+        *response.status_mut() = self.status;
+        let headers = response.headers_mut();
+        if let Ok(value) = HeaderValue::from_str(&self.content_type) {
+            headers.insert(header::CONTENT_TYPE, value);
+        }
+        for (name, value) in &self.headers {
+            if let (Ok(name), Ok(value)) = (
+                HeaderName::from_bytes(name.as_bytes()),
+                HeaderValue::from_str(value),
+            ) {
+                headers.insert(name, value);
+            }
+        }
+        response
+    }
+}
+
+/// Mock 站点构建器
+#[derive(Debug, Clone, Default)]
+pub struct MockSite {
+    routes: HashMap<String, MockResponse>,
+}
+
+impl MockSite {
+    /// 创建空站点（未配置的路径返回 404）
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 注册路由响应，重复注册同一路径时后者覆盖前者
+    pub fn route(mut self, path: impl Into<String>, response: MockResponse) -> Self {
+        self.routes.insert(path.into(), response);
+        self
+    }
+
+    /// 设置 `/robots.txt` 内容
+    pub fn robots_txt(self, body: impl Into<String>) -> Self {
+        self.route("/robots.txt", MockResponse::text(body))
+    }
+
+    /// 在 `path` 注册反爬挑战页（403 + Cloudflare 风格拦截页）
+    pub fn challenge_page(self, path: impl Into<String>) -> Self {
+        self.route(
+            path,
+            MockResponse::html(CHALLENGE_BODY)
+                .with_status(StatusCode::FORBIDDEN)
+                .with_header("server", "cloudflare")
+                .with_header("cf-mitigated", "challenge"),
+        )
+    }
+
+    /// 注册重定向链：`from` → `from/1` → … → `from/{hops-1}` → `target`
+    ///
+    /// `hops` 为重定向次数，0 视为 1。
+    pub fn redirect_chain(mut self, from: &str, hops: usize, target: &str) -> Self {
+        let hops = hops.max(1);
+        let hop_path = |i: usize| {
+            if i == 0 {
+                from.to_string()
+            } else {
+                format!("{}/{}", from.trim_end_matches('/'), i)
+            }
+        };
+        for i in 0..hops {
+            let next = if i + 1 == hops {
+                target.to_string()
+            } else {
+                hop_path(i + 1)
+            };
+            self.routes
+                .insert(hop_path(i), MockResponse::redirect(next));
+        }
+        self
+    }
+
+    /// 注册互相链接的 HTML 页面集合
+    ///
+    /// `pages` 中每一项为 `(路径, 该页面链接到的路径列表)`。
+    pub fn link_graph(mut self, pages: &[(&str, &[&str])]) -> Self {
+        for (path, links) in pages {
+            let anchors: String = links
+                .iter()
+                .map(|link| format!("<a href=\"{}\">{}</a>", link, link))
+                .collect();
+            let body = format!(
+                "<!DOCTYPE html><html><head><title>{}</title></head><body>{}</body></html>",
+                path, anchors
+            );
+            self.routes
+                .insert((*path).to_string(), MockResponse::html(body));
+        }
+        self
+    }
+
+    /// 绑定 `127.0.0.1` 随机端口并在后台启动站点
+    pub async fn start(self) -> std::io::Result<MockSiteHandle> {
+        let listener = TcpListener::bind(("127.0.0.1", 0)).await?;
+        let addr = listener.local_addr()?;
+        let state = Arc::new(SiteState {
+            routes: self.routes,
+            hits: DashMap::new(),
+        });
+
+        let app = Router::new().fallback(serve).with_state(state.clone());
+        let (shutdown_tx, shutdown_rx) = oneshot::channel::<()>();
+        let server = tokio::spawn(async move {
+            let _ = axum::serve(listener, app)
+                .with_graceful_shutdown(async {
+                    let _ = shutdown_rx.await;
+                })
+                .await;
+        });
+
+        Ok(MockSiteHandle {
+            addr,
+            state,
+            shutdown: Some(shutdown_tx),
+            server,
+        })
+    }
+}
+
+struct SiteState {
+    routes: HashMap<String, MockResponse>,
+    hits: DashMap<String, usize>,
+}
+
+async fn serve(State(state): State<Arc<SiteState>>, request: Request<Body>) -> Response<Body> {
+    let path = request.uri().path().to_string();
+    *state.hits.entry(path.clone()).or_insert(0) += 1;
+
+    match state.routes.get(&path) {
+        Some(route) => {
+            if let Some(delay) = route.delay {
+                tokio::time::sleep(delay).await;
+            }
+            route.to_response()
+        }
+        None => MockResponse::text("Not Found")
+            .with_status(StatusCode::NOT_FOUND)
+            .to_response(),
+    }
+}
+
+/// 运行中的 mock 站点，drop 时自动关闭
+pub struct MockSiteHandle {
+    addr: SocketAddr,
+    state: Arc<SiteState>,
+    shutdown: Option<oneshot::Sender<()>>,
+    server: JoinHandle<()>,
+}
+
+impl MockSiteHandle {
+    /// 站点监听地址
+    pub fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    /// 站点根 URL（不含末尾斜杠），如 `http://127.0.0.1:38211`
+    pub fn base_url(&self) -> String {
+        format!("http://{}", self.addr)
+    }
+
+    /// 拼接站点内路径的完整 URL
+    pub fn url(&self, path: &str) -> String {
+        format!("{}/{}", self.base_url(), path.trim_start_matches('/'))
+    }
+
+    /// 路径被请求的次数（含 404）
+    pub fn hits(&self, path: &str) -> usize {
+        self.state.hits.get(path).map(|h| *h).unwrap_or(0)
+    }
+
+    /// 所有路径的总请求次数
+    pub fn total_hits(&self) -> usize {
+        self.state.hits.iter().map(|h| *h.value()).sum()
+    }
+
+    /// 主动关闭站点并等待服务任务退出
+    pub async fn shutdown(mut self) {
+        if let Some(tx) = self.shutdown.take() {
+            let _ = tx.send(());
+        }
+        let _ = (&mut self.server).await;
+    }
+}
+
+impl Drop for MockSiteHandle {
+    fn drop(&mut self) {
+        if let Some(tx) = self.shutdown.take() {
+            let _ = tx.send(());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Instant;
+
+    fn no_redirect_client() -> reqwest::Client {
+        reqwest::Client::builder()
+            .redirect(reqwest::redirect::Policy::none())
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_route_returns_configured_response() {
+        let site = MockSite::new()
+            .route(
+                "/page",
+                MockResponse::html("<h1>hello</h1>").with_header("x-test", "1"),
+            )
+            .start()
+            .await
+            .unwrap();
+
+        let resp = reqwest::get(site.url("/page")).await.unwrap();
+        assert_eq!(resp.status(), 200);
+        assert_eq!(resp.headers()["x-test"], "1");
+        assert!(resp.headers()["content-type"]
+            .to_str()
+            .unwrap()
+            .starts_with("text/html"));
+        assert_eq!(resp.text().await.unwrap(), "<h1>hello</h1>");
+        assert_eq!(site.hits("/page"), 1);
+    }
+
+    #[tokio::test]
+    async fn test_unknown_path_returns_404() {
+        let site = MockSite::new().start().await.unwrap();
+        let resp = reqwest::get(site.url("/missing")).await.unwrap();
+        assert_eq!(resp.status(), 404);
+        assert_eq!(site.hits("/missing"), 1);
+    }
+
+    #[tokio::test]
+    async fn test_robots_txt_served_as_text() {
+        let site = MockSite::new()
+            .robots_txt("User-agent: *\nDisallow: /private")
+            .start()
+            .await
+            .unwrap();
+        let resp = reqwest::get(site.url("/robots.txt")).await.unwrap();
+        assert!(resp.headers()["content-type"]
+            .to_str()
+            .unwrap()
+            .starts_with("text/plain"));
+        assert!(resp.text().await.unwrap().contains("Disallow: /private"));
+    }
+
+    #[tokio::test]
+    async fn test_delay_is_applied() {
+        let site = MockSite::new()
+            .route(
+                "/slow",
+                MockResponse::text("ok").with_delay(Duration::from_millis(100)),
+            )
+            .start()
+            .await
+            .unwrap();
+        let started = Instant::now();
+        let resp = reqwest::get(site.url("/slow")).await.unwrap();
+        assert_eq!(resp.status(), 200);
+        assert!(started.elapsed() >= Duration::from_millis(100));
+    }
+
+    #[tokio::test]
+    async fn test_challenge_page_returns_403() {
+        let site = MockSite::new()
+            .challenge_page("/protected")
+            .start()
+            .await
+            .unwrap();
+        let resp = reqwest::get(site.url("/protected")).await.unwrap();
+        assert_eq!(resp.status(), 403);
+        assert_eq!(resp.headers()["cf-mitigated"], "challenge");
+        assert!(resp.text().await.unwrap().contains("Just a moment..."));
+    }
+
+    #[tokio::test]
+    async fn test_redirect_chain_hops() {
+        let site = MockSite::new()
+            .route("/final", MockResponse::text("done"))
+            .redirect_chain("/start", 3, "/final")
+            .start()
+            .await
+            .unwrap();
+
+        let client = no_redirect_client();
+        let first = client.get(site.url("/start")).send().await.unwrap();
+        assert_eq!(first.status(), 302);
+        assert_eq!(first.headers()["location"], "/start/1");
+
+        let resp = reqwest::get(site.url("/start")).await.unwrap();
+        assert_eq!(resp.text().await.unwrap(), "done");
+        assert_eq!(site.hits("/start/1"), 1);
+        assert_eq!(site.hits("/start/2"), 1);
+        assert_eq!(site.hits("/final"), 1);
+    }
+
+    #[tokio::test]
+    async fn test_link_graph_renders_anchors() {
+        let site = MockSite::new()
+            .link_graph(&[("/", &["/a", "/b"]), ("/a", &["/"]), ("/b", &[])])
+            .start()
+            .await
+            .unwrap();
+        let body = reqwest::get(site.url("/"))
+            .await
+            .unwrap()
+            .text()
+            .await
+            .unwrap();
+        assert!(body.contains("href=\"/a\""));
+        assert!(body.contains("href=\"/b\""));
+        assert_eq!(site.total_hits(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_stops_server() {
+        let site = MockSite::new()
+            .route("/", MockResponse::text("ok"))
+            .start()
+            .await
+            .unwrap();
+        let url = site.url("/");
+        site.shutdown().await;
+        assert!(reqwest::get(url).await.is_err());
+    }
+}
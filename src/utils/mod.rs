@@ -3,19 +3,30 @@
 // Licensed under the Apache License, Version 2.0
 // See LICENSE file in the project root for full license information.
 
+pub mod content_compression;
 pub mod crawl_text_integration;
 pub mod error_helpers;
+pub mod feed;
+pub mod html_table;
 /// 工具模块
 ///
 /// 提供通用的工具函数和辅助功能
 /// 包括文本处理、URL工具、错误处理等功能
 pub mod http_client;
+pub mod link_graph;
+#[cfg(any(test, feature = "test-mocks"))]
+pub mod mock_site;
 pub mod port_sniffer;
 pub mod regex_cache;
 pub mod retry_policy;
 pub mod robots;
+pub mod search_snippet;
 pub mod search_test;
+pub mod seo_audit;
+pub mod sitemap;
+pub mod structured_data;
 pub mod telemetry;
+pub mod text_diff;
 pub mod text_processing;
 pub mod url;
 
@@ -7,9 +7,11 @@ use crate::engines::client::reqwest::ReqwestEngine;
 use crate::engines::engine_client::{EngineClient, HttpMethod, ScrapeOptions, ScrapeRequest};
 use crate::engines::router::{EngineRouter, EngineRouterTrait};
 use crate::impl_basic_error_conversions;
+use crate::infrastructure::metrics::{record_robots_cache_lookup, record_robots_fetch_error};
 use crate::infrastructure::oxcache::CacheService;
 use crate::utils::retry_policy::RetryPolicy;
 use anyhow::Result;
+use chrono::{DateTime, Utc};
 use robotstxt::DefaultMatcher;
 use std::collections::HashMap;
 use std::sync::atomic::{AtomicU64, Ordering};
@@ -42,11 +44,18 @@ impl From<crate::presentation::helpers::ssrf::SsrfError> for RobotsCheckerError
     }
 }
 
+/// 默认缓存时长：抓取成功（或确认不存在）后保留 1 小时
+pub const DEFAULT_ROBOTS_TTL: Duration = Duration::from_secs(3600);
+
+/// 默认抓取失败缓存时长：按允许全部处理，5 分钟后重新抓取
+pub const DEFAULT_ROBOTS_ERROR_TTL: Duration = Duration::from_secs(300);
+
 /// Robots.txt缓存统计
 #[derive(Default, Clone)]
 pub struct CacheStats {
     pub hits: Arc<AtomicU64>,
     pub misses: Arc<AtomicU64>,
+    pub errors: Arc<AtomicU64>,
 }
 
 impl CacheStats {
@@ -69,6 +78,63 @@ impl CacheStats {
     pub fn record_miss(&self) {
         self.misses.fetch_add(1, Ordering::Relaxed);
     }
+
+    /// 获取抓取失败次数
+    pub fn errors(&self) -> u64 {
+        self.errors.load(Ordering::Relaxed)
+    }
+
+    /// 记录抓取失败
+    pub fn record_error(&self) {
+        self.errors.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+/// robots.txt 缓存条目的来源
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RobotsFetchStatus {
+    /// 抓取成功
+    Fetched,
+    /// 不存在（404 或其他 4xx），允许全部
+    Missing,
+    /// 重试耗尽仍失败，允许全部
+    FetchFailed,
+    /// 从共享缓存加载
+    SharedCache,
+}
+
+impl RobotsFetchStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Fetched => "fetched",
+            Self::Missing => "missing",
+            Self::FetchFailed => "fetch_failed",
+            Self::SharedCache => "shared_cache",
+        }
+    }
+}
+
+/// 内存缓存中的 robots.txt 条目
+#[derive(Debug, Clone)]
+pub struct RobotsCacheEntry {
+    /// robots.txt 地址（含 scheme 与端口）
+    pub robots_url: String,
+    /// 条目来源
+    pub status: RobotsFetchStatus,
+    /// 内容字节数
+    pub content_length: usize,
+    /// 写入缓存的时间
+    pub cached_at: DateTime<Utc>,
+    /// 剩余有效期
+    pub expires_in: Duration,
+}
+
+/// 缓存命中、未命中与抓取失败计数
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RobotsCacheCounters {
+    pub hits: u64,
+    pub misses: u64,
+    pub fetch_errors: u64,
 }
 
 /// Robots.txt检查器接口
@@ -78,6 +144,28 @@ pub trait RobotsCheckerTrait: Send + Sync {
     async fn is_allowed(&self, url_str: &str, user_agent: &str) -> Result<bool>;
     /// 获取爬取延迟
     async fn get_crawl_delay(&self, url_str: &str, user_agent: &str) -> Result<Option<Duration>>;
+    /// 获取 robots.txt 中 `Sitemap:` 指令声明的站点地图 URL
+    ///
+    /// 默认实现返回空列表，不声明站点地图。
+    async fn get_sitemaps(&self, _url_str: &str) -> Result<Vec<String>> {
+        Ok(Vec::new())
+    }
+    /// 列出本进程内存缓存中属于指定主机的 robots.txt 条目
+    ///
+    /// 默认实现不缓存，返回空列表。
+    async fn cached_entries(&self, _host: &str) -> Vec<RobotsCacheEntry> {
+        Vec::new()
+    }
+    /// 丢弃指定主机的缓存条目并重新抓取，返回刷新后的条目
+    ///
+    /// 默认实现不缓存，返回空列表。
+    async fn refresh(&self, _host: &str) -> Result<Vec<RobotsCacheEntry>> {
+        Ok(Vec::new())
+    }
+    /// 缓存命中、未命中与抓取失败计数
+    fn cache_counters(&self) -> RobotsCacheCounters {
+        RobotsCacheCounters::default()
+    }
 }
 
 /// 缓存的Robots.txt内容
@@ -88,6 +176,23 @@ struct CachedRobots {
 
     /// 过期时间
     expires_at: Instant,
+
+    /// 条目来源
+    status: RobotsFetchStatus,
+
+    /// 写入缓存的时间
+    cached_at: DateTime<Utc>,
+}
+
+impl CachedRobots {
+    fn new(content: String, status: RobotsFetchStatus, ttl: Duration) -> Self {
+        Self {
+            content,
+            expires_at: Instant::now() + ttl,
+            status,
+            cached_at: Utc::now(),
+        }
+    }
 }
 
 /// Robots.txt检查器
@@ -107,6 +212,12 @@ pub struct RobotsChecker {
 
     /// 缓存统计
     cache_stats: Arc<CacheStats>,
+
+    /// 抓取成功（或确认不存在）后的缓存时长
+    ttl: Duration,
+
+    /// 抓取失败后的缓存时长
+    error_ttl: Duration,
 }
 
 #[async_trait]
@@ -122,6 +233,87 @@ impl RobotsCheckerTrait for RobotsChecker {
         let content = self.get_robots_content(url_str).await?;
         Ok(self.parse_crawl_delay(&content, user_agent))
     }
+
+    async fn get_sitemaps(&self, url_str: &str) -> Result<Vec<String>> {
+        let content = self.get_robots_content(url_str).await?;
+        let base_url = Url::parse(url_str)?;
+        Ok(self.parse_sitemaps(&content, &base_url))
+    }
+
+    async fn cached_entries(&self, host: &str) -> Vec<RobotsCacheEntry> {
+        let host = host.trim_end_matches('.').to_ascii_lowercase();
+        let now = Instant::now();
+        let cache = self.memory_cache.lock().await;
+        let mut entries: Vec<RobotsCacheEntry> = cache
+            .iter()
+            .filter(|(robots_url, cached)| {
+                cached.expires_at > now && robots_url_host(robots_url).as_deref() == Some(&host)
+            })
+            .map(|(robots_url, cached)| RobotsCacheEntry {
+                robots_url: robots_url.clone(),
+                status: cached.status,
+                content_length: cached.content.len(),
+                cached_at: cached.cached_at,
+                expires_in: cached.expires_at.saturating_duration_since(now),
+            })
+            .collect();
+        entries.sort_by(|a, b| a.robots_url.cmp(&b.robots_url));
+        entries
+    }
+
+    async fn refresh(&self, host: &str) -> Result<Vec<RobotsCacheEntry>> {
+        let host = host.trim_end_matches('.').to_ascii_lowercase();
+        // 内存中已缓存的 scheme / 端口组合逐个刷新，未缓存时刷新 https 默认端口
+        let mut robots_urls: Vec<String> = {
+            let mut cache = self.memory_cache.lock().await;
+            let urls: Vec<String> = cache
+                .keys()
+                .filter(|robots_url| robots_url_host(robots_url).as_deref() == Some(&host))
+                .cloned()
+                .collect();
+            for robots_url in &urls {
+                cache.remove(robots_url);
+            }
+            urls
+        };
+        if robots_urls.is_empty() {
+            robots_urls.push(format!("https://{}:443/robots.txt", host));
+        }
+
+        if let Some(ref cache_service) = self.cache_service {
+            let default_urls = [
+                format!("https://{}:443/robots.txt", host),
+                format!("http://{}:80/robots.txt", host),
+            ];
+            for robots_url in robots_urls.iter().chain(default_urls.iter()) {
+                let cache_key = format!("robots_cache:{}", robots_url);
+                if let Err(e) = cache_service.delete(&cache_key).await {
+                    log::warn!("Failed to evict {} from robots cache: {}", robots_url, e);
+                }
+            }
+        }
+
+        for robots_url in &robots_urls {
+            self.get_robots_content(robots_url).await?;
+        }
+        Ok(self.cached_entries(&host).await)
+    }
+
+    fn cache_counters(&self) -> RobotsCacheCounters {
+        RobotsCacheCounters {
+            hits: self.cache_stats.hits(),
+            misses: self.cache_stats.misses(),
+            fetch_errors: self.cache_stats.errors(),
+        }
+    }
+}
+
+/// 缓存键（robots.txt 地址）中的主机名
+fn robots_url_host(robots_url: &str) -> Option<String> {
+    Url::parse(robots_url)
+        .ok()?
+        .host_str()
+        .map(|host| host.to_ascii_lowercase())
 }
 
 impl RobotsChecker {
@@ -153,9 +345,21 @@ impl RobotsChecker {
                 ..Default::default()
             },
             cache_stats: cache_stats.unwrap_or_else(|| Arc::new(CacheStats::default())),
+            ttl: DEFAULT_ROBOTS_TTL,
+            error_ttl: DEFAULT_ROBOTS_ERROR_TTL,
         }
     }
 
+    /// 设置缓存时长
+    ///
+    /// 注入自 `settings.robots`：`ttl` 用于抓取成功或确认不存在的条目，
+    /// `error_ttl` 用于抓取失败（按允许全部处理）的条目。
+    pub fn with_ttl(mut self, ttl: Duration, error_ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self.error_ttl = error_ttl;
+        self
+    }
+
     /// 获取Robots.txt内容（带缓存）
     async fn get_robots_content(&self, url_str: &str) -> Result<String, RobotsCheckerError> {
         let url =
@@ -174,6 +378,7 @@ impl RobotsChecker {
             if let Some(cached) = cache.get(&robots_url) {
                 if cached.expires_at > Instant::now() {
                     self.cache_stats.record_hit();
+                    record_robots_cache_lookup("memory_hit");
                     return Ok(cached.content.clone());
                 } else {
                     cache.remove(&robots_url);
@@ -191,22 +396,23 @@ impl RobotsChecker {
                 let mut cache = self.memory_cache.lock().await;
                 cache.insert(
                     robots_url.clone(),
-                    CachedRobots {
-                        content: content.clone(),
-                        expires_at: Instant::now() + Duration::from_secs(3600),
-                    },
+                    CachedRobots::new(content.clone(), RobotsFetchStatus::SharedCache, self.ttl),
                 );
                 self.cache_stats.record_hit();
+                record_robots_cache_lookup("shared_hit");
                 return Ok(content);
             }
         }
 
+        record_robots_cache_lookup("miss");
+
         // SSRF protection
         crate::engines::validators::validate_url(&robots_url).await?;
 
         // 3. Fetch robots.txt with retry
         let mut attempt = 0;
         let mut content = String::new();
+        let mut status = RobotsFetchStatus::Missing;
         let mut last_error = None;
 
         while attempt < self.retry_policy.max_retries {
@@ -228,6 +434,7 @@ impl RobotsChecker {
                 Ok(resp) => {
                     if resp.is_success() {
                         content = resp.content;
+                        status = RobotsFetchStatus::Fetched;
                         last_error = None;
                         break;
                     } else if resp.status_code == 404 {
@@ -257,23 +464,30 @@ impl RobotsChecker {
             log::warn!("Failed to fetch robots.txt from {}: {}", robots_url, err);
             // Default to empty content on persistent error
             content = "".to_string();
+            status = RobotsFetchStatus::FetchFailed;
+            self.cache_stats.record_error();
+            record_robots_fetch_error();
         }
 
-        // 4. Update memory cache
+        // 4. Update memory cache（抓取失败只短暂缓存）
+        let ttl = if status == RobotsFetchStatus::FetchFailed {
+            self.error_ttl
+        } else {
+            self.ttl
+        };
         {
             let mut cache = self.memory_cache.lock().await;
             cache.insert(
                 robots_url.clone(),
-                CachedRobots {
-                    content: content.clone(),
-                    expires_at: Instant::now() + Duration::from_secs(3600), // Cache for 1 hour
-                },
+                CachedRobots::new(content.clone(), status, ttl),
             );
         }
 
-        // 5. Update cache service
+        // 5. Update cache service（抓取失败不写入共享缓存，避免其他实例长期沿用）
         if let Some(ref cache_service) = self.cache_service {
-            let _ = cache_service.set(&cache_key, &content, 86400).await; // Cache for 24 hours
+            if status != RobotsFetchStatus::FetchFailed {
+                let _ = cache_service.set(&cache_key, &content, ttl.as_secs()).await;
+            }
         }
 
         Ok(content)
@@ -320,6 +534,42 @@ impl RobotsChecker {
         delay.map(Duration::from_secs_f64)
     }
 
+    /// 解析 Sitemap 指令
+    ///
+    /// `Sitemap:` 不属于任何 User-agent 分组，对所有爬虫生效；相对路径按站点解析，
+    /// 只保留 http/https URL 并按出现顺序去重。
+    fn parse_sitemaps(&self, content: &str, base_url: &Url) -> Vec<String> {
+        let mut sitemaps: Vec<String> = Vec::new();
+
+        for line in content.lines() {
+            let line = line.trim();
+            let Some(value) = line
+                .get(..8)
+                .filter(|prefix| prefix.eq_ignore_ascii_case("sitemap:"))
+                .map(|_| &line[8..])
+            else {
+                continue;
+            };
+
+            // 去掉行尾注释
+            let value = value.split('#').next().unwrap_or("").trim();
+            if value.is_empty() {
+                continue;
+            }
+
+            if let Ok(url) = base_url.join(value) {
+                if matches!(url.scheme(), "http" | "https") {
+                    let url = url.to_string();
+                    if !sitemaps.contains(&url) {
+                        sitemaps.push(url);
+                    }
+                }
+            }
+        }
+
+        sitemaps
+    }
+
     /// 旧的公开方法，为了兼容性保留
     pub async fn is_allowed(&self, url_str: &str, user_agent: &str) -> Result<bool> {
         RobotsCheckerTrait::is_allowed(self, url_str, user_agent).await
@@ -553,6 +803,35 @@ mod tests {
         assert_eq!(misses, 1);
     }
 
+    // ----- parse_sitemaps tests -----
+
+    #[test]
+    fn test_parse_sitemaps_outside_agent_groups() {
+        let checker = make_checker();
+        let base = Url::parse("https://example.com/blog/post").unwrap();
+        let content = "Sitemap: https://example.com/sitemap.xml\n\
+                       User-agent: *\n\
+                       Disallow: /private\n\
+                       SITEMAP: /news-sitemap.xml # news\n\
+                       sitemap: https://example.com/sitemap.xml\n";
+        assert_eq!(
+            checker.parse_sitemaps(content, &base),
+            vec![
+                "https://example.com/sitemap.xml".to_string(),
+                "https://example.com/news-sitemap.xml".to_string(),
+            ],
+            "directives are case-insensitive, resolved against the site and de-duplicated"
+        );
+    }
+
+    #[test]
+    fn test_parse_sitemaps_ignores_invalid_entries() {
+        let checker = make_checker();
+        let base = Url::parse("https://example.com").unwrap();
+        let content = "Sitemap:\nSitemap: ftp://example.com/sitemap.xml\nAllow: /\n";
+        assert!(checker.parse_sitemaps(content, &base).is_empty());
+    }
+
     // ----- parse_crawl_delay tests -----
 
     #[test]
@@ -754,10 +1033,11 @@ Crawl-delay: 8
         let mut cache = checker.memory_cache.lock().await;
         cache.insert(
             robots_url.to_string(),
-            CachedRobots {
-                content: content.to_string(),
-                expires_at: Instant::now() + Duration::from_secs(3600),
-            },
+            CachedRobots::new(
+                content.to_string(),
+                RobotsFetchStatus::Fetched,
+                DEFAULT_ROBOTS_TTL,
+            ),
         );
     }
 
@@ -1076,6 +1356,20 @@ Crawl-delay: 8
                     content_type: "text/plain".to_string(),
                     headers: HashMap::new(),
                     response_time_ms: 0,
+                    truncated: false,
+                    raw_content: None,
+                    http_version: None,
+                    tls_profile: None,
+                    har: None,
+                    mhtml: None,
+                    pdf: None,
+                    blocked_requests: None,
+                    evaluate_results: Vec::new(),
+                    frames: Vec::new(),
+                    accessibility: None,
+                    timings: None,
+                    ip_family: None,
+                    escalations: Vec::new(),
                 }),
                 error: None,
                 call_count: AtomicU64::new(0),
@@ -1144,6 +1438,8 @@ Crawl-delay: 8
                 ..Default::default()
             },
             cache_stats: Arc::new(CacheStats::default()),
+            ttl: DEFAULT_ROBOTS_TTL,
+            error_ttl: DEFAULT_ROBOTS_ERROR_TTL,
         }
     }
 
@@ -1165,6 +1461,8 @@ Crawl-delay: 8
             cache_service: Some(mock_cache as Arc<dyn CacheService>),
             retry_policy: RetryPolicy::default(),
             cache_stats: Arc::new(CacheStats::default()),
+            ttl: DEFAULT_ROBOTS_TTL,
+            error_ttl: DEFAULT_ROBOTS_ERROR_TTL,
         };
 
         let allowed = checker
@@ -1203,6 +1501,8 @@ Crawl-delay: 8
             cache_service: Some(mock_cache as Arc<dyn CacheService>),
             retry_policy: RetryPolicy::default(),
             cache_stats: Arc::new(CacheStats::default()),
+            ttl: DEFAULT_ROBOTS_TTL,
+            error_ttl: DEFAULT_ROBOTS_ERROR_TTL,
         };
 
         // Pre-populate memory cache with an EXPIRED entry.
@@ -1213,6 +1513,8 @@ Crawl-delay: 8
                 CachedRobots {
                     content: "old-expired-content".to_string(),
                     expires_at: Instant::now() - Duration::from_secs(1),
+                    status: RobotsFetchStatus::Fetched,
+                    cached_at: Utc::now(),
                 },
             );
         }
@@ -1377,6 +1679,8 @@ Crawl-delay: 8
                 ..Default::default()
             },
             cache_stats: Arc::new(CacheStats::default()),
+            ttl: DEFAULT_ROBOTS_TTL,
+            error_ttl: DEFAULT_ROBOTS_ERROR_TTL,
         };
 
         checker
@@ -1451,4 +1755,60 @@ Crawl-delay: 8
             "no HTTP fetch should occur when SSRF blocks the URL"
         );
     }
+
+    // ========== Cache observability & refresh tests ==========
+
+    #[tokio::test]
+    async fn test_fetch_error_is_counted_and_cached_briefly() {
+        let router = Arc::new(MockEngineRouter::with_error("connection refused"));
+        let checker = make_checker_with_mock_router(router)
+            .with_ttl(DEFAULT_ROBOTS_TTL, Duration::from_secs(30));
+
+        checker
+            .is_allowed("http://8.8.8.8/anything", "MyBot")
+            .await
+            .expect("should succeed with empty content after request errors");
+
+        assert_eq!(
+            checker.cache_counters(),
+            RobotsCacheCounters {
+                hits: 0,
+                misses: 1,
+                fetch_errors: 1,
+            }
+        );
+        let entries = checker.cached_entries("8.8.8.8").await;
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].status, RobotsFetchStatus::FetchFailed);
+        assert!(entries[0].expires_in <= Duration::from_secs(30));
+    }
+
+    #[tokio::test]
+    async fn test_cached_entries_and_refresh_by_host() {
+        let router = Arc::new(MockEngineRouter::with_response(
+            200,
+            "User-agent: *\nDisallow: /private\n",
+        ));
+        let router_ref = router.clone();
+        let checker = make_checker_with_mock_router(router);
+
+        assert!(checker.cached_entries("8.8.8.8").await.is_empty());
+        checker
+            .is_allowed("http://8.8.8.8/page", "MyBot")
+            .await
+            .expect("should succeed via HTTP fetch");
+
+        let entries = checker.cached_entries("8.8.8.8").await;
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].robots_url, "http://8.8.8.8:80/robots.txt");
+        assert_eq!(entries[0].status, RobotsFetchStatus::Fetched);
+        assert_eq!(entries[0].content_length, 33);
+        assert!(checker.cached_entries("example.com").await.is_empty());
+
+        // 刷新丢弃缓存并重新抓取同一 scheme / 端口
+        let refreshed = checker.refresh("8.8.8.8").await.expect("refresh");
+        assert_eq!(router_ref.call_count(), 2);
+        assert_eq!(refreshed.len(), 1);
+        assert_eq!(refreshed[0].robots_url, "http://8.8.8.8:80/robots.txt");
+    }
 }
@@ -0,0 +1,212 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! 全文检索的文本提取与摘要
+//!
+//! 保存结果时提取用于建立 tsvector 索引的纯文本；检索命中后，从结果正文中截取
+//! 包含最多查询词的片段作为摘要。正文可能以 zstd 压缩保存，数据库无法直接读取，
+//! 因此摘要在读取并解压结果后生成，而不使用 `ts_headline`。
+//!
+//! 分词规则与 Postgres `simple` 配置一致：连续的字母数字为一个词，忽略大小写，
+//! 不做词干化。
+
+use crate::utils::text_diff::html_to_text;
+
+/// 建立索引的正文最大字节数，tsvector 的上限为 1MB
+pub const MAX_INDEXED_TEXT_BYTES: usize = 512 * 1024;
+
+/// 摘要包含的最大词数
+pub const SNIPPET_WORDS: usize = 30;
+
+/// 摘要中命中词之前保留的上下文词数
+const SNIPPET_LEADING_WORDS: usize = 5;
+
+/// 提取结果正文中用于检索的纯文本
+///
+/// HTML 正文移除标签与脚本，其余文本类型原样使用，二进制内容不建立索引。
+/// 文本按 UTF-8 字符边界截断到 `MAX_INDEXED_TEXT_BYTES`。
+pub fn searchable_text(content: &str, content_type: &str) -> String {
+    let content_type = content_type.to_ascii_lowercase();
+    let text = if content_type.contains("html") {
+        html_to_text(content)
+    } else if content_type.is_empty()
+        || content_type.starts_with("text/")
+        || content_type.contains("json")
+        || content_type.contains("xml")
+        || content_type.contains("markdown")
+    {
+        content.to_string()
+    } else {
+        return String::new();
+    };
+    truncate_to_char_boundary(text, MAX_INDEXED_TEXT_BYTES)
+}
+
+/// 从查询中取出参与匹配的词
+///
+/// 支持 `websearch_to_tsquery` 的语法：引号短语拆为单词，忽略 `or` 与以 `-` 排除的词。
+pub fn query_terms(query: &str) -> Vec<String> {
+    let mut terms = Vec::new();
+    for token in query.split_whitespace() {
+        if token.starts_with('-') || token.eq_ignore_ascii_case("or") {
+            continue;
+        }
+        for (start, end) in word_ranges(token) {
+            let term = token[start..end].to_lowercase();
+            if !terms.contains(&term) {
+                terms.push(term);
+            }
+        }
+    }
+    terms
+}
+
+/// 生成命中摘要
+///
+/// 选取包含最多命中词的 `SNIPPET_WORDS` 个词，命中词以 `<b>` 标记，其余文本做 HTML
+/// 转义，前后被截断时加省略号。没有命中时返回正文开头。
+pub fn build_snippet(text: &str, terms: &[String]) -> String {
+    let words = word_ranges(text);
+    if words.is_empty() {
+        return String::new();
+    }
+    let is_match = |&(start, end): &(usize, usize)| {
+        let word = text[start..end].to_lowercase();
+        terms.contains(&word)
+    };
+    let matches: Vec<usize> = (0..words.len()).filter(|&i| is_match(&words[i])).collect();
+
+    // 以每个命中词为起点，统计窗口内的命中数，取最多的一个
+    let anchor = matches
+        .iter()
+        .map(|&i| {
+            let hits = matches
+                .iter()
+                .filter(|&&j| j >= i && j < i + SNIPPET_WORDS)
+                .count();
+            (hits, i)
+        })
+        .max_by(|a, b| a.0.cmp(&b.0).then(b.1.cmp(&a.1)))
+        .map(|(_, i)| i.saturating_sub(SNIPPET_LEADING_WORDS))
+        .unwrap_or(0);
+    let first = anchor.min(words.len().saturating_sub(SNIPPET_WORDS));
+    let last = (first + SNIPPET_WORDS).min(words.len());
+
+    let mut snippet = String::new();
+    if first > 0 {
+        snippet.push_str("… ");
+    }
+    let mut cursor = words[first].0;
+    for word in &words[first..last] {
+        snippet.push_str(&escape_html(&text[cursor..word.0]));
+        let escaped = escape_html(&text[word.0..word.1]);
+        if is_match(word) {
+            snippet.push_str("<b>");
+            snippet.push_str(&escaped);
+            snippet.push_str("</b>");
+        } else {
+            snippet.push_str(&escaped);
+        }
+        cursor = word.1;
+    }
+    if last < words.len() {
+        snippet.push_str(" …");
+    }
+    snippet
+}
+
+/// 文本中每个词（连续的字母数字）的字节范围
+fn word_ranges(text: &str) -> Vec<(usize, usize)> {
+    let mut ranges = Vec::new();
+    let mut start = None;
+    for (i, c) in text.char_indices() {
+        match (c.is_alphanumeric(), start) {
+            (true, None) => start = Some(i),
+            (false, Some(s)) => {
+                ranges.push((s, i));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    if let Some(s) = start {
+        ranges.push((s, text.len()));
+    }
+    ranges
+}
+
+fn truncate_to_char_boundary(mut text: String, max_bytes: usize) -> String {
+    if text.len() > max_bytes {
+        let mut end = max_bytes;
+        while !text.is_char_boundary(end) {
+            end -= 1;
+        }
+        text.truncate(end);
+    }
+    text
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('\n', " ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_searchable_text_by_content_type() {
+        let html = "<html><script>var x;</script><h1>Pricing</h1><p>Basic plan</p></html>";
+        assert_eq!(
+            searchable_text(html, "text/html; charset=utf-8"),
+            "Pricing\nBasic plan"
+        );
+        assert_eq!(
+            searchable_text("{\"a\":1}", "application/json"),
+            "{\"a\":1}"
+        );
+        assert_eq!(searchable_text("%PDF-1.7", "application/pdf"), "");
+
+        let long = "é".repeat(MAX_INDEXED_TEXT_BYTES);
+        let text = searchable_text(&long, "text/plain");
+        assert!(text.len() <= MAX_INDEXED_TEXT_BYTES);
+        assert!(text.chars().all(|c| c == 'é'));
+    }
+
+    #[test]
+    fn test_query_terms_follow_websearch_syntax() {
+        assert_eq!(
+            query_terms("\"Basic Plan\" or pricing -enterprise Pricing"),
+            vec!["basic", "plan", "pricing"]
+        );
+        assert!(query_terms("  ").is_empty());
+    }
+
+    #[test]
+    fn test_build_snippet_highlights_densest_window() {
+        let filler = "lorem ".repeat(50);
+        let text = format!(
+            "pricing intro {} the basic plan has flexible pricing & <support> {}",
+            filler, filler
+        );
+        let terms = query_terms("basic pricing");
+        let snippet = build_snippet(&text, &terms);
+
+        assert!(snippet.starts_with("… "));
+        assert!(snippet.ends_with(" …"));
+        assert!(snippet.contains("the <b>basic</b> plan has flexible <b>pricing</b>"));
+        assert!(snippet.contains("&amp; &lt;support&gt;"));
+    }
+
+    #[test]
+    fn test_build_snippet_without_match_uses_start() {
+        let snippet = build_snippet("Hello world", &["missing".to_string()]);
+        assert_eq!(snippet, "Hello world");
+        assert_eq!(build_snippet("", &[]), "");
+    }
+}
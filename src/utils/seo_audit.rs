@@ -0,0 +1,487 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! SEO 审计
+//!
+//! 对单个页面计算 SEO 指标（标题与描述长度、H1、canonical、hreflang、图片 alt 覆盖率、
+//! 字数、站内外链接数）并标记问题（`formats: ["seo"]`），
+//! 再将一次爬取中各页面的审计汇总为爬取级报告。
+
+use crate::utils::text_diff::html_to_text;
+use crate::utils::url::registrable_domain;
+use scraper::{Html, Selector};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use url::Url;
+
+/// SEO 审计的格式名
+pub const SEO_FORMAT: &str = "seo";
+
+/// 建议的标题长度范围（字符）
+pub const TITLE_LENGTH_RANGE: (usize, usize) = (30, 60);
+
+/// 建议的描述长度范围（字符）
+pub const DESCRIPTION_LENGTH_RANGE: (usize, usize) = (70, 160);
+
+/// 低于该字数的页面视为内容过少
+pub const THIN_CONTENT_WORDS: usize = 300;
+
+/// 报告中列出的问题页面上限
+pub const MAX_REPORTED_PAGES: usize = 1000;
+
+/// `formats` 是否请求 SEO 审计
+pub fn requests_seo(formats: Option<&[String]>) -> bool {
+    formats.is_some_and(|formats| {
+        formats
+            .iter()
+            .any(|format| format.trim().eq_ignore_ascii_case(SEO_FORMAT))
+    })
+}
+
+/// SEO 问题
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SeoIssue {
+    /// 缺少 `<title>`
+    MissingTitle,
+    /// 标题过短
+    TitleTooShort,
+    /// 标题过长
+    TitleTooLong,
+    /// 缺少 meta description
+    MissingDescription,
+    /// 描述过短
+    DescriptionTooShort,
+    /// 描述过长
+    DescriptionTooLong,
+    /// 没有 H1
+    MissingH1,
+    /// 多个 H1
+    MultipleH1,
+    /// 缺少 canonical 链接
+    MissingCanonical,
+    /// 存在缺少 alt 的图片
+    MissingImageAlt,
+    /// 内容过少
+    ThinContent,
+    /// meta robots 禁止索引
+    Noindex,
+}
+
+/// hreflang 备用语言链接
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Hreflang {
+    /// 语言代码，如 `en-us`、`x-default`
+    pub lang: String,
+    /// 备用页面地址
+    pub href: String,
+}
+
+/// 单个页面的 SEO 审计
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SeoAudit {
+    /// 页面标题
+    pub title: Option<String>,
+    /// 标题长度（字符）
+    pub title_length: usize,
+    /// meta description
+    pub description: Option<String>,
+    /// 描述长度（字符）
+    pub description_length: usize,
+    /// H1 数量
+    pub h1_count: usize,
+    /// 第一个 H1 的文本
+    pub h1: Option<String>,
+    /// canonical 地址，已解析为绝对地址
+    pub canonical: Option<String>,
+    /// hreflang 备用语言链接
+    pub hreflang: Vec<Hreflang>,
+    /// 图片数
+    pub images: usize,
+    /// 缺少 alt（或 alt 为空）的图片数
+    pub images_missing_alt: usize,
+    /// 带 alt 的图片占比（0.0-1.0），没有图片时为 1.0
+    pub image_alt_coverage: f64,
+    /// 正文字数
+    pub word_count: usize,
+    /// 指向同一可注册域名的链接数
+    pub internal_links: usize,
+    /// 指向其他域名的链接数
+    pub external_links: usize,
+    /// meta robots 是否包含 noindex
+    pub noindex: bool,
+    /// 发现的问题
+    pub issues: Vec<SeoIssue>,
+}
+
+fn selector(css: &str) -> Selector {
+    Selector::parse(css).expect("valid seo selector")
+}
+
+fn collapse_whitespace(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// 审计页面 HTML，`page_url` 用于解析相对链接并区分站内外链接
+pub fn audit_page(html: &str, page_url: &str) -> SeoAudit {
+    let document = Html::parse_document(html);
+    let base = Url::parse(page_url).ok();
+    let page_domain = base
+        .as_ref()
+        .and_then(|url| url.host_str())
+        .map(registrable_domain);
+    let resolve = |href: &str| match &base {
+        Some(base) => base.join(href.trim()).ok().map(|url| url.to_string()),
+        None => Url::parse(href.trim()).ok().map(|url| url.to_string()),
+    };
+
+    let title = document
+        .select(&selector("title"))
+        .next()
+        .map(|title| collapse_whitespace(&title.text().collect::<String>()))
+        .filter(|title| !title.is_empty());
+    let meta_content = |name: &str| {
+        document
+            .select(&selector("meta[name][content]"))
+            .find(|meta| {
+                meta.value()
+                    .attr("name")
+                    .is_some_and(|n| n.trim().eq_ignore_ascii_case(name))
+            })
+            .and_then(|meta| meta.value().attr("content"))
+            .map(collapse_whitespace)
+    };
+    let description = meta_content("description").filter(|d| !d.is_empty());
+    let noindex = meta_content("robots")
+        .is_some_and(|robots| robots.to_ascii_lowercase().contains("noindex"));
+
+    let h1s: Vec<String> = document
+        .select(&selector("h1"))
+        .map(|h1| collapse_whitespace(&h1.text().collect::<String>()))
+        .collect();
+
+    let mut canonical = None;
+    let mut hreflang = Vec::new();
+    for link in document.select(&selector("link[rel][href]")) {
+        let rel = link.value().attr("rel").unwrap_or_default().to_lowercase();
+        let Some(href) = link.value().attr("href").and_then(resolve) else {
+            continue;
+        };
+        let rels: Vec<&str> = rel.split_whitespace().collect();
+        if rels.contains(&"canonical") && canonical.is_none() {
+            canonical = Some(href);
+        } else if rels.contains(&"alternate") {
+            if let Some(lang) = link.value().attr("hreflang") {
+                hreflang.push(Hreflang {
+                    lang: lang.trim().to_lowercase(),
+                    href,
+                });
+            }
+        }
+    }
+
+    let images: Vec<bool> = document
+        .select(&selector("img"))
+        .map(|img| {
+            img.value()
+                .attr("alt")
+                .is_some_and(|alt| !alt.trim().is_empty())
+        })
+        .collect();
+    let images_missing_alt = images.iter().filter(|has_alt| !**has_alt).count();
+    let image_alt_coverage = if images.is_empty() {
+        1.0
+    } else {
+        (images.len() - images_missing_alt) as f64 / images.len() as f64
+    };
+
+    let body_html = document
+        .select(&selector("body"))
+        .next()
+        .map(|body| body.html())
+        .unwrap_or_else(|| html.to_string());
+    let word_count = html_to_text(&body_html).split_whitespace().count();
+
+    let mut internal_links = 0;
+    let mut external_links = 0;
+    for anchor in document.select(&selector("a[href]")) {
+        let href = anchor.value().attr("href").unwrap_or_default().trim();
+        if href.is_empty() || href.starts_with('#') {
+            continue;
+        }
+        let Some(url) = resolve(href).and_then(|url| Url::parse(&url).ok()) else {
+            continue;
+        };
+        if !matches!(url.scheme(), "http" | "https") {
+            continue;
+        }
+        let same_site = url
+            .host_str()
+            .map(registrable_domain)
+            .is_some_and(|domain| page_domain.as_deref() == Some(domain.as_str()));
+        if same_site {
+            internal_links += 1;
+        } else {
+            external_links += 1;
+        }
+    }
+
+    let mut audit = SeoAudit {
+        title_length: title.as_deref().map_or(0, |t| t.chars().count()),
+        title,
+        description_length: description.as_deref().map_or(0, |d| d.chars().count()),
+        description,
+        h1_count: h1s.len(),
+        h1: h1s.into_iter().find(|h1| !h1.is_empty()),
+        canonical,
+        hreflang,
+        images: images.len(),
+        images_missing_alt,
+        image_alt_coverage,
+        word_count,
+        internal_links,
+        external_links,
+        noindex,
+        issues: Vec::new(),
+    };
+    audit.issues = audit_issues(&audit);
+    audit
+}
+
+fn audit_issues(audit: &SeoAudit) -> Vec<SeoIssue> {
+    let mut issues = Vec::new();
+    let (title_min, title_max) = TITLE_LENGTH_RANGE;
+    match audit.title_length {
+        0 => issues.push(SeoIssue::MissingTitle),
+        n if n < title_min => issues.push(SeoIssue::TitleTooShort),
+        n if n > title_max => issues.push(SeoIssue::TitleTooLong),
+        _ => {}
+    }
+    let (description_min, description_max) = DESCRIPTION_LENGTH_RANGE;
+    match audit.description_length {
+        0 => issues.push(SeoIssue::MissingDescription),
+        n if n < description_min => issues.push(SeoIssue::DescriptionTooShort),
+        n if n > description_max => issues.push(SeoIssue::DescriptionTooLong),
+        _ => {}
+    }
+    match audit.h1_count {
+        0 => issues.push(SeoIssue::MissingH1),
+        1 => {}
+        _ => issues.push(SeoIssue::MultipleH1),
+    }
+    if audit.canonical.is_none() {
+        issues.push(SeoIssue::MissingCanonical);
+    }
+    if audit.images_missing_alt > 0 {
+        issues.push(SeoIssue::MissingImageAlt);
+    }
+    if audit.word_count < THIN_CONTENT_WORDS {
+        issues.push(SeoIssue::ThinContent);
+    }
+    if audit.noindex {
+        issues.push(SeoIssue::Noindex);
+    }
+    issues
+}
+
+/// 报告中的问题页面
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SeoPageIssues {
+    /// 页面地址
+    pub url: String,
+    /// 页面的问题
+    pub issues: Vec<SeoIssue>,
+}
+
+/// 多个页面共用的标题或描述
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DuplicateValue {
+    /// 重复的文本
+    pub value: String,
+    /// 使用该文本的页面
+    pub urls: Vec<String>,
+}
+
+/// 爬取级 SEO 报告
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct SeoReport {
+    /// 审计的页面数
+    pub pages: usize,
+    /// 各问题出现的页面数
+    pub issue_counts: BTreeMap<SeoIssue, usize>,
+    /// 平均字数
+    pub average_word_count: f64,
+    /// 全部图片中带 alt 的占比，没有图片时为 1.0
+    pub image_alt_coverage: f64,
+    /// 站内链接总数
+    pub internal_links: usize,
+    /// 站外链接总数
+    pub external_links: usize,
+    /// 重复的标题
+    pub duplicate_titles: Vec<DuplicateValue>,
+    /// 重复的描述
+    pub duplicate_descriptions: Vec<DuplicateValue>,
+    /// 存在问题的页面，最多 `MAX_REPORTED_PAGES` 个
+    pub pages_with_issues: Vec<SeoPageIssues>,
+}
+
+impl SeoReport {
+    /// 汇总各页面的审计，`pages` 为页面地址与审计结果
+    pub fn from_audits(pages: impl IntoIterator<Item = (String, SeoAudit)>) -> Self {
+        let mut report = SeoReport::default();
+        let mut words = 0usize;
+        let mut images = 0usize;
+        let mut images_missing_alt = 0usize;
+        let mut titles: HashMap<String, Vec<String>> = HashMap::new();
+        let mut descriptions: HashMap<String, Vec<String>> = HashMap::new();
+
+        for (url, audit) in pages {
+            report.pages += 1;
+            words += audit.word_count;
+            images += audit.images;
+            images_missing_alt += audit.images_missing_alt;
+            report.internal_links += audit.internal_links;
+            report.external_links += audit.external_links;
+            for issue in &audit.issues {
+                *report.issue_counts.entry(*issue).or_default() += 1;
+            }
+            if let Some(title) = audit.title {
+                titles.entry(title).or_default().push(url.clone());
+            }
+            if let Some(description) = audit.description {
+                descriptions
+                    .entry(description)
+                    .or_default()
+                    .push(url.clone());
+            }
+            if !audit.issues.is_empty() && report.pages_with_issues.len() < MAX_REPORTED_PAGES {
+                report.pages_with_issues.push(SeoPageIssues {
+                    url,
+                    issues: audit.issues,
+                });
+            }
+        }
+
+        if report.pages > 0 {
+            report.average_word_count = words as f64 / report.pages as f64;
+        }
+        report.image_alt_coverage = if images == 0 {
+            1.0
+        } else {
+            (images - images_missing_alt) as f64 / images as f64
+        };
+        report.duplicate_titles = duplicates(titles);
+        report.duplicate_descriptions = duplicates(descriptions);
+        report
+    }
+}
+
+fn duplicates(values: HashMap<String, Vec<String>>) -> Vec<DuplicateValue> {
+    let mut duplicates: Vec<DuplicateValue> = values
+        .into_iter()
+        .filter(|(_, urls)| urls.len() > 1)
+        .map(|(value, urls)| DuplicateValue { value, urls })
+        .collect();
+    duplicates.sort_by(|a, b| b.urls.len().cmp(&a.urls.len()).then(a.value.cmp(&b.value)));
+    duplicates
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn page(words: usize) -> String {
+        format!(
+            r##"<html lang="en"><head>
+<title>Widgets for every workshop and garage</title>
+<meta name="description" content="Browse our range of durable widgets, compare prices and find the right widget for your next project.">
+<link rel="canonical" href="/widgets">
+<link rel="alternate" hreflang="de-DE" href="https://example.de/widgets">
+<link rel="alternate" hreflang="x-default" href="https://example.com/widgets">
+</head><body>
+<h1>Widgets</h1>
+<img src="a.png" alt="Blue widget"><img src="b.png" alt=" "><img src="c.png">
+<p>{}</p>
+<a href="/about">About</a><a href="https://blog.example.com/post">Blog</a>
+<a href="https://other.org/">Other</a><a href="#top">Top</a><a href="mailto:a@example.com">Mail</a>
+</body></html>"##,
+            "word ".repeat(words)
+        )
+    }
+
+    #[test]
+    fn test_requests_seo() {
+        assert!(requests_seo(Some(&[" SEO ".to_string()])));
+        assert!(!requests_seo(Some(&["markdown".to_string()])));
+        assert!(!requests_seo(None));
+    }
+
+    #[test]
+    fn test_audit_page_metrics() {
+        let audit = audit_page(&page(400), "https://www.example.com/widgets?page=1");
+        assert_eq!(
+            audit.title.as_deref(),
+            Some("Widgets for every workshop and garage")
+        );
+        assert_eq!(audit.title_length, 37);
+        assert_eq!(audit.h1_count, 1);
+        assert_eq!(audit.h1.as_deref(), Some("Widgets"));
+        assert_eq!(
+            audit.canonical.as_deref(),
+            Some("https://www.example.com/widgets")
+        );
+        assert_eq!(audit.hreflang.len(), 2);
+        assert_eq!(audit.hreflang[0].lang, "de-de");
+        assert_eq!(audit.images, 3);
+        assert_eq!(audit.images_missing_alt, 2);
+        assert!((audit.image_alt_coverage - 1.0 / 3.0).abs() < 1e-9);
+        assert!(audit.word_count >= 400);
+        assert_eq!(audit.internal_links, 2);
+        assert_eq!(audit.external_links, 1);
+        assert_eq!(audit.issues, vec![SeoIssue::MissingImageAlt]);
+    }
+
+    #[test]
+    fn test_audit_page_issues() {
+        let html = r#"<html><head><meta name="robots" content="NOINDEX, follow"></head>
+            <body><h1>One</h1><h1>Two</h1><p>Too short</p></body></html>"#;
+        let audit = audit_page(html, "https://example.com/");
+        assert_eq!(
+            audit.issues,
+            vec![
+                SeoIssue::MissingTitle,
+                SeoIssue::MissingDescription,
+                SeoIssue::MultipleH1,
+                SeoIssue::MissingCanonical,
+                SeoIssue::ThinContent,
+                SeoIssue::Noindex,
+            ]
+        );
+        assert_eq!(audit.image_alt_coverage, 1.0);
+    }
+
+    #[test]
+    fn test_report_aggregates_audits() {
+        let good = audit_page(&page(400), "https://example.com/a");
+        let thin = audit_page(&page(10), "https://example.com/b");
+        let report = SeoReport::from_audits(vec![
+            ("https://example.com/a".to_string(), good),
+            ("https://example.com/b".to_string(), thin),
+        ]);
+
+        assert_eq!(report.pages, 2);
+        assert_eq!(report.issue_counts[&SeoIssue::MissingImageAlt], 2);
+        assert_eq!(report.issue_counts[&SeoIssue::ThinContent], 1);
+        assert_eq!(report.duplicate_titles.len(), 1);
+        assert_eq!(report.duplicate_titles[0].urls.len(), 2);
+        assert_eq!(report.duplicate_descriptions.len(), 1);
+        assert_eq!(report.internal_links, 4);
+        assert_eq!(report.pages_with_issues.len(), 2);
+        assert!((report.image_alt_coverage - 1.0 / 3.0).abs() < 1e-9);
+
+        let json = serde_json::to_value(&report).unwrap();
+        assert_eq!(json["issue_counts"]["thin_content"], 1);
+    }
+}
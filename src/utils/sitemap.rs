@@ -0,0 +1,339 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! 站点地图解析工具
+//!
+//! 解析 sitemaps.org 协议的 `<urlset>` 与 `<sitemapindex>` 文档，
+//! 供爬取任务从 robots.txt 声明的站点地图中发现种子 URL，
+//! 也供 `POST /v1/sitemap` 递归展开站点地图索引并返回带元数据的 URL 列表。
+
+use crate::engines::engine_client::{EngineClient, HttpMethod, ScrapeOptions, ScrapeRequest};
+use log::warn;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::Serialize;
+use std::collections::{HashSet, VecDeque};
+use std::time::Duration;
+
+/// 单个站点地图文件的最大字节数（sitemaps.org 协议上限 50 MiB）
+pub const MAX_SITEMAP_BYTES: usize = 50 * 1024 * 1024;
+
+/// 站点地图索引的最大嵌套深度
+pub const MAX_SITEMAP_DEPTH: usize = 3;
+
+/// `<url>` 条目
+static URL_ENTRY_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?is)<url>(.*?)</url>").expect("valid sitemap url regex"));
+
+/// 条目中的 `<loc>`
+static LOC_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?is)<loc>\s*(.*?)\s*</loc>").expect("valid sitemap loc regex"));
+
+/// 条目中的 `<lastmod>`
+static LASTMOD_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?is)<lastmod>\s*(.*?)\s*</lastmod>").expect("valid sitemap lastmod regex")
+});
+
+/// 条目中的 `<changefreq>`
+static CHANGEFREQ_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?is)<changefreq>\s*(.*?)\s*</changefreq>")
+        .expect("valid sitemap changefreq regex")
+});
+
+/// 条目中的 `<priority>`
+static PRIORITY_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?is)<priority>\s*(.*?)\s*</priority>").expect("valid sitemap priority regex")
+});
+
+/// `<sitemap>` 条目中的 `<loc>`
+static SITEMAP_LOC_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?is)<sitemap>.*?<loc>\s*(.*?)\s*</loc>").expect("valid sitemap index regex")
+});
+
+/// 站点地图中的页面条目
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct SitemapUrl {
+    /// 页面 URL
+    pub loc: String,
+    /// 最后修改时间，保留站点地图中的原文
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lastmod: Option<String>,
+    /// 更新频率（always / hourly / daily / weekly / monthly / yearly / never）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub changefreq: Option<String>,
+    /// 优先级（0.0-1.0），超出范围或无法解析时忽略
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<f32>,
+}
+
+/// 解析后的站点地图
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SitemapDocument {
+    /// 页面条目（来自 `<urlset>`）
+    pub urls: Vec<SitemapUrl>,
+    /// 子站点地图 URL（来自 `<sitemapindex>`）
+    pub sitemaps: Vec<String>,
+}
+
+/// 解析站点地图 XML
+///
+/// 只提取 `<loc>` 并还原 XML 实体，非 http/https 的条目被忽略。
+pub fn parse_sitemap(content: &str) -> SitemapDocument {
+    SitemapDocument {
+        urls: extract_urls(content),
+        sitemaps: extract_locs(&SITEMAP_LOC_RE, content),
+    }
+}
+
+fn extract_urls(content: &str) -> Vec<SitemapUrl> {
+    URL_ENTRY_RE
+        .captures_iter(content)
+        .filter_map(|caps| caps.get(1))
+        .filter_map(|entry| {
+            let entry = entry.as_str();
+            let loc = unescape_xml(strip_cdata(&capture(&LOC_RE, entry)?));
+            if !(loc.starts_with("http://") || loc.starts_with("https://")) {
+                return None;
+            }
+            Some(SitemapUrl {
+                loc,
+                lastmod: capture(&LASTMOD_RE, entry),
+                changefreq: capture(&CHANGEFREQ_RE, entry).map(|freq| freq.to_lowercase()),
+                priority: capture(&PRIORITY_RE, entry)
+                    .and_then(|priority| priority.parse::<f32>().ok())
+                    .filter(|priority| (0.0..=1.0).contains(priority)),
+            })
+        })
+        .collect()
+}
+
+fn capture(re: &Regex, entry: &str) -> Option<String> {
+    re.captures(entry)
+        .and_then(|caps| caps.get(1))
+        .map(|value| value.as_str().to_string())
+        .filter(|value| !value.is_empty())
+}
+
+fn extract_locs(re: &Regex, content: &str) -> Vec<String> {
+    re.captures_iter(content)
+        .filter_map(|caps| caps.get(1))
+        .map(|loc| unescape_xml(strip_cdata(loc.as_str())))
+        .filter(|loc| loc.starts_with("http://") || loc.starts_with("https://"))
+        .collect()
+}
+
+fn strip_cdata(value: &str) -> &str {
+    value
+        .strip_prefix("<![CDATA[")
+        .and_then(|v| v.strip_suffix("]]>"))
+        .map(str::trim)
+        .unwrap_or(value)
+}
+
+fn unescape_xml(value: &str) -> String {
+    value
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+/// 递归抓取站点地图的上限
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SitemapLimits {
+    /// 最多抓取的站点地图文件数（含索引展开的子地图）
+    pub max_sitemaps: usize,
+    /// 最多返回的页面 URL 数
+    pub max_urls: usize,
+}
+
+/// 一个站点地图文件的抓取结果
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SitemapFetch {
+    /// 站点地图地址
+    pub url: String,
+    /// 在索引中的嵌套深度，请求的站点地图为 0
+    pub depth: usize,
+    /// 文件中的页面条目数
+    pub url_count: usize,
+    /// 文件中的子站点地图数
+    pub sitemap_count: usize,
+    /// 抓取失败或被跳过的原因
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// 递归抓取的站点地图集合
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct SitemapCollection {
+    /// 去重后的页面条目，按发现顺序
+    pub urls: Vec<SitemapUrl>,
+    /// 抓取过的站点地图文件
+    pub sitemaps: Vec<SitemapFetch>,
+    /// 是否因达到上限而未读完全部站点地图或条目
+    pub truncated: bool,
+}
+
+/// 从 `root` 开始按广度优先递归抓取站点地图与站点地图索引
+///
+/// 超过 `MAX_SITEMAP_DEPTH` 层的索引、超过 `MAX_SITEMAP_BYTES` 或被引擎截断的文件不解析，
+/// 达到 `limits` 时停止并标记 `truncated`。单个站点地图获取失败只记录在对应的
+/// `SitemapFetch` 中。
+pub async fn collect_sitemap(
+    engine_client: &EngineClient,
+    root: &str,
+    limits: SitemapLimits,
+) -> SitemapCollection {
+    let mut collection = SitemapCollection::default();
+    let mut seen_sitemaps: HashSet<String> = HashSet::new();
+    let mut seen_urls: HashSet<String> = HashSet::new();
+    let mut pending: VecDeque<(String, usize)> = VecDeque::from([(root.to_string(), 0)]);
+
+    while let Some((sitemap_url, depth)) = pending.pop_front() {
+        if !seen_sitemaps.insert(sitemap_url.clone()) {
+            continue;
+        }
+        if collection.sitemaps.len() >= limits.max_sitemaps
+            || collection.urls.len() >= limits.max_urls
+        {
+            collection.truncated = true;
+            break;
+        }
+
+        let mut fetch = SitemapFetch {
+            url: sitemap_url.clone(),
+            depth,
+            url_count: 0,
+            sitemap_count: 0,
+            error: None,
+        };
+        let request = ScrapeRequest::new(&sitemap_url).with_options(
+            ScrapeOptions::builder()
+                .method(HttpMethod::Get)
+                .timeout(Duration::from_secs(10))
+                .build(),
+        );
+        match engine_client.scrape(&request).await {
+            Ok(response) if !response.is_success() => {
+                fetch.error = Some(format!("HTTP {}", response.status_code));
+            }
+            Ok(response) if response.truncated || response.content.len() > MAX_SITEMAP_BYTES => {
+                fetch.error = Some(format!(
+                    "Sitemap is larger than {} bytes",
+                    MAX_SITEMAP_BYTES
+                ));
+            }
+            Ok(response) => {
+                let document = parse_sitemap(&response.content);
+                fetch.url_count = document.urls.len();
+                fetch.sitemap_count = document.sitemaps.len();
+                for url in document.urls {
+                    if collection.urls.len() >= limits.max_urls {
+                        collection.truncated = true;
+                        break;
+                    }
+                    if seen_urls.insert(url.loc.clone()) {
+                        collection.urls.push(url);
+                    }
+                }
+                if depth < MAX_SITEMAP_DEPTH {
+                    pending.extend(document.sitemaps.into_iter().map(|url| (url, depth + 1)));
+                } else if !document.sitemaps.is_empty() {
+                    collection.truncated = true;
+                }
+            }
+            Err(e) => {
+                warn!("Failed to fetch sitemap {}: {}", sitemap_url, e);
+                fetch.error = Some(e.to_string());
+            }
+        }
+        collection.sitemaps.push(fetch);
+    }
+
+    collection
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_urlset() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">
+  <url><loc>https://example.com/</loc><lastmod>2025-01-01</lastmod></url>
+  <url>
+    <loc>
+      https://example.com/search?q=a&amp;page=2
+    </loc>
+  </url>
+  <url><loc><![CDATA[https://example.com/cdata]]></loc></url>
+  <url><loc>mailto:someone@example.com</loc></url>
+</urlset>"#;
+        let doc = parse_sitemap(xml);
+        let locs: Vec<&str> = doc.urls.iter().map(|url| url.loc.as_str()).collect();
+        assert_eq!(
+            locs,
+            vec![
+                "https://example.com/",
+                "https://example.com/search?q=a&page=2",
+                "https://example.com/cdata",
+            ]
+        );
+        assert_eq!(doc.urls[0].lastmod.as_deref(), Some("2025-01-01"));
+        assert!(doc.sitemaps.is_empty());
+    }
+
+    #[test]
+    fn test_parse_url_metadata() {
+        let xml = r#"<urlset>
+  <url>
+    <loc>https://example.com/a</loc>
+    <lastmod>2025-03-04T10:00:00+00:00</lastmod>
+    <changefreq>Weekly</changefreq>
+    <priority>0.8</priority>
+  </url>
+  <url><loc>https://example.com/b</loc><priority>7</priority></url>
+</urlset>"#;
+        let doc = parse_sitemap(xml);
+        assert_eq!(
+            doc.urls[0],
+            SitemapUrl {
+                loc: "https://example.com/a".to_string(),
+                lastmod: Some("2025-03-04T10:00:00+00:00".to_string()),
+                changefreq: Some("weekly".to_string()),
+                priority: Some(0.8),
+            }
+        );
+        assert_eq!(doc.urls[1].priority, None);
+        assert_eq!(doc.urls[1].lastmod, None);
+    }
+
+    #[test]
+    fn test_parse_sitemap_index() {
+        let xml = r#"<sitemapindex xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">
+  <sitemap><loc>https://example.com/sitemap-posts.xml</loc></sitemap>
+  <SITEMAP><LOC>https://example.com/sitemap-pages.xml</LOC></SITEMAP>
+</sitemapindex>"#;
+        let doc = parse_sitemap(xml);
+        assert!(doc.urls.is_empty());
+        assert_eq!(
+            doc.sitemaps,
+            vec![
+                "https://example.com/sitemap-posts.xml",
+                "https://example.com/sitemap-pages.xml",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_non_sitemap_content() {
+        assert_eq!(
+            parse_sitemap("<html><body>Not found</body></html>"),
+            SitemapDocument::default()
+        );
+    }
+}
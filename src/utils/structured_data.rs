@@ -0,0 +1,440 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! 结构化数据提取
+//!
+//! 从页面的 JSON-LD、Microdata 与 RDFa 标记中收集 schema.org 实体（Product、Article、
+//! Event、Recipe…），统一为相同的结构（`formats: ["structured_data"]`）：
+//!
+//! ```json
+//! {"type": "Product", "source": "json-ld", "id": "...", "properties": {"name": "..."}}
+//! ```
+//!
+//! 类型与属性名去掉 schema.org 前缀；嵌套实体以 `{type, properties}` 形式作为属性值，
+//! 同名属性出现多次时合并为数组，链接类属性按页面 URL 解析为绝对地址。
+
+use scraper::{ElementRef, Html, Selector};
+use serde_json::{json, Map, Value};
+use url::Url;
+
+/// 请求结构化数据提取的格式名
+pub const STRUCTURED_DATA_FORMAT: &str = "structured_data";
+
+/// 每个页面最多返回的实体数
+pub const MAX_ENTITIES: usize = 200;
+
+/// 嵌套实体的最大深度，更深的实体只保留文本
+const MAX_DEPTH: usize = 8;
+
+/// `formats` 是否请求结构化数据提取
+pub fn requests_structured_data(formats: Option<&[String]>) -> bool {
+    formats.is_some_and(|formats| {
+        formats
+            .iter()
+            .any(|format| format.trim().eq_ignore_ascii_case(STRUCTURED_DATA_FORMAT))
+    })
+}
+
+/// 提取页面中的结构化数据实体，依次为 JSON-LD、Microdata 与 RDFa
+///
+/// 无法解析的 JSON-LD 脚本被忽略。
+pub fn extract_structured_data(html: &str, base_url: Option<&str>) -> Vec<Value> {
+    let document = Html::parse_document(html);
+    let base = base_url.and_then(|u| Url::parse(u).ok());
+
+    let mut entities = json_ld_entities(&document);
+    for syntax in [&MICRODATA, &RDFA] {
+        entities.extend(markup_entities(&document, syntax, base.as_ref()));
+    }
+    entities.truncate(MAX_ENTITIES);
+    entities
+}
+
+/// 去掉 schema.org 命名空间前缀：`https://schema.org/Product`、`schema:Product` → `Product`
+pub fn normalize_term(term: &str) -> String {
+    let term = term.trim();
+    for prefix in [
+        "https://schema.org/",
+        "http://schema.org/",
+        "https://www.schema.org/",
+        "http://www.schema.org/",
+        "schema:",
+    ] {
+        if let Some(rest) = term.strip_prefix(prefix) {
+            return rest.to_string();
+        }
+    }
+    term.to_string()
+}
+
+/// 组装实体：第一个类型为 `type`，其余类型放在 `additional_types`
+fn entity(types: Vec<String>, id: Option<String>, properties: Map<String, Value>) -> Value {
+    let mut types = types.into_iter();
+    let mut value = json!({
+        "type": types.next(),
+        "properties": properties,
+    });
+    let additional: Vec<String> = types.collect();
+    if !additional.is_empty() {
+        value["additional_types"] = json!(additional);
+    }
+    if let Some(id) = id {
+        value["id"] = Value::String(id);
+    }
+    value
+}
+
+/// 同名属性再次出现时合并为数组
+fn push_property(properties: &mut Map<String, Value>, name: String, value: Value) {
+    match properties.get_mut(&name) {
+        Some(Value::Array(values)) => values.push(value),
+        Some(existing) => {
+            let first = existing.take();
+            *existing = Value::Array(vec![first, value]);
+        }
+        None => {
+            properties.insert(name, value);
+        }
+    }
+}
+
+// ========== JSON-LD ==========
+
+fn json_ld_entities(document: &Html) -> Vec<Value> {
+    let selector = Selector::parse("script[type]").expect("valid selector");
+    let mut entities = Vec::new();
+    for script in document.select(&selector).filter(|script| {
+        script
+            .value()
+            .attr("type")
+            .is_some_and(|t| t.trim().eq_ignore_ascii_case("application/ld+json"))
+    }) {
+        let text = script.text().collect::<String>();
+        let Ok(value) = serde_json::from_str::<Value>(text.trim()) else {
+            continue;
+        };
+        collect_json_ld(&value, &mut entities);
+    }
+    for entity in &mut entities {
+        entity["source"] = Value::String("json-ld".to_string());
+    }
+    entities
+}
+
+/// 展开顶层数组与 `@graph`，收集带 `@type` 的对象
+fn collect_json_ld(value: &Value, entities: &mut Vec<Value>) {
+    match value {
+        Value::Array(items) => items
+            .iter()
+            .for_each(|item| collect_json_ld(item, entities)),
+        Value::Object(object) => {
+            if let Some(graph) = object.get("@graph") {
+                collect_json_ld(graph, entities);
+            }
+            if object.contains_key("@type") {
+                entities.push(json_ld_entity(object, 0));
+            }
+        }
+        _ => {}
+    }
+}
+
+fn json_ld_entity(object: &Map<String, Value>, depth: usize) -> Value {
+    let types = match object.get("@type") {
+        Some(Value::String(t)) => vec![normalize_term(t)],
+        Some(Value::Array(ts)) => ts
+            .iter()
+            .filter_map(Value::as_str)
+            .map(normalize_term)
+            .collect(),
+        _ => Vec::new(),
+    };
+    let id = object
+        .get("@id")
+        .and_then(Value::as_str)
+        .map(str::to_string);
+    let properties = object
+        .iter()
+        .filter(|(key, _)| !key.starts_with('@'))
+        .map(|(key, value)| (normalize_term(key), json_ld_value(value, depth + 1)))
+        .collect();
+    entity(types, id, properties)
+}
+
+fn json_ld_value(value: &Value, depth: usize) -> Value {
+    match value {
+        Value::Array(items) => Value::Array(
+            items
+                .iter()
+                .map(|item| json_ld_value(item, depth))
+                .collect(),
+        ),
+        Value::Object(object) if depth <= MAX_DEPTH => {
+            if let Some(literal) = object.get("@value") {
+                literal.clone()
+            } else if object.contains_key("@type") {
+                json_ld_entity(object, depth)
+            } else {
+                Value::Object(
+                    object
+                        .iter()
+                        .filter(|(key, _)| *key == "@id" || !key.starts_with('@'))
+                        .map(|(key, value)| (normalize_term(key), json_ld_value(value, depth + 1)))
+                        .collect(),
+                )
+            }
+        }
+        Value::Object(_) => Value::Null,
+        other => other.clone(),
+    }
+}
+
+// ========== Microdata / RDFa ==========
+
+/// 标记语法使用的属性名
+struct MarkupSyntax {
+    /// 写入实体的 `source`
+    source: &'static str,
+    /// 开启实体的属性
+    scope: &'static str,
+    /// 属性名所在的属性
+    property: &'static str,
+    /// 类型所在的属性
+    types: &'static str,
+    /// 实体标识所在的属性
+    ids: &'static [&'static str],
+}
+
+const MICRODATA: MarkupSyntax = MarkupSyntax {
+    source: "microdata",
+    scope: "itemscope",
+    property: "itemprop",
+    types: "itemtype",
+    ids: &["itemid"],
+};
+
+const RDFA: MarkupSyntax = MarkupSyntax {
+    source: "rdfa",
+    scope: "typeof",
+    property: "property",
+    types: "typeof",
+    ids: &["resource", "about"],
+};
+
+/// 顶层实体：开启实体且自身不是其他实体属性的元素
+fn markup_entities(document: &Html, syntax: &MarkupSyntax, base: Option<&Url>) -> Vec<Value> {
+    let selector = Selector::parse(&format!("[{}]", syntax.scope)).expect("valid selector");
+    document
+        .select(&selector)
+        .filter(|element| element.value().attr(syntax.property).is_none())
+        .map(|element| {
+            let mut value = markup_item(element, syntax, base, 0);
+            value["source"] = Value::String(syntax.source.to_string());
+            value
+        })
+        .collect()
+}
+
+fn markup_item(
+    element: ElementRef,
+    syntax: &MarkupSyntax,
+    base: Option<&Url>,
+    depth: usize,
+) -> Value {
+    let types = element
+        .value()
+        .attr(syntax.types)
+        .unwrap_or_default()
+        .split_whitespace()
+        .map(normalize_term)
+        .collect();
+    let id = syntax
+        .ids
+        .iter()
+        .find_map(|attr| element.value().attr(attr))
+        .map(str::to_string);
+    let mut properties = Map::new();
+    collect_markup_properties(element, syntax, base, depth, &mut properties);
+    entity(types, id, properties)
+}
+
+/// 收集属于当前实体的属性，遇到嵌套实体时不再向下查找
+fn collect_markup_properties(
+    element: ElementRef,
+    syntax: &MarkupSyntax,
+    base: Option<&Url>,
+    depth: usize,
+    properties: &mut Map<String, Value>,
+) {
+    for child in element.children().filter_map(ElementRef::wrap) {
+        let scoped = child.value().attr(syntax.scope).is_some();
+        if let Some(names) = child.value().attr(syntax.property) {
+            let value = if scoped && depth < MAX_DEPTH {
+                markup_item(child, syntax, base, depth + 1)
+            } else {
+                Value::String(markup_value(child, base))
+            };
+            for name in names.split_whitespace() {
+                push_property(properties, normalize_term(name), value.clone());
+            }
+        }
+        if !scoped {
+            collect_markup_properties(child, syntax, base, depth, properties);
+        }
+    }
+}
+
+/// 属性值：`content` 属性、链接、时间与数值属性，否则为元素文本
+fn markup_value(element: ElementRef, base: Option<&Url>) -> String {
+    let value = element.value();
+    if let Some(content) = value.attr("content") {
+        return content.trim().to_string();
+    }
+    let url_attr = match value.name() {
+        "a" | "area" | "link" => Some("href"),
+        "img" | "audio" | "video" | "source" | "iframe" | "embed" | "track" => Some("src"),
+        "object" => Some("data"),
+        _ => None,
+    };
+    if let Some(url) = url_attr.and_then(|attr| value.attr(attr)) {
+        return base
+            .and_then(|base| base.join(url.trim()).ok())
+            .map(|url| url.to_string())
+            .unwrap_or_else(|| url.trim().to_string());
+    }
+    let literal_attr = match value.name() {
+        "time" => value.attr("datetime"),
+        "data" | "meter" => value.attr("value"),
+        _ => None,
+    };
+    if let Some(literal) = literal_attr {
+        return literal.trim().to_string();
+    }
+    element
+        .text()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_requests_structured_data() {
+        assert!(requests_structured_data(Some(&[
+            "Structured_Data".to_string()
+        ])));
+        assert!(!requests_structured_data(Some(&["tables".to_string()])));
+        assert!(!requests_structured_data(None));
+    }
+
+    #[test]
+    fn test_normalize_term() {
+        assert_eq!(normalize_term("https://schema.org/Product"), "Product");
+        assert_eq!(normalize_term("http://schema.org/Offer"), "Offer");
+        assert_eq!(normalize_term("schema:name"), "name");
+        assert_eq!(normalize_term("og:title"), "og:title");
+    }
+
+    #[test]
+    fn test_json_ld_graph_and_nested_entities() {
+        let html = r##"<script type="application/ld+json">
+            {"@context": "https://schema.org", "@graph": [
+              {"@type": "Product", "@id": "#p1", "name": "Widget",
+               "offers": {"@type": "Offer", "price": "9.99", "priceCurrency": "USD"}},
+              {"@type": ["Article", "NewsArticle"], "headline": "Hello",
+               "dateCreated": {"@value": "2025-01-01", "@type": "Date"}}
+            ]}
+            </script>
+            <script type="application/ld+json">{ not json</script>"##;
+
+        let entities = extract_structured_data(html, None);
+        assert_eq!(entities.len(), 2);
+        assert_eq!(
+            entities[0],
+            json!({
+                "type": "Product",
+                "id": "#p1",
+                "source": "json-ld",
+                "properties": {
+                    "name": "Widget",
+                    "offers": {"type": "Offer", "properties": {"price": "9.99", "priceCurrency": "USD"}}
+                }
+            })
+        );
+        assert_eq!(entities[1]["type"], "Article");
+        assert_eq!(entities[1]["additional_types"], json!(["NewsArticle"]));
+        assert_eq!(entities[1]["properties"]["dateCreated"], "2025-01-01");
+    }
+
+    #[test]
+    fn test_microdata_with_nested_items_and_urls() {
+        let html = r#"
+            <div itemscope itemtype="https://schema.org/Recipe">
+              <h1 itemprop="name">Pancakes</h1>
+              <img itemprop="image" src="/img/pancakes.jpg">
+              <time itemprop="cookTime" datetime="PT20M">20 minutes</time>
+              <span itemprop="recipeIngredient">Flour</span>
+              <span itemprop="recipeIngredient">Milk</span>
+              <div itemprop="author" itemscope itemtype="https://schema.org/Person">
+                <span itemprop="name">Ana</span>
+              </div>
+              <meta itemprop="recipeYield" content="4">
+            </div>"#;
+
+        let entities = extract_structured_data(html, Some("https://example.com/recipes/1"));
+        assert_eq!(entities.len(), 1);
+        let recipe = &entities[0];
+        assert_eq!(recipe["type"], "Recipe");
+        assert_eq!(recipe["source"], "microdata");
+        let properties = &recipe["properties"];
+        assert_eq!(properties["name"], "Pancakes");
+        assert_eq!(properties["image"], "https://example.com/img/pancakes.jpg");
+        assert_eq!(properties["cookTime"], "PT20M");
+        assert_eq!(properties["recipeIngredient"], json!(["Flour", "Milk"]));
+        assert_eq!(properties["recipeYield"], "4");
+        assert_eq!(
+            properties["author"],
+            json!({"type": "Person", "properties": {"name": "Ana"}})
+        );
+    }
+
+    #[test]
+    fn test_rdfa_entities() {
+        let html = r##"
+            <div vocab="https://schema.org/" typeof="Event" resource="#launch">
+              <span property="name">Launch party</span>
+              <span property="startDate" content="2025-06-01T18:00">June 1st</span>
+              <div property="location" typeof="Place">
+                <span property="name">Town hall</span>
+              </div>
+            </div>"##;
+
+        let entities = extract_structured_data(html, None);
+        assert_eq!(entities.len(), 1);
+        assert_eq!(
+            entities[0],
+            json!({
+                "type": "Event",
+                "id": "#launch",
+                "source": "rdfa",
+                "properties": {
+                    "name": "Launch party",
+                    "startDate": "2025-06-01T18:00",
+                    "location": {"type": "Place", "properties": {"name": "Town hall"}}
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn test_page_without_markup_has_no_entities() {
+        assert!(extract_structured_data("<p>plain</p>", None).is_empty());
+    }
+}
@@ -0,0 +1,281 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! 文本差异工具
+//!
+//! 将同一 URL 的两次抓取内容转换为按行的文本，并生成统一格式（unified）的差异，
+//! 供页面变更监控使用。
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+/// 差异中每个变更块保留的上下文行数
+const CONTEXT_LINES: usize = 3;
+
+/// LCS 表的最大单元数，超出时中间部分按整体替换处理，避免超大页面占用过多内存
+const MAX_DIFF_CELLS: usize = 4_000_000;
+
+/// 不产生可见文本的元素（连同内容一起移除）
+static HIDDEN_ELEMENT_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?is)<(script|style|noscript|template)\b[^>]*>.*?</(script|style|noscript|template)>|<!--.*?-->")
+        .expect("valid hidden element regex")
+});
+
+/// 块级元素边界，转换为换行
+static BLOCK_BOUNDARY_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)<br\s*/?>|</?(p|div|section|article|header|footer|nav|aside|main|h[1-6]|li|ul|ol|tr|table|blockquote|pre|dt|dd)\b[^>]*>")
+        .expect("valid block boundary regex")
+});
+
+/// 其余标签
+static TAG_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?s)<[^>]+>").expect("valid tag regex"));
+
+/// 行内连续空白
+static INLINE_WHITESPACE_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"[ \t\r\f\v]+").expect("valid whitespace regex"));
+
+/// 单行差异
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DiffOp<'a> {
+    Equal(&'a str),
+    Removed(&'a str),
+    Added(&'a str),
+}
+
+/// 两段文本的差异结果
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TextDiff {
+    /// 新增行数
+    pub added_lines: usize,
+    /// 删除行数
+    pub removed_lines: usize,
+    /// 统一格式的差异文本，内容相同时为空
+    pub unified: String,
+}
+
+impl TextDiff {
+    /// 两段文本是否存在差异
+    pub fn has_changes(&self) -> bool {
+        self.added_lines > 0 || self.removed_lines > 0
+    }
+}
+
+/// 将 HTML 转换为按块分行的纯文本
+///
+/// 移除脚本、样式与注释，块级元素边界转为换行，去除空行。
+pub fn html_to_text(html: &str) -> String {
+    let text = HIDDEN_ELEMENT_RE.replace_all(html, "");
+    let text = BLOCK_BOUNDARY_RE.replace_all(&text, "\n");
+    let text = TAG_RE.replace_all(&text, "");
+    let text = unescape_html(&text);
+
+    text.lines()
+        .map(|line| {
+            INLINE_WHITESPACE_RE
+                .replace_all(line, " ")
+                .trim()
+                .to_string()
+        })
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// 计算两段文本按行的差异
+pub fn diff_text(old: &str, new: &str) -> TextDiff {
+    let old_lines: Vec<&str> = old.lines().collect();
+    let new_lines: Vec<&str> = new.lines().collect();
+    let ops = diff_lines(&old_lines, &new_lines);
+
+    let added_lines = ops
+        .iter()
+        .filter(|op| matches!(op, DiffOp::Added(_)))
+        .count();
+    let removed_lines = ops
+        .iter()
+        .filter(|op| matches!(op, DiffOp::Removed(_)))
+        .count();
+
+    TextDiff {
+        added_lines,
+        removed_lines,
+        unified: render_unified(&ops),
+    }
+}
+
+fn unescape_html(value: &str) -> String {
+    value
+        .replace("&nbsp;", " ")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+/// 基于最长公共子序列的行差异
+fn diff_lines<'a>(old: &[&'a str], new: &[&'a str]) -> Vec<DiffOp<'a>> {
+    let prefix = old
+        .iter()
+        .zip(new.iter())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    let old_mid = &old[prefix..old.len() - suffix];
+    let new_mid = &new[prefix..new.len() - suffix];
+
+    let mut ops: Vec<DiffOp<'a>> = old[..prefix].iter().copied().map(DiffOp::Equal).collect();
+
+    if old_mid.len().saturating_mul(new_mid.len()) > MAX_DIFF_CELLS {
+        ops.extend(old_mid.iter().copied().map(DiffOp::Removed));
+        ops.extend(new_mid.iter().copied().map(DiffOp::Added));
+    } else {
+        ops.extend(lcs_diff(old_mid, new_mid));
+    }
+
+    ops.extend(old[old.len() - suffix..].iter().copied().map(DiffOp::Equal));
+    ops
+}
+
+fn lcs_diff<'a>(old: &[&'a str], new: &[&'a str]) -> Vec<DiffOp<'a>> {
+    let (n, m) = (old.len(), new.len());
+    // table[i][j]：old[i..] 与 new[j..] 的 LCS 长度
+    let mut table = vec![0u32; (n + 1) * (m + 1)];
+    let idx = |i: usize, j: usize| i * (m + 1) + j;
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            table[idx(i, j)] = if old[i] == new[j] {
+                table[idx(i + 1, j + 1)] + 1
+            } else {
+                table[idx(i + 1, j)].max(table[idx(i, j + 1)])
+            };
+        }
+    }
+
+    let mut ops = Vec::with_capacity(n + m);
+    let (mut i, mut j) = (0, 0);
+    while i < n && j < m {
+        if old[i] == new[j] {
+            ops.push(DiffOp::Equal(old[i]));
+            i += 1;
+            j += 1;
+        } else if table[idx(i + 1, j)] >= table[idx(i, j + 1)] {
+            ops.push(DiffOp::Removed(old[i]));
+            i += 1;
+        } else {
+            ops.push(DiffOp::Added(new[j]));
+            j += 1;
+        }
+    }
+    ops.extend(old[i..].iter().copied().map(DiffOp::Removed));
+    ops.extend(new[j..].iter().copied().map(DiffOp::Added));
+    ops
+}
+
+/// 按 unified diff 格式输出变更块（`@@ -a,b +c,d @@`）
+fn render_unified(ops: &[DiffOp<'_>]) -> String {
+    let changed: Vec<usize> = ops
+        .iter()
+        .enumerate()
+        .filter(|(_, op)| !matches!(op, DiffOp::Equal(_)))
+        .map(|(i, _)| i)
+        .collect();
+    if changed.is_empty() {
+        return String::new();
+    }
+
+    // 合并上下文重叠的变更为同一个块
+    let mut hunks: Vec<(usize, usize)> = Vec::new();
+    for &i in &changed {
+        let start = i.saturating_sub(CONTEXT_LINES);
+        let end = (i + CONTEXT_LINES + 1).min(ops.len());
+        match hunks.last_mut() {
+            Some((_, last_end)) if start <= *last_end => *last_end = end,
+            _ => hunks.push((start, end)),
+        }
+    }
+
+    let mut out = String::new();
+    for (start, end) in hunks {
+        // 块起点之前的行数决定行号
+        let old_start = ops[..start]
+            .iter()
+            .filter(|op| !matches!(op, DiffOp::Added(_)))
+            .count();
+        let new_start = ops[..start]
+            .iter()
+            .filter(|op| !matches!(op, DiffOp::Removed(_)))
+            .count();
+        let hunk = &ops[start..end];
+        let old_len = hunk
+            .iter()
+            .filter(|op| !matches!(op, DiffOp::Added(_)))
+            .count();
+        let new_len = hunk
+            .iter()
+            .filter(|op| !matches!(op, DiffOp::Removed(_)))
+            .count();
+
+        out.push_str(&format!(
+            "@@ -{},{} +{},{} @@\n",
+            old_start + 1,
+            old_len,
+            new_start + 1,
+            new_len
+        ));
+        for op in hunk {
+            let (prefix, line) = match op {
+                DiffOp::Equal(line) => (' ', line),
+                DiffOp::Removed(line) => ('-', line),
+                DiffOp::Added(line) => ('+', line),
+            };
+            out.push(prefix);
+            out.push_str(line);
+            out.push('\n');
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_html_to_text_splits_blocks_and_drops_scripts() {
+        let html = r#"<html><head><style>p { color: red; }</style></head>
+<body><h1>Title</h1><p>First &amp; <b>bold</b></p><!-- note --><script>var a = 1;</script>
+<ul><li>One</li><li>Two</li></ul></body></html>"#;
+        assert_eq!(html_to_text(html), "Title\nFirst & bold\nOne\nTwo");
+    }
+
+    #[test]
+    fn test_diff_text_identical_has_no_changes() {
+        let diff = diff_text("a\nb\nc", "a\nb\nc");
+        assert!(!diff.has_changes());
+        assert!(diff.unified.is_empty());
+    }
+
+    #[test]
+    fn test_diff_text_reports_changed_lines() {
+        let old = "one\ntwo\nthree\nfour\nfive\nsix\nseven\neight";
+        let new = "one\ntwo\nthree\nfour\nFIVE\nsix\nseven\neight\nnine";
+        let diff = diff_text(old, new);
+
+        assert_eq!(diff.added_lines, 2);
+        assert_eq!(diff.removed_lines, 1);
+        assert_eq!(
+            diff.unified,
+            "@@ -2,7 +2,8 @@\n two\n three\n four\n-five\n+FIVE\n six\n seven\n eight\n+nine\n"
+        );
+    }
+}
@@ -5,7 +5,7 @@
 
 //! URL处理工具模块
 //!
-//! 提供URL解析、强类型封装和路径解析功能
+//! 提供URL解析、强类型封装、路径解析和规范化功能
 
 use thiserror::Error;
 use url::{ParseError, Url};
@@ -22,6 +22,106 @@ pub fn resolve_url(base_url: &Url, path: &str) -> Result<Url, ParseError> {
     base_url.join(path)
 }
 
+/// 常见的多级公共后缀
+///
+/// 未引入完整的 Public Suffix List，只覆盖主流国家/地区的二级公共后缀。
+const MULTI_LABEL_PUBLIC_SUFFIXES: &[&str] = &[
+    "co.uk", "org.uk", "ac.uk", "gov.uk", "me.uk", "net.uk", "com.au", "net.au", "org.au",
+    "edu.au", "gov.au", "co.nz", "org.nz", "co.jp", "ne.jp", "or.jp", "ac.jp", "go.jp", "com.cn",
+    "net.cn", "org.cn", "gov.cn", "edu.cn", "com.hk", "com.tw", "com.sg", "co.kr", "or.kr",
+    "co.in", "net.in", "org.in", "com.br", "net.br", "org.br", "com.mx", "com.ar", "co.za",
+    "com.tr", "com.ru", "com.ua", "co.il", "co.id", "com.my", "com.ph", "com.vn",
+];
+
+/// 计算主机名的可注册域名（eTLD+1），如 `blog.example.co.uk` -> `example.co.uk`
+///
+/// IP 地址与单标签主机原样返回。
+pub fn registrable_domain(host: &str) -> String {
+    let host = host.trim_end_matches('.').to_ascii_lowercase();
+    if host.starts_with('[') || host.parse::<std::net::IpAddr>().is_ok() {
+        return host;
+    }
+
+    let labels: Vec<&str> = host.split('.').collect();
+    if labels.len() <= 2 {
+        return host;
+    }
+    let suffix = labels[labels.len() - 2..].join(".");
+    let keep = if MULTI_LABEL_PUBLIC_SUFFIXES.contains(&suffix.as_str()) {
+        3
+    } else {
+        2
+    };
+    labels[labels.len().saturating_sub(keep)..].join(".")
+}
+
+/// 不影响页面内容的跟踪类查询参数（`utm_` 前缀参数另行匹配）
+const TRACKING_QUERY_PARAMS: &[&str] = &[
+    "gclid", "dclid", "fbclid", "msclkid", "yclid", "twclid", "igshid", "mc_cid", "mc_eid", "_ga",
+    "_gl", "_hsenc", "_hsmi", "mkt_tok",
+];
+
+fn is_tracking_param(name: &str) -> bool {
+    let name = name.to_ascii_lowercase();
+    name.starts_with("utm_") || TRACKING_QUERY_PARAMS.contains(&name.as_str())
+}
+
+/// 规范化 URL，使等价 URL 得到相同的字符串形式
+///
+/// 主机名小写、去除默认端口并解析 `./` 与 `../`（由 `url` 解析完成），
+/// 另外去除片段、合并路径中的连续 `/`、移除跟踪参数并按参数名排序其余查询参数。
+pub fn canonicalize_url(url: &Url) -> Url {
+    let mut url = url.clone();
+    url.set_fragment(None);
+
+    if url.path().contains("//") {
+        let mut path = String::with_capacity(url.path().len());
+        for c in url.path().chars() {
+            if !(c == '/' && path.ends_with('/')) {
+                path.push(c);
+            }
+        }
+        url.set_path(&path);
+    }
+
+    if url.query().is_some() {
+        let mut params: Vec<(String, String)> = url
+            .query_pairs()
+            .filter(|(name, _)| !is_tracking_param(name))
+            .map(|(name, value)| (name.into_owned(), value.into_owned()))
+            .collect();
+        if params.is_empty() {
+            url.set_query(None);
+        } else {
+            // 稳定排序，保留同名参数的相对顺序
+            params.sort_by(|a, b| a.0.cmp(&b.0));
+            url.query_pairs_mut().clear().extend_pairs(params);
+        }
+    }
+
+    url
+}
+
+/// 移除指定名称的查询参数（名称不区分大小写），其余参数保持原顺序
+pub fn strip_query_params(url: &Url, names: &[String]) -> Url {
+    let mut url = url.clone();
+    if url.query().is_none() {
+        return url;
+    }
+
+    let params: Vec<(String, String)> = url
+        .query_pairs()
+        .filter(|(name, _)| !names.iter().any(|n| n.eq_ignore_ascii_case(name)))
+        .map(|(name, value)| (name.into_owned(), value.into_owned()))
+        .collect();
+    if params.is_empty() {
+        url.set_query(None);
+    } else {
+        url.query_pairs_mut().clear().extend_pairs(params);
+    }
+    url
+}
+
 /// 强类型URL封装
 ///
 /// 替代String类型，提供类型安全的URL处理
@@ -131,6 +231,58 @@ impl<'de> serde::Deserialize<'de> for SafeUrl {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_registrable_domain() {
+        assert_eq!(registrable_domain("example.com"), "example.com");
+        assert_eq!(registrable_domain("Blog.Example.com."), "example.com");
+        assert_eq!(registrable_domain("a.b.example.co.uk"), "example.co.uk");
+        assert_eq!(registrable_domain("example.co.uk"), "example.co.uk");
+        assert_eq!(registrable_domain("127.0.0.1"), "127.0.0.1");
+        assert_eq!(registrable_domain("localhost"), "localhost");
+    }
+
+    #[test]
+    fn test_canonicalize_url() {
+        let canonical = |s: &str| canonicalize_url(&Url::parse(s).unwrap()).to_string();
+
+        assert_eq!(
+            canonical("HTTPS://Example.COM:443/a/./b/../c#section"),
+            "https://example.com/a/c"
+        );
+        assert_eq!(
+            canonical("https://example.com//docs///page"),
+            "https://example.com/docs/page"
+        );
+        assert_eq!(
+            canonical("https://example.com/p?utm_source=x&b=2&a=1&fbclid=y&UTM_Medium=z"),
+            "https://example.com/p?a=1&b=2"
+        );
+        assert_eq!(
+            canonical("https://example.com/p?utm_campaign=spring"),
+            "https://example.com/p"
+        );
+        assert_eq!(
+            canonical("https://example.com/p?tag=b&tag=a"),
+            "https://example.com/p?tag=b&tag=a"
+        );
+    }
+
+    #[test]
+    fn test_strip_query_params() {
+        let url = Url::parse("https://example.com/shop?color=red&Sort=price&page=2").unwrap();
+        let names = vec!["sort".to_string(), "color".to_string()];
+        assert_eq!(
+            strip_query_params(&url, &names).as_str(),
+            "https://example.com/shop?page=2"
+        );
+
+        let url = Url::parse("https://example.com/shop?sort=price").unwrap();
+        assert_eq!(
+            strip_query_params(&url, &names).as_str(),
+            "https://example.com/shop"
+        );
+    }
+
     #[test]
     fn test_resolve_absolute_url() {
         let base = Url::parse("http://example.com/a/b").unwrap();
@@ -0,0 +1,42 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+use crate::domain::services::bandwidth_service::BandwidthService;
+use crate::workers::worker::{ProcessResult, WorkerProcess};
+use async_trait::async_trait;
+use log::debug;
+use std::sync::Arc;
+
+/// 流量汇总工作器
+///
+/// 定期把计数器中有变化的团队当日下载量写入数据库。写入失败的团队日保留在
+/// 计数器中，下一轮重试。
+pub struct BandwidthRollupWorker {
+    bandwidth_service: Arc<BandwidthService>,
+}
+
+impl BandwidthRollupWorker {
+    pub fn new(bandwidth_service: Arc<BandwidthService>) -> Self {
+        Self { bandwidth_service }
+    }
+}
+
+#[async_trait]
+impl WorkerProcess for BandwidthRollupWorker {
+    fn name(&self) -> &str {
+        "bandwidth-rollup-worker"
+    }
+
+    async fn process(&self) -> ProcessResult {
+        match self.bandwidth_service.rollup().await {
+            Ok(0) => ProcessResult::Empty,
+            Ok(written) => {
+                debug!("Rolled up bandwidth usage for {} team days", written);
+                ProcessResult::Completed
+            }
+            Err(e) => ProcessResult::Error(format!("{:#}", e)),
+        }
+    }
+}
@@ -4,13 +4,26 @@
 // See LICENSE file in the project root for full license information.
 
 use crate::application::use_cases::create_scrape::CreateScrapeUseCaseTrait;
+use crate::domain::repositories::crawl_link_repository::CrawlLinkRepository;
 use crate::domain::repositories::crawl_repository::CrawlRepository;
 use crate::domain::repositories::credits_repository::CreditsRepository;
+use crate::domain::repositories::domain_throttle_repository::DomainThrottleRepository;
 use crate::domain::repositories::scrape_result_repository::ScrapeResultRepository;
+use crate::domain::repositories::storage_repository::StorageRepository;
+use crate::domain::repositories::task_event_repository::TaskEventRepository;
 use crate::domain::repositories::task_repository::TaskRepository;
-use crate::domain::services::webhook_service::WebhookService;
+use crate::domain::repositories::url_blocklist_repository::UrlBlocklistRepository;
+use crate::domain::services::bandwidth_service::BandwidthService;
+use crate::domain::services::plan_service::PlanService;
+use crate::domain::services::pricing_service::PricingService;
+use crate::domain::services::result_indexer::ResultIndexer;
+use crate::domain::services::result_uploader::ResultUploader;
+use crate::domain::services::team_export_service::TeamExportService;
+use crate::domain::services::webhook_service::{WebhookManagementService, WebhookService};
 use crate::engines::engine_client::EngineClient;
 use crate::presentation::middleware::team_semaphore::TeamSemaphore;
+use crate::queue::result_stream::ResultStreamBus;
+use crate::queue::scheduler::DelayedTaskScheduler;
 use crate::queue::task_queue::TaskQueue;
 use crate::utils::regex_cache::RegexCache;
 use crate::workers::expiration_worker::ExpirationWorker;
@@ -42,6 +55,20 @@ pub struct WorkerManager {
     extraction_service:
         Arc<dyn crate::domain::services::extraction_service::ExtractionServiceTrait>,
     regex_cache: RegexCache,
+    webhook_management_service: Option<Arc<dyn WebhookManagementService>>,
+    domain_throttle_repository: Option<Arc<dyn DomainThrottleRepository>>,
+    task_event_repository: Option<Arc<dyn TaskEventRepository>>,
+    url_blocklist_repository: Option<Arc<dyn UrlBlocklistRepository>>,
+    storage_repository: Option<Arc<dyn StorageRepository>>,
+    plan_service: Option<Arc<PlanService>>,
+    team_export_service: Option<Arc<TeamExportService>>,
+    pricing_service: Option<PricingService>,
+    delayed_scheduler: Option<Arc<DelayedTaskScheduler>>,
+    crawl_link_repository: Option<Arc<dyn CrawlLinkRepository>>,
+    result_uploader: Option<Arc<dyn ResultUploader>>,
+    result_stream: Option<Arc<dyn ResultStreamBus>>,
+    result_indexer: Option<Arc<dyn ResultIndexer>>,
+    bandwidth_service: Option<Arc<BandwidthService>>,
 }
 
 /// Worker Manager Dependencies
@@ -86,9 +113,125 @@ impl WorkerManager {
             handles: Vec::new(),
             extraction_service: deps.extraction_service,
             regex_cache: deps.regex_cache,
+            webhook_management_service: None,
+            domain_throttle_repository: None,
+            task_event_repository: None,
+            url_blocklist_repository: None,
+            storage_repository: None,
+            plan_service: None,
+            team_export_service: None,
+            pricing_service: None,
+            delayed_scheduler: None,
+            crawl_link_repository: None,
+            result_uploader: None,
+            result_stream: None,
+            result_indexer: None,
+            bandwidth_service: None,
         }
     }
 
+    /// 注入 webhook 管理服务，使抓取工作器在爬取结束时推送 `crawl.summary` 事件
+    pub fn with_webhook_management_service(
+        mut self,
+        webhook_management_service: Arc<dyn WebhookManagementService>,
+    ) -> Self {
+        self.webhook_management_service = Some(webhook_management_service);
+        self
+    }
+
+    /// 注入域名节流仓储，使抓取工作器在上游返回 429/503 时对该域名自适应退避
+    pub fn with_domain_throttle_repository(
+        mut self,
+        domain_throttle_repository: Arc<dyn DomainThrottleRepository>,
+    ) -> Self {
+        self.domain_throttle_repository = Some(domain_throttle_repository);
+        self
+    }
+
+    /// 注入任务事件仓储，使抓取工作器记录任务执行时间线事件
+    pub fn with_task_event_repository(
+        mut self,
+        task_event_repository: Arc<dyn TaskEventRepository>,
+    ) -> Self {
+        self.task_event_repository = Some(task_event_repository);
+        self
+    }
+
+    /// 注入 URL 黑名单仓储，使抓取工作器展开链接时过滤数据库中的黑名单条目
+    pub fn with_url_blocklist_repository(
+        mut self,
+        url_blocklist_repository: Arc<dyn UrlBlocklistRepository>,
+    ) -> Self {
+        self.url_blocklist_repository = Some(url_blocklist_repository);
+        self
+    }
+
+    /// 注入对象存储仓储，使抓取工作器在下载模式下保存二进制资源
+    pub fn with_storage_repository(
+        mut self,
+        storage_repository: Arc<dyn StorageRepository>,
+    ) -> Self {
+        self.storage_repository = Some(storage_repository);
+        self
+    }
+
+    /// 注入团队套餐服务，使抓取工作器按团队套餐的并发上限执行任务
+    pub fn with_plan_service(mut self, plan_service: Arc<PlanService>) -> Self {
+        self.plan_service = Some(plan_service);
+        self
+    }
+
+    /// 注入团队数据导出服务，使抓取工作器执行导出任务
+    pub fn with_team_export_service(mut self, team_export_service: Arc<TeamExportService>) -> Self {
+        self.team_export_service = Some(team_export_service);
+        self
+    }
+
+    /// 注入积分价格服务，替代按启动配置加载的价格表（如随配置热重载更新的价格）
+    pub fn with_pricing_service(mut self, pricing_service: PricingService) -> Self {
+        self.pricing_service = Some(pricing_service);
+        self
+    }
+
+    /// 注入延迟任务调度器，由管理器启动其分发循环，并在任务到期时唤醒抓取工作器
+    pub fn with_delayed_scheduler(mut self, delayed_scheduler: Arc<DelayedTaskScheduler>) -> Self {
+        self.delayed_scheduler = Some(delayed_scheduler);
+        self
+    }
+
+    /// 注入爬取链接仓储，使抓取工作器记录页面之间的链接，供 `GET /v1/crawl/{id}/graph` 导出
+    pub fn with_crawl_link_repository(
+        mut self,
+        crawl_link_repository: Arc<dyn CrawlLinkRepository>,
+    ) -> Self {
+        self.crawl_link_repository = Some(crawl_link_repository);
+        self
+    }
+
+    /// 注入结果投递上传器，使抓取工作器将爬取结果上传到爬取指定的存储桶或预签名前缀
+    pub fn with_result_uploader(mut self, result_uploader: Arc<dyn ResultUploader>) -> Self {
+        self.result_uploader = Some(result_uploader);
+        self
+    }
+
+    /// 注入结果推送总线，使抓取工作器在保存爬取结果与爬取结束时通知跟随结果的客户端
+    pub fn with_result_stream(mut self, result_stream: Arc<dyn ResultStreamBus>) -> Self {
+        self.result_stream = Some(result_stream);
+        self
+    }
+
+    /// 注入外部检索索引，使抓取工作器将保存的结果写入 OpenSearch 等检索集群
+    pub fn with_result_indexer(mut self, result_indexer: Arc<dyn ResultIndexer>) -> Self {
+        self.result_indexer = Some(result_indexer);
+        self
+    }
+
+    /// 注入流量统计服务，使抓取工作器统计下载流量并按团队每日上限拒绝任务
+    pub fn with_bandwidth_service(mut self, bandwidth_service: Arc<BandwidthService>) -> Self {
+        self.bandwidth_service = Some(bandwidth_service);
+        self
+    }
+
     /// 启动工作进程
     ///
     /// 创建并启动指定数量的工作进程
@@ -105,6 +248,13 @@ impl WorkerManager {
             expiration_worker.run().await;
         }));
 
+        if let Some(scheduler) = &self.delayed_scheduler {
+            let scheduler = scheduler.clone();
+            self.handles.push(tokio::spawn(async move {
+                scheduler.run().await;
+            }));
+        }
+
         for _ in 0..count {
             let worker = ScrapeWorker::new(
                 self.repository.clone(),
@@ -121,6 +271,62 @@ impl WorkerManager {
                 self.extraction_service.clone(),
                 self.regex_cache.clone(),
             );
+            let worker = match &self.webhook_management_service {
+                Some(service) => worker.with_webhook_management_service(service.clone()),
+                None => worker,
+            };
+            let worker = match &self.domain_throttle_repository {
+                Some(repository) => worker.with_domain_throttle_repository(repository.clone()),
+                None => worker,
+            };
+            let worker = match &self.task_event_repository {
+                Some(repository) => worker.with_task_event_repository(repository.clone()),
+                None => worker,
+            };
+            let worker = match &self.url_blocklist_repository {
+                Some(repository) => worker.with_url_blocklist_repository(repository.clone()),
+                None => worker,
+            };
+            let worker = match &self.storage_repository {
+                Some(repository) => worker.with_storage_repository(repository.clone()),
+                None => worker,
+            };
+            let worker = match &self.plan_service {
+                Some(plan_service) => worker.with_plan_service(plan_service.clone()),
+                None => worker,
+            };
+            let worker = match &self.team_export_service {
+                Some(service) => worker.with_team_export_service(service.clone()),
+                None => worker,
+            };
+            let worker = match &self.pricing_service {
+                Some(pricing) => worker.with_pricing_service(pricing.clone()),
+                None => worker,
+            };
+            let worker = match &self.delayed_scheduler {
+                Some(scheduler) => worker.with_delayed_scheduler(scheduler.clone()),
+                None => worker,
+            };
+            let worker = match &self.crawl_link_repository {
+                Some(repository) => worker.with_crawl_link_repository(repository.clone()),
+                None => worker,
+            };
+            let worker = match &self.result_uploader {
+                Some(uploader) => worker.with_result_uploader(uploader.clone()),
+                None => worker,
+            };
+            let worker = match &self.result_stream {
+                Some(result_stream) => worker.with_result_stream(result_stream.clone()),
+                None => worker,
+            };
+            let worker = match &self.result_indexer {
+                Some(indexer) => worker.with_result_indexer(indexer.clone()),
+                None => worker,
+            };
+            let worker = match &self.bandwidth_service {
+                Some(bandwidth_service) => worker.with_bandwidth_service(bandwidth_service.clone()),
+                None => worker,
+            };
 
             let queue = self.queue.clone();
             // We spawn the worker loop on a separate task to avoid blocking the main thread
@@ -696,9 +902,41 @@ mod tests {
         async fn find_by_task_ids(&self, _task_ids: &[Uuid]) -> anyhow::Result<Vec<ScrapeResult>> {
             Ok(vec![])
         }
+        async fn find_latest_for_crawl_url(
+            &self,
+            _crawl_id: Uuid,
+            _url: &str,
+        ) -> anyhow::Result<Option<ScrapeResult>> {
+            Ok(None)
+        }
+        async fn count_not_modified(&self, _task_ids: &[Uuid]) -> anyhow::Result<u64> {
+            Ok(0)
+        }
+        async fn response_times(&self, _task_ids: &[Uuid]) -> anyhow::Result<Vec<i64>> {
+            Ok(Vec::new())
+        }
         async fn get_team_avg_response_time(&self, _team_id: Uuid) -> anyhow::Result<f64> {
             Ok(0.0)
         }
+
+        async fn delete_for_team(
+            &self,
+            _team_id: Uuid,
+            _filter: &crate::domain::repositories::scrape_result_repository::ResultDeletionFilter,
+        ) -> anyhow::Result<Vec<ScrapeResult>> {
+            Ok(vec![])
+        }
+
+        async fn search_crawl_results(
+            &self,
+            _crawl_id: Uuid,
+            _query: &str,
+            _limit: u64,
+        ) -> anyhow::Result<
+            Vec<crate::domain::repositories::scrape_result_repository::ResultSearchHit>,
+        > {
+            Ok(vec![])
+        }
     }
 
     struct MockCrawlRepository;
@@ -8,10 +8,17 @@
 /// 提供后台任务处理和工作器管理功能
 /// 包括任务执行、工作器生命周期管理和并发控制
 pub mod backlog_worker;
+pub mod bandwidth_rollup_worker;
 pub mod errors;
 pub mod expiration_worker;
 pub mod manager;
+pub mod monitor_worker;
+pub mod plan_credit_worker;
+pub mod proxy_health_worker;
+pub mod proxy_provider_worker;
 pub mod scrape_worker;
+pub mod stalled_task_reaper;
+pub mod task_lease;
 pub mod task_state_machine;
 pub mod webhook_worker;
 pub mod worker;
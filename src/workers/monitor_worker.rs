@@ -0,0 +1,919 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+use crate::domain::models::{
+    CreditsTransactionType, Monitor, MonitorChange, MonitorMode, WebhookEventType,
+};
+use crate::domain::repositories::credits_repository::CreditsRepository;
+use crate::domain::repositories::monitor_repository::MonitorRepository;
+use crate::domain::services::extraction_service::{ExtractionRule, ExtractionServiceTrait};
+use crate::domain::services::pricing_service::PricingService;
+use crate::domain::services::url_blocklist_service::UrlBlocklistService;
+use crate::domain::services::webhook_service::WebhookManagementService;
+use crate::engines::engine_client::{EngineClientTrait, ScrapeRequest};
+use crate::utils::robots::RobotsCheckerTrait;
+use crate::utils::text_diff::{diff_text, html_to_text};
+use crate::workers::worker::{ProcessResult, WorkerProcess};
+use async_trait::async_trait;
+use chrono::Utc;
+use log::{error, info, warn};
+#[cfg(feature = "metrics")]
+use metrics::counter;
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// 单个周期最多检查的监控数
+const MAX_CHECKS_PER_CYCLE: u64 = 20;
+
+/// 领取监控的租期（秒），检查进程崩溃时监控在租期结束后被重新领取
+const CHECK_LEASE_SECONDS: i64 = 300;
+
+/// `monitor.changed` 负载中差异文本的最大字节数
+const MAX_DIFF_BYTES: usize = 64 * 1024;
+
+/// 检查 robots.txt 时使用的 User-Agent，与抓取任务一致
+const ROBOTS_USER_AGENT: &str = "crawlrs-bot";
+
+/// 单次检查的结果
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CheckOutcome {
+    Unchanged,
+    Changed,
+    Failed,
+    Paused,
+}
+
+/// 抓取前检查的结果
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Preflight {
+    /// 可以抓取
+    Ready,
+    /// 本次跳过，监控保持启用（如黑名单或余额暂时无法读取）
+    Skip(String),
+    /// URL 被拦截或余额不足，暂停监控
+    Pause(String),
+}
+
+/// 单个周期的检查结果
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct CheckSummary {
+    /// 检查的监控数
+    pub checked: u64,
+    /// 检测到变更的监控数
+    pub changed: u64,
+    /// 抓取或提取失败的监控数
+    pub failed: u64,
+    /// 因 URL 被拦截或余额不足而暂停的监控数
+    pub paused: u64,
+}
+
+/// 页面变更监控工作器
+///
+/// 定期领取已到期的监控，重新抓取页面并与上一次快照比较，
+/// 发生变更时向团队 webhook 推送附带差异的 `monitor.changed` 事件。
+pub struct MonitorWorker {
+    repository: Arc<dyn MonitorRepository>,
+    engine_client: Arc<dyn EngineClientTrait>,
+    extraction_service: Arc<dyn ExtractionServiceTrait>,
+    webhook_management_service: Option<Arc<dyn WebhookManagementService>>,
+    credits_repository: Option<(Arc<dyn CreditsRepository>, PricingService)>,
+    url_blocklist: UrlBlocklistService,
+    robots_checker: Option<Arc<dyn RobotsCheckerTrait>>,
+    /// 单次抓取超时
+    timeout: Duration,
+}
+
+impl MonitorWorker {
+    /// 创建监控工作器
+    pub fn new(
+        repository: Arc<dyn MonitorRepository>,
+        engine_client: Arc<dyn EngineClientTrait>,
+        extraction_service: Arc<dyn ExtractionServiceTrait>,
+        timeout: Duration,
+    ) -> Self {
+        Self {
+            repository,
+            engine_client,
+            extraction_service,
+            webhook_management_service: None,
+            credits_repository: None,
+            url_blocklist: UrlBlocklistService::default(),
+            robots_checker: None,
+            timeout,
+        }
+    }
+
+    /// 注入 webhook 管理服务，用于推送 `monitor.changed` 事件
+    pub fn with_webhook_management_service(
+        mut self,
+        webhook_management_service: Arc<dyn WebhookManagementService>,
+    ) -> Self {
+        self.webhook_management_service = Some(webhook_management_service);
+        self
+    }
+
+    /// 注入积分仓储，每次成功检查按团队的抓取价格扣费，余额不足时暂停监控
+    pub fn with_credits_repository(
+        mut self,
+        credits_repository: Arc<dyn CreditsRepository>,
+        pricing: PricingService,
+    ) -> Self {
+        self.credits_repository = Some((credits_repository, pricing));
+        self
+    }
+
+    /// 注入 URL 黑名单，每次检查前重新匹配，URL 被拦截时暂停监控
+    pub fn with_url_blocklist(mut self, url_blocklist: UrlBlocklistService) -> Self {
+        self.url_blocklist = url_blocklist;
+        self
+    }
+
+    /// 注入 robots.txt 检查器，每次检查前重新检查，禁止抓取时暂停监控
+    pub fn with_robots_checker(mut self, robots_checker: Arc<dyn RobotsCheckerTrait>) -> Self {
+        self.robots_checker = Some(robots_checker);
+        self
+    }
+
+    /// 检查一批已到期的监控
+    pub async fn check_due(&self) -> Result<CheckSummary, String> {
+        let monitors = self
+            .repository
+            .claim_due(
+                MAX_CHECKS_PER_CYCLE,
+                chrono::Duration::seconds(CHECK_LEASE_SECONDS),
+            )
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let mut summary = CheckSummary::default();
+        for monitor in monitors {
+            let monitor_id = monitor.id;
+            let outcome = self
+                .check(monitor)
+                .await
+                .map_err(|e| format!("failed to save check of monitor {}: {}", monitor_id, e))?;
+            summary.checked += 1;
+            match outcome {
+                CheckOutcome::Changed => summary.changed += 1,
+                CheckOutcome::Failed => summary.failed += 1,
+                CheckOutcome::Paused => summary.paused += 1,
+                CheckOutcome::Unchanged => {}
+            }
+            #[cfg(feature = "metrics")]
+            counter!(
+                "monitor_checks_total",
+                "outcome" => match outcome {
+                    CheckOutcome::Unchanged => "unchanged",
+                    CheckOutcome::Changed => "changed",
+                    CheckOutcome::Failed => "failed",
+                    CheckOutcome::Paused => "paused",
+                }
+            )
+            .increment(1);
+        }
+        Ok(summary)
+    }
+
+    /// 检查单个监控并保存结果
+    async fn check(
+        &self,
+        mut monitor: Monitor,
+    ) -> Result<CheckOutcome, crate::domain::repositories::task_repository::RepositoryError> {
+        let checked_at = Utc::now();
+        match self.preflight(&monitor).await {
+            Preflight::Ready => {}
+            Preflight::Skip(reason) => {
+                warn!(
+                    "Monitor {} check of {} skipped: {}",
+                    monitor.id, monitor.url, reason
+                );
+                monitor.record_failure(reason, checked_at);
+                self.repository.update(&monitor).await?;
+                return Ok(CheckOutcome::Failed);
+            }
+            Preflight::Pause(reason) => {
+                warn!(
+                    "Monitor {} of {} paused: {}",
+                    monitor.id, monitor.url, reason
+                );
+                monitor.record_failure(reason, checked_at);
+                monitor.set_active(false);
+                self.repository.update(&monitor).await?;
+                return Ok(CheckOutcome::Paused);
+            }
+        }
+
+        let snapshot = match self.snapshot(&monitor).await {
+            Ok(snapshot) => snapshot,
+            Err(e) => {
+                warn!(
+                    "Monitor {} check of {} failed: {}",
+                    monitor.id, monitor.url, e
+                );
+                monitor.record_failure(e, checked_at);
+                self.repository.update(&monitor).await?;
+                return Ok(CheckOutcome::Failed);
+            }
+        };
+
+        let hash = hex::encode(Sha256::digest(snapshot.as_bytes()));
+        let previous_hash = monitor.last_hash.clone().unwrap_or_default();
+        let previous_snapshot = monitor.last_snapshot.clone().unwrap_or_default();
+        let changed = monitor.record_snapshot(hash, snapshot, checked_at);
+        self.repository.update(&monitor).await?;
+        self.charge(&monitor).await;
+
+        if !changed {
+            return Ok(CheckOutcome::Unchanged);
+        }
+        let current_snapshot = monitor.last_snapshot.as_deref().unwrap_or_default();
+        let diff = diff_text(&previous_snapshot, current_snapshot);
+        let (unified, diff_truncated) = truncate_diff(diff.unified);
+        let change = MonitorChange {
+            monitor_id: monitor.id,
+            team_id: monitor.team_id,
+            url: monitor.url.clone(),
+            mode: monitor.mode,
+            previous_hash,
+            current_hash: monitor.last_hash.clone().unwrap_or_default(),
+            added_lines: diff.added_lines,
+            removed_lines: diff.removed_lines,
+            diff: unified,
+            diff_truncated,
+            checked_at,
+        };
+        self.emit_change(&change).await;
+        Ok(CheckOutcome::Changed)
+    }
+
+    /// 抓取前重新执行与提交抓取任务相同的检查：URL 黑名单、robots.txt 与积分余额
+    ///
+    /// 监控创建后黑名单、robots.txt 与余额都可能变化，每次检查前都需要重新判断。
+    /// 黑名单读取失败时不抓取（与提交时一致），robots.txt 读取失败时视为允许。
+    async fn preflight(&self, monitor: &Monitor) -> Preflight {
+        match self
+            .url_blocklist
+            .find_blocked(monitor.team_id, [monitor.url.as_str()])
+            .await
+        {
+            Ok(None) => {}
+            Ok(Some((_, entry))) => {
+                return Preflight::Pause(format!(
+                    "URL is blocked by blocklist pattern '{}'",
+                    entry.pattern
+                ))
+            }
+            Err(e) => return Preflight::Skip(format!("failed to check URL blocklist: {}", e)),
+        }
+
+        if let Some(robots_checker) = &self.robots_checker {
+            let allowed = robots_checker
+                .is_allowed(&monitor.url, ROBOTS_USER_AGENT)
+                .await
+                .unwrap_or(true);
+            if !allowed {
+                return Preflight::Pause("Access denied by robots.txt".to_string());
+            }
+        }
+
+        if let Some((credits_repository, pricing)) = &self.credits_repository {
+            let credits = pricing.pricing_for(monitor.team_id).scrape;
+            if credits > 0 {
+                match credits_repository.get_balance(monitor.team_id).await {
+                    Ok(balance) if balance < credits => {
+                        return Preflight::Pause(format!(
+                            "Insufficient credits: {} required, {} available",
+                            credits, balance
+                        ))
+                    }
+                    Ok(_) => {}
+                    Err(e) => {
+                        return Preflight::Skip(format!("failed to read credits balance: {}", e))
+                    }
+                }
+            }
+        }
+
+        Preflight::Ready
+    }
+
+    /// 抓取页面并生成用于比较的快照
+    ///
+    /// `hash` 模式为页面可见文本，`extract` 模式为格式化的提取结果（每个字段一行，
+    /// 差异按字段呈现）。
+    async fn snapshot(&self, monitor: &Monitor) -> Result<String, String> {
+        let request = ScrapeRequest::new(monitor.url.clone()).timeout(self.timeout);
+        let response = self
+            .engine_client
+            .scrape(&request)
+            .await
+            .map_err(|e| e.to_string())?;
+        if !response.is_success() {
+            return Err(format!("HTTP {}", response.status_code));
+        }
+
+        match monitor.mode {
+            MonitorMode::Hash => {
+                if response.content_type.is_empty() || response.content_type.contains("html") {
+                    Ok(html_to_text(&response.content))
+                } else {
+                    Ok(response.content)
+                }
+            }
+            MonitorMode::Extract => {
+                let rules: HashMap<String, ExtractionRule> =
+                    serde_json::from_value(monitor.extraction_rules.clone().unwrap_or_default())
+                        .map_err(|e| format!("invalid extraction rules: {}", e))?;
+                let data = self
+                    .extraction_service
+                    .extract_with_selectors(&response.content, &rules, Some(&monitor.url))
+                    .map_err(|e| format!("extraction failed: {}", e))?;
+                serde_json::to_string_pretty(&data).map_err(|e| e.to_string())
+            }
+        }
+    }
+
+    /// 按团队的抓取价格扣除一次检查的积分
+    async fn charge(&self, monitor: &Monitor) {
+        let Some((credits_repository, pricing)) = &self.credits_repository else {
+            return;
+        };
+        let credits = pricing.pricing_for(monitor.team_id).scrape;
+        if credits <= 0 {
+            return;
+        }
+        if let Err(e) = credits_repository
+            .deduct_credits(
+                monitor.team_id,
+                credits,
+                CreditsTransactionType::Scrape,
+                format!("Monitor check of {}", monitor.url),
+                Some(monitor.id),
+            )
+            .await
+        {
+            error!("Failed to deduct credits for monitor {}: {}", monitor.id, e);
+        }
+    }
+
+    /// 向团队 webhook 推送 `monitor.changed` 事件
+    async fn emit_change(&self, change: &MonitorChange) {
+        let Some(management) = &self.webhook_management_service else {
+            return;
+        };
+        let payload = match serde_json::to_value(change) {
+            Ok(payload) => payload,
+            Err(e) => {
+                error!(
+                    "Failed to serialize change of monitor {}: {}",
+                    change.monitor_id, e
+                );
+                return;
+            }
+        };
+
+        match management
+            .dispatch_event(change.team_id, WebhookEventType::MonitorChanged, payload)
+            .await
+        {
+            Ok(delivered) => info!(
+                "Dispatched change of monitor {} to {} webhook(s)",
+                change.monitor_id, delivered
+            ),
+            Err(e) => error!(
+                "Failed to dispatch change of monitor {}: {}",
+                change.monitor_id, e
+            ),
+        }
+    }
+}
+
+/// 截断过长的差异文本，返回是否发生截断
+fn truncate_diff(diff: String) -> (String, bool) {
+    if diff.len() <= MAX_DIFF_BYTES {
+        return (diff, false);
+    }
+    let mut end = MAX_DIFF_BYTES;
+    while !diff.is_char_boundary(end) {
+        end -= 1;
+    }
+    (diff[..end].to_string(), true)
+}
+
+#[async_trait]
+impl WorkerProcess for MonitorWorker {
+    fn name(&self) -> &str {
+        "monitor-worker"
+    }
+
+    async fn process(&self) -> ProcessResult {
+        match self.check_due().await {
+            Ok(summary) if summary.checked == 0 => ProcessResult::Empty,
+            Ok(summary) => {
+                info!(
+                    "Checked {} monitors: {} changed, {} failed, {} paused",
+                    summary.checked, summary.changed, summary.failed, summary.paused
+                );
+                ProcessResult::Completed
+            }
+            Err(e) => ProcessResult::Error(format!("Failed to check monitors: {}", e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::credits_model::{CreditsTransaction, CreditsTransactionType};
+    use crate::domain::models::Webhook;
+    use crate::domain::repositories::credits_repository::CreditsRepositoryError;
+    use crate::domain::repositories::task_repository::RepositoryError;
+    use crate::domain::services::credit_pricing::PricingTable;
+    use crate::domain::services::extraction_service::TokenUsage;
+    use crate::engines::engine_client::{EngineError, EngineHealthStatus, ScrapeResponse};
+    use anyhow::Result;
+    use serde_json::{json, Value};
+    use std::sync::Mutex;
+    use uuid::Uuid;
+
+    /// Keeps monitors in memory; every stored monitor is due.
+    #[derive(Default)]
+    struct InMemoryMonitorRepository {
+        monitors: Mutex<Vec<Monitor>>,
+    }
+
+    #[async_trait]
+    impl MonitorRepository for InMemoryMonitorRepository {
+        async fn create(&self, monitor: &Monitor) -> Result<Monitor, RepositoryError> {
+            self.monitors.lock().unwrap().push(monitor.clone());
+            Ok(monitor.clone())
+        }
+        async fn find_by_id(&self, id: Uuid) -> Result<Option<Monitor>, RepositoryError> {
+            Ok(self
+                .monitors
+                .lock()
+                .unwrap()
+                .iter()
+                .find(|m| m.id == id)
+                .cloned())
+        }
+        async fn find_by_team(&self, team_id: Uuid) -> Result<Vec<Monitor>, RepositoryError> {
+            Ok(self
+                .monitors
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|m| m.team_id == team_id)
+                .cloned()
+                .collect())
+        }
+        async fn update(&self, monitor: &Monitor) -> Result<Monitor, RepositoryError> {
+            let mut monitors = self.monitors.lock().unwrap();
+            let stored = monitors
+                .iter_mut()
+                .find(|m| m.id == monitor.id)
+                .ok_or(RepositoryError::NotFound)?;
+            *stored = monitor.clone();
+            Ok(monitor.clone())
+        }
+        async fn delete(&self, id: Uuid) -> Result<bool, RepositoryError> {
+            let mut monitors = self.monitors.lock().unwrap();
+            let before = monitors.len();
+            monitors.retain(|m| m.id != id);
+            Ok(monitors.len() != before)
+        }
+        async fn claim_due(
+            &self,
+            limit: u64,
+            _lease: chrono::Duration,
+        ) -> Result<Vec<Monitor>, RepositoryError> {
+            Ok(self
+                .monitors
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|m| m.active)
+                .take(limit as usize)
+                .cloned()
+                .collect())
+        }
+    }
+
+    /// Serves the configured page, or fails when none is set.
+    #[derive(Default)]
+    struct StaticEngineClient {
+        page: Mutex<Option<String>>,
+    }
+
+    impl StaticEngineClient {
+        fn serve(&self, page: &str) {
+            *self.page.lock().unwrap() = Some(page.to_string());
+        }
+    }
+
+    #[async_trait]
+    impl EngineClientTrait for StaticEngineClient {
+        async fn scrape(&self, _request: &ScrapeRequest) -> Result<ScrapeResponse, EngineError> {
+            match self.page.lock().unwrap().clone() {
+                Some(page) => Ok(ScrapeResponse::new(200, page, "text/html")),
+                None => Err(EngineError::RequestFailed("connection refused".to_string())),
+            }
+        }
+        async fn health_check(&self) -> EngineHealthStatus {
+            EngineHealthStatus::Healthy
+        }
+        fn engine_count(&self) -> usize {
+            1
+        }
+        fn registered_engines(&self) -> Vec<String> {
+            vec!["static".to_string()]
+        }
+    }
+
+    /// Returns the page text under every rule name.
+    struct EchoExtractionService;
+
+    #[async_trait]
+    impl ExtractionServiceTrait for EchoExtractionService {
+        async fn extract(
+            &self,
+            _html_content: &str,
+            _rules: &HashMap<String, ExtractionRule>,
+            _base_url: Option<&str>,
+        ) -> Result<(Value, TokenUsage)> {
+            Ok((json!({}), TokenUsage::default()))
+        }
+        async fn extract_with_schema(
+            &self,
+            _html_content: &str,
+            _schema: &Value,
+        ) -> Result<(Value, TokenUsage)> {
+            Ok((json!({}), TokenUsage::default()))
+        }
+        fn extract_with_selectors(
+            &self,
+            html_content: &str,
+            rules: &HashMap<String, ExtractionRule>,
+            _base_url: Option<&str>,
+        ) -> Result<Value> {
+            let text = html_to_text(html_content);
+            Ok(rules
+                .keys()
+                .map(|name| (name.clone(), json!(text)))
+                .collect())
+        }
+    }
+
+    /// Records every dispatched event.
+    #[derive(Default)]
+    struct RecordingWebhookManagementService {
+        dispatched: Mutex<Vec<(Uuid, WebhookEventType, Value)>>,
+    }
+
+    #[async_trait]
+    impl WebhookManagementService for RecordingWebhookManagementService {
+        async fn register_webhook(&self, _team_id: Uuid, _url: String) -> Result<Webhook> {
+            anyhow::bail!("not used")
+        }
+        async fn trigger_webhook(
+            &self,
+            _webhook_id: Uuid,
+            _event_type: WebhookEventType,
+            _payload: Value,
+        ) -> Result<()> {
+            Ok(())
+        }
+        async fn dispatch_event(
+            &self,
+            team_id: Uuid,
+            event_type: WebhookEventType,
+            payload: Value,
+        ) -> Result<usize> {
+            self.dispatched
+                .lock()
+                .unwrap()
+                .push((team_id, event_type, payload));
+            Ok(1)
+        }
+        async fn retry_failed(&self, _limit: u64) -> Result<u64> {
+            Ok(0)
+        }
+        async fn list_webhooks(&self, _team_id: Uuid) -> Result<Vec<Webhook>> {
+            Ok(vec![])
+        }
+    }
+
+    struct Fixture {
+        repository: Arc<InMemoryMonitorRepository>,
+        engine: Arc<StaticEngineClient>,
+        webhooks: Arc<RecordingWebhookManagementService>,
+        worker: MonitorWorker,
+    }
+
+    fn fixture() -> Fixture {
+        let repository = Arc::new(InMemoryMonitorRepository::default());
+        let engine = Arc::new(StaticEngineClient::default());
+        let webhooks = Arc::new(RecordingWebhookManagementService::default());
+        let worker = MonitorWorker::new(
+            repository.clone(),
+            engine.clone(),
+            Arc::new(EchoExtractionService),
+            Duration::from_secs(5),
+        )
+        .with_webhook_management_service(webhooks.clone());
+        Fixture {
+            repository,
+            engine,
+            webhooks,
+            worker,
+        }
+    }
+
+    async fn add_monitor(repository: &InMemoryMonitorRepository, monitor: Monitor) -> Monitor {
+        repository.create(&monitor).await.unwrap()
+    }
+
+    #[test]
+    fn test_worker_name() {
+        assert_eq!(fixture().worker.name(), "monitor-worker");
+    }
+
+    #[test]
+    fn test_truncate_diff_respects_char_boundaries() {
+        let (diff, truncated) = truncate_diff("+ok".to_string());
+        assert_eq!(diff, "+ok");
+        assert!(!truncated);
+
+        let (diff, truncated) = truncate_diff("é".repeat(MAX_DIFF_BYTES));
+        assert!(truncated);
+        assert!(diff.len() <= MAX_DIFF_BYTES);
+        assert!(diff.chars().all(|c| c == 'é'));
+    }
+
+    #[tokio::test]
+    async fn test_process_is_empty_without_due_monitors() {
+        assert_eq!(fixture().worker.process().await, ProcessResult::Empty);
+    }
+
+    #[tokio::test]
+    async fn test_change_is_dispatched_with_diff_after_baseline() {
+        let f = fixture();
+        let team_id = Uuid::new_v4();
+        let monitor = add_monitor(
+            &f.repository,
+            Monitor::new(team_id, "https://example.com", 300, MonitorMode::Hash),
+        )
+        .await;
+
+        f.engine
+            .serve("<html><script>var t = 1;</script><p>Price</p><p>$10</p></html>");
+        let summary = f.worker.check_due().await.unwrap();
+        assert_eq!(summary.checked, 1);
+        assert_eq!(summary.changed, 0);
+        assert!(f.webhooks.dispatched.lock().unwrap().is_empty());
+
+        // Hidden script content changes are ignored in hash mode
+        f.engine
+            .serve("<html><script>var t = 2;</script><p>Price</p><p>$10</p></html>");
+        assert_eq!(f.worker.check_due().await.unwrap().changed, 0);
+
+        f.engine.serve("<html><p>Price</p><p>$12</p></html>");
+        let summary = f.worker.check_due().await.unwrap();
+        assert_eq!(summary.changed, 1);
+
+        {
+            let dispatched = f.webhooks.dispatched.lock().unwrap();
+            assert_eq!(dispatched.len(), 1);
+            let (dispatched_team, event_type, payload) = &dispatched[0];
+            assert_eq!(*dispatched_team, team_id);
+            assert_eq!(*event_type, WebhookEventType::MonitorChanged);
+            assert_eq!(payload["monitor_id"], json!(monitor.id));
+            assert_eq!(payload["added_lines"], 1);
+            assert_eq!(payload["removed_lines"], 1);
+            let diff = payload["diff"].as_str().unwrap();
+            assert!(diff.contains("-$10"));
+            assert!(diff.contains("+$12"));
+        }
+
+        let stored = f.repository.find_by_id(monitor.id).await.unwrap().unwrap();
+        assert!(stored.last_changed_at.is_some());
+        assert!(stored.next_check_at > Utc::now());
+    }
+
+    #[tokio::test]
+    async fn test_failed_check_keeps_baseline() {
+        let f = fixture();
+        let monitor = add_monitor(
+            &f.repository,
+            Monitor::new(
+                Uuid::new_v4(),
+                "https://example.com",
+                300,
+                MonitorMode::Hash,
+            ),
+        )
+        .await;
+
+        f.engine.serve("<p>Hello</p>");
+        f.worker.check_due().await.unwrap();
+        *f.engine.page.lock().unwrap() = None;
+
+        let summary = f.worker.check_due().await.unwrap();
+        assert_eq!(summary.failed, 1);
+        let stored = f.repository.find_by_id(monitor.id).await.unwrap().unwrap();
+        assert!(stored
+            .last_error
+            .as_deref()
+            .unwrap()
+            .contains("connection refused"));
+        assert_eq!(stored.last_snapshot.as_deref(), Some("Hello"));
+
+        f.engine.serve("<p>Hello</p>");
+        assert_eq!(f.worker.check_due().await.unwrap().changed, 0);
+        assert!(f.webhooks.dispatched.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_extract_mode_compares_extracted_fields() {
+        let f = fixture();
+        add_monitor(
+            &f.repository,
+            Monitor::new(
+                Uuid::new_v4(),
+                "https://example.com",
+                300,
+                MonitorMode::Extract,
+            )
+            .with_extraction_rules(json!({"price": {"selector": ".price", "is_array": false}})),
+        )
+        .await;
+
+        f.engine.serve("<p>$10</p>");
+        f.worker.check_due().await.unwrap();
+        f.engine.serve("<p>$12</p>");
+        assert_eq!(f.worker.check_due().await.unwrap().changed, 1);
+
+        let dispatched = f.webhooks.dispatched.lock().unwrap();
+        let payload = &dispatched[0].2;
+        assert_eq!(payload["mode"], "extract");
+        assert!(payload["diff"]
+            .as_str()
+            .unwrap()
+            .contains("+  \"price\": \"$12\""));
+    }
+
+    struct DenyAllRobotsChecker;
+
+    #[async_trait]
+    impl RobotsCheckerTrait for DenyAllRobotsChecker {
+        async fn is_allowed(&self, _url_str: &str, _user_agent: &str) -> Result<bool> {
+            Ok(false)
+        }
+
+        async fn get_crawl_delay(
+            &self,
+            _url_str: &str,
+            _user_agent: &str,
+        ) -> Result<Option<Duration>> {
+            Ok(None)
+        }
+    }
+
+    struct FixedBalanceCreditsRepository {
+        balance: Mutex<i64>,
+    }
+
+    #[async_trait]
+    impl CreditsRepository for FixedBalanceCreditsRepository {
+        async fn get_balance(&self, _team_id: Uuid) -> Result<i64, CreditsRepositoryError> {
+            Ok(*self.balance.lock().unwrap())
+        }
+
+        async fn deduct_credits(
+            &self,
+            _team_id: Uuid,
+            amount: i64,
+            _transaction_type: CreditsTransactionType,
+            _description: String,
+            _reference_id: Option<Uuid>,
+        ) -> Result<(), CreditsRepositoryError> {
+            *self.balance.lock().unwrap() -= amount;
+            Ok(())
+        }
+
+        async fn add_credits(
+            &self,
+            _team_id: Uuid,
+            amount: i64,
+            _transaction_type: CreditsTransactionType,
+            _description: String,
+            _reference_id: Option<Uuid>,
+        ) -> Result<i64, CreditsRepositoryError> {
+            let mut balance = self.balance.lock().unwrap();
+            *balance += amount;
+            Ok(*balance)
+        }
+
+        async fn get_transaction_history(
+            &self,
+            _team_id: Uuid,
+            _limit: Option<u32>,
+        ) -> Result<Vec<CreditsTransaction>, CreditsRepositoryError> {
+            Ok(Vec::new())
+        }
+
+        async fn initialize_team_credits(
+            &self,
+            _team_id: Uuid,
+            initial_balance: i64,
+        ) -> Result<i64, CreditsRepositoryError> {
+            Ok(initial_balance)
+        }
+    }
+
+    async fn assert_paused(f: &Fixture, monitor_id: Uuid, reason: &str) {
+        let stored = f.repository.find_by_id(monitor_id).await.unwrap().unwrap();
+        assert!(!stored.active);
+        assert!(stored.last_error.as_deref().unwrap().contains(reason));
+        assert!(stored.last_snapshot.is_none());
+        // A paused monitor is no longer claimed
+        assert_eq!(f.worker.check_due().await.unwrap().checked, 0);
+    }
+
+    #[tokio::test]
+    async fn test_blocked_url_pauses_monitor_before_scraping() {
+        let mut f = fixture();
+        f.worker = f
+            .worker
+            .with_url_blocklist(UrlBlocklistService::new(&["example.com".to_string()]));
+        let monitor = add_monitor(
+            &f.repository,
+            Monitor::new(
+                Uuid::new_v4(),
+                "https://example.com/pricing",
+                300,
+                MonitorMode::Hash,
+            ),
+        )
+        .await;
+
+        f.engine.serve("<p>Hello</p>");
+        let summary = f.worker.check_due().await.unwrap();
+        assert_eq!(summary.paused, 1);
+        assert_paused(&f, monitor.id, "blocklist").await;
+    }
+
+    #[tokio::test]
+    async fn test_robots_disallow_pauses_monitor() {
+        let mut f = fixture();
+        f.worker = f.worker.with_robots_checker(Arc::new(DenyAllRobotsChecker));
+        let monitor = add_monitor(
+            &f.repository,
+            Monitor::new(
+                Uuid::new_v4(),
+                "https://example.com",
+                300,
+                MonitorMode::Hash,
+            ),
+        )
+        .await;
+
+        f.engine.serve("<p>Hello</p>");
+        assert_eq!(f.worker.check_due().await.unwrap().paused, 1);
+        assert_paused(&f, monitor.id, "robots.txt").await;
+    }
+
+    #[tokio::test]
+    async fn test_insufficient_credits_pauses_monitor_without_charging() {
+        let credits = Arc::new(FixedBalanceCreditsRepository {
+            balance: Mutex::new(0),
+        });
+        let mut f = fixture();
+        f.worker = f.worker.with_credits_repository(
+            credits.clone(),
+            PricingService::new(PricingTable {
+                scrape: 1,
+                ..PricingTable::default()
+            }),
+        );
+        let monitor = add_monitor(
+            &f.repository,
+            Monitor::new(
+                Uuid::new_v4(),
+                "https://example.com",
+                300,
+                MonitorMode::Hash,
+            ),
+        )
+        .await;
+
+        f.engine.serve("<p>Hello</p>");
+        assert_eq!(f.worker.check_due().await.unwrap().paused, 1);
+        assert_eq!(*credits.balance.lock().unwrap(), 0);
+        assert_paused(&f, monitor.id, "Insufficient credits").await;
+    }
+}
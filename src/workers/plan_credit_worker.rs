@@ -0,0 +1,298 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+use crate::domain::models::{credit_period_start, CreditsTransactionType, TeamPlanAssignment};
+use crate::domain::repositories::credits_repository::CreditsRepository;
+use crate::domain::repositories::team_plan_repository::TeamPlanRepository;
+use crate::domain::services::plan_service::PlanService;
+use crate::workers::worker::{ProcessResult, WorkerProcess};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use log::{info, warn};
+use std::sync::Arc;
+
+/// 每轮最多处理的团队数
+const GRANT_BATCH_SIZE: u64 = 100;
+
+/// 月度积分发放工作器
+///
+/// 定期查找本月（UTC）尚未发放月度积分的团队，余额低于套餐的月度积分时补足到该值。
+/// 发放前先以条件更新抢占发放权，多个实例同时运行时每个团队每月只发放一次。
+pub struct PlanCreditWorker {
+    plan_repository: Arc<dyn TeamPlanRepository>,
+    credits_repository: Arc<dyn CreditsRepository>,
+    plan_service: Arc<PlanService>,
+}
+
+impl PlanCreditWorker {
+    pub fn new(
+        plan_repository: Arc<dyn TeamPlanRepository>,
+        credits_repository: Arc<dyn CreditsRepository>,
+        plan_service: Arc<PlanService>,
+    ) -> Self {
+        Self {
+            plan_repository,
+            credits_repository,
+            plan_service,
+        }
+    }
+
+    /// 为一个团队发放本月积分，返回补充的积分数；已被其他实例发放时返回 0
+    async fn grant(
+        &self,
+        assignment: &TeamPlanAssignment,
+        now: DateTime<Utc>,
+    ) -> Result<i64, String> {
+        let period_start = credit_period_start(now);
+        let claimed = self
+            .plan_repository
+            .claim_credit_grant(assignment.team_id, period_start, now)
+            .await
+            .map_err(|e| e.to_string())?;
+        if !claimed {
+            return Ok(0);
+        }
+
+        let monthly_credits = self.plan_service.limits(assignment.plan).monthly_credits;
+        let balance = self
+            .credits_repository
+            .get_balance(assignment.team_id)
+            .await
+            .map_err(|e| e.to_string())?;
+        let top_up = monthly_credits - balance;
+        if top_up <= 0 {
+            return Ok(0);
+        }
+
+        self.credits_repository
+            .add_credits(
+                assignment.team_id,
+                top_up,
+                CreditsTransactionType::Subscription,
+                format!(
+                    "Monthly {} plan credits for {}",
+                    assignment.plan,
+                    period_start.format("%Y-%m")
+                ),
+                None,
+            )
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(top_up)
+    }
+
+    async fn grant_due_credits(&self, now: DateTime<Utc>) -> Result<(usize, i64), String> {
+        let due = self
+            .plan_repository
+            .find_due_for_credit_grant(credit_period_start(now), GRANT_BATCH_SIZE)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let mut teams = 0;
+        let mut credits = 0;
+        for assignment in &due {
+            match self.grant(assignment, now).await {
+                Ok(granted) => {
+                    teams += 1;
+                    credits += granted;
+                }
+                Err(e) => warn!(
+                    "Failed to grant monthly credits to team {}: {}",
+                    assignment.team_id, e
+                ),
+            }
+        }
+        Ok((teams, credits))
+    }
+}
+
+#[async_trait]
+impl WorkerProcess for PlanCreditWorker {
+    fn name(&self) -> &str {
+        "plan-credit-worker"
+    }
+
+    async fn process(&self) -> ProcessResult {
+        match self.grant_due_credits(Utc::now()).await {
+            Ok((teams, credits)) => {
+                if teams > 0 {
+                    info!(
+                        "Refreshed monthly plan credits for {} teams ({} credits added)",
+                        teams, credits
+                    );
+                }
+                ProcessResult::Completed
+            }
+            Err(e) => ProcessResult::Error(format!("Failed to grant monthly credits: {}", e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::settings::PlanSettings;
+    use crate::domain::models::{CreditsTransaction, TeamPlan};
+    use crate::domain::repositories::credits_repository::CreditsRepositoryError;
+    use crate::domain::repositories::task_repository::RepositoryError;
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+    use uuid::Uuid;
+
+    #[derive(Default)]
+    struct InMemoryPlanRepository {
+        teams: Mutex<HashMap<Uuid, TeamPlanAssignment>>,
+    }
+
+    #[async_trait]
+    impl TeamPlanRepository for InMemoryPlanRepository {
+        async fn get_plan(&self, team_id: Uuid) -> Result<TeamPlanAssignment, RepositoryError> {
+            self.teams
+                .lock()
+                .unwrap()
+                .get(&team_id)
+                .copied()
+                .ok_or(RepositoryError::NotFound)
+        }
+
+        async fn set_plan(
+            &self,
+            team_id: Uuid,
+            plan: TeamPlan,
+        ) -> Result<TeamPlanAssignment, RepositoryError> {
+            let mut teams = self.teams.lock().unwrap();
+            let assignment = teams.get_mut(&team_id).ok_or(RepositoryError::NotFound)?;
+            assignment.plan = plan;
+            Ok(*assignment)
+        }
+
+        async fn find_due_for_credit_grant(
+            &self,
+            period_start: DateTime<Utc>,
+            _limit: u64,
+        ) -> Result<Vec<TeamPlanAssignment>, RepositoryError> {
+            Ok(self
+                .teams
+                .lock()
+                .unwrap()
+                .values()
+                .filter(|a| a.credit_grant_due(period_start))
+                .copied()
+                .collect())
+        }
+
+        async fn claim_credit_grant(
+            &self,
+            team_id: Uuid,
+            period_start: DateTime<Utc>,
+            granted_at: DateTime<Utc>,
+        ) -> Result<bool, RepositoryError> {
+            let mut teams = self.teams.lock().unwrap();
+            let assignment = teams.get_mut(&team_id).ok_or(RepositoryError::NotFound)?;
+            if !assignment.credit_grant_due(period_start) {
+                return Ok(false);
+            }
+            assignment.credits_granted_at = Some(granted_at);
+            Ok(true)
+        }
+    }
+
+    #[derive(Default)]
+    struct InMemoryCreditsRepository {
+        balances: Mutex<HashMap<Uuid, i64>>,
+    }
+
+    #[async_trait]
+    impl CreditsRepository for InMemoryCreditsRepository {
+        async fn get_balance(&self, team_id: Uuid) -> Result<i64, CreditsRepositoryError> {
+            Ok(*self.balances.lock().unwrap().get(&team_id).unwrap_or(&0))
+        }
+
+        async fn deduct_credits(
+            &self,
+            team_id: Uuid,
+            amount: i64,
+            _transaction_type: CreditsTransactionType,
+            _description: String,
+            _reference_id: Option<Uuid>,
+        ) -> Result<(), CreditsRepositoryError> {
+            *self.balances.lock().unwrap().entry(team_id).or_default() -= amount;
+            Ok(())
+        }
+
+        async fn add_credits(
+            &self,
+            team_id: Uuid,
+            amount: i64,
+            _transaction_type: CreditsTransactionType,
+            _description: String,
+            _reference_id: Option<Uuid>,
+        ) -> Result<i64, CreditsRepositoryError> {
+            let mut balances = self.balances.lock().unwrap();
+            let balance = balances.entry(team_id).or_default();
+            *balance += amount;
+            Ok(*balance)
+        }
+
+        async fn get_transaction_history(
+            &self,
+            _team_id: Uuid,
+            _limit: Option<u32>,
+        ) -> Result<Vec<CreditsTransaction>, CreditsRepositoryError> {
+            Ok(Vec::new())
+        }
+
+        async fn initialize_team_credits(
+            &self,
+            _team_id: Uuid,
+            initial_balance: i64,
+        ) -> Result<i64, CreditsRepositoryError> {
+            Ok(initial_balance)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_grants_top_up_once_per_month() {
+        let drained = Uuid::new_v4();
+        let rich = Uuid::new_v4();
+        let plans = Arc::new(InMemoryPlanRepository::default());
+        for team_id in [drained, rich] {
+            plans.teams.lock().unwrap().insert(
+                team_id,
+                TeamPlanAssignment {
+                    team_id,
+                    plan: TeamPlan::Free,
+                    credits_granted_at: None,
+                },
+            );
+        }
+        let credits = Arc::new(InMemoryCreditsRepository::default());
+        credits.balances.lock().unwrap().insert(drained, 120);
+        credits.balances.lock().unwrap().insert(rich, 5000);
+        let plan_service = Arc::new(PlanService::from_settings(
+            plans.clone(),
+            &PlanSettings::default(),
+        ));
+        let worker = PlanCreditWorker::new(plans.clone(), credits.clone(), plan_service);
+
+        let now = "2025-03-15T10:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        assert_eq!(worker.grant_due_credits(now).await.unwrap(), (2, 380));
+        assert_eq!(credits.get_balance(drained).await.unwrap(), 500);
+        assert_eq!(credits.get_balance(rich).await.unwrap(), 5000);
+
+        // 同一个月内不再发放
+        credits.balances.lock().unwrap().insert(drained, 0);
+        assert_eq!(worker.grant_due_credits(now).await.unwrap(), (0, 0));
+        assert_eq!(credits.get_balance(drained).await.unwrap(), 0);
+
+        // 下个月再次补足
+        let next_month = "2025-04-01T00:30:00Z".parse::<DateTime<Utc>>().unwrap();
+        assert_eq!(
+            worker.grant_due_credits(next_month).await.unwrap(),
+            (2, 500)
+        );
+        assert_eq!(credits.get_balance(drained).await.unwrap(), 500);
+    }
+}
@@ -0,0 +1,289 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+use crate::config::settings::ProxyHealthCheckSettings;
+use crate::domain::services::geo_location::GeoLocationService;
+use crate::engines::block_detection::detect_block;
+use crate::engines::proxy_pool::{PoolProxy, ProbeFailure, ProxyPool, ProxyProbe};
+use crate::infrastructure::metrics::{record_proxy_health_check, record_proxy_pool_size};
+use crate::workers::worker::{ProcessResult, WorkerProcess};
+use async_trait::async_trait;
+use chrono::Utc;
+use log::{debug, info, warn};
+use std::collections::HashMap;
+use std::net::IpAddr;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// 单个周期的检查结果
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ProxyCheckSummary {
+    /// 检查通过的代理数
+    pub healthy: usize,
+    /// 检查失败的代理数
+    pub failed: usize,
+    /// 本周期被标记为不可用的代理数
+    pub deactivated: usize,
+    /// 本周期恢复可用的代理数
+    pub reactivated: usize,
+}
+
+/// 代理健康检查工作器
+///
+/// 定期通过池中每个代理请求出口 IP 地址测量延迟，按配置的国家校验出口 IP 所在地，
+/// 再请求探测站点检查是否被封禁。结果写回代理池，连续失败的代理不再参与轮换。
+pub struct ProxyHealthWorker {
+    pool: Arc<ProxyPool>,
+    settings: ProxyHealthCheckSettings,
+    /// 出口 IP 地理定位，未设置时不校验国家
+    geo_location: Option<Arc<dyn GeoLocationService>>,
+}
+
+impl ProxyHealthWorker {
+    pub fn new(pool: Arc<ProxyPool>, settings: ProxyHealthCheckSettings) -> Self {
+        Self {
+            pool,
+            settings,
+            geo_location: None,
+        }
+    }
+
+    /// 设置出口 IP 地理定位服务，配置了 `country` 的代理据此校验出口国家
+    pub fn with_geo_location(mut self, geo_location: Arc<dyn GeoLocationService>) -> Self {
+        self.geo_location = Some(geo_location);
+        self
+    }
+
+    /// 检查池中所有代理并更新可用状态
+    pub async fn check_all(&self) -> ProxyCheckSummary {
+        let proxies = self.pool.proxies();
+        let probes = futures::future::join_all(proxies.iter().map(|proxy| self.probe(proxy))).await;
+
+        let mut summary = ProxyCheckSummary::default();
+        for (proxy, probe) in proxies.iter().zip(probes) {
+            let latency = probe.latency_ms.map(Duration::from_millis);
+            match &probe.failure {
+                None => {
+                    summary.healthy += 1;
+                    record_proxy_health_check("healthy", latency);
+                    debug!(
+                        "Proxy {} healthy ({} ms, exit {:?})",
+                        proxy.label(),
+                        probe.latency_ms.unwrap_or_default(),
+                        probe.exit_ip
+                    );
+                }
+                Some(failure) => {
+                    summary.failed += 1;
+                    record_proxy_health_check(failure.kind(), latency);
+                    warn!("Proxy {} failed health check: {}", proxy.label(), failure);
+                }
+            }
+            match self.pool.record_probe(&proxy.url, probe) {
+                Some(false) => {
+                    summary.deactivated += 1;
+                    warn!("Proxy {} marked inactive", proxy.label());
+                }
+                Some(true) => {
+                    summary.reactivated += 1;
+                    info!("Proxy {} is active again", proxy.label());
+                }
+                None => {}
+            }
+        }
+
+        let active = self.pool.active_count();
+        record_proxy_pool_size(active, self.pool.len() - active);
+        summary
+    }
+
+    /// 检查单个代理
+    async fn probe(&self, proxy: &PoolProxy) -> ProxyProbe {
+        let mut probe = ProxyProbe {
+            checked_at: Utc::now(),
+            latency_ms: None,
+            exit_ip: None,
+            country: None,
+            failure: None,
+        };
+
+        let client = match self.client_for(&proxy.url) {
+            Ok(client) => client,
+            Err(e) => {
+                probe.failure = Some(ProbeFailure::Unreachable(e.to_string()));
+                return probe;
+            }
+        };
+
+        let started = Instant::now();
+        let exit_ip = match fetch_exit_ip(&client, &self.settings.ip_check_url).await {
+            Ok(ip) => ip,
+            Err(e) => {
+                probe.failure = Some(ProbeFailure::Unreachable(e));
+                return probe;
+            }
+        };
+        probe.latency_ms = Some(started.elapsed().as_millis() as u64);
+        probe.exit_ip = Some(exit_ip);
+
+        if let (Some(expected), Some(geo_location)) = (&proxy.country, &self.geo_location) {
+            // 地理定位服务本身失败时不判定代理失败
+            match geo_location.get_location(&exit_ip).await {
+                Ok(location) => {
+                    let actual = location.country_code.trim().to_ascii_uppercase();
+                    if actual != *expected {
+                        probe.failure = Some(ProbeFailure::CountryMismatch {
+                            expected: expected.clone(),
+                            actual: actual.clone(),
+                        });
+                    }
+                    probe.country = Some(actual);
+                }
+                Err(e) => warn!("Failed to geolocate exit IP of {}: {}", proxy.label(), e),
+            }
+            if probe.failure.is_some() {
+                return probe;
+            }
+        }
+
+        for canary_url in &self.settings.canary_urls {
+            // 探测站点自身不可达时不判定代理被封禁
+            match client.get(canary_url).send().await {
+                Ok(response) => {
+                    let status = response.status().as_u16();
+                    let headers: HashMap<String, String> = response
+                        .headers()
+                        .iter()
+                        .filter_map(|(name, value)| {
+                            Some((name.to_string(), value.to_str().ok()?.to_string()))
+                        })
+                        .collect();
+                    let body = response.text().await.unwrap_or_default();
+                    if let Some(blocked_by) = detect_block(status, &headers, &body) {
+                        probe.failure = Some(ProbeFailure::Banned {
+                            canary_url: canary_url.clone(),
+                            blocked_by,
+                        });
+                        break;
+                    }
+                }
+                Err(e) => warn!(
+                    "Canary {} through proxy {} failed: {}",
+                    canary_url,
+                    proxy.label(),
+                    e
+                ),
+            }
+        }
+        probe
+    }
+
+    fn client_for(&self, proxy_url: &str) -> reqwest::Result<reqwest::Client> {
+        reqwest::Client::builder()
+            .proxy(reqwest::Proxy::all(proxy_url)?)
+            .timeout(Duration::from_secs(self.settings.timeout_seconds.max(1)))
+            .build()
+    }
+}
+
+/// 请求出口 IP 地址
+async fn fetch_exit_ip(client: &reqwest::Client, ip_check_url: &str) -> Result<IpAddr, String> {
+    let response = client
+        .get(ip_check_url)
+        .send()
+        .await
+        .map_err(|e| e.to_string())?;
+    if !response.status().is_success() {
+        return Err(format!("IP check returned HTTP {}", response.status()));
+    }
+    let body = response.text().await.map_err(|e| e.to_string())?;
+    parse_exit_ip(&body).ok_or_else(|| "IP check response has no IP address".to_string())
+}
+
+/// 从纯文本 IP 或带 `ip` 字段的 JSON 中解析出口 IP
+fn parse_exit_ip(body: &str) -> Option<IpAddr> {
+    let body = body.trim();
+    if let Ok(ip) = body.parse() {
+        return Some(ip);
+    }
+    serde_json::from_str::<serde_json::Value>(body)
+        .ok()?
+        .get("ip")?
+        .as_str()?
+        .trim()
+        .parse()
+        .ok()
+}
+
+#[async_trait]
+impl WorkerProcess for ProxyHealthWorker {
+    fn name(&self) -> &str {
+        "proxy-health-worker"
+    }
+
+    async fn process(&self) -> ProcessResult {
+        if self.pool.is_empty() {
+            return ProcessResult::Empty;
+        }
+        let summary = self.check_all().await;
+        if summary.deactivated > 0 || summary.reactivated > 0 {
+            info!(
+                "Proxy health check: {} healthy, {} failed, {} deactivated, {} reactivated",
+                summary.healthy, summary.failed, summary.deactivated, summary.reactivated
+            );
+        }
+        ProcessResult::Completed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_exit_ip() {
+        assert_eq!(parse_exit_ip("203.0.113.7\n"), "203.0.113.7".parse().ok());
+        assert_eq!(
+            parse_exit_ip(r#"{"ip": "2001:db8::1"}"#),
+            "2001:db8::1".parse().ok()
+        );
+        assert_eq!(parse_exit_ip("<html>error</html>"), None);
+        assert_eq!(parse_exit_ip(r#"{"origin": "203.0.113.7"}"#), None);
+    }
+
+    #[tokio::test]
+    async fn test_unreachable_proxy_is_marked_inactive() {
+        let pool = Arc::new(ProxyPool::new(
+            vec![PoolProxy {
+                url: "http://127.0.0.1:1".to_string(),
+                country: None,
+            }],
+            1,
+        ));
+        let settings = ProxyHealthCheckSettings {
+            timeout_seconds: 2,
+            ip_check_url: "http://example.com/ip".to_string(),
+            ..ProxyHealthCheckSettings::default()
+        };
+        let worker = ProxyHealthWorker::new(pool.clone(), settings);
+
+        let summary = worker.check_all().await;
+        assert_eq!(summary.failed, 1);
+        assert_eq!(summary.deactivated, 1);
+        assert_eq!(pool.active_count(), 0);
+        let status = &pool.statuses()[0];
+        assert_eq!(
+            status
+                .last_probe
+                .as_ref()
+                .unwrap()
+                .failure
+                .as_ref()
+                .unwrap()
+                .kind(),
+            "unreachable"
+        );
+    }
+}
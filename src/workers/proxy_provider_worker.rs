@@ -0,0 +1,143 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+use crate::engines::proxy_pool::ProxyPool;
+use crate::engines::proxy_providers::ProxyProvider;
+use crate::infrastructure::metrics::record_proxy_provider_refresh;
+use crate::workers::worker::{ProcessResult, WorkerProcess};
+use async_trait::async_trait;
+use log::{debug, warn};
+use std::sync::Arc;
+
+/// 代理提供商刷新工作器
+///
+/// 定期从每个提供商获取代理列表并替换代理池中该提供商的代理。某个提供商刷新失败时
+/// 保留其上一次的代理，不影响其他提供商。
+pub struct ProxyProviderWorker {
+    pool: Arc<ProxyPool>,
+    providers: Vec<Arc<dyn ProxyProvider>>,
+}
+
+impl ProxyProviderWorker {
+    pub fn new(pool: Arc<ProxyPool>, providers: Vec<Arc<dyn ProxyProvider>>) -> Self {
+        Self { pool, providers }
+    }
+
+    /// 刷新所有提供商，返回刷新失败的提供商数
+    pub async fn refresh_all(&self) -> usize {
+        let results = futures::future::join_all(
+            self.providers
+                .iter()
+                .map(|provider| async move { (provider, provider.fetch_proxies().await) }),
+        )
+        .await;
+
+        let mut failed = 0;
+        for (provider, result) in results {
+            match result {
+                Ok(proxies) => {
+                    let count = self.pool.replace_source(provider.name(), proxies);
+                    record_proxy_provider_refresh(provider.name(), true);
+                    debug!(
+                        "Proxy provider {} supplied {} proxies",
+                        provider.name(),
+                        count
+                    );
+                }
+                Err(e) => {
+                    failed += 1;
+                    record_proxy_provider_refresh(provider.name(), false);
+                    warn!(
+                        "Failed to refresh proxies from {}: {:#}",
+                        provider.name(),
+                        e
+                    );
+                }
+            }
+        }
+        failed
+    }
+}
+
+#[async_trait]
+impl WorkerProcess for ProxyProviderWorker {
+    fn name(&self) -> &str {
+        "proxy-provider-worker"
+    }
+
+    async fn process(&self) -> ProcessResult {
+        if self.providers.is_empty() {
+            return ProcessResult::Empty;
+        }
+        match self.refresh_all().await {
+            0 => ProcessResult::Completed,
+            failed => ProcessResult::Error(format!(
+                "{} of {} proxy providers failed to refresh",
+                failed,
+                self.providers.len()
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engines::proxy_pool::PoolProxy;
+
+    struct StaticProvider {
+        name: &'static str,
+        urls: Option<Vec<&'static str>>,
+    }
+
+    #[async_trait]
+    impl ProxyProvider for StaticProvider {
+        fn name(&self) -> &str {
+            self.name
+        }
+
+        async fn fetch_proxies(&self) -> anyhow::Result<Vec<PoolProxy>> {
+            let urls = self
+                .urls
+                .clone()
+                .ok_or_else(|| anyhow::anyhow!("provider unavailable"))?;
+            Ok(urls
+                .into_iter()
+                .map(|url| PoolProxy {
+                    url: url.to_string(),
+                    country: None,
+                })
+                .collect())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_refresh_feeds_pool_and_keeps_going_after_failures() {
+        let pool = Arc::new(ProxyPool::new(Vec::new(), 1));
+        let worker = ProxyProviderWorker::new(
+            pool.clone(),
+            vec![
+                Arc::new(StaticProvider {
+                    name: "webshare",
+                    urls: Some(vec!["http://a:1", "http://b:1"]),
+                }),
+                Arc::new(StaticProvider {
+                    name: "brightdata",
+                    urls: None,
+                }),
+            ],
+        );
+
+        assert_eq!(
+            worker.process().await,
+            ProcessResult::Error("1 of 2 proxy providers failed to refresh".to_string())
+        );
+        assert_eq!(pool.len(), 2);
+        assert!(pool
+            .statuses()
+            .iter()
+            .all(|status| status.source == "webshare"));
+    }
+}
@@ -4,15 +4,17 @@
 // See LICENSE file in the project root for full license information.
 
 use anyhow::{Context, Result};
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 use dashmap::DashMap;
 use log::{debug, error, info, warn};
 use scraper::{Html, Selector};
 use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
 use std::collections::{HashMap, HashSet};
+use std::net::IpAddr;
 use std::sync::atomic::{AtomicI64, Ordering};
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use tokio::sync::OwnedSemaphorePermit;
 use tokio::time::sleep;
 use url::Url;
@@ -20,32 +22,91 @@ use uuid::Uuid;
 
 use crate::application::dto::crawl_request::CrawlConfigDto;
 use crate::application::dto::extract_request::ExtractRequestDto;
-use crate::application::dto::scrape_request::ScrapeRequestDto;
+use crate::application::dto::feed_request::FeedRequestDto;
+use crate::application::dto::scrape_request::{
+    AccessibilityOptionsDto, PdfOptionsDto, ScrapeActionDto, ScrapeRequestDto,
+};
 use crate::application::use_cases::create_scrape::CreateScrapeUseCaseTrait;
+use crate::common::constants::crawl_task::{
+    MAX_SITEMAP_FILES, MAX_SITEMAP_SEED_URLS, NOFOLLOW_LINK_RELS,
+};
 use crate::config::settings::Settings;
+use crate::domain::models::domain_throttle_model::parse_retry_after;
+use crate::domain::models::result_destination_model::RESULT_OBJECT_CONTENT_TYPE;
 use crate::domain::models::scrape_result::ScrapeResult;
-use crate::domain::models::CrawlStatus;
-use crate::domain::models::{Task, TaskStatus, TaskType};
+use crate::domain::models::{Crawl, CrawlLink, CrawlStatus, WebhookEventType};
+use crate::domain::models::{
+    DomainThrottle, ResultDestination, ThrottlePolicy, BANDWIDTH_CAP_FAILURE_REASON,
+};
+use crate::domain::models::{Task, TaskEvent, TaskEventType, TaskStatus, TaskType};
+use crate::domain::repositories::crawl_link_repository::CrawlLinkRepository;
 use crate::domain::repositories::crawl_repository::CrawlRepository;
 use crate::domain::repositories::credits_repository::CreditsRepository;
+use crate::domain::repositories::domain_throttle_repository::DomainThrottleRepository;
 use crate::domain::repositories::scrape_result_repository::ScrapeResultRepository;
+use crate::domain::repositories::storage_repository::{team_storage_key, StorageRepository};
+use crate::domain::repositories::task_event_repository::TaskEventRepository;
 use crate::domain::repositories::task_repository::TaskRepository;
-use crate::domain::services::extraction_service::ExtractionServiceTrait;
+use crate::domain::repositories::url_blocklist_repository::UrlBlocklistRepository;
+use crate::domain::services::bandwidth_service::BandwidthService;
+use crate::domain::services::extraction_service::{
+    ExtractionRule, ExtractionServiceTrait, TokenUsage,
+};
+use crate::domain::services::plan_service::PlanService;
+use crate::domain::services::pricing_service::PricingService;
+use crate::domain::services::result_indexer::{IndexedResult, ResultIndexer};
+use crate::domain::services::result_uploader::ResultUploader;
 use crate::domain::services::retry_handler::RetryHandler;
-use crate::domain::services::webhook_service::WebhookService;
+use crate::domain::services::team_export_service::{TeamExportService, EXPORT_CONTENT_TYPE};
+use crate::domain::services::url_blocklist_service::UrlBlocklistService;
+use crate::domain::services::webhook_service::{WebhookManagementService, WebhookService};
 use crate::utils::regex_cache::RegexCache;
 
+use crate::engines::accessibility::requests_accessibility;
+use crate::engines::auto_scroll::AutoScroll;
+use crate::engines::block_detection::{BlockedBy, BLOCKED_FAILURE_REASON};
 use crate::engines::engine_client::{
-    EngineClient, HttpMethod, PageAction, ScrapeOptions, ScrapeRequest, ScrapeResponse,
-    ScreenshotConfig, ScrollDirection,
+    ActionErrorPolicy, ContentTypeFilter, EngineClient, EngineError, HttpMethod, HttpProtocol,
+    PageAction, ScrapeOptions, ScrapeRequest, ScrapeResponse, ScreenshotConfig, ScrollDirection,
+    DEFAULT_ACTION_TIMEOUT_MS,
 };
-use crate::presentation::helpers::ssrf::is_internal_url;
+use crate::engines::har::requests_har;
+use crate::engines::host_resolve::parse_resolve_overrides;
+use crate::engines::iframe::{find_frame, IframeCapture};
+use crate::engines::mhtml::{count_resources, requests_mhtml, MHTML_CONTENT_TYPE};
+use crate::engines::pdf::requests_pdf;
+use crate::engines::proxy_url::redact_proxy_url;
+use crate::engines::resource_blocking::ResourceBlocking;
+use crate::infrastructure::metrics::{record_bandwidth_cap_rejection, record_bandwidth_downloaded};
+use crate::presentation::helpers::ssrf::{is_internal_proxy_url, is_internal_url};
 use crate::presentation::middleware::team_semaphore::TeamSemaphore;
+use crate::queue::result_stream::{CrawlStreamEvent, ResultStreamBus};
+use crate::queue::scheduler::DelayedTaskScheduler;
 use crate::queue::task_queue::TaskQueue;
 use crate::utils::crawl_text_integration::{CrawlTextIntegration, ScrapeResponseInput};
+use crate::utils::feed::{is_feed_content_type, parse_feed, Feed};
+use crate::utils::html_table::{requests_tables, tables_to_json, TableOptions};
 use crate::utils::retry_policy::RetryPolicy;
 use crate::utils::robots::RobotsCheckerTrait;
+use crate::utils::seo_audit::{audit_page, requests_seo};
+use crate::utils::sitemap::parse_sitemap;
+use crate::utils::structured_data::{extract_structured_data, requests_structured_data};
+use crate::utils::url::{canonicalize_url, registrable_domain, strip_query_params};
 use crate::workers::errors::ScrapeWorkerError;
+use crate::workers::task_lease::{LeaseOutcome, TaskLease};
+#[cfg(feature = "metrics")]
+use metrics::{counter, histogram};
+
+/// 记录单任务热路径（并发许可、robots 查找、Token 计数）上的状态操作耗时
+///
+/// 这些状态均保存在进程内（信号量、oxcache、DashMap），该指标用于在高 worker 数下
+/// 观察每任务的固定开销。
+#[cfg_attr(not(feature = "metrics"), allow(unused_variables))]
+fn record_hot_path_latency(operation: &'static str, started: Instant) {
+    #[cfg(feature = "metrics")]
+    histogram!("worker_hot_path_duration_seconds", "operation" => operation)
+        .record(started.elapsed().as_secs_f64());
+}
 
 /// 从缓存获取正则表达式
 fn get_cached_regex(pattern: &str, cache: &RegexCache) -> Result<regex::Regex, ScrapeWorkerError> {
@@ -54,6 +115,527 @@ fn get_cached_regex(pattern: &str, cache: &RegexCache) -> Result<regex::Regex, S
         .map_err(ScrapeWorkerError::RegexError)
 }
 
+/// 解析锚点的 rel 属性为小写取值列表
+fn parse_link_rel(rel: Option<&str>) -> Vec<String> {
+    rel.map(|rel| {
+        rel.split_ascii_whitespace()
+            .map(|value| value.to_ascii_lowercase())
+            .collect()
+    })
+    .unwrap_or_default()
+}
+
+/// rel 是否包含 nofollow / ugc / sponsored
+fn is_nofollow_rel(rel: &[String]) -> bool {
+    rel.iter()
+        .any(|value| NOFOLLOW_LINK_RELS.contains(&value.as_str()))
+}
+
+/// 读取页面 `<link rel="canonical">` 声明的规范 URL（规范化后），仅接受 http/https
+fn page_canonical_url(document: &Html, base_url: &Url) -> Option<Url> {
+    let selector = Selector::parse("link[rel][href]").ok()?;
+    document
+        .select(&selector)
+        .find(|element| {
+            parse_link_rel(element.value().attr("rel"))
+                .iter()
+                .any(|rel| rel == "canonical")
+        })
+        .and_then(|element| element.value().attr("href"))
+        .and_then(|href| base_url.join(href.trim()).ok())
+        .filter(|url| matches!(url.scheme(), "http" | "https"))
+        .map(|url| canonicalize_url(&url))
+}
+
+/// 按爬取配置移除被忽略的查询参数
+fn apply_query_param_rules(url: Url, config: &CrawlConfigDto) -> Url {
+    if config.ignore_all_query_params.unwrap_or(false) {
+        let mut url = url;
+        url.set_query(None);
+        return url;
+    }
+    match &config.ignore_query_params {
+        Some(names) if !names.is_empty() => strip_query_params(&url, names),
+        _ => url,
+    }
+}
+
+/// URL 去掉查询串后的部分，用于按路径统计查询参数组合
+fn url_without_query(url: &str) -> &str {
+    url.split_once('?').map_or(url, |(path, _)| path)
+}
+
+/// 将子任务 payload 中的链接来源写入结果元数据
+///
+/// 未配置提取规则时元数据只包含 `discovered_via`；提取结果为对象时追加该字段，
+/// 不覆盖同名的提取字段。
+fn with_discovered_via(meta_data: Option<Value>, payload: &Value) -> Option<Value> {
+    let Some(discovered_via) = payload.get("discovered_via").cloned() else {
+        return meta_data;
+    };
+    match meta_data {
+        None => Some(json!({ "discovered_via": discovered_via })),
+        Some(Value::Object(mut map)) => {
+            map.entry("discovered_via").or_insert(discovered_via);
+            Some(Value::Object(map))
+        }
+        other => other,
+    }
+}
+
+/// 响应体被引擎截断时在结果元数据中标记 `truncated`
+fn with_truncation_flag(meta_data: Value, truncated: bool) -> Value {
+    if !truncated {
+        return meta_data;
+    }
+    with_meta_field(meta_data, "truncated", Value::Bool(true))
+}
+
+/// 被拦截后引擎升级的路径，每一步包含 `step`，以及仍被拦截时的 `blocked_by` 或失败时的 `error`
+fn escalation_path(response: &ScrapeResponse) -> Option<Value> {
+    if response.escalations.is_empty() {
+        return None;
+    }
+    let path = response
+        .escalations
+        .iter()
+        .map(|attempt| {
+            let mut step = json!({ "step": attempt.step.as_str() });
+            if let Some(blocked_by) = attempt.blocked_by {
+                step["blocked_by"] = json!(blocked_by.as_str());
+            }
+            if let Some(error) = &attempt.error {
+                step["error"] = json!(error);
+            }
+            step
+        })
+        .collect();
+    Some(Value::Array(path))
+}
+
+/// 在结果元数据中写入一个字段，元数据为空时新建对象
+fn with_meta_field(meta_data: Value, key: &str, value: Value) -> Value {
+    match meta_data {
+        Value::Null => json!({ key: value }),
+        Value::Object(mut map) => {
+            map.insert(key.to_string(), value);
+            Value::Object(map)
+        }
+        other => other,
+    }
+}
+
+/// 由爬取配置构建内容类型过滤条件，未配置时返回 None
+fn content_type_filter(config: &CrawlConfigDto) -> Option<ContentTypeFilter> {
+    let allowed = config.allowed_content_types.clone().unwrap_or_default();
+    let excluded = config.excluded_content_types.clone().unwrap_or_default();
+    if allowed.is_empty() && excluded.is_empty() {
+        return None;
+    }
+    Some(ContentTypeFilter { allowed, excluded })
+}
+
+/// 由爬取配置构建主机名解析覆盖
+///
+/// 提交时已校验；直接入队的非法配置（如指向内网的地址）整体丢弃，按正常 DNS 解析抓取。
+fn resolve_overrides(config: &CrawlConfigDto) -> HashMap<String, IpAddr> {
+    let Some(resolve) = &config.resolve else {
+        return HashMap::new();
+    };
+    parse_resolve_overrides(resolve).unwrap_or_else(|e| {
+        warn!("Ignoring invalid crawl resolve overrides: {}", e);
+        HashMap::new()
+    })
+}
+
+/// 任务是否请求下载二进制资源：爬取任务读取 `config.download_assets`，抓取任务读取 `download`
+fn download_requested(task: &Task) -> bool {
+    let flag = match task.task_type {
+        TaskType::Crawl => task
+            .payload
+            .get("config")
+            .and_then(|config| config.get("download_assets")),
+        _ => task.payload.get("download"),
+    };
+    flag.and_then(Value::as_bool).unwrap_or(false)
+}
+
+/// 爬取任务配置的结果投递目标，以及投递成功后是否仍在本地保留完整结果
+fn result_destination(task: &Task) -> Option<(ResultDestination, bool)> {
+    if task.task_type != TaskType::Crawl {
+        return None;
+    }
+    let config = task.payload.get("config")?;
+    let destination = config.get("result_destination").filter(|v| !v.is_null())?;
+    let destination = match serde_json::from_value(destination.clone()) {
+        Ok(destination) => destination,
+        Err(e) => {
+            warn!("Invalid result destination in task {}: {}", task.id, e);
+            return None;
+        }
+    };
+    let keep_local = config
+        .get("keep_local_results")
+        .and_then(Value::as_bool)
+        .unwrap_or(true);
+    Some((destination, keep_local))
+}
+
+/// 任务请求的输出格式，爬取任务读取爬取配置中的 `formats`
+fn task_formats(task: &Task) -> Option<Vec<String>> {
+    let formats = task
+        .payload
+        .get("formats")
+        .filter(|formats| !formats.is_null())
+        .or_else(|| task.payload.get("config")?.get("formats"))?;
+    serde_json::from_value(formats.clone()).ok()
+}
+
+/// 结果的性能计时：引擎测得的各阶段耗时加上总耗时 `response_time_ms`
+fn result_performance(response: &ScrapeResponse) -> Value {
+    let mut performance = response
+        .timings
+        .as_ref()
+        .and_then(|timings| serde_json::to_value(timings).ok())
+        .unwrap_or_else(|| json!({}));
+    performance["response_time_ms"] = json!(response.response_time_ms);
+    performance
+}
+
+/// 任务请求 `seo` 格式且响应为 HTML 时，审计页面的 SEO 指标
+fn result_seo(task: &Task, response: &ScrapeResponse) -> Option<Value> {
+    let formats = task_formats(task)?;
+    if !requests_seo(Some(&formats)) || !response.content_type.to_lowercase().contains("html") {
+        return None;
+    }
+    let page_url = response.final_url.as_deref().unwrap_or(&task.url);
+    serde_json::to_value(audit_page(&response.content, page_url)).ok()
+}
+
+/// 任务请求 `structured_data` 格式且响应为 HTML 时，提取页面中的 schema.org 实体
+fn result_structured_data(task: &Task, response: &ScrapeResponse) -> Option<Value> {
+    let formats = task_formats(task)?;
+    if !requests_structured_data(Some(&formats))
+        || !response.content_type.to_lowercase().contains("html")
+    {
+        return None;
+    }
+    Some(Value::Array(extract_structured_data(
+        &response.content,
+        Some(&task.url),
+    )))
+}
+
+/// 响应内容类型为 RSS/Atom 时，解析订阅源条目
+fn result_feed(task: &Task, response: &ScrapeResponse) -> Option<Value> {
+    if !is_feed_content_type(&response.content_type) {
+        return None;
+    }
+    let feed = parse_feed(&response.content, Some(&task.url))?;
+    serde_json::to_value(feed).ok()
+}
+
+/// 任务请求 `tables` 格式且响应为 HTML 时，提取页面中的表格
+///
+/// 表格配置读取自 `options.table_options`，无效时使用默认配置。
+fn result_tables(task: &Task, response: &ScrapeResponse) -> Option<Value> {
+    let formats = task_formats(task)?;
+    if !requests_tables(Some(&formats)) || !response.content_type.to_lowercase().contains("html") {
+        return None;
+    }
+    let options: TableOptions = task
+        .payload
+        .get("options")
+        .and_then(|options| options.get("table_options"))
+        .filter(|v| !v.is_null())
+        .and_then(|v| serde_json::from_value(v.clone()).ok())
+        .unwrap_or_default();
+    Some(tables_to_json(&response.content, &options))
+}
+
+/// 大小写不敏感地读取响应头
+fn response_header(headers: &HashMap<String, String>, name: &str) -> Option<String> {
+    headers
+        .iter()
+        .find(|(key, _)| key.eq_ignore_ascii_case(name))
+        .map(|(_, value)| value.clone())
+}
+
+/// 为增量重爬请求附加上一次结果的条件请求头
+///
+/// 用户在 `headers` 中显式设置的同名请求头优先。
+fn add_conditional_headers(request: &mut ScrapeRequest, previous: &ScrapeResult) {
+    let validators = [
+        ("If-None-Match", &previous.etag),
+        ("If-Modified-Since", &previous.last_modified),
+    ];
+    for (name, value) in validators {
+        let Some(value) = value else {
+            continue;
+        };
+        let headers = &mut request.options.headers;
+        if !headers.keys().any(|key| key.eq_ignore_ascii_case(name)) {
+            headers.insert(name.to_string(), value.clone());
+        }
+    }
+}
+
+/// 链接是否在爬取配置允许的域名范围内
+///
+/// - 默认：与来源页面同一主机（忽略 `www.` 前缀）
+/// - `allow_subdomains`：同一可注册域名下的任意子域名
+/// - `allow_external_domains`：不限制
+///
+/// 来源页面本身受同样的约束，因此逐跳比较与和根 URL 比较等价。
+fn within_domain_scope(source_url: &str, url: &str, config: &CrawlConfigDto) -> bool {
+    if config.allow_external_domains.unwrap_or(false) {
+        return true;
+    }
+    let host_of = |value: &str| {
+        Url::parse(value)
+            .ok()?
+            .host_str()
+            .map(|host| host.trim_end_matches('.').to_ascii_lowercase())
+    };
+    let (Some(source), Some(target)) = (host_of(source_url), host_of(url)) else {
+        return false;
+    };
+
+    let strip_www = |host: &str| host.strip_prefix("www.").unwrap_or(host).to_string();
+    if strip_www(&source) == strip_www(&target) {
+        return true;
+    }
+    config.allow_subdomains.unwrap_or(false)
+        && registrable_domain(&source) == registrable_domain(&target)
+}
+
+/// 链接是否在爬取范围内：域名范围、包含模式（需匹配其一）与排除模式（不能匹配任何一个）
+pub(crate) fn should_crawl_url(
+    source_url: &str,
+    url: &str,
+    config: &CrawlConfigDto,
+    regex_cache: &RegexCache,
+) -> bool {
+    // 0. 检查域名范围（默认只跟随同一主机）
+    if !within_domain_scope(source_url, url, config) {
+        return false;
+    }
+
+    // 1. 检查包含模式 (如果有配置，必须匹配其中一个)
+    if let Some(includes) = &config.include_patterns {
+        let mut matched = false;
+        for pattern in includes {
+            if let Ok(re) = get_cached_regex(pattern, regex_cache) {
+                if re.is_match(url) {
+                    matched = true;
+                    break;
+                }
+            } else if url.contains(pattern) {
+                // 简单的字符串包含回退
+                matched = true;
+                break;
+            }
+        }
+        if !matched {
+            return false;
+        }
+    }
+
+    // 2. 检查排除模式 (如果有配置，不能匹配任何一个)
+    if let Some(excludes) = &config.exclude_patterns {
+        for pattern in excludes {
+            if let Ok(re) = get_cached_regex(pattern, regex_cache) {
+                if re.is_match(url) {
+                    return false;
+                }
+            } else if url.contains(pattern) {
+                return false;
+            }
+        }
+    }
+
+    true
+}
+
+/// 链接图中锚文本的最大字符数
+const MAX_ANCHOR_TEXT_CHARS: usize = 200;
+
+/// 页面中指向同一 URL 的所有链接的汇总
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct PageLink {
+    /// 所有锚点的 rel 取值（去重）
+    pub rel: Vec<String>,
+    /// 第一个非空的锚文本（图片为 alt 文本），空白折叠后截断到 `MAX_ANCHOR_TEXT_CHARS`
+    pub anchor_text: Option<String>,
+}
+
+/// 折叠空白并截断锚文本，空文本返回 `None`
+fn normalize_anchor_text(text: &str) -> Option<String> {
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    (!text.is_empty()).then(|| text.chars().take(MAX_ANCHOR_TEXT_CHARS).collect())
+}
+
+/// 解析页面中的链接，返回规范化后的 URL 到指向它的锚点 rel 取值与锚文本的映射
+///
+/// 过滤非 http/https 链接、页面自身（含 canonical 地址）以及 `accept` 拒绝的 URL
+/// （包含/排除模式、黑名单）。`config.skip_nofollow_links` 与 `download_assets` 同样生效。
+pub(crate) fn discover_page_links(
+    page_url: &str,
+    content: &str,
+    config: &CrawlConfigDto,
+    accept: impl Fn(&str) -> bool,
+) -> Result<HashMap<String, PageLink>> {
+    let skip_nofollow = config.skip_nofollow_links.unwrap_or(false);
+    // 开启资源下载时图片地址与页面链接一同入队
+    let selector = if config.download_assets.unwrap_or(false) {
+        "a, img"
+    } else {
+        "a"
+    };
+    let document = Html::parse_document(content);
+    let selector =
+        Selector::parse(selector).map_err(|e| ScrapeWorkerError::SelectorError(e.to_string()))?;
+    let base_url = Url::parse(page_url)?;
+
+    // 当前页面自身的规范形式，包括 `<link rel="canonical">` 声明的地址
+    let mut self_urls: HashSet<String> = HashSet::new();
+    self_urls.insert(canonicalize_url(&base_url).to_string());
+    if let Some(canonical) = page_canonical_url(&document, &base_url) {
+        self_urls.insert(canonical.to_string());
+    }
+
+    // URL -> 指向该 URL 的所有锚点的 rel 取值与锚文本（用于构建链接图）
+    let mut links: HashMap<String, PageLink> = HashMap::new();
+
+    for element in document.select(&selector) {
+        let target = match element.value().name() {
+            "img" => element.value().attr("src"),
+            _ => element.value().attr("href"),
+        };
+        if let Some(href) = target {
+            let rel = parse_link_rel(element.value().attr("rel"));
+            if skip_nofollow && is_nofollow_rel(&rel) {
+                continue;
+            }
+
+            // 转换相对路径为绝对路径，并规范化后再去重
+            if let Ok(absolute_url) = base_url.join(href) {
+                let url_str =
+                    apply_query_param_rules(canonicalize_url(&absolute_url), config).to_string();
+
+                // 过滤非 http/https 协议
+                if !url_str.starts_with("http") {
+                    continue;
+                }
+
+                // 过滤自身
+                if self_urls.contains(&url_str) {
+                    continue;
+                }
+
+                // 检查包含/排除模式与黑名单
+                if !accept(&url_str) {
+                    continue;
+                }
+
+                let link = links.entry(url_str).or_default();
+                for value in rel {
+                    if !link.rel.contains(&value) {
+                        link.rel.push(value);
+                    }
+                }
+                if link.anchor_text.is_none() {
+                    link.anchor_text = match element.value().name() {
+                        "img" => element.value().attr("alt").and_then(normalize_anchor_text),
+                        _ => normalize_anchor_text(&element.text().collect::<String>()),
+                    };
+                }
+            }
+        }
+    }
+    Ok(links)
+}
+
+/// 从站点地图（含嵌套的站点地图索引）中发现 URL，返回 URL 到链接来源的映射
+///
+/// 最多读取 `MAX_SITEMAP_FILES` 个站点地图、`MAX_SITEMAP_SEED_URLS` 个 URL；跳过页面自身与
+/// `accept` 拒绝的 URL。站点地图获取失败只记录日志。
+pub(crate) async fn discover_sitemap_urls(
+    engine_client: &EngineClient,
+    page_url: &str,
+    mut pending: Vec<String>,
+    config: &CrawlConfigDto,
+    accept: impl Fn(&str) -> bool,
+) -> HashMap<String, Value> {
+    let mut fetched: HashSet<String> = HashSet::new();
+    let mut links: HashMap<String, Value> = HashMap::new();
+    // 页面自身按与站点地图 URL 相同的规则规范化后再比较
+    let self_url = Url::parse(page_url)
+        .map(|parsed| apply_query_param_rules(canonicalize_url(&parsed), config).to_string())
+        .unwrap_or_else(|_| page_url.to_string());
+
+    while let Some(sitemap_url) = pending.pop() {
+        if fetched.len() >= MAX_SITEMAP_FILES || links.len() >= MAX_SITEMAP_SEED_URLS {
+            break;
+        }
+        if !fetched.insert(sitemap_url.clone()) {
+            continue;
+        }
+
+        let request = ScrapeRequest::new(&sitemap_url).with_options(
+            ScrapeOptions::builder()
+                .method(HttpMethod::Get)
+                .timeout(Duration::from_secs(10))
+                .build(),
+        );
+        let content = match engine_client.scrape(&request).await {
+            Ok(response) if response.is_success() => response.content,
+            Ok(response) => {
+                warn!(
+                    "Sitemap {} returned status {}",
+                    sitemap_url, response.status_code
+                );
+                continue;
+            }
+            Err(e) => {
+                warn!("Failed to fetch sitemap {}: {}", sitemap_url, e);
+                continue;
+            }
+        };
+
+        let document = parse_sitemap(&content);
+        pending.extend(document.sitemaps);
+        for url in document.urls {
+            if links.len() >= MAX_SITEMAP_SEED_URLS {
+                break;
+            }
+            let url = match Url::parse(&url.loc) {
+                Ok(parsed) => {
+                    apply_query_param_rules(canonicalize_url(&parsed), config).to_string()
+                }
+                Err(_) => continue,
+            };
+            if url == self_url || !accept(&url) {
+                continue;
+            }
+            links.entry(url).or_insert_with(|| {
+                json!({
+                    "source_url": sitemap_url,
+                    "sitemap": true
+                })
+            });
+        }
+    }
+
+    info!(
+        "Discovered {} URLs from {} sitemap(s) for {}",
+        links.len(),
+        fetched.len(),
+        page_url
+    );
+    links
+}
+
 /// 抓取工作者
 pub struct ScrapeWorker {
     repository: Arc<dyn TaskRepository>,
@@ -72,6 +654,21 @@ pub struct ScrapeWorker {
     retry_handler: RetryHandler,
     extraction_service: Arc<dyn ExtractionServiceTrait>,
     regex_cache: RegexCache,
+    webhook_management_service: Option<Arc<dyn WebhookManagementService>>,
+    domain_throttle_repository: Option<Arc<dyn DomainThrottleRepository>>,
+    throttle_policy: ThrottlePolicy,
+    task_event_repository: Option<Arc<dyn TaskEventRepository>>,
+    url_blocklist: UrlBlocklistService,
+    storage_repository: Option<Arc<dyn StorageRepository>>,
+    pricing: PricingService,
+    plan_service: Option<Arc<PlanService>>,
+    team_export_service: Option<Arc<TeamExportService>>,
+    delayed_scheduler: Option<Arc<DelayedTaskScheduler>>,
+    crawl_link_repository: Option<Arc<dyn CrawlLinkRepository>>,
+    result_uploader: Option<Arc<dyn ResultUploader>>,
+    result_stream: Option<Arc<dyn ResultStreamBus>>,
+    result_indexer: Option<Arc<dyn ResultIndexer>>,
+    bandwidth_service: Option<Arc<BandwidthService>>,
 }
 
 impl std::fmt::Debug for ScrapeWorker {
@@ -104,6 +701,8 @@ impl ScrapeWorker {
         // 根据任务类型选择合适的重试策略
         let retry_policy = RetryPolicy::slow(); // 网络请求适合慢速重试策略
         let retry_handler = RetryHandler::new(repository.clone(), retry_policy.clone());
+        let url_blocklist = UrlBlocklistService::new(&settings.url_blocklist.patterns);
+        let pricing = PricingService::from_settings(&settings.pricing);
 
         Self {
             repository,
@@ -122,7 +721,124 @@ impl ScrapeWorker {
             retry_handler,
             extraction_service,
             regex_cache,
-        }
+            webhook_management_service: None,
+            domain_throttle_repository: None,
+            throttle_policy: ThrottlePolicy::default(),
+            task_event_repository: None,
+            url_blocklist,
+            storage_repository: None,
+            pricing,
+            plan_service: None,
+            team_export_service: None,
+            delayed_scheduler: None,
+            crawl_link_repository: None,
+            result_uploader: None,
+            result_stream: None,
+            result_indexer: None,
+            bandwidth_service: None,
+        }
+    }
+
+    /// 注入 webhook 管理服务，用于在爬取结束时向团队 webhook 推送汇总事件
+    pub fn with_webhook_management_service(
+        mut self,
+        webhook_management_service: Arc<dyn WebhookManagementService>,
+    ) -> Self {
+        self.webhook_management_service = Some(webhook_management_service);
+        self
+    }
+
+    /// 注入域名节流仓储，启用上游 429/503 时的自适应退避
+    pub fn with_domain_throttle_repository(
+        mut self,
+        domain_throttle_repository: Arc<dyn DomainThrottleRepository>,
+    ) -> Self {
+        self.domain_throttle_repository = Some(domain_throttle_repository);
+        self
+    }
+
+    /// 注入任务事件仓储，记录引擎尝试、推迟、重试与结束事件以组装任务执行时间线
+    pub fn with_task_event_repository(
+        mut self,
+        task_event_repository: Arc<dyn TaskEventRepository>,
+    ) -> Self {
+        self.task_event_repository = Some(task_event_repository);
+        self
+    }
+
+    /// 注入 URL 黑名单仓储，展开链接时在配置模式之外同时过滤数据库中的全局与团队条目
+    pub fn with_url_blocklist_repository(
+        mut self,
+        url_blocklist_repository: Arc<dyn UrlBlocklistRepository>,
+    ) -> Self {
+        self.url_blocklist = self.url_blocklist.with_repository(url_blocklist_repository);
+        self
+    }
+
+    /// 注入对象存储仓储，下载模式下保存图片、PDF 等二进制响应
+    pub fn with_storage_repository(
+        mut self,
+        storage_repository: Arc<dyn StorageRepository>,
+    ) -> Self {
+        self.storage_repository = Some(storage_repository);
+        self
+    }
+
+    /// 注入团队套餐服务，按团队套餐的并发上限限制同时执行的任务数
+    pub fn with_plan_service(mut self, plan_service: Arc<PlanService>) -> Self {
+        self.plan_service = Some(plan_service);
+        self
+    }
+
+    /// 注入团队数据导出服务，执行 `export` 类型的任务
+    pub fn with_team_export_service(mut self, team_export_service: Arc<TeamExportService>) -> Self {
+        self.team_export_service = Some(team_export_service);
+        self
+    }
+
+    /// 替换积分价格服务（默认按启动配置的 `[pricing]` 加载）
+    pub fn with_pricing_service(mut self, pricing: PricingService) -> Self {
+        self.pricing = pricing;
+        self
+    }
+
+    /// 注入延迟任务调度器，推迟与重试的任务到期时立即唤醒空闲 Worker
+    pub fn with_delayed_scheduler(mut self, scheduler: Arc<DelayedTaskScheduler>) -> Self {
+        self.delayed_scheduler = Some(scheduler);
+        self
+    }
+
+    /// 注入爬取链接仓储，展开链接时记录页面之间的链接以导出站点链接图
+    pub fn with_crawl_link_repository(
+        mut self,
+        crawl_link_repository: Arc<dyn CrawlLinkRepository>,
+    ) -> Self {
+        self.crawl_link_repository = Some(crawl_link_repository);
+        self
+    }
+
+    /// 注入结果投递上传器，将爬取结果上传到爬取配置的 `result_destination`
+    pub fn with_result_uploader(mut self, result_uploader: Arc<dyn ResultUploader>) -> Self {
+        self.result_uploader = Some(result_uploader);
+        self
+    }
+
+    /// 注入结果推送总线，保存爬取结果与爬取结束时通知跟随结果的客户端
+    pub fn with_result_stream(mut self, result_stream: Arc<dyn ResultStreamBus>) -> Self {
+        self.result_stream = Some(result_stream);
+        self
+    }
+
+    /// 注入外部检索索引，保存结果后写入 OpenSearch 等检索集群
+    pub fn with_result_indexer(mut self, result_indexer: Arc<dyn ResultIndexer>) -> Self {
+        self.result_indexer = Some(result_indexer);
+        self
+    }
+
+    /// 注入流量统计服务，累计团队与任务的下载流量并按每日上限拒绝任务
+    pub fn with_bandwidth_service(mut self, bandwidth_service: Arc<BandwidthService>) -> Self {
+        self.bandwidth_service = Some(bandwidth_service);
+        self
     }
 
     /// 运行抓取工作器
@@ -133,7 +849,7 @@ impl ScrapeWorker {
             match self.process_next_task(&queue).await {
                 Ok(processed) => {
                     if !processed {
-                        sleep(Duration::from_secs(1)).await;
+                        self.wait_for_work().await;
                     }
                 }
                 Err(e) => {
@@ -144,6 +860,21 @@ impl ScrapeWorker {
         }
     }
 
+    /// 队列为空时等待：有延迟任务被提升时立即返回，最多等待 1 秒
+    async fn wait_for_work(&self) {
+        match &self.delayed_scheduler {
+            Some(scheduler) => scheduler.wait_for_promotion(Duration::from_secs(1)).await,
+            None => sleep(Duration::from_secs(1)).await,
+        }
+    }
+
+    /// 将带 `scheduled_at` 重新排队的任务交给延迟任务调度器
+    async fn schedule_delayed(&self, task: &Task) {
+        if let (Some(scheduler), Some(due_at)) = (&self.delayed_scheduler, task.scheduled_at) {
+            scheduler.schedule(task.id, due_at).await;
+        }
+    }
+
     async fn process_next_task(&self, queue: &dyn TaskQueue) -> Result<bool> {
         let task_opt = queue.dequeue(self.worker_id).await?;
 
@@ -155,8 +886,22 @@ impl ScrapeWorker {
         Ok(false)
     }
 
+    /// 任务锁时长，与领取任务时加锁的时长一致
+    fn lock_duration(&self) -> Duration {
+        Duration::from_secs(
+            self.settings
+                .concurrency
+                .task_lock_duration_seconds
+                .max(1)
+                .unsigned_abs(),
+        )
+    }
+
     fn acquire_concurrency_permit(&self, task: &Task) -> Option<OwnedSemaphorePermit> {
-        self.team_semaphore.try_acquire(task.team_id)
+        let started = Instant::now();
+        let permit = self.team_semaphore.try_acquire(task.team_id);
+        record_hot_path_latency("concurrency_permit", started);
+        permit
     }
 
     async fn process_task(&self, mut task: Task) -> Result<()> {
@@ -170,7 +915,7 @@ impl ScrapeWorker {
         if let Some(expires_at) = task.expires_at {
             if Utc::now() > expires_at {
                 warn!("Task {} expired at {}", task.id, expires_at);
-                self.repository.mark_failed(task.id).await?;
+                self.mark_task_failed(&task, "Task expired").await?;
                 // Trigger failure webhook if needed
                 self.trigger_webhook(&task, Some("Task expired".to_string()))
                     .await;
@@ -178,8 +923,40 @@ impl ScrapeWorker {
             }
         }
 
+        // 团队当日下载量达到上限时直接失败，次日（UTC）提交的任务恢复执行
+        if let Some(cap) = self.bandwidth_cap_exceeded(&task).await {
+            let reason = self.fail_over_bandwidth_cap(&task, cap).await?;
+            self.trigger_webhook(&task, Some(reason)).await;
+            return Ok(());
+        }
+
+        // 域名自适应节流：目标站点处于退避期内时推迟任务，不占用并发许可
+        if let Some(throttled_until) = self.domain_throttled_until(&task).await {
+            debug!(
+                "Domain throttled, deferring task {} until {}",
+                task.id, throttled_until
+            );
+            #[cfg(feature = "metrics")]
+            counter!("domain_throttle_deferred_tasks_total").increment(1);
+            task.scheduled_at = Some(throttled_until);
+            task.status = TaskStatus::Queued;
+            self.repository.update(&task).await?;
+            self.schedule_delayed(&task).await;
+            self.record_task_event(
+                self.task_event(&task, TaskEventType::Deferred)
+                    .with_message(format!("Domain throttled until {}", throttled_until)),
+            )
+            .await;
+            return Ok(());
+        }
+
         // Concurrency Check (Layer 2: Team Semaphore)
         // The permit is held for the duration of task processing and auto-releases on drop.
+        if let Some(plan_service) = &self.plan_service {
+            let limits = plan_service.limits_for(task.team_id).await;
+            self.team_semaphore
+                .set_team_permits(task.team_id, limits.max_concurrency as usize);
+        }
         let _permit = match self.acquire_concurrency_permit(&task) {
             Some(p) => p,
             None => {
@@ -192,19 +969,52 @@ impl ScrapeWorker {
                 task.scheduled_at = Some(Utc::now() + chrono::Duration::seconds(30));
                 task.status = TaskStatus::Queued;
                 self.repository.update(&task).await?;
+                self.schedule_delayed(&task).await;
+                self.record_task_event(
+                    self.task_event(&task, TaskEventType::Deferred)
+                        .with_message("Team concurrency limit exceeded"),
+                )
+                .await;
                 return Ok(());
             }
         };
 
         let task_type = task.task_type;
 
+        // Renew the task lock while processing; a lost lease means another worker
+        // has picked the task up, so this execution is dropped instead of finishing twice.
+        let lease = TaskLease::new(
+            self.repository.clone(),
+            &task,
+            self.worker_id,
+            self.lock_duration(),
+        );
+        let lease_lost_event = self.task_event(&task, TaskEventType::LeaseLost);
+
         // Take task by value only for the specific branch that needs it
         // This avoids 3 unnecessary clones in the match
-        let result = match task_type.as_str() {
-            "scrape" => self.process_scrape_task(task).await,
-            "crawl" => self.process_crawl_task(task).await,
-            "extract" => self.process_extract_task(task).await,
-            _ => return Err(anyhow::anyhow!("Unknown task type: {}", task_type)),
+        let processing = async {
+            match task_type.as_str() {
+                "scrape" => self.process_scrape_task(task).await,
+                "crawl" => self.process_crawl_task(task).await,
+                "extract" => self.process_extract_task(task).await,
+                "export" => self.process_export_task(task).await,
+                "feed" => self.process_feed_task(task).await,
+                _ => Err(anyhow::anyhow!("Unknown task type: {}", task_type)),
+            }
+        };
+        let result = match lease.run(processing).await {
+            LeaseOutcome::Completed(result) => result,
+            LeaseOutcome::Lost => {
+                warn!(
+                    "Lost lock on task {}, aborting stale execution",
+                    lease_lost_event.task_id
+                );
+                #[cfg(feature = "metrics")]
+                counter!("task_lease_lost_total").increment(1);
+                self.record_task_event(lease_lost_event).await;
+                return Ok(());
+            }
         };
 
         // _permit auto-releases here when it goes out of scope
@@ -222,33 +1032,51 @@ impl ScrapeWorker {
         debug!("task_id: {}", task.id);
 
         // Resolve engine router directly to handle actions if they exist
-        let scrape_request = Self::build_scrape_request(&task).unwrap_or_else(|e| {
+        let mut scrape_request = Self::build_scrape_request(&task).unwrap_or_else(|e| {
             error!("Failed to parse task payload, using default: {}", e);
             ScrapeRequest::new(task.url.clone()).timeout(Duration::from_secs(
                 self.settings.timeouts.engines.default_timeout_seconds,
             ))
         });
 
+        // 团队被禁用的引擎在任何档位下都不参与路由
+        scrape_request.options.excluded_engines = self
+            .settings
+            .engines
+            .excluded_engines_for(&task.team_id.to_string());
+
         // SSRF 防护 (CWE-918)：静态校验 options.proxy 不指向内部网络（防御纵深）。
         // handler 层已通过 validate_url 完成完整 DNS 解析校验，
         // 此处仅用静态检查拦截直接入队的恶意任务（如 private IP / localhost），不依赖网络。
         if let Some(ref proxy_url) = scrape_request.options.proxy {
-            if is_internal_url(proxy_url) {
+            if is_internal_proxy_url(proxy_url) {
                 warn!(
                     "SSRF via proxy blocked in worker proxy={} task_id={} team_id={}",
-                    proxy_url, task.id, task.team_id
+                    redact_proxy_url(proxy_url),
+                    task.id,
+                    task.team_id
                 );
-                self.repository.mark_failed(task.id).await?;
+                self.mark_task_failed(&task, "SSRF via proxy blocked")
+                    .await?;
                 return Ok(());
             }
         }
 
+        let started = Instant::now();
         let response = self.engine_client.scrape(&scrape_request).await;
+        self.record_engine_attempt(&task, started, &response).await;
 
         match response {
             Ok(response) => {
                 debug!("status_code: {}", response.status_code);
                 info!("Scrape successful, status: {}", response.status_code);
+                self.record_domain_throttle(&task.url, &response).await;
+
+                if let Some(blocked_by) = response.blocked_by {
+                    let reason = self.fail_blocked_task(&task, &response, blocked_by).await?;
+                    self.trigger_webhook(&task, Some(reason)).await;
+                    return Ok(());
+                }
 
                 // Map ScrapeResponse to ScrapeResult
                 // _result variable is currently unused but might be used later or for debugging
@@ -264,6 +1092,8 @@ impl ScrapeWorker {
                     screenshot: response.screenshot.clone(),
                     response_time_ms: response.response_time_ms as i64,
                     created_at: Utc::now().naive_utc(),
+                    etag: None,
+                    last_modified: None,
                 };
 
                 if let Err(e) = self.handle_scrape_success(&task, &response).await {
@@ -305,6 +1135,11 @@ impl ScrapeWorker {
                         }
                         t.payload = payload;
                         self.repository.update(&t).await?;
+                        self.record_task_event(
+                            self.task_event(&t, TaskEventType::Failed)
+                                .with_message(e.to_string()),
+                        )
+                        .await;
                     }
                 } else {
                     self.handle_failure(&mut task).await?;
@@ -317,19 +1152,88 @@ impl ScrapeWorker {
         }
     }
 
-    /// 解析 Crawl 任务特定的 Payload
-    async fn parse_crawl_payload(&self, task: &Task) -> Result<(Uuid, u32, CrawlConfigDto)> {
-        let payload = &task.payload;
-        let crawl_id = match payload.get("crawl_id").and_then(|v| v.as_str()) {
-            Some(id) => Uuid::parse_str(id).unwrap_or_default(),
-            None => {
-                return Err(anyhow::anyhow!("Missing crawl_id in task payload"));
+    /// 查询任务目标域名的节流截止时间，未节流或查询失败时返回 `None`
+    async fn domain_throttled_until(&self, task: &Task) -> Option<chrono::DateTime<Utc>> {
+        let repository = self.domain_throttle_repository.as_ref()?;
+        let domain = DomainThrottle::domain_of(&task.url)?;
+        match repository.find_by_domain(&domain).await {
+            Ok(Some(throttle)) if throttle.is_active(Utc::now()) => Some(throttle.throttled_until),
+            Ok(_) => None,
+            Err(e) => {
+                warn!("Failed to load domain throttle for {}: {}", domain, e);
+                None
             }
+        }
+    }
+
+    /// 上游返回 429/503 时记录域名节流状态，按指数退避延长节流窗口
+    ///
+    /// 节流状态存储在数据库中，所有 worker 与 API 进程共享；写入失败只记录日志。
+    async fn record_domain_throttle(&self, url: &str, response: &ScrapeResponse) {
+        if !DomainThrottle::is_throttle_status(response.status_code) {
+            return;
+        }
+        let Some(repository) = self.domain_throttle_repository.as_ref() else {
+            return;
+        };
+        let Some(domain) = DomainThrottle::domain_of(url) else {
+            return;
         };
 
-        let depth = payload.get("depth").and_then(|v| v.as_u64()).unwrap_or(0) as u32;
-        let config: CrawlConfigDto =
-            serde_json::from_value(payload.get("config").cloned().unwrap_or(json!({})))?;
+        let now = Utc::now();
+        let retry_after = response
+            .headers
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case("retry-after"))
+            .and_then(|(_, value)| parse_retry_after(value, now));
+
+        let mut throttle = match repository.find_by_domain(&domain).await {
+            Ok(existing) => existing.unwrap_or_else(|| DomainThrottle::new(domain.clone())),
+            Err(e) => {
+                warn!("Failed to load domain throttle for {}: {}", domain, e);
+                return;
+            }
+        };
+        let delay = throttle.record_error(
+            response.status_code,
+            retry_after,
+            &self.throttle_policy,
+            now,
+        );
+
+        #[cfg(feature = "metrics")]
+        counter!(
+            "domain_throttle_events_total",
+            "status" => response.status_code.to_string()
+        )
+        .increment(1);
+
+        warn!(
+            "Upstream {} returned {}, throttling domain for {}s (consecutive errors: {})",
+            domain,
+            response.status_code,
+            delay.num_seconds(),
+            throttle.consecutive_errors
+        );
+
+        if let Err(e) = repository.upsert(&throttle).await {
+            warn!("Failed to persist domain throttle for {}: {}", domain, e);
+        }
+    }
+
+    /// 解析 Crawl 任务特定的 Payload
+    async fn parse_crawl_payload(&self, task: &Task) -> Result<(Uuid, u32, CrawlConfigDto)> {
+        let payload = &task.payload;
+        let crawl_id = match payload.get("crawl_id").and_then(|v| v.as_str()) {
+            Some(id) => Uuid::parse_str(id).unwrap_or_default(),
+            None => {
+                return Err(anyhow::anyhow!("Missing crawl_id in task payload"));
+            }
+        };
+
+        let depth = payload.get("depth").and_then(|v| v.as_u64()).unwrap_or(0) as u32;
+        let config: CrawlConfigDto =
+            serde_json::from_value(payload.get("config").cloned().unwrap_or(json!({})))?;
 
         Ok((crawl_id, depth, config))
     }
@@ -338,22 +1242,30 @@ impl ScrapeWorker {
     async fn check_robots_txt(&self, task: &Task) -> bool {
         let user_agent = "crawlrs-bot";
 
-        if !self
+        // 允许判定与 crawl-delay 共用同一份缓存的 robots.txt，只在此处统计查找耗时，
+        // 不把 crawl-delay 的休眠计入热路径延迟
+        let started = Instant::now();
+        let allowed = self
             .robots_checker
             .is_allowed(&task.url, user_agent)
             .await
-            .unwrap_or(true)
-        {
+            .unwrap_or(true);
+        let crawl_delay = if allowed {
+            self.robots_checker
+                .get_crawl_delay(&task.url, user_agent)
+                .await
+                .unwrap_or(None)
+        } else {
+            None
+        };
+        record_hot_path_latency("robots_lookup", started);
+
+        if !allowed {
             info!("Access denied by robots.txt for {}", task.url);
             return false;
         }
 
-        if let Some(delay) = self
-            .robots_checker
-            .get_crawl_delay(&task.url, user_agent)
-            .await
-            .unwrap_or(None)
-        {
+        if let Some(delay) = crawl_delay {
             info!("Respecting crawl delay of {:?} for {}", delay, task.url);
             sleep(delay).await;
         }
@@ -367,14 +1279,28 @@ impl ScrapeWorker {
             Ok(result) => result,
             Err(e) => {
                 error!("Failed to parse crawl payload: {}", e);
-                self.repository.mark_failed(task.id).await?;
+                self.mark_task_failed(&task, "Invalid crawl payload")
+                    .await?;
                 return Ok(());
             }
         };
 
-        // 2. Robots.txt Check
-        if !self.check_robots_txt(&task).await {
-            self.repository.mark_failed(task.id).await?;
+        // 1.5 时间预算：超出 max_duration_seconds 后不再执行任务
+        if config.max_duration_seconds.is_some()
+            && self.crawl_time_budget_exhausted(&task, crawl_id).await
+        {
+            return Ok(());
+        }
+
+        // 2. Robots.txt Check（ignore_robots 已在提交时校验团队能力并写入审计日志）
+        if config.ignore_robots == Some(true) {
+            warn!(
+                "Ignoring robots.txt for {} task_id={} crawl_id={} team_id={}",
+                task.url, task.id, crawl_id, task.team_id
+            );
+        } else if !self.check_robots_txt(&task).await {
+            self.mark_task_failed(&task, "Disallowed by robots.txt")
+                .await?;
             return Ok(());
         }
 
@@ -382,24 +1308,55 @@ impl ScrapeWorker {
         // handler 层已通过 validate_url 完成完整 DNS 解析校验，
         // 此处仅用静态检查拦截直接入队的恶意任务（如 private IP / localhost），不依赖网络。
         if let Some(ref proxy_url) = config.proxy {
-            if is_internal_url(proxy_url) {
+            if is_internal_proxy_url(proxy_url) {
                 warn!(
                     "SSRF via proxy blocked in worker proxy={} task_id={} team_id={}",
-                    proxy_url, task.id, task.team_id
+                    redact_proxy_url(proxy_url),
+                    task.id,
+                    task.team_id
                 );
-                self.repository.mark_failed(task.id).await?;
+                self.mark_task_failed(&task, "SSRF via proxy blocked")
+                    .await?;
                 return Ok(());
             }
         }
 
-        // 3. 构建并执行抓取请求
-        let request = self.build_crawl_request(&task, &config);
+        // 3. 构建并执行抓取请求（增量重爬时附带上一次爬取的缓存校验值）
+        let previous = self.previous_crawl_result(&task, &config).await;
+        let mut request = self.build_crawl_request(&task, &config);
+        if let Some(previous) = &previous {
+            add_conditional_headers(&mut request, previous);
+        }
+        let started = Instant::now();
         let response = self.engine_client.scrape(&request).await;
+        self.record_engine_attempt(&task, started, &response).await;
 
         // 4. 处理结果
         match response {
             Ok(response) => {
-                self.handle_crawl_success(&task, response, crawl_id, depth, &config, &request)
+                self.record_domain_throttle(&task.url, &response).await;
+                if let Some(blocked_by) = response.blocked_by {
+                    return self
+                        .handle_crawl_blocked(&task, &response, blocked_by, crawl_id)
+                        .await;
+                }
+                match previous {
+                    Some(previous) if response.status_code == 304 => {
+                        self.handle_crawl_not_modified(
+                            &task, response, previous, crawl_id, depth, &config, &request,
+                        )
+                        .await
+                    }
+                    _ => {
+                        self.handle_crawl_success(
+                            &task, response, crawl_id, depth, &config, &request,
+                        )
+                        .await
+                    }
+                }
+            }
+            Err(EngineError::ContentTypeRejected(content_type)) => {
+                self.handle_crawl_content_type_skipped(&task, crawl_id, &content_type)
                     .await
             }
             Err(e) => {
@@ -437,6 +1394,22 @@ impl ScrapeWorker {
             use_fire_engine: false,
             actions: Vec::new(),
             sync_wait_ms: 0,
+            content_type_filter: content_type_filter(config),
+            http_protocol: HttpProtocol::Auto,
+            tls_profile: None,
+            capture_har: false,
+            capture_mhtml: false,
+            pdf: None,
+            resource_blocking: None,
+            action_error_policies: Vec::new(),
+            iframe_capture: None,
+            flatten_shadow_dom: false,
+            auto_scroll: None,
+            accessibility: None,
+            engine_tier: None,
+            excluded_engines: Vec::new(),
+            resolve: resolve_overrides(config),
+            address_family: None,
         })
     }
 
@@ -455,6 +1428,14 @@ impl ScrapeWorker {
             task.url, response.status_code
         );
 
+        // 未在读取响应头时中止的引擎（如浏览器引擎）在此补充过滤
+        if content_type_filter(config).is_some_and(|filter| !filter.accepts(&response.content_type))
+        {
+            return self
+                .handle_crawl_content_type_skipped(task, crawl_id, &response.content_type)
+                .await;
+        }
+
         // 文本编码处理
         let processed_content = match self.process_text_encoding(task, &response).await {
             Ok(content) => content,
@@ -474,18 +1455,154 @@ impl ScrapeWorker {
             .extract_data_with_rules(task, &processed_response, config)
             .await;
 
-        // 保存结果
-        self.save_result(task, &processed_response, extracted_data)
+        // 保存结果（附带链接来源与 rel，便于构建链接图）
+        let meta_data = with_discovered_via(extracted_data, &task.payload);
+        self.save_result(task, &processed_response, meta_data)
             .await?;
 
-        // 如果深度未达上限，解析链接并生成子任务
+        // 如果深度未达上限，解析链接并生成子任务；根任务额外从站点地图播种
         if depth < config.max_depth {
+            if depth == 0 && !config.ignore_sitemap.unwrap_or(false) {
+                self.seed_from_sitemaps(task, crawl_id, config).await?;
+            }
             self.extract_and_queue_links(task, &processed_response, crawl_id, depth, config)
                 .await?;
         }
 
+        self.complete_crawl_step(
+            task,
+            crawl_id,
+            processed_response.screenshot.is_some(),
+            request,
+        )
+        .await
+    }
+
+    /// 读取上一次爬取中同一 URL 的结果，用于增量重爬的条件请求
+    ///
+    /// 结果没有 ETag / Last-Modified 时无法发起条件请求，按普通抓取处理。
+    async fn previous_crawl_result(
+        &self,
+        task: &Task,
+        config: &CrawlConfigDto,
+    ) -> Option<ScrapeResult> {
+        let previous_crawl_id = config.previous_crawl_id?;
+        match self
+            .result_repository
+            .find_latest_for_crawl_url(previous_crawl_id, &task.url)
+            .await
+        {
+            Ok(result) => result.filter(|r| r.etag.is_some() || r.last_modified.is_some()),
+            Err(e) => {
+                warn!(
+                    "Failed to load previous result for {} in crawl {}: {}",
+                    task.url, previous_crawl_id, e
+                );
+                None
+            }
+        }
+    }
+
+    /// 处理增量重爬的 304 Not Modified 响应
+    ///
+    /// 不重新保存正文，只记录一条带校验值的 304 结果供下一次增量重爬使用；
+    /// 子链接从上一次保存的正文中解析，未变更页面下的新页面仍会被发现。
+    #[allow(clippy::too_many_arguments)]
+    async fn handle_crawl_not_modified(
+        &self,
+        task: &Task,
+        response: ScrapeResponse,
+        previous: ScrapeResult,
+        crawl_id: Uuid,
+        depth: u32,
+        config: &CrawlConfigDto,
+        request: &ScrapeRequest,
+    ) -> Result<()> {
+        info!(
+            "Crawl step unchanged since previous crawl, url: {}",
+            task.url
+        );
+
+        // 正文所在的任务：上一次结果本身也是 304 时沿用它记录的来源
+        let content_task_id = previous
+            .meta_data
+            .get("content_task_id")
+            .and_then(Value::as_str)
+            .and_then(|id| Uuid::parse_str(id).ok())
+            .unwrap_or(previous.task_id);
+
+        let meta_data = with_discovered_via(
+            Some(json!({ "unchanged": true, "content_task_id": content_task_id })),
+            &task.payload,
+        )
+        .unwrap_or(Value::Null);
+        let result = ScrapeResult {
+            id: Uuid::new_v4(),
+            task_id: task.id,
+            url: task.url.clone(),
+            status_code: response.status_code as i32,
+            content: String::new(),
+            content_type: previous.content_type.clone(),
+            headers: serde_json::to_value(&response.headers).unwrap_or(Value::Null),
+            meta_data,
+            screenshot: None,
+            response_time_ms: response.response_time_ms as i64,
+            created_at: Utc::now().naive_utc(),
+            etag: response_header(&response.headers, "etag").or(previous.etag.clone()),
+            last_modified: response_header(&response.headers, "last-modified")
+                .or(previous.last_modified.clone()),
+        };
+        let result_id = result.id;
+        self.result_repository.save(result).await?;
+        self.publish_result(task, result_id).await;
+
+        if depth < config.max_depth {
+            if depth == 0 && !config.ignore_sitemap.unwrap_or(false) {
+                self.seed_from_sitemaps(task, crawl_id, config).await?;
+            }
+            let content = if content_task_id == previous.task_id {
+                previous.content
+            } else {
+                match self
+                    .result_repository
+                    .find_by_task_id(content_task_id)
+                    .await
+                {
+                    Ok(result) => result.map(|r| r.content).unwrap_or_default(),
+                    Err(e) => {
+                        warn!(
+                            "Failed to load stored content of task {}: {}",
+                            content_task_id, e
+                        );
+                        String::new()
+                    }
+                }
+            };
+            let stored_response = ScrapeResponse {
+                content,
+                content_type: previous.content_type,
+                ..response
+            };
+            self.extract_and_queue_links(task, &stored_response, crawl_id, depth, config)
+                .await?;
+        }
+
+        self.complete_crawl_step(task, crawl_id, false, request)
+            .await
+    }
+
+    /// 标记 Crawl 子任务完成并更新爬取统计与计费
+    async fn complete_crawl_step(
+        &self,
+        task: &Task,
+        crawl_id: Uuid,
+        has_screenshot: bool,
+        request: &ScrapeRequest,
+    ) -> Result<()> {
         // 更新任务状态和 Crawl 统计
         self.repository.mark_completed(task.id).await?;
+        self.record_task_event(self.task_event(task, TaskEventType::Completed))
+            .await;
         if let Err(e) = self
             .crawl_repository
             .increment_completed_tasks(crawl_id)
@@ -504,7 +1621,7 @@ impl ScrapeWorker {
         self.deduct_feature_credits(
             task.team_id,
             task.id,
-            processed_response.screenshot.is_some(),
+            has_screenshot,
             request.options.proxy.is_some(),
         )
         .await;
@@ -512,6 +1629,37 @@ impl ScrapeWorker {
         Ok(())
     }
 
+    /// 响应内容类型被爬取配置过滤时结束任务：不保存结果、不扣除费用
+    async fn handle_crawl_content_type_skipped(
+        &self,
+        task: &Task,
+        crawl_id: Uuid,
+        content_type: &str,
+    ) -> Result<()> {
+        info!(
+            "Skipping {} with filtered content type {} in crawl {}",
+            task.url, content_type, crawl_id
+        );
+        self.repository.mark_completed(task.id).await?;
+        self.record_task_event(
+            self.task_event(task, TaskEventType::Completed)
+                .with_message(format!("Skipped content type {}", content_type)),
+        )
+        .await;
+        if let Err(e) = self
+            .crawl_repository
+            .increment_completed_tasks(crawl_id)
+            .await
+        {
+            error!(
+                "Failed to increment completed tasks for crawl {}: {}",
+                crawl_id, e
+            );
+        }
+        self.update_crawl_completion_status(crawl_id).await;
+        Ok(())
+    }
+
     /// 使用配置的规则提取数据
     async fn extract_data_with_rules(
         &self,
@@ -581,25 +1729,64 @@ impl ScrapeWorker {
         Ok(())
     }
 
+    /// 处理 Crawl 中被反爬拦截的页面：任务失败并计入爬取失败数，不再解析链接
+    async fn handle_crawl_blocked(
+        &self,
+        task: &Task,
+        response: &ScrapeResponse,
+        blocked_by: BlockedBy,
+        crawl_id: Uuid,
+    ) -> Result<()> {
+        let reason = self.fail_blocked_task(task, response, blocked_by).await?;
+
+        if let Err(e) = self.crawl_repository.increment_failed_tasks(crawl_id).await {
+            error!(
+                "Failed to increment failed tasks for crawl {}: {}",
+                crawl_id, e
+            );
+        }
+        self.update_crawl_completion_status(crawl_id).await;
+        self.trigger_webhook(task, Some(reason)).await;
+        Ok(())
+    }
+
     /// 更新 Crawl 完成状态（检查是否所有任务都已完成）
     async fn update_crawl_completion_status(&self, crawl_id: Uuid) {
         match self.crawl_repository.find_by_id(crawl_id).await {
-            Ok(Some(c)) => {
+            Ok(Some(mut c)) => {
                 if c.completed_tasks() + c.failed_tasks() == c.total_tasks() {
-                    info!(
-                        "All tasks completed for crawl {}, marking as completed",
-                        crawl_id
-                    );
-                    if let Err(e) = self
-                        .crawl_repository
-                        .update_status(crawl_id, CrawlStatus::Completed)
-                        .await
-                    {
-                        error!(
-                            "Failed to update crawl status to completed for crawl {}: {}",
-                            crawl_id, e
-                        );
+                    if c.is_finished() {
+                        debug!("Crawl {} already finished as {}", crawl_id, c.status);
+                        return;
+                    }
+                    // 页面预算用尽时以 completed_with_limit 结束
+                    let status = if c.page_limit_reached() {
+                        CrawlStatus::CompletedWithLimit
+                    } else {
+                        CrawlStatus::Completed
+                    };
+                    // 条件更新保证并发完成最后任务的 Worker 中只有一个发送汇总
+                    match self.crawl_repository.mark_finished(crawl_id, status).await {
+                        Ok(true) => {
+                            info!(
+                                "All tasks completed for crawl {}, marked as {}",
+                                crawl_id, status
+                            );
+                        }
+                        Ok(false) => {
+                            debug!("Crawl {} was finished by another worker", crawl_id);
+                            return;
+                        }
+                        Err(e) => {
+                            error!(
+                                "Failed to update crawl status to {} for crawl {}: {}",
+                                status, crawl_id, e
+                            );
+                            return;
+                        }
                     }
+                    c.status = status;
+                    self.emit_crawl_summary(c).await;
                 }
             }
             Ok(None) => {
@@ -611,6 +1798,166 @@ impl ScrapeWorker {
         }
     }
 
+    /// 检查爬取的时间预算，返回 true 表示当前任务不再执行
+    ///
+    /// 首个发现超时的任务负责停止整个爬取；爬取已因预算结束后仍被取到的残留任务
+    /// 直接标记为取消。
+    async fn crawl_time_budget_exhausted(&self, task: &Task, crawl_id: Uuid) -> bool {
+        let crawl = match self.crawl_repository.find_by_id(crawl_id).await {
+            Ok(Some(crawl)) => crawl,
+            Ok(None) => return false,
+            Err(e) => {
+                warn!("Failed to check time budget for crawl {}: {}", crawl_id, e);
+                return false;
+            }
+        };
+
+        if crawl.status == CrawlStatus::CompletedWithLimit {
+            if let Err(e) = self.repository.mark_cancelled(task.id).await {
+                error!("Failed to cancel task {} after crawl limit: {}", task.id, e);
+            }
+            return true;
+        }
+        if crawl.is_finished() || !crawl.duration_exceeded(Utc::now()) {
+            return false;
+        }
+
+        info!(
+            "Crawl {} exceeded max_duration_seconds, stopping remaining tasks",
+            crawl_id
+        );
+        self.finish_crawl_with_limit(crawl).await;
+        true
+    }
+
+    /// 预算耗尽：取消剩余任务并将爬取标记为 completed_with_limit
+    async fn finish_crawl_with_limit(&self, mut crawl: Crawl) {
+        match self.repository.cancel_tasks_by_crawl_id(crawl.id).await {
+            Ok(cancelled) => info!(
+                "Cancelled {} remaining task(s) for crawl {}",
+                cancelled, crawl.id
+            ),
+            Err(e) => error!(
+                "Failed to cancel remaining tasks for crawl {}: {}",
+                crawl.id, e
+            ),
+        }
+
+        match self
+            .crawl_repository
+            .mark_finished(crawl.id, CrawlStatus::CompletedWithLimit)
+            .await
+        {
+            Ok(true) => {}
+            Ok(false) => return,
+            Err(e) => {
+                error!(
+                    "Failed to mark crawl {} as completed_with_limit: {}",
+                    crawl.id, e
+                );
+                return;
+            }
+        }
+        crawl.complete_with_limit();
+        self.emit_crawl_summary(crawl).await;
+    }
+
+    /// 通知跟随结果的客户端爬取已结束，并向团队 webhook 推送 `crawl.summary` 汇总事件
+    ///
+    /// 推送与汇总失败只记录日志，不影响爬取状态。
+    async fn emit_crawl_summary(&self, crawl: Crawl) {
+        if let Some(result_stream) = &self.result_stream {
+            let event = CrawlStreamEvent::Finished {
+                status: crawl.status,
+            };
+            if let Err(e) = result_stream.publish(crawl.id, &event).await {
+                warn!("Failed to publish completion of crawl {}: {}", crawl.id, e);
+            }
+        }
+
+        let management = match &self.webhook_management_service {
+            Some(management) => management,
+            None => return,
+        };
+
+        let task_ids = match self.repository.find_by_crawl_id(crawl.id).await {
+            Ok(tasks) => tasks.iter().map(|t| t.id).collect::<Vec<_>>(),
+            Err(e) => {
+                warn!("Failed to list tasks for crawl {}: {}", crawl.id, e);
+                Vec::new()
+            }
+        };
+        let credits_consumed = self.crawl_credits_consumed(&crawl, &task_ids).await;
+        let unchanged_pages = self.crawl_unchanged_pages(&crawl, &task_ids).await;
+        let response_times = self
+            .result_repository
+            .response_times(&task_ids)
+            .await
+            .unwrap_or_else(|e| {
+                warn!(
+                    "Failed to load response times for crawl {}: {}",
+                    crawl.id, e
+                );
+                Vec::new()
+            });
+        let summary = crawl
+            .summary(Utc::now(), credits_consumed)
+            .with_unchanged_pages(unchanged_pages)
+            .with_latency(response_times);
+        let payload = match serde_json::to_value(&summary) {
+            Ok(payload) => payload,
+            Err(e) => {
+                error!("Failed to serialize summary for crawl {}: {}", crawl.id, e);
+                return;
+            }
+        };
+
+        match management
+            .dispatch_event(crawl.team_id, WebhookEventType::CrawlSummary, payload)
+            .await
+        {
+            Ok(delivered) => info!(
+                "Dispatched summary for crawl {} to {} webhook(s)",
+                crawl.id, delivered
+            ),
+            Err(e) => error!("Failed to dispatch summary for crawl {}: {}", crawl.id, e),
+        }
+    }
+
+    /// 统计爬取消耗的积分：创建时的固定费用 + 子任务的额外扣费
+    async fn crawl_credits_consumed(&self, crawl: &Crawl, task_ids: &[Uuid]) -> i64 {
+        let mut reference_ids = task_ids.to_vec();
+        reference_ids.push(crawl.id);
+
+        let task_credits = self
+            .credits_repository
+            .sum_deducted_for_references(crawl.team_id, &reference_ids)
+            .await
+            .unwrap_or_else(|e| {
+                warn!("Failed to sum credits for crawl {}: {}", crawl.id, e);
+                0
+            });
+
+        self.pricing.pricing_for(crawl.team_id).crawl + task_credits
+    }
+
+    /// 统计增量重爬中未变更（304）的页面数，非增量爬取恒为 0
+    async fn crawl_unchanged_pages(&self, crawl: &Crawl, task_ids: &[Uuid]) -> i32 {
+        if crawl.previous_crawl_id().is_none() {
+            return 0;
+        }
+        match self.result_repository.count_not_modified(task_ids).await {
+            Ok(count) => count.min(i32::MAX as u64) as i32,
+            Err(e) => {
+                warn!(
+                    "Failed to count unchanged pages for crawl {}: {}",
+                    crawl.id, e
+                );
+                0
+            }
+        }
+    }
+
     /// 解析 Extract 任务特定的 Payload
     async fn parse_extract_payload(&self, task: &Task) -> Result<(ExtractRequestDto, String)> {
         let payload: ExtractRequestDto = serde_json::from_value(task.payload.clone())
@@ -638,6 +1985,22 @@ impl ScrapeWorker {
             use_fire_engine: false,
             actions: vec![],
             sync_wait_ms: 0,
+            content_type_filter: None,
+            http_protocol: HttpProtocol::Auto,
+            tls_profile: None,
+            capture_har: false,
+            capture_mhtml: false,
+            pdf: None,
+            resource_blocking: None,
+            action_error_policies: Vec::new(),
+            iframe_capture: None,
+            flatten_shadow_dom: false,
+            auto_scroll: None,
+            accessibility: None,
+            engine_tier: None,
+            excluded_engines: Vec::new(),
+            resolve: HashMap::new(),
+            address_family: None,
         })
     }
 
@@ -693,22 +2056,79 @@ impl ScrapeWorker {
             .await
     }
 
-    /// 处理基于规则的提取
-    async fn handle_rules_extraction(
+    /// 执行提取规则
+    ///
+    /// 指定了 `frame` 的规则作用于对应 iframe 的 HTML，其余规则作用于页面内容；
+    /// 找不到对应 frame 的规则结果为 null。
+    async fn extract_rules(
         &self,
-        task: &mut Task,
         response: &ScrapeResponse,
-        rules: &HashMap<String, crate::domain::services::extraction_service::ExtractionRule>,
+        rules: &HashMap<String, ExtractionRule>,
         url: &str,
-    ) -> Result<()> {
-        debug!("rules: {:?}", rules);
+    ) -> Result<(Value, TokenUsage)> {
+        if rules.values().all(|rule| rule.frame.is_none()) {
+            return self
+                .extraction_service
+                .extract(&response.content, rules, Some(url))
+                .await;
+        }
 
-        let (extracted_data, usage) = self
-            .extraction_service
-            .extract(&response.content, rules, Some(url))
-            .await?;
+        let (frame_rules, page_rules): (HashMap<_, _>, HashMap<_, _>) = rules
+            .iter()
+            .map(|(name, rule)| (name.clone(), rule.clone()))
+            .partition(|(_, rule)| rule.frame.is_some());
 
-        self.deduct_token_credits(
+        let (mut data, mut usage) = if page_rules.is_empty() {
+            (json!({}), TokenUsage::default())
+        } else {
+            self.extraction_service
+                .extract(&response.content, &page_rules, Some(url))
+                .await?
+        };
+
+        for (name, rule) in frame_rules {
+            let target = rule.frame.clone().unwrap_or_default();
+            let value = match find_frame(&response.frames, &target) {
+                Some(frame) => {
+                    let single = HashMap::from([(name.clone(), rule)]);
+                    let (frame_data, frame_usage) = self
+                        .extraction_service
+                        .extract(&frame.html, &single, Some(&frame.url))
+                        .await?;
+                    usage.prompt_tokens += frame_usage.prompt_tokens;
+                    usage.completion_tokens += frame_usage.completion_tokens;
+                    usage.total_tokens += frame_usage.total_tokens;
+                    frame_data.get(&name).cloned().unwrap_or(Value::Null)
+                }
+                None => {
+                    warn!(
+                        "Extraction rule '{}' targets unknown frame '{}'",
+                        name, target
+                    );
+                    Value::Null
+                }
+            };
+            if let Some(obj) = data.as_object_mut() {
+                obj.insert(name, value);
+            }
+        }
+
+        Ok((data, usage))
+    }
+
+    /// 处理基于规则的提取
+    async fn handle_rules_extraction(
+        &self,
+        task: &mut Task,
+        response: &ScrapeResponse,
+        rules: &HashMap<String, crate::domain::services::extraction_service::ExtractionRule>,
+        url: &str,
+    ) -> Result<()> {
+        debug!("rules: {:?}", rules);
+
+        let (extracted_data, usage) = self.extract_rules(response, rules, url).await?;
+
+        self.deduct_token_credits(
             task.team_id,
             task.id,
             &usage,
@@ -738,6 +2158,7 @@ impl ScrapeWorker {
                 use_llm: Some(true),
                 llm_prompt: Some(prompt),
                 output_format: None,
+                frame: None,
             },
         );
 
@@ -789,6 +2210,7 @@ impl ScrapeWorker {
         let meta_data = extracted_data
             .map(|data| json!({ "extracted_data": data }))
             .unwrap_or(json!({}));
+        let meta_data = with_truncation_flag(meta_data, response.truncated);
 
         let scrape_result = ScrapeResult {
             id: Uuid::new_v4(),
@@ -802,18 +2224,291 @@ impl ScrapeWorker {
             screenshot: None,
             response_time_ms: 0,
             created_at: Utc::now().naive_utc(),
+            etag: None,
+            last_modified: None,
         };
 
         self.result_repository.save(scrape_result).await?;
 
         task.status = TaskStatus::Completed;
         self.repository.update(task).await?;
+        self.record_task_event(self.task_event(task, TaskEventType::Completed))
+            .await;
 
         self.trigger_webhook(task, None).await;
 
         Ok(())
     }
 
+    /// 执行团队数据导出任务
+    ///
+    /// 归档写入团队存储后保存一条结果（`meta_data.export` 记录下载链接），并向订阅了
+    /// `export.completed` 的团队 webhook 推送下载链接；导出失败时任务直接失败并推送
+    /// `export.failed`，由用户重新发起导出。
+    async fn process_export_task(&self, mut task: Task) -> Result<()> {
+        info!(
+            "Processing export task {} for team {}",
+            task.id, task.team_id
+        );
+
+        let exported = match &self.team_export_service {
+            Some(service) => service.export(task.team_id, task.id).await,
+            None => Err(anyhow::anyhow!("Data export is not configured")),
+        };
+        let export = match exported {
+            Ok(export) => export,
+            Err(e) => {
+                error!("Export {} failed: {:#}", task.id, e);
+                self.mark_task_failed(&task, &e.to_string()).await?;
+                self.dispatch_export_event(
+                    &task,
+                    WebhookEventType::ExportFailed,
+                    json!({ "error": e.to_string() }),
+                )
+                .await;
+                return Ok(());
+            }
+        };
+
+        let result = ScrapeResult {
+            id: Uuid::new_v4(),
+            task_id: task.id,
+            url: task.url.clone(),
+            status_code: 200,
+            content: String::new(),
+            content_type: EXPORT_CONTENT_TYPE.to_string(),
+            headers: json!({}),
+            meta_data: json!({ "export": export }),
+            screenshot: None,
+            response_time_ms: 0,
+            created_at: Utc::now().naive_utc(),
+            etag: None,
+            last_modified: None,
+        };
+        self.result_repository.save(result).await?;
+
+        task.status = TaskStatus::Completed;
+        self.repository.update(&task).await?;
+        self.record_task_event(self.task_event(&task, TaskEventType::Completed))
+            .await;
+
+        self.dispatch_export_event(
+            &task,
+            WebhookEventType::ExportCompleted,
+            json!({
+                "download_url": export.download_url,
+                "size": export.size,
+                "sha256": export.sha256,
+                "counts": export.counts,
+            }),
+        )
+        .await;
+        Ok(())
+    }
+
+    /// 处理订阅源任务：抓取并解析 RSS/Atom，条目写入结果的 `meta_data.feed`
+    ///
+    /// 请求 `enqueue_items` 时将条目链接作为抓取任务入队，入队的任务 ID 写入
+    /// `meta_data.enqueued_task_ids`。
+    async fn process_feed_task(&self, mut task: Task) -> Result<()> {
+        info!("Processing feed task {} for {}", task.id, task.url);
+
+        let request: FeedRequestDto = serde_json::from_value(task.payload.clone())
+            .context("Failed to parse feed task payload")?;
+        let scrape_request = ScrapeRequest::new(request.url.clone()).timeout(Duration::from_secs(
+            self.settings.timeouts.engines.default_timeout_seconds,
+        ));
+
+        let started = Instant::now();
+        let response = self.engine_client.scrape(&scrape_request).await;
+        self.record_engine_attempt(&task, started, &response).await;
+        let response = match response {
+            Ok(response) if response.is_success() => response,
+            Ok(response) => {
+                warn!(
+                    "Feed {} returned status {}",
+                    request.url, response.status_code
+                );
+                self.handle_failure(&mut task).await?;
+                return Ok(());
+            }
+            Err(e) => {
+                error!("Failed to fetch feed {}: {}", request.url, e);
+                self.handle_failure(&mut task).await?;
+                return Ok(());
+            }
+        };
+
+        let Some(feed) = parse_feed(&response.content, Some(&request.url)) else {
+            self.mark_task_failed(&task, "Response is not an RSS or Atom feed")
+                .await?;
+            return Ok(());
+        };
+
+        let enqueued = if request.enqueue_items {
+            self.enqueue_feed_items(&task, &request, &feed).await?
+        } else {
+            Vec::new()
+        };
+        info!(
+            "Feed {} has {} items, enqueued {} scrape tasks",
+            request.url,
+            feed.items.len(),
+            enqueued.len()
+        );
+
+        let result = ScrapeResult {
+            id: Uuid::new_v4(),
+            task_id: task.id,
+            url: task.url.clone(),
+            status_code: response.status_code as i32,
+            content: response.content.clone(),
+            content_type: response.content_type.clone(),
+            headers: serde_json::to_value(&response.headers).unwrap_or(Value::Null),
+            meta_data: json!({ "feed": feed, "enqueued_task_ids": enqueued }),
+            screenshot: None,
+            response_time_ms: response.response_time_ms as i64,
+            created_at: Utc::now().naive_utc(),
+            etag: None,
+            last_modified: None,
+        };
+        self.result_repository.save(result).await?;
+
+        task.status = TaskStatus::Completed;
+        self.repository.update(&task).await?;
+        self.record_task_event(self.task_event(&task, TaskEventType::Completed))
+            .await;
+        Ok(())
+    }
+
+    /// 将订阅源条目链接作为抓取任务入队，返回创建的任务 ID
+    ///
+    /// 跳过重复、内网和黑名单中的链接，以及早于 `published_after` 的条目；
+    /// 每个条目按抓取价格扣费，积分不足时停止入队。
+    async fn enqueue_feed_items(
+        &self,
+        task: &Task,
+        request: &FeedRequestDto,
+        feed: &Feed,
+    ) -> Result<Vec<Uuid>> {
+        let blocklist = self.url_blocklist.blocklist_for_team(task.team_id).await?;
+        let cost = self.pricing.pricing_for(task.team_id).scrape;
+        let mut seen = HashSet::new();
+        let mut task_ids = Vec::new();
+
+        for item in &feed.items {
+            if task_ids.len() >= request.max_items() {
+                break;
+            }
+            let Some(link) = item.link.as_deref() else {
+                continue;
+            };
+            if let Some(after) = request.published_after {
+                let published = item
+                    .published
+                    .as_deref()
+                    .and_then(|published| DateTime::parse_from_rfc3339(published).ok());
+                if published.is_none_or(|published| published <= after) {
+                    continue;
+                }
+            }
+            let url = match Url::parse(link) {
+                Ok(url) if matches!(url.scheme(), "http" | "https") => url.to_string(),
+                _ => continue,
+            };
+            if is_internal_url(&url) || blocklist.find_match(&url).is_some() {
+                debug!("Skipping feed item {} of feed {}", url, task.id);
+                continue;
+            }
+            if !seen.insert(url.clone()) {
+                continue;
+            }
+
+            if cost > 0 {
+                if let Err(e) = self
+                    .credits_repository
+                    .deduct_credits(
+                        task.team_id,
+                        cost,
+                        crate::domain::models::CreditsTransactionType::Scrape,
+                        format!("Scrape feed item {} from feed {}", url, task.id),
+                        Some(task.id),
+                    )
+                    .await
+                {
+                    warn!("Stopped enqueueing items of feed {}: {}", task.id, e);
+                    break;
+                }
+            }
+
+            let now = Utc::now();
+            let item_task = Task {
+                id: Uuid::new_v4(),
+                task_type: TaskType::Scrape,
+                status: TaskStatus::Queued,
+                priority: task.priority,
+                team_id: task.team_id,
+                api_key_id: task.api_key_id,
+                url: url.clone(),
+                payload: json!({
+                    "url": url,
+                    "formats": request.item_formats,
+                    "metadata": { "feed_id": task.id }
+                }),
+                retry_count: 0,
+                attempt_count: 0,
+                max_retries: 3,
+                scheduled_at: None,
+                created_at: now,
+                started_at: None,
+                completed_at: None,
+                crawl_id: None,
+                updated_at: now,
+                lock_token: None,
+                lock_expires_at: None,
+                expires_at: None,
+            };
+            self.repository.create(&item_task).await?;
+            task_ids.push(item_task.id);
+        }
+
+        Ok(task_ids)
+    }
+
+    /// 向团队 webhook 推送导出事件，推送失败只记录日志
+    async fn dispatch_export_event(
+        &self,
+        task: &Task,
+        event_type: WebhookEventType,
+        mut payload: Value,
+    ) {
+        let Some(management) = &self.webhook_management_service else {
+            warn!(
+                "No webhook management service configured, {} for export {} not delivered",
+                event_type, task.id
+            );
+            return;
+        };
+
+        payload["event"] = json!(event_type.to_string());
+        payload["export_id"] = json!(task.id);
+        payload["team_id"] = json!(task.team_id);
+        payload["timestamp"] = json!(Utc::now().to_rfc3339());
+        match management
+            .dispatch_event(task.team_id, event_type.clone(), payload)
+            .await
+        {
+            Ok(delivered) => info!(
+                "Dispatched {} for export {} to {} webhook(s)",
+                event_type, task.id, delivered
+            ),
+            Err(e) => error!(
+                "Failed to dispatch {} for export {}: {}",
+                event_type, task.id, e
+            ),
+        }
+    }
+
     async fn extract_and_queue_links(
         &self,
         task: &Task,
@@ -827,57 +2522,189 @@ impl ScrapeWorker {
             return Ok(());
         }
 
-        let unique_links = {
-            let document = Html::parse_document(&response.content);
-            let selector = Selector::parse("a")
-                .map_err(|e| ScrapeWorkerError::SelectorError(e.to_string()))?;
-            let base_url = Url::parse(&task.url)?;
+        let blocklist = self.url_blocklist.blocklist_for_team(task.team_id).await?;
 
-            let mut links = HashSet::new();
+        let unique_links = discover_page_links(&task.url, &response.content, config, |url| {
+            if !self.should_crawl(&task.url, url, config) {
+                return false;
+            }
+            // 过滤黑名单中的 URL
+            if let Some(entry) = blocklist.find_match(url) {
+                debug!(
+                    "Skipping blocked link {} (pattern '{}')",
+                    url, entry.pattern
+                );
+                return false;
+            }
+            true
+        })?;
 
-            for element in document.select(&selector) {
-                if let Some(href) = element.value().attr("href") {
-                    // 转换相对路径为绝对路径
-                    if let Ok(absolute_url) = base_url.join(href) {
-                        let url_str = absolute_url.to_string();
+        info!("Found {} unique links on {}", unique_links.len(), task.url);
+        self.record_crawl_links(task, crawl_id, &unique_links).await;
+
+        let links = unique_links
+            .into_iter()
+            .map(|(link, page_link)| {
+                let discovered_via = json!({
+                    "source_url": task.url,
+                    "rel": page_link.rel
+                });
+                (link, discovered_via)
+            })
+            .collect();
+        self.queue_crawl_links(task, links, crawl_id, current_depth + 1, config)
+            .await
+    }
 
-                        // 过滤非 http/https 协议
-                        if !url_str.starts_with("http") {
-                            continue;
-                        }
+    /// 记录页面到所发现链接的边，未注入仓储时为空操作，写入失败不影响链接入队
+    async fn record_crawl_links(
+        &self,
+        task: &Task,
+        crawl_id: Uuid,
+        links: &HashMap<String, PageLink>,
+    ) {
+        let Some(repository) = self.crawl_link_repository.as_ref() else {
+            return;
+        };
+        if links.is_empty() {
+            return;
+        }
+        let edges: Vec<CrawlLink> = links
+            .iter()
+            .map(|(target_url, link)| {
+                CrawlLink::new(
+                    crawl_id,
+                    task.url.clone(),
+                    target_url.clone(),
+                    link.anchor_text.clone(),
+                    link.rel.clone(),
+                )
+            })
+            .collect();
+        if let Err(e) = repository.record(&edges).await {
+            warn!(
+                "Failed to record {} links of {} for crawl {}: {}",
+                edges.len(),
+                task.url,
+                crawl_id,
+                e
+            );
+        }
+    }
 
-                        // 过滤自身
-                        if url_str == task.url {
-                            continue;
-                        }
+    /// 从 robots.txt 声明的站点地图中发现 URL，作为根任务的子任务入队
+    ///
+    /// 站点地图获取或解析失败只记录日志，不影响当前任务。
+    async fn seed_from_sitemaps(
+        &self,
+        task: &Task,
+        crawl_id: Uuid,
+        config: &CrawlConfigDto,
+    ) -> Result<()> {
+        let pending = match self.robots_checker.get_sitemaps(&task.url).await {
+            Ok(sitemaps) => sitemaps,
+            Err(e) => {
+                warn!(
+                    "Failed to read sitemaps from robots.txt for {}: {}",
+                    task.url, e
+                );
+                return Ok(());
+            }
+        };
+        if pending.is_empty() {
+            return Ok(());
+        }
 
-                        // 检查包含/排除模式
-                        if !self.should_crawl(&url_str, config) {
-                            continue;
-                        }
+        let blocklist = self.url_blocklist.blocklist_for_team(task.team_id).await?;
+        let links = discover_sitemap_urls(&self.engine_client, &task.url, pending, config, |url| {
+            self.should_crawl(&task.url, url, config) && !blocklist.is_blocked(url)
+        })
+        .await;
+        if links.is_empty() {
+            return Ok(());
+        }
+        self.queue_crawl_links(task, links, crawl_id, 1, config)
+            .await
+    }
 
-                        links.insert(url_str);
-                    }
+    /// 为未抓取过的 URL 创建子任务
+    ///
+    /// `links` 为 URL 到链接来源（写入子任务 payload 的 `discovered_via`）的映射。
+    /// 配置了预算时，超出时间预算后不再入队，页面预算只允许入队剩余额度内的 URL
+    /// （多个 worker 并发入队时为尽力而为）。查询参数组合上限同样按爬取内已有任务尽力而为地统计。
+    async fn queue_crawl_links(
+        &self,
+        task: &Task,
+        links: HashMap<String, Value>,
+        crawl_id: Uuid,
+        depth: u32,
+        config: &CrawlConfigDto,
+    ) -> Result<()> {
+        let mut remaining_pages: Option<usize> = None;
+        if config.limit.is_some() || config.max_duration_seconds.is_some() {
+            if let Some(crawl) = self.crawl_repository.find_by_id(crawl_id).await? {
+                if crawl.duration_exceeded(Utc::now()) {
+                    info!(
+                        "Crawl {} exceeded its time budget, not queueing new links",
+                        crawl_id
+                    );
+                    return Ok(());
                 }
+                remaining_pages = crawl.remaining_page_budget();
             }
-            links
-        };
-
-        info!("Found {} unique links on {}", unique_links.len(), task.url);
+        }
 
         // 使用批量查询优化 N+1 问题
-        // unique_links 是 HashSet<String>，需要转换为 Vec<String>
-        let links_vec: Vec<String> = unique_links.iter().cloned().collect();
+        let links_vec: Vec<String> = links.keys().cloned().collect();
         let existing_urls = self.repository.find_existing_urls(&links_vec).await?;
-        let existing_url_set: std::collections::HashSet<String> =
-            existing_urls.into_iter().collect();
+        let existing_url_set: HashSet<String> = existing_urls.into_iter().collect();
+
+        // 每个路径已跟踪的查询参数组合数（仅在配置了上限且存在带查询串的链接时统计）
+        let mut query_variants: HashMap<String, usize> = HashMap::new();
+        let max_query_variants = config.max_query_variants_per_path.map(|max| max as usize);
+        if max_query_variants.is_some() && links.keys().any(|link| link.contains('?')) {
+            for existing in self.repository.find_by_crawl_id(crawl_id).await? {
+                if existing.url.contains('?') {
+                    *query_variants
+                        .entry(url_without_query(&existing.url).to_string())
+                        .or_default() += 1;
+                }
+            }
+        }
 
-        for link in unique_links.iter() {
+        for (link, discovered_via) in links {
             // 检查是否已经抓取过 (去重)
-            if existing_url_set.contains(link) {
+            if existing_url_set.contains(&link) {
                 continue;
             }
 
+            // 同一路径的查询参数组合达到上限后不再跟踪新的组合
+            if let (Some(max), true) = (max_query_variants, link.contains('?')) {
+                let count = query_variants
+                    .entry(url_without_query(&link).to_string())
+                    .or_default();
+                if *count >= max {
+                    debug!(
+                        "Skipping {}: query variant limit reached for its path",
+                        link
+                    );
+                    continue;
+                }
+                *count += 1;
+            }
+
+            // 页面预算用尽后停止入队
+            if let Some(remaining) = remaining_pages.as_mut() {
+                if *remaining == 0 {
+                    info!(
+                        "Crawl {} reached its page limit, not queueing further links",
+                        crawl_id
+                    );
+                    break;
+                }
+                *remaining -= 1;
+            }
+
             // Re-construct with strategy adjustment
             let mut priority = task.priority;
             if let Some(strategy) = &config.strategy {
@@ -893,11 +2720,12 @@ impl ScrapeWorker {
                 priority,
                 team_id: task.team_id,
                 api_key_id: task.api_key_id,
-                url: link.to_string(),
+                url: link,
                 payload: json!({
                     "crawl_id": crawl_id.to_string(),
-                    "depth": current_depth + 1,
-                    "config": config
+                    "depth": depth,
+                    "config": config,
+                    "discovered_via": discovered_via
                 }),
                 retry_count: 0,
                 attempt_count: 0,
@@ -922,41 +2750,8 @@ impl ScrapeWorker {
         Ok(())
     }
 
-    fn should_crawl(&self, url: &str, config: &CrawlConfigDto) -> bool {
-        // 1. 检查包含模式 (如果有配置，必须匹配其中一个)
-        if let Some(includes) = &config.include_patterns {
-            let mut matched = false;
-            for pattern in includes {
-                if let Ok(re) = get_cached_regex(pattern, &self.regex_cache) {
-                    if re.is_match(url) {
-                        matched = true;
-                        break;
-                    }
-                } else if url.contains(pattern) {
-                    // 简单的字符串包含回退
-                    matched = true;
-                    break;
-                }
-            }
-            if !matched {
-                return false;
-            }
-        }
-
-        // 2. 检查排除模式 (如果有配置，不能匹配任何一个)
-        if let Some(excludes) = &config.exclude_patterns {
-            for pattern in excludes {
-                if let Ok(re) = get_cached_regex(pattern, &self.regex_cache) {
-                    if re.is_match(url) {
-                        return false;
-                    }
-                } else if url.contains(pattern) {
-                    return false;
-                }
-            }
-        }
-
-        true
+    fn should_crawl(&self, source_url: &str, url: &str, config: &CrawlConfigDto) -> bool {
+        should_crawl_url(source_url, url, config, &self.regex_cache)
     }
 
     async fn handle_scrape_success(&self, task: &Task, response: &ScrapeResponse) -> Result<()> {
@@ -987,48 +2782,19 @@ impl ScrapeWorker {
         if let Ok(req) = serde_json::from_value::<ScrapeRequestDto>(task.payload.clone()) {
             if let Some(rules) = &req.extraction_rules {
                 match self
-                    .extraction_service
-                    .extract(&processed_response.content, rules, Some(&task.url))
+                    .extract_rules(&processed_response, rules, &task.url)
                     .await
                 {
                     Ok((data, usage)) => {
                         extracted_data = Some(data);
                         // Record usage (PRD-334: Tokens Billing)
-                        if usage.total_tokens > 0 {
-                            // 1. Record in-memory for real-time tracking
-                            self.token_usage
-                                .entry(task.team_id)
-                                .or_insert_with(|| AtomicI64::new(0))
-                                .fetch_add(usage.total_tokens as i64, Ordering::Relaxed);
-
-                            // 2. Convert to credits and deduct from database
-                            // Rate: 10 credits per 1000 tokens, minimum 1 credit for any usage
-                            let credits_to_deduct =
-                                std::cmp::max(1, (usage.total_tokens as i64 * 10 + 999) / 1000);
-                            if credits_to_deduct > 0 {
-                                if let Err(e) = self
-                                    .credits_repository
-                                    .deduct_credits(
-                                        task.team_id,
-                                        credits_to_deduct,
-                                        crate::domain::models::CreditsTransactionType::Extract,
-                                        format!(
-                                            "Tokens used for extraction ({} tokens)",
-                                            usage.total_tokens
-                                        ),
-                                        Some(task.id),
-                                    )
-                                    .await
-                                {
-                                    error!("Failed to deduct credits for token usage: {}", e);
-                                } else {
-                                    info!(
-                                        "Deducted {} credits for {} tokens for team {}",
-                                        credits_to_deduct, usage.total_tokens, task.team_id
-                                    );
-                                }
-                            }
-                        }
+                        self.deduct_token_credits(
+                            task.team_id,
+                            task.id,
+                            &usage,
+                            "Tokens used for extraction",
+                        )
+                        .await;
                     }
                     Err(e) => {
                         error!("Extraction failed for url {}: {}", task.url, e);
@@ -1041,6 +2807,8 @@ impl ScrapeWorker {
             .await?;
         debug!("task_id: {}, About to mark task as completed", task.id);
         self.repository.mark_completed(task.id).await?;
+        self.record_task_event(self.task_event(task, TaskEventType::Completed))
+            .await;
         debug!(
             "task_id: {}, Successfully marked task as completed",
             task.id
@@ -1118,14 +2886,71 @@ impl ScrapeWorker {
         if let Some(data) = extra_data {
             meta_data = data;
         }
-
-        // Content and screenshot from response
-        let content_to_store = response.content.clone();
-        let _screenshot_to_store = response.screenshot.clone();
-
-        // Create result entity
-        let result = ScrapeResult {
-            id: Uuid::new_v4(),
+        let mut meta_data = with_truncation_flag(meta_data, response.truncated);
+        if let Some(http_version) = &response.http_version {
+            meta_data = with_meta_field(meta_data, "http_version", json!(http_version));
+        }
+        if let Some(ip_family) = response.ip_family {
+            meta_data = with_meta_field(meta_data, "ip_family", json!(ip_family.as_str()));
+        }
+        if let Some(tls_profile) = &response.tls_profile {
+            meta_data = with_meta_field(meta_data, "tls_profile", json!(tls_profile));
+        }
+        if let Some(blocked) = response.blocked_requests {
+            meta_data = with_meta_field(meta_data, "blocked_requests", json!(blocked));
+        }
+        if let Some(path) = escalation_path(response) {
+            meta_data = with_meta_field(meta_data, "escalation_path", path);
+        }
+        if !response.evaluate_results.is_empty() {
+            meta_data = with_meta_field(
+                meta_data,
+                "evaluate_results",
+                json!(response.evaluate_results),
+            );
+        }
+        if !response.frames.is_empty() {
+            meta_data = with_meta_field(meta_data, "frames", json!(response.frames));
+        }
+        if let Some(report) = &response.accessibility {
+            meta_data = with_meta_field(meta_data, "accessibility", json!(report));
+        }
+        meta_data = with_meta_field(meta_data, "performance", result_performance(response));
+        if let Some(tables) = result_tables(task, response) {
+            meta_data = with_meta_field(meta_data, "tables", tables);
+        }
+        if let Some(entities) = result_structured_data(task, response) {
+            meta_data = with_meta_field(meta_data, "structured_data", entities);
+        }
+        if let Some(feed) = result_feed(task, response) {
+            meta_data = with_meta_field(meta_data, "feed", feed);
+        }
+        if let Some(seo) = result_seo(task, response) {
+            meta_data = with_meta_field(meta_data, "seo", seo);
+        }
+
+        // Content and screenshot from response
+        let mut content_to_store = response.content.clone();
+
+        // 下载模式下二进制响应写入对象存储，结果中只保留存储地址与校验信息
+        if let Some(asset) = self.store_asset(task, response).await? {
+            meta_data = with_meta_field(meta_data, "asset", asset);
+            content_to_store = String::new();
+        }
+        if let Some(har) = self.store_har(task, response).await? {
+            meta_data = with_meta_field(meta_data, "har", har);
+        }
+        if let Some(mhtml) = self.store_mhtml(task, response).await? {
+            meta_data = with_meta_field(meta_data, "mhtml", mhtml);
+        }
+        if let Some(pdf) = self.store_pdf(task, response).await? {
+            meta_data = with_meta_field(meta_data, "pdf", pdf);
+        }
+        let _screenshot_to_store = response.screenshot.clone();
+
+        // Create result entity
+        let mut result = ScrapeResult {
+            id: Uuid::new_v4(),
             task_id: task.id,
             url: task.url.clone(),
             status_code: response.status_code as i32,
@@ -1136,12 +2961,248 @@ impl ScrapeWorker {
             screenshot: response.screenshot.clone(),
             response_time_ms: response.response_time_ms as i64,
             created_at: Utc::now().naive_utc(),
+            etag: response_header(&response.headers, "etag"),
+            last_modified: response_header(&response.headers, "last-modified"),
         };
 
+        // 爬取配置了投递目标时上传结果；投递成功且不保留本地副本时只保存元数据
+        if let Some((destination, keep_local)) = result_destination(task) {
+            let mut delivery = self.deliver_result(task, &destination, &result).await;
+            let delivered = delivery["status"] == "delivered";
+            let local_copy = keep_local || !delivered;
+            if !local_copy {
+                result.content = String::new();
+                result.screenshot = None;
+            }
+            delivery["local_copy"] = json!(local_copy);
+            result.meta_data = with_meta_field(result.meta_data, "delivery", delivery);
+        }
+
+        let result_id = result.id;
+        let indexed = self.result_indexer.as_ref().map(|_| result.clone());
         self.result_repository.save(result).await?;
+        self.publish_result(task, result_id).await;
+        if let Some(result) = indexed {
+            self.index_result(task, result).await;
+        }
         Ok(())
     }
 
+    /// 将保存的结果写入外部检索索引，写入失败只记录日志
+    async fn index_result(&self, task: &Task, result: ScrapeResult) {
+        let Some(indexer) = self.result_indexer.as_ref() else {
+            return;
+        };
+        let indexed = IndexedResult {
+            result,
+            team_id: task.team_id,
+            crawl_id: task.crawl_id,
+        };
+        if let Err(e) = indexer.index(std::slice::from_ref(&indexed)).await {
+            warn!("Failed to index result of task {}: {}", task.id, e);
+        }
+    }
+
+    /// 通知跟随爬取结果的客户端有新结果保存，推送失败只记录日志
+    async fn publish_result(&self, task: &Task, result_id: Uuid) {
+        let (Some(result_stream), Some(crawl_id)) = (&self.result_stream, task.crawl_id) else {
+            return;
+        };
+        let event = CrawlStreamEvent::Result {
+            task_id: task.id,
+            result_id,
+        };
+        if let Err(e) = result_stream.publish(crawl_id, &event).await {
+            warn!("Failed to publish result of task {}: {}", task.id, e);
+        }
+    }
+
+    /// 将结果以 JSON 上传到投递目标的 `{crawl_id}/{task_id}.json`
+    ///
+    /// 返回写入结果元数据的 `delivery` 字段。上传失败不影响本地保存，失败原因记录在
+    /// `error` 中，结果仍保留完整的本地副本。
+    async fn deliver_result(
+        &self,
+        task: &Task,
+        destination: &ResultDestination,
+        result: &ScrapeResult,
+    ) -> Value {
+        let crawl_id = task.crawl_id.unwrap_or_default();
+        let name = ResultDestination::result_object_name(crawl_id, task.id);
+        let Some(uploader) = self.result_uploader.as_ref() else {
+            return json!({
+                "status": "failed",
+                "error": "Result delivery is not configured on this worker",
+            });
+        };
+        let body = match serde_json::to_vec(result) {
+            Ok(body) => body,
+            Err(e) => {
+                return json!({
+                    "status": "failed",
+                    "error": format!("Failed to serialize result: {}", e),
+                })
+            }
+        };
+        let size = body.len();
+
+        match uploader
+            .upload(destination, &name, body, RESULT_OBJECT_CONTENT_TYPE)
+            .await
+        {
+            Ok(location) => {
+                debug!("Delivered result of task {} to {}", task.id, location);
+                json!({
+                    "status": "delivered",
+                    "location": location,
+                    "size": size,
+                })
+            }
+            Err(e) => {
+                warn!(
+                    "Failed to deliver result of task {} to {}: {}",
+                    task.id,
+                    destination.location(&name),
+                    e
+                );
+                json!({
+                    "status": "failed",
+                    "location": destination.location(&name),
+                    "error": e.to_string(),
+                })
+            }
+        }
+    }
+
+    /// 下载模式下将二进制响应保存到对象存储
+    ///
+    /// 对象保存在团队命名空间下，以 sha256 命名，相同内容只保存一份。返回写入结果元数据的
+    /// `asset` 字段；未请求下载、响应为文本或未配置存储时返回 None。
+    async fn store_asset(&self, task: &Task, response: &ScrapeResponse) -> Result<Option<Value>> {
+        let Some(bytes) = response.raw_content.as_ref() else {
+            return Ok(None);
+        };
+        if !download_requested(task) {
+            return Ok(None);
+        }
+        let Some(storage) = self.storage_repository.as_ref() else {
+            warn!(
+                "Download requested for task {} but no storage repository is configured",
+                task.id
+            );
+            return Ok(None);
+        };
+
+        let checksum = hex::encode(Sha256::digest(bytes));
+        let key = team_storage_key(task.team_id, &checksum);
+        let storage_url = storage
+            .put(task.team_id, &checksum, bytes, &response.content_type)
+            .await
+            .with_context(|| format!("Failed to store asset for task {}", task.id))?;
+
+        Ok(Some(json!({
+            "storage_url": storage_url,
+            "storage_key": key,
+            "content_type": response.content_type,
+            "size": bytes.len(),
+            "sha256": checksum,
+        })))
+    }
+
+    /// 将浏览器引擎记录的 HAR 文档保存到对象存储
+    ///
+    /// 对象保存在团队命名空间下的 `har/{task_id}.har`。返回写入结果元数据的 `har` 字段；
+    /// 响应不含 HAR 或未配置存储时返回 None。
+    async fn store_har(&self, task: &Task, response: &ScrapeResponse) -> Result<Option<Value>> {
+        let Some(har) = response.har.as_ref() else {
+            return Ok(None);
+        };
+        let Some(storage) = self.storage_repository.as_ref() else {
+            warn!(
+                "HAR captured for task {} but no storage repository is configured",
+                task.id
+            );
+            return Ok(None);
+        };
+
+        let bytes = serde_json::to_vec(har)?;
+        let name = format!("har/{}.har", task.id);
+        let key = team_storage_key(task.team_id, &name);
+        let storage_url = storage
+            .put(task.team_id, &name, &bytes, "application/json")
+            .await
+            .with_context(|| format!("Failed to store HAR for task {}", task.id))?;
+
+        let entries = har["log"]["entries"].as_array().map_or(0, Vec::len);
+        Ok(Some(json!({
+            "storage_url": storage_url,
+            "storage_key": key,
+            "entries": entries,
+            "size": bytes.len(),
+        })))
+    }
+
+    /// 将浏览器引擎生成的 MHTML 快照保存到对象存储
+    ///
+    /// 对象保存在团队命名空间下的 `mhtml/{task_id}.mhtml`。返回写入结果元数据的 `mhtml` 字段；
+    /// 响应不含快照或未配置存储时返回 None。
+    async fn store_mhtml(&self, task: &Task, response: &ScrapeResponse) -> Result<Option<Value>> {
+        let Some(mhtml) = response.mhtml.as_ref() else {
+            return Ok(None);
+        };
+        let Some(storage) = self.storage_repository.as_ref() else {
+            warn!(
+                "MHTML snapshot captured for task {} but no storage repository is configured",
+                task.id
+            );
+            return Ok(None);
+        };
+
+        let name = format!("mhtml/{}.mhtml", task.id);
+        let key = team_storage_key(task.team_id, &name);
+        let storage_url = storage
+            .put(task.team_id, &name, mhtml.as_bytes(), MHTML_CONTENT_TYPE)
+            .await
+            .with_context(|| format!("Failed to store MHTML snapshot for task {}", task.id))?;
+
+        Ok(Some(json!({
+            "storage_url": storage_url,
+            "storage_key": key,
+            "resources": count_resources(mhtml),
+            "size": mhtml.len(),
+        })))
+    }
+
+    /// 将浏览器引擎打印的 PDF 保存到对象存储
+    ///
+    /// 对象保存在团队命名空间下的 `pdf/{task_id}.pdf`。返回写入结果元数据的 `pdf` 字段；
+    /// 响应不含 PDF 或未配置存储时返回 None。
+    async fn store_pdf(&self, task: &Task, response: &ScrapeResponse) -> Result<Option<Value>> {
+        let Some(pdf) = response.pdf.as_ref() else {
+            return Ok(None);
+        };
+        let Some(storage) = self.storage_repository.as_ref() else {
+            warn!(
+                "PDF rendered for task {} but no storage repository is configured",
+                task.id
+            );
+            return Ok(None);
+        };
+
+        let name = format!("pdf/{}.pdf", task.id);
+        let key = team_storage_key(task.team_id, &name);
+        let storage_url = storage
+            .put(task.team_id, &name, pdf, "application/pdf")
+            .await
+            .with_context(|| format!("Failed to store PDF for task {}", task.id))?;
+
+        Ok(Some(json!({
+            "storage_url": storage_url,
+            "storage_key": key,
+            "size": pdf.len(),
+        })))
+    }
+
     async fn trigger_webhook(&self, task: &Task, error_msg: Option<String>) {
         let result = match error_msg {
             Some(msg) => self.webhook_service.trigger_failure(task, msg).await,
@@ -1155,12 +3216,185 @@ impl ScrapeWorker {
 
     async fn handle_failure(&self, task: &mut Task) -> Result<()> {
         match self.retry_handler.handle_failure(task).await {
-            crate::domain::services::retry_handler::HandleFailureResult::Retried { .. } => Ok(()),
-            crate::domain::services::retry_handler::HandleFailureResult::Failed => Ok(()),
+            crate::domain::services::retry_handler::HandleFailureResult::Retried {
+                next_retry_at,
+                ..
+            } => {
+                self.schedule_delayed(task).await;
+                self.record_task_event(
+                    self.task_event(task, TaskEventType::Retried)
+                        .with_message(format!("Next attempt at {}", next_retry_at)),
+                )
+                .await;
+                Ok(())
+            }
+            crate::domain::services::retry_handler::HandleFailureResult::Failed => {
+                self.record_task_event(
+                    self.task_event(task, TaskEventType::Failed)
+                        .with_message("Max retries exceeded"),
+                )
+                .await;
+                Ok(())
+            }
             crate::domain::services::retry_handler::HandleFailureResult::Error(e) => Err(e),
         }
     }
 
+    /// 构建由当前 worker 产生的任务事件
+    fn task_event(&self, task: &Task, event_type: TaskEventType) -> TaskEvent {
+        TaskEvent::new(task.id, event_type)
+            .with_worker(self.worker_id)
+            .with_attempt(task.attempt_count)
+    }
+
+    /// 记录任务生命周期事件，未注入仓储时为空操作，写入失败不影响任务处理
+    async fn record_task_event(&self, event: TaskEvent) {
+        let Some(repository) = self.task_event_repository.as_ref() else {
+            return;
+        };
+        if let Err(e) = repository.record(&event).await {
+            warn!(
+                "Failed to record {} event for task {}: {}",
+                event.event_type, event.task_id, e
+            );
+        }
+    }
+
+    /// 记录一次引擎抓取尝试的耗时与结果
+    async fn record_engine_attempt(
+        &self,
+        task: &Task,
+        started: Instant,
+        response: &std::result::Result<ScrapeResponse, EngineError>,
+    ) {
+        let message = match response {
+            Ok(response) => {
+                self.record_bandwidth(task, response).await;
+                format!("HTTP {}", response.status_code)
+            }
+            Err(e) => e.to_string(),
+        };
+        self.record_task_event(
+            self.task_event(task, TaskEventType::EngineAttempt)
+                .with_duration_ms(started.elapsed().as_millis() as i64)
+                .with_message(message),
+        )
+        .await;
+    }
+
+    /// 将响应正文字节数计入团队与任务的下载流量
+    async fn record_bandwidth(&self, task: &Task, response: &ScrapeResponse) {
+        let Some(bandwidth) = self.bandwidth_service.as_ref() else {
+            return;
+        };
+        let bytes = response.body_bytes();
+        record_bandwidth_downloaded(bytes);
+        bandwidth.record(task.team_id, task.id, bytes).await;
+    }
+
+    /// 团队当日下载量已达上限时返回上限
+    async fn bandwidth_cap_exceeded(&self, task: &Task) -> Option<u64> {
+        self.bandwidth_service
+            .as_ref()?
+            .cap_exceeded(task.team_id)
+            .await
+    }
+
+    /// 团队达到每日下载上限时将任务标记为失败，不重试，返回失败原因
+    async fn fail_over_bandwidth_cap(&self, task: &Task, cap: u64) -> Result<String> {
+        let reason = format!("Daily bandwidth cap of {} bytes exceeded", cap);
+        warn!("{} team_id={} task_id={}", reason, task.team_id, task.id);
+        record_bandwidth_cap_rejection();
+
+        if let Some(mut t) = self.repository.find_by_id(task.id).await? {
+            t.status = TaskStatus::Failed;
+            t.completed_at = Some(Utc::now());
+            if let Some(obj) = t.payload.as_object_mut() {
+                obj.insert("error".to_string(), json!(reason));
+                obj.insert(
+                    "failure_reason".to_string(),
+                    json!(BANDWIDTH_CAP_FAILURE_REASON),
+                );
+            }
+            self.repository.update(&t).await?;
+        }
+        self.record_task_event(
+            self.task_event(task, TaskEventType::Failed)
+                .with_message(reason.clone()),
+        )
+        .await;
+        Ok(reason)
+    }
+
+    /// 将任务标记为失败并记录失败原因
+    async fn mark_task_failed(&self, task: &Task, reason: &str) -> Result<()> {
+        self.repository.mark_failed(task.id).await?;
+        self.record_task_event(
+            self.task_event(task, TaskEventType::Failed)
+                .with_message(reason),
+        )
+        .await;
+        Ok(())
+    }
+
+    /// 将被反爬拦截的任务标记为失败
+    ///
+    /// 拦截页作为结果保存，`meta_data.blocked_by` 记录拦截来源，但不投递、不索引、
+    /// 不推送给结果流订阅方。任务以 `failure_reason: "blocked"` 失败且不重试，
+    /// 同一引擎重试通常仍会被拦截。返回失败原因。
+    async fn fail_blocked_task(
+        &self,
+        task: &Task,
+        response: &ScrapeResponse,
+        blocked_by: BlockedBy,
+    ) -> Result<String> {
+        let reason = format!("Blocked by anti-bot protection: {}", blocked_by);
+        warn!(
+            "{} (HTTP {}) url={} task_id={}",
+            reason, response.status_code, task.url, task.id
+        );
+        #[cfg(feature = "metrics")]
+        counter!("scrape_blocked_total", "blocked_by" => blocked_by.as_str()).increment(1);
+
+        let mut meta_data = json!({ "blocked_by": blocked_by.as_str() });
+        if let Some(path) = escalation_path(response) {
+            meta_data = with_meta_field(meta_data, "escalation_path", path);
+        }
+        let result = ScrapeResult {
+            id: Uuid::new_v4(),
+            task_id: task.id,
+            url: task.url.clone(),
+            status_code: response.status_code as i32,
+            content: response.content.clone(),
+            content_type: response.content_type.clone(),
+            headers: serde_json::to_value(&response.headers).unwrap_or(Value::Null),
+            meta_data,
+            screenshot: response.screenshot.clone(),
+            response_time_ms: response.response_time_ms as i64,
+            created_at: Utc::now().naive_utc(),
+            etag: None,
+            last_modified: None,
+        };
+        self.result_repository.save(result).await?;
+
+        if let Some(mut t) = self.repository.find_by_id(task.id).await? {
+            t.status = TaskStatus::Failed;
+            t.completed_at = Some(Utc::now());
+            if let Some(obj) = t.payload.as_object_mut() {
+                obj.insert("error".to_string(), json!(reason));
+                obj.insert("failure_reason".to_string(), json!(BLOCKED_FAILURE_REASON));
+                obj.insert("blocked_by".to_string(), json!(blocked_by.as_str()));
+            }
+            self.repository.update(&t).await?;
+        }
+        self.record_task_event(
+            self.task_event(task, TaskEventType::Failed)
+                .with_message(reason.clone()),
+        )
+        .await;
+        Ok(reason)
+    }
+
     async fn deduct_feature_credits(
         &self,
         team_id: Uuid,
@@ -1168,17 +3402,10 @@ impl ScrapeWorker {
         screenshot: bool,
         proxy: bool,
     ) {
-        let mut extra_credits = 0;
-
-        // 2. Screenshot: 2 Credits
-        if screenshot {
-            extra_credits += 2;
-        }
-
-        // 3. Proxy: 1 Credit
-        if proxy {
-            extra_credits += 1;
-        }
+        let extra_credits = self
+            .pricing
+            .pricing_for(team_id)
+            .feature_credits(screenshot, proxy);
 
         if extra_credits > 0 {
             if let Err(e) = self
@@ -1209,14 +3436,18 @@ impl ScrapeWorker {
     ) {
         if usage.total_tokens > 0 {
             // 1. Record in-memory for real-time tracking
+            let started = Instant::now();
             self.token_usage
                 .entry(team_id)
                 .or_insert_with(|| AtomicI64::new(0))
                 .fetch_add(usage.total_tokens as i64, Ordering::Relaxed);
+            record_hot_path_latency("token_usage", started);
 
             // 2. Convert to credits and deduct from database
-            // Rate: 10 credits per 1000 tokens, minimum 1 credit for any usage
-            let credits_to_deduct = std::cmp::max(1, (usage.total_tokens as i64 * 10 + 999) / 1000);
+            let credits_to_deduct = self
+                .pricing
+                .pricing_for(team_id)
+                .token_credits(usage.total_tokens as u64);
             if credits_to_deduct > 0 {
                 if let Err(e) = self
                     .credits_repository
@@ -1266,15 +3497,101 @@ impl ScrapeWorker {
             .unwrap_or(false)
             || options.and_then(|o| o.js_rendering).unwrap_or(false);
 
+        // iframe、Shadow DOM 与无限滚动只能由浏览器引擎处理
+        let iframe_capture = options
+            .and_then(|o| IframeCapture::from_parts(o.iframes.as_deref(), o.cross_origin_iframes));
+        let flatten_shadow_dom = options.and_then(|o| o.flatten_shadow_dom).unwrap_or(false);
+        let auto_scroll = options
+            .and_then(|o| o.scroll.as_ref())
+            .and_then(|s| AutoScroll::from_parts(&s.mode, s.max_scrolls, s.idle_ms));
+        let needs_js =
+            needs_js || iframe_capture.is_some() || flatten_shadow_dom || auto_scroll.is_some();
+
         let screenshot_config = options.and_then(|o| {
             o.screenshot_options.as_ref().map(|so| ScreenshotConfig {
                 full_page: so.full_page.unwrap_or(false),
                 selector: so.selector.clone(),
                 quality: so.quality,
                 format: so.format.clone(),
+                max_height: so.max_height,
             })
         });
 
+        // 动作与失败策略一一对应，截图动作被过滤时两者一起跳过
+        let (actions, action_error_policies): (Vec<PageAction>, Vec<ActionErrorPolicy>) =
+            scrape_request
+                .actions
+                .clone()
+                .unwrap_or_default()
+                .into_iter()
+                .filter_map(|step| {
+                    let policy = step
+                        .on_error
+                        .as_deref()
+                        .and_then(|p| p.parse::<ActionErrorPolicy>().ok())
+                        .unwrap_or_default();
+                    let action = match step.action {
+                        ScrapeActionDto::Wait { milliseconds } => {
+                            Some(PageAction::Wait { milliseconds })
+                        }
+                        ScrapeActionDto::Click { selector } => Some(PageAction::Click { selector }),
+                        ScrapeActionDto::Scroll { direction } => {
+                            // Map string direction to ScrollDirection enum
+                            let dir = match direction.to_lowercase().as_str() {
+                                "up" => ScrollDirection::Up,
+                                "top" => ScrollDirection::Top,
+                                "bottom" => ScrollDirection::Bottom,
+                                _ => ScrollDirection::Down,
+                            };
+                            Some(PageAction::Scroll { direction: dir })
+                        }
+                        ScrapeActionDto::Screenshot { .. } => {
+                            // Screenshot action is handled by global needs_screenshot option
+                            None
+                        }
+                        ScrapeActionDto::Input { selector, text } => {
+                            Some(PageAction::Input { selector, text })
+                        }
+                        ScrapeActionDto::Evaluate { script } => {
+                            Some(PageAction::Evaluate { script })
+                        }
+                        ScrapeActionDto::WaitForSelector {
+                            selector,
+                            timeout_ms,
+                            state,
+                        } => Some(PageAction::WaitForSelector {
+                            selector,
+                            timeout_ms: timeout_ms.unwrap_or(DEFAULT_ACTION_TIMEOUT_MS),
+                            state: state
+                                .as_deref()
+                                .and_then(|s| s.parse().ok())
+                                .unwrap_or_default(),
+                        }),
+                        ScrapeActionDto::WaitForNavigation { timeout_ms } => {
+                            Some(PageAction::WaitForNavigation {
+                                timeout_ms: timeout_ms.unwrap_or(DEFAULT_ACTION_TIMEOUT_MS),
+                            })
+                        }
+                        ScrapeActionDto::Select { selector, value } => {
+                            Some(PageAction::Select { selector, value })
+                        }
+                        ScrapeActionDto::Check { selector, checked } => Some(PageAction::Check {
+                            selector,
+                            checked: checked.unwrap_or(true),
+                        }),
+                        ScrapeActionDto::PressKey { key } => Some(PageAction::PressKey { key }),
+                        ScrapeActionDto::Submit { selector } => {
+                            Some(PageAction::Submit { selector })
+                        }
+                        ScrapeActionDto::Hover { selector } => Some(PageAction::Hover { selector }),
+                        ScrapeActionDto::Drag { from, to } => Some(PageAction::Drag { from, to }),
+                        ScrapeActionDto::KeyDown { key } => Some(PageAction::KeyDown { key }),
+                        ScrapeActionDto::KeyUp { key } => Some(PageAction::KeyUp { key }),
+                    };
+                    action.map(|action| (action, policy))
+                })
+                .unzip();
+
         Ok(ScrapeRequest {
             url: scrape_request.url.clone(),
             options: ScrapeOptions {
@@ -1290,47 +3607,55 @@ impl ScrapeWorker {
                 skip_tls_verification: options
                     .and_then(|o| o.skip_tls_verification)
                     .unwrap_or(false),
+                // 指定指纹配置即意味着需要 TLS 指纹引擎
                 needs_tls_fingerprint: options
                     .and_then(|o| o.needs_tls_fingerprint)
-                    .unwrap_or(false),
+                    .unwrap_or(false)
+                    || options.is_some_and(|o| o.tls_profile.is_some()),
                 use_fire_engine: options.and_then(|o| o.use_fire_engine).unwrap_or(false),
-                actions: scrape_request
-                    .actions
-                    .clone()
-                    .unwrap_or_default()
-                    .into_iter()
-                    .filter_map(|a| match a {
-                        crate::application::dto::scrape_request::ScrapeActionDto::Wait {
-                            milliseconds,
-                        } => Some(PageAction::Wait { milliseconds }),
-                        crate::application::dto::scrape_request::ScrapeActionDto::Click {
-                            selector,
-                        } => Some(PageAction::Click { selector }),
-                        crate::application::dto::scrape_request::ScrapeActionDto::Scroll {
-                            direction,
-                        } => {
-                            // Map string direction to ScrollDirection enum
-                            let dir = match direction.to_lowercase().as_str() {
-                                "up" => ScrollDirection::Up,
-                                "top" => ScrollDirection::Top,
-                                "bottom" => ScrollDirection::Bottom,
-                                _ => ScrollDirection::Down,
-                            };
-                            Some(PageAction::Scroll { direction: dir })
-                        }
-                        crate::application::dto::scrape_request::ScrapeActionDto::Screenshot {
-                            ..
-                        } => {
-                            // Screenshot action is handled by global needs_screenshot option
-                            None
-                        }
-                        crate::application::dto::scrape_request::ScrapeActionDto::Input {
-                            selector,
-                            text,
-                        } => Some(PageAction::Input { selector, text }),
-                    })
-                    .collect(),
+                content_type_filter: None,
+                http_protocol: options
+                    .and_then(|o| o.http_protocol.as_deref())
+                    .and_then(|p| p.parse().ok())
+                    .unwrap_or(HttpProtocol::Auto),
+                tls_profile: options.and_then(|o| o.tls_profile.clone()),
+                capture_har: requests_har(scrape_request.formats.as_deref()),
+                capture_mhtml: requests_mhtml(scrape_request.formats.as_deref()),
+                pdf: requests_pdf(scrape_request.formats.as_deref()).then(|| {
+                    options
+                        .and_then(|o| o.pdf_options.as_ref())
+                        .map(PdfOptionsDto::to_options)
+                        .unwrap_or_default()
+                }),
+                resource_blocking: options.and_then(|o| {
+                    ResourceBlocking::from_parts(
+                        o.block_resources.clone(),
+                        o.blocked_domains.clone(),
+                        o.block_ads,
+                    )
+                }),
+                actions,
+                action_error_policies,
                 sync_wait_ms: scrape_request.sync_wait_ms.unwrap_or(0),
+                iframe_capture,
+                flatten_shadow_dom,
+                auto_scroll,
+                accessibility: requests_accessibility(scrape_request.formats.as_deref()).then(
+                    || {
+                        options
+                            .and_then(|o| o.accessibility_options.as_ref())
+                            .map(AccessibilityOptionsDto::to_options)
+                            .unwrap_or_default()
+                    },
+                ),
+                engine_tier: options
+                    .and_then(|o| o.engine_tier.as_deref())
+                    .and_then(|t| t.parse().ok()),
+                excluded_engines: Vec::new(),
+                resolve: HashMap::new(),
+                address_family: options
+                    .and_then(|o| o.address_family.as_deref())
+                    .and_then(|f| f.parse().ok()),
             },
         })
     }
@@ -1353,6 +3678,18 @@ pub struct ScrapeWorkerBuilder {
     default_concurrency_limit: usize,
     extraction_service: Option<Arc<dyn ExtractionServiceTrait>>,
     regex_cache: Option<RegexCache>,
+    webhook_management_service: Option<Arc<dyn WebhookManagementService>>,
+    domain_throttle_repository: Option<Arc<dyn DomainThrottleRepository>>,
+    task_event_repository: Option<Arc<dyn TaskEventRepository>>,
+    url_blocklist_repository: Option<Arc<dyn UrlBlocklistRepository>>,
+    storage_repository: Option<Arc<dyn StorageRepository>>,
+    plan_service: Option<Arc<PlanService>>,
+    team_export_service: Option<Arc<TeamExportService>>,
+    crawl_link_repository: Option<Arc<dyn CrawlLinkRepository>>,
+    result_uploader: Option<Arc<dyn ResultUploader>>,
+    result_stream: Option<Arc<dyn ResultStreamBus>>,
+    result_indexer: Option<Arc<dyn ResultIndexer>>,
+    bandwidth_service: Option<Arc<BandwidthService>>,
 }
 
 impl Default for ScrapeWorkerBuilder {
@@ -1371,6 +3708,18 @@ impl Default for ScrapeWorkerBuilder {
             default_concurrency_limit: 10,
             extraction_service: None,
             regex_cache: None,
+            webhook_management_service: None,
+            domain_throttle_repository: None,
+            task_event_repository: None,
+            url_blocklist_repository: None,
+            storage_repository: None,
+            plan_service: None,
+            team_export_service: None,
+            crawl_link_repository: None,
+            result_uploader: None,
+            result_stream: None,
+            result_indexer: None,
+            bandwidth_service: None,
         }
     }
 }
@@ -1471,54 +3820,194 @@ impl ScrapeWorkerBuilder {
         self
     }
 
-    /// 构建 ScrapeWorker 实例
-    #[allow(clippy::too_many_arguments)]
-    pub fn build(self) -> Result<ScrapeWorker, &'static str> {
-        let repository = self.repository.ok_or("repository is required")?;
-        let result_repository = self
-            .result_repository
-            .ok_or("result_repository is required")?;
-        let crawl_repository = self
-            .crawl_repository
-            .ok_or("crawl_repository is required")?;
-        let webhook_service = self.webhook_service.ok_or("webhook_service is required")?;
-        let credits_repository = self
-            .credits_repository
-            .ok_or("credits_repository is required")?;
-        let engine_client = self.engine_client.ok_or("engine_client is required")?;
-        let create_scrape_use_case = self
-            .create_scrape_use_case
-            .ok_or("create_scrape_use_case is required")?;
-        let team_semaphore = self.team_semaphore.ok_or("team_semaphore is required")?;
-        let robots_checker = self.robots_checker.ok_or("robots_checker is required")?;
-        let settings = self.settings.ok_or("settings is required")?;
-        let extraction_service = self
-            .extraction_service
-            .ok_or("extraction_service is required")?;
-        let regex_cache = self.regex_cache.ok_or("regex_cache is required")?;
+    /// 设置 Webhook 管理服务 (可选，启用 `crawl.summary` 事件)
+    pub fn with_webhook_management_service(
+        mut self,
+        webhook_management_service: Arc<dyn WebhookManagementService>,
+    ) -> Self {
+        self.webhook_management_service = Some(webhook_management_service);
+        self
+    }
 
-        Ok(ScrapeWorker::new(
-            repository,
-            result_repository,
-            crawl_repository,
-            webhook_service,
-            credits_repository,
-            engine_client,
-            create_scrape_use_case,
-            team_semaphore,
-            robots_checker,
-            settings,
-            self.default_concurrency_limit,
+    /// 设置域名节流仓储 (可选，启用 429/503 自适应节流)
+    pub fn with_domain_throttle_repository(
+        mut self,
+        domain_throttle_repository: Arc<dyn DomainThrottleRepository>,
+    ) -> Self {
+        self.domain_throttle_repository = Some(domain_throttle_repository);
+        self
+    }
+
+    /// 设置任务事件仓储 (可选，启用任务执行时间线)
+    pub fn with_task_event_repository(
+        mut self,
+        task_event_repository: Arc<dyn TaskEventRepository>,
+    ) -> Self {
+        self.task_event_repository = Some(task_event_repository);
+        self
+    }
+
+    /// 设置 URL 黑名单仓储 (可选，展开链接时过滤数据库中的黑名单条目)
+    pub fn with_url_blocklist_repository(
+        mut self,
+        url_blocklist_repository: Arc<dyn UrlBlocklistRepository>,
+    ) -> Self {
+        self.url_blocklist_repository = Some(url_blocklist_repository);
+        self
+    }
+
+    /// 设置对象存储仓储 (可选，下载模式保存二进制资源)
+    pub fn with_storage_repository(
+        mut self,
+        storage_repository: Arc<dyn StorageRepository>,
+    ) -> Self {
+        self.storage_repository = Some(storage_repository);
+        self
+    }
+
+    /// 设置团队套餐服务 (可选，按套餐限制团队并发)
+    pub fn with_plan_service(mut self, plan_service: Arc<PlanService>) -> Self {
+        self.plan_service = Some(plan_service);
+        self
+    }
+
+    /// 设置团队数据导出服务 (可选，执行导出任务)
+    pub fn with_team_export_service(mut self, team_export_service: Arc<TeamExportService>) -> Self {
+        self.team_export_service = Some(team_export_service);
+        self
+    }
+
+    /// 设置爬取链接仓储 (可选，记录链接图)
+    pub fn with_crawl_link_repository(
+        mut self,
+        crawl_link_repository: Arc<dyn CrawlLinkRepository>,
+    ) -> Self {
+        self.crawl_link_repository = Some(crawl_link_repository);
+        self
+    }
+
+    /// 设置结果投递上传器 (可选，上传到爬取指定的存储桶或预签名前缀)
+    pub fn with_result_uploader(mut self, result_uploader: Arc<dyn ResultUploader>) -> Self {
+        self.result_uploader = Some(result_uploader);
+        self
+    }
+
+    /// 设置结果推送总线 (可选，供 `?follow=true` 实时推送爬取结果)
+    pub fn with_result_stream(mut self, result_stream: Arc<dyn ResultStreamBus>) -> Self {
+        self.result_stream = Some(result_stream);
+        self
+    }
+
+    /// 设置外部检索索引 (可选，将结果写入 OpenSearch)
+    pub fn with_result_indexer(mut self, result_indexer: Arc<dyn ResultIndexer>) -> Self {
+        self.result_indexer = Some(result_indexer);
+        self
+    }
+
+    /// 设置流量统计服务 (可选，统计下载流量并执行每日上限)
+    pub fn with_bandwidth_service(mut self, bandwidth_service: Arc<BandwidthService>) -> Self {
+        self.bandwidth_service = Some(bandwidth_service);
+        self
+    }
+
+    /// 构建 ScrapeWorker 实例
+    #[allow(clippy::too_many_arguments)]
+    pub fn build(self) -> Result<ScrapeWorker, &'static str> {
+        let repository = self.repository.ok_or("repository is required")?;
+        let result_repository = self
+            .result_repository
+            .ok_or("result_repository is required")?;
+        let crawl_repository = self
+            .crawl_repository
+            .ok_or("crawl_repository is required")?;
+        let webhook_service = self.webhook_service.ok_or("webhook_service is required")?;
+        let credits_repository = self
+            .credits_repository
+            .ok_or("credits_repository is required")?;
+        let engine_client = self.engine_client.ok_or("engine_client is required")?;
+        let create_scrape_use_case = self
+            .create_scrape_use_case
+            .ok_or("create_scrape_use_case is required")?;
+        let team_semaphore = self.team_semaphore.ok_or("team_semaphore is required")?;
+        let robots_checker = self.robots_checker.ok_or("robots_checker is required")?;
+        let settings = self.settings.ok_or("settings is required")?;
+        let extraction_service = self
+            .extraction_service
+            .ok_or("extraction_service is required")?;
+        let regex_cache = self.regex_cache.ok_or("regex_cache is required")?;
+
+        let worker = ScrapeWorker::new(
+            repository,
+            result_repository,
+            crawl_repository,
+            webhook_service,
+            credits_repository,
+            engine_client,
+            create_scrape_use_case,
+            team_semaphore,
+            robots_checker,
+            settings,
+            self.default_concurrency_limit,
             extraction_service,
             regex_cache,
-        ))
+        );
+        let worker = match self.webhook_management_service {
+            Some(service) => worker.with_webhook_management_service(service),
+            None => worker,
+        };
+        let worker = match self.domain_throttle_repository {
+            Some(repository) => worker.with_domain_throttle_repository(repository),
+            None => worker,
+        };
+        let worker = match self.task_event_repository {
+            Some(repository) => worker.with_task_event_repository(repository),
+            None => worker,
+        };
+        let worker = match self.url_blocklist_repository {
+            Some(repository) => worker.with_url_blocklist_repository(repository),
+            None => worker,
+        };
+        let worker = match self.storage_repository {
+            Some(repository) => worker.with_storage_repository(repository),
+            None => worker,
+        };
+        let worker = match self.plan_service {
+            Some(plan_service) => worker.with_plan_service(plan_service),
+            None => worker,
+        };
+        let worker = match self.team_export_service {
+            Some(service) => worker.with_team_export_service(service),
+            None => worker,
+        };
+        let worker = match self.crawl_link_repository {
+            Some(repository) => worker.with_crawl_link_repository(repository),
+            None => worker,
+        };
+        let worker = match self.result_uploader {
+            Some(uploader) => worker.with_result_uploader(uploader),
+            None => worker,
+        };
+        let worker = match self.result_stream {
+            Some(result_stream) => worker.with_result_stream(result_stream),
+            None => worker,
+        };
+        let worker = match self.result_indexer {
+            Some(indexer) => worker.with_result_indexer(indexer),
+            None => worker,
+        };
+        Ok(match self.bandwidth_service {
+            Some(bandwidth_service) => worker.with_bandwidth_service(bandwidth_service),
+            None => worker,
+        })
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::engines::performance::PageTimings;
     use crate::engines::EngineError;
+    use crate::engines::EngineTier;
     use crate::infrastructure::oxcache::RegexCacheType;
     use std::time::Duration;
 
@@ -1970,6 +4459,35 @@ mod tests {
 
     // ========== build_scrape_request: actions mapping ==========
 
+    #[test]
+    fn test_build_scrape_request_form_actions_keep_error_policy() {
+        let task = make_task(json!({
+            "url": "https://example.com/login",
+            "actions": [
+                {"type": "input", "selector": "#user", "text": "alice"},
+                {"type": "screenshot"},
+                {"type": "check", "selector": "#remember", "on_error": "continue"},
+                {"type": "pressKey", "key": "Enter"},
+                {"type": "submit", "selector": "form"}
+            ]
+        }));
+        let request = ScrapeWorker::build_scrape_request(&task).expect("should succeed");
+        assert_eq!(request.options.actions.len(), 4);
+        assert_eq!(
+            request.options.action_error_policies,
+            vec![
+                ActionErrorPolicy::Abort,
+                ActionErrorPolicy::Continue,
+                ActionErrorPolicy::Abort,
+                ActionErrorPolicy::Abort,
+            ]
+        );
+        assert!(matches!(
+            &request.options.actions[1],
+            PageAction::Check { checked: true, .. }
+        ));
+    }
+
     #[test]
     fn test_build_scrape_request_action_wait_mapped() {
         let task = make_task(json!({
@@ -2411,9 +4929,40 @@ mod tests {
         async fn find_by_task_ids(&self, _task_ids: &[Uuid]) -> Result<Vec<ScrapeResult>> {
             Ok(vec![])
         }
+        async fn find_latest_for_crawl_url(
+            &self,
+            _crawl_id: Uuid,
+            _url: &str,
+        ) -> Result<Option<ScrapeResult>> {
+            Ok(None)
+        }
+        async fn count_not_modified(&self, _task_ids: &[Uuid]) -> Result<u64> {
+            Ok(0)
+        }
+        async fn response_times(&self, _task_ids: &[Uuid]) -> Result<Vec<i64>> {
+            Ok(Vec::new())
+        }
         async fn get_team_avg_response_time(&self, _team_id: Uuid) -> Result<f64> {
             Ok(0.0)
         }
+
+        async fn delete_for_team(
+            &self,
+            _team_id: Uuid,
+            _filter: &crate::domain::repositories::scrape_result_repository::ResultDeletionFilter,
+        ) -> Result<Vec<ScrapeResult>> {
+            Ok(vec![])
+        }
+
+        async fn search_crawl_results(
+            &self,
+            _crawl_id: Uuid,
+            _query: &str,
+            _limit: u64,
+        ) -> Result<Vec<crate::domain::repositories::scrape_result_repository::ResultSearchHit>>
+        {
+            Ok(vec![])
+        }
     }
 
     /// Mock CrawlRepository — all methods return Ok with default values.
@@ -2543,6 +5092,21 @@ mod tests {
                 headers: HashMap::new(),
                 response_time_ms: 0,
                 final_url: None,
+                truncated: false,
+                raw_content: None,
+                http_version: None,
+                tls_profile: None,
+                har: None,
+                mhtml: None,
+                pdf: None,
+                blocked_requests: None,
+                evaluate_results: Vec::new(),
+                frames: Vec::new(),
+                accessibility: None,
+                timings: None,
+                ip_family: None,
+                blocked_by: None,
+                escalations: Vec::new(),
             })
         }
     }
@@ -2600,6 +5164,13 @@ mod tests {
     /// services. The TeamSemaphore is an in-memory primitive — no external
     /// service is required during these tests.
     async fn build_mock_worker() -> ScrapeWorker {
+        build_mock_worker_with_extraction(Arc::new(MockExtractionService)).await
+    }
+
+    /// Build a mock ScrapeWorker with the given extraction service.
+    async fn build_mock_worker_with_extraction(
+        extraction_service: Arc<dyn ExtractionServiceTrait>,
+    ) -> ScrapeWorker {
         let regex_cache = make_regex_cache().await;
         let engine_client = Arc::new(EngineClient::new());
         let settings = crate::bootstrap::config::load_settings()
@@ -2619,116 +5190,424 @@ mod tests {
             Arc::new(MockRobotsChecker) as Arc<dyn RobotsCheckerTrait>,
             settings_arc,
             10,
-            Arc::new(MockExtractionService) as Arc<dyn ExtractionServiceTrait>,
+            extraction_service,
             regex_cache,
         )
     }
 
-    // --- should_crawl tests ---
+    /// Extraction mock that returns the HTML it was given under every rule name.
+    struct EchoExtractionService;
 
-    #[tokio::test]
-    async fn test_mock_should_crawl_no_patterns_returns_true() {
-        let worker = build_mock_worker().await;
-        let config = make_crawl_config(None, None);
-        assert!(worker.should_crawl("https://example.com/page1", &config));
-        assert!(worker.should_crawl("https://other.com/page2", &config));
+    #[async_trait::async_trait]
+    impl ExtractionServiceTrait for EchoExtractionService {
+        async fn extract(
+            &self,
+            html_content: &str,
+            rules: &HashMap<String, ExtractionRule>,
+            _base_url: Option<&str>,
+        ) -> Result<(Value, TokenUsage)> {
+            let data: serde_json::Map<String, Value> = rules
+                .keys()
+                .map(|name| (name.clone(), json!(html_content)))
+                .collect();
+            Ok((Value::Object(data), TokenUsage::default()))
+        }
+        async fn extract_with_schema(
+            &self,
+            _html_content: &str,
+            _schema: &Value,
+        ) -> Result<(Value, TokenUsage)> {
+            Ok((json!({}), TokenUsage::default()))
+        }
+        fn extract_with_selectors(
+            &self,
+            _html_content: &str,
+            _rules: &HashMap<String, ExtractionRule>,
+            _base_url: Option<&str>,
+        ) -> Result<Value> {
+            Ok(json!({}))
+        }
     }
 
     #[tokio::test]
-    async fn test_mock_should_crawl_include_pattern_match() {
-        let worker = build_mock_worker().await;
-        let config = make_crawl_config(Some(vec!["example\\.com".to_string()]), None);
-        assert!(worker.should_crawl("https://example.com/page", &config));
-        assert!(worker.should_crawl("https://example.com/sub/page", &config));
+    async fn test_extract_rules_routes_frame_rules_to_frame_html() {
+        let worker = build_mock_worker_with_extraction(Arc::new(EchoExtractionService)).await;
+        let mut response = ScrapeResponse::new(200, "<p>page</p>", "text/html");
+        response.frames = vec![crate::engines::iframe::FrameContent {
+            name: "comments".to_string(),
+            url: "https://example.com/comments".to_string(),
+            html: "<p>frame</p>".to_string(),
+        }];
+
+        let rule = |frame: Option<&str>| ExtractionRule {
+            selector: Some("p".to_string()),
+            attr: None,
+            is_array: false,
+            use_llm: None,
+            llm_prompt: None,
+            output_format: None,
+            frame: frame.map(str::to_string),
+        };
+        let rules = HashMap::from([
+            ("title".to_string(), rule(None)),
+            ("comment".to_string(), rule(Some("comments"))),
+            ("missing".to_string(), rule(Some("ads"))),
+        ]);
+
+        let (data, _) = worker
+            .extract_rules(&response, &rules, "https://example.com")
+            .await
+            .unwrap();
+        assert_eq!(data["title"], "<p>page</p>");
+        assert_eq!(data["comment"], "<p>frame</p>");
+        assert_eq!(data["missing"], Value::Null);
     }
 
-    #[tokio::test]
-    async fn test_mock_should_crawl_include_pattern_no_match() {
-        let worker = build_mock_worker().await;
-        let config = make_crawl_config(Some(vec!["example\\.com".to_string()]), None);
-        assert!(!worker.should_crawl("https://other.com/page", &config));
-        assert!(!worker.should_crawl("https://foo.org/path", &config));
+    // --- domain throttle tests ---
+
+    /// In-memory DomainThrottleRepository keyed by domain.
+    #[derive(Default)]
+    struct MockDomainThrottleRepo {
+        throttles: std::sync::Mutex<HashMap<String, DomainThrottle>>,
     }
 
-    #[tokio::test]
-    async fn test_mock_should_crawl_exclude_pattern_match() {
-        let worker = build_mock_worker().await;
-        let config = make_crawl_config(None, Some(vec!["blocked".to_string()]));
-        assert!(!worker.should_crawl("https://example.com/blocked", &config));
-        assert!(!worker.should_crawl("https://example.com/blocked/page", &config));
+    #[async_trait::async_trait]
+    impl DomainThrottleRepository for MockDomainThrottleRepo {
+        async fn find_by_domain(
+            &self,
+            domain: &str,
+        ) -> Result<Option<DomainThrottle>, RepositoryError> {
+            Ok(self.throttles.lock().unwrap().get(domain).cloned())
+        }
+        async fn upsert(&self, throttle: &DomainThrottle) -> Result<(), RepositoryError> {
+            self.throttles
+                .lock()
+                .unwrap()
+                .insert(throttle.domain.clone(), throttle.clone());
+            Ok(())
+        }
     }
 
     #[tokio::test]
-    async fn test_mock_should_crawl_exclude_pattern_no_match() {
-        let worker = build_mock_worker().await;
-        let config = make_crawl_config(None, Some(vec!["blocked".to_string()]));
-        assert!(worker.should_crawl("https://example.com/page", &config));
-        assert!(worker.should_crawl("https://example.com/allowed", &config));
+    async fn test_record_domain_throttle_on_429_honours_retry_after() {
+        let repo = Arc::new(MockDomainThrottleRepo::default());
+        let worker = build_mock_worker()
+            .await
+            .with_domain_throttle_repository(repo.clone());
+
+        let mut response = ScrapeResponse::new(429, "", "text/html");
+        response
+            .headers
+            .insert("Retry-After".to_string(), "45".to_string());
+        worker
+            .record_domain_throttle("https://Example.com/page", &response)
+            .await;
+
+        let throttle = repo
+            .find_by_domain("example.com")
+            .await
+            .unwrap()
+            .expect("throttle should be recorded");
+        assert_eq!(throttle.consecutive_errors, 1);
+        assert_eq!(throttle.last_status, 429);
+        let remaining = (throttle.throttled_until - Utc::now()).num_seconds();
+        assert!((40..=45).contains(&remaining), "remaining={}", remaining);
+
+        let task = make_task(json!({}));
+        assert_eq!(
+            worker.domain_throttled_until(&task).await,
+            Some(throttle.throttled_until)
+        );
     }
 
     #[tokio::test]
-    async fn test_mock_should_crawl_both_include_and_exclude() {
-        let worker = build_mock_worker().await;
-        let config = make_crawl_config(
-            Some(vec!["example\\.com".to_string()]),
-            Some(vec!["blocked".to_string()]),
+    async fn test_record_domain_throttle_ignores_non_throttle_status() {
+        let repo = Arc::new(MockDomainThrottleRepo::default());
+        let worker = build_mock_worker()
+            .await
+            .with_domain_throttle_repository(repo.clone());
+
+        worker
+            .record_domain_throttle(
+                "https://example.com",
+                &ScrapeResponse::new(500, "", "text/html"),
+            )
+            .await;
+
+        assert!(repo.throttles.lock().unwrap().is_empty());
+        assert_eq!(
+            worker.domain_throttled_until(&make_task(json!({}))).await,
+            None
         );
-        // Matches include, doesn't match exclude → true
-        assert!(worker.should_crawl("https://example.com/page", &config));
-        // Matches include, matches exclude → false
-        assert!(!worker.should_crawl("https://example.com/blocked", &config));
-        // Doesn't match include → false (include takes priority)
-        assert!(!worker.should_crawl("https://other.com/blocked", &config));
     }
 
     #[tokio::test]
-    async fn test_mock_should_crawl_multiple_include_patterns() {
+    async fn test_domain_throttled_until_without_repository_is_none() {
         let worker = build_mock_worker().await;
-        let config = make_crawl_config(
-            Some(vec!["example\\.com".to_string(), "test\\.org".to_string()]),
-            None,
+        worker
+            .record_domain_throttle(
+                "https://example.com",
+                &ScrapeResponse::new(503, "", "text/html"),
+            )
+            .await;
+        assert_eq!(
+            worker.domain_throttled_until(&make_task(json!({}))).await,
+            None
         );
-        assert!(worker.should_crawl("https://example.com/page", &config));
-        assert!(worker.should_crawl("https://test.org/page", &config));
-        assert!(!worker.should_crawl("https://other.com/page", &config));
     }
 
-    #[tokio::test]
-    async fn test_mock_should_crawl_multiple_exclude_patterns() {
-        let worker = build_mock_worker().await;
-        let config =
-            make_crawl_config(None, Some(vec!["blocked".to_string(), "admin".to_string()]));
-        assert!(worker.should_crawl("https://example.com/page", &config));
-        assert!(!worker.should_crawl("https://example.com/blocked", &config));
-        assert!(!worker.should_crawl("https://example.com/admin", &config));
+    // --- task event tests ---
+
+    /// In-memory TaskEventRepository collecting recorded events.
+    #[derive(Default)]
+    struct MockTaskEventRepo {
+        events: std::sync::Mutex<Vec<TaskEvent>>,
     }
 
-    #[tokio::test]
-    #[allow(clippy::invalid_regex)]
-    async fn test_mock_should_crawl_include_fallback_string_match() {
-        let worker = build_mock_worker().await;
-        // Invalid regex — should fall back to string contains
-        let config = make_crawl_config(Some(vec!["[unclosed".to_string()]), None);
-        assert!(worker.should_crawl("https://example.com/[unclosed", &config));
-        assert!(!worker.should_crawl("https://example.com/other", &config));
+    #[async_trait::async_trait]
+    impl TaskEventRepository for MockTaskEventRepo {
+        async fn record(&self, event: &TaskEvent) -> Result<(), RepositoryError> {
+            self.events.lock().unwrap().push(event.clone());
+            Ok(())
+        }
+        async fn find_by_task_id(&self, task_id: Uuid) -> Result<Vec<TaskEvent>, RepositoryError> {
+            Ok(self
+                .events
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|event| event.task_id == task_id)
+                .cloned()
+                .collect())
+        }
     }
 
     #[tokio::test]
-    #[allow(clippy::invalid_regex)]
-    async fn test_mock_should_crawl_exclude_fallback_string_match() {
-        let worker = build_mock_worker().await;
-        let config = make_crawl_config(None, Some(vec!["[unclosed".to_string()]));
-        assert!(!worker.should_crawl("https://example.com/[unclosed", &config));
-        assert!(worker.should_crawl("https://example.com/other", &config));
-    }
+    async fn test_record_engine_attempt_captures_duration_and_outcome() {
+        let repo = Arc::new(MockTaskEventRepo::default());
+        let worker = build_mock_worker()
+            .await
+            .with_task_event_repository(repo.clone());
+        let task = make_task(json!({}));
 
-    // --- build_crawl_request tests ---
+        worker
+            .record_engine_attempt(
+                &task,
+                Instant::now(),
+                &Ok(ScrapeResponse::new(200, "", "text/html")),
+            )
+            .await;
+        worker
+            .record_engine_attempt(
+                &task,
+                Instant::now(),
+                &Err(EngineError::Timeout(Duration::from_secs(30))),
+            )
+            .await;
+
+        let events = repo.find_by_task_id(task.id).await.unwrap();
+        assert_eq!(events.len(), 2);
+        assert!(events
+            .iter()
+            .all(|event| event.event_type == TaskEventType::EngineAttempt
+                && event.worker_id == Some(worker.worker_id)
+                && event.duration_ms.is_some()));
+        assert_eq!(events[0].message.as_deref(), Some("HTTP 200"));
+        assert!(events[1].message.as_deref().unwrap().contains("timed out"));
+    }
 
     #[tokio::test]
-    async fn test_mock_build_crawl_request_basic() {
-        let worker = build_mock_worker().await;
-        let task = Task::new(
-            Uuid::new_v4(),
+    async fn test_mark_task_failed_records_reason() {
+        let repo = Arc::new(MockTaskEventRepo::default());
+        let worker = build_mock_worker()
+            .await
+            .with_task_event_repository(repo.clone());
+        let task = make_task(json!({}));
+
+        worker
+            .mark_task_failed(&task, "Disallowed by robots.txt")
+            .await
+            .unwrap();
+
+        let events = repo.find_by_task_id(task.id).await.unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].event_type, TaskEventType::Failed);
+        assert_eq!(
+            events[0].message.as_deref(),
+            Some("Disallowed by robots.txt")
+        );
+    }
+
+    // --- should_crawl tests ---
+
+    #[tokio::test]
+    async fn test_mock_should_crawl_no_patterns_returns_true() {
+        let worker = build_mock_worker().await;
+        let mut config = make_crawl_config(None, None);
+        config.allow_external_domains = Some(true);
+        assert!(worker.should_crawl("https://example.com", "https://example.com/page1", &config));
+        assert!(worker.should_crawl("https://example.com", "https://other.com/page2", &config));
+    }
+
+    #[tokio::test]
+    async fn test_mock_should_crawl_domain_scope() {
+        let worker = build_mock_worker().await;
+        let root = "https://www.example.com/";
+        let mut config = make_crawl_config(None, None);
+
+        // 默认：同一主机（忽略 www.）
+        assert!(worker.should_crawl(root, "https://example.com/a", &config));
+        assert!(worker.should_crawl(root, "https://WWW.example.com/b", &config));
+        assert!(!worker.should_crawl(root, "https://blog.example.com/c", &config));
+        assert!(!worker.should_crawl(root, "https://other.com/d", &config));
+
+        // 同一可注册域名
+        config.allow_subdomains = Some(true);
+        assert!(worker.should_crawl(root, "https://blog.example.com/c", &config));
+        assert!(worker.should_crawl(
+            "https://shop.example.co.uk",
+            "https://help.example.co.uk/e",
+            &config
+        ));
+        assert!(!worker.should_crawl(
+            "https://shop.example.co.uk",
+            "https://other.co.uk/f",
+            &config
+        ));
+        assert!(!worker.should_crawl(root, "https://other.com/d", &config));
+
+        // 不限制
+        config.allow_external_domains = Some(true);
+        assert!(worker.should_crawl(root, "https://other.com/d", &config));
+    }
+
+    #[tokio::test]
+    async fn test_mock_should_crawl_include_pattern_match() {
+        let worker = build_mock_worker().await;
+        let config = make_crawl_config(Some(vec!["example\\.com".to_string()]), None);
+        assert!(worker.should_crawl("https://example.com", "https://example.com/page", &config));
+        assert!(worker.should_crawl(
+            "https://example.com",
+            "https://example.com/sub/page",
+            &config
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_mock_should_crawl_include_pattern_no_match() {
+        let worker = build_mock_worker().await;
+        let config = make_crawl_config(Some(vec!["example\\.com".to_string()]), None);
+        assert!(!worker.should_crawl("https://example.com", "https://other.com/page", &config));
+        assert!(!worker.should_crawl("https://example.com", "https://foo.org/path", &config));
+    }
+
+    #[tokio::test]
+    async fn test_mock_should_crawl_exclude_pattern_match() {
+        let worker = build_mock_worker().await;
+        let config = make_crawl_config(None, Some(vec!["blocked".to_string()]));
+        assert!(!worker.should_crawl(
+            "https://example.com",
+            "https://example.com/blocked",
+            &config
+        ));
+        assert!(!worker.should_crawl(
+            "https://example.com",
+            "https://example.com/blocked/page",
+            &config
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_mock_should_crawl_exclude_pattern_no_match() {
+        let worker = build_mock_worker().await;
+        let config = make_crawl_config(None, Some(vec!["blocked".to_string()]));
+        assert!(worker.should_crawl("https://example.com", "https://example.com/page", &config));
+        assert!(worker.should_crawl(
+            "https://example.com",
+            "https://example.com/allowed",
+            &config
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_mock_should_crawl_both_include_and_exclude() {
+        let worker = build_mock_worker().await;
+        let config = make_crawl_config(
+            Some(vec!["example\\.com".to_string()]),
+            Some(vec!["blocked".to_string()]),
+        );
+        // Matches include, doesn't match exclude → true
+        assert!(worker.should_crawl("https://example.com", "https://example.com/page", &config));
+        // Matches include, matches exclude → false
+        assert!(!worker.should_crawl(
+            "https://example.com",
+            "https://example.com/blocked",
+            &config
+        ));
+        // Doesn't match include → false (include takes priority)
+        assert!(!worker.should_crawl("https://example.com", "https://other.com/blocked", &config));
+    }
+
+    #[tokio::test]
+    async fn test_mock_should_crawl_multiple_include_patterns() {
+        let worker = build_mock_worker().await;
+        let config = make_crawl_config(
+            Some(vec!["example\\.com".to_string(), "test\\.org".to_string()]),
+            None,
+        );
+        assert!(worker.should_crawl("https://example.com", "https://example.com/page", &config));
+        assert!(worker.should_crawl("https://test.org", "https://test.org/page", &config));
+        assert!(!worker.should_crawl("https://example.com", "https://other.com/page", &config));
+    }
+
+    #[tokio::test]
+    async fn test_mock_should_crawl_multiple_exclude_patterns() {
+        let worker = build_mock_worker().await;
+        let config =
+            make_crawl_config(None, Some(vec!["blocked".to_string(), "admin".to_string()]));
+        assert!(worker.should_crawl("https://example.com", "https://example.com/page", &config));
+        assert!(!worker.should_crawl(
+            "https://example.com",
+            "https://example.com/blocked",
+            &config
+        ));
+        assert!(!worker.should_crawl("https://example.com", "https://example.com/admin", &config));
+    }
+
+    #[tokio::test]
+    #[allow(clippy::invalid_regex)]
+    async fn test_mock_should_crawl_include_fallback_string_match() {
+        let worker = build_mock_worker().await;
+        // Invalid regex — should fall back to string contains
+        let config = make_crawl_config(Some(vec!["[unclosed".to_string()]), None);
+        assert!(worker.should_crawl(
+            "https://example.com",
+            "https://example.com/[unclosed",
+            &config
+        ));
+        assert!(!worker.should_crawl("https://example.com", "https://example.com/other", &config));
+    }
+
+    #[tokio::test]
+    #[allow(clippy::invalid_regex)]
+    async fn test_mock_should_crawl_exclude_fallback_string_match() {
+        let worker = build_mock_worker().await;
+        let config = make_crawl_config(None, Some(vec!["[unclosed".to_string()]));
+        assert!(!worker.should_crawl(
+            "https://example.com",
+            "https://example.com/[unclosed",
+            &config
+        ));
+        assert!(worker.should_crawl("https://example.com", "https://example.com/other", &config));
+    }
+
+    // --- build_crawl_request tests ---
+
+    #[tokio::test]
+    async fn test_mock_build_crawl_request_basic() {
+        let worker = build_mock_worker().await;
+        let task = Task::new(
+            Uuid::new_v4(),
             TaskType::Crawl,
             Uuid::new_v4(),
             Uuid::new_v4(),
@@ -2750,6 +5629,38 @@ mod tests {
         assert!(!request.options.use_fire_engine);
         assert!(request.options.actions.is_empty());
         assert_eq!(request.options.sync_wait_ms, 0);
+        assert!(request.options.resolve.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_mock_build_crawl_request_with_resolve_overrides() {
+        let worker = build_mock_worker().await;
+        let task = Task::new(
+            Uuid::new_v4(),
+            TaskType::Crawl,
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            "https://staging.example.com".to_string(),
+            json!({}),
+        );
+        let mut config = make_crawl_config(None, None);
+        config.resolve = Some(HashMap::from([(
+            "Staging.Example.com".to_string(),
+            "203.0.113.5".to_string(),
+        )]));
+        let request = worker.build_crawl_request(&task, &config);
+        assert_eq!(
+            request.options.resolve.get("staging.example.com"),
+            Some(&"203.0.113.5".parse::<IpAddr>().unwrap())
+        );
+
+        // 直接入队的内网覆盖被整体丢弃
+        config.resolve = Some(HashMap::from([(
+            "staging.example.com".to_string(),
+            "10.0.0.5".to_string(),
+        )]));
+        let request = worker.build_crawl_request(&task, &config);
+        assert!(request.options.resolve.is_empty());
     }
 
     #[tokio::test]
@@ -2776,6 +5687,24 @@ mod tests {
                 "Authorization": "Bearer token123"
             })),
             extraction_rules: None,
+            skip_nofollow_links: None,
+            ignore_robots: None,
+            ignore_sitemap: None,
+            limit: None,
+            max_duration_seconds: None,
+            previous_crawl_id: None,
+            allow_subdomains: None,
+            allow_external_domains: None,
+            ignore_query_params: None,
+            ignore_all_query_params: None,
+            max_query_variants_per_path: None,
+            download_assets: None,
+            allowed_content_types: None,
+            excluded_content_types: None,
+            result_destination: None,
+            keep_local_results: None,
+            formats: None,
+            resolve: None,
         };
         let request = worker.build_crawl_request(&task, &config);
         assert_eq!(
@@ -2814,6 +5743,24 @@ mod tests {
                 "X-Valid": "ok"
             })),
             extraction_rules: None,
+            skip_nofollow_links: None,
+            ignore_robots: None,
+            ignore_sitemap: None,
+            limit: None,
+            max_duration_seconds: None,
+            previous_crawl_id: None,
+            allow_subdomains: None,
+            allow_external_domains: None,
+            ignore_query_params: None,
+            ignore_all_query_params: None,
+            max_query_variants_per_path: None,
+            download_assets: None,
+            allowed_content_types: None,
+            excluded_content_types: None,
+            result_destination: None,
+            keep_local_results: None,
+            formats: None,
+            resolve: None,
         };
         let request = worker.build_crawl_request(&task, &config);
         assert_eq!(request.options.headers.len(), 1);
@@ -2847,6 +5794,24 @@ mod tests {
             proxy: Some("http://proxy:3128".to_string()),
             headers: None,
             extraction_rules: None,
+            skip_nofollow_links: None,
+            ignore_robots: None,
+            ignore_sitemap: None,
+            limit: None,
+            max_duration_seconds: None,
+            previous_crawl_id: None,
+            allow_subdomains: None,
+            allow_external_domains: None,
+            ignore_query_params: None,
+            ignore_all_query_params: None,
+            max_query_variants_per_path: None,
+            download_assets: None,
+            allowed_content_types: None,
+            excluded_content_types: None,
+            result_destination: None,
+            keep_local_results: None,
+            formats: None,
+            resolve: None,
         };
         let request = worker.build_crawl_request(&task, &config);
         assert_eq!(request.options.proxy, Some("http://proxy:3128".to_string()));
@@ -2873,6 +5838,24 @@ mod tests {
             proxy: None,
             headers: Some(json!({})),
             extraction_rules: None,
+            skip_nofollow_links: None,
+            ignore_robots: None,
+            ignore_sitemap: None,
+            limit: None,
+            max_duration_seconds: None,
+            previous_crawl_id: None,
+            allow_subdomains: None,
+            allow_external_domains: None,
+            ignore_query_params: None,
+            ignore_all_query_params: None,
+            max_query_variants_per_path: None,
+            download_assets: None,
+            allowed_content_types: None,
+            excluded_content_types: None,
+            result_destination: None,
+            keep_local_results: None,
+            formats: None,
+            resolve: None,
         };
         let request = worker.build_crawl_request(&task, &config);
         assert!(request.options.headers.is_empty());
@@ -2984,6 +5967,21 @@ mod tests {
             headers: HashMap::new(),
             response_time_ms: 100,
             final_url: None,
+            truncated: false,
+            raw_content: None,
+            http_version: None,
+            tls_profile: None,
+            har: None,
+            mhtml: None,
+            pdf: None,
+            blocked_requests: None,
+            evaluate_results: Vec::new(),
+            frames: Vec::new(),
+            accessibility: None,
+            timings: None,
+            ip_family: None,
+            blocked_by: None,
+            escalations: Vec::new(),
         };
         let result = worker.save_result(&task, &response, None).await;
         assert!(result.is_ok());
@@ -3001,6 +5999,21 @@ mod tests {
             headers: HashMap::new(),
             response_time_ms: 50,
             final_url: None,
+            truncated: false,
+            raw_content: None,
+            http_version: None,
+            tls_profile: None,
+            har: None,
+            mhtml: None,
+            pdf: None,
+            blocked_requests: None,
+            evaluate_results: Vec::new(),
+            frames: Vec::new(),
+            accessibility: None,
+            timings: None,
+            ip_family: None,
+            blocked_by: None,
+            escalations: Vec::new(),
         };
         let extra = json!({"title": "Test Page", "links": 5});
         let result = worker.save_result(&task, &response, Some(extra)).await;
@@ -3019,6 +6032,21 @@ mod tests {
             headers: HashMap::new(),
             response_time_ms: 200,
             final_url: None,
+            truncated: false,
+            raw_content: None,
+            http_version: None,
+            tls_profile: None,
+            har: None,
+            mhtml: None,
+            pdf: None,
+            blocked_requests: None,
+            evaluate_results: Vec::new(),
+            frames: Vec::new(),
+            accessibility: None,
+            timings: None,
+            ip_family: None,
+            blocked_by: None,
+            escalations: Vec::new(),
         };
         let result = worker.save_result(&task, &response, None).await;
         assert!(result.is_ok());
@@ -3038,6 +6066,21 @@ mod tests {
             headers: HashMap::new(),
             response_time_ms: 100,
             final_url: None,
+            truncated: false,
+            raw_content: None,
+            http_version: None,
+            tls_profile: None,
+            har: None,
+            mhtml: None,
+            pdf: None,
+            blocked_requests: None,
+            evaluate_results: Vec::new(),
+            frames: Vec::new(),
+            accessibility: None,
+            timings: None,
+            ip_family: None,
+            blocked_by: None,
+            escalations: Vec::new(),
         };
         let result = worker.process_text_encoding(&task, &response).await;
         // Should either return processed content or an error (depending on
@@ -3151,6 +6194,21 @@ mod tests {
             headers: HashMap::new(),
             response_time_ms: 50,
             final_url: None,
+            truncated: false,
+            raw_content: None,
+            http_version: None,
+            tls_profile: None,
+            har: None,
+            mhtml: None,
+            pdf: None,
+            blocked_requests: None,
+            evaluate_results: Vec::new(),
+            frames: Vec::new(),
+            accessibility: None,
+            timings: None,
+            ip_family: None,
+            blocked_by: None,
+            escalations: Vec::new(),
         };
         let mut rules = HashMap::new();
         rules.insert(
@@ -3162,6 +6220,7 @@ mod tests {
                 use_llm: None,
                 llm_prompt: None,
                 output_format: None,
+                frame: None,
             },
         );
         let result = worker
@@ -3185,6 +6244,21 @@ mod tests {
             headers: HashMap::new(),
             response_time_ms: 30,
             final_url: None,
+            truncated: false,
+            raw_content: None,
+            http_version: None,
+            tls_profile: None,
+            har: None,
+            mhtml: None,
+            pdf: None,
+            blocked_requests: None,
+            evaluate_results: Vec::new(),
+            frames: Vec::new(),
+            accessibility: None,
+            timings: None,
+            ip_family: None,
+            blocked_by: None,
+            escalations: Vec::new(),
         };
         let result = worker
             .handle_prompt_extraction(
@@ -3212,6 +6286,21 @@ mod tests {
             headers: HashMap::new(),
             response_time_ms: 20,
             final_url: None,
+            truncated: false,
+            raw_content: None,
+            http_version: None,
+            tls_profile: None,
+            har: None,
+            mhtml: None,
+            pdf: None,
+            blocked_requests: None,
+            evaluate_results: Vec::new(),
+            frames: Vec::new(),
+            accessibility: None,
+            timings: None,
+            ip_family: None,
+            blocked_by: None,
+            escalations: Vec::new(),
         };
         let schema = json!({"type": "object", "properties": {"title": {"type": "string"}}});
         let result = worker
@@ -3235,6 +6324,21 @@ mod tests {
             headers: HashMap::new(),
             response_time_ms: 10,
             final_url: None,
+            truncated: false,
+            raw_content: None,
+            http_version: None,
+            tls_profile: None,
+            har: None,
+            mhtml: None,
+            pdf: None,
+            blocked_requests: None,
+            evaluate_results: Vec::new(),
+            frames: Vec::new(),
+            accessibility: None,
+            timings: None,
+            ip_family: None,
+            blocked_by: None,
+            escalations: Vec::new(),
         };
         let result = worker
             .save_extract_result(
@@ -3260,6 +6364,21 @@ mod tests {
             headers: HashMap::new(),
             response_time_ms: 5,
             final_url: None,
+            truncated: false,
+            raw_content: None,
+            http_version: None,
+            tls_profile: None,
+            har: None,
+            mhtml: None,
+            pdf: None,
+            blocked_requests: None,
+            evaluate_results: Vec::new(),
+            frames: Vec::new(),
+            accessibility: None,
+            timings: None,
+            ip_family: None,
+            blocked_by: None,
+            escalations: Vec::new(),
         };
         let result = worker
             .save_extract_result(&mut task, &response, None, "https://example.com")
@@ -3288,6 +6407,21 @@ mod tests {
             headers: HashMap::new(),
             response_time_ms: 100,
             final_url: None,
+            truncated: false,
+            raw_content: None,
+            http_version: None,
+            tls_profile: None,
+            har: None,
+            mhtml: None,
+            pdf: None,
+            blocked_requests: None,
+            evaluate_results: Vec::new(),
+            frames: Vec::new(),
+            accessibility: None,
+            timings: None,
+            ip_family: None,
+            blocked_by: None,
+            escalations: Vec::new(),
         };
         let config = make_crawl_config(None, None);
         let result = worker
@@ -3308,6 +6442,21 @@ mod tests {
             headers: HashMap::new(),
             response_time_ms: 10,
             final_url: None,
+            truncated: false,
+            raw_content: None,
+            http_version: None,
+            tls_profile: None,
+            har: None,
+            mhtml: None,
+            pdf: None,
+            blocked_requests: None,
+            evaluate_results: Vec::new(),
+            frames: Vec::new(),
+            accessibility: None,
+            timings: None,
+            ip_family: None,
+            blocked_by: None,
+            escalations: Vec::new(),
         };
         let config = make_crawl_config(None, None);
         let result = worker
@@ -3333,6 +6482,21 @@ mod tests {
             headers: HashMap::new(),
             response_time_ms: 100,
             final_url: None,
+            truncated: false,
+            raw_content: None,
+            http_version: None,
+            tls_profile: None,
+            har: None,
+            mhtml: None,
+            pdf: None,
+            blocked_requests: None,
+            evaluate_results: Vec::new(),
+            frames: Vec::new(),
+            accessibility: None,
+            timings: None,
+            ip_family: None,
+            blocked_by: None,
+            escalations: Vec::new(),
         };
         let config = make_crawl_config(Some(vec!["example\\.com".to_string()]), None);
         let result = worker
@@ -3389,6 +6553,21 @@ mod tests {
             headers: HashMap::new(),
             response_time_ms: 50,
             final_url: None,
+            truncated: false,
+            raw_content: None,
+            http_version: None,
+            tls_profile: None,
+            har: None,
+            mhtml: None,
+            pdf: None,
+            blocked_requests: None,
+            evaluate_results: Vec::new(),
+            frames: Vec::new(),
+            accessibility: None,
+            timings: None,
+            ip_family: None,
+            blocked_by: None,
+            escalations: Vec::new(),
         };
         let result = worker.handle_scrape_success(&task, &response).await;
         assert!(result.is_ok());
@@ -3418,6 +6597,21 @@ mod tests {
             headers: HashMap::new(),
             response_time_ms: 50,
             final_url: None,
+            truncated: false,
+            raw_content: None,
+            http_version: None,
+            tls_profile: None,
+            har: None,
+            mhtml: None,
+            pdf: None,
+            blocked_requests: None,
+            evaluate_results: Vec::new(),
+            frames: Vec::new(),
+            accessibility: None,
+            timings: None,
+            ip_family: None,
+            blocked_by: None,
+            escalations: Vec::new(),
         };
         let result = worker.handle_scrape_success(&task, &response).await;
         assert!(result.is_ok());
@@ -3437,6 +6631,21 @@ mod tests {
             headers: HashMap::new(),
             response_time_ms: 100,
             final_url: None,
+            truncated: false,
+            raw_content: None,
+            http_version: None,
+            tls_profile: None,
+            har: None,
+            mhtml: None,
+            pdf: None,
+            blocked_requests: None,
+            evaluate_results: Vec::new(),
+            frames: Vec::new(),
+            accessibility: None,
+            timings: None,
+            ip_family: None,
+            blocked_by: None,
+            escalations: Vec::new(),
         };
         let config = make_crawl_config(None, None);
         let request = worker.build_crawl_request(&task, &config);
@@ -3458,6 +6667,21 @@ mod tests {
             headers: HashMap::new(),
             response_time_ms: 100,
             final_url: None,
+            truncated: false,
+            raw_content: None,
+            http_version: None,
+            tls_profile: None,
+            har: None,
+            mhtml: None,
+            pdf: None,
+            blocked_requests: None,
+            evaluate_results: Vec::new(),
+            frames: Vec::new(),
+            accessibility: None,
+            timings: None,
+            ip_family: None,
+            blocked_by: None,
+            escalations: Vec::new(),
         };
         let mut config = make_crawl_config(None, None);
         config.max_depth = 1;
@@ -3986,6 +7210,7 @@ mod tests {
             engines.engine_client.clone(),
             infra.http_client.clone(),
             &settings,
+            None,
         )
         .await;
 
@@ -4024,6 +7249,24 @@ mod tests {
             proxy: None,
             headers: None,
             extraction_rules: None,
+            skip_nofollow_links: None,
+            ignore_robots: None,
+            ignore_sitemap: None,
+            limit: None,
+            max_duration_seconds: None,
+            previous_crawl_id: None,
+            allow_subdomains: None,
+            allow_external_domains: None,
+            ignore_query_params: None,
+            ignore_all_query_params: None,
+            max_query_variants_per_path: None,
+            download_assets: None,
+            allowed_content_types: None,
+            excluded_content_types: None,
+            result_destination: None,
+            keep_local_results: None,
+            formats: None,
+            resolve: None,
         }
     }
 
@@ -4061,7 +7304,7 @@ mod tests {
         };
         let config = make_crawl_config(None, None);
         // With no include/exclude patterns, should_crawl should return true.
-        assert!(worker.should_crawl("https://example.com/page1", &config));
+        assert!(worker.should_crawl("https://example.com", "https://example.com/page1", &config));
     }
 
     #[tokio::test]
@@ -4081,9 +7324,9 @@ mod tests {
         };
         let config = make_crawl_config(Some(vec!["example\\.com".to_string()]), None);
         // URL matching include pattern → should crawl.
-        assert!(worker.should_crawl("https://example.com/page", &config));
+        assert!(worker.should_crawl("https://example.com", "https://example.com/page", &config));
         // URL not matching include pattern → should not crawl.
-        assert!(!worker.should_crawl("https://other.com/page", &config));
+        assert!(!worker.should_crawl("https://example.com", "https://other.com/page", &config));
     }
 
     #[tokio::test]
@@ -4103,9 +7346,13 @@ mod tests {
         };
         let config = make_crawl_config(None, Some(vec!["blocked".to_string()]));
         // URL not matching exclude pattern → should crawl.
-        assert!(worker.should_crawl("https://example.com/page", &config));
+        assert!(worker.should_crawl("https://example.com", "https://example.com/page", &config));
         // URL matching exclude pattern → should not crawl.
-        assert!(!worker.should_crawl("https://example.com/blocked", &config));
+        assert!(!worker.should_crawl(
+            "https://example.com",
+            "https://example.com/blocked",
+            &config
+        ));
     }
 
     #[tokio::test]
@@ -4144,6 +7391,7 @@ mod tests {
             engines.engine_client.clone(),
             infra.http_client.clone(),
             &settings,
+            None,
         )
         .await;
 
@@ -4369,6 +7617,21 @@ mod tests {
             headers: HashMap::new(),
             response_time_ms: 100,
             final_url: None,
+            truncated: false,
+            raw_content: None,
+            http_version: None,
+            tls_profile: None,
+            har: None,
+            mhtml: None,
+            pdf: None,
+            blocked_requests: None,
+            evaluate_results: Vec::new(),
+            frames: Vec::new(),
+            accessibility: None,
+            timings: None,
+            ip_family: None,
+            blocked_by: None,
+            escalations: Vec::new(),
         };
         let mut rules = HashMap::new();
         rules.insert(
@@ -4380,6 +7643,7 @@ mod tests {
                 use_llm: None,
                 llm_prompt: None,
                 output_format: None,
+                frame: None,
             },
         );
         let config = CrawlConfigDto {
@@ -4392,6 +7656,24 @@ mod tests {
             proxy: None,
             headers: None,
             extraction_rules: Some(rules),
+            skip_nofollow_links: None,
+            ignore_robots: None,
+            ignore_sitemap: None,
+            limit: None,
+            max_duration_seconds: None,
+            previous_crawl_id: None,
+            allow_subdomains: None,
+            allow_external_domains: None,
+            ignore_query_params: None,
+            ignore_all_query_params: None,
+            max_query_variants_per_path: None,
+            download_assets: None,
+            allowed_content_types: None,
+            excluded_content_types: None,
+            result_destination: None,
+            keep_local_results: None,
+            formats: None,
+            resolve: None,
         };
         let request = worker.build_crawl_request(&task, &config);
         let result = worker
@@ -4426,6 +7708,21 @@ mod tests {
             headers: HashMap::new(),
             response_time_ms: 50,
             final_url: None,
+            truncated: false,
+            raw_content: None,
+            http_version: None,
+            tls_profile: None,
+            har: None,
+            mhtml: None,
+            pdf: None,
+            blocked_requests: None,
+            evaluate_results: Vec::new(),
+            frames: Vec::new(),
+            accessibility: None,
+            timings: None,
+            ip_family: None,
+            blocked_by: None,
+            escalations: Vec::new(),
         };
         let result = worker.handle_scrape_success(&task, &response).await;
         assert!(result.is_ok());
@@ -4479,7 +7776,7 @@ mod tests {
         let config = make_crawl_config(Some(vec![]), None);
         // Empty include patterns vec: for loop doesn't run, matched stays false,
         // then `if !matched { return false; }` triggers → returns false.
-        assert!(!worker.should_crawl("https://example.com/page", &config));
+        assert!(!worker.should_crawl("https://example.com", "https://example.com/page", &config));
     }
 
     #[tokio::test]
@@ -4487,7 +7784,7 @@ mod tests {
         let worker = build_mock_worker().await;
         let config = make_crawl_config(None, Some(vec![]));
         // Empty exclude patterns — for loop doesn't run, no exclusion → returns true
-        assert!(worker.should_crawl("https://example.com/page", &config));
+        assert!(worker.should_crawl("https://example.com", "https://example.com/page", &config));
     }
 
     // --- extract_and_queue_links with DFS strategy ---
@@ -4509,6 +7806,21 @@ mod tests {
             headers: HashMap::new(),
             response_time_ms: 100,
             final_url: None,
+            truncated: false,
+            raw_content: None,
+            http_version: None,
+            tls_profile: None,
+            har: None,
+            mhtml: None,
+            pdf: None,
+            blocked_requests: None,
+            evaluate_results: Vec::new(),
+            frames: Vec::new(),
+            accessibility: None,
+            timings: None,
+            ip_family: None,
+            blocked_by: None,
+            escalations: Vec::new(),
         };
         let config = CrawlConfigDto {
             max_depth: 3,
@@ -4520,6 +7832,24 @@ mod tests {
             proxy: None,
             headers: None,
             extraction_rules: None,
+            skip_nofollow_links: None,
+            ignore_robots: None,
+            ignore_sitemap: None,
+            limit: None,
+            max_duration_seconds: None,
+            previous_crawl_id: None,
+            allow_subdomains: None,
+            allow_external_domains: None,
+            ignore_query_params: None,
+            ignore_all_query_params: None,
+            max_query_variants_per_path: None,
+            download_assets: None,
+            allowed_content_types: None,
+            excluded_content_types: None,
+            result_destination: None,
+            keep_local_results: None,
+            formats: None,
+            resolve: None,
         };
         let result = worker
             .extract_and_queue_links(&task, &response, Uuid::new_v4(), 0, &config)
@@ -4549,12 +7879,113 @@ mod tests {
             headers: HashMap::new(),
             response_time_ms: 100,
             final_url: None,
+            truncated: false,
+            raw_content: None,
+            http_version: None,
+            tls_profile: None,
+            har: None,
+            mhtml: None,
+            pdf: None,
+            blocked_requests: None,
+            evaluate_results: Vec::new(),
+            frames: Vec::new(),
+            accessibility: None,
+            timings: None,
+            ip_family: None,
+            blocked_by: None,
+            escalations: Vec::new(),
+        };
+        let config = make_crawl_config(None, None);
+        let result = worker
+            .extract_and_queue_links(&task, &response, Uuid::new_v4(), 0, &config)
+            .await;
+        assert!(result.is_ok());
+    }
+
+    // --- extract_and_queue_links consults the URL blocklist ---
+
+    #[derive(Default)]
+    struct MockUrlBlocklistRepo {
+        calls: std::sync::atomic::AtomicUsize,
+    }
+
+    #[async_trait::async_trait]
+    impl UrlBlocklistRepository for MockUrlBlocklistRepo {
+        async fn create(
+            &self,
+            entry: &crate::domain::models::BlocklistEntry,
+        ) -> Result<crate::domain::models::BlocklistEntry, RepositoryError> {
+            Ok(entry.clone())
+        }
+        async fn find_by_id(
+            &self,
+            _id: Uuid,
+        ) -> Result<Option<crate::domain::models::BlocklistEntry>, RepositoryError> {
+            Ok(None)
+        }
+        async fn find_all(
+            &self,
+        ) -> Result<Vec<crate::domain::models::BlocklistEntry>, RepositoryError> {
+            Ok(Vec::new())
+        }
+        async fn find_for_team(
+            &self,
+            team_id: Uuid,
+        ) -> Result<Vec<crate::domain::models::BlocklistEntry>, RepositoryError> {
+            self.calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            Ok(vec![crate::domain::models::BlocklistEntry::new(
+                Some(team_id),
+                "other.com",
+                crate::domain::models::BlocklistPatternType::Wildcard,
+            )])
+        }
+        async fn delete(&self, _id: Uuid) -> Result<bool, RepositoryError> {
+            Ok(false)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_mock_extract_and_queue_links_loads_team_blocklist() {
+        let repo = Arc::new(MockUrlBlocklistRepo::default());
+        let worker = build_mock_worker()
+            .await
+            .with_url_blocklist_repository(repo.clone());
+        let mut task = make_task(json!({}));
+        task.url = "https://example.com".to_string();
+        let html = r#"<html><body>
+            <a href="https://example.com/page">Allowed</a>
+            <a href="https://www.other.com/page">Blocked</a>
+        </body></html>"#;
+        let response = ScrapeResponse {
+            content: html.to_string(),
+            status_code: 200,
+            screenshot: None,
+            content_type: "text/html".to_string(),
+            headers: HashMap::new(),
+            response_time_ms: 100,
+            final_url: None,
+            truncated: false,
+            raw_content: None,
+            http_version: None,
+            tls_profile: None,
+            har: None,
+            mhtml: None,
+            pdf: None,
+            blocked_requests: None,
+            evaluate_results: Vec::new(),
+            frames: Vec::new(),
+            accessibility: None,
+            timings: None,
+            ip_family: None,
+            blocked_by: None,
+            escalations: Vec::new(),
         };
         let config = make_crawl_config(None, None);
         let result = worker
             .extract_and_queue_links(&task, &response, Uuid::new_v4(), 0, &config)
             .await;
         assert!(result.is_ok());
+        assert_eq!(repo.calls.load(std::sync::atomic::Ordering::SeqCst), 1);
     }
 
     // --- build_crawl_request with extraction_rules in config ---
@@ -4580,6 +8011,7 @@ mod tests {
                 use_llm: None,
                 llm_prompt: None,
                 output_format: None,
+                frame: None,
             },
         );
         let config = CrawlConfigDto {
@@ -4592,6 +8024,24 @@ mod tests {
             proxy: None,
             headers: None,
             extraction_rules: Some(rules),
+            skip_nofollow_links: None,
+            ignore_robots: None,
+            ignore_sitemap: None,
+            limit: None,
+            max_duration_seconds: None,
+            previous_crawl_id: None,
+            allow_subdomains: None,
+            allow_external_domains: None,
+            ignore_query_params: None,
+            ignore_all_query_params: None,
+            max_query_variants_per_path: None,
+            download_assets: None,
+            allowed_content_types: None,
+            excluded_content_types: None,
+            result_destination: None,
+            keep_local_results: None,
+            formats: None,
+            resolve: None,
         };
         let request = worker.build_crawl_request(&task, &config);
         assert_eq!(request.url, "https://example.com");
@@ -4636,6 +8086,21 @@ mod tests {
             headers: HashMap::new(),
             response_time_ms: 50,
             final_url: None,
+            truncated: false,
+            raw_content: None,
+            http_version: None,
+            tls_profile: None,
+            har: None,
+            mhtml: None,
+            pdf: None,
+            blocked_requests: None,
+            evaluate_results: Vec::new(),
+            frames: Vec::new(),
+            accessibility: None,
+            timings: None,
+            ip_family: None,
+            blocked_by: None,
+            escalations: Vec::new(),
         };
         let mut rules = HashMap::new();
         rules.insert(
@@ -4647,6 +8112,7 @@ mod tests {
                 use_llm: None,
                 llm_prompt: None,
                 output_format: None,
+                frame: None,
             },
         );
         let result = worker
@@ -4668,6 +8134,21 @@ mod tests {
             headers: HashMap::new(),
             response_time_ms: 30,
             final_url: None,
+            truncated: false,
+            raw_content: None,
+            http_version: None,
+            tls_profile: None,
+            har: None,
+            mhtml: None,
+            pdf: None,
+            blocked_requests: None,
+            evaluate_results: Vec::new(),
+            frames: Vec::new(),
+            accessibility: None,
+            timings: None,
+            ip_family: None,
+            blocked_by: None,
+            escalations: Vec::new(),
         };
         let result = worker
             .handle_prompt_extraction(
@@ -4693,6 +8174,21 @@ mod tests {
             headers: HashMap::new(),
             response_time_ms: 20,
             final_url: None,
+            truncated: false,
+            raw_content: None,
+            http_version: None,
+            tls_profile: None,
+            har: None,
+            mhtml: None,
+            pdf: None,
+            blocked_requests: None,
+            evaluate_results: Vec::new(),
+            frames: Vec::new(),
+            accessibility: None,
+            timings: None,
+            ip_family: None,
+            blocked_by: None,
+            escalations: Vec::new(),
         };
         let schema = json!({"type": "object", "properties": {"title": {"type": "string"}}});
         let result = worker
@@ -4718,6 +8214,24 @@ mod tests {
             proxy: Some("http://proxy:3128".to_string()),
             headers: None,
             extraction_rules: None,
+            skip_nofollow_links: None,
+            ignore_robots: None,
+            ignore_sitemap: None,
+            limit: None,
+            max_duration_seconds: None,
+            previous_crawl_id: None,
+            allow_subdomains: None,
+            allow_external_domains: None,
+            ignore_query_params: None,
+            ignore_all_query_params: None,
+            max_query_variants_per_path: None,
+            download_assets: None,
+            allowed_content_types: None,
+            excluded_content_types: None,
+            result_destination: None,
+            keep_local_results: None,
+            formats: None,
+            resolve: None,
         };
         let request = worker.build_crawl_request(&task, &config);
         let result = worker
@@ -4745,6 +8259,21 @@ mod tests {
             headers: HashMap::new(),
             response_time_ms: 100,
             final_url: None,
+            truncated: false,
+            raw_content: None,
+            http_version: None,
+            tls_profile: None,
+            har: None,
+            mhtml: None,
+            pdf: None,
+            blocked_requests: None,
+            evaluate_results: Vec::new(),
+            frames: Vec::new(),
+            accessibility: None,
+            timings: None,
+            ip_family: None,
+            blocked_by: None,
+            escalations: Vec::new(),
         };
         let config = make_crawl_config(None, None);
         let request = worker.build_crawl_request(&task, &config);
@@ -4768,6 +8297,21 @@ mod tests {
             headers: HashMap::new(),
             response_time_ms: 30,
             final_url: None,
+            truncated: false,
+            raw_content: None,
+            http_version: None,
+            tls_profile: None,
+            har: None,
+            mhtml: None,
+            pdf: None,
+            blocked_requests: None,
+            evaluate_results: Vec::new(),
+            frames: Vec::new(),
+            accessibility: None,
+            timings: None,
+            ip_family: None,
+            blocked_by: None,
+            escalations: Vec::new(),
         };
         let result = worker.process_text_encoding(&task, &response).await;
         // Should not panic — may succeed or fail depending on integration
@@ -4789,6 +8333,21 @@ mod tests {
             headers: HashMap::new(),
             response_time_ms: 5,
             final_url: None,
+            truncated: false,
+            raw_content: None,
+            http_version: None,
+            tls_profile: None,
+            har: None,
+            mhtml: None,
+            pdf: None,
+            blocked_requests: None,
+            evaluate_results: Vec::new(),
+            frames: Vec::new(),
+            accessibility: None,
+            timings: None,
+            ip_family: None,
+            blocked_by: None,
+            escalations: Vec::new(),
         };
         let result = worker.process_text_encoding(&task, &response).await;
         match result {
@@ -4813,6 +8372,21 @@ mod tests {
             headers: HashMap::new(),
             response_time_ms: 500,
             final_url: None,
+            truncated: false,
+            raw_content: None,
+            http_version: None,
+            tls_profile: None,
+            har: None,
+            mhtml: None,
+            pdf: None,
+            blocked_requests: None,
+            evaluate_results: Vec::new(),
+            frames: Vec::new(),
+            accessibility: None,
+            timings: None,
+            ip_family: None,
+            blocked_by: None,
+            escalations: Vec::new(),
         };
         let result = worker.save_result(&task, &response, None).await;
         assert!(result.is_ok());
@@ -4980,6 +8554,8 @@ mod tests {
         fail_increment_failed: AtomicBool,
         fail_update_status: AtomicBool,
         update_status_count: AtomicU32,
+        /// Emulates the conditional terminal transition of the database repository
+        finished: AtomicBool,
     }
 
     impl ConfigurableCrawlRepo {
@@ -4991,6 +8567,7 @@ mod tests {
                 fail_increment_failed: AtomicBool::new(false),
                 fail_update_status: AtomicBool::new(false),
                 update_status_count: AtomicU32::new(0),
+                finished: AtomicBool::new(false),
             }
         }
 
@@ -5048,6 +8625,14 @@ mod tests {
             }
             Ok(())
         }
+        async fn mark_finished(
+            &self,
+            id: Uuid,
+            status: CrawlStatus,
+        ) -> Result<bool, RepositoryError> {
+            self.update_status(id, status).await?;
+            Ok(!self.finished.swap(true, Ordering::SeqCst))
+        }
         async fn increment_total_tasks(&self, _id: Uuid) -> Result<(), RepositoryError> {
             Ok(())
         }
@@ -5080,9 +8665,40 @@ mod tests {
         async fn find_by_task_ids(&self, _task_ids: &[Uuid]) -> Result<Vec<ScrapeResult>> {
             Ok(vec![])
         }
+        async fn find_latest_for_crawl_url(
+            &self,
+            _crawl_id: Uuid,
+            _url: &str,
+        ) -> Result<Option<ScrapeResult>> {
+            Ok(None)
+        }
+        async fn count_not_modified(&self, _task_ids: &[Uuid]) -> Result<u64> {
+            Ok(0)
+        }
+        async fn response_times(&self, _task_ids: &[Uuid]) -> Result<Vec<i64>> {
+            Ok(Vec::new())
+        }
         async fn get_team_avg_response_time(&self, _team_id: Uuid) -> Result<f64> {
             Ok(0.0)
         }
+
+        async fn delete_for_team(
+            &self,
+            _team_id: Uuid,
+            _filter: &crate::domain::repositories::scrape_result_repository::ResultDeletionFilter,
+        ) -> Result<Vec<ScrapeResult>> {
+            Ok(vec![])
+        }
+
+        async fn search_crawl_results(
+            &self,
+            _crawl_id: Uuid,
+            _query: &str,
+            _limit: u64,
+        ) -> Result<Vec<crate::domain::repositories::scrape_result_repository::ResultSearchHit>>
+        {
+            Ok(vec![])
+        }
     }
 
     // --- FailingWebhookService ---
@@ -5228,6 +8844,10 @@ mod tests {
                     EngineError::Timeout(d) => EngineError::Timeout(*d),
                     EngineError::AllEnginesFailed(s) => EngineError::AllEnginesFailed(s.clone()),
                     EngineError::Expired => EngineError::Expired,
+                    EngineError::ResponseTooLarge(n) => EngineError::ResponseTooLarge(*n),
+                    EngineError::ContentTypeRejected(ct) => {
+                        EngineError::ContentTypeRejected(ct.clone())
+                    }
                     EngineError::NoEnginesAvailable => EngineError::NoEnginesAvailable,
                     EngineError::InvalidUrl(s) => EngineError::InvalidUrl(s.clone()),
                     EngineError::SsrfProtection(s) => EngineError::SsrfProtection(s.clone()),
@@ -5628,64 +9248,242 @@ mod tests {
         );
     }
 
+    /// Records every dispatched event for crawl summary assertions.
+    #[derive(Default)]
+    struct RecordingWebhookManagementService {
+        dispatched: std::sync::Mutex<Vec<(Uuid, WebhookEventType, Value)>>,
+    }
+
+    #[async_trait::async_trait]
+    impl WebhookManagementService for RecordingWebhookManagementService {
+        async fn register_webhook(
+            &self,
+            _team_id: Uuid,
+            _url: String,
+        ) -> Result<crate::domain::models::Webhook> {
+            anyhow::bail!("not used")
+        }
+
+        async fn trigger_webhook(
+            &self,
+            _webhook_id: Uuid,
+            _event_type: WebhookEventType,
+            _payload: Value,
+        ) -> Result<()> {
+            Ok(())
+        }
+
+        async fn dispatch_event(
+            &self,
+            team_id: Uuid,
+            event_type: WebhookEventType,
+            payload: Value,
+        ) -> Result<usize> {
+            self.dispatched
+                .lock()
+                .unwrap()
+                .push((team_id, event_type, payload));
+            Ok(1)
+        }
+
+        async fn retry_failed(&self, _limit: u64) -> Result<u64> {
+            Ok(0)
+        }
+
+        async fn list_webhooks(
+            &self,
+            _team_id: Uuid,
+        ) -> Result<Vec<crate::domain::models::Webhook>> {
+            Ok(vec![])
+        }
+    }
+
     #[tokio::test]
-    async fn test_update_crawl_completion_status_not_all_tasks_completed() {
+    async fn test_update_crawl_completion_status_dispatches_summary() {
         let crawl_repo = Arc::new(ConfigurableCrawlRepo::new());
         let crawl_id = Uuid::new_v4();
-        // Set up a crawl where completed + failed < total
-        let crawl = Crawl::with_all_fields(
+        let team_id = Uuid::new_v4();
+        crawl_repo.set_crawl(Crawl::with_all_fields(
             crawl_id,
-            Uuid::new_v4(),
+            team_id,
             "test".to_string(),
             "https://example.com".to_string(),
             "https://example.com".to_string(),
             CrawlStatus::Processing,
             json!({}),
-            10, // total
-            3,  // completed
-            2,  // failed
+            5,
+            4,
+            1,
             Utc::now(),
             Utc::now(),
             None,
-        );
-        crawl_repo.set_crawl(crawl);
-
+        ));
+        let management = Arc::new(RecordingWebhookManagementService::default());
         let worker = build_configurable_worker(
             Arc::new(ConfigurableTaskRepo::new()),
             crawl_repo.clone(),
             Arc::new(MockRobotsChecker),
             Arc::new(EngineClient::new()),
         )
-        .await;
+        .await
+        .with_webhook_management_service(management.clone());
 
         worker.update_crawl_completion_status(crawl_id).await;
 
-        // update_status should NOT have been called
+        let dispatched = management.dispatched.lock().unwrap();
+        assert_eq!(dispatched.len(), 1, "summary should be dispatched");
+        let (dispatched_team, event_type, payload) = &dispatched[0];
+        assert_eq!(*dispatched_team, team_id);
+        assert_eq!(*event_type, WebhookEventType::CrawlSummary);
+        assert_eq!(payload["total_pages"], 5);
+        assert_eq!(payload["succeeded_pages"], 4);
+        assert_eq!(payload["failed_pages"], 1);
+        assert_eq!(payload["changed_pages"], 4);
+        assert_eq!(payload["unchanged_pages"], 0);
+        assert_eq!(payload["status"], "completed");
         assert_eq!(
-            crawl_repo.update_status_count(),
-            0,
-            "update_status should not be called when tasks are still pending"
+            payload["credits_consumed"],
+            crate::common::constants::crawl_task::CRAWL_TASK_CREDITS_COST
+        );
+        assert_eq!(
+            payload["results_url"],
+            format!("/v1/crawl/{}/results", crawl_id)
         );
     }
 
     #[tokio::test]
-    async fn test_update_crawl_completion_status_crawl_repo_error() {
+    async fn test_racing_completions_dispatch_one_summary() {
         let crawl_repo = Arc::new(ConfigurableCrawlRepo::new());
-        crawl_repo.fail_find_by_id.store(true, Ordering::SeqCst);
         let crawl_id = Uuid::new_v4();
-
-        let worker = build_configurable_worker(
-            Arc::new(ConfigurableTaskRepo::new()),
-            crawl_repo.clone(),
-            Arc::new(MockRobotsChecker),
-            Arc::new(EngineClient::new()),
-        )
-        .await;
-
-        // Should not panic — just logs the error
-        worker.update_crawl_completion_status(crawl_id).await;
-        assert_eq!(crawl_repo.update_status_count(), 0);
-    }
+        crawl_repo.set_crawl(Crawl::with_all_fields(
+            crawl_id,
+            Uuid::new_v4(),
+            "test".to_string(),
+            "https://example.com".to_string(),
+            "https://example.com".to_string(),
+            CrawlStatus::Processing,
+            json!({}),
+            2,
+            2,
+            0,
+            Utc::now(),
+            Utc::now(),
+            None,
+        ));
+        let management = Arc::new(RecordingWebhookManagementService::default());
+        let mut workers = Vec::new();
+        for _ in 0..2 {
+            workers.push(
+                build_configurable_worker(
+                    Arc::new(ConfigurableTaskRepo::new()),
+                    crawl_repo.clone(),
+                    Arc::new(MockRobotsChecker),
+                    Arc::new(EngineClient::new()),
+                )
+                .await
+                .with_webhook_management_service(management.clone()),
+            );
+        }
+
+        // Both workers see the crawl as unfinished; only the one that wins the
+        // terminal transition dispatches the summary
+        tokio::join!(
+            workers[0].update_crawl_completion_status(crawl_id),
+            workers[1].update_crawl_completion_status(crawl_id)
+        );
+
+        assert_eq!(crawl_repo.update_status_count(), 2);
+        assert_eq!(management.dispatched.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_export_without_service_fails_and_dispatches_export_failed() {
+        let task_repo = Arc::new(ConfigurableTaskRepo::new());
+        let management = Arc::new(RecordingWebhookManagementService::default());
+        let worker = build_configurable_worker(
+            task_repo.clone(),
+            Arc::new(ConfigurableCrawlRepo::new()),
+            Arc::new(MockRobotsChecker),
+            Arc::new(EngineClient::new()),
+        )
+        .await
+        .with_webhook_management_service(management.clone());
+        let mut task = make_task(json!({}));
+        task.task_type = TaskType::Export;
+
+        worker
+            .process_export_task(task.clone())
+            .await
+            .expect("export failure should not error the worker");
+
+        assert_eq!(task_repo.mark_failed_count(), 1);
+        let dispatched = management.dispatched.lock().unwrap();
+        assert_eq!(dispatched.len(), 1);
+        let (dispatched_team, event_type, payload) = &dispatched[0];
+        assert_eq!(*dispatched_team, task.team_id);
+        assert_eq!(*event_type, WebhookEventType::ExportFailed);
+        assert_eq!(payload["export_id"], task.id.to_string());
+        assert_eq!(payload["event"], "export.failed");
+    }
+
+    #[tokio::test]
+    async fn test_update_crawl_completion_status_not_all_tasks_completed() {
+        let crawl_repo = Arc::new(ConfigurableCrawlRepo::new());
+        let crawl_id = Uuid::new_v4();
+        // Set up a crawl where completed + failed < total
+        let crawl = Crawl::with_all_fields(
+            crawl_id,
+            Uuid::new_v4(),
+            "test".to_string(),
+            "https://example.com".to_string(),
+            "https://example.com".to_string(),
+            CrawlStatus::Processing,
+            json!({}),
+            10, // total
+            3,  // completed
+            2,  // failed
+            Utc::now(),
+            Utc::now(),
+            None,
+        );
+        crawl_repo.set_crawl(crawl);
+
+        let worker = build_configurable_worker(
+            Arc::new(ConfigurableTaskRepo::new()),
+            crawl_repo.clone(),
+            Arc::new(MockRobotsChecker),
+            Arc::new(EngineClient::new()),
+        )
+        .await;
+
+        worker.update_crawl_completion_status(crawl_id).await;
+
+        // update_status should NOT have been called
+        assert_eq!(
+            crawl_repo.update_status_count(),
+            0,
+            "update_status should not be called when tasks are still pending"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_update_crawl_completion_status_crawl_repo_error() {
+        let crawl_repo = Arc::new(ConfigurableCrawlRepo::new());
+        crawl_repo.fail_find_by_id.store(true, Ordering::SeqCst);
+        let crawl_id = Uuid::new_v4();
+
+        let worker = build_configurable_worker(
+            Arc::new(ConfigurableTaskRepo::new()),
+            crawl_repo.clone(),
+            Arc::new(MockRobotsChecker),
+            Arc::new(EngineClient::new()),
+        )
+        .await;
+
+        // Should not panic — just logs the error
+        worker.update_crawl_completion_status(crawl_id).await;
+        assert_eq!(crawl_repo.update_status_count(), 0);
+    }
 
     // ========== check_robots_txt: denied and delay paths ==========
 
@@ -5862,6 +9660,21 @@ mod tests {
             headers: HashMap::new(),
             response_time_ms: 10,
             final_url: None,
+            truncated: false,
+            raw_content: None,
+            http_version: None,
+            tls_profile: None,
+            har: None,
+            mhtml: None,
+            pdf: None,
+            blocked_requests: None,
+            evaluate_results: Vec::new(),
+            frames: Vec::new(),
+            accessibility: None,
+            timings: None,
+            ip_family: None,
+            blocked_by: None,
+            escalations: Vec::new(),
         };
         let result = worker
             .save_extract_result(&mut task, &response, None, "https://example.com")
@@ -5998,6 +9811,21 @@ mod tests {
             headers: HashMap::new(),
             response_time_ms: 100,
             final_url: None,
+            truncated: false,
+            raw_content: None,
+            http_version: None,
+            tls_profile: None,
+            har: None,
+            mhtml: None,
+            pdf: None,
+            blocked_requests: None,
+            evaluate_results: Vec::new(),
+            frames: Vec::new(),
+            accessibility: None,
+            timings: None,
+            ip_family: None,
+            blocked_by: None,
+            escalations: Vec::new(),
         };
         let config = make_crawl_config(None, None);
         let request = worker.build_crawl_request(&task, &config);
@@ -6010,6 +9838,86 @@ mod tests {
         );
     }
 
+    // ========== handle_crawl_success: content type filter ==========
+
+    #[tokio::test]
+    async fn test_handle_crawl_success_skips_filtered_content_type() {
+        let regex_cache = make_regex_cache().await;
+        let settings = crate::bootstrap::config::load_settings().expect("Failed to load settings");
+
+        // 结果仓储写入即失败：被过滤的响应不应保存
+        let worker = ScrapeWorker::new(
+            Arc::new(MockTaskRepository) as Arc<dyn TaskRepository>,
+            Arc::new(FailingScrapeResultRepo) as Arc<dyn ScrapeResultRepository>,
+            Arc::new(MockCrawlRepository) as Arc<dyn CrawlRepository>,
+            Arc::new(MockWebhookService) as Arc<dyn WebhookService>,
+            Arc::new(MockCreditsRepo::default()) as Arc<dyn CreditsRepository>,
+            Arc::new(EngineClient::new()),
+            Arc::new(MockCreateScrapeUseCase) as Arc<dyn CreateScrapeUseCaseTrait>,
+            Arc::new(TeamSemaphore::new(10)),
+            Arc::new(MockRobotsChecker) as Arc<dyn RobotsCheckerTrait>,
+            Arc::new(settings),
+            10,
+            Arc::new(MockExtractionService) as Arc<dyn ExtractionServiceTrait>,
+            regex_cache,
+        );
+
+        let task = make_task(json!({}));
+        let response = ScrapeResponse {
+            content: String::new(),
+            status_code: 200,
+            screenshot: None,
+            content_type: "application/zip".to_string(),
+            headers: HashMap::new(),
+            response_time_ms: 100,
+            final_url: None,
+            truncated: false,
+            raw_content: None,
+            http_version: None,
+            tls_profile: None,
+            har: None,
+            mhtml: None,
+            pdf: None,
+            blocked_requests: None,
+            evaluate_results: Vec::new(),
+            frames: Vec::new(),
+            accessibility: None,
+            timings: None,
+            ip_family: None,
+            blocked_by: None,
+            escalations: Vec::new(),
+        };
+        let mut config = make_crawl_config(None, None);
+        config.allowed_content_types = Some(vec!["text/html".to_string()]);
+        let request = worker.build_crawl_request(&task, &config);
+        assert_eq!(
+            request.options.content_type_filter,
+            Some(ContentTypeFilter {
+                allowed: vec!["text/html".to_string()],
+                excluded: Vec::new(),
+            })
+        );
+
+        let result = worker
+            .handle_crawl_success(&task, response, Uuid::new_v4(), 0, &config, &request)
+            .await;
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_content_type_filter_from_config() {
+        let mut config = make_crawl_config(None, None);
+        assert_eq!(content_type_filter(&config), None);
+
+        config.allowed_content_types = Some(Vec::new());
+        assert_eq!(content_type_filter(&config), None);
+
+        config.excluded_content_types = Some(vec!["image/*".to_string()]);
+        let filter = content_type_filter(&config).unwrap();
+        assert!(!filter.accepts("image/png"));
+        assert!(filter.accepts("text/html"));
+    }
+
     // ========== handle_crawl_success: increment_completed_tasks error path ==========
 
     #[tokio::test]
@@ -6049,6 +9957,21 @@ mod tests {
             headers: HashMap::new(),
             response_time_ms: 100,
             final_url: None,
+            truncated: false,
+            raw_content: None,
+            http_version: None,
+            tls_profile: None,
+            har: None,
+            mhtml: None,
+            pdf: None,
+            blocked_requests: None,
+            evaluate_results: Vec::new(),
+            frames: Vec::new(),
+            accessibility: None,
+            timings: None,
+            ip_family: None,
+            blocked_by: None,
+            escalations: Vec::new(),
         };
         let mut config = make_crawl_config(None, None);
         config.max_depth = 0; // No link extraction — depth 0 < max_depth 0 is false
@@ -6150,251 +10073,1239 @@ mod tests {
         );
     }
 
-    // ========== Success-path mocks for process_scrape_task / run() coverage ==========
+    #[tokio::test]
+    async fn test_process_crawl_task_ignore_robots_skips_robots_check() {
+        let router: Arc<dyn EngineRouterTrait> = Arc::new(SuccessEngineRouter::new());
+        let engine_client = Arc::new(EngineClient::with_router(router));
 
-    // --- SuccessEngineRouter ---
+        let task_repo = Arc::new(ConfigurableTaskRepo::new());
+        let worker = build_configurable_worker(
+            task_repo.clone(),
+            Arc::new(ConfigurableCrawlRepo::new()),
+            Arc::new(DenyingRobotsChecker),
+            engine_client,
+        )
+        .await;
 
-    /// EngineRouter that returns a configurable successful InternalScrapeResponse.
-    struct SuccessEngineRouter {
-        response: crate::engines::engine_client::InternalScrapeResponse,
+        let task = make_task(json!({
+            "crawl_id": Uuid::new_v4().to_string(),
+            "depth": 0,
+            "config": {"max_depth": 0, "ignore_robots": true}
+        }));
+
+        let result = worker.process_crawl_task(task).await;
+        assert!(result.is_ok());
+        assert_eq!(
+            task_repo.mark_failed_count(),
+            0,
+            "robots.txt denial must be skipped when ignore_robots is set"
+        );
+        assert_eq!(task_repo.mark_completed_count(), 1);
     }
 
-    impl SuccessEngineRouter {
-        fn new() -> Self {
-            use crate::engines::engine_client::InternalScrapeResponse;
-            Self {
-                response: InternalScrapeResponse {
-                    status_code: 200,
-                    content: "<html><body><h1>Hello</h1></body></html>".to_string(),
-                    screenshot: None,
-                    content_type: "text/html".to_string(),
-                    headers: HashMap::new(),
-                    response_time_ms: 10,
-                },
-            }
-        }
+    // ========== seed_from_sitemaps ==========
 
-        fn with_screenshot(mut self, screenshot: String) -> Self {
-            self.response.screenshot = Some(screenshot);
-            self
-        }
-    }
+    /// Robots checker that declares a single sitemap
+    struct SitemapRobotsChecker;
 
     #[async_trait::async_trait]
-    impl EngineRouterTrait for SuccessEngineRouter {
-        async fn route(
-            &self,
-            _request: &crate::engines::engine_client::InternalScrapeRequest,
-        ) -> Result<crate::engines::engine_client::InternalScrapeResponse, EngineError> {
-            Ok(self.response.clone())
+    impl RobotsCheckerTrait for SitemapRobotsChecker {
+        async fn is_allowed(&self, _url_str: &str, _user_agent: &str) -> Result<bool> {
+            Ok(true)
         }
-        async fn aggregate(
+        async fn get_crawl_delay(
             &self,
-            _request: &crate::engines::engine_client::InternalScrapeRequest,
-        ) -> Result<crate::engines::engine_client::InternalScrapeResponse, EngineError> {
-            Ok(self.response.clone())
-        }
-        fn get_engine_stats(&self) -> std::collections::HashMap<String, EngineStats> {
-            std::collections::HashMap::new()
+            _url_str: &str,
+            _user_agent: &str,
+        ) -> Result<Option<Duration>> {
+            Ok(None)
         }
-        fn reset_engine_stats(&self, _engine_name: &str) {}
-        fn registered_engines(&self) -> Vec<String> {
-            vec!["mock-success-engine".to_string()]
+        async fn get_sitemaps(&self, _url_str: &str) -> Result<Vec<String>> {
+            Ok(vec!["https://example.com/sitemap.xml".to_string()])
         }
     }
 
-    // --- FailingExtractionService ---
+    #[tokio::test]
+    async fn test_seed_from_sitemaps_queues_filtered_urls() {
+        let mut router = SuccessEngineRouter::new();
+        router.response.content = r#"<urlset>
+            <url><loc>https://example.com</loc></url>
+            <url><loc>https://example.com/docs/a</loc></url>
+            <url><loc>https://example.com/docs/b</loc></url>
+            <url><loc>https://example.com/private/c</loc></url>
+        </urlset>"#
+            .to_string();
+        let router: Arc<dyn EngineRouterTrait> = Arc::new(router);
 
-    /// ExtractionService that always returns an error on extract().
-    struct FailingExtractionService;
+        let task_repo = Arc::new(ConfigurableTaskRepo::new());
+        let worker = build_configurable_worker(
+            task_repo.clone(),
+            Arc::new(ConfigurableCrawlRepo::new()),
+            Arc::new(SitemapRobotsChecker),
+            Arc::new(EngineClient::with_router(router)),
+        )
+        .await;
 
-    #[async_trait::async_trait]
-    impl ExtractionServiceTrait for FailingExtractionService {
-        async fn extract(
-            &self,
-            _html_content: &str,
-            _rules: &HashMap<String, ExtractionRule>,
-            _base_url: Option<&str>,
-        ) -> Result<(Value, TokenUsage)> {
-            Err(anyhow::anyhow!("Mock extraction failure"))
-        }
-        async fn extract_with_schema(
-            &self,
-            _html_content: &str,
-            _schema: &Value,
-        ) -> Result<(Value, TokenUsage)> {
-            Err(anyhow::anyhow!("Mock extraction failure"))
-        }
-        fn extract_with_selectors(
-            &self,
-            _html_content: &str,
-            _rules: &HashMap<String, ExtractionRule>,
-            _base_url: Option<&str>,
-        ) -> Result<Value> {
-            Err(anyhow::anyhow!("Mock extraction failure"))
-        }
+        let mut task = make_task(json!({}));
+        task.url = "https://example.com".to_string();
+        let config = make_crawl_config(None, Some(vec!["/private/".to_string()]));
+        worker
+            .seed_from_sitemaps(&task, Uuid::new_v4(), &config)
+            .await
+            .unwrap();
+
+        // The root URL itself and excluded URLs are not queued
+        assert_eq!(task_repo.create_count(), 2);
     }
 
-    // --- Helper: build worker with configurable credits + extraction ---
+    // ========== crawl page / time budget ==========
 
-    async fn build_worker_for_success_tests(
-        task_repo: Arc<dyn TaskRepository>,
-        engine_client: Arc<EngineClient>,
+    fn make_budget_crawl(crawl_id: Uuid, config: Value, total: i32, completed: i32) -> Crawl {
+        Crawl::with_all_fields(
+            crawl_id,
+            Uuid::new_v4(),
+            "budget".to_string(),
+            "https://example.com".to_string(),
+            "https://example.com".to_string(),
+            CrawlStatus::Processing,
+            config,
+            total,
+            completed,
+            0,
+            Utc::now() - chrono::Duration::seconds(120),
+            Utc::now(),
+            None,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_queue_crawl_links_respects_page_limit() {
+        let crawl_id = Uuid::new_v4();
+        let crawl_repo = Arc::new(ConfigurableCrawlRepo::new());
+        crawl_repo.set_crawl(make_budget_crawl(crawl_id, json!({"limit": 3}), 1, 0));
+        let task_repo = Arc::new(ConfigurableTaskRepo::new());
+        let worker = build_configurable_worker(
+            task_repo.clone(),
+            crawl_repo,
+            Arc::new(MockRobotsChecker),
+            Arc::new(EngineClient::new()),
+        )
+        .await;
+
+        let links = (1..=4)
+            .map(|i| (format!("https://example.com/{}", i), json!({})))
+            .collect();
+        let mut config = make_crawl_config(None, None);
+        config.limit = Some(3);
+        worker
+            .queue_crawl_links(&make_task(json!({})), links, crawl_id, 1, &config)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            task_repo.create_count(),
+            2,
+            "only the remaining page budget should be queued"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_process_crawl_task_stops_crawl_after_max_duration() {
+        let crawl_id = Uuid::new_v4();
+        let crawl_repo = Arc::new(ConfigurableCrawlRepo::new());
+        crawl_repo.set_crawl(make_budget_crawl(
+            crawl_id,
+            json!({"max_duration_seconds": 60}),
+            5,
+            2,
+        ));
+        let task_repo = Arc::new(ConfigurableTaskRepo::new());
+        let router: Arc<dyn EngineRouterTrait> = Arc::new(SuccessEngineRouter::new());
+        let worker = build_configurable_worker(
+            task_repo.clone(),
+            crawl_repo.clone(),
+            Arc::new(MockRobotsChecker),
+            Arc::new(EngineClient::with_router(router)),
+        )
+        .await;
+
+        let task = make_task(json!({
+            "crawl_id": crawl_id.to_string(),
+            "depth": 1,
+            "config": {"max_depth": 2, "max_duration_seconds": 60}
+        }));
+        worker.process_crawl_task(task).await.unwrap();
+
+        assert_eq!(
+            crawl_repo.update_status_count(),
+            1,
+            "crawl should be marked completed_with_limit"
+        );
+        assert_eq!(task_repo.mark_completed_count(), 0, "task must not run");
+    }
+
+    #[tokio::test]
+    async fn test_update_crawl_completion_status_reports_page_limit() {
+        let crawl_id = Uuid::new_v4();
+        let crawl_repo = Arc::new(ConfigurableCrawlRepo::new());
+        crawl_repo.set_crawl(make_budget_crawl(crawl_id, json!({"limit": 5}), 5, 5));
+        let management = Arc::new(RecordingWebhookManagementService::default());
+        let worker = build_configurable_worker(
+            Arc::new(ConfigurableTaskRepo::new()),
+            crawl_repo,
+            Arc::new(MockRobotsChecker),
+            Arc::new(EngineClient::new()),
+        )
+        .await
+        .with_webhook_management_service(management.clone());
+
+        worker.update_crawl_completion_status(crawl_id).await;
+
+        let dispatched = management.dispatched.lock().unwrap();
+        assert_eq!(dispatched.len(), 1);
+        assert_eq!(dispatched[0].2["status"], "completed_with_limit");
+    }
+
+    // ========== incremental re-crawl ==========
+
+    /// Result repository holding the previous crawl's result and recording saves
+    struct PreviousResultRepo {
+        previous: ScrapeResult,
+        saved: std::sync::Mutex<Vec<ScrapeResult>>,
+    }
+
+    #[async_trait::async_trait]
+    impl ScrapeResultRepository for PreviousResultRepo {
+        async fn save(&self, result: ScrapeResult) -> Result<()> {
+            self.saved.lock().unwrap().push(result);
+            Ok(())
+        }
+        async fn find_by_task_id(&self, _task_id: Uuid) -> Result<Option<ScrapeResult>> {
+            Ok(None)
+        }
+        async fn find_by_task_ids(&self, _task_ids: &[Uuid]) -> Result<Vec<ScrapeResult>> {
+            Ok(vec![])
+        }
+        async fn find_latest_for_crawl_url(
+            &self,
+            _crawl_id: Uuid,
+            url: &str,
+        ) -> Result<Option<ScrapeResult>> {
+            Ok(Some(self.previous.clone()).filter(|r| r.url == url))
+        }
+        async fn count_not_modified(&self, _task_ids: &[Uuid]) -> Result<u64> {
+            Ok(0)
+        }
+        async fn response_times(&self, _task_ids: &[Uuid]) -> Result<Vec<i64>> {
+            Ok(Vec::new())
+        }
+        async fn get_team_avg_response_time(&self, _team_id: Uuid) -> Result<f64> {
+            Ok(0.0)
+        }
+
+        async fn delete_for_team(
+            &self,
+            _team_id: Uuid,
+            _filter: &crate::domain::repositories::scrape_result_repository::ResultDeletionFilter,
+        ) -> Result<Vec<ScrapeResult>> {
+            Ok(vec![])
+        }
+
+        async fn search_crawl_results(
+            &self,
+            _crawl_id: Uuid,
+            _query: &str,
+            _limit: u64,
+        ) -> Result<Vec<crate::domain::repositories::scrape_result_repository::ResultSearchHit>>
+        {
+            Ok(vec![])
+        }
+    }
+
+    /// Engine router answering `304 Not Modified` and recording request headers
+    #[derive(Default)]
+    struct NotModifiedEngineRouter {
+        request_headers: std::sync::Mutex<HashMap<String, String>>,
+    }
+
+    #[async_trait::async_trait]
+    impl EngineRouterTrait for NotModifiedEngineRouter {
+        async fn route(
+            &self,
+            request: &crate::engines::engine_client::InternalScrapeRequest,
+        ) -> Result<crate::engines::engine_client::InternalScrapeResponse, EngineError> {
+            *self.request_headers.lock().unwrap() = request.headers.clone();
+            Ok(crate::engines::engine_client::InternalScrapeResponse {
+                status_code: 304,
+                content: String::new(),
+                screenshot: None,
+                content_type: String::new(),
+                headers: HashMap::from([("etag".to_string(), "\"v1\"".to_string())]),
+                response_time_ms: 5,
+                truncated: false,
+                raw_content: None,
+                http_version: None,
+                tls_profile: None,
+                har: None,
+                mhtml: None,
+                pdf: None,
+                blocked_requests: None,
+                evaluate_results: Vec::new(),
+                frames: Vec::new(),
+                accessibility: None,
+                timings: None,
+                ip_family: None,
+                escalations: Vec::new(),
+            })
+        }
+        async fn aggregate(
+            &self,
+            request: &crate::engines::engine_client::InternalScrapeRequest,
+        ) -> Result<crate::engines::engine_client::InternalScrapeResponse, EngineError> {
+            self.route(request).await
+        }
+        fn get_engine_stats(&self) -> std::collections::HashMap<String, EngineStats> {
+            std::collections::HashMap::new()
+        }
+        fn reset_engine_stats(&self, _engine_name: &str) {}
+        fn registered_engines(&self) -> Vec<String> {
+            vec!["mock-not-modified-engine".to_string()]
+        }
+    }
+
+    #[test]
+    fn test_add_conditional_headers_keeps_user_headers() {
+        let previous = ScrapeResult {
+            id: Uuid::new_v4(),
+            task_id: Uuid::new_v4(),
+            url: "https://example.com".to_string(),
+            status_code: 200,
+            content: String::new(),
+            content_type: "text/html".to_string(),
+            headers: json!({}),
+            meta_data: json!({}),
+            screenshot: None,
+            response_time_ms: 0,
+            created_at: Utc::now().naive_utc(),
+            etag: Some("\"v1\"".to_string()),
+            last_modified: Some("Wed, 21 Oct 2015 07:28:00 GMT".to_string()),
+        };
+        let mut request = ScrapeRequest::new("https://example.com".to_string());
+        request
+            .options
+            .headers
+            .insert("if-none-match".to_string(), "\"user\"".to_string());
+
+        add_conditional_headers(&mut request, &previous);
+
+        assert_eq!(request.options.headers["if-none-match"], "\"user\"");
+        assert!(!request.options.headers.contains_key("If-None-Match"));
+        assert_eq!(
+            request.options.headers["If-Modified-Since"],
+            "Wed, 21 Oct 2015 07:28:00 GMT"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_process_crawl_task_not_modified_keeps_previous_content() {
+        let previous_crawl_id = Uuid::new_v4();
+        let task = make_task(json!({
+            "crawl_id": Uuid::new_v4().to_string(),
+            "depth": 0,
+            "config": {
+                "max_depth": 1,
+                "ignore_sitemap": true,
+                "previous_crawl_id": previous_crawl_id
+            }
+        }));
+        let previous = ScrapeResult {
+            id: Uuid::new_v4(),
+            task_id: Uuid::new_v4(),
+            url: task.url.clone(),
+            status_code: 200,
+            content: r#"<html><body><a href="/next">Next</a></body></html>"#.to_string(),
+            content_type: "text/html".to_string(),
+            headers: json!({}),
+            meta_data: json!({}),
+            screenshot: None,
+            response_time_ms: 10,
+            created_at: Utc::now().naive_utc(),
+            etag: Some("\"v1\"".to_string()),
+            last_modified: None,
+        };
+        let result_repo = Arc::new(PreviousResultRepo {
+            previous: previous.clone(),
+            saved: std::sync::Mutex::new(Vec::new()),
+        });
+        let router = Arc::new(NotModifiedEngineRouter::default());
+        let task_repo = Arc::new(ConfigurableTaskRepo::new());
+        let mut worker = build_configurable_worker(
+            task_repo.clone(),
+            Arc::new(ConfigurableCrawlRepo::new()),
+            Arc::new(MockRobotsChecker),
+            Arc::new(EngineClient::with_router(
+                router.clone() as Arc<dyn EngineRouterTrait>
+            )),
+        )
+        .await;
+        worker.result_repository = result_repo.clone();
+
+        worker.process_crawl_task(task).await.unwrap();
+
+        assert_eq!(
+            router.request_headers.lock().unwrap().get("If-None-Match"),
+            Some(&"\"v1\"".to_string())
+        );
+        let saved = result_repo.saved.lock().unwrap();
+        assert_eq!(saved.len(), 1);
+        assert_eq!(saved[0].status_code, 304);
+        assert!(saved[0].content.is_empty(), "304 must not re-store content");
+        assert_eq!(saved[0].etag.as_deref(), Some("\"v1\""));
+        assert_eq!(saved[0].meta_data["unchanged"], true);
+        assert_eq!(
+            saved[0].meta_data["content_task_id"],
+            previous.task_id.to_string()
+        );
+        assert_eq!(task_repo.mark_completed_count(), 1);
+        assert_eq!(
+            task_repo.create_count(),
+            1,
+            "links of the unchanged page come from the previous content"
+        );
+    }
+
+    // ========== Success-path mocks for process_scrape_task / run() coverage ==========
+
+    // --- SuccessEngineRouter ---
+
+    /// EngineRouter that returns a configurable successful InternalScrapeResponse.
+    struct SuccessEngineRouter {
+        response: crate::engines::engine_client::InternalScrapeResponse,
+    }
+
+    impl SuccessEngineRouter {
+        fn new() -> Self {
+            use crate::engines::engine_client::InternalScrapeResponse;
+            Self {
+                response: InternalScrapeResponse {
+                    status_code: 200,
+                    content: "<html><body><h1>Hello</h1></body></html>".to_string(),
+                    screenshot: None,
+                    content_type: "text/html".to_string(),
+                    headers: HashMap::new(),
+                    response_time_ms: 10,
+                    truncated: false,
+                    raw_content: None,
+                    http_version: None,
+                    tls_profile: None,
+                    har: None,
+                    mhtml: None,
+                    pdf: None,
+                    blocked_requests: None,
+                    evaluate_results: Vec::new(),
+                    frames: Vec::new(),
+                    accessibility: None,
+                    timings: None,
+                    ip_family: None,
+                    escalations: Vec::new(),
+                },
+            }
+        }
+
+        fn with_screenshot(mut self, screenshot: String) -> Self {
+            self.response.screenshot = Some(screenshot);
+            self
+        }
+
+        fn with_cloudflare_challenge(mut self) -> Self {
+            self.response.status_code = 403;
+            self.response
+                .headers
+                .insert("cf-mitigated".to_string(), "challenge".to_string());
+            self.response.content = "<title>Just a moment...</title>".to_string();
+            self
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl EngineRouterTrait for SuccessEngineRouter {
+        async fn route(
+            &self,
+            _request: &crate::engines::engine_client::InternalScrapeRequest,
+        ) -> Result<crate::engines::engine_client::InternalScrapeResponse, EngineError> {
+            Ok(self.response.clone())
+        }
+        async fn aggregate(
+            &self,
+            _request: &crate::engines::engine_client::InternalScrapeRequest,
+        ) -> Result<crate::engines::engine_client::InternalScrapeResponse, EngineError> {
+            Ok(self.response.clone())
+        }
+        fn get_engine_stats(&self) -> std::collections::HashMap<String, EngineStats> {
+            std::collections::HashMap::new()
+        }
+        fn reset_engine_stats(&self, _engine_name: &str) {}
+        fn registered_engines(&self) -> Vec<String> {
+            vec!["mock-success-engine".to_string()]
+        }
+    }
+
+    // --- FailingExtractionService ---
+
+    /// ExtractionService that always returns an error on extract().
+    struct FailingExtractionService;
+
+    #[async_trait::async_trait]
+    impl ExtractionServiceTrait for FailingExtractionService {
+        async fn extract(
+            &self,
+            _html_content: &str,
+            _rules: &HashMap<String, ExtractionRule>,
+            _base_url: Option<&str>,
+        ) -> Result<(Value, TokenUsage)> {
+            Err(anyhow::anyhow!("Mock extraction failure"))
+        }
+        async fn extract_with_schema(
+            &self,
+            _html_content: &str,
+            _schema: &Value,
+        ) -> Result<(Value, TokenUsage)> {
+            Err(anyhow::anyhow!("Mock extraction failure"))
+        }
+        fn extract_with_selectors(
+            &self,
+            _html_content: &str,
+            _rules: &HashMap<String, ExtractionRule>,
+            _base_url: Option<&str>,
+        ) -> Result<Value> {
+            Err(anyhow::anyhow!("Mock extraction failure"))
+        }
+    }
+
+    // --- Helper: build worker with configurable credits + extraction ---
+
+    async fn build_worker_for_success_tests(
+        task_repo: Arc<dyn TaskRepository>,
+        engine_client: Arc<EngineClient>,
         credits_repo: Arc<dyn CreditsRepository>,
         extraction_service: Arc<dyn ExtractionServiceTrait>,
     ) -> ScrapeWorker {
         let regex_cache = make_regex_cache().await;
-        let settings = crate::bootstrap::config::load_settings()
-            .expect("Failed to load settings for success tests");
+        let settings = crate::bootstrap::config::load_settings()
+            .expect("Failed to load settings for success tests");
+        let team_semaphore = Arc::new(TeamSemaphore::new(10));
+
+        ScrapeWorker::new(
+            task_repo,
+            Arc::new(MockScrapeResultRepository) as Arc<dyn ScrapeResultRepository>,
+            Arc::new(ConfigurableCrawlRepo::new()) as Arc<dyn CrawlRepository>,
+            Arc::new(MockWebhookService) as Arc<dyn WebhookService>,
+            credits_repo,
+            engine_client,
+            Arc::new(MockCreateScrapeUseCase) as Arc<dyn CreateScrapeUseCaseTrait>,
+            team_semaphore,
+            Arc::new(MockRobotsChecker) as Arc<dyn RobotsCheckerTrait>,
+            Arc::new(settings),
+            10,
+            extraction_service,
+            regex_cache,
+        )
+    }
+
+    // ========== process_scrape_task: success path ==========
+
+    #[tokio::test]
+    async fn test_process_scrape_task_success_path_marks_completed() {
+        let router: Arc<dyn EngineRouterTrait> = Arc::new(SuccessEngineRouter::new());
+        let engine_client = Arc::new(EngineClient::with_router(router));
+
+        let task_repo = Arc::new(ConfigurableTaskRepo::new());
+        let worker = build_worker_for_success_tests(
+            task_repo.clone(),
+            engine_client,
+            Arc::new(MockCreditsRepo::default()) as Arc<dyn CreditsRepository>,
+            Arc::new(MockExtractionService) as Arc<dyn ExtractionServiceTrait>,
+        )
+        .await;
+
+        let task = make_task(json!({"url": "https://example.com"}));
+        let result = worker.process_scrape_task(task).await;
+        assert!(result.is_ok(), "success path should return Ok(())");
+
+        // mark_completed should be called by handle_scrape_success
+        assert_eq!(
+            task_repo.mark_completed_count(),
+            1,
+            "mark_completed should be called once for successful scrape"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_process_scrape_task_blocked_response_fails_with_blocked_reason() {
+        let router: Arc<dyn EngineRouterTrait> =
+            Arc::new(SuccessEngineRouter::new().with_cloudflare_challenge());
+        let engine_client = Arc::new(EngineClient::with_router(router));
+
+        let task_repo = Arc::new(ConfigurableTaskRepo::new());
+        let worker = build_worker_for_success_tests(
+            task_repo.clone(),
+            engine_client,
+            Arc::new(MockCreditsRepo::default()) as Arc<dyn CreditsRepository>,
+            Arc::new(MockExtractionService) as Arc<dyn ExtractionServiceTrait>,
+        )
+        .await;
+
+        let mut task = make_task(json!({"url": "https://example.com"}));
+        task.status = TaskStatus::Active;
+        task_repo
+            .find_by_id_result
+            .lock()
+            .unwrap()
+            .replace(task.clone());
+
+        let result = worker.process_scrape_task(task).await;
+        assert!(result.is_ok());
+        assert_eq!(task_repo.mark_completed_count(), 0);
+        assert_eq!(task_repo.update_count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_process_scrape_task_success_with_screenshot_and_proxy_deducts_credits() {
+        // Engine returns a response with screenshot
+        let router: Arc<dyn EngineRouterTrait> =
+            Arc::new(SuccessEngineRouter::new().with_screenshot("base64data".to_string()));
+        let engine_client = Arc::new(EngineClient::with_router(router));
+
+        let credits_repo = Arc::new(MockCreditsRepo::default());
+        // Share the deducted log so we can inspect after
+        let deducted_log = credits_repo.deducted.clone();
+
+        let task_repo = Arc::new(ConfigurableTaskRepo::new());
+        let worker = build_worker_for_success_tests(
+            task_repo.clone(),
+            engine_client,
+            credits_repo as Arc<dyn CreditsRepository>,
+            Arc::new(MockExtractionService) as Arc<dyn ExtractionServiceTrait>,
+        )
+        .await;
+
+        // Payload includes proxy option → deduct_feature_credits should charge 2 (screenshot) + 1 (proxy) = 3
+        let task = make_task(json!({
+            "url": "https://example.com",
+            "options": {
+                "proxy": "http://proxy.example.com:8080"
+            }
+        }));
+        let result = worker.process_scrape_task(task).await;
+        assert!(result.is_ok());
+
+        // At least one deduct_credits call (extra credits for screenshot + proxy)
+        let deductions = deducted_log.lock().unwrap();
+        assert!(
+            !deductions.is_empty(),
+            "deduct_credits should be called for screenshot + proxy"
+        );
+        // The extra-credits call should be 3 (screenshot=2 + proxy=1)
+        let has_extra_credits = deductions.iter().any(|(_, amount)| *amount == 3);
+        assert!(
+            has_extra_credits,
+            "expected a deduct_credits call with amount 3 (screenshot + proxy), got {:?}",
+            deductions
+        );
+    }
+
+    #[tokio::test]
+    async fn test_process_scrape_task_success_handle_scrape_failure_calls_handle_failure() {
+        // Engine succeeds but save_result fails → handle_scrape_success returns Err
+        // → process_scrape_task calls handle_failure
+        let router: Arc<dyn EngineRouterTrait> = Arc::new(SuccessEngineRouter::new());
+        let engine_client = Arc::new(EngineClient::with_router(router));
+
+        let task_repo = Arc::new(ConfigurableTaskRepo::new());
+        let regex_cache = make_regex_cache().await;
+        let settings = crate::bootstrap::config::load_settings().expect("Failed to load settings");
         let team_semaphore = Arc::new(TeamSemaphore::new(10));
 
-        ScrapeWorker::new(
-            task_repo,
-            Arc::new(MockScrapeResultRepository) as Arc<dyn ScrapeResultRepository>,
-            Arc::new(ConfigurableCrawlRepo::new()) as Arc<dyn CrawlRepository>,
-            Arc::new(MockWebhookService) as Arc<dyn WebhookService>,
-            credits_repo,
-            engine_client,
-            Arc::new(MockCreateScrapeUseCase) as Arc<dyn CreateScrapeUseCaseTrait>,
-            team_semaphore,
-            Arc::new(MockRobotsChecker) as Arc<dyn RobotsCheckerTrait>,
-            Arc::new(settings),
-            10,
-            extraction_service,
-            regex_cache,
-        )
+        let worker = ScrapeWorker::new(
+            task_repo.clone(),
+            Arc::new(FailingScrapeResultRepo) as Arc<dyn ScrapeResultRepository>,
+            Arc::new(ConfigurableCrawlRepo::new()) as Arc<dyn CrawlRepository>,
+            Arc::new(MockWebhookService) as Arc<dyn WebhookService>,
+            Arc::new(MockCreditsRepo::default()) as Arc<dyn CreditsRepository>,
+            engine_client,
+            Arc::new(MockCreateScrapeUseCase) as Arc<dyn CreateScrapeUseCaseTrait>,
+            team_semaphore,
+            Arc::new(MockRobotsChecker) as Arc<dyn RobotsCheckerTrait>,
+            Arc::new(settings),
+            10,
+            Arc::new(MockExtractionService) as Arc<dyn ExtractionServiceTrait>,
+            regex_cache,
+        );
+
+        let task = make_task(json!({"url": "https://example.com"}));
+        let result = worker.process_scrape_task(task).await;
+        // handle_failure path returns Ok(()) after retry handling
+        assert!(result.is_ok(), "handle_failure path should return Ok(())");
+    }
+
+    // ========== run() infinite loop coverage ==========
+
+    #[tokio::test]
+    async fn test_run_with_empty_queue_loops_and_sleeps() {
+        let worker = build_mock_worker().await;
+        let queue = Arc::new(MockTaskQueue) as Arc<dyn TaskQueue>;
+
+        // run() is an infinite loop; with empty queue it sleeps 1s per iteration.
+        // Use timeout to verify it enters the loop without returning.
+        let result =
+            tokio::time::timeout(Duration::from_millis(150), worker.run(Arc::clone(&queue))).await;
+
+        // Timeout means run() was still looping (expected behavior)
+        assert!(
+            result.is_err(),
+            "run() should loop indefinitely; timeout expected"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_run_processes_task_then_continues_looping() {
+        let router: Arc<dyn EngineRouterTrait> = Arc::new(SuccessEngineRouter::new());
+        let engine_client = Arc::new(EngineClient::with_router(router));
+        let task_repo = Arc::new(ConfigurableTaskRepo::new());
+        let worker = build_worker_for_success_tests(
+            task_repo.clone(),
+            engine_client,
+            Arc::new(MockCreditsRepo::default()) as Arc<dyn CreditsRepository>,
+            Arc::new(MockExtractionService) as Arc<dyn ExtractionServiceTrait>,
+        )
+        .await;
+
+        let task = make_task(json!({"url": "https://example.com"}));
+        let queue = Arc::new(TaskQueueWithTask::new(task)) as Arc<dyn TaskQueue>;
+
+        // run() processes the task then loops (sleeping on empty queue)
+        let result = tokio::time::timeout(Duration::from_millis(500), worker.run(queue)).await;
+
+        // Timeout means run() processed the task and continued looping
+        assert!(
+            result.is_err(),
+            "run() should loop indefinitely after processing"
+        );
+
+        // mark_completed should have been called for the scrape task
+        assert!(
+            task_repo.mark_completed_count() >= 1,
+            "mark_completed should be called after processing scrape task in run()"
+        );
+    }
+
+    // ========== extract_and_queue_links: find_existing_urls failure path ==========
+
+    #[tokio::test]
+    async fn test_extract_and_queue_links_find_existing_urls_failure_returns_err() {
+        let task_repo = Arc::new(ConfigurableTaskRepo::new());
+        // Configure find_existing_urls to fail
+        task_repo
+            .fail_find_existing_urls
+            .store(true, Ordering::SeqCst);
+
+        let worker = build_configurable_worker(
+            task_repo,
+            Arc::new(ConfigurableCrawlRepo::new()),
+            Arc::new(MockRobotsChecker),
+            Arc::new(EngineClient::new()),
+        )
+        .await;
+
+        let mut task = make_task(json!({}));
+        task.url = "https://example.com".to_string();
+        let html = r#"<html><body>
+            <a href="https://example.com/page1">Page 1</a>
+            <a href="https://example.com/page2">Page 2</a>
+        </body></html>"#;
+        let response = ScrapeResponse {
+            content: html.to_string(),
+            status_code: 200,
+            screenshot: None,
+            content_type: "text/html".to_string(),
+            headers: HashMap::new(),
+            response_time_ms: 10,
+            final_url: None,
+            truncated: false,
+            raw_content: None,
+            http_version: None,
+            tls_profile: None,
+            har: None,
+            mhtml: None,
+            pdf: None,
+            blocked_requests: None,
+            evaluate_results: Vec::new(),
+            frames: Vec::new(),
+            accessibility: None,
+            timings: None,
+            ip_family: None,
+            blocked_by: None,
+            escalations: Vec::new(),
+        };
+        let config = make_crawl_config(None, None);
+        let result = worker
+            .extract_and_queue_links(&task, &response, Uuid::new_v4(), 0, &config)
+            .await;
+
+        assert!(
+            result.is_err(),
+            "extract_and_queue_links should return Err when find_existing_urls fails"
+        );
+    }
+
+    // ========== handle_scrape_success: extraction failure path ==========
+
+    #[tokio::test]
+    async fn test_handle_scrape_success_extraction_failure_continues_and_marks_completed() {
+        let router: Arc<dyn EngineRouterTrait> = Arc::new(SuccessEngineRouter::new());
+        let engine_client = Arc::new(EngineClient::with_router(router));
+
+        let task_repo = Arc::new(ConfigurableTaskRepo::new());
+        let worker = build_worker_for_success_tests(
+            task_repo.clone(),
+            engine_client,
+            Arc::new(MockCreditsRepo::default()) as Arc<dyn CreditsRepository>,
+            Arc::new(FailingExtractionService) as Arc<dyn ExtractionServiceTrait>,
+        )
+        .await;
+
+        // Payload includes extraction_rules → extraction will be attempted and fail,
+        // but handle_scrape_success should continue and still mark_completed.
+        let task = make_task(json!({
+            "url": "https://example.com",
+            "extraction_rules": {
+                "title": {
+                    "selector": "h1",
+                    "attr": null,
+                    "is_array": false,
+                    "use_llm": null,
+                    "llm_prompt": null,
+                    "output_format": null
+                }
+            }
+        }));
+        let response = ScrapeResponse {
+            content: "<html><body><h1>Title</h1></body></html>".to_string(),
+            status_code: 200,
+            screenshot: None,
+            content_type: "text/html".to_string(),
+            headers: HashMap::new(),
+            response_time_ms: 10,
+            final_url: None,
+            truncated: false,
+            raw_content: None,
+            http_version: None,
+            tls_profile: None,
+            har: None,
+            mhtml: None,
+            pdf: None,
+            blocked_requests: None,
+            evaluate_results: Vec::new(),
+            frames: Vec::new(),
+            accessibility: None,
+            timings: None,
+            ip_family: None,
+            blocked_by: None,
+            escalations: Vec::new(),
+        };
+
+        let result = worker.handle_scrape_success(&task, &response).await;
+        assert!(
+            result.is_ok(),
+            "handle_scrape_success should not fail when extraction fails"
+        );
+
+        // mark_completed should still be called
+        assert_eq!(
+            task_repo.mark_completed_count(),
+            1,
+            "mark_completed should be called even when extraction fails"
+        );
+    }
+
+    // ========== process_scrape_task: success path without screenshot/proxy (no extra credits) ==========
+
+    #[tokio::test]
+    async fn test_process_scrape_task_success_without_screenshot_proxy_no_extra_credits() {
+        let router: Arc<dyn EngineRouterTrait> = Arc::new(SuccessEngineRouter::new());
+        let engine_client = Arc::new(EngineClient::with_router(router));
+
+        let credits_repo = Arc::new(MockCreditsRepo::default());
+        let deducted_log = credits_repo.deducted.clone();
+
+        let task_repo = Arc::new(ConfigurableTaskRepo::new());
+        let worker = build_worker_for_success_tests(
+            task_repo.clone(),
+            engine_client,
+            credits_repo as Arc<dyn CreditsRepository>,
+            Arc::new(MockExtractionService) as Arc<dyn ExtractionServiceTrait>,
+        )
+        .await;
+
+        // No screenshot, no proxy → deduct_feature_credits does nothing (extra_credits == 0)
+        let task = make_task(json!({"url": "https://example.com"}));
+        let result = worker.process_scrape_task(task).await;
+        assert!(result.is_ok());
+
+        // No deduct_credits calls for feature credits (extraction also returns 0 tokens)
+        let deductions = deducted_log.lock().unwrap();
+        assert!(
+            deductions.is_empty(),
+            "no deduct_credits calls expected without screenshot/proxy/token-usage, got {:?}",
+            deductions
+        );
+    }
+
+    // ========== run() Err path: FailingTaskQueue exercises lines 138-140 ==========
+
+    #[tokio::test]
+    async fn test_run_with_failing_queue_logs_error_and_sleeps() {
+        let worker = build_mock_worker().await;
+        let queue = Arc::new(FailingTaskQueue) as Arc<dyn TaskQueue>;
+
+        // run() is an infinite loop; with FailingTaskQueue, dequeue returns Err
+        // → process_next_task returns Err → run() hits the Err branch (lines 138-140)
+        // and sleeps 1s per iteration. Use timeout to verify it enters the loop.
+        let result =
+            tokio::time::timeout(Duration::from_millis(150), worker.run(Arc::clone(&queue))).await;
+
+        // Timeout means run() was still looping (expected behavior)
+        assert!(
+            result.is_err(),
+            "run() should loop indefinitely on queue errors; timeout expected"
+        );
     }
 
-    // ========== process_scrape_task: success path ==========
+    // ========== process_crawl_task success path: exercises lines 372-374 ==========
 
     #[tokio::test]
-    async fn test_process_scrape_task_success_path_marks_completed() {
+    async fn test_process_crawl_task_success_calls_handle_crawl_success() {
         let router: Arc<dyn EngineRouterTrait> = Arc::new(SuccessEngineRouter::new());
         let engine_client = Arc::new(EngineClient::with_router(router));
 
         let task_repo = Arc::new(ConfigurableTaskRepo::new());
-        let worker = build_worker_for_success_tests(
+        let worker = build_configurable_worker(
             task_repo.clone(),
+            Arc::new(ConfigurableCrawlRepo::new()),
+            Arc::new(MockRobotsChecker),
             engine_client,
-            Arc::new(MockCreditsRepo::default()) as Arc<dyn CreditsRepository>,
-            Arc::new(MockExtractionService) as Arc<dyn ExtractionServiceTrait>,
         )
         .await;
 
-        let task = make_task(json!({"url": "https://example.com"}));
-        let result = worker.process_scrape_task(task).await;
+        let crawl_id = Uuid::new_v4();
+        // max_depth: 0 → depth 0 < max_depth 0 is false → no link extraction
+        let task = make_task(json!({
+            "crawl_id": crawl_id.to_string(),
+            "depth": 0,
+            "config": {"max_depth": 0}
+        }));
+
+        let result = worker.process_crawl_task(task).await;
         assert!(result.is_ok(), "success path should return Ok(())");
 
-        // mark_completed should be called by handle_scrape_success
+        // mark_completed should be called by handle_crawl_success
         assert_eq!(
             task_repo.mark_completed_count(),
             1,
-            "mark_completed should be called once for successful scrape"
+            "mark_completed should be called once for successful crawl"
         );
     }
 
+    // ========== extract_data_with_rules failure path: exercises lines 509-511 ==========
+
     #[tokio::test]
-    async fn test_process_scrape_task_success_with_screenshot_and_proxy_deducts_credits() {
-        // Engine returns a response with screenshot
-        let router: Arc<dyn EngineRouterTrait> =
-            Arc::new(SuccessEngineRouter::new().with_screenshot("base64data".to_string()));
+    async fn test_extract_data_with_rules_failure_returns_none_and_logs() {
+        let router: Arc<dyn EngineRouterTrait> = Arc::new(SuccessEngineRouter::new());
         let engine_client = Arc::new(EngineClient::with_router(router));
 
-        let credits_repo = Arc::new(MockCreditsRepo::default());
-        // Share the deducted log so we can inspect after
-        let deducted_log = credits_repo.deducted.clone();
+        let worker = build_worker_for_success_tests(
+            Arc::new(ConfigurableTaskRepo::new()),
+            engine_client,
+            Arc::new(MockCreditsRepo::default()) as Arc<dyn CreditsRepository>,
+            Arc::new(FailingExtractionService) as Arc<dyn ExtractionServiceTrait>,
+        )
+        .await;
+
+        let task = make_task(json!({}));
+        let response = ScrapeResponse {
+            content: "<html><body><h1>Title</h1></body></html>".to_string(),
+            status_code: 200,
+            screenshot: None,
+            content_type: "text/html".to_string(),
+            headers: HashMap::new(),
+            response_time_ms: 10,
+            final_url: None,
+            truncated: false,
+            raw_content: None,
+            http_version: None,
+            tls_profile: None,
+            har: None,
+            mhtml: None,
+            pdf: None,
+            blocked_requests: None,
+            evaluate_results: Vec::new(),
+            frames: Vec::new(),
+            accessibility: None,
+            timings: None,
+            ip_family: None,
+            blocked_by: None,
+            escalations: Vec::new(),
+        };
+        let mut rules = HashMap::new();
+        rules.insert(
+            "title".to_string(),
+            ExtractionRule {
+                selector: Some("h1".to_string()),
+                attr: None,
+                is_array: false,
+                use_llm: None,
+                llm_prompt: None,
+                output_format: None,
+                frame: None,
+            },
+        );
+        let config = CrawlConfigDto {
+            max_depth: 1,
+            include_patterns: None,
+            exclude_patterns: None,
+            strategy: None,
+            crawl_delay_ms: None,
+            max_concurrency: None,
+            proxy: None,
+            headers: None,
+            extraction_rules: Some(rules),
+            skip_nofollow_links: None,
+            ignore_robots: None,
+            ignore_sitemap: None,
+            limit: None,
+            max_duration_seconds: None,
+            previous_crawl_id: None,
+            allow_subdomains: None,
+            allow_external_domains: None,
+            ignore_query_params: None,
+            ignore_all_query_params: None,
+            max_query_variants_per_path: None,
+            download_assets: None,
+            allowed_content_types: None,
+            excluded_content_types: None,
+            result_destination: None,
+            keep_local_results: None,
+            formats: None,
+            resolve: None,
+        };
+
+        // FailingExtractionService.extract returns Err → lines 509-511
+        let result = worker
+            .extract_data_with_rules(&task, &response, &config)
+            .await;
+        assert!(
+            result.is_none(),
+            "extract_data_with_rules should return None when extraction fails"
+        );
+    }
+
+    // ========== handle_crawl_failure: increment_failed_tasks error (line 540) ==========
+
+    #[tokio::test]
+    async fn test_handle_crawl_failure_increment_failed_error_logs_and_continues() {
+        let crawl_repo = Arc::new(ConfigurableCrawlRepo::new());
+        crawl_repo
+            .fail_increment_failed
+            .store(true, Ordering::SeqCst);
+
+        let worker = build_configurable_worker(
+            Arc::new(ConfigurableTaskRepo::new()),
+            crawl_repo,
+            Arc::new(MockRobotsChecker),
+            Arc::new(EngineClient::new()),
+        )
+        .await;
+
+        let mut task = make_task(json!({}));
+        let config = make_crawl_config(None, None);
+        let request = worker.build_crawl_request(&task, &config);
+        let result = worker
+            .handle_crawl_failure(
+                &mut task,
+                anyhow::anyhow!("Network error"),
+                Uuid::new_v4(),
+                &request,
+            )
+            .await;
+
+        // Should succeed — increment_failed_tasks error is just logged (line 540)
+        assert!(
+            result.is_ok(),
+            "handle_crawl_failure should not fail when increment_failed_tasks errors"
+        );
+    }
+
+    // ========== update_crawl_completion_status: update_status error (line 569) ==========
+
+    #[tokio::test]
+    async fn test_update_crawl_completion_status_update_status_error_logs_and_continues() {
+        let crawl_repo = Arc::new(ConfigurableCrawlRepo::new());
+        crawl_repo.fail_update_status.store(true, Ordering::SeqCst);
+
+        let crawl_id = Uuid::new_v4();
+        // completed + failed == total → enters the update_status branch
+        let crawl = Crawl::with_all_fields(
+            crawl_id,
+            Uuid::new_v4(),
+            "test".to_string(),
+            "https://example.com".to_string(),
+            "https://example.com".to_string(),
+            CrawlStatus::Processing,
+            json!({}),
+            10,
+            8,
+            2,
+            Utc::now(),
+            Utc::now(),
+            None,
+        );
+        crawl_repo.set_crawl(crawl);
+
+        let worker = build_configurable_worker(
+            Arc::new(ConfigurableTaskRepo::new()),
+            crawl_repo.clone(),
+            Arc::new(MockRobotsChecker),
+            Arc::new(EngineClient::new()),
+        )
+        .await;
+
+        // update_status returns Err → line 569 error log
+        worker.update_crawl_completion_status(crawl_id).await;
+
+        // update_status was called (and failed)
+        assert_eq!(
+            crawl_repo.update_status_count(),
+            1,
+            "update_status should be called once even when it errors"
+        );
+    }
+
+    // ========== process_extract_task: rules path (lines 622, 630-634, 644-647) ==========
+
+    #[tokio::test]
+    async fn test_process_extract_task_with_rules_calls_handle_rules_extraction() {
+        let router: Arc<dyn EngineRouterTrait> = Arc::new(SuccessEngineRouter::new());
+        let engine_client = Arc::new(EngineClient::with_router(router));
 
         let task_repo = Arc::new(ConfigurableTaskRepo::new());
         let worker = build_worker_for_success_tests(
             task_repo.clone(),
             engine_client,
-            credits_repo as Arc<dyn CreditsRepository>,
+            Arc::new(MockCreditsRepo::default()) as Arc<dyn CreditsRepository>,
             Arc::new(MockExtractionService) as Arc<dyn ExtractionServiceTrait>,
         )
         .await;
 
-        // Payload includes proxy option → deduct_feature_credits should charge 2 (screenshot) + 1 (proxy) = 3
+        // payload.rules = Some → line 622 (debug! rules_count) + lines 644-647 (handle_rules_extraction)
         let task = make_task(json!({
-            "url": "https://example.com",
-            "options": {
-                "proxy": "http://proxy.example.com:8080"
+            "urls": ["https://example.com/page"],
+            "rules": {
+                "title": {
+                    "selector": "h1",
+                    "attr": null,
+                    "is_array": false,
+                    "use_llm": null,
+                    "llm_prompt": null,
+                    "output_format": null
+                }
             }
         }));
-        let result = worker.process_scrape_task(task).await;
-        assert!(result.is_ok());
 
-        // At least one deduct_credits call (extra credits for screenshot + proxy)
-        let deductions = deducted_log.lock().unwrap();
+        let result = worker.process_extract_task(task).await;
+        assert!(
+            result.is_ok(),
+            "process_extract_task with rules should return Ok(())"
+        );
+        // save_extract_result calls repository.update (mark as Completed)
+        assert!(
+            task_repo.update_count() >= 1,
+            "update should be called to mark task as Completed"
+        );
+    }
+
+    // ========== process_extract_task: prompt path (lines 650-653) ==========
+
+    #[tokio::test]
+    async fn test_process_extract_task_with_prompt_calls_handle_prompt_extraction() {
+        let router: Arc<dyn EngineRouterTrait> = Arc::new(SuccessEngineRouter::new());
+        let engine_client = Arc::new(EngineClient::with_router(router));
+
+        let task_repo = Arc::new(ConfigurableTaskRepo::new());
+        let worker = build_worker_for_success_tests(
+            task_repo.clone(),
+            engine_client,
+            Arc::new(MockCreditsRepo::default()) as Arc<dyn CreditsRepository>,
+            Arc::new(MockExtractionService) as Arc<dyn ExtractionServiceTrait>,
+        )
+        .await;
+
+        // payload.prompt = Some (no rules) → lines 650-653 (handle_prompt_extraction)
+        let task = make_task(json!({
+            "urls": ["https://example.com/page"],
+            "prompt": "Extract the title"
+        }));
+
+        let result = worker.process_extract_task(task).await;
         assert!(
-            !deductions.is_empty(),
-            "deduct_credits should be called for screenshot + proxy"
+            result.is_ok(),
+            "process_extract_task with prompt should return Ok(())"
         );
-        // The extra-credits call should be 3 (screenshot=2 + proxy=1)
-        let has_extra_credits = deductions.iter().any(|(_, amount)| *amount == 3);
         assert!(
-            has_extra_credits,
-            "expected a deduct_credits call with amount 3 (screenshot + proxy), got {:?}",
-            deductions
+            task_repo.update_count() >= 1,
+            "update should be called to mark task as Completed"
         );
     }
 
+    // ========== process_extract_task: schema path (lines 656-659) ==========
+
     #[tokio::test]
-    async fn test_process_scrape_task_success_handle_scrape_failure_calls_handle_failure() {
-        // Engine succeeds but save_result fails → handle_scrape_success returns Err
-        // → process_scrape_task calls handle_failure
+    async fn test_process_extract_task_with_schema_calls_handle_schema_extraction() {
         let router: Arc<dyn EngineRouterTrait> = Arc::new(SuccessEngineRouter::new());
         let engine_client = Arc::new(EngineClient::with_router(router));
 
         let task_repo = Arc::new(ConfigurableTaskRepo::new());
-        let regex_cache = make_regex_cache().await;
-        let settings = crate::bootstrap::config::load_settings().expect("Failed to load settings");
-        let team_semaphore = Arc::new(TeamSemaphore::new(10));
-
-        let worker = ScrapeWorker::new(
+        let worker = build_worker_for_success_tests(
             task_repo.clone(),
-            Arc::new(FailingScrapeResultRepo) as Arc<dyn ScrapeResultRepository>,
-            Arc::new(ConfigurableCrawlRepo::new()) as Arc<dyn CrawlRepository>,
-            Arc::new(MockWebhookService) as Arc<dyn WebhookService>,
-            Arc::new(MockCreditsRepo::default()) as Arc<dyn CreditsRepository>,
             engine_client,
-            Arc::new(MockCreateScrapeUseCase) as Arc<dyn CreateScrapeUseCaseTrait>,
-            team_semaphore,
-            Arc::new(MockRobotsChecker) as Arc<dyn RobotsCheckerTrait>,
-            Arc::new(settings),
-            10,
+            Arc::new(MockCreditsRepo::default()) as Arc<dyn CreditsRepository>,
             Arc::new(MockExtractionService) as Arc<dyn ExtractionServiceTrait>,
-            regex_cache,
-        );
-
-        let task = make_task(json!({"url": "https://example.com"}));
-        let result = worker.process_scrape_task(task).await;
-        // handle_failure path returns Ok(()) after retry handling
-        assert!(result.is_ok(), "handle_failure path should return Ok(())");
-    }
-
-    // ========== run() infinite loop coverage ==========
-
-    #[tokio::test]
-    async fn test_run_with_empty_queue_loops_and_sleeps() {
-        let worker = build_mock_worker().await;
-        let queue = Arc::new(MockTaskQueue) as Arc<dyn TaskQueue>;
+        )
+        .await;
 
-        // run() is an infinite loop; with empty queue it sleeps 1s per iteration.
-        // Use timeout to verify it enters the loop without returning.
-        let result =
-            tokio::time::timeout(Duration::from_millis(150), worker.run(Arc::clone(&queue))).await;
+        // payload.schema = Some (no rules, no prompt) → lines 656-659 (handle_schema_extraction)
+        let task = make_task(json!({
+            "urls": ["https://example.com/page"],
+            "schema": {"type": "object", "properties": {"title": {"type": "string"}}}
+        }));
 
-        // Timeout means run() was still looping (expected behavior)
+        let result = worker.process_extract_task(task).await;
         assert!(
-            result.is_err(),
-            "run() should loop indefinitely; timeout expected"
+            result.is_ok(),
+            "process_extract_task with schema should return Ok(())"
+        );
+        assert!(
+            task_repo.update_count() >= 1,
+            "update should be called to mark task as Completed"
         );
     }
 
+    // ========== process_extract_task: fallback path (lines 663-664) ==========
+
     #[tokio::test]
-    async fn test_run_processes_task_then_continues_looping() {
+    async fn test_process_extract_task_fallback_saves_raw_result() {
         let router: Arc<dyn EngineRouterTrait> = Arc::new(SuccessEngineRouter::new());
         let engine_client = Arc::new(EngineClient::with_router(router));
+
         let task_repo = Arc::new(ConfigurableTaskRepo::new());
         let worker = build_worker_for_success_tests(
             task_repo.clone(),
@@ -6404,48 +11315,45 @@ mod tests {
         )
         .await;
 
-        let task = make_task(json!({"url": "https://example.com"}));
-        let queue = Arc::new(TaskQueueWithTask::new(task)) as Arc<dyn TaskQueue>;
-
-        // run() processes the task then loops (sleeping on empty queue)
-        let result = tokio::time::timeout(Duration::from_millis(500), worker.run(queue)).await;
+        // No rules, no prompt, no schema → lines 663-664 (save_extract_result fallback)
+        let task = make_task(json!({
+            "urls": ["https://example.com/page"]
+        }));
 
-        // Timeout means run() processed the task and continued looping
+        let result = worker.process_extract_task(task).await;
         assert!(
-            result.is_err(),
-            "run() should loop indefinitely after processing"
+            result.is_ok(),
+            "process_extract_task fallback should return Ok(())"
         );
-
-        // mark_completed should have been called for the scrape task
         assert!(
-            task_repo.mark_completed_count() >= 1,
-            "mark_completed should be called after processing scrape task in run()"
+            task_repo.update_count() >= 1,
+            "update should be called to mark task as Completed"
         );
     }
 
-    // ========== extract_and_queue_links: find_existing_urls failure path ==========
+    // ========== extract_and_queue_links: skip existing URLs (line 849) ==========
 
     #[tokio::test]
-    async fn test_extract_and_queue_links_find_existing_urls_failure_returns_err() {
+    async fn test_extract_and_queue_links_skips_existing_urls() {
         let task_repo = Arc::new(ConfigurableTaskRepo::new());
-        // Configure find_existing_urls to fail
-        task_repo
-            .fail_find_existing_urls
-            .store(true, Ordering::SeqCst);
+        // Pre-populate existing URLs: page1 already crawled → should be skipped
+        let mut existing = HashSet::new();
+        existing.insert("https://example.com/page1".to_string());
+        task_repo.set_existing_urls(existing);
 
         let worker = build_configurable_worker(
-            task_repo,
+            task_repo.clone(),
             Arc::new(ConfigurableCrawlRepo::new()),
             Arc::new(MockRobotsChecker),
             Arc::new(EngineClient::new()),
         )
         .await;
 
-        let mut task = make_task(json!({}));
-        task.url = "https://example.com".to_string();
+        let task = make_task(json!({}));
+        // task.url = "https://example.com" (from make_task default)
         let html = r#"<html><body>
-            <a href="https://example.com/page1">Page 1</a>
-            <a href="https://example.com/page2">Page 2</a>
+            <a href="/page1">Page 1</a>
+            <a href="/page2">Page 2</a>
         </body></html>"#;
         let response = ScrapeResponse {
             content: html.to_string(),
@@ -6453,467 +11361,948 @@ mod tests {
             screenshot: None,
             content_type: "text/html".to_string(),
             headers: HashMap::new(),
-            response_time_ms: 10,
+            response_time_ms: 100,
             final_url: None,
+            truncated: false,
+            raw_content: None,
+            http_version: None,
+            tls_profile: None,
+            har: None,
+            mhtml: None,
+            pdf: None,
+            blocked_requests: None,
+            evaluate_results: Vec::new(),
+            frames: Vec::new(),
+            accessibility: None,
+            timings: None,
+            ip_family: None,
+            blocked_by: None,
+            escalations: Vec::new(),
         };
         let config = make_crawl_config(None, None);
         let result = worker
             .extract_and_queue_links(&task, &response, Uuid::new_v4(), 0, &config)
             .await;
+        assert!(result.is_ok());
 
-        assert!(
-            result.is_err(),
-            "extract_and_queue_links should return Err when find_existing_urls fails"
+        // page1 was skipped (existing), page2 was created → create_count == 1
+        assert_eq!(
+            task_repo.create_count(),
+            1,
+            "only non-existing URLs should be created (page1 skipped, page2 created)"
         );
     }
 
-    // ========== handle_scrape_success: extraction failure path ==========
-
     #[tokio::test]
-    async fn test_handle_scrape_success_extraction_failure_continues_and_marks_completed() {
-        let router: Arc<dyn EngineRouterTrait> = Arc::new(SuccessEngineRouter::new());
-        let engine_client = Arc::new(EngineClient::with_router(router));
+    async fn test_extract_and_queue_links_skip_nofollow_links() {
+        let html = r#"<html><body>
+            <a href="/follow">Follow</a>
+            <a href="/nofollow" rel="nofollow">Nofollow</a>
+            <a href="/ugc" rel="UGC noopener">UGC</a>
+            <a href="/sponsored" rel="sponsored">Sponsored</a>
+        </body></html>"#;
+        let response = ScrapeResponse {
+            content: html.to_string(),
+            status_code: 200,
+            screenshot: None,
+            content_type: "text/html".to_string(),
+            headers: HashMap::new(),
+            response_time_ms: 100,
+            final_url: None,
+            truncated: false,
+            raw_content: None,
+            http_version: None,
+            tls_profile: None,
+            har: None,
+            mhtml: None,
+            pdf: None,
+            blocked_requests: None,
+            evaluate_results: Vec::new(),
+            frames: Vec::new(),
+            accessibility: None,
+            timings: None,
+            ip_family: None,
+            blocked_by: None,
+            escalations: Vec::new(),
+        };
+
+        for (skip, expected) in [(None, 4), (Some(true), 1)] {
+            let task_repo = Arc::new(ConfigurableTaskRepo::new());
+            let worker = build_configurable_worker(
+                task_repo.clone(),
+                Arc::new(ConfigurableCrawlRepo::new()),
+                Arc::new(MockRobotsChecker),
+                Arc::new(EngineClient::new()),
+            )
+            .await;
+            let mut config = make_crawl_config(None, None);
+            config.skip_nofollow_links = skip;
+
+            let result = worker
+                .extract_and_queue_links(
+                    &make_task(json!({})),
+                    &response,
+                    Uuid::new_v4(),
+                    0,
+                    &config,
+                )
+                .await;
+            assert!(result.is_ok());
+            assert_eq!(
+                task_repo.create_count(),
+                expected,
+                "skip_nofollow_links={:?}",
+                skip
+            );
+        }
+    }
 
+    #[tokio::test]
+    async fn test_extract_and_queue_links_dedups_canonical_urls() {
+        let html = r#"<html><head>
+            <link rel="canonical" href="https://example.com/home">
+        </head><body>
+            <a href="/home?utm_source=nav">Home (canonical of this page)</a>
+            <a href="/docs#intro">Docs</a>
+            <a href="/docs?utm_campaign=x">Docs again</a>
+            <a href="HTTPS://EXAMPLE.COM//docs">Docs with double slash</a>
+            <a href="/list?b=2&a=1">List</a>
+            <a href="./list?a=1&b=2&fbclid=abc">List reordered</a>
+        </body></html>"#;
+        let response = ScrapeResponse {
+            content: html.to_string(),
+            status_code: 200,
+            screenshot: None,
+            content_type: "text/html".to_string(),
+            headers: HashMap::new(),
+            response_time_ms: 100,
+            final_url: None,
+            truncated: false,
+            raw_content: None,
+            http_version: None,
+            tls_profile: None,
+            har: None,
+            mhtml: None,
+            pdf: None,
+            blocked_requests: None,
+            evaluate_results: Vec::new(),
+            frames: Vec::new(),
+            accessibility: None,
+            timings: None,
+            ip_family: None,
+            blocked_by: None,
+            escalations: Vec::new(),
+        };
         let task_repo = Arc::new(ConfigurableTaskRepo::new());
-        let worker = build_worker_for_success_tests(
+        let worker = build_configurable_worker(
             task_repo.clone(),
-            engine_client,
-            Arc::new(MockCreditsRepo::default()) as Arc<dyn CreditsRepository>,
-            Arc::new(FailingExtractionService) as Arc<dyn ExtractionServiceTrait>,
+            Arc::new(ConfigurableCrawlRepo::new()),
+            Arc::new(MockRobotsChecker),
+            Arc::new(EngineClient::new()),
         )
         .await;
 
-        // Payload includes extraction_rules → extraction will be attempted and fail,
-        // but handle_scrape_success should continue and still mark_completed.
-        let task = make_task(json!({
-            "url": "https://example.com",
-            "extraction_rules": {
-                "title": {
-                    "selector": "h1",
-                    "attr": null,
-                    "is_array": false,
-                    "use_llm": null,
-                    "llm_prompt": null,
-                    "output_format": null
-                }
-            }
-        }));
+        let result = worker
+            .extract_and_queue_links(
+                &make_task(json!({})),
+                &response,
+                Uuid::new_v4(),
+                0,
+                &make_crawl_config(None, None),
+            )
+            .await;
+        assert!(result.is_ok());
+        // 只剩 /docs 与 /list?a=1&b=2 两个不同页面
+        assert_eq!(task_repo.create_count(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_extract_and_queue_links_query_param_rules() {
+        let html = r#"<html><body>
+            <a href="/shop?color=red&sort=asc">Red</a>
+            <a href="/shop?sort=desc&color=red">Red, sorted</a>
+            <a href="/shop?color=blue">Blue</a>
+            <a href="/shop?color=green">Green</a>
+            <a href="/about">About</a>
+        </body></html>"#;
         let response = ScrapeResponse {
-            content: "<html><body><h1>Title</h1></body></html>".to_string(),
+            content: html.to_string(),
+            status_code: 200,
+            screenshot: None,
+            content_type: "text/html".to_string(),
+            headers: HashMap::new(),
+            response_time_ms: 100,
+            final_url: None,
+            truncated: false,
+            raw_content: None,
+            http_version: None,
+            tls_profile: None,
+            har: None,
+            mhtml: None,
+            pdf: None,
+            blocked_requests: None,
+            evaluate_results: Vec::new(),
+            frames: Vec::new(),
+            accessibility: None,
+            timings: None,
+            ip_family: None,
+            blocked_by: None,
+            escalations: Vec::new(),
+        };
+
+        let cases = [
+            // (ignore_query_params, ignore_all_query_params, max_query_variants_per_path, expected)
+            (None, None, None, 5),
+            (Some(vec!["SORT".to_string()]), None, None, 4),
+            (Some(vec!["sort".to_string()]), None, Some(2), 3),
+            (None, Some(true), None, 2),
+            (None, None, Some(0), 1),
+        ];
+        for (ignore, ignore_all, max_variants, expected) in cases {
+            let task_repo = Arc::new(ConfigurableTaskRepo::new());
+            let worker = build_configurable_worker(
+                task_repo.clone(),
+                Arc::new(ConfigurableCrawlRepo::new()),
+                Arc::new(MockRobotsChecker),
+                Arc::new(EngineClient::new()),
+            )
+            .await;
+            let mut config = make_crawl_config(None, None);
+            config.ignore_query_params = ignore.clone();
+            config.ignore_all_query_params = ignore_all;
+            config.max_query_variants_per_path = max_variants;
+
+            let result = worker
+                .extract_and_queue_links(
+                    &make_task(json!({})),
+                    &response,
+                    Uuid::new_v4(),
+                    0,
+                    &config,
+                )
+                .await;
+            assert!(result.is_ok());
+            assert_eq!(
+                task_repo.create_count(),
+                expected,
+                "ignore={:?} ignore_all={:?} max_variants={:?}",
+                ignore,
+                ignore_all,
+                max_variants
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_link_rel_and_nofollow_detection() {
+        let rel = parse_link_rel(Some(" UGC  noopener "));
+        assert_eq!(rel, vec!["ugc".to_string(), "noopener".to_string()]);
+        assert!(is_nofollow_rel(&rel));
+        assert!(!is_nofollow_rel(&parse_link_rel(Some(
+            "noopener noreferrer"
+        ))));
+        assert!(parse_link_rel(None).is_empty());
+    }
+
+    #[test]
+    fn test_discover_page_links_records_anchor_text() {
+        let mut config = make_crawl_config(None, None);
+        config.download_assets = Some(true);
+        let html = r#"<html><body>
+            <a href="/about"><span>  </span></a>
+            <a href="/about" rel="nofollow">About
+                us</a>
+            <a href="/blog">Blog</a>
+            <img src="/logo.png" alt="Logo">
+            <img src="/spacer.gif">
+        </body></html>"#;
+
+        let links = discover_page_links("https://example.com/", html, &config, |_| true).unwrap();
+        let about = &links["https://example.com/about"];
+        assert_eq!(about.anchor_text.as_deref(), Some("About us"));
+        assert_eq!(about.rel, vec!["nofollow".to_string()]);
+        assert_eq!(
+            links["https://example.com/blog"].anchor_text.as_deref(),
+            Some("Blog")
+        );
+        assert_eq!(
+            links["https://example.com/logo.png"].anchor_text.as_deref(),
+            Some("Logo")
+        );
+        assert_eq!(links["https://example.com/spacer.gif"].anchor_text, None);
+        assert_eq!(
+            normalize_anchor_text(&"x".repeat(MAX_ANCHOR_TEXT_CHARS + 10))
+                .unwrap()
+                .len(),
+            MAX_ANCHOR_TEXT_CHARS
+        );
+    }
+
+    #[test]
+    fn test_with_truncation_flag() {
+        assert_eq!(with_truncation_flag(Value::Null, false), Value::Null);
+        assert_eq!(
+            with_truncation_flag(Value::Null, true),
+            json!({"truncated": true})
+        );
+        assert_eq!(
+            with_truncation_flag(json!({"title": "Hi"}), true),
+            json!({"title": "Hi", "truncated": true})
+        );
+    }
+
+    #[test]
+    fn test_download_requested() {
+        assert!(!download_requested(&make_task(json!({}))));
+        assert!(download_requested(&make_task(json!({"download": true}))));
+
+        let mut crawl_task = make_task(json!({"config": {"download_assets": true}}));
+        crawl_task.task_type = TaskType::Crawl;
+        assert!(download_requested(&crawl_task));
+        crawl_task.payload = json!({"download": true});
+        assert!(!download_requested(&crawl_task));
+    }
+
+    #[tokio::test]
+    async fn test_store_asset_saves_binary_response() {
+        let dir = tempfile::tempdir().unwrap();
+        let storage = Arc::new(crate::infrastructure::storage::LocalStorageRepository::new(
+            dir.path(),
+            "/v1/assets",
+        ));
+        let worker = build_configurable_worker(
+            Arc::new(ConfigurableTaskRepo::new()),
+            Arc::new(ConfigurableCrawlRepo::new()),
+            Arc::new(MockRobotsChecker),
+            Arc::new(EngineClient::new()),
+        )
+        .await
+        .with_storage_repository(storage.clone());
+        let response = ScrapeResponse {
+            content: String::new(),
             status_code: 200,
             screenshot: None,
-            content_type: "text/html".to_string(),
+            content_type: "image/png".to_string(),
             headers: HashMap::new(),
             response_time_ms: 10,
             final_url: None,
+            truncated: false,
+            raw_content: Some(bytes::Bytes::from_static(b"\x89PNG")),
+            http_version: None,
+            tls_profile: None,
+            har: None,
+            mhtml: None,
+            pdf: None,
+            blocked_requests: None,
+            evaluate_results: Vec::new(),
+            frames: Vec::new(),
+            accessibility: None,
+            timings: None,
+            ip_family: None,
+            blocked_by: None,
+            escalations: Vec::new(),
         };
 
-        let result = worker.handle_scrape_success(&task, &response).await;
-        assert!(
-            result.is_ok(),
-            "handle_scrape_success should not fail when extraction fails"
-        );
+        // 未请求下载时不写入存储
+        let task = make_task(json!({}));
+        assert!(worker
+            .store_asset(&task, &response)
+            .await
+            .unwrap()
+            .is_none());
 
-        // mark_completed should still be called
-        assert_eq!(
-            task_repo.mark_completed_count(),
-            1,
-            "mark_completed should be called even when extraction fails"
-        );
-    }
+        let task = make_task(json!({"download": true}));
+        let asset = worker.store_asset(&task, &response).await.unwrap().unwrap();
+        let checksum = hex::encode(Sha256::digest(b"\x89PNG"));
+        let key = format!("{}/{}", task.team_id, checksum);
+        assert_eq!(asset["sha256"], checksum);
+        assert_eq!(asset["size"], 4);
+        assert_eq!(asset["content_type"], "image/png");
+        assert_eq!(asset["storage_url"], format!("/v1/assets/{}", key));
 
-    // ========== process_scrape_task: success path without screenshot/proxy (no extra credits) ==========
+        let stored = storage.get(task.team_id, &checksum).await.unwrap().unwrap();
+        assert_eq!(stored.bytes, b"\x89PNG");
+        assert_eq!(stored.content_type, "image/png");
+    }
 
     #[tokio::test]
-    async fn test_process_scrape_task_success_without_screenshot_proxy_no_extra_credits() {
-        let router: Arc<dyn EngineRouterTrait> = Arc::new(SuccessEngineRouter::new());
-        let engine_client = Arc::new(EngineClient::with_router(router));
-
-        let credits_repo = Arc::new(MockCreditsRepo::default());
-        let deducted_log = credits_repo.deducted.clone();
+    async fn test_store_har_saves_captured_document() {
+        let dir = tempfile::tempdir().unwrap();
+        let storage = Arc::new(crate::infrastructure::storage::LocalStorageRepository::new(
+            dir.path(),
+            "/v1/assets",
+        ));
+        let worker = build_configurable_worker(
+            Arc::new(ConfigurableTaskRepo::new()),
+            Arc::new(ConfigurableCrawlRepo::new()),
+            Arc::new(MockRobotsChecker),
+            Arc::new(EngineClient::new()),
+        )
+        .await
+        .with_storage_repository(storage.clone());
+        let mut response = ScrapeResponse::new(200, "<html></html>", "text/html");
+        let task = make_task(json!({"formats": ["har"]}));
+
+        // 引擎未返回 HAR 时不写入存储
+        assert!(worker.store_har(&task, &response).await.unwrap().is_none());
+
+        response.har = Some(json!({"log": {"version": "1.2", "entries": [{}, {}]}}));
+        let har = worker.store_har(&task, &response).await.unwrap().unwrap();
+        let key = format!("{}/har/{}.har", task.team_id, task.id);
+        assert_eq!(har["storage_key"], key);
+        assert_eq!(har["entries"], 2);
+
+        let stored = storage
+            .get(task.team_id, &format!("har/{}.har", task.id))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(stored.content_type, "application/json");
+        let document: Value = serde_json::from_slice(&stored.bytes).unwrap();
+        assert_eq!(document["log"]["version"], "1.2");
+    }
 
-        let task_repo = Arc::new(ConfigurableTaskRepo::new());
-        let worker = build_worker_for_success_tests(
-            task_repo.clone(),
-            engine_client,
-            credits_repo as Arc<dyn CreditsRepository>,
-            Arc::new(MockExtractionService) as Arc<dyn ExtractionServiceTrait>,
+    #[tokio::test]
+    async fn test_store_mhtml_saves_snapshot() {
+        let dir = tempfile::tempdir().unwrap();
+        let storage = Arc::new(crate::infrastructure::storage::LocalStorageRepository::new(
+            dir.path(),
+            "/v1/assets",
+        ));
+        let worker = build_configurable_worker(
+            Arc::new(ConfigurableTaskRepo::new()),
+            Arc::new(ConfigurableCrawlRepo::new()),
+            Arc::new(MockRobotsChecker),
+            Arc::new(EngineClient::new()),
         )
-        .await;
+        .await
+        .with_storage_repository(storage.clone());
+        let mut response = ScrapeResponse::new(200, "<html></html>", "text/html");
+        let task = make_task(json!({"formats": ["mhtml"]}));
+
+        // 引擎未返回快照时不写入存储
+        assert!(worker
+            .store_mhtml(&task, &response)
+            .await
+            .unwrap()
+            .is_none());
+
+        let snapshot = "Content-Type: multipart/related; boundary=\"b\"\r\n\r\n--b\r\n\r\n<html></html>\r\n--b\r\n\r\nbody{}\r\n--b--\r\n";
+        response.mhtml = Some(snapshot.to_string());
+        let mhtml = worker.store_mhtml(&task, &response).await.unwrap().unwrap();
+        let key = format!("{}/mhtml/{}.mhtml", task.team_id, task.id);
+        assert_eq!(mhtml["storage_key"], key);
+        assert_eq!(mhtml["resources"], 1);
+        assert_eq!(mhtml["size"], snapshot.len());
+
+        let stored = storage
+            .get(task.team_id, &format!("mhtml/{}.mhtml", task.id))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(stored.content_type, MHTML_CONTENT_TYPE);
+        assert_eq!(stored.bytes, snapshot.as_bytes());
+    }
 
-        // No screenshot, no proxy → deduct_feature_credits does nothing (extra_credits == 0)
-        let task = make_task(json!({"url": "https://example.com"}));
-        let result = worker.process_scrape_task(task).await;
-        assert!(result.is_ok());
+    #[test]
+    fn test_result_structured_data_for_requested_html() {
+        let html = r#"<script type="application/ld+json">{"@type": "Product", "name": "Widget"}</script>
+            <a itemscope itemtype="https://schema.org/Thing" itemprop="url" href="/w">w</a>"#;
+        let response = ScrapeResponse::new(200, html, "text/html");
 
-        // No deduct_credits calls for feature credits (extraction also returns 0 tokens)
-        let deductions = deducted_log.lock().unwrap();
         assert!(
-            deductions.is_empty(),
-            "no deduct_credits calls expected without screenshot/proxy/token-usage, got {:?}",
-            deductions
+            result_structured_data(&make_task(json!({"formats": ["html"]})), &response).is_none()
+        );
+        let task = make_task(json!({"formats": ["structured_data"]}));
+        let entities = result_structured_data(&task, &response).unwrap();
+        assert_eq!(
+            entities,
+            json!([{"type": "Product", "source": "json-ld", "properties": {"name": "Widget"}}])
         );
     }
 
-    // ========== run() Err path: FailingTaskQueue exercises lines 138-140 ==========
+    #[test]
+    fn test_result_feed_for_feed_content_types() {
+        let rss = "<rss><channel><title>News</title><item><title>One</title><link>/1</link></item></channel></rss>";
+        let task = make_task(json!({"url": "https://example.com/feed"}));
 
-    #[tokio::test]
-    async fn test_run_with_failing_queue_logs_error_and_sleeps() {
-        let worker = build_mock_worker().await;
-        let queue = Arc::new(FailingTaskQueue) as Arc<dyn TaskQueue>;
+        assert!(result_feed(&task, &ScrapeResponse::new(200, rss, "text/html")).is_none());
+        let response = ScrapeResponse::new(200, rss, "application/rss+xml; charset=utf-8");
+        let feed = result_feed(&task, &response).unwrap();
+        assert_eq!(feed["kind"], "rss");
+        assert_eq!(feed["title"], "News");
+        assert_eq!(feed["items"][0]["link"], "https://example.com/1");
+    }
 
-        // run() is an infinite loop; with FailingTaskQueue, dequeue returns Err
-        // → process_next_task returns Err → run() hits the Err branch (lines 138-140)
-        // and sleeps 1s per iteration. Use timeout to verify it enters the loop.
-        let result =
-            tokio::time::timeout(Duration::from_millis(150), worker.run(Arc::clone(&queue))).await;
+    #[test]
+    fn test_result_performance_merges_engine_timings() {
+        let mut response = ScrapeResponse::new(200, "<html></html>", "text/html");
+        response.response_time_ms = 240;
+        assert_eq!(
+            result_performance(&response),
+            json!({"response_time_ms": 240})
+        );
 
-        // Timeout means run() was still looping (expected behavior)
-        assert!(
-            result.is_err(),
-            "run() should loop indefinitely on queue errors; timeout expected"
+        response.timings = Some(PageTimings {
+            ttfb_ms: Some(95.5),
+            largest_contentful_paint_ms: Some(1200.0),
+            ..Default::default()
+        });
+        assert_eq!(
+            result_performance(&response),
+            json!({"ttfb_ms": 95.5, "largest_contentful_paint_ms": 1200.0, "response_time_ms": 240})
         );
     }
 
-    // ========== process_crawl_task success path: exercises lines 372-374 ==========
-
-    #[tokio::test]
-    async fn test_process_crawl_task_success_calls_handle_crawl_success() {
-        let router: Arc<dyn EngineRouterTrait> = Arc::new(SuccessEngineRouter::new());
-        let engine_client = Arc::new(EngineClient::with_router(router));
-
-        let task_repo = Arc::new(ConfigurableTaskRepo::new());
-        let worker = build_configurable_worker(
-            task_repo.clone(),
-            Arc::new(ConfigurableCrawlRepo::new()),
-            Arc::new(MockRobotsChecker),
-            engine_client,
-        )
-        .await;
-
-        let crawl_id = Uuid::new_v4();
-        // max_depth: 0 → depth 0 < max_depth 0 is false → no link extraction
-        let task = make_task(json!({
-            "crawl_id": crawl_id.to_string(),
-            "depth": 0,
-            "config": {"max_depth": 0}
-        }));
+    #[test]
+    fn test_escalation_path_lists_attempts() {
+        use crate::engines::block_escalation::{EscalationAttempt, EscalationStep};
 
-        let result = worker.process_crawl_task(task).await;
-        assert!(result.is_ok(), "success path should return Ok(())");
+        let mut response = ScrapeResponse::new(200, "<html></html>", "text/html");
+        assert_eq!(escalation_path(&response), None);
 
-        // mark_completed should be called by handle_crawl_success
+        response.escalations = vec![
+            EscalationAttempt::blocked(EscalationStep::Initial, BlockedBy::Cloudflare),
+            EscalationAttempt::failed(EscalationStep::TlsFingerprint, "no engine"),
+            EscalationAttempt::succeeded(EscalationStep::Browser),
+        ];
         assert_eq!(
-            task_repo.mark_completed_count(),
-            1,
-            "mark_completed should be called once for successful crawl"
+            escalation_path(&response),
+            Some(json!([
+                {"step": "initial", "blocked_by": "cloudflare"},
+                {"step": "tls_fingerprint", "error": "no engine"},
+                {"step": "browser"}
+            ]))
         );
     }
 
-    // ========== extract_data_with_rules failure path: exercises lines 509-511 ==========
+    #[test]
+    fn test_result_seo_reads_crawl_config_formats() {
+        let html = "<html><head><title>Home</title></head><body><h1>Home</h1></body></html>";
+        let response = ScrapeResponse::new(200, html, "text/html");
 
-    #[tokio::test]
-    async fn test_extract_data_with_rules_failure_returns_none_and_logs() {
-        let router: Arc<dyn EngineRouterTrait> = Arc::new(SuccessEngineRouter::new());
-        let engine_client = Arc::new(EngineClient::with_router(router));
+        assert!(result_seo(&make_task(json!({"config": {"max_depth": 1}})), &response).is_none());
+        let task = make_task(json!({"config": {"max_depth": 1, "formats": ["seo"]}}));
+        let audit = result_seo(&task, &response).unwrap();
+        assert_eq!(audit["title"], "Home");
+        assert_eq!(audit["h1_count"], 1);
+        assert!(result_seo(&task, &ScrapeResponse::new(200, "{}", "application/json")).is_none());
+    }
 
-        let worker = build_worker_for_success_tests(
-            Arc::new(ConfigurableTaskRepo::new()),
-            engine_client,
-            Arc::new(MockCreditsRepo::default()) as Arc<dyn CreditsRepository>,
-            Arc::new(FailingExtractionService) as Arc<dyn ExtractionServiceTrait>,
-        )
-        .await;
+    #[test]
+    fn test_result_tables_only_for_requested_html() {
+        let html = "<table><tr><th>Plan</th><th>Price</th></tr><tr><td>Basic</td><td>$10</td></tr></table>";
+        let response = ScrapeResponse::new(200, html, "text/html; charset=utf-8");
 
-        let task = make_task(json!({}));
-        let response = ScrapeResponse {
-            content: "<html><body><h1>Title</h1></body></html>".to_string(),
-            status_code: 200,
-            screenshot: None,
-            content_type: "text/html".to_string(),
-            headers: HashMap::new(),
-            response_time_ms: 10,
-            final_url: None,
-        };
-        let mut rules = HashMap::new();
-        rules.insert(
-            "title".to_string(),
-            ExtractionRule {
-                selector: Some("h1".to_string()),
-                attr: None,
-                is_array: false,
-                use_llm: None,
-                llm_prompt: None,
-                output_format: None,
-            },
-        );
-        let config = CrawlConfigDto {
-            max_depth: 1,
-            include_patterns: None,
-            exclude_patterns: None,
-            strategy: None,
-            crawl_delay_ms: None,
-            max_concurrency: None,
-            proxy: None,
-            headers: None,
-            extraction_rules: Some(rules),
-        };
+        assert!(result_tables(&make_task(json!({"formats": ["markdown"]})), &response).is_none());
+        let json_response = ScrapeResponse::new(200, html, "application/json");
+        let task = make_task(json!({"formats": ["tables"]}));
+        assert!(result_tables(&task, &json_response).is_none());
 
-        // FailingExtractionService.extract returns Err → lines 509-511
-        let result = worker
-            .extract_data_with_rules(&task, &response, &config)
-            .await;
-        assert!(
-            result.is_none(),
-            "extract_data_with_rules should return None when extraction fails"
+        let tables = result_tables(&task, &response).unwrap();
+        assert_eq!(
+            tables[0]["records"],
+            json!([{"Plan": "Basic", "Price": "$10"}])
         );
-    }
+        assert!(tables[0].get("csv").is_none());
 
-    // ========== handle_crawl_failure: increment_failed_tasks error (line 540) ==========
+        let task =
+            make_task(json!({"formats": ["tables"], "options": {"table_options": {"csv": true}}}));
+        let tables = result_tables(&task, &response).unwrap();
+        assert_eq!(tables[0]["csv"], "Plan,Price\r\nBasic,$10\r\n");
+    }
 
     #[tokio::test]
-    async fn test_handle_crawl_failure_increment_failed_error_logs_and_continues() {
-        let crawl_repo = Arc::new(ConfigurableCrawlRepo::new());
-        crawl_repo
-            .fail_increment_failed
-            .store(true, Ordering::SeqCst);
-
+    async fn test_store_pdf_saves_rendered_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let storage = Arc::new(crate::infrastructure::storage::LocalStorageRepository::new(
+            dir.path(),
+            "/v1/assets",
+        ));
         let worker = build_configurable_worker(
             Arc::new(ConfigurableTaskRepo::new()),
-            crawl_repo,
+            Arc::new(ConfigurableCrawlRepo::new()),
             Arc::new(MockRobotsChecker),
             Arc::new(EngineClient::new()),
         )
-        .await;
+        .await
+        .with_storage_repository(storage.clone());
+        let mut response = ScrapeResponse::new(200, "<html></html>", "text/html");
+        let task = make_task(json!({"formats": ["pdf"]}));
+
+        // 引擎未返回 PDF 时不写入存储
+        assert!(worker.store_pdf(&task, &response).await.unwrap().is_none());
+
+        response.pdf = Some(bytes::Bytes::from_static(b"%PDF-1.4"));
+        let pdf = worker.store_pdf(&task, &response).await.unwrap().unwrap();
+        let key = format!("{}/pdf/{}.pdf", task.team_id, task.id);
+        assert_eq!(pdf["storage_key"], key);
+        assert_eq!(pdf["storage_url"], format!("/v1/assets/{}", key));
+        assert_eq!(pdf["size"], 8);
+
+        let stored = storage
+            .get(task.team_id, &format!("pdf/{}.pdf", task.id))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(stored.content_type, "application/pdf");
+        assert_eq!(stored.bytes, b"%PDF-1.4");
+    }
 
-        let mut task = make_task(json!({}));
-        let config = make_crawl_config(None, None);
-        let request = worker.build_crawl_request(&task, &config);
-        let result = worker
-            .handle_crawl_failure(
-                &mut task,
-                anyhow::anyhow!("Network error"),
-                Uuid::new_v4(),
-                &request,
-            )
-            .await;
+    /// Records uploads and fails for object keys containing "denied"
+    #[derive(Default)]
+    struct RecordingUploader {
+        uploads: std::sync::Mutex<Vec<(String, Vec<u8>)>>,
+    }
 
-        // Should succeed — increment_failed_tasks error is just logged (line 540)
+    #[async_trait::async_trait]
+    impl ResultUploader for RecordingUploader {
+        async fn upload(
+            &self,
+            destination: &ResultDestination,
+            name: &str,
+            body: Vec<u8>,
+            _content_type: &str,
+        ) -> Result<String> {
+            if destination.object_key(name).contains("denied") {
+                return Err(anyhow::anyhow!("Result upload failed with status 403"));
+            }
+            self.uploads.lock().unwrap().push((name.to_string(), body));
+            Ok(destination.location(name))
+        }
+    }
+
+    #[test]
+    fn test_result_destination_from_crawl_config() {
+        let destination = json!({
+            "type": "presigned",
+            "url": "https://uploads.example.com/acme?sig=abc"
+        });
+        let mut task = make_task(json!({"config": {"result_destination": destination}}));
         assert!(
-            result.is_ok(),
-            "handle_crawl_failure should not fail when increment_failed_tasks errors"
+            result_destination(&task).is_none(),
+            "only crawl tasks deliver"
         );
-    }
 
-    // ========== update_crawl_completion_status: update_status error (line 569) ==========
+        task.task_type = TaskType::Crawl;
+        let (parsed, keep_local) = result_destination(&task).unwrap();
+        assert!(matches!(parsed, ResultDestination::Presigned { .. }));
+        assert!(keep_local);
 
-    #[tokio::test]
-    async fn test_update_crawl_completion_status_update_status_error_logs_and_continues() {
-        let crawl_repo = Arc::new(ConfigurableCrawlRepo::new());
-        crawl_repo.fail_update_status.store(true, Ordering::SeqCst);
+        task.payload["config"]["keep_local_results"] = json!(false);
+        assert!(!result_destination(&task).unwrap().1);
 
-        let crawl_id = Uuid::new_v4();
-        // completed + failed == total → enters the update_status branch
-        let crawl = Crawl::with_all_fields(
-            crawl_id,
-            Uuid::new_v4(),
-            "test".to_string(),
-            "https://example.com".to_string(),
-            "https://example.com".to_string(),
-            CrawlStatus::Processing,
-            json!({}),
-            10,
-            8,
-            2,
-            Utc::now(),
-            Utc::now(),
-            None,
+        task.payload = json!({"config": {"max_depth": 1}});
+        assert!(result_destination(&task).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_deliver_result_records_outcome() {
+        let uploader = Arc::new(RecordingUploader::default());
+        let worker = build_configurable_worker(
+            Arc::new(ConfigurableTaskRepo::new()),
+            Arc::new(ConfigurableCrawlRepo::new()),
+            Arc::new(MockRobotsChecker),
+            Arc::new(EngineClient::new()),
+        )
+        .await;
+        let destination = ResultDestination::Presigned {
+            url: "https://uploads.example.com/acme?sig=abc".to_string(),
+            headers: None,
+        };
+        let mut task = make_task(json!({}));
+        task.crawl_id = Some(Uuid::new_v4());
+        let result = ScrapeResult {
+            id: Uuid::new_v4(),
+            task_id: task.id,
+            url: task.url.clone(),
+            status_code: 200,
+            content: "# Hello".to_string(),
+            content_type: "text/html".to_string(),
+            headers: json!({}),
+            meta_data: Value::Null,
+            screenshot: None,
+            response_time_ms: 10,
+            created_at: Utc::now().naive_utc(),
+            etag: None,
+            last_modified: None,
+        };
+
+        // 未注入上传器时记录失败
+        let delivery = worker.deliver_result(&task, &destination, &result).await;
+        assert_eq!(delivery["status"], "failed");
+
+        let worker = worker.with_result_uploader(uploader.clone());
+        let delivery = worker.deliver_result(&task, &destination, &result).await;
+        let name = format!("{}/{}.json", task.crawl_id.unwrap(), task.id);
+        assert_eq!(delivery["status"], "delivered");
+        assert_eq!(
+            delivery["location"],
+            format!("https://uploads.example.com/acme/{}", name)
         );
-        crawl_repo.set_crawl(crawl);
+        {
+            let uploads = uploader.uploads.lock().unwrap();
+            assert_eq!(uploads.len(), 1);
+            assert_eq!(uploads[0].0, name);
+            let uploaded: Value = serde_json::from_slice(&uploads[0].1).unwrap();
+            assert_eq!(uploaded["content"], "# Hello");
+            assert_eq!(delivery["size"], uploads[0].1.len());
+        }
+
+        let denied_destination = ResultDestination::S3 {
+            bucket: "acme".to_string(),
+            region: "us-east-1".to_string(),
+            endpoint: None,
+            prefix: Some("denied".to_string()),
+            access_key_id: "a".to_string(),
+            secret_access_key: "b".to_string(),
+            session_token: None,
+        };
+        let delivery = worker
+            .deliver_result(&task, &denied_destination, &result)
+            .await;
+        assert_eq!(delivery["status"], "failed");
+        assert!(delivery["error"].as_str().unwrap().contains("403"));
+    }
+
+    /// Indexer that records indexed results and fails for empty content
+    #[derive(Default)]
+    struct RecordingIndexer {
+        indexed: std::sync::Mutex<Vec<IndexedResult>>,
+    }
+
+    #[async_trait::async_trait]
+    impl ResultIndexer for RecordingIndexer {
+        async fn ensure_index(&self) -> Result<()> {
+            Ok(())
+        }
+
+        async fn index(&self, results: &[IndexedResult]) -> Result<usize> {
+            if results
+                .iter()
+                .any(|indexed| indexed.result.content.is_empty())
+            {
+                return Err(anyhow::anyhow!("Result indexing failed with status 400"));
+            }
+            self.indexed.lock().unwrap().extend_from_slice(results);
+            Ok(results.len())
+        }
+    }
 
+    #[tokio::test]
+    async fn test_index_result_attaches_task_ownership() {
+        let indexer = Arc::new(RecordingIndexer::default());
         let worker = build_configurable_worker(
             Arc::new(ConfigurableTaskRepo::new()),
-            crawl_repo.clone(),
+            Arc::new(ConfigurableCrawlRepo::new()),
             Arc::new(MockRobotsChecker),
             Arc::new(EngineClient::new()),
         )
-        .await;
+        .await
+        .with_result_indexer(indexer.clone());
+        let mut task = make_task(json!({}));
+        task.crawl_id = Some(Uuid::new_v4());
+        let result = ScrapeResult {
+            id: Uuid::new_v4(),
+            task_id: task.id,
+            url: task.url.clone(),
+            status_code: 200,
+            content: "<title>Hello</title>".to_string(),
+            content_type: "text/html".to_string(),
+            headers: json!({}),
+            meta_data: Value::Null,
+            screenshot: None,
+            response_time_ms: 10,
+            created_at: Utc::now().naive_utc(),
+            etag: None,
+            last_modified: None,
+        };
 
-        // update_status returns Err → line 569 error log
-        worker.update_crawl_completion_status(crawl_id).await;
+        worker.index_result(&task, result.clone()).await;
+        // 写入失败只记录日志
+        worker
+            .index_result(
+                &task,
+                ScrapeResult {
+                    content: String::new(),
+                    ..result.clone()
+                },
+            )
+            .await;
 
-        // update_status was called (and failed)
-        assert_eq!(
-            crawl_repo.update_status_count(),
-            1,
-            "update_status should be called once even when it errors"
-        );
+        let indexed = indexer.indexed.lock().unwrap();
+        assert_eq!(indexed.len(), 1);
+        assert_eq!(indexed[0].result.id, result.id);
+        assert_eq!(indexed[0].team_id, task.team_id);
+        assert_eq!(indexed[0].crawl_id, task.crawl_id);
     }
 
-    // ========== process_extract_task: rules path (lines 622, 630-634, 644-647) ==========
-
-    #[tokio::test]
-    async fn test_process_extract_task_with_rules_calls_handle_rules_extraction() {
-        let router: Arc<dyn EngineRouterTrait> = Arc::new(SuccessEngineRouter::new());
-        let engine_client = Arc::new(EngineClient::with_router(router));
+    #[test]
+    fn test_build_scrape_request_iframes_imply_js() {
+        let task = make_task(json!({
+            "url": "https://example.com",
+            "options": {"iframes": "inline", "cross_origin_iframes": true}
+        }));
+        let request = ScrapeWorker::build_scrape_request(&task).unwrap();
+        assert!(request.options.needs_js);
+        assert_eq!(
+            request.options.iframe_capture,
+            Some(IframeCapture {
+                mode: crate::engines::iframe::IframeMode::Inline,
+                cross_origin: true,
+            })
+        );
 
-        let task_repo = Arc::new(ConfigurableTaskRepo::new());
-        let worker = build_worker_for_success_tests(
-            task_repo.clone(),
-            engine_client,
-            Arc::new(MockCreditsRepo::default()) as Arc<dyn CreditsRepository>,
-            Arc::new(MockExtractionService) as Arc<dyn ExtractionServiceTrait>,
-        )
-        .await;
+        let task = make_task(json!({"url": "https://example.com"}));
+        let request = ScrapeWorker::build_scrape_request(&task).unwrap();
+        assert!(request.options.iframe_capture.is_none());
+    }
 
-        // payload.rules = Some → line 622 (debug! rules_count) + lines 644-647 (handle_rules_extraction)
+    #[test]
+    fn test_build_scrape_request_flatten_shadow_dom_implies_js() {
         let task = make_task(json!({
-            "urls": ["https://example.com/page"],
-            "rules": {
-                "title": {
-                    "selector": "h1",
-                    "attr": null,
-                    "is_array": false,
-                    "use_llm": null,
-                    "llm_prompt": null,
-                    "output_format": null
-                }
-            }
+            "url": "https://example.com",
+            "options": {"flatten_shadow_dom": true}
         }));
+        let request = ScrapeWorker::build_scrape_request(&task).unwrap();
+        assert!(request.options.flatten_shadow_dom);
+        assert!(request.options.needs_js);
+    }
 
-        let result = worker.process_extract_task(task).await;
-        assert!(
-            result.is_ok(),
-            "process_extract_task with rules should return Ok(())"
-        );
-        // save_extract_result calls repository.update (mark as Completed)
-        assert!(
-            task_repo.update_count() >= 1,
-            "update should be called to mark task as Completed"
+    #[test]
+    fn test_build_scrape_request_auto_scroll_implies_js() {
+        let task = make_task(json!({
+            "url": "https://example.com/feed",
+            "options": {"scroll": {"mode": "auto", "max_scrolls": 8}}
+        }));
+        let request = ScrapeWorker::build_scrape_request(&task).unwrap();
+        assert_eq!(
+            request.options.auto_scroll,
+            Some(AutoScroll {
+                max_scrolls: 8,
+                idle_ms: 1000,
+            })
         );
+        assert!(request.options.needs_js);
     }
 
-    // ========== process_extract_task: prompt path (lines 650-653) ==========
-
-    #[tokio::test]
-    async fn test_process_extract_task_with_prompt_calls_handle_prompt_extraction() {
-        let router: Arc<dyn EngineRouterTrait> = Arc::new(SuccessEngineRouter::new());
-        let engine_client = Arc::new(EngineClient::with_router(router));
-
-        let task_repo = Arc::new(ConfigurableTaskRepo::new());
-        let worker = build_worker_for_success_tests(
-            task_repo.clone(),
-            engine_client,
-            Arc::new(MockCreditsRepo::default()) as Arc<dyn CreditsRepository>,
-            Arc::new(MockExtractionService) as Arc<dyn ExtractionServiceTrait>,
-        )
-        .await;
-
-        // payload.prompt = Some (no rules) → lines 650-653 (handle_prompt_extraction)
+    #[test]
+    fn test_build_scrape_request_engine_tier() {
         let task = make_task(json!({
-            "urls": ["https://example.com/page"],
-            "prompt": "Extract the title"
+            "url": "https://example.com",
+            "options": {"engine_tier": "cheap"}
         }));
+        let request = ScrapeWorker::build_scrape_request(&task).unwrap();
+        assert_eq!(request.options.engine_tier, Some(EngineTier::Cheap));
 
-        let result = worker.process_extract_task(task).await;
-        assert!(
-            result.is_ok(),
-            "process_extract_task with prompt should return Ok(())"
-        );
-        assert!(
-            task_repo.update_count() >= 1,
-            "update should be called to mark task as Completed"
-        );
+        let task = make_task(json!({"url": "https://example.com"}));
+        let request = ScrapeWorker::build_scrape_request(&task).unwrap();
+        assert_eq!(request.options.engine_tier, None);
     }
 
-    // ========== process_extract_task: schema path (lines 656-659) ==========
+    #[test]
+    fn test_build_scrape_request_capture_har_from_formats() {
+        let task = make_task(json!({"url": "https://example.com", "formats": ["markdown", "har"]}));
+        let request = ScrapeWorker::build_scrape_request(&task).unwrap();
+        assert!(request.options.capture_har);
 
-    #[tokio::test]
-    async fn test_process_extract_task_with_schema_calls_handle_schema_extraction() {
-        let router: Arc<dyn EngineRouterTrait> = Arc::new(SuccessEngineRouter::new());
-        let engine_client = Arc::new(EngineClient::with_router(router));
+        let task = make_task(json!({"url": "https://example.com", "formats": ["html"]}));
+        let request = ScrapeWorker::build_scrape_request(&task).unwrap();
+        assert!(!request.options.capture_har);
+    }
 
-        let task_repo = Arc::new(ConfigurableTaskRepo::new());
-        let worker = build_worker_for_success_tests(
-            task_repo.clone(),
-            engine_client,
-            Arc::new(MockCreditsRepo::default()) as Arc<dyn CreditsRepository>,
-            Arc::new(MockExtractionService) as Arc<dyn ExtractionServiceTrait>,
-        )
-        .await;
+    #[test]
+    fn test_build_scrape_request_capture_mhtml_from_formats() {
+        let task = make_task(json!({"url": "https://example.com", "formats": ["mhtml"]}));
+        let request = ScrapeWorker::build_scrape_request(&task).unwrap();
+        assert!(request.options.capture_mhtml);
+        assert!(!request.options.capture_har);
 
-        // payload.schema = Some (no rules, no prompt) → lines 656-659 (handle_schema_extraction)
+        let task = make_task(json!({"url": "https://example.com"}));
+        let request = ScrapeWorker::build_scrape_request(&task).unwrap();
+        assert!(!request.options.capture_mhtml);
+    }
+
+    #[test]
+    fn test_build_scrape_request_pdf_from_formats() {
         let task = make_task(json!({
-            "urls": ["https://example.com/page"],
-            "schema": {"type": "object", "properties": {"title": {"type": "string"}}}
+            "url": "https://example.com",
+            "formats": ["pdf"],
+            "options": {"pdf_options": {"paper_size": "a4", "landscape": true, "margin": {"top": 0}}}
         }));
-
-        let result = worker.process_extract_task(task).await;
-        assert!(
-            result.is_ok(),
-            "process_extract_task with schema should return Ok(())"
-        );
-        assert!(
-            task_repo.update_count() >= 1,
-            "update should be called to mark task as Completed"
-        );
+        let request = ScrapeWorker::build_scrape_request(&task).unwrap();
+        let pdf = request.options.pdf.unwrap();
+        assert_eq!(pdf.paper_size, crate::engines::pdf::PaperSize::A4);
+        assert!(pdf.landscape);
+        assert_eq!(pdf.margins.top, 0.0);
+        assert_eq!(pdf.margins.left, crate::engines::pdf::DEFAULT_PDF_MARGIN_MM);
+
+        // 未请求 pdf 格式时忽略打印配置
+        let task = make_task(json!({
+            "url": "https://example.com",
+            "options": {"pdf_options": {"paper_size": "a4"}}
+        }));
+        let request = ScrapeWorker::build_scrape_request(&task).unwrap();
+        assert!(request.options.pdf.is_none());
     }
 
-    // ========== process_extract_task: fallback path (lines 663-664) ==========
+    #[test]
+    fn test_build_scrape_request_accessibility_from_formats() {
+        let task = make_task(json!({
+            "url": "https://example.com",
+            "formats": ["accessibility"],
+            "options": {"accessibility_options": {"tags": ["wcag2a", "wcag2aa"]}}
+        }));
+        let request = ScrapeWorker::build_scrape_request(&task).unwrap();
+        let accessibility = request.options.accessibility.unwrap();
+        assert_eq!(accessibility.tags, vec!["wcag2a", "wcag2aa"]);
 
-    #[tokio::test]
-    async fn test_process_extract_task_fallback_saves_raw_result() {
-        let router: Arc<dyn EngineRouterTrait> = Arc::new(SuccessEngineRouter::new());
-        let engine_client = Arc::new(EngineClient::with_router(router));
+        let task = make_task(json!({"url": "https://example.com", "formats": ["accessibility"]}));
+        let request = ScrapeWorker::build_scrape_request(&task).unwrap();
+        assert!(request.options.accessibility.unwrap().tags.is_empty());
 
-        let task_repo = Arc::new(ConfigurableTaskRepo::new());
-        let worker = build_worker_for_success_tests(
-            task_repo.clone(),
-            engine_client,
-            Arc::new(MockCreditsRepo::default()) as Arc<dyn CreditsRepository>,
-            Arc::new(MockExtractionService) as Arc<dyn ExtractionServiceTrait>,
-        )
-        .await;
+        let task = make_task(json!({"url": "https://example.com"}));
+        let request = ScrapeWorker::build_scrape_request(&task).unwrap();
+        assert!(request.options.accessibility.is_none());
+    }
 
-        // No rules, no prompt, no schema → lines 663-664 (save_extract_result fallback)
+    #[test]
+    fn test_build_scrape_request_action_evaluate_mapped() {
         let task = make_task(json!({
-            "urls": ["https://example.com/page"]
+            "url": "https://example.com",
+            "actions": [{"type": "evaluate", "script": "document.title"}]
         }));
-
-        let result = worker.process_extract_task(task).await;
-        assert!(
-            result.is_ok(),
-            "process_extract_task fallback should return Ok(())"
-        );
-        assert!(
-            task_repo.update_count() >= 1,
-            "update should be called to mark task as Completed"
-        );
+        let request = ScrapeWorker::build_scrape_request(&task).expect("should succeed");
+        assert!(request.options.needs_js);
+        match &request.options.actions[..] {
+            [PageAction::Evaluate { script }] => assert_eq!(script, "document.title"),
+            other => panic!("Expected Evaluate, got {:?}", other),
+        }
     }
 
-    // ========== extract_and_queue_links: skip existing URLs (line 849) ==========
-
-    #[tokio::test]
-    async fn test_extract_and_queue_links_skips_existing_urls() {
-        let task_repo = Arc::new(ConfigurableTaskRepo::new());
-        // Pre-populate existing URLs: page1 already crawled → should be skipped
-        let mut existing = HashSet::new();
-        existing.insert("https://example.com/page1".to_string());
-        task_repo.set_existing_urls(existing);
+    #[test]
+    fn test_build_scrape_request_maps_resource_blocking() {
+        let task = make_task(json!({
+            "url": "https://example.com",
+            "options": {"js_rendering": true, "block_resources": ["image", "font"], "block_ads": true}
+        }));
+        let request = ScrapeWorker::build_scrape_request(&task).unwrap();
+        let blocking = request.options.resource_blocking.unwrap();
+        assert_eq!(blocking.resource_types, vec!["image", "font"]);
+        assert!(blocking.block_ads);
+        assert!(blocking.blocked_domains.is_empty());
 
-        let worker = build_configurable_worker(
-            task_repo.clone(),
-            Arc::new(ConfigurableCrawlRepo::new()),
-            Arc::new(MockRobotsChecker),
-            Arc::new(EngineClient::new()),
-        )
-        .await;
+        let task =
+            make_task(json!({"url": "https://example.com", "options": {"js_rendering": true}}));
+        let request = ScrapeWorker::build_scrape_request(&task).unwrap();
+        assert!(request.options.resource_blocking.is_none());
+    }
 
-        let task = make_task(json!({}));
-        // task.url = "https://example.com" (from make_task default)
+    #[tokio::test]
+    async fn test_extract_and_queue_links_includes_images_with_download_assets() {
         let html = r#"<html><body>
-            <a href="/page1">Page 1</a>
-            <a href="/page2">Page 2</a>
+            <a href="/docs">Docs</a>
+            <img src="/logo.png">
         </body></html>"#;
         let response = ScrapeResponse {
             content: html.to_string(),
@@ -6923,19 +12312,66 @@ mod tests {
             headers: HashMap::new(),
             response_time_ms: 100,
             final_url: None,
+            truncated: false,
+            raw_content: None,
+            http_version: None,
+            tls_profile: None,
+            har: None,
+            mhtml: None,
+            pdf: None,
+            blocked_requests: None,
+            evaluate_results: Vec::new(),
+            frames: Vec::new(),
+            accessibility: None,
+            timings: None,
+            ip_family: None,
+            blocked_by: None,
+            escalations: Vec::new(),
         };
-        let config = make_crawl_config(None, None);
-        let result = worker
-            .extract_and_queue_links(&task, &response, Uuid::new_v4(), 0, &config)
+
+        for (download_assets, expected) in [(None, 1), (Some(true), 2)] {
+            let task_repo = Arc::new(ConfigurableTaskRepo::new());
+            let worker = build_configurable_worker(
+                task_repo.clone(),
+                Arc::new(ConfigurableCrawlRepo::new()),
+                Arc::new(MockRobotsChecker),
+                Arc::new(EngineClient::new()),
+            )
             .await;
-        assert!(result.is_ok());
+            let mut config = make_crawl_config(None, None);
+            config.download_assets = download_assets;
+
+            worker
+                .extract_and_queue_links(
+                    &make_task(json!({})),
+                    &response,
+                    Uuid::new_v4(),
+                    0,
+                    &config,
+                )
+                .await
+                .unwrap();
+            assert_eq!(task_repo.create_count(), expected);
+        }
+    }
 
-        // page1 was skipped (existing), page2 was created → create_count == 1
-        assert_eq!(
-            task_repo.create_count(),
-            1,
-            "only non-existing URLs should be created (page1 skipped, page2 created)"
-        );
+    #[test]
+    fn test_with_discovered_via_records_link_source() {
+        let payload = json!({
+            "discovered_via": {"source_url": "https://example.com", "rel": ["nofollow"]}
+        });
+
+        let meta = with_discovered_via(None, &payload).unwrap();
+        assert_eq!(meta["discovered_via"]["rel"][0], "nofollow");
+
+        let meta = with_discovered_via(Some(json!({"title": "Hi"})), &payload).unwrap();
+        assert_eq!(meta["title"], "Hi");
+        assert_eq!(meta["discovered_via"]["source_url"], "https://example.com");
+
+        // 提取字段同名时保留提取结果；无来源信息时原样返回
+        let meta = with_discovered_via(Some(json!({"discovered_via": 1})), &payload).unwrap();
+        assert_eq!(meta["discovered_via"], 1);
+        assert_eq!(with_discovered_via(None, &json!({})), None);
     }
 
     // ========== handle_scrape_success: token deduct failure (line 994) ==========
@@ -6978,6 +12414,21 @@ mod tests {
             headers: HashMap::new(),
             response_time_ms: 10,
             final_url: None,
+            truncated: false,
+            raw_content: None,
+            http_version: None,
+            tls_profile: None,
+            har: None,
+            mhtml: None,
+            pdf: None,
+            blocked_requests: None,
+            evaluate_results: Vec::new(),
+            frames: Vec::new(),
+            accessibility: None,
+            timings: None,
+            ip_family: None,
+            blocked_by: None,
+            escalations: Vec::new(),
         };
 
         let result = worker.handle_scrape_success(&task, &response).await;
@@ -0,0 +1,295 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+use crate::domain::models::{TaskEvent, TaskEventType};
+use crate::domain::repositories::task_event_repository::TaskEventRepository;
+use crate::domain::repositories::task_repository::TaskRepository;
+use crate::domain::services::retry_handler::{HandleFailureResult, RetryHandler};
+use crate::utils::retry_policy::RetryPolicy;
+use crate::workers::worker::{ProcessResult, WorkerProcess};
+use async_trait::async_trait;
+use log::{info, warn};
+#[cfg(feature = "metrics")]
+use metrics::counter;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// 单个周期最多回收的任务数
+const MAX_REAPED_PER_CYCLE: usize = 100;
+
+/// 单个周期的回收结果
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ReapSummary {
+    /// 重新排队等待重试的任务数
+    pub requeued: u64,
+    /// 超过重试次数被标记失败的任务数
+    pub failed: u64,
+}
+
+/// 卡死任务回收工作器
+///
+/// 定期扫描锁已过期但仍处于 `Active` 状态的任务（执行它的 Worker 已崩溃），
+/// 将尝试次数加一后按重试策略重新排队或标记失败，避免任务被永久搁置。
+pub struct StalledTaskReaper {
+    repository: Arc<dyn TaskRepository>,
+    retry_handler: RetryHandler,
+    event_repository: Option<Arc<dyn TaskEventRepository>>,
+    /// 回收时用于加锁的身份
+    reaper_id: Uuid,
+}
+
+impl StalledTaskReaper {
+    /// 创建回收工作器，重试策略与抓取工作器一致
+    pub fn new(repository: Arc<dyn TaskRepository>) -> Self {
+        Self {
+            retry_handler: RetryHandler::new(repository.clone(), RetryPolicy::slow()),
+            repository,
+            event_repository: None,
+            reaper_id: Uuid::new_v4(),
+        }
+    }
+
+    /// 注入任务事件仓储，记录重试与失败事件
+    pub fn with_event_repository(mut self, event_repository: Arc<dyn TaskEventRepository>) -> Self {
+        self.event_repository = Some(event_repository);
+        self
+    }
+
+    async fn record_event(&self, event: TaskEvent) {
+        if let Some(event_repository) = &self.event_repository {
+            if let Err(e) = event_repository.record(&event).await {
+                warn!(
+                    "Failed to record {} event for task {}: {}",
+                    event.event_type, event.task_id, e
+                );
+            }
+        }
+    }
+
+    /// 回收一批卡死任务
+    pub async fn reap(&self) -> Result<ReapSummary, String> {
+        let mut summary = ReapSummary::default();
+        for _ in 0..MAX_REAPED_PER_CYCLE {
+            let Some(mut task) = self
+                .repository
+                .acquire_stalled(self.reaper_id)
+                .await
+                .map_err(|e| e.to_string())?
+            else {
+                break;
+            };
+
+            warn!(
+                "Task {} stalled: lock expired while active (attempt {})",
+                task.id, task.attempt_count
+            );
+            task.release_lock();
+            let attempt = task.attempt_count;
+            match self.retry_handler.handle_failure(&mut task).await {
+                HandleFailureResult::Retried { next_retry_at, .. } => {
+                    summary.requeued += 1;
+                    #[cfg(feature = "metrics")]
+                    counter!("stalled_tasks_reaped_total", "outcome" => "requeued").increment(1);
+                    self.record_event(
+                        TaskEvent::new(task.id, TaskEventType::Retried)
+                            .with_attempt(attempt)
+                            .with_message(format!(
+                                "Worker lock expired, retrying at {}",
+                                next_retry_at
+                            )),
+                    )
+                    .await;
+                }
+                HandleFailureResult::Failed => {
+                    summary.failed += 1;
+                    #[cfg(feature = "metrics")]
+                    counter!("stalled_tasks_reaped_total", "outcome" => "failed").increment(1);
+                    self.record_event(
+                        TaskEvent::new(task.id, TaskEventType::Failed)
+                            .with_attempt(attempt)
+                            .with_message("Worker lock expired, retries exhausted"),
+                    )
+                    .await;
+                }
+                HandleFailureResult::Error(e) => {
+                    return Err(format!("failed to reschedule task {}: {}", task.id, e));
+                }
+            }
+        }
+        Ok(summary)
+    }
+}
+
+#[async_trait]
+impl WorkerProcess for StalledTaskReaper {
+    fn name(&self) -> &str {
+        "stalled-task-reaper"
+    }
+
+    async fn process(&self) -> ProcessResult {
+        match self.reap().await {
+            Ok(summary) if summary == ReapSummary::default() => ProcessResult::Empty,
+            Ok(summary) => {
+                info!(
+                    "Reaped stalled tasks: {} requeued, {} failed",
+                    summary.requeued, summary.failed
+                );
+                ProcessResult::Completed
+            }
+            Err(e) => ProcessResult::Error(format!("Failed to reap stalled tasks: {}", e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::{Task, TaskStatus, TaskType};
+    use crate::domain::repositories::task_repository::{RepositoryError, TaskQueryParams};
+    use chrono::Utc;
+    use std::collections::HashSet;
+    use std::sync::Mutex;
+
+    /// Hands out the seeded stalled tasks one by one and records updates.
+    struct MockTaskRepository {
+        stalled: Mutex<Vec<Task>>,
+        updated: Mutex<Vec<Task>>,
+    }
+
+    impl MockTaskRepository {
+        fn with_stalled(stalled: Vec<Task>) -> Self {
+            Self {
+                stalled: Mutex::new(stalled),
+                updated: Mutex::new(vec![]),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl TaskRepository for MockTaskRepository {
+        async fn create(&self, task: &Task) -> Result<Task, RepositoryError> {
+            Ok(task.clone())
+        }
+        async fn find_by_id(&self, _id: Uuid) -> Result<Option<Task>, RepositoryError> {
+            Ok(None)
+        }
+        async fn update(&self, task: &Task) -> Result<Task, RepositoryError> {
+            self.updated.lock().unwrap().push(task.clone());
+            Ok(task.clone())
+        }
+        async fn acquire_next(&self, _worker_id: Uuid) -> Result<Option<Task>, RepositoryError> {
+            Ok(None)
+        }
+        async fn acquire_stalled(&self, worker_id: Uuid) -> Result<Option<Task>, RepositoryError> {
+            let mut task = self.stalled.lock().unwrap().pop();
+            if let Some(task) = task.as_mut() {
+                task.acquire_lock(worker_id, chrono::Duration::minutes(5));
+            }
+            Ok(task)
+        }
+        async fn mark_completed(&self, _id: Uuid) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+        async fn mark_failed(&self, _id: Uuid) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+        async fn mark_cancelled(&self, _id: Uuid) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+        async fn exists_by_url(&self, _url: &str) -> Result<bool, RepositoryError> {
+            Ok(false)
+        }
+        async fn find_existing_urls(
+            &self,
+            _urls: &[String],
+        ) -> Result<HashSet<String>, RepositoryError> {
+            Ok(HashSet::new())
+        }
+        async fn reset_stuck_tasks(
+            &self,
+            _timeout: chrono::Duration,
+        ) -> Result<u64, RepositoryError> {
+            Ok(0)
+        }
+        async fn cancel_tasks_by_crawl_id(&self, _crawl_id: Uuid) -> Result<u64, RepositoryError> {
+            Ok(0)
+        }
+        async fn expire_tasks(&self) -> Result<u64, RepositoryError> {
+            Ok(0)
+        }
+        async fn find_by_crawl_id(&self, _crawl_id: Uuid) -> Result<Vec<Task>, RepositoryError> {
+            Ok(vec![])
+        }
+        async fn query_tasks(
+            &self,
+            _params: TaskQueryParams,
+        ) -> Result<(Vec<Task>, u64), RepositoryError> {
+            Ok((vec![], 0))
+        }
+        async fn batch_cancel(
+            &self,
+            _task_ids: Vec<Uuid>,
+            _team_id: Uuid,
+            _force: bool,
+        ) -> Result<(Vec<Uuid>, Vec<(Uuid, String)>), RepositoryError> {
+            Ok((vec![], vec![]))
+        }
+    }
+
+    fn stalled_task(attempt_count: i32) -> Task {
+        let mut task = Task::new(
+            Uuid::new_v4(),
+            TaskType::Scrape,
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            "https://example.com".to_string(),
+            serde_json::json!({}),
+        );
+        task.start();
+        task.acquire_lock(Uuid::new_v4(), chrono::Duration::minutes(-1));
+        task.attempt_count = attempt_count;
+        task
+    }
+
+    #[test]
+    fn test_worker_name() {
+        let reaper = StalledTaskReaper::new(Arc::new(MockTaskRepository::with_stalled(vec![])));
+        assert_eq!(reaper.name(), "stalled-task-reaper");
+    }
+
+    #[tokio::test]
+    async fn test_process_is_empty_without_stalled_tasks() {
+        let reaper = StalledTaskReaper::new(Arc::new(MockTaskRepository::with_stalled(vec![])));
+        assert_eq!(reaper.process().await, ProcessResult::Empty);
+    }
+
+    #[tokio::test]
+    async fn test_reap_requeues_or_fails_by_attempts() {
+        let repo = Arc::new(MockTaskRepository::with_stalled(vec![
+            stalled_task(0),
+            stalled_task(5),
+        ]));
+        let reaper = StalledTaskReaper::new(repo.clone());
+
+        let summary = reaper.reap().await.expect("reap should succeed");
+        assert_eq!(
+            summary,
+            ReapSummary {
+                requeued: 1,
+                failed: 1
+            }
+        );
+
+        let updated = repo.updated.lock().unwrap();
+        let failed = &updated[0];
+        assert_eq!(failed.status, TaskStatus::Failed);
+        assert_eq!(failed.attempt_count, 6);
+        let requeued = &updated[1];
+        assert_eq!(requeued.status, TaskStatus::Queued);
+        assert_eq!(requeued.attempt_count, 1);
+        assert!(requeued.lock_token.is_none());
+        assert!(requeued.scheduled_at.unwrap() > Utc::now());
+    }
+}
@@ -0,0 +1,266 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! 任务锁租约
+//!
+//! 领取任务时只加锁 `task_lock_duration_seconds`，渲染耗时超过锁时长的任务会被
+//! 其他 Worker 当作锁过期任务重新领取。执行期间按锁时长的三分之一周期续期，
+//! 续期发现锁已被他人持有、任务已结束，或续期持续出错直到锁到期时视为租约丢失，
+//! 当前执行随即中止，避免同一任务被重复处理。
+
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::Utc;
+use log::{debug, warn};
+use tokio::time::Instant;
+use uuid::Uuid;
+
+use crate::domain::models::Task;
+use crate::domain::repositories::task_repository::TaskRepository;
+
+/// 续期的最短间隔
+const MIN_RENEW_INTERVAL: Duration = Duration::from_secs(1);
+
+/// 受租约保护的执行结果
+#[derive(Debug, PartialEq, Eq)]
+pub enum LeaseOutcome<T> {
+    /// 执行在持有租约期间完成
+    Completed(T),
+    /// 租约丢失，执行已中止
+    Lost,
+}
+
+/// 任务锁租约
+pub struct TaskLease {
+    repository: Arc<dyn TaskRepository>,
+    task_id: Uuid,
+    worker_id: Uuid,
+    lock_duration: Duration,
+    /// 当前锁的本地到期时间
+    expires_at: Instant,
+}
+
+impl TaskLease {
+    /// 为已领取的任务创建租约
+    ///
+    /// # 参数
+    ///
+    /// * `repository` - 任务仓库
+    /// * `task` - 已由 `worker_id` 加锁的任务
+    /// * `worker_id` - 持有锁的 Worker
+    /// * `lock_duration` - 每次续期延长的锁时长
+    pub fn new(
+        repository: Arc<dyn TaskRepository>,
+        task: &Task,
+        worker_id: Uuid,
+        lock_duration: Duration,
+    ) -> Self {
+        let remaining = task
+            .lock_expires_at
+            .and_then(|expires_at| (expires_at - Utc::now()).to_std().ok())
+            .unwrap_or(lock_duration);
+        Self {
+            repository,
+            task_id: task.id,
+            worker_id,
+            lock_duration,
+            expires_at: Instant::now() + remaining,
+        }
+    }
+
+    /// 续期间隔：锁时长的三分之一，至少 1 秒
+    pub fn renew_interval(&self) -> Duration {
+        (self.lock_duration / 3).max(MIN_RENEW_INTERVAL)
+    }
+
+    /// 在持有租约期间执行 `work`，租约丢失时丢弃 `work` 并返回 [`LeaseOutcome::Lost`]
+    pub async fn run<F: Future>(mut self, work: F) -> LeaseOutcome<F::Output> {
+        tokio::select! {
+            biased;
+            output = work => LeaseOutcome::Completed(output),
+            _ = self.keep_alive() => LeaseOutcome::Lost,
+        }
+    }
+
+    /// 周期性续期，仅在租约丢失时返回
+    async fn keep_alive(&mut self) {
+        let interval = self.renew_interval();
+        loop {
+            // 最迟在本地记录的到期时间发起续期
+            let next = (Instant::now() + interval).min(self.expires_at);
+            tokio::time::sleep_until(next).await;
+
+            match self
+                .repository
+                .renew_lock(self.task_id, self.worker_id)
+                .await
+            {
+                Ok(true) => {
+                    self.expires_at = Instant::now() + self.lock_duration;
+                    debug!("Renewed lock on task {}", self.task_id);
+                }
+                Ok(false) => {
+                    warn!(
+                        "Worker {} no longer holds the lock on task {}",
+                        self.worker_id, self.task_id
+                    );
+                    return;
+                }
+                Err(e) => {
+                    warn!("Failed to renew lock on task {}: {}", self.task_id, e);
+                    if Instant::now() >= self.expires_at {
+                        warn!("Lock on task {} expired without renewal", self.task_id);
+                        return;
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::{TaskStatus, TaskType};
+    use crate::domain::repositories::task_repository::{RepositoryError, TaskQueryParams};
+    use async_trait::async_trait;
+    use std::collections::HashSet;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Grants `grants` renewals, then reports the lock as taken over.
+    struct RenewingRepo {
+        grants: usize,
+        renewals: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl TaskRepository for RenewingRepo {
+        async fn create(&self, task: &Task) -> Result<Task, RepositoryError> {
+            Ok(task.clone())
+        }
+        async fn find_by_id(&self, _id: Uuid) -> Result<Option<Task>, RepositoryError> {
+            Ok(None)
+        }
+        async fn update(&self, task: &Task) -> Result<Task, RepositoryError> {
+            Ok(task.clone())
+        }
+        async fn acquire_next(&self, _worker_id: Uuid) -> Result<Option<Task>, RepositoryError> {
+            Ok(None)
+        }
+        async fn renew_lock(&self, _id: Uuid, _worker_id: Uuid) -> Result<bool, RepositoryError> {
+            let renewals = self.renewals.fetch_add(1, Ordering::SeqCst);
+            Ok(renewals < self.grants)
+        }
+        async fn mark_completed(&self, _id: Uuid) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+        async fn mark_failed(&self, _id: Uuid) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+        async fn mark_cancelled(&self, _id: Uuid) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+        async fn exists_by_url(&self, _url: &str) -> Result<bool, RepositoryError> {
+            Ok(false)
+        }
+        async fn find_existing_urls(
+            &self,
+            _urls: &[String],
+        ) -> Result<HashSet<String>, RepositoryError> {
+            Ok(HashSet::new())
+        }
+        async fn reset_stuck_tasks(
+            &self,
+            _timeout: chrono::Duration,
+        ) -> Result<u64, RepositoryError> {
+            Ok(0)
+        }
+        async fn cancel_tasks_by_crawl_id(&self, _crawl_id: Uuid) -> Result<u64, RepositoryError> {
+            Ok(0)
+        }
+        async fn expire_tasks(&self) -> Result<u64, RepositoryError> {
+            Ok(0)
+        }
+        async fn find_by_crawl_id(&self, _crawl_id: Uuid) -> Result<Vec<Task>, RepositoryError> {
+            Ok(vec![])
+        }
+        async fn query_tasks(
+            &self,
+            _params: TaskQueryParams,
+        ) -> Result<(Vec<Task>, u64), RepositoryError> {
+            Ok((vec![], 0))
+        }
+        async fn batch_cancel(
+            &self,
+            _task_ids: Vec<Uuid>,
+            _team_id: Uuid,
+            _force: bool,
+        ) -> Result<(Vec<Uuid>, Vec<(Uuid, String)>), RepositoryError> {
+            Ok((vec![], vec![]))
+        }
+    }
+
+    fn locked_task(worker_id: Uuid, lock_duration: Duration) -> Task {
+        let mut task = Task::new(
+            Uuid::new_v4(),
+            TaskType::Scrape,
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            "https://example.com".to_string(),
+            serde_json::json!({}),
+        );
+        task.status = TaskStatus::Active;
+        task.acquire_lock(
+            worker_id,
+            chrono::Duration::from_std(lock_duration).unwrap(),
+        );
+        task
+    }
+
+    fn lease(grants: usize) -> (Arc<RenewingRepo>, TaskLease) {
+        let repo = Arc::new(RenewingRepo {
+            grants,
+            renewals: AtomicUsize::new(0),
+        });
+        let worker_id = Uuid::new_v4();
+        let lock_duration = Duration::from_secs(30);
+        let task = locked_task(worker_id, lock_duration);
+        let lease = TaskLease::new(repo.clone(), &task, worker_id, lock_duration);
+        (repo, lease)
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_long_running_work_keeps_renewing_lease() {
+        let (repo, lease) = lease(usize::MAX);
+        assert_eq!(lease.renew_interval(), Duration::from_secs(10));
+
+        let outcome = lease
+            .run(async {
+                tokio::time::sleep(Duration::from_secs(95)).await;
+                "done"
+            })
+            .await;
+
+        assert_eq!(outcome, LeaseOutcome::Completed("done"));
+        assert_eq!(repo.renewals.load(Ordering::SeqCst), 9);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_lost_lease_aborts_work() {
+        let (repo, lease) = lease(2);
+
+        let outcome = lease
+            .run(async {
+                tokio::time::sleep(Duration::from_secs(300)).await;
+                "done"
+            })
+            .await;
+
+        assert_eq!(outcome, LeaseOutcome::Lost);
+        assert_eq!(repo.renewals.load(Ordering::SeqCst), 3);
+    }
+}
@@ -3,8 +3,9 @@
 // Licensed under the Apache License, Version 2.0
 // See LICENSE file in the project root for full license information.
 
-use crate::domain::models::{WebhookEvent, WebhookStatus};
+use crate::domain::models::{DeliveryChannel, WebhookEvent, WebhookEventType, WebhookStatus};
 use crate::domain::repositories::webhook_event_repository::WebhookEventRepository;
+use crate::domain::services::notification_service::EventNotifier;
 use crate::domain::services::webhook_service::WebhookService;
 use crate::utils::retry_policy::RetryPolicy;
 use crate::workers::worker::{ProcessResult, WorkerProcess};
@@ -13,6 +14,7 @@ use chrono::Utc;
 use log::{error, info, warn};
 #[cfg(feature = "metrics")]
 use metrics::counter;
+use serde_json::{json, Value};
 use std::sync::Arc;
 
 /// Webhook Worker Trait
@@ -23,7 +25,8 @@ pub trait WebhookWorkerTrait: Send + Sync {
 
 /// Webhook工作器
 ///
-/// 负责处理webhook事件的发送和重试
+/// 负责处理webhook事件的发送和重试。邮件与聊天频道的通知投递记录同样保存在
+/// webhook 事件仓库中，由工作器交给事件通知重新投递。
 pub struct WebhookWorker {
     /// Webhook事件仓库
     repo: Arc<dyn WebhookEventRepository>,
@@ -31,6 +34,24 @@ pub struct WebhookWorker {
     webhook_service: Arc<dyn WebhookService>,
     /// 重试策略
     retry_policy: RetryPolicy,
+    /// 事件通知（可选），负责重试邮件与聊天频道的投递记录，webhook 事件进入死信状态时
+    /// 通知团队的邮件联系人与聊天频道
+    notifier: Option<Arc<dyn EventNotifier>>,
+}
+
+/// 构造 `webhook.delivery_failed` 通知的负载
+fn delivery_failed_payload(event: &WebhookEvent) -> Value {
+    json!({
+        "event": WebhookEventType::WebhookDeliveryFailed.to_string(),
+        "team_id": event.team_id,
+        "webhook_id": event.webhook_id,
+        "event_id": event.id,
+        "event_type": event.event_type.to_string(),
+        "webhook_url": event.webhook_url,
+        "attempts": event.attempt_count,
+        "error": event.error_message,
+        "timestamp": Utc::now().to_rfc3339(),
+    })
 }
 
 impl WebhookWorker {
@@ -44,9 +65,17 @@ impl WebhookWorker {
             repo,
             webhook_service,
             retry_policy,
+            notifier: None,
         }
     }
 
+    /// 配置事件通知，用于重试通知投递记录，并在 webhook 投递最终失败时发送
+    /// `webhook.delivery_failed` 通知
+    pub fn with_notifier(mut self, notifier: Arc<dyn EventNotifier>) -> Self {
+        self.notifier = Some(notifier);
+        self
+    }
+
     /// 使用默认重试策略创建webhook工作器
     pub fn with_default_policy(
         repo: Arc<dyn WebhookEventRepository>,
@@ -83,8 +112,16 @@ impl WebhookWorker {
             event.id, event.webhook_url, event.attempt_count
         );
 
-        // 尝试发送webhook
-        match self.webhook_service.send_webhook(&event).await {
+        // 尝试发送webhook，通知投递记录交给对应渠道
+        let result = match (event.channel, &self.notifier) {
+            (DeliveryChannel::Webhook, _) => self.webhook_service.send_webhook(&event).await,
+            (_, Some(notifier)) => notifier.redeliver(&event).await,
+            (channel, None) => Err(anyhow::anyhow!(
+                "No notifier configured to deliver {} notifications",
+                channel
+            )),
+        };
+        match result {
             Ok(_) => {
                 info!("Successfully delivered webhook {}", event.id);
                 event.status = WebhookStatus::Delivered;
@@ -165,6 +202,23 @@ impl WebhookWorker {
             .update(&event)
             .await
             .map_err(|e| anyhow::anyhow!("Failed to update event: {}", e))?;
+
+        // 只有 webhook 投递最终失败时通知，通知投递记录失败不再触发通知以免循环；
+        // 通知失败只记录日志
+        if let (WebhookStatus::Dead, DeliveryChannel::Webhook, Some(notifier)) =
+            (&event.status, event.channel, &self.notifier)
+        {
+            if let Err(e) = notifier
+                .notify(
+                    event.team_id,
+                    &WebhookEventType::WebhookDeliveryFailed,
+                    &delivery_failed_payload(&event),
+                )
+                .await
+            {
+                warn!("{}", e);
+            }
+        }
         Ok(())
     }
 }
@@ -517,6 +571,76 @@ mod tests {
         assert!(updated[0].error_message.is_some());
     }
 
+    /// Records the payload of every notification
+    #[derive(Default)]
+    struct RecordingNotifier {
+        payloads: Mutex<Vec<(WebhookEventType, serde_json::Value)>>,
+        redelivered: Mutex<Vec<Uuid>>,
+    }
+
+    #[async_trait]
+    impl EventNotifier for RecordingNotifier {
+        async fn notify(
+            &self,
+            _team_id: Uuid,
+            event_type: &WebhookEventType,
+            payload: &serde_json::Value,
+        ) -> Result<usize> {
+            self.payloads
+                .lock()
+                .unwrap()
+                .push((event_type.clone(), payload.clone()));
+            Ok(1)
+        }
+
+        async fn redeliver(&self, delivery: &WebhookEvent) -> Result<()> {
+            self.redelivered.lock().unwrap().push(delivery.id);
+            Err(anyhow!("channel unavailable"))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_dead_webhook_notifies_delivery_failure() {
+        let mut dead = make_test_event(4);
+        dead.max_retries = 5;
+        let retried = make_test_event(0);
+        let notifier = Arc::new(RecordingNotifier::default());
+        let repo = Arc::new(MockWebhookRepo::new(vec![dead.clone(), retried]));
+        let worker = make_worker(repo, Arc::new(MockWebhookService::new_failure()))
+            .with_notifier(notifier.clone());
+
+        worker.process_pending_webhooks().await.unwrap();
+
+        let payloads = notifier.payloads.lock().unwrap();
+        assert_eq!(payloads.len(), 1, "only the dead event is notified");
+        assert_eq!(payloads[0].0, WebhookEventType::WebhookDeliveryFailed);
+        assert_eq!(payloads[0].1["event_id"], dead.id.to_string());
+        assert_eq!(payloads[0].1["event_type"], "scrape.completed");
+        assert_eq!(payloads[0].1["attempts"], 5);
+    }
+
+    #[tokio::test]
+    async fn test_notification_delivery_is_redelivered_through_notifier() {
+        let mut dead = make_test_event(4).with_channel(DeliveryChannel::Slack);
+        dead.max_retries = 5;
+        let notifier = Arc::new(RecordingNotifier::default());
+        let repo = Arc::new(MockWebhookRepo::new(vec![dead.clone()]));
+        let service = Arc::new(MockWebhookService::new_success());
+        let worker = make_worker(repo.clone(), service.clone()).with_notifier(notifier.clone());
+
+        worker.process_pending_webhooks().await.unwrap();
+
+        assert_eq!(service.send_count(), 0, "webhook service is not used");
+        assert_eq!(*notifier.redelivered.lock().unwrap(), vec![dead.id]);
+        let updated = repo.updated_events();
+        assert_eq!(updated.len(), 1);
+        assert_eq!(updated[0].status, WebhookStatus::Dead);
+        assert!(
+            notifier.payloads.lock().unwrap().is_empty(),
+            "dead notifications do not trigger webhook.delivery_failed"
+        );
+    }
+
     #[tokio::test]
     async fn test_webhook_failure_with_non_retryable_error_moves_to_dead() {
         // Use a service that returns a non-retryable error
@@ -7,3 +7,8 @@
 ///
 /// 提供数据库设置等测试固件
 pub mod database;
+
+/// 确定性的 mock 目标站点（路由、延迟、robots.txt、挑战页、重定向链）
+///
+/// 实现位于 `crawlrs::utils::mock_site`，需以 `--features test-mocks` 运行。
+pub use crawlrs::utils::mock_site::{MockResponse, MockSite, MockSiteHandle};
@@ -36,6 +36,8 @@ fn make_scrape_result(
         screenshot: None,
         response_time_ms,
         created_at: chrono::Utc::now().naive_utc(),
+        etag: None,
+        last_modified: None,
     }
 }
 
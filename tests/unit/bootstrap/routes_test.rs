@@ -40,8 +40,8 @@ use crawlrs::bootstrap::routes::{
     create_v2_routes_with_state,
 };
 use crawlrs::di::modules::{
-    CacheModule, DatabaseModule, EngineModule, HttpModule, InfrastructureModule, RepositoryModule,
-    ServiceModule, SettingsModule,
+    CacheModule, DatabaseModule, EngineModule, HttpModule, InfrastructureModule,
+    ReloadableSettingsModule, RepositoryModule, ServiceModule, SettingsModule,
 };
 use crawlrs::di::{CrawlRsState, CrawlRsStateExt};
 use trait_kit::AsyncKit;
@@ -136,6 +136,8 @@ async fn build_app_state(
         .map_err(|e| anyhow::anyhow!("register CacheModule: {e}"))?;
     kit.register::<RepositoryModule>()
         .map_err(|e| anyhow::anyhow!("register RepositoryModule: {e}"))?;
+    kit.register::<ReloadableSettingsModule>()
+        .map_err(|e| anyhow::anyhow!("register ReloadableSettingsModule: {e}"))?;
     kit.register::<EngineModule>()
         .map_err(|e| anyhow::anyhow!("register EngineModule: {e}"))?;
     kit.register::<InfrastructureModule>()
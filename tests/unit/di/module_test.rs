@@ -19,7 +19,7 @@ use crawlrs::bootstrap::config::load_settings;
 use crawlrs::config::settings::Settings;
 use crawlrs::di::modules::{
     CacheComponents, CacheModule, DatabaseModule, EngineModule, HttpModule, InfrastructureModule,
-    ModuleBuildError, RepositoryModule, ServiceModule, SettingsModule,
+    ModuleBuildError, ReloadableSettingsModule, RepositoryModule, ServiceModule, SettingsModule,
 };
 use crawlrs::di::CrawlRsState;
 
@@ -32,6 +32,11 @@ fn settings_module_name() {
     assert_eq!(SettingsModule::NAME, "settings");
 }
 
+#[test]
+fn reloadable_settings_module_name() {
+    assert_eq!(ReloadableSettingsModule::NAME, "reloadable-settings");
+}
+
 #[test]
 fn database_module_name() {
     assert_eq!(DatabaseModule::NAME, "database");
@@ -95,6 +100,13 @@ fn settings_module_has_no_dependencies() {
     );
 }
 
+#[test]
+fn reloadable_settings_module_depends_on_settings() {
+    let deps = ReloadableSettingsModule::dependencies();
+    assert_eq!(deps.len(), 1);
+    assert_dep_contains(deps, SettingsModule::NAME, TypeId::of::<SettingsModule>());
+}
+
 #[test]
 fn database_module_depends_on_settings() {
     let deps = DatabaseModule::dependencies();
@@ -126,9 +138,14 @@ fn repository_module_depends_on_database() {
 #[test]
 fn engine_module_depends_on_http_and_settings() {
     let deps = EngineModule::dependencies();
-    assert_eq!(deps.len(), 2);
+    assert_eq!(deps.len(), 3);
     assert_dep_contains(deps, HttpModule::NAME, TypeId::of::<HttpModule>());
     assert_dep_contains(deps, SettingsModule::NAME, TypeId::of::<SettingsModule>());
+    assert_dep_contains(
+        deps,
+        ReloadableSettingsModule::NAME,
+        TypeId::of::<ReloadableSettingsModule>(),
+    );
 }
 
 #[test]
@@ -146,9 +163,9 @@ fn infrastructure_module_depends_on_four_modules() {
 }
 
 #[test]
-fn service_module_depends_on_three_modules() {
+fn service_module_depends_on_four_modules() {
     let deps = ServiceModule::dependencies();
-    assert_eq!(deps.len(), 3);
+    assert_eq!(deps.len(), 4);
     assert_dep_contains(
         deps,
         InfrastructureModule::NAME,
@@ -156,6 +173,11 @@ fn service_module_depends_on_three_modules() {
     );
     assert_dep_contains(deps, EngineModule::NAME, TypeId::of::<EngineModule>());
     assert_dep_contains(deps, SettingsModule::NAME, TypeId::of::<SettingsModule>());
+    assert_dep_contains(
+        deps,
+        ReloadableSettingsModule::NAME,
+        TypeId::of::<ReloadableSettingsModule>(),
+    );
 }
 
 // =============================================================================
@@ -167,6 +189,10 @@ fn dependency_graph_has_no_dangling_references() {
     // 所有模块名 → TypeId 的有效映射
     let valid_names: &[(&str, TypeId)] = &[
         (SettingsModule::NAME, TypeId::of::<SettingsModule>()),
+        (
+            ReloadableSettingsModule::NAME,
+            TypeId::of::<ReloadableSettingsModule>(),
+        ),
         (DatabaseModule::NAME, TypeId::of::<DatabaseModule>()),
         (HttpModule::NAME, TypeId::of::<HttpModule>()),
         (CacheModule::NAME, TypeId::of::<CacheModule>()),
@@ -181,6 +207,7 @@ fn dependency_graph_has_no_dangling_references() {
 
     let all_deps = [
         SettingsModule::dependencies(),
+        ReloadableSettingsModule::dependencies(),
         DatabaseModule::dependencies(),
         HttpModule::dependencies(),
         CacheModule::dependencies(),
@@ -221,6 +248,26 @@ async fn settings_module_registers_and_resolves() {
     assert!(kit.contains::<SettingsModule>());
 }
 
+/// ReloadableSettingsModule 以启动配置为初始快照，返回 Arc<ReloadableSettings>。
+#[tokio::test]
+async fn reloadable_settings_module_registers_and_resolves() {
+    let settings = Arc::new(load_settings().expect("Failed to load settings"));
+
+    let mut kit = AsyncKit::new();
+    kit.set_config(settings.clone());
+    kit.register::<SettingsModule>()
+        .expect("Failed to register SettingsModule");
+    kit.register::<ReloadableSettingsModule>()
+        .expect("Failed to register ReloadableSettingsModule");
+
+    let kit = kit.build().await.expect("Failed to build kit");
+    let cap = kit
+        .require::<ReloadableSettingsModule>()
+        .expect("Failed to require ReloadableSettingsModule");
+
+    assert_eq!(cap.current().server.port, settings.server.port);
+}
+
 /// HttpModule 依赖 SettingsModule，可注册、构建并解析，返回 Arc<reqwest::Client>。
 #[tokio::test]
 async fn http_module_registers_and_resolves() {
@@ -275,6 +322,8 @@ async fn engine_module_registers_and_resolves() {
         .expect("Failed to register SettingsModule");
     kit.register::<HttpModule>()
         .expect("Failed to register HttpModule");
+    kit.register::<ReloadableSettingsModule>()
+        .expect("Failed to register ReloadableSettingsModule");
     kit.register::<EngineModule>()
         .expect("Failed to register EngineModule");
 
@@ -494,6 +543,8 @@ async fn from_kit_non_docker_modules_only_returns_dependency_missing_error() {
         .expect("register SettingsModule");
     kit.register::<HttpModule>().expect("register HttpModule");
     kit.register::<CacheModule>().expect("register CacheModule");
+    kit.register::<ReloadableSettingsModule>()
+        .expect("register ReloadableSettingsModule");
     kit.register::<EngineModule>()
         .expect("register EngineModule");
 
@@ -53,6 +53,7 @@ fn test_extraction_service_basic_selectors() {
             use_llm: None,
             llm_prompt: None,
             output_format: None,
+            frame: None,
         },
     );
     rules.insert(
@@ -64,6 +65,7 @@ fn test_extraction_service_basic_selectors() {
             use_llm: None,
             llm_prompt: None,
             output_format: None,
+            frame: None,
         },
     );
     rules.insert(
@@ -75,6 +77,7 @@ fn test_extraction_service_basic_selectors() {
             use_llm: None,
             llm_prompt: None,
             output_format: None,
+            frame: None,
         },
     );
 
@@ -109,6 +112,7 @@ fn test_extraction_service_missing_elements() {
             use_llm: None,
             llm_prompt: None,
             output_format: None,
+            frame: None,
         },
     );
 
@@ -133,6 +137,7 @@ fn test_extraction_service_empty_array() {
             use_llm: None,
             llm_prompt: None,
             output_format: None,
+            frame: None,
         },
     );
 
@@ -161,6 +166,7 @@ async fn test_extraction_service_with_llm() {
             use_llm: Some(true),
             llm_prompt: Some("Extract product name and price".to_string()),
             output_format: None,
+            frame: None,
         },
     );
 
@@ -241,6 +247,7 @@ async fn test_extraction_service_comprehensive_with_real_html_and_llm() {
             use_llm: None,
             llm_prompt: None,
             output_format: None,
+            frame: None,
         },
     );
 
@@ -254,6 +261,7 @@ async fn test_extraction_service_comprehensive_with_real_html_and_llm() {
             use_llm: None,
             llm_prompt: None,
             output_format: None,
+            frame: None,
         },
     );
 
@@ -267,6 +275,7 @@ async fn test_extraction_service_comprehensive_with_real_html_and_llm() {
             use_llm: None,
             llm_prompt: None,
             output_format: None,
+            frame: None,
         },
     );
 
@@ -280,6 +289,7 @@ async fn test_extraction_service_comprehensive_with_real_html_and_llm() {
             use_llm: None,
             llm_prompt: None,
             output_format: None,
+            frame: None,
         },
     );
 
@@ -293,6 +303,7 @@ async fn test_extraction_service_comprehensive_with_real_html_and_llm() {
             use_llm: None,
             llm_prompt: None,
             output_format: None,
+            frame: None,
         },
     );
 
@@ -306,6 +317,7 @@ async fn test_extraction_service_comprehensive_with_real_html_and_llm() {
             use_llm: None,
             llm_prompt: None,
             output_format: None,
+            frame: None,
         },
     );
 
@@ -319,6 +331,7 @@ async fn test_extraction_service_comprehensive_with_real_html_and_llm() {
             use_llm: None,
             llm_prompt: None,
             output_format: None,
+            frame: None,
         },
     );
 
@@ -336,6 +349,7 @@ async fn test_extraction_service_comprehensive_with_real_html_and_llm() {
                     .to_string(),
             ),
             output_format: None,
+            frame: None,
         },
     );
 
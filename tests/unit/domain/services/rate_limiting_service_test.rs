@@ -591,7 +591,7 @@ async fn test_get_team_rate_limit_config_returns_stored_config() {
 }
 
 #[tokio::test]
-async fn test_update_team_rate_limit_config_is_noop_returns_ok() {
+async fn test_update_team_rate_limit_config_stores_team_override() {
     let task_repo = Arc::new(MockTaskRepository::new());
     let backlog_repo = Arc::new(MockTasksBacklogRepository::new());
     let credits_repo = Arc::new(MockCreditsRepository::new());
@@ -604,11 +604,26 @@ async fn test_update_team_rate_limit_config_is_noop_returns_ok() {
     .await;
 
     let team_id = Uuid::new_v4();
-    let new_config = RateLimitConfig::default();
+    let new_config = RateLimitConfig {
+        requests_per_minute: 60,
+        bucket_capacity: Some(20),
+        ..RateLimitConfig::default()
+    };
     let result = service
         .update_team_rate_limit_config(team_id, new_config)
         .await;
     assert!(result.is_ok());
+
+    let stored = service.get_team_rate_limit_config(team_id).await.unwrap();
+    assert_eq!(stored.requests_per_minute, 60);
+    assert_eq!(stored.burst_capacity(), 20);
+
+    // Other teams keep the global config
+    let other = service
+        .get_team_rate_limit_config(Uuid::new_v4())
+        .await
+        .unwrap();
+    assert_eq!(other.requests_per_minute, 100);
 }
 
 #[tokio::test]
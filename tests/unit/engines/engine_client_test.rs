@@ -107,6 +107,8 @@ mod engine_client_tests {
             EngineError::SsrfProtection(msg) => EngineError::SsrfProtection(msg.clone()),
             EngineError::BrowserError(msg) => EngineError::BrowserError(msg.clone()),
             EngineError::Expired => EngineError::Expired,
+            EngineError::ResponseTooLarge(n) => EngineError::ResponseTooLarge(*n),
+            EngineError::ContentTypeRejected(ct) => EngineError::ContentTypeRejected(ct.clone()),
             EngineError::Other(msg) => EngineError::Other(msg.clone()),
             EngineError::NoEnginesAvailable => EngineError::NoEnginesAvailable,
             EngineError::InvalidUrl(msg) => EngineError::InvalidUrl(msg.clone()),
@@ -122,6 +124,20 @@ mod engine_client_tests {
             content_type: "text/html".to_string(),
             headers: HashMap::new(),
             response_time_ms: 100,
+            truncated: false,
+            raw_content: None,
+            http_version: None,
+            tls_profile: None,
+            har: None,
+            mhtml: None,
+            pdf: None,
+            blocked_requests: None,
+            evaluate_results: Vec::new(),
+            frames: Vec::new(),
+            accessibility: None,
+            timings: None,
+            ip_family: None,
+            escalations: Vec::new(),
         }
     }
 
@@ -225,6 +241,7 @@ mod engine_client_tests {
             selector: Some(".content".to_string()),
             quality: Some(90),
             format: Some("png".to_string()),
+            max_height: None,
         };
 
         let options = ScrapeOptions::builder()
@@ -514,6 +531,7 @@ mod engine_client_tests {
             selector: None,
             quality: Some(1),
             format: Some("jpeg".to_string()),
+            max_height: None,
         };
         assert_eq!(low_quality.quality, Some(1));
 
@@ -522,6 +540,7 @@ mod engine_client_tests {
             selector: None,
             quality: Some(100),
             format: Some("jpeg".to_string()),
+            max_height: None,
         };
         assert_eq!(high_quality.quality, Some(100));
     }
@@ -602,6 +621,20 @@ mod engine_client_tests {
             content_type: "text/html".to_string(),
             headers: HashMap::new(),
             response_time_ms: 50,
+            truncated: false,
+            raw_content: None,
+            http_version: None,
+            tls_profile: None,
+            har: None,
+            mhtml: None,
+            pdf: None,
+            blocked_requests: None,
+            evaluate_results: Vec::new(),
+            frames: Vec::new(),
+            accessibility: None,
+            timings: None,
+            ip_family: None,
+            escalations: Vec::new(),
         };
         let router: Arc<dyn EngineRouterTrait> =
             Arc::new(MockEngineRouter::with_success_response(response_data));
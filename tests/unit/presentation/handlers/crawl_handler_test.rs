@@ -341,9 +341,29 @@ fn test_crawl_request_dto_validate_success() {
             proxy: None,
             headers: None,
             extraction_rules: None,
+            skip_nofollow_links: None,
+            ignore_robots: None,
+            ignore_sitemap: None,
+            limit: None,
+            max_duration_seconds: None,
+            previous_crawl_id: None,
+            allow_subdomains: None,
+            allow_external_domains: None,
+            ignore_query_params: None,
+            ignore_all_query_params: None,
+            max_query_variants_per_path: None,
+            download_assets: None,
+            allowed_content_types: None,
+            excluded_content_types: None,
+            result_destination: None,
+            keep_local_results: None,
+            formats: None,
+            resolve: None,
         },
         sync_wait_ms: Some(5000),
         expires_at: None,
+        dry_run: None,
+        priority: None,
     };
     assert!(dto.validate().is_ok());
 }
@@ -364,9 +384,29 @@ fn test_crawl_request_dto_validate_empty_url_fails() {
             proxy: None,
             headers: None,
             extraction_rules: None,
+            skip_nofollow_links: None,
+            ignore_robots: None,
+            ignore_sitemap: None,
+            limit: None,
+            max_duration_seconds: None,
+            previous_crawl_id: None,
+            allow_subdomains: None,
+            allow_external_domains: None,
+            ignore_query_params: None,
+            ignore_all_query_params: None,
+            max_query_variants_per_path: None,
+            download_assets: None,
+            allowed_content_types: None,
+            excluded_content_types: None,
+            result_destination: None,
+            keep_local_results: None,
+            formats: None,
+            resolve: None,
         },
         sync_wait_ms: None,
         expires_at: None,
+        dry_run: None,
+        priority: None,
     };
     assert!(dto.validate().is_err());
 }
@@ -387,9 +427,29 @@ fn test_crawl_request_dto_validate_sync_wait_ms_exceeds_max_fails() {
             proxy: None,
             headers: None,
             extraction_rules: None,
+            skip_nofollow_links: None,
+            ignore_robots: None,
+            ignore_sitemap: None,
+            limit: None,
+            max_duration_seconds: None,
+            previous_crawl_id: None,
+            allow_subdomains: None,
+            allow_external_domains: None,
+            ignore_query_params: None,
+            ignore_all_query_params: None,
+            max_query_variants_per_path: None,
+            download_assets: None,
+            allowed_content_types: None,
+            excluded_content_types: None,
+            result_destination: None,
+            keep_local_results: None,
+            formats: None,
+            resolve: None,
         },
         sync_wait_ms: Some(30001),
         expires_at: None,
+        dry_run: None,
+        priority: None,
     };
     assert!(dto.validate().is_err());
 }
@@ -410,9 +470,29 @@ fn test_crawl_request_dto_validate_sync_wait_ms_zero_passes() {
             proxy: None,
             headers: None,
             extraction_rules: None,
+            skip_nofollow_links: None,
+            ignore_robots: None,
+            ignore_sitemap: None,
+            limit: None,
+            max_duration_seconds: None,
+            previous_crawl_id: None,
+            allow_subdomains: None,
+            allow_external_domains: None,
+            ignore_query_params: None,
+            ignore_all_query_params: None,
+            max_query_variants_per_path: None,
+            download_assets: None,
+            allowed_content_types: None,
+            excluded_content_types: None,
+            result_destination: None,
+            keep_local_results: None,
+            formats: None,
+            resolve: None,
         },
         sync_wait_ms: Some(0),
         expires_at: None,
+        dry_run: None,
+        priority: None,
     };
     assert!(dto.validate().is_ok());
 }
@@ -433,9 +513,29 @@ fn test_crawl_request_dto_serialization_roundtrip() {
             proxy: None,
             headers: None,
             extraction_rules: None,
+            skip_nofollow_links: None,
+            ignore_robots: None,
+            ignore_sitemap: None,
+            limit: None,
+            max_duration_seconds: None,
+            previous_crawl_id: None,
+            allow_subdomains: None,
+            allow_external_domains: None,
+            ignore_query_params: None,
+            ignore_all_query_params: None,
+            max_query_variants_per_path: None,
+            download_assets: None,
+            allowed_content_types: None,
+            excluded_content_types: None,
+            result_destination: None,
+            keep_local_results: None,
+            formats: None,
+            resolve: None,
         },
         sync_wait_ms: Some(5000),
         expires_at: None,
+        dry_run: None,
+        priority: None,
     };
     let json = serde_json::to_string(&dto).unwrap();
     let deserialized: CrawlRequestDto = serde_json::from_str(&json).unwrap();
@@ -461,9 +561,29 @@ fn test_crawl_request_dto_validated_url_skipped_in_serialization() {
             proxy: None,
             headers: None,
             extraction_rules: None,
+            skip_nofollow_links: None,
+            ignore_robots: None,
+            ignore_sitemap: None,
+            limit: None,
+            max_duration_seconds: None,
+            previous_crawl_id: None,
+            allow_subdomains: None,
+            allow_external_domains: None,
+            ignore_query_params: None,
+            ignore_all_query_params: None,
+            max_query_variants_per_path: None,
+            download_assets: None,
+            allowed_content_types: None,
+            excluded_content_types: None,
+            result_destination: None,
+            keep_local_results: None,
+            formats: None,
+            resolve: None,
         },
         sync_wait_ms: None,
         expires_at: None,
+        dry_run: None,
+        priority: None,
     };
     let json = serde_json::to_string(&dto).unwrap();
     assert!(!json.contains("validated_url"));
@@ -673,6 +793,24 @@ fn test_crawl_config_dto_clone_preserves_fields() {
         proxy: Some("http://proxy:8080".to_string()),
         headers: Some(serde_json::json!({"Accept": "text/html"})),
         extraction_rules: None,
+        skip_nofollow_links: None,
+        ignore_robots: None,
+        ignore_sitemap: None,
+        limit: None,
+        max_duration_seconds: None,
+        previous_crawl_id: None,
+        allow_subdomains: None,
+        allow_external_domains: None,
+        ignore_query_params: None,
+        ignore_all_query_params: None,
+        max_query_variants_per_path: None,
+        download_assets: None,
+        allowed_content_types: None,
+        excluded_content_types: None,
+        result_destination: None,
+        keep_local_results: None,
+        formats: None,
+        resolve: None,
     };
     let cloned = config.clone();
     assert_eq!(cloned.max_depth, 3);
@@ -696,6 +834,24 @@ fn test_crawl_config_dto_serialization_roundtrip() {
         proxy: None,
         headers: None,
         extraction_rules: None,
+        skip_nofollow_links: None,
+        ignore_robots: None,
+        ignore_sitemap: None,
+        limit: None,
+        max_duration_seconds: None,
+        previous_crawl_id: None,
+        allow_subdomains: None,
+        allow_external_domains: None,
+        ignore_query_params: None,
+        ignore_all_query_params: None,
+        max_query_variants_per_path: None,
+        download_assets: None,
+        allowed_content_types: None,
+        excluded_content_types: None,
+        result_destination: None,
+        keep_local_results: None,
+        formats: None,
+        resolve: None,
     };
     let json = serde_json::to_string(&config).unwrap();
     let deserialized: CrawlConfigDto = serde_json::from_str(&json).unwrap();
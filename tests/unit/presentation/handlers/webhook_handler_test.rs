@@ -52,6 +52,9 @@ fn tc_create_webhook_request_empty_url() {
 fn tc_create_webhook_request_serialization_round_trip() {
     let original = CreateWebhookRequest {
         url: "https://my.webhook.site/abc123".to_string(),
+        event_types: vec![],
+        custom_headers: Default::default(),
+        payload_template: None,
     };
     let json = serde_json::to_string(&original).expect("must serialize");
     let parsed: CreateWebhookRequest = serde_json::from_str(&json).expect("must deserialize");
@@ -82,6 +85,7 @@ fn tc_webhook_response_serialization_with_secret() {
         id: Uuid::new_v4(),
         team_id: Uuid::new_v4(),
         url: "https://example.com/hook".to_string(),
+        event_types: vec![],
         created_at: Utc::now(),
         is_active: true,
         secret: Some("secret123".to_string()),
@@ -99,6 +103,7 @@ fn tc_webhook_response_serialization_secret_none() {
         id: Uuid::new_v4(),
         team_id: Uuid::new_v4(),
         url: "https://example.com/hook".to_string(),
+        event_types: vec![],
         created_at: Utc::now(),
         is_active: false,
         secret: None,
@@ -117,6 +122,7 @@ fn tc_webhook_response_serialization_includes_all_fields() {
         id,
         team_id,
         url: "https://x.com".to_string(),
+        event_types: vec![],
         created_at: Utc::now(),
         is_active: true,
         secret: Some("s".to_string()),
@@ -136,6 +142,7 @@ fn tc_webhook_response_clone_preserves_fields() {
         id: Uuid::new_v4(),
         team_id: Uuid::new_v4(),
         url: "https://clone.example.com".to_string(),
+        event_types: vec![],
         created_at: Utc::now(),
         is_active: true,
         secret: Some("secret".to_string()),
@@ -169,6 +176,7 @@ fn tc_webhook_list_response_with_items() {
         id: Uuid::new_v4(),
         team_id: Uuid::new_v4(),
         url: "https://hook1.example.com".to_string(),
+        event_types: vec![],
         created_at: Utc::now(),
         is_active: true,
         secret: None,
@@ -177,6 +185,7 @@ fn tc_webhook_list_response_with_items() {
         id: Uuid::new_v4(),
         team_id: Uuid::new_v4(),
         url: "https://hook2.example.com".to_string(),
+        event_types: vec![],
         created_at: Utc::now(),
         is_active: false,
         secret: Some("s2".to_string()),
@@ -200,6 +209,7 @@ fn tc_webhook_list_response_total_matches_count() {
             id: Uuid::new_v4(),
             team_id: Uuid::new_v4(),
             url: "https://example.com".to_string(),
+            event_types: vec![],
             created_at: Utc::now(),
             is_active: true,
             secret: None,
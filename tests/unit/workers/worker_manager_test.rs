@@ -227,9 +227,43 @@ impl ScrapeResultRepository for MockScrapeResultRepository {
         Ok(vec![])
     }
 
+    async fn find_latest_for_crawl_url(
+        &self,
+        _crawl_id: Uuid,
+        _url: &str,
+    ) -> anyhow::Result<Option<ScrapeResult>> {
+        Ok(None)
+    }
+
+    async fn count_not_modified(&self, _task_ids: &[Uuid]) -> anyhow::Result<u64> {
+        Ok(0)
+    }
+
+    async fn response_times(&self, _task_ids: &[Uuid]) -> anyhow::Result<Vec<i64>> {
+        Ok(Vec::new())
+    }
+
     async fn get_team_avg_response_time(&self, _team_id: Uuid) -> anyhow::Result<f64> {
         Ok(0.0)
     }
+
+    async fn delete_for_team(
+        &self,
+        _team_id: Uuid,
+        _filter: &crawlrs::domain::repositories::scrape_result_repository::ResultDeletionFilter,
+    ) -> anyhow::Result<Vec<ScrapeResult>> {
+        Ok(vec![])
+    }
+
+    async fn search_crawl_results(
+        &self,
+        _crawl_id: Uuid,
+        _query: &str,
+        _limit: u64,
+    ) -> anyhow::Result<Vec<crawlrs::domain::repositories::scrape_result_repository::ResultSearchHit>>
+    {
+        Ok(vec![])
+    }
 }
 
 /// Mock CrawlRepository that always succeeds.
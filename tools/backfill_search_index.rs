@@ -0,0 +1,65 @@
+// Copyright (c) 2025 Kirky.X
+//
+// Licensed under the Apache License, Version 2.0
+// See LICENSE file in the project root for full license information.
+
+//! Admin CLI: mirror stored scrape results into the external search index.
+//!
+//! Reads the `[search_index]` settings, creates the index if it is missing and
+//! bulk-indexes results in batches of `search_index.batch_size`. Documents are
+//! keyed by result id, so the backfill can be re-run safely.
+//!
+//! Usage:
+//!   CRAWLRS__SEARCH_INDEX__URL=http://localhost:9200 \
+//!     cargo run --features admin-tools --bin backfill_search_index -- [crawl-uuid]
+
+use std::sync::Arc;
+
+use crawlrs::bootstrap::config::load_settings;
+use crawlrs::domain::services::result_indexer::ResultIndexer;
+use crawlrs::infrastructure::database::repositories::scrape_result_repo_impl::ScrapeResultRepositoryImpl;
+use crawlrs::infrastructure::services::opensearch_indexer::OpenSearchResultIndexer;
+use dbnexus::{DbConfig, DbPool};
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let settings = load_settings()?;
+    if settings.search_index.url.is_empty() {
+        return Err("search_index.url must be set".into());
+    }
+    let crawl_id = std::env::args()
+        .nth(1)
+        .map(|arg| uuid::Uuid::parse_str(&arg))
+        .transpose()?;
+
+    println!("Connecting to database...");
+    let cfg = DbConfig {
+        url: settings.database.url().to_string(),
+        ..Default::default()
+    };
+    let pool = Arc::new(DbPool::with_config(cfg).await?);
+    let repo = ScrapeResultRepositoryImpl::new(pool);
+
+    let indexer = OpenSearchResultIndexer::from_settings(&settings.search_index);
+    indexer.ensure_index().await?;
+
+    let batch_size = u64::from(settings.search_index.batch_size.max(1));
+    let mut after = None;
+    let (mut read, mut indexed) = (0usize, 0usize);
+    loop {
+        let batch = repo.find_for_indexing(crawl_id, after, batch_size).await?;
+        let Some(last) = batch.last() else {
+            break;
+        };
+        after = Some((last.result.created_at.and_utc(), last.result.id));
+        read += batch.len();
+        indexed += indexer.index(&batch).await?;
+        println!("Indexed {} of {} results...", indexed, read);
+    }
+
+    println!(
+        "Backfill complete: {} of {} results indexed into '{}'",
+        indexed, read, settings.search_index.index
+    );
+    Ok(())
+}